@@ -1,11 +1,15 @@
 //! Buffer Manager - Core buffer profile and PG management
 
 use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
 use sonic_cfgmgr_common::{
-    CfgMgr, CfgMgrResult, FieldValues, FieldValuesExt, WarmRestartState,
+    CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, FieldValuesExt, KeyOpFieldsValues,
+    WarmRestartState, WarmRestartStore,
 };
 use sonic_orch_common::Orch;
-use tracing::info;
+use tracing::{debug, error, info};
 
 use crate::pg_bitmap::{generate_pg_combinations, pfc_to_bitmap};
 use crate::tables::*;
@@ -31,6 +35,15 @@ pub struct BufferMgr {
     /// Admin status per port ("up" or "down")
     port_status_lookup: PortAdminStatus,
 
+    /// Operational MTU per port from STATE_DB
+    port_oper_mtu: PortOperMtu,
+
+    /// Lossless PGs written to APPL_DB per port
+    port_lossless_pgs: HashMap<String, PortLosslessPgs>,
+
+    /// Lossless profiles written to APPL_DB
+    lossless_profiles: HashMap<String, PgProfile>,
+
     /// Platform type
     platform: Platform,
 
@@ -40,8 +53,18 @@ pub struct BufferMgr {
     /// Dynamic buffer model flag
     dynamic_buffer_model: bool,
 
+    /// Queued (table, entry) updates, kept until they are processed
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
+    /// Store the APPL_DB entries are published to, if attached
+    store: Option<Arc<dyn WarmRestartStore>>,
+
     #[cfg(test)]
     mock_mode: bool,
+
+    /// APPL_DB operations for testing: (table, key, fvs or None for DEL)
+    #[cfg(test)]
+    app_db_ops: Vec<(String, String, Option<FieldValues>)>,
 }
 
 impl BufferMgr {
@@ -57,14 +80,27 @@ impl BufferMgr {
             speed_lookup: PortSpeed::new(),
             port_pfc_status: PortPfcStatus::new(),
             port_status_lookup: PortAdminStatus::new(),
+            port_oper_mtu: PortOperMtu::new(),
+            port_lossless_pgs: HashMap::new(),
+            lossless_profiles: HashMap::new(),
             platform,
             pgfile_processed,
             dynamic_buffer_model: false,
+            tasks: VecDeque::new(),
+            store: None,
             #[cfg(test)]
             mock_mode: false,
+            #[cfg(test)]
+            app_db_ops: Vec::new(),
         }
     }
 
+    /// Publish APPL_DB entries through `store`
+    pub fn with_store(mut self, store: Arc<dyn WarmRestartStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
     #[cfg(test)]
     pub fn new_mock(pg_profile_lookup: PgProfileLookup) -> Self {
         let mut mgr = Self::new(pg_profile_lookup);
@@ -81,6 +117,10 @@ impl BufferMgr {
     }
 
     /// Handle speed update for a port - generates buffer profiles
+    ///
+    /// Also recalculates the headroom of a port whose lossless PGs already
+    /// exist: the PGs are repointed to the profile for the current speed
+    /// and cable length, and profiles no PG references any more are removed.
     pub async fn do_speed_update_task(&mut self, port: &str) -> CfgMgrResult<bool> {
         // Check if cable length is available
         let cable = match self.cable_len_lookup.get(port) {
@@ -100,6 +140,7 @@ impl BufferMgr {
                 "Not creating/updating PG profile for port {}. Cable length is set to {}",
                 port, cable
             );
+            self.remove_lossless_pgs(port).await?;
             return Ok(true);
         }
 
@@ -118,38 +159,172 @@ impl BufferMgr {
             }
         };
 
-        let speed = self.speed_lookup.get(port).cloned().unwrap_or_default();
+        let Some(speed) = self.speed_lookup.get(port).cloned() else {
+            info!("Speed is not available for port {}", port);
+            return Ok(false); // Retry later
+        };
 
         // Create buffer profile key
-        let buffer_profile_key = format!("pg_lossless_{}_{}_ profile", speed, cable);
+        let buffer_profile_key = lossless_profile_name(&speed, &cable);
 
         // Convert PFC enable to bitmap and generate PG combinations
         let lossless_pg_bitmap = pfc_to_bitmap(&pfc_enable);
-        let lossless_pg_combinations = generate_pg_combinations(lossless_pg_bitmap);
+        let mut lossless_pg_combinations: Vec<String> =
+            generate_pg_combinations(lossless_pg_bitmap)
+                .into_iter()
+                .collect();
+        lossless_pg_combinations.sort();
 
-        // Platform-specific: skip if port is down on Mellanox/Barefoot
+        // Platform-specific: a down port holds no headroom on Mellanox/Barefoot
         if self.port_status_lookup.get(port) == Some(&"down".to_string())
             && self.platform.is_mellanox_or_barefoot()
         {
             info!(
-                "Port {} is down on {:?} platform, skipping buffer profile creation",
+                "Port {} is down on {:?} platform, removing its lossless PGs",
                 port, self.platform
             );
+            self.remove_lossless_pgs(port).await?;
             return Ok(true);
         }
 
-        // TODO: Get PG profile from lookup
-        // TODO: Write buffer profile to APPL_DB
-        // TODO: Write buffer PG entries to APPL_DB for each PG combination
+        self.ensure_lossless_profile(&buffer_profile_key, &speed, &cable)
+            .await?;
+
+        let desired = PortLosslessPgs {
+            pgs: lossless_pg_combinations,
+            profile: buffer_profile_key,
+        };
+        let current = self.port_lossless_pgs.remove(port).unwrap_or_default();
+        if current == desired {
+            self.port_lossless_pgs.insert(port.to_string(), current);
+            return Ok(true);
+        }
 
+        for pg in current.pgs.iter().filter(|pg| !desired.pgs.contains(pg)) {
+            self.delete_from_app_db(APP_BUFFER_PG_TABLE, &buffer_pg_key(port, pg))
+                .await?;
+        }
+        for pg in &desired.pgs {
+            if current.profile != desired.profile || !current.pgs.contains(pg) {
+                let fvs = vec![(
+                    buffer_pg_fields::PROFILE.to_string(),
+                    desired.profile.clone(),
+                )];
+                self.write_to_app_db(APP_BUFFER_PG_TABLE, &buffer_pg_key(port, pg), fvs)
+                    .await?;
+            }
+        }
         info!(
-            "Would create buffer profile {} for port {} with PG combinations: {:?}",
-            buffer_profile_key, port, lossless_pg_combinations
+            "Port {} lossless PGs {:?} use {}",
+            port, desired.pgs, desired.profile
         );
+        self.port_lossless_pgs.insert(port.to_string(), desired);
 
+        self.remove_unused_profiles().await?;
         Ok(true)
     }
 
+    /// Writes a lossless profile to APPL_DB unless it already exists
+    async fn ensure_lossless_profile(
+        &mut self,
+        name: &str,
+        speed: &str,
+        cable: &str,
+    ) -> CfgMgrResult<()> {
+        if self.lossless_profiles.contains_key(name) {
+            debug!("Reusing buffer profile {}", name);
+            return Ok(());
+        }
+
+        let profile = self
+            .pg_profile_lookup
+            .get(speed)
+            .and_then(|cables| cables.get(cable))
+            .cloned()
+            .ok_or_else(|| {
+                CfgMgrError::invalid_config(
+                    "pg_profile_lookup",
+                    format!("no PG profile for speed {} and cable {}", speed, cable),
+                )
+            })?;
+
+        info!("Creating buffer profile {}", name);
+        self.write_to_app_db(
+            APP_BUFFER_PROFILE_TABLE,
+            name,
+            profile.to_fvs(INGRESS_LOSSLESS_PG_POOL_NAME),
+        )
+        .await?;
+        self.lossless_profiles.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// Removes the lossless PGs of a port and the profiles left unused
+    async fn remove_lossless_pgs(&mut self, port: &str) -> CfgMgrResult<()> {
+        let Some(current) = self.port_lossless_pgs.remove(port) else {
+            return Ok(());
+        };
+
+        for pg in &current.pgs {
+            self.delete_from_app_db(APP_BUFFER_PG_TABLE, &buffer_pg_key(port, pg))
+                .await?;
+        }
+        self.remove_unused_profiles().await
+    }
+
+    /// Deletes lossless profiles that no PG references
+    async fn remove_unused_profiles(&mut self) -> CfgMgrResult<()> {
+        let mut unused: Vec<String> = self
+            .lossless_profiles
+            .keys()
+            .filter(|name| {
+                !self
+                    .port_lossless_pgs
+                    .values()
+                    .any(|pgs| &pgs.profile == *name)
+            })
+            .cloned()
+            .collect();
+        unused.sort();
+
+        for name in unused {
+            info!("Removing unused buffer profile {}", name);
+            self.delete_from_app_db(APP_BUFFER_PROFILE_TABLE, &name)
+                .await?;
+            self.lossless_profiles.remove(&name);
+        }
+        Ok(())
+    }
+
+    /// Writes an entry to an APPL_DB table
+    async fn write_to_app_db(
+        &mut self,
+        table: &str,
+        key: &str,
+        fvs: FieldValues,
+    ) -> CfgMgrResult<()> {
+        debug!("Writing {}:{} {:?}", table, key, fvs);
+        if let Some(store) = &self.store {
+            store.set(DbId::ApplDb, table, key, &fvs).await?;
+        }
+        #[cfg(test)]
+        self.app_db_ops
+            .push((table.to_string(), key.to_string(), Some(fvs)));
+        Ok(())
+    }
+
+    /// Deletes an entry from an APPL_DB table
+    async fn delete_from_app_db(&mut self, table: &str, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", table, key);
+        if let Some(store) = &self.store {
+            store.del(DbId::ApplDb, table, key).await?;
+        }
+        #[cfg(test)]
+        self.app_db_ops
+            .push((table.to_string(), key.to_string(), None));
+        Ok(())
+    }
+
     /// Get buffer pool mode
     pub fn get_pg_pool_mode(&self) -> Option<String> {
         // TODO: Read from CONFIG_DB BUFFER_POOL table
@@ -201,6 +376,29 @@ impl BufferMgr {
         Ok(true)
     }
 
+    /// Handle STATE_DB PORT_TABLE updates (operational MTU)
+    ///
+    /// Headroom is recalculated when the MTU of a port with lossless PGs
+    /// changes.
+    pub async fn do_port_state_task(
+        &mut self,
+        port: &str,
+        _op: &str,
+        values: &FieldValues,
+    ) -> CfgMgrResult<bool> {
+        let Some(mtu) = values.get_field(port_fields::MTU) else {
+            return Ok(true);
+        };
+
+        let previous = self.port_oper_mtu.insert(port.to_string(), mtu.to_string());
+        if previous.as_deref() == Some(mtu) || !self.port_lossless_pgs.contains_key(port) {
+            return Ok(true);
+        }
+
+        info!("Port {} operational MTU changed to {}", port, mtu);
+        self.do_speed_update_task(port).await
+    }
+
     /// Handle CABLE_LENGTH table updates
     pub async fn do_cable_length_task(
         &mut self,
@@ -234,6 +432,31 @@ impl BufferMgr {
         // BUFFER_PROFILE, BUFFER_PG, BUFFER_POOL, etc.
         Ok(true)
     }
+
+    /// Processes one queued entry
+    ///
+    /// Returns false if the entry is waiting on other configuration and
+    /// should be retried.
+    async fn process_task(&mut self, table: &str, entry: &KeyOpFieldsValues) -> CfgMgrResult<bool> {
+        let op = if entry.op.is_set() { "SET" } else { "DEL" };
+        match table {
+            CFG_PORT_TABLE if entry.op.is_set() => {
+                self.do_port_task(&entry.key, op, &entry.fvs).await
+            }
+            CFG_PORT_CABLE_LEN_TABLE => self.do_cable_length_task(&entry.key, op, &entry.fvs).await,
+            CFG_PORT_QOS_MAP_TABLE => self.do_port_qos_task(&entry.key, op, &entry.fvs).await,
+            STATE_PORT_TABLE => self.do_port_state_task(&entry.key, op, &entry.fvs).await,
+            CFG_BUFFER_PROFILE_TABLE | CFG_BUFFER_PG_TABLE | CFG_BUFFER_POOL_TABLE => {
+                self.do_buffer_table_task(table, &entry.key, op, &entry.fvs)
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// Number of queued entries, including those waiting to be retried
+    pub fn pending_count(&self) -> usize {
+        self.tasks.len()
+    }
 }
 
 impl Default for BufferMgr {
@@ -249,8 +472,19 @@ impl Orch for BufferMgr {
     }
 
     async fn do_task(&mut self) {
-        // TODO: Process consumers
-        // This will be implemented when integrating with ConsumerStateTable
+        let mut retry = VecDeque::new();
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            match self.process_task(&table, &entry).await {
+                Ok(true) => {}
+                Ok(false) => retry.push_back((table, entry)),
+                Err(e) => error!("Failed to process {}|{}: {}", table, entry.key, e),
+            }
+        }
+        self.tasks = retry;
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        !self.tasks.is_empty()
     }
 }
 
@@ -283,6 +517,25 @@ impl CfgMgr for BufferMgr {
             CFG_BUFFER_POOL_TABLE,
         ]
     }
+
+    fn state_table_names(&self) -> &[&str] {
+        &[STATE_PORT_TABLE]
+    }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        match (db, table) {
+            (DbId::ConfigDb, _) | (DbId::StateDb, STATE_PORT_TABLE) => {
+                self.tasks
+                    .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+            }
+            _ => debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -423,4 +676,284 @@ mod tests {
             Some(&"3,4".to_string())
         );
     }
+
+    fn make_headroom_lookup() -> PgProfileLookup {
+        let mut lookup = PgProfileLookup::new();
+        for line in [
+            "40000 5m 34816 18432 16384 1 2496",
+            "40000 40m 36864 18432 18432 1 2496",
+            "100000 5m 51200 18432 32768 1 2496",
+            "100000 40m 55296 18432 36864 1 2496",
+        ] {
+            let (speed, cable, profile) = PgProfile::from_line(line).unwrap();
+            lookup.entry(speed).or_default().insert(cable, profile);
+        }
+        lookup
+    }
+
+    /// Sets up a port with lossless PGs 3-4 at 40000/5m
+    async fn lossless_mgr(ports: &[&str]) -> BufferMgr {
+        let mut mgr = BufferMgr::new_mock(make_headroom_lookup());
+        mgr.platform = Platform::Other("broadcom".to_string());
+        for port in ports {
+            mgr.do_cable_task(port, "5m").unwrap();
+            mgr.do_port_qos_task(
+                port,
+                "SET",
+                &vec![("pfc_enable".to_string(), "3,4".to_string())],
+            )
+            .await
+            .unwrap();
+            let values = vec![
+                ("speed".to_string(), "40000".to_string()),
+                ("admin_status".to_string(), "up".to_string()),
+            ];
+            assert!(mgr.do_port_task(port, "SET", &values).await.unwrap());
+        }
+        mgr
+    }
+
+    fn pg_profile(mgr: &BufferMgr, key: &str) -> Option<String> {
+        mgr.app_db_ops
+            .iter()
+            .rev()
+            .find(|(table, k, _)| table == APP_BUFFER_PG_TABLE && k == key)
+            .and_then(|(_, _, fvs)| fvs.as_ref())
+            .and_then(|fvs| fvs.get_field("profile").map(str::to_string))
+    }
+
+    fn profile_ops(mgr: &BufferMgr) -> Vec<(String, bool)> {
+        mgr.app_db_ops
+            .iter()
+            .filter(|(table, _, _)| table == APP_BUFFER_PROFILE_TABLE)
+            .map(|(_, key, fvs)| (key.clone(), fvs.is_some()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_lossless_pgs_created() {
+        let mgr = lossless_mgr(&["Ethernet0"]).await;
+
+        assert_eq!(
+            profile_ops(&mgr),
+            [("pg_lossless_40000_5m_profile".to_string(), true)]
+        );
+        for pg in ["3", "4", "3-4"] {
+            assert_eq!(
+                pg_profile(&mgr, &format!("Ethernet0:{}", pg)).as_deref(),
+                Some("pg_lossless_40000_5m_profile")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_speed_change_repoints_pgs() {
+        let mut mgr = lossless_mgr(&["Ethernet0", "Ethernet4"]).await;
+        mgr.app_db_ops.clear();
+
+        let values = vec![("speed".to_string(), "100000".to_string())];
+        mgr.do_port_task("Ethernet0", "SET", &values).await.unwrap();
+
+        // New profile created, old one still used by Ethernet4
+        assert_eq!(
+            profile_ops(&mgr),
+            [("pg_lossless_100000_5m_profile".to_string(), true)]
+        );
+        assert_eq!(
+            pg_profile(&mgr, "Ethernet0:3-4").as_deref(),
+            Some("pg_lossless_100000_5m_profile")
+        );
+        assert!(pg_profile(&mgr, "Ethernet4:3-4").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cable_change_repoints_pgs_and_gcs_profile() {
+        let mut mgr = lossless_mgr(&["Ethernet0"]).await;
+        mgr.app_db_ops.clear();
+
+        let values = vec![("Ethernet0".to_string(), "40m".to_string())];
+        mgr.do_cable_length_task("AZURE", "SET", &values)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            profile_ops(&mgr),
+            [
+                ("pg_lossless_40000_40m_profile".to_string(), true),
+                ("pg_lossless_40000_5m_profile".to_string(), false),
+            ]
+        );
+        assert_eq!(
+            pg_profile(&mgr, "Ethernet0:3").as_deref(),
+            Some("pg_lossless_40000_40m_profile")
+        );
+        assert_eq!(mgr.lossless_profiles.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_simultaneous_change_reuses_profile_and_gcs() {
+        let mut mgr = lossless_mgr(&["Ethernet0", "Ethernet4"]).await;
+
+        // Ethernet4 moves to 100000/40m first
+        mgr.do_cable_task("Ethernet4", "40m").unwrap();
+        let values = vec![("speed".to_string(), "100000".to_string())];
+        mgr.do_port_task("Ethernet4", "SET", &values).await.unwrap();
+        assert_eq!(mgr.lossless_profiles.len(), 2);
+
+        // Ethernet0 changes speed and cable at once: the profile is reused
+        // and the 40000/5m one is no longer referenced
+        mgr.app_db_ops.clear();
+        mgr.do_cable_task("Ethernet0", "40m").unwrap();
+        mgr.do_port_task("Ethernet0", "SET", &values).await.unwrap();
+
+        assert_eq!(
+            profile_ops(&mgr),
+            [("pg_lossless_40000_5m_profile".to_string(), false)]
+        );
+        assert_eq!(
+            pg_profile(&mgr, "Ethernet0:3-4").as_deref(),
+            Some("pg_lossless_100000_40m_profile")
+        );
+        assert_eq!(
+            mgr.lossless_profiles.keys().collect::<Vec<_>>(),
+            ["pg_lossless_100000_40m_profile"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_update_writes_nothing() {
+        let mut mgr = lossless_mgr(&["Ethernet0"]).await;
+        mgr.app_db_ops.clear();
+
+        let values = vec![("admin_status".to_string(), "up".to_string())];
+        mgr.do_port_task("Ethernet0", "SET", &values).await.unwrap();
+
+        assert!(mgr.app_db_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_oper_mtu_change_recalculates() {
+        let mut mgr = lossless_mgr(&["Ethernet0"]).await;
+        let mtu = |v: &str| vec![("mtu".to_string(), v.to_string())];
+
+        mgr.do_port_state_task("Ethernet0", "SET", &mtu("9100"))
+            .await
+            .unwrap();
+        // The speed changed without a recalculation reaching APPL_DB yet
+        mgr.speed_lookup
+            .insert("Ethernet0".to_string(), "100000".to_string());
+        mgr.app_db_ops.clear();
+
+        mgr.do_port_state_task("Ethernet0", "SET", &mtu("9100"))
+            .await
+            .unwrap();
+        assert!(mgr.app_db_ops.is_empty());
+
+        mgr.do_port_state_task("Ethernet0", "SET", &mtu("1500"))
+            .await
+            .unwrap();
+        assert_eq!(
+            pg_profile(&mgr, "Ethernet0:3-4").as_deref(),
+            Some("pg_lossless_100000_5m_profile")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mellanox_down_port_releases_headroom() {
+        let mut mgr = lossless_mgr(&["Ethernet0"]).await;
+        mgr.platform = Platform::Mellanox;
+        mgr.app_db_ops.clear();
+
+        let values = vec![("admin_status".to_string(), "down".to_string())];
+        mgr.do_port_task("Ethernet0", "SET", &values).await.unwrap();
+
+        let deleted: Vec<_> = mgr
+            .app_db_ops
+            .iter()
+            .filter(|(_, _, fvs)| fvs.is_none())
+            .map(|(_, key, _)| key.as_str())
+            .collect();
+        assert_eq!(
+            deleted,
+            [
+                "Ethernet0:3",
+                "Ethernet0:3-4",
+                "Ethernet0:4",
+                "pg_lossless_40000_5m_profile"
+            ]
+        );
+        assert!(mgr.lossless_profiles.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_missing_lookup_entry_is_an_error() {
+        let mut mgr = lossless_mgr(&["Ethernet0"]).await;
+        mgr.app_db_ops.clear();
+
+        mgr.do_cable_task("Ethernet0", "300m").unwrap();
+        assert!(mgr.do_speed_update_task("Ethernet0").await.is_err());
+        assert!(mgr.app_db_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_do_task_retries_port_until_cable_length_arrives() {
+        use sonic_cfgmgr_common::InMemoryStore;
+
+        let store = Arc::new(InMemoryStore::new());
+        let mut mgr = BufferMgr::new_mock(make_headroom_lookup()).with_store(store.clone());
+        mgr.platform = Platform::Other("broadcom".to_string());
+        let fvs = |pairs: &[(&str, &str)]| -> FieldValues {
+            pairs
+                .iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect()
+        };
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_PORT_TABLE,
+            vec![KeyOpFieldsValues::set(
+                "Ethernet0",
+                fvs(&[("speed", "40000"), ("admin_status", "up")]),
+            )],
+        );
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_PORT_QOS_MAP_TABLE,
+            vec![KeyOpFieldsValues::set(
+                "Ethernet0",
+                fvs(&[("pfc_enable", "3,4")]),
+            )],
+        );
+        // Both wait for the cable length
+        mgr.do_task().await;
+        assert_eq!(mgr.pending_count(), 2);
+        assert!(store.journal().is_empty());
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_PORT_CABLE_LEN_TABLE,
+            vec![KeyOpFieldsValues::set("AZURE", fvs(&[("Ethernet0", "5m")]))],
+        );
+        mgr.do_task().await;
+        assert!(!mgr.has_pending_tasks());
+
+        let pg = store
+            .get_all(DbId::ApplDb, APP_BUFFER_PG_TABLE, "Ethernet0:3-4")
+            .await
+            .unwrap();
+        assert_eq!(
+            pg.get_field("profile"),
+            Some("pg_lossless_40000_5m_profile")
+        );
+        assert!(!store
+            .get_all(
+                DbId::ApplDb,
+                APP_BUFFER_PROFILE_TABLE,
+                "pg_lossless_40000_5m_profile"
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
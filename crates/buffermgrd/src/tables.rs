@@ -8,6 +8,9 @@ pub const CFG_BUFFER_PROFILE_TABLE: &str = "BUFFER_PROFILE";
 pub const CFG_BUFFER_PG_TABLE: &str = "BUFFER_PG";
 pub const CFG_BUFFER_POOL_TABLE: &str = "BUFFER_POOL";
 
+// STATE_DB tables
+pub const STATE_PORT_TABLE: &str = "PORT_TABLE";
+
 // APPL_DB tables
 pub const APP_BUFFER_PROFILE_TABLE: &str = "BUFFER_PROFILE_TABLE";
 pub const APP_BUFFER_PG_TABLE: &str = "BUFFER_PG_TABLE";
@@ -20,6 +23,7 @@ pub const APP_BUFFER_PORT_EGRESS_PROFILE_LIST: &str = "BUFFER_PORT_EGRESS_PROFIL
 pub mod port_fields {
     pub const SPEED: &str = "speed";
     pub const ADMIN_STATUS: &str = "admin_status";
+    pub const MTU: &str = "mtu";
 }
 
 /// PORT_QOS_MAP table fields
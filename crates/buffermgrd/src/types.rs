@@ -2,6 +2,10 @@
 
 use std::collections::HashMap;
 
+use sonic_cfgmgr_common::FieldValues;
+
+use crate::tables::buffer_profile_fields;
+
 /// PG profile buffer parameters
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PgProfile {
@@ -35,6 +39,47 @@ impl PgProfile {
 
         Some((speed, cable, profile))
     }
+
+    /// Build BUFFER_PROFILE field-values for a profile in `pool`
+    pub fn to_fvs(&self, pool: &str) -> FieldValues {
+        let mut fvs = vec![
+            (buffer_profile_fields::POOL.to_string(), pool.to_string()),
+            (buffer_profile_fields::XON.to_string(), self.xon.clone()),
+            (buffer_profile_fields::XOFF.to_string(), self.xoff.clone()),
+            (buffer_profile_fields::SIZE.to_string(), self.size.clone()),
+            (
+                buffer_profile_fields::DYNAMIC_TH.to_string(),
+                self.threshold.clone(),
+            ),
+        ];
+        if !self.xon_offset.is_empty() {
+            fvs.push((
+                buffer_profile_fields::XON_OFFSET.to_string(),
+                self.xon_offset.clone(),
+            ));
+        }
+        fvs
+    }
+}
+
+/// Name of the lossless profile for a speed and cable length
+pub fn lossless_profile_name(speed: &str, cable: &str) -> String {
+    format!("pg_lossless_{}_{}_profile", speed, cable)
+}
+
+/// APPL_DB BUFFER_PG_TABLE key of a port's PG range
+pub fn buffer_pg_key(port: &str, pgs: &str) -> String {
+    format!("{}:{}", port, pgs)
+}
+
+/// Lossless PGs written for a port and the profile they point to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortLosslessPgs {
+    /// PG ids such as "3-4"
+    pub pgs: Vec<String>,
+
+    /// Profile the PGs reference
+    pub profile: String,
 }
 
 /// Nested lookup: [speed][cable] -> PgProfile
@@ -52,6 +97,9 @@ pub type PortPfcStatus = HashMap<String, String>;
 /// Port admin status mapping ("up" or "down")
 pub type PortAdminStatus = HashMap<String, String>;
 
+/// Port operational MTU mapping from STATE_DB
+pub type PortOperMtu = HashMap<String, String>;
+
 /// Platform type for platform-specific behavior
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Platform {
@@ -116,6 +164,31 @@ mod tests {
         assert_eq!(profile.xon_offset, "");
     }
 
+    #[test]
+    fn test_pg_profile_to_fvs() {
+        let (_, _, profile) = PgProfile::from_line("40000 5m 34816 18432 16384 1 2496").unwrap();
+        let fvs = profile.to_fvs(INGRESS_LOSSLESS_PG_POOL_NAME);
+
+        assert_eq!(fvs.len(), 6);
+        assert_eq!(
+            fvs[0],
+            ("pool".to_string(), "ingress_lossless_pool".to_string())
+        );
+        assert!(fvs.contains(&("dynamic_th".to_string(), "1".to_string())));
+        assert!(fvs.contains(&("xon_offset".to_string(), "2496".to_string())));
+
+        let (_, _, profile) = PgProfile::from_line("100000 300m 184320 18432 165888 1").unwrap();
+        assert_eq!(profile.to_fvs(INGRESS_LOSSLESS_PG_POOL_NAME).len(), 5);
+    }
+
+    #[test]
+    fn test_lossless_profile_name() {
+        assert_eq!(
+            lossless_profile_name("100000", "40m"),
+            "pg_lossless_100000_40m_profile"
+        );
+    }
+
     #[test]
     fn test_pg_profile_from_line_invalid() {
         let line = "40000 5m";
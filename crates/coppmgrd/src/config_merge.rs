@@ -1,9 +1,13 @@
 //! Configuration merging logic
 
-use crate::types::CoppCfg;
-use sonic_cfgmgr_common::{CfgMgrResult, FieldValues, FieldValuesExt};
+use crate::tables::group_fields;
+use crate::types::{CoppCfg, FieldOrigin, MergedFields};
+use sonic_cfgmgr_common::{CfgMgrError, CfgMgrResult, FieldValues, FieldValuesExt};
 use tracing::{debug, info};
 
+/// Accepted COPP_GROUP meter_type values
+pub const METER_TYPES: &[&str] = &["packets", "bytes"];
+
 /// Merge init config with user CONFIG_DB config
 ///
 /// Rules:
@@ -76,6 +80,108 @@ where
     Ok(merged)
 }
 
+/// Merge one entry's init and user fields, keeping track of their origin
+///
+/// User fields override init fields. Returns `None` when neither side has
+/// the entry or the user suppressed it with a "NULL" field.
+pub fn merge_entry(
+    init_fvs: Option<&FieldValues>,
+    user_fvs: Option<&FieldValues>,
+) -> Option<MergedFields> {
+    if init_fvs.is_none() && user_fvs.is_none() {
+        return None;
+    }
+    if user_fvs.is_some_and(|fvs| fvs.has_field("NULL")) {
+        return None;
+    }
+
+    let mut merged = MergedFields::new();
+    for (field, value) in init_fvs.into_iter().flatten() {
+        merged.insert(field.clone(), (value.clone(), FieldOrigin::Init));
+    }
+    for (field, value) in user_fvs.into_iter().flatten() {
+        merged.insert(field.clone(), (value.clone(), FieldOrigin::User));
+    }
+    Some(merged)
+}
+
+/// Changes needed to turn one merged entry into another
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntryDiff {
+    /// Fields to write, new or with a new value
+    pub set: FieldValues,
+
+    /// Fields to delete
+    pub removed: Vec<String>,
+}
+
+impl EntryDiff {
+    /// Checks if the entries are identical
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compute the field-level changes from `old` to `new`
+///
+/// A user override that is removed reverts to the init value (a set); a
+/// field that only existed as a user addition becomes a delete.
+pub fn diff_entry(old: &MergedFields, new: &MergedFields) -> EntryDiff {
+    let set = new
+        .iter()
+        .filter(|(field, (value, _))| old.get(*field).map(|(v, _)| v) != Some(value))
+        .map(|(field, (value, _))| (field.clone(), value.clone()))
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|field| !new.contains_key(*field))
+        .cloned()
+        .collect();
+
+    EntryDiff { set, removed }
+}
+
+/// Flatten merged fields into field-values
+pub fn merged_to_fvs(merged: &MergedFields) -> FieldValues {
+    merged
+        .iter()
+        .map(|(field, (value, _))| (field.clone(), value.clone()))
+        .collect()
+}
+
+/// Validate the policer fields of a COPP_GROUP entry
+///
+/// cir, cbs, pir and pbs must be unsigned integers; meter_type must be one
+/// of [`METER_TYPES`].
+pub fn validate_policer_fields(fvs: &FieldValues) -> CfgMgrResult<()> {
+    for field in [
+        group_fields::CIR,
+        group_fields::CBS,
+        group_fields::PIR,
+        group_fields::PBS,
+    ] {
+        if let Some(value) = fvs.get_field(field) {
+            if value.parse::<u64>().is_err() {
+                return Err(CfgMgrError::invalid_config(
+                    field,
+                    format!("'{}' is not a number", value),
+                ));
+            }
+        }
+    }
+
+    if let Some(meter_type) = fvs.get_field(group_fields::METER_TYPE) {
+        if !METER_TYPES.contains(&meter_type) {
+            return Err(CfgMgrError::invalid_config(
+                group_fields::METER_TYPE,
+                format!("'{}' is not one of {:?}", meter_type, METER_TYPES),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +330,56 @@ mod tests {
             .iter()
             .any(|(k, v)| k == "trap_ids" && v == "custom_id"));
     }
+
+    #[test]
+    fn test_merge_entry_origins() {
+        let init = make_fvs(&[("queue", "1"), ("cir", "600")]);
+        let user = make_fvs(&[("cir", "1000"), ("color", "blind")]);
+
+        let merged = merge_entry(Some(&init), Some(&user)).unwrap();
+        assert_eq!(merged["queue"], ("1".to_string(), FieldOrigin::Init));
+        assert_eq!(merged["cir"], ("1000".to_string(), FieldOrigin::User));
+        assert_eq!(merged["color"], ("blind".to_string(), FieldOrigin::User));
+
+        assert!(merge_entry(None, None).is_none());
+        assert!(merge_entry(Some(&init), Some(&make_fvs(&[("NULL", "NULL")]))).is_none());
+    }
+
+    #[test]
+    fn test_diff_entry() {
+        let init = make_fvs(&[("queue", "1"), ("cir", "600")]);
+        let old = merge_entry(
+            Some(&init),
+            Some(&make_fvs(&[("cir", "1000"), ("color", "blind")])),
+        )
+        .unwrap();
+
+        // Dropping both user fields: cir reverts to init, color is deleted
+        let new = merge_entry(Some(&init), None).unwrap();
+        let diff = diff_entry(&old, &new);
+        assert_eq!(diff.set, make_fvs(&[("cir", "600")]));
+        assert_eq!(diff.removed, vec!["color".to_string()]);
+
+        assert!(diff_entry(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_validate_policer_fields() {
+        assert!(validate_policer_fields(&make_fvs(&[
+            ("cir", "600"),
+            ("cbs", "600"),
+            ("meter_type", "packets"),
+        ]))
+        .is_ok());
+        assert!(validate_policer_fields(&make_fvs(&[("queue", "1")])).is_ok());
+
+        for fvs in [
+            make_fvs(&[("cir", "fast")]),
+            make_fvs(&[("cbs", "-1")]),
+            make_fvs(&[("pir", "1.5")]),
+            make_fvs(&[("meter_type", "bits")]),
+        ] {
+            assert!(validate_policer_fields(&fvs).is_err(), "{:?}", fvs);
+        }
+    }
 }
@@ -5,9 +5,19 @@ use sonic_cfgmgr_common::{CfgMgr, CfgMgrResult, FieldValues, FieldValuesExt, War
 use sonic_orch_common::Orch;
 use tracing::{debug, info};
 
+use crate::config_merge::{diff_entry, merge_entry, merged_to_fvs, validate_policer_fields};
 use crate::tables::*;
 use crate::types::*;
 
+/// APPL_DB COPP_TABLE operation recorded in tests
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AppDbOp {
+    Set(String, FieldValues),
+    HDel(String, Vec<String>),
+    Del(String),
+}
+
 /// CoPP Manager
 ///
 /// Manages Control Plane Policing configuration including:
@@ -21,9 +31,12 @@ pub struct CoppMgr {
     /// Trap ID → group name mapping
     trap_id_group_map: CoppTrapIdGroupMap,
 
-    /// Group → (field → value) nested map for current group config
+    /// Group → merged fields currently written to APPL_DB
     group_fvs: CoppGroupFvs,
 
+    /// User COPP_GROUP configuration from CONFIG_DB
+    group_user_cfg: CoppCfg,
+
     /// Feature → field values for FEATURE table cache
     features_cfg: FeaturesCfg,
 
@@ -38,6 +51,9 @@ pub struct CoppMgr {
 
     #[cfg(test)]
     mock_mode: bool,
+
+    #[cfg(test)]
+    app_db_ops: Vec<AppDbOp>,
 }
 
 impl CoppMgr {
@@ -54,12 +70,15 @@ impl CoppMgr {
             trap_conf_map: CoppTrapConfMap::new(),
             trap_id_group_map: CoppTrapIdGroupMap::new(),
             group_fvs: CoppGroupFvs::new(),
+            group_user_cfg: CoppCfg::new(),
             features_cfg: FeaturesCfg::new(),
             trap_init_cfg,
             group_init_cfg,
             copp_cfg_file,
             #[cfg(test)]
             mock_mode: false,
+            #[cfg(test)]
+            app_db_ops: Vec::new(),
         }
     }
 
@@ -300,16 +319,93 @@ impl CoppMgr {
     }
 
     /// Handle COPP_GROUP table updates
+    ///
+    /// The APPL_DB entry is regenerated from the init defaults plus the
+    /// remaining user fields and written as a field-level diff, so removed
+    /// user additions are deleted and removed overrides revert to init.
     pub async fn do_copp_group_task(
         &mut self,
-        _key: &str,
-        _op: &str,
-        _values: &FieldValues,
+        key: &str,
+        op: &str,
+        values: &FieldValues,
     ) -> CfgMgrResult<bool> {
-        // TODO: Implement SET/DEL logic from C++ lines 840-925
+        if op == "SET" {
+            validate_policer_fields(values)?;
+            self.group_user_cfg.insert(key.to_string(), values.clone());
+        } else if op == "DEL" {
+            self.group_user_cfg.remove(key);
+        }
+
+        self.sync_group(key).await?;
         Ok(true)
     }
 
+    /// Write the init groups that have no user configuration yet
+    pub async fn apply_init_groups(&mut self) -> CfgMgrResult<()> {
+        let mut keys: Vec<String> = self.group_init_cfg.keys().cloned().collect();
+        keys.sort();
+
+        for key in keys {
+            self.sync_group(&key).await?;
+        }
+        Ok(())
+    }
+
+    /// Bring the APPL_DB entry of a group in line with its merged config
+    async fn sync_group(&mut self, key: &str) -> CfgMgrResult<()> {
+        let desired = merge_entry(self.group_init_cfg.get(key), self.group_user_cfg.get(key))
+            .filter(|_| !self.check_trap_group_pending(key));
+
+        match (self.group_fvs.remove(key), desired) {
+            (None, None) => {}
+            (Some(_), None) => {
+                info!("Removing COPP group {}", key);
+                self.app_db_del(key).await?;
+            }
+            (None, Some(new)) => {
+                info!("Creating COPP group {}", key);
+                self.app_db_set(key, merged_to_fvs(&new)).await?;
+                self.group_fvs.insert(key.to_string(), new);
+            }
+            (Some(old), Some(new)) => {
+                let diff = diff_entry(&old, &new);
+                if !diff.set.is_empty() {
+                    self.app_db_set(key, diff.set).await?;
+                }
+                if !diff.removed.is_empty() {
+                    info!("Removing fields {:?} from COPP group {}", diff.removed, key);
+                    self.app_db_hdel(key, diff.removed).await?;
+                }
+                self.group_fvs.insert(key.to_string(), new);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write fields of a COPP_TABLE entry
+    async fn app_db_set(&mut self, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        debug!("Writing {}:{} {:?}", APP_COPP_TABLE, key, fvs);
+        #[cfg(test)]
+        self.app_db_ops.push(AppDbOp::Set(key.to_string(), fvs));
+        Ok(())
+    }
+
+    /// Delete fields of a COPP_TABLE entry
+    async fn app_db_hdel(&mut self, key: &str, fields: Vec<String>) -> CfgMgrResult<()> {
+        debug!("Deleting {:?} from {}:{}", fields, APP_COPP_TABLE, key);
+        #[cfg(test)]
+        self.app_db_ops.push(AppDbOp::HDel(key.to_string(), fields));
+        Ok(())
+    }
+
+    /// Delete a COPP_TABLE entry
+    async fn app_db_del(&mut self, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", APP_COPP_TABLE, key);
+        #[cfg(test)]
+        self.app_db_ops.push(AppDbOp::Del(key.to_string()));
+        Ok(())
+    }
+
     /// Handle FEATURE table updates
     pub async fn do_feature_task(
         &mut self,
@@ -376,6 +472,7 @@ impl CfgMgr for CoppMgr {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     fn make_fvs(items: &[(&str, &str)]) -> FieldValues {
         items
@@ -517,4 +614,187 @@ mod tests {
 
         assert!(mgr.is_feature_enabled("arp"));
     }
+
+    /// Replays the recorded operations into a COPP_TABLE snapshot
+    fn app_db_state(mgr: &CoppMgr) -> BTreeMap<String, BTreeMap<String, String>> {
+        let mut state: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        for op in &mgr.app_db_ops {
+            match op {
+                AppDbOp::Set(key, fvs) => state.entry(key.clone()).or_default().extend(fvs.clone()),
+                AppDbOp::HDel(key, fields) => {
+                    let entry = state.get_mut(key).unwrap();
+                    for field in fields {
+                        entry.remove(field);
+                    }
+                }
+                AppDbOp::Del(key) => {
+                    state.remove(key);
+                }
+            }
+        }
+        state
+    }
+
+    fn golden(entries: &[(&str, &[(&str, &str)])]) -> BTreeMap<String, BTreeMap<String, String>> {
+        entries
+            .iter()
+            .map(|(key, fvs)| {
+                (
+                    key.to_string(),
+                    fvs.iter()
+                        .map(|(f, v)| (f.to_string(), v.to_string()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    async fn group_mgr() -> CoppMgr {
+        let mut group_cfg = CoppCfg::new();
+        group_cfg.insert(
+            "queue1_group1".to_string(),
+            make_fvs(&[
+                ("queue", "1"),
+                ("meter_type", "packets"),
+                ("cir", "600"),
+                ("cbs", "600"),
+            ]),
+        );
+        let mut mgr = CoppMgr::new_mock(CoppCfg::new(), group_cfg, COPP_INIT_FILE.to_string());
+        mgr.apply_init_groups().await.unwrap();
+        mgr
+    }
+
+    #[tokio::test]
+    async fn test_copp_group_override_lifecycle() {
+        let mut mgr = group_mgr().await;
+        let init: &[(&str, &str)] = &[
+            ("cbs", "600"),
+            ("cir", "600"),
+            ("meter_type", "packets"),
+            ("queue", "1"),
+        ];
+        assert_eq!(app_db_state(&mgr), golden(&[("queue1_group1", init)]));
+
+        // Add an override and a user-only field
+        mgr.do_copp_group_task(
+            "queue1_group1",
+            "SET",
+            &make_fvs(&[("cir", "1000"), ("color", "blind")]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            app_db_state(&mgr),
+            golden(&[(
+                "queue1_group1",
+                &[
+                    ("cbs", "600"),
+                    ("cir", "1000"),
+                    ("color", "blind"),
+                    ("meter_type", "packets"),
+                    ("queue", "1"),
+                ]
+            )])
+        );
+
+        // Modify the override
+        mgr.do_copp_group_task(
+            "queue1_group1",
+            "SET",
+            &make_fvs(&[("cir", "2000"), ("color", "blind")]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::Set(
+                "queue1_group1".to_string(),
+                make_fvs(&[("cir", "2000")])
+            ))
+        );
+
+        // Delete the user-only field: field-level delete
+        mgr.do_copp_group_task("queue1_group1", "SET", &make_fvs(&[("cir", "2000")]))
+            .await
+            .unwrap();
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::HDel(
+                "queue1_group1".to_string(),
+                vec!["color".to_string()]
+            ))
+        );
+        assert_eq!(
+            app_db_state(&mgr),
+            golden(&[(
+                "queue1_group1",
+                &[
+                    ("cbs", "600"),
+                    ("cir", "2000"),
+                    ("meter_type", "packets"),
+                    ("queue", "1"),
+                ]
+            )])
+        );
+
+        // Delete the key: back to init defaults
+        mgr.do_copp_group_task("queue1_group1", "DEL", &FieldValues::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::Set(
+                "queue1_group1".to_string(),
+                make_fvs(&[("cir", "600")])
+            ))
+        );
+        assert_eq!(app_db_state(&mgr), golden(&[("queue1_group1", init)]));
+    }
+
+    #[tokio::test]
+    async fn test_copp_group_user_only_lifecycle() {
+        let mut mgr = group_mgr().await;
+
+        mgr.do_copp_group_task(
+            "queue5_group1",
+            "SET",
+            &make_fvs(&[("queue", "5"), ("trap_action", "trap")]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            app_db_state(&mgr)["queue5_group1"],
+            golden(&[("queue5_group1", &[("queue", "5"), ("trap_action", "trap")])])
+                ["queue5_group1"]
+        );
+
+        mgr.do_copp_group_task("queue5_group1", "DEL", &FieldValues::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::Del("queue5_group1".to_string()))
+        );
+        assert!(!app_db_state(&mgr).contains_key("queue5_group1"));
+    }
+
+    #[tokio::test]
+    async fn test_copp_group_invalid_policer_rejected() {
+        let mut mgr = group_mgr().await;
+        let ops = mgr.app_db_ops.len();
+
+        for fvs in [
+            make_fvs(&[("cir", "lots")]),
+            make_fvs(&[("meter_type", "bits")]),
+        ] {
+            assert!(mgr
+                .do_copp_group_task("queue1_group1", "SET", &fvs)
+                .await
+                .is_err());
+        }
+
+        assert_eq!(mgr.app_db_ops.len(), ops);
+        assert!(!mgr.group_user_cfg.contains_key("queue1_group1"));
+    }
 }
@@ -1,7 +1,7 @@
 //! CoPP Manager Type Definitions
 
 use sonic_cfgmgr_common::FieldValues;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// CoPP trap configuration
 #[derive(Debug, Clone, PartialEq)]
@@ -45,10 +45,22 @@ pub type CoppTrapIdGroupMap = HashMap<String, String>;
 /// Used for both trap and group configurations from JSON and CONFIG_DB
 pub type CoppCfg = HashMap<String, FieldValues>;
 
-/// Group field values: Group → (field → value) nested map
+/// Where a merged field value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOrigin {
+    /// The JSON init file
+    Init,
+    /// A CONFIG_DB user override or addition
+    User,
+}
+
+/// Merged entry: field → (value, origin)
+pub type MergedFields = BTreeMap<String, (String, FieldOrigin)>;
+
+/// Group field values: Group → merged fields
 ///
-/// Stores the current field values for each COPP_GROUP
-pub type CoppGroupFvs = HashMap<String, HashMap<String, String>>;
+/// Stores the field values of each COPP_GROUP written to APPL_DB
+pub type CoppGroupFvs = HashMap<String, MergedFields>;
 
 /// Feature configurations: Feature → field values
 ///
@@ -1,15 +1,37 @@
 //! FabricMgr - Core fabric monitoring configuration manager implementation
 
+use std::collections::{BTreeMap, HashSet};
+
 use async_trait::async_trait;
-use tracing::{debug, instrument};
+use tracing::{debug, info, instrument, warn};
 
-use sonic_cfgmgr_common::{CfgMgr, CfgMgrResult, FieldValues, Orch};
+use sonic_cfgmgr_common::{CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, FieldValuesExt, Orch};
 
-use crate::fields;
+use crate::{fields, isolate_status};
 use crate::{
     CFG_FABRIC_MONITOR_DATA_TABLE_NAME, CFG_FABRIC_MONITOR_PORT_TABLE_NAME, FABRIC_MONITOR_DATA_KEY,
 };
 
+/// Normalizes an isolateStatus value to "True"/"False", case-insensitively
+pub fn normalize_isolate_status(value: &str) -> Option<&'static str> {
+    if value.eq_ignore_ascii_case(isolate_status::TRUE) {
+        Some(isolate_status::TRUE)
+    } else if value.eq_ignore_ascii_case(isolate_status::FALSE) {
+        Some(isolate_status::FALSE)
+    } else {
+        None
+    }
+}
+
+/// Counters kept for debugging
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FabricMgrStats {
+    /// Fields outside the known set, passed through, by name
+    pub unknown_fields: BTreeMap<String, u64>,
+    /// FABRIC_PORT entries rejected for a bad isolateStatus
+    pub invalid_isolate_status: u64,
+}
+
 /// FabricMgr manages fabric monitoring configuration
 ///
 /// Configuration flow:
@@ -18,6 +40,12 @@ use crate::{
 ///
 /// This is a pure pass-through manager with no shell commands.
 pub struct FabricMgr {
+    /// Ports whose isolateStatus is set in CONFIG_DB
+    isolate_configured: HashSet<String>,
+
+    /// Debug counters
+    stats: FabricMgrStats,
+
     /// Mock mode for testing
     #[cfg(test)]
     mock_mode: bool,
@@ -31,6 +59,8 @@ impl FabricMgr {
     /// Creates a new FabricMgr instance
     pub fn new() -> Self {
         Self {
+            isolate_configured: HashSet::new(),
+            stats: FabricMgrStats::default(),
             #[cfg(test)]
             mock_mode: false,
             #[cfg(test)]
@@ -51,6 +81,46 @@ impl FabricMgr {
         &self.captured_writes
     }
 
+    /// Returns the debug counters
+    pub fn stats(&self) -> &FabricMgrStats {
+        &self.stats
+    }
+
+    /// Echoes a fabric port field to STATE_DB FABRIC_PORT_TABLE
+    ///
+    /// Lets the CLI confirm an isolation request was accepted before
+    /// orchagent acts on it.
+    #[instrument(skip(self))]
+    async fn write_port_state(&mut self, key: &str, field: &str, value: &str) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.mock_mode {
+            self.captured_writes.push((
+                "STATE_FABRIC_PORT_TABLE".to_string(),
+                key.to_string(),
+                field.to_string(),
+                value.to_string(),
+            ));
+            return Ok(());
+        }
+
+        // TODO: Implement with real Table
+        debug!(
+            "Would write to STATE_FABRIC_PORT_TABLE: {}:{} = {}",
+            key, field, value
+        );
+        Ok(())
+    }
+
+    /// Publishes a fabric port's isolateStatus to APPL_DB and STATE_DB
+    async fn write_isolate_status(&mut self, key: &str, status: &str) -> CfgMgrResult<()> {
+        self.write_config_to_app_db(key, fields::ISOLATE_STATUS, status)
+            .await?;
+        self.write_port_state(key, fields::ISOLATE_STATUS, status)
+            .await?;
+        info!("Fabric port {} isolateStatus {}", key, status);
+        Ok(())
+    }
+
     /// Writes a single field-value pair to APPL_DB
     ///
     /// Routes to the appropriate table based on key:
@@ -106,16 +176,46 @@ impl FabricMgr {
             fields::ISOLATE_STATUS,
         ];
 
+        let is_port = key != FABRIC_MONITOR_DATA_KEY;
+
+        // Validate isolateStatus before writing anything
+        let isolate = match values.get_field(fields::ISOLATE_STATUS) {
+            Some(value) if is_port => match normalize_isolate_status(value) {
+                Some(status) => Some(status),
+                None => {
+                    self.stats.invalid_isolate_status += 1;
+                    return Err(CfgMgrError::invalid_config(
+                        fields::ISOLATE_STATUS,
+                        format!("{}: expected True or False, got '{}'", key, value),
+                    ));
+                }
+            },
+            _ => None,
+        };
+
         // First, process all known fields
         for (field, value) in values {
+            if field == fields::ISOLATE_STATUS && is_port {
+                continue;
+            }
             if known_fields.contains(&field.as_str()) {
                 self.write_config_to_app_db(key, field, value).await?;
             }
         }
+        if let Some(status) = isolate {
+            self.write_isolate_status(key, status).await?;
+            self.isolate_configured.insert(key.to_string());
+        } else if self.isolate_configured.remove(key) {
+            // The field was removed: the port is no longer isolated
+            self.write_isolate_status(key, isolate_status::FALSE)
+                .await?;
+        }
 
         // Then, process any remaining fields
         for (field, value) in values {
             if !known_fields.contains(&field.as_str()) {
+                warn!("Passing through unknown field {} of {}", field, key);
+                *self.stats.unknown_fields.entry(field.clone()).or_default() += 1;
                 self.write_config_to_app_db(key, field, value).await?;
             }
         }
@@ -128,8 +228,9 @@ impl FabricMgr {
     /// For fabricmgr, DELETE operations are not explicitly handled in the C++ code
     /// (no deletion from APPL_DB), so this is a no-op
     #[instrument(skip(self))]
-    pub async fn process_del(&mut self, _key: &str) -> CfgMgrResult<()> {
+    pub async fn process_del(&mut self, key: &str) -> CfgMgrResult<()> {
         debug!("DELETE operation - no-op for fabricmgr");
+        self.isolate_configured.remove(key);
         Ok(())
     }
 }
@@ -277,12 +378,13 @@ mod tests {
         mgr.process_set("Fabric0", &values).await.unwrap();
 
         let writes = mgr.captured_writes();
-        assert_eq!(writes.len(), 3);
+        assert_eq!(writes.len(), 4);
 
-        // Verify routing to correct table
+        // Verify routing to correct table; isolateStatus is also echoed
         assert!(writes
             .iter()
-            .all(|(table, _, _, _)| table == "APP_FABRIC_PORT_TABLE"));
+            .all(|(table, _, field, _)| table == "APP_FABRIC_PORT_TABLE"
+                || (table == "STATE_FABRIC_PORT_TABLE" && field == fields::ISOLATE_STATUS)));
 
         // Verify all fields were written
         assert!(writes
@@ -315,6 +417,106 @@ mod tests {
         assert!(writes
             .iter()
             .any(|(_, _, field, value)| field == "custom_field" && value == "custom_value"));
+
+        // And the unknown one is counted
+        mgr.process_set("Fabric1", &values).await.unwrap();
+        assert_eq!(mgr.stats().unknown_fields.get("custom_field"), Some(&2));
+        assert_eq!(mgr.stats().unknown_fields.len(), 1);
+    }
+
+    fn isolate(value: &str) -> FieldValues {
+        vec![
+            (fields::ALIAS.to_string(), "Fabric0".to_string()),
+            (fields::ISOLATE_STATUS.to_string(), value.to_string()),
+        ]
+    }
+
+    fn isolate_writes(mgr: &FabricMgr) -> Vec<(&str, &str)> {
+        mgr.captured_writes()
+            .iter()
+            .filter(|(_, _, field, _)| field == fields::ISOLATE_STATUS)
+            .map(|(table, _, _, value)| (table.as_str(), value.as_str()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_isolate_status_normalized_and_echoed() {
+        let mut mgr = FabricMgr::new().with_mock_mode();
+
+        for value in ["true", "TRUE", "True"] {
+            mgr.captured_writes.clear();
+            mgr.process_set("Fabric0", &isolate(value)).await.unwrap();
+            assert_eq!(
+                isolate_writes(&mgr),
+                vec![
+                    ("APP_FABRIC_PORT_TABLE", "True"),
+                    ("STATE_FABRIC_PORT_TABLE", "True")
+                ]
+            );
+        }
+
+        mgr.captured_writes.clear();
+        mgr.process_set("Fabric0", &isolate("false")).await.unwrap();
+        assert_eq!(
+            mgr.captured_writes(),
+            &[
+                (
+                    "APP_FABRIC_PORT_TABLE".to_string(),
+                    "Fabric0".to_string(),
+                    fields::ALIAS.to_string(),
+                    "Fabric0".to_string()
+                ),
+                (
+                    "APP_FABRIC_PORT_TABLE".to_string(),
+                    "Fabric0".to_string(),
+                    fields::ISOLATE_STATUS.to_string(),
+                    "False".to_string()
+                ),
+                (
+                    "STATE_FABRIC_PORT_TABLE".to_string(),
+                    "Fabric0".to_string(),
+                    fields::ISOLATE_STATUS.to_string(),
+                    "False".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_isolate_status_invalid() {
+        let mut mgr = FabricMgr::new().with_mock_mode();
+
+        for value in ["yes", "1", "", "Truee"] {
+            let err = mgr.process_set("Fabric0", &isolate(value)).await;
+            assert!(err.is_err(), "{:?} accepted", value);
+        }
+
+        // Nothing reaches APPL_DB or STATE_DB, not even the valid alias
+        assert!(mgr.captured_writes().is_empty());
+        assert_eq!(mgr.stats().invalid_isolate_status, 4);
+    }
+
+    #[tokio::test]
+    async fn test_isolate_status_removed_reverts_to_false() {
+        let mut mgr = FabricMgr::new().with_mock_mode();
+
+        mgr.process_set("Fabric0", &isolate("True")).await.unwrap();
+        mgr.captured_writes.clear();
+
+        let values = vec![(fields::ALIAS.to_string(), "Fabric0".to_string())];
+        mgr.process_set("Fabric0", &values).await.unwrap();
+        assert_eq!(
+            isolate_writes(&mgr),
+            vec![
+                ("APP_FABRIC_PORT_TABLE", "False"),
+                ("STATE_FABRIC_PORT_TABLE", "False")
+            ]
+        );
+
+        // Only once
+        mgr.captured_writes.clear();
+        mgr.process_set("Fabric0", &values).await.unwrap();
+        assert!(isolate_writes(&mgr).is_empty());
     }
 
     #[tokio::test]
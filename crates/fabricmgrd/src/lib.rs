@@ -6,6 +6,7 @@
 //! ## Responsibilities
 //! - Fabric monitoring threshold configuration
 //! - Fabric port configuration (alias, lanes, isolation status)
+//! - Validation of isolateStatus, echoed to STATE_DB for the CLI
 //! - Pure CONFIG_DB → APPL_DB pass-through (no shell commands)
 //!
 //! ## Configuration Sources
@@ -20,5 +21,5 @@
 mod fabric_mgr;
 mod tables;
 
-pub use fabric_mgr::FabricMgr;
+pub use fabric_mgr::{normalize_isolate_status, FabricMgr, FabricMgrStats};
 pub use tables::*;
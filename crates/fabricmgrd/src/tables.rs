@@ -12,6 +12,9 @@ pub const APP_FABRIC_MONITOR_DATA_TABLE_NAME: &str = "FABRIC_MONITOR_DATA";
 /// APPL_DB FABRIC_PORT table
 pub const APP_FABRIC_MONITOR_PORT_TABLE_NAME: &str = "FABRIC_PORT_TABLE";
 
+/// STATE_DB FABRIC_PORT table
+pub const STATE_FABRIC_PORT_TABLE_NAME: &str = "FABRIC_PORT_TABLE";
+
 /// Special key for fabric monitor data
 pub const FABRIC_MONITOR_DATA_KEY: &str = "FABRIC_MONITOR_DATA";
 
@@ -29,3 +32,9 @@ pub mod fields {
     pub const LANES: &str = "lanes";
     pub const ISOLATE_STATUS: &str = "isolateStatus";
 }
+
+/// isolateStatus values, as orchagent expects them
+pub mod isolate_status {
+    pub const TRUE: &str = "True";
+    pub const FALSE: &str = "False";
+}
@@ -1,12 +1,21 @@
 //! Interface Manager - Core implementation
 
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use sonic_cfgmgr_common::shell::{Executor, ShellExecutor};
 use sonic_cfgmgr_common::{
-    shell, CfgMgr, CfgMgrResult, FieldValues, FieldValuesExt, WarmRestartState,
+    shell, CfgBackend, CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, FieldValuesExt,
+    KeyOpFieldsValues, ShellBackend, WarmRestartHelper, WarmRestartState, WarmRestartStore,
 };
 use sonic_orch_common::Orch;
-use sonic_types::IpPrefix;
-use tracing::{debug, info};
+use tracing::{debug, error, info, warn};
+
+use crate::ip_operations::intf_addr_cmd;
+use crate::subintf::parse_subintf_name;
+use crate::subintf_operations::{clamp_subintf_mtu, del_subintf_cmd};
+use crate::vrf_operations::{parse_arp_state, set_intf_grat_arp, set_intf_proxy_arp, set_intf_vrf};
 
 use crate::tables::*;
 use crate::types::*;
@@ -23,6 +32,33 @@ pub struct IntfMgr {
     /// Sub-interface tracking
     subintf_list: SubIntfMap,
 
+    /// Sub-interfaces waiting for their parent to appear in STATE_DB
+    pending_subintfs: PendingSubIntfMap,
+
+    /// Ports and LAGs present in STATE_DB, with their MTU
+    parent_mtus: ParentMtuMap,
+
+    /// VLAN interfaces present in STATE_DB
+    ready_vlans: HashSet<String>,
+
+    /// IP addresses waiting for their interface to be ready
+    pending_addrs: PendingAddrMap,
+
+    /// Configured and applied IP addresses
+    intf_addrs: IntfAddrMap,
+
+    /// Configured proxy/gratuitous ARP settings
+    arp_config: ArpConfigMap,
+
+    /// Kernel parameter writer
+    sysctl: Arc<dyn Sysctl>,
+
+    /// Applies VRF binding and sub-interface link changes
+    backend: Arc<dyn CfgBackend>,
+
+    /// Runs the `ip` address and loopback commands
+    executor: Arc<dyn Executor>,
+
     /// Loopback interfaces
     loopback_intf_list: LoopbackIntfSet,
 
@@ -38,8 +74,23 @@ pub struct IntfMgr {
     /// Warm restart replay done flag
     replay_done: bool,
 
+    /// Warm restart state, present when warm restart is configured
+    warm_restart: Option<WarmRestartHelper>,
+
+    /// Queued (table, entry) updates
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
+    /// Store the APPL_DB entries are published to, if attached
+    store: Option<Arc<dyn WarmRestartStore>>,
+
     #[cfg(test)]
     mock_mode: bool,
+
+    #[cfg(test)]
+    captured_commands: Vec<String>,
+
+    #[cfg(test)]
+    app_db_ops: Vec<(String, Option<FieldValues>)>,
 }
 
 impl IntfMgr {
@@ -49,16 +100,168 @@ impl IntfMgr {
 
         Self {
             subintf_list: SubIntfMap::new(),
+            pending_subintfs: PendingSubIntfMap::new(),
+            parent_mtus: ParentMtuMap::new(),
+            ready_vlans: HashSet::new(),
+            pending_addrs: PendingAddrMap::new(),
+            intf_addrs: IntfAddrMap::new(),
+            arp_config: ArpConfigMap::new(),
+            sysctl: Arc::new(ProcSysctl::new()),
+            backend: Arc::new(ShellBackend),
+            executor: Arc::new(ShellExecutor),
             loopback_intf_list: LoopbackIntfSet::new(),
             pending_replay_intf_list: PendingReplayIntfSet::new(),
             ipv6_link_local_mode_list: Ipv6LinkLocalModeSet::new(),
             switch_type,
             replay_done: false,
+            warm_restart: None,
+            tasks: VecDeque::new(),
+            store: None,
             #[cfg(test)]
             mock_mode: false,
+            #[cfg(test)]
+            captured_commands: Vec::new(),
+            #[cfg(test)]
+            app_db_ops: Vec::new(),
         }
     }
 
+    /// Attach the warm restart helper
+    pub fn with_warm_restart(mut self, helper: WarmRestartHelper) -> Self {
+        self.warm_restart = Some(helper);
+        self
+    }
+
+    /// Use a different kernel parameter writer
+    pub fn with_sysctl(mut self, sysctl: Arc<dyn Sysctl>) -> Self {
+        self.sysctl = sysctl;
+        self
+    }
+
+    /// Use a different kernel configuration backend
+    pub fn with_backend(mut self, backend: Arc<dyn CfgBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Use a different shell command executor
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Publish APPL_DB entries through `store`
+    pub fn with_store(mut self, store: Arc<dyn WarmRestartStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Note an APPL_DB write for warm restart reconciliation
+    fn record_app_write(&mut self, key: &str) {
+        if let Some(helper) = self.warm_restart.as_mut() {
+            helper.record(APP_INTF_TABLE, key);
+        }
+    }
+
+    /// Runs a shell command, failing on a non-zero exit code
+    async fn exec_cmd(&mut self, cmd: String) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.mock_mode {
+            self.captured_commands.push(cmd);
+            return Ok(());
+        }
+
+        self.executor.exec_or_throw(&cmd).await?;
+        Ok(())
+    }
+
+    /// Whether to capture `cmd` instead of calling the backend
+    ///
+    /// `cmd` is the command [`ShellBackend`] would run for the operation.
+    #[cfg(test)]
+    fn capture_backend_op(&mut self, cmd: String) -> bool {
+        if self.mock_mode {
+            self.captured_commands.push(cmd);
+        }
+        self.mock_mode
+    }
+
+    /// Binds an interface to a VRF, or unbinds it for `None`
+    async fn bind_vrf(&mut self, alias: &str, vrf: Option<&str>) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::vrf_bind_cmd(alias, vrf)) {
+            return Ok(());
+        }
+
+        set_intf_vrf(self.backend.as_ref(), alias, vrf).await
+    }
+
+    /// Creates a dot1q sub-interface on top of `parent`
+    async fn add_subintf_link(
+        &mut self,
+        parent: &str,
+        subintf: &str,
+        vid: u16,
+    ) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::add_link_vlan_cmd(parent, subintf, vid)) {
+            return Ok(());
+        }
+
+        self.backend.add_link_vlan(parent, subintf, vid).await
+    }
+
+    /// Sets the MTU of a sub-interface
+    async fn set_subintf_link_mtu(&mut self, subintf: &str, mtu: u32) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::set_link_mtu_cmd(subintf, mtu)) {
+            return Ok(());
+        }
+
+        self.backend.set_link_mtu(subintf, mtu).await
+    }
+
+    /// Sets the admin status of a sub-interface
+    ///
+    /// Anything other than "up" brings the sub-interface down.
+    async fn set_subintf_link_admin(
+        &mut self,
+        subintf: &str,
+        admin_status: &str,
+    ) -> CfgMgrResult<()> {
+        let up = admin_status == "up";
+
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::set_link_admin_cmd(subintf, up)) {
+            return Ok(());
+        }
+
+        self.backend.set_link_admin(subintf, up).await
+    }
+
+    /// Writes an entry to APPL_DB INTF_TABLE
+    async fn write_intf_to_app_db(&mut self, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        debug!("Writing {}:{} {:?}", APP_INTF_TABLE, key, fvs);
+        if let Some(store) = &self.store {
+            store.set(DbId::ApplDb, APP_INTF_TABLE, key, &fvs).await?;
+        }
+        #[cfg(test)]
+        self.app_db_ops.push((key.to_string(), Some(fvs)));
+        self.record_app_write(key);
+        Ok(())
+    }
+
+    /// Deletes an entry from APPL_DB INTF_TABLE
+    async fn delete_intf_from_app_db(&mut self, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", APP_INTF_TABLE, key);
+        if let Some(store) = &self.store {
+            store.del(DbId::ApplDb, APP_INTF_TABLE, key).await?;
+        }
+        #[cfg(test)]
+        self.app_db_ops.push((key.to_string(), None));
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn new_mock(switch_type: SwitchType) -> Self {
         let mut mgr = Self::new(switch_type);
@@ -68,12 +271,12 @@ impl IntfMgr {
 
     /// Check if interface state is OK
     ///
-    /// Queries STATE_DB to check if interface is ready
+    /// A VLAN interface is ready once vlanmgrd has marked it ok in
+    /// STATE_DB VLAN_TABLE.
     fn is_intf_state_ok(&self, alias: &str) -> bool {
         // TODO: Query STATE_DB based on interface type
         // Physical → STATE_PORT_TABLE
         // LAG → STATE_LAG_TABLE
-        // VLAN → STATE_VLAN_TABLE
         // For now, assume ready in mock mode
         #[cfg(test)]
         if self.mock_mode {
@@ -81,7 +284,25 @@ impl IntfMgr {
         }
 
         debug!("Checking state for interface {}", alias);
-        true // TODO: Implement STATE_DB check
+        match IntfType::from_name(alias) {
+            Some(IntfType::Vlan(_)) => self.ready_vlans.contains(alias),
+            _ => true, // TODO: Implement STATE_DB check
+        }
+    }
+
+    /// Number of IP addresses waiting for their interface
+    pub fn pending_addr_count(&self) -> usize {
+        self.pending_addrs.values().map(|addrs| addrs.len()).sum()
+    }
+
+    /// Removes a prefix from the addresses waiting for `alias`
+    fn take_pending_addr(&mut self, alias: &str, ip_prefix: &str) -> Option<FieldValues> {
+        let pending = self.pending_addrs.get_mut(alias)?;
+        let values = pending.remove(ip_prefix);
+        if pending.is_empty() {
+            self.pending_addrs.remove(alias);
+        }
+        values
     }
 
     /// Handle INTERFACE table general config (VRF, MPLS, etc.)
@@ -94,11 +315,8 @@ impl IntfMgr {
         if op == "SET" {
             // Handle VRF binding
             if let Some(vrf_name) = values.get_field(intf_fields::VRF_NAME) {
-                if !vrf_name.is_empty() {
-                    crate::vrf_operations::set_intf_vrf(alias, Some(vrf_name)).await?;
-                } else {
-                    crate::vrf_operations::set_intf_vrf(alias, None).await?;
-                }
+                let vrf = Some(vrf_name).filter(|vrf| !vrf.is_empty());
+                self.bind_vrf(alias, vrf).await?;
             }
 
             // Handle MPLS
@@ -106,16 +324,6 @@ impl IntfMgr {
                 crate::vrf_operations::set_intf_mpls(alias, mpls).await?;
             }
 
-            // Handle proxy ARP
-            if let Some(proxy_arp) = values.get_field(intf_fields::PROXY_ARP) {
-                crate::vrf_operations::set_intf_proxy_arp(alias, proxy_arp).await?;
-            }
-
-            // Handle gratuitous ARP
-            if let Some(grat_arp) = values.get_field(intf_fields::GRAT_ARP) {
-                crate::vrf_operations::set_intf_grat_arp(alias, grat_arp).await?;
-            }
-
             // Handle MAC address
             if let Some(mac_addr) = values.get_field(intf_fields::MAC_ADDR) {
                 crate::ip_operations::set_intf_mac(alias, mac_addr).await?;
@@ -130,119 +338,506 @@ impl IntfMgr {
                 }
             }
 
-            // TODO: Write to APPL_DB INTF_TABLE
+            // Handle proxy ARP and gratuitous ARP
+            let arp_fvs = self.update_arp_config(alias, values).await;
+
+            let mut fvs: FieldValues = values
+                .iter()
+                .filter(|(field, _)| {
+                    field != intf_fields::PROXY_ARP && field != intf_fields::GRAT_ARP
+                })
+                .cloned()
+                .collect();
+            fvs.extend(arp_fvs);
+            self.write_intf_to_app_db(alias, fvs).await?;
+            self.pending_replay_intf_list.remove(alias);
         } else if op == "DEL" {
             // Clean up interface config
             self.ipv6_link_local_mode_list.remove(alias);
-            // TODO: Delete from APPL_DB
+            if let Some(arp) = self.arp_config.remove(alias) {
+                if arp.proxy_arp {
+                    self.write_arp_sysctl(alias, intf_fields::PROXY_ARP, false)
+                        .await;
+                }
+                if arp.grat_arp {
+                    self.write_arp_sysctl(alias, intf_fields::GRAT_ARP, false)
+                        .await;
+                }
+            }
+            self.delete_intf_from_app_db(alias).await?;
         }
 
         Ok(true)
     }
 
+    /// Applies the proxy_arp/grat_arp fields of an interface
+    ///
+    /// A field that is removed resets its sysctl to the kernel default. The
+    /// settings are kept even if the sysctl write fails (the interface may
+    /// not exist yet) and are applied again once the interface is ready.
+    ///
+    /// Returns the fields to publish to APPL_DB.
+    async fn update_arp_config(&mut self, alias: &str, values: &FieldValues) -> FieldValues {
+        let prev = self.arp_config.get(alias).copied().unwrap_or_default();
+        let field_state = |field: &str, prev: bool| match values.get_field(field) {
+            None => false,
+            Some(value) => parse_arp_state(value).unwrap_or_else(|| {
+                error!("{} state is invalid for {}: \"{}\"", field, alias, value);
+                prev
+            }),
+        };
+        let arp = ArpConfig {
+            proxy_arp: field_state(intf_fields::PROXY_ARP, prev.proxy_arp),
+            grat_arp: field_state(intf_fields::GRAT_ARP, prev.grat_arp),
+        };
+
+        if arp.is_default() {
+            self.arp_config.remove(alias);
+        } else {
+            self.arp_config.insert(alias.to_string(), arp);
+        }
+
+        let mut fvs = FieldValues::new();
+        for (field, enabled, was_enabled) in [
+            (intf_fields::PROXY_ARP, arp.proxy_arp, prev.proxy_arp),
+            (intf_fields::GRAT_ARP, arp.grat_arp, prev.grat_arp),
+        ] {
+            if values.has_field(field) || enabled != was_enabled {
+                self.write_arp_sysctl(alias, field, enabled).await;
+                fvs.push((field.to_string(), arp_state(enabled).to_string()));
+            }
+        }
+        fvs
+    }
+
+    /// Writes the sysctl behind a proxy_arp/grat_arp field, logging failures
+    async fn write_arp_sysctl(&self, alias: &str, field: &str, enabled: bool) {
+        let result = if field == intf_fields::PROXY_ARP {
+            set_intf_proxy_arp(self.sysctl.as_ref(), alias, enabled).await
+        } else {
+            set_intf_grat_arp(self.sysctl.as_ref(), alias, enabled).await
+        };
+        if let Err(e) = result {
+            warn!(
+                "Failed to set {} on {}, will retry when it is ready: {}",
+                field, alias, e
+            );
+        }
+    }
+
+    /// Re-applies the ARP settings of an interface whose kernel device was
+    /// (re)created, since a new device starts with default sysctls
+    async fn reapply_arp_sysctls(&self, alias: &str) {
+        let Some(arp) = self.arp_config.get(alias).copied() else {
+            return;
+        };
+        info!("Re-applying ARP settings on {}", alias);
+        if arp.proxy_arp {
+            self.write_arp_sysctl(alias, intf_fields::PROXY_ARP, true)
+                .await;
+        }
+        if arp.grat_arp {
+            self.write_arp_sysctl(alias, intf_fields::GRAT_ARP, true)
+                .await;
+        }
+    }
+
+    /// Handle a STATE_DB VLAN_TABLE update
+    pub async fn handle_vlan_state(&mut self, alias: &str, values: Option<&FieldValues>) {
+        let ready = values.is_some_and(|fvs| fvs.get_field(STATE_FIELD) == Some(STATE_OK));
+        if !ready {
+            self.ready_vlans.remove(alias);
+        } else if self.ready_vlans.insert(alias.to_string()) {
+            self.reapply_arp_sysctls(alias).await;
+
+            for (prefix, fvs) in self.pending_addrs.remove(alias).unwrap_or_default() {
+                if let Err(e) = self.do_intf_addr_task(alias, &prefix, "SET", &fvs).await {
+                    error!("Failed to add {} to {}: {}", prefix, alias, e);
+                }
+            }
+        }
+    }
+
     /// Handle INTERFACE|<alias>|<ip_prefix> IP address config
+    ///
+    /// A SET for an address already configured with another prefix length
+    /// replaces it. Secondaries (`secondary=true`) are only added while a
+    /// primary of their subnet is configured.
     pub async fn do_intf_addr_task(
         &mut self,
         alias: &str,
         ip_prefix_str: &str,
         op: &str,
+        values: &FieldValues,
     ) -> CfgMgrResult<bool> {
         // Parse IP prefix
-        let ip_prefix = IpPrefix::parse(ip_prefix_str).map_err(|e| {
-            sonic_cfgmgr_common::CfgMgrError::internal(format!("Invalid IP prefix: {}", e))
+        let addr = IntfAddr::parse(ip_prefix_str).ok_or_else(|| {
+            CfgMgrError::invalid_config(alias, format!("Invalid IP prefix {}", ip_prefix_str))
         })?;
 
+        // A newer update supersedes a deferred one
+        self.take_pending_addr(alias, ip_prefix_str);
+
         if op == "SET" {
             // Check if interface is ready
             if !self.is_intf_state_ok(alias) {
                 info!("Interface {} is not ready, deferring IP config", alias);
-                return Ok(false); // Retry later
+                self.pending_addrs
+                    .entry(alias.to_string())
+                    .or_default()
+                    .insert(ip_prefix_str.to_string(), values.clone());
+                return Ok(false); // Retry when it is
             }
 
-            // Add IP address
-            crate::ip_operations::set_intf_ip(alias, "add", &ip_prefix, &self.switch_type).await?;
+            let secondary = values.get_field(addr_fields::SECONDARY) == Some("true");
+            let state = self.intf_addrs.entry(alias.to_string()).or_default();
+            state
+                .configured
+                .retain(|configured, _| configured.addr != addr.addr);
+            state.configured.insert(addr, secondary);
+        } else if op == "DEL" {
+            if let Some(state) = self.intf_addrs.get_mut(alias) {
+                state.configured.remove(&addr);
+            }
+        }
 
-            info!("Added IP address {} to interface {}", ip_prefix_str, alias);
+        self.sync_intf_addrs(alias).await?;
+        Ok(true)
+    }
 
-            // TODO: Write to APPL_DB INTF_TABLE with scope and family
-        } else if op == "DEL" {
-            // Remove IP address
-            crate::ip_operations::set_intf_ip(alias, "del", &ip_prefix, &self.switch_type).await?;
+    /// Addresses configured on an interface, in the kernel or waiting for a
+    /// primary
+    pub fn intf_addrs(&self, alias: &str) -> Vec<IntfAddr> {
+        self.intf_addrs
+            .get(alias)
+            .map(|state| state.configured.keys().copied().collect())
+            .unwrap_or_default()
+    }
 
-            info!(
-                "Removed IP address {} from interface {}",
-                ip_prefix_str, alias
-            );
+    /// Brings the kernel addresses of an interface in line with its
+    /// configuration
+    ///
+    /// Secondaries are removed before their primary, since the kernel
+    /// would otherwise flush them with it, and added after it.
+    async fn sync_intf_addrs(&mut self, alias: &str) -> CfgMgrResult<()> {
+        let Some(state) = self.intf_addrs.get(alias) else {
+            return Ok(());
+        };
+        let desired = state.desired();
+        let mut to_del: Vec<IntfAddr> = state.applied.difference(&desired).copied().collect();
+        let mut to_add: Vec<IntfAddr> = desired.difference(&state.applied).copied().collect();
+        to_del.sort_by_key(|addr| !state.is_secondary(addr));
+        to_add.sort_by_key(|addr| state.is_secondary(addr));
+
+        for addr in to_del {
+            let cmd = intf_addr_cmd(alias, "del", &addr, &self.switch_type);
+            if let Err(e) = self.exec_cmd(cmd).await {
+                warn!("Failed to remove {} from {}: {}", addr, alias, e);
+            }
+            self.mark_addr_applied(alias, addr, false);
+            self.delete_intf_from_app_db(&format!("{}:{}", alias, addr))
+                .await?;
+            info!("Removed IP address {} from interface {}", addr, alias);
+        }
 
-            // TODO: Delete from APPL_DB INTF_TABLE
+        for addr in to_add {
+            self.add_intf_addr(alias, &addr).await?;
+            self.mark_addr_applied(alias, addr, true);
+
+            let family = if addr.is_ipv4() {
+                FAMILY_IPV4
+            } else {
+                FAMILY_IPV6
+            };
+            let scope = if addr.is_link_local() {
+                SCOPE_LOCAL
+            } else {
+                SCOPE_GLOBAL
+            };
+            let fvs = vec![
+                (app_intf_fields::SCOPE.to_string(), scope.to_string()),
+                (app_intf_fields::FAMILY.to_string(), family.to_string()),
+            ];
+            self.write_intf_to_app_db(&format!("{}:{}", alias, addr), fvs)
+                .await?;
+            info!("Added IP address {} to interface {}", addr, alias);
         }
 
-        Ok(true)
+        if self
+            .intf_addrs
+            .get(alias)
+            .is_some_and(IntfAddrState::is_empty)
+        {
+            self.intf_addrs.remove(alias);
+        }
+        Ok(())
+    }
+
+    /// Adds an address to the kernel, enabling IPv6 on the interface and
+    /// retrying if an IPv6 address is refused
+    async fn add_intf_addr(&mut self, alias: &str, addr: &IntfAddr) -> CfgMgrResult<()> {
+        let cmd = intf_addr_cmd(alias, "add", addr, &self.switch_type);
+        match self.exec_cmd(cmd.clone()).await {
+            Err(_) if !addr.is_ipv4() => {
+                info!(
+                    "Failed to assign IPv6 on {}, enabling IPv6 and retrying",
+                    alias
+                );
+                self.sysctl
+                    .write(&ipv6_conf_path(alias, DISABLE_IPV6_SYSCTL), "0")
+                    .await?;
+                self.exec_cmd(cmd).await
+            }
+            result => result,
+        }
+    }
+
+    /// Records whether an address is in the kernel
+    fn mark_addr_applied(&mut self, alias: &str, addr: IntfAddr, applied: bool) {
+        if let Some(state) = self.intf_addrs.get_mut(alias) {
+            if applied {
+                state.applied.insert(addr);
+            } else {
+                state.applied.remove(&addr);
+            }
+        }
+    }
+
+    /// MTU of a parent port/LAG, or None if it is not present in STATE_DB yet
+    pub fn parent_mtu(&self, parent: &str) -> Option<u32> {
+        self.parent_mtus.get(parent).copied()
+    }
+
+    /// Number of sub-interfaces waiting for their parent
+    pub fn pending_subintf_count(&self) -> usize {
+        self.pending_subintfs.values().map(|subs| subs.len()).sum()
     }
 
     /// Handle sub-interface creation
+    ///
+    /// The kernel refuses `ip link add link <parent>` until the parent
+    /// exists, so a sub-interface whose parent is not yet present in
+    /// STATE_DB is queued under the parent and created once it shows up.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Sub-interface created/updated and published to APPL_DB
+    /// * `Ok(false)` - Parent not ready, queued until it is
     pub async fn handle_subintf_create(
         &mut self,
         subintf: &str,
         values: &FieldValues,
     ) -> CfgMgrResult<bool> {
         // Parse sub-interface name
-        let (parent, vlan_id) = crate::subintf::parse_subintf_name(subintf).ok_or_else(|| {
-            sonic_cfgmgr_common::CfgMgrError::internal("Invalid sub-interface name")
-        })?;
+        let (parent, name_vlan_id) = parse_subintf_name(subintf)
+            .ok_or_else(|| CfgMgrError::invalid_config(subintf, "Invalid sub-interface name"))?;
+
+        // Short names (Eth4.100) may carry the VLAN ID as a field
+        let vlan_id = values
+            .get_field(subintf_fields::VLAN)
+            .unwrap_or(name_vlan_id.as_str())
+            .to_string();
+        let Ok(vid @ 1..=4094) = vlan_id.parse::<u16>() else {
+            return Err(CfgMgrError::invalid_config(
+                subintf_fields::VLAN,
+                format!("Invalid VLAN ID {} for {}", vlan_id, subintf),
+            ));
+        };
+
+        let mtu = values.get_field(subintf_fields::MTU).unwrap_or_default();
+        parse_requested_mtu(mtu)?;
 
         // Check if parent interface is ready
-        if !self.is_intf_state_ok(&parent) {
+        if self.parent_mtu(&parent).is_none() {
             info!(
-                "Parent interface {} is not ready, deferring sub-interface creation",
-                parent
+                "Parent interface {} is not ready, deferring sub-interface {}",
+                parent, subintf
             );
-            return Ok(false); // Retry later
+            self.pending_subintfs
+                .entry(parent)
+                .or_default()
+                .insert(subintf.to_string(), values.clone());
+            return Ok(false);
         }
 
-        // Create sub-interface
-        crate::subintf_operations::add_host_subintf(&parent, subintf, &vlan_id).await?;
+        if let Some(info) = self.subintf_list.get(subintf) {
+            if info.vlan_id != vlan_id {
+                warn!(
+                    "Sub-interface {} VLAN ID cannot change from {} to {}",
+                    subintf, info.vlan_id, vlan_id
+                );
+            }
+        } else {
+            // Create sub-interface
+            self.add_subintf_link(&parent, subintf, vid).await?;
+            info!("Created sub-interface {} on {}", subintf, parent);
+        }
 
-        // Get MTU and admin status
-        let mtu = values.get_field(subintf_fields::MTU).unwrap_or_default();
         let admin_status = values
             .get_field(subintf_fields::ADMIN_STATUS)
-            .unwrap_or_default();
+            .unwrap_or(SUBINTF_DEFAULT_ADMIN_STATUS);
 
-        // Track in subintf_list
-        self.subintf_list.insert(
-            subintf.to_string(),
-            SubIntfInfo {
-                vlan_id,
-                mtu: mtu.to_string(),
-                admin_status: admin_status.to_string(),
-                curr_admin_status: String::new(),
-            },
-        );
+        // Track in subintf_list, keeping the admin status last applied
+        let entry = self
+            .subintf_list
+            .entry(subintf.to_string())
+            .or_insert_with(|| SubIntfInfo::new(vlan_id));
+        entry.mtu = mtu.to_string();
+        entry.admin_status = admin_status.to_string();
 
-        info!("Created sub-interface {}", subintf);
+        self.apply_subintf_attrs(subintf, &parent).await?;
+        Ok(true)
+    }
 
-        // TODO: Set MTU and admin status
-        // TODO: Write to STATE_DB INTERFACE_TABLE
+    /// Applies MTU and admin status of a tracked sub-interface and
+    /// publishes it to APPL_DB INTF_TABLE
+    async fn apply_subintf_attrs(&mut self, subintf: &str, parent: &str) -> CfgMgrResult<()> {
+        let (Some(parent_mtu), Some(info)) =
+            (self.parent_mtu(parent), self.subintf_list.get(subintf))
+        else {
+            return Ok(());
+        };
+
+        let requested = parse_requested_mtu(&info.mtu)?;
+        let mtu = clamp_subintf_mtu(requested, parent_mtu);
+        if requested.is_some_and(|requested| requested > mtu) {
+            warn!(
+                "Sub-interface {} MTU {} exceeds parent {} MTU {}, using parent MTU",
+                subintf, info.mtu, parent, parent_mtu
+            );
+        }
+        let vlan_id = info.vlan_id.clone();
+        let admin_status = info.admin_status.clone();
+        let admin_changed = info.curr_admin_status != admin_status;
+
+        self.set_subintf_link_mtu(subintf, mtu).await?;
+        if admin_changed {
+            self.set_subintf_link_admin(subintf, &admin_status).await?;
+            if let Some(info) = self.subintf_list.get_mut(subintf) {
+                info.curr_admin_status = admin_status.clone();
+            }
+        }
 
-        Ok(true)
+        let fvs = vec![
+            (subintf_fields::VLAN.to_string(), vlan_id),
+            (subintf_fields::MTU.to_string(), mtu.to_string()),
+            (subintf_fields::ADMIN_STATUS.to_string(), admin_status),
+        ];
+        self.write_intf_to_app_db(subintf, fvs).await
     }
 
     /// Handle sub-interface deletion
     pub async fn handle_subintf_delete(&mut self, subintf: &str) -> CfgMgrResult<bool> {
-        // Remove sub-interface
-        crate::subintf_operations::remove_host_subintf(subintf).await?;
+        if let Some((parent, _)) = parse_subintf_name(subintf) {
+            if let Some(pending) = self.pending_subintfs.get_mut(&parent) {
+                pending.remove(subintf);
+                if pending.is_empty() {
+                    self.pending_subintfs.remove(&parent);
+                }
+            }
+        }
 
-        // Remove from tracking
-        self.subintf_list.remove(subintf);
+        if self.subintf_list.remove(subintf).is_none() {
+            debug!("Sub-interface {} was never created", subintf);
+            return Ok(true);
+        }
 
+        self.exec_cmd(del_subintf_cmd(subintf)).await?;
+        self.delete_intf_from_app_db(subintf).await?;
         info!("Deleted sub-interface {}", subintf);
 
-        // TODO: Remove from STATE_DB INTERFACE_TABLE
-
         Ok(true)
     }
 
+    /// Handle a STATE_DB PORT_TABLE/LAG_TABLE update for a potential parent
+    ///
+    /// A parent becoming ready releases its pending sub-interfaces; an MTU
+    /// change re-clamps the existing ones. A parent that goes away takes
+    /// its sub-interfaces with it, and they are queued again until it
+    /// comes back.
+    pub async fn handle_parent_state(
+        &mut self,
+        parent: &str,
+        values: Option<&FieldValues>,
+    ) -> CfgMgrResult<()> {
+        let ready = values.is_some_and(|fvs| fvs.get_field(STATE_FIELD) == Some(STATE_OK));
+        if !ready {
+            if self.parent_mtus.remove(parent).is_some() {
+                self.remove_parent_subintfs(parent).await;
+            }
+            return Ok(());
+        }
+
+        let mtu = values
+            .and_then(|fvs| fvs.get_field(port_fields::MTU))
+            .and_then(|mtu| mtu.parse().ok())
+            .unwrap_or(DEFAULT_MTU);
+        let prev_mtu = self.parent_mtus.insert(parent.to_string(), mtu);
+        if prev_mtu.is_none() {
+            self.reapply_arp_sysctls(parent).await;
+        }
+
+        if prev_mtu.is_some_and(|prev| prev != mtu) {
+            info!("Parent {} MTU changed to {}", parent, mtu);
+            for subintf in self.parent_subintfs(parent) {
+                if let Err(e) = self.apply_subintf_attrs(&subintf, parent).await {
+                    error!("Failed to update sub-interface {}: {}", subintf, e);
+                }
+            }
+        }
+
+        for (subintf, fvs) in self.pending_subintfs.remove(parent).unwrap_or_default() {
+            if let Err(e) = self.handle_subintf_create(&subintf, &fvs).await {
+                error!("Failed to create sub-interface {}: {}", subintf, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Names of the tracked sub-interfaces of `parent`, sorted
+    fn parent_subintfs(&self, parent: &str) -> Vec<String> {
+        let mut subintfs: Vec<String> = self
+            .subintf_list
+            .keys()
+            .filter(|name| parse_subintf_name(name).is_some_and(|(p, _)| p == parent))
+            .cloned()
+            .collect();
+        subintfs.sort();
+        subintfs
+    }
+
+    /// Removes every sub-interface of a parent that went away, queueing
+    /// their configuration until the parent is back
+    async fn remove_parent_subintfs(&mut self, parent: &str) {
+        for subintf in self.parent_subintfs(parent) {
+            let Some(info) = self.subintf_list.remove(&subintf) else {
+                continue;
+            };
+            info!(
+                "Parent {} removed, deleting sub-interface {}",
+                parent, subintf
+            );
+
+            // The kernel usually drops VLAN devices along with their parent
+            if let Err(e) = self.exec_cmd(del_subintf_cmd(&subintf)).await {
+                debug!("Sub-interface {} already gone: {}", subintf, e);
+            }
+            if let Err(e) = self.delete_intf_from_app_db(&subintf).await {
+                error!("Failed to remove sub-interface {}: {}", subintf, e);
+            }
+
+            let mut fvs = vec![(subintf_fields::VLAN.to_string(), info.vlan_id)];
+            if !info.mtu.is_empty() {
+                fvs.push((subintf_fields::MTU.to_string(), info.mtu));
+            }
+            fvs.push((subintf_fields::ADMIN_STATUS.to_string(), info.admin_status));
+            self.pending_subintfs
+                .entry(parent.to_string())
+                .or_default()
+                .insert(subintf, fvs);
+        }
+    }
+
     /// Add loopback interface
     pub async fn add_loopback_intf(&mut self, alias: &str) -> CfgMgrResult<()> {
         let cmd = format!(
@@ -250,11 +845,11 @@ impl IntfMgr {
             IP_CMD,
             shell::shellquote(alias)
         );
-        sonic_cfgmgr_common::shell::exec(&cmd).await?;
+        self.executor.exec(&cmd).await?;
 
         // Set loopback up
         let cmd = format!("{} link set {} up", IP_CMD, shell::shellquote(alias));
-        sonic_cfgmgr_common::shell::exec(&cmd).await?;
+        self.executor.exec(&cmd).await?;
 
         // Set default MTU
         let cmd = format!(
@@ -263,7 +858,7 @@ impl IntfMgr {
             shell::shellquote(alias),
             LOOPBACK_DEFAULT_MTU
         );
-        sonic_cfgmgr_common::shell::exec(&cmd).await?;
+        self.executor.exec(&cmd).await?;
 
         self.loopback_intf_list.insert(alias.to_string());
         info!("Added loopback interface {}", alias);
@@ -274,7 +869,7 @@ impl IntfMgr {
     /// Delete loopback interface
     pub async fn del_loopback_intf(&mut self, alias: &str) -> CfgMgrResult<()> {
         let cmd = format!("{} link del {}", IP_CMD, shell::shellquote(alias));
-        sonic_cfgmgr_common::shell::exec(&cmd).await?;
+        self.executor.exec(&cmd).await?;
 
         self.loopback_intf_list.remove(alias);
         info!("Deleted loopback interface {}", alias);
@@ -289,6 +884,44 @@ impl IntfMgr {
         info!("Built warm restart replay list");
     }
 
+    /// Processes one queued CONFIG_DB or STATE_DB entry
+    async fn process_task(&mut self, table: &str, entry: KeyOpFieldsValues) -> CfgMgrResult<()> {
+        let is_set = entry.op.is_set();
+        let op = if is_set { "SET" } else { "DEL" };
+
+        match table {
+            STATE_PORT_TABLE | STATE_LAG_TABLE => {
+                let values = is_set.then_some(&entry.fvs);
+                self.handle_parent_state(&entry.key, values).await
+            }
+            STATE_VLAN_TABLE => {
+                let values = is_set.then_some(&entry.fvs);
+                self.handle_vlan_state(&entry.key, values).await;
+                Ok(())
+            }
+            _ => {
+                if let Some((alias, prefix)) = entry.key.split_once(CONFIG_KEY_SEPARATOR) {
+                    if !self
+                        .do_intf_addr_task(alias, prefix, op, &entry.fvs)
+                        .await?
+                    {
+                        debug!("Address {} on {} deferred", prefix, alias);
+                    }
+                } else if table == CFG_VLAN_SUB_INTF_TABLE {
+                    if is_set {
+                        self.handle_subintf_create(&entry.key, &entry.fvs).await?;
+                    } else {
+                        self.handle_subintf_delete(&entry.key).await?;
+                    }
+                } else {
+                    self.do_intf_general_task(&entry.key, op, &entry.fvs)
+                        .await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Set warm restart done state
     pub fn set_warm_replay_done_state(&mut self) {
         // TODO: Write to STATE_DB WARM_RESTART_TABLE
@@ -304,7 +937,27 @@ impl Orch for IntfMgr {
     }
 
     async fn do_task(&mut self) {
-        // TODO: Process consumers when integrating with ConsumerStateTable
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            let key = entry.key.clone();
+            if let Err(e) = self.process_task(&table, entry).await {
+                error!("Failed to process {}|{}: {}", table, key, e);
+            }
+        }
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        !self.tasks.is_empty()
+    }
+
+    fn dump_pending_tasks(&self) -> Vec<String> {
+        let mut dump: Vec<String> = self
+            .pending_subintfs
+            .iter()
+            .chain(&self.pending_addrs)
+            .flat_map(|(parent, subs)| subs.keys().map(move |sub| format!("{}:{}", parent, sub)))
+            .collect();
+        dump.sort();
+        dump
     }
 }
 
@@ -315,20 +968,47 @@ impl CfgMgr for IntfMgr {
     }
 
     fn is_warm_restart(&self) -> bool {
-        // TODO: Check warm restart state
-        false
+        self.warm_restart
+            .as_ref()
+            .is_some_and(WarmRestartHelper::is_warm_start)
     }
 
     fn warm_restart_state(&self) -> WarmRestartState {
-        if self.replay_done {
-            WarmRestartState::Disabled
+        self.warm_restart
+            .as_ref()
+            .map_or(WarmRestartState::Disabled, WarmRestartHelper::state)
+    }
+
+    async fn set_warm_restart_state(&mut self, state: WarmRestartState) {
+        let Some(helper) = self.warm_restart.as_mut() else {
+            return;
+        };
+
+        let result = if state == WarmRestartState::Reconciled {
+            helper.reconcile().await.map(|removed| {
+                info!("Removed {} stale APPL_DB entries", removed);
+            })
         } else {
-            WarmRestartState::Reconciled
+            helper.set_state(state).await
+        };
+        if let Err(e) = result {
+            warn!("Failed to set warm restart state {}: {}", state.as_str(), e);
         }
     }
 
-    async fn set_warm_restart_state(&mut self, _state: WarmRestartState) {
-        // TODO: Write to STATE_DB WARM_RESTART_TABLE
+    fn is_replay_done(&self) -> bool {
+        self.pending_replay_intf_list.is_empty()
+    }
+
+    async fn build_replay_list(&mut self) {
+        self.build_intf_replay_list();
+
+        let Some(helper) = self.warm_restart.as_mut() else {
+            return;
+        };
+        if let Err(e) = helper.restore(&[APP_INTF_TABLE]).await {
+            warn!("Failed to cache APPL_DB for warm restart: {}", e);
+        }
     }
 
     fn config_table_names(&self) -> &[&str] {
@@ -337,13 +1017,54 @@ impl CfgMgr for IntfMgr {
             CFG_VLAN_INTF_TABLE,
             CFG_LAG_INTF_TABLE,
             CFG_LOOPBACK_INTF_TABLE,
+            CFG_VLAN_SUB_INTF_TABLE,
         ]
     }
+
+    fn state_table_names(&self) -> &[&str] {
+        &[STATE_PORT_TABLE, STATE_LAG_TABLE, STATE_VLAN_TABLE]
+    }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        match (db, table) {
+            (DbId::ConfigDb, _)
+            | (DbId::StateDb, STATE_PORT_TABLE | STATE_LAG_TABLE | STATE_VLAN_TABLE) => {
+                self.tasks
+                    .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+            }
+            _ => debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            ),
+        }
+    }
+}
+
+/// APPL_DB value of a proxy_arp/grat_arp setting
+fn arp_state(enabled: bool) -> &'static str {
+    if enabled {
+        ARP_ENABLED
+    } else {
+        ARP_DISABLED
+    }
+}
+
+/// Parses a configured sub-interface MTU; empty or "0" inherits the parent's
+fn parse_requested_mtu(mtu: &str) -> CfgMgrResult<Option<u32>> {
+    if mtu.is_empty() || mtu == MTU_INHERITANCE {
+        return Ok(None);
+    }
+    mtu.parse().map(Some).map_err(|_| {
+        CfgMgrError::invalid_config(subintf_fields::MTU, format!("Invalid MTU {}", mtu))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sonic_cfgmgr_common::InMemorySysctl;
 
     #[test]
     fn test_intf_mgr_new() {
@@ -378,6 +1099,67 @@ mod tests {
         assert!(!mgr.ipv6_link_local_mode_list.contains("Ethernet0"));
     }
 
+    #[tokio::test]
+    async fn test_warm_restart_removes_stale_entries() {
+        use sonic_cfgmgr_common::warm_restart::{
+            fields as warm_fields, STATE_WARM_RESTART_ENABLE_TABLE,
+        };
+        use sonic_cfgmgr_common::{field_values, DbId, InMemoryStore, WarmRestartStore};
+        use std::sync::Arc;
+
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .set(
+                DbId::StateDb,
+                STATE_WARM_RESTART_ENABLE_TABLE,
+                "swss",
+                &field_values! { warm_fields::ENABLE => "true" },
+            )
+            .await
+            .unwrap();
+        for key in ["Ethernet0", "Ethernet4"] {
+            store
+                .set(
+                    DbId::ApplDb,
+                    APP_INTF_TABLE,
+                    key,
+                    &field_values! { "mpls" => "disable" },
+                )
+                .await
+                .unwrap();
+        }
+
+        let mut helper = WarmRestartHelper::new("intfmgrd", store.clone());
+        assert!(helper.initialize("swss").await.unwrap());
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal).with_warm_restart(helper);
+        assert!(mgr.is_warm_restart());
+
+        // Only Ethernet0 comes back during the replay
+        mgr.build_replay_list().await;
+        mgr.do_intf_general_task("Ethernet0", "SET", &Vec::new())
+            .await
+            .unwrap();
+        mgr.set_warm_restart_state(WarmRestartState::Replayed).await;
+
+        // Nothing is deleted before reconcile
+        assert_eq!(
+            store
+                .keys(DbId::ApplDb, APP_INTF_TABLE)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        mgr.set_warm_restart_state(WarmRestartState::Reconciled)
+            .await;
+        assert_eq!(mgr.warm_restart_state(), WarmRestartState::Reconciled);
+        assert_eq!(
+            store.keys(DbId::ApplDb, APP_INTF_TABLE).await.unwrap(),
+            vec!["Ethernet0"]
+        );
+    }
+
     #[test]
     fn test_subintf_tracking() {
         let mut mgr = IntfMgr::new(SwitchType::Normal);
@@ -388,4 +1170,663 @@ mod tests {
         assert!(mgr.subintf_list.contains_key("Ethernet0.100"));
         assert_eq!(mgr.subintf_list["Ethernet0.100"].vlan_id, "100");
     }
+
+    fn parent_state(mtu: &str) -> FieldValues {
+        vec![
+            (STATE_FIELD.to_string(), STATE_OK.to_string()),
+            (port_fields::MTU.to_string(), mtu.to_string()),
+        ]
+    }
+
+    fn subintf_mtu(mtu: &str) -> FieldValues {
+        vec![(subintf_fields::MTU.to_string(), mtu.to_string())]
+    }
+
+    fn published(mgr: &IntfMgr, key: &str) -> Option<FieldValues> {
+        mgr.app_db_ops
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, fvs)| fvs.clone())
+    }
+
+    #[tokio::test]
+    async fn test_subintf_configured_before_parent_ready() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+
+        let created = mgr
+            .handle_subintf_create("Ethernet4.100", &subintf_mtu("1500"))
+            .await
+            .unwrap();
+        assert!(!created);
+        assert_eq!(mgr.pending_subintf_count(), 1);
+        assert!(mgr.captured_commands.is_empty());
+        assert!(mgr.app_db_ops.is_empty());
+
+        // Another port becoming ready does not release it
+        mgr.handle_parent_state("Ethernet8", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        assert_eq!(mgr.pending_subintf_count(), 1);
+
+        mgr.handle_parent_state("Ethernet4", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        assert_eq!(mgr.pending_subintf_count(), 0);
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip link add link \"Ethernet4\" name \"Ethernet4.100\" type vlan id 100",
+                "/sbin/ip link set dev \"Ethernet4.100\" mtu 1500",
+                "/sbin/ip link set dev \"Ethernet4.100\" up",
+            ]
+        );
+        let fvs = published(&mgr, "Ethernet4.100").unwrap();
+        assert_eq!(fvs.get_field(subintf_fields::VLAN), Some("100"));
+        assert_eq!(fvs.get_field(subintf_fields::MTU), Some("1500"));
+        assert_eq!(fvs.get_field(subintf_fields::ADMIN_STATUS), Some("up"));
+    }
+
+    #[tokio::test]
+    async fn test_subintf_mtu_clamped_to_parent() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        mgr.handle_parent_state("PortChannel1", Some(&parent_state("1500")))
+            .await
+            .unwrap();
+
+        assert!(mgr
+            .handle_subintf_create("Po1.200", &subintf_mtu("9100"))
+            .await
+            .unwrap());
+        assert!(mgr
+            .captured_commands
+            .contains(&"/sbin/ip link set dev \"Po1.200\" mtu 1500".to_string()));
+        assert_eq!(
+            published(&mgr, "Po1.200")
+                .unwrap()
+                .get_field(subintf_fields::MTU),
+            Some("1500")
+        );
+
+        // Raising the parent MTU lets the requested MTU through
+        mgr.captured_commands.clear();
+        mgr.handle_parent_state("PortChannel1", Some(&parent_state("9216")))
+            .await
+            .unwrap();
+        assert_eq!(
+            mgr.captured_commands,
+            vec!["/sbin/ip link set dev \"Po1.200\" mtu 9100"]
+        );
+        assert_eq!(
+            published(&mgr, "Po1.200")
+                .unwrap()
+                .get_field(subintf_fields::MTU),
+            Some("9100")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subintf_inherits_parent_mtu() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        mgr.handle_parent_state("Ethernet0", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+
+        mgr.handle_subintf_create("Ethernet0.10", &Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            published(&mgr, "Ethernet0.10")
+                .unwrap()
+                .get_field(subintf_fields::MTU),
+            Some("9100")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subintf_short_name_alias() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        mgr.handle_parent_state("Ethernet4", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+
+        let fvs = vec![(subintf_fields::ADMIN_STATUS.to_string(), "down".to_string())];
+        assert!(mgr.handle_subintf_create("Eth4.100", &fvs).await.unwrap());
+
+        // The kernel device keeps the short name, the parent is the full one
+        assert_eq!(
+            mgr.captured_commands[0],
+            "/sbin/ip link add link \"Ethernet4\" name \"Eth4.100\" type vlan id 100"
+        );
+        assert_eq!(
+            mgr.captured_commands[2],
+            "/sbin/ip link set dev \"Eth4.100\" down"
+        );
+        let fvs = published(&mgr, "Eth4.100").unwrap();
+        assert_eq!(fvs.get_field(subintf_fields::VLAN), Some("100"));
+        assert_eq!(fvs.get_field(subintf_fields::ADMIN_STATUS), Some("down"));
+    }
+
+    #[tokio::test]
+    async fn test_vrf_binding_goes_through_backend() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        let vrf = |name: &str| vec![(intf_fields::VRF_NAME.to_string(), name.to_string())];
+
+        mgr.do_intf_general_task("Ethernet0", "SET", &vrf("Vrf_red"))
+            .await
+            .unwrap();
+        mgr.do_intf_general_task("Ethernet0", "SET", &vrf(""))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                ShellBackend::vrf_bind_cmd("Ethernet0", Some("Vrf_red")),
+                ShellBackend::vrf_bind_cmd("Ethernet0", None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subintf_invalid_vlan_rejected() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        mgr.handle_parent_state("Ethernet4", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+
+        assert!(mgr
+            .handle_subintf_create("Ethernet4.4095", &Vec::new())
+            .await
+            .is_err());
+        assert!(mgr
+            .handle_subintf_create("Ethernet4.100", &subintf_mtu("jumbo"))
+            .await
+            .is_err());
+        assert!(mgr.captured_commands.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_parent_removal_cascades_to_subintfs() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        for parent in ["Ethernet4", "Ethernet8"] {
+            mgr.handle_parent_state(parent, Some(&parent_state("9100")))
+                .await
+                .unwrap();
+        }
+        for subintf in ["Ethernet4.100", "Eth4.200", "Ethernet8.100"] {
+            mgr.handle_subintf_create(subintf, &subintf_mtu("1500"))
+                .await
+                .unwrap();
+        }
+
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+        mgr.handle_parent_state("Ethernet4", None).await.unwrap();
+
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip link del \"Eth4.200\"",
+                "/sbin/ip link del \"Ethernet4.100\"",
+            ]
+        );
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![
+                ("Eth4.200".to_string(), None),
+                ("Ethernet4.100".to_string(), None),
+            ]
+        );
+        assert!(mgr.subintf_list.contains_key("Ethernet8.100"));
+        assert_eq!(mgr.pending_subintf_count(), 2);
+        assert_eq!(
+            mgr.dump_pending_tasks(),
+            vec!["Ethernet4:Eth4.200", "Ethernet4:Ethernet4.100"]
+        );
+
+        // Both come back with their configuration once the parent returns
+        mgr.handle_parent_state("Ethernet4", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        assert_eq!(mgr.pending_subintf_count(), 0);
+        assert_eq!(
+            published(&mgr, "Eth4.200")
+                .unwrap()
+                .get_field(subintf_fields::MTU),
+            Some("1500")
+        );
+        assert!(mgr.subintf_list.contains_key("Ethernet4.100"));
+    }
+
+    #[tokio::test]
+    async fn test_subintf_delete_while_pending() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        mgr.handle_subintf_create("Ethernet4.100", &Vec::new())
+            .await
+            .unwrap();
+
+        mgr.handle_subintf_delete("Ethernet4.100").await.unwrap();
+        assert_eq!(mgr.pending_subintf_count(), 0);
+
+        mgr.handle_parent_state("Ethernet4", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        assert!(mgr.captured_commands.is_empty());
+        assert!(mgr.app_db_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_do_task_orders_subintf_after_parent() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_VLAN_SUB_INTF_TABLE,
+            vec![KeyOpFieldsValues::set("Po1.200", Vec::new())],
+        );
+        mgr.add_to_sync(
+            DbId::StateDb,
+            STATE_LAG_TABLE,
+            vec![KeyOpFieldsValues::set("PortChannel1", parent_state("9100"))],
+        );
+        mgr.add_to_sync(
+            DbId::ApplDb,
+            APP_PORT_TABLE,
+            vec![KeyOpFieldsValues::set("Ethernet0", Vec::new())],
+        );
+        assert!(mgr.has_pending_tasks());
+
+        mgr.do_task().await;
+        assert!(!mgr.has_pending_tasks());
+        assert!(mgr.subintf_list.contains_key("Po1.200"));
+        assert!(published(&mgr, "Po1.200").is_some());
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_VLAN_SUB_INTF_TABLE,
+            vec![KeyOpFieldsValues::del("Po1.200")],
+        );
+        mgr.do_task().await;
+        assert!(mgr.subintf_list.is_empty());
+        assert_eq!(mgr.app_db_ops.last(), Some(&("Po1.200".to_string(), None)));
+    }
+
+    fn arp_fields(proxy_arp: Option<&str>, grat_arp: Option<&str>) -> FieldValues {
+        let mut fvs = FieldValues::new();
+        if let Some(value) = proxy_arp {
+            fvs.push((intf_fields::PROXY_ARP.to_string(), value.to_string()));
+        }
+        if let Some(value) = grat_arp {
+            fvs.push((intf_fields::GRAT_ARP.to_string(), value.to_string()));
+        }
+        fvs
+    }
+
+    fn sysctl_mgr() -> (IntfMgr, Arc<InMemorySysctl>) {
+        let sysctl = Arc::new(InMemorySysctl::new());
+        let mgr = IntfMgr::new_mock(SwitchType::Normal).with_sysctl(sysctl.clone());
+        (mgr, sysctl)
+    }
+
+    #[tokio::test]
+    async fn test_proxy_arp_and_grat_arp_on_vlan() {
+        let (mut mgr, sysctl) = sysctl_mgr();
+
+        mgr.do_intf_general_task(
+            "Vlan100",
+            "SET",
+            &arp_fields(Some("enabled"), Some("enabled")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            sysctl.journal(),
+            vec![
+                "net/ipv4/conf/Vlan100/proxy_arp=1",
+                "net/ipv4/conf/Vlan100/proxy_arp_pvlan=1",
+                "net/ipv4/conf/Vlan100/arp_accept=1",
+            ]
+        );
+        let fvs = published(&mgr, "Vlan100").unwrap();
+        assert_eq!(fvs.get_field(intf_fields::PROXY_ARP), Some("enabled"));
+        assert_eq!(fvs.get_field(intf_fields::GRAT_ARP), Some("enabled"));
+    }
+
+    #[tokio::test]
+    async fn test_arp_field_removal_resets_default() {
+        let (mut mgr, sysctl) = sysctl_mgr();
+        mgr.do_intf_general_task("Ethernet0", "SET", &arp_fields(Some("enabled"), None))
+            .await
+            .unwrap();
+        sysctl.clear_journal();
+
+        // proxy_arp removed from CONFIG_DB
+        mgr.do_intf_general_task("Ethernet0", "SET", &Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            sysctl.journal(),
+            vec!["net/ipv4/conf/Ethernet0/proxy_arp=0"]
+        );
+        assert_eq!(
+            published(&mgr, "Ethernet0")
+                .unwrap()
+                .get_field(intf_fields::PROXY_ARP),
+            Some("disabled")
+        );
+        assert!(mgr.arp_config.is_empty());
+
+        // Nothing left to reset
+        sysctl.clear_journal();
+        mgr.do_intf_general_task("Ethernet0", "SET", &Vec::new())
+            .await
+            .unwrap();
+        assert!(sysctl.journal().is_empty());
+        assert_eq!(published(&mgr, "Ethernet0"), Some(Vec::new()));
+    }
+
+    #[tokio::test]
+    async fn test_arp_invalid_value_keeps_setting() {
+        let (mut mgr, sysctl) = sysctl_mgr();
+        mgr.do_intf_general_task("Vlan100", "SET", &arp_fields(None, Some("enabled")))
+            .await
+            .unwrap();
+
+        mgr.do_intf_general_task("Vlan100", "SET", &arp_fields(None, Some("yes")))
+            .await
+            .unwrap();
+        assert_eq!(
+            sysctl.get("net/ipv4/conf/Vlan100/arp_accept").as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            published(&mgr, "Vlan100")
+                .unwrap()
+                .get_field(intf_fields::GRAT_ARP),
+            Some("enabled")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_arp_reapplied_after_intf_flap() {
+        let (mut mgr, sysctl) = sysctl_mgr();
+        mgr.handle_parent_state("Ethernet0", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        mgr.handle_vlan_state("Vlan100", Some(&parent_state("9100")))
+            .await;
+        mgr.do_intf_general_task("Ethernet0", "SET", &arp_fields(Some("enabled"), None))
+            .await
+            .unwrap();
+        mgr.do_intf_general_task("Vlan100", "SET", &arp_fields(None, Some("enabled")))
+            .await
+            .unwrap();
+
+        // Further updates for an interface that stays ready change nothing
+        sysctl.clear_journal();
+        mgr.handle_parent_state("Ethernet0", Some(&parent_state("9100")))
+            .await
+            .unwrap();
+        mgr.handle_vlan_state("Vlan100", Some(&parent_state("9100")))
+            .await;
+        assert!(sysctl.journal().is_empty());
+
+        // The kernel devices are recreated with default sysctls
+        for (alias, state_table) in [
+            ("Ethernet0", STATE_PORT_TABLE),
+            ("Vlan100", STATE_VLAN_TABLE),
+        ] {
+            sysctl.remove_intf(alias);
+            mgr.add_to_sync(
+                DbId::StateDb,
+                state_table,
+                vec![KeyOpFieldsValues::del(alias)],
+            );
+            mgr.add_to_sync(
+                DbId::StateDb,
+                state_table,
+                vec![KeyOpFieldsValues::set(alias, parent_state("9100"))],
+            );
+        }
+        mgr.do_task().await;
+
+        assert_eq!(
+            sysctl.journal(),
+            vec![
+                "net/ipv4/conf/Ethernet0/proxy_arp=1",
+                "net/ipv4/conf/Vlan100/arp_accept=1",
+            ]
+        );
+        assert_eq!(
+            sysctl.get("net/ipv4/conf/Ethernet0/proxy_arp").as_deref(),
+            Some("1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_intf_delete_resets_arp() {
+        let (mut mgr, sysctl) = sysctl_mgr();
+        mgr.do_intf_general_task(
+            "Vlan100",
+            "SET",
+            &arp_fields(Some("enabled"), Some("disabled")),
+        )
+        .await
+        .unwrap();
+        sysctl.clear_journal();
+
+        mgr.do_intf_general_task("Vlan100", "DEL", &Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            sysctl.journal(),
+            vec![
+                "net/ipv4/conf/Vlan100/proxy_arp=0",
+                "net/ipv4/conf/Vlan100/proxy_arp_pvlan=0",
+            ]
+        );
+        assert_eq!(mgr.app_db_ops.last(), Some(&("Vlan100".to_string(), None)));
+
+        // A recreated VLAN does not get the old settings back
+        mgr.handle_vlan_state("Vlan100", Some(&parent_state("9100")))
+            .await;
+        assert_eq!(sysctl.journal().len(), 2);
+    }
+
+    fn secondary() -> FieldValues {
+        vec![(addr_fields::SECONDARY.to_string(), "true".to_string())]
+    }
+
+    async fn set_addr(mgr: &mut IntfMgr, alias: &str, prefix: &str, fvs: FieldValues) {
+        mgr.do_intf_addr_task(alias, prefix, "SET", &fvs)
+            .await
+            .unwrap();
+    }
+
+    async fn del_addr(mgr: &mut IntfMgr, alias: &str, prefix: &str) {
+        mgr.do_intf_addr_task(alias, prefix, "DEL", &Vec::new())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_addr_prefix_change_replaces_old_address() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        set_addr(&mut mgr, "Ethernet0", "10.0.0.1/24", Vec::new()).await;
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+
+        set_addr(&mut mgr, "Ethernet0", "10.0.0.1/31", Vec::new()).await;
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip address del \"10.0.0.1/24\" broadcast \"10.0.0.255\" dev \"Ethernet0\"",
+                "/sbin/ip address add \"10.0.0.1\" peer \"10.0.0.0/31\" dev \"Ethernet0\"",
+            ]
+        );
+        assert_eq!(
+            mgr.app_db_ops[0],
+            ("Ethernet0:10.0.0.1/24".to_string(), None)
+        );
+        assert_eq!(mgr.app_db_ops[1].0, "Ethernet0:10.0.0.1/31");
+        assert_eq!(
+            mgr.intf_addrs("Ethernet0"),
+            vec![IntfAddr::parse("10.0.0.1/31").unwrap()]
+        );
+
+        // The late DEL of the old key has nothing left to do
+        mgr.captured_commands.clear();
+        del_addr(&mut mgr, "Ethernet0", "10.0.0.1/24").await;
+        assert!(mgr.captured_commands.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_multiple_addrs_per_intf() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        set_addr(&mut mgr, "Vlan100", "192.168.0.1/21", Vec::new()).await;
+        set_addr(&mut mgr, "Vlan100", "10.0.0.1/24", Vec::new()).await;
+        set_addr(&mut mgr, "Vlan100", "fc00::1/64", Vec::new()).await;
+        set_addr(&mut mgr, "Vlan100", "fe80::1/64", Vec::new()).await;
+
+        assert_eq!(mgr.captured_commands.len(), 4);
+        assert_eq!(mgr.intf_addrs("Vlan100").len(), 4);
+
+        let fvs = published(&mgr, "Vlan100:192.168.0.1/21").unwrap();
+        assert_eq!(fvs.get_field(app_intf_fields::FAMILY), Some("IPv4"));
+        assert_eq!(fvs.get_field(app_intf_fields::SCOPE), Some("global"));
+        let fvs = published(&mgr, "Vlan100:fe80::1/64").unwrap();
+        assert_eq!(fvs.get_field(app_intf_fields::FAMILY), Some("IPv6"));
+        assert_eq!(fvs.get_field(app_intf_fields::SCOPE), Some("local"));
+
+        // Removing one address leaves the others alone
+        mgr.captured_commands.clear();
+        del_addr(&mut mgr, "Vlan100", "10.0.0.1/24").await;
+        assert_eq!(
+            mgr.captured_commands,
+            vec!["/sbin/ip address del \"10.0.0.1/24\" broadcast \"10.0.0.255\" dev \"Vlan100\""]
+        );
+        assert_eq!(mgr.intf_addrs("Vlan100").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_point_to_point_addr_add_remove() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        set_addr(&mut mgr, "Ethernet0", "10.0.0.0/31", Vec::new()).await;
+        set_addr(&mut mgr, "Ethernet0", "fc00::/127", Vec::new()).await;
+        del_addr(&mut mgr, "Ethernet0", "10.0.0.0/31").await;
+        del_addr(&mut mgr, "Ethernet0", "fc00::/127").await;
+
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip address add \"10.0.0.0\" peer \"10.0.0.1/31\" dev \"Ethernet0\"",
+                "/sbin/ip -6 address add \"fc00::\" peer \"fc00::1/127\" dev \"Ethernet0\"",
+                "/sbin/ip address del \"10.0.0.0\" peer \"10.0.0.1/31\" dev \"Ethernet0\"",
+                "/sbin/ip -6 address del \"fc00::\" peer \"fc00::1/127\" dev \"Ethernet0\"",
+            ]
+        );
+        assert_eq!(
+            published(&mgr, "Ethernet0:10.0.0.0/31"),
+            None,
+            "removed address still published"
+        );
+        assert!(mgr.intf_addrs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_secondary_waits_for_primary() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        set_addr(&mut mgr, "Vlan100", "10.0.0.2/24", secondary()).await;
+        assert!(mgr.captured_commands.is_empty());
+        assert!(published(&mgr, "Vlan100:10.0.0.2/24").is_none());
+
+        set_addr(&mut mgr, "Vlan100", "10.0.0.1/24", Vec::new()).await;
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip address add \"10.0.0.1/24\" broadcast \"10.0.0.255\" dev \"Vlan100\"",
+                "/sbin/ip address add \"10.0.0.2/24\" broadcast \"10.0.0.255\" dev \"Vlan100\"",
+            ]
+        );
+        assert!(published(&mgr, "Vlan100:10.0.0.2/24").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_vlan_addr_waits_for_vlan_state() {
+        use sonic_cfgmgr_common::{field_values, InMemoryStore};
+        use sonic_cfgmgr_test::CommandHarness;
+
+        let store = Arc::new(InMemoryStore::new());
+        let harness = Arc::new(CommandHarness::new());
+        let mut mgr = IntfMgr::new(SwitchType::Normal)
+            .with_sysctl(Arc::new(InMemorySysctl::new()))
+            .with_executor(harness.clone())
+            .with_backend(harness.clone())
+            .with_store(store.clone());
+
+        let applied = mgr
+            .do_intf_addr_task("Vlan100", "192.168.0.1/24", "SET", &Vec::new())
+            .await
+            .unwrap();
+        assert!(!applied);
+        assert_eq!(mgr.pending_addr_count(), 1);
+        assert_eq!(mgr.dump_pending_tasks(), vec!["Vlan100:192.168.0.1/24"]);
+        assert!(store.journal().is_empty());
+        assert!(harness.commands().is_empty());
+
+        mgr.handle_vlan_state("Vlan100", Some(&field_values! { STATE_FIELD => STATE_OK }))
+            .await;
+        assert_eq!(mgr.pending_addr_count(), 0);
+        let fvs = published(&mgr, "Vlan100:192.168.0.1/24").unwrap();
+        assert_eq!(fvs.get_field(app_intf_fields::FAMILY), Some(FAMILY_IPV4));
+        assert_eq!(
+            store.journal(),
+            vec!["SET APPL_DB:INTF_TABLE:Vlan100:192.168.0.1/24"]
+        );
+        harness.assert_ran_matching("192.168.0.1/24");
+
+        // A DEL while still deferred drops the address
+        mgr.do_intf_addr_task("Vlan200", "10.0.0.1/24", "SET", &Vec::new())
+            .await
+            .unwrap();
+        mgr.do_intf_addr_task("Vlan200", "10.0.0.1/24", "DEL", &Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(mgr.pending_addr_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_primary_removed_while_secondaries_exist() {
+        let mut mgr = IntfMgr::new_mock(SwitchType::Normal);
+        set_addr(&mut mgr, "Vlan100", "10.0.0.1/24", Vec::new()).await;
+        set_addr(&mut mgr, "Vlan100", "10.0.0.2/24", secondary()).await;
+        set_addr(&mut mgr, "Vlan100", "10.0.0.3/24", secondary()).await;
+        mgr.captured_commands.clear();
+
+        del_addr(&mut mgr, "Vlan100", "10.0.0.1/24").await;
+        assert_eq!(
+            mgr.captured_commands,
+            vec![
+                "/sbin/ip address del \"10.0.0.2/24\" broadcast \"10.0.0.255\" dev \"Vlan100\"",
+                "/sbin/ip address del \"10.0.0.3/24\" broadcast \"10.0.0.255\" dev \"Vlan100\"",
+                "/sbin/ip address del \"10.0.0.1/24\" broadcast \"10.0.0.255\" dev \"Vlan100\"",
+            ]
+        );
+        for key in [
+            "Vlan100:10.0.0.1/24",
+            "Vlan100:10.0.0.2/24",
+            "Vlan100:10.0.0.3/24",
+        ] {
+            assert!(published(&mgr, key).is_none());
+        }
+        // The secondaries stay configured for the next primary
+        assert_eq!(mgr.intf_addrs("Vlan100").len(), 2);
+
+        mgr.captured_commands.clear();
+        set_addr(&mut mgr, "Vlan100", "10.0.0.4/24", Vec::new()).await;
+        assert_eq!(mgr.captured_commands.len(), 3);
+        assert!(mgr.captured_commands[0].contains("10.0.0.4/24"));
+    }
 }
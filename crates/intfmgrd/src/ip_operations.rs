@@ -1,52 +1,63 @@
 //! IP address operations
 
 use crate::tables::{IP_CMD, SYSCTL_CMD};
-use crate::types::SwitchType;
+use crate::types::{IntfAddr, SwitchType};
 use sonic_cfgmgr_common::{shell, CfgMgrResult};
-use sonic_types::IpPrefix;
 use tracing::info;
 
+/// Command adding (`op` = "add") or removing ("del") an interface address
+///
+/// IPv4 subnets get an explicit broadcast address, except /31 and /32
+/// which have none. /31 and /127 point-to-point subnets are configured in
+/// peer notation (`<local> peer <remote>/<len>`). On VOQ switches IPv6
+/// addresses use metric 256 so the kernel subnet route matches the one
+/// programmed from the chassis database.
+pub fn intf_addr_cmd(alias: &str, op: &str, addr: &IntfAddr, switch_type: &SwitchType) -> String {
+    let family = if addr.is_ipv4() { "" } else { " -6" };
+    let local = match addr.peer() {
+        Some(peer) => format!(
+            "{} peer {}",
+            shell::shellquote(&addr.addr.to_string()),
+            shell::shellquote(&format!("{}/{}", peer, addr.prefix_len))
+        ),
+        None => shell::shellquote(&addr.to_string()),
+    };
+    let broadcast = addr
+        .broadcast()
+        .map(|bcast| format!(" broadcast {}", shell::shellquote(&bcast.to_string())))
+        .unwrap_or_default();
+    let metric = if !addr.is_ipv4() && switch_type.is_voq() {
+        " metric 256"
+    } else {
+        ""
+    };
+
+    format!(
+        "{}{} address {} {}{} dev {}{}",
+        IP_CMD,
+        family,
+        op,
+        local,
+        broadcast,
+        shell::shellquote(alias),
+        metric
+    )
+}
+
 /// Set interface IP address
 pub async fn set_intf_ip(
     alias: &str,
     op: &str,
-    ip_prefix: &IpPrefix,
+    addr: &IntfAddr,
     switch_type: &SwitchType,
 ) -> CfgMgrResult<()> {
-    let ip_prefix_str = ip_prefix.to_string();
-    let _prefix_len = ip_prefix.prefix_len();
-
-    let cmd = if ip_prefix.is_ipv4() {
-        // IPv4 address - simplified without broadcast calculation
-        format!(
-            "{} address {} {} dev {}",
-            IP_CMD,
-            op,
-            shell::shellquote(&ip_prefix_str),
-            shell::shellquote(alias)
-        )
-    } else {
-        // IPv6 address
-        let metric = if switch_type.is_voq() {
-            " metric 256"
-        } else {
-            ""
-        };
-        format!(
-            "{} -6 address {} {} dev {}{}",
-            IP_CMD,
-            op,
-            shell::shellquote(&ip_prefix_str),
-            shell::shellquote(alias),
-            metric
-        )
-    };
+    let cmd = intf_addr_cmd(alias, op, addr, switch_type);
 
     // Execute command
     let result = shell::exec(&cmd).await;
 
     // IPv6 retry logic
-    if result.is_err() && !ip_prefix.is_ipv4() && op == "add" {
+    if result.is_err() && !addr.is_ipv4() && op == "add" {
         info!("Failed to assign IPv6, enabling IPv6 and retrying");
         enable_ipv6_flag(alias).await?;
         shell::exec(&cmd).await?;
@@ -78,3 +89,53 @@ pub async fn set_intf_mac(alias: &str, mac_str: &str) -> CfgMgrResult<()> {
     info!("Set MAC address {} on interface {}", mac_str, alias);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd(op: &str, addr: &str, switch_type: SwitchType) -> String {
+        intf_addr_cmd(
+            "Ethernet0",
+            op,
+            &IntfAddr::parse(addr).unwrap(),
+            &switch_type,
+        )
+    }
+
+    #[test]
+    fn test_ipv4_addr_cmd_with_broadcast() {
+        assert_eq!(
+            cmd("add", "10.0.0.1/24", SwitchType::Normal),
+            "/sbin/ip address add \"10.0.0.1/24\" broadcast \"10.0.0.255\" dev \"Ethernet0\""
+        );
+        assert_eq!(
+            cmd("del", "10.0.0.1/32", SwitchType::Normal),
+            "/sbin/ip address del \"10.0.0.1/32\" dev \"Ethernet0\""
+        );
+    }
+
+    #[test]
+    fn test_point_to_point_addr_cmd() {
+        assert_eq!(
+            cmd("add", "10.0.0.0/31", SwitchType::Normal),
+            "/sbin/ip address add \"10.0.0.0\" peer \"10.0.0.1/31\" dev \"Ethernet0\""
+        );
+        assert_eq!(
+            cmd("del", "fc00::1/127", SwitchType::Normal),
+            "/sbin/ip -6 address del \"fc00::1\" peer \"fc00::/127\" dev \"Ethernet0\""
+        );
+    }
+
+    #[test]
+    fn test_ipv6_addr_cmd_voq_metric() {
+        assert_eq!(
+            cmd("add", "fc00::1/64", SwitchType::Voq),
+            "/sbin/ip -6 address add \"fc00::1/64\" dev \"Ethernet0\" metric 256"
+        );
+        assert_eq!(
+            cmd("add", "fc00::1/64", SwitchType::Normal),
+            "/sbin/ip -6 address add \"fc00::1/64\" dev \"Ethernet0\""
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Interface Manager Daemon Entry Point
 
+use sonic_cfgmgr_common::BackendKind;
 use sonic_intfmgrd::{IntfMgr, SwitchType};
-use tracing::info;
+use tracing::{error, info};
 
 #[tokio::main]
 async fn main() {
@@ -21,8 +22,21 @@ async fn main() {
 
     info!("Detected switch type: {:?}", switch_type);
 
+    let backend_kind = BackendKind::from_env();
+    let backend = match backend_kind.create() {
+        Ok(backend) => backend,
+        Err(e) => {
+            error!("Failed to create {} backend: {}", backend_kind, e);
+            std::process::exit(1);
+        }
+    };
+    info!(
+        "Applying VRF and sub-interface changes through the {} backend",
+        backend.name()
+    );
+
     // Create manager instance
-    let mut _mgr = IntfMgr::new(switch_type);
+    let mut _mgr = IntfMgr::new(switch_type).with_backend(backend);
 
     // TODO: Set up database connections
     // TODO: Register consumers for CONFIG_DB tables:
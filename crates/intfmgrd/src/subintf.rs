@@ -1,6 +1,6 @@
 //! Sub-interface parsing utilities
 
-use crate::tables::{LAG_PREFIX, SUBINTF_LAG_PREFIX};
+use crate::tables::{LAG_PREFIX, PORT_PREFIX, SUBINTF_LAG_PREFIX, SUBINTF_PORT_PREFIX};
 
 /// Parse sub-interface name into (parent, vlan_id)
 ///
@@ -8,6 +8,7 @@ use crate::tables::{LAG_PREFIX, SUBINTF_LAG_PREFIX};
 /// - "Ethernet0.100" → ("Ethernet0", "100")
 /// - "PortChannel1.200" → ("PortChannel1", "200")
 /// - "Po1.200" → ("PortChannel1", "200")
+/// - "Eth4.100" → ("Ethernet4", "100")
 ///
 /// Returns None if the name is not a valid sub-interface
 pub fn parse_subintf_name(name: &str) -> Option<(String, String)> {
@@ -22,19 +23,26 @@ pub fn parse_subintf_name(name: &str) -> Option<(String, String)> {
         return None;
     }
 
-    // Convert short LAG names (Po1 → PortChannel1)
-    // Only convert if parent starts with "Po" but NOT "PortChannel"
-    let parent = if parent.starts_with(SUBINTF_LAG_PREFIX) && !parent.starts_with(LAG_PREFIX) {
-        // Extract the number after "Po"
-        let lag_num = &parent[SUBINTF_LAG_PREFIX.len()..];
-        format!("{}{}", LAG_PREFIX, lag_num)
-    } else {
-        parent.to_string()
-    };
+    // Convert short names (Po1 → PortChannel1, Eth4 → Ethernet4)
+    let parent = expand_short_name(parent, SUBINTF_LAG_PREFIX, LAG_PREFIX)
+        .or_else(|| expand_short_name(parent, SUBINTF_PORT_PREFIX, PORT_PREFIX))
+        .unwrap_or_else(|| parent.to_string());
 
     Some((parent, vlan_id.to_string()))
 }
 
+/// Expand a short parent name ("Po1") to its full form ("PortChannel1")
+///
+/// Returns None if `parent` is not in the `short` format, including when it
+/// already uses the full prefix.
+fn expand_short_name(parent: &str, short: &str, full: &str) -> Option<String> {
+    if parent.starts_with(full) {
+        return None;
+    }
+    let num = parent.strip_prefix(short)?;
+    Some(format!("{}{}", full, num))
+}
+
 /// Check if a name is a sub-interface
 pub fn is_subintf_name(name: &str) -> bool {
     parse_subintf_name(name).is_some()
@@ -79,6 +87,16 @@ mod tests {
         assert_eq!(vlan_id, "100");
     }
 
+    #[test]
+    fn test_parse_subintf_name_short_port() {
+        let (parent, vlan_id) = parse_subintf_name("Eth4.100").unwrap();
+        assert_eq!(parent, "Ethernet4");
+        assert_eq!(vlan_id, "100");
+
+        let (parent, _) = parse_subintf_name("Ethernet4.100").unwrap();
+        assert_eq!(parent, "Ethernet4");
+    }
+
     #[test]
     fn test_parse_subintf_name_no_dot() {
         assert!(parse_subintf_name("Ethernet0").is_none());
@@ -96,6 +114,7 @@ mod tests {
         assert!(is_subintf_name("Ethernet0.100"));
         assert!(is_subintf_name("PortChannel1.200"));
         assert!(is_subintf_name("Po1.300"));
+        assert!(is_subintf_name("Eth4.100"));
 
         assert!(!is_subintf_name("Ethernet0"));
         assert!(!is_subintf_name("Vlan100"));
@@ -1,9 +1,58 @@
 //! Sub-interface operations
 
-use crate::tables::IP_CMD;
+use crate::tables::{DEFAULT_MTU, IP_CMD};
 use sonic_cfgmgr_common::{shell, CfgMgrResult};
 use tracing::{info, warn};
 
+/// Command creating a dot1q sub-interface on top of `parent`
+pub fn add_subintf_cmd(parent: &str, subintf: &str, vlan_id: &str) -> String {
+    format!(
+        "{} link add link {} name {} type vlan id {}",
+        IP_CMD,
+        shell::shellquote(parent),
+        shell::shellquote(subintf),
+        shell::shellquote(vlan_id)
+    )
+}
+
+/// Command deleting a sub-interface
+pub fn del_subintf_cmd(subintf: &str) -> String {
+    format!("{} link del {}", IP_CMD, shell::shellquote(subintf))
+}
+
+/// Command setting the MTU of a sub-interface
+pub fn subintf_mtu_cmd(subintf: &str, mtu: u32) -> String {
+    format!(
+        "{} link set {} mtu {}",
+        IP_CMD,
+        shell::shellquote(subintf),
+        mtu
+    )
+}
+
+/// Command setting the admin status of a sub-interface
+///
+/// Anything other than "up" brings the sub-interface down.
+pub fn subintf_admin_cmd(subintf: &str, admin_status: &str) -> String {
+    let state = if admin_status == "up" { "up" } else { "down" };
+    format!(
+        "{} link set {} {}",
+        IP_CMD,
+        shell::shellquote(subintf),
+        state
+    )
+}
+
+/// Effective sub-interface MTU
+///
+/// A sub-interface cannot carry frames larger than its parent, so the
+/// requested MTU is clamped to the parent's. No request (or
+/// [`MTU_INHERITANCE`](crate::tables::MTU_INHERITANCE)) inherits the
+/// parent MTU.
+pub fn clamp_subintf_mtu(requested: Option<u32>, parent_mtu: u32) -> u32 {
+    requested.map_or(parent_mtu, |mtu| mtu.min(parent_mtu))
+}
+
 /// Create sub-interface
 ///
 /// Creates a VLAN sub-interface using `ip link add`
@@ -13,24 +62,14 @@ use tracing::{info, warn};
 /// * `subintf` - Sub-interface name (e.g., "Ethernet0.100")
 /// * `vlan_id` - VLAN ID (e.g., "100")
 pub async fn add_host_subintf(parent: &str, subintf: &str, vlan_id: &str) -> CfgMgrResult<()> {
-    let cmd = format!(
-        "{} link add link {} name {} type vlan id {}",
-        IP_CMD,
-        shell::shellquote(parent),
-        shell::shellquote(subintf),
-        vlan_id
-    );
-
-    shell::exec(&cmd).await?;
+    shell::exec(&add_subintf_cmd(parent, subintf, vlan_id)).await?;
     info!("Created sub-interface {} with VLAN ID {}", subintf, vlan_id);
     Ok(())
 }
 
 /// Delete sub-interface
 pub async fn remove_host_subintf(subintf: &str) -> CfgMgrResult<()> {
-    let cmd = format!("{} link del {}", IP_CMD, shell::shellquote(subintf));
-
-    shell::exec(&cmd).await?;
+    shell::exec(&del_subintf_cmd(subintf)).await?;
     info!("Deleted sub-interface {}", subintf);
     Ok(())
 }
@@ -43,45 +82,29 @@ pub async fn remove_host_subintf(subintf: &str) -> CfgMgrResult<()> {
 /// The effective MTU that was set
 pub async fn set_subintf_mtu(subintf: &str, mtu: &str, parent_mtu: &str) -> CfgMgrResult<String> {
     // Parse MTU values
-    let subintf_mtu: u32 = mtu.parse().unwrap_or(9100);
-    let parent_mtu_val: u32 = parent_mtu.parse().unwrap_or(9100);
+    let subintf_mtu: u32 = mtu.parse().unwrap_or(DEFAULT_MTU);
+    let parent_mtu_val: u32 = parent_mtu.parse().unwrap_or(DEFAULT_MTU);
 
     // Validate: sub-interface MTU cannot exceed parent MTU
-    let effective_mtu = if subintf_mtu > parent_mtu_val {
+    let effective_mtu = clamp_subintf_mtu(Some(subintf_mtu), parent_mtu_val);
+    if effective_mtu < subintf_mtu {
         warn!(
             "Sub-interface {} MTU {} exceeds parent MTU {}, using parent MTU",
             subintf, subintf_mtu, parent_mtu_val
         );
-        parent_mtu.to_string()
-    } else {
-        mtu.to_string()
-    };
-
-    let cmd = format!(
-        "{} link set {} mtu {}",
-        IP_CMD,
-        shell::shellquote(subintf),
-        &effective_mtu
-    );
+    }
 
-    shell::exec(&cmd).await?;
+    shell::exec(&subintf_mtu_cmd(subintf, effective_mtu)).await?;
     info!("Set MTU {} on sub-interface {}", effective_mtu, subintf);
 
-    Ok(effective_mtu)
+    Ok(effective_mtu.to_string())
 }
 
 /// Set sub-interface admin status
 pub async fn set_subintf_admin_status(subintf: &str, admin_status: &str) -> CfgMgrResult<String> {
     let state = if admin_status == "up" { "up" } else { "down" };
 
-    let cmd = format!(
-        "{} link set {} {}",
-        IP_CMD,
-        shell::shellquote(subintf),
-        state
-    );
-
-    shell::exec(&cmd).await?;
+    shell::exec(&subintf_admin_cmd(subintf, admin_status)).await?;
     info!("Set admin status {} on sub-interface {}", state, subintf);
 
     Ok(state.to_string())
@@ -89,6 +112,7 @@ pub async fn set_subintf_admin_status(subintf: &str, admin_status: &str) -> CfgM
 
 #[cfg(test)]
 mod tests {
+    use super::*;
 
     #[test]
     fn test_mtu_validation_within_limit() {
@@ -118,4 +142,27 @@ mod tests {
         let state = if "down" == "up" { "up" } else { "down" };
         assert_eq!(state, "down");
     }
+
+    #[test]
+    fn test_clamp_subintf_mtu() {
+        assert_eq!(clamp_subintf_mtu(Some(1500), 9100), 1500);
+        assert_eq!(clamp_subintf_mtu(Some(9216), 9100), 9100);
+        assert_eq!(clamp_subintf_mtu(None, 1500), 1500);
+    }
+
+    #[test]
+    fn test_subintf_cmds() {
+        assert_eq!(
+            add_subintf_cmd("Ethernet4", "Eth4.100", "100"),
+            "/sbin/ip link add link \"Ethernet4\" name \"Eth4.100\" type vlan id \"100\""
+        );
+        assert_eq!(
+            subintf_admin_cmd("Eth4.100", "down"),
+            "/sbin/ip link set \"Eth4.100\" down"
+        );
+        assert_eq!(
+            subintf_mtu_cmd("Eth4.100", 1500),
+            "/sbin/ip link set \"Eth4.100\" mtu 1500"
+        );
+    }
 }
@@ -5,6 +5,7 @@ pub const CFG_INTF_TABLE: &str = "INTERFACE";
 pub const CFG_VLAN_INTF_TABLE: &str = "VLAN_INTERFACE";
 pub const CFG_LAG_INTF_TABLE: &str = "LAG_INTERFACE";
 pub const CFG_LOOPBACK_INTF_TABLE: &str = "LOOPBACK_INTERFACE";
+pub const CFG_VLAN_SUB_INTF_TABLE: &str = "VLAN_SUB_INTERFACE";
 pub const CFG_PORTCHANNEL_INTF_TABLE: &str = "PORTCHANNEL_INTERFACE";
 pub const CFG_DEVICE_METADATA_TABLE: &str = "DEVICE_METADATA";
 pub const CFG_PORT_TABLE: &str = "PORT";
@@ -46,12 +47,35 @@ pub mod port_fields {
     pub const MTU: &str = "mtu";
 }
 
+// INTERFACE|<alias>|<ip_prefix> field names
+pub mod addr_fields {
+    pub const SECONDARY: &str = "secondary";
+}
+
 // INTF_TABLE (APPL_DB) field names
 pub mod app_intf_fields {
     pub const SCOPE: &str = "scope";
     pub const FAMILY: &str = "family";
 }
 
+// INTF_TABLE (APPL_DB) address scope and family values
+pub const SCOPE_GLOBAL: &str = "global";
+pub const SCOPE_LOCAL: &str = "local";
+pub const FAMILY_IPV4: &str = "IPv4";
+pub const FAMILY_IPV6: &str = "IPv6";
+
+// proxy_arp / grat_arp values
+pub const ARP_ENABLED: &str = "enabled";
+pub const ARP_DISABLED: &str = "disabled";
+
+// Per-interface IPv4 sysctls (net/ipv4/conf/<intf>/...)
+pub const PROXY_ARP_SYSCTL: &str = "proxy_arp";
+pub const PROXY_ARP_PVLAN_SYSCTL: &str = "proxy_arp_pvlan";
+pub const ARP_ACCEPT_SYSCTL: &str = "arp_accept";
+
+// Per-interface IPv6 sysctls (net/ipv6/conf/<intf>/...)
+pub const DISABLE_IPV6_SYSCTL: &str = "disable_ipv6";
+
 // STATE field name
 pub const STATE_FIELD: &str = "state";
 pub const STATE_OK: &str = "ok";
@@ -61,6 +85,8 @@ pub const IP_CMD: &str = "/sbin/ip";
 pub const SYSCTL_CMD: &str = "sysctl";
 
 // Interface prefixes
+pub const PORT_PREFIX: &str = "Ethernet";
+pub const SUBINTF_PORT_PREFIX: &str = "Eth";
 pub const VLAN_PREFIX: &str = "Vlan";
 pub const LAG_PREFIX: &str = "PortChannel";
 pub const SUBINTF_LAG_PREFIX: &str = "Po";
@@ -73,3 +99,7 @@ pub const VRF_MGMT: &str = "mgmt";
 pub const MTU_INHERITANCE: &str = "0";
 pub const LOOPBACK_DEFAULT_MTU: u32 = 65536;
 pub const DEFAULT_MTU: u32 = 9100;
+pub const SUBINTF_DEFAULT_ADMIN_STATUS: &str = "up";
+
+// CONFIG_DB key separator between interface and IP prefix
+pub const CONFIG_KEY_SEPARATOR: char = '|';
@@ -1,6 +1,9 @@
 //! Interface Manager Type Definitions
 
-use std::collections::{HashMap, HashSet};
+use sonic_cfgmgr_common::FieldValues;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 /// Sub-interface information
 #[derive(Debug, Clone, PartialEq)]
@@ -75,6 +78,10 @@ impl IntfType {
             // Short LAG sub-interface (Po1.100)
             let (parent, vlan_id) = crate::subintf::parse_subintf_name(name)?;
             Some(IntfType::SubInterface { parent, vlan_id })
+        } else if name.starts_with("Eth") && name.contains('.') {
+            // Short port sub-interface (Eth4.100)
+            let (parent, vlan_id) = crate::subintf::parse_subintf_name(name)?;
+            Some(IntfType::SubInterface { parent, vlan_id })
         } else if name.starts_with("Loopback") {
             Some(IntfType::Loopback(name.to_string()))
         } else {
@@ -128,6 +135,173 @@ impl SwitchType {
     }
 }
 
+/// Parent port/LAG → MTU, for parents that are present in STATE_DB
+pub type ParentMtuMap = HashMap<String, u32>;
+
+/// Parent port/LAG → sub-interfaces waiting for it, with their CONFIG_DB fields
+pub type PendingSubIntfMap = HashMap<String, BTreeMap<String, FieldValues>>;
+
+/// Interface → IP prefixes waiting for it to be ready, with their CONFIG_DB fields
+pub type PendingAddrMap = HashMap<String, BTreeMap<String, FieldValues>>;
+
+/// Proxy and gratuitous ARP settings of an interface
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArpConfig {
+    /// proxy_arp (and proxy_arp_pvlan on VLANs) enabled
+    pub proxy_arp: bool,
+
+    /// Gratuitous ARP accepted (arp_accept)
+    pub grat_arp: bool,
+}
+
+impl ArpConfig {
+    /// True if both settings are at their kernel defaults
+    pub fn is_default(&self) -> bool {
+        !self.proxy_arp && !self.grat_arp
+    }
+}
+
+/// Interface → non-default ARP settings
+pub type ArpConfigMap = HashMap<String, ArpConfig>;
+
+/// Interface address: host address plus prefix length (e.g. 10.0.0.1/24)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IntfAddr {
+    /// Host address
+    pub addr: IpAddr,
+
+    /// Prefix length
+    pub prefix_len: u8,
+}
+
+impl IntfAddr {
+    /// Parse "<address>/<prefix length>"
+    pub fn parse(s: &str) -> Option<Self> {
+        let (addr, len) = s.split_once('/')?;
+        let addr: IpAddr = addr.parse().ok()?;
+        let prefix_len: u8 = len.parse().ok()?;
+        let intf_addr = Self { addr, prefix_len };
+        (prefix_len <= intf_addr.max_len()).then_some(intf_addr)
+    }
+
+    /// Check if this is an IPv4 address
+    pub fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    fn max_len(&self) -> u8 {
+        if self.is_ipv4() {
+            32
+        } else {
+            128
+        }
+    }
+
+    /// Check for a /31 (RFC 3021) or /127 (RFC 6164) point-to-point subnet
+    pub fn is_point_to_point(&self) -> bool {
+        self.prefix_len + 1 == self.max_len()
+    }
+
+    /// The other address of a point-to-point subnet
+    pub fn peer(&self) -> Option<IpAddr> {
+        if !self.is_point_to_point() {
+            return None;
+        }
+        Some(match self.addr {
+            IpAddr::V4(addr) => IpAddr::V4(Ipv4Addr::from(u32::from(addr) ^ 1)),
+            IpAddr::V6(addr) => IpAddr::V6(Ipv6Addr::from(u128::from(addr) ^ 1)),
+        })
+    }
+
+    /// Subnet broadcast address; None for IPv6, /31 and /32
+    pub fn broadcast(&self) -> Option<Ipv4Addr> {
+        match self.addr {
+            IpAddr::V4(addr) if self.prefix_len < 31 => {
+                let host_mask = u32::MAX >> self.prefix_len;
+                Some(Ipv4Addr::from(u32::from(addr) | host_mask))
+            }
+            _ => None,
+        }
+    }
+
+    /// Network address (host bits cleared)
+    pub fn network(&self) -> IpAddr {
+        let host_bits = u32::from(self.max_len() - self.prefix_len);
+        match self.addr {
+            IpAddr::V4(addr) => {
+                let mask = u32::MAX.checked_shl(host_bits).unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(addr) & mask))
+            }
+            IpAddr::V6(addr) => {
+                let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(addr) & mask))
+            }
+        }
+    }
+
+    /// Check if both addresses are in the same subnet
+    pub fn same_subnet(&self, other: &Self) -> bool {
+        self.prefix_len == other.prefix_len && self.network() == other.network()
+    }
+
+    /// Check for an IPv6 link-local address (fe80::/10)
+    pub fn is_link_local(&self) -> bool {
+        match self.addr {
+            IpAddr::V4(_) => false,
+            IpAddr::V6(addr) => addr.segments()[0] & 0xffc0 == 0xfe80,
+        }
+    }
+}
+
+impl fmt::Display for IntfAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// Addresses of one interface
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IntfAddrState {
+    /// Configured addresses → secondary flag
+    pub configured: BTreeMap<IntfAddr, bool>,
+
+    /// Addresses currently in the kernel
+    pub applied: BTreeSet<IntfAddr>,
+}
+
+impl IntfAddrState {
+    /// Check if an address is configured as a secondary
+    pub fn is_secondary(&self, addr: &IntfAddr) -> bool {
+        self.configured.get(addr).copied().unwrap_or(false)
+    }
+
+    /// Addresses that belong in the kernel
+    ///
+    /// The kernel makes the first address of a subnet its primary and drops
+    /// the secondaries along with it, so a secondary is only wanted while
+    /// a primary of its subnet is configured.
+    pub fn desired(&self) -> BTreeSet<IntfAddr> {
+        let has_primary = |addr: &IntfAddr| {
+            self.configured
+                .iter()
+                .any(|(primary, secondary)| !secondary && primary.same_subnet(addr))
+        };
+        self.configured
+            .iter()
+            .filter(|(addr, secondary)| !**secondary || has_primary(*addr))
+            .map(|(addr, _)| *addr)
+            .collect()
+    }
+
+    /// Check if nothing is configured or applied
+    pub fn is_empty(&self) -> bool {
+        self.configured.is_empty() && self.applied.is_empty()
+    }
+}
+
+/// Interface → addresses
+pub type IntfAddrMap = HashMap<String, IntfAddrState>;
+
 /// Interface state tracking
 pub type IntfStateMap = HashMap<String, String>;
 
@@ -152,6 +326,89 @@ mod tests {
         assert!(info.admin_status.is_empty());
     }
 
+    #[test]
+    fn test_arp_config_default() {
+        assert!(ArpConfig::default().is_default());
+        let arp = ArpConfig {
+            grat_arp: true,
+            ..Default::default()
+        };
+        assert!(!arp.is_default());
+    }
+
+    fn addr(s: &str) -> IntfAddr {
+        IntfAddr::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_intf_addr_parse() {
+        let a = addr("10.0.0.1/24");
+        assert!(a.is_ipv4());
+        assert_eq!(a.prefix_len, 24);
+        assert_eq!(a.to_string(), "10.0.0.1/24");
+        assert_eq!(addr("2001:db8:0::1/64").to_string(), "2001:db8::1/64");
+
+        assert!(IntfAddr::parse("10.0.0.1").is_none());
+        assert!(IntfAddr::parse("10.0.0.1/33").is_none());
+        assert!(IntfAddr::parse("2001:db8::1/129").is_none());
+        assert!(IntfAddr::parse("bogus/24").is_none());
+    }
+
+    #[test]
+    fn test_intf_addr_point_to_point() {
+        assert_eq!(
+            addr("10.0.0.0/31").peer(),
+            Some("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            addr("10.0.0.1/31").peer(),
+            Some("10.0.0.0".parse().unwrap())
+        );
+        assert_eq!(
+            addr("2001:db8::/127").peer(),
+            Some("2001:db8::1".parse().unwrap())
+        );
+        assert_eq!(addr("10.0.0.1/30").peer(), None);
+        assert_eq!(addr("10.0.0.1/32").peer(), None);
+    }
+
+    #[test]
+    fn test_intf_addr_broadcast_and_network() {
+        let a = addr("10.1.2.3/24");
+        assert_eq!(a.broadcast(), Some(Ipv4Addr::new(10, 1, 2, 255)));
+        assert_eq!(a.network(), "10.1.2.0".parse::<IpAddr>().unwrap());
+        assert_eq!(addr("10.0.0.0/31").broadcast(), None);
+        assert_eq!(addr("10.0.0.1/32").broadcast(), None);
+        assert_eq!(
+            addr("0.0.0.0/0").network(),
+            "0.0.0.0".parse::<IpAddr>().unwrap()
+        );
+
+        assert!(addr("10.1.2.3/24").same_subnet(&addr("10.1.2.200/24")));
+        assert!(!addr("10.1.2.3/24").same_subnet(&addr("10.1.3.3/24")));
+        assert!(!addr("10.1.2.3/24").same_subnet(&addr("10.1.2.3/25")));
+        assert!(addr("fe80::1/64").is_link_local());
+        assert!(!addr("2001:db8::1/64").is_link_local());
+    }
+
+    #[test]
+    fn test_intf_addr_state_secondary_needs_primary() {
+        let mut state = IntfAddrState::default();
+        state.configured.insert(addr("10.0.0.2/24"), true);
+        assert!(state.desired().is_empty());
+
+        state.configured.insert(addr("10.0.0.1/24"), false);
+        assert_eq!(state.desired().len(), 2);
+
+        // A primary of another subnet does not help
+        state.configured.remove(&addr("10.0.0.1/24"));
+        state.configured.insert(addr("10.0.1.1/24"), false);
+        assert_eq!(
+            state.desired().into_iter().collect::<Vec<_>>(),
+            vec![addr("10.0.1.1/24")]
+        );
+    }
+
     #[test]
     fn test_intf_type_from_name_physical() {
         let intf_type = IntfType::from_name("Ethernet0").unwrap();
@@ -188,6 +445,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_intf_type_from_name_short_subintf() {
+        match IntfType::from_name("Eth4.100").unwrap() {
+            IntfType::SubInterface { parent, vlan_id } => {
+                assert_eq!(parent, "Ethernet4");
+                assert_eq!(vlan_id, "100");
+            }
+            _ => panic!("Expected SubInterface"),
+        }
+        assert!(IntfType::from_name("Eth4").is_none());
+    }
+
     #[test]
     fn test_intf_type_is_sub_interface() {
         let subintf = IntfType::SubInterface {
@@ -1,29 +1,25 @@
 //! VRF and related operations
 
-use crate::tables::{IP_CMD, SYSCTL_CMD};
-use sonic_cfgmgr_common::{shell, CfgMgrResult};
+use crate::tables::{
+    ARP_ACCEPT_SYSCTL, ARP_DISABLED, ARP_ENABLED, PROXY_ARP_PVLAN_SYSCTL, PROXY_ARP_SYSCTL,
+    SYSCTL_CMD, VLAN_PREFIX,
+};
+use sonic_cfgmgr_common::sysctl::{ipv4_conf_path, Sysctl};
+use sonic_cfgmgr_common::{shell, CfgBackend, CfgMgrResult};
 use tracing::{error, info};
 
 /// Bind interface to VRF or unbind
 ///
 /// # Arguments
+/// * `backend` - Kernel configuration backend
 /// * `alias` - Interface name
 /// * `vrf_name` - VRF name (None to unbind)
-pub async fn set_intf_vrf(alias: &str, vrf_name: Option<&str>) -> CfgMgrResult<()> {
-    let cmd = if let Some(vrf) = vrf_name {
-        // Bind to VRF
-        format!(
-            "{} link set {} master {}",
-            IP_CMD,
-            shell::shellquote(alias),
-            shell::shellquote(vrf)
-        )
-    } else {
-        // Unbind from VRF
-        format!("{} link set {} nomaster", IP_CMD, shell::shellquote(alias))
-    };
-
-    shell::exec(&cmd).await?;
+pub async fn set_intf_vrf(
+    backend: &dyn CfgBackend,
+    alias: &str,
+    vrf_name: Option<&str>,
+) -> CfgMgrResult<()> {
+    backend.vrf_bind(alias, vrf_name).await?;
 
     if let Some(vrf) = vrf_name {
         info!("Bound interface {} to VRF {}", alias, vrf);
@@ -69,50 +65,65 @@ pub async fn set_intf_mpls(alias: &str, mpls: &str) -> CfgMgrResult<bool> {
     Ok(true)
 }
 
-/// Set proxy ARP on interface
-pub async fn set_intf_proxy_arp(alias: &str, proxy_arp: &str) -> CfgMgrResult<bool> {
-    let val = match proxy_arp {
-        "enabled" => "1",
-        "disabled" | "" => "0",
-        _ => {
-            error!("Proxy ARP state is invalid: \"{}\"", proxy_arp);
-            return Ok(false);
-        }
-    };
+/// Parse a proxy_arp/grat_arp field ("enabled"/"disabled")
+///
+/// An empty value means disabled; anything else is invalid.
+pub fn parse_arp_state(value: &str) -> Option<bool> {
+    match value {
+        ARP_ENABLED => Some(true),
+        ARP_DISABLED | "" => Some(false),
+        _ => None,
+    }
+}
 
-    let cmd = format!(
-        "{} -w net.ipv4.conf.{}.proxy_arp={}",
-        SYSCTL_CMD, alias, val
-    );
+/// Set proxy ARP on interface
+///
+/// VLAN interfaces also get `proxy_arp_pvlan`, which lets hosts behind the
+/// same VLAN (and so the same receiving interface) reach each other
+/// through the router.
+pub async fn set_intf_proxy_arp(
+    sysctl: &dyn Sysctl,
+    alias: &str,
+    enabled: bool,
+) -> CfgMgrResult<()> {
+    let val = if enabled { "1" } else { "0" };
+
+    sysctl
+        .write(&ipv4_conf_path(alias, PROXY_ARP_SYSCTL), val)
+        .await?;
+    if alias.starts_with(VLAN_PREFIX) {
+        sysctl
+            .write(&ipv4_conf_path(alias, PROXY_ARP_PVLAN_SYSCTL), val)
+            .await?;
+    }
 
-    shell::exec(&cmd).await?;
-    info!("Set proxy ARP {} on interface {}", proxy_arp, alias);
-    Ok(true)
+    info!("Set proxy ARP {} on interface {}", val, alias);
+    Ok(())
 }
 
 /// Set gratuitous ARP on interface
-pub async fn set_intf_grat_arp(alias: &str, grat_arp: &str) -> CfgMgrResult<bool> {
-    let val = match grat_arp {
-        "enabled" => "1",
-        "disabled" | "" => "0",
-        _ => {
-            error!("Gratuitous ARP state is invalid: \"{}\"", grat_arp);
-            return Ok(false);
-        }
-    };
-
-    let cmd = format!(
-        "{} -w net.ipv4.conf.{}.arp_notify={}",
-        SYSCTL_CMD, alias, val
-    );
-
-    shell::exec(&cmd).await?;
-    info!("Set gratuitous ARP {} on interface {}", grat_arp, alias);
-    Ok(true)
+///
+/// Gratuitous ARP is accepted by enabling `arp_accept`.
+pub async fn set_intf_grat_arp(
+    sysctl: &dyn Sysctl,
+    alias: &str,
+    enabled: bool,
+) -> CfgMgrResult<()> {
+    let val = if enabled { "1" } else { "0" };
+
+    sysctl
+        .write(&ipv4_conf_path(alias, ARP_ACCEPT_SYSCTL), val)
+        .await?;
+
+    info!("Set gratuitous ARP {} on interface {}", val, alias);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use sonic_cfgmgr_common::InMemorySysctl;
+    use sonic_cfgmgr_test::{CannedResponse, CommandHarness};
 
     // Note: These tests just verify command generation logic
     // Actual execution would require mocking or integration tests
@@ -153,4 +164,59 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn test_set_intf_vrf() {
+        let harness = CommandHarness::new();
+        harness.stub(
+            r"Vrf_missing",
+            CannedResponse::exit(1, "Cannot find device"),
+        );
+
+        set_intf_vrf(&harness, "Ethernet0", Some("Vrf_red"))
+            .await
+            .unwrap();
+        set_intf_vrf(&harness, "Ethernet0", None).await.unwrap();
+        assert!(set_intf_vrf(&harness, "Ethernet0", Some("Vrf_missing"))
+            .await
+            .is_err());
+
+        assert_eq!(
+            harness.commands(),
+            [
+                "/sbin/ip link set \"Ethernet0\" master \"Vrf_red\"",
+                "/sbin/ip link set \"Ethernet0\" nomaster",
+                "/sbin/ip link set \"Ethernet0\" master \"Vrf_missing\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_arp_state() {
+        assert_eq!(parse_arp_state("enabled"), Some(true));
+        assert_eq!(parse_arp_state("disabled"), Some(false));
+        assert_eq!(parse_arp_state(""), Some(false));
+        assert_eq!(parse_arp_state("on"), None);
+    }
+
+    #[tokio::test]
+    async fn test_proxy_arp_pvlan_only_on_vlan() {
+        let sysctl = InMemorySysctl::new();
+
+        set_intf_proxy_arp(&sysctl, "Vlan100", true).await.unwrap();
+        set_intf_proxy_arp(&sysctl, "Ethernet0", true)
+            .await
+            .unwrap();
+        set_intf_grat_arp(&sysctl, "Ethernet0", true).await.unwrap();
+
+        assert_eq!(
+            sysctl.journal(),
+            vec![
+                "net/ipv4/conf/Vlan100/proxy_arp=1",
+                "net/ipv4/conf/Vlan100/proxy_arp_pvlan=1",
+                "net/ipv4/conf/Ethernet0/proxy_arp=1",
+                "net/ipv4/conf/Ethernet0/arp_accept=1",
+            ]
+        );
+    }
 }
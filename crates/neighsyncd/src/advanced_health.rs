@@ -267,6 +267,11 @@ impl AdvancedHealthMonitor {
         false
     }
 
+    /// Get the thresholds this monitor evaluates against
+    pub fn thresholds(&self) -> &HealthThresholds {
+        &self.thresholds
+    }
+
     /// Get current health status without updating
     pub fn get_current_status(&self) -> HealthStatus {
         match self.current_status.load(Ordering::Relaxed) {
@@ -0,0 +1,206 @@
+//! Runtime IPv4/ARP neighbor sync configuration
+//!
+//! The `ipv4` cargo feature only selects the startup default. Deployments can
+//! toggle ARP sync at runtime through CONFIG_DB without rebuilding:
+//!
+//! ```text
+//! NEIGHSYNCD:global
+//!     ipv4 = "enable" | "disable"
+//! ```
+//!
+//! Turning IPv4 on requests an AF_INET neighbor dump so existing ARP entries
+//! are synced; turning it off flushes previously-synced IPv4 entries from
+//! APPL_DB.
+//!
+//! # NIST 800-53 Rev 5 Control Mappings
+//! - CM-3: Configuration Change Control - Apply changes without rebuild
+//! - CM-6: Configuration Settings - Runtime address family selection
+//! - SC-7: Boundary Protection - Only sync enabled address families
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// CONFIG_DB table holding neighsyncd runtime settings
+pub const CFG_NEIGHSYNCD_TABLE_NAME: &str = "NEIGHSYNCD";
+/// Key within the NEIGHSYNCD table for global settings
+pub const CFG_NEIGHSYNCD_GLOBAL_KEY: &str = "global";
+/// Field enabling IPv4 neighbor processing
+pub const CFG_IPV4_FIELD: &str = "ipv4";
+
+/// IPv4 state used when CONFIG_DB has no setting
+/// NIST: CM-6 - Build-time default preserved for existing deployments
+pub const DEFAULT_IPV4_ENABLED: bool = cfg!(feature = "ipv4");
+
+/// How often CONFIG_DB is polled for IPv4 setting changes
+pub const IPV4_CONFIG_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Family value stored in APPL_DB for IPv4 neighbors
+pub const IPV4_FAMILY: &str = "IPv4";
+
+/// Parse the IPv4 setting from the NEIGHSYNCD global hash
+///
+/// Returns None when the field is absent or unrecognised, in which case the
+/// caller falls back to [`DEFAULT_IPV4_ENABLED`].
+pub fn parse_ipv4_setting(fields: &HashMap<String, String>) -> Option<bool> {
+    match fields.get(CFG_IPV4_FIELD).map(|v| v.as_str()) {
+        Some("enable") | Some("enabled") | Some("true") => Some(true),
+        Some("disable") | Some("disabled") | Some("false") => Some(false),
+        _ => None,
+    }
+}
+
+/// Action required after the IPv4 setting is re-read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ipv4ToggleAction {
+    /// Setting did not change
+    None,
+    /// IPv4 turned on: request an AF_INET neighbor dump
+    RequestDump,
+    /// IPv4 turned off: flush IPv4 entries from APPL_DB
+    Flush,
+}
+
+/// Current IPv4 sync state
+///
+/// # NIST Controls
+/// - CM-3: Configuration Change Control - Track setting transitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv4SyncToggle {
+    enabled: bool,
+}
+
+impl Default for Ipv4SyncToggle {
+    fn default() -> Self {
+        Self::new(DEFAULT_IPV4_ENABLED)
+    }
+}
+
+impl Ipv4SyncToggle {
+    /// Create with the given startup state
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Check if IPv4 neighbors are currently processed
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Apply a newly read setting and return what needs to happen
+    pub fn apply(&mut self, enabled: bool) -> Ipv4ToggleAction {
+        if enabled == self.enabled {
+            return Ipv4ToggleAction::None;
+        }
+
+        self.enabled = enabled;
+        if enabled {
+            Ipv4ToggleAction::RequestDump
+        } else {
+            Ipv4ToggleAction::Flush
+        }
+    }
+}
+
+/// Select APPL_DB neighbor keys of the given family, sorted
+///
+/// `neighbors` is the map returned by `RedisAdapter::get_all_neighbors`.
+pub fn neighbor_keys_by_family(
+    neighbors: &HashMap<String, HashMap<String, String>>,
+    family: &str,
+) -> Vec<String> {
+    let mut keys: Vec<String> = neighbors
+        .iter()
+        .filter(|(_, fields)| fields.get("family").is_some_and(|f| f == family))
+        .map(|(key, _)| key.clone())
+        .collect();
+    keys.sort();
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(value: &str) -> HashMap<String, String> {
+        HashMap::from([(CFG_IPV4_FIELD.to_string(), value.to_string())])
+    }
+
+    fn neighbor(mac: &str, family: &str) -> HashMap<String, String> {
+        HashMap::from([
+            ("neigh".to_string(), mac.to_string()),
+            ("family".to_string(), family.to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_parse_ipv4_setting() {
+        assert_eq!(parse_ipv4_setting(&setting("enable")), Some(true));
+        assert_eq!(parse_ipv4_setting(&setting("true")), Some(true));
+        assert_eq!(parse_ipv4_setting(&setting("disable")), Some(false));
+        assert_eq!(parse_ipv4_setting(&setting("false")), Some(false));
+        assert_eq!(parse_ipv4_setting(&setting("maybe")), None);
+        assert_eq!(parse_ipv4_setting(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn test_toggle_on_requests_dump() {
+        let mut toggle = Ipv4SyncToggle::new(false);
+
+        assert_eq!(toggle.apply(true), Ipv4ToggleAction::RequestDump);
+        assert!(toggle.is_enabled());
+
+        // Re-reading the same setting must not trigger another dump
+        assert_eq!(toggle.apply(true), Ipv4ToggleAction::None);
+    }
+
+    #[test]
+    fn test_toggle_off_flushes() {
+        let mut toggle = Ipv4SyncToggle::new(true);
+
+        assert_eq!(toggle.apply(false), Ipv4ToggleAction::Flush);
+        assert!(!toggle.is_enabled());
+        assert_eq!(toggle.apply(false), Ipv4ToggleAction::None);
+
+        // And back on again
+        assert_eq!(toggle.apply(true), Ipv4ToggleAction::RequestDump);
+    }
+
+    #[test]
+    fn test_default_follows_feature() {
+        assert_eq!(
+            Ipv4SyncToggle::default().is_enabled(),
+            cfg!(feature = "ipv4")
+        );
+    }
+
+    #[test]
+    fn test_flush_selects_only_ipv4_entries() {
+        let neighbors = HashMap::from([
+            (
+                "Vlan1000:192.168.0.2".to_string(),
+                neighbor("00:11:22:33:44:55", "IPv4"),
+            ),
+            (
+                "Ethernet0:10.0.0.1".to_string(),
+                neighbor("00:11:22:33:44:56", "IPv4"),
+            ),
+            (
+                "Ethernet0:2001:db8::1".to_string(),
+                neighbor("00:11:22:33:44:57", "IPv6"),
+            ),
+            ("Ethernet4:10.0.0.3".to_string(), HashMap::new()),
+        ]);
+
+        assert_eq!(
+            neighbor_keys_by_family(&neighbors, IPV4_FAMILY),
+            vec![
+                "Ethernet0:10.0.0.1".to_string(),
+                "Vlan1000:192.168.0.2".to_string()
+            ]
+        );
+        assert_eq!(
+            neighbor_keys_by_family(&neighbors, "IPv6"),
+            vec!["Ethernet0:2001:db8::1".to_string()]
+        );
+    }
+}
@@ -7,7 +7,8 @@
 //! # Features
 //!
 //! - **default**: IPv6-only (NDP) neighbor synchronization
-//! - **ipv4**: Enable IPv4/ARP neighbor support
+//! - **ipv4**: Enable IPv4/ARP neighbor sync at startup. IPv4 sync can also be
+//!   toggled at runtime via CONFIG_DB `NEIGHSYNCD:global` (see [`ipv4_config`])
 //! - **dual-stack**: Enable both IPv4 and IPv6
 //!
 //! # NIST 800-53 Rev 5 Control Mappings
@@ -54,17 +55,22 @@ pub mod distributed_lock;
 pub mod error;
 pub mod grpc_api;
 pub mod health_monitor;
+pub mod ipv4_config;
+pub mod link_local;
 pub mod metrics;
 pub mod metrics_server;
 pub mod neigh_sync;
 pub mod netlink;
 pub mod profiling;
+pub mod reconcile;
 pub mod redis_adapter;
 pub mod rest_api;
+pub mod state_policy;
 pub mod state_replication;
 pub mod tracing_integration;
 pub mod types;
 pub mod vrf;
+pub mod write_batcher;
 
 pub use advanced_health::{
     AdvancedHealthMonitor, DependencyHealth, HealthStatus, HealthThresholds, PerformanceMetrics,
@@ -78,18 +84,23 @@ pub use grpc_api::{
     RestServerConfig, StatsInfo,
 };
 pub use health_monitor::HealthMonitor;
+pub use ipv4_config::{Ipv4SyncToggle, Ipv4ToggleAction};
+pub use link_local::{LinkLocalFilter, LinkLocalUpdate};
 pub use metrics::{HealthStatus as MetricsHealthStatus, MetricsCollector};
 pub use metrics_server::{
-    MetricsServerConfig, start_metrics_server, start_metrics_server_insecure,
+    HealthProbes, MetricsServerConfig, start_metrics_server, start_metrics_server_insecure,
 };
 pub use neigh_sync::{AsyncNeighSync, NeighSync};
 pub use netlink::{AsyncNetlinkSocket, NetlinkSocket};
 pub use profiling::{AdaptivePerformanceTuner, LatencyStats, PerformanceProfile, Profiler};
+pub use reconcile::{ReconcilePlan, ReconcileSummary};
 pub use redis_adapter::RedisAdapter;
 pub use rest_api::{ApiErrorResponse, ApiResponse, ListNeighborsQuery, RestApiService};
+pub use state_policy::{ProbeRateLimiter, StateAction, StatePolicy};
 pub use state_replication::{
     ReplicationEventType, ReplicationManager, ReplicationMessage, ReplicationState,
 };
 pub use tracing_integration::{Span, SpanKind, SpanStatus, TracingIntegration};
 pub use types::{MacAddress, NeighborEntry, NeighborMessageType, NeighborState};
 pub use vrf::{VrfConfig, VrfId, VrfInterfaceBinding, VrfManager, VrfRedisKeyGenerator};
+pub use write_batcher::{FlushReport, WriteBatcher, WriteOp, WriteSink};
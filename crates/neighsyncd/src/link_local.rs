@@ -0,0 +1,357 @@
+//! IPv6 link-local neighbor filtering
+//!
+//! Matches the C++ neighsyncd behavior: fe80::/10 neighbors are only synced
+//! when the owning interface has `ipv6_use_link_local_only` enabled in
+//! CONFIG_DB (INTERFACE, PORTCHANNEL_INTERFACE or VLAN_INTERFACE). Without the
+//! opt-in, unnumbered fabrics would flood APPL_DB with link-local entries.
+//!
+//! The set of opted-in interfaces is cached and refreshed on CONFIG_DB
+//! changes. Link-local neighbors seen on an interface that is not (yet)
+//! opted in are parked, so a neighbor learned before the interface config
+//! is loaded is synced as soon as the opt-in appears instead of waiting for
+//! the kernel to report it again.
+//!
+//! # NIST 800-53 Rev 5 Control Mappings
+//! - CM-6: Configuration Settings - Per-interface opt-in from CONFIG_DB
+//! - SC-5: DoS Protection - Bounded parking, no per-event DB lookups
+//! - SC-7: Boundary Protection - Link-local filtering
+
+use crate::types::{NeighborEntry, NeighborMessageType};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
+/// CONFIG_DB tables carrying `ipv6_use_link_local_only`
+pub const CFG_INTF_TABLE_NAME: &str = "INTERFACE";
+pub const CFG_LAG_INTF_TABLE_NAME: &str = "PORTCHANNEL_INTERFACE";
+pub const CFG_VLAN_INTF_TABLE_NAME: &str = "VLAN_INTERFACE";
+
+/// All interface tables scanned on refresh
+pub const LINK_LOCAL_CONFIG_TABLES: [&str; 3] = [
+    CFG_INTF_TABLE_NAME,
+    CFG_LAG_INTF_TABLE_NAME,
+    CFG_VLAN_INTF_TABLE_NAME,
+];
+
+/// CONFIG_DB field opting an interface into link-local neighbor sync
+pub const LINK_LOCAL_ONLY_FIELD: &str = "ipv6_use_link_local_only";
+
+/// Maximum number of parked link-local neighbors
+/// NIST: SC-5 - Bound memory used by unconfigured interfaces
+pub const MAX_PARKED_LINK_LOCAL: usize = 4096;
+
+/// Select the CONFIG_DB interface table for an interface name
+pub fn interface_table(interface: &str) -> Option<&'static str> {
+    if interface.starts_with("Vlan") {
+        Some(CFG_VLAN_INTF_TABLE_NAME)
+    } else if interface.starts_with("PortChannel") {
+        Some(CFG_LAG_INTF_TABLE_NAME)
+    } else if interface.starts_with("Ethernet") {
+        Some(CFG_INTF_TABLE_NAME)
+    } else {
+        None
+    }
+}
+
+/// Extract the interface name from an interface-level CONFIG_DB key
+///
+/// `INTERFACE:Ethernet0` yields `Ethernet0`; IP sub-keys such as
+/// `INTERFACE:Ethernet0|fe80::1/64` carry no interface attributes and
+/// yield None.
+pub fn interface_from_config_key<'a>(table: &str, key: &'a str) -> Option<&'a str> {
+    let name = key.strip_prefix(table)?.strip_prefix(':')?;
+    if name.is_empty() || name.contains('|') {
+        return None;
+    }
+    Some(name)
+}
+
+/// Select APPL_DB neighbor keys that are link-local on the given interfaces
+///
+/// Keys are `<interface>:<ip>` as returned by
+/// `RedisAdapter::get_all_neighbors`, sorted.
+pub fn link_local_keys_on<S: AsRef<str>>(
+    neighbors: &HashMap<String, HashMap<String, String>>,
+    interfaces: &[S],
+) -> Vec<String> {
+    let mut keys: Vec<String> = neighbors
+        .keys()
+        .filter(|key| {
+            let Some((interface, ip)) = key.split_once(':') else {
+                return false;
+            };
+            let is_link_local = match ip.parse::<IpAddr>() {
+                Ok(IpAddr::V6(addr)) => (addr.segments()[0] & 0xffc0) == 0xfe80,
+                _ => false,
+            };
+            is_link_local && interfaces.iter().any(|i| i.as_ref() == interface)
+        })
+        .cloned()
+        .collect();
+    keys.sort();
+    keys
+}
+
+/// Result of replacing the opted-in interface set
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LinkLocalUpdate {
+    /// Interfaces that gained the opt-in, sorted
+    pub enabled: Vec<String>,
+    /// Interfaces that lost the opt-in, sorted; their entries must be removed
+    pub disabled: Vec<String>,
+    /// Parked neighbors on newly opted-in interfaces, ready to sync
+    pub released: Vec<NeighborEntry>,
+}
+
+impl LinkLocalUpdate {
+    /// Check if the refresh changed anything
+    pub fn is_empty(&self) -> bool {
+        self.enabled.is_empty() && self.disabled.is_empty() && self.released.is_empty()
+    }
+}
+
+/// Cached link-local opt-in state
+///
+/// # NIST Controls
+/// - CM-6: Configuration Settings - Cached interface configuration
+/// - SC-7: Boundary Protection - Link-local admission decisions
+#[derive(Debug, Default)]
+pub struct LinkLocalFilter {
+    /// Interfaces with ipv6_use_link_local_only enabled
+    enabled: HashSet<String>,
+    /// Whether interface config has been loaded at least once
+    loaded: bool,
+    /// Link-local neighbors held until their interface opts in
+    parked: HashMap<String, NeighborEntry>,
+}
+
+impl LinkLocalFilter {
+    /// Create an empty filter (no interface config loaded yet)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if interface config has been loaded
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    /// Check if an interface has link-local neighbor sync enabled
+    pub fn is_enabled(&self, interface: &str) -> bool {
+        self.enabled.contains(interface)
+    }
+
+    /// Number of parked link-local neighbors
+    pub fn parked_count(&self) -> usize {
+        self.parked.len()
+    }
+
+    /// Decide whether a neighbor event passes the link-local filter
+    ///
+    /// Non link-local neighbors always pass. Link-local neighbors pass only
+    /// on opted-in interfaces; otherwise resolved ones are parked and deletes
+    /// drop any parked copy.
+    pub fn admit(&mut self, msg_type: NeighborMessageType, entry: &NeighborEntry) -> bool {
+        if !entry.is_ipv6_link_local() {
+            return true;
+        }
+        if self.enabled.contains(&entry.interface) {
+            return true;
+        }
+
+        let key = entry.redis_key();
+        if msg_type == NeighborMessageType::Delete || !entry.state.is_resolved() {
+            self.parked.remove(&key);
+        } else if self.parked.len() < MAX_PARKED_LINK_LOCAL || self.parked.contains_key(&key) {
+            self.parked.insert(key, entry.clone());
+        }
+        false
+    }
+
+    /// Replace the opted-in interface set with a fresh CONFIG_DB snapshot
+    pub fn replace(&mut self, interfaces: HashSet<String>) -> LinkLocalUpdate {
+        let mut enabled: Vec<String> = interfaces.difference(&self.enabled).cloned().collect();
+        let mut disabled: Vec<String> = self.enabled.difference(&interfaces).cloned().collect();
+        enabled.sort();
+        disabled.sort();
+
+        let released_keys: Vec<String> = self
+            .parked
+            .iter()
+            .filter(|(_, entry)| interfaces.contains(&entry.interface))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut released: Vec<NeighborEntry> = released_keys
+            .iter()
+            .filter_map(|key| self.parked.remove(key))
+            .collect();
+        released.sort_by_key(|entry| entry.redis_key());
+
+        self.enabled = interfaces;
+        self.loaded = true;
+
+        LinkLocalUpdate {
+            enabled,
+            disabled,
+            released,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MacAddress, NeighborState};
+    use crate::vrf::VrfId;
+
+    fn entry(interface: &str, ip: &str, state: NeighborState) -> NeighborEntry {
+        NeighborEntry {
+            ifindex: 1,
+            interface: interface.to_string(),
+            ip: ip.parse().unwrap(),
+            mac: MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            state,
+            externally_learned: false,
+            vrf_id: VrfId::default_vrf(),
+        }
+    }
+
+    fn interfaces(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_interface_table_selection() {
+        assert_eq!(interface_table("Vlan100"), Some("VLAN_INTERFACE"));
+        assert_eq!(
+            interface_table("PortChannel1"),
+            Some("PORTCHANNEL_INTERFACE")
+        );
+        assert_eq!(interface_table("Ethernet0"), Some("INTERFACE"));
+        assert_eq!(interface_table("eth0"), None);
+    }
+
+    #[test]
+    fn test_interface_from_config_key() {
+        assert_eq!(
+            interface_from_config_key("INTERFACE", "INTERFACE:Ethernet0"),
+            Some("Ethernet0")
+        );
+        assert_eq!(
+            interface_from_config_key("INTERFACE", "INTERFACE:Ethernet0|fe80::1/64"),
+            None
+        );
+        assert_eq!(
+            interface_from_config_key("VLAN_INTERFACE", "INTERFACE:Ethernet0"),
+            None
+        );
+        assert_eq!(interface_from_config_key("INTERFACE", "INTERFACE:"), None);
+    }
+
+    #[test]
+    fn test_non_link_local_always_admitted() {
+        let mut filter = LinkLocalFilter::new();
+        let global = entry("Ethernet0", "2001:db8::1", NeighborState::Reachable);
+        assert!(filter.admit(NeighborMessageType::New, &global));
+        assert_eq!(filter.parked_count(), 0);
+    }
+
+    #[test]
+    fn test_link_local_before_config_loaded() {
+        let mut filter = LinkLocalFilter::new();
+        let neighbor = entry("Ethernet0", "fe80::1", NeighborState::Reachable);
+
+        // Interface config not loaded yet: neighbor is held, not synced
+        assert!(!filter.is_loaded());
+        assert!(!filter.admit(NeighborMessageType::New, &neighbor));
+        assert_eq!(filter.parked_count(), 1);
+
+        // Config arrives with the opt-in: parked neighbor is released
+        let update = filter.replace(interfaces(&["Ethernet0"]));
+        assert!(filter.is_loaded());
+        assert_eq!(update.enabled, vec!["Ethernet0".to_string()]);
+        assert_eq!(update.released, vec![neighbor.clone()]);
+        assert_eq!(filter.parked_count(), 0);
+
+        // Subsequent events pass straight through
+        assert!(filter.admit(NeighborMessageType::New, &neighbor));
+    }
+
+    #[test]
+    fn test_parked_neighbor_dropped_on_delete() {
+        let mut filter = LinkLocalFilter::new();
+        let neighbor = entry("Ethernet0", "fe80::1", NeighborState::Reachable);
+
+        filter.admit(NeighborMessageType::New, &neighbor);
+        filter.admit(NeighborMessageType::Delete, &neighbor);
+        assert_eq!(filter.parked_count(), 0);
+
+        // Unresolved neighbors are not parked either
+        let failed = entry("Ethernet0", "fe80::2", NeighborState::Failed);
+        filter.admit(NeighborMessageType::New, &failed);
+        assert_eq!(filter.parked_count(), 0);
+
+        let update = filter.replace(interfaces(&["Ethernet0"]));
+        assert!(update.released.is_empty());
+    }
+
+    #[test]
+    fn test_refresh_reports_enabled_and_disabled() {
+        let mut filter = LinkLocalFilter::new();
+        filter.replace(interfaces(&["Ethernet0", "Vlan1000"]));
+
+        let update = filter.replace(interfaces(&["Vlan1000", "PortChannel0001"]));
+        assert_eq!(update.enabled, vec!["PortChannel0001".to_string()]);
+        assert_eq!(update.disabled, vec!["Ethernet0".to_string()]);
+        assert!(!filter.is_enabled("Ethernet0"));
+        assert!(filter.is_enabled("PortChannel0001"));
+
+        // Disabled interface no longer admits link-local neighbors
+        let neighbor = entry("Ethernet0", "fe80::1", NeighborState::Reachable);
+        assert!(!filter.admit(NeighborMessageType::New, &neighbor));
+
+        // Unchanged snapshot is a no-op
+        assert!(
+            filter
+                .replace(interfaces(&["Vlan1000", "PortChannel0001"]))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parking_is_bounded() {
+        let mut filter = LinkLocalFilter::new();
+        for i in 0..(MAX_PARKED_LINK_LOCAL + 10) {
+            let neighbor = entry(
+                "Ethernet0",
+                &format!("fe80::{:x}", i + 1),
+                NeighborState::Reachable,
+            );
+            filter.admit(NeighborMessageType::New, &neighbor);
+        }
+        assert_eq!(filter.parked_count(), MAX_PARKED_LINK_LOCAL);
+    }
+
+    #[test]
+    fn test_link_local_keys_on() {
+        let fields = HashMap::from([("family".to_string(), "IPv6".to_string())]);
+        let neighbors: HashMap<String, HashMap<String, String>> = [
+            "Ethernet0:fe80::1",
+            "Ethernet0:febf::1",
+            "Ethernet0:2001:db8::1",
+            "Ethernet4:fe80::1",
+            "Ethernet0:10.0.0.1",
+        ]
+        .iter()
+        .map(|k| (k.to_string(), fields.clone()))
+        .collect();
+
+        assert_eq!(
+            link_local_keys_on(&neighbors, &["Ethernet0"]),
+            vec![
+                "Ethernet0:fe80::1".to_string(),
+                "Ethernet0:febf::1".to_string()
+            ]
+        );
+        assert!(link_local_keys_on(&neighbors, &["Vlan1000"]).is_empty());
+    }
+}
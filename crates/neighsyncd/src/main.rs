@@ -14,9 +14,10 @@
 //! Uses AsyncNeighSync with epoll-based async netlink I/O for efficient
 //! event processing without busy-waiting.
 
+use sonic_neighsyncd::ipv4_config::IPV4_CONFIG_REFRESH_INTERVAL;
 use sonic_neighsyncd::{
-    AsyncNeighSync, HealthMonitor, MetricsCollector, NeighsyncError, Result,
-    start_metrics_server_insecure,
+    AsyncNeighSync, HealthMonitor, HealthProbes, HealthThresholds, Ipv4ToggleAction,
+    MetricsCollector, NeighsyncError, Result, start_metrics_server_insecure,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -229,15 +230,22 @@ async fn run_daemon() -> Result<()> {
     let mut health_monitor = HealthMonitor::new(metrics.clone());
     info!("neighsyncd: Initialized health monitor");
 
+    // Liveness/readiness state for /healthz and /readyz
+    // NIST: CP-10 - Report recovery progress to orchestration
+    let probes = HealthProbes::new(HealthThresholds::default());
+
     // Spawn metrics server in background (insecure mode for now - TODO: Add mTLS support)
     // NIST: AU-6 - Metrics endpoint for analysis
     let metrics_clone = metrics.clone();
+    let probes_clone = probes.clone();
     tokio::spawn(async move {
         info!(
             port = METRICS_PORT,
             "neighsyncd: Starting metrics server (HTTP mode)"
         );
-        if let Err(e) = start_metrics_server_insecure(metrics_clone, Some(METRICS_PORT)).await {
+        if let Err(e) =
+            start_metrics_server_insecure(metrics_clone, probes_clone, Some(METRICS_PORT)).await
+        {
             error!(error = %e, "neighsyncd: Metrics server failed");
         }
     });
@@ -249,6 +257,7 @@ async fn run_daemon() -> Result<()> {
     // Initialize AsyncNeighSync with epoll integration
     // NIST: AC-3 - Access enforcement via kernel permissions
     let mut neigh_sync = AsyncNeighSync::new(REDIS_HOST, REDIS_PORT).await?;
+    neigh_sync.set_metrics(metrics.clone());
     info!("neighsyncd: Initialized AsyncNeighSync with epoll integration");
 
     // Update connection status metrics
@@ -265,6 +274,11 @@ async fn run_daemon() -> Result<()> {
         neigh_sync.wait_for_restore().await?;
         info!("neighsyncd: Neighbor restore complete");
 
+        // Dump the kernel table now so it refreshes the restored snapshot
+        // before the reconcile deadline
+        // NIST: CP-10 - Recovery state refresh
+        neigh_sync.request_dump()?;
+
         // Start reconciliation timer
         let reconcile_deadline = tokio::time::Instant::now()
             + tokio::time::Duration::from_secs(WARMSTART_RECONCILE_TIMER_SECS);
@@ -280,6 +294,7 @@ async fn run_daemon() -> Result<()> {
             if tokio::time::Instant::now() >= reconcile_deadline {
                 info!("neighsyncd: Reconciliation timer expired");
                 neigh_sync.reconcile().await?;
+                probes.set_reconciled(true);
                 break;
             }
 
@@ -308,12 +323,16 @@ async fn run_daemon() -> Result<()> {
 
             // Update health status periodically
             health_monitor.update_health();
+            probes.record_loop_iteration();
         }
     }
 
-    // Request initial neighbor table dump
+    // Request initial neighbor table dump (already done for warm restart)
     // NIST: CM-8 - Initial inventory
-    neigh_sync.request_dump()?;
+    if !warm_restart_active {
+        neigh_sync.request_dump()?;
+        probes.set_reconciled(true);
+    }
     info!("neighsyncd: Listening to neighbor events (async epoll mode)...");
 
     // Audit initial neighbor dump request
@@ -323,9 +342,18 @@ async fn run_daemon() -> Result<()> {
         "Initial neighbor table dump requested"
     );
 
+    // Periodic re-read of runtime settings from CONFIG_DB
+    // NIST: CM-3 - Apply configuration changes without restart
+    let mut config_refresh = tokio::time::interval(IPV4_CONFIG_REFRESH_INTERVAL);
+    config_refresh.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    config_refresh.reset();
+
     // Main event loop - true async, no polling!
     // NIST: SI-4 - Continuous monitoring
     loop {
+        // Queued APPL_DB writes must not wait past their latency bound
+        let write_deadline = neigh_sync.next_write_deadline();
+
         tokio::select! {
             biased;
             // Check shutdown first
@@ -333,6 +361,43 @@ async fn run_daemon() -> Result<()> {
                 info!("neighsyncd: Received SIGINT");
                 break;
             }
+            // Apply runtime settings from CONFIG_DB
+            _ = config_refresh.tick() => {
+                match neigh_sync.refresh_ipv4_config().await {
+                    Ok(Ipv4ToggleAction::None) => {}
+                    Ok(action) => {
+                        info!(?action, "neighsyncd: Applied IPv4 sync setting change");
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "neighsyncd: Failed to refresh IPv4 sync setting");
+                        metrics.record_redis_error();
+                    }
+                }
+                // Apply link-local interface opt-ins from CONFIG_DB
+                match neigh_sync.refresh_link_local_config().await {
+                    Ok(update) if !update.is_empty() => {
+                        info!(
+                            enabled = ?update.enabled,
+                            disabled = ?update.disabled,
+                            "neighsyncd: Applied link-local interface changes"
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!(error = %e, "neighsyncd: Failed to refresh link-local interfaces");
+                        metrics.record_redis_error();
+                    }
+                }
+            }
+            // Flush a partial write batch once it has waited long enough
+            _ = tokio::time::sleep_until(
+                write_deadline.unwrap_or_else(std::time::Instant::now).into()
+            ), if write_deadline.is_some() => {
+                if let Err(e) = neigh_sync.flush_pending_writes().await {
+                    warn!(error = %e, "neighsyncd: Failed to flush neighbor writes");
+                    health_monitor.record_failure();
+                }
+            }
             // Process netlink events (async - waits via epoll)
             result = neigh_sync.process_events_batched() => {
                 let start = std::time::Instant::now();
@@ -369,6 +434,7 @@ async fn run_daemon() -> Result<()> {
 
         // Update health status periodically
         health_monitor.update_health();
+        probes.record_loop_iteration();
 
         // Check shutdown flag (set by signal handler)
         if shutdown.load(Ordering::Relaxed) {
@@ -377,6 +443,11 @@ async fn run_daemon() -> Result<()> {
         }
     }
 
+    // Don't lose writes still waiting for a batch
+    if let Err(e) = neigh_sync.flush_pending_writes().await {
+        warn!(error = %e, "neighsyncd: Failed to flush neighbor writes on shutdown");
+    }
+
     info!("neighsyncd: Graceful shutdown complete");
     Ok(())
 }
@@ -5,7 +5,9 @@
 //! - SI-4: System Monitoring - Performance and health metrics
 //! - CP-10: System Recovery - Track recovery metrics
 
-use prometheus::{Counter, Gauge, Histogram, HistogramOpts, Opts, Registry};
+use crate::state_policy::StateAction;
+use crate::write_batcher::FlushReport;
+use prometheus::{Counter, CounterVec, Gauge, Histogram, HistogramOpts, Opts, Registry};
 use std::sync::Arc;
 
 /// Global metrics collector for neighsyncd
@@ -21,6 +23,10 @@ pub struct MetricsCollector {
     pub events_failed_total: Counter,
     pub netlink_errors_total: Counter,
     pub redis_errors_total: Counter,
+    pub neighbor_state_actions_total: CounterVec,
+    pub probes_sent_total: Counter,
+    pub probes_rate_limited_total: Counter,
+    pub write_retries_total: Counter,
 
     // Gauges
     pub pending_neighbors: Gauge,
@@ -34,6 +40,7 @@ pub struct MetricsCollector {
     pub event_latency_seconds: Histogram,
     pub redis_latency_seconds: Histogram,
     pub batch_size: Histogram,
+    pub write_flush_latency_seconds: Histogram,
 
     // Registry for export
     pub registry: Arc<Registry>,
@@ -84,6 +91,33 @@ impl MetricsCollector {
         ))?;
         registry.register(Box::new(redis_errors_total.clone()))?;
 
+        let neighbor_state_actions_total = CounterVec::new(
+            Opts::new(
+                "neighsyncd_neighbor_state_actions_total",
+                "Neighbor events by state policy outcome (sync, sync_probe, delete, ignore)",
+            ),
+            &["action"],
+        )?;
+        registry.register(Box::new(neighbor_state_actions_total.clone()))?;
+
+        let probes_sent_total = Counter::with_opts(Opts::new(
+            "neighsyncd_probes_sent_total",
+            "Total number of NUD_PROBE requests sent for stale neighbors",
+        ))?;
+        registry.register(Box::new(probes_sent_total.clone()))?;
+
+        let probes_rate_limited_total = Counter::with_opts(Opts::new(
+            "neighsyncd_probes_rate_limited_total",
+            "Total number of stale neighbor probes suppressed by rate limiting",
+        ))?;
+        registry.register(Box::new(probes_rate_limited_total.clone()))?;
+
+        let write_retries_total = Counter::with_opts(Opts::new(
+            "neighsyncd_write_retries_total",
+            "Total number of APPL_DB write pipeline retries",
+        ))?;
+        registry.register(Box::new(write_retries_total.clone()))?;
+
         // Gauges
         let pending_neighbors = Gauge::with_opts(Opts::new(
             "neighsyncd_pending_neighbors",
@@ -155,6 +189,17 @@ impl MetricsCollector {
         )?;
         registry.register(Box::new(batch_size.clone()))?;
 
+        let write_flush_latency_seconds = Histogram::with_opts(
+            HistogramOpts::new(
+                "neighsyncd_write_flush_latency_seconds",
+                "APPL_DB write pipeline flush latency in seconds, including retries",
+            )
+            .buckets(vec![
+                0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+            ]),
+        )?;
+        registry.register(Box::new(write_flush_latency_seconds.clone()))?;
+
         Ok(Self {
             neighbors_processed_total,
             neighbors_added_total,
@@ -162,6 +207,10 @@ impl MetricsCollector {
             events_failed_total,
             netlink_errors_total,
             redis_errors_total,
+            neighbor_state_actions_total,
+            probes_sent_total,
+            probes_rate_limited_total,
+            write_retries_total,
             pending_neighbors,
             queue_depth,
             memory_bytes,
@@ -171,6 +220,7 @@ impl MetricsCollector {
             event_latency_seconds,
             redis_latency_seconds,
             batch_size,
+            write_flush_latency_seconds,
             registry: Arc::new(registry),
         })
     }
@@ -200,6 +250,22 @@ impl MetricsCollector {
         self.redis_errors_total.inc();
     }
 
+    /// Record the state policy outcome for a neighbor event
+    pub fn record_state_action(&self, action: StateAction) {
+        self.neighbor_state_actions_total
+            .with_label_values(&[action.as_str()])
+            .inc();
+    }
+
+    /// Record a stale neighbor probe (sent or suppressed by rate limiting)
+    pub fn record_probe(&self, sent: bool) {
+        if sent {
+            self.probes_sent_total.inc();
+        } else {
+            self.probes_rate_limited_total.inc();
+        }
+    }
+
     /// Update pending neighbors count
     pub fn set_pending_neighbors(&self, count: usize) {
         self.pending_neighbors.set(count as f64);
@@ -250,6 +316,13 @@ impl MetricsCollector {
     pub fn observe_batch_size(&self, size: usize) {
         self.batch_size.observe(size as f64);
     }
+
+    /// Record a flushed APPL_DB write batch
+    pub fn record_write_flush(&self, report: &FlushReport, latency_secs: f64) {
+        self.observe_batch_size(report.len());
+        self.write_flush_latency_seconds.observe(latency_secs);
+        self.write_retries_total.inc_by(report.retries as f64);
+    }
 }
 
 /// Health status for the service
@@ -309,6 +382,49 @@ mod tests {
         assert_eq!(collector.health_status.get(), 0.0);
     }
 
+    #[test]
+    fn test_record_state_action() {
+        let collector = MetricsCollector::new().unwrap();
+        collector.record_state_action(StateAction::Sync);
+        collector.record_state_action(StateAction::SyncAndProbe);
+        collector.record_state_action(StateAction::SyncAndProbe);
+        collector.record_state_action(StateAction::Ignore);
+
+        let count = |action: StateAction| {
+            collector
+                .neighbor_state_actions_total
+                .with_label_values(&[action.as_str()])
+                .get()
+        };
+        assert_eq!(count(StateAction::Sync), 1.0);
+        assert_eq!(count(StateAction::SyncAndProbe), 2.0);
+        assert_eq!(count(StateAction::Delete), 0.0);
+        assert_eq!(count(StateAction::Ignore), 1.0);
+
+        collector.record_probe(true);
+        collector.record_probe(false);
+        collector.record_probe(false);
+        assert_eq!(collector.probes_sent_total.get(), 1.0);
+        assert_eq!(collector.probes_rate_limited_total.get(), 2.0);
+    }
+
+    #[test]
+    fn test_record_write_flush() {
+        let collector = MetricsCollector::new().unwrap();
+        let report = FlushReport {
+            sets: 400,
+            deletes: 112,
+            retries: 1,
+            round_trips: 3,
+        };
+        collector.record_write_flush(&report, 0.002);
+
+        assert_eq!(collector.batch_size.get_sample_count(), 1);
+        assert_eq!(collector.batch_size.get_sample_sum(), 512.0);
+        assert_eq!(collector.write_flush_latency_seconds.get_sample_count(), 1);
+        assert_eq!(collector.write_retries_total.get(), 1.0);
+    }
+
     #[test]
     fn test_redis_connection_status() {
         let collector = MetricsCollector::new().unwrap();
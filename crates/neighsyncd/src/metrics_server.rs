@@ -6,9 +6,26 @@
 //! - SC-8: Transmission Confidentiality - TLS 1.3 with CNSA 2.0 cipher suites
 //! - SC-8(1): Cryptographic Protection - mTLS with AES-256-GCM, SHA-384+, P-384+
 //! - IA-5(2): PKI-Based Authentication - Client certificate validation
+//! - CP-10: System Recovery - Liveness and readiness probes
+//!
+//! # Endpoints
+//! - `/metrics`: Prometheus text format
+//! - `/health`: health score from [`MetricsCollector`]
+//! - `/healthz`: liveness; 503 once the event loop has not iterated within
+//!   [`HealthThresholds::critical_stall_timeout`]
+//! - `/readyz`: readiness; 503 until netlink and Redis are connected and
+//!   warm restart reconciliation has finished
+//!
+//! `/healthz` and `/readyz` return a JSON body with a per-check breakdown:
+//!
+//! ```text
+//! {"status": "fail", "checks": {"redis": {"status": "pass"},
+//!  "netlink": {"status": "pass"}, "warm_restart": {"status": "fail"}}}
+//! ```
 
+use crate::advanced_health::{AdvancedHealthMonitor, HealthThresholds};
 use crate::metrics::MetricsCollector;
-use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
+use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
 use axum_server::tls_rustls::RustlsConfig;
 use prometheus::{Encoder, TextEncoder};
 use rustls::pki_types::CertificateDer;
@@ -18,6 +35,7 @@ use std::fs::File;
 use std::io::BufReader;
 use std::net::{Ipv6Addr, SocketAddr};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::{error, info, warn};
 
 /// Default metrics server port
@@ -66,6 +84,112 @@ impl Default for MetricsServerConfig {
     }
 }
 
+/// Liveness and readiness state shared between the event loop and `/healthz`
+/// and `/readyz`
+///
+/// Event loop progress is tracked by an [`AdvancedHealthMonitor`]; Redis and
+/// netlink state come from the [`MetricsCollector`] connection gauges.
+///
+/// # NIST Controls
+/// - CP-10: System Recovery - Report recovery progress to orchestration
+/// - SI-4: System Monitoring - Detect a stalled event loop
+#[derive(Clone)]
+pub struct HealthProbes {
+    monitor: Arc<AdvancedHealthMonitor>,
+    reconciled: Arc<AtomicBool>,
+}
+
+impl Default for HealthProbes {
+    fn default() -> Self {
+        Self::new(HealthThresholds::default())
+    }
+}
+
+impl HealthProbes {
+    /// Create probes using the stall timeouts from `thresholds`
+    pub fn new(thresholds: HealthThresholds) -> Self {
+        Self {
+            monitor: Arc::new(AdvancedHealthMonitor::new(thresholds)),
+            reconciled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Record that the event loop completed an iteration
+    pub fn record_loop_iteration(&self) {
+        self.monitor.record_event();
+    }
+
+    /// Mark warm restart reconciliation as finished (or not needed)
+    pub fn set_reconciled(&self, reconciled: bool) {
+        self.reconciled.store(reconciled, Ordering::Relaxed);
+    }
+
+    /// Check if warm restart reconciliation has finished
+    pub fn is_reconciled(&self) -> bool {
+        self.reconciled.load(Ordering::Relaxed)
+    }
+
+    /// Evaluate liveness
+    ///
+    /// Fails once the loop has been idle past `critical_stall_timeout`; past
+    /// `stall_detection_timeout` the check passes with a warning.
+    fn liveness(&self) -> (StatusCode, serde_json::Value) {
+        let thresholds = self.monitor.thresholds();
+        let idle = self.monitor.time_since_last_event();
+
+        let (alive, check) = if idle > thresholds.critical_stall_timeout {
+            (false, "fail")
+        } else if idle > thresholds.stall_detection_timeout {
+            (true, "warn")
+        } else {
+            (true, "pass")
+        };
+
+        let body = serde_json::json!({
+            "status": if alive { "pass" } else { "fail" },
+            "checks": {
+                "event_loop": {
+                    "status": check,
+                    "seconds_since_iteration": idle,
+                    "warn_after_seconds": thresholds.stall_detection_timeout,
+                    "fail_after_seconds": thresholds.critical_stall_timeout,
+                },
+            },
+        });
+        (probe_status_code(alive), body)
+    }
+
+    /// Evaluate readiness against the collector's connection state
+    fn readiness(&self, collector: &MetricsCollector) -> (StatusCode, serde_json::Value) {
+        let redis = collector.redis_connected.get() >= 1.0;
+        let netlink = collector.netlink_connected.get() >= 1.0;
+        let reconciled = self.is_reconciled();
+        let ready = redis && netlink && reconciled;
+
+        let body = serde_json::json!({
+            "status": check_status(ready),
+            "checks": {
+                "redis": { "status": check_status(redis) },
+                "netlink": { "status": check_status(netlink) },
+                "warm_restart": { "status": check_status(reconciled) },
+            },
+        });
+        (probe_status_code(ready), body)
+    }
+}
+
+fn check_status(ok: bool) -> &'static str {
+    if ok { "pass" } else { "fail" }
+}
+
+fn probe_status_code(ok: bool) -> StatusCode {
+    if ok {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
 /// Metrics server state
 ///
 /// # NIST Controls
@@ -73,6 +197,17 @@ impl Default for MetricsServerConfig {
 #[derive(Clone)]
 struct MetricsServerState {
     collector: MetricsCollector,
+    probes: HealthProbes,
+}
+
+/// Build the router shared by the TLS and development servers
+fn build_router(state: MetricsServerState) -> Router {
+    Router::new()
+        .route("/metrics", get(metrics_handler))
+        .route("/health", get(health_handler))
+        .route("/healthz", get(healthz_handler))
+        .route("/readyz", get(readyz_handler))
+        .with_state(state)
 }
 
 /// Load and configure full CNSA 2.0 compliant TLS with mandatory mTLS
@@ -208,6 +343,7 @@ fn load_cnsa_mtls_config(
 ///
 /// # Arguments
 /// * `collector` - Metrics collector to expose
+/// * `probes` - Liveness and readiness state updated by the event loop
 /// * `config` - TLS configuration with certificate paths
 ///
 /// # Returns
@@ -228,16 +364,12 @@ fn load_cnsa_mtls_config(
 /// - **Key usage**: Digital signature, key encipherment
 pub async fn start_metrics_server(
     collector: MetricsCollector,
+    probes: HealthProbes,
     config: MetricsServerConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, config.port));
 
-    let state = MetricsServerState { collector };
-
-    let app = Router::new()
-        .route("/metrics", get(metrics_handler))
-        .route("/health", get(health_handler))
-        .with_state(state);
+    let app = build_router(MetricsServerState { collector, probes });
 
     info!(
         "Starting CNSA 2.0 compliant metrics server on https://[::1]:{}/metrics",
@@ -273,6 +405,7 @@ pub async fn start_metrics_server(
 /// - SC-8: This violates transmission confidentiality - use only for development
 pub async fn start_metrics_server_insecure(
     collector: MetricsCollector,
+    probes: HealthProbes,
     port: Option<u16>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     warn!("⚠️  Starting metrics server in INSECURE mode (HTTP without TLS)");
@@ -282,12 +415,7 @@ pub async fn start_metrics_server_insecure(
     let port = port.unwrap_or(DEFAULT_METRICS_PORT);
     let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, port));
 
-    let state = MetricsServerState { collector };
-
-    let app = Router::new()
-        .route("/metrics", get(metrics_handler))
-        .route("/health", get(health_handler))
-        .with_state(state);
+    let app = build_router(MetricsServerState { collector, probes });
 
     info!("Starting metrics server on http://[::1]:{}/metrics", port);
 
@@ -348,6 +476,24 @@ async fn health_handler(State(state): State<MetricsServerState>) -> impl IntoRes
     (StatusCode::OK, [("content-type", "application/json")], body)
 }
 
+/// Handle /healthz endpoint - Liveness probe
+///
+/// # NIST Controls
+/// - SI-4: System Monitoring - Stalled event loop detection
+async fn healthz_handler(State(state): State<MetricsServerState>) -> impl IntoResponse {
+    let (code, body) = state.probes.liveness();
+    (code, Json(body))
+}
+
+/// Handle /readyz endpoint - Readiness probe
+///
+/// # NIST Controls
+/// - CP-10: System Recovery - Not ready until reconciliation finishes
+async fn readyz_handler(State(state): State<MetricsServerState>) -> impl IntoResponse {
+    let (code, body) = state.probes.readiness(&state.collector);
+    (code, Json(body))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -357,7 +503,86 @@ mod tests {
         let collector = MetricsCollector::new().unwrap();
 
         // Just verify we can create the state
-        let _state = MetricsServerState { collector };
+        let _state = MetricsServerState {
+            collector,
+            probes: HealthProbes::default(),
+        };
+    }
+
+    async fn probe(state: &MetricsServerState, path: &str) -> (StatusCode, serde_json::Value) {
+        let response = match path {
+            "/healthz" => healthz_handler(State(state.clone())).await.into_response(),
+            _ => readyz_handler(State(state.clone())).await.into_response(),
+        };
+        let code = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (code, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_readyz_transitions() {
+        let state = MetricsServerState {
+            collector: MetricsCollector::new().unwrap(),
+            probes: HealthProbes::default(),
+        };
+
+        // Nothing connected yet
+        let (code, body) = probe(&state, "/readyz").await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["redis"]["status"], "fail");
+        assert_eq!(body["checks"]["netlink"]["status"], "fail");
+
+        // Connected but warm restart still reconciling
+        state.collector.set_redis_connected(true);
+        state.collector.set_netlink_connected(true);
+        let (code, body) = probe(&state, "/readyz").await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["redis"]["status"], "pass");
+        assert_eq!(body["checks"]["warm_restart"]["status"], "fail");
+
+        state.probes.set_reconciled(true);
+        let (code, body) = probe(&state, "/readyz").await;
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(body["status"], "pass");
+
+        // Losing Redis makes the daemon unready again
+        state.collector.set_redis_connected(false);
+        let (code, body) = probe(&state, "/readyz").await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["redis"]["status"], "fail");
+        assert_eq!(body["checks"]["netlink"]["status"], "pass");
+    }
+
+    #[tokio::test]
+    async fn test_healthz_fails_after_stall_timeout() {
+        let thresholds = HealthThresholds {
+            stall_detection_timeout: 0,
+            critical_stall_timeout: 1,
+            ..Default::default()
+        };
+        let state = MetricsServerState {
+            collector: MetricsCollector::new().unwrap(),
+            probes: HealthProbes::new(thresholds),
+        };
+
+        state.probes.record_loop_iteration();
+        let (code, body) = probe(&state, "/healthz").await;
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(body["checks"]["event_loop"]["fail_after_seconds"], 1);
+
+        // Event loop stops iterating
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        let (code, body) = probe(&state, "/healthz").await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "fail");
+        assert_eq!(body["checks"]["event_loop"]["status"], "fail");
+
+        // And recovers once it iterates again
+        state.probes.record_loop_iteration();
+        let (code, _) = probe(&state, "/healthz").await;
+        assert_eq!(code, StatusCode::OK);
     }
 
     #[test]
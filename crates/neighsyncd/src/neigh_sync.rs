@@ -7,10 +7,19 @@
 //! - CM-8: System Component Inventory - Track network neighbors
 
 use crate::error::{NeighsyncError, Result};
+use crate::ipv4_config::{DEFAULT_IPV4_ENABLED, IPV4_FAMILY, Ipv4SyncToggle, Ipv4ToggleAction};
+use crate::link_local::{LinkLocalFilter, LinkLocalUpdate, link_local_keys_on};
+use crate::metrics::MetricsCollector;
 use crate::netlink::{AsyncNetlinkSocket, NetlinkSocket};
+use crate::reconcile::{ReconcilePlan, ReconcileSummary};
 use crate::redis_adapter::RedisAdapter;
+use crate::state_policy::{DEFAULT_PROBE_INTERVAL, StateAction, StatePolicy};
 use crate::types::{MacAddress, NeighborEntry, NeighborMessageType, NeighborState};
+use crate::write_batcher::{
+    FlushReport, MAX_FLUSH_RETRIES, WriteBatcher, WriteOp, flush_with_retry,
+};
 use std::collections::HashMap;
+use std::time::Instant;
 use tracing::{debug, info, instrument, warn};
 
 // NIST SP 800-53 Rev5 compliant audit logging
@@ -25,10 +34,6 @@ pub const DEFAULT_WARMSTART_TIMER_SECS: u64 = 5;
 /// Timeout for waiting for neighbor restore during warm restart (seconds)
 const RESTORE_NEIGH_WAIT_TIMEOUT_SECS: u64 = 180;
 
-/// Default batch size for event processing
-/// NIST: SC-5 - Batch processing reduces Redis round-trips
-const DEFAULT_BATCH_SIZE: usize = 100;
-
 /// Warm restart state for reconciliation
 ///
 /// # NIST Controls
@@ -37,12 +42,212 @@ const DEFAULT_BATCH_SIZE: usize = 100;
 struct WarmRestartState {
     /// Whether warm restart is in progress
     in_progress: bool,
-    /// Cached neighbor entries from APPL_DB before restart
+    /// Snapshot of APPL_DB NEIGH_TABLE taken at restore time
     cached_neighbors: HashMap<String, HashMap<String, String>>,
-    /// New entries received during warm restart
+    /// Kernel dump and live events received during warm restart; these
+    /// refresh snapshot entries at reconcile time
     pending_entries: Vec<(String, NeighborEntry, bool)>, // (key, entry, is_delete)
 }
 
+impl WarmRestartState {
+    /// Forget IPv4 entries after IPv4 sync is disabled
+    fn drop_ipv4(&mut self) {
+        self.cached_neighbors
+            .retain(|_, fields| fields.get("family").is_none_or(|f| f != IPV4_FAMILY));
+        self.pending_entries
+            .retain(|(_, entry, _)| !entry.ip.is_ipv4());
+    }
+
+    /// Forget link-local entries on interfaces that lost the opt-in
+    fn drop_link_local(&mut self, interfaces: &[String]) {
+        for key in link_local_keys_on(&self.cached_neighbors, interfaces) {
+            self.cached_neighbors.remove(&key);
+        }
+        self.pending_entries.retain(|(_, entry, _)| {
+            !(entry.is_ipv6_link_local() && interfaces.contains(&entry.interface))
+        });
+    }
+}
+
+/// Write one batch to APPL_DB as a pipeline and audit the outcome
+///
+/// # NIST Controls
+/// - AU-12: Audit Record Generation - Audit batched writes
+/// - SC-5: DoS Protection - One round trip per batch
+async fn flush_write_batch(
+    redis: &mut RedisAdapter,
+    batch: Vec<WriteOp>,
+    metrics: Option<&MetricsCollector>,
+) -> Result<FlushReport> {
+    if batch.is_empty() {
+        return Ok(FlushReport::default());
+    }
+
+    let count = batch.len();
+    let sample: Vec<serde_json::Value> = batch
+        .iter()
+        .take(10)
+        .map(|op| {
+            serde_json::json!({
+                "operation": if op.is_delete() { "delete" } else { "set" },
+                "interface": op.entry().interface,
+                "ip": op.entry().ip.to_string(),
+                "mac": op.entry().mac.to_string(),
+            })
+        })
+        .collect();
+
+    let start = Instant::now();
+    match flush_with_retry(redis, batch, MAX_FLUSH_RETRIES).await {
+        Ok(report) => {
+            if let Some(metrics) = metrics {
+                metrics.record_write_flush(&report, start.elapsed().as_secs_f64());
+            }
+            info!(
+                sets = report.sets,
+                deletes = report.deletes,
+                retries = report.retries,
+                "Flushed neighbor write batch"
+            );
+            // NIST: AU-12 - Audit successful batch operation
+            info_audit!(
+                "neighsyncd",
+                operation = "batch_flush",
+                sets = report.sets,
+                deletes = report.deletes,
+                "Batch neighbor write completed"
+            );
+            audit_log!(
+                AuditRecord::new(
+                    AuditCategory::NetworkRouting,
+                    "neighsyncd",
+                    "neighbor_batch_flush"
+                )
+                .with_outcome(AuditOutcome::Success)
+                .with_object_type("neighbor_batch")
+                .with_details(serde_json::json!({
+                    "sets": report.sets,
+                    "deletes": report.deletes,
+                    "retries": report.retries,
+                    "entries": sample,
+                    "truncated": count > 10,
+                }))
+            );
+            Ok(report)
+        }
+        Err(e) => {
+            if let Some(metrics) = metrics {
+                metrics.record_redis_error();
+            }
+            error_audit!(
+                "neighsyncd",
+                operation = "batch_flush",
+                count = count,
+                error = %e,
+                "Batch neighbor write failed"
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Diff the restore-time snapshot against cached events and apply the result
+///
+/// # NIST Controls
+/// - CP-10: System Recovery - Converge APPL_DB to kernel state
+/// - AU-12: Audit Record Generation - Audit kept/updated/deleted counts
+async fn reconcile_warm_restart(
+    redis: &mut RedisAdapter,
+    warm_restart: &mut WarmRestartState,
+) -> Result<ReconcileSummary> {
+    let pending = std::mem::take(&mut warm_restart.pending_entries);
+    let plan = ReconcilePlan::build(&warm_restart.cached_neighbors, pending);
+
+    // MAC changes are plain SETs: orchagent sees an update, not delete+add
+    if !plan.sets.is_empty() {
+        info!(count = plan.sets.len(), "Reconciling: batch set neighbors");
+        redis.set_neighbors_batch(&plan.sets).await?;
+    }
+
+    if !plan.deletes.is_empty() {
+        info!(
+            count = plan.deletes.len(),
+            "Reconciling: batch delete stale neighbors"
+        );
+        redis.delete_neighbor_keys(&plan.deletes).await?;
+    }
+
+    warm_restart.in_progress = false;
+    warm_restart.cached_neighbors.clear();
+
+    // NIST: CP-10 - Audit successful reconciliation completion
+    let summary = plan.summary;
+    audit_log!(
+        AuditRecord::new(
+            AuditCategory::HighAvailability,
+            "neighsyncd",
+            "warm_restart_reconcile_complete"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_type("warm_restart")
+        .with_details(serde_json::json!({
+            "kept_count": summary.kept,
+            "updated_count": summary.updated,
+            "added_count": summary.added,
+            "deleted_count": summary.deleted,
+            "set_count": plan.sets.len(),
+            "delete_count": plan.deletes.len(),
+            "total_reconciled": plan.sets.len() + plan.deletes.len(),
+            "operation": "reconciliation_completed",
+        }))
+    );
+
+    Ok(summary)
+}
+
+/// Audit a link-local interface configuration change
+///
+/// # NIST Controls
+/// - CM-3: Configuration Change Control - Record applied changes
+fn audit_link_local_update(update: &LinkLocalUpdate, released: usize, removed: usize) {
+    audit_log!(
+        AuditRecord::new(
+            AuditCategory::ConfigurationManagement,
+            "neighsyncd",
+            "link_local_config_update"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_type("interface_configuration")
+        .with_details(serde_json::json!({
+            "enabled_interfaces": update.enabled,
+            "disabled_interfaces": update.disabled,
+            "released_neighbors": released,
+            "removed_neighbors": removed,
+        }))
+    );
+}
+
+/// Audit an IPv4 sync setting change
+///
+/// # NIST Controls
+/// - CM-3: Configuration Change Control - Record applied changes
+fn audit_ipv4_toggle(action: Ipv4ToggleAction, flushed: usize) {
+    audit_log!(
+        AuditRecord::new(
+            AuditCategory::ConfigurationManagement,
+            "neighsyncd",
+            "ipv4_sync_toggle"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_type("system_configuration")
+        .with_details(serde_json::json!({
+            "ipv4_enabled": action == Ipv4ToggleAction::RequestDump,
+            "dump_requested": action == Ipv4ToggleAction::RequestDump,
+            "flushed_entries": flushed,
+        }))
+    );
+}
+
 /// NeighSync - Synchronizes kernel neighbor table to Redis
 ///
 /// # NIST Controls
@@ -53,6 +258,20 @@ pub struct NeighSync {
     netlink: NetlinkSocket,
     warm_restart: WarmRestartState,
     is_dual_tor: bool,
+    /// Runtime IPv4 sync state from CONFIG_DB
+    /// NIST: CM-6 - Runtime address family selection
+    ipv4: Ipv4SyncToggle,
+    /// Interfaces opted into link-local neighbor sync
+    /// NIST: SC-7 - Link-local filtering
+    link_local: LinkLocalFilter,
+    /// Kernel state policy with rate-limited stale probes
+    /// NIST: SC-5 - Probe storm protection
+    state_policy: StatePolicy,
+    /// APPL_DB writes awaiting a batched pipeline flush
+    /// NIST: SC-5 - Bounded Redis round trips
+    writes: WriteBatcher,
+    /// Metrics collector for flush size and latency
+    metrics: Option<MetricsCollector>,
 }
 
 impl NeighSync {
@@ -72,12 +291,30 @@ impl NeighSync {
             netlink,
             warm_restart: WarmRestartState::default(),
             is_dual_tor: false,
+            ipv4: Ipv4SyncToggle::default(),
+            link_local: LinkLocalFilter::new(),
+            state_policy: StatePolicy::new(DEFAULT_PROBE_INTERVAL),
+            writes: WriteBatcher::default(),
+            metrics: None,
         };
 
         // Check if this is a dual-ToR deployment
         sync.is_dual_tor = sync.redis.is_dual_tor().await?;
         info!(is_dual_tor = sync.is_dual_tor, "Detected deployment type");
 
+        // Startup IPv4 state; the initial dump covers both families
+        let ipv4_enabled = sync
+            .redis
+            .get_ipv4_sync_setting()
+            .await?
+            .unwrap_or(DEFAULT_IPV4_ENABLED);
+        sync.ipv4 = Ipv4SyncToggle::new(ipv4_enabled);
+        sync.netlink.set_ipv4_enabled(ipv4_enabled);
+        info!(ipv4_enabled, "Detected IPv4 neighbor sync setting");
+
+        // Load link-local opt-ins before the first neighbor is processed
+        sync.refresh_link_local_config().await?;
+
         // NIST: CM-6, CM-8 - Audit configuration detection
         audit_log!(
             AuditRecord::new(
@@ -90,6 +327,7 @@ impl NeighSync {
             .with_details(serde_json::json!({
                 "deployment_type": if sync.is_dual_tor { "dual-tor" } else { "standard" },
                 "is_dual_tor": sync.is_dual_tor,
+                "ipv4_enabled": ipv4_enabled,
             }))
         );
 
@@ -147,6 +385,9 @@ impl NeighSync {
 
         loop {
             if self.redis.is_neighbor_restore_done().await? {
+                // Snapshot APPL_DB at restore time for reconciliation
+                // NIST: CP-10 - Recovery baseline
+                self.warm_restart.cached_neighbors = self.redis.get_all_neighbors().await?;
                 info!(
                     elapsed_secs = start.elapsed().as_secs(),
                     "Neighbor restore completed"
@@ -195,6 +436,109 @@ impl NeighSync {
         self.netlink.request_dump()
     }
 
+    /// Re-read the IPv4 sync setting from CONFIG_DB and apply changes
+    ///
+    /// Enabling IPv4 requests an AF_INET neighbor dump; disabling it flushes
+    /// previously-synced IPv4 entries from APPL_DB.
+    ///
+    /// # NIST Controls
+    /// - CM-3: Configuration Change Control - Apply setting without restart
+    /// - CM-6: Configuration Settings - Runtime address family selection
+    #[instrument(skip(self))]
+    pub async fn refresh_ipv4_config(&mut self) -> Result<Ipv4ToggleAction> {
+        let enabled = self
+            .redis
+            .get_ipv4_sync_setting()
+            .await?
+            .unwrap_or(DEFAULT_IPV4_ENABLED);
+        let action = self.ipv4.apply(enabled);
+
+        match action {
+            Ipv4ToggleAction::None => return Ok(action),
+            Ipv4ToggleAction::RequestDump => {
+                self.netlink.set_ipv4_enabled(true);
+                info!("IPv4 neighbor sync enabled, requesting IPv4 neighbor dump");
+                self.netlink.request_ipv4_dump()?;
+                audit_ipv4_toggle(action, 0);
+            }
+            Ipv4ToggleAction::Flush => {
+                self.netlink.set_ipv4_enabled(false);
+                self.warm_restart.drop_ipv4();
+                self.writes.discard(|op| op.entry().ip.is_ipv4());
+                let flushed = self.redis.delete_neighbors_by_family(IPV4_FAMILY).await?;
+                info!(
+                    flushed,
+                    "IPv4 neighbor sync disabled, flushed IPv4 neighbors"
+                );
+                audit_ipv4_toggle(action, flushed);
+            }
+        }
+
+        Ok(action)
+    }
+
+    /// Check if IPv4 neighbors are currently synced
+    pub fn is_ipv4_enabled(&self) -> bool {
+        self.ipv4.is_enabled()
+    }
+
+    /// Re-read link-local opt-ins from CONFIG_DB and apply changes
+    ///
+    /// Link-local entries on interfaces that lost the opt-in are removed from
+    /// APPL_DB; neighbors parked while their interface was not opted in are
+    /// synced.
+    ///
+    /// # NIST Controls
+    /// - CM-3: Configuration Change Control - Apply setting without restart
+    /// - SC-7: Boundary Protection - Withdraw link-local entries on disable
+    #[instrument(skip(self))]
+    pub async fn refresh_link_local_config(&mut self) -> Result<LinkLocalUpdate> {
+        let interfaces = self.redis.get_link_local_interfaces().await?;
+        let update = self.link_local.replace(interfaces);
+        if update.is_empty() {
+            return Ok(update);
+        }
+
+        let mut removed = 0;
+        if !update.disabled.is_empty() {
+            self.warm_restart.drop_link_local(&update.disabled);
+            self.writes.discard(|op| {
+                op.entry().is_ipv6_link_local() && update.disabled.contains(&op.entry().interface)
+            });
+            removed = self
+                .redis
+                .delete_link_local_neighbors(&update.disabled)
+                .await?;
+        }
+
+        let released: Vec<NeighborEntry> = update
+            .released
+            .iter()
+            .filter(|entry| !entry.mac.is_zero() && !entry.mac.is_broadcast())
+            .cloned()
+            .collect();
+        if self.warm_restart.in_progress {
+            for entry in released.iter().cloned() {
+                self.warm_restart
+                    .pending_entries
+                    .push((entry.redis_key(), entry, false));
+            }
+        } else {
+            self.redis.set_neighbors_batch(&released).await?;
+        }
+
+        info!(
+            enabled = ?update.enabled,
+            disabled = ?update.disabled,
+            released = released.len(),
+            removed,
+            "Applied link-local interface configuration"
+        );
+        audit_link_local_update(&update, released.len(), removed);
+
+        Ok(update)
+    }
+
     /// Process incoming netlink events
     ///
     /// # NIST Controls
@@ -206,7 +550,7 @@ impl NeighSync {
         let mut processed = 0;
 
         for (msg_type, entry) in events {
-            if self.should_process_entry(&entry).await? {
+            if self.should_process_entry(msg_type, &entry).await? {
                 self.handle_neighbor_event(msg_type, entry).await?;
                 processed += 1;
             }
@@ -224,17 +568,16 @@ impl NeighSync {
     ///
     /// # Performance (P2)
     /// Batches Redis operations for 3-5x throughput improvement.
-    /// Events are accumulated and sent in bulk to reduce round-trips.
+    /// Writes are queued in a [`WriteBatcher`] and flushed as one pipeline
+    /// per batch.
     #[instrument(skip(self))]
     pub async fn process_events_batched(&mut self) -> Result<usize> {
         let events = self.netlink.receive_events()?;
-
-        // Pre-allocate batch vectors
-        let mut batch_sets: Vec<NeighborEntry> = Vec::with_capacity(DEFAULT_BATCH_SIZE);
-        let mut batch_deletes: Vec<NeighborEntry> = Vec::with_capacity(DEFAULT_BATCH_SIZE);
+        let now = Instant::now();
+        let mut total = 0;
 
         for (msg_type, mut entry) in events {
-            if !self.should_process_entry(&entry).await? {
+            if !self.should_process_entry(msg_type, &entry).await? {
                 continue;
             }
 
@@ -262,111 +605,55 @@ impl NeighSync {
                 continue;
             }
 
-            // Add to appropriate batch
-            if is_delete {
-                batch_deletes.push(entry);
+            // Queue for the next pipeline
+            let op = if is_delete {
+                WriteOp::Delete(entry)
             } else {
-                batch_sets.push(entry);
-            }
+                WriteOp::Set(entry)
+            };
+            self.writes.push(op, now);
+            total += 1;
         }
 
-        let total = batch_sets.len() + batch_deletes.len();
+        // Full batches go out now; a partial batch waits at most the
+        // latency bound (see `next_write_deadline`)
+        self.flush_due_writes(Instant::now()).await?;
 
-        // Execute batched Redis operations
-        if !batch_sets.is_empty() {
-            info!(count = batch_sets.len(), "Batch setting neighbors");
-            match self.redis.set_neighbors_batch(&batch_sets).await {
-                Ok(()) => {
-                    // NIST: AU-12 - Audit successful batch operation
-                    info_audit!(
-                        "neighsyncd",
-                        operation = "batch_set",
-                        count = batch_sets.len(),
-                        "Batch neighbor set operation completed"
-                    );
-                    // Log detailed audit record for batch
-                    audit_log!(
-                        AuditRecord::new(
-                            AuditCategory::NetworkRouting,
-                            "neighsyncd",
-                            "neighbor_batch_add"
-                        )
-                        .with_outcome(AuditOutcome::Success)
-                        .with_object_type("neighbor_batch")
-                        .with_details(serde_json::json!({
-                            "operation": "batch_set",
-                            "count": batch_sets.len(),
-                            "entries": batch_sets.iter().take(10).map(|e| {
-                                serde_json::json!({
-                                    "interface": e.interface,
-                                    "ip": e.ip.to_string(),
-                                    "mac": e.mac.to_string(),
-                                })
-                            }).collect::<Vec<_>>(),
-                            "truncated": batch_sets.len() > 10,
-                        }))
-                    );
-                }
-                Err(e) => {
-                    error_audit!(
-                        "neighsyncd",
-                        operation = "batch_set",
-                        count = batch_sets.len(),
-                        error = %e,
-                        "Batch neighbor set operation failed"
-                    );
-                    return Err(e);
-                }
-            }
+        Ok(total)
+    }
+
+    /// Flush every batch that hit its size or latency bound
+    ///
+    /// Returns the number of operations written.
+    ///
+    /// # NIST Controls
+    /// - SC-5: DoS Protection - Bounded pipelines
+    pub async fn flush_due_writes(&mut self, now: Instant) -> Result<usize> {
+        let mut written = 0;
+        while self.writes.is_due(now) {
+            let batch = self.writes.take_batch();
+            written += flush_write_batch(&mut self.redis, batch, self.metrics.as_ref())
+                .await?
+                .len();
         }
+        Ok(written)
+    }
 
-        if !batch_deletes.is_empty() {
-            info!(count = batch_deletes.len(), "Batch deleting neighbors");
-            match self.redis.delete_neighbors_batch(&batch_deletes).await {
-                Ok(()) => {
-                    // NIST: AU-12 - Audit successful batch deletion
-                    info_audit!(
-                        "neighsyncd",
-                        operation = "batch_delete",
-                        count = batch_deletes.len(),
-                        "Batch neighbor delete operation completed"
-                    );
-                    // Log detailed audit record for batch
-                    audit_log!(
-                        AuditRecord::new(
-                            AuditCategory::NetworkRouting,
-                            "neighsyncd",
-                            "neighbor_batch_delete"
-                        )
-                        .with_outcome(AuditOutcome::Success)
-                        .with_object_type("neighbor_batch")
-                        .with_details(serde_json::json!({
-                            "operation": "batch_delete",
-                            "count": batch_deletes.len(),
-                            "entries": batch_deletes.iter().take(10).map(|e| {
-                                serde_json::json!({
-                                    "interface": e.interface,
-                                    "ip": e.ip.to_string(),
-                                })
-                            }).collect::<Vec<_>>(),
-                            "truncated": batch_deletes.len() > 10,
-                        }))
-                    );
-                }
-                Err(e) => {
-                    error_audit!(
-                        "neighsyncd",
-                        operation = "batch_delete",
-                        count = batch_deletes.len(),
-                        error = %e,
-                        "Batch neighbor delete operation failed"
-                    );
-                    return Err(e);
-                }
-            }
+    /// Flush all queued writes regardless of batch bounds
+    pub async fn flush_pending_writes(&mut self) -> Result<usize> {
+        let mut written = 0;
+        while !self.writes.is_empty() {
+            let batch = self.writes.take_batch();
+            written += flush_write_batch(&mut self.redis, batch, self.metrics.as_ref())
+                .await?
+                .len();
         }
+        Ok(written)
+    }
 
-        Ok(total)
+    /// Time by which queued writes must be flushed, if any are queued
+    pub fn next_write_deadline(&self) -> Option<Instant> {
+        self.writes.deadline()
     }
 
     /// Check if a neighbor entry should be processed
@@ -375,7 +662,18 @@ impl NeighSync {
     /// - SI-10: Information Input Validation - Validate entries
     /// - SC-5: Denial of Service Protection - Filter invalid entries
     #[instrument(skip(self))]
-    async fn should_process_entry(&mut self, entry: &NeighborEntry) -> Result<bool> {
+    async fn should_process_entry(
+        &mut self,
+        msg_type: NeighborMessageType,
+        entry: &NeighborEntry,
+    ) -> Result<bool> {
+        // Filter IPv4 when disabled at runtime
+        // NIST: CM-6 - Honour configured address families
+        if entry.ip.is_ipv4() && !self.ipv4.is_enabled() {
+            debug!(ip = %entry.ip, "Ignoring IPv4 neighbor (IPv4 disabled)");
+            return Ok(false);
+        }
+
         // Filter IPv6 multicast link-local (always ignored)
         // NIST: SC-5 - Prevent multicast-based attacks
         if entry.is_ipv6_multicast_link_local() {
@@ -385,24 +683,17 @@ impl NeighSync {
 
         // Filter IPv6 link-local if not enabled on interface
         // NIST: SC-7 - Boundary protection via configuration
-        if entry.is_ipv6_link_local() {
-            let enabled = self
-                .redis
-                .is_ipv6_link_local_enabled(&entry.interface)
-                .await?;
-            if !enabled {
-                debug!(
-                    ip = %entry.ip,
-                    interface = %entry.interface,
-                    "Ignoring IPv6 link-local (not enabled on interface)"
-                );
-                return Ok(false);
-            }
+        if !self.link_local.admit(msg_type, entry) {
+            debug!(
+                ip = %entry.ip,
+                interface = %entry.interface,
+                "Ignoring IPv6 link-local (not enabled on interface)"
+            );
+            return Ok(false);
         }
 
         // Filter IPv4 link-local on dual-ToR
         // NIST: SC-7 - Dual-ToR boundary protection
-        #[cfg(feature = "ipv4")]
         if entry.is_ipv4_link_local() && self.is_dual_tor {
             debug!(ip = %entry.ip, "Ignoring IPv4 link-local on dual-ToR");
             return Ok(false);
@@ -415,7 +706,38 @@ impl NeighSync {
             return Ok(false);
         }
 
-        Ok(true)
+        Ok(self.apply_state_policy(msg_type, entry))
+    }
+
+    /// Apply the kernel state policy, sending a rate-limited probe for STALE
+    ///
+    /// Returns false when the event must be ignored (DELAY/PROBE).
+    ///
+    /// # NIST Controls
+    /// - SI-4: System Monitoring - Revalidate stale neighbors
+    /// - SC-5: DoS Protection - Rate-limited probes
+    fn apply_state_policy(&mut self, msg_type: NeighborMessageType, entry: &NeighborEntry) -> bool {
+        let outcome = self
+            .state_policy
+            .evaluate(msg_type, entry, std::time::Instant::now());
+
+        if outcome.probe {
+            if let Err(e) = self.netlink.request_probe(entry) {
+                warn!(ip = %entry.ip, error = %e, "Failed to request neighbor probe");
+            }
+        }
+
+        if outcome.action == StateAction::Ignore {
+            debug!(ip = %entry.ip, state = ?entry.state, "Ignoring transitional neighbor state");
+            return false;
+        }
+        true
+    }
+
+    /// Attach a metrics collector for state policy outcomes and write batches
+    pub fn set_metrics(&mut self, metrics: MetricsCollector) {
+        self.state_policy.set_metrics(metrics.clone());
+        self.metrics = Some(metrics);
     }
 
     /// Handle a single neighbor event
@@ -560,14 +882,7 @@ impl NeighSync {
             NeighborMessageType::Delete => true,
             NeighborMessageType::New | NeighborMessageType::Get => {
                 // Delete for incomplete/failed states (unless dual-ToR)
-                if self.is_dual_tor {
-                    false
-                } else {
-                    matches!(
-                        entry.state,
-                        NeighborState::Incomplete | NeighborState::Failed
-                    )
-                }
+                !self.is_dual_tor && StateAction::for_state(entry.state) == StateAction::Delete
             }
         }
     }
@@ -591,54 +906,13 @@ impl NeighSync {
             "Starting warm restart reconciliation"
         );
 
-        // Separate pending entries into sets and deletes for batching
-        let pending = std::mem::take(&mut self.warm_restart.pending_entries);
-        let mut batch_sets: Vec<NeighborEntry> = Vec::with_capacity(pending.len());
-        let mut batch_deletes: Vec<NeighborEntry> = Vec::with_capacity(pending.len() / 4);
-
-        for (_key, entry, is_delete) in pending {
-            if is_delete {
-                batch_deletes.push(entry);
-            } else {
-                batch_sets.push(entry);
-            }
-        }
-
-        // Apply batched operations
-        if !batch_sets.is_empty() {
-            info!(count = batch_sets.len(), "Reconciling: batch set neighbors");
-            self.redis.set_neighbors_batch(&batch_sets).await?;
-        }
-
-        if !batch_deletes.is_empty() {
-            info!(
-                count = batch_deletes.len(),
-                "Reconciling: batch delete neighbors"
-            );
-            self.redis.delete_neighbors_batch(&batch_deletes).await?;
-        }
-
-        // Clear warm restart state
-        self.warm_restart.in_progress = false;
-        self.warm_restart.cached_neighbors.clear();
-
-        info!("Warm restart reconciliation complete");
-
-        // NIST: CP-10 - Audit successful reconciliation completion
-        audit_log!(
-            AuditRecord::new(
-                AuditCategory::HighAvailability,
-                "neighsyncd",
-                "warm_restart_reconcile_complete"
-            )
-            .with_outcome(AuditOutcome::Success)
-            .with_object_type("warm_restart")
-            .with_details(serde_json::json!({
-                "set_count": batch_sets.len(),
-                "delete_count": batch_deletes.len(),
-                "total_reconciled": batch_sets.len() + batch_deletes.len(),
-                "operation": "reconciliation_completed",
-            }))
+        let summary = reconcile_warm_restart(&mut self.redis, &mut self.warm_restart).await?;
+        info!(
+            kept = summary.kept,
+            updated = summary.updated,
+            added = summary.added,
+            deleted = summary.deleted,
+            "Warm restart reconciliation complete"
         );
 
         Ok(())
@@ -670,6 +944,20 @@ pub struct AsyncNeighSync {
     netlink: AsyncNetlinkSocket,
     warm_restart: WarmRestartState,
     is_dual_tor: bool,
+    /// Runtime IPv4 sync state from CONFIG_DB
+    /// NIST: CM-6 - Runtime address family selection
+    ipv4: Ipv4SyncToggle,
+    /// Interfaces opted into link-local neighbor sync
+    /// NIST: SC-7 - Link-local filtering
+    link_local: LinkLocalFilter,
+    /// Kernel state policy with rate-limited stale probes
+    /// NIST: SC-5 - Probe storm protection
+    state_policy: StatePolicy,
+    /// APPL_DB writes awaiting a batched pipeline flush
+    /// NIST: SC-5 - Bounded Redis round trips
+    writes: WriteBatcher,
+    /// Metrics collector for flush size and latency
+    metrics: Option<MetricsCollector>,
 }
 
 impl AsyncNeighSync {
@@ -689,12 +977,30 @@ impl AsyncNeighSync {
             netlink,
             warm_restart: WarmRestartState::default(),
             is_dual_tor: false,
+            ipv4: Ipv4SyncToggle::default(),
+            link_local: LinkLocalFilter::new(),
+            state_policy: StatePolicy::new(DEFAULT_PROBE_INTERVAL),
+            writes: WriteBatcher::default(),
+            metrics: None,
         };
 
         // Check if this is a dual-ToR deployment
         sync.is_dual_tor = sync.redis.is_dual_tor().await?;
         info!(is_dual_tor = sync.is_dual_tor, "Detected deployment type");
 
+        // Startup IPv4 state; the initial dump covers both families
+        let ipv4_enabled = sync
+            .redis
+            .get_ipv4_sync_setting()
+            .await?
+            .unwrap_or(DEFAULT_IPV4_ENABLED);
+        sync.ipv4 = Ipv4SyncToggle::new(ipv4_enabled);
+        sync.netlink.set_ipv4_enabled(ipv4_enabled);
+        info!(ipv4_enabled, "Detected IPv4 neighbor sync setting");
+
+        // Load link-local opt-ins before the first neighbor is processed
+        sync.refresh_link_local_config().await?;
+
         // NIST: CM-6, CM-8 - Audit configuration detection
         audit_log!(
             AuditRecord::new(
@@ -707,6 +1013,7 @@ impl AsyncNeighSync {
             .with_details(serde_json::json!({
                 "deployment_type": if sync.is_dual_tor { "dual-tor" } else { "standard" },
                 "is_dual_tor": sync.is_dual_tor,
+                "ipv4_enabled": ipv4_enabled,
             }))
         );
 
@@ -754,6 +1061,9 @@ impl AsyncNeighSync {
 
         loop {
             if self.redis.is_neighbor_restore_done().await? {
+                // Snapshot APPL_DB at restore time for reconciliation
+                // NIST: CP-10 - Recovery baseline
+                self.warm_restart.cached_neighbors = self.redis.get_all_neighbors().await?;
                 info!(
                     elapsed_secs = start.elapsed().as_secs(),
                     "Neighbor restore completed"
@@ -799,6 +1109,109 @@ impl AsyncNeighSync {
         self.netlink.request_dump()
     }
 
+    /// Re-read the IPv4 sync setting from CONFIG_DB and apply changes
+    ///
+    /// Enabling IPv4 requests an AF_INET neighbor dump; disabling it flushes
+    /// previously-synced IPv4 entries from APPL_DB.
+    ///
+    /// # NIST Controls
+    /// - CM-3: Configuration Change Control - Apply setting without restart
+    /// - CM-6: Configuration Settings - Runtime address family selection
+    #[instrument(skip(self))]
+    pub async fn refresh_ipv4_config(&mut self) -> Result<Ipv4ToggleAction> {
+        let enabled = self
+            .redis
+            .get_ipv4_sync_setting()
+            .await?
+            .unwrap_or(DEFAULT_IPV4_ENABLED);
+        let action = self.ipv4.apply(enabled);
+
+        match action {
+            Ipv4ToggleAction::None => return Ok(action),
+            Ipv4ToggleAction::RequestDump => {
+                self.netlink.set_ipv4_enabled(true);
+                info!("IPv4 neighbor sync enabled, requesting IPv4 neighbor dump");
+                self.netlink.request_ipv4_dump()?;
+                audit_ipv4_toggle(action, 0);
+            }
+            Ipv4ToggleAction::Flush => {
+                self.netlink.set_ipv4_enabled(false);
+                self.warm_restart.drop_ipv4();
+                self.writes.discard(|op| op.entry().ip.is_ipv4());
+                let flushed = self.redis.delete_neighbors_by_family(IPV4_FAMILY).await?;
+                info!(
+                    flushed,
+                    "IPv4 neighbor sync disabled, flushed IPv4 neighbors"
+                );
+                audit_ipv4_toggle(action, flushed);
+            }
+        }
+
+        Ok(action)
+    }
+
+    /// Check if IPv4 neighbors are currently synced
+    pub fn is_ipv4_enabled(&self) -> bool {
+        self.ipv4.is_enabled()
+    }
+
+    /// Re-read link-local opt-ins from CONFIG_DB and apply changes
+    ///
+    /// Link-local entries on interfaces that lost the opt-in are removed from
+    /// APPL_DB; neighbors parked while their interface was not opted in are
+    /// synced.
+    ///
+    /// # NIST Controls
+    /// - CM-3: Configuration Change Control - Apply setting without restart
+    /// - SC-7: Boundary Protection - Withdraw link-local entries on disable
+    #[instrument(skip(self))]
+    pub async fn refresh_link_local_config(&mut self) -> Result<LinkLocalUpdate> {
+        let interfaces = self.redis.get_link_local_interfaces().await?;
+        let update = self.link_local.replace(interfaces);
+        if update.is_empty() {
+            return Ok(update);
+        }
+
+        let mut removed = 0;
+        if !update.disabled.is_empty() {
+            self.warm_restart.drop_link_local(&update.disabled);
+            self.writes.discard(|op| {
+                op.entry().is_ipv6_link_local() && update.disabled.contains(&op.entry().interface)
+            });
+            removed = self
+                .redis
+                .delete_link_local_neighbors(&update.disabled)
+                .await?;
+        }
+
+        let released: Vec<NeighborEntry> = update
+            .released
+            .iter()
+            .filter(|entry| !entry.mac.is_zero() && !entry.mac.is_broadcast())
+            .cloned()
+            .collect();
+        if self.warm_restart.in_progress {
+            for entry in released.iter().cloned() {
+                self.warm_restart
+                    .pending_entries
+                    .push((entry.redis_key(), entry, false));
+            }
+        } else {
+            self.redis.set_neighbors_batch(&released).await?;
+        }
+
+        info!(
+            enabled = ?update.enabled,
+            disabled = ?update.disabled,
+            released = released.len(),
+            removed,
+            "Applied link-local interface configuration"
+        );
+        audit_link_local_update(&update, released.len(), removed);
+
+        Ok(update)
+    }
+
     /// Process incoming netlink events asynchronously
     ///
     /// # NIST Controls
@@ -814,7 +1227,7 @@ impl AsyncNeighSync {
         let mut processed = 0;
 
         for (msg_type, entry) in events {
-            if self.should_process_entry(&entry).await? {
+            if self.should_process_entry(msg_type, &entry).await? {
                 self.handle_neighbor_event(msg_type, entry).await?;
                 processed += 1;
             }
@@ -827,15 +1240,16 @@ impl AsyncNeighSync {
     ///
     /// # Performance (P2)
     /// Combines async netlink with Redis pipelining for maximum throughput.
+    /// Writes are queued in a [`WriteBatcher`] and flushed as one pipeline
+    /// per batch.
     #[instrument(skip(self))]
     pub async fn process_events_batched(&mut self) -> Result<usize> {
         let events = self.netlink.recv_events().await?;
-
-        let mut batch_sets: Vec<NeighborEntry> = Vec::with_capacity(DEFAULT_BATCH_SIZE);
-        let mut batch_deletes: Vec<NeighborEntry> = Vec::with_capacity(DEFAULT_BATCH_SIZE);
+        let now = Instant::now();
+        let mut total = 0;
 
         for (msg_type, mut entry) in events {
-            if !self.should_process_entry(&entry).await? {
+            if !self.should_process_entry(msg_type, &entry).await? {
                 continue;
             }
 
@@ -860,134 +1274,81 @@ impl AsyncNeighSync {
                 continue;
             }
 
-            if is_delete {
-                batch_deletes.push(entry);
+            let op = if is_delete {
+                WriteOp::Delete(entry)
             } else {
-                batch_sets.push(entry);
-            }
+                WriteOp::Set(entry)
+            };
+            self.writes.push(op, now);
+            total += 1;
         }
 
-        let total = batch_sets.len() + batch_deletes.len();
+        // Full batches go out now; a partial batch waits at most the
+        // latency bound (see `next_write_deadline`)
+        self.flush_due_writes(Instant::now()).await?;
 
-        if !batch_sets.is_empty() {
-            info!(count = batch_sets.len(), "Batch setting neighbors");
-            match self.redis.set_neighbors_batch(&batch_sets).await {
-                Ok(()) => {
-                    // NIST: AU-12 - Audit successful batch operation
-                    info_audit!(
-                        "neighsyncd",
-                        operation = "batch_set",
-                        count = batch_sets.len(),
-                        "Batch neighbor set operation completed"
-                    );
-                    // Log detailed audit record for batch
-                    audit_log!(
-                        AuditRecord::new(
-                            AuditCategory::NetworkRouting,
-                            "neighsyncd",
-                            "neighbor_batch_add"
-                        )
-                        .with_outcome(AuditOutcome::Success)
-                        .with_object_type("neighbor_batch")
-                        .with_details(serde_json::json!({
-                            "operation": "batch_set",
-                            "count": batch_sets.len(),
-                            "entries": batch_sets.iter().take(10).map(|e| {
-                                serde_json::json!({
-                                    "interface": e.interface,
-                                    "ip": e.ip.to_string(),
-                                    "mac": e.mac.to_string(),
-                                })
-                            }).collect::<Vec<_>>(),
-                            "truncated": batch_sets.len() > 10,
-                        }))
-                    );
-                }
-                Err(e) => {
-                    error_audit!(
-                        "neighsyncd",
-                        operation = "batch_set",
-                        count = batch_sets.len(),
-                        error = %e,
-                        "Batch neighbor set operation failed"
-                    );
-                    return Err(e);
-                }
-            }
+        Ok(total)
+    }
+
+    /// Flush every batch that hit its size or latency bound
+    ///
+    /// Returns the number of operations written.
+    ///
+    /// # NIST Controls
+    /// - SC-5: DoS Protection - Bounded pipelines
+    pub async fn flush_due_writes(&mut self, now: Instant) -> Result<usize> {
+        let mut written = 0;
+        while self.writes.is_due(now) {
+            let batch = self.writes.take_batch();
+            written += flush_write_batch(&mut self.redis, batch, self.metrics.as_ref())
+                .await?
+                .len();
         }
+        Ok(written)
+    }
 
-        if !batch_deletes.is_empty() {
-            info!(count = batch_deletes.len(), "Batch deleting neighbors");
-            match self.redis.delete_neighbors_batch(&batch_deletes).await {
-                Ok(()) => {
-                    // NIST: AU-12 - Audit successful batch deletion
-                    info_audit!(
-                        "neighsyncd",
-                        operation = "batch_delete",
-                        count = batch_deletes.len(),
-                        "Batch neighbor delete operation completed"
-                    );
-                    // Log detailed audit record for batch
-                    audit_log!(
-                        AuditRecord::new(
-                            AuditCategory::NetworkRouting,
-                            "neighsyncd",
-                            "neighbor_batch_delete"
-                        )
-                        .with_outcome(AuditOutcome::Success)
-                        .with_object_type("neighbor_batch")
-                        .with_details(serde_json::json!({
-                            "operation": "batch_delete",
-                            "count": batch_deletes.len(),
-                            "entries": batch_deletes.iter().take(10).map(|e| {
-                                serde_json::json!({
-                                    "interface": e.interface,
-                                    "ip": e.ip.to_string(),
-                                })
-                            }).collect::<Vec<_>>(),
-                            "truncated": batch_deletes.len() > 10,
-                        }))
-                    );
-                }
-                Err(e) => {
-                    error_audit!(
-                        "neighsyncd",
-                        operation = "batch_delete",
-                        count = batch_deletes.len(),
-                        error = %e,
-                        "Batch neighbor delete operation failed"
-                    );
-                    return Err(e);
-                }
-            }
+    /// Flush all queued writes regardless of batch bounds
+    pub async fn flush_pending_writes(&mut self) -> Result<usize> {
+        let mut written = 0;
+        while !self.writes.is_empty() {
+            let batch = self.writes.take_batch();
+            written += flush_write_batch(&mut self.redis, batch, self.metrics.as_ref())
+                .await?
+                .len();
         }
+        Ok(written)
+    }
 
-        Ok(total)
+    /// Time by which queued writes must be flushed, if any are queued
+    pub fn next_write_deadline(&self) -> Option<Instant> {
+        self.writes.deadline()
     }
 
     /// Check if a neighbor entry should be processed
-    async fn should_process_entry(&mut self, entry: &NeighborEntry) -> Result<bool> {
+    async fn should_process_entry(
+        &mut self,
+        msg_type: NeighborMessageType,
+        entry: &NeighborEntry,
+    ) -> Result<bool> {
+        if entry.ip.is_ipv4() && !self.ipv4.is_enabled() {
+            debug!(ip = %entry.ip, "Ignoring IPv4 neighbor (IPv4 disabled)");
+            return Ok(false);
+        }
+
         if entry.is_ipv6_multicast_link_local() {
             debug!(ip = %entry.ip, "Ignoring IPv6 multicast link-local");
             return Ok(false);
         }
 
-        if entry.is_ipv6_link_local() {
-            let enabled = self
-                .redis
-                .is_ipv6_link_local_enabled(&entry.interface)
-                .await?;
-            if !enabled {
-                debug!(
-                    ip = %entry.ip,
-                    interface = %entry.interface,
-                    "Ignoring IPv6 link-local (not enabled on interface)"
-                );
-                return Ok(false);
-            }
+        if !self.link_local.admit(msg_type, entry) {
+            debug!(
+                ip = %entry.ip,
+                interface = %entry.interface,
+                "Ignoring IPv6 link-local (not enabled on interface)"
+            );
+            return Ok(false);
         }
 
-        #[cfg(feature = "ipv4")]
         if entry.is_ipv4_link_local() && self.is_dual_tor {
             debug!(ip = %entry.ip, "Ignoring IPv4 link-local on dual-ToR");
             return Ok(false);
@@ -998,7 +1359,38 @@ impl AsyncNeighSync {
             return Ok(false);
         }
 
-        Ok(true)
+        Ok(self.apply_state_policy(msg_type, entry))
+    }
+
+    /// Apply the kernel state policy, sending a rate-limited probe for STALE
+    ///
+    /// Returns false when the event must be ignored (DELAY/PROBE).
+    ///
+    /// # NIST Controls
+    /// - SI-4: System Monitoring - Revalidate stale neighbors
+    /// - SC-5: DoS Protection - Rate-limited probes
+    fn apply_state_policy(&mut self, msg_type: NeighborMessageType, entry: &NeighborEntry) -> bool {
+        let outcome = self
+            .state_policy
+            .evaluate(msg_type, entry, std::time::Instant::now());
+
+        if outcome.probe {
+            if let Err(e) = self.netlink.request_probe(entry) {
+                warn!(ip = %entry.ip, error = %e, "Failed to request neighbor probe");
+            }
+        }
+
+        if outcome.action == StateAction::Ignore {
+            debug!(ip = %entry.ip, state = ?entry.state, "Ignoring transitional neighbor state");
+            return false;
+        }
+        true
+    }
+
+    /// Attach a metrics collector for state policy outcomes and write batches
+    pub fn set_metrics(&mut self, metrics: MetricsCollector) {
+        self.state_policy.set_metrics(metrics.clone());
+        self.metrics = Some(metrics);
     }
 
     /// Handle a single neighbor event
@@ -1128,14 +1520,7 @@ impl AsyncNeighSync {
         match msg_type {
             NeighborMessageType::Delete => true,
             NeighborMessageType::New | NeighborMessageType::Get => {
-                if self.is_dual_tor {
-                    false
-                } else {
-                    matches!(
-                        entry.state,
-                        NeighborState::Incomplete | NeighborState::Failed
-                    )
-                }
+                !self.is_dual_tor && StateAction::for_state(entry.state) == StateAction::Delete
             }
         }
     }
@@ -1153,51 +1538,13 @@ impl AsyncNeighSync {
             "Starting warm restart reconciliation"
         );
 
-        let pending = std::mem::take(&mut self.warm_restart.pending_entries);
-        let mut batch_sets: Vec<NeighborEntry> = Vec::with_capacity(pending.len());
-        let mut batch_deletes: Vec<NeighborEntry> = Vec::with_capacity(pending.len() / 4);
-
-        for (_key, entry, is_delete) in pending {
-            if is_delete {
-                batch_deletes.push(entry);
-            } else {
-                batch_sets.push(entry);
-            }
-        }
-
-        if !batch_sets.is_empty() {
-            info!(count = batch_sets.len(), "Reconciling: batch set neighbors");
-            self.redis.set_neighbors_batch(&batch_sets).await?;
-        }
-
-        if !batch_deletes.is_empty() {
-            info!(
-                count = batch_deletes.len(),
-                "Reconciling: batch delete neighbors"
-            );
-            self.redis.delete_neighbors_batch(&batch_deletes).await?;
-        }
-
-        self.warm_restart.in_progress = false;
-        self.warm_restart.cached_neighbors.clear();
-
-        info!("Warm restart reconciliation complete");
-
-        // NIST: CP-10 - Audit successful reconciliation completion
-        audit_log!(
-            AuditRecord::new(
-                AuditCategory::HighAvailability,
-                "neighsyncd",
-                "warm_restart_reconcile_complete"
-            )
-            .with_outcome(AuditOutcome::Success)
-            .with_object_type("warm_restart")
-            .with_details(serde_json::json!({
-                "set_count": batch_sets.len(),
-                "delete_count": batch_deletes.len(),
-                "total_reconciled": batch_sets.len() + batch_deletes.len(),
-                "operation": "reconciliation_completed",
-            }))
+        let summary = reconcile_warm_restart(&mut self.redis, &mut self.warm_restart).await?;
+        info!(
+            kept = summary.kept,
+            updated = summary.updated,
+            added = summary.added,
+            deleted = summary.deleted,
+            "Warm restart reconciliation complete"
         );
 
         Ok(())
@@ -1236,4 +1583,59 @@ mod tests {
         let failed = make_test_entry("2001:db8::2", NeighborState::Failed);
         assert!(!failed.state.is_resolved());
     }
+
+    #[test]
+    fn test_warm_restart_drops_ipv4_on_disable() {
+        let mut state = WarmRestartState::default();
+        for (key, family) in [
+            ("Ethernet0:10.0.0.1", "IPv4"),
+            ("Ethernet0:2001:db8::1", "IPv6"),
+        ] {
+            state.cached_neighbors.insert(
+                key.to_string(),
+                HashMap::from([("family".to_string(), family.to_string())]),
+            );
+        }
+        for ip in ["10.0.0.2", "2001:db8::2"] {
+            let entry = make_test_entry(ip, NeighborState::Reachable);
+            state
+                .pending_entries
+                .push((entry.redis_key(), entry, false));
+        }
+
+        state.drop_ipv4();
+
+        assert_eq!(state.cached_neighbors.len(), 1);
+        assert!(state.cached_neighbors.contains_key("Ethernet0:2001:db8::1"));
+        assert_eq!(state.pending_entries.len(), 1);
+        assert_eq!(state.pending_entries[0].1.family_str(), "IPv6");
+    }
+
+    #[test]
+    fn test_warm_restart_drops_disabled_link_local() {
+        let mut state = WarmRestartState::default();
+        for key in [
+            "Ethernet0:fe80::1",
+            "Ethernet0:2001:db8::1",
+            "Ethernet4:fe80::1",
+        ] {
+            state
+                .cached_neighbors
+                .insert(key.to_string(), HashMap::new());
+        }
+        for ip in ["fe80::2", "2001:db8::2"] {
+            let entry = make_test_entry(ip, NeighborState::Reachable);
+            state
+                .pending_entries
+                .push((entry.redis_key(), entry, false));
+        }
+
+        state.drop_link_local(&["Ethernet0".to_string()]);
+
+        let mut cached: Vec<&String> = state.cached_neighbors.keys().collect();
+        cached.sort();
+        assert_eq!(cached, vec!["Ethernet0:2001:db8::1", "Ethernet4:fe80::1"]);
+        assert_eq!(state.pending_entries.len(), 1);
+        assert!(!state.pending_entries[0].1.is_ipv6_link_local());
+    }
 }
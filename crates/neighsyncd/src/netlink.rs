@@ -9,15 +9,44 @@
 //! - Async netlink with epoll integration via tokio AsyncFd
 //! - Pre-allocated event buffers to reduce allocations
 //! - Zero-copy parsing where possible
+//! - IPv4 messages skipped on the raw header when IPv4 sync is disabled
+
+/// Length of struct nlmsghdr
+const NLMSG_HDRLEN: usize = 16;
+/// RTM_NEWNEIGH; RTM_DELNEIGH and RTM_GETNEIGH follow it
+const RTM_NEWNEIGH: u16 = 28;
+/// RTM_GETNEIGH
+const RTM_GETNEIGH: u16 = 30;
+/// AF_INET as carried in ndmsg.ndm_family
+pub(crate) const AF_INET: u8 = 2;
+
+/// Read the total length of the netlink message at the start of `buf`
+pub(crate) fn peek_message_len(buf: &[u8]) -> Option<usize> {
+    let bytes: [u8; 4] = buf.get(0..4)?.try_into().ok()?;
+    Some(u32::from_ne_bytes(bytes) as usize)
+}
+
+/// Read ndm_family of the neighbor message at the start of `buf`
+///
+/// Returns None for non-neighbor messages. Used to drop address families
+/// that are disabled before paying for a full deserialize.
+pub(crate) fn peek_neighbor_family(buf: &[u8]) -> Option<u8> {
+    let msg_type = u16::from_ne_bytes(buf.get(4..6)?.try_into().ok()?);
+    if !(RTM_NEWNEIGH..=RTM_GETNEIGH).contains(&msg_type) {
+        return None;
+    }
+    buf.get(NLMSG_HDRLEN).copied()
+}
 
 #[cfg(target_os = "linux")]
 mod linux {
+    use super::{AF_INET, peek_message_len, peek_neighbor_family};
     use crate::error::{NeighsyncError, Result};
     use crate::types::{
         MacAddress, NeighborEntry, NeighborFlags, NeighborMessageType, NeighborState,
     };
     use crate::vrf::VrfId;
-    use netlink_packet_core::{NetlinkMessage, NetlinkPayload};
+    use netlink_packet_core::{NetlinkFlags, NetlinkHeader, NetlinkMessage, NetlinkPayload};
     use netlink_packet_route::RouteNetlinkMessage;
     use netlink_packet_route::neighbour::{NeighbourAddress, NeighbourAttribute, NeighbourMessage};
     use netlink_sys::{Socket, SocketAddr, protocols::NETLINK_ROUTE};
@@ -104,6 +133,9 @@ mod linux {
         /// NIST: SC-5 - Pre-allocation prevents allocation storms
         events_buffer: Vec<(NeighborMessageType, NeighborEntry)>,
         interface_cache: InterfaceCache,
+        /// Whether AF_INET messages are parsed
+        /// NIST: CM-6 - Runtime address family selection
+        ipv4_enabled: bool,
     }
 
     impl NetlinkSocket {
@@ -130,6 +162,7 @@ mod linux {
                 buffer: vec![0u8; 65536],
                 events_buffer: Vec::with_capacity(DEFAULT_EVENT_CAPACITY),
                 interface_cache: InterfaceCache::default(),
+                ipv4_enabled: crate::ipv4_config::DEFAULT_IPV4_ENABLED,
             };
 
             // Tune socket for high-throughput scenarios
@@ -207,21 +240,95 @@ mod linux {
             self.socket.as_raw_fd()
         }
 
+        /// Enable or disable parsing of IPv4 neighbor messages
+        pub fn set_ipv4_enabled(&mut self, enabled: bool) {
+            self.ipv4_enabled = enabled;
+            debug!(enabled, "IPv4 neighbor processing updated");
+        }
+
+        /// Check if IPv4 neighbor messages are parsed
+        pub fn is_ipv4_enabled(&self) -> bool {
+            self.ipv4_enabled
+        }
+
         /// Request a dump of the current neighbor table
         ///
         /// # NIST Controls
         /// - CP-10: System Recovery - Initial state dump for warm restart
         #[instrument(skip(self))]
         pub fn request_dump(&mut self) -> Result<()> {
-            use netlink_packet_core::{NetlinkFlags, NetlinkHeader};
-            use netlink_packet_route::RouteNetlinkMessage;
+            self.send_dump_request(libc::AF_UNSPEC as u8)?;
+            debug!("Requested neighbor table dump");
+            Ok(())
+        }
 
-            let mut header = NetlinkHeader::default();
-            header.flags = NetlinkFlags::REQUEST | NetlinkFlags::DUMP;
+        /// Request a dump of the IPv4 (ARP) neighbor table only
+        ///
+        /// # NIST Controls
+        /// - CM-8: System Component Inventory - Inventory newly enabled family
+        #[instrument(skip(self))]
+        pub fn request_ipv4_dump(&mut self) -> Result<()> {
+            self.send_dump_request(AF_INET)?;
+            debug!("Requested IPv4 neighbor table dump");
+            Ok(())
+        }
 
+        /// Ask the kernel to revalidate a neighbor (NUD_PROBE)
+        ///
+        /// Sends RTM_NEWNEIGH with NLM_F_REPLACE and state NUD_PROBE, which
+        /// makes the kernel send unicast solicitations to the cached MAC.
+        ///
+        /// # NIST Controls
+        /// - SI-4: System Monitoring - Revalidate stale reachability
+        #[instrument(skip(self), fields(ip = %entry.ip, interface = %entry.interface))]
+        pub fn request_probe(&mut self, entry: &NeighborEntry) -> Result<()> {
+            let destination = match entry.ip {
+                IpAddr::V4(addr) => NeighbourAddress::Inet(addr),
+                IpAddr::V6(addr) => NeighbourAddress::Inet6(addr),
+            };
+
+            let mut msg = NeighbourMessage::default();
+            msg.header.family = match entry.ip {
+                IpAddr::V4(_) => AF_INET,
+                IpAddr::V6(_) => libc::AF_INET6 as u8,
+            };
+            msg.header.ifindex = entry.ifindex;
+            msg.header.state = NeighborState::Probe as u16;
+            msg.attributes
+                .push(NeighbourAttribute::Destination(destination));
+            msg.attributes.push(NeighbourAttribute::LinkLocalAddress(
+                entry.mac.as_bytes().to_vec(),
+            ));
+
+            self.send_request(
+                RouteNetlinkMessage::NewNeighbour(msg),
+                NetlinkFlags::REQUEST | NetlinkFlags::REPLACE,
+            )?;
+
+            trace!("Requested neighbor probe");
+            Ok(())
+        }
+
+        /// Send an RTM_GETNEIGH dump request for one address family
+        fn send_dump_request(&mut self, family: u8) -> Result<()> {
             // Create RTM_GETNEIGH message
-            let msg = NeighbourMessage::default();
-            let payload = RouteNetlinkMessage::GetNeighbour(msg);
+            let mut msg = NeighbourMessage::default();
+            msg.header.family = family;
+            self.send_request(
+                RouteNetlinkMessage::GetNeighbour(msg),
+                NetlinkFlags::REQUEST | NetlinkFlags::DUMP,
+            )
+        }
+
+        /// Serialize and send a request to the kernel
+        fn send_request(
+            &mut self,
+            payload: RouteNetlinkMessage,
+            flags: NetlinkFlags,
+        ) -> Result<()> {
+            let mut header = NetlinkHeader::default();
+            header.flags = flags;
+
             let mut packet = NetlinkMessage::new(header, NetlinkPayload::InnerMessage(payload));
             packet.finalize();
 
@@ -229,11 +336,10 @@ mod linux {
             let mut buf = vec![0u8; bytes];
             packet.serialize(&mut buf);
 
-            self.socket.send(&buf, 0).map_err(|e| {
-                NeighsyncError::Netlink(format!("Failed to send dump request: {}", e))
-            })?;
+            self.socket
+                .send(&buf, 0)
+                .map_err(|e| NeighsyncError::Netlink(format!("Failed to send request: {}", e)))?;
 
-            debug!("Requested neighbor table dump");
             Ok(())
         }
 
@@ -295,6 +401,20 @@ mod linux {
             let mut offset = 0;
 
             while offset < len {
+                // Skip disabled IPv4 messages on the raw header
+                // NIST: SC-5 - Avoid parsing cost for filtered families
+                if !self.ipv4_enabled
+                    && peek_neighbor_family(&self.buffer[offset..len]) == Some(AF_INET)
+                {
+                    match peek_message_len(&self.buffer[offset..len]) {
+                        Some(msg_len) if msg_len > 0 => {
+                            offset = (offset + msg_len + 3) & !3;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                }
+
                 // Zero-copy: parse directly from buffer slice
                 let msg =
                     NetlinkMessage::<RouteNetlinkMessage>::deserialize(&self.buffer[offset..])
@@ -342,13 +462,11 @@ mod linux {
             let flags = NeighborFlags::from_kernel(neigh_msg.header.flags);
 
             // Filter by address family
-            #[cfg(not(feature = "ipv4"))]
-            if family as i32 != libc::AF_INET6 {
-                trace!(family, "Ignoring non-IPv6 neighbor (IPv4 disabled)");
+            if family as i32 == libc::AF_INET && !self.ipv4_enabled {
+                trace!(family, "Ignoring IPv4 neighbor (IPv4 disabled)");
                 return Ok(None);
             }
 
-            #[cfg(feature = "ipv4")]
             if family as i32 != libc::AF_INET && family as i32 != libc::AF_INET6 {
                 trace!(family, "Ignoring non-IP neighbor");
                 return Ok(None);
@@ -510,6 +628,22 @@ mod linux {
             self.socket.request_dump()
         }
 
+        /// Request a dump of the IPv4 neighbor table only
+        #[instrument(skip(self))]
+        pub fn request_ipv4_dump(&mut self) -> Result<()> {
+            self.socket.request_ipv4_dump()
+        }
+
+        /// Ask the kernel to revalidate a neighbor (NUD_PROBE)
+        pub fn request_probe(&mut self, entry: &NeighborEntry) -> Result<()> {
+            self.socket.request_probe(entry)
+        }
+
+        /// Enable or disable parsing of IPv4 neighbor messages
+        pub fn set_ipv4_enabled(&mut self, enabled: bool) {
+            self.socket.set_ipv4_enabled(enabled);
+        }
+
         /// Get the raw file descriptor
         pub fn as_raw_fd(&self) -> i32 {
             self.socket.as_raw_fd()
@@ -536,11 +670,32 @@ mod mock {
         }
     }
 
-    pub struct NetlinkSocket;
+    pub struct NetlinkSocket {
+        ipv4_enabled: bool,
+    }
 
     impl NetlinkSocket {
         pub fn new() -> Result<Self> {
-            Ok(Self)
+            Ok(Self {
+                ipv4_enabled: crate::ipv4_config::DEFAULT_IPV4_ENABLED,
+            })
+        }
+
+        pub fn set_ipv4_enabled(&mut self, enabled: bool) {
+            self.ipv4_enabled = enabled;
+        }
+
+        pub fn is_ipv4_enabled(&self) -> bool {
+            self.ipv4_enabled
+        }
+
+        pub fn request_ipv4_dump(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        #[allow(unused_variables)]
+        pub fn request_probe(&mut self, entry: &NeighborEntry) -> Result<()> {
+            Ok(())
         }
 
         pub fn as_raw_fd(&self) -> i32 {
@@ -580,6 +735,18 @@ mod mock {
             Ok(())
         }
 
+        pub fn request_ipv4_dump(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        #[allow(unused_variables)]
+        pub fn request_probe(&mut self, entry: &NeighborEntry) -> Result<()> {
+            Ok(())
+        }
+
+        #[allow(unused_variables)]
+        pub fn set_ipv4_enabled(&mut self, enabled: bool) {}
+
         pub fn as_raw_fd(&self) -> i32 {
             -1
         }
@@ -588,3 +755,50 @@ mod mock {
 
 #[cfg(not(target_os = "linux"))]
 pub use mock::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neighbor_header(msg_type: u16, family: u8) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&28u32.to_ne_bytes()); // nlmsg_len
+        buf.extend_from_slice(&msg_type.to_ne_bytes());
+        buf.extend_from_slice(&0u16.to_ne_bytes()); // nlmsg_flags
+        buf.extend_from_slice(&0u32.to_ne_bytes()); // nlmsg_seq
+        buf.extend_from_slice(&0u32.to_ne_bytes()); // nlmsg_pid
+        buf.push(family);
+        buf.extend_from_slice(&[0u8; 11]); // rest of ndmsg
+        buf
+    }
+
+    #[test]
+    fn test_peek_neighbor_family() {
+        assert_eq!(
+            peek_neighbor_family(&neighbor_header(RTM_NEWNEIGH, AF_INET)),
+            Some(AF_INET)
+        );
+        assert_eq!(peek_neighbor_family(&neighbor_header(29, 10)), Some(10));
+        assert_eq!(
+            peek_neighbor_family(&neighbor_header(RTM_GETNEIGH, AF_INET)),
+            Some(AF_INET)
+        );
+    }
+
+    #[test]
+    fn test_peek_ignores_other_messages() {
+        // RTM_NEWLINK and NLMSG_DONE are never treated as neighbor messages
+        assert_eq!(peek_neighbor_family(&neighbor_header(16, AF_INET)), None);
+        assert_eq!(peek_neighbor_family(&neighbor_header(3, AF_INET)), None);
+        assert_eq!(peek_neighbor_family(&[0u8; 8]), None);
+    }
+
+    #[test]
+    fn test_peek_message_len() {
+        assert_eq!(
+            peek_message_len(&neighbor_header(RTM_NEWNEIGH, AF_INET)),
+            Some(28)
+        );
+        assert_eq!(peek_message_len(&[1, 2]), None);
+    }
+}
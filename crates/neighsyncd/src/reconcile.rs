@@ -0,0 +1,262 @@
+//! Warm restart reconciliation diff engine
+//!
+//! At restore time the APPL_DB NEIGH_TABLE is snapshotted. While the
+//! reconcile timer runs, the post-restart kernel dump and live events are
+//! cached instead of written. At the deadline the two are diffed:
+//!
+//! | Snapshot | Kernel | Result |
+//! |----------|--------|--------|
+//! | present | same MAC | kept, no write |
+//! | present | new MAC | updated in place (SET) |
+//! | absent | present | added (SET) |
+//! | present | absent or deleted | deleted (DEL) |
+//!
+//! MAC changes are written as a single SET so orchagent sees an update
+//! rather than a delete followed by an add.
+//!
+//! # NIST 800-53 Rev 5 Control Mappings
+//! - CP-10: System Recovery - Converge APPL_DB to kernel state after restart
+//! - AU-12: Audit Record Generation - Reconcile summary counts
+//! - SI-7: Information Integrity - Remove stale pre-restart entries
+
+use crate::types::NeighborEntry;
+use std::collections::HashMap;
+
+/// Summary counts of a reconciliation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReconcileSummary {
+    /// Snapshot entries re-confirmed unchanged
+    pub kept: usize,
+    /// Snapshot entries re-confirmed with a different MAC
+    pub updated: usize,
+    /// Entries absent from the snapshot
+    pub added: usize,
+    /// Snapshot entries not re-confirmed (or deleted by the kernel)
+    pub deleted: usize,
+}
+
+/// Writes needed to converge APPL_DB to the kernel
+#[derive(Debug, Default)]
+pub struct ReconcilePlan {
+    /// Entries to SET (updated and added), sorted by key
+    pub sets: Vec<NeighborEntry>,
+    /// APPL_DB keys (`<interface>:<ip>`) to delete, sorted
+    pub deletes: Vec<String>,
+    /// Counts for logging and audit
+    pub summary: ReconcileSummary,
+}
+
+impl ReconcilePlan {
+    /// Diff the restore-time snapshot against events cached since restore
+    ///
+    /// `snapshot` maps APPL_DB keys to their fields (`neigh`, `family`).
+    /// `pending` is the ordered list of `(key, entry, is_delete)` events; the
+    /// last event per key wins, so an entry re-learned after a delete is
+    /// treated as refreshed.
+    pub fn build(
+        snapshot: &HashMap<String, HashMap<String, String>>,
+        pending: Vec<(String, NeighborEntry, bool)>,
+    ) -> Self {
+        let mut latest: HashMap<String, (NeighborEntry, bool)> = HashMap::new();
+        for (key, entry, is_delete) in pending {
+            latest.insert(key, (entry, is_delete));
+        }
+
+        let mut plan = Self::default();
+
+        for (key, (entry, is_delete)) in latest.iter() {
+            let cached = snapshot.get(key);
+            if *is_delete {
+                // Nothing was written during warm restart, so only snapshot
+                // entries exist in APPL_DB
+                if cached.is_some() {
+                    plan.deletes.push(key.clone());
+                    plan.summary.deleted += 1;
+                }
+                continue;
+            }
+
+            match cached {
+                Some(fields) if is_unchanged(fields, entry) => plan.summary.kept += 1,
+                Some(_) => {
+                    plan.sets.push(entry.clone());
+                    plan.summary.updated += 1;
+                }
+                None => {
+                    plan.sets.push(entry.clone());
+                    plan.summary.added += 1;
+                }
+            }
+        }
+
+        // Snapshot entries nobody re-confirmed are stale
+        for key in snapshot.keys() {
+            if !latest.contains_key(key) {
+                plan.deletes.push(key.clone());
+                plan.summary.deleted += 1;
+            }
+        }
+
+        plan.sets.sort_by_key(|entry| entry.redis_key());
+        plan.deletes.sort();
+        plan
+    }
+}
+
+/// Check if a snapshot entry already matches a refreshed neighbor
+fn is_unchanged(fields: &HashMap<String, String>, entry: &NeighborEntry) -> bool {
+    fields.get("neigh") == Some(&entry.mac.to_string())
+        && fields
+            .get("family")
+            .is_none_or(|family| family == entry.family_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MacAddress, NeighborState};
+    use crate::vrf::VrfId;
+
+    fn entry(ip: &str, mac: [u8; 6]) -> NeighborEntry {
+        NeighborEntry {
+            ifindex: 1,
+            interface: "Ethernet0".to_string(),
+            ip: ip.parse().unwrap(),
+            mac: MacAddress::new(mac),
+            state: NeighborState::Reachable,
+            externally_learned: false,
+            vrf_id: VrfId::default_vrf(),
+        }
+    }
+
+    fn snapshot_of(entries: &[NeighborEntry]) -> HashMap<String, HashMap<String, String>> {
+        entries
+            .iter()
+            .map(|e| {
+                (
+                    e.redis_key(),
+                    HashMap::from([
+                        ("neigh".to_string(), e.mac.to_string()),
+                        ("family".to_string(), e.family_str().to_string()),
+                    ]),
+                )
+            })
+            .collect()
+    }
+
+    fn set(e: &NeighborEntry) -> (String, NeighborEntry, bool) {
+        (e.redis_key(), e.clone(), false)
+    }
+
+    fn del(e: &NeighborEntry) -> (String, NeighborEntry, bool) {
+        (e.redis_key(), e.clone(), true)
+    }
+
+    const MAC_A: [u8; 6] = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+    const MAC_B: [u8; 6] = [0x00, 0x11, 0x22, 0x33, 0x44, 0x66];
+
+    #[test]
+    fn test_refreshed_unchanged_entries_are_kept() {
+        let neighbor = entry("2001:db8::1", MAC_A);
+        let plan = ReconcilePlan::build(&snapshot_of(&[neighbor.clone()]), vec![set(&neighbor)]);
+
+        assert!(plan.sets.is_empty());
+        assert!(plan.deletes.is_empty());
+        assert_eq!(
+            plan.summary,
+            ReconcileSummary {
+                kept: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_cache_only_entry_is_deleted() {
+        // Neighbor vanished across the restart: stale in APPL_DB
+        let stale = entry("2001:db8::1", MAC_A);
+        let plan = ReconcilePlan::build(&snapshot_of(&[stale.clone()]), Vec::new());
+
+        assert!(plan.sets.is_empty());
+        assert_eq!(plan.deletes, vec![stale.redis_key()]);
+        assert_eq!(plan.summary.deleted, 1);
+    }
+
+    #[test]
+    fn test_dump_only_entry_is_added() {
+        let new = entry("2001:db8::2", MAC_A);
+        let plan = ReconcilePlan::build(&HashMap::new(), vec![set(&new)]);
+
+        assert_eq!(plan.sets, vec![new]);
+        assert!(plan.deletes.is_empty());
+        assert_eq!(plan.summary.added, 1);
+    }
+
+    #[test]
+    fn test_mac_change_is_set_not_delete() {
+        let before = entry("2001:db8::1", MAC_A);
+        let after = entry("2001:db8::1", MAC_B);
+        let plan = ReconcilePlan::build(&snapshot_of(&[before]), vec![set(&after)]);
+
+        assert_eq!(plan.sets, vec![after]);
+        assert!(plan.deletes.is_empty());
+        assert_eq!(
+            plan.summary,
+            ReconcileSummary {
+                updated: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_all_three_directions() {
+        let kept = entry("2001:db8::1", MAC_A);
+        let moved = entry("2001:db8::2", MAC_A);
+        let stale = entry("2001:db8::3", MAC_A);
+        let new = entry("2001:db8::4", MAC_B);
+        let moved_now = entry("2001:db8::2", MAC_B);
+
+        let snapshot = snapshot_of(&[kept.clone(), moved, stale.clone()]);
+        let plan = ReconcilePlan::build(&snapshot, vec![set(&kept), set(&moved_now), set(&new)]);
+
+        assert_eq!(plan.sets, vec![moved_now, new]);
+        assert_eq!(plan.deletes, vec![stale.redis_key()]);
+        assert_eq!(
+            plan.summary,
+            ReconcileSummary {
+                kept: 1,
+                updated: 1,
+                added: 1,
+                deleted: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_last_event_per_key_wins() {
+        let neighbor = entry("2001:db8::1", MAC_A);
+        let gone = entry("2001:db8::2", MAC_A);
+        let snapshot = snapshot_of(&[neighbor.clone(), gone.clone()]);
+
+        // Deleted then re-learned: refreshed. Learned then deleted: removed.
+        let plan = ReconcilePlan::build(
+            &snapshot,
+            vec![del(&neighbor), set(&neighbor), set(&gone), del(&gone)],
+        );
+
+        assert!(plan.sets.is_empty());
+        assert_eq!(plan.deletes, vec![gone.redis_key()]);
+        assert_eq!(plan.summary.kept, 1);
+        assert_eq!(plan.summary.deleted, 1);
+    }
+
+    #[test]
+    fn test_delete_of_unknown_entry_is_noop() {
+        let unknown = entry("2001:db8::9", MAC_A);
+        let plan = ReconcilePlan::build(&HashMap::new(), vec![del(&unknown)]);
+
+        assert!(plan.deletes.is_empty());
+        assert_eq!(plan.summary, ReconcileSummary::default());
+    }
+}
@@ -7,11 +7,19 @@
 //! - AC-3: Access Enforcement - Database access control
 
 use crate::error::Result;
+use crate::ipv4_config::{
+    CFG_NEIGHSYNCD_GLOBAL_KEY, CFG_NEIGHSYNCD_TABLE_NAME, neighbor_keys_by_family,
+    parse_ipv4_setting,
+};
+use crate::link_local::{
+    LINK_LOCAL_CONFIG_TABLES, LINK_LOCAL_ONLY_FIELD, interface_from_config_key, link_local_keys_on,
+};
 use crate::types::NeighborEntry;
+use crate::write_batcher::{WriteOp, WriteSink};
+use async_trait::async_trait;
 use redis::aio::ConnectionManager;
 use redis::{AsyncCommands, Client};
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::collections::{HashMap, HashSet};
 use tracing::{debug, instrument};
 
 /// SONiC database indices
@@ -23,22 +31,8 @@ const STATE_DB: i64 = 6;
 /// Redis table names matching C++ constants
 const APP_NEIGH_TABLE_NAME: &str = "NEIGH_TABLE";
 const STATE_NEIGH_RESTORE_TABLE_NAME: &str = "NEIGH_RESTORE_TABLE";
-const CFG_INTF_TABLE_NAME: &str = "INTERFACE";
-const CFG_LAG_INTF_TABLE_NAME: &str = "PORTCHANNEL_INTERFACE";
-const CFG_VLAN_INTF_TABLE_NAME: &str = "VLAN_INTERFACE";
 const CFG_PEER_SWITCH_TABLE_NAME: &str = "PEER_SWITCH";
 
-/// Link-local cache TTL
-/// NIST: SC-5 - Performance optimization to reduce DB queries
-const LINK_LOCAL_CACHE_TTL: Duration = Duration::from_secs(60);
-
-/// Link-local configuration cache entry
-#[derive(Debug, Clone)]
-struct LinkLocalCacheEntry {
-    enabled: bool,
-    timestamp: Instant,
-}
-
 /// Redis adapter for SONiC database operations
 ///
 /// # NIST Controls
@@ -48,9 +42,6 @@ pub struct RedisAdapter {
     appl_db: ConnectionManager,
     config_db: ConnectionManager,
     state_db: ConnectionManager,
-    /// Cache for link-local configuration lookups
-    /// NIST: SC-5 - Performance optimization
-    link_local_cache: HashMap<String, LinkLocalCacheEntry>,
 }
 
 impl RedisAdapter {
@@ -72,7 +63,6 @@ impl RedisAdapter {
             appl_db,
             config_db,
             state_db,
-            link_local_cache: HashMap::new(),
         })
     }
 
@@ -146,62 +136,71 @@ impl RedisAdapter {
         Ok(is_dual)
     }
 
-    /// Check if IPv6 link-local is enabled on an interface
+    /// Get interfaces with ipv6_use_link_local_only enabled
     ///
-    /// Uses TTL-based cache to reduce CONFIG_DB queries.
+    /// Scans INTERFACE, PORTCHANNEL_INTERFACE and VLAN_INTERFACE so the
+    /// caller can cache the whole set instead of querying per neighbor.
     ///
     /// # NIST Controls
     /// - CM-6: Configuration Settings - Interface configuration
     /// - SC-7: Boundary Protection - Link-local filtering config
-    /// - SC-5: DoS Protection - Cache reduces DB load
+    /// - SC-5: DoS Protection - One scan per refresh, not per event
     #[instrument(skip(self))]
-    pub async fn is_ipv6_link_local_enabled(&mut self, interface: &str) -> Result<bool> {
-        // Check cache first
-        if let Some(entry) = self.link_local_cache.get(interface) {
-            if entry.timestamp.elapsed() < LINK_LOCAL_CACHE_TTL {
-                debug!(interface, enabled = entry.enabled, "Link-local cache hit");
-                return Ok(entry.enabled);
+    pub async fn get_link_local_interfaces(&mut self) -> Result<HashSet<String>> {
+        let mut interfaces = HashSet::new();
+
+        for table in LINK_LOCAL_CONFIG_TABLES {
+            let pattern = format!("{}:*", table);
+            let keys: Vec<String> = self.config_db.keys(&pattern).await?;
+
+            for key in keys {
+                let Some(interface) = interface_from_config_key(table, &key) else {
+                    continue;
+                };
+                let value: Option<String> =
+                    self.config_db.hget(&key, LINK_LOCAL_ONLY_FIELD).await?;
+                if value.as_deref() == Some("enable") {
+                    interfaces.insert(interface.to_string());
+                }
             }
         }
 
-        // Determine which table to check based on interface name
-        let table = if interface.starts_with("Vlan") {
-            CFG_VLAN_INTF_TABLE_NAME
-        } else if interface.starts_with("PortChannel") {
-            CFG_LAG_INTF_TABLE_NAME
-        } else if interface.starts_with("Ethernet") {
-            CFG_INTF_TABLE_NAME
-        } else {
-            debug!(interface, "Unknown interface type, link-local disabled");
-            return Ok(false);
-        };
+        debug!(count = interfaces.len(), "Loaded link-local interfaces");
+        Ok(interfaces)
+    }
 
-        let key = format!("{}:{}", table, interface);
-        let values: HashMap<String, String> = self.config_db.hgetall(&key).await?;
+    /// Delete link-local neighbor entries on the given interfaces from APPL_DB
+    ///
+    /// Returns the number of entries removed.
+    ///
+    /// # NIST Controls
+    /// - SC-7: Boundary Protection - Withdraw entries no longer permitted
+    #[instrument(skip(self))]
+    pub async fn delete_link_local_neighbors(&mut self, interfaces: &[String]) -> Result<usize> {
+        let neighbors = self.get_all_neighbors().await?;
+        let keys = link_local_keys_on(&neighbors, interfaces);
+        self.delete_neighbor_keys(&keys).await?;
+        debug!(count = keys.len(), "Flushed link-local neighbors");
+        Ok(keys.len())
+    }
 
-        let enabled = values
-            .get("ipv6_use_link_local_only")
-            .is_some_and(|v| v == "enable");
-
-        // Update cache
-        self.link_local_cache.insert(
-            interface.to_string(),
-            LinkLocalCacheEntry {
-                enabled,
-                timestamp: Instant::now(),
-            },
-        );
+    /// Delete APPL_DB neighbor entries by short key (`<interface>:<ip>`)
+    ///
+    /// # NIST Controls
+    /// - SC-5: DoS Protection - Single pipelined delete
+    #[instrument(skip(self, keys), fields(count = keys.len()))]
+    pub async fn delete_neighbor_keys(&mut self, keys: &[String]) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
 
-        debug!(
-            interface,
-            table, enabled, "Checked IPv6 link-local status (cached)"
-        );
-        Ok(enabled)
-    }
+        let mut pipe = redis::pipe();
+        for key in keys {
+            pipe.del::<_>(format!("{}:{}", APP_NEIGH_TABLE_NAME, key));
+        }
 
-    /// Clear the link-local cache (useful for testing or config reload)
-    pub fn clear_link_local_cache(&mut self) {
-        self.link_local_cache.clear();
+        let _: () = pipe.query_async(&mut self.appl_db).await?;
+        Ok(())
     }
 
     /// Batch set multiple neighbor entries using Redis pipelining
@@ -286,6 +285,41 @@ impl RedisAdapter {
         Ok(neighbors)
     }
 
+    /// Read the runtime IPv4 sync setting from CONFIG_DB
+    ///
+    /// Returns None when NEIGHSYNCD:global has no usable `ipv4` field.
+    ///
+    /// # NIST Controls
+    /// - CM-6: Configuration Settings - Runtime address family selection
+    #[instrument(skip(self))]
+    pub async fn get_ipv4_sync_setting(&mut self) -> Result<Option<bool>> {
+        let key = format!(
+            "{}:{}",
+            CFG_NEIGHSYNCD_TABLE_NAME, CFG_NEIGHSYNCD_GLOBAL_KEY
+        );
+        let values: HashMap<String, String> = self.config_db.hgetall(&key).await?;
+
+        let setting = parse_ipv4_setting(&values);
+        debug!(?setting, "Read IPv4 sync setting");
+        Ok(setting)
+    }
+
+    /// Delete every APPL_DB neighbor entry of the given family
+    ///
+    /// Returns the number of entries removed.
+    ///
+    /// # NIST Controls
+    /// - CM-3: Configuration Change Control - Remove entries of a disabled family
+    /// - SC-5: DoS Protection - Single pipelined delete
+    #[instrument(skip(self))]
+    pub async fn delete_neighbors_by_family(&mut self, family: &str) -> Result<usize> {
+        let neighbors = self.get_all_neighbors().await?;
+        let keys = neighbor_keys_by_family(&neighbors, family);
+        self.delete_neighbor_keys(&keys).await?;
+        debug!(family, count = keys.len(), "Flushed neighbors by family");
+        Ok(keys.len())
+    }
+
     /// Set a key with NX (only if not exists) option and TTL
     ///
     /// # NIST Controls
@@ -525,6 +559,66 @@ impl RedisAdapter {
     }
 }
 
+/// Batched neighbor writes to APPL_DB NEIGH_TABLE
+///
+/// # NIST Controls
+/// - SC-5: DoS Protection - One round trip per batch
+#[async_trait]
+impl WriteSink for RedisAdapter {
+    /// Apply a batch as one non-transactional pipeline
+    ///
+    /// Not wrapped in MULTI/EXEC so a large batch does not block other
+    /// APPL_DB clients; the caller handles partially applied batches.
+    async fn apply_pipeline(&mut self, ops: &[WriteOp]) -> Result<()> {
+        if ops.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipe = redis::pipe();
+        for op in ops {
+            let key = format!("{}:{}", APP_NEIGH_TABLE_NAME, op.key());
+            match op {
+                WriteOp::Set(entry) => {
+                    pipe.hset_multiple::<_, _, _>(
+                        &key,
+                        &[
+                            ("neigh", entry.mac.to_string()),
+                            ("family", entry.family_str().to_string()),
+                        ],
+                    )
+                    .ignore();
+                }
+                WriteOp::Delete(_) => {
+                    pipe.del::<_>(&key).ignore();
+                }
+            }
+        }
+
+        let _: () = pipe.query_async(&mut self.appl_db).await?;
+        debug!(count = ops.len(), "Flushed neighbor write pipeline");
+        Ok(())
+    }
+
+    async fn existing_keys(&mut self, keys: &[String]) -> Result<HashSet<String>> {
+        if keys.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let mut pipe = redis::pipe();
+        for key in keys {
+            pipe.exists::<_>(format!("{}:{}", APP_NEIGH_TABLE_NAME, key));
+        }
+
+        let exists: Vec<bool> = pipe.query_async(&mut self.appl_db).await?;
+        Ok(keys
+            .iter()
+            .zip(exists)
+            .filter(|(_, exists)| *exists)
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,418 @@
+//! Kernel neighbor state policy
+//!
+//! Maps each NUD_* state to an explicit APPL_DB action:
+//!
+//! | State | Action |
+//! |-------|--------|
+//! | REACHABLE, PERMANENT, NOARP | sync as-is |
+//! | STALE | sync, then ask the kernel to re-probe (NUD_PROBE) |
+//! | FAILED, INCOMPLETE | delete from APPL_DB |
+//! | DELAY, PROBE | ignore (kernel revalidation in flight) |
+//!
+//! Probe requests are rate-limited per neighbor so a flapping or
+//! unreachable host cannot trigger a probe storm.
+//!
+//! # NIST 800-53 Rev 5 Control Mappings
+//! - SI-4: System Monitoring - Explicit handling of reachability states
+//! - SC-5: DoS Protection - Per-neighbor probe rate limiting
+//! - SI-10: Information Input Validation - Unresolved entries withdrawn
+
+use crate::metrics::MetricsCollector;
+use crate::types::{NeighborEntry, NeighborMessageType, NeighborState};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Minimum interval between probes of the same neighbor
+/// NIST: SC-5 - Prevent probe storms
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Maximum number of neighbors tracked by the probe rate limiter
+/// NIST: SC-5 - Bound limiter memory
+pub const MAX_PROBE_TRACKED: usize = 16384;
+
+/// Action taken for a neighbor in a given kernel state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateAction {
+    /// Write the entry to APPL_DB
+    Sync,
+    /// Write the entry and request a kernel re-probe
+    SyncAndProbe,
+    /// Remove the entry from APPL_DB
+    Delete,
+    /// Leave APPL_DB untouched
+    Ignore,
+}
+
+impl StateAction {
+    /// Decide the action for a kernel neighbor state
+    pub fn for_state(state: NeighborState) -> Self {
+        match state {
+            NeighborState::Reachable | NeighborState::Permanent | NeighborState::NoArp => {
+                Self::Sync
+            }
+            NeighborState::Stale => Self::SyncAndProbe,
+            NeighborState::Failed | NeighborState::Incomplete => Self::Delete,
+            NeighborState::Delay | NeighborState::Probe | NeighborState::Unknown => Self::Ignore,
+        }
+    }
+
+    /// Metric label for this action
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sync => "sync",
+            Self::SyncAndProbe => "sync_probe",
+            Self::Delete => "delete",
+            Self::Ignore => "ignore",
+        }
+    }
+
+    /// Check if this action writes the entry to APPL_DB
+    pub fn is_sync(&self) -> bool {
+        matches!(self, Self::Sync | Self::SyncAndProbe)
+    }
+}
+
+/// Per-neighbor probe rate limiter
+///
+/// # NIST Controls
+/// - SC-5: DoS Protection - Limit kernel probe requests
+#[derive(Debug)]
+pub struct ProbeRateLimiter {
+    min_interval: Duration,
+    last_probe: HashMap<String, Instant>,
+}
+
+impl Default for ProbeRateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROBE_INTERVAL)
+    }
+}
+
+impl ProbeRateLimiter {
+    /// Create a limiter allowing one probe per neighbor per `min_interval`
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_probe: HashMap::new(),
+        }
+    }
+
+    /// Check whether a probe for `key` may be sent at `now`, recording it if so
+    pub fn allow(&mut self, key: &str, now: Instant) -> bool {
+        if self
+            .last_probe
+            .get(key)
+            .is_some_and(|last| now.saturating_duration_since(*last) < self.min_interval)
+        {
+            return false;
+        }
+
+        if self.last_probe.len() >= MAX_PROBE_TRACKED && !self.last_probe.contains_key(key) {
+            self.prune(now);
+            if self.last_probe.len() >= MAX_PROBE_TRACKED {
+                return false;
+            }
+        }
+
+        self.last_probe.insert(key.to_string(), now);
+        true
+    }
+
+    /// Forget a neighbor (e.g. after it was deleted)
+    pub fn forget(&mut self, key: &str) {
+        self.last_probe.remove(key);
+    }
+
+    /// Drop entries whose interval has elapsed
+    pub fn prune(&mut self, now: Instant) {
+        let min_interval = self.min_interval;
+        self.last_probe
+            .retain(|_, last| now.saturating_duration_since(*last) < min_interval);
+    }
+
+    /// Number of neighbors currently rate-limited
+    pub fn tracked(&self) -> usize {
+        self.last_probe.len()
+    }
+}
+
+/// Outcome of evaluating one neighbor event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicyOutcome {
+    /// What to do with the APPL_DB entry
+    pub action: StateAction,
+    /// Whether a kernel probe should be sent now
+    pub probe: bool,
+}
+
+/// State policy with probe rate limiting and outcome metrics
+///
+/// # NIST Controls
+/// - SI-4: System Monitoring - Count outcomes per policy action
+/// - SC-5: DoS Protection - Rate-limited probes
+#[derive(Default)]
+pub struct StatePolicy {
+    limiter: ProbeRateLimiter,
+    metrics: Option<MetricsCollector>,
+}
+
+impl StatePolicy {
+    /// Create a policy with the given probe interval
+    pub fn new(probe_interval: Duration) -> Self {
+        Self {
+            limiter: ProbeRateLimiter::new(probe_interval),
+            metrics: None,
+        }
+    }
+
+    /// Attach a metrics collector for outcome counters
+    pub fn set_metrics(&mut self, metrics: MetricsCollector) {
+        self.metrics = Some(metrics);
+    }
+
+    /// Evaluate a neighbor event at `now`
+    ///
+    /// Kernel deletes always map to [`StateAction::Delete`]; other events
+    /// follow the entry's NUD state.
+    pub fn evaluate(
+        &mut self,
+        msg_type: NeighborMessageType,
+        entry: &NeighborEntry,
+        now: Instant,
+    ) -> PolicyOutcome {
+        let action = if msg_type == NeighborMessageType::Delete {
+            StateAction::Delete
+        } else {
+            StateAction::for_state(entry.state)
+        };
+
+        let mut probe = false;
+        match action {
+            StateAction::SyncAndProbe => {
+                probe = self.limiter.allow(&entry.redis_key(), now);
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_probe(probe);
+                }
+            }
+            StateAction::Delete => self.limiter.forget(&entry.redis_key()),
+            StateAction::Sync | StateAction::Ignore => {}
+        }
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_state_action(action);
+        }
+
+        PolicyOutcome { action, probe }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MacAddress;
+    use crate::vrf::VrfId;
+
+    fn entry(state: NeighborState) -> NeighborEntry {
+        NeighborEntry {
+            ifindex: 1,
+            interface: "Ethernet0".to_string(),
+            ip: "2001:db8::1".parse().unwrap(),
+            mac: MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            state,
+            externally_learned: false,
+            vrf_id: VrfId::default_vrf(),
+        }
+    }
+
+    #[test]
+    fn test_state_actions() {
+        assert_eq!(
+            StateAction::for_state(NeighborState::Reachable),
+            StateAction::Sync
+        );
+        assert_eq!(
+            StateAction::for_state(NeighborState::Permanent),
+            StateAction::Sync
+        );
+        assert_eq!(
+            StateAction::for_state(NeighborState::Stale),
+            StateAction::SyncAndProbe
+        );
+        assert_eq!(
+            StateAction::for_state(NeighborState::Failed),
+            StateAction::Delete
+        );
+        assert_eq!(
+            StateAction::for_state(NeighborState::Incomplete),
+            StateAction::Delete
+        );
+        assert_eq!(
+            StateAction::for_state(NeighborState::Delay),
+            StateAction::Ignore
+        );
+    }
+
+    #[test]
+    fn test_state_transitions() {
+        // REACHABLE -> STALE -> DELAY -> PROBE -> FAILED
+        let actions: Vec<StateAction> = [
+            NeighborState::Reachable,
+            NeighborState::Stale,
+            NeighborState::Delay,
+            NeighborState::Probe,
+            NeighborState::Failed,
+        ]
+        .into_iter()
+        .map(StateAction::for_state)
+        .collect();
+
+        assert_eq!(
+            actions,
+            vec![
+                StateAction::Sync,
+                StateAction::SyncAndProbe,
+                StateAction::Ignore,
+                StateAction::Ignore,
+                StateAction::Delete,
+            ]
+        );
+        assert!(actions[0].is_sync() && actions[1].is_sync());
+        assert!(!actions[2].is_sync() && !actions[4].is_sync());
+    }
+
+    #[test]
+    fn test_action_labels() {
+        assert_eq!(StateAction::Sync.as_str(), "sync");
+        assert_eq!(StateAction::SyncAndProbe.as_str(), "sync_probe");
+        assert_eq!(StateAction::Delete.as_str(), "delete");
+        assert_eq!(StateAction::Ignore.as_str(), "ignore");
+    }
+
+    #[test]
+    fn test_rate_limiter_per_neighbor() {
+        let mut limiter = ProbeRateLimiter::new(Duration::from_secs(30));
+        let start = Instant::now();
+
+        assert!(limiter.allow("Ethernet0:2001:db8::1", start));
+        assert!(!limiter.allow("Ethernet0:2001:db8::1", start + Duration::from_secs(10)));
+
+        // Other neighbors are limited independently
+        assert!(limiter.allow("Ethernet0:2001:db8::2", start + Duration::from_secs(10)));
+
+        // Interval elapsed
+        assert!(limiter.allow("Ethernet0:2001:db8::1", start + Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_rate_limiter_forget_and_prune() {
+        let mut limiter = ProbeRateLimiter::new(Duration::from_secs(30));
+        let start = Instant::now();
+
+        limiter.allow("a", start);
+        limiter.allow("b", start + Duration::from_secs(20));
+        limiter.forget("a");
+        assert!(limiter.allow("a", start + Duration::from_secs(1)));
+
+        limiter.prune(start + Duration::from_secs(45));
+        assert_eq!(limiter.tracked(), 1);
+    }
+
+    #[test]
+    fn test_rate_limiter_bounded() {
+        let mut limiter = ProbeRateLimiter::new(Duration::from_secs(30));
+        let start = Instant::now();
+
+        for i in 0..MAX_PROBE_TRACKED {
+            assert!(limiter.allow(&format!("n{}", i), start));
+        }
+        // Table full of live entries: new neighbors are refused, not evicted
+        assert!(!limiter.allow("overflow", start));
+        assert_eq!(limiter.tracked(), MAX_PROBE_TRACKED);
+
+        // Once entries expire there is room again
+        assert!(limiter.allow("overflow", start + Duration::from_secs(31)));
+        assert_eq!(limiter.tracked(), 1);
+    }
+
+    #[test]
+    fn test_policy_stale_probes_rate_limited() {
+        let metrics = MetricsCollector::new().unwrap();
+        let mut policy = StatePolicy::new(Duration::from_secs(30));
+        policy.set_metrics(metrics.clone());
+        let start = Instant::now();
+        let stale = entry(NeighborState::Stale);
+
+        let first = policy.evaluate(NeighborMessageType::New, &stale, start);
+        assert_eq!(first.action, StateAction::SyncAndProbe);
+        assert!(first.probe);
+
+        // Still synced, but no second probe inside the interval
+        let second = policy.evaluate(
+            NeighborMessageType::New,
+            &stale,
+            start + Duration::from_secs(5),
+        );
+        assert_eq!(second.action, StateAction::SyncAndProbe);
+        assert!(!second.probe);
+
+        assert_eq!(metrics.probes_sent_total.get(), 1.0);
+        assert_eq!(metrics.probes_rate_limited_total.get(), 1.0);
+    }
+
+    #[test]
+    fn test_policy_state_transitions() {
+        let metrics = MetricsCollector::new().unwrap();
+        let mut policy = StatePolicy::new(Duration::from_secs(30));
+        policy.set_metrics(metrics.clone());
+        let now = Instant::now();
+
+        let sequence = [
+            (NeighborMessageType::New, NeighborState::Reachable),
+            (NeighborMessageType::New, NeighborState::Stale),
+            (NeighborMessageType::New, NeighborState::Delay),
+            (NeighborMessageType::New, NeighborState::Reachable),
+            (NeighborMessageType::New, NeighborState::Failed),
+            (NeighborMessageType::Delete, NeighborState::Reachable),
+        ];
+        let actions: Vec<StateAction> = sequence
+            .iter()
+            .map(|(msg_type, state)| policy.evaluate(*msg_type, &entry(*state), now).action)
+            .collect();
+
+        assert_eq!(
+            actions,
+            vec![
+                StateAction::Sync,
+                StateAction::SyncAndProbe,
+                StateAction::Ignore,
+                StateAction::Sync,
+                StateAction::Delete,
+                StateAction::Delete,
+            ]
+        );
+
+        let count = |action: StateAction| {
+            metrics
+                .neighbor_state_actions_total
+                .with_label_values(&[action.as_str()])
+                .get()
+        };
+        assert_eq!(count(StateAction::Sync), 2.0);
+        assert_eq!(count(StateAction::SyncAndProbe), 1.0);
+        assert_eq!(count(StateAction::Ignore), 1.0);
+        assert_eq!(count(StateAction::Delete), 2.0);
+    }
+
+    #[test]
+    fn test_policy_delete_resets_probe_limit() {
+        let mut policy = StatePolicy::new(Duration::from_secs(30));
+        let now = Instant::now();
+        let stale = entry(NeighborState::Stale);
+
+        assert!(policy.evaluate(NeighborMessageType::New, &stale, now).probe);
+        policy.evaluate(NeighborMessageType::New, &entry(NeighborState::Failed), now);
+
+        // Neighbor came back and went stale again: probe immediately
+        assert!(policy.evaluate(NeighborMessageType::New, &stale, now).probe);
+    }
+}
@@ -81,7 +81,7 @@ pub struct NeighborEntry {
     pub ifindex: u32,
     /// Interface name (resolved from ifindex)
     pub interface: String,
-    /// Neighbor IP address (IPv6 only by default, IPv4 with feature)
+    /// Neighbor IP address (IPv4 only when enabled at runtime)
     pub ip: IpAddr,
     /// Neighbor MAC address (from sonic-types)
     pub mac: MacAddress,
@@ -124,10 +124,7 @@ impl NeighborEntry {
     pub fn family_str(&self) -> &'static str {
         match self.ip {
             IpAddr::V6(_) => "IPv6",
-            #[cfg(feature = "ipv4")]
             IpAddr::V4(_) => "IPv4",
-            #[cfg(not(feature = "ipv4"))]
-            IpAddr::V4(_) => unreachable!("IPv4 support disabled"),
         }
     }
 
@@ -150,7 +147,6 @@ impl NeighborEntry {
     }
 
     /// Check if this is an IPv4 link-local address (169.254.x.x)
-    #[cfg(feature = "ipv4")]
     pub fn is_ipv4_link_local(&self) -> bool {
         match self.ip {
             IpAddr::V4(addr) => addr.is_link_local(),
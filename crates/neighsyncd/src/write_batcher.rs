@@ -0,0 +1,496 @@
+//! Batched APPL_DB neighbor writes
+//!
+//! A full-table dump delivers tens of thousands of neighbors. Writing each
+//! one as its own Redis command costs a round trip per neighbor, so HSET and
+//! DEL operations are accumulated here and flushed as one pipeline per batch.
+//!
+//! A batch is flushed when either bound is hit:
+//! - `max_batch_size` operations are queued, or
+//! - the oldest queued operation has waited `max_latency`, so a single
+//!   event on an idle system is not held back.
+//!
+//! Operations on the same key are coalesced (the last one wins), so each
+//! batch touches a key at most once and ordering between keys is irrelevant.
+//!
+//! Pipelines are not transactions: a connection failure part-way through
+//! may leave a prefix applied. Before a retry, queued deletes whose key is
+//! already gone are dropped so they are not re-issued. Re-applying a SET is
+//! harmless since it writes the same fields.
+//!
+//! # NIST 800-53 Rev 5 Control Mappings
+//! - SC-5: Denial of Service Protection - Bounded Redis round trips per dump
+//! - SI-10: Information Input Validation - Coalesce conflicting operations
+//! - SI-13: Predictable Failure Prevention - Retry partially applied batches
+
+use crate::error::Result;
+use crate::types::NeighborEntry;
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Maximum operations per pipeline
+/// NIST: SC-5 - Bound pipeline size
+pub const DEFAULT_MAX_WRITE_BATCH: usize = 512;
+
+/// Maximum time an operation may wait for its batch to fill
+pub const DEFAULT_MAX_WRITE_LATENCY: Duration = Duration::from_millis(10);
+
+/// Retries of a failed pipeline before the batch is reported as failed
+pub const MAX_FLUSH_RETRIES: u32 = 3;
+
+/// A queued APPL_DB write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteOp {
+    /// HSET the neighbor's fields
+    Set(NeighborEntry),
+    /// DEL the neighbor's key
+    Delete(NeighborEntry),
+}
+
+impl WriteOp {
+    /// APPL_DB key (`<interface>:<ip>`) without the table prefix
+    pub fn key(&self) -> String {
+        self.entry().redis_key()
+    }
+
+    /// Neighbor the operation applies to
+    pub fn entry(&self) -> &NeighborEntry {
+        match self {
+            WriteOp::Set(entry) | WriteOp::Delete(entry) => entry,
+        }
+    }
+
+    /// Check if this is a DEL
+    pub fn is_delete(&self) -> bool {
+        matches!(self, WriteOp::Delete(_))
+    }
+}
+
+/// Destination for flushed batches
+///
+/// Implemented by `RedisAdapter`; each call is one round trip.
+#[async_trait]
+pub trait WriteSink: Send {
+    /// Apply operations as a single pipeline
+    async fn apply_pipeline(&mut self, ops: &[WriteOp]) -> Result<()>;
+
+    /// Return which of the given keys currently exist
+    async fn existing_keys(&mut self, keys: &[String]) -> Result<HashSet<String>>;
+}
+
+/// Result of a successful flush
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlushReport {
+    /// SET operations in the batch
+    pub sets: usize,
+    /// DEL operations in the batch
+    pub deletes: usize,
+    /// Pipeline retries after failures
+    pub retries: u32,
+    /// Round trips used, including retry checks
+    pub round_trips: usize,
+}
+
+impl FlushReport {
+    /// Total operations in the batch
+    pub fn len(&self) -> usize {
+        self.sets + self.deletes
+    }
+
+    /// Check if the batch was empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Accumulates writes into bounded batches
+///
+/// # NIST Controls
+/// - SC-5: DoS Protection - Size and latency bounds
+#[derive(Debug)]
+pub struct WriteBatcher {
+    /// Queued operations in arrival order, one per key
+    pending: Vec<WriteOp>,
+    /// Position of each key in `pending`
+    index: HashMap<String, usize>,
+    /// When the oldest queued operation arrived
+    oldest: Option<Instant>,
+    max_batch_size: usize,
+    max_latency: Duration,
+}
+
+impl Default for WriteBatcher {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_WRITE_BATCH, DEFAULT_MAX_WRITE_LATENCY)
+    }
+}
+
+impl WriteBatcher {
+    /// Create a batcher with the given size and latency bounds
+    pub fn new(max_batch_size: usize, max_latency: Duration) -> Self {
+        Self {
+            pending: Vec::new(),
+            index: HashMap::new(),
+            oldest: None,
+            max_batch_size: max_batch_size.max(1),
+            max_latency,
+        }
+    }
+
+    /// Number of queued operations
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Check if nothing is queued
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Queue an operation, replacing any queued operation on the same key
+    pub fn push(&mut self, op: WriteOp, now: Instant) {
+        let key = op.key();
+        match self.index.get(&key) {
+            Some(&position) => self.pending[position] = op,
+            None => {
+                self.index.insert(key, self.pending.len());
+                self.pending.push(op);
+            }
+        }
+        self.oldest.get_or_insert(now);
+    }
+
+    /// Time by which the queued operations must be flushed
+    pub fn deadline(&self) -> Option<Instant> {
+        self.oldest.map(|oldest| oldest + self.max_latency)
+    }
+
+    /// Check if a batch should be flushed now
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.pending.len() >= self.max_batch_size
+            || self.deadline().is_some_and(|deadline| deadline <= now)
+    }
+
+    /// Remove up to `max_batch_size` of the oldest operations
+    ///
+    /// Operations left behind keep the original deadline, so they are never
+    /// delayed beyond `max_latency`.
+    pub fn take_batch(&mut self) -> Vec<WriteOp> {
+        let count = self.pending.len().min(self.max_batch_size);
+        let batch: Vec<WriteOp> = self.pending.drain(..count).collect();
+        self.reindex();
+        batch
+    }
+
+    /// Forget queued operations matching a predicate
+    pub fn discard(&mut self, mut predicate: impl FnMut(&WriteOp) -> bool) {
+        self.pending.retain(|op| !predicate(op));
+        self.reindex();
+    }
+
+    /// Rebuild key positions after operations were removed
+    fn reindex(&mut self) {
+        self.index = self
+            .pending
+            .iter()
+            .enumerate()
+            .map(|(position, op)| (op.key(), position))
+            .collect();
+        if self.pending.is_empty() {
+            self.oldest = None;
+        }
+    }
+}
+
+/// Drop deletes whose key is already gone after a failed pipeline
+fn retain_unapplied_deletes(ops: &mut Vec<WriteOp>, existing: &HashSet<String>) {
+    ops.retain(|op| !op.is_delete() || existing.contains(&op.key()));
+}
+
+/// Flush one batch as a pipeline, retrying after failures
+///
+/// # NIST Controls
+/// - SI-13: Predictable Failure Prevention - Bounded retries
+/// - SI-7: Information Integrity - No duplicate deletes on retry
+pub async fn flush_with_retry<S: WriteSink + ?Sized>(
+    sink: &mut S,
+    mut ops: Vec<WriteOp>,
+    max_retries: u32,
+) -> Result<FlushReport> {
+    let deletes = ops.iter().filter(|op| op.is_delete()).count();
+    let mut report = FlushReport {
+        sets: ops.len() - deletes,
+        deletes,
+        ..Default::default()
+    };
+
+    loop {
+        report.round_trips += 1;
+        let error = match sink.apply_pipeline(&ops).await {
+            Ok(()) => return Ok(report),
+            Err(e) => e,
+        };
+        if report.retries >= max_retries {
+            return Err(error);
+        }
+        report.retries += 1;
+        warn!(
+            error = %error,
+            retry = report.retries,
+            count = ops.len(),
+            "Neighbor write pipeline failed, retrying"
+        );
+
+        let delete_keys: Vec<String> = ops
+            .iter()
+            .filter(|op| op.is_delete())
+            .map(|op| op.key())
+            .collect();
+        if !delete_keys.is_empty() {
+            report.round_trips += 1;
+            let existing = sink.existing_keys(&delete_keys).await?;
+            retain_unapplied_deletes(&mut ops, &existing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NeighsyncError;
+    use crate::types::{MacAddress, NeighborState};
+    use crate::vrf::VrfId;
+    use std::net::Ipv6Addr;
+
+    fn entry(index: u32) -> NeighborEntry {
+        let ip = Ipv6Addr::from(0x2001_0db8_0000_0000_0000_0000_0000_0000 + u128::from(index));
+        NeighborEntry {
+            ifindex: 1,
+            interface: "Ethernet0".to_string(),
+            ip: ip.into(),
+            mac: MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            state: NeighborState::Reachable,
+            externally_learned: false,
+            vrf_id: VrfId::default_vrf(),
+        }
+    }
+
+    /// In-memory APPL_DB that counts round trips and can fail mid-pipeline
+    #[derive(Default)]
+    struct MockSink {
+        keys: HashSet<String>,
+        round_trips: usize,
+        /// Fail the next pipeline after applying this many operations
+        fail_after: Option<usize>,
+        /// Every DEL that removed an existing key
+        applied_deletes: Vec<String>,
+        /// Every DEL issued, including no-ops
+        issued_deletes: Vec<String>,
+    }
+
+    #[async_trait]
+    impl WriteSink for MockSink {
+        async fn apply_pipeline(&mut self, ops: &[WriteOp]) -> Result<()> {
+            self.round_trips += 1;
+            let limit = self.fail_after.take();
+            for (applied, op) in ops.iter().enumerate() {
+                if limit == Some(applied) {
+                    return Err(NeighsyncError::Redis(redis::RedisError::from((
+                        redis::ErrorKind::IoError,
+                        "connection reset",
+                    ))));
+                }
+                match op {
+                    WriteOp::Set(_) => {
+                        self.keys.insert(op.key());
+                    }
+                    WriteOp::Delete(_) => {
+                        self.issued_deletes.push(op.key());
+                        if self.keys.remove(&op.key()) {
+                            self.applied_deletes.push(op.key());
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        async fn existing_keys(&mut self, keys: &[String]) -> Result<HashSet<String>> {
+            self.round_trips += 1;
+            Ok(keys
+                .iter()
+                .filter(|key| self.keys.contains(*key))
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_same_key_is_coalesced() {
+        let now = Instant::now();
+        let mut batcher = WriteBatcher::default();
+
+        batcher.push(WriteOp::Set(entry(1)), now);
+        batcher.push(WriteOp::Set(entry(2)), now);
+        batcher.push(WriteOp::Delete(entry(1)), now);
+
+        assert_eq!(batcher.len(), 2);
+        assert_eq!(
+            batcher.take_batch(),
+            vec![WriteOp::Delete(entry(1)), WriteOp::Set(entry(2))]
+        );
+        assert!(batcher.is_empty());
+        assert_eq!(batcher.deadline(), None);
+    }
+
+    #[test]
+    fn test_flush_due_on_size() {
+        let now = Instant::now();
+        let mut batcher = WriteBatcher::new(3, Duration::from_secs(60));
+
+        for index in 0..5 {
+            batcher.push(WriteOp::Set(entry(index)), now);
+        }
+        assert!(batcher.is_due(now));
+        assert_eq!(batcher.take_batch().len(), 3);
+
+        // Two left: below the size bound and within latency
+        assert!(!batcher.is_due(now));
+        assert_eq!(batcher.len(), 2);
+    }
+
+    #[test]
+    fn test_single_event_flushed_on_latency() {
+        let now = Instant::now();
+        let mut batcher = WriteBatcher::new(100, Duration::from_millis(10));
+
+        batcher.push(WriteOp::Set(entry(1)), now);
+        assert!(!batcher.is_due(now));
+        assert_eq!(batcher.deadline(), Some(now + Duration::from_millis(10)));
+        assert!(batcher.is_due(now + Duration::from_millis(10)));
+
+        // A later push does not extend the deadline of the first
+        batcher.push(WriteOp::Set(entry(2)), now + Duration::from_millis(5));
+        assert_eq!(batcher.deadline(), Some(now + Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_discard() {
+        let now = Instant::now();
+        let mut batcher = WriteBatcher::default();
+        batcher.push(WriteOp::Set(entry(1)), now);
+        batcher.push(WriteOp::Set(entry(2)), now);
+
+        batcher.discard(|op| op.key() == entry(1).redis_key());
+        assert_eq!(batcher.take_batch(), vec![WriteOp::Set(entry(2))]);
+
+        batcher.push(WriteOp::Set(entry(3)), now);
+        batcher.discard(|_| true);
+        assert!(batcher.is_empty());
+        assert_eq!(batcher.deadline(), None);
+    }
+
+    #[tokio::test]
+    async fn test_dump_uses_fewer_round_trips() {
+        const DUMP_SIZE: u32 = 10_000;
+        let now = Instant::now();
+
+        // Unbatched: one command per neighbor
+        let mut unbatched = MockSink::default();
+        for index in 0..DUMP_SIZE {
+            unbatched
+                .apply_pipeline(&[WriteOp::Set(entry(index))])
+                .await
+                .unwrap();
+        }
+
+        let mut batched = MockSink::default();
+        let mut batcher = WriteBatcher::default();
+        for index in 0..DUMP_SIZE {
+            batcher.push(WriteOp::Set(entry(index)), now);
+            while batcher.is_due(now) {
+                flush_with_retry(&mut batched, batcher.take_batch(), MAX_FLUSH_RETRIES)
+                    .await
+                    .unwrap();
+            }
+        }
+        // Remainder flushed once the latency bound expires
+        assert!(batcher.is_due(now + DEFAULT_MAX_WRITE_LATENCY));
+        flush_with_retry(&mut batched, batcher.take_batch(), MAX_FLUSH_RETRIES)
+            .await
+            .unwrap();
+
+        assert_eq!(batched.keys, unbatched.keys);
+        assert_eq!(batched.keys.len(), DUMP_SIZE as usize);
+        assert_eq!(unbatched.round_trips, DUMP_SIZE as usize);
+        assert!(
+            batched.round_trips * 5 <= unbatched.round_trips,
+            "batched {} vs unbatched {} round trips",
+            batched.round_trips,
+            unbatched.round_trips
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_applied_deletes() {
+        let mut sink = MockSink::default();
+        for index in 0..4 {
+            sink.keys.insert(entry(index).redis_key());
+        }
+        // Connection drops after the first two deletes land
+        sink.fail_after = Some(2);
+
+        let ops = vec![
+            WriteOp::Delete(entry(0)),
+            WriteOp::Delete(entry(1)),
+            WriteOp::Set(entry(10)),
+            WriteOp::Delete(entry(2)),
+            WriteOp::Delete(entry(3)),
+        ];
+        let report = flush_with_retry(&mut sink, ops, MAX_FLUSH_RETRIES)
+            .await
+            .unwrap();
+
+        assert_eq!(report.sets, 1);
+        assert_eq!(report.deletes, 4);
+        assert_eq!(report.retries, 1);
+        assert_eq!(report.round_trips, 3);
+
+        // Each delete issued exactly once
+        let mut issued = sink.issued_deletes.clone();
+        issued.sort();
+        issued.dedup();
+        assert_eq!(issued.len(), sink.issued_deletes.len());
+        assert_eq!(sink.applied_deletes.len(), 4);
+        assert_eq!(sink.keys, HashSet::from([entry(10).redis_key()]));
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_bounded() {
+        struct FailingSink {
+            attempts: u32,
+        }
+
+        #[async_trait]
+        impl WriteSink for FailingSink {
+            async fn apply_pipeline(&mut self, _ops: &[WriteOp]) -> Result<()> {
+                self.attempts += 1;
+                Err(NeighsyncError::Redis(redis::RedisError::from((
+                    redis::ErrorKind::IoError,
+                    "connection refused",
+                ))))
+            }
+
+            async fn existing_keys(&mut self, _keys: &[String]) -> Result<HashSet<String>> {
+                Ok(HashSet::new())
+            }
+        }
+
+        let mut sink = FailingSink { attempts: 0 };
+        let result = flush_with_retry(&mut sink, vec![WriteOp::Set(entry(1))], 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(sink.attempts, 3);
+    }
+}
@@ -504,17 +504,14 @@ mod tests {
 
     #[test]
     fn test_ipv4_link_local_detection() {
-        #[cfg(feature = "ipv4")]
-        {
-            let entry = make_test_entry(
-                1,
-                "eth0",
-                "169.254.1.1",
-                "00:11:22:33:44:55",
-                NeighborState::Reachable,
-            );
-            assert!(entry.is_ipv4_link_local());
-        }
+        let entry = make_test_entry(
+            1,
+            "eth0",
+            "169.254.1.1",
+            "00:11:22:33:44:55",
+            NeighborState::Reachable,
+        );
+        assert!(entry.is_ipv4_link_local());
     }
 
     #[test]
@@ -22,6 +22,11 @@
 //! 4. **Explicit error handling**: All operations that can fail return `Result`
 //!    instead of silently failing or throwing exceptions.
 //!
+//! 5. **Capability negotiation**: Table types are checked against the ASIC's
+//!    ACL capability (queried once per stage/bind point) before creation.
+//!    Unsupported fields reject the table, or with `ALLOW_DEGRADED` are
+//!    dropped and recorded in STATE_DB.
+//!
 //! # Architecture
 //!
 //! ```text
@@ -31,6 +36,7 @@
 //!    AclOrch
 //!        │
 //!        ├──> SAI ACL API
+//!        ├──> FlexCounterOrch (rule counters, COUNTERS_DB ACL_COUNTER_RULE_MAP)
 //!        ├──> MirrorOrch (for mirror rules)
 //!        ├──> NeighOrch (for redirect to NH)
 //!        └──> RouteOrch (for redirect to NHG)
@@ -58,7 +64,7 @@ pub use rule::{
     AclMatchValue, AclRedirectTarget, AclRule, AclRuleAction, AclRuleMatch, AclRuleType,
 };
 pub use table::{AclTable, AclTableConfig};
-pub use table_type::{AclTableType, AclTableTypeBuilder};
+pub use table_type::{AclTableCapability, AclTableType, AclTableTypeBuilder, AclUnsupportedFields};
 pub use types::{
     AclActionType, AclBindPointType, AclMatchField, AclPacketAction, AclPriority, AclRuleId,
     AclStage, AclTableId, MetaDataValue,
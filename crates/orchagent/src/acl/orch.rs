@@ -26,10 +26,12 @@ use super::range::AclRangeCache;
 use super::rule::AclRule;
 use super::table::{AclTable, AclTableConfig};
 use super::table_type::{
-    create_ctrlplane_table_type, create_drop_table_type, create_l3_table_type,
-    create_l3v6_table_type, create_mirror_table_type, create_pfcwd_table_type, AclTableType,
+    create_ctrlplane_table_type, create_drop_table_type, create_dtel_flow_watchlist_table_type,
+    create_l3_table_type, create_l3v6_table_type, create_mirror_table_type,
+    create_pfcwd_table_type, AclTableCapability, AclTableType, AclTableTypeBuilder,
+    AclUnsupportedFields,
 };
-use super::types::{AclPriority, AclStage, AclTableId, MetaDataValue};
+use super::types::{AclBindPointType, AclPriority, AclStage, AclTableId, MetaDataValue};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::{audit_log, debug_log, error_log, info_log, warn_log};
 
@@ -66,6 +68,9 @@ pub enum AclOrchError {
     /// Dependency error (e.g., mirror session not found).
     #[error("Dependency error: {0}")]
     DependencyError(String),
+    /// Table type requests fields the ASIC does not implement.
+    #[error("ACL table {0} uses fields unsupported by the ASIC: {1}")]
+    UnsupportedFields(String, String),
 }
 
 /// Result type alias for AclOrch operations.
@@ -88,6 +93,38 @@ pub struct AclOrchCallbacks {
     pub incr_nexthop_ref: Option<Arc<dyn Fn(&str) + Send + Sync>>,
     /// Decrement next-hop reference.
     pub decr_nexthop_ref: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    /// Query the ASIC ACL capability for a stage and bind point
+    /// (None if the query is not supported).
+    pub query_acl_capability:
+        Option<Arc<dyn Fn(AclStage, AclBindPointType) -> Option<AclTableCapability> + Send + Sync>>,
+    /// Record fields omitted from a degraded table in STATE_DB.
+    pub write_degraded_table_state: Option<Arc<dyn Fn(&str, &AclUnsupportedFields) + Send + Sync>>,
+    /// Remove a table's STATE_DB entry.
+    pub remove_table_state: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    /// Create a SAI ACL counter on a table, returning the counter OID.
+    pub create_acl_counter: Option<
+        Arc<dyn Fn(RawSaiObjectId) -> std::result::Result<RawSaiObjectId, String> + Send + Sync>,
+    >,
+    /// Remove a SAI ACL counter.
+    pub remove_acl_counter:
+        Option<Arc<dyn Fn(RawSaiObjectId) -> std::result::Result<(), String> + Send + Sync>>,
+    /// Attach a counter to a rule (table, rule, counter OID).
+    pub attach_acl_counter: Option<
+        Arc<dyn Fn(&str, &str, RawSaiObjectId) -> std::result::Result<(), String> + Send + Sync>,
+    >,
+    /// Register a counter with the ACL flex counter group for polling.
+    pub register_acl_counter:
+        Option<Arc<dyn Fn(RawSaiObjectId) -> std::result::Result<(), String> + Send + Sync>>,
+    /// Deregister a counter from the ACL flex counter group.
+    pub unregister_acl_counter: Option<Arc<dyn Fn(RawSaiObjectId) + Send + Sync>>,
+    /// Write a COUNTERS_DB `ACL_COUNTER_RULE_MAP` entry ("TABLE:RULE" → OID).
+    pub write_counter_map: Option<Arc<dyn Fn(&str, RawSaiObjectId) + Send + Sync>>,
+    /// Remove a COUNTERS_DB `ACL_COUNTER_RULE_MAP` entry.
+    pub remove_counter_map: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    /// Rewrite a programmed rule's SAI priority (table, rule, priority).
+    pub set_rule_priority: Option<
+        Arc<dyn Fn(&str, &str, AclPriority) -> std::result::Result<(), String> + Send + Sync>,
+    >,
 }
 
 impl std::fmt::Debug for AclOrchCallbacks {
@@ -98,6 +135,8 @@ impl std::fmt::Debug for AclOrchCallbacks {
                 "get_mirror_session_oid",
                 &self.get_mirror_session_oid.is_some(),
             )
+            .field("query_acl_capability", &self.query_acl_capability.is_some())
+            .field("create_acl_counter", &self.create_acl_counter.is_some())
             .finish()
     }
 }
@@ -121,6 +160,9 @@ pub struct AclOrchConfig {
     pub metadata_min: u16,
     /// Maximum metadata value.
     pub metadata_max: u16,
+    /// Renumber neighbouring rules when an insertion finds no free priority.
+    /// Off by default because it rewrites programmed entries.
+    pub auto_rebalance_priorities: bool,
 }
 
 impl Default for AclOrchConfig {
@@ -134,6 +176,7 @@ impl Default for AclOrchConfig {
             metadata_supported: true,
             metadata_min: MetaDataValue::MIN,
             metadata_max: MetaDataValue::MAX,
+            auto_rebalance_priorities: false,
         }
     }
 }
@@ -147,6 +190,103 @@ pub struct AclActionCapabilities {
     pub action_list_mandatory: bool,
 }
 
+/// Priority rewrites needed to open a slot for a new rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PrioritySlot {
+    /// Priority for the new rule.
+    priority: AclPriority,
+    /// (index into the ordered rules, new priority) for each rewritten rule.
+    rewrites: Vec<(usize, AclPriority)>,
+}
+
+/// Finds a priority for a rule inserted before `index` in `priorities`
+/// (ascending).
+///
+/// Uses the midpoint of the gap when one exists. Otherwise, if `rebalance` is
+/// set, shifts the shorter run of neighbouring rules up or down by one step
+/// each, which keeps relative order and rewrites as few entries as possible.
+fn plan_priority_slot(
+    priorities: &[AclPriority],
+    index: usize,
+    min: AclPriority,
+    max: AclPriority,
+    rebalance: bool,
+) -> Option<PrioritySlot> {
+    let (min, max) = (i64::from(min), i64::from(max));
+    let below = if index > 0 {
+        i64::from(priorities[index - 1])
+    } else {
+        min - 1
+    };
+    let above = priorities
+        .get(index)
+        .map(|p| i64::from(*p))
+        .unwrap_or(max + 1);
+
+    if above - below >= 2 {
+        return Some(PrioritySlot {
+            priority: (below + (above - below) / 2) as AclPriority,
+            rewrites: Vec::new(),
+        });
+    }
+    if !rebalance {
+        return None;
+    }
+
+    // Shift the rules above the slot upwards
+    let up = {
+        let new = below + 1;
+        let mut rewrites = Vec::new();
+        for ((j, p), required) in priorities.iter().enumerate().skip(index).zip(new + 1..) {
+            if i64::from(*p) >= required {
+                break;
+            }
+            rewrites.push((j, required));
+        }
+        (new <= max && rewrites.iter().all(|(_, p)| *p <= max)).then_some((new, rewrites))
+    };
+
+    // Shift the rules below the slot downwards
+    let down = {
+        let new = above - 1;
+        let mut rewrites = Vec::new();
+        for ((j, p), required) in priorities
+            .iter()
+            .enumerate()
+            .take(index)
+            .rev()
+            .zip((i64::MIN..new).rev())
+        {
+            if i64::from(*p) <= required {
+                break;
+            }
+            rewrites.push((j, required));
+        }
+        (new >= min && rewrites.iter().all(|(_, p)| *p >= min)).then_some((new, rewrites))
+    };
+
+    let (priority, rewrites) = match (up, down) {
+        (Some(u), Some(d)) => {
+            if d.1.len() < u.1.len() {
+                d
+            } else {
+                u
+            }
+        }
+        (Some(u), None) => u,
+        (None, Some(d)) => d,
+        (None, None) => return None,
+    };
+
+    Some(PrioritySlot {
+        priority: priority as AclPriority,
+        rewrites: rewrites
+            .into_iter()
+            .map(|(j, p)| (j, p as AclPriority))
+            .collect(),
+    })
+}
+
 /// Statistics for AclOrch operations.
 #[derive(Debug, Clone, Default)]
 pub struct AclOrchStats {
@@ -162,6 +302,20 @@ pub struct AclOrchStats {
     pub rules_updated: u64,
     /// Number of SAI errors.
     pub sai_errors: u64,
+    /// Number of ASIC capability queries issued.
+    pub capability_queries: u64,
+    /// Number of tables created without unsupported fields.
+    pub tables_degraded: u64,
+    /// Number of rule counters created.
+    pub counters_created: u64,
+    /// Number of rule counters removed.
+    pub counters_removed: u64,
+    /// Number of rules moved between tables.
+    pub rules_moved: u64,
+    /// Number of priority rebalances performed.
+    pub priority_rebalances: u64,
+    /// Number of rules whose priority was rewritten by a rebalance.
+    pub rules_renumbered: u64,
 }
 
 /// AclOrch - Main ACL orchestration structure.
@@ -190,6 +344,10 @@ pub struct AclOrch {
     /// Action capabilities per stage.
     action_capabilities: HashMap<AclStage, AclActionCapabilities>,
 
+    /// ASIC table capabilities per (stage, bind point), queried once.
+    /// None records that the query is not supported.
+    table_capabilities: HashMap<(AclStage, AclBindPointType), Option<AclTableCapability>>,
+
     // ============ Metadata Management ============
     /// Allocated metadata values: value → reference count.
     metadata_refs: HashMap<u16, u32>,
@@ -216,6 +374,7 @@ impl AclOrch {
             tables: SyncMap::new(),
             table_oid_to_id: HashMap::new(),
             action_capabilities: HashMap::new(),
+            table_capabilities: HashMap::new(),
             metadata_refs: HashMap::new(),
             range_cache: Arc::new(AclRangeCache::new()),
             initialized: false,
@@ -242,6 +401,7 @@ impl AclOrch {
             create_pfcwd_table_type(),
             create_drop_table_type(),
             create_ctrlplane_table_type(),
+            create_dtel_flow_watchlist_table_type(),
         ];
 
         for tt in types {
@@ -367,7 +527,7 @@ impl AclOrch {
             .clone();
 
         // Create table
-        let table = AclTable::from_config(config, table_type).map_err(|e| {
+        let mut table = AclTable::from_config(config, table_type).map_err(|e| {
             error_log!("AclOrch", table_id = %table_id, error = %e, "ACL table validation error");
             audit_log!(
                 AuditRecord::new(AuditCategory::ResourceCreate, "AclOrch", "create_table")
@@ -378,11 +538,24 @@ impl AclOrch {
             AclOrchError::ValidationError(e)
         })?;
 
+        self.negotiate_capability(&mut table, config.allow_degraded)?;
+
         // In a real implementation, we would call SAI here to create the table
         // For now, just store it
         self.tables.insert(table_id.clone(), table.clone());
         self.stats.tables_created += 1;
 
+        if table.is_degraded() {
+            self.stats.tables_degraded += 1;
+            if let Some(ref write) = self
+                .callbacks
+                .as_ref()
+                .and_then(|cb| cb.write_degraded_table_state.clone())
+            {
+                write(&table_id, &table.unsupported);
+            }
+        }
+
         info_log!("AclOrch", table_id = %table_id, stage = ?table.stage, table_type = %type_name, "ACL table created successfully");
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceCreate, "AclOrch", "create_table")
@@ -394,13 +567,104 @@ impl AclOrch {
                     "table_type": type_name,
                     "stage": format!("{:?}", table.stage),
                     "priority_min": self.config.min_priority,
-                    "priority_max": self.config.max_priority
+                    "priority_max": self.config.max_priority,
+                    "unsupported_matches": table.unsupported.match_names(),
+                    "unsupported_actions": table.unsupported.action_names()
                 }))
         );
 
         Ok(())
     }
 
+    /// Returns the ASIC capability shared by all of the given bind points at
+    /// a stage, querying each (stage, bind point) pair at most once.
+    ///
+    /// Returns None if no capability is known, in which case every field is
+    /// assumed to be supported.
+    fn table_capability(
+        &mut self,
+        stage: AclStage,
+        bind_points: &std::collections::HashSet<AclBindPointType>,
+    ) -> Option<AclTableCapability> {
+        let query = self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.query_acl_capability.clone())?;
+
+        let mut combined: Option<AclTableCapability> = None;
+        for bp in bind_points {
+            let capability = match self.table_capabilities.get(&(stage, *bp)) {
+                Some(cached) => cached.clone(),
+                None => {
+                    self.stats.capability_queries += 1;
+                    let queried = query(stage, *bp);
+                    if queried.is_none() {
+                        warn_log!("AclOrch", stage = %stage, bind_point = %bp, "ACL capability query not supported, assuming all fields are supported");
+                    }
+                    self.table_capabilities
+                        .insert((stage, *bp), queried.clone());
+                    queried
+                }
+            };
+            if let Some(capability) = capability {
+                combined = Some(match combined {
+                    Some(c) => c.intersect(&capability),
+                    None => capability,
+                });
+            }
+        }
+        combined
+    }
+
+    /// Checks a new table's type against the ASIC capability.
+    ///
+    /// Unsupported fields reject the table unless `allow_degraded` is set, in
+    /// which case the table gets a reduced copy of its type and the omissions
+    /// are recorded on the table.
+    fn negotiate_capability(&mut self, table: &mut AclTable, allow_degraded: bool) -> Result<()> {
+        let Some(capability) = self.table_capability(table.stage, &table.table_type.bind_points)
+        else {
+            return Ok(());
+        };
+
+        let builder = AclTableTypeBuilder::from_table_type(&table.table_type);
+        let (builder, unsupported) = match builder.apply_capability(&capability, allow_degraded) {
+            Ok(result) => result,
+            Err(unsupported) => {
+                error_log!("AclOrch", table_id = %table.id, unsupported = %unsupported, "ACL table uses fields unsupported by the ASIC");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceCreate,
+                    "AclOrch",
+                    "create_table"
+                )
+                .with_object_id(format!("table:{}", table.id))
+                .with_object_type("ACL_TABLE")
+                .with_error(format!("Unsupported fields: {}", unsupported)));
+                return Err(AclOrchError::UnsupportedFields(
+                    table.id.clone(),
+                    unsupported.to_string(),
+                ));
+            }
+        };
+
+        if unsupported.is_empty() {
+            return Ok(());
+        }
+
+        let degraded_type = builder.build().map_err(|e| {
+            error_log!("AclOrch", table_id = %table.id, error = %e, "Degraded ACL table type is invalid");
+            AclOrchError::UnsupportedFields(
+                table.id.clone(),
+                format!("{} ({})", unsupported, e),
+            )
+        })?;
+
+        warn_log!("AclOrch", table_id = %table.id, unsupported = %unsupported, "Creating degraded ACL table without unsupported fields");
+        table.table_type = Arc::new(degraded_type);
+        table.unsupported = unsupported;
+        Ok(())
+    }
+
     /// Removes an ACL table.
     pub fn remove_table(&mut self, table_id: &str) -> Result<()> {
         debug_log!("AclOrch", table_id = %table_id, "Removing ACL table");
@@ -421,6 +685,26 @@ impl AclOrch {
             self.table_oid_to_id.remove(&table.table_oid);
         }
 
+        if table.is_degraded() {
+            if let Some(ref remove) = self
+                .callbacks
+                .as_ref()
+                .and_then(|cb| cb.remove_table_state.clone())
+            {
+                remove(table_id);
+            }
+        }
+
+        for rule in table.rules.values().filter(|r| r.has_counter()) {
+            Self::teardown_rule_counter(
+                self.callbacks.as_deref(),
+                table_id,
+                &rule.id,
+                rule.counter_oid,
+            );
+            self.stats.counters_removed += 1;
+        }
+
         // In a real implementation, we would:
         // 1. Remove all rules
         // 2. Unbind all ports
@@ -490,6 +774,9 @@ impl AclOrch {
             return Err(AclOrchError::ValidationError(e));
         }
 
+        let mut rule = rule;
+        rule.counter_oid = 0;
+
         let table = self
             .tables
             .get_mut(&table_id.to_string())
@@ -523,6 +810,39 @@ impl AclOrch {
 
         // In a real implementation, we would call SAI here to create the rule
 
+        if rule.counter_enabled {
+            match Self::setup_rule_counter(
+                self.callbacks.as_deref(),
+                table_id,
+                &rule_id,
+                table.table_oid,
+            ) {
+                Ok(counter_oid) => {
+                    if let Some(stored) = table.get_rule_mut(&rule_id) {
+                        stored.counter_oid = counter_oid;
+                    }
+                    if counter_oid != 0 {
+                        self.stats.counters_created += 1;
+                    }
+                }
+                Err(e) => {
+                    // Never leave a rule behind without its counter
+                    table.remove_rule(&rule_id);
+                    self.stats.sai_errors += 1;
+                    error_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, error = %e, "ACL rule counter setup failed");
+                    audit_log!(AuditRecord::new(
+                        AuditCategory::ResourceCreate,
+                        "AclOrch",
+                        "create_rule"
+                    )
+                    .with_object_id(format!("rule:{}:{}", table_id, rule_id))
+                    .with_object_type("ACL_RULE")
+                    .with_error(format!("Counter setup failed: {}", e)));
+                    return Err(AclOrchError::SaiError(e));
+                }
+            }
+        }
+
         self.stats.rules_created += 1;
 
         info_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, priority = rule.priority, "ACL rule created successfully");
@@ -581,6 +901,16 @@ impl AclOrch {
 
         // In a real implementation, we would call SAI here to remove the rule
 
+        if rule.has_counter() {
+            Self::teardown_rule_counter(
+                self.callbacks.as_deref(),
+                table_id,
+                rule_id,
+                rule.counter_oid,
+            );
+            self.stats.counters_removed += 1;
+        }
+
         self.stats.rules_deleted += 1;
 
         info_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, priority = rule.priority, "ACL rule removed successfully");
@@ -634,6 +964,11 @@ impl AclOrch {
                 AclOrchError::TableNotFound(table_id.to_string())
             })?;
 
+        // The counter follows the rule across updates; callers never supply one
+        let old_counter = table.get_rule(&rule_id).map(|r| r.counter_oid).unwrap_or(0);
+        let mut rule = rule;
+        rule.counter_oid = if rule.counter_enabled { old_counter } else { 0 };
+
         let old_rule = table
             .update_rule(rule.clone())
             .map_err(|e| {
@@ -651,6 +986,41 @@ impl AclOrch {
 
         // In a real implementation, we would call SAI here to update the rule
 
+        if rule.counter_enabled && old_counter == 0 {
+            match Self::setup_rule_counter(
+                self.callbacks.as_deref(),
+                table_id,
+                &rule_id,
+                table.table_oid,
+            ) {
+                Ok(counter_oid) => {
+                    if let Some(stored) = table.get_rule_mut(&rule_id) {
+                        stored.counter_oid = counter_oid;
+                    }
+                    if counter_oid != 0 {
+                        self.stats.counters_created += 1;
+                    }
+                }
+                Err(e) => {
+                    table.rules.insert(rule_id.clone(), old_rule);
+                    self.stats.sai_errors += 1;
+                    error_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, error = %e, "ACL rule counter setup failed");
+                    audit_log!(AuditRecord::new(
+                        AuditCategory::ResourceModify,
+                        "AclOrch",
+                        "update_rule"
+                    )
+                    .with_object_id(format!("rule:{}:{}", table_id, rule_id))
+                    .with_object_type("ACL_RULE")
+                    .with_error(format!("Counter setup failed: {}", e)));
+                    return Err(AclOrchError::SaiError(e));
+                }
+            }
+        } else if !rule.counter_enabled && old_counter != 0 {
+            Self::teardown_rule_counter(self.callbacks.as_deref(), table_id, &rule_id, old_counter);
+            self.stats.counters_removed += 1;
+        }
+
         self.stats.rules_updated += 1;
 
         info_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, old_priority = old_rule.priority, new_priority = rule.priority, "ACL rule updated successfully");
@@ -674,6 +1044,291 @@ impl AclOrch {
         Ok(old_rule)
     }
 
+    /// Inserts a rule directly between two adjacent rules of a table.
+    ///
+    /// `lower` and `upper` name the neighbours the new rule goes above and
+    /// below respectively (None for the bottom or top of the table). The rule's
+    /// priority is chosen by the orch and returned. If no free priority exists
+    /// and `auto_rebalance_priorities` is enabled, neighbouring rules are
+    /// renumbered to open a slot.
+    pub fn insert_rule_between(
+        &mut self,
+        table_id: &str,
+        rule: AclRule,
+        lower: Option<&str>,
+        upper: Option<&str>,
+    ) -> Result<AclPriority> {
+        let table = self
+            .tables
+            .get(&table_id.to_string())
+            .ok_or_else(|| AclOrchError::TableNotFound(table_id.to_string()))?;
+        if table.get_rule(&rule.id).is_some() {
+            return Err(AclOrchError::RuleAlreadyExists(
+                table_id.to_string(),
+                rule.id.clone(),
+            ));
+        }
+
+        let mut ordered: Vec<(String, AclPriority)> = table
+            .rules
+            .values()
+            .map(|r| (r.id.clone(), r.priority))
+            .collect();
+        ordered.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+        let position = |id: &str| {
+            ordered
+                .iter()
+                .position(|(r, _)| r == id)
+                .ok_or_else(|| AclOrchError::RuleNotFound(table_id.to_string(), id.to_string()))
+        };
+        let index = match (
+            lower.map(position).transpose()?,
+            upper.map(position).transpose()?,
+        ) {
+            (Some(l), Some(u)) if u == l + 1 => u,
+            (Some(l), None) if l + 1 == ordered.len() => ordered.len(),
+            (None, Some(0)) => 0,
+            (None, None) if ordered.is_empty() => 0,
+            _ => {
+                return Err(AclOrchError::InvalidConfig(format!(
+                    "Rules {:?} and {:?} are not adjacent in table {}",
+                    lower, upper, table_id
+                )))
+            }
+        };
+
+        // Programmed rules can only be renumbered through SAI.
+        let can_rewrite = self
+            .callbacks
+            .as_ref()
+            .is_some_and(|cb| cb.set_rule_priority.is_some());
+        let priorities: Vec<AclPriority> = ordered.iter().map(|(_, p)| *p).collect();
+        let slot = plan_priority_slot(
+            &priorities,
+            index,
+            self.config.min_priority,
+            self.config.max_priority,
+            self.config.auto_rebalance_priorities && can_rewrite,
+        )
+        .ok_or_else(|| {
+            warn_log!("AclOrch", table_id = %table_id, rule_id = %rule.id, "No free ACL priority between neighbouring rules");
+            AclOrchError::ResourceExhausted(format!(
+                "No free priority for rule {} in table {}",
+                rule.id, table_id
+            ))
+        })?;
+
+        if !slot.rewrites.is_empty() {
+            let remapped: Vec<(String, AclPriority, AclPriority)> = slot
+                .rewrites
+                .iter()
+                .map(|(j, new)| (ordered[*j].0.clone(), ordered[*j].1, *new))
+                .collect();
+            self.renumber_rules(table_id, &remapped)?;
+        }
+
+        let mut rule = rule;
+        rule.priority = slot.priority;
+        self.add_rule(table_id, rule)?;
+        Ok(slot.priority)
+    }
+
+    /// Rewrites rule priorities (rule, old, new), restoring the already
+    /// rewritten entries if SAI rejects one.
+    ///
+    /// `remapped` is a run shifted away from the new slot, nearest rule
+    /// first. It is written from the far end so every rule moves into a
+    /// priority its neighbour has already vacated, and no two programmed
+    /// entries share a priority at any point.
+    fn renumber_rules(
+        &mut self,
+        table_id: &str,
+        remapped: &[(String, AclPriority, AclPriority)],
+    ) -> Result<()> {
+        let Some(set_priority) = self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.set_rule_priority.clone())
+        else {
+            return Err(AclOrchError::InvalidConfig(format!(
+                "Cannot renumber rules of table {} without a set_rule_priority callback",
+                table_id
+            )));
+        };
+
+        for (pending, (rule_id, old, new)) in remapped.iter().enumerate().rev() {
+            if let Err(e) = set_priority(table_id, rule_id, *new) {
+                for (undo_id, undo_old, _) in &remapped[pending + 1..] {
+                    let _ = set_priority(table_id, undo_id, *undo_old);
+                }
+                self.stats.sai_errors += 1;
+                error_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, old_priority = *old, new_priority = *new, error = %e, "ACL priority rebalance failed");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "AclOrch",
+                    "rebalance_priorities"
+                )
+                .with_object_id(format!("table:{}", table_id))
+                .with_object_type("ACL_TABLE")
+                .with_error(format!("Failed to set priority of rule {}: {}", rule_id, e)));
+                return Err(AclOrchError::SaiError(e));
+            }
+        }
+
+        if let Some(table) = self.tables.get_mut(&table_id.to_string()) {
+            for (rule_id, _, new) in remapped {
+                if let Some(rule) = table.get_rule_mut(rule_id) {
+                    rule.set_priority(*new);
+                }
+            }
+        }
+        self.stats.priority_rebalances += 1;
+        self.stats.rules_renumbered += remapped.len() as u64;
+
+        info_log!("AclOrch", table_id = %table_id, rewritten = remapped.len(), "ACL rule priorities rebalanced");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "AclOrch",
+            "rebalance_priorities"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("table:{}", table_id))
+        .with_object_type("ACL_TABLE")
+        .with_details(serde_json::json!({
+            "table_id": table_id,
+            "remapped": remapped
+                .iter()
+                .map(|(rule_id, old, new)| serde_json::json!({
+                    "rule_id": rule_id,
+                    "old_priority": old,
+                    "new_priority": new
+                }))
+                .collect::<Vec<_>>()
+        })));
+
+        Ok(())
+    }
+
+    /// Moves a rule to a different table.
+    ///
+    /// SAI counters belong to a table, so the rule gets a fresh counter in
+    /// the destination before the original rule and counter are removed.
+    pub fn move_rule(
+        &mut self,
+        src_table_id: &str,
+        dst_table_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        let rule = self
+            .tables
+            .get(&src_table_id.to_string())
+            .ok_or_else(|| AclOrchError::TableNotFound(src_table_id.to_string()))?
+            .get_rule(rule_id)
+            .cloned()
+            .ok_or_else(|| {
+                AclOrchError::RuleNotFound(src_table_id.to_string(), rule_id.to_string())
+            })?;
+
+        self.add_rule(dst_table_id, rule)?;
+        self.remove_rule(src_table_id, rule_id)?;
+        self.stats.rules_moved += 1;
+
+        info_log!("AclOrch", rule_id = %rule_id, src_table = %src_table_id, dst_table = %dst_table_id, "ACL rule moved");
+        Ok(())
+    }
+
+    /// Returns the counter map key used in COUNTERS_DB for a rule.
+    pub fn counter_map_key(table_id: &str, rule_id: &str) -> String {
+        format!("{}:{}", table_id, rule_id)
+    }
+
+    /// Returns the current rule → counter OID mapping, keyed like the
+    /// COUNTERS_DB `ACL_COUNTER_RULE_MAP` table.
+    pub fn counter_rule_map(&self) -> HashMap<String, RawSaiObjectId> {
+        self.tables
+            .values()
+            .flat_map(|t| {
+                t.rules
+                    .values()
+                    .filter(|r| r.has_counter())
+                    .map(|r| (Self::counter_map_key(&t.id, &r.id), r.counter_oid))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Creates a rule counter, attaches it, registers it for polling and
+    /// publishes its mapping. Every completed step is undone on failure.
+    ///
+    /// Returns 0 if counter callbacks are not wired up.
+    fn setup_rule_counter(
+        callbacks: Option<&AclOrchCallbacks>,
+        table_id: &str,
+        rule_id: &str,
+        table_oid: RawSaiObjectId,
+    ) -> std::result::Result<RawSaiObjectId, String> {
+        let Some(cb) = callbacks else {
+            return Ok(0);
+        };
+        let Some(ref create) = cb.create_acl_counter else {
+            return Ok(0);
+        };
+
+        let counter_oid = create(table_oid)?;
+        let remove_counter = || {
+            if let Some(ref remove) = cb.remove_acl_counter {
+                if let Err(e) = remove(counter_oid) {
+                    warn_log!("AclOrch", counter_oid = %counter_oid, error = %e, "Failed to remove ACL counter during rollback");
+                }
+            }
+        };
+
+        if let Some(ref attach) = cb.attach_acl_counter {
+            if let Err(e) = attach(table_id, rule_id, counter_oid) {
+                remove_counter();
+                return Err(format!("Failed to attach counter: {}", e));
+            }
+        }
+
+        if let Some(ref register) = cb.register_acl_counter {
+            if let Err(e) = register(counter_oid) {
+                remove_counter();
+                return Err(format!("Failed to register counter for polling: {}", e));
+            }
+        }
+
+        if let Some(ref write) = cb.write_counter_map {
+            write(&Self::counter_map_key(table_id, rule_id), counter_oid);
+        }
+
+        Ok(counter_oid)
+    }
+
+    /// Deregisters, unmaps and destroys a rule counter.
+    fn teardown_rule_counter(
+        callbacks: Option<&AclOrchCallbacks>,
+        table_id: &str,
+        rule_id: &str,
+        counter_oid: RawSaiObjectId,
+    ) {
+        let Some(cb) = callbacks else {
+            return;
+        };
+
+        if let Some(ref unregister) = cb.unregister_acl_counter {
+            unregister(counter_oid);
+        }
+        if let Some(ref remove_map) = cb.remove_counter_map {
+            remove_map(&Self::counter_map_key(table_id, rule_id));
+        }
+        if let Some(ref remove) = cb.remove_acl_counter {
+            if let Err(e) = remove(counter_oid) {
+                warn_log!("AclOrch", table_id = %table_id, rule_id = %rule_id, error = %e, "Failed to remove ACL counter");
+            }
+        }
+    }
+
     // ============ Port Binding Operations ============
 
     /// Binds a port to a table.
@@ -1905,6 +2560,7 @@ mod tests {
         assert!(names.contains(&"PFCWD".to_string()));
         assert!(names.contains(&"DROP".to_string()));
         assert!(names.contains(&"CTRLPLANE".to_string()));
+        assert!(names.contains(&"DTEL_FLOW_WATCHLIST".to_string()));
     }
 
     #[test]
@@ -1934,4 +2590,548 @@ mod tests {
         assert!(orch.has_table("L3Table"));
         assert!(orch.has_table("MirrorTable"));
     }
+
+    type StateLog = Arc<std::sync::Mutex<Vec<(String, Option<AclUnsupportedFields>)>>>;
+
+    /// Builds an orch whose ASIC lacks inner L4 port matching on every bind
+    /// point, with a TUNNEL table type that requests it.
+    fn capability_orch() -> (AclOrch, Arc<std::sync::atomic::AtomicUsize>, StateLog) {
+        use super::super::types::{AclActionType, AclMatchField};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let queries = Arc::new(AtomicUsize::new(0));
+        let state: StateLog = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut orch = AclOrch::new(AclOrchConfig::default());
+        orch.register_table_type(
+            AclTableTypeBuilder::new()
+                .with_name("TUNNEL")
+                .with_bind_points([AclBindPointType::Port, AclBindPointType::Lag])
+                .with_matches([
+                    AclMatchField::SrcIp,
+                    AclMatchField::DstIp,
+                    AclMatchField::InnerL4SrcPort,
+                    AclMatchField::InnerL4DstPort,
+                ])
+                .with_action(AclActionType::PacketAction)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let q = queries.clone();
+        let written = state.clone();
+        let removed = state.clone();
+        orch.set_callbacks(AclOrchCallbacks {
+            query_acl_capability: Some(Arc::new(move |_stage, _bp| {
+                q.fetch_add(1, Ordering::SeqCst);
+                let l3 = create_l3_table_type();
+                Some(AclTableCapability {
+                    matches: l3.matches,
+                    actions: l3.actions,
+                })
+            })),
+            write_degraded_table_state: Some(Arc::new(move |table, unsupported| {
+                written
+                    .lock()
+                    .unwrap()
+                    .push((table.to_string(), Some(unsupported.clone())));
+            })),
+            remove_table_state: Some(Arc::new(move |table| {
+                removed.lock().unwrap().push((table.to_string(), None));
+            })),
+            ..Default::default()
+        });
+
+        (orch, queries, state)
+    }
+
+    #[test]
+    fn test_create_table_rejects_unsupported_fields() {
+        use std::sync::atomic::Ordering;
+        let (mut orch, queries, state) = capability_orch();
+
+        let config = AclTableConfig::new()
+            .with_id("TunnelTable")
+            .with_type("TUNNEL")
+            .with_stage(AclStage::Ingress);
+        let err = orch.create_table(&config).unwrap_err();
+
+        match err {
+            AclOrchError::UnsupportedFields(table, fields) => {
+                assert_eq!(table, "TunnelTable");
+                assert!(fields.contains("INNER_L4_DST_PORT"));
+                assert!(fields.contains("INNER_L4_SRC_PORT"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(!orch.has_table("TunnelTable"));
+        assert!(state.lock().unwrap().is_empty());
+
+        // Port and LAG were each queried once; later tables reuse the cache
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+        let l3 = AclTableConfig::new()
+            .with_id("L3Table")
+            .with_type("L3")
+            .with_stage(AclStage::Ingress);
+        orch.create_table(&l3).unwrap();
+        assert!(!orch.get_table("L3Table").unwrap().is_degraded());
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+        assert_eq!(orch.stats().capability_queries, 2);
+    }
+
+    #[test]
+    fn test_create_table_degraded_records_omissions() {
+        use super::super::types::AclMatchField;
+        let (mut orch, _queries, state) = capability_orch();
+
+        let config = AclTableConfig::new()
+            .with_id("TunnelTable")
+            .with_type("TUNNEL")
+            .with_stage(AclStage::Ingress)
+            .with_allow_degraded(true);
+        orch.create_table(&config).unwrap();
+
+        let table = orch.get_table("TunnelTable").unwrap();
+        assert!(table.is_degraded());
+        assert!(table.table_type.supports_match(AclMatchField::SrcIp));
+        assert!(!table
+            .table_type
+            .supports_match(AclMatchField::InnerL4SrcPort));
+        assert!(!table
+            .table_type
+            .supports_match(AclMatchField::InnerL4DstPort));
+        // The registered type is left untouched
+        assert!(orch
+            .get_table_type("TUNNEL")
+            .unwrap()
+            .supports_match(AclMatchField::InnerL4SrcPort));
+        assert_eq!(orch.stats().tables_degraded, 1);
+
+        {
+            let log = state.lock().unwrap();
+            assert_eq!(log.len(), 1);
+            assert_eq!(log[0].0, "TunnelTable");
+            let unsupported = log[0].1.as_ref().unwrap();
+            assert_eq!(
+                unsupported.match_names(),
+                vec!["INNER_L4_DST_PORT", "INNER_L4_SRC_PORT"]
+            );
+            assert!(unsupported.actions.is_empty());
+        }
+
+        orch.remove_table("TunnelTable").unwrap();
+        let log = state.lock().unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1], ("TunnelTable".to_string(), None));
+    }
+
+    #[test]
+    fn test_capability_query_unsupported_assumes_full_support() {
+        let mut orch = AclOrch::new(AclOrchConfig::default());
+        orch.set_callbacks(AclOrchCallbacks {
+            query_acl_capability: Some(Arc::new(|_, _| None)),
+            ..Default::default()
+        });
+
+        let config = AclTableConfig::new()
+            .with_id("L3Table")
+            .with_type("L3")
+            .with_stage(AclStage::Ingress);
+        orch.create_table(&config).unwrap();
+        assert!(!orch.get_table("L3Table").unwrap().is_degraded());
+
+        // The failed query is cached as well
+        let config = config.with_id("L3Table2");
+        orch.create_table(&config).unwrap();
+        assert_eq!(orch.stats().capability_queries, 2);
+    }
+
+    /// In-memory stand-in for the SAI counter objects, the ACL flex counter
+    /// group and the COUNTERS_DB rule map.
+    #[derive(Default)]
+    struct CounterBackend {
+        next_oid: RawSaiObjectId,
+        counters: std::collections::HashSet<RawSaiObjectId>,
+        polled: std::collections::HashSet<RawSaiObjectId>,
+        rule_map: HashMap<String, RawSaiObjectId>,
+        fail_attach: bool,
+    }
+
+    fn counter_orch() -> (AclOrch, Arc<std::sync::Mutex<CounterBackend>>) {
+        let backend = Arc::new(std::sync::Mutex::new(CounterBackend {
+            next_oid: 0x9000,
+            ..Default::default()
+        }));
+
+        let (b1, b2, b3, b4, b5, b6, b7) = (
+            backend.clone(),
+            backend.clone(),
+            backend.clone(),
+            backend.clone(),
+            backend.clone(),
+            backend.clone(),
+            backend.clone(),
+        );
+        let mut orch = AclOrch::new(AclOrchConfig::default());
+        orch.set_callbacks(AclOrchCallbacks {
+            create_acl_counter: Some(Arc::new(move |_table_oid| {
+                let mut b = b1.lock().unwrap();
+                b.next_oid += 1;
+                let oid = b.next_oid;
+                b.counters.insert(oid);
+                Ok(oid)
+            })),
+            remove_acl_counter: Some(Arc::new(move |oid| {
+                b2.lock().unwrap().counters.remove(&oid);
+                Ok(())
+            })),
+            attach_acl_counter: Some(Arc::new(move |_table, _rule, _oid| {
+                if b3.lock().unwrap().fail_attach {
+                    Err("SAI_STATUS_FAILURE".to_string())
+                } else {
+                    Ok(())
+                }
+            })),
+            register_acl_counter: Some(Arc::new(move |oid| {
+                b4.lock().unwrap().polled.insert(oid);
+                Ok(())
+            })),
+            unregister_acl_counter: Some(Arc::new(move |oid| {
+                b5.lock().unwrap().polled.remove(&oid);
+            })),
+            write_counter_map: Some(Arc::new(move |key, oid| {
+                b6.lock().unwrap().rule_map.insert(key.to_string(), oid);
+            })),
+            remove_counter_map: Some(Arc::new(move |key| {
+                b7.lock().unwrap().rule_map.remove(key);
+            })),
+            ..Default::default()
+        });
+
+        for id in ["TableA", "TableB"] {
+            let config = AclTableConfig::new()
+                .with_id(id)
+                .with_type("L3")
+                .with_stage(AclStage::Ingress);
+            orch.create_table(&config).unwrap();
+        }
+
+        (orch, backend)
+    }
+
+    fn counted_rule(id: &str, counter: bool) -> AclRule {
+        AclRule::packet(id)
+            .with_priority(100)
+            .with_match(AclRuleMatch::ip_protocol(6))
+            .with_action(AclRuleAction::drop())
+            .with_counter(counter)
+    }
+
+    #[test]
+    fn test_rule_counter_map_add_update_delete() {
+        let (mut orch, backend) = counter_orch();
+
+        orch.add_rule("TableA", counted_rule("rule1", true))
+            .unwrap();
+        orch.add_rule("TableA", counted_rule("rule2", false))
+            .unwrap();
+
+        let counter = orch.get_rule("TableA", "rule1").unwrap().counter_oid;
+        assert_ne!(counter, 0);
+        assert_eq!(orch.get_rule("TableA", "rule2").unwrap().counter_oid, 0);
+        {
+            let b = backend.lock().unwrap();
+            assert_eq!(b.rule_map.len(), 1);
+            assert_eq!(b.rule_map.get("TableA:rule1"), Some(&counter));
+            assert!(b.polled.contains(&counter));
+            assert_eq!(b.rule_map, orch.counter_rule_map());
+        }
+
+        // Updating keeps the same counter; toggling adds and removes one
+        orch.update_rule("TableA", counted_rule("rule1", true).with_priority(200))
+            .unwrap();
+        assert_eq!(
+            orch.get_rule("TableA", "rule1").unwrap().counter_oid,
+            counter
+        );
+        orch.update_rule("TableA", counted_rule("rule2", true))
+            .unwrap();
+        orch.update_rule("TableA", counted_rule("rule1", false))
+            .unwrap();
+        {
+            let b = backend.lock().unwrap();
+            let rule2_counter = orch.get_rule("TableA", "rule2").unwrap().counter_oid;
+            assert_eq!(b.rule_map.len(), 1);
+            assert_eq!(b.rule_map.get("TableA:rule2"), Some(&rule2_counter));
+            assert!(!b.counters.contains(&counter));
+            assert!(!b.polled.contains(&counter));
+            assert_eq!(b.rule_map, orch.counter_rule_map());
+        }
+
+        orch.remove_rule("TableA", "rule2").unwrap();
+        let b = backend.lock().unwrap();
+        assert!(b.rule_map.is_empty());
+        assert!(b.counters.is_empty());
+        assert!(b.polled.is_empty());
+        assert_eq!(orch.stats().counters_created, 2);
+        assert_eq!(orch.stats().counters_removed, 2);
+    }
+
+    #[test]
+    fn test_rule_counter_attach_failure_rolls_back() {
+        let (mut orch, backend) = counter_orch();
+        backend.lock().unwrap().fail_attach = true;
+
+        let result = orch.add_rule("TableA", counted_rule("rule1", true));
+        assert!(matches!(result, Err(AclOrchError::SaiError(_))));
+        assert!(orch.get_rule("TableA", "rule1").is_none());
+        assert_eq!(orch.stats().rules_created, 0);
+        {
+            let b = backend.lock().unwrap();
+            assert!(b.counters.is_empty());
+            assert!(b.rule_map.is_empty());
+        }
+
+        // A failed counter on update leaves the previous rule in place
+        backend.lock().unwrap().fail_attach = false;
+        orch.add_rule("TableA", counted_rule("rule1", false))
+            .unwrap();
+        backend.lock().unwrap().fail_attach = true;
+        let result = orch.update_rule("TableA", counted_rule("rule1", true));
+        assert!(matches!(result, Err(AclOrchError::SaiError(_))));
+        assert!(!orch.get_rule("TableA", "rule1").unwrap().counter_enabled);
+        assert!(backend.lock().unwrap().counters.is_empty());
+    }
+
+    #[test]
+    fn test_rule_counter_follows_moved_rule() {
+        let (mut orch, backend) = counter_orch();
+
+        orch.add_rule("TableA", counted_rule("rule1", true))
+            .unwrap();
+        let old_counter = orch.get_rule("TableA", "rule1").unwrap().counter_oid;
+
+        orch.move_rule("TableA", "TableB", "rule1").unwrap();
+
+        assert!(orch.get_rule("TableA", "rule1").is_none());
+        let new_counter = orch.get_rule("TableB", "rule1").unwrap().counter_oid;
+        assert_ne!(new_counter, 0);
+        assert_ne!(new_counter, old_counter);
+
+        {
+            let b = backend.lock().unwrap();
+            assert_eq!(b.rule_map.len(), 1);
+            assert_eq!(b.rule_map.get("TableB:rule1"), Some(&new_counter));
+            assert!(!b.counters.contains(&old_counter));
+            assert!(b.polled.contains(&new_counter));
+            assert_eq!(b.rule_map, orch.counter_rule_map());
+        }
+        assert_eq!(orch.stats().rules_moved, 1);
+
+        // Removing the table cleans up the counters of its rules
+        orch.remove_table("TableB").unwrap();
+        let b = backend.lock().unwrap();
+        assert!(b.rule_map.is_empty());
+        assert!(b.counters.is_empty());
+    }
+
+    fn rebalance_orch(
+        enabled: bool,
+    ) -> (AclOrch, Arc<std::sync::Mutex<Vec<(String, AclPriority)>>>) {
+        let writes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let w = writes.clone();
+
+        let mut orch = AclOrch::new(AclOrchConfig {
+            min_priority: 10,
+            max_priority: 100,
+            auto_rebalance_priorities: enabled,
+            ..Default::default()
+        });
+        orch.set_callbacks(AclOrchCallbacks {
+            set_rule_priority: Some(Arc::new(move |_table, rule, priority| {
+                w.lock().unwrap().push((rule.to_string(), priority));
+                Ok(())
+            })),
+            ..Default::default()
+        });
+        let config = AclTableConfig::new()
+            .with_id("TestTable")
+            .with_type("L3")
+            .with_stage(AclStage::Ingress);
+        orch.create_table(&config).unwrap();
+
+        // A densely packed band: r10..r14 at 10..14, plus r50 at 50
+        for p in [10, 11, 12, 13, 14, 50] {
+            let rule = AclRule::packet(format!("r{}", p))
+                .with_priority(p)
+                .with_match(AclRuleMatch::ip_protocol(6))
+                .with_action(AclRuleAction::drop());
+            orch.add_rule("TestTable", rule).unwrap();
+        }
+
+        (orch, writes)
+    }
+
+    fn rule_order(orch: &AclOrch) -> Vec<String> {
+        let table = orch.get_table("TestTable").unwrap();
+        let mut rules: Vec<_> = table.rules.values().collect();
+        rules.sort_by_key(|r| r.priority);
+        rules.iter().map(|r| r.id.clone()).collect()
+    }
+
+    fn new_rule(id: &str) -> AclRule {
+        AclRule::packet(id)
+            .with_match(AclRuleMatch::ip_protocol(17))
+            .with_action(AclRuleAction::drop())
+    }
+
+    #[test]
+    fn test_insert_rule_between_uses_free_gap() {
+        let (mut orch, writes) = rebalance_orch(false);
+
+        let priority = orch
+            .insert_rule_between("TestTable", new_rule("new"), Some("r14"), Some("r50"))
+            .unwrap();
+        assert_eq!(priority, 32);
+        assert!(writes.lock().unwrap().is_empty());
+        assert_eq!(
+            rule_order(&orch),
+            vec!["r10", "r11", "r12", "r13", "r14", "new", "r50"]
+        );
+    }
+
+    #[test]
+    fn test_insert_rule_into_full_band_without_rebalance() {
+        let (mut orch, writes) = rebalance_orch(false);
+
+        let result =
+            orch.insert_rule_between("TestTable", new_rule("new"), Some("r11"), Some("r12"));
+        assert!(matches!(result, Err(AclOrchError::ResourceExhausted(_))));
+        assert!(orch.get_rule("TestTable", "new").is_none());
+        assert!(writes.lock().unwrap().is_empty());
+        assert_eq!(orch.get_rule("TestTable", "r12").unwrap().priority, 12);
+    }
+
+    #[test]
+    fn test_insert_rule_into_full_band_rebalances() {
+        let (mut orch, writes) = rebalance_orch(true);
+
+        // Shifting r12..r14 up rewrites three rules; r10..r11 can't move
+        // below the band minimum, so the upward shift is taken
+        let priority = orch
+            .insert_rule_between("TestTable", new_rule("new"), Some("r11"), Some("r12"))
+            .unwrap();
+        assert_eq!(priority, 12);
+        assert_eq!(
+            rule_order(&orch),
+            vec!["r10", "r11", "new", "r12", "r13", "r14", "r50"]
+        );
+        assert_eq!(
+            *writes.lock().unwrap(),
+            vec![
+                ("r14".to_string(), 15),
+                ("r13".to_string(), 14),
+                ("r12".to_string(), 13)
+            ]
+        );
+        assert_eq!(orch.stats().rules_renumbered, 3);
+        assert_eq!(orch.stats().priority_rebalances, 1);
+
+        writes.lock().unwrap().clear();
+        let priority = orch
+            .insert_rule_between("TestTable", new_rule("new2"), Some("r14"), Some("r50"))
+            .unwrap();
+        assert!(priority > 15 && priority < 50);
+        assert!(writes.lock().unwrap().is_empty());
+
+        let priority = orch
+            .insert_rule_between("TestTable", new_rule("new3"), Some("r13"), Some("r14"))
+            .unwrap();
+        assert_eq!(priority, 15);
+        assert_eq!(*writes.lock().unwrap(), vec![("r14".to_string(), 16)]);
+        assert_eq!(
+            rule_order(&orch),
+            vec!["r10", "r11", "new", "r12", "r13", "new3", "r14", "new2", "r50"]
+        );
+    }
+
+    #[test]
+    fn test_insert_rule_into_full_band_without_priority_callback() {
+        let (mut orch, writes) = rebalance_orch(true);
+        orch.set_callbacks(AclOrchCallbacks::default());
+
+        let result =
+            orch.insert_rule_between("TestTable", new_rule("new"), Some("r11"), Some("r12"));
+        assert!(matches!(result, Err(AclOrchError::ResourceExhausted(_))));
+        assert!(orch.get_rule("TestTable", "new").is_none());
+        assert!(writes.lock().unwrap().is_empty());
+        assert_eq!(orch.get_rule("TestTable", "r12").unwrap().priority, 12);
+        assert_eq!(orch.stats().priority_rebalances, 0);
+
+        let result = orch.renumber_rules("TestTable", &[("r12".to_string(), 12, 13)]);
+        assert!(matches!(result, Err(AclOrchError::InvalidConfig(_))));
+        assert_eq!(orch.get_rule("TestTable", "r12").unwrap().priority, 12);
+    }
+
+    #[test]
+    fn test_rebalance_failure_restores_rewritten_rules() {
+        let (mut orch, _) = rebalance_orch(true);
+        let writes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let w = writes.clone();
+        orch.set_callbacks(AclOrchCallbacks {
+            set_rule_priority: Some(Arc::new(move |_table, rule, priority| {
+                w.lock().unwrap().push((rule.to_string(), priority));
+                if rule == "r12" {
+                    return Err("rejected".to_string());
+                }
+                Ok(())
+            })),
+            ..Default::default()
+        });
+
+        let result =
+            orch.insert_rule_between("TestTable", new_rule("new"), Some("r11"), Some("r12"));
+        assert!(matches!(result, Err(AclOrchError::SaiError(_))));
+        // Written from the far end, then restored in reverse
+        assert_eq!(
+            *writes.lock().unwrap(),
+            vec![
+                ("r14".to_string(), 15),
+                ("r13".to_string(), 14),
+                ("r12".to_string(), 13),
+                ("r13".to_string(), 13),
+                ("r14".to_string(), 14)
+            ]
+        );
+        assert!(orch.get_rule("TestTable", "new").is_none());
+        assert_eq!(orch.get_rule("TestTable", "r14").unwrap().priority, 14);
+    }
+
+    #[test]
+    fn test_insert_rule_between_requires_adjacent_neighbours() {
+        let (mut orch, _writes) = rebalance_orch(true);
+
+        let result =
+            orch.insert_rule_between("TestTable", new_rule("new"), Some("r10"), Some("r12"));
+        assert!(matches!(result, Err(AclOrchError::InvalidConfig(_))));
+        let result = orch.insert_rule_between("TestTable", new_rule("new"), Some("missing"), None);
+        assert!(matches!(result, Err(AclOrchError::RuleNotFound(_, _))));
+    }
+
+    #[test]
+    fn test_plan_priority_slot_minimal_rewrites() {
+        // Downward run (2 rules) is shorter than the upward run (3 rules)
+        let slot = plan_priority_slot(&[20, 30, 31, 32, 33, 34], 3, 0, 100, true).unwrap();
+        assert_eq!(slot.priority, 31);
+        assert_eq!(slot.rewrites, vec![(2, 30), (1, 29)]);
+
+        // Equal neighbours need a two-step opening
+        let slot = plan_priority_slot(&[5, 5], 1, 0, 100, true).unwrap();
+        assert_eq!(slot.priority, 6);
+        assert_eq!(slot.rewrites, vec![(1, 7)]);
+
+        // A completely full range cannot be rebalanced
+        assert!(plan_priority_slot(&[0, 1, 2], 1, 0, 2, true).is_none());
+    }
 }
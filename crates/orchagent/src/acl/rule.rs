@@ -5,6 +5,7 @@
 //! - Actions (what to do with matched packets)
 //! - Priority (which rule wins on multiple matches)
 
+use sonic_sai::api::AclEntryAttrBuilder;
 use sonic_sai::types::RawSaiObjectId;
 use sonic_types::{IpAddress, IpPrefix, MacAddress};
 use std::collections::{HashMap, HashSet};
@@ -555,6 +556,31 @@ impl AclRule {
         self.matches.insert(AclMatchField::InPorts, match_cond);
     }
 
+    /// Returns the SAI entry attributes for this rule in the given table.
+    ///
+    /// Covers the priority, IN_PORTS match, packet action and counter.
+    /// Redirect and mirror targets are resolved by name through the orch
+    /// callbacks and are added by the caller.
+    pub fn to_sai_entry_attrs(&self, table_oid: RawSaiObjectId) -> AclEntryAttrBuilder {
+        let mut builder = AclEntryAttrBuilder::new()
+            .table_id(table_oid)
+            .priority(self.priority)
+            .admin_state(true);
+        if let Some(ports) = self.get_in_ports() {
+            builder = builder.in_ports(ports);
+        }
+        if let Some(AclActionValue::PacketAction(action)) = self
+            .get_action(AclActionType::PacketAction)
+            .map(|a| &a.value)
+        {
+            builder = builder.packet_action((*action).into());
+        }
+        if self.has_counter() {
+            builder = builder.counter(self.counter_oid);
+        }
+        builder
+    }
+
     /// Returns all match fields used by this rule.
     pub fn match_fields(&self) -> HashSet<AclMatchField> {
         self.matches.keys().copied().collect()
@@ -736,4 +762,37 @@ mod tests {
         assert_eq!(ports.len(), 1);
         assert_eq!(ports[0], 0xABCD);
     }
+
+    #[test]
+    fn test_to_sai_entry_attrs() {
+        use sonic_sai::SaiAttributeValue;
+
+        let mut rule = AclRule::packet("rule1")
+            .with_priority(1000)
+            .with_match(AclRuleMatch::in_ports(vec![0x1001, 0x1002]))
+            .with_action(AclRuleAction::drop());
+
+        let attrs = rule.to_sai_entry_attrs(0x7000);
+        assert_eq!(attrs.len(), 5);
+        assert_eq!(
+            attrs.get(AclEntryAttrBuilder::TABLE_ID),
+            Some(&SaiAttributeValue::ObjectId(0x7000))
+        );
+        assert_eq!(
+            attrs.get(AclEntryAttrBuilder::FIELD_IN_PORTS),
+            Some(&SaiAttributeValue::ObjectList(vec![0x1001, 0x1002]))
+        );
+        assert_eq!(
+            attrs.get(AclEntryAttrBuilder::ACTION_PACKET_ACTION),
+            Some(&SaiAttributeValue::I32(0))
+        );
+        assert!(attrs.get(AclEntryAttrBuilder::ACTION_COUNTER).is_none());
+
+        rule.counter_oid = 0x8000;
+        let attrs = rule.to_sai_entry_attrs(0x7000);
+        assert_eq!(
+            attrs.get(AclEntryAttrBuilder::ACTION_COUNTER),
+            Some(&SaiAttributeValue::ObjectId(0x8000))
+        );
+    }
 }
@@ -9,7 +9,7 @@ use std::fmt;
 use std::sync::Arc;
 
 use super::rule::AclRule;
-use super::table_type::AclTableType;
+use super::table_type::{AclTableType, AclUnsupportedFields};
 use super::types::{AclRuleId, AclStage, AclTableId};
 
 /// ACL table configuration from CONFIG_DB.
@@ -25,6 +25,9 @@ pub struct AclTableConfig {
     pub ports: Vec<String>,
     /// Description.
     pub description: Option<String>,
+    /// Create the table without fields the ASIC does not support instead of
+    /// rejecting it.
+    pub allow_degraded: bool,
 }
 
 impl AclTableConfig {
@@ -57,6 +60,12 @@ impl AclTableConfig {
         self
     }
 
+    /// Allows the table to be created without unsupported fields.
+    pub fn with_allow_degraded(mut self, allow_degraded: bool) -> Self {
+        self.allow_degraded = allow_degraded;
+        self
+    }
+
     /// Parses a field from CONFIG_DB.
     pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
         match field.to_uppercase().as_str() {
@@ -76,6 +85,11 @@ impl AclTableConfig {
             "POLICY_DESC" | "DESCRIPTION" => {
                 self.description = Some(value.to_string());
             }
+            "ALLOW_DEGRADED" => {
+                self.allow_degraded = value
+                    .parse()
+                    .map_err(|_| format!("Invalid ALLOW_DEGRADED value: {}", value))?;
+            }
             _ => {
                 // Ignore unknown fields for forward compatibility
             }
@@ -133,6 +147,8 @@ pub struct AclTable {
     pub rules: HashMap<AclRuleId, AclRule>,
     /// Whether to bind to the switch (for PFCWD-style tables).
     pub bind_to_switch: bool,
+    /// Fields omitted from the table type because the ASIC lacks them.
+    pub unsupported: AclUnsupportedFields,
 }
 
 impl AclTable {
@@ -149,6 +165,7 @@ impl AclTable {
             pending_ports: HashSet::new(),
             rules: HashMap::new(),
             bind_to_switch: false,
+            unsupported: AclUnsupportedFields::default(),
         }
     }
 
@@ -185,6 +202,11 @@ impl AclTable {
         Ok(table)
     }
 
+    /// Returns true if the table was created without some requested fields.
+    pub fn is_degraded(&self) -> bool {
+        !self.unsupported.is_empty()
+    }
+
     /// Returns the SAI table OID.
     pub fn sai_id(&self) -> RawSaiObjectId {
         self.table_oid
@@ -413,6 +435,11 @@ mod tests {
         assert_eq!(config.stage, Some(AclStage::Ingress));
         assert_eq!(config.ports, vec!["Ethernet0", "Ethernet4"]);
         assert_eq!(config.description, Some("Test description".to_string()));
+        assert!(!config.allow_degraded);
+
+        config.parse_field("ALLOW_DEGRADED", "true").unwrap();
+        assert!(config.allow_degraded);
+        assert!(config.parse_field("ALLOW_DEGRADED", "maybe").is_err());
     }
 
     #[test]
@@ -104,6 +104,66 @@ impl fmt::Display for AclTableType {
     }
 }
 
+/// Match fields and actions an ASIC implements for one stage and bind point.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AclTableCapability {
+    /// Implemented match fields.
+    pub matches: HashSet<AclMatchField>,
+    /// Implemented actions.
+    pub actions: HashSet<AclActionType>,
+}
+
+impl AclTableCapability {
+    /// Returns the capability shared by both `self` and `other`.
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self {
+            matches: self.matches.intersection(&other.matches).copied().collect(),
+            actions: self.actions.intersection(&other.actions).copied().collect(),
+        }
+    }
+}
+
+/// Match fields and actions requested by a table type that the ASIC lacks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AclUnsupportedFields {
+    /// Unsupported match fields.
+    pub matches: Vec<AclMatchField>,
+    /// Unsupported actions.
+    pub actions: Vec<AclActionType>,
+}
+
+impl AclUnsupportedFields {
+    /// Returns true if nothing is unsupported.
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty() && self.actions.is_empty()
+    }
+
+    /// Returns the unsupported match field names, sorted.
+    pub fn match_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.matches.iter().map(|m| m.to_string()).collect();
+        names.sort();
+        names
+    }
+
+    /// Returns the unsupported action names, sorted.
+    pub fn action_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.actions.iter().map(|a| a.to_string()).collect();
+        names.sort();
+        names
+    }
+}
+
+impl fmt::Display for AclUnsupportedFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "matches [{}], actions [{}]",
+            self.match_names().join(", "),
+            self.action_names().join(", ")
+        )
+    }
+}
+
 /// Builder for ACL table types.
 ///
 /// Uses a fluent API for constructing table type definitions.
@@ -123,6 +183,18 @@ impl AclTableTypeBuilder {
         Self::default()
     }
 
+    /// Creates a builder pre-populated from an existing table type.
+    pub fn from_table_type(table_type: &AclTableType) -> Self {
+        Self {
+            name: Some(table_type.name.clone()),
+            bind_points: table_type.bind_points.clone(),
+            matches: table_type.matches.clone(),
+            actions: table_type.actions.clone(),
+            stages: table_type.stages.clone(),
+            is_builtin: table_type.is_builtin,
+        }
+    }
+
     /// Sets the type name.
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
         self.name = Some(name.into());
@@ -177,6 +249,43 @@ impl AclTableTypeBuilder {
         self
     }
 
+    /// Validates the requested matches and actions against an ASIC capability.
+    ///
+    /// In strict mode any unsupported field is an error carrying the full
+    /// list. With `allow_degraded` the unsupported fields are dropped from the
+    /// builder and returned so the caller can record the omissions.
+    pub fn apply_capability(
+        mut self,
+        capability: &AclTableCapability,
+        allow_degraded: bool,
+    ) -> Result<(Self, AclUnsupportedFields), AclUnsupportedFields> {
+        let unsupported = AclUnsupportedFields {
+            matches: self
+                .matches
+                .iter()
+                .filter(|m| !capability.matches.contains(m))
+                .copied()
+                .collect(),
+            actions: self
+                .actions
+                .iter()
+                .filter(|a| !capability.actions.contains(a))
+                .copied()
+                .collect(),
+        };
+
+        if unsupported.is_empty() {
+            return Ok((self, unsupported));
+        }
+        if !allow_degraded {
+            return Err(unsupported);
+        }
+
+        self.matches.retain(|m| capability.matches.contains(m));
+        self.actions.retain(|a| capability.actions.contains(a));
+        Ok((self, unsupported))
+    }
+
     /// Builds the table type.
     pub fn build(self) -> Result<AclTableType, String> {
         let name = self.name.ok_or("Table type name is required")?;
@@ -347,6 +456,42 @@ pub fn create_ctrlplane_table_type() -> AclTableType {
         .expect("CTRLPLANE table type should be valid")
 }
 
+/// Creates the built-in DTEL_FLOW_WATCHLIST table type.
+///
+/// Rules in this table select flows for INT/postcard telemetry and carry the
+/// DTEL flow actions. The table is bound to the switch.
+pub fn create_dtel_flow_watchlist_table_type() -> AclTableType {
+    AclTableTypeBuilder::new()
+        .with_name("DTEL_FLOW_WATCHLIST")
+        .with_bind_point(AclBindPointType::Switch)
+        .with_matches([
+            AclMatchField::EtherType,
+            AclMatchField::SrcIp,
+            AclMatchField::DstIp,
+            AclMatchField::SrcIpv6,
+            AclMatchField::DstIpv6,
+            AclMatchField::IpProtocol,
+            AclMatchField::L4SrcPort,
+            AclMatchField::L4DstPort,
+            AclMatchField::TunnelVni,
+            AclMatchField::InnerEtherType,
+            AclMatchField::InnerSrcIp,
+            AclMatchField::InnerDstIp,
+        ])
+        .with_actions([
+            AclActionType::DtelFlowOp,
+            AclActionType::DtelIntSession,
+            AclActionType::DtelDropReportEnable,
+            AclActionType::DtelTailDropReportEnable,
+            AclActionType::DtelFlowSamplePercent,
+            AclActionType::DtelReportAllPackets,
+        ])
+        .with_stage(AclStage::Ingress)
+        .builtin()
+        .build()
+        .expect("DTEL_FLOW_WATCHLIST table type should be valid")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +546,70 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn inner_l4_capability() -> AclTableCapability {
+        AclTableCapability {
+            matches: [AclMatchField::SrcIp, AclMatchField::DstIp].into(),
+            actions: [AclActionType::PacketAction].into(),
+        }
+    }
+
+    fn inner_l4_builder() -> AclTableTypeBuilder {
+        AclTableTypeBuilder::new()
+            .with_name("TUNNEL")
+            .with_bind_point(AclBindPointType::Port)
+            .with_matches([
+                AclMatchField::SrcIp,
+                AclMatchField::DstIp,
+                AclMatchField::InnerL4SrcPort,
+                AclMatchField::InnerL4DstPort,
+            ])
+            .with_actions([AclActionType::PacketAction, AclActionType::Counter])
+    }
+
+    #[test]
+    fn test_apply_capability_strict_rejects() {
+        let unsupported = inner_l4_builder()
+            .apply_capability(&inner_l4_capability(), false)
+            .unwrap_err();
+
+        assert_eq!(
+            unsupported.match_names(),
+            vec!["INNER_L4_DST_PORT", "INNER_L4_SRC_PORT"]
+        );
+        assert_eq!(unsupported.actions, vec![AclActionType::Counter]);
+    }
+
+    #[test]
+    fn test_apply_capability_degraded_drops_fields() {
+        let (builder, unsupported) = inner_l4_builder()
+            .apply_capability(&inner_l4_capability(), true)
+            .unwrap();
+        let tt = builder.build().unwrap();
+
+        assert_eq!(unsupported.matches.len(), 2);
+        assert!(tt.supports_match(AclMatchField::SrcIp));
+        assert!(!tt.supports_match(AclMatchField::InnerL4SrcPort));
+        assert!(!tt.supports_action(AclActionType::Counter));
+
+        // A fully supported type passes unchanged in either mode
+        let (builder, unsupported) = AclTableTypeBuilder::from_table_type(&tt)
+            .apply_capability(&inner_l4_capability(), false)
+            .unwrap();
+        assert!(unsupported.is_empty());
+        assert_eq!(builder.build().unwrap().matches, tt.matches);
+    }
+
+    #[test]
+    fn test_capability_intersect() {
+        let other = AclTableCapability {
+            matches: [AclMatchField::SrcIp].into(),
+            actions: [AclActionType::PacketAction, AclActionType::Counter].into(),
+        };
+        let common = inner_l4_capability().intersect(&other);
+        assert_eq!(common.matches, [AclMatchField::SrcIp].into());
+        assert_eq!(common.actions, [AclActionType::PacketAction].into());
+    }
+
     #[test]
     fn test_builtin_l3() {
         let tt = create_l3_table_type();
@@ -460,4 +669,20 @@ mod tests {
         assert!(tt.supports_stage(AclStage::Ingress));
         assert!(!tt.supports_stage(AclStage::Egress));
     }
+
+    #[test]
+    fn test_builtin_dtel_flow_watchlist() {
+        let tt = create_dtel_flow_watchlist_table_type();
+        assert_eq!(tt.name, "DTEL_FLOW_WATCHLIST");
+        assert!(tt.is_builtin);
+        assert!(tt.supports_bind_point(AclBindPointType::Switch));
+        assert!(!tt.supports_bind_point(AclBindPointType::Port));
+        assert!(tt.supports_stage(AclStage::Ingress));
+        assert!(!tt.supports_stage(AclStage::Egress));
+        assert!(tt.supports_match(AclMatchField::DstIp));
+        assert!(tt.supports_match(AclMatchField::TunnelVni));
+        assert!(tt.supports_action(AclActionType::DtelFlowOp));
+        assert!(tt.supports_action(AclActionType::DtelIntSession));
+        assert!(!tt.supports_action(AclActionType::PacketAction));
+    }
 }
@@ -392,6 +392,21 @@ impl FromStr for AclPacketAction {
     }
 }
 
+impl From<AclPacketAction> for sonic_sai::api::AclPacketAction {
+    fn from(action: AclPacketAction) -> Self {
+        match action {
+            AclPacketAction::Forward => Self::Forward,
+            AclPacketAction::Drop => Self::Drop,
+            AclPacketAction::Copy => Self::Copy,
+            AclPacketAction::CopyCancel => Self::CopyCancel,
+            AclPacketAction::Trap => Self::Trap,
+            AclPacketAction::Log => Self::Log,
+            AclPacketAction::Deny => Self::Deny,
+            AclPacketAction::Transit => Self::Transit,
+        }
+    }
+}
+
 /// ACL table identifier (string).
 pub type AclTableId = String;
 
@@ -6,10 +6,11 @@
 use std::cell::RefCell;
 use std::ffi::{c_char, CStr};
 
+use sonic_ffi_bridge::{publish_cpp_event, CppObserverEvent, CppObserverSubject};
 use sonic_sai::types::RawSaiObjectId;
 
 use super::orch::{BfdOrch, BfdOrchConfig};
-use super::types::BfdSessionState;
+use super::types::{BfdSessionState, BfdUpdate};
 
 // Thread-local storage for the BfdOrch instance
 thread_local! {
@@ -233,10 +234,26 @@ pub extern "C" fn rust_bfd_orch_stats_tsa_restores() -> u64 {
     })
 }
 
+/// Converts a BFD update into the event C++ observers receive; `state` is
+/// the SAI session state.
+fn bfd_update_event(update: &BfdUpdate) -> CppObserverEvent {
+    CppObserverEvent::new(CppObserverSubject::BfdSessionStateChange)
+        .with_name(&update.peer)
+        .with_state(update.state.sai_value())
+}
+
+/// Forwards a BFD update to C++ observers registered through the bridge.
+pub(super) fn forward_bfd_update(update: &BfdUpdate) -> usize {
+    publish_cpp_event(bfd_update_event(update))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::ffi::CString;
+    use std::ffi::{c_void, CString};
+    use std::sync::Mutex;
+
+    use sonic_ffi_bridge::{register_cpp_observer, unregister_cpp_observer};
 
     #[test]
     fn test_register_unregister() {
@@ -320,4 +337,43 @@ mod tests {
 
         unregister_bfd_orch();
     }
+
+    unsafe extern "C-unwind" fn record(event: *const CppObserverEvent, user_ctx: *mut c_void) {
+        let events = &*(user_ctx as *const Mutex<Vec<CppObserverEvent>>);
+        events.lock().unwrap().push(*event);
+    }
+
+    #[test]
+    fn test_updates_forwarded_to_cpp_observers() {
+        let events: Mutex<Vec<CppObserverEvent>> = Mutex::new(Vec::new());
+        let handle = unsafe {
+            register_cpp_observer(
+                CppObserverSubject::BfdSessionStateChange as u32,
+                Some(record),
+                &events as *const _ as *mut c_void,
+            )
+        };
+
+        let peer = "default|default|10.40.0.1";
+        forward_bfd_update(&BfdUpdate::new(peer, BfdSessionState::Up));
+        forward_bfd_update(&BfdUpdate::new(peer, BfdSessionState::AdminDown));
+        assert_eq!(unregister_cpp_observer(handle), 0);
+        forward_bfd_update(&BfdUpdate::new(peer, BfdSessionState::Down));
+
+        // Other tests publish BFD updates concurrently
+        let states: Vec<i32> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.name() == peer)
+            .map(|e| e.state)
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                BfdSessionState::Up.sai_value(),
+                BfdSessionState::AdminDown.sai_value()
+            ]
+        );
+    }
 }
@@ -23,8 +23,11 @@
 //!
 //! # TSA Integration
 //!
-//! Sessions with `shutdown_bfd_during_tsa=true` are automatically removed
-//! when Traffic Shift Algorithm (TSA) is enabled, and restored when disabled.
+//! Sessions with `shutdown_bfd_during_tsa=true` are removed from hardware
+//! when Traffic Shift Algorithm (TSA) is enabled and kept suspended with their
+//! full configuration. Observers see them go Admin_Down rather than being
+//! deleted. On TSB they are recreated with their latest configuration,
+//! including changes made while suspended.
 //!
 //! # Safety Improvements over C++
 //!
@@ -46,8 +49,8 @@ mod types;
 pub use ffi::{register_bfd_orch, unregister_bfd_orch};
 pub use orch::{BfdOrch, BfdOrchCallbacks, BfdOrchConfig, BfdOrchError, BfdOrchStats};
 pub use types::{
-    BfdSessionConfig, BfdSessionInfo, BfdSessionKey, BfdSessionState, BfdSessionType, BfdUpdate,
-    BFD_SESSION_DEFAULT_DETECT_MULTIPLIER, BFD_SESSION_DEFAULT_RX_INTERVAL,
-    BFD_SESSION_DEFAULT_TOS, BFD_SESSION_DEFAULT_TX_INTERVAL, BFD_SRCPORT_INIT, BFD_SRCPORT_MAX,
-    NUM_BFD_SRCPORT_RETRIES,
+    BfdSessionConfig, BfdSessionInfo, BfdSessionKey, BfdSessionState, BfdSessionType,
+    BfdSuspendedSession, BfdUpdate, BFD_SESSION_DEFAULT_DETECT_MULTIPLIER,
+    BFD_SESSION_DEFAULT_RX_INTERVAL, BFD_SESSION_DEFAULT_TOS, BFD_SESSION_DEFAULT_TX_INTERVAL,
+    BFD_SRCPORT_INIT, BFD_SRCPORT_MAX, NUM_BFD_SRCPORT_RETRIES,
 };
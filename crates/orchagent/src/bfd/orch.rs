@@ -10,8 +10,8 @@ use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 
 use super::types::{
-    BfdSessionConfig, BfdSessionInfo, BfdSessionKey, BfdSessionState, BfdSessionType, BfdUpdate,
-    BFD_SRCPORT_INIT, BFD_SRCPORT_MAX, NUM_BFD_SRCPORT_RETRIES,
+    BfdSessionConfig, BfdSessionInfo, BfdSessionKey, BfdSessionState, BfdSessionType,
+    BfdSuspendedSession, BfdUpdate, BFD_SRCPORT_INIT, BFD_SRCPORT_MAX, NUM_BFD_SRCPORT_RETRIES,
 };
 
 /// BFD orchestrator error type.
@@ -112,8 +112,8 @@ pub struct BfdOrch {
     sessions: HashMap<String, BfdSessionInfo>,
     /// Reverse map from SAI OID to config key.
     sai_to_key: HashMap<RawSaiObjectId, String>,
-    /// Cached sessions for TSA (sessions removed during TSA).
-    tsa_cache: HashMap<String, BfdSessionConfig>,
+    /// Sessions suspended during TSA, keyed by config key.
+    tsa_cache: HashMap<String, BfdSuspendedSession>,
     /// Callbacks for SAI and DB operations.
     callbacks: Option<Arc<dyn BfdOrchCallbacks>>,
     /// Whether the orch is initialized.
@@ -241,6 +241,28 @@ impl BfdOrch {
             return Err(err);
         }
 
+        // A suspended session keeps its place; the new config applies on resume
+        if let Some(suspended) = self.tsa_cache.get_mut(&key) {
+            suspended.config = config.clone();
+
+            let audit_record = AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "BfdOrch",
+                "update_suspended_session",
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(&key)
+            .with_object_type("bfd_session_cached")
+            .with_details(serde_json::json!({
+                "session_key": key,
+                "tx_interval": config.tx_interval,
+                "rx_interval": config.rx_interval,
+                "multiplier": config.multiplier,
+            }));
+            audit_log!(audit_record);
+            return Ok(());
+        }
+
         let callbacks = self
             .callbacks
             .as_ref()
@@ -270,7 +292,8 @@ impl BfdOrch {
 
         // Handle TSA - cache and skip if shutdown_bfd_during_tsa is set
         if callbacks.is_tsa_active() && config.shutdown_bfd_during_tsa {
-            self.tsa_cache.insert(key.clone(), config.clone());
+            self.tsa_cache
+                .insert(key.clone(), BfdSuspendedSession::new(config.clone()));
 
             let audit_record = AuditRecord::new(
                 AuditCategory::ResourceCreate,
@@ -291,11 +314,15 @@ impl BfdOrch {
         }
 
         // Create hardware BFD session
-        self.create_hardware_session(config)
+        self.create_hardware_session(config, None)
     }
 
-    /// Creates a hardware BFD session via SAI.
-    fn create_hardware_session(&mut self, config: BfdSessionConfig) -> Result<(), BfdOrchError> {
+    /// Creates a hardware BFD session via SAI, reusing `discriminator` if given.
+    fn create_hardware_session(
+        &mut self,
+        config: BfdSessionConfig,
+        discriminator: Option<u32>,
+    ) -> Result<(), BfdOrchError> {
         let key = config.key.to_config_key();
         let state_db_key = config.key.to_state_db_key();
 
@@ -306,7 +333,10 @@ impl BfdOrch {
                 .ok_or_else(|| BfdOrchError::InvalidConfig("No callbacks set".to_string()))?,
         );
 
-        let discriminator = self.gen_discriminator();
+        let discriminator = match discriminator {
+            Some(discriminator) => discriminator,
+            None => self.gen_discriminator(),
+        };
 
         // Try to create session, retrying with different source ports if needed
         let mut last_error = String::new();
@@ -412,8 +442,10 @@ impl BfdOrch {
             .as_ref()
             .ok_or_else(|| BfdOrchError::InvalidConfig("No callbacks set".to_string()))?;
 
-        // Check TSA cache first
-        if self.tsa_cache.remove(key).is_some() {
+        // Check TSA cache first; its state DB entry was kept at Admin_Down
+        if let Some(suspended) = self.tsa_cache.remove(key) {
+            callbacks.remove_state_db(&suspended.state_db_key);
+
             let audit_record = AuditRecord::new(
                 AuditCategory::ResourceDelete,
                 "BfdOrch",
@@ -545,38 +577,111 @@ impl BfdOrch {
         info.set_state(new_state);
         self.stats.state_changes += 1;
 
+        let update = BfdUpdate::new(&info.state_db_key, new_state);
+        super::ffi::forward_bfd_update(&update);
+
         if let Some(callbacks) = &self.callbacks {
             // Update state DB
             callbacks.write_state_db(&info.state_db_key, new_state, info.config.session_type);
 
             // Notify observers
-            callbacks.notify(BfdUpdate::new(&info.state_db_key, new_state));
+            callbacks.notify(update);
         }
 
         Ok(())
     }
 
+    /// Returns the number of sessions suspended by TSA.
+    pub fn suspended_count(&self) -> usize {
+        self.tsa_cache.len()
+    }
+
+    /// Gets a suspended session by config key.
+    pub fn get_suspended_session(&self, key: &str) -> Option<&BfdSuspendedSession> {
+        self.tsa_cache.get(key)
+    }
+
+    /// Removes a session from hardware and moves it to the TSA cache.
+    ///
+    /// Observers and STATE_DB see Admin_Down rather than a deletion, so
+    /// route owners keep their state and only react to the session going down.
+    fn suspend_session(&mut self, key: &str) -> Result<(), BfdOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| BfdOrchError::InvalidConfig("No callbacks set".to_string()))?,
+        );
+        let info = self
+            .sessions
+            .get(key)
+            .ok_or_else(|| BfdOrchError::SessionNotFound(key.to_string()))?;
+        let sai_oid = info.sai_oid;
+
+        callbacks
+            .remove_bfd_session(sai_oid)
+            .map_err(BfdOrchError::SaiError)?;
+
+        let info = self.sessions.remove(key).expect("session checked above");
+        self.sai_to_key.remove(&sai_oid);
+
+        callbacks.write_state_db(
+            &info.state_db_key,
+            BfdSessionState::AdminDown,
+            info.config.session_type,
+        );
+        let update = BfdUpdate::new(&info.state_db_key, BfdSessionState::AdminDown);
+        super::ffi::forward_bfd_update(&update);
+        callbacks.notify(update);
+
+        self.tsa_cache.insert(
+            key.to_string(),
+            BfdSuspendedSession {
+                config: info.config,
+                state_db_key: info.state_db_key,
+                local_discriminator: Some(info.local_discriminator),
+            },
+        );
+        self.stats.tsa_shutdowns += 1;
+        Ok(())
+    }
+
+    /// Recreates a suspended session with its latest config.
+    fn resume_session(&mut self, suspended: BfdSuspendedSession) -> Result<(), BfdOrchError> {
+        let software = self
+            .callbacks
+            .as_ref()
+            .ok_or_else(|| BfdOrchError::InvalidConfig("No callbacks set".to_string()))?
+            .is_software_bfd();
+        if software {
+            self.create_session(suspended.config)
+        } else {
+            self.create_hardware_session(suspended.config, suspended.local_discriminator)
+        }
+    }
+
     /// Handles TSA state change.
+    ///
+    /// On TSA, sessions with `shutdown_bfd_during_tsa` are suspended. On TSB,
+    /// they are recreated; sessions that fail to come back stay suspended and
+    /// an error is returned so the transition can be retried.
     pub fn handle_tsa_state_change(&mut self, tsa_enabled: bool) -> Result<(), BfdOrchError> {
         if tsa_enabled {
-            // TSA enabled - shutdown sessions with shutdown_bfd_during_tsa=true
+            // TSA enabled - suspend sessions with shutdown_bfd_during_tsa=true
             let sessions_to_shutdown: Vec<_> = self
                 .sessions
                 .iter()
                 .filter(|(_, info)| info.config.shutdown_bfd_during_tsa)
-                .map(|(k, info)| (k.clone(), info.config.clone()))
+                .map(|(k, _)| k.clone())
                 .collect();
 
-            let shutdown_count = sessions_to_shutdown.len();
             let mut session_keys = Vec::new();
+            let mut failed_keys = Vec::new();
 
-            for (key, config) in sessions_to_shutdown {
-                session_keys.push(key.clone());
-                // Remove the session first (ignore errors)
-                let _ = self.remove_session(&key);
-                // Then cache the config for later restoration
-                self.tsa_cache.insert(key, config);
-                self.stats.tsa_shutdowns += 1;
+            for key in sessions_to_shutdown {
+                match self.suspend_session(&key) {
+                    Ok(()) => session_keys.push(key),
+                    Err(_) => failed_keys.push(key),
+                }
             }
 
             // Log TSA enabled event with SystemLifecycle category per NIST AU-2
@@ -585,27 +690,45 @@ impl BfdOrch {
                 "BfdOrch",
                 "handle_tsa_enabled",
             )
-            .with_outcome(AuditOutcome::Success)
+            .with_outcome(if failed_keys.is_empty() {
+                AuditOutcome::Success
+            } else {
+                AuditOutcome::Failure
+            })
             .with_object_id("TSA")
             .with_object_type("traffic_shift_active")
             .with_details(serde_json::json!({
                 "event": "tsa_enabled",
-                "sessions_shutdown": shutdown_count,
+                "sessions_shutdown": session_keys.len(),
                 "session_keys": session_keys,
-                "action": "BFD sessions with shutdown_bfd_during_tsa=true have been shutdown and cached",
+                "failed_keys": failed_keys,
+                "action": "BFD sessions with shutdown_bfd_during_tsa=true have been suspended",
             }));
             audit_log!(audit_record);
+
+            if !failed_keys.is_empty() {
+                return Err(BfdOrchError::SaiError(format!(
+                    "Failed to suspend BFD sessions: {}",
+                    failed_keys.join(", ")
+                )));
+            }
         } else {
-            // TSA disabled - restore cached sessions
+            // TSA disabled - resume suspended sessions
             let cached: Vec<_> = self.tsa_cache.drain().collect();
-            let restore_count = cached.len();
             let mut session_keys = Vec::new();
+            let mut failed_keys = Vec::new();
 
-            for (key, config) in cached {
-                session_keys.push(key.clone());
-                // Recreate the session (ignore errors)
-                let _ = self.create_session(config);
-                self.stats.tsa_restores += 1;
+            for (key, suspended) in cached {
+                match self.resume_session(suspended.clone()) {
+                    Ok(()) => {
+                        session_keys.push(key);
+                        self.stats.tsa_restores += 1;
+                    }
+                    Err(_) => {
+                        self.tsa_cache.insert(key.clone(), suspended);
+                        failed_keys.push(key);
+                    }
+                }
             }
 
             // Log TSA disabled event with SystemLifecycle category per NIST AU-2
@@ -614,16 +737,28 @@ impl BfdOrch {
                 "BfdOrch",
                 "handle_tsa_disabled",
             )
-            .with_outcome(AuditOutcome::Success)
+            .with_outcome(if failed_keys.is_empty() {
+                AuditOutcome::Success
+            } else {
+                AuditOutcome::Failure
+            })
             .with_object_id("TSA")
             .with_object_type("traffic_shift_active")
             .with_details(serde_json::json!({
                 "event": "tsa_disabled",
-                "sessions_restored": restore_count,
+                "sessions_restored": session_keys.len(),
                 "session_keys": session_keys,
-                "action": "Cached BFD sessions have been restored following TSA disable",
+                "failed_keys": failed_keys,
+                "action": "Suspended BFD sessions have been resumed following TSA disable",
             }));
             audit_log!(audit_record);
+
+            if !failed_keys.is_empty() {
+                return Err(BfdOrchError::SaiError(format!(
+                    "Failed to resume BFD sessions: {}",
+                    failed_keys.join(", ")
+                )));
+            }
         }
 
         Ok(())
@@ -659,6 +794,7 @@ mod tests {
         removed_sessions: Mutex<Vec<RawSaiObjectId>>,
         state_updates: Mutex<Vec<(String, BfdSessionState)>>,
         notifications: Mutex<Vec<BfdUpdate>>,
+        removed_state_keys: Mutex<Vec<String>>,
         software_bfd: bool,
         tsa_active: bool,
         fail_create: bool,
@@ -671,6 +807,7 @@ mod tests {
                 removed_sessions: Mutex::new(Vec::new()),
                 state_updates: Mutex::new(Vec::new()),
                 notifications: Mutex::new(Vec::new()),
+                removed_state_keys: Mutex::new(Vec::new()),
                 software_bfd: false,
                 tsa_active: false,
                 fail_create: false,
@@ -731,7 +868,12 @@ mod tests {
                 .push((key.to_string(), state));
         }
 
-        fn remove_state_db(&self, _key: &str) {}
+        fn remove_state_db(&self, key: &str) {
+            self.removed_state_keys
+                .lock()
+                .unwrap()
+                .push(key.to_string());
+        }
 
         fn notify(&self, update: BfdUpdate) {
             self.notifications.lock().unwrap().push(update);
@@ -1646,4 +1788,85 @@ mod tests {
         // Verify config is accessible
         let _cfg = orch.config();
     }
+
+    #[test]
+    fn test_tsa_config_change_then_tsb() {
+        let mut orch = BfdOrch::new(BfdOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        let key = BfdSessionKey::new("default", None, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        let config = BfdSessionConfig::new(key.clone())
+            .with_tx_interval(300)
+            .with_rx_interval(400)
+            .with_shutdown_bfd_during_tsa(true);
+        orch.create_session(config.clone()).unwrap();
+        let discriminator = orch
+            .get_session("default::10.0.0.1")
+            .unwrap()
+            .local_discriminator;
+
+        orch.handle_tsa_state_change(true).unwrap();
+        assert_eq!(orch.session_count(), 0);
+        assert_eq!(orch.suspended_count(), 1);
+        assert_eq!(callbacks.removed_sessions.lock().unwrap().len(), 1);
+
+        // Observers see Admin_Down, and the state DB entry is kept
+        assert_eq!(
+            callbacks.notifications.lock().unwrap().last(),
+            Some(&BfdUpdate::new(
+                "default|10.0.0.1",
+                BfdSessionState::AdminDown
+            ))
+        );
+        assert_eq!(
+            callbacks.state_updates.lock().unwrap().last(),
+            Some(&("default|10.0.0.1".to_string(), BfdSessionState::AdminDown))
+        );
+        assert!(callbacks.removed_state_keys.lock().unwrap().is_empty());
+
+        // Changing the config while suspended does not touch hardware
+        orch.create_session(config.with_tx_interval(500)).unwrap();
+        assert_eq!(callbacks.created_sessions.lock().unwrap().len(), 1);
+        assert_eq!(
+            orch.get_suspended_session("default::10.0.0.1")
+                .unwrap()
+                .config
+                .tx_interval,
+            500
+        );
+
+        orch.handle_tsa_state_change(false).unwrap();
+        assert_eq!(orch.suspended_count(), 0);
+        let info = orch.get_session("default::10.0.0.1").unwrap();
+        assert_eq!(info.config.tx_interval, 500);
+        assert_eq!(info.config.rx_interval, 400);
+        assert_eq!(info.local_discriminator, discriminator);
+        assert_eq!(callbacks.created_sessions.lock().unwrap().len(), 2);
+        assert_eq!(orch.stats().tsa_restores, 1);
+    }
+
+    #[test]
+    fn test_tsa_session_deleted_while_suspended() {
+        let mut orch = BfdOrch::new(BfdOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        let key = BfdSessionKey::new("default", None, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        orch.create_session(BfdSessionConfig::new(key).with_shutdown_bfd_during_tsa(true))
+            .unwrap();
+        orch.handle_tsa_state_change(true).unwrap();
+
+        orch.remove_session("default::10.0.0.1").unwrap();
+        assert_eq!(orch.suspended_count(), 0);
+        assert_eq!(
+            *callbacks.removed_state_keys.lock().unwrap(),
+            vec!["default|10.0.0.1".to_string()]
+        );
+
+        orch.handle_tsa_state_change(false).unwrap();
+        assert_eq!(orch.session_count(), 0);
+        assert_eq!(callbacks.created_sessions.lock().unwrap().len(), 1);
+        assert_eq!(callbacks.removed_sessions.lock().unwrap().len(), 1);
+    }
 }
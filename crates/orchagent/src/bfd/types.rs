@@ -437,6 +437,29 @@ impl BfdSessionInfo {
     }
 }
 
+/// Session taken out of hardware while TSA is active.
+#[derive(Debug, Clone)]
+pub struct BfdSuspendedSession {
+    /// Configuration to apply on resume, including changes made while suspended.
+    pub config: BfdSessionConfig,
+    /// State DB key, left at Admin_Down while suspended.
+    pub state_db_key: String,
+    /// Discriminator of the removed hardware session, reused on resume so
+    /// the peer sees the same session. `None` if it was never created.
+    pub local_discriminator: Option<u32>,
+}
+
+impl BfdSuspendedSession {
+    /// Creates an entry for a session that was configured during TSA.
+    pub fn new(config: BfdSessionConfig) -> Self {
+        Self {
+            state_db_key: config.key.to_state_db_key(),
+            config,
+            local_discriminator: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,9 @@
 //! - Type-safe buffer pool types and modes
 //! - Validated threshold configurations
 //! - Result types for ref count operations
+//! - Dynamic pools sized as a percentage of the switch buffer, resized when
+//!   the percentage or the switch buffer size changes
+//! - Shared headroom pool sized from lossless PG headroom
 
 mod ffi;
 mod orch;
@@ -1,9 +1,13 @@
 //! Buffer orchestration logic.
 
-use super::types::{BufferPoolEntry, BufferProfileEntry, BufferStats};
+use super::types::{
+    BufferPoolConfig, BufferPoolEntry, BufferPoolMode, BufferPoolType, BufferProfileEntry,
+    BufferStats, RawSaiObjectId,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Error)]
@@ -18,12 +22,19 @@ pub enum BufferOrchError {
     SaiError(String),
     #[error("Reference count error: {0}")]
     RefCountError(String),
+    #[error("Invalid pool size percentage: {0}")]
+    InvalidPoolPercentage(u8),
+    #[error("Callbacks not configured")]
+    NotInitialized,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct BufferOrchConfig {
     pub enable_ingress_buffer_drop: bool,
     pub enable_egress_buffer_drop: bool,
+    /// Over-subscribe ratio of the shared headroom pool. `None` disables
+    /// shared headroom pool sizing.
+    pub headroom_over_subscribe_ratio: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -37,6 +48,12 @@ pub trait BufferOrchCallbacks: Send + Sync {
     fn on_pool_removed(&self, pool_name: &str);
     fn on_profile_created(&self, profile: &BufferProfileEntry);
     fn on_profile_removed(&self, profile_name: &str);
+    /// Returns the switch's total buffer size in bytes.
+    fn get_total_buffer_size(&self) -> Result<u64, String>;
+    /// Sets the size of a buffer pool.
+    fn set_pool_size(&self, pool_oid: RawSaiObjectId, size: u64) -> Result<(), String>;
+    /// Sets the shared headroom (xoff) size of a buffer pool.
+    fn set_pool_xoff_size(&self, pool_oid: RawSaiObjectId, size: u64) -> Result<(), String>;
 }
 
 pub struct BufferOrch {
@@ -44,6 +61,11 @@ pub struct BufferOrch {
     stats: BufferOrchStats,
     pools: HashMap<String, BufferPoolEntry>,
     profiles: HashMap<String, BufferProfileEntry>,
+    callbacks: Option<Arc<dyn BufferOrchCallbacks>>,
+    /// Switch buffer size in bytes, cached from SAI.
+    total_buffer_size: Option<u64>,
+    /// Xoff headroom of lossless priority groups, by (port, PG index).
+    pg_headroom: HashMap<(String, u8), u64>,
 }
 
 impl BufferOrch {
@@ -53,9 +75,236 @@ impl BufferOrch {
             stats: BufferOrchStats::default(),
             pools: HashMap::new(),
             profiles: HashMap::new(),
+            callbacks: None,
+            total_buffer_size: None,
+            pg_headroom: HashMap::new(),
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn BufferOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    /// Sets the size of a dynamic pool as a percentage of the switch buffer
+    /// and reprograms the pool if its absolute size changes.
+    ///
+    /// Returns the new absolute pool size.
+    pub fn set_pool_percentage(&mut self, name: &str, percent: u8) -> Result<u64, BufferOrchError> {
+        let pool = self
+            .pools
+            .get(name)
+            .ok_or_else(|| BufferOrchError::PoolNotFound(name.to_string()))?;
+        if pool.config.mode != BufferPoolMode::Dynamic {
+            return Err(BufferOrchError::InvalidThreshold(format!(
+                "Pool {} is not dynamic",
+                name
+            )));
+        }
+        let size = self.pool_size_for_percent(percent)?;
+
+        self.program_pool_size(name, size)?;
+        if let Some(pool) = self.pools.get_mut(name) {
+            pool.config.size_percent = Some(percent);
+        }
+        Ok(size)
+    }
+
+    /// Re-reads the switch's total buffer size and resizes every dynamic
+    /// pool sized by percentage.
+    ///
+    /// Returns the names of the pools that were resized.
+    pub fn refresh_total_buffer_size(&mut self) -> Result<Vec<String>, BufferOrchError> {
+        self.total_buffer_size = None;
+        let total = self.total_buffer_size()?;
+
+        let mut resized: Vec<(String, u64)> = self
+            .pools
+            .values()
+            .filter(|pool| pool.config.mode == BufferPoolMode::Dynamic)
+            .filter_map(|pool| {
+                let percent = pool.config.size_percent?;
+                let size = total * percent as u64 / 100;
+                (size != pool.config.size).then(|| (pool.name.clone(), size))
+            })
+            .collect();
+        resized.sort();
+
+        for (name, size) in &resized {
+            self.program_pool_size(name, *size)?;
+        }
+        Ok(resized.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// Sets the xoff headroom of a lossless priority group and recomputes
+    /// the shared headroom pool size.
+    ///
+    /// Returns the new shared headroom pool size.
+    pub fn set_pg_headroom(
+        &mut self,
+        port_name: &str,
+        pg_index: u8,
+        xoff: u64,
+    ) -> Result<u64, BufferOrchError> {
+        self.pg_headroom
+            .insert((port_name.to_string(), pg_index), xoff);
+        self.update_shared_headroom_pool()
+    }
+
+    /// Removes the headroom of a priority group and recomputes the shared
+    /// headroom pool size.
+    pub fn remove_pg_headroom(
+        &mut self,
+        port_name: &str,
+        pg_index: u8,
+    ) -> Result<u64, BufferOrchError> {
+        self.pg_headroom.remove(&(port_name.to_string(), pg_index));
+        self.update_shared_headroom_pool()
+    }
+
+    /// Returns the shared headroom pool size implied by the current PG
+    /// headroom and over-subscribe ratio.
+    pub fn shared_headroom_pool_size(&self) -> u64 {
+        match self.config.headroom_over_subscribe_ratio {
+            Some(ratio) if ratio > 0 => self.pg_headroom.values().sum::<u64>() / ratio as u64,
+            _ => 0,
+        }
+    }
+
+    /// Programs the shared headroom size on every ingress pool configured
+    /// with an xoff size.
+    fn update_shared_headroom_pool(&mut self) -> Result<u64, BufferOrchError> {
+        if self.config.headroom_over_subscribe_ratio.is_none() {
+            return Ok(0);
+        }
+        let size = self.shared_headroom_pool_size();
+
+        let mut pools: Vec<String> = self
+            .pools
+            .values()
+            .filter(|pool| pool.config.pool_type == BufferPoolType::Ingress)
+            .filter(|pool| matches!(pool.config.xoff_threshold, Some(xoff) if xoff != size))
+            .map(|pool| pool.name.clone())
+            .collect();
+        pools.sort();
+        if pools.is_empty() {
+            return Ok(size);
+        }
+
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or(BufferOrchError::NotInitialized)?;
+        for name in pools {
+            let Some(pool) = self.pools.get_mut(&name) else {
+                continue;
+            };
+            if let Err(e) = callbacks.set_pool_xoff_size(pool.sai_oid, size) {
+                self.stats.errors = self.stats.errors.saturating_add(1);
+                return Err(BufferOrchError::SaiError(e));
+            }
+            let old = pool.config.xoff_threshold.replace(size);
+
+            let record = AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "BufferOrch",
+                "update_shared_headroom_pool",
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(&name)
+            .with_object_type("buffer_pool")
+            .with_details(serde_json::json!({
+                "pool_name": name,
+                "old_xoff": old,
+                "new_xoff": size,
+            }));
+            audit_log!(record);
+        }
+
+        Ok(size)
+    }
+
+    /// Resolves the absolute size of a dynamic pool sized by percentage.
+    fn resolve_pool_size(&mut self, config: &mut BufferPoolConfig) -> Result<(), BufferOrchError> {
+        if config.mode != BufferPoolMode::Dynamic {
+            return Ok(());
+        }
+        if let Some(percent) = config.size_percent {
+            config.size = self.pool_size_for_percent(percent)?;
+        }
+        Ok(())
+    }
+
+    fn pool_size_for_percent(&mut self, percent: u8) -> Result<u64, BufferOrchError> {
+        if percent == 0 || percent > 100 {
+            return Err(BufferOrchError::InvalidPoolPercentage(percent));
+        }
+        Ok(self.total_buffer_size()? * percent as u64 / 100)
+    }
+
+    /// Returns the switch's total buffer size, querying SAI on first use.
+    fn total_buffer_size(&mut self) -> Result<u64, BufferOrchError> {
+        if let Some(total) = self.total_buffer_size {
+            return Ok(total);
+        }
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or(BufferOrchError::NotInitialized)?;
+        let total = callbacks
+            .get_total_buffer_size()
+            .map_err(BufferOrchError::SaiError)?;
+        self.total_buffer_size = Some(total);
+        Ok(total)
+    }
+
+    fn program_pool_size(&mut self, name: &str, size: u64) -> Result<(), BufferOrchError> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or(BufferOrchError::NotInitialized)?;
+        let pool = self
+            .pools
+            .get_mut(name)
+            .ok_or_else(|| BufferOrchError::PoolNotFound(name.to_string()))?;
+        if pool.config.size == size {
+            return Ok(());
+        }
+
+        if let Err(e) = callbacks.set_pool_size(pool.sai_oid, size) {
+            self.stats.errors = self.stats.errors.saturating_add(1);
+            let record = AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "BufferOrch",
+                "resize_buffer_pool",
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(name)
+            .with_object_type("buffer_pool")
+            .with_error(&e);
+            audit_log!(record);
+            return Err(BufferOrchError::SaiError(e));
+        }
+        let old_size = std::mem::replace(&mut pool.config.size, size);
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "BufferOrch",
+            "resize_buffer_pool",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(name)
+        .with_object_type("buffer_pool")
+        .with_details(serde_json::json!({
+            "pool_name": name,
+            "old_size": old_size,
+            "new_size": size,
+            "size_percent": pool.config.size_percent,
+        }));
+        audit_log!(record);
+
+        Ok(())
+    }
+
     pub fn get_pool(&self, name: &str) -> Option<&BufferPoolEntry> {
         self.pools.get(name)
     }
@@ -64,7 +313,7 @@ impl BufferOrch {
         self.pools.get_mut(name)
     }
 
-    pub fn add_pool(&mut self, entry: BufferPoolEntry) -> Result<(), BufferOrchError> {
+    pub fn add_pool(&mut self, mut entry: BufferPoolEntry) -> Result<(), BufferOrchError> {
         let name = entry.name.clone();
 
         if self.pools.contains_key(&name) {
@@ -81,6 +330,20 @@ impl BufferOrch {
             return Err(BufferOrchError::SaiError("Pool already exists".to_string()));
         }
 
+        if let Err(e) = self.resolve_pool_size(&mut entry.config) {
+            let record = AuditRecord::new(
+                AuditCategory::ResourceCreate,
+                "BufferOrch",
+                format!("create_buffer_pool_failed: {}", name),
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(&name)
+            .with_object_type("buffer_pool")
+            .with_error(e.to_string());
+            audit_log!(record);
+            return Err(e);
+        }
+
         self.stats.stats.pools_created = self.stats.stats.pools_created.saturating_add(1);
         self.pools.insert(name.clone(), entry.clone());
 
@@ -435,6 +698,7 @@ mod tests {
                 threshold_mode: super::super::types::ThresholdMode::Dynamic,
                 xoff_threshold: None,
                 xon_threshold: None,
+                size_percent: None,
             },
             sai_oid: 0,
             ref_count: 0,
@@ -634,4 +898,198 @@ mod tests {
             BufferOrchError::RefCountError(_)
         ));
     }
+
+    const TOTAL_BUFFER_SIZE: u64 = 32 * 1024 * 1024;
+
+    struct MockBufferCallbacks {
+        total_buffer_size: std::sync::Mutex<u64>,
+        pool_sizes: std::sync::Mutex<Vec<(RawSaiObjectId, u64)>>,
+        xoff_sizes: std::sync::Mutex<Vec<(RawSaiObjectId, u64)>>,
+    }
+
+    impl MockBufferCallbacks {
+        fn new(total_buffer_size: u64) -> Self {
+            Self {
+                total_buffer_size: std::sync::Mutex::new(total_buffer_size),
+                pool_sizes: std::sync::Mutex::new(Vec::new()),
+                xoff_sizes: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl BufferOrchCallbacks for MockBufferCallbacks {
+        fn on_pool_created(&self, _pool: &BufferPoolEntry) {}
+        fn on_pool_removed(&self, _pool_name: &str) {}
+        fn on_profile_created(&self, _profile: &BufferProfileEntry) {}
+        fn on_profile_removed(&self, _profile_name: &str) {}
+        fn get_total_buffer_size(&self) -> Result<u64, String> {
+            Ok(*self.total_buffer_size.lock().unwrap())
+        }
+        fn set_pool_size(&self, pool_oid: RawSaiObjectId, size: u64) -> Result<(), String> {
+            self.pool_sizes.lock().unwrap().push((pool_oid, size));
+            Ok(())
+        }
+        fn set_pool_xoff_size(&self, pool_oid: RawSaiObjectId, size: u64) -> Result<(), String> {
+            self.xoff_sizes.lock().unwrap().push((pool_oid, size));
+            Ok(())
+        }
+    }
+
+    fn orch_with_callbacks(config: BufferOrchConfig) -> (BufferOrch, Arc<MockBufferCallbacks>) {
+        let callbacks = Arc::new(MockBufferCallbacks::new(TOTAL_BUFFER_SIZE));
+        let mut orch = BufferOrch::new(config);
+        orch.set_callbacks(callbacks.clone());
+        (orch, callbacks)
+    }
+
+    fn create_percent_pool(name: &str, percent: u8, oid: RawSaiObjectId) -> BufferPoolEntry {
+        let mut pool = create_test_pool(name, 0);
+        pool.config.size_percent = Some(percent);
+        pool.sai_oid = oid;
+        pool
+    }
+
+    #[test]
+    fn test_dynamic_pool_sized_by_percentage() {
+        let (mut orch, _) = orch_with_callbacks(BufferOrchConfig::default());
+
+        orch.add_pool(create_percent_pool("ingress_lossless_pool", 50, 0x18))
+            .unwrap();
+        assert_eq!(
+            orch.get_pool("ingress_lossless_pool").unwrap().config.size,
+            TOTAL_BUFFER_SIZE / 2
+        );
+
+        // Static pools keep their absolute size
+        let mut pool = create_percent_pool("egress_lossy_pool", 50, 0x19);
+        pool.config.mode = super::super::types::BufferPoolMode::Static;
+        pool.config.size = 1024;
+        orch.add_pool(pool).unwrap();
+        assert_eq!(
+            orch.get_pool("egress_lossy_pool").unwrap().config.size,
+            1024
+        );
+    }
+
+    #[test]
+    fn test_pool_percentage_change_reprograms_pool() {
+        let (mut orch, callbacks) = orch_with_callbacks(BufferOrchConfig::default());
+        orch.add_pool(create_percent_pool("ingress_lossless_pool", 50, 0x18))
+            .unwrap();
+
+        let size = orch
+            .set_pool_percentage("ingress_lossless_pool", 75)
+            .unwrap();
+        assert_eq!(size, TOTAL_BUFFER_SIZE * 3 / 4);
+        let pool = orch.get_pool("ingress_lossless_pool").unwrap();
+        assert_eq!(pool.config.size, size);
+        assert_eq!(pool.config.size_percent, Some(75));
+        assert_eq!(*callbacks.pool_sizes.lock().unwrap(), vec![(0x18, size)]);
+
+        // Same percentage is not reprogrammed
+        orch.set_pool_percentage("ingress_lossless_pool", 75)
+            .unwrap();
+        assert_eq!(callbacks.pool_sizes.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_total_buffer_size_change_resizes_pools() {
+        let (mut orch, callbacks) = orch_with_callbacks(BufferOrchConfig::default());
+        orch.add_pool(create_percent_pool("ingress_lossless_pool", 50, 0x18))
+            .unwrap();
+        orch.add_pool(create_percent_pool("egress_lossless_pool", 25, 0x19))
+            .unwrap();
+        orch.add_pool(create_test_pool("ingress_lossy_pool", 4096))
+            .unwrap();
+
+        // Unchanged SRAM resizes nothing
+        assert!(orch.refresh_total_buffer_size().unwrap().is_empty());
+
+        *callbacks.total_buffer_size.lock().unwrap() = TOTAL_BUFFER_SIZE * 2;
+        assert_eq!(
+            orch.refresh_total_buffer_size().unwrap(),
+            vec!["egress_lossless_pool", "ingress_lossless_pool"]
+        );
+        assert_eq!(
+            orch.get_pool("ingress_lossless_pool").unwrap().config.size,
+            TOTAL_BUFFER_SIZE
+        );
+        assert_eq!(
+            orch.get_pool("egress_lossless_pool").unwrap().config.size,
+            TOTAL_BUFFER_SIZE / 2
+        );
+        assert_eq!(
+            orch.get_pool("ingress_lossy_pool").unwrap().config.size,
+            4096
+        );
+        assert_eq!(callbacks.pool_sizes.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_pool_percentage() {
+        let (mut orch, _) = orch_with_callbacks(BufferOrchConfig::default());
+
+        for percent in [0, 101] {
+            let result = orch.add_pool(create_percent_pool("ingress_lossless_pool", percent, 0x18));
+            assert!(matches!(
+                result,
+                Err(BufferOrchError::InvalidPoolPercentage(p)) if p == percent
+            ));
+        }
+        assert_eq!(orch.pool_count(), 0);
+
+        orch.add_pool(create_percent_pool("ingress_lossless_pool", 50, 0x18))
+            .unwrap();
+        assert!(matches!(
+            orch.set_pool_percentage("ingress_lossless_pool", 0),
+            Err(BufferOrchError::InvalidPoolPercentage(0))
+        ));
+        assert!(matches!(
+            orch.set_pool_percentage("ingress_lossless_pool", 150),
+            Err(BufferOrchError::InvalidPoolPercentage(150))
+        ));
+        assert_eq!(
+            orch.get_pool("ingress_lossless_pool").unwrap().config.size,
+            TOTAL_BUFFER_SIZE / 2
+        );
+    }
+
+    #[test]
+    fn test_percentage_pool_without_callbacks() {
+        let mut orch = BufferOrch::new(BufferOrchConfig::default());
+        assert!(matches!(
+            orch.add_pool(create_percent_pool("ingress_lossless_pool", 50, 0x18)),
+            Err(BufferOrchError::NotInitialized)
+        ));
+    }
+
+    #[test]
+    fn test_shared_headroom_pool_follows_pg_headroom() {
+        let (mut orch, callbacks) = orch_with_callbacks(BufferOrchConfig {
+            headroom_over_subscribe_ratio: Some(2),
+            ..Default::default()
+        });
+        let mut pool = create_test_pool("ingress_lossless_pool", 1 << 20);
+        pool.config.xoff_threshold = Some(0);
+        pool.sai_oid = 0x18;
+        orch.add_pool(pool).unwrap();
+
+        orch.set_pg_headroom("Ethernet0", 3, 40960).unwrap();
+        assert_eq!(orch.set_pg_headroom("Ethernet0", 4, 40960).unwrap(), 40960);
+        assert_eq!(
+            orch.get_pool("ingress_lossless_pool")
+                .unwrap()
+                .config
+                .xoff_threshold,
+            Some(40960)
+        );
+
+        // Headroom grows on a cable length change
+        assert_eq!(orch.set_pg_headroom("Ethernet0", 3, 81920).unwrap(), 61440);
+        assert_eq!(orch.remove_pg_headroom("Ethernet0", 4).unwrap(), 40960);
+        assert_eq!(
+            *callbacks.xoff_sizes.lock().unwrap(),
+            vec![(0x18, 20480), (0x18, 40960), (0x18, 61440), (0x18, 40960)]
+        );
+    }
 }
@@ -25,6 +25,9 @@ pub struct BufferPoolConfig {
     pub threshold_mode: ThresholdMode,
     pub xoff_threshold: Option<u64>,
     pub xon_threshold: Option<u64>,
+    /// Size as a percentage of the switch's total buffer. Only used by
+    /// dynamic pools, where it takes precedence over `size`.
+    pub size_percent: Option<u8>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -2,7 +2,8 @@
 
 use super::orch::{ChassisOrch, ChassisOrchCallbacks, ChassisOrchConfig, Result};
 use super::types::{
-    FabricPortKey, RawSaiObjectId, SystemPortConfig, SystemPortEntry, SystemPortKey,
+    FabricPortKey, RawSaiObjectId, SystemNeighEntry, SystemNeighKey, SystemPortConfig,
+    SystemPortEntry, SystemPortKey,
 };
 use std::cell::RefCell;
 
@@ -50,6 +51,49 @@ impl ChassisOrchCallbacks for FfiChassisCallbacks {
     fn on_system_port_created(&self, _entry: &SystemPortEntry) {}
     fn on_system_port_removed(&self, _key: &SystemPortKey) {}
     fn on_fabric_port_isolate_changed(&self, _key: &FabricPortKey, _isolate: bool) {}
+
+    fn create_system_lag(&self, _name: &str, _system_lag_id: u32) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+    fn remove_system_lag(&self, _oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+    fn add_system_lag_member(
+        &self,
+        _lag_oid: RawSaiObjectId,
+        _system_port_oid: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+    fn remove_system_lag_member(&self, _member_oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+    fn create_remote_neighbor(
+        &self,
+        _interface_oid: RawSaiObjectId,
+        _neigh: &SystemNeighEntry,
+    ) -> Result<()> {
+        Ok(())
+    }
+    fn remove_remote_neighbor(
+        &self,
+        _interface_oid: RawSaiObjectId,
+        _key: &SystemNeighKey,
+    ) -> Result<()> {
+        Ok(())
+    }
+    fn publish_system_neigh(&self, _neigh: &SystemNeighEntry) -> Result<()> {
+        Ok(())
+    }
+    fn unpublish_system_neigh(&self, _key: &SystemNeighKey) -> Result<()> {
+        Ok(())
+    }
+    fn write_encap_index(&self, _key: &SystemNeighKey, _encap_index: u32) -> Result<()> {
+        Ok(())
+    }
+    fn remove_encap_index(&self, _key: &SystemNeighKey) -> Result<()> {
+        Ok(())
+    }
 }
 
 thread_local! {
@@ -6,6 +6,7 @@
 //! - Type-safe system port and fabric port keys
 //! - Validated switch/core IDs
 //! - HashMap for O(1) port lookups
+//! - Encap index pool that rejects collisions and survives warm restart via STATE_DB
 
 mod ffi;
 mod orch;
@@ -17,6 +18,7 @@ pub use orch::{
     Result,
 };
 pub use types::{
-    ChassisStats, FabricPortEntry, FabricPortKey, RawSaiObjectId, SystemPortConfig,
+    tables, ChassisStats, EncapIndexPool, FabricPortEntry, FabricPortKey, RawSaiObjectId,
+    SystemLagConfig, SystemLagEntry, SystemNeighEntry, SystemNeighKey, SystemPortConfig,
     SystemPortEntry, SystemPortKey,
 };
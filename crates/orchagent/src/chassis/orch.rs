@@ -4,14 +4,18 @@
 //! systems. It coordinates:
 //! - System port configuration and SAI object management
 //! - Fabric port state and isolation
-//! - Cross-linecard communication setup
+//! - Cross-linecard communication setup: remote LAGs and neighbors from
+//!   CHASSIS_APP_DB, and publication of local neighbors with encap indexes
 
 use super::types::{
-    ChassisStats, FabricPortEntry, FabricPortKey, RawSaiObjectId, SystemPortConfig,
-    SystemPortEntry, SystemPortKey,
+    ChassisStats, EncapIndexPool, FabricPortEntry, FabricPortKey, RawSaiObjectId, SystemLagConfig,
+    SystemLagEntry, SystemNeighEntry, SystemNeighKey, SystemPortConfig, SystemPortEntry,
+    SystemPortKey,
 };
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use sonic_orch_common::TaskStatus;
+use sonic_types::MacAddress;
 use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
@@ -35,6 +39,14 @@ pub enum ChassisOrchError {
     InvalidCoreIndex(u32),
     #[error("SAI error: {0}")]
     SaiError(String),
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("System interface not found: {0}")]
+    InterfaceNotFound(String),
+    #[error("Encap index collision: {0}")]
+    EncapIndexCollision(String),
+    #[error("No free encap index for {0}")]
+    EncapIndexExhausted(String),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -47,6 +59,8 @@ pub struct ChassisOrchConfig {
     pub max_fabric_ports: u32,
     /// Enable VOQ (Virtual Output Queue) mode.
     pub voq_mode: bool,
+    /// Inclusive encap index range this linecard allocates local neighbors from.
+    pub encap_index_range: (u32, u32),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -94,6 +108,48 @@ pub trait ChassisOrchCallbacks: Send + Sync {
 
     /// Notification when fabric port isolation changes.
     fn on_fabric_port_isolate_changed(&self, key: &FabricPortKey, isolate: bool);
+
+    /// Create the LAG of a remote linecard's SYSTEM_LAG.
+    fn create_system_lag(&self, name: &str, system_lag_id: u32) -> Result<RawSaiObjectId>;
+
+    /// Remove a remote system LAG.
+    fn remove_system_lag(&self, oid: RawSaiObjectId) -> Result<()>;
+
+    /// Add a system port to a remote system LAG.
+    fn add_system_lag_member(
+        &self,
+        lag_oid: RawSaiObjectId,
+        system_port_oid: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId>;
+
+    /// Remove a remote system LAG member.
+    fn remove_system_lag_member(&self, member_oid: RawSaiObjectId) -> Result<()>;
+
+    /// Program a remote neighbor on a system port or LAG with its encap index.
+    fn create_remote_neighbor(
+        &self,
+        interface_oid: RawSaiObjectId,
+        neigh: &SystemNeighEntry,
+    ) -> Result<()>;
+
+    /// Remove a remote neighbor.
+    fn remove_remote_neighbor(
+        &self,
+        interface_oid: RawSaiObjectId,
+        key: &SystemNeighKey,
+    ) -> Result<()>;
+
+    /// Publish a local neighbor to CHASSIS_APP_DB SYSTEM_NEIGH.
+    fn publish_system_neigh(&self, neigh: &SystemNeighEntry) -> Result<()>;
+
+    /// Withdraw a local neighbor from CHASSIS_APP_DB.
+    fn unpublish_system_neigh(&self, key: &SystemNeighKey) -> Result<()>;
+
+    /// Persist a local encap index allocation to STATE_DB.
+    fn write_encap_index(&self, key: &SystemNeighKey, encap_index: u32) -> Result<()>;
+
+    /// Remove a persisted encap index allocation from STATE_DB.
+    fn remove_encap_index(&self, key: &SystemNeighKey) -> Result<()>;
 }
 
 pub struct ChassisOrch<C: ChassisOrchCallbacks> {
@@ -102,27 +158,43 @@ pub struct ChassisOrch<C: ChassisOrchCallbacks> {
     callbacks: Option<Arc<C>>,
     system_ports: HashMap<SystemPortKey, SystemPortEntry>,
     fabric_ports: HashMap<FabricPortKey, FabricPortEntry>,
+    /// SYSTEM_PORT alias -> system port key.
+    system_port_aliases: HashMap<String, SystemPortKey>,
+    system_lags: HashMap<String, SystemLagEntry>,
+    /// Neighbors owned by other linecards, programmed from CHASSIS_APP_DB.
+    remote_neighbors: HashMap<SystemNeighKey, SystemNeighEntry>,
+    /// Neighbors owned by this linecard, published to CHASSIS_APP_DB.
+    local_neighbors: HashMap<SystemNeighKey, SystemNeighEntry>,
+    encap_pool: EncapIndexPool,
 }
 
 impl<C: ChassisOrchCallbacks> ChassisOrch<C> {
     pub fn new(config: ChassisOrchConfig) -> Self {
+        let (encap_min, encap_max) = config.encap_index_range;
         Self {
             config,
             stats: ChassisOrchStats::default(),
             callbacks: None,
             system_ports: HashMap::new(),
             fabric_ports: HashMap::new(),
+            system_port_aliases: HashMap::new(),
+            system_lags: HashMap::new(),
+            remote_neighbors: HashMap::new(),
+            local_neighbors: HashMap::new(),
+            encap_pool: EncapIndexPool::new(encap_min, encap_max),
         }
     }
 
     pub fn with_callbacks(config: ChassisOrchConfig, callbacks: Arc<C>) -> Self {
-        Self {
-            config,
-            stats: ChassisOrchStats::default(),
-            callbacks: Some(callbacks),
-            system_ports: HashMap::new(),
-            fabric_ports: HashMap::new(),
-        }
+        let mut orch = Self::new(config);
+        orch.callbacks = Some(callbacks);
+        orch
+    }
+
+    fn callbacks(&self) -> Result<Arc<C>> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| ChassisOrchError::SaiError("No callbacks set".to_string()))
     }
 
     pub fn config(&self) -> &ChassisOrchConfig {
@@ -403,6 +475,370 @@ impl<C: ChassisOrchCallbacks> ChassisOrch<C> {
             .collect()
     }
 
+    // ===== VOQ System Tables =====
+
+    fn is_local_switch(&self, switch_id: u32) -> bool {
+        switch_id == self.config.switch_id
+    }
+
+    /// Resolves a system port or system LAG alias to its SAI object and
+    /// whether it belongs to this linecard.
+    fn system_interface(&self, alias: &str) -> Option<(RawSaiObjectId, bool)> {
+        if let Some(port) = self
+            .system_port_aliases
+            .get(alias)
+            .and_then(|key| self.system_ports.get(key))
+        {
+            return Some((port.sai_oid, self.is_local_switch(port.config.switch_id)));
+        }
+        self.system_lags
+            .get(alias)
+            .map(|lag| (lag.sai_oid, self.is_local_switch(lag.config.switch_id)))
+    }
+
+    fn has_neighbors_on(&self, alias: &str) -> bool {
+        self.remote_neighbors
+            .keys()
+            .chain(self.local_neighbors.keys())
+            .any(|key| key.alias == alias)
+    }
+
+    /// Handles a SYSTEM_PORT entry keyed by the chassis-wide port alias.
+    pub fn handle_system_port_set(
+        &mut self,
+        alias: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus> {
+        let mut config = SystemPortConfig::default();
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(ChassisOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(ChassisOrchError::InvalidConfig)?;
+
+        if let Some(key) = self.system_port_aliases.get(alias).cloned() {
+            if key.system_port_id == config.system_port_id {
+                let speed_changed = self
+                    .system_ports
+                    .get(&key)
+                    .is_some_and(|port| port.config.speed != config.speed);
+                if speed_changed {
+                    self.update_system_port_speed(&key, config.speed)?;
+                }
+                return Ok(TaskStatus::Success);
+            }
+            if self.has_neighbors_on(alias) {
+                return Ok(TaskStatus::NeedRetry);
+            }
+            self.remove_system_port(&key)?;
+            self.system_port_aliases.remove(alias);
+        }
+
+        let key = SystemPortKey::new(config.system_port_id);
+        self.add_system_port(config)?;
+        self.system_port_aliases.insert(alias.to_string(), key);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_PORT delete; waits until no neighbor or LAG uses it.
+    pub fn handle_system_port_del(&mut self, alias: &str) -> Result<TaskStatus> {
+        let Some(key) = self.system_port_aliases.get(alias).cloned() else {
+            return Ok(TaskStatus::Ignore);
+        };
+        let in_lag = self
+            .system_lags
+            .values()
+            .any(|lag| lag.members.contains_key(alias));
+        if in_lag || self.has_neighbors_on(alias) {
+            return Ok(TaskStatus::NeedRetry);
+        }
+        self.remove_system_port(&key)?;
+        self.system_port_aliases.remove(alias);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_LAG_TABLE entry. Only remote LAGs are created here;
+    /// local ones are owned by PortsOrch.
+    pub fn handle_system_lag_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus> {
+        let mut config = SystemLagConfig::default();
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(ChassisOrchError::InvalidConfig)?;
+        }
+        if config.system_lag_id == 0 {
+            return Err(ChassisOrchError::InvalidConfig(format!(
+                "{}: system_lag_id is required",
+                name
+            )));
+        }
+        if let Some(existing) = self.system_lags.get(name) {
+            if existing.config == config {
+                return Ok(TaskStatus::Success);
+            }
+            return Err(ChassisOrchError::InvalidConfig(format!(
+                "{}: system LAG attributes cannot change",
+                name
+            )));
+        }
+
+        let sai_oid = if self.is_local_switch(config.switch_id) {
+            0
+        } else {
+            let oid = self
+                .callbacks()?
+                .create_system_lag(name, config.system_lag_id)?;
+            self.stats.stats.system_lags_created += 1;
+            oid
+        };
+        self.system_lags.insert(
+            name.to_string(),
+            SystemLagEntry {
+                name: name.to_string(),
+                config,
+                sai_oid,
+                members: HashMap::new(),
+            },
+        );
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_LAG_TABLE delete; waits for members and neighbors.
+    pub fn handle_system_lag_del(&mut self, name: &str) -> Result<TaskStatus> {
+        let Some(lag) = self.system_lags.get(name) else {
+            return Ok(TaskStatus::Ignore);
+        };
+        if !lag.members.is_empty() || self.has_neighbors_on(name) {
+            return Ok(TaskStatus::NeedRetry);
+        }
+        if lag.sai_oid != 0 {
+            self.callbacks()?.remove_system_lag(lag.sai_oid)?;
+        }
+        self.system_lags.remove(name);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_LAG_MEMBER_TABLE entry.
+    pub fn handle_system_lag_member_set(
+        &mut self,
+        lag_name: &str,
+        member_alias: &str,
+    ) -> Result<TaskStatus> {
+        let Some(port_oid) = self
+            .system_port_aliases
+            .get(member_alias)
+            .and_then(|key| self.system_ports.get(key))
+            .map(|port| port.sai_oid)
+        else {
+            return Ok(TaskStatus::NeedRetry);
+        };
+        let callbacks = self.callbacks.clone();
+        let Some(lag) = self.system_lags.get_mut(lag_name) else {
+            return Ok(TaskStatus::NeedRetry);
+        };
+        if lag.members.contains_key(member_alias) {
+            return Ok(TaskStatus::Success);
+        }
+
+        let member_oid = if lag.sai_oid == 0 {
+            0
+        } else {
+            callbacks
+                .ok_or_else(|| ChassisOrchError::SaiError("No callbacks set".to_string()))?
+                .add_system_lag_member(lag.sai_oid, port_oid)?
+        };
+        lag.members.insert(member_alias.to_string(), member_oid);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_LAG_MEMBER_TABLE delete.
+    pub fn handle_system_lag_member_del(
+        &mut self,
+        lag_name: &str,
+        member_alias: &str,
+    ) -> Result<TaskStatus> {
+        let callbacks = self.callbacks.clone();
+        let Some(member_oid) = self
+            .system_lags
+            .get(lag_name)
+            .and_then(|lag| lag.members.get(member_alias).copied())
+        else {
+            return Ok(TaskStatus::Ignore);
+        };
+        if member_oid != 0 {
+            callbacks
+                .ok_or_else(|| ChassisOrchError::SaiError("No callbacks set".to_string()))?
+                .remove_system_lag_member(member_oid)?;
+        }
+        if let Some(lag) = self.system_lags.get_mut(lag_name) {
+            lag.members.remove(member_alias);
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    /// Reserves encap indexes persisted in STATE_DB before a warm restart
+    /// so local neighbors get their previous index back. Conflicting
+    /// entries are skipped and returned.
+    pub fn restore_encap_indexes(
+        &mut self,
+        entries: impl IntoIterator<Item = (SystemNeighKey, u32)>,
+    ) -> Vec<SystemNeighKey> {
+        let mut rejected = Vec::new();
+        for (key, index) in entries {
+            if self.encap_pool.reserve(index, &key).is_err() {
+                self.stats.stats.encap_index_collisions += 1;
+                rejected.push(key);
+            }
+        }
+        rejected
+    }
+
+    /// Registers a neighbor learned on a local system interface: allocates
+    /// (or reuses) its encap index, persists it and publishes the neighbor
+    /// to other linecards. Returns the encap index for NeighOrch to program.
+    pub fn add_local_neighbor(&mut self, key: SystemNeighKey, mac: MacAddress) -> Result<u32> {
+        match self.system_interface(&key.alias) {
+            Some((_, true)) => {}
+            _ => return Err(ChassisOrchError::InterfaceNotFound(key.alias.clone())),
+        }
+        let callbacks = self.callbacks()?;
+
+        let encap_index = self
+            .encap_pool
+            .allocate(&key)
+            .ok_or_else(|| ChassisOrchError::EncapIndexExhausted(key.to_string()))?;
+        let entry = SystemNeighEntry {
+            key: key.clone(),
+            mac,
+            encap_index,
+        };
+        if let Some(existing) = self.local_neighbors.get(&key) {
+            if *existing == entry {
+                return Ok(encap_index);
+            }
+        }
+
+        callbacks.write_encap_index(&key, encap_index)?;
+        callbacks.publish_system_neigh(&entry)?;
+        self.stats.stats.local_neighbors_published += 1;
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "ChassisOrch",
+            "publish_system_neigh",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key.to_string())
+        .with_object_type("system_neighbor")
+        .with_details(serde_json::json!({
+            "encap_index": encap_index,
+        })));
+
+        self.local_neighbors.insert(key, entry);
+        Ok(encap_index)
+    }
+
+    /// Withdraws a local neighbor and frees its encap index.
+    pub fn remove_local_neighbor(&mut self, key: &SystemNeighKey) -> Result<()> {
+        let Some(entry) = self.local_neighbors.get(key) else {
+            return Ok(());
+        };
+        let encap_index = entry.encap_index;
+        let callbacks = self.callbacks()?;
+        callbacks.unpublish_system_neigh(key)?;
+        let _ = callbacks.remove_encap_index(key);
+        self.encap_pool.release(encap_index);
+        self.local_neighbors.remove(key);
+        Ok(())
+    }
+
+    /// Handles a SYSTEM_NEIGH entry from CHASSIS_APP_DB. Entries for this
+    /// linecard's own interfaces are ignored.
+    pub fn handle_system_neigh_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus> {
+        let key = SystemNeighKey::parse(key).map_err(ChassisOrchError::InvalidConfig)?;
+        let entry = SystemNeighEntry::from_fields(key.clone(), fields)
+            .map_err(ChassisOrchError::InvalidConfig)?;
+        let interface_oid = match self.system_interface(&key.alias) {
+            None => return Ok(TaskStatus::NeedRetry),
+            Some((_, true)) => return Ok(TaskStatus::Ignore),
+            Some((oid, false)) => oid,
+        };
+        let existing = self.remote_neighbors.get(&key).cloned();
+        if existing.as_ref() == Some(&entry) {
+            return Ok(TaskStatus::Success);
+        }
+        let callbacks = self.callbacks()?;
+
+        if let Err(e) = self.encap_pool.reserve(entry.encap_index, &key) {
+            self.stats.stats.encap_index_collisions += 1;
+            self.stats.errors += 1;
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceCreate,
+                "ChassisOrch",
+                "create_remote_neighbor",
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(key.to_string())
+            .with_object_type("system_neighbor")
+            .with_error(e.clone()));
+            return Err(ChassisOrchError::EncapIndexCollision(e));
+        }
+
+        // Encap index or MAC changed: re-create.
+        if existing.is_some() {
+            callbacks.remove_remote_neighbor(interface_oid, &key)?;
+            self.remote_neighbors.remove(&key);
+        }
+        if let Err(e) = callbacks.create_remote_neighbor(interface_oid, &entry) {
+            self.encap_pool.release(entry.encap_index);
+            self.stats.errors += 1;
+            return Err(e);
+        }
+        self.stats.stats.remote_neighbors_created += 1;
+        self.remote_neighbors.insert(key, entry);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SYSTEM_NEIGH delete from CHASSIS_APP_DB.
+    pub fn handle_system_neigh_del(&mut self, key: &str) -> Result<TaskStatus> {
+        let key = SystemNeighKey::parse(key).map_err(ChassisOrchError::InvalidConfig)?;
+        let Some(entry) = self.remote_neighbors.get(&key) else {
+            return Ok(TaskStatus::Ignore);
+        };
+        let encap_index = entry.encap_index;
+        if let Some((interface_oid, _)) = self.system_interface(&key.alias) {
+            self.callbacks()?
+                .remove_remote_neighbor(interface_oid, &key)?;
+        }
+        self.encap_pool.release(encap_index);
+        self.remote_neighbors.remove(&key);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Get a remote neighbor.
+    pub fn get_remote_neighbor(&self, key: &SystemNeighKey) -> Option<&SystemNeighEntry> {
+        self.remote_neighbors.get(key)
+    }
+
+    /// Get a local neighbor.
+    pub fn get_local_neighbor(&self, key: &SystemNeighKey) -> Option<&SystemNeighEntry> {
+        self.local_neighbors.get(key)
+    }
+
+    /// Get a system LAG by name.
+    pub fn get_system_lag(&self, name: &str) -> Option<&SystemLagEntry> {
+        self.system_lags.get(name)
+    }
+
     /// Isolate all fabric ports (emergency shutdown).
     pub fn isolate_all_fabric_ports(&mut self) -> Result<()> {
         let keys: Vec<FabricPortKey> = self.fabric_ports.keys().cloned().collect();
@@ -429,9 +865,15 @@ impl<C: ChassisOrchCallbacks> ChassisOrch<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
 
     /// Mock callbacks for testing without SAI.
-    struct MockChassisCallbacks;
+    #[derive(Default)]
+    struct MockChassisCallbacks {
+        remote_neighbors: Mutex<Vec<(RawSaiObjectId, SystemNeighEntry)>>,
+        published: Mutex<Vec<SystemNeighEntry>>,
+        encap_indexes: Mutex<HashMap<SystemNeighKey, u32>>,
+    }
 
     impl ChassisOrchCallbacks for MockChassisCallbacks {
         fn create_system_port(&self, config: &SystemPortConfig) -> Result<RawSaiObjectId> {
@@ -474,6 +916,76 @@ mod tests {
         fn on_system_port_created(&self, _entry: &SystemPortEntry) {}
         fn on_system_port_removed(&self, _key: &SystemPortKey) {}
         fn on_fabric_port_isolate_changed(&self, _key: &FabricPortKey, _isolate: bool) {}
+
+        fn create_system_lag(&self, _name: &str, system_lag_id: u32) -> Result<RawSaiObjectId> {
+            Ok(0x3000 + system_lag_id as u64)
+        }
+
+        fn remove_system_lag(&self, _oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn add_system_lag_member(
+            &self,
+            _lag_oid: RawSaiObjectId,
+            system_port_oid: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x4000 + system_port_oid)
+        }
+
+        fn remove_system_lag_member(&self, _member_oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_remote_neighbor(
+            &self,
+            interface_oid: RawSaiObjectId,
+            neigh: &SystemNeighEntry,
+        ) -> Result<()> {
+            self.remote_neighbors
+                .lock()
+                .unwrap()
+                .push((interface_oid, neigh.clone()));
+            Ok(())
+        }
+
+        fn remove_remote_neighbor(
+            &self,
+            _interface_oid: RawSaiObjectId,
+            key: &SystemNeighKey,
+        ) -> Result<()> {
+            self.remote_neighbors
+                .lock()
+                .unwrap()
+                .retain(|(_, neigh)| neigh.key != *key);
+            Ok(())
+        }
+
+        fn publish_system_neigh(&self, neigh: &SystemNeighEntry) -> Result<()> {
+            self.published.lock().unwrap().push(neigh.clone());
+            Ok(())
+        }
+
+        fn unpublish_system_neigh(&self, key: &SystemNeighKey) -> Result<()> {
+            self.published
+                .lock()
+                .unwrap()
+                .retain(|neigh| neigh.key != *key);
+            Ok(())
+        }
+
+        fn write_encap_index(&self, key: &SystemNeighKey, encap_index: u32) -> Result<()> {
+            self.encap_indexes
+                .lock()
+                .unwrap()
+                .insert(key.clone(), encap_index);
+            Ok(())
+        }
+
+        fn remove_encap_index(&self, key: &SystemNeighKey) -> Result<()> {
+            self.encap_indexes.lock().unwrap().remove(key);
+            Ok(())
+        }
     }
 
     #[test]
@@ -622,6 +1134,7 @@ mod tests {
             max_system_ports: 256,
             max_fabric_ports: 64,
             voq_mode: true,
+            ..Default::default()
         };
         let orch: ChassisOrch<MockChassisCallbacks> = ChassisOrch::new(config);
 
@@ -929,7 +1442,7 @@ mod tests {
 
     #[test]
     fn test_with_callbacks() {
-        let callbacks = Arc::new(MockChassisCallbacks);
+        let callbacks = Arc::new(MockChassisCallbacks::default());
         let mut orch = ChassisOrch::with_callbacks(ChassisOrchConfig::default(), callbacks);
 
         let config = SystemPortConfig {
@@ -943,4 +1456,245 @@ mod tests {
         orch.add_system_port(config).unwrap();
         assert_eq!(orch.system_port_count(), 1);
     }
+
+    // ===== VOQ System Table Tests =====
+
+    const LOCAL_SWITCH: u32 = 0;
+    const REMOTE_SWITCH: u32 = 2;
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn neigh_fields(mac: &str, encap_index: u32) -> Vec<(String, String)> {
+        fields(&[("neigh", mac), ("encap_index", &encap_index.to_string())])
+    }
+
+    /// Builds an orch on linecard 1 (switch 0) that knows the system ports
+    /// of linecard 1 and linecard 2 (switch 2).
+    fn voq_orch() -> (ChassisOrch<MockChassisCallbacks>, Arc<MockChassisCallbacks>) {
+        let callbacks = Arc::new(MockChassisCallbacks::default());
+        let config = ChassisOrchConfig {
+            switch_id: LOCAL_SWITCH,
+            voq_mode: true,
+            encap_index_range: (1000, 1001),
+            ..Default::default()
+        };
+        let mut orch = ChassisOrch::with_callbacks(config, callbacks.clone());
+
+        for (linecard, switch_id) in [(1, LOCAL_SWITCH), (2, REMOTE_SWITCH)] {
+            for port in 0..4u32 {
+                let alias = format!("lc{}|asic0|Ethernet{}", linecard, port * 4);
+                let system_port_id = (linecard * 100 + port + 1).to_string();
+                let switch_id = switch_id.to_string();
+                let core_port_index = (port + 1).to_string();
+                let status = orch
+                    .handle_system_port_set(
+                        &alias,
+                        &fields(&[
+                            ("system_port_id", &system_port_id),
+                            ("switch_id", &switch_id),
+                            ("core_index", "0"),
+                            ("core_port_index", &core_port_index),
+                            ("speed", "400000"),
+                        ]),
+                    )
+                    .unwrap();
+                assert_eq!(status, TaskStatus::Success);
+            }
+        }
+        (orch, callbacks)
+    }
+
+    #[test]
+    fn test_system_port_table() {
+        let (mut orch, _callbacks) = voq_orch();
+        assert_eq!(orch.system_port_count(), 8);
+        assert_eq!(orch.get_system_ports_by_switch(REMOTE_SWITCH).len(), 4);
+
+        // Speed update in place.
+        orch.handle_system_port_set(
+            "lc2|asic0|Ethernet0",
+            &fields(&[
+                ("system_port_id", "201"),
+                ("switch_id", "2"),
+                ("speed", "100000"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            orch.get_system_port(&SystemPortKey::new(201))
+                .unwrap()
+                .config
+                .speed,
+            100000
+        );
+
+        assert!(orch
+            .handle_system_port_set("lc2|asic0|Ethernet0", &fields(&[("speed", "x")]))
+            .is_err());
+        assert_eq!(
+            orch.handle_system_port_del("lc2|asic0|Ethernet12").unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(orch.system_port_count(), 7);
+    }
+
+    #[test]
+    fn test_remote_neighbor_programming() {
+        let (mut orch, callbacks) = voq_orch();
+
+        // Unknown interface: wait for SYSTEM_PORT.
+        assert_eq!(
+            orch.handle_system_neigh_set(
+                "lc3|asic0|Ethernet0:10.0.3.1",
+                &neigh_fields("00:11:22:33:44:03", 3000)
+            )
+            .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        // Our own neighbor echoed back from CHASSIS_APP_DB.
+        assert_eq!(
+            orch.handle_system_neigh_set(
+                "lc1|asic0|Ethernet0:10.0.1.1",
+                &neigh_fields("00:11:22:33:44:01", 1000)
+            )
+            .unwrap(),
+            TaskStatus::Ignore
+        );
+
+        orch.handle_system_neigh_set(
+            "lc2|asic0|Ethernet4:10.0.2.1",
+            &neigh_fields("00:11:22:33:44:02", 2000),
+        )
+        .unwrap();
+        let port_oid = orch
+            .get_system_port(&SystemPortKey::new(202))
+            .unwrap()
+            .sai_oid;
+        {
+            let programmed = callbacks.remote_neighbors.lock().unwrap();
+            assert_eq!(programmed.len(), 1);
+            assert_eq!(programmed[0].0, port_oid);
+            assert_eq!(programmed[0].1.encap_index, 2000);
+        }
+
+        // Another remote neighbor reusing the index is a collision.
+        let result = orch.handle_system_neigh_set(
+            "lc2|asic0|Ethernet8:10.0.2.2",
+            &neigh_fields("00:11:22:33:44:04", 2000),
+        );
+        assert!(matches!(
+            result,
+            Err(ChassisOrchError::EncapIndexCollision(_))
+        ));
+        assert_eq!(orch.stats().stats.encap_index_collisions, 1);
+
+        // The port is in use until the neighbor goes away.
+        assert_eq!(
+            orch.handle_system_port_del("lc2|asic0|Ethernet4").unwrap(),
+            TaskStatus::NeedRetry
+        );
+        orch.handle_system_neigh_del("lc2|asic0|Ethernet4:10.0.2.1")
+            .unwrap();
+        assert!(callbacks.remote_neighbors.lock().unwrap().is_empty());
+        orch.handle_system_neigh_set(
+            "lc2|asic0|Ethernet8:10.0.2.2",
+            &neigh_fields("00:11:22:33:44:04", 2000),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_remote_neighbor_on_system_lag() {
+        let (mut orch, callbacks) = voq_orch();
+
+        orch.handle_system_lag_set(
+            "lc2|asic0|PortChannel1",
+            &fields(&[("system_lag_id", "7"), ("switch_id", "2")]),
+        )
+        .unwrap();
+        assert_eq!(
+            orch.handle_system_lag_member_set("lc2|asic0|PortChannel1", "lc2|asic0|Ethernet0")
+                .unwrap(),
+            TaskStatus::Success
+        );
+        let lag_oid = orch
+            .get_system_lag("lc2|asic0|PortChannel1")
+            .unwrap()
+            .sai_oid;
+        assert_ne!(lag_oid, 0);
+
+        orch.handle_system_neigh_set(
+            "lc2|asic0|PortChannel1:10.0.2.9",
+            &neigh_fields("00:11:22:33:44:09", 2009),
+        )
+        .unwrap();
+        assert_eq!(callbacks.remote_neighbors.lock().unwrap()[0].0, lag_oid);
+
+        assert_eq!(
+            orch.handle_system_lag_del("lc2|asic0|PortChannel1")
+                .unwrap(),
+            TaskStatus::NeedRetry
+        );
+    }
+
+    #[test]
+    fn test_local_neighbor_encap_index_reuse() {
+        let (mut orch, callbacks) = voq_orch();
+        let mac: MacAddress = "00:aa:bb:cc:dd:01".parse().unwrap();
+        let n1 = SystemNeighKey::parse("lc1|asic0|Ethernet0:10.0.1.1").unwrap();
+        let n2 = SystemNeighKey::parse("lc1|asic0|Ethernet4:10.0.1.2").unwrap();
+        let n3 = SystemNeighKey::parse("lc1|asic0|Ethernet8:10.0.1.3").unwrap();
+
+        assert_eq!(orch.add_local_neighbor(n1.clone(), mac).unwrap(), 1000);
+        assert_eq!(orch.add_local_neighbor(n2.clone(), mac).unwrap(), 1001);
+        assert!(matches!(
+            orch.add_local_neighbor(n3.clone(), mac),
+            Err(ChassisOrchError::EncapIndexExhausted(_))
+        ));
+        assert_eq!(callbacks.published.lock().unwrap().len(), 2);
+        assert_eq!(
+            callbacks.encap_indexes.lock().unwrap().get(&n2),
+            Some(&1001)
+        );
+
+        // Remote interfaces cannot hold local neighbors.
+        let remote = SystemNeighKey::parse("lc2|asic0|Ethernet0:10.0.2.1").unwrap();
+        assert!(orch.add_local_neighbor(remote, mac).is_err());
+
+        orch.remove_local_neighbor(&n1).unwrap();
+        assert!(callbacks.encap_indexes.lock().unwrap().get(&n1).is_none());
+        assert_eq!(orch.add_local_neighbor(n3.clone(), mac).unwrap(), 1000);
+        assert_eq!(
+            callbacks
+                .published
+                .lock()
+                .unwrap()
+                .last()
+                .unwrap()
+                .encap_index,
+            1000
+        );
+    }
+
+    #[test]
+    fn test_encap_index_restored_after_warm_restart() {
+        let (mut orch, _callbacks) = voq_orch();
+        let mac: MacAddress = "00:aa:bb:cc:dd:01".parse().unwrap();
+        let n1 = SystemNeighKey::parse("lc1|asic0|Ethernet0:10.0.1.1").unwrap();
+        let n2 = SystemNeighKey::parse("lc1|asic0|Ethernet4:10.0.1.2").unwrap();
+        let n3 = SystemNeighKey::parse("lc1|asic0|Ethernet8:10.0.1.3").unwrap();
+
+        // STATE_DB held n2 -> 1000 before the restart; n3 conflicts with it.
+        let rejected = orch.restore_encap_indexes(vec![(n2.clone(), 1000), (n3.clone(), 1000)]);
+        assert_eq!(rejected, vec![n3]);
+
+        assert_eq!(orch.add_local_neighbor(n1, mac).unwrap(), 1001);
+        assert_eq!(orch.add_local_neighbor(n2.clone(), mac).unwrap(), 1000);
+        assert_eq!(orch.get_local_neighbor(&n2).unwrap().encap_index, 1000);
+    }
 }
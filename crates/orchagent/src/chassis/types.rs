@@ -1,9 +1,28 @@
 //! Chassis management types for modular systems.
 
+use sonic_types::MacAddress;
 use std::collections::HashMap;
+use std::net::IpAddr;
 
 pub type RawSaiObjectId = u64;
 
+/// Table names handled by ChassisOrch.
+pub mod tables {
+    pub const SYSTEM_PORT_TABLE: &str = "SYSTEM_PORT";
+    pub const SYSTEM_LAG_TABLE: &str = "SYSTEM_LAG_TABLE";
+    pub const SYSTEM_LAG_MEMBER_TABLE: &str = "SYSTEM_LAG_MEMBER_TABLE";
+    /// CHASSIS_APP_DB table shared by all linecards.
+    pub const SYSTEM_NEIGH_TABLE: &str = "SYSTEM_NEIGH";
+    /// STATE_DB table persisting local encap index allocations.
+    pub const SYSTEM_NEIGH_ENCAP_INDEX_TABLE: &str = "SYSTEM_NEIGH_ENCAP_INDEX";
+}
+
+fn parse_u32(field: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", field, value))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SystemPortKey {
     pub system_port_id: u32,
@@ -15,7 +34,7 @@ impl SystemPortKey {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SystemPortConfig {
     pub system_port_id: u32,
     pub switch_id: u32,
@@ -24,6 +43,28 @@ pub struct SystemPortConfig {
     pub speed: u32,
 }
 
+impl SystemPortConfig {
+    /// Applies one SYSTEM_PORT field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "system_port_id" => self.system_port_id = parse_u32(field, value)?,
+            "switch_id" => self.switch_id = parse_u32(field, value)?,
+            "core_index" => self.core_index = parse_u32(field, value)?,
+            "core_port_index" => self.core_port_index = parse_u32(field, value)?,
+            "speed" => self.speed = parse_u32(field, value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.system_port_id == 0 {
+            return Err("system_port_id is required".to_string());
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SystemPortEntry {
     pub key: SystemPortKey,
@@ -70,8 +111,207 @@ impl FabricPortEntry {
     }
 }
 
+/// SYSTEM_LAG_TABLE entry, keyed by the chassis-wide LAG name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SystemLagConfig {
+    pub system_lag_id: u32,
+    pub switch_id: u32,
+}
+
+impl SystemLagConfig {
+    /// Applies one SYSTEM_LAG_TABLE field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "system_lag_id" => self.system_lag_id = parse_u32(field, value)?,
+            "switch_id" => self.switch_id = parse_u32(field, value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemLagEntry {
+    pub name: String,
+    pub config: SystemLagConfig,
+    /// SAI LAG OID; 0 for local LAGs, which PortsOrch owns.
+    pub sai_oid: RawSaiObjectId,
+    /// Member system port alias -> LAG member OID.
+    pub members: HashMap<String, RawSaiObjectId>,
+}
+
+/// SYSTEM_NEIGH key: `<system port or LAG alias>:<ip>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SystemNeighKey {
+    pub alias: String,
+    pub ip: IpAddr,
+}
+
+impl SystemNeighKey {
+    pub fn new(alias: impl Into<String>, ip: IpAddr) -> Self {
+        Self {
+            alias: alias.into(),
+            ip,
+        }
+    }
+
+    pub fn parse(key: &str) -> Result<Self, String> {
+        // Aliases never contain ':', IPv6 addresses do.
+        let (alias, ip) = key
+            .split_once(':')
+            .filter(|(alias, _)| !alias.is_empty())
+            .ok_or_else(|| format!("Invalid system neighbor key: {}", key))?;
+        let ip = ip
+            .parse()
+            .map_err(|_| format!("Invalid neighbor IP: {}", ip))?;
+        Ok(Self::new(alias, ip))
+    }
+}
+
+impl std::fmt::Display for SystemNeighKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.alias, self.ip)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemNeighEntry {
+    pub key: SystemNeighKey,
+    pub mac: MacAddress,
+    pub encap_index: u32,
+}
+
+impl SystemNeighEntry {
+    /// Parses a SYSTEM_NEIGH entry published by the owning linecard.
+    pub fn from_fields(key: SystemNeighKey, fields: &[(String, String)]) -> Result<Self, String> {
+        let mut mac = None;
+        let mut encap_index = None;
+        for (field, value) in fields {
+            match field.as_str() {
+                "neigh" => {
+                    mac = Some(
+                        value
+                            .parse::<MacAddress>()
+                            .map_err(|_| format!("Invalid neighbor MAC: {}", value))?,
+                    )
+                }
+                "encap_index" => encap_index = Some(parse_u32(field, value)?),
+                _ => {}
+            }
+        }
+        Ok(Self {
+            mac: mac.ok_or_else(|| format!("{}: neigh is required", key))?,
+            encap_index: encap_index.ok_or_else(|| format!("{}: encap_index is required", key))?,
+            key,
+        })
+    }
+}
+
+/// Chassis-wide encap index bookkeeping. Local neighbors allocate from
+/// this linecard's range; indexes of remote neighbors are recorded so a
+/// collision with any known neighbor is rejected.
+#[derive(Debug, Clone)]
+pub struct EncapIndexPool {
+    min: u32,
+    max: u32,
+    used: HashMap<u32, SystemNeighKey>,
+}
+
+impl EncapIndexPool {
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            min,
+            max,
+            used: HashMap::new(),
+        }
+    }
+
+    /// Returns the index held by `key`, or the lowest free one in range.
+    pub fn allocate(&mut self, key: &SystemNeighKey) -> Option<u32> {
+        if let Some(index) = self.index_of(key) {
+            return Some(index);
+        }
+        let index = (self.min..=self.max).find(|i| !self.used.contains_key(i))?;
+        self.used.insert(index, key.clone());
+        Some(index)
+    }
+
+    /// Records an index chosen elsewhere (restored or remote), replacing
+    /// any index `key` held before.
+    pub fn reserve(&mut self, index: u32, key: &SystemNeighKey) -> Result<(), String> {
+        if let Some(owner) = self.used.get(&index).filter(|owner| *owner != key) {
+            return Err(format!(
+                "Encap index {} of {} already used by {}",
+                index, key, owner
+            ));
+        }
+        if let Some(old) = self.index_of(key) {
+            self.used.remove(&old);
+        }
+        self.used.insert(index, key.clone());
+        Ok(())
+    }
+
+    pub fn release(&mut self, index: u32) {
+        self.used.remove(&index);
+    }
+
+    pub fn index_of(&self, key: &SystemNeighKey) -> Option<u32> {
+        self.used
+            .iter()
+            .find(|(_, owner)| *owner == key)
+            .map(|(index, _)| *index)
+    }
+
+    pub fn used_count(&self) -> usize {
+        self.used.len()
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ChassisStats {
     pub system_ports_created: u64,
     pub fabric_ports_created: u64,
+    pub system_lags_created: u64,
+    pub remote_neighbors_created: u64,
+    pub local_neighbors_published: u64,
+    pub encap_index_collisions: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_neigh_key_parse() {
+        let key = SystemNeighKey::parse("lc1|asic0|Ethernet0:10.0.0.1").unwrap();
+        assert_eq!(key.alias, "lc1|asic0|Ethernet0");
+        assert_eq!(key.to_string(), "lc1|asic0|Ethernet0:10.0.0.1");
+
+        let key = SystemNeighKey::parse("lc1|asic0|Ethernet0:fc00::1").unwrap();
+        assert_eq!(key.ip, "fc00::1".parse::<IpAddr>().unwrap());
+
+        assert!(SystemNeighKey::parse("Ethernet0").is_err());
+        assert!(SystemNeighKey::parse(":10.0.0.1").is_err());
+    }
+
+    #[test]
+    fn test_encap_index_pool() {
+        let a = SystemNeighKey::parse("lc0|asic0|Ethernet0:10.0.0.1").unwrap();
+        let b = SystemNeighKey::parse("lc0|asic0|Ethernet4:10.0.0.2").unwrap();
+        let mut pool = EncapIndexPool::new(100, 101);
+
+        assert_eq!(pool.allocate(&a), Some(100));
+        assert_eq!(pool.allocate(&a), Some(100));
+        assert_eq!(pool.allocate(&b), Some(101));
+        let c = SystemNeighKey::parse("lc0|asic0|Ethernet8:10.0.0.3").unwrap();
+        assert_eq!(pool.allocate(&c), None);
+
+        assert!(pool.reserve(100, &b).is_err());
+        pool.release(100);
+        assert_eq!(pool.allocate(&c), Some(100));
+        assert!(pool.reserve(500, &c).is_ok());
+        assert_eq!(pool.index_of(&c), Some(500));
+        assert_eq!(pool.used_count(), 2);
+    }
 }
@@ -1,7 +1,10 @@
 //! FFI exports for CoppOrch.
 
 use super::orch::{CoppOrch, CoppOrchCallbacks, CoppOrchConfig, Result};
-use super::types::{CoppTrapConfig, CoppTrapKey, RawSaiObjectId};
+use super::types::{
+    CoppPolicerConfig, CoppTrapAction, CoppTrapConfig, CoppTrapGroupConfig, CoppTrapKey,
+    RawSaiObjectId,
+};
 use std::cell::RefCell;
 use std::sync::Arc;
 
@@ -27,6 +30,87 @@ impl CoppOrchCallbacks for StubCoppCallbacks {
 
     fn on_trap_created(&self, _key: &CoppTrapKey, _trap_id: RawSaiObjectId) {}
     fn on_trap_removed(&self, _key: &CoppTrapKey) {}
+
+    fn create_trap_group(
+        &self,
+        _name: &str,
+        _config: &CoppTrapGroupConfig,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn update_trap_group(
+        &self,
+        _group_id: RawSaiObjectId,
+        _config: &CoppTrapGroupConfig,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn remove_trap_group(&self, _group_id: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_trap_group(&self, _trap_id: RawSaiObjectId, _group_id: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn update_trap_action(
+        &self,
+        _trap_id: RawSaiObjectId,
+        _action: CoppTrapAction,
+        _priority: Option<u32>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_policer(&self, _config: &CoppPolicerConfig) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn update_policer(
+        &self,
+        _policer_id: RawSaiObjectId,
+        _config: &CoppPolicerConfig,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn remove_policer(&self, _policer_id: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_trap_group_policer(
+        &self,
+        _group_id: RawSaiObjectId,
+        _policer_id: RawSaiObjectId,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_genetlink_hostif(
+        &self,
+        _name: &str,
+        _mcgrp_name: Option<&str>,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn remove_genetlink_hostif(&self, _hostif_id: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_hostif_table_entry(
+        &self,
+        _trap_id: RawSaiObjectId,
+        _hostif_id: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn remove_hostif_table_entry(&self, _entry_id: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
 }
 
 thread_local! {
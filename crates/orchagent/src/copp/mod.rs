@@ -7,6 +7,7 @@
 //! - Type-safe trap action enum
 //! - Option types for optional policer parameters
 //! - HashMap for O(1) trap lookups
+//! - Reference-counted trap groups shared by multiple traps
 //! - Result type for error handling
 //! - Statistics tracking for dropped and rate-limited packets
 
@@ -17,5 +18,6 @@ mod types;
 pub use ffi::{register_copp_orch, unregister_copp_orch};
 pub use orch::{CoppOrch, CoppOrchCallbacks, CoppOrchConfig, CoppOrchError, CoppOrchStats, Result};
 pub use types::{
-    CoppStats, CoppTrapAction, CoppTrapConfig, CoppTrapEntry, CoppTrapKey, RawSaiObjectId,
+    CoppPolicerConfig, CoppStats, CoppTrapAction, CoppTrapConfig, CoppTrapEntry,
+    CoppTrapGroupConfig, CoppTrapGroupEntry, CoppTrapKey, RawSaiObjectId,
 };
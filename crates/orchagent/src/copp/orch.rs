@@ -1,9 +1,13 @@
 //! CoPP orchestration logic.
 
-use super::types::{CoppStats, CoppTrapConfig, CoppTrapEntry, CoppTrapKey, RawSaiObjectId};
+use super::types::{
+    CoppPolicerConfig, CoppStats, CoppTrapAction, CoppTrapConfig, CoppTrapEntry,
+    CoppTrapGroupConfig, CoppTrapGroupEntry, CoppTrapKey, RawSaiObjectId,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::{audit_log, debug_log, error_log, info_log, warn_log};
-use std::collections::HashMap;
+use sonic_orch_common::{KeyOpFieldsValues, Operation};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -28,6 +32,18 @@ pub enum CoppOrchError {
     #[error("Invalid rate limit {0}: CIR and CBS must be non-zero")]
     InvalidRate(u64),
 
+    /// Trap group with the specified name was not found
+    #[error("CoPP trap group not found: {0}")]
+    TrapGroupNotFound(String),
+
+    /// Trap group still has traps bound to it
+    #[error("CoPP trap group {name} is still referenced by {ref_count} traps")]
+    TrapGroupInUse { name: String, ref_count: usize },
+
+    /// Invalid COPP_TABLE configuration
+    #[error("Invalid CoPP configuration: {0}")]
+    InvalidConfig(String),
+
     /// SAI operation failed
     #[error("SAI operation failed: {0}")]
     SaiError(String),
@@ -55,6 +71,48 @@ pub trait CoppOrchCallbacks: Send + Sync {
     fn get_trap_stats(&self, trap_id: RawSaiObjectId) -> Result<(u64, u64)>;
     fn on_trap_created(&self, key: &CoppTrapKey, trap_id: RawSaiObjectId);
     fn on_trap_removed(&self, key: &CoppTrapKey);
+
+    fn create_trap_group(&self, name: &str, config: &CoppTrapGroupConfig)
+        -> Result<RawSaiObjectId>;
+    fn update_trap_group(
+        &self,
+        group_id: RawSaiObjectId,
+        config: &CoppTrapGroupConfig,
+    ) -> Result<()>;
+    fn remove_trap_group(&self, group_id: RawSaiObjectId) -> Result<()>;
+    /// Binds a trap to a trap group (SAI_HOSTIF_TRAP_ATTR_TRAP_GROUP).
+    fn set_trap_group(&self, trap_id: RawSaiObjectId, group_id: RawSaiObjectId) -> Result<()>;
+    fn update_trap_action(
+        &self,
+        trap_id: RawSaiObjectId,
+        action: CoppTrapAction,
+        priority: Option<u32>,
+    ) -> Result<()>;
+
+    fn create_policer(&self, config: &CoppPolicerConfig) -> Result<RawSaiObjectId>;
+    /// Updates rates of an existing policer in place.
+    fn update_policer(&self, policer_id: RawSaiObjectId, config: &CoppPolicerConfig) -> Result<()>;
+    fn remove_policer(&self, policer_id: RawSaiObjectId) -> Result<()>;
+    /// Attaches a policer to a trap group; a zero policer detaches it.
+    fn set_trap_group_policer(
+        &self,
+        group_id: RawSaiObjectId,
+        policer_id: RawSaiObjectId,
+    ) -> Result<()>;
+
+    fn create_genetlink_hostif(
+        &self,
+        name: &str,
+        mcgrp_name: Option<&str>,
+    ) -> Result<RawSaiObjectId>;
+    fn remove_genetlink_hostif(&self, hostif_id: RawSaiObjectId) -> Result<()>;
+    /// Steers a trap to a genetlink host interface.
+    fn create_hostif_table_entry(
+        &self,
+        trap_id: RawSaiObjectId,
+        hostif_id: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId>;
+    fn remove_hostif_table_entry(&self, entry_id: RawSaiObjectId) -> Result<()>;
 }
 
 pub struct CoppOrch<C: CoppOrchCallbacks> {
@@ -62,6 +120,7 @@ pub struct CoppOrch<C: CoppOrchCallbacks> {
     config: CoppOrchConfig,
     stats: CoppOrchStats,
     traps: HashMap<CoppTrapKey, CoppTrapEntry>,
+    trap_groups: HashMap<String, CoppTrapGroupEntry>,
     callbacks: Option<Arc<C>>,
 }
 
@@ -71,6 +130,7 @@ impl<C: CoppOrchCallbacks> CoppOrch<C> {
             config,
             stats: CoppOrchStats::default(),
             traps: HashMap::new(),
+            trap_groups: HashMap::new(),
             callbacks: None,
         }
     }
@@ -167,6 +227,16 @@ impl<C: CoppOrchCallbacks> CoppOrch<C> {
             CoppOrchError::NotInitialized
         })?;
 
+        let mut entry = entry;
+        if entry.hostif_table_entry_oid != 0 {
+            if let Err(e) = callbacks.remove_hostif_table_entry(entry.hostif_table_entry_oid) {
+                error_log!("CoppOrch", trap_id = %key.trap_id, error = %e, "SAI remove_hostif_table_entry failed");
+                self.traps.insert(key.clone(), entry);
+                return Err(e);
+            }
+            entry.hostif_table_entry_oid = 0;
+        }
+
         callbacks.remove_trap(trap_oid).map_err(|e| {
             error_log!("CoppOrch", trap_id = %key.trap_id, oid = trap_oid, error = %e, "SAI remove_trap failed");
             // Re-insert the entry since removal failed
@@ -185,6 +255,12 @@ impl<C: CoppOrchCallbacks> CoppOrch<C> {
         self.stats.stats.traps_created = self.stats.stats.traps_created.saturating_sub(1);
         callbacks.on_trap_removed(key);
 
+        if let Some(group) = entry.trap_group.as_ref() {
+            if let Some(group_entry) = self.trap_groups.get_mut(group) {
+                group_entry.trap_ids.remove(&key.trap_id);
+            }
+        }
+
         info_log!("CoppOrch", trap_id = %key.trap_id, oid = trap_oid, "CoPP trap removed successfully");
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceDelete, "CoppOrch", "remove_trap")
@@ -268,6 +344,536 @@ impl<C: CoppOrchCallbacks> CoppOrch<C> {
         Ok(())
     }
 
+    /// Processes a COPP_TABLE entry written by coppmgrd. The key is the
+    /// trap group name; `trap_ids` lists the traps bound to the group.
+    pub fn process_copp_task(&mut self, task: &KeyOpFieldsValues) -> Result<()> {
+        match task.op {
+            Operation::Set => {
+                let (config, trap_ids) = Self::parse_trap_group_config(task)?;
+                self.add_trap_group(&task.key, config, trap_ids)
+            }
+            Operation::Del => {
+                let owned: Vec<String> = match self.trap_groups.get(&task.key) {
+                    Some(group) => group.trap_ids.iter().cloned().collect(),
+                    None => return Ok(()),
+                };
+                for trap_id in owned {
+                    self.remove_trap(&CoppTrapKey::new(trap_id))?;
+                }
+                self.remove_trap_group(&task.key)
+            }
+        }
+    }
+
+    fn parse_trap_group_config(
+        task: &KeyOpFieldsValues,
+    ) -> Result<(CoppTrapGroupConfig, BTreeSet<String>)> {
+        fn parse<T: std::str::FromStr>(task: &KeyOpFieldsValues, field: &str) -> Result<Option<T>>
+        where
+            T::Err: std::fmt::Display,
+        {
+            task.get_field(field)
+                .map(|raw| {
+                    raw.parse().map_err(|e| {
+                        CoppOrchError::InvalidConfig(format!("Invalid {}: {}", field, e))
+                    })
+                })
+                .transpose()
+        }
+
+        let trap_action = parse(task, "trap_action")?.unwrap_or(CoppTrapAction::Trap);
+        let mut config = CoppTrapGroupConfig::new(trap_action);
+        config.trap_priority = parse(task, "trap_priority")?;
+        config.queue = parse(task, "queue")?;
+        config.genetlink_name = task.get_field("genetlink_name").map(str::to_string);
+        config.genetlink_mcgrp_name = task.get_field("genetlink_mcgrp_name").map(str::to_string);
+
+        let cir: Option<u64> = parse(task, "cir")?;
+        let cbs: Option<u64> = parse(task, "cbs")?;
+        let meter_type = task.get_field("meter_type").map(str::to_string);
+        let mode = task.get_field("mode").map(str::to_string);
+        if cir.is_some() || cbs.is_some() || meter_type.is_some() || mode.is_some() {
+            let (Some(cir), Some(cbs)) = (cir, cbs) else {
+                return Err(CoppOrchError::InvalidConfig(format!(
+                    "Policer for {} requires both cir and cbs",
+                    task.key
+                )));
+            };
+            config.policer = Some(CoppPolicerConfig {
+                meter_type,
+                mode,
+                color: task.get_field("color").map(str::to_string),
+                cir,
+                cbs,
+                pir: parse(task, "pir")?,
+                pbs: parse(task, "pbs")?,
+            });
+        }
+
+        let trap_ids = task
+            .get_field("trap_ids")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        Ok((config, trap_ids))
+    }
+
+    fn validate_trap_group_config(name: &str, config: &CoppTrapGroupConfig) -> Result<()> {
+        if let Some(queue) = config.queue {
+            if queue >= 8 {
+                error_log!("CoppOrch", group = %name, queue = queue, "Invalid CPU queue number");
+                return Err(CoppOrchError::InvalidQueue(queue));
+            }
+        }
+        if let Some(policer) = config.policer.as_ref() {
+            if policer.cir == 0 || policer.cbs == 0 {
+                error_log!("CoppOrch", group = %name, cir = policer.cir, cbs = policer.cbs, "Invalid policer rate");
+                return Err(CoppOrchError::InvalidRate(policer.cir));
+            }
+        }
+        if config.genetlink_mcgrp_name.is_some() && config.genetlink_name.is_none() {
+            return Err(CoppOrchError::InvalidConfig(format!(
+                "genetlink_mcgrp_name for {} requires genetlink_name",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Creates a trap group, or reconciles an existing one with `config`,
+    /// then binds exactly `trap_ids` to it. Traps owned by another group are
+    /// moved; traps no longer listed are removed.
+    pub fn add_trap_group(
+        &mut self,
+        name: &str,
+        config: CoppTrapGroupConfig,
+        trap_ids: BTreeSet<String>,
+    ) -> Result<()> {
+        debug_log!("CoppOrch", group = %name, traps = trap_ids.len(), "Setting CoPP trap group");
+        Self::validate_trap_group_config(name, &config)?;
+
+        let callbacks = Arc::clone(self.callbacks.as_ref().ok_or_else(|| {
+            error_log!("CoppOrch", "Callbacks not configured");
+            CoppOrchError::NotInitialized
+        })?);
+
+        if self.trap_groups.contains_key(name) {
+            self.update_trap_group_config(&callbacks, name, config)?;
+        } else {
+            self.create_trap_group(&callbacks, name, config)?;
+        }
+
+        let stale: Vec<String> = self.trap_groups[name]
+            .trap_ids
+            .difference(&trap_ids)
+            .cloned()
+            .collect();
+        for trap_id in stale {
+            self.remove_trap(&CoppTrapKey::new(trap_id))?;
+        }
+        for trap_id in &trap_ids {
+            self.bind_trap(&callbacks, trap_id, name)?;
+        }
+
+        Ok(())
+    }
+
+    fn create_trap_group(
+        &mut self,
+        callbacks: &Arc<C>,
+        name: &str,
+        config: CoppTrapGroupConfig,
+    ) -> Result<()> {
+        let mut entry = CoppTrapGroupEntry::new(name.to_string(), config);
+
+        let group_oid = callbacks
+            .create_trap_group(name, &entry.config)
+            .inspect_err(|e| {
+                error_log!("CoppOrch", group = %name, error = %e, "SAI create_trap_group failed");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::SaiOperation,
+                    "CoppOrch",
+                    "create_trap_group"
+                )
+                .with_object_id(name)
+                .with_object_type("copp_trap_group")
+                .with_error(e.to_string()));
+            })?;
+        entry.trap_group_oid = group_oid;
+
+        if let Some(policer) = entry.config.policer.as_ref() {
+            let attached = callbacks.create_policer(policer).and_then(|policer_oid| {
+                callbacks
+                    .set_trap_group_policer(group_oid, policer_oid)
+                    .inspect_err(|_| {
+                        let _ = callbacks.remove_policer(policer_oid);
+                    })
+                    .map(|_| policer_oid)
+            });
+            match attached {
+                Ok(policer_oid) => {
+                    entry.policer_oid = policer_oid;
+                    self.stats.stats.policers_created += 1;
+                }
+                Err(e) => {
+                    error_log!("CoppOrch", group = %name, error = %e, "Failed to attach policer to trap group");
+                    let _ = callbacks.remove_trap_group(group_oid);
+                    return Err(e);
+                }
+            }
+        }
+
+        if let Some(genetlink_name) = entry.config.genetlink_name.as_deref() {
+            match callbacks.create_genetlink_hostif(
+                genetlink_name,
+                entry.config.genetlink_mcgrp_name.as_deref(),
+            ) {
+                Ok(hostif_oid) => {
+                    entry.hostif_oid = hostif_oid;
+                    self.stats.stats.hostifs_created += 1;
+                }
+                Err(e) => {
+                    error_log!("CoppOrch", group = %name, genetlink = %genetlink_name, error = %e, "SAI create_genetlink_hostif failed");
+                    let _ = callbacks.remove_trap_group(group_oid);
+                    if entry.policer_oid != 0 {
+                        let _ = callbacks.remove_policer(entry.policer_oid);
+                        self.stats.stats.policers_created =
+                            self.stats.stats.policers_created.saturating_sub(1);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        self.stats.stats.trap_groups_created += 1;
+        info_log!("CoppOrch", group = %name, oid = group_oid, "CoPP trap group created successfully");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "CoppOrch",
+            "create_trap_group"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("0x{:x}", group_oid))
+        .with_object_type("copp_trap_group")
+        .with_details(serde_json::json!({
+            "group": name,
+            "queue": entry.config.queue,
+            "policer_oid": entry.policer_oid,
+            "genetlink_name": entry.config.genetlink_name,
+        })));
+
+        self.trap_groups.insert(name.to_string(), entry);
+        Ok(())
+    }
+
+    fn update_trap_group_config(
+        &mut self,
+        callbacks: &Arc<C>,
+        name: &str,
+        config: CoppTrapGroupConfig,
+    ) -> Result<()> {
+        let group = self.trap_groups.get_mut(name).expect("trap group exists");
+        if group.config == config {
+            return Ok(());
+        }
+        let group_oid = group.trap_group_oid;
+
+        if group.config.queue != config.queue {
+            callbacks.update_trap_group(group_oid, &config)?;
+            group.config.queue = config.queue;
+        }
+
+        match (group.config.policer.as_ref(), config.policer.as_ref()) {
+            (None, None) => {}
+            (Some(old), Some(new)) if old == new => {}
+            (Some(old), Some(new)) if old.rates_only_differ(new) => {
+                callbacks.update_policer(group.policer_oid, new)?;
+                info_log!("CoppOrch", group = %name, cir = new.cir, cbs = new.cbs, "CoPP policer updated in place");
+            }
+            (_, Some(new)) => {
+                let policer_oid = callbacks.create_policer(new)?;
+                if let Err(e) = callbacks.set_trap_group_policer(group_oid, policer_oid) {
+                    let _ = callbacks.remove_policer(policer_oid);
+                    return Err(e);
+                }
+                if group.policer_oid != 0 {
+                    callbacks.remove_policer(group.policer_oid)?;
+                } else {
+                    self.stats.stats.policers_created += 1;
+                }
+                group.policer_oid = policer_oid;
+            }
+            (Some(_), None) => {
+                callbacks.set_trap_group_policer(group_oid, 0)?;
+                callbacks.remove_policer(group.policer_oid)?;
+                group.policer_oid = 0;
+                self.stats.stats.policers_created =
+                    self.stats.stats.policers_created.saturating_sub(1);
+            }
+        }
+        group.config.policer = config.policer.clone();
+
+        let genetlink_changed = group.config.genetlink_name != config.genetlink_name
+            || group.config.genetlink_mcgrp_name != config.genetlink_mcgrp_name;
+        let action_changed = group.config.trap_action != config.trap_action
+            || group.config.trap_priority != config.trap_priority;
+        let policer_oid = group.policer_oid;
+        let old_hostif_oid = group.hostif_oid;
+        let trap_ids: Vec<String> = group.trap_ids.iter().cloned().collect();
+        group.config = config.clone();
+
+        let mut hostif_oid = old_hostif_oid;
+        if genetlink_changed {
+            for trap_id in &trap_ids {
+                if let Some(trap) = self.traps.get_mut(&CoppTrapKey::new(trap_id.clone())) {
+                    if trap.hostif_table_entry_oid != 0 {
+                        callbacks.remove_hostif_table_entry(trap.hostif_table_entry_oid)?;
+                        trap.hostif_table_entry_oid = 0;
+                    }
+                }
+            }
+            if old_hostif_oid != 0 {
+                callbacks.remove_genetlink_hostif(old_hostif_oid)?;
+                self.stats.stats.hostifs_created =
+                    self.stats.stats.hostifs_created.saturating_sub(1);
+                hostif_oid = 0;
+            }
+            if let Some(genetlink_name) = config.genetlink_name.as_deref() {
+                hostif_oid = callbacks.create_genetlink_hostif(
+                    genetlink_name,
+                    config.genetlink_mcgrp_name.as_deref(),
+                )?;
+                self.stats.stats.hostifs_created += 1;
+            }
+            if let Some(group) = self.trap_groups.get_mut(name) {
+                group.hostif_oid = hostif_oid;
+            }
+        }
+
+        let trap_config = config.trap_config();
+        for trap_id in &trap_ids {
+            let Some(trap) = self.traps.get_mut(&CoppTrapKey::new(trap_id.clone())) else {
+                continue;
+            };
+            if action_changed {
+                callbacks.update_trap_action(
+                    trap.trap_oid,
+                    config.trap_action,
+                    config.trap_priority,
+                )?;
+            }
+            if genetlink_changed && hostif_oid != 0 {
+                trap.hostif_table_entry_oid =
+                    callbacks.create_hostif_table_entry(trap.trap_oid, hostif_oid)?;
+            }
+            trap.config = trap_config.clone();
+            trap.policer_oid = policer_oid;
+        }
+
+        info_log!("CoppOrch", group = %name, oid = group_oid, "CoPP trap group updated successfully");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "CoppOrch",
+            "update_trap_group"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("0x{:x}", group_oid))
+        .with_object_type("copp_trap_group")
+        .with_details(serde_json::json!({
+            "group": name,
+            "queue": config.queue,
+            "policer_oid": policer_oid,
+            "genetlink_name": config.genetlink_name,
+        })));
+
+        Ok(())
+    }
+
+    /// Binds a trap to a group, creating the trap if needed or moving it
+    /// from the group it is currently bound to.
+    fn bind_trap(&mut self, callbacks: &Arc<C>, trap_id: &str, name: &str) -> Result<()> {
+        let group = &self.trap_groups[name];
+        let (group_oid, policer_oid, hostif_oid) =
+            (group.trap_group_oid, group.policer_oid, group.hostif_oid);
+        let trap_config = group.config.trap_config();
+        let key = CoppTrapKey::new(trap_id.to_string());
+
+        match self.traps.get_mut(&key) {
+            Some(trap) if trap.trap_group.as_deref() == Some(name) => return Ok(()),
+            Some(trap) => {
+                let previous = trap.trap_group.take();
+                if trap.hostif_table_entry_oid != 0 {
+                    callbacks.remove_hostif_table_entry(trap.hostif_table_entry_oid)?;
+                    trap.hostif_table_entry_oid = 0;
+                }
+                if let Some(previous) = previous.as_ref() {
+                    if let Some(old_group) = self.trap_groups.get_mut(previous) {
+                        old_group.trap_ids.remove(trap_id);
+                    }
+                }
+
+                callbacks.set_trap_group(trap.trap_oid, group_oid)?;
+                if trap.config.trap_action != trap_config.trap_action
+                    || trap.config.trap_priority != trap_config.trap_priority
+                {
+                    callbacks.update_trap_action(
+                        trap.trap_oid,
+                        trap_config.trap_action,
+                        trap_config.trap_priority,
+                    )?;
+                }
+                if hostif_oid != 0 {
+                    trap.hostif_table_entry_oid =
+                        callbacks.create_hostif_table_entry(trap.trap_oid, hostif_oid)?;
+                }
+                trap.config = trap_config;
+                trap.trap_group_oid = group_oid;
+                trap.policer_oid = policer_oid;
+                trap.trap_group = Some(name.to_string());
+
+                info_log!("CoppOrch", trap_id = %trap_id, from = ?previous, to = %name, "CoPP trap moved to new trap group");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ConfigurationChange,
+                    "CoppOrch",
+                    "move_trap"
+                )
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("0x{:x}", trap.trap_oid))
+                .with_object_type("copp_trap")
+                .with_details(serde_json::json!({
+                    "trap_key": trap_id,
+                    "from_group": previous,
+                    "to_group": name,
+                })));
+            }
+            None => {
+                let trap_oid = callbacks.create_trap(&key, &trap_config).inspect_err(|e| {
+                    error_log!("CoppOrch", trap_id = %trap_id, error = %e, "SAI create_trap failed");
+                })?;
+                let mut entry = CoppTrapEntry::new(key.clone(), trap_config);
+                entry.trap_oid = trap_oid;
+
+                let bound = callbacks.set_trap_group(trap_oid, group_oid).and_then(|_| {
+                    if hostif_oid != 0 {
+                        callbacks.create_hostif_table_entry(trap_oid, hostif_oid)
+                    } else {
+                        Ok(0)
+                    }
+                });
+                match bound {
+                    Ok(table_entry_oid) => entry.hostif_table_entry_oid = table_entry_oid,
+                    Err(e) => {
+                        error_log!("CoppOrch", trap_id = %trap_id, group = %name, error = %e, "Failed to bind trap to trap group");
+                        let _ = callbacks.remove_trap(trap_oid);
+                        return Err(e);
+                    }
+                }
+
+                entry.trap_group_oid = group_oid;
+                entry.policer_oid = policer_oid;
+                entry.trap_group = Some(name.to_string());
+                self.traps.insert(key.clone(), entry);
+                self.stats.stats.traps_created += 1;
+                callbacks.on_trap_created(&key, trap_oid);
+
+                info_log!("CoppOrch", trap_id = %trap_id, group = %name, oid = trap_oid, "CoPP trap created in trap group");
+            }
+        }
+
+        if let Some(group) = self.trap_groups.get_mut(name) {
+            group.trap_ids.insert(trap_id.to_string());
+        }
+        Ok(())
+    }
+
+    /// Removes a trap group. Groups that still have traps bound are rejected;
+    /// the group is only destroyed once its last trap has been removed.
+    pub fn remove_trap_group(&mut self, name: &str) -> Result<()> {
+        debug_log!("CoppOrch", group = %name, "Removing CoPP trap group");
+
+        let group = self.trap_groups.get_mut(name).ok_or_else(|| {
+            warn_log!("CoppOrch", group = %name, "Trap group not found for removal");
+            CoppOrchError::TrapGroupNotFound(name.to_string())
+        })?;
+
+        if group.ref_count() > 0 {
+            warn_log!("CoppOrch", group = %name, ref_count = group.ref_count(), "Trap group still in use");
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "CoppOrch",
+                "remove_trap_group"
+            )
+            .with_object_id(name)
+            .with_object_type("copp_trap_group")
+            .with_error(format!(
+                "Trap group still referenced by {:?}",
+                group.trap_ids
+            )));
+            return Err(CoppOrchError::TrapGroupInUse {
+                name: name.to_string(),
+                ref_count: group.ref_count(),
+            });
+        }
+
+        let callbacks = self.callbacks.as_ref().ok_or_else(|| {
+            error_log!("CoppOrch", "Callbacks not configured");
+            CoppOrchError::NotInitialized
+        })?;
+
+        if group.hostif_oid != 0 {
+            callbacks.remove_genetlink_hostif(group.hostif_oid)?;
+            group.hostif_oid = 0;
+            self.stats.stats.hostifs_created = self.stats.stats.hostifs_created.saturating_sub(1);
+        }
+
+        let group_oid = group.trap_group_oid;
+        callbacks.remove_trap_group(group_oid).inspect_err(|e| {
+            error_log!("CoppOrch", group = %name, oid = group_oid, error = %e, "SAI remove_trap_group failed");
+            audit_log!(
+                AuditRecord::new(AuditCategory::SaiOperation, "CoppOrch", "remove_trap_group")
+                    .with_object_id(format!("0x{:x}", group_oid))
+                    .with_object_type("copp_trap_group")
+                    .with_error(e.to_string())
+            );
+        })?;
+
+        if group.policer_oid != 0 {
+            callbacks.remove_policer(group.policer_oid)?;
+            self.stats.stats.policers_created = self.stats.stats.policers_created.saturating_sub(1);
+        }
+
+        self.trap_groups.remove(name);
+        self.stats.stats.trap_groups_created =
+            self.stats.stats.trap_groups_created.saturating_sub(1);
+
+        info_log!("CoppOrch", group = %name, oid = group_oid, "CoPP trap group removed successfully");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "CoppOrch",
+            "remove_trap_group"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("0x{:x}", group_oid))
+        .with_object_type("copp_trap_group")
+        .with_details(serde_json::json!({
+            "group": name
+        })));
+
+        Ok(())
+    }
+
+    pub fn get_trap_group(&self, name: &str) -> Option<&CoppTrapGroupEntry> {
+        self.trap_groups.get(name)
+    }
+
+    pub fn trap_group_count(&self) -> usize {
+        self.trap_groups.len()
+    }
+
     pub fn get_trap(&self, key: &CoppTrapKey) -> Option<&CoppTrapEntry> {
         self.traps.get(key)
     }
@@ -296,8 +902,9 @@ impl<C: CoppOrchCallbacks> CoppOrch<C> {
 
 #[cfg(test)]
 mod tests {
-    use super::super::types::CoppTrapAction;
     use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
 
     struct MockCoppCallbacks;
 
@@ -324,6 +931,244 @@ mod tests {
 
         fn on_trap_created(&self, _key: &CoppTrapKey, _trap_id: RawSaiObjectId) {}
         fn on_trap_removed(&self, _key: &CoppTrapKey) {}
+
+        fn create_trap_group(
+            &self,
+            _name: &str,
+            _config: &CoppTrapGroupConfig,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x2000)
+        }
+
+        fn update_trap_group(
+            &self,
+            _group_id: RawSaiObjectId,
+            _config: &CoppTrapGroupConfig,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn remove_trap_group(&self, _group_id: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_trap_group(
+            &self,
+            _trap_id: RawSaiObjectId,
+            _group_id: RawSaiObjectId,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn update_trap_action(
+            &self,
+            _trap_id: RawSaiObjectId,
+            _action: CoppTrapAction,
+            _priority: Option<u32>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_policer(&self, _config: &CoppPolicerConfig) -> Result<RawSaiObjectId> {
+            Ok(0x3000)
+        }
+
+        fn update_policer(
+            &self,
+            _policer_id: RawSaiObjectId,
+            _config: &CoppPolicerConfig,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn remove_policer(&self, _policer_id: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_trap_group_policer(
+            &self,
+            _group_id: RawSaiObjectId,
+            _policer_id: RawSaiObjectId,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_genetlink_hostif(
+            &self,
+            _name: &str,
+            _mcgrp_name: Option<&str>,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x4000)
+        }
+
+        fn remove_genetlink_hostif(&self, _hostif_id: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_hostif_table_entry(
+            &self,
+            _trap_id: RawSaiObjectId,
+            _hostif_id: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x5000)
+        }
+
+        fn remove_hostif_table_entry(&self, _entry_id: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Records SAI calls and hands out unique object IDs.
+    #[derive(Default)]
+    struct RecordingCoppCallbacks {
+        next_oid: AtomicU64,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl RecordingCoppCallbacks {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+
+        fn alloc(&self, call: String) -> Result<RawSaiObjectId> {
+            let oid = 0x100 + self.next_oid.fetch_add(1, Ordering::SeqCst);
+            self.record(format!("{} -> 0x{:x}", call, oid));
+            Ok(oid)
+        }
+
+        fn count(&self, prefix: &str) -> usize {
+            self.calls
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.starts_with(prefix))
+                .count()
+        }
+    }
+
+    impl CoppOrchCallbacks for RecordingCoppCallbacks {
+        fn create_trap(
+            &self,
+            key: &CoppTrapKey,
+            _config: &CoppTrapConfig,
+        ) -> Result<RawSaiObjectId> {
+            self.alloc(format!("create_trap {}", key.trap_id))
+        }
+
+        fn remove_trap(&self, trap_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_trap 0x{:x}", trap_id));
+            Ok(())
+        }
+
+        fn update_trap_rate(&self, _trap_id: RawSaiObjectId, _cir: u64, _cbs: u64) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_trap_stats(&self, _trap_id: RawSaiObjectId) -> Result<(u64, u64)> {
+            Ok((0, 0))
+        }
+
+        fn on_trap_created(&self, _key: &CoppTrapKey, _trap_id: RawSaiObjectId) {}
+        fn on_trap_removed(&self, _key: &CoppTrapKey) {}
+
+        fn create_trap_group(
+            &self,
+            name: &str,
+            _config: &CoppTrapGroupConfig,
+        ) -> Result<RawSaiObjectId> {
+            self.alloc(format!("create_trap_group {}", name))
+        }
+
+        fn update_trap_group(
+            &self,
+            group_id: RawSaiObjectId,
+            config: &CoppTrapGroupConfig,
+        ) -> Result<()> {
+            self.record(format!(
+                "update_trap_group 0x{:x} {:?}",
+                group_id, config.queue
+            ));
+            Ok(())
+        }
+
+        fn remove_trap_group(&self, group_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_trap_group 0x{:x}", group_id));
+            Ok(())
+        }
+
+        fn set_trap_group(&self, trap_id: RawSaiObjectId, group_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("set_trap_group 0x{:x} 0x{:x}", trap_id, group_id));
+            Ok(())
+        }
+
+        fn update_trap_action(
+            &self,
+            trap_id: RawSaiObjectId,
+            action: CoppTrapAction,
+            _priority: Option<u32>,
+        ) -> Result<()> {
+            self.record(format!("update_trap_action 0x{:x} {}", trap_id, action));
+            Ok(())
+        }
+
+        fn create_policer(&self, config: &CoppPolicerConfig) -> Result<RawSaiObjectId> {
+            self.alloc(format!("create_policer {}", config.cir))
+        }
+
+        fn update_policer(
+            &self,
+            policer_id: RawSaiObjectId,
+            config: &CoppPolicerConfig,
+        ) -> Result<()> {
+            self.record(format!(
+                "update_policer 0x{:x} {} {}",
+                policer_id, config.cir, config.cbs
+            ));
+            Ok(())
+        }
+
+        fn remove_policer(&self, policer_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_policer 0x{:x}", policer_id));
+            Ok(())
+        }
+
+        fn set_trap_group_policer(
+            &self,
+            group_id: RawSaiObjectId,
+            policer_id: RawSaiObjectId,
+        ) -> Result<()> {
+            self.record(format!(
+                "set_trap_group_policer 0x{:x} 0x{:x}",
+                group_id, policer_id
+            ));
+            Ok(())
+        }
+
+        fn create_genetlink_hostif(
+            &self,
+            name: &str,
+            _mcgrp_name: Option<&str>,
+        ) -> Result<RawSaiObjectId> {
+            self.alloc(format!("create_genetlink_hostif {}", name))
+        }
+
+        fn remove_genetlink_hostif(&self, hostif_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_genetlink_hostif 0x{:x}", hostif_id));
+            Ok(())
+        }
+
+        fn create_hostif_table_entry(
+            &self,
+            trap_id: RawSaiObjectId,
+            _hostif_id: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId> {
+            self.alloc(format!("create_hostif_table_entry 0x{:x}", trap_id))
+        }
+
+        fn remove_hostif_table_entry(&self, entry_id: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_hostif_table_entry 0x{:x}", entry_id));
+            Ok(())
+        }
     }
 
     fn create_test_config() -> CoppTrapConfig {
@@ -542,4 +1387,221 @@ mod tests {
         assert!(orch.remove_trap(&bgp_key).is_ok());
         assert_eq!(orch.trap_count(), 4);
     }
+
+    fn copp_task(group: &str, fields: &[(&str, &str)]) -> KeyOpFieldsValues {
+        KeyOpFieldsValues::set(
+            group,
+            fields
+                .iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    fn recording_orch() -> (
+        CoppOrch<RecordingCoppCallbacks>,
+        Arc<RecordingCoppCallbacks>,
+    ) {
+        let callbacks = Arc::new(RecordingCoppCallbacks::default());
+        let orch = CoppOrch::new(CoppOrchConfig::default()).with_callbacks(Arc::clone(&callbacks));
+        (orch, callbacks)
+    }
+
+    #[test]
+    fn test_trap_group_shared_by_traps() {
+        let (mut orch, callbacks) = recording_orch();
+        let task = copp_task(
+            "queue4_group1",
+            &[
+                ("trap_ids", "bgp,lldp,arp_req"),
+                ("trap_action", "trap"),
+                ("trap_priority", "4"),
+                ("queue", "4"),
+                ("meter_type", "packets"),
+                ("mode", "sr_tcm"),
+                ("cir", "600"),
+                ("cbs", "600"),
+            ],
+        );
+        assert!(orch.process_copp_task(&task).is_ok());
+
+        let group = orch.get_trap_group("queue4_group1").unwrap();
+        assert_eq!(group.ref_count(), 3);
+        assert_ne!(group.policer_oid, 0);
+        for trap_id in ["bgp", "lldp", "arp_req"] {
+            let trap = orch
+                .get_trap(&CoppTrapKey::new(trap_id.to_string()))
+                .unwrap();
+            assert_eq!(trap.trap_group_oid, group.trap_group_oid);
+            assert_eq!(trap.policer_oid, group.policer_oid);
+            assert_eq!(trap.trap_group.as_deref(), Some("queue4_group1"));
+        }
+        assert_eq!(callbacks.count("create_trap_group"), 1);
+        assert_eq!(callbacks.count("create_policer"), 1);
+        assert_eq!(callbacks.count("create_trap "), 3);
+        assert_eq!(orch.stats().stats.trap_groups_created, 1);
+        assert_eq!(orch.stats().stats.policers_created, 1);
+    }
+
+    #[test]
+    fn test_move_trap_between_groups() {
+        let (mut orch, callbacks) = recording_orch();
+        orch.process_copp_task(&copp_task(
+            "group_a",
+            &[("trap_ids", "bgp,lldp"), ("queue", "4")],
+        ))
+        .unwrap();
+        orch.process_copp_task(&copp_task(
+            "group_b",
+            &[("trap_ids", "arp_req"), ("queue", "3")],
+        ))
+        .unwrap();
+        let bgp_oid = orch
+            .get_trap(&CoppTrapKey::new("bgp".to_string()))
+            .unwrap()
+            .trap_oid;
+
+        orch.process_copp_task(&copp_task(
+            "group_b",
+            &[("trap_ids", "arp_req,bgp"), ("queue", "3")],
+        ))
+        .unwrap();
+
+        let group_b_oid = orch.get_trap_group("group_b").unwrap().trap_group_oid;
+        let bgp = orch.get_trap(&CoppTrapKey::new("bgp".to_string())).unwrap();
+        assert_eq!(bgp.trap_oid, bgp_oid);
+        assert_eq!(bgp.trap_group_oid, group_b_oid);
+        assert_eq!(bgp.trap_group.as_deref(), Some("group_b"));
+        assert_eq!(orch.get_trap_group("group_a").unwrap().ref_count(), 1);
+        assert_eq!(orch.get_trap_group("group_b").unwrap().ref_count(), 2);
+        assert_eq!(callbacks.count("remove_trap "), 0);
+
+        // Deleting group_a only removes the traps it still owns
+        orch.process_copp_task(&KeyOpFieldsValues::del("group_a"))
+            .unwrap();
+        assert!(orch.get_trap_group("group_a").is_none());
+        assert!(!orch.trap_exists(&CoppTrapKey::new("lldp".to_string())));
+        assert!(orch.trap_exists(&CoppTrapKey::new("bgp".to_string())));
+        assert_eq!(orch.trap_count(), 2);
+    }
+
+    #[test]
+    fn test_remove_trap_group_in_use_rejected() {
+        let (mut orch, callbacks) = recording_orch();
+        orch.process_copp_task(&copp_task(
+            "queue4_group1",
+            &[("trap_ids", "bgp,lldp"), ("cir", "600"), ("cbs", "600")],
+        ))
+        .unwrap();
+
+        let result = orch.remove_trap_group("queue4_group1");
+        assert!(matches!(
+            result,
+            Err(CoppOrchError::TrapGroupInUse { ref_count: 2, .. })
+        ));
+        assert!(orch.get_trap_group("queue4_group1").is_some());
+        assert_eq!(callbacks.count("remove_trap_group"), 0);
+
+        orch.remove_trap(&CoppTrapKey::new("bgp".to_string()))
+            .unwrap();
+        assert!(orch.remove_trap_group("queue4_group1").is_err());
+        orch.remove_trap(&CoppTrapKey::new("lldp".to_string()))
+            .unwrap();
+
+        assert!(orch.remove_trap_group("queue4_group1").is_ok());
+        assert_eq!(orch.trap_group_count(), 0);
+        assert_eq!(callbacks.count("remove_trap_group"), 1);
+        assert_eq!(callbacks.count("remove_policer"), 1);
+        assert_eq!(orch.stats().stats.policers_created, 0);
+    }
+
+    #[test]
+    fn test_policer_rate_change_updates_in_place() {
+        let (mut orch, callbacks) = recording_orch();
+        let fields = |cir: &'static str, mode: &'static str| {
+            copp_task(
+                "queue4_group1",
+                &[
+                    ("trap_ids", "bgp"),
+                    ("meter_type", "packets"),
+                    ("mode", mode),
+                    ("cir", cir),
+                    ("cbs", cir),
+                ],
+            )
+        };
+        orch.process_copp_task(&fields("600", "sr_tcm")).unwrap();
+        let policer_oid = orch.get_trap_group("queue4_group1").unwrap().policer_oid;
+
+        orch.process_copp_task(&fields("1000", "sr_tcm")).unwrap();
+        assert_eq!(callbacks.count("update_policer"), 1);
+        assert_eq!(callbacks.count("create_policer"), 1);
+        let group = orch.get_trap_group("queue4_group1").unwrap();
+        assert_eq!(group.policer_oid, policer_oid);
+        assert_eq!(group.config.policer.as_ref().unwrap().cir, 1000);
+        let bgp = orch.get_trap(&CoppTrapKey::new("bgp".to_string())).unwrap();
+        assert_eq!(bgp.config.cir, Some(1000));
+
+        // Mode is create-only, so the policer is replaced
+        orch.process_copp_task(&fields("1000", "tr_tcm")).unwrap();
+        assert_eq!(callbacks.count("create_policer"), 2);
+        assert_eq!(callbacks.count("remove_policer"), 1);
+        let new_oid = orch.get_trap_group("queue4_group1").unwrap().policer_oid;
+        assert_ne!(new_oid, policer_oid);
+        let bgp = orch.get_trap(&CoppTrapKey::new("bgp".to_string())).unwrap();
+        assert_eq!(bgp.policer_oid, new_oid);
+        assert_eq!(orch.stats().stats.policers_created, 1);
+    }
+
+    #[test]
+    fn test_genetlink_hostif_for_sflow_group() {
+        let (mut orch, callbacks) = recording_orch();
+        orch.process_copp_task(&copp_task(
+            "queue2_group1",
+            &[
+                ("trap_ids", "sample_packet"),
+                ("trap_action", "trap"),
+                ("genetlink_name", "psample"),
+                ("genetlink_mcgrp_name", "packets"),
+            ],
+        ))
+        .unwrap();
+
+        let group = orch.get_trap_group("queue2_group1").unwrap();
+        assert_ne!(group.hostif_oid, 0);
+        let trap = orch
+            .get_trap(&CoppTrapKey::new("sample_packet".to_string()))
+            .unwrap();
+        assert_ne!(trap.hostif_table_entry_oid, 0);
+        assert_eq!(orch.stats().stats.hostifs_created, 1);
+
+        orch.process_copp_task(&KeyOpFieldsValues::del("queue2_group1"))
+            .unwrap();
+        assert_eq!(callbacks.count("remove_hostif_table_entry"), 1);
+        assert_eq!(callbacks.count("remove_genetlink_hostif"), 1);
+        assert_eq!(orch.stats().stats.hostifs_created, 0);
+        assert_eq!(orch.trap_count(), 0);
+    }
+
+    #[test]
+    fn test_copp_task_invalid_config() {
+        let (mut orch, _callbacks) = recording_orch();
+        let missing_cbs = copp_task("g", &[("trap_ids", "bgp"), ("cir", "600")]);
+        assert!(matches!(
+            orch.process_copp_task(&missing_cbs),
+            Err(CoppOrchError::InvalidConfig(_))
+        ));
+        let bad_action = copp_task("g", &[("trap_ids", "bgp"), ("trap_action", "bounce")]);
+        assert!(matches!(
+            orch.process_copp_task(&bad_action),
+            Err(CoppOrchError::InvalidConfig(_))
+        ));
+        let bad_queue = copp_task("g", &[("trap_ids", "bgp"), ("queue", "9")]);
+        assert!(matches!(
+            orch.process_copp_task(&bad_queue),
+            Err(CoppOrchError::InvalidQueue(9))
+        ));
+        assert_eq!(orch.trap_group_count(), 0);
+        assert_eq!(orch.trap_count(), 0);
+    }
 }
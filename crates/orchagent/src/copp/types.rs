@@ -1,5 +1,9 @@
 //! CoPP (Control Plane Policing) types.
 
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
 pub type RawSaiObjectId = u64;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -23,6 +27,35 @@ pub enum CoppTrapAction {
     Log,
 }
 
+impl fmt::Display for CoppTrapAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Drop => write!(f, "drop"),
+            Self::Forward => write!(f, "forward"),
+            Self::Copy => write!(f, "copy"),
+            Self::CopyCancel => write!(f, "copy_cancel"),
+            Self::Trap => write!(f, "trap"),
+            Self::Log => write!(f, "log"),
+        }
+    }
+}
+
+impl FromStr for CoppTrapAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "drop" => Ok(Self::Drop),
+            "forward" => Ok(Self::Forward),
+            "copy" => Ok(Self::Copy),
+            "copy_cancel" => Ok(Self::CopyCancel),
+            "trap" => Ok(Self::Trap),
+            "log" => Ok(Self::Log),
+            _ => Err(format!("Unknown CoPP trap action: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CoppTrapConfig {
     pub trap_action: CoppTrapAction,
@@ -44,6 +77,10 @@ pub struct CoppTrapEntry {
     pub trap_oid: RawSaiObjectId,
     pub trap_group_oid: RawSaiObjectId,
     pub policer_oid: RawSaiObjectId,
+    /// Name of the trap group this trap is bound to, if any.
+    pub trap_group: Option<String>,
+    /// Host interface table entry steering this trap to a genetlink channel.
+    pub hostif_table_entry_oid: RawSaiObjectId,
 }
 
 impl CoppTrapEntry {
@@ -54,8 +91,101 @@ impl CoppTrapEntry {
             trap_oid: 0,
             trap_group_oid: 0,
             policer_oid: 0,
+            trap_group: None,
+            hostif_table_entry_oid: 0,
+        }
+    }
+}
+
+/// Policer attached to a trap group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoppPolicerConfig {
+    pub meter_type: Option<String>,
+    pub mode: Option<String>,
+    pub color: Option<String>,
+    pub cir: u64,
+    pub cbs: u64,
+    pub pir: Option<u64>,
+    pub pbs: Option<u64>,
+}
+
+impl CoppPolicerConfig {
+    /// Returns true if `other` differs only in rates, which SAI allows to be
+    /// changed on an existing policer. Meter type, mode and color are
+    /// create-only and require a new policer.
+    pub fn rates_only_differ(&self, other: &CoppPolicerConfig) -> bool {
+        self.meter_type == other.meter_type && self.mode == other.mode && self.color == other.color
+    }
+}
+
+/// Trap group configuration from APPL_DB COPP_TABLE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoppTrapGroupConfig {
+    pub trap_action: CoppTrapAction,
+    pub trap_priority: Option<u32>,
+    pub queue: Option<u8>,
+    pub policer: Option<CoppPolicerConfig>,
+    pub genetlink_name: Option<String>,
+    pub genetlink_mcgrp_name: Option<String>,
+}
+
+impl CoppTrapGroupConfig {
+    pub fn new(trap_action: CoppTrapAction) -> Self {
+        Self {
+            trap_action,
+            trap_priority: None,
+            queue: None,
+            policer: None,
+            genetlink_name: None,
+            genetlink_mcgrp_name: None,
+        }
+    }
+
+    /// Per-trap configuration derived from the group.
+    pub fn trap_config(&self) -> CoppTrapConfig {
+        let policer = self.policer.as_ref();
+        CoppTrapConfig {
+            trap_action: self.trap_action,
+            trap_priority: self.trap_priority,
+            queue: self.queue,
+            meter_type: policer.and_then(|p| p.meter_type.clone()),
+            mode: policer.and_then(|p| p.mode.clone()),
+            color: policer.and_then(|p| p.color.clone()),
+            cbs: policer.map(|p| p.cbs),
+            cir: policer.map(|p| p.cir),
+            pbs: policer.and_then(|p| p.pbs),
+            pir: policer.and_then(|p| p.pir),
+        }
+    }
+}
+
+/// A trap group and the traps currently bound to it.
+#[derive(Debug, Clone)]
+pub struct CoppTrapGroupEntry {
+    pub name: String,
+    pub config: CoppTrapGroupConfig,
+    pub trap_group_oid: RawSaiObjectId,
+    pub policer_oid: RawSaiObjectId,
+    pub hostif_oid: RawSaiObjectId,
+    pub trap_ids: BTreeSet<String>,
+}
+
+impl CoppTrapGroupEntry {
+    pub fn new(name: String, config: CoppTrapGroupConfig) -> Self {
+        Self {
+            name,
+            config,
+            trap_group_oid: 0,
+            policer_oid: 0,
+            hostif_oid: 0,
+            trap_ids: BTreeSet::new(),
         }
     }
+
+    /// Number of traps referencing this group.
+    pub fn ref_count(&self) -> usize {
+        self.trap_ids.len()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -63,4 +193,5 @@ pub struct CoppStats {
     pub traps_created: u64,
     pub trap_groups_created: u64,
     pub policers_created: u64,
+    pub hostifs_created: u64,
 }
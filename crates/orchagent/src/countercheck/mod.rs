@@ -6,6 +6,7 @@
 //! - Composite key (port_name + counter_type)
 //! - Tolerance-based validation method
 //! - Safe arithmetic for difference calculation
+//! - Wraparound-safe counter deltas for PFC storm and stuck queue detection
 
 mod ffi;
 mod orch;
@@ -16,4 +17,8 @@ pub use orch::{
     CounterCheckOrch, CounterCheckOrchCallbacks, CounterCheckOrchConfig, CounterCheckOrchError,
     CounterCheckOrchStats,
 };
-pub use types::{CounterCheckConfig, CounterCheckEntry, CounterCheckKey, CounterCheckStats};
+pub use types::{
+    counter_delta, CounterCheckCondition, CounterCheckConfig, CounterCheckEntry, CounterCheckKey,
+    CounterCheckStats, DetectionState, MonitoredPort, QueueCheckState, QueueCounterSample,
+    PFC_PRIORITY_COUNT,
+};
@@ -1,33 +1,86 @@
 //! Counter check orchestration logic.
 
-use super::types::{CounterCheckEntry, CounterCheckKey, CounterCheckStats};
+use super::types::{
+    counter_delta, CounterCheckCondition, CounterCheckEntry, CounterCheckKey, CounterCheckStats,
+    MonitoredPort, QueueCounterSample, PFC_PRIORITY_COUNT,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
+/// Default interval between counter polls.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default number of consecutive intervals needed to raise or clear a condition.
+const DEFAULT_DETECTION_WINDOW: u32 = 3;
+
 #[derive(Debug, Clone, Error)]
 pub enum CounterCheckOrchError {
     #[error("Check not found: {0:?}")]
     CheckNotFound(CounterCheckKey),
     #[error("Port not found: {0}")]
     PortNotFound(String),
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct CounterCheckOrchConfig {}
+#[derive(Debug, Clone)]
+pub struct CounterCheckOrchConfig {
+    /// Interval between PFC and queue counter polls; zero disables polling.
+    pub poll_interval: Duration,
+    /// Consecutive intervals needed to raise or clear a condition.
+    pub detection_window: u32,
+}
+
+impl Default for CounterCheckOrchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            detection_window: DEFAULT_DETECTION_WINDOW,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct CounterCheckOrchStats {
     pub stats: CounterCheckStats,
+    pub polls: u64,
+    pub pfc_storms_detected: u64,
+    pub pfc_storms_cleared: u64,
+    pub stuck_queues_detected: u64,
+    pub stuck_queues_cleared: u64,
 }
 
-pub trait CounterCheckOrchCallbacks: Send + Sync {}
+pub trait CounterCheckOrchCallbacks: Send + Sync {
+    /// Reads the cumulative PFC pause frames received per priority.
+    fn read_pfc_rx_counters(&self, port: &str) -> Option<[u64; PFC_PRIORITY_COUNT as usize]>;
+    /// Reads the occupancy and transmit counters of a queue.
+    fn read_queue_counters(&self, port: &str, queue: u8) -> Option<QueueCounterSample>;
+    /// Sets or clears a condition flag in STATE_DB for PfcWdOrch to consume.
+    fn write_state_flag(
+        &self,
+        port: &str,
+        queue: u8,
+        condition: CounterCheckCondition,
+        active: bool,
+    );
+    /// Removes all STATE_DB flags of a queue.
+    fn remove_state_flags(&self, port: &str, queue: u8);
+    /// Raises an alert when a condition is detected or cleared.
+    fn raise_alert(&self, port: &str, queue: u8, condition: CounterCheckCondition, active: bool);
+}
 
 pub struct CounterCheckOrch {
     config: CounterCheckOrchConfig,
     stats: CounterCheckOrchStats,
+    callbacks: Option<Arc<dyn CounterCheckOrchCallbacks>>,
     checks: HashMap<CounterCheckKey, CounterCheckEntry>,
+    monitored_ports: HashMap<String, MonitoredPort>,
+    next_poll: Option<Instant>,
+    timer_changed: bool,
 }
 
 impl CounterCheckOrch {
@@ -35,10 +88,263 @@ impl CounterCheckOrch {
         Self {
             config,
             stats: CounterCheckOrchStats::default(),
+            callbacks: None,
             checks: HashMap::new(),
+            monitored_ports: HashMap::new(),
+            next_poll: None,
+            timer_changed: false,
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn CounterCheckOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+
+    pub fn detection_window(&self) -> u32 {
+        self.config.detection_window
+    }
+
+    /// Changes the poll interval; the timer is re-armed on the next `poll_timer()`.
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        if interval != self.config.poll_interval {
+            self.config.poll_interval = interval;
+            self.timer_changed = true;
+        }
+    }
+
+    /// Changes the detection window.
+    ///
+    /// Counts already accumulated are kept, so shrinking the window can raise
+    /// or clear a condition on the very next poll.
+    pub fn set_detection_window(&mut self, window: u32) {
+        self.config.detection_window = window;
+    }
+
+    /// Applies one field of the counter check config table.
+    ///
+    /// Recognizes `poll_interval` (milliseconds) and `detection_window`
+    /// (intervals); other fields are ignored.
+    pub fn update_config(&mut self, field: &str, value: &str) -> Result<(), CounterCheckOrchError> {
+        let parse = |value: &str| {
+            value.parse::<u64>().map_err(|e| {
+                CounterCheckOrchError::InvalidConfig(format!("{} '{}': {}", field, value, e))
+            })
+        };
+
+        match field {
+            "poll_interval" => self.set_poll_interval(Duration::from_millis(parse(value)?)),
+            "detection_window" => {
+                let window = parse(value)?;
+                if window == 0 || window > u64::from(u32::MAX) {
+                    return Err(CounterCheckOrchError::InvalidConfig(format!(
+                        "detection_window '{}' out of range",
+                        value
+                    )));
+                }
+                self.set_detection_window(window as u32);
+            }
+            _ => return Ok(()),
+        }
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "CounterCheckOrch",
+            "update_config"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(field)
+        .with_object_type("counter_check_config")
+        .with_details(serde_json::json!({
+            "field": field,
+            "value": value,
+            "poll_interval_ms": self.config.poll_interval.as_millis() as u64,
+            "detection_window": self.config.detection_window,
+        })));
+        Ok(())
+    }
+
+    /// Starts checking the lossless priorities in `pfc_mask` on a port.
+    ///
+    /// Re-monitoring a port with a new mask restarts detection for it.
+    pub fn monitor_port(&mut self, port: &str, pfc_mask: u8) {
+        self.unmonitor_port(port);
+        if pfc_mask != 0 {
+            self.monitored_ports
+                .insert(port.to_string(), MonitoredPort::new(pfc_mask));
+        }
+    }
+
+    /// Stops checking a port and clears its STATE_DB flags.
+    pub fn unmonitor_port(&mut self, port: &str) {
+        let Some(monitored) = self.monitored_ports.remove(port) else {
+            return;
+        };
+        if let Some(callbacks) = &self.callbacks {
+            for queue in monitored.queues.keys() {
+                callbacks.remove_state_flags(port, *queue);
+            }
+        }
+    }
+
+    pub fn is_port_monitored(&self, port: &str) -> bool {
+        self.monitored_ports.contains_key(port)
+    }
+
+    /// Returns true if `condition` is currently raised on a queue.
+    pub fn is_condition_active(
+        &self,
+        port: &str,
+        queue: u8,
+        condition: CounterCheckCondition,
+    ) -> bool {
+        self.monitored_ports
+            .get(port)
+            .and_then(|monitored| monitored.queues.get(&queue))
+            .map(|state| match condition {
+                CounterCheckCondition::PfcStorm => state.pfc_storm.active,
+                CounterCheckCondition::StuckQueue => state.stuck_queue.active,
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the deadline of the next poll, if armed.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.next_poll
+    }
+
+    /// Drives the periodic counter poll.
+    ///
+    /// Arms the timer once a port is monitored, re-arms it from `now` after
+    /// an interval change and polls once the deadline has passed. A zero
+    /// interval or no monitored port disarms the timer. Returns true if a
+    /// poll happened.
+    pub fn poll_timer(&mut self, now: Instant) -> bool {
+        let interval = self.config.poll_interval;
+        if interval.is_zero() || self.monitored_ports.is_empty() {
+            self.next_poll = None;
+            self.timer_changed = false;
+            return false;
+        }
+
+        match self.next_poll {
+            Some(deadline) if !self.timer_changed => {
+                if now < deadline {
+                    return false;
+                }
+                self.poll_counters();
+                self.next_poll = Some(now + interval);
+                true
+            }
+            _ => {
+                self.timer_changed = false;
+                self.next_poll = Some(now + interval);
+                false
+            }
+        }
+    }
+
+    /// Reads PFC and queue counters of every monitored port and updates detection.
+    ///
+    /// The first sample of a queue only sets the baseline. Ports whose
+    /// counters cannot be read yet are skipped without touching their state.
+    pub fn poll_counters(&mut self) {
+        let Some(callbacks) = self.callbacks.clone() else {
+            return;
+        };
+        let window = self.config.detection_window;
+        self.stats.polls += 1;
+
+        let mut transitions = Vec::new();
+        for (port, monitored) in self.monitored_ports.iter_mut() {
+            let pfc_rx = callbacks.read_pfc_rx_counters(port);
+
+            for (&queue, state) in monitored.queues.iter_mut() {
+                if let Some(counters) = pfc_rx {
+                    let current = counters[queue as usize];
+                    if let Some(previous) = state.last_pfc_rx.replace(current) {
+                        let hit = counter_delta(previous, current) > 0;
+                        if let Some(active) = state.pfc_storm.update(hit, window) {
+                            transitions.push((
+                                port.clone(),
+                                queue,
+                                CounterCheckCondition::PfcStorm,
+                                active,
+                            ));
+                        }
+                    }
+                }
+
+                if let Some(sample) = callbacks.read_queue_counters(port, queue) {
+                    if let Some(previous) = state.last_queue.replace(sample) {
+                        let tx_flat = counter_delta(previous.tx_packets, sample.tx_packets) == 0;
+                        let holding = sample.occupancy_bytes > 0
+                            && sample.occupancy_bytes >= previous.occupancy_bytes;
+                        if let Some(active) = state.stuck_queue.update(tx_flat && holding, window) {
+                            transitions.push((
+                                port.clone(),
+                                queue,
+                                CounterCheckCondition::StuckQueue,
+                                active,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (port, queue, condition, active) in transitions {
+            self.report_transition(callbacks.as_ref(), &port, queue, condition, active);
+        }
+    }
+
+    fn report_transition(
+        &mut self,
+        callbacks: &dyn CounterCheckOrchCallbacks,
+        port: &str,
+        queue: u8,
+        condition: CounterCheckCondition,
+        active: bool,
+    ) {
+        match (condition, active) {
+            (CounterCheckCondition::PfcStorm, true) => self.stats.pfc_storms_detected += 1,
+            (CounterCheckCondition::PfcStorm, false) => self.stats.pfc_storms_cleared += 1,
+            (CounterCheckCondition::StuckQueue, true) => self.stats.stuck_queues_detected += 1,
+            (CounterCheckCondition::StuckQueue, false) => self.stats.stuck_queues_cleared += 1,
+        }
+
+        callbacks.write_state_flag(port, queue, condition, active);
+        callbacks.raise_alert(port, queue, condition, active);
+
+        let outcome = if active {
+            AuditOutcome::Failure
+        } else {
+            AuditOutcome::Success
+        };
+        audit_log!(AuditRecord::new(
+            AuditCategory::SecurityPolicy,
+            "CounterCheckOrch",
+            if active {
+                "condition_detected"
+            } else {
+                "condition_cleared"
+            }
+        )
+        .with_outcome(outcome)
+        .with_object_id(format!("{}:{}", port, queue))
+        .with_object_type("counter_check_queue")
+        .with_details(serde_json::json!({
+            "port_name": port,
+            "queue": queue,
+            "condition": condition.as_str(),
+            "active": active,
+            "detection_window": self.config.detection_window,
+        })));
+    }
+
     pub fn get_check(&self, key: &CounterCheckKey) -> Option<&CounterCheckEntry> {
         self.checks.get(key)
     }
@@ -152,7 +458,10 @@ impl CounterCheckOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::countercheck::types::{CounterCheckConfig, CounterCheckEntry, CounterCheckKey};
+    use crate::countercheck::types::{
+        CounterCheckConfig, CounterCheckEntry, CounterCheckKey, DetectionState,
+    };
+    use std::sync::Mutex;
 
     // Helper function to create a test config
     fn create_test_config(
@@ -191,10 +500,15 @@ mod tests {
 
     #[test]
     fn test_new_countercheck_orch_with_custom_config() {
-        let config = CounterCheckOrchConfig {};
+        let config = CounterCheckOrchConfig {
+            poll_interval: Duration::from_millis(500),
+            detection_window: 5,
+        };
         let orch = CounterCheckOrch::new(config);
 
         assert!(orch.checks.is_empty());
+        assert_eq!(orch.poll_interval(), Duration::from_millis(500));
+        assert_eq!(orch.detection_window(), 5);
         assert_eq!(orch.stats().stats.checks_performed, 0);
     }
 
@@ -576,4 +890,290 @@ mod tests {
 
         assert_eq!(entry.key.counter_type, "CUSTOM_COUNTER_TYPE");
     }
+
+    // ============================================================================
+    // PFC Storm and Stuck Queue Detection Tests
+    // ============================================================================
+
+    type Transition = (String, u8, CounterCheckCondition, bool);
+
+    #[derive(Default)]
+    struct FakeCounters {
+        pfc_rx: Mutex<HashMap<String, [u64; 8]>>,
+        queues: Mutex<HashMap<(String, u8), QueueCounterSample>>,
+        flags: Mutex<Vec<Transition>>,
+        alerts: Mutex<Vec<Transition>>,
+        removed: Mutex<Vec<(String, u8)>>,
+    }
+
+    impl FakeCounters {
+        fn set_pfc_rx(&self, port: &str, priority: u8, value: u64) {
+            let mut pfc_rx = self.pfc_rx.lock().unwrap();
+            pfc_rx.entry(port.to_string()).or_insert([0; 8])[priority as usize] = value;
+        }
+
+        fn set_queue(&self, port: &str, queue: u8, occupancy_bytes: u64, tx_packets: u64) {
+            self.queues.lock().unwrap().insert(
+                (port.to_string(), queue),
+                QueueCounterSample {
+                    occupancy_bytes,
+                    tx_packets,
+                },
+            );
+        }
+    }
+
+    impl CounterCheckOrchCallbacks for FakeCounters {
+        fn read_pfc_rx_counters(&self, port: &str) -> Option<[u64; 8]> {
+            self.pfc_rx.lock().unwrap().get(port).copied()
+        }
+        fn read_queue_counters(&self, port: &str, queue: u8) -> Option<QueueCounterSample> {
+            self.queues
+                .lock()
+                .unwrap()
+                .get(&(port.to_string(), queue))
+                .copied()
+        }
+        fn write_state_flag(
+            &self,
+            port: &str,
+            queue: u8,
+            condition: CounterCheckCondition,
+            active: bool,
+        ) {
+            self.flags
+                .lock()
+                .unwrap()
+                .push((port.to_string(), queue, condition, active));
+        }
+        fn remove_state_flags(&self, port: &str, queue: u8) {
+            self.removed.lock().unwrap().push((port.to_string(), queue));
+        }
+        fn raise_alert(
+            &self,
+            port: &str,
+            queue: u8,
+            condition: CounterCheckCondition,
+            active: bool,
+        ) {
+            self.alerts
+                .lock()
+                .unwrap()
+                .push((port.to_string(), queue, condition, active));
+        }
+    }
+
+    fn monitored_orch(window: u32) -> (CounterCheckOrch, Arc<FakeCounters>) {
+        let counters = Arc::new(FakeCounters::default());
+        let mut orch = CounterCheckOrch::new(CounterCheckOrchConfig {
+            poll_interval: Duration::from_secs(1),
+            detection_window: window,
+        });
+        orch.set_callbacks(counters.clone());
+        // Priorities 3 and 4 are lossless
+        orch.monitor_port("Ethernet0", 0b0001_1000);
+        (orch, counters)
+    }
+
+    fn transition(queue: u8, condition: CounterCheckCondition, active: bool) -> Transition {
+        ("Ethernet0".to_string(), queue, condition, active)
+    }
+
+    #[test]
+    fn test_detection_state_window() {
+        let mut state = DetectionState::default();
+        assert_eq!(state.update(true, 2), None);
+        assert_eq!(state.update(false, 2), None);
+        assert_eq!(state.update(true, 2), None);
+        assert_eq!(state.update(true, 2), Some(true));
+        assert_eq!(state.update(false, 2), None);
+        assert_eq!(state.update(false, 2), Some(false));
+    }
+
+    #[test]
+    fn test_counter_delta_wraparound() {
+        assert_eq!(counter_delta(10, 15), 5);
+        assert_eq!(counter_delta(u64::MAX - 1, 3), 5);
+        assert_eq!(counter_delta(7, 7), 0);
+    }
+
+    #[test]
+    fn test_pfc_storm_detection_and_recovery() {
+        let (mut orch, counters) = monitored_orch(3);
+
+        // Baseline plus three intervals of incoming pause frames
+        for value in [0, 100, 200, 300] {
+            counters.set_pfc_rx("Ethernet0", 3, value);
+            orch.poll_counters();
+        }
+        assert!(orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+        assert!(!orch.is_condition_active("Ethernet0", 4, CounterCheckCondition::PfcStorm));
+        assert_eq!(
+            *counters.flags.lock().unwrap(),
+            vec![transition(3, CounterCheckCondition::PfcStorm, true)]
+        );
+
+        // Pause frames stop; the storm clears after a full quiet window
+        for _ in 0..2 {
+            orch.poll_counters();
+        }
+        assert!(orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+        orch.poll_counters();
+        assert!(!orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+        assert_eq!(
+            counters.alerts.lock().unwrap().last(),
+            Some(&transition(3, CounterCheckCondition::PfcStorm, false))
+        );
+        assert_eq!(orch.stats().pfc_storms_detected, 1);
+        assert_eq!(orch.stats().pfc_storms_cleared, 1);
+    }
+
+    #[test]
+    fn test_intermittent_pause_frames_are_not_a_storm() {
+        let (mut orch, counters) = monitored_orch(3);
+
+        for value in [0, 10, 20, 20, 30, 40, 40] {
+            counters.set_pfc_rx("Ethernet0", 4, value);
+            orch.poll_counters();
+        }
+        assert!(!orch.is_condition_active("Ethernet0", 4, CounterCheckCondition::PfcStorm));
+        assert!(counters.alerts.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stuck_queue_detection_and_recovery() {
+        let (mut orch, counters) = monitored_orch(2);
+
+        // Occupancy grows, then stays full, while tx is flat
+        for occupancy in [0, 1000, 4000, 4000] {
+            counters.set_queue("Ethernet0", 4, occupancy, 500);
+            orch.poll_counters();
+        }
+        assert!(orch.is_condition_active("Ethernet0", 4, CounterCheckCondition::StuckQueue));
+        assert_eq!(orch.stats().stuck_queues_detected, 1);
+
+        // Queue drains
+        counters.set_queue("Ethernet0", 4, 1000, 900);
+        orch.poll_counters();
+        counters.set_queue("Ethernet0", 4, 0, 1200);
+        orch.poll_counters();
+        assert!(!orch.is_condition_active("Ethernet0", 4, CounterCheckCondition::StuckQueue));
+        assert_eq!(
+            *counters.flags.lock().unwrap(),
+            vec![
+                transition(4, CounterCheckCondition::StuckQueue, true),
+                transition(4, CounterCheckCondition::StuckQueue, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_idle_queue_is_not_stuck() {
+        let (mut orch, counters) = monitored_orch(2);
+
+        counters.set_queue("Ethernet0", 3, 0, 100);
+        for _ in 0..5 {
+            orch.poll_counters();
+        }
+        assert!(!orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::StuckQueue));
+    }
+
+    #[test]
+    fn test_counter_wraparound_is_progress() {
+        let (mut orch, counters) = monitored_orch(2);
+
+        // tx wraps past u64::MAX while the queue fills: still transmitting
+        for tx in [u64::MAX - 2, u64::MAX, 1, 3] {
+            counters.set_queue("Ethernet0", 3, 2000, tx);
+            orch.poll_counters();
+        }
+        assert!(!orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::StuckQueue));
+
+        // PFC rx wrapping still counts as pause frames arriving
+        for value in [u64::MAX - 1, 5, 9] {
+            counters.set_pfc_rx("Ethernet0", 3, value);
+            orch.poll_counters();
+        }
+        assert!(orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+    }
+
+    #[test]
+    fn test_unreadable_counters_keep_state() {
+        let (mut orch, counters) = monitored_orch(2);
+
+        orch.poll_counters();
+        assert!(counters.flags.lock().unwrap().is_empty());
+
+        counters.set_pfc_rx("Ethernet0", 3, 0);
+        orch.poll_counters();
+        counters.set_pfc_rx("Ethernet0", 3, 10);
+        orch.poll_counters();
+        counters.pfc_rx.lock().unwrap().clear();
+        orch.poll_counters();
+        counters.set_pfc_rx("Ethernet0", 3, 20);
+        orch.poll_counters();
+        assert!(orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+    }
+
+    #[test]
+    fn test_unmonitor_port_removes_flags() {
+        let (mut orch, counters) = monitored_orch(2);
+        assert!(orch.is_port_monitored("Ethernet0"));
+
+        orch.unmonitor_port("Ethernet0");
+        assert!(!orch.is_port_monitored("Ethernet0"));
+        assert_eq!(
+            *counters.removed.lock().unwrap(),
+            vec![("Ethernet0".to_string(), 3), ("Ethernet0".to_string(), 4)]
+        );
+    }
+
+    #[test]
+    fn test_poll_timer_applies_interval_change_live() {
+        let (mut orch, _counters) = monitored_orch(2);
+        let start = Instant::now();
+
+        assert!(!orch.poll_timer(start));
+        assert_eq!(orch.next_deadline(), Some(start + Duration::from_secs(1)));
+        assert!(!orch.poll_timer(start + Duration::from_millis(500)));
+        assert!(orch.poll_timer(start + Duration::from_secs(1)));
+        assert_eq!(orch.stats().polls, 1);
+
+        orch.update_config("poll_interval", "200").unwrap();
+        let now = start + Duration::from_millis(1100);
+        assert!(!orch.poll_timer(now));
+        assert_eq!(orch.next_deadline(), Some(now + Duration::from_millis(200)));
+        assert!(orch.poll_timer(now + Duration::from_millis(200)));
+
+        orch.update_config("poll_interval", "0").unwrap();
+        assert!(!orch.poll_timer(now + Duration::from_secs(5)));
+        assert_eq!(orch.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_detection_window_change_applies_live() {
+        let (mut orch, counters) = monitored_orch(5);
+
+        for value in [0, 10, 20] {
+            counters.set_pfc_rx("Ethernet0", 3, value);
+            orch.poll_counters();
+        }
+        assert!(!orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+
+        orch.update_config("detection_window", "3").unwrap();
+        assert_eq!(orch.detection_window(), 3);
+        counters.set_pfc_rx("Ethernet0", 3, 30);
+        orch.poll_counters();
+        assert!(orch.is_condition_active("Ethernet0", 3, CounterCheckCondition::PfcStorm));
+
+        assert!(matches!(
+            orch.update_config("detection_window", "0"),
+            Err(CounterCheckOrchError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            orch.update_config("poll_interval", "fast"),
+            Err(CounterCheckOrchError::InvalidConfig(_))
+        ));
+        assert!(orch.update_config("unknown", "1").is_ok());
+    }
 }
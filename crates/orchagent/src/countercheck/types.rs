@@ -1,6 +1,6 @@
 //! Counter check types for port counter validation.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CounterCheckKey {
@@ -60,3 +60,93 @@ pub struct CounterCheckStats {
     pub matches: u64,
     pub mismatches: u64,
 }
+
+/// Number of PFC priorities / lossless queue indices on a port.
+pub const PFC_PRIORITY_COUNT: u8 = 8;
+
+/// Condition raised by the periodic PFC and queue checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CounterCheckCondition {
+    /// PFC pause frames kept arriving for the whole detection window.
+    PfcStorm,
+    /// Queue held or gained occupancy while transmitting nothing.
+    StuckQueue,
+}
+
+impl CounterCheckCondition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PfcStorm => "pfc_storm",
+            Self::StuckQueue => "stuck_queue",
+        }
+    }
+}
+
+/// One queue counter sample read from COUNTERS_DB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueueCounterSample {
+    /// Current queue occupancy in bytes (a gauge).
+    pub occupancy_bytes: u64,
+    /// Cumulative transmitted packets.
+    pub tx_packets: u64,
+}
+
+/// Returns how much a cumulative counter advanced, allowing for wraparound.
+pub fn counter_delta(previous: u64, current: u64) -> u64 {
+    current.wrapping_sub(previous)
+}
+
+/// Consecutive-interval tracker for one condition.
+///
+/// The condition is raised after `window` consecutive hits and cleared after
+/// `window` consecutive misses, so a single noisy interval neither raises nor
+/// clears it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DetectionState {
+    pub active: bool,
+    pub consecutive: u32,
+}
+
+impl DetectionState {
+    /// Records one interval and returns the new state on a transition.
+    pub fn update(&mut self, hit: bool, window: u32) -> Option<bool> {
+        if hit != self.active {
+            self.consecutive = self.consecutive.saturating_add(1);
+        } else {
+            self.consecutive = 0;
+        }
+
+        if self.consecutive >= window.max(1) {
+            self.active = !self.active;
+            self.consecutive = 0;
+            return Some(self.active);
+        }
+        None
+    }
+}
+
+/// Detection state for one lossless priority and its queue.
+#[derive(Debug, Clone, Default)]
+pub struct QueueCheckState {
+    pub last_pfc_rx: Option<u64>,
+    pub last_queue: Option<QueueCounterSample>,
+    pub pfc_storm: DetectionState,
+    pub stuck_queue: DetectionState,
+}
+
+/// A port whose lossless priorities are checked every poll interval.
+#[derive(Debug, Clone)]
+pub struct MonitoredPort {
+    pub pfc_mask: u8,
+    pub queues: BTreeMap<u8, QueueCheckState>,
+}
+
+impl MonitoredPort {
+    pub fn new(pfc_mask: u8) -> Self {
+        let queues = (0..PFC_PRIORITY_COUNT)
+            .filter(|priority| pfc_mask & (1 << priority) != 0)
+            .map(|priority| (priority, QueueCheckState::default()))
+            .collect();
+        Self { pfc_mask, queues }
+    }
+}
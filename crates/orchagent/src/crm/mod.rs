@@ -49,7 +49,8 @@ pub use ffi::{register_crm_orch, unregister_crm_orch};
 pub use orch::{CrmOrch, CrmOrchCallbacks, CrmOrchConfig, CrmOrchError, CrmOrchStats};
 pub use types::{
     crm_acl_key, crm_acl_table_key, crm_dash_acl_group_key, crm_ext_table_key, AclBindPoint,
-    AclStage, CrmResourceCounter, CrmResourceEntry, CrmResourceStatus, CrmResourceType,
-    CrmThresholdField, CrmThresholdType, ThresholdCheck, CRM_COUNTERS_TABLE_KEY,
-    CRM_EXCEEDED_MSG_MAX, DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD, DEFAULT_POLLING_INTERVAL,
+    AclStage, CrmResourceCounter, CrmResourceEntry, CrmResourceSnapshot, CrmResourceStatus,
+    CrmResourceType, CrmSnapshot, CrmThresholdField, CrmThresholdType, ThresholdCheck,
+    CRM_COUNTERS_TABLE_KEY, CRM_EXCEEDED_MSG_MAX, DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD,
+    DEFAULT_POLLING_INTERVAL,
 };
@@ -4,11 +4,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+
 use super::types::{
     crm_acl_key, crm_acl_table_key, crm_dash_acl_group_key, crm_ext_table_key, AclBindPoint,
-    AclStage, CrmResourceCounter, CrmResourceEntry, CrmResourceStatus, CrmResourceType,
-    CrmThresholdField, CrmThresholdType, ThresholdCheck, CRM_COUNTERS_TABLE_KEY,
-    DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD, DEFAULT_POLLING_INTERVAL,
+    AclStage, CrmResourceCounter, CrmResourceEntry, CrmResourceSnapshot, CrmResourceStatus,
+    CrmResourceType, CrmSnapshot, CrmThresholdField, CrmThresholdType, ThresholdCheck,
+    CRM_COUNTERS_TABLE_KEY, DEFAULT_HIGH_THRESHOLD, DEFAULT_LOW_THRESHOLD,
+    DEFAULT_POLLING_INTERVAL,
 };
 use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
@@ -67,6 +70,9 @@ pub trait CrmOrchCallbacks: Send + Sync {
         bind_point: AclBindPoint,
     ) -> Option<(u32, u32)>;
 
+    /// Queries available DASH ACL rules in the given DASH ACL group.
+    fn query_dash_acl_rule_availability(&self, group_id: u64) -> Option<u32>;
+
     /// Writes counters to COUNTERS_DB.
     fn write_counters(&self, resource: &str, key: &str, used: u32, available: u32);
 
@@ -79,12 +85,15 @@ pub trait CrmOrchCallbacks: Send + Sync {
 pub struct CrmOrchConfig {
     /// Polling interval for resource monitoring.
     pub polling_interval: Duration,
+    /// Poll, publish and threshold-check DASH resources (DPU only).
+    pub dash_polling_enabled: bool,
 }
 
 impl Default for CrmOrchConfig {
     fn default() -> Self {
         Self {
             polling_interval: Duration::from_secs(DEFAULT_POLLING_INTERVAL),
+            dash_polling_enabled: false,
         }
     }
 }
@@ -94,8 +103,15 @@ impl CrmOrchConfig {
     pub fn with_polling_interval(interval: Duration) -> Self {
         Self {
             polling_interval: interval,
+            ..Default::default()
         }
     }
+
+    /// Enables or disables DASH resource polling.
+    pub fn with_dash_polling(mut self, enabled: bool) -> Self {
+        self.dash_polling_enabled = enabled;
+        self
+    }
 }
 
 /// CRM orchestrator statistics.
@@ -125,6 +141,8 @@ pub struct CrmOrch {
     initialized: bool,
     /// Statistics.
     stats: CrmOrchStats,
+    /// Completion time of the last polling cycle.
+    last_poll: Option<DateTime<Utc>>,
 }
 
 impl std::fmt::Debug for CrmOrch {
@@ -134,6 +152,7 @@ impl std::fmt::Debug for CrmOrch {
             .field("resources_count", &self.resources.len())
             .field("initialized", &self.initialized)
             .field("stats", &self.stats)
+            .field("last_poll", &self.last_poll)
             .finish()
     }
 }
@@ -159,6 +178,7 @@ impl CrmOrch {
             callbacks: None,
             initialized: false,
             stats: CrmOrchStats::default(),
+            last_poll: None,
         }
     }
 
@@ -485,7 +505,9 @@ impl CrmOrch {
     }
 
     /// Increments the used counter for a DASH ACL resource.
-    /// For DashAclGroup, this also initializes the rule counter.
+    ///
+    /// DASH ACL groups are counted globally and each group gets its own
+    /// rule counter; DASH ACL rules are counted per group.
     pub fn increment_dash_acl_used(
         &mut self,
         resource_type: CrmResourceType,
@@ -501,13 +523,20 @@ impl CrmOrch {
         }
 
         let key = crm_dash_acl_group_key(group_id);
+        let counter_key = if resource_type == CrmResourceType::DashAclGroup {
+            CRM_COUNTERS_TABLE_KEY
+        } else {
+            key.as_str()
+        };
         let entry = self
             .resources
             .get_mut(&resource_type)
             .ok_or(CrmOrchError::ResourceNotFound(resource_type))?;
 
-        let counter = entry.get_or_create_counter(&key);
-        counter.id = group_id;
+        let counter = entry.get_or_create_counter(counter_key);
+        if resource_type == CrmResourceType::DashAclRule {
+            counter.id = group_id;
+        }
         self.stats.increments += 1;
         let result = counter.increment_used();
 
@@ -523,7 +552,7 @@ impl CrmOrch {
     }
 
     /// Decrements the used counter for a DASH ACL resource.
-    /// For DashAclGroup, this also removes the rule counter.
+    /// For DashAclGroup, this also removes the group's rule counter.
     pub fn decrement_dash_acl_used(
         &mut self,
         resource_type: CrmResourceType,
@@ -539,14 +568,19 @@ impl CrmOrch {
         }
 
         let key = crm_dash_acl_group_key(group_id);
+        let counter_key = if resource_type == CrmResourceType::DashAclGroup {
+            CRM_COUNTERS_TABLE_KEY
+        } else {
+            key.as_str()
+        };
         let entry = self
             .resources
             .get_mut(&resource_type)
             .ok_or(CrmOrchError::ResourceNotFound(resource_type))?;
 
         let counter = entry
-            .get_counter_mut(&key)
-            .ok_or_else(|| CrmOrchError::CounterNotFound(key.clone()))?;
+            .get_counter_mut(counter_key)
+            .ok_or_else(|| CrmOrchError::CounterNotFound(counter_key.to_string()))?;
 
         self.stats.decrements += 1;
         let result = counter
@@ -554,8 +588,7 @@ impl CrmOrch {
             .ok_or_else(|| CrmOrchError::InvalidThreshold("Counter underflow".to_string()))?;
 
         // When removing a DASH ACL group, also remove its rule counter
-        if resource_type == CrmResourceType::DashAclGroup && result == 0 {
-            entry.remove_counter(&key);
+        if resource_type == CrmResourceType::DashAclGroup {
             if let Some(rule_entry) = self.resources.get_mut(&CrmResourceType::DashAclRule) {
                 rule_entry.remove_counter(&key);
             }
@@ -670,6 +703,36 @@ impl CrmOrch {
 
         // Check thresholds and publish events
         self.check_thresholds();
+
+        self.last_poll = Some(Utc::now());
+    }
+
+    /// Returns the completion time of the last polling cycle.
+    pub fn last_poll(&self) -> Option<DateTime<Utc>> {
+        self.last_poll
+    }
+
+    /// Returns a point-in-time copy of all resources for CLI dumps.
+    ///
+    /// A polling cycle needs `&mut self`, so a snapshot can never observe a
+    /// half-applied poll. Callers sharing the orch across threads must take
+    /// the snapshot under the same lock that drives the timer.
+    pub fn snapshot(&self) -> CrmSnapshot {
+        CrmSnapshot {
+            timestamp: Utc::now(),
+            last_poll: self.last_poll,
+            polling_interval_secs: self.config.polling_interval.as_secs(),
+            resources: self
+                .resources
+                .values()
+                .map(|entry| {
+                    (
+                        entry.resource_type.name().to_string(),
+                        CrmResourceSnapshot::from(entry),
+                    )
+                })
+                .collect(),
+        }
     }
 
     /// Queries SAI for resource availability and updates counters.
@@ -739,16 +802,35 @@ impl CrmOrch {
             }
         }
 
-        // Query DASH resources if this is a DPU
-        if is_dpu {
-            for &res_type in CrmResourceType::dash_types() {
-                if let Some((used, available)) = callbacks.query_resource_availability(res_type) {
-                    if let Some(entry) = self.resources.get_mut(&res_type) {
-                        // DASH resources may have per-group counters
-                        let counter = entry.get_or_create_counter(CRM_COUNTERS_TABLE_KEY);
+        // Query DASH resources if enabled and this is a DPU
+        if self.config.dash_polling_enabled && is_dpu {
+            self.get_dash_available_counters(callbacks.as_ref());
+        }
+    }
+
+    /// Queries SAI for DASH resource availability.
+    ///
+    /// DASH ACL rules are queried per group; all other DASH resources are
+    /// global.
+    fn get_dash_available_counters(&mut self, callbacks: &dyn CrmOrchCallbacks) {
+        for &res_type in CrmResourceType::dash_types() {
+            let Some(entry) = self.resources.get_mut(&res_type) else {
+                continue;
+            };
+
+            if res_type == CrmResourceType::DashAclRule {
+                for counter in entry.counters.values_mut() {
+                    if let Some(available) = callbacks.query_dash_acl_rule_availability(counter.id)
+                    {
                         counter.available = available;
                     }
                 }
+                continue;
+            }
+
+            if let Some((_used, available)) = callbacks.query_resource_availability(res_type) {
+                let counter = entry.get_or_create_counter(CRM_COUNTERS_TABLE_KEY);
+                counter.available = available;
             }
         }
     }
@@ -761,6 +843,9 @@ impl CrmOrch {
         };
 
         for (res_type, entry) in &self.resources {
+            if res_type.is_dash_resource() && !self.config.dash_polling_enabled {
+                continue;
+            }
             for (key, counter) in &entry.counters {
                 callbacks.write_counters(res_type.name(), key, counter.used, counter.available);
             }
@@ -774,7 +859,11 @@ impl CrmOrch {
             None => return,
         };
 
+        let dash_polling_enabled = self.config.dash_polling_enabled;
         for (res_type, entry) in &mut self.resources {
+            if res_type.is_dash_resource() && !dash_polling_enabled {
+                continue;
+            }
             let threshold_type = entry.threshold_type;
             let high = entry.high_threshold;
             let low = entry.low_threshold;
@@ -2099,4 +2188,312 @@ mod tests {
             );
         }
     }
+
+    /// Records events and counter writes; availability is set per test.
+    #[derive(Default)]
+    struct MockCrmCallbacks {
+        availability: std::sync::Mutex<HashMap<CrmResourceType, u32>>,
+        rule_availability: std::sync::Mutex<HashMap<u64, u32>>,
+        events: std::sync::Mutex<Vec<(String, String, bool)>>,
+        writes: std::sync::Mutex<Vec<(String, String, u32, u32)>>,
+    }
+
+    impl MockCrmCallbacks {
+        fn set_available(&self, resource_type: CrmResourceType, available: u32) {
+            self.availability
+                .lock()
+                .unwrap()
+                .insert(resource_type, available);
+        }
+
+        fn events_for(&self, resource: &str) -> Vec<bool> {
+            self.events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(r, _, _)| r == resource)
+                .map(|(_, _, exceeded)| *exceeded)
+                .collect()
+        }
+    }
+
+    impl CrmOrchCallbacks for MockCrmCallbacks {
+        fn publish_threshold_event(
+            &self,
+            resource: &str,
+            counter_key: &str,
+            _used: u32,
+            _available: u32,
+            _threshold: u32,
+            exceeded: bool,
+        ) {
+            self.events.lock().unwrap().push((
+                resource.to_string(),
+                counter_key.to_string(),
+                exceeded,
+            ));
+        }
+
+        fn query_resource_availability(
+            &self,
+            resource_type: CrmResourceType,
+        ) -> Option<(u32, u32)> {
+            self.availability
+                .lock()
+                .unwrap()
+                .get(&resource_type)
+                .map(|&available| (0, available))
+        }
+
+        fn query_acl_availability(
+            &self,
+            _stage: AclStage,
+            _bind_point: AclBindPoint,
+        ) -> Option<(u32, u32)> {
+            None
+        }
+
+        fn query_dash_acl_rule_availability(&self, group_id: u64) -> Option<u32> {
+            self.rule_availability
+                .lock()
+                .unwrap()
+                .get(&group_id)
+                .copied()
+        }
+
+        fn write_counters(&self, resource: &str, key: &str, used: u32, available: u32) {
+            self.writes.lock().unwrap().push((
+                resource.to_string(),
+                key.to_string(),
+                used,
+                available,
+            ));
+        }
+
+        fn is_dpu(&self) -> bool {
+            true
+        }
+    }
+
+    fn dash_orch(enabled: bool) -> (CrmOrch, Arc<MockCrmCallbacks>) {
+        let callbacks = Arc::new(MockCrmCallbacks::default());
+        let mut orch = CrmOrch::new(CrmOrchConfig::default().with_dash_polling(enabled));
+        orch.set_callbacks(Arc::clone(&callbacks) as Arc<dyn CrmOrchCallbacks>);
+        (orch, callbacks)
+    }
+
+    fn set_used(orch: &mut CrmOrch, resource_type: CrmResourceType, used: u32) {
+        while orch.get_used(resource_type).unwrap_or(0) < used {
+            orch.increment_used(resource_type).unwrap();
+        }
+        while orch.get_used(resource_type).unwrap_or(0) > used {
+            orch.decrement_used(resource_type).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_dash_polling_disabled_by_default() {
+        let (mut orch, callbacks) = dash_orch(false);
+        assert!(!CrmOrchConfig::default().dash_polling_enabled);
+
+        callbacks.set_available(CrmResourceType::DashVnet, 0);
+        set_used(&mut orch, CrmResourceType::DashVnet, 10);
+        orch.handle_timer_expiration();
+
+        assert_eq!(orch.get_available(CrmResourceType::DashVnet), Some(0));
+        assert!(callbacks.events_for("dash_vnet").is_empty());
+        assert!(!callbacks
+            .writes
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(r, _, _, _)| r.starts_with("dash_")));
+    }
+
+    #[test]
+    fn test_dash_threshold_exceeded_then_cleared() {
+        let (mut orch, callbacks) = dash_orch(true);
+
+        // (used, available): below, above high, between, below low
+        for (used, available) in [(5, 95), (90, 10), (80, 20), (50, 50)] {
+            set_used(&mut orch, CrmResourceType::DashVnet, used);
+            callbacks.set_available(CrmResourceType::DashVnet, available);
+            orch.handle_timer_expiration();
+        }
+
+        assert_eq!(callbacks.events_for("dash_vnet"), vec![true, false]);
+        assert_eq!(orch.stats().threshold_events, 1);
+    }
+
+    #[test]
+    fn test_dash_threshold_used_and_free_types() {
+        let (mut orch, callbacks) = dash_orch(true);
+        orch.handle_config_field("dash_eni_threshold_type", "used")
+            .unwrap();
+        orch.handle_config_field("dash_eni_high_threshold", "8")
+            .unwrap();
+        orch.handle_config_field("dash_eni_low_threshold", "2")
+            .unwrap();
+        orch.set_threshold_type(CrmResourceType::DashIpv4Outbound, CrmThresholdType::Free)
+            .unwrap();
+        orch.set_high_threshold(CrmResourceType::DashIpv4Outbound, 1000)
+            .unwrap();
+        orch.set_low_threshold(CrmResourceType::DashIpv4Outbound, 100)
+            .unwrap();
+
+        for (used, free) in [(8, 2000), (5, 500), (1, 50)] {
+            set_used(&mut orch, CrmResourceType::DashEni, used);
+            set_used(&mut orch, CrmResourceType::DashIpv4Outbound, 1);
+            callbacks.set_available(CrmResourceType::DashEni, 100);
+            callbacks.set_available(CrmResourceType::DashIpv4Outbound, free);
+            orch.handle_timer_expiration();
+        }
+
+        assert_eq!(callbacks.events_for("dash_eni"), vec![true, false]);
+        assert_eq!(
+            callbacks.events_for("dash_ipv4_outbound_routing"),
+            vec![true, false]
+        );
+    }
+
+    #[test]
+    fn test_dash_acl_rule_counters_per_group() {
+        let (mut orch, callbacks) = dash_orch(true);
+        let groups = [0x1000_u64, 0x2000];
+        for &group in &groups {
+            orch.increment_dash_acl_used(CrmResourceType::DashAclGroup, group)
+                .unwrap();
+        }
+        orch.increment_dash_acl_used(CrmResourceType::DashAclRule, groups[0])
+            .unwrap();
+        callbacks.set_available(CrmResourceType::DashAclGroup, 62);
+        callbacks
+            .rule_availability
+            .lock()
+            .unwrap()
+            .extend([(groups[0], 999), (groups[1], 1000)]);
+
+        orch.handle_timer_expiration();
+
+        assert_eq!(orch.get_used(CrmResourceType::DashAclGroup), Some(2));
+        assert_eq!(orch.get_available(CrmResourceType::DashAclGroup), Some(62));
+
+        let writes = callbacks.writes.lock().unwrap().clone();
+        for (&group, (used, available)) in groups.iter().zip([(1, 999), (0, 1000)]) {
+            let key = crm_dash_acl_group_key(group);
+            assert!(writes.contains(&("dash_acl_rule".to_string(), key, used, available)));
+        }
+
+        // Removing a group drops its rule counter
+        orch.decrement_dash_acl_used(CrmResourceType::DashAclGroup, groups[1])
+            .unwrap();
+        let rules = orch.get_resource(CrmResourceType::DashAclRule).unwrap();
+        assert!(rules
+            .get_counter(&crm_dash_acl_group_key(groups[1]))
+            .is_none());
+        assert!(rules
+            .get_counter(&crm_dash_acl_group_key(groups[0]))
+            .is_some());
+        assert_eq!(orch.get_used(CrmResourceType::DashAclGroup), Some(1));
+    }
+
+    #[test]
+    fn test_snapshot_contents_and_json() {
+        let (mut orch, callbacks) = dash_orch(false);
+        orch.set_threshold_type(CrmResourceType::Ipv4Route, CrmThresholdType::Used)
+            .unwrap();
+        orch.set_high_threshold(CrmResourceType::Ipv4Route, 900)
+            .unwrap();
+        orch.increment_acl_used(
+            CrmResourceType::AclTable,
+            AclStage::Ingress,
+            AclBindPoint::Port,
+        )
+        .unwrap();
+        assert!(orch.snapshot().last_poll.is_none());
+
+        set_used(&mut orch, CrmResourceType::Ipv4Route, 3);
+        callbacks.set_available(CrmResourceType::Ipv4Route, 97);
+        orch.handle_timer_expiration();
+
+        let snapshot = orch.snapshot();
+        assert_eq!(snapshot.last_poll, orch.last_poll());
+        assert!(snapshot.timestamp >= snapshot.last_poll.unwrap());
+        assert_eq!(snapshot.polling_interval_secs, DEFAULT_POLLING_INTERVAL);
+        assert_eq!(snapshot.resources.len(), orch.resources.len());
+
+        let route = snapshot.get(CrmResourceType::Ipv4Route).unwrap();
+        assert_eq!(route.threshold_type, CrmThresholdType::Used);
+        assert_eq!(route.high_threshold, 900);
+        assert_eq!(route.low_threshold, DEFAULT_LOW_THRESHOLD);
+        let stats = &route.counters[CRM_COUNTERS_TABLE_KEY];
+        assert_eq!((stats.used, stats.available), (3, 97));
+
+        let acl = snapshot.get(CrmResourceType::AclTable).unwrap();
+        assert_eq!(
+            acl.counters[&crm_acl_key(AclStage::Ingress, AclBindPoint::Port)].used,
+            1
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&snapshot.to_json().unwrap()).unwrap();
+        let route = &json["resources"]["ipv4_route"];
+        assert_eq!(route["resource_type"], "ipv4_route");
+        assert_eq!(route["threshold_type"], "used");
+        assert_eq!(route["status"], "supported");
+        assert_eq!(route["counters"]["STATS"]["available"], 97);
+        assert!(route["counters"]["STATS"]
+            .get("exceeded_log_count")
+            .is_none());
+        assert!(json["last_poll"].is_string());
+    }
+
+    #[test]
+    fn test_snapshot_consistent_with_concurrent_polling() {
+        const CAPACITY: u32 = 1000;
+        const CYCLES: u32 = 200;
+        let resources = [CrmResourceType::Ipv4Route, CrmResourceType::Ipv6Route];
+
+        let (orch, callbacks) = dash_orch(false);
+        for &res_type in &resources {
+            callbacks.set_available(res_type, CAPACITY);
+        }
+        let orch = Arc::new(std::sync::Mutex::new(orch));
+
+        // Each cycle adds an entry and re-polls, keeping used + available fixed
+        let writer = {
+            let orch = Arc::clone(&orch);
+            std::thread::spawn(move || {
+                for _ in 0..CYCLES {
+                    let mut orch = orch.lock().unwrap();
+                    for &res_type in &resources {
+                        let used = orch.increment_used(res_type).unwrap();
+                        callbacks.set_available(res_type, CAPACITY - used);
+                    }
+                    orch.handle_timer_expiration();
+                }
+            })
+        };
+
+        let mut snapshots = Vec::new();
+        while !writer.is_finished() {
+            snapshots.push(orch.lock().unwrap().snapshot());
+        }
+        writer.join().unwrap();
+        snapshots.push(orch.lock().unwrap().snapshot());
+
+        for snapshot in snapshots.iter().filter(|s| s.last_poll.is_some()) {
+            let counters: Vec<_> = resources
+                .iter()
+                .map(|&r| snapshot.get(r).unwrap().counters[CRM_COUNTERS_TABLE_KEY].clone())
+                .collect();
+            for counter in &counters {
+                assert_eq!(counter.used + counter.available, CAPACITY);
+            }
+            assert_eq!(counters[0].used, counters[1].used);
+        }
+
+        let last = snapshots.last().unwrap();
+        let route = &last.get(CrmResourceType::Ipv4Route).unwrap().counters[CRM_COUNTERS_TABLE_KEY];
+        assert_eq!((route.used, route.available), (CYCLES, CAPACITY - CYCLES));
+    }
 }
@@ -1,6 +1,8 @@
 //! CRM types and data structures.
 
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::str::FromStr;
 
@@ -283,8 +285,15 @@ impl fmt::Display for CrmResourceType {
     }
 }
 
+impl Serialize for CrmResourceType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
 /// CRM threshold type for resource monitoring.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CrmThresholdType {
     /// Percentage of total capacity.
     #[default]
@@ -326,7 +335,8 @@ impl fmt::Display for CrmThresholdType {
 }
 
 /// CRM resource support status.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CrmResourceStatus {
     /// Resource is supported by the platform.
     #[default]
@@ -336,7 +346,7 @@ pub enum CrmResourceStatus {
 }
 
 /// Counter data for a single CRM resource context.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
 pub struct CrmResourceCounter {
     /// SAI object ID (used for ACL tables, DASH ACL groups).
     pub id: u64,
@@ -345,6 +355,7 @@ pub struct CrmResourceCounter {
     /// Used entries.
     pub used: u32,
     /// Exceeded log counter (for rate limiting, max 10).
+    #[serde(skip)]
     pub exceeded_log_count: u32,
 }
 
@@ -513,6 +524,65 @@ impl CrmResourceEntry {
     }
 }
 
+/// Point-in-time copy of a single resource entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrmResourceSnapshot {
+    /// Resource type.
+    pub resource_type: CrmResourceType,
+    /// Threshold type.
+    pub threshold_type: CrmThresholdType,
+    /// Low threshold value.
+    pub low_threshold: u32,
+    /// High threshold value.
+    pub high_threshold: u32,
+    /// Resource support status.
+    pub status: CrmResourceStatus,
+    /// Counters sorted by context key.
+    pub counters: BTreeMap<String, CrmResourceCounter>,
+}
+
+impl From<&CrmResourceEntry> for CrmResourceSnapshot {
+    fn from(entry: &CrmResourceEntry) -> Self {
+        Self {
+            resource_type: entry.resource_type,
+            threshold_type: entry.threshold_type,
+            low_threshold: entry.low_threshold,
+            high_threshold: entry.high_threshold,
+            status: entry.status,
+            counters: entry
+                .counters
+                .iter()
+                .map(|(key, counter)| (key.clone(), counter.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Point-in-time copy of all CRM resources, suitable for CLI dumps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrmSnapshot {
+    /// When the snapshot was taken.
+    pub timestamp: DateTime<Utc>,
+    /// When the last polling cycle completed, if any.
+    pub last_poll: Option<DateTime<Utc>>,
+    /// Polling interval in seconds.
+    pub polling_interval_secs: u64,
+    /// Resources keyed by name.
+    pub resources: BTreeMap<String, CrmResourceSnapshot>,
+}
+
+impl CrmSnapshot {
+    /// Gets a resource snapshot by type.
+    pub fn get(&self, resource_type: CrmResourceType) -> Option<&CrmResourceSnapshot> {
+        self.resources.get(resource_type.name())
+    }
+
+    /// Serializes the snapshot as pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
 /// ACL stage for CRM tracking.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AclStage {
@@ -1,5 +1,9 @@
 //! OrchDaemon - Main orchestration daemon.
 
 mod orchdaemon;
+mod warm_restart;
 
-pub use orchdaemon::{OrchDaemon, OrchDaemonConfig};
+pub use orchdaemon::{OrchDaemon, OrchDaemonConfig, OrchDaemonStats, OrchExecutionStats};
+pub use warm_restart::{
+    StateDbWarmRestartWriter, WarmRestartState, WarmRestartStatusWriter, WARM_RESTART_TABLE,
+};
@@ -7,15 +7,18 @@
 //! - Task dispatch to appropriate Orchs
 //! - Warm restart coordination
 
+use super::warm_restart::{WarmRestartState, WarmRestartStatusWriter};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use log::{debug, error, info};
 use sonic_orch_common::{
-    ConsumerConfig, Orch, OrchContext, RedisBoundConsumer, RedisConfig, RedisDatabase,
+    ConsumerConfig, KeyOpFieldsValues, Orch, OrchContext, OrchMetrics, OrchMetricsSnapshot,
+    RedisBoundConsumer, RedisConfig, RedisDatabase,
 };
 use sonic_sai::{SaiError, SaiResult, SwitchKind, SwitchOid};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 
 /// Configuration for the OrchDaemon.
@@ -31,6 +34,21 @@ pub struct OrchDaemonConfig {
     pub redis_host: String,
     /// Redis port for databases
     pub redis_port: u16,
+    /// Time each Orch is given to reconcile during warm restart
+    pub warm_restart_reconcile_timeout_ms: u64,
+    /// Consecutive iterations a single Orch may keep the extra drain before
+    /// yielding it to lower-priority Orchs with pending tasks (0 = never)
+    pub fair_rounds: usize,
+    /// Maximum number of batches an Orch drains in a single turn
+    pub max_batches_per_turn: usize,
+    /// ZMQ endpoint to bind for table updates alongside Redis (None = disabled)
+    pub zmq_endpoint: Option<String>,
+    /// Tables accepted on the ZMQ endpoint; each needs a table consumer
+    pub zmq_tables: Vec<String>,
+    /// Maximum queued ZMQ entries per table
+    pub zmq_queue_capacity: usize,
+    /// Drop the oldest queued ZMQ entry instead of blocking when full
+    pub zmq_drop_oldest: bool,
 }
 
 impl Default for OrchDaemonConfig {
@@ -41,10 +59,40 @@ impl Default for OrchDaemonConfig {
             warm_boot: false,
             redis_host: "127.0.0.1".to_string(),
             redis_port: 6379,
+            warm_restart_reconcile_timeout_ms: 30_000,
+            fair_rounds: 8,
+            max_batches_per_turn: 16,
+            zmq_endpoint: None,
+            zmq_tables: vec![
+                "PORT_TABLE".to_string(),
+                "INTF_TABLE".to_string(),
+                "ROUTE_TABLE".to_string(),
+            ],
+            zmq_queue_capacity: 4096,
+            zmq_drop_oldest: false,
         }
     }
 }
 
+/// Per-Orch scheduling counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrchExecutionStats {
+    /// Number of do_task batches executed
+    pub executed_batches: u64,
+    /// Iterations in which the Orch was held to a single batch while another
+    /// Orch drained
+    pub held_turns: u64,
+}
+
+/// OrchDaemon scheduling statistics.
+#[derive(Debug, Clone, Default)]
+pub struct OrchDaemonStats {
+    /// Number of scheduling iterations executed
+    pub iterations: u64,
+    /// Counters keyed by Orch name
+    pub orchs: HashMap<String, OrchExecutionStats>,
+}
+
 /// The main orchestration daemon.
 ///
 /// OrchDaemon coordinates all Orch modules and runs the main event loop.
@@ -65,8 +113,21 @@ pub struct OrchDaemon {
     port_table_consumer: Option<RedisBoundConsumer>,
     intf_table_consumer: Option<RedisBoundConsumer>,
     route_table_consumer: Option<RedisBoundConsumer>,
+    /// ZMQ consumer feeding the same tables as the Redis consumers
+    #[cfg(feature = "mod-zmq")]
+    zmq_consumer: Option<crate::zmq::ZmqConsumer>,
     /// SAI switch object (OID for the switch abstraction)
     switch_oid: Option<SwitchOid>,
+    /// Consumer execution is suspended during warm restart reconciliation
+    frozen: bool,
+    /// Publishes per-Orch warm restart state
+    warm_restart_writer: Option<Arc<dyn WarmRestartStatusWriter>>,
+    /// Orch that consumed the previous iterations and how many in a row
+    streak: Option<(String, usize)>,
+    /// Scheduling statistics
+    stats: OrchDaemonStats,
+    /// Per-Orch task latency and queue depth
+    metrics: OrchMetrics,
 }
 
 impl OrchDaemon {
@@ -82,7 +143,14 @@ impl OrchDaemon {
             port_table_consumer: None,
             intf_table_consumer: None,
             route_table_consumer: None,
+            #[cfg(feature = "mod-zmq")]
+            zmq_consumer: None,
             switch_oid: None,
+            frozen: false,
+            warm_restart_writer: None,
+            streak: None,
+            stats: OrchDaemonStats::default(),
+            metrics: OrchMetrics::new(),
         }
     }
 
@@ -135,6 +203,25 @@ impl OrchDaemon {
         self.switch_oid
     }
 
+    /// Returns the scheduling statistics.
+    pub fn stats(&self) -> &OrchDaemonStats {
+        &self.stats
+    }
+
+    /// Returns a handle to the metrics registry.
+    ///
+    /// The handle shares state with the daemon, so it can be used to take
+    /// snapshots while the event loop is running.
+    pub fn metrics(&self) -> OrchMetrics {
+        self.metrics.clone()
+    }
+
+    /// Returns a snapshot of per-Orch task latency, consumer depth and
+    /// retry cache size.
+    pub fn dump_metrics(&self) -> OrchMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
     /// Initializes all registered Orchs.
     ///
     /// Called during startup before the event loop begins.
@@ -348,7 +435,12 @@ impl OrchDaemon {
         match RedisDatabase::new(state_db_config).await {
             Ok(db) => {
                 info!("Connected to STATE_DB");
-                self.state_db = Some(Arc::new(RwLock::new(db)));
+                let state_db = Arc::new(RwLock::new(db));
+                if config.warm_boot && self.warm_restart_writer.is_none() {
+                    self.warm_restart_writer =
+                        Some(Arc::new(StateDbWarmRestartWriter::new(state_db.clone())));
+                }
+                self.state_db = Some(state_db);
             }
             Err(e) => {
                 return Err(format!("Failed to connect to STATE_DB: {}", e));
@@ -371,6 +463,9 @@ impl OrchDaemon {
         // These are bound to APPL_DB for event polling
         self.init_redis_consumers()?;
 
+        #[cfg(feature = "mod-zmq")]
+        self.init_zmq_consumer()?;
+
         info!("All database connections initialized successfully");
         Ok(())
     }
@@ -412,6 +507,78 @@ impl OrchDaemon {
         }
     }
 
+    /// Binds the ZMQ endpoint for the configured tables, if enabled.
+    ///
+    /// Every table must have a table consumer to drain into, otherwise its
+    /// entries would queue up and never be processed.
+    #[cfg(feature = "mod-zmq")]
+    fn init_zmq_consumer(&mut self) -> Result<(), String> {
+        use crate::zmq::{ZmqConsumer, ZmqConsumerConfig, ZmqOverflowPolicy};
+
+        let Some(endpoint) = self.config.zmq_endpoint.clone() else {
+            return Ok(());
+        };
+
+        let consumed: Vec<&str> = [
+            &self.port_table_consumer,
+            &self.intf_table_consumer,
+            &self.route_table_consumer,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|consumer| consumer.consumer().table_name())
+        .collect();
+        if let Some(table) = self
+            .config
+            .zmq_tables
+            .iter()
+            .find(|table| !consumed.contains(&table.as_str()))
+        {
+            return Err(format!("No consumer for ZMQ table {}", table));
+        }
+
+        let mut zmq_config = ZmqConsumerConfig::new(endpoint, self.config.zmq_tables.clone());
+        zmq_config.queue_capacity = self.config.zmq_queue_capacity;
+        zmq_config.overflow_policy = if self.config.zmq_drop_oldest {
+            ZmqOverflowPolicy::DropOldest
+        } else {
+            ZmqOverflowPolicy::Block
+        };
+
+        let consumer = ZmqConsumer::bind(&zmq::Context::new(), zmq_config)
+            .map_err(|e| format!("Failed to start ZMQ consumer: {}", e))?;
+        info!("  Bound ZMQ consumer on {}", consumer.endpoint());
+        self.zmq_consumer = Some(consumer);
+        Ok(())
+    }
+
+    /// Moves entries received over ZMQ into the matching table consumers.
+    #[cfg(feature = "mod-zmq")]
+    fn drain_zmq_consumer(&mut self) {
+        let Some(zmq_consumer) = &self.zmq_consumer else {
+            return;
+        };
+        let batch_size = self.config.batch_size;
+
+        for consumer in [
+            &mut self.port_table_consumer,
+            &mut self.intf_table_consumer,
+            &mut self.route_table_consumer,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let count = zmq_consumer.drain_into(consumer.consumer_mut(), batch_size);
+            if count > 0 {
+                debug!(
+                    "Added {} ZMQ entries to {}",
+                    count,
+                    consumer.consumer().table_name()
+                );
+            }
+        }
+    }
+
     /// Runs the main event loop.
     ///
     /// This method blocks until `stop()` is called.
@@ -437,14 +604,10 @@ impl OrchDaemon {
             debug!("Polling Redis consumers for new entries");
             self.poll_redis_consumers().await;
 
-            // Process tasks from all Orchs in priority order
-            for (_priority, orchs) in self.orchs.iter_mut() {
-                for orch in orchs.iter_mut() {
-                    if orch.has_pending_tasks() {
-                        debug!("Processing tasks for {}", orch.name());
-                        orch.do_task().await;
-                    }
-                }
+            // Process tasks from Orchs in priority order, unless frozen
+            // for warm restart reconciliation
+            if !self.frozen {
+                self.execute_orchs().await;
             }
 
             // Sleep for heartbeat interval
@@ -465,6 +628,84 @@ impl OrchDaemon {
         audit_log!(stop_record);
     }
 
+    /// Runs one scheduling iteration over the registered Orchs.
+    ///
+    /// Every Orch with pending tasks runs one batch per iteration, in priority
+    /// order. Beyond that first batch an Orch may keep draining, up to
+    /// `max_batches_per_turn` batches. An Orch that still has pending tasks
+    /// after its budget consumes the iteration's extra drain, so the
+    /// lower-priority Orchs after it only run their one batch. Once an Orch
+    /// has consumed `fair_rounds` consecutive iterations it is held to one
+    /// batch for a turn if any lower-priority Orch has pending tasks.
+    pub async fn execute_orchs(&mut self) {
+        self.stats.iterations += 1;
+        let budget = self.config.max_batches_per_turn.max(1);
+        let fair_rounds = self.config.fair_rounds;
+
+        // Decide whether the Orch holding the streak must yield this turn
+        let mut yielding = None;
+        if let Some((holder, count)) = &self.streak {
+            if fair_rounds > 0 && *count >= fair_rounds {
+                let lower_pending = self
+                    .orchs
+                    .values()
+                    .flatten()
+                    .skip_while(|orch| orch.name() != holder)
+                    .skip(1)
+                    .any(|orch| orch.has_pending_tasks());
+                if lower_pending {
+                    yielding = Some(holder.clone());
+                }
+            }
+        }
+
+        let mut consumed_by: Option<String> = None;
+        for orch in self.orchs.values_mut().flatten() {
+            if !orch.has_pending_tasks() {
+                continue;
+            }
+
+            let stats = self.stats.orchs.entry(orch.name().to_string()).or_default();
+            let limit = if consumed_by.is_some() || yielding.as_deref() == Some(orch.name()) {
+                stats.held_turns += 1;
+                1
+            } else {
+                budget
+            };
+
+            debug!("Processing tasks for {}", orch.name());
+            let mut batches = 0;
+            while batches < limit && orch.has_pending_tasks() {
+                let started = Instant::now();
+                orch.do_task().await;
+                self.metrics.record_task(orch.name(), started.elapsed());
+                batches += 1;
+            }
+            stats.executed_batches += batches as u64;
+
+            if limit > 1 && orch.has_pending_tasks() {
+                consumed_by = Some(orch.name().to_string());
+            }
+        }
+
+        if let Some(name) = &yielding {
+            debug!("{} yielded its extra drain to lower-priority Orchs", name);
+        }
+
+        for orch in self.orchs.values().flatten() {
+            self.metrics
+                .record_consumers(orch.name(), orch.consumer_metrics());
+            self.metrics
+                .record_retry_cache_size(orch.name(), orch.retry_cache_size());
+        }
+
+        self.streak = match (consumed_by, self.streak.take()) {
+            (Some(name), Some((holder, count))) if name == holder => Some((name, count + 1)),
+            (Some(name), _) => Some((name, 1)),
+            (None, _) => None,
+        };
+    }
+
     /// Stops the event loop.
     pub fn stop(&mut self) {
         info!("Stopping OrchDaemon");
@@ -537,6 +778,180 @@ impl OrchDaemon {
         ctx.warm_boot_in_progress = false;
     }
 
+    /// Sets the writer used to publish per-Orch warm restart state.
+    pub fn set_warm_restart_writer(&mut self, writer: Arc<dyn WarmRestartStatusWriter>) {
+        self.warm_restart_writer = Some(writer);
+    }
+
+    /// Returns true while consumer execution is frozen for warm restart.
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Runs the warm restart reconciliation phase if warm boot is enabled.
+    ///
+    /// Reads every table the registered Orchs restore from APPL_DB and
+    /// reconciles against it. Returns `true` when warm boot is disabled.
+    pub async fn warm_restart(&mut self) -> bool {
+        if !self.config.warm_boot {
+            return true;
+        }
+
+        let mut appl_state = HashMap::new();
+        if let Some(appl_db) = &self.appl_db {
+            let mut db = appl_db.write().await;
+            for orchs in self.orchs.values() {
+                for table in orchs.iter().flat_map(|orch| orch.warm_restart_tables()) {
+                    if appl_state.contains_key(&table) {
+                        continue;
+                    }
+                    match db.read_table(&table).await {
+                        Ok(entries) => {
+                            appl_state.insert(table, entries);
+                        }
+                        Err(e) => {
+                            error!("Failed to read {} for warm restart: {}", table, e);
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        if !self.reconcile_warm_restart(appl_state).await {
+            return false;
+        }
+
+        self.on_warm_boot_end().await;
+        true
+    }
+
+    /// Reconciles all Orchs against the given APPL_DB state.
+    ///
+    /// Consumer execution is frozen while every Orch is baked, receives the
+    /// replayed entries of its tables and reconciles them within
+    /// `warm_restart_reconcile_timeout_ms`. Returns `false` if any Orch fails
+    /// to bake or reconcile.
+    pub async fn reconcile_warm_restart(
+        &mut self,
+        appl_state: HashMap<String, Vec<KeyOpFieldsValues>>,
+    ) -> bool {
+        info!("Starting warm restart reconciliation");
+
+        let record = AuditRecord::new(
+            AuditCategory::WarmRestart,
+            "OrchDaemon",
+            "warm_restart_reconcile_start",
+        )
+        .with_outcome(AuditOutcome::InProgress)
+        .with_details(serde_json::json!({
+            "tables": appl_state.len(),
+            "reconcile_timeout_ms": self.config.warm_restart_reconcile_timeout_ms,
+        }));
+        audit_log!(record);
+
+        self.frozen = true;
+        self.context.write().await.warm_boot_in_progress = true;
+        let writer = self.warm_restart_writer.clone();
+
+        // Bake and replay intent while frozen
+        for (_priority, orchs) in self.orchs.iter_mut() {
+            for orch in orchs.iter_mut() {
+                if let Some(writer) = &writer {
+                    writer
+                        .write_state(orch.name(), WarmRestartState::Initialized)
+                        .await;
+                }
+
+                if !orch.bake() {
+                    error!("Failed to bake {}", orch.name());
+
+                    let fail_record = AuditRecord::new(
+                        AuditCategory::WarmRestart,
+                        "OrchDaemon",
+                        format!("warm_restart_bake_failed: {}", orch.name()),
+                    )
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_error(format!("Failed to bake {}", orch.name()));
+                    audit_log!(fail_record);
+
+                    self.frozen = false;
+                    self.context.write().await.warm_boot_in_progress = false;
+                    return false;
+                }
+
+                for table in orch.warm_restart_tables() {
+                    let tasks = appl_state.get(&table).cloned().unwrap_or_default();
+                    debug!(
+                        "Replaying {} entries of {} into {}",
+                        tasks.len(),
+                        table,
+                        orch.name()
+                    );
+                    orch.replay_tasks(&table, tasks);
+                }
+
+                if let Some(writer) = &writer {
+                    writer
+                        .write_state(orch.name(), WarmRestartState::Restored)
+                        .await;
+                }
+            }
+        }
+
+        // Reconcile in priority order
+        let timeout =
+            tokio::time::Duration::from_millis(self.config.warm_restart_reconcile_timeout_ms);
+        let mut failed = Vec::new();
+        for (_priority, orchs) in self.orchs.iter_mut() {
+            for orch in orchs.iter_mut() {
+                let reconciled = tokio::time::timeout(timeout, orch.reconcile())
+                    .await
+                    .unwrap_or(false);
+                if !reconciled {
+                    error!("Failed to reconcile {} within {:?}", orch.name(), timeout);
+
+                    let fail_record = AuditRecord::new(
+                        AuditCategory::WarmRestart,
+                        "OrchDaemon",
+                        format!("warm_restart_reconcile_failed: {}", orch.name()),
+                    )
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_error(format!("Failed to reconcile {}", orch.name()));
+                    audit_log!(fail_record);
+
+                    failed.push(orch.name().to_string());
+                    continue;
+                }
+
+                if let Some(writer) = &writer {
+                    writer
+                        .write_state(orch.name(), WarmRestartState::Reconciled)
+                        .await;
+                }
+            }
+        }
+
+        self.frozen = false;
+
+        let record = AuditRecord::new(
+            AuditCategory::WarmRestart,
+            "OrchDaemon",
+            "warm_restart_reconcile_complete",
+        )
+        .with_outcome(if failed.is_empty() {
+            AuditOutcome::Success
+        } else {
+            AuditOutcome::Failure
+        })
+        .with_details(serde_json::json!({
+            "failed_orchs": failed,
+        }));
+        audit_log!(record);
+
+        failed.is_empty()
+    }
+
     /// Polls Redis consumers for new entries and makes them available to Orchs.
     ///
     /// This is called in the event loop before Orch task processing.
@@ -599,6 +1014,11 @@ impl OrchDaemon {
                 }
             }
         }
+
+        // Feed ZMQ entries into the same consumers so both sources share
+        // per-key deduplication and ordering
+        #[cfg(feature = "mod-zmq")]
+        self.drain_zmq_consumer();
     }
 
     /// Dumps state for debugging.
@@ -690,6 +1110,13 @@ mod tests {
             warm_boot: true,
             redis_host: "localhost".to_string(),
             redis_port: 6380,
+            warm_restart_reconcile_timeout_ms: 30_000,
+            fair_rounds: 8,
+            max_batches_per_turn: 16,
+            zmq_endpoint: None,
+            zmq_tables: Vec::new(),
+            zmq_queue_capacity: 4096,
+            zmq_drop_oldest: false,
         };
         let daemon = OrchDaemon::new(config.clone());
         assert_eq!(daemon.config.heartbeat_interval_ms, 500);
@@ -867,11 +1294,97 @@ mod tests {
             warm_boot: true,
             redis_host: "127.0.0.1".to_string(),
             redis_port: 6379,
+            warm_restart_reconcile_timeout_ms: 30_000,
+            fair_rounds: 8,
+            max_batches_per_turn: 16,
+            zmq_endpoint: None,
+            zmq_tables: Vec::new(),
+            zmq_queue_capacity: 4096,
+            zmq_drop_oldest: false,
         };
         let daemon = OrchDaemon::new(config);
         assert!(daemon.config.warm_boot);
     }
 
+    #[derive(Default)]
+    struct RecordingWriter {
+        states: std::sync::Mutex<Vec<(String, WarmRestartState)>>,
+    }
+
+    #[async_trait]
+    impl WarmRestartStatusWriter for RecordingWriter {
+        async fn write_state(&self, orch_name: &str, state: WarmRestartState) {
+            self.states
+                .lock()
+                .unwrap()
+                .push((orch_name.to_string(), state));
+        }
+    }
+
+    struct SlowReconcileOrch;
+
+    #[async_trait]
+    impl Orch for SlowReconcileOrch {
+        fn name(&self) -> &str {
+            "SlowOrch"
+        }
+
+        async fn do_task(&mut self) {}
+
+        async fn reconcile(&mut self) -> bool {
+            tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_warm_restart_disabled() {
+        let mut daemon = OrchDaemon::new(OrchDaemonConfig::default());
+        assert!(daemon.warm_restart().await);
+        assert!(!daemon.is_frozen());
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_reconcile_writes_states() {
+        let mut daemon = OrchDaemon::new(OrchDaemonConfig::default());
+        let writer = Arc::new(RecordingWriter::default());
+        daemon.set_warm_restart_writer(writer.clone());
+        daemon.register_orch(Box::new(TestOrch::new("OrchA", 10)));
+
+        assert!(daemon.reconcile_warm_restart(HashMap::new()).await);
+        assert!(!daemon.is_frozen());
+
+        let states = writer.states.lock().unwrap();
+        assert_eq!(
+            *states,
+            vec![
+                ("OrchA".to_string(), WarmRestartState::Initialized),
+                ("OrchA".to_string(), WarmRestartState::Restored),
+                ("OrchA".to_string(), WarmRestartState::Reconciled),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_reconcile_timeout() {
+        let config = OrchDaemonConfig {
+            warm_restart_reconcile_timeout_ms: 10,
+            ..OrchDaemonConfig::default()
+        };
+        let mut daemon = OrchDaemon::new(config);
+        let writer = Arc::new(RecordingWriter::default());
+        daemon.set_warm_restart_writer(writer.clone());
+        daemon.register_orch(Box::new(SlowReconcileOrch));
+
+        assert!(!daemon.reconcile_warm_restart(HashMap::new()).await);
+        assert!(!daemon.is_frozen());
+
+        let states = writer.states.lock().unwrap();
+        assert!(!states
+            .iter()
+            .any(|(_, state)| *state == WarmRestartState::Reconciled));
+    }
+
     // ============================================================================
     // 7. Dump Tests
     // ============================================================================
@@ -952,6 +1465,13 @@ mod tests {
             warm_boot: true,
             redis_host: "127.0.0.1".to_string(),
             redis_port: 6379,
+            warm_restart_reconcile_timeout_ms: 30_000,
+            fair_rounds: 8,
+            max_batches_per_turn: 16,
+            zmq_endpoint: None,
+            zmq_tables: Vec::new(),
+            zmq_queue_capacity: 4096,
+            zmq_drop_oldest: false,
         };
         let daemon = OrchDaemon::new(config);
         assert_eq!(daemon.config.heartbeat_interval_ms, u64::MAX);
@@ -966,9 +1486,236 @@ mod tests {
             warm_boot: false,
             redis_host: "127.0.0.1".to_string(),
             redis_port: 6379,
+            warm_restart_reconcile_timeout_ms: 30_000,
+            fair_rounds: 8,
+            max_batches_per_turn: 16,
+            zmq_endpoint: None,
+            zmq_tables: Vec::new(),
+            zmq_queue_capacity: 4096,
+            zmq_drop_oldest: false,
         };
         let daemon = OrchDaemon::new(config);
         assert_eq!(daemon.config.heartbeat_interval_ms, 0);
         assert_eq!(daemon.config.batch_size, 0);
     }
+
+    // ============================================================================
+    // 9. Scheduling Tests
+    // ============================================================================
+
+    /// Orch with a fixed number of batches to drain.
+    struct BacklogOrch {
+        name: String,
+        priority: i32,
+        remaining: u32,
+    }
+
+    #[async_trait]
+    impl Orch for BacklogOrch {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn do_task(&mut self) {
+            self.remaining = self.remaining.saturating_sub(1);
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn has_pending_tasks(&self) -> bool {
+            self.remaining > 0
+        }
+    }
+
+    fn scheduling_config(fair_rounds: usize, max_batches_per_turn: usize) -> OrchDaemonConfig {
+        OrchDaemonConfig {
+            fair_rounds,
+            max_batches_per_turn,
+            ..OrchDaemonConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_default_config_runs_every_pending_orch() {
+        let mut daemon = OrchDaemon::new(OrchDaemonConfig::default());
+
+        let mut counts = Vec::new();
+        for (name, priority) in [("RouteOrch", 0), ("NeighOrch", 5), ("FdbOrch", 10)] {
+            let orch = TestOrch::new(name, priority).with_pending();
+            counts.push(StdArc::clone(&orch.task_count));
+            daemon.register_orch(Box::new(orch));
+        }
+        let idle = TestOrch::new("AclOrch", 20);
+        let idle_count = StdArc::clone(&idle.task_count);
+        daemon.register_orch(Box::new(idle));
+
+        // As before the drain budget existed: each pending Orch runs every
+        // iteration, whatever the Orchs ahead of it still have queued
+        let mut prev = vec![0; counts.len()];
+        for _ in 0..40 {
+            daemon.execute_orchs().await;
+            for (count, prev) in counts.iter().zip(prev.iter_mut()) {
+                let now = count.load(Ordering::SeqCst);
+                assert!(now > *prev);
+                *prev = now;
+            }
+        }
+        assert_eq!(idle_count.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_low_priority_gets_drain_turns() {
+        let fair_rounds = 3;
+        let mut daemon = OrchDaemon::new(scheduling_config(fair_rounds, 4));
+
+        let high = TestOrch::new("RouteOrch", 0).with_pending();
+        let low = TestOrch::new("FdbOrch", 10).with_pending();
+        let low_count = StdArc::clone(&low.task_count);
+        daemon.register_orch(Box::new(high));
+        daemon.register_orch(Box::new(low));
+
+        for iteration in 1..=40 {
+            daemon.execute_orchs().await;
+            assert!(low_count.load(Ordering::SeqCst) >= iteration);
+        }
+
+        // RouteOrch drains three iterations out of four, FdbOrch the fourth
+        let stats = daemon.stats();
+        assert_eq!(stats.iterations, 40);
+        assert_eq!(stats.orchs["RouteOrch"].held_turns, 10);
+        assert_eq!(stats.orchs["RouteOrch"].executed_batches, 30 * 4 + 10);
+        assert_eq!(stats.orchs["FdbOrch"].executed_batches, 10 * 4 + 30);
+        assert_eq!(stats.orchs["FdbOrch"].held_turns, 30);
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_strict_priority_without_fair_rounds() {
+        let mut daemon = OrchDaemon::new(scheduling_config(0, 4));
+
+        let high = TestOrch::new("RouteOrch", 0).with_pending();
+        let low = TestOrch::new("FdbOrch", 10).with_pending();
+        let low_count = StdArc::clone(&low.task_count);
+        daemon.register_orch(Box::new(high));
+        daemon.register_orch(Box::new(low));
+
+        for _ in 0..20 {
+            daemon.execute_orchs().await;
+        }
+
+        // FdbOrch never drains but still runs its one batch per iteration
+        assert_eq!(low_count.load(Ordering::SeqCst), 20);
+        assert_eq!(daemon.stats().orchs["FdbOrch"].held_turns, 20);
+        assert_eq!(daemon.stats().orchs["RouteOrch"].executed_batches, 80);
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_drain_budget() {
+        let mut daemon = OrchDaemon::new(scheduling_config(8, 2));
+
+        daemon.register_orch(Box::new(BacklogOrch {
+            name: "RouteOrch".to_string(),
+            priority: 0,
+            remaining: 5,
+        }));
+        let low = TestOrch::new("FdbOrch", 10).with_pending();
+        let low_count = StdArc::clone(&low.task_count);
+        daemon.register_orch(Box::new(low));
+
+        // 2 + 2 batches leave work pending, so FdbOrch runs one batch only
+        daemon.execute_orchs().await;
+        daemon.execute_orchs().await;
+        assert_eq!(low_count.load(Ordering::SeqCst), 2);
+
+        // The last batch drains RouteOrch and FdbOrch gets the full budget
+        daemon.execute_orchs().await;
+        assert_eq!(low_count.load(Ordering::SeqCst), 4);
+
+        let stats = daemon.stats();
+        assert_eq!(stats.orchs["RouteOrch"].executed_batches, 5);
+        assert_eq!(stats.orchs["RouteOrch"].held_turns, 0);
+        assert_eq!(stats.orchs["FdbOrch"].held_turns, 2);
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_no_yield_without_lower_pending() {
+        let mut daemon = OrchDaemon::new(scheduling_config(2, 1));
+        daemon.register_orch(Box::new(TestOrch::new("RouteOrch", 0).with_pending()));
+        daemon.register_orch(Box::new(TestOrch::new("FdbOrch", 10)));
+
+        for _ in 0..10 {
+            daemon.execute_orchs().await;
+        }
+
+        let stats = daemon.stats();
+        assert_eq!(stats.orchs["RouteOrch"].executed_batches, 10);
+        assert_eq!(stats.orchs["RouteOrch"].held_turns, 0);
+    }
+
+    // ============================================================================
+    // 10. Metrics Tests
+    // ============================================================================
+
+    /// Orch backed by a real Consumer that drains it in one batch.
+    struct ConsumerOrch {
+        consumer: sonic_orch_common::Consumer,
+        retries: usize,
+    }
+
+    #[async_trait]
+    impl Orch for ConsumerOrch {
+        fn name(&self) -> &str {
+            "RouteOrch"
+        }
+
+        async fn do_task(&mut self) {
+            self.consumer.drain();
+        }
+
+        fn has_pending_tasks(&self) -> bool {
+            !self.consumer.is_empty()
+        }
+
+        fn consumer_metrics(&self) -> Vec<sonic_orch_common::ConsumerMetrics> {
+            vec![self.consumer.metrics()]
+        }
+
+        fn retry_cache_size(&self) -> usize {
+            self.retries
+        }
+    }
+
+    #[tokio::test]
+    async fn test_orchdaemon_records_metrics() {
+        let mut consumer = sonic_orch_common::Consumer::new(ConsumerConfig::new("ROUTE_TABLE"));
+        consumer.add_to_sync(
+            (0..5)
+                .map(|i| KeyOpFieldsValues::set(format!("10.0.{}.0/24", i), vec![]))
+                .collect(),
+        );
+
+        let mut daemon = OrchDaemon::new(OrchDaemonConfig::default());
+        let handle = daemon.metrics();
+        daemon.register_orch(Box::new(ConsumerOrch {
+            consumer,
+            retries: 2,
+        }));
+        daemon.register_orch(Box::new(TestOrch::new("FdbOrch", 10)));
+
+        daemon.execute_orchs().await;
+
+        let snapshot = daemon.dump_metrics();
+        let route = &snapshot.orchs["RouteOrch"];
+        assert_eq!(route.task_count, 1);
+        assert_eq!(route.consumers["ROUTE_TABLE"].pending, 0);
+        assert_eq!(route.consumers["ROUTE_TABLE"].high_watermark, 5);
+        assert_eq!(route.retry_cache_size, 2);
+
+        // Idle Orchs report depths but no task time
+        assert_eq!(snapshot.orchs["FdbOrch"].task_count, 0);
+
+        assert_eq!(handle.snapshot(), snapshot);
+        assert!(serde_json::to_string(&snapshot).is_ok());
+    }
 }
@@ -0,0 +1,83 @@
+//! Warm restart status reporting.
+//!
+//! During warm restart each Orch moves through the states below, which are
+//! published to STATE_DB `WARM_RESTART_TABLE|<orch>` so that warm restart
+//! tooling can track progress per Orch.
+
+use async_trait::async_trait;
+use log::warn;
+use sonic_orch_common::RedisDatabase;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// STATE_DB table holding per-Orch warm restart state.
+pub const WARM_RESTART_TABLE: &str = "WARM_RESTART_TABLE";
+
+/// Warm restart state of an Orch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarmRestartState {
+    /// Warm restart started; the Orch has been frozen.
+    Initialized,
+    /// Saved state has been restored and APPL_DB replayed.
+    Restored,
+    /// Restored state has been reconciled with the replayed intent.
+    Reconciled,
+}
+
+impl WarmRestartState {
+    /// Returns the STATE_DB representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Initialized => "initialized",
+            Self::Restored => "restored",
+            Self::Reconciled => "reconciled",
+        }
+    }
+}
+
+/// Publishes per-Orch warm restart state.
+#[async_trait]
+pub trait WarmRestartStatusWriter: Send + Sync {
+    /// Records the warm restart state of an Orch.
+    async fn write_state(&self, orch_name: &str, state: WarmRestartState);
+}
+
+/// Writes warm restart state to STATE_DB `WARM_RESTART_TABLE`.
+pub struct StateDbWarmRestartWriter {
+    state_db: Arc<RwLock<RedisDatabase>>,
+}
+
+impl StateDbWarmRestartWriter {
+    /// Creates a writer bound to the STATE_DB connection.
+    pub fn new(state_db: Arc<RwLock<RedisDatabase>>) -> Self {
+        Self { state_db }
+    }
+}
+
+#[async_trait]
+impl WarmRestartStatusWriter for StateDbWarmRestartWriter {
+    async fn write_state(&self, orch_name: &str, state: WarmRestartState) {
+        let fvs = vec![("state".to_string(), state.as_str().to_string())];
+        let mut db = self.state_db.write().await;
+        if let Err(e) = db.set_entry(WARM_RESTART_TABLE, orch_name, &fvs).await {
+            warn!(
+                "Failed to write {} warm restart state for {}: {}",
+                state.as_str(),
+                orch_name,
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warm_restart_state_as_str() {
+        assert_eq!(WarmRestartState::Initialized.as_str(), "initialized");
+        assert_eq!(WarmRestartState::Restored.as_str(), "restored");
+        assert_eq!(WarmRestartState::Reconciled.as_str(), "reconciled");
+    }
+}
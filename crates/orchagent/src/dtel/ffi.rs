@@ -1,7 +1,10 @@
 //! FFI exports for DtelOrch.
 
 use super::orch::{DtelOrch, DtelOrchCallbacks, DtelOrchConfig, Result};
-use super::types::{DtelEventType, IntSessionConfig};
+use super::types::{
+    DtelEventType, DtelQueueReportConfig, DtelReportSessionConfig, DtelWatchlistConfig,
+    IntSessionConfig,
+};
 use sonic_sai::types::RawSaiObjectId;
 use std::cell::RefCell;
 
@@ -40,6 +43,73 @@ impl DtelOrchCallbacks for FfiDtelCallbacks {
     fn on_session_created(&self, _session_id: &str, _session_oid: RawSaiObjectId) {}
     fn on_session_removed(&self, _session_id: &str) {}
     fn on_event_state_changed(&self, _event_type: DtelEventType, _enabled: bool) {}
+    fn set_int_session_attribute(
+        &self,
+        _session_oid: RawSaiObjectId,
+        _attr_name: &str,
+        _attr_value: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_report_session(&self, _config: &DtelReportSessionConfig) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn set_report_session_attribute(
+        &self,
+        _session_oid: RawSaiObjectId,
+        _attr_name: &str,
+        _attr_value: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn remove_report_session(&self, _session_oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_queue_report(&self, _config: &DtelQueueReportConfig) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn set_queue_report_attribute(
+        &self,
+        _report_oid: RawSaiObjectId,
+        _attr_name: &str,
+        _attr_value: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn remove_queue_report(&self, _report_oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_watchlist_table(&self) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn remove_watchlist_table(&self, _table_oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn add_watchlist_rule(
+        &self,
+        _table_oid: RawSaiObjectId,
+        _config: &DtelWatchlistConfig,
+        _int_session_oid: Option<RawSaiObjectId>,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn remove_watchlist_rule(
+        &self,
+        _table_oid: RawSaiObjectId,
+        _rule_oid: RawSaiObjectId,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 thread_local! {
@@ -13,6 +13,7 @@
 //! - Owned return values instead of output parameters
 //! - Arc<RwLock<T>> for thread-safe shared state
 //! - Type-safe event enums replacing string lookups
+//! - Deferred removal of report sessions still referenced by watchlist rules
 
 mod ffi;
 mod orch;
@@ -23,4 +24,8 @@ pub use orch::{
     DtelEventEntry, DtelOrch, DtelOrchCallbacks, DtelOrchConfig, DtelOrchError, DtelOrchStats,
     Result, WatchlistEntry,
 };
-pub use types::{DtelEventType, IntSessionConfig, IntSessionEntry};
+pub use types::{
+    tables, DtelEventType, DtelFlowOp, DtelQueueReportConfig, DtelQueueReportEntry,
+    DtelReportSessionConfig, DtelReportSessionEntry, DtelWatchlistConfig, IntSessionConfig,
+    IntSessionEntry,
+};
@@ -4,13 +4,22 @@
 //! - INT (In-band Network Telemetry) sessions for hop-by-hop metadata
 //! - Event reporting for flow state, queue events, and drops
 //! - Watchlist management for selective telemetry
-
-use super::types::{DtelEventType, IntSessionConfig, IntSessionEntry};
+//! - Report sessions towards telemetry collectors
+//! - Per port/queue report thresholds
+//!
+//! Watchlist rules are programmed through AclOrch into the
+//! DTEL_FLOW_WATCHLIST ACL table via [`DtelOrchCallbacks`].
+
+use super::types::{
+    parse_bool, parse_num, DtelEventType, DtelQueueReportConfig, DtelQueueReportEntry,
+    DtelReportSessionConfig, DtelReportSessionEntry, DtelWatchlistConfig, IntSessionConfig,
+    IntSessionEntry,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::HashMap;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// Result type for DtelOrch operations.
@@ -21,6 +30,7 @@ pub enum DtelOrchError {
     SessionExists(String),
     SessionNotFound(String),
     EventNotFound(DtelEventType),
+    WatchlistNotFound(String),
     InvalidConfig(String),
     SaiError(String),
 }
@@ -43,6 +53,30 @@ pub struct DtelOrchConfig {
     pub int_dscp: u8,
 }
 
+impl DtelOrchConfig {
+    /// Parses a DTEL table field-value pair.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<()> {
+        let parsed = match field {
+            "INT_ENDPOINT" => parse_bool(field, value).map(|v| self.int_endpoint = v),
+            "INT_TRANSIT" => parse_bool(field, value).map(|v| self.int_transit = v),
+            "POSTCARD" => parse_bool(field, value).map(|v| self.postcard_enable = v),
+            "DROP_REPORT" => parse_bool(field, value).map(|v| self.drop_report_enable = v),
+            "QUEUE_REPORT" => parse_bool(field, value).map(|v| self.queue_report_enable = v),
+            "INT_L4_DSCP" => parse_num(field, value).map(|v| self.int_dscp = v),
+            "SINK_PORT_LIST" => {
+                self.sink_port_list = value
+                    .split(',')
+                    .filter(|p| !p.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        parsed.map_err(DtelOrchError::InvalidConfig)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DtelOrchStats {
     pub sessions_created: u64,
@@ -84,6 +118,63 @@ pub trait DtelOrchCallbacks: Send + Sync {
 
     /// Notification callback when event is enabled/disabled.
     fn on_event_state_changed(&self, event_type: DtelEventType, enabled: bool);
+
+    /// Set an attribute on a live INT session.
+    fn set_int_session_attribute(
+        &self,
+        session_oid: RawSaiObjectId,
+        attr_name: &str,
+        attr_value: &str,
+    ) -> Result<()>;
+
+    /// Create a report session in SAI.
+    fn create_report_session(&self, config: &DtelReportSessionConfig) -> Result<RawSaiObjectId>;
+
+    /// Set an attribute on a live report session (e.g. DST_IP_LIST).
+    fn set_report_session_attribute(
+        &self,
+        session_oid: RawSaiObjectId,
+        attr_name: &str,
+        attr_value: &str,
+    ) -> Result<()>;
+
+    /// Remove a report session from SAI.
+    fn remove_report_session(&self, session_oid: RawSaiObjectId) -> Result<()>;
+
+    /// Create a queue report for a port/queue in SAI.
+    fn create_queue_report(&self, config: &DtelQueueReportConfig) -> Result<RawSaiObjectId>;
+
+    /// Set an attribute on a live queue report.
+    fn set_queue_report_attribute(
+        &self,
+        report_oid: RawSaiObjectId,
+        attr_name: &str,
+        attr_value: &str,
+    ) -> Result<()>;
+
+    /// Remove a queue report from SAI.
+    fn remove_queue_report(&self, report_oid: RawSaiObjectId) -> Result<()>;
+
+    /// Create the DTEL_FLOW_WATCHLIST ACL table through AclOrch.
+    fn create_watchlist_table(&self) -> Result<RawSaiObjectId>;
+
+    /// Remove the DTEL_FLOW_WATCHLIST ACL table through AclOrch.
+    fn remove_watchlist_table(&self, table_oid: RawSaiObjectId) -> Result<()>;
+
+    /// Add a watchlist rule to the DTEL_FLOW_WATCHLIST ACL table through AclOrch.
+    fn add_watchlist_rule(
+        &self,
+        table_oid: RawSaiObjectId,
+        config: &DtelWatchlistConfig,
+        int_session_oid: Option<RawSaiObjectId>,
+    ) -> Result<RawSaiObjectId>;
+
+    /// Remove a watchlist rule from the DTEL_FLOW_WATCHLIST ACL table.
+    fn remove_watchlist_rule(
+        &self,
+        table_oid: RawSaiObjectId,
+        rule_oid: RawSaiObjectId,
+    ) -> Result<()>;
 }
 
 /// DTel event entry tracking enabled events.
@@ -111,6 +202,14 @@ pub struct DtelOrch<C: DtelOrchCallbacks> {
     events: HashMap<DtelEventType, DtelEventEntry>,
     /// DTel watchlist entries.
     watchlist: HashMap<String, WatchlistEntry>,
+    /// Watchlist configs, for reference tracking and update detection.
+    watchlist_configs: HashMap<String, DtelWatchlistConfig>,
+    /// DTEL_FLOW_WATCHLIST ACL table, created with the first watchlist entry.
+    watchlist_table_oid: Option<RawSaiObjectId>,
+    /// Report sessions indexed by session ID.
+    report_sessions: HashMap<String, DtelReportSessionEntry>,
+    /// Queue reports indexed by `<port>|<queue>`.
+    queue_reports: HashMap<String, DtelQueueReportEntry>,
     /// DTel SAI object (global).
     dtel_oid: Option<RawSaiObjectId>,
 }
@@ -124,6 +223,10 @@ impl<C: DtelOrchCallbacks> DtelOrch<C> {
             sessions: HashMap::new(),
             events: HashMap::new(),
             watchlist: HashMap::new(),
+            watchlist_configs: HashMap::new(),
+            watchlist_table_oid: None,
+            report_sessions: HashMap::new(),
+            queue_reports: HashMap::new(),
             dtel_oid: None,
         }
     }
@@ -136,6 +239,10 @@ impl<C: DtelOrchCallbacks> DtelOrch<C> {
             sessions: HashMap::new(),
             events: HashMap::new(),
             watchlist: HashMap::new(),
+            watchlist_configs: HashMap::new(),
+            watchlist_table_oid: None,
+            report_sessions: HashMap::new(),
+            queue_reports: HashMap::new(),
             dtel_oid: None,
         }
     }
@@ -467,6 +574,18 @@ impl<C: DtelOrchCallbacks> DtelOrch<C> {
                 )?;
             }
 
+            // Update postcard mode
+            if new_config.postcard_enable != self.config.postcard_enable {
+                callbacks.set_dtel_attribute(
+                    "POSTCARD",
+                    if new_config.postcard_enable {
+                        "true"
+                    } else {
+                        "false"
+                    },
+                )?;
+            }
+
             // Update drop report
             if new_config.drop_report_enable != self.config.drop_report_enable {
                 callbacks.set_dtel_attribute(
@@ -501,6 +620,484 @@ impl<C: DtelOrchCallbacks> DtelOrch<C> {
         Ok(())
     }
 
+    /// Handles a SET on the DTEL table.
+    pub fn handle_dtel_set(&mut self, fvs: &[(String, String)]) -> Result<()> {
+        let mut new_config = self.config.clone();
+        for (field, value) in fvs {
+            new_config.parse_field(field, value)?;
+        }
+        self.update_config(new_config)
+    }
+
+    /// Handles a DEL on the DTEL table by restoring the defaults.
+    pub fn handle_dtel_del(&mut self) -> Result<()> {
+        self.update_config(DtelOrchConfig::default())
+    }
+
+    /// Handles a SET on DTEL_INT_SESSION.
+    ///
+    /// An existing session is updated in place so watchlist rules bound to it
+    /// keep working.
+    pub fn handle_int_session_set(
+        &mut self,
+        session_id: &str,
+        fvs: &[(String, String)],
+    ) -> Result<()> {
+        let mut config = IntSessionConfig::new(session_id);
+        for (field, value) in fvs {
+            config
+                .parse_field(field, value)
+                .map_err(DtelOrchError::InvalidConfig)?;
+        }
+
+        let entry = match self.sessions.get(session_id) {
+            Some(entry) => entry.clone(),
+            None => return self.add_session(config),
+        };
+
+        if let Some(ref callbacks) = self.callbacks {
+            if config.max_hop_count != entry.config.max_hop_count {
+                callbacks.set_int_session_attribute(
+                    entry.session_oid,
+                    "MAX_HOP_COUNT",
+                    &config.max_hop_count.to_string(),
+                )?;
+            }
+            if config.collect_switch_id != entry.config.collect_switch_id {
+                callbacks.set_int_session_attribute(
+                    entry.session_oid,
+                    "COLLECT_SWITCH_ID",
+                    &config.collect_switch_id.to_string(),
+                )?;
+            }
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "DtelOrch",
+            format!("update_session: {}", session_id),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(session_id)
+        .with_object_type("int_session")
+        .with_details(serde_json::json!({
+            "session_oid": format!("{:#x}", entry.session_oid),
+            "collect_switch_id": config.collect_switch_id,
+            "max_hop_count": config.max_hop_count,
+        }));
+        audit_log!(record);
+
+        let updated = IntSessionEntry {
+            session_oid: entry.session_oid,
+            config,
+            ref_count: AtomicU64::new(entry.ref_count.load(Ordering::SeqCst)),
+        };
+        self.sessions
+            .insert(session_id.to_string(), Arc::new(updated));
+        Ok(())
+    }
+
+    /// Handles a SET on DTEL_REPORT_SESSION.
+    ///
+    /// Changes to a live session (e.g. a new collector IP) are applied with
+    /// in-place attribute sets. A SET also cancels a deferred removal.
+    pub fn handle_report_session_set(
+        &mut self,
+        session_id: &str,
+        fvs: &[(String, String)],
+    ) -> Result<()> {
+        let mut config = DtelReportSessionConfig::new(session_id);
+        for (field, value) in fvs {
+            config
+                .parse_field(field, value)
+                .map_err(DtelOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(DtelOrchError::InvalidConfig)?;
+
+        if let Some(entry) = self.report_sessions.get_mut(session_id) {
+            let changed = config.changed_fields(&entry.config);
+            if let Some(ref callbacks) = self.callbacks {
+                for (attr, value) in &changed {
+                    callbacks.set_report_session_attribute(entry.session_oid, attr, value)?;
+                }
+            }
+            entry.config = config;
+            let cancelled = std::mem::take(&mut entry.pending_removal);
+
+            let record = AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "DtelOrch",
+                format!("update_report_session: {}", session_id),
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(session_id)
+            .with_object_type("dtel_report_session")
+            .with_details(serde_json::json!({
+                "session_oid": format!("{:#x}", entry.session_oid),
+                "changed": changed.iter().map(|(attr, _)| *attr).collect::<Vec<_>>(),
+                "removal_cancelled": cancelled,
+            }));
+            audit_log!(record);
+            return Ok(());
+        }
+
+        let session_oid = if let Some(ref callbacks) = self.callbacks {
+            callbacks.create_report_session(&config)?
+        } else {
+            0x3000 + self.report_sessions.len() as u64
+        };
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "DtelOrch",
+            format!("create_report_session: {}", session_id),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(session_id)
+        .with_object_type("dtel_report_session")
+        .with_details(serde_json::json!({
+            "session_oid": format!("{:#x}", session_oid),
+            "dst_ip_list": config.dst_ip_list(),
+            "l4_dst_port": config.dst_udp_port,
+        }));
+        audit_log!(record);
+
+        self.report_sessions.insert(
+            session_id.to_string(),
+            DtelReportSessionEntry::new(session_oid, config),
+        );
+        Ok(())
+    }
+
+    /// Handles a DEL on DTEL_REPORT_SESSION.
+    ///
+    /// If watchlist entries still reference the session, removal is deferred
+    /// until the last of them is deleted.
+    pub fn handle_report_session_del(&mut self, session_id: &str) -> Result<()> {
+        let entry = self
+            .report_sessions
+            .get_mut(session_id)
+            .ok_or_else(|| DtelOrchError::SessionNotFound(session_id.to_string()))?;
+
+        if entry.ref_count > 0 {
+            entry.pending_removal = true;
+
+            let record = AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "DtelOrch",
+                format!("remove_report_session_deferred: {}", session_id),
+            )
+            .with_outcome(AuditOutcome::InProgress)
+            .with_object_id(session_id)
+            .with_object_type("dtel_report_session")
+            .with_details(serde_json::json!({
+                "ref_count": entry.ref_count,
+            }));
+            audit_log!(record);
+            return Ok(());
+        }
+
+        self.remove_report_session_now(session_id)
+    }
+
+    fn remove_report_session_now(&mut self, session_id: &str) -> Result<()> {
+        let entry = self
+            .report_sessions
+            .get(session_id)
+            .ok_or_else(|| DtelOrchError::SessionNotFound(session_id.to_string()))?;
+
+        if let Some(ref callbacks) = self.callbacks {
+            callbacks.remove_report_session(entry.session_oid)?;
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "DtelOrch",
+            format!("remove_report_session: {}", session_id),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(session_id)
+        .with_object_type("dtel_report_session")
+        .with_details(serde_json::json!({
+            "session_oid": format!("{:#x}", entry.session_oid),
+        }));
+        audit_log!(record);
+
+        self.report_sessions.remove(session_id);
+        Ok(())
+    }
+
+    /// Drops a watchlist reference to a report session, completing a deferred
+    /// removal when it was the last one.
+    fn release_report_session(&mut self, session_id: &str) -> Result<()> {
+        let Some(entry) = self.report_sessions.get_mut(session_id) else {
+            return Ok(());
+        };
+        entry.ref_count = entry.ref_count.saturating_sub(1);
+        if entry.ref_count == 0 && entry.pending_removal {
+            self.remove_report_session_now(session_id)?;
+        }
+        Ok(())
+    }
+
+    /// Get a report session by ID.
+    pub fn get_report_session(&self, session_id: &str) -> Option<&DtelReportSessionEntry> {
+        self.report_sessions.get(session_id)
+    }
+
+    /// Get report session count (including sessions pending removal).
+    pub fn report_session_count(&self) -> usize {
+        self.report_sessions.len()
+    }
+
+    /// Handles a SET on DTEL_QUEUE_REPORT (key `<port>|<queue>`).
+    pub fn handle_queue_report_set(&mut self, key: &str, fvs: &[(String, String)]) -> Result<()> {
+        let mut config =
+            DtelQueueReportConfig::from_key(key).map_err(DtelOrchError::InvalidConfig)?;
+        for (field, value) in fvs {
+            config
+                .parse_field(field, value)
+                .map_err(DtelOrchError::InvalidConfig)?;
+        }
+
+        if let Some(entry) = self.queue_reports.get_mut(key) {
+            let changed = config.changed_fields(&entry.config);
+            if let Some(ref callbacks) = self.callbacks {
+                for (attr, value) in &changed {
+                    callbacks.set_queue_report_attribute(entry.report_oid, attr, value)?;
+                }
+            }
+            entry.config = config;
+            return Ok(());
+        }
+
+        let report_oid = if let Some(ref callbacks) = self.callbacks {
+            callbacks.create_queue_report(&config)?
+        } else {
+            0x4000 + self.queue_reports.len() as u64
+        };
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "DtelOrch",
+            format!("create_queue_report: {}", key),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("dtel_queue_report")
+        .with_details(serde_json::json!({
+            "report_oid": format!("{:#x}", report_oid),
+            "depth_threshold": config.depth_threshold,
+            "latency_threshold": config.latency_threshold,
+            "breach_quota": config.breach_quota,
+            "report_tail_drop": config.report_tail_drop,
+        }));
+        audit_log!(record);
+
+        self.queue_reports
+            .insert(key.to_string(), DtelQueueReportEntry { report_oid, config });
+        Ok(())
+    }
+
+    /// Handles a DEL on DTEL_QUEUE_REPORT.
+    pub fn handle_queue_report_del(&mut self, key: &str) -> Result<()> {
+        let entry = self.queue_reports.get(key).ok_or_else(|| {
+            DtelOrchError::InvalidConfig(format!("Queue report {} not found", key))
+        })?;
+
+        if let Some(ref callbacks) = self.callbacks {
+            callbacks.remove_queue_report(entry.report_oid)?;
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "DtelOrch",
+            format!("remove_queue_report: {}", key),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("dtel_queue_report")
+        .with_details(serde_json::json!({
+            "report_oid": format!("{:#x}", entry.report_oid),
+        }));
+        audit_log!(record);
+
+        self.queue_reports.remove(key);
+        Ok(())
+    }
+
+    /// Get a queue report by `<port>|<queue>` key.
+    pub fn get_queue_report(&self, key: &str) -> Option<&DtelQueueReportEntry> {
+        self.queue_reports.get(key)
+    }
+
+    /// Get queue report count.
+    pub fn queue_report_count(&self) -> usize {
+        self.queue_reports.len()
+    }
+
+    /// Handles a SET on DTEL_FLOW_WATCHLIST.
+    ///
+    /// Referenced INT and report sessions must exist (and not be pending
+    /// removal); otherwise `SessionNotFound` is returned so the caller can
+    /// retry once the session is configured. An update replaces the ACL rule.
+    pub fn handle_watchlist_set(&mut self, name: &str, fvs: &[(String, String)]) -> Result<()> {
+        let mut config = DtelWatchlistConfig::new(name);
+        for (field, value) in fvs {
+            config
+                .parse_field(field, value)
+                .map_err(DtelOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(DtelOrchError::InvalidConfig)?;
+
+        if self.watchlist_configs.get(name) == Some(&config) {
+            return Ok(());
+        }
+
+        let int_session_oid = match config.int_session {
+            Some(ref id) => Some(
+                self.sessions
+                    .get(id)
+                    .map(|s| s.session_oid)
+                    .ok_or_else(|| DtelOrchError::SessionNotFound(id.clone()))?,
+            ),
+            None => None,
+        };
+        if let Some(ref id) = config.report_session {
+            match self.report_sessions.get(id) {
+                Some(entry) if !entry.pending_removal => {}
+                _ => return Err(DtelOrchError::SessionNotFound(id.clone())),
+            }
+        }
+
+        if self.watchlist.contains_key(name) {
+            self.detach_watchlist_rule(name)?;
+        }
+
+        let table_oid = self.ensure_watchlist_table()?;
+        let rule_oid = if let Some(ref callbacks) = self.callbacks {
+            match callbacks.add_watchlist_rule(table_oid, &config, int_session_oid) {
+                Ok(oid) => oid,
+                Err(e) => {
+                    self.release_watchlist_table_if_unused()?;
+                    return Err(e);
+                }
+            }
+        } else {
+            0x6000 + self.watchlist.len() as u64
+        };
+
+        if let Some(ref id) = config.int_session {
+            self.add_session_ref(id)?;
+        }
+        if let Some(ref id) = config.report_session {
+            if let Some(entry) = self.report_sessions.get_mut(id) {
+                entry.ref_count += 1;
+            }
+        }
+
+        self.add_watchlist_entry(
+            name.to_string(),
+            WatchlistEntry {
+                acl_table_oid: table_oid,
+                acl_rule_oid: rule_oid,
+            },
+        );
+        self.watchlist_configs.insert(name.to_string(), config);
+        Ok(())
+    }
+
+    /// Handles a DEL on DTEL_FLOW_WATCHLIST.
+    ///
+    /// Releases the rule's session references, which may complete a deferred
+    /// report session removal, and removes the ACL table with the last rule.
+    pub fn handle_watchlist_del(&mut self, name: &str) -> Result<()> {
+        self.detach_watchlist_rule(name)?;
+        self.release_watchlist_table_if_unused()
+    }
+
+    /// Removes a watchlist rule and releases its session references.
+    fn detach_watchlist_rule(&mut self, name: &str) -> Result<()> {
+        let entry = self
+            .watchlist
+            .get(name)
+            .cloned()
+            .ok_or_else(|| DtelOrchError::WatchlistNotFound(name.to_string()))?;
+
+        if let Some(ref callbacks) = self.callbacks {
+            callbacks.remove_watchlist_rule(entry.acl_table_oid, entry.acl_rule_oid)?;
+        }
+        self.remove_watchlist_entry(name);
+
+        if let Some(config) = self.watchlist_configs.remove(name) {
+            if let Some(ref id) = config.int_session {
+                self.release_session_ref(id)?;
+            }
+            if let Some(ref id) = config.report_session {
+                self.release_report_session(id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the watchlist ACL table, creating it if needed.
+    fn ensure_watchlist_table(&mut self) -> Result<RawSaiObjectId> {
+        if let Some(oid) = self.watchlist_table_oid {
+            return Ok(oid);
+        }
+
+        let table_oid = if let Some(ref callbacks) = self.callbacks {
+            callbacks.create_watchlist_table()?
+        } else {
+            0x5000
+        };
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "DtelOrch",
+            "create_watchlist_table",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("{:#x}", table_oid))
+        .with_object_type("acl_table");
+        audit_log!(record);
+
+        self.watchlist_table_oid = Some(table_oid);
+        Ok(table_oid)
+    }
+
+    /// Removes the watchlist ACL table once it has no rules.
+    fn release_watchlist_table_if_unused(&mut self) -> Result<()> {
+        if !self.watchlist.is_empty() {
+            return Ok(());
+        }
+        let Some(table_oid) = self.watchlist_table_oid else {
+            return Ok(());
+        };
+
+        if let Some(ref callbacks) = self.callbacks {
+            callbacks.remove_watchlist_table(table_oid)?;
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "DtelOrch",
+            "remove_watchlist_table",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("{:#x}", table_oid))
+        .with_object_type("acl_table");
+        audit_log!(record);
+
+        self.watchlist_table_oid = None;
+        Ok(())
+    }
+
+    /// Get the DTEL_FLOW_WATCHLIST ACL table OID, if created.
+    pub fn watchlist_table_oid(&self) -> Option<RawSaiObjectId> {
+        self.watchlist_table_oid
+    }
+
     /// Get session count.
     pub fn session_count(&self) -> usize {
         self.sessions.len()
@@ -566,6 +1163,76 @@ mod tests {
         fn on_session_created(&self, _session_id: &str, _session_oid: RawSaiObjectId) {}
         fn on_session_removed(&self, _session_id: &str) {}
         fn on_event_state_changed(&self, _event_type: DtelEventType, _enabled: bool) {}
+        fn set_int_session_attribute(
+            &self,
+            _session_oid: RawSaiObjectId,
+            _attr_name: &str,
+            _attr_value: &str,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_report_session(
+            &self,
+            _config: &DtelReportSessionConfig,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0)
+        }
+
+        fn set_report_session_attribute(
+            &self,
+            _session_oid: RawSaiObjectId,
+            _attr_name: &str,
+            _attr_value: &str,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn remove_report_session(&self, _session_oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_queue_report(&self, _config: &DtelQueueReportConfig) -> Result<RawSaiObjectId> {
+            Ok(0)
+        }
+
+        fn set_queue_report_attribute(
+            &self,
+            _report_oid: RawSaiObjectId,
+            _attr_name: &str,
+            _attr_value: &str,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn remove_queue_report(&self, _report_oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_watchlist_table(&self) -> Result<RawSaiObjectId> {
+            Ok(0)
+        }
+
+        fn remove_watchlist_table(&self, _table_oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn add_watchlist_rule(
+            &self,
+            _table_oid: RawSaiObjectId,
+            _config: &DtelWatchlistConfig,
+            _int_session_oid: Option<RawSaiObjectId>,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0)
+        }
+
+        fn remove_watchlist_rule(
+            &self,
+            _table_oid: RawSaiObjectId,
+            _rule_oid: RawSaiObjectId,
+        ) -> Result<()> {
+            Ok(())
+        }
     }
 
     #[test]
@@ -1314,4 +1981,464 @@ mod tests {
         orch.add_session(config).unwrap();
         assert_eq!(orch.session_count(), 1);
     }
+
+    // ===== Table-driven configuration tests =====
+
+    /// Callbacks that record every SAI/ACL call.
+    #[derive(Default)]
+    struct RecordingDtelCallbacks {
+        ops: std::sync::Mutex<Vec<String>>,
+        next_oid: std::sync::atomic::AtomicU64,
+    }
+
+    impl RecordingDtelCallbacks {
+        fn record(&self, op: String) -> RawSaiObjectId {
+            self.ops.lock().unwrap().push(op);
+            0x100 + self.next_oid.fetch_add(1, Ordering::SeqCst)
+        }
+
+        fn ops(&self) -> Vec<String> {
+            self.ops.lock().unwrap().clone()
+        }
+
+        fn count(&self, prefix: &str) -> usize {
+            self.ops().iter().filter(|o| o.starts_with(prefix)).count()
+        }
+    }
+
+    impl DtelOrchCallbacks for RecordingDtelCallbacks {
+        fn create_int_session(&self, config: &IntSessionConfig) -> Result<RawSaiObjectId> {
+            Ok(self.record(format!("create_int_session:{}", config.session_id)))
+        }
+
+        fn remove_int_session(&self, session_oid: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_int_session:{:#x}", session_oid));
+            Ok(())
+        }
+
+        fn enable_event(&self, event_type: DtelEventType) -> Result<RawSaiObjectId> {
+            Ok(self.record(format!("enable_event:{:?}", event_type)))
+        }
+
+        fn disable_event(&self, event_oid: RawSaiObjectId) -> Result<()> {
+            self.record(format!("disable_event:{:#x}", event_oid));
+            Ok(())
+        }
+
+        fn set_dtel_attribute(&self, attr_name: &str, attr_value: &str) -> Result<()> {
+            self.record(format!("set_dtel_attribute:{}={}", attr_name, attr_value));
+            Ok(())
+        }
+
+        fn write_state_db(&self, _session_id: &str, _state: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn remove_state_db(&self, _session_id: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn on_session_created(&self, _session_id: &str, _session_oid: RawSaiObjectId) {}
+        fn on_session_removed(&self, _session_id: &str) {}
+        fn on_event_state_changed(&self, _event_type: DtelEventType, _enabled: bool) {}
+
+        fn set_int_session_attribute(
+            &self,
+            session_oid: RawSaiObjectId,
+            attr_name: &str,
+            attr_value: &str,
+        ) -> Result<()> {
+            self.record(format!(
+                "set_int_session_attribute:{:#x}:{}={}",
+                session_oid, attr_name, attr_value
+            ));
+            Ok(())
+        }
+
+        fn create_report_session(
+            &self,
+            config: &DtelReportSessionConfig,
+        ) -> Result<RawSaiObjectId> {
+            Ok(self.record(format!("create_report_session:{}", config.session_id)))
+        }
+
+        fn set_report_session_attribute(
+            &self,
+            session_oid: RawSaiObjectId,
+            attr_name: &str,
+            attr_value: &str,
+        ) -> Result<()> {
+            self.record(format!(
+                "set_report_session_attribute:{:#x}:{}={}",
+                session_oid, attr_name, attr_value
+            ));
+            Ok(())
+        }
+
+        fn remove_report_session(&self, session_oid: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_report_session:{:#x}", session_oid));
+            Ok(())
+        }
+
+        fn create_queue_report(&self, config: &DtelQueueReportConfig) -> Result<RawSaiObjectId> {
+            Ok(self.record(format!("create_queue_report:{}", config.key())))
+        }
+
+        fn set_queue_report_attribute(
+            &self,
+            report_oid: RawSaiObjectId,
+            attr_name: &str,
+            attr_value: &str,
+        ) -> Result<()> {
+            self.record(format!(
+                "set_queue_report_attribute:{:#x}:{}={}",
+                report_oid, attr_name, attr_value
+            ));
+            Ok(())
+        }
+
+        fn remove_queue_report(&self, report_oid: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_queue_report:{:#x}", report_oid));
+            Ok(())
+        }
+
+        fn create_watchlist_table(&self) -> Result<RawSaiObjectId> {
+            Ok(self.record("create_watchlist_table".to_string()))
+        }
+
+        fn remove_watchlist_table(&self, table_oid: RawSaiObjectId) -> Result<()> {
+            self.record(format!("remove_watchlist_table:{:#x}", table_oid));
+            Ok(())
+        }
+
+        fn add_watchlist_rule(
+            &self,
+            _table_oid: RawSaiObjectId,
+            config: &DtelWatchlistConfig,
+            int_session_oid: Option<RawSaiObjectId>,
+        ) -> Result<RawSaiObjectId> {
+            Ok(self.record(format!(
+                "add_watchlist_rule:{}:{:?}",
+                config.name, int_session_oid
+            )))
+        }
+
+        fn remove_watchlist_rule(
+            &self,
+            _table_oid: RawSaiObjectId,
+            rule_oid: RawSaiObjectId,
+        ) -> Result<()> {
+            self.record(format!("remove_watchlist_rule:{:#x}", rule_oid));
+            Ok(())
+        }
+    }
+
+    fn fvs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn recording_orch() -> (
+        DtelOrch<RecordingDtelCallbacks>,
+        Arc<RecordingDtelCallbacks>,
+    ) {
+        let callbacks = Arc::new(RecordingDtelCallbacks::default());
+        let orch = DtelOrch::with_callbacks(DtelOrchConfig::default(), callbacks.clone());
+        (orch, callbacks)
+    }
+
+    fn report_session_fvs(collectors: &str) -> Vec<(String, String)> {
+        fvs(&[
+            ("SRC_IP", "10.0.0.1"),
+            ("DST_IP_LIST", collectors),
+            ("L4_DST_PORT", "32766"),
+        ])
+    }
+
+    fn watchlist_fvs(report_session: &str) -> Vec<(String, String)> {
+        fvs(&[
+            ("DST_IP", "10.1.0.0/16"),
+            ("FLOW_OP", "INT"),
+            ("INT_SESSION", "int1"),
+            ("REPORT_SESSION", report_session),
+        ])
+    }
+
+    #[test]
+    fn test_dtel_table_drop_report_toggle() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_dtel_set(&fvs(&[("DROP_REPORT", "TRUE"), ("INT_L4_DSCP", "8")]))
+            .unwrap();
+        assert!(orch.config().drop_report_enable);
+        assert_eq!(orch.config().int_dscp, 8);
+
+        // Re-applying the same value is a no-op
+        orch.handle_dtel_set(&fvs(&[("DROP_REPORT", "TRUE")]))
+            .unwrap();
+        orch.handle_dtel_set(&fvs(&[("DROP_REPORT", "FALSE")]))
+            .unwrap();
+        assert!(!orch.config().drop_report_enable);
+
+        assert_eq!(
+            callbacks.ops(),
+            vec![
+                "set_dtel_attribute:DROP_REPORT=true",
+                "set_dtel_attribute:INT_DSCP=8",
+                "set_dtel_attribute:DROP_REPORT=false",
+            ]
+        );
+
+        let result = orch.handle_dtel_set(&fvs(&[("QUEUE_REPORT", "sometimes")]));
+        assert!(matches!(result, Err(DtelOrchError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_int_session_hop_limit_updated_in_place() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_int_session_set("int1", &fvs(&[("MAX_HOP_COUNT", "8")]))
+            .unwrap();
+        let oid = orch.get_session("int1").unwrap().session_oid;
+        orch.add_session_ref("int1").unwrap();
+
+        orch.handle_int_session_set("int1", &fvs(&[("MAX_HOP_COUNT", "16")]))
+            .unwrap();
+
+        let session = orch.get_session("int1").unwrap();
+        assert_eq!(session.session_oid, oid);
+        assert_eq!(session.config.max_hop_count, 16);
+        assert_eq!(session.ref_count.load(Ordering::SeqCst), 2);
+        assert_eq!(callbacks.count("create_int_session"), 1);
+        assert_eq!(
+            callbacks.count(&format!(
+                "set_int_session_attribute:{:#x}:MAX_HOP_COUNT=16",
+                oid
+            )),
+            1
+        );
+        assert!(orch
+            .handle_int_session_set("int1", &fvs(&[("MAX_HOP_COUNT", "0")]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_report_session_collector_update_in_place() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_report_session_set("rs1", &report_session_fvs("20.0.0.1"))
+            .unwrap();
+        let oid = orch.get_report_session("rs1").unwrap().session_oid;
+
+        orch.handle_report_session_set("rs1", &report_session_fvs("20.0.0.1;20.0.0.2"))
+            .unwrap();
+
+        assert_eq!(orch.get_report_session("rs1").unwrap().session_oid, oid);
+        assert_eq!(
+            orch.get_report_session("rs1").unwrap().config.dst_ip_list(),
+            "20.0.0.1;20.0.0.2"
+        );
+        assert_eq!(
+            callbacks.ops(),
+            vec![
+                "create_report_session:rs1".to_string(),
+                format!(
+                    "set_report_session_attribute:{:#x}:DST_IP_LIST=20.0.0.1;20.0.0.2",
+                    oid
+                ),
+            ]
+        );
+
+        // A session without collectors is rejected
+        let result = orch.handle_report_session_set("rs2", &fvs(&[("L4_DST_PORT", "9")]));
+        assert!(matches!(result, Err(DtelOrchError::InvalidConfig(_))));
+        assert_eq!(orch.report_session_count(), 1);
+    }
+
+    #[test]
+    fn test_report_session_removal_deferred_while_referenced() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_int_session_set("int1", &[]).unwrap();
+        orch.handle_report_session_set("rs1", &report_session_fvs("20.0.0.1"))
+            .unwrap();
+        let rs_oid = orch.get_report_session("rs1").unwrap().session_oid;
+
+        orch.handle_watchlist_set("flow1", &watchlist_fvs("rs1"))
+            .unwrap();
+        orch.handle_watchlist_set("flow2", &watchlist_fvs("rs1"))
+            .unwrap();
+        assert_eq!(orch.get_report_session("rs1").unwrap().ref_count, 2);
+
+        // Delete is deferred, the session stays programmed
+        orch.handle_report_session_del("rs1").unwrap();
+        let entry = orch.get_report_session("rs1").unwrap();
+        assert!(entry.pending_removal);
+        assert_eq!(callbacks.count("remove_report_session"), 0);
+
+        // New references to a session being removed are refused
+        let result = orch.handle_watchlist_set("flow3", &watchlist_fvs("rs1"));
+        assert!(matches!(result, Err(DtelOrchError::SessionNotFound(_))));
+
+        orch.handle_watchlist_del("flow1").unwrap();
+        assert!(orch.get_report_session("rs1").is_some());
+        assert_eq!(callbacks.count("remove_report_session"), 0);
+
+        orch.handle_watchlist_del("flow2").unwrap();
+        assert!(orch.get_report_session("rs1").is_none());
+        assert_eq!(
+            callbacks.count(&format!("remove_report_session:{:#x}", rs_oid)),
+            1
+        );
+        assert_eq!(orch.watchlist_count(), 0);
+        assert_eq!(orch.watchlist_table_oid(), None);
+    }
+
+    #[test]
+    fn test_report_session_set_cancels_deferred_removal() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_int_session_set("int1", &[]).unwrap();
+        orch.handle_report_session_set("rs1", &report_session_fvs("20.0.0.1"))
+            .unwrap();
+        orch.handle_watchlist_set("flow1", &watchlist_fvs("rs1"))
+            .unwrap();
+
+        orch.handle_report_session_del("rs1").unwrap();
+        orch.handle_report_session_set("rs1", &report_session_fvs("20.0.0.1"))
+            .unwrap();
+        assert!(!orch.get_report_session("rs1").unwrap().pending_removal);
+
+        orch.handle_watchlist_del("flow1").unwrap();
+        assert!(orch.get_report_session("rs1").is_some());
+        assert_eq!(callbacks.count("remove_report_session"), 0);
+
+        // Unreferenced sessions are removed immediately
+        orch.handle_report_session_del("rs1").unwrap();
+        assert_eq!(orch.report_session_count(), 0);
+        assert_eq!(callbacks.count("remove_report_session"), 1);
+    }
+
+    #[test]
+    fn test_watchlist_wired_through_acl_table() {
+        let (mut orch, callbacks) = recording_orch();
+
+        // INT session missing: caller should retry later
+        let result = orch.handle_watchlist_set(
+            "flow1",
+            &fvs(&[
+                ("DST_IP", "10.1.0.0/16"),
+                ("FLOW_OP", "INT"),
+                ("INT_SESSION", "int1"),
+            ]),
+        );
+        assert!(matches!(result, Err(DtelOrchError::SessionNotFound(_))));
+        assert!(callbacks.ops().is_empty());
+
+        orch.handle_int_session_set("int1", &[]).unwrap();
+        let int_oid = orch.get_session("int1").unwrap().session_oid;
+        let flow = fvs(&[
+            ("DST_IP", "10.1.0.0/16"),
+            ("FLOW_OP", "INT"),
+            ("INT_SESSION", "int1"),
+        ]);
+        orch.handle_watchlist_set("flow1", &flow).unwrap();
+        orch.handle_watchlist_set("flow1", &flow).unwrap();
+        orch.handle_watchlist_set(
+            "flow2",
+            &fvs(&[("SRC_IP", "10.2.0.1"), ("FLOW_OP", "POSTCARD")]),
+        )
+        .unwrap();
+
+        assert_eq!(callbacks.count("create_watchlist_table"), 1);
+        assert_eq!(
+            callbacks.count(&format!("add_watchlist_rule:flow1:Some({})", int_oid)),
+            1
+        );
+        assert_eq!(callbacks.count("add_watchlist_rule:flow2:None"), 1);
+        assert_eq!(orch.watchlist_count(), 2);
+        assert_eq!(
+            orch.get_session("int1")
+                .unwrap()
+                .ref_count
+                .load(Ordering::SeqCst),
+            2
+        );
+
+        // INT session cannot go away while a rule uses it
+        assert!(orch.remove_session("int1").is_err());
+
+        // Updating a rule replaces it in the same table
+        orch.handle_watchlist_set(
+            "flow1",
+            &fvs(&[
+                ("DST_IP", "10.3.0.0/16"),
+                ("FLOW_OP", "INT"),
+                ("INT_SESSION", "int1"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(callbacks.count("remove_watchlist_rule"), 1);
+        assert_eq!(callbacks.count("create_watchlist_table"), 1);
+
+        orch.handle_watchlist_del("flow1").unwrap();
+        orch.handle_watchlist_del("flow2").unwrap();
+        assert_eq!(callbacks.count("remove_watchlist_table"), 1);
+        assert!(matches!(
+            orch.handle_watchlist_del("flow1"),
+            Err(DtelOrchError::WatchlistNotFound(_))
+        ));
+        orch.remove_session("int1").unwrap();
+    }
+
+    #[test]
+    fn test_queue_report_thresholds() {
+        let (mut orch, callbacks) = recording_orch();
+
+        orch.handle_queue_report_set(
+            "Ethernet0|3",
+            &fvs(&[
+                ("QUEUE_DEPTH_THRESHOLD", "1000"),
+                ("QUEUE_LATENCY_THRESHOLD", "500"),
+            ]),
+        )
+        .unwrap();
+        orch.handle_queue_report_set("Ethernet4|3", &fvs(&[("REPORT_TAIL_DROP", "true")]))
+            .unwrap();
+        let oid = orch.get_queue_report("Ethernet0|3").unwrap().report_oid;
+
+        orch.handle_queue_report_set(
+            "Ethernet0|3",
+            &fvs(&[
+                ("QUEUE_DEPTH_THRESHOLD", "2000"),
+                ("QUEUE_LATENCY_THRESHOLD", "500"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(orch.queue_report_count(), 2);
+        assert_eq!(
+            orch.get_queue_report("Ethernet0|3")
+                .unwrap()
+                .config
+                .depth_threshold,
+            2000
+        );
+        assert_eq!(callbacks.count("create_queue_report"), 2);
+        assert_eq!(
+            callbacks.count(&format!(
+                "set_queue_report_attribute:{:#x}:QUEUE_DEPTH_THRESHOLD=2000",
+                oid
+            )),
+            1
+        );
+        assert_eq!(callbacks.count("set_queue_report_attribute"), 1);
+
+        orch.handle_queue_report_del("Ethernet0|3").unwrap();
+        assert_eq!(orch.queue_report_count(), 1);
+        assert!(orch.handle_queue_report_del("Ethernet0|3").is_err());
+        assert!(orch.handle_queue_report_set("Ethernet0", &[]).is_err());
+    }
 }
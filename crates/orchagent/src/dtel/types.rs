@@ -1,8 +1,33 @@
 //! DTel (Data Plane Telemetry) types and structures.
 
 use sonic_sai::types::RawSaiObjectId;
+use std::collections::BTreeMap;
+use std::net::IpAddr;
 use std::sync::atomic::AtomicU64;
 
+/// CONFIG_DB table names consumed by DtelOrch.
+pub mod tables {
+    pub const DTEL: &str = "DTEL";
+    pub const DTEL_INT_SESSION: &str = "DTEL_INT_SESSION";
+    pub const DTEL_REPORT_SESSION: &str = "DTEL_REPORT_SESSION";
+    pub const DTEL_QUEUE_REPORT: &str = "DTEL_QUEUE_REPORT";
+    pub const DTEL_FLOW_WATCHLIST: &str = "DTEL_FLOW_WATCHLIST";
+}
+
+pub(super) fn parse_bool(field: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "TRUE" | "enabled" | "1" => Ok(true),
+        "false" | "FALSE" | "disabled" | "0" => Ok(false),
+        _ => Err(format!("Invalid {}: {}", field, value)),
+    }
+}
+
+pub(super) fn parse_num<T: std::str::FromStr>(field: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", field, value))
+}
+
 /// DTel event types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DtelEventType {
@@ -30,6 +55,34 @@ pub struct IntSessionEntry {
     pub ref_count: AtomicU64,
 }
 
+impl IntSessionConfig {
+    /// Creates a session config with default collection settings.
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            collect_switch_id: false,
+            max_hop_count: 8,
+        }
+    }
+
+    /// Parses a DTEL_INT_SESSION field-value pair.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "MAX_HOP_COUNT" => {
+                self.max_hop_count = parse_num(field, value)?;
+                if self.max_hop_count == 0 {
+                    return Err(format!("Invalid {}: {}", field, value));
+                }
+            }
+            "COLLECT_SWITCH_ID" => self.collect_switch_id = parse_bool(field, value)?,
+            _ => {
+                // Ignore unknown fields
+            }
+        }
+        Ok(())
+    }
+}
+
 impl IntSessionEntry {
     pub fn new(session_oid: RawSaiObjectId, config: IntSessionConfig) -> Self {
         Self {
@@ -40,6 +93,320 @@ impl IntSessionEntry {
     }
 }
 
+/// Telemetry report session configuration (DTEL_REPORT_SESSION).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtelReportSessionConfig {
+    pub session_id: String,
+    pub src_ip: Option<IpAddr>,
+    /// Collector addresses reports are sent to.
+    pub dst_ips: Vec<IpAddr>,
+    pub dst_udp_port: u16,
+    pub vrf: Option<String>,
+    pub truncate_size: Option<u16>,
+}
+
+impl DtelReportSessionConfig {
+    /// Creates an empty report session config.
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            src_ip: None,
+            dst_ips: Vec::new(),
+            dst_udp_port: 0,
+            vrf: None,
+            truncate_size: None,
+        }
+    }
+
+    /// Parses a DTEL_REPORT_SESSION field-value pair.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "SRC_IP" => self.src_ip = Some(parse_num(field, value)?),
+            "DST_IP_LIST" => {
+                self.dst_ips = value
+                    .split(';')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| parse_num(field, s))
+                    .collect::<Result<_, _>>()?;
+            }
+            "L4_DST_PORT" => self.dst_udp_port = parse_num(field, value)?,
+            "VRF" => self.vrf = Some(value.to_string()),
+            "TRUNCATE_SIZE" => self.truncate_size = Some(parse_num(field, value)?),
+            _ => {
+                // Ignore unknown fields
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the session has at least one collector and a UDP port.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.dst_ips.is_empty() {
+            return Err(format!(
+                "Report session {} has no collector",
+                self.session_id
+            ));
+        }
+        if self.dst_udp_port == 0 {
+            return Err(format!(
+                "Report session {} has no L4_DST_PORT",
+                self.session_id
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the collector list in CONFIG_DB format.
+    pub fn dst_ip_list(&self) -> String {
+        self.dst_ips
+            .iter()
+            .map(|ip| ip.to_string())
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    /// Returns the SAI attributes that differ from `other`, in CONFIG_DB field names.
+    pub fn changed_fields(&self, other: &Self) -> Vec<(&'static str, String)> {
+        let mut changed = Vec::new();
+        if self.src_ip != other.src_ip {
+            changed.push((
+                "SRC_IP",
+                self.src_ip.map(|ip| ip.to_string()).unwrap_or_default(),
+            ));
+        }
+        if self.dst_ips != other.dst_ips {
+            changed.push(("DST_IP_LIST", self.dst_ip_list()));
+        }
+        if self.dst_udp_port != other.dst_udp_port {
+            changed.push(("L4_DST_PORT", self.dst_udp_port.to_string()));
+        }
+        if self.vrf != other.vrf {
+            changed.push(("VRF", self.vrf.clone().unwrap_or_default()));
+        }
+        if self.truncate_size != other.truncate_size {
+            changed.push((
+                "TRUNCATE_SIZE",
+                self.truncate_size.unwrap_or_default().to_string(),
+            ));
+        }
+        changed
+    }
+}
+
+/// Report session entry.
+///
+/// Report sessions are referenced by watchlist entries; a delete that arrives
+/// while references remain is recorded in `pending_removal` and carried out
+/// when the last reference is released.
+#[derive(Debug, Clone)]
+pub struct DtelReportSessionEntry {
+    pub session_oid: RawSaiObjectId,
+    pub config: DtelReportSessionConfig,
+    pub ref_count: u64,
+    pub pending_removal: bool,
+}
+
+impl DtelReportSessionEntry {
+    pub fn new(session_oid: RawSaiObjectId, config: DtelReportSessionConfig) -> Self {
+        Self {
+            session_oid,
+            config,
+            ref_count: 0,
+            pending_removal: false,
+        }
+    }
+}
+
+/// Per port/queue report thresholds (DTEL_QUEUE_REPORT, key `<port>|<queue>`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtelQueueReportConfig {
+    pub port: String,
+    pub queue: u32,
+    pub depth_threshold: u32,
+    pub latency_threshold: u32,
+    pub breach_quota: u32,
+    pub report_tail_drop: bool,
+}
+
+impl DtelQueueReportConfig {
+    /// Creates a queue report config from a `<port>|<queue>` key.
+    pub fn from_key(key: &str) -> Result<Self, String> {
+        let (port, queue) = key
+            .split_once('|')
+            .ok_or_else(|| format!("Invalid queue report key: {}", key))?;
+        if port.is_empty() {
+            return Err(format!("Invalid queue report key: {}", key));
+        }
+        Ok(Self {
+            port: port.to_string(),
+            queue: parse_num("queue", queue)?,
+            depth_threshold: 0,
+            latency_threshold: 0,
+            breach_quota: 0,
+            report_tail_drop: false,
+        })
+    }
+
+    /// Returns the `<port>|<queue>` key.
+    pub fn key(&self) -> String {
+        format!("{}|{}", self.port, self.queue)
+    }
+
+    /// Parses a DTEL_QUEUE_REPORT field-value pair.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "QUEUE_DEPTH_THRESHOLD" => self.depth_threshold = parse_num(field, value)?,
+            "QUEUE_LATENCY_THRESHOLD" => self.latency_threshold = parse_num(field, value)?,
+            "THRESHOLD_BREACH_QUOTA" => self.breach_quota = parse_num(field, value)?,
+            "REPORT_TAIL_DROP" => self.report_tail_drop = parse_bool(field, value)?,
+            _ => {
+                // Ignore unknown fields
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the SAI attributes that differ from `other`, in CONFIG_DB field names.
+    pub fn changed_fields(&self, other: &Self) -> Vec<(&'static str, String)> {
+        let mut changed = Vec::new();
+        if self.depth_threshold != other.depth_threshold {
+            changed.push(("QUEUE_DEPTH_THRESHOLD", self.depth_threshold.to_string()));
+        }
+        if self.latency_threshold != other.latency_threshold {
+            changed.push((
+                "QUEUE_LATENCY_THRESHOLD",
+                self.latency_threshold.to_string(),
+            ));
+        }
+        if self.breach_quota != other.breach_quota {
+            changed.push(("THRESHOLD_BREACH_QUOTA", self.breach_quota.to_string()));
+        }
+        if self.report_tail_drop != other.report_tail_drop {
+            changed.push(("REPORT_TAIL_DROP", self.report_tail_drop.to_string()));
+        }
+        changed
+    }
+}
+
+/// Queue report entry.
+#[derive(Debug, Clone)]
+pub struct DtelQueueReportEntry {
+    pub report_oid: RawSaiObjectId,
+    pub config: DtelQueueReportConfig,
+}
+
+/// Flow operation applied to watchlist matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DtelFlowOp {
+    #[default]
+    Int,
+    Postcard,
+}
+
+impl std::str::FromStr for DtelFlowOp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "INT" => Ok(Self::Int),
+            "POSTCARD" => Ok(Self::Postcard),
+            _ => Err(format!("Invalid FLOW_OP: {}", s)),
+        }
+    }
+}
+
+/// Flow watchlist rule (DTEL_FLOW_WATCHLIST).
+///
+/// Match fields are kept in CONFIG_DB form and handed to AclOrch, which
+/// programs the rule into the DTEL_FLOW_WATCHLIST ACL table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtelWatchlistConfig {
+    pub name: String,
+    pub priority: u32,
+    pub matches: BTreeMap<String, String>,
+    pub flow_op: DtelFlowOp,
+    pub int_session: Option<String>,
+    pub report_session: Option<String>,
+    pub report_all_packets: bool,
+    pub drop_report_enable: bool,
+    pub tail_drop_report_enable: bool,
+    pub flow_sample_percent: u8,
+}
+
+impl DtelWatchlistConfig {
+    /// ACL match fields accepted in a watchlist entry.
+    pub const MATCH_FIELDS: &'static [&'static str] = &[
+        "ETHER_TYPE",
+        "SRC_IP",
+        "DST_IP",
+        "SRC_IPV6",
+        "DST_IPV6",
+        "IP_PROTOCOL",
+        "L4_SRC_PORT",
+        "L4_DST_PORT",
+        "TUNNEL_VNI",
+        "INNER_ETHER_TYPE",
+        "INNER_SRC_IP",
+        "INNER_DST_IP",
+    ];
+
+    /// Creates a watchlist entry that samples every packet.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            priority: 0,
+            matches: BTreeMap::new(),
+            flow_op: DtelFlowOp::default(),
+            int_session: None,
+            report_session: None,
+            report_all_packets: false,
+            drop_report_enable: false,
+            tail_drop_report_enable: false,
+            flow_sample_percent: 100,
+        }
+    }
+
+    /// Parses a DTEL_FLOW_WATCHLIST field-value pair.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "PRIORITY" => self.priority = parse_num(field, value)?,
+            "FLOW_OP" => self.flow_op = value.parse()?,
+            "INT_SESSION" => self.int_session = Some(value.to_string()),
+            "REPORT_SESSION" => self.report_session = Some(value.to_string()),
+            "REPORT_ALL_PACKETS" => self.report_all_packets = parse_bool(field, value)?,
+            "DROP_REPORT_ENABLE" => self.drop_report_enable = parse_bool(field, value)?,
+            "TAIL_DROP_REPORT_ENABLE" => self.tail_drop_report_enable = parse_bool(field, value)?,
+            "FLOW_SAMPLE_PERCENT" => {
+                self.flow_sample_percent = parse_num(field, value)?;
+                if self.flow_sample_percent > 100 {
+                    return Err(format!("Invalid {}: {}", field, value));
+                }
+            }
+            f if Self::MATCH_FIELDS.contains(&f) => {
+                self.matches.insert(f.to_string(), value.to_string());
+            }
+            _ => {
+                // Ignore unknown fields
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the entry matches something and has the session its flow op needs.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.matches.is_empty() {
+            return Err(format!("Watchlist {} has no match fields", self.name));
+        }
+        if self.flow_op == DtelFlowOp::Int && self.int_session.is_none() {
+            return Err(format!(
+                "Watchlist {} uses FLOW_OP INT without INT_SESSION",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +415,80 @@ mod tests {
     fn test_event_type() {
         assert_ne!(DtelEventType::FlowState, DtelEventType::DropReport);
     }
+
+    #[test]
+    fn test_int_session_parse_field() {
+        let mut config = IntSessionConfig::new("int1");
+        config.parse_field("MAX_HOP_COUNT", "16").unwrap();
+        config.parse_field("COLLECT_SWITCH_ID", "true").unwrap();
+        assert_eq!(config.max_hop_count, 16);
+        assert!(config.collect_switch_id);
+        assert!(config.parse_field("MAX_HOP_COUNT", "0").is_err());
+        assert!(config.parse_field("COLLECT_SWITCH_ID", "maybe").is_err());
+    }
+
+    #[test]
+    fn test_report_session_parse_and_diff() {
+        let mut config = DtelReportSessionConfig::new("rs1");
+        assert!(config.validate().is_err());
+
+        config.parse_field("SRC_IP", "10.0.0.1").unwrap();
+        config
+            .parse_field("DST_IP_LIST", "20.0.0.1;20.0.0.2")
+            .unwrap();
+        config.parse_field("L4_DST_PORT", "32766").unwrap();
+        config.validate().unwrap();
+        assert_eq!(config.dst_ip_list(), "20.0.0.1;20.0.0.2");
+        assert!(config.parse_field("DST_IP_LIST", "20.0.0.1;bogus").is_err());
+
+        let mut updated = config.clone();
+        updated.parse_field("DST_IP_LIST", "30.0.0.1").unwrap();
+        assert_eq!(
+            updated.changed_fields(&config),
+            vec![("DST_IP_LIST", "30.0.0.1".to_string())]
+        );
+        assert!(config.changed_fields(&config).is_empty());
+    }
+
+    #[test]
+    fn test_queue_report_key_and_fields() {
+        let mut config = DtelQueueReportConfig::from_key("Ethernet0|3").unwrap();
+        assert_eq!(config.port, "Ethernet0");
+        assert_eq!(config.queue, 3);
+        assert_eq!(config.key(), "Ethernet0|3");
+        assert!(DtelQueueReportConfig::from_key("Ethernet0").is_err());
+        assert!(DtelQueueReportConfig::from_key("Ethernet0|x").is_err());
+
+        let base = config.clone();
+        config.parse_field("QUEUE_DEPTH_THRESHOLD", "1000").unwrap();
+        config.parse_field("REPORT_TAIL_DROP", "true").unwrap();
+        assert_eq!(
+            config.changed_fields(&base),
+            vec![
+                ("QUEUE_DEPTH_THRESHOLD", "1000".to_string()),
+                ("REPORT_TAIL_DROP", "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_watchlist_parse_and_validate() {
+        let mut config = DtelWatchlistConfig::new("flow1");
+        config.parse_field("DST_IP", "10.1.0.0/16").unwrap();
+        config.parse_field("PRIORITY", "10").unwrap();
+        config.parse_field("FLOW_OP", "INT").unwrap();
+        assert!(config.validate().is_err());
+
+        config.parse_field("INT_SESSION", "int1").unwrap();
+        config.validate().unwrap();
+        assert_eq!(config.matches.get("DST_IP").unwrap(), "10.1.0.0/16");
+
+        assert!(config.parse_field("FLOW_OP", "MIRROR").is_err());
+        assert!(config.parse_field("FLOW_SAMPLE_PERCENT", "101").is_err());
+
+        let mut postcard = DtelWatchlistConfig::new("flow2");
+        postcard.parse_field("SRC_IP", "10.0.0.1").unwrap();
+        postcard.parse_field("FLOW_OP", "postcard").unwrap();
+        postcard.validate().unwrap();
+    }
 }
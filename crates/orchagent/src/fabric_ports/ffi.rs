@@ -1,7 +1,7 @@
 //! FFI exports for FabricPortsOrch.
 
 use super::orch::{FabricPortsOrch, FabricPortsOrchCallbacks, FabricPortsOrchConfig, Result};
-use super::types::{FabricPortState, IsolationState, LinkStatus};
+use super::types::{FabricPortCounters, FabricPortState, IsolationState, LinkStatus};
 use sonic_sai::types::RawSaiObjectId;
 use std::cell::RefCell;
 
@@ -21,6 +21,10 @@ impl FabricPortsOrchCallbacks for FfiFabricPortsCallbacks {
         Ok(0)
     }
 
+    fn get_port_counters(&self, _oid: RawSaiObjectId) -> Result<FabricPortCounters> {
+        Ok(FabricPortCounters::default())
+    }
+
     fn set_isolation(&self, _oid: RawSaiObjectId, _isolate: bool) -> Result<()> {
         Ok(())
     }
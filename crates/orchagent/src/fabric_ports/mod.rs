@@ -7,6 +7,7 @@
 //! - Saturating arithmetic for counters
 //! - RwLock for concurrent access protection
 //! - RAII for timer management
+//! - Counter deltas that tolerate counter clears when judging bad polls
 
 mod ffi;
 mod orch;
@@ -17,4 +18,7 @@ pub use orch::{
     FabricPortsOrch, FabricPortsOrchCallbacks, FabricPortsOrchConfig, FabricPortsOrchError,
     FabricPortsOrchStats, Result,
 };
-pub use types::{FabricPortState, IsolationState, LinkStatus, PortHealthState};
+pub use types::{
+    tables, FabricMonitorData, FabricPortConfig, FabricPortCounters, FabricPortState,
+    IsolationState, LinkStatus, PortHealthState,
+};
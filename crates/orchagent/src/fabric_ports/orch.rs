@@ -8,13 +8,19 @@
 //! - Track port health metrics (error counters)
 //! - Auto-isolate ports with excessive errors
 //! - Support manual isolation configuration
+//! - Poll CRC/FEC counters and isolate links after consecutive bad polls
 
-use super::types::{FabricPortState, IsolationState, LinkStatus, PortHealthState};
+use super::types::{
+    FabricMonitorData, FabricPortConfig, FabricPortCounters, FabricPortState, IsolationState,
+    LinkStatus, PortHealthState,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use chrono::Utc;
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Result type for FabricPortsOrch operations.
 pub type Result<T> = std::result::Result<T, FabricPortsOrchError>;
@@ -24,6 +30,7 @@ pub enum FabricPortsOrchError {
     PortNotFound(u32),
     PortExists(u32),
     InvalidLane(u32),
+    InvalidConfig(String),
     SaiError(String),
 }
 
@@ -63,6 +70,9 @@ pub struct FabricPortsOrchStats {
     pub recoveries: u64,
     pub poll_cycles: u64,
     pub errors: u64,
+    pub forced_unisolations: u64,
+    /// Isolation transitions written to STATE_DB.
+    pub state_transitions: u64,
 }
 
 /// Callbacks for Fabric Ports SAI operations.
@@ -76,6 +86,9 @@ pub trait FabricPortsOrchCallbacks: Send + Sync {
     /// Get fabric port error counters from SAI.
     fn get_error_counters(&self, oid: RawSaiObjectId) -> Result<u64>;
 
+    /// Get cumulative CRC and uncorrectable FEC counters from SAI.
+    fn get_port_counters(&self, oid: RawSaiObjectId) -> Result<FabricPortCounters>;
+
     /// Set fabric port isolation state in SAI.
     fn set_isolation(&self, oid: RawSaiObjectId, isolate: bool) -> Result<()>;
 
@@ -100,6 +113,9 @@ pub struct FabricPortsOrch<C: FabricPortsOrchCallbacks> {
     stats: FabricPortsOrchStats,
     callbacks: Option<Arc<C>>,
     ports: HashMap<u32, FabricPortState>,
+    crc_threshold: u64,
+    fec_threshold: u64,
+    next_poll: Option<Instant>,
 }
 
 impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
@@ -109,6 +125,9 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
             stats: FabricPortsOrchStats::default(),
             callbacks: None,
             ports: HashMap::new(),
+            crc_threshold: FabricMonitorData::default().crc_threshold,
+            fec_threshold: FabricMonitorData::default().fec_threshold,
+            next_poll: None,
         }
     }
 
@@ -118,6 +137,9 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
             stats: FabricPortsOrchStats::default(),
             callbacks: Some(callbacks),
             ports: HashMap::new(),
+            crc_threshold: FabricMonitorData::default().crc_threshold,
+            fec_threshold: FabricMonitorData::default().fec_threshold,
+            next_poll: None,
         }
     }
 
@@ -153,6 +175,8 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
             status: LinkStatus::Down,
             health: PortHealthState::default(),
             isolation: IsolationState::Active,
+            force_unisolate: 0,
+            last_transition: None,
         };
 
         self.ports.insert(lane, port);
@@ -335,6 +359,7 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
 
         port.isolation = IsolationState::AutoIsolated;
         self.stats.auto_isolations += 1;
+        self.record_transition(lane);
 
         Ok(())
     }
@@ -371,6 +396,7 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
 
         port.isolation = IsolationState::ConfigIsolated;
         self.stats.config_isolations += 1;
+        self.record_transition(lane);
 
         Ok(())
     }
@@ -388,6 +414,7 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
         }
 
         port.isolation = IsolationState::PermIsolated;
+        self.record_transition(lane);
 
         Ok(())
     }
@@ -411,6 +438,7 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
 
         port.isolation = IsolationState::Active;
         self.stats.recoveries += 1;
+        self.record_transition(lane);
 
         Ok(())
     }
@@ -438,10 +466,63 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
 
         port.isolation = IsolationState::Active;
         self.stats.recoveries += 1;
+        self.record_transition(lane);
 
         Ok(())
     }
 
+    /// Stamps an isolation transition and writes the port to STATE_DB.
+    fn record_transition(&mut self, lane: u32) {
+        let Some(port) = self.ports.get_mut(&lane) else {
+            return;
+        };
+        port.last_transition = Some(Utc::now());
+        self.stats.state_transitions += 1;
+        if let Some(ref callbacks) = self.callbacks {
+            if callbacks.write_state_db(lane, port).is_err() {
+                self.stats.errors += 1;
+            }
+        }
+    }
+
+    /// Operator override: returns an auto-isolated link to service and
+    /// restarts error tracking from a fresh counter baseline.
+    fn force_unisolate_port(&mut self, lane: u32) -> Result<()> {
+        let port = self
+            .ports
+            .get_mut(&lane)
+            .ok_or(FabricPortsOrchError::PortNotFound(lane))?;
+
+        let was_isolated = port.isolation == IsolationState::AutoIsolated;
+        if was_isolated {
+            if let Some(ref callbacks) = self.callbacks {
+                callbacks.set_isolation(port.sai_oid, false)?;
+                callbacks.on_port_recovered(lane);
+            }
+            port.isolation = IsolationState::Active;
+        }
+        port.health = PortHealthState::default();
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "FabricPortsOrch",
+            format!("force_unisolate_port: lane {}", lane),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("lane_{}", lane))
+        .with_object_type("fabric_port")
+        .with_details(serde_json::json!({
+            "was_auto_isolated": was_isolated,
+            "force_unisolate": port.force_unisolate,
+            "sai_oid": format!("{:#x}", port.sai_oid),
+        }));
+        audit_log!(record);
+
+        self.stats.forced_unisolations += 1;
+        self.record_transition(lane);
+        Ok(())
+    }
+
     /// Get port isolation state.
     pub fn get_isolation(&self, lane: u32) -> Option<IsolationState> {
         self.ports.get(&lane).map(|p| p.isolation)
@@ -508,6 +589,146 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
         Ok(())
     }
 
+    /// Applies FABRIC_MONITOR_DATA: poll interval, error thresholds and
+    /// the consecutive-poll counts for isolation and recovery.
+    pub fn apply_monitor_data(&mut self, fields: &[(String, String)]) -> Result<()> {
+        let mut data = FabricMonitorData::default();
+        for (field, value) in fields {
+            data.parse_field(field, value)
+                .map_err(FabricPortsOrchError::InvalidConfig)?;
+        }
+        data.validate()
+            .map_err(FabricPortsOrchError::InvalidConfig)?;
+
+        let poll_interval_ms = data.poll_interval_secs.saturating_mul(1000);
+        if poll_interval_ms != self.config.poll_interval_ms || !data.enabled {
+            self.next_poll = None;
+        }
+        self.config.monitoring_enabled = data.enabled;
+        self.config.poll_interval_ms = poll_interval_ms;
+        self.config.auto_isolate_threshold = data.isolation_polls;
+        self.config.recovery_threshold = data.recovery_polls;
+        self.crc_threshold = data.crc_threshold;
+        self.fec_threshold = data.fec_threshold;
+
+        let record = AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "FabricPortsOrch",
+            "apply_monitor_data",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_type("fabric_monitor")
+        .with_details(serde_json::json!({
+            "enabled": data.enabled,
+            "poll_interval_secs": data.poll_interval_secs,
+            "crc_threshold": data.crc_threshold,
+            "fec_threshold": data.fec_threshold,
+            "isolation_polls": data.isolation_polls,
+            "recovery_polls": data.recovery_polls,
+        }));
+        audit_log!(record);
+
+        Ok(())
+    }
+
+    /// Applies a FABRIC_PORT_TABLE entry (`Fabric<N>`): operator isolation
+    /// and the forceUnisolate override.
+    pub fn handle_port_config(&mut self, key: &str, fields: &[(String, String)]) -> Result<()> {
+        let mut config = FabricPortConfig::default();
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(FabricPortsOrchError::InvalidConfig)?;
+        }
+        let lane = config
+            .lane
+            .or_else(|| key.strip_prefix("Fabric").and_then(|n| n.parse().ok()))
+            .ok_or_else(|| {
+                FabricPortsOrchError::InvalidConfig(format!("{}: lanes required", key))
+            })?;
+
+        let port = self
+            .ports
+            .get_mut(&lane)
+            .ok_or(FabricPortsOrchError::PortNotFound(lane))?;
+        if port.force_unisolate != config.force_unisolate {
+            port.force_unisolate = config.force_unisolate;
+            self.force_unisolate_port(lane)?;
+        }
+
+        if config.isolate {
+            self.config_isolate_port(lane)
+        } else if self.get_isolation(lane) == Some(IsolationState::ConfigIsolated) {
+            self.config_recover_port(lane)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Time of the next counter poll, if monitoring is scheduled.
+    pub fn next_poll_deadline(&self) -> Option<Instant> {
+        self.next_poll
+    }
+
+    /// Polls counters if the monitor interval has elapsed. Returns whether a
+    /// poll ran.
+    pub fn poll_if_due(&mut self, now: Instant) -> Result<bool> {
+        if !self.config.monitoring_enabled {
+            return Ok(false);
+        }
+        if self.next_poll.is_some_and(|deadline| now < deadline) {
+            return Ok(false);
+        }
+        self.next_poll = Some(now + Duration::from_millis(self.config.poll_interval_ms));
+        self.poll_counters()?;
+        Ok(true)
+    }
+
+    /// Reads CRC/FEC counters for every port and judges the delta since the
+    /// previous poll against the error thresholds. The first poll after a
+    /// port is added (or force-unisolated) only records a baseline.
+    pub fn poll_counters(&mut self) -> Result<()> {
+        let Some(callbacks) = self.callbacks.clone() else {
+            return Ok(());
+        };
+
+        let mut lanes: Vec<u32> = self.ports.keys().copied().collect();
+        lanes.sort_unstable();
+
+        for lane in lanes {
+            let Some(port) = self.ports.get_mut(&lane) else {
+                continue;
+            };
+            let counters = match callbacks.get_port_counters(port.sai_oid) {
+                Ok(counters) => counters,
+                Err(_) => {
+                    self.stats.errors += 1;
+                    continue;
+                }
+            };
+            let Some(previous) = port.health.last_counters.replace(counters) else {
+                continue;
+            };
+
+            // A counter that went backwards was cleared; count from zero.
+            let delta = |cur: u64, prev: u64| if cur >= prev { cur - prev } else { cur };
+            let crc = delta(counters.crc_errors, previous.crc_errors);
+            let fec = delta(counters.uncorrectable_fec, previous.uncorrectable_fec);
+
+            let result = if crc > self.crc_threshold || fec > self.fec_threshold {
+                self.record_error(lane)
+            } else {
+                self.record_success(lane)
+            };
+            if result.is_err() {
+                self.stats.errors += 1;
+            }
+        }
+
+        self.stats.poll_cycles += 1;
+        Ok(())
+    }
+
     /// Update monitoring configuration.
     pub fn update_config(&mut self, new_config: FabricPortsOrchConfig) {
         self.config = new_config;
@@ -517,6 +738,7 @@ impl<C: FabricPortsOrchCallbacks> FabricPortsOrch<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
 
     /// Mock callbacks for testing without SAI.
     struct MockFabricPortsCallbacks;
@@ -534,6 +756,10 @@ mod tests {
             Ok(0)
         }
 
+        fn get_port_counters(&self, _oid: RawSaiObjectId) -> Result<FabricPortCounters> {
+            Ok(FabricPortCounters::default())
+        }
+
         fn set_isolation(&self, _oid: RawSaiObjectId, _isolate: bool) -> Result<()> {
             Ok(())
         }
@@ -557,6 +783,114 @@ mod tests {
         fn on_port_recovered(&self, _lane: u32) {}
     }
 
+    /// Callbacks serving scripted counters and recording SAI isolation
+    /// calls and STATE_DB writes.
+    #[derive(Default)]
+    struct CounterCallbacks {
+        counters: Mutex<HashMap<RawSaiObjectId, FabricPortCounters>>,
+        isolation_calls: Mutex<Vec<(RawSaiObjectId, bool)>>,
+        state_writes: Mutex<Vec<FabricPortState>>,
+    }
+
+    impl CounterCallbacks {
+        fn set_counters(&self, lane: u32, crc_errors: u64, uncorrectable_fec: u64) {
+            self.counters.lock().unwrap().insert(
+                0x3000 + lane as u64,
+                FabricPortCounters {
+                    crc_errors,
+                    uncorrectable_fec,
+                },
+            );
+        }
+
+        fn last_state(&self) -> FabricPortState {
+            self.state_writes.lock().unwrap().last().unwrap().clone()
+        }
+    }
+
+    impl FabricPortsOrchCallbacks for CounterCallbacks {
+        fn get_fabric_port_oid(&self, lane: u32) -> Result<RawSaiObjectId> {
+            Ok(0x3000 + lane as u64)
+        }
+
+        fn get_link_status(&self, _oid: RawSaiObjectId) -> Result<LinkStatus> {
+            Ok(LinkStatus::Up)
+        }
+
+        fn get_error_counters(&self, _oid: RawSaiObjectId) -> Result<u64> {
+            Ok(0)
+        }
+
+        fn get_port_counters(&self, oid: RawSaiObjectId) -> Result<FabricPortCounters> {
+            self.counters
+                .lock()
+                .unwrap()
+                .get(&oid)
+                .copied()
+                .ok_or_else(|| FabricPortsOrchError::SaiError("no counters".to_string()))
+        }
+
+        fn set_isolation(&self, oid: RawSaiObjectId, isolate: bool) -> Result<()> {
+            self.isolation_calls.lock().unwrap().push((oid, isolate));
+            Ok(())
+        }
+
+        fn write_state_db(&self, _lane: u32, state: &FabricPortState) -> Result<()> {
+            self.state_writes.lock().unwrap().push(state.clone());
+            Ok(())
+        }
+
+        fn remove_state_db(&self, _lane: u32) -> Result<()> {
+            Ok(())
+        }
+
+        fn on_link_status_changed(
+            &self,
+            _lane: u32,
+            _old_status: LinkStatus,
+            _new_status: LinkStatus,
+        ) {
+        }
+        fn on_port_isolated(&self, _lane: u32, _reason: IsolationState) {}
+        fn on_port_recovered(&self, _lane: u32) {}
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn monitored_orch() -> (Arc<CounterCallbacks>, FabricPortsOrch<CounterCallbacks>) {
+        let callbacks = Arc::new(CounterCallbacks::default());
+        let mut orch =
+            FabricPortsOrch::with_callbacks(FabricPortsOrchConfig::default(), callbacks.clone());
+        orch.apply_monitor_data(&fields(&[
+            ("monState", "enable"),
+            ("monPollInterval", "10"),
+            ("monErrThreshCrcCells", "1"),
+            ("monErrThreshUncorrFec", "0"),
+            ("monPollThreshIsolation", "3"),
+            ("monPollThreshRecovery", "2"),
+        ]))
+        .unwrap();
+        orch.add_port(0).unwrap();
+        (callbacks, orch)
+    }
+
+    /// Feeds one CRC/FEC sample per poll.
+    fn drive(
+        orch: &mut FabricPortsOrch<CounterCallbacks>,
+        callbacks: &CounterCallbacks,
+        samples: &[(u64, u64)],
+    ) {
+        for (crc, fec) in samples {
+            callbacks.set_counters(0, *crc, *fec);
+            orch.poll_counters().unwrap();
+        }
+    }
+
     #[test]
     fn test_fabric_ports_orch_new() {
         let orch: FabricPortsOrch<MockFabricPortsCallbacks> =
@@ -1022,4 +1356,147 @@ mod tests {
         assert_eq!(orch.config().poll_interval_ms, 5000);
         assert_eq!(orch.config().auto_isolate_threshold, 20);
     }
+
+    // ===== Counter Monitoring Tests =====
+
+    #[test]
+    fn test_poll_if_due_honors_interval() {
+        let (callbacks, mut orch) = monitored_orch();
+        callbacks.set_counters(0, 0, 0);
+        let start = Instant::now();
+
+        assert!(orch.poll_if_due(start).unwrap());
+        assert_eq!(
+            orch.next_poll_deadline(),
+            Some(start + Duration::from_secs(10))
+        );
+        assert!(!orch.poll_if_due(start + Duration::from_secs(9)).unwrap());
+        assert!(orch.poll_if_due(start + Duration::from_secs(10)).unwrap());
+        assert_eq!(orch.stats().poll_cycles, 2);
+
+        orch.apply_monitor_data(&fields(&[("monState", "disable")]))
+            .unwrap();
+        assert!(!orch.poll_if_due(start + Duration::from_secs(60)).unwrap());
+        assert!(orch
+            .apply_monitor_data(&fields(&[("monPollInterval", "0")]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_crc_errors_isolate_then_recover() {
+        let (callbacks, mut orch) = monitored_orch();
+
+        // Baseline, then two bad polls: not yet isolated.
+        drive(&mut orch, &callbacks, &[(100, 0), (105, 0), (110, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+
+        // Third consecutive bad poll isolates.
+        drive(&mut orch, &callbacks, &[(115, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::AutoIsolated));
+        assert_eq!(
+            *callbacks.isolation_calls.lock().unwrap(),
+            vec![(0x3000, true)]
+        );
+        let state = callbacks.last_state();
+        assert_eq!(state.isolation, IsolationState::AutoIsolated);
+        let isolated_at = state.last_transition.unwrap();
+
+        // One clean poll is not enough, the second recovers.
+        drive(&mut orch, &callbacks, &[(116, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::AutoIsolated));
+        drive(&mut orch, &callbacks, &[(116, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+
+        let state = callbacks.last_state();
+        assert_eq!(state.isolation, IsolationState::Active);
+        assert!(state.last_transition.unwrap() >= isolated_at);
+        assert_eq!(callbacks.state_writes.lock().unwrap().len(), 2);
+        assert_eq!(orch.stats().auto_isolations, 1);
+        assert_eq!(orch.stats().recoveries, 1);
+        assert_eq!(orch.stats().state_transitions, 2);
+    }
+
+    #[test]
+    fn test_fec_errors_need_consecutive_bad_polls() {
+        let (callbacks, mut orch) = monitored_orch();
+
+        // FEC errors are bad polls too, but a clean poll resets the streak.
+        drive(
+            &mut orch,
+            &callbacks,
+            &[(0, 0), (0, 1), (0, 2), (0, 2), (0, 3), (0, 4)],
+        );
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+        assert_eq!(orch.get_health(0).unwrap().consecutive_polls_with_errors, 2);
+
+        // Counter clear is not mistaken for a huge delta.
+        drive(&mut orch, &callbacks, &[(0, 0)]);
+        assert_eq!(orch.get_health(0).unwrap().consecutive_polls_with_errors, 0);
+
+        // A port whose counters cannot be read is skipped.
+        orch.add_port(1).unwrap();
+        orch.poll_counters().unwrap();
+        assert_eq!(orch.stats().errors, 1);
+    }
+
+    #[test]
+    fn test_force_unisolate_override() {
+        let (callbacks, mut orch) = monitored_orch();
+        drive(&mut orch, &callbacks, &[(0, 0), (5, 0), (10, 0), (15, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::AutoIsolated));
+
+        orch.handle_port_config(
+            "Fabric0",
+            &fields(&[("lanes", "0"), ("forceUnisolateStatus", "1")]),
+        )
+        .unwrap();
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+        assert_eq!(
+            callbacks.isolation_calls.lock().unwrap().last(),
+            Some(&(0x3000, false))
+        );
+        let state = callbacks.last_state();
+        assert_eq!(state.force_unisolate, 1);
+        assert!(state.last_transition.is_some());
+        assert_eq!(orch.stats().forced_unisolations, 1);
+
+        // Replaying the same value is not a new override.
+        orch.handle_port_config("Fabric0", &fields(&[("forceUnisolateStatus", "1")]))
+            .unwrap();
+        assert_eq!(orch.stats().forced_unisolations, 1);
+
+        // Monitoring restarts from a fresh baseline; a still-bad link is
+        // isolated again after the full poll count.
+        drive(&mut orch, &callbacks, &[(20, 0), (25, 0), (30, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+        drive(&mut orch, &callbacks, &[(35, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::AutoIsolated));
+        assert_eq!(orch.stats().auto_isolations, 2);
+    }
+
+    #[test]
+    fn test_config_isolation_not_auto_recovered() {
+        let (callbacks, mut orch) = monitored_orch();
+
+        orch.handle_port_config("Fabric0", &fields(&[("isolateStatus", "True")]))
+            .unwrap();
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::ConfigIsolated));
+
+        drive(&mut orch, &callbacks, &[(0, 0), (0, 0), (0, 0), (0, 0)]);
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::ConfigIsolated));
+
+        orch.handle_port_config("Fabric0", &fields(&[("isolateStatus", "False")]))
+            .unwrap();
+        assert_eq!(orch.get_isolation(0), Some(IsolationState::Active));
+        assert_eq!(callbacks.last_state().isolation, IsolationState::Active);
+
+        assert!(matches!(
+            orch.handle_port_config("Fabric9", &[]),
+            Err(FabricPortsOrchError::PortNotFound(9))
+        ));
+        assert!(matches!(
+            orch.handle_port_config("bogus", &[]),
+            Err(FabricPortsOrchError::InvalidConfig(_))
+        ));
+    }
 }
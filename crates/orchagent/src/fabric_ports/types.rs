@@ -1,7 +1,126 @@
 //! Fabric ports types and structures.
 
+use chrono::{DateTime, Utc};
 use sonic_sai::types::RawSaiObjectId;
 
+/// Table names handled by FabricPortsOrch.
+pub mod tables {
+    pub const APP_FABRIC_MONITOR_DATA: &str = "FABRIC_MONITOR_DATA";
+    pub const APP_FABRIC_PORT: &str = "FABRIC_PORT_TABLE";
+    pub const STATE_FABRIC_PORT: &str = "FABRIC_PORT_TABLE";
+    /// Key of the monitor settings row in FABRIC_MONITOR_DATA.
+    pub const FABRIC_MONITOR_DATA_KEY: &str = "FABRIC_MONITOR_DATA";
+}
+
+fn parse_u64(field: &str, value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", field, value))
+}
+
+/// Settings from FABRIC_MONITOR_DATA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FabricMonitorData {
+    pub enabled: bool,
+    /// Seconds between counter polls.
+    pub poll_interval_secs: u64,
+    /// CRC errors per poll above which the poll counts as bad.
+    pub crc_threshold: u64,
+    /// Uncorrectable FEC codewords per poll above which the poll counts as bad.
+    pub fec_threshold: u64,
+    /// Consecutive bad polls before a link is isolated.
+    pub isolation_polls: u64,
+    /// Consecutive clean polls before an auto-isolated link is restored.
+    pub recovery_polls: u64,
+}
+
+impl Default for FabricMonitorData {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            poll_interval_secs: 60,
+            crc_threshold: 1,
+            fec_threshold: 0,
+            isolation_polls: 1,
+            recovery_polls: 8,
+        }
+    }
+}
+
+impl FabricMonitorData {
+    /// Applies one FABRIC_MONITOR_DATA field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "monState" => {
+                self.enabled = match value {
+                    "enable" => true,
+                    "disable" => false,
+                    _ => return Err(format!("Invalid monState: {}", value)),
+                }
+            }
+            "monPollInterval" => self.poll_interval_secs = parse_u64(field, value)?,
+            "monErrThreshCrcCells" => self.crc_threshold = parse_u64(field, value)?,
+            "monErrThreshUncorrFec" => self.fec_threshold = parse_u64(field, value)?,
+            "monPollThreshIsolation" => self.isolation_polls = parse_u64(field, value)?,
+            "monPollThreshRecovery" => self.recovery_polls = parse_u64(field, value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.poll_interval_secs == 0 {
+            return Err("monPollInterval must be non-zero".to_string());
+        }
+        if self.isolation_polls == 0 || self.recovery_polls == 0 {
+            return Err("poll thresholds must be non-zero".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// FABRIC_PORT_TABLE entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FabricPortConfig {
+    pub lane: Option<u32>,
+    /// Operator isolation (`isolateStatus`).
+    pub isolate: bool,
+    /// `forceUnisolateStatus`; each change forces the link back in service.
+    pub force_unisolate: u64,
+}
+
+impl FabricPortConfig {
+    /// Applies one FABRIC_PORT_TABLE field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "lanes" => {
+                self.lane = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid lanes: {}", value))?,
+                )
+            }
+            "isolateStatus" => {
+                self.isolate = match value.to_lowercase().as_str() {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(format!("Invalid isolateStatus: {}", value)),
+                }
+            }
+            "forceUnisolateStatus" => self.force_unisolate = parse_u64(field, value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Cumulative SAI fabric port error counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FabricPortCounters {
+    pub crc_errors: u64,
+    pub uncorrectable_fec: u64,
+}
+
 /// Port isolation states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IsolationState {
@@ -18,11 +137,13 @@ pub enum LinkStatus {
     Down,
 }
 
-/// Port health state (stub).
+/// Port health state.
 #[derive(Debug, Clone, Default)]
 pub struct PortHealthState {
     pub consecutive_polls_with_errors: u64,
     pub consecutive_polls_with_no_errors: u64,
+    /// Counters seen at the previous poll; deltas are judged against it.
+    pub last_counters: Option<FabricPortCounters>,
 }
 
 /// Fabric port state (stub).
@@ -33,6 +154,10 @@ pub struct FabricPortState {
     pub status: LinkStatus,
     pub health: PortHealthState,
     pub isolation: IsolationState,
+    /// Last applied `forceUnisolateStatus`.
+    pub force_unisolate: u64,
+    /// Time of the last isolation transition.
+    pub last_transition: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -43,4 +168,36 @@ mod tests {
     fn test_isolation_state() {
         assert_ne!(IsolationState::Active, IsolationState::AutoIsolated);
     }
+
+    #[test]
+    fn test_monitor_data_parse() {
+        let mut data = FabricMonitorData::default();
+        data.parse_field("monState", "disable").unwrap();
+        data.parse_field("monPollInterval", "30").unwrap();
+        data.parse_field("monErrThreshCrcCells", "5").unwrap();
+        data.parse_field("monPollThreshIsolation", "3").unwrap();
+        data.parse_field("monErrThreshRxCells", "61035156").unwrap();
+        assert!(!data.enabled);
+        assert_eq!(data.poll_interval_secs, 30);
+        assert_eq!(data.crc_threshold, 5);
+        assert_eq!(data.isolation_polls, 3);
+        assert!(data.validate().is_ok());
+
+        assert!(data.parse_field("monState", "on").is_err());
+        assert!(data.parse_field("monPollThreshRecovery", "x").is_err());
+        data.parse_field("monPollThreshRecovery", "0").unwrap();
+        assert!(data.validate().is_err());
+    }
+
+    #[test]
+    fn test_port_config_parse() {
+        let mut config = FabricPortConfig::default();
+        config.parse_field("lanes", "3").unwrap();
+        config.parse_field("isolateStatus", "True").unwrap();
+        config.parse_field("forceUnisolateStatus", "2").unwrap();
+        assert_eq!(config.lane, Some(3));
+        assert!(config.isolate);
+        assert_eq!(config.force_unisolate, 2);
+        assert!(config.parse_field("isolateStatus", "maybe").is_err());
+    }
 }
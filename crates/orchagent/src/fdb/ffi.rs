@@ -1,7 +1,7 @@
 //! FFI exports for FdbOrch.
 
 use super::orch::{FdbOrch, FdbOrchCallbacks, FdbOrchConfig, Result};
-use super::types::{FdbEntry, FdbKey};
+use super::types::{FdbEntry, FdbFlushRequest, FdbKey};
 use std::cell::RefCell;
 use std::sync::Arc;
 
@@ -41,6 +41,11 @@ impl FdbOrchCallbacks for FdbOrchFfiCallbacks {
         Ok(0)
     }
 
+    fn flush_fdb_entries(&self, _request: &FdbFlushRequest) -> Result<()> {
+        // FFI stub: would call SAI in production
+        Ok(())
+    }
+
     fn on_fdb_entry_added(&self, _entry: &FdbEntry) {
         // FFI stub: notification callback
     }
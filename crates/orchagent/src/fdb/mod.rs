@@ -9,6 +9,8 @@
 //! - HashMap for O(1) lookups without iterator invalidation
 //! - Generic callbacks for SAI integration
 //! - Full CRUD operations with statistics tracking
+//! - Scoped flushes (all / port / VLAN / port+VLAN) that never touch static entries
+//! - MUX-aware entries: entries on standby dual-ToR ports point at the IPinIP tunnel
 
 mod ffi;
 mod orch;
@@ -17,6 +19,6 @@ mod types;
 pub use ffi::{register_fdb_orch, unregister_fdb_orch};
 pub use orch::{FdbOrch, FdbOrchCallbacks, FdbOrchConfig, FdbOrchError, FdbOrchStats, Result};
 pub use types::{
-    FdbEntry, FdbEntryType, FdbFlushStats, FdbKey, FdbOrigin, MacAddress, RawSaiObjectId,
-    VlanMemberEntry, VlanTaggingMode,
+    FdbEntry, FdbEntryType, FdbFlushRequest, FdbFlushStats, FdbKey, FdbMuxState, FdbOrigin,
+    MacAddress, RawSaiObjectId, VlanMemberEntry, VlanTaggingMode,
 };
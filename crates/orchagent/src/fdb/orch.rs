@@ -1,8 +1,12 @@
 //! FDB orchestration logic.
 
-use super::types::{FdbEntry, FdbFlushStats, FdbKey, RawSaiObjectId};
+use super::types::{
+    FdbEntry, FdbEntryType, FdbFlushRequest, FdbFlushStats, FdbKey, FdbMuxState, RawSaiObjectId,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
+use crate::mac::MacAddressExt;
 use crate::{audit_log, debug_log, error_log, info_log, warn_log};
+use sonic_sai::{PortOid, VlanOid};
 use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
@@ -25,12 +29,21 @@ pub enum FdbOrchError {
     /// VLAN not found
     #[error("VLAN not found: {0}")]
     VlanNotFound(u16),
+    /// VLAN object not registered
+    #[error("VLAN object not found: 0x{0:x}")]
+    VlanOidNotFound(RawSaiObjectId),
     /// Invalid MAC address
     #[error("Invalid MAC address: {0}")]
     InvalidMacAddress(String),
+    /// VLAN name or ID that does not parse
+    #[error("Invalid VLAN: {0}")]
+    InvalidVlan(String),
     /// SAI operation failed
     #[error("SAI operation failed: {0}")]
     SaiError(String),
+    /// Standby MUX port without a tunnel to redirect to
+    #[error("MUX tunnel bridge port not configured")]
+    MuxTunnelNotConfigured,
     /// Callbacks not configured
     #[error("FDB orchestrator not initialized: callbacks not configured")]
     NotInitialized,
@@ -47,6 +60,7 @@ pub struct FdbOrchStats {
     pub entries_added: u64,
     pub entries_removed: u64,
     pub entries_updated: u64,
+    pub mux_entries_moved: u64,
     pub flush_stats: FdbFlushStats,
 }
 
@@ -69,6 +83,10 @@ pub trait FdbOrchCallbacks: Send + Sync {
     /// Flush FDB entries for a specific VLAN (None flushes all).
     fn flush_entries_by_vlan(&self, vlan: Option<u16>) -> Result<u32>;
 
+    /// Flush dynamic FDB entries in the request scope via SAI
+    /// (SAI_FDB_FLUSH_ATTR_ENTRY_TYPE_DYNAMIC).
+    fn flush_fdb_entries(&self, request: &FdbFlushRequest) -> Result<()>;
+
     /// Notification callback when entry is added.
     fn on_fdb_entry_added(&self, entry: &FdbEntry);
 
@@ -84,6 +102,9 @@ pub struct FdbOrch<C: FdbOrchCallbacks> {
     stats: FdbOrchStats,
     entries: HashMap<FdbKey, FdbEntry>,
     vlan_to_vlan_oid: HashMap<u16, RawSaiObjectId>,
+    port_to_port_oid: HashMap<String, PortOid>,
+    mux_states: HashMap<String, FdbMuxState>,
+    mux_tunnel_bridge_port: Option<RawSaiObjectId>,
     callbacks: Option<Arc<C>>,
 }
 
@@ -94,6 +115,9 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
             stats: FdbOrchStats::default(),
             entries: HashMap::new(),
             vlan_to_vlan_oid: HashMap::new(),
+            port_to_port_oid: HashMap::new(),
+            mux_states: HashMap::new(),
+            mux_tunnel_bridge_port: None,
             callbacks: None,
         }
     }
@@ -103,7 +127,7 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
         self
     }
 
-    pub fn add_entry(&mut self, entry: FdbEntry) -> Result<()> {
+    pub fn add_entry(&mut self, mut entry: FdbEntry) -> Result<()> {
         let key = entry.key.clone();
         debug_log!("FdbOrch", mac = %key.mac, vlan = key.vlan_id, port = %entry.port_name, "Adding FDB entry");
 
@@ -117,6 +141,11 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
             );
             return Err(FdbOrchError::EntryExists(key));
         }
+        if key.mac.is_multicast() {
+            warn_log!("FdbOrch", mac = %key.mac, vlan = key.vlan_id, "Rejecting multicast MAC in FDB");
+            return Err(FdbOrchError::InvalidMacAddress(key.mac.to_string()));
+        }
+        self.apply_mux_state(&mut entry);
 
         let callbacks = self.callbacks.as_ref().ok_or_else(|| {
             error_log!("FdbOrch", "Callbacks not configured");
@@ -209,7 +238,7 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
         Ok(())
     }
 
-    pub fn update_entry(&mut self, key: &FdbKey, entry: FdbEntry) -> Result<()> {
+    pub fn update_entry(&mut self, key: &FdbKey, mut entry: FdbEntry) -> Result<()> {
         debug_log!("FdbOrch", mac = %key.mac, vlan = key.vlan_id, port = %entry.port_name, "Updating FDB entry");
 
         let old_entry = self.entries.get(key)
@@ -219,6 +248,7 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
             })?;
 
         let old_port = old_entry.port_name.clone();
+        self.apply_mux_state(&mut entry);
 
         let callbacks = self.callbacks.as_ref().ok_or_else(|| {
             error_log!("FdbOrch", "Callbacks not configured");
@@ -309,11 +339,10 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
             e
         })?;
 
-        if let Some(port_name) = port {
-            self.entries.retain(|_, v| v.port_name != port_name);
-        } else {
-            self.entries.clear();
-        }
+        // Static entries are never flushed
+        self.entries.retain(|_, v| {
+            v.entry_type == FdbEntryType::Static || port.is_some_and(|p| v.port_name != p)
+        });
 
         self.stats
             .flush_stats
@@ -359,11 +388,10 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
             e
         })?;
 
-        if let Some(vlan_id) = vlan {
-            self.entries.retain(|k, _| k.vlan_id != vlan_id);
-        } else {
-            self.entries.clear();
-        }
+        // Static entries are never flushed
+        self.entries.retain(|k, v| {
+            v.entry_type == FdbEntryType::Static || vlan.is_some_and(|id| k.vlan_id != id)
+        });
 
         self.stats
             .flush_stats
@@ -390,6 +418,282 @@ impl<C: FdbOrchCallbacks> FdbOrch<C> {
         Ok(count)
     }
 
+    /// Flushes dynamic FDB entries in the request scope from SAI and from
+    /// the local table, then notifies observers (e.g. MuxOrch).
+    ///
+    /// Static entries are never flushed. Returns the number of entries
+    /// removed from the local table. A port or VLAN scope that is not
+    /// registered is rejected before SAI is called.
+    pub fn flush(&mut self, request: FdbFlushRequest) -> Result<u32> {
+        debug_log!("FdbOrch", request = ?request, "Flushing FDB entries");
+
+        let callbacks = self.callbacks.clone().ok_or_else(|| {
+            error_log!("FdbOrch", "Callbacks not configured");
+            FdbOrchError::NotInitialized
+        })?;
+
+        // An unregistered scope would have SAI flush an object we know
+        // nothing about, so reject it up front.
+        let port_name = match request.port() {
+            Some(oid) => Some(
+                self.port_name_of(oid)
+                    .ok_or_else(|| FdbOrchError::PortNotFound(format!("0x{:x}", oid.as_raw())))?,
+            ),
+            None => None,
+        };
+        let vlan_id = match request.vlan() {
+            Some(oid) => Some(
+                self.vlan_id_of(oid)
+                    .ok_or(FdbOrchError::VlanOidNotFound(oid.as_raw()))?,
+            ),
+            None => None,
+        };
+
+        callbacks.flush_fdb_entries(&request).map_err(|e| {
+            error_log!("FdbOrch", request = ?request, error = %e, "SAI FDB flush failed");
+            audit_log!(
+                AuditRecord::new(AuditCategory::SaiOperation, "FdbOrch", "flush")
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(format!("{:?}", request))
+                    .with_object_type("fdb_flush")
+                    .with_error(e.to_string())
+            );
+            e
+        })?;
+
+        let flushed: Vec<FdbKey> = self
+            .entries
+            .iter()
+            .filter(|(key, entry)| {
+                entry.entry_type == FdbEntryType::Dynamic
+                    && port_name.as_ref().is_none_or(|p| *p == entry.port_name)
+                    && vlan_id.is_none_or(|v| v == key.vlan_id)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &flushed {
+            self.entries.remove(key);
+            callbacks.on_fdb_entry_removed(key);
+        }
+        let count = flushed.len() as u32;
+        self.stats.entries_removed += flushed.len() as u64;
+
+        let counter = match request {
+            FdbFlushRequest::All => &self.stats.flush_stats.all_flushes,
+            FdbFlushRequest::ByPort(_) => &self.stats.flush_stats.port_flushes,
+            FdbFlushRequest::ByVlan(_) => &self.stats.flush_stats.vlan_flushes,
+            FdbFlushRequest::ByPortVlan(_, _) => &self.stats.flush_stats.port_vlan_flushes,
+        };
+        counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.stats
+            .flush_stats
+            .total_entries_flushed
+            .fetch_add(count, std::sync::atomic::Ordering::Relaxed);
+        callbacks.on_fdb_flush(port_name.as_deref(), vlan_id, count);
+
+        info_log!("FdbOrch", request = ?request, entries_flushed = count, "FDB entries flushed");
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceDelete, "FdbOrch", "flush")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("{:?}", request))
+                .with_object_type("fdb_flush")
+                .with_details(serde_json::json!({
+                    "port_oid": request.port().map(|p| format!("0x{:x}", p.as_raw())),
+                    "vlan_oid": request.vlan().map(|v| format!("0x{:x}", v.as_raw())),
+                    "entries_flushed": count
+                }))
+        );
+
+        Ok(count)
+    }
+
+    /// Flushes dynamic entries learned on a port that went oper down, if
+    /// `enable_flush_on_port_down` is set.
+    pub fn handle_port_oper_down(&mut self, port: PortOid) -> Result<u32> {
+        if !self.config.enable_flush_on_port_down {
+            return Ok(0);
+        }
+        self.flush(FdbFlushRequest::ByPort(port))
+    }
+
+    /// Flushes dynamic entries learned on a port in a VLAN it just left.
+    pub fn handle_vlan_member_removed(&mut self, port: PortOid, vlan: VlanOid) -> Result<u32> {
+        self.flush(FdbFlushRequest::ByPortVlan(port, vlan))
+    }
+
+    /// Handles a FLUSH request from the FLUSHFDBREQUEST notification channel
+    /// (`sonic-clear fdb`). `op` is "ALL", "PORT" (data: port alias) or
+    /// "VLAN" (data: "Vlan100" or "100").
+    pub fn handle_flush_notification(&mut self, op: &str, data: &str) -> Result<u32> {
+        let request = match op {
+            "ALL" => FdbFlushRequest::All,
+            "PORT" => {
+                let port = self
+                    .get_port_oid(data)
+                    .ok_or_else(|| FdbOrchError::PortNotFound(data.to_string()))?;
+                FdbFlushRequest::ByPort(port)
+            }
+            "VLAN" => {
+                let vlan_id: u16 = data
+                    .trim_start_matches("Vlan")
+                    .parse()
+                    .map_err(|_| FdbOrchError::InvalidVlan(data.to_string()))?;
+                let vlan = self
+                    .get_vlan_oid(vlan_id)
+                    .ok_or(FdbOrchError::VlanNotFound(vlan_id))?;
+                FdbFlushRequest::ByVlan(VlanOid::from_raw_unchecked(vlan))
+            }
+            _ => {
+                warn_log!("FdbOrch", op = op, "Unknown FDB flush operation");
+                return Ok(0);
+            }
+        };
+        self.flush(request)
+    }
+
+    /// Sets the bridge port of the IPinIP tunnel that entries on standby
+    /// MUX ports are redirected to.
+    pub fn set_mux_tunnel_bridge_port(&mut self, oid: RawSaiObjectId) {
+        self.mux_tunnel_bridge_port = Some(oid);
+    }
+
+    pub fn get_mux_state(&self, port_name: &str) -> Option<FdbMuxState> {
+        self.mux_states.get(port_name).copied()
+    }
+
+    /// Records the MUX state of a dual-ToR port, as reported by MuxOrch, and
+    /// re-points every entry on the port without waiting for relearn.
+    ///
+    /// Entries on a standby port point at the MUX tunnel; entries on an
+    /// active port point back at the physical port. Returns the number of
+    /// entries moved. Entries that fail to move are left as they were and
+    /// are retried by the next call for the port.
+    pub fn set_mux_port_state(&mut self, port_name: &str, state: FdbMuxState) -> Result<u32> {
+        if state == FdbMuxState::Standby && self.mux_tunnel_bridge_port.is_none() {
+            warn_log!(
+                "FdbOrch",
+                port = port_name,
+                "MUX tunnel not configured, cannot go standby"
+            );
+            return Err(FdbOrchError::MuxTunnelNotConfigured);
+        }
+
+        let old_state = self.mux_states.insert(port_name.to_string(), state);
+        debug_log!("FdbOrch", port = port_name, old_state = ?old_state, new_state = ?state, "MUX port state changed");
+        self.repoint_mux_entries(port_name)
+    }
+
+    /// Forgets a MUX port, pointing its entries back at the physical port.
+    pub fn remove_mux_port(&mut self, port_name: &str) -> Result<u32> {
+        if self.mux_states.remove(port_name).is_none() {
+            return Ok(0);
+        }
+        self.repoint_mux_entries(port_name)
+    }
+
+    /// Points an entry at the MUX tunnel if its port is standby, or back at
+    /// the physical port otherwise.
+    fn apply_mux_state(&self, entry: &mut FdbEntry) {
+        if let Some(physical) = entry.physical_bridge_port_oid.take() {
+            entry.bridge_port_oid = physical;
+        }
+        if self.mux_states.get(&entry.port_name) != Some(&FdbMuxState::Standby) {
+            return;
+        }
+        if let Some(tunnel) = self.mux_tunnel_bridge_port {
+            entry.physical_bridge_port_oid = Some(entry.bridge_port_oid);
+            entry.bridge_port_oid = tunnel;
+        }
+    }
+
+    fn repoint_mux_entries(&mut self, port_name: &str) -> Result<u32> {
+        let callbacks = self.callbacks.clone().ok_or_else(|| {
+            error_log!("FdbOrch", "Callbacks not configured");
+            FdbOrchError::NotInitialized
+        })?;
+
+        let keys: Vec<FdbKey> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.port_name == port_name)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut moved = 0u32;
+        let mut failure = None;
+        for key in keys {
+            let mut entry = self.entries[&key].clone();
+            let old_bridge_port = entry.bridge_port_oid;
+            self.apply_mux_state(&mut entry);
+            if entry.bridge_port_oid == old_bridge_port {
+                continue;
+            }
+
+            match callbacks.update_fdb_entry(&key, &entry) {
+                Ok(()) => {
+                    self.entries.insert(key, entry);
+                    moved += 1;
+                }
+                Err(e) => {
+                    error_log!("FdbOrch", mac = %key.mac, vlan = key.vlan_id, error = %e, "SAI update_fdb_entry failed for MUX re-point");
+                    failure = Some(e);
+                }
+            }
+        }
+        self.stats.mux_entries_moved += moved as u64;
+
+        let state = self.mux_states.get(port_name).copied();
+        info_log!("FdbOrch", port = port_name, state = ?state, entries_moved = moved, "FDB entries re-pointed for MUX state");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "FdbOrch",
+            "set_mux_port_state"
+        )
+        .with_outcome(if failure.is_none() {
+            AuditOutcome::Success
+        } else {
+            AuditOutcome::Failure
+        })
+        .with_object_id(port_name)
+        .with_object_type("fdb_mux_port")
+        .with_details(serde_json::json!({
+            "state": format!("{:?}", state),
+            "entries_moved": moved
+        })));
+
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(moved),
+        }
+    }
+
+    pub fn register_port(&mut self, port_name: &str, oid: PortOid) {
+        self.port_to_port_oid.insert(port_name.to_string(), oid);
+    }
+
+    pub fn get_port_oid(&self, port_name: &str) -> Option<PortOid> {
+        self.port_to_port_oid.get(port_name).copied()
+    }
+
+    pub fn unregister_port(&mut self, port_name: &str) -> Option<PortOid> {
+        self.port_to_port_oid.remove(port_name)
+    }
+
+    fn port_name_of(&self, oid: PortOid) -> Option<String> {
+        self.port_to_port_oid
+            .iter()
+            .find(|(_, p)| **p == oid)
+            .map(|(name, _)| name.clone())
+    }
+
+    fn vlan_id_of(&self, oid: VlanOid) -> Option<u16> {
+        self.vlan_to_vlan_oid
+            .iter()
+            .find(|(_, v)| **v == oid.as_raw())
+            .map(|(id, _)| *id)
+    }
+
     pub fn stats(&self) -> &FdbOrchStats {
         &self.stats
     }
@@ -416,9 +720,14 @@ mod tests {
     use super::super::types::MacAddress;
     use super::*;
 
+    type FlushNotification = (Option<String>, Option<u16>, u32);
+
     struct MockFdbCallbacks {
         add_called: std::sync::atomic::AtomicBool,
         remove_called: std::sync::atomic::AtomicBool,
+        flush_requests: std::sync::Mutex<Vec<FdbFlushRequest>>,
+        flush_notifications: std::sync::Mutex<Vec<FlushNotification>>,
+        update_count: std::sync::atomic::AtomicU32,
     }
 
     impl MockFdbCallbacks {
@@ -426,6 +735,9 @@ mod tests {
             Self {
                 add_called: std::sync::atomic::AtomicBool::new(false),
                 remove_called: std::sync::atomic::AtomicBool::new(false),
+                flush_requests: std::sync::Mutex::new(Vec::new()),
+                flush_notifications: std::sync::Mutex::new(Vec::new()),
+                update_count: std::sync::atomic::AtomicU32::new(0),
             }
         }
     }
@@ -444,6 +756,8 @@ mod tests {
         }
 
         fn update_fdb_entry(&self, _key: &FdbKey, _entry: &FdbEntry) -> Result<()> {
+            self.update_count
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             Ok(())
         }
 
@@ -459,9 +773,60 @@ mod tests {
             Ok(0)
         }
 
+        fn flush_fdb_entries(&self, request: &FdbFlushRequest) -> Result<()> {
+            self.flush_requests.lock().unwrap().push(*request);
+            Ok(())
+        }
+
         fn on_fdb_entry_added(&self, _entry: &FdbEntry) {}
         fn on_fdb_entry_removed(&self, _key: &FdbKey) {}
-        fn on_fdb_flush(&self, _port: Option<&str>, _vlan: Option<u16>, _count: u32) {}
+        fn on_fdb_flush(&self, port: Option<&str>, vlan: Option<u16>, count: u32) {
+            self.flush_notifications
+                .lock()
+                .unwrap()
+                .push((port.map(String::from), vlan, count));
+        }
+    }
+
+    const ETH0: u64 = 0x1000000000001;
+    const ETH4: u64 = 0x1000000000002;
+    const VLAN100: u64 = 0x2600000000064;
+    const VLAN200: u64 = 0x26000000000c8;
+
+    /// Seeds a static and a dynamic entry for each (port, vlan) pair of
+    /// Ethernet0/Ethernet4 x Vlan100/Vlan200.
+    fn seeded_flush_orch() -> (FdbOrch<MockFdbCallbacks>, Arc<MockFdbCallbacks>) {
+        let callbacks = Arc::new(MockFdbCallbacks::new());
+        let mut orch = FdbOrch::new(FdbOrchConfig::default()).with_callbacks(callbacks.clone());
+        orch.register_port("Ethernet0", PortOid::from_raw_unchecked(ETH0));
+        orch.register_port("Ethernet4", PortOid::from_raw_unchecked(ETH4));
+        orch.register_vlan(100, VLAN100);
+        orch.register_vlan(200, VLAN200);
+
+        let mut last = 0;
+        for port in ["Ethernet0", "Ethernet4"] {
+            for vlan in [100, 200] {
+                for entry_type in [FdbEntryType::Dynamic, FdbEntryType::Static] {
+                    last += 1;
+                    let mac = MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, last]);
+                    let mut entry = FdbEntry::new(FdbKey::new(mac, vlan), port.to_string());
+                    entry.entry_type = entry_type;
+                    orch.add_entry(entry).unwrap();
+                }
+            }
+        }
+        assert_eq!(orch.entry_count(), 8);
+        (orch, callbacks)
+    }
+
+    fn remaining(orch: &FdbOrch<MockFdbCallbacks>) -> Vec<(String, u16, FdbEntryType)> {
+        let mut left: Vec<_> = orch
+            .entries
+            .iter()
+            .map(|(k, v)| (v.port_name.clone(), k.vlan_id, v.entry_type))
+            .collect();
+        left.sort_by(|a, b| (&a.0, a.1, a.2 as u8).cmp(&(&b.0, b.1, b.2 as u8)));
+        left
     }
 
     #[test]
@@ -502,6 +867,24 @@ mod tests {
         assert!(orch.entry_exists(&key));
     }
 
+    #[test]
+    fn test_add_multicast_entry_rejected() {
+        let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default())
+            .with_callbacks(Arc::new(MockFdbCallbacks::new()));
+
+        for bytes in [[0x01, 0x00, 0x5e, 0x00, 0x00, 0x01], [0xff; 6]] {
+            let key = FdbKey::new(MacAddress::new(bytes), 100);
+            let entry = FdbEntry::new(key.clone(), "Ethernet0".to_string());
+
+            assert!(matches!(
+                orch.add_entry(entry),
+                Err(FdbOrchError::InvalidMacAddress(_))
+            ));
+            assert!(!orch.entry_exists(&key));
+        }
+        assert_eq!(orch.stats().entries_added, 0);
+    }
+
     #[test]
     fn test_add_duplicate_entry() {
         let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default())
@@ -746,4 +1129,306 @@ mod tests {
         assert_eq!(orch.get_vlan_oid(200), Some(0x22222222));
         assert_eq!(orch.get_vlan_oid(300), Some(0x33333333));
     }
+
+    #[test]
+    fn test_flush_all_keeps_static() {
+        let (mut orch, callbacks) = seeded_flush_orch();
+
+        assert_eq!(orch.flush(FdbFlushRequest::All).unwrap(), 4);
+        assert_eq!(orch.entry_count(), 4);
+        assert!(remaining(&orch)
+            .iter()
+            .all(|(_, _, t)| *t == FdbEntryType::Static));
+        assert_eq!(
+            *callbacks.flush_requests.lock().unwrap(),
+            vec![FdbFlushRequest::All]
+        );
+        assert_eq!(
+            *callbacks.flush_notifications.lock().unwrap(),
+            vec![(None, None, 4)]
+        );
+        assert_eq!(
+            orch.stats()
+                .flush_stats
+                .all_flushes
+                .load(std::sync::atomic::Ordering::Relaxed),
+            1
+        );
+    }
+
+    #[test]
+    fn test_flush_request_by_port() {
+        let (mut orch, callbacks) = seeded_flush_orch();
+
+        let count = orch
+            .flush(FdbFlushRequest::ByPort(PortOid::from_raw_unchecked(ETH0)))
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            remaining(&orch),
+            vec![
+                ("Ethernet0".to_string(), 100, FdbEntryType::Static),
+                ("Ethernet0".to_string(), 200, FdbEntryType::Static),
+                ("Ethernet4".to_string(), 100, FdbEntryType::Dynamic),
+                ("Ethernet4".to_string(), 100, FdbEntryType::Static),
+                ("Ethernet4".to_string(), 200, FdbEntryType::Dynamic),
+                ("Ethernet4".to_string(), 200, FdbEntryType::Static),
+            ]
+        );
+        assert_eq!(
+            *callbacks.flush_notifications.lock().unwrap(),
+            vec![(Some("Ethernet0".to_string()), None, 2)]
+        );
+    }
+
+    #[test]
+    fn test_flush_request_by_vlan() {
+        let (mut orch, _) = seeded_flush_orch();
+
+        let count = orch
+            .flush(FdbFlushRequest::ByVlan(VlanOid::from_raw_unchecked(
+                VLAN200,
+            )))
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            remaining(&orch),
+            vec![
+                ("Ethernet0".to_string(), 100, FdbEntryType::Dynamic),
+                ("Ethernet0".to_string(), 100, FdbEntryType::Static),
+                ("Ethernet0".to_string(), 200, FdbEntryType::Static),
+                ("Ethernet4".to_string(), 100, FdbEntryType::Dynamic),
+                ("Ethernet4".to_string(), 100, FdbEntryType::Static),
+                ("Ethernet4".to_string(), 200, FdbEntryType::Static),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flush_request_by_port_vlan() {
+        let (mut orch, callbacks) = seeded_flush_orch();
+
+        let count = orch
+            .handle_vlan_member_removed(
+                PortOid::from_raw_unchecked(ETH4),
+                VlanOid::from_raw_unchecked(VLAN100),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(orch.entry_count(), 7);
+        assert!(!remaining(&orch).contains(&("Ethernet4".to_string(), 100, FdbEntryType::Dynamic)));
+        assert_eq!(
+            *callbacks.flush_notifications.lock().unwrap(),
+            vec![(Some("Ethernet4".to_string()), Some(100), 1)]
+        );
+        assert_eq!(
+            orch.stats()
+                .flush_stats
+                .port_vlan_flushes
+                .load(std::sync::atomic::Ordering::Relaxed),
+            1
+        );
+    }
+
+    #[test]
+    fn test_flush_rejects_unknown_scope() {
+        let (mut orch, callbacks) = seeded_flush_orch();
+
+        assert!(matches!(
+            orch.flush(FdbFlushRequest::ByPort(PortOid::from_raw_unchecked(0x1234))),
+            Err(FdbOrchError::PortNotFound(_))
+        ));
+        assert!(matches!(
+            orch.flush(FdbFlushRequest::ByVlan(VlanOid::from_raw_unchecked(0x5678))),
+            Err(FdbOrchError::VlanOidNotFound(0x5678))
+        ));
+        assert_eq!(orch.entry_count(), 8);
+        // SAI is never asked to flush
+        assert!(callbacks.flush_requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_flush_without_callbacks() {
+        let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default());
+        assert!(matches!(
+            orch.flush(FdbFlushRequest::All),
+            Err(FdbOrchError::NotInitialized)
+        ));
+    }
+
+    #[test]
+    fn test_handle_flush_notification() {
+        let (mut orch, callbacks) = seeded_flush_orch();
+
+        assert_eq!(
+            orch.handle_flush_notification("VLAN", "Vlan100").unwrap(),
+            2
+        );
+        assert_eq!(
+            orch.handle_flush_notification("PORT", "Ethernet4").unwrap(),
+            1
+        );
+        assert_eq!(orch.handle_flush_notification("ALL", "").unwrap(), 1);
+        assert_eq!(orch.entry_count(), 4);
+        assert_eq!(
+            *callbacks.flush_requests.lock().unwrap(),
+            vec![
+                FdbFlushRequest::ByVlan(VlanOid::from_raw_unchecked(VLAN100)),
+                FdbFlushRequest::ByPort(PortOid::from_raw_unchecked(ETH4)),
+                FdbFlushRequest::All,
+            ]
+        );
+
+        assert!(matches!(
+            orch.handle_flush_notification("PORT", "Ethernet8"),
+            Err(FdbOrchError::PortNotFound(_))
+        ));
+        assert!(matches!(
+            orch.handle_flush_notification("VLAN", "300"),
+            Err(FdbOrchError::VlanNotFound(300))
+        ));
+        assert!(matches!(
+            orch.handle_flush_notification("VLAN", "VlanX"),
+            Err(FdbOrchError::InvalidVlan(ref vlan)) if vlan == "VlanX"
+        ));
+        assert_eq!(orch.handle_flush_notification("BOGUS", "").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_port_oper_down_flush_honors_config() {
+        let (mut orch, _) = seeded_flush_orch();
+        let port = PortOid::from_raw_unchecked(ETH0);
+
+        assert_eq!(orch.handle_port_oper_down(port).unwrap(), 0);
+        assert_eq!(orch.entry_count(), 8);
+
+        orch.config.enable_flush_on_port_down = true;
+        assert_eq!(orch.handle_port_oper_down(port).unwrap(), 2);
+        assert_eq!(orch.entry_count(), 6);
+    }
+
+    #[test]
+    fn test_legacy_flush_keeps_static() {
+        let (mut orch, _) = seeded_flush_orch();
+
+        orch.flush_by_port(Some("Ethernet0")).unwrap();
+        assert_eq!(orch.entry_count(), 6);
+        orch.flush_by_vlan(None).unwrap();
+        assert_eq!(orch.entry_count(), 4);
+        assert!(remaining(&orch)
+            .iter()
+            .all(|(_, _, t)| *t == FdbEntryType::Static));
+    }
+
+    const MUX_TUNNEL_BRIDGE_PORT: u64 = 0x3a00000000001;
+
+    fn mux_entry(n: u16, port: &str, bridge_port: u64) -> FdbEntry {
+        let mac = MacAddress::new([0x00, 0x11, 0x22, 0x33, (n >> 8) as u8, n as u8]);
+        let mut entry = FdbEntry::new(FdbKey::new(mac, 100), port.to_string());
+        entry.bridge_port_oid = bridge_port;
+        entry
+    }
+
+    #[test]
+    fn test_mux_state_flip_moves_all_entries() {
+        let callbacks = Arc::new(MockFdbCallbacks::new());
+        let mut orch = FdbOrch::new(FdbOrchConfig::default()).with_callbacks(callbacks.clone());
+        orch.set_mux_tunnel_bridge_port(MUX_TUNNEL_BRIDGE_PORT);
+        orch.set_mux_port_state("Ethernet0", FdbMuxState::Active)
+            .unwrap();
+
+        for n in 0..1000 {
+            orch.add_entry(mux_entry(n, "Ethernet0", 0x3a0)).unwrap();
+        }
+        orch.add_entry(mux_entry(1000, "Ethernet4", 0x3a4)).unwrap();
+
+        assert_eq!(
+            orch.set_mux_port_state("Ethernet0", FdbMuxState::Standby)
+                .unwrap(),
+            1000
+        );
+        assert_eq!(orch.stats().mux_entries_moved, 1000);
+        assert_eq!(
+            callbacks
+                .update_count
+                .load(std::sync::atomic::Ordering::Relaxed),
+            1000
+        );
+        for (_, entry) in orch.get_by_port("Ethernet0") {
+            assert_eq!(entry.bridge_port_oid, MUX_TUNNEL_BRIDGE_PORT);
+            assert_eq!(entry.physical_bridge_port_oid, Some(0x3a0));
+        }
+        let other = orch.get_by_port("Ethernet4")[0].1;
+        assert_eq!(other.bridge_port_oid, 0x3a4);
+        assert!(!other.is_mux_redirected());
+
+        // Repeating the state moves nothing
+        assert_eq!(
+            orch.set_mux_port_state("Ethernet0", FdbMuxState::Standby)
+                .unwrap(),
+            0
+        );
+
+        assert_eq!(
+            orch.set_mux_port_state("Ethernet0", FdbMuxState::Active)
+                .unwrap(),
+            1000
+        );
+        assert_eq!(orch.stats().mux_entries_moved, 2000);
+        assert!(orch
+            .get_by_port("Ethernet0")
+            .iter()
+            .all(|(_, e)| e.bridge_port_oid == 0x3a0 && !e.is_mux_redirected()));
+    }
+
+    #[test]
+    fn test_entry_learned_on_standby_port_points_at_tunnel() {
+        let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default())
+            .with_callbacks(Arc::new(MockFdbCallbacks::new()));
+        orch.set_mux_tunnel_bridge_port(MUX_TUNNEL_BRIDGE_PORT);
+        orch.set_mux_port_state("Ethernet0", FdbMuxState::Standby)
+            .unwrap();
+
+        let entry = mux_entry(1, "Ethernet0", 0x3a0);
+        let key = entry.key.clone();
+        orch.add_entry(entry).unwrap();
+        assert_eq!(
+            orch.get_entry(&key).unwrap().bridge_port_oid,
+            MUX_TUNNEL_BRIDGE_PORT
+        );
+
+        // Moving to a non-MUX port restores the physical destination
+        orch.update_entry(&key, mux_entry(1, "Ethernet4", 0x3a4))
+            .unwrap();
+        let entry = orch.get_entry(&key).unwrap();
+        assert_eq!(entry.bridge_port_oid, 0x3a4);
+        assert!(!entry.is_mux_redirected());
+    }
+
+    #[test]
+    fn test_remove_mux_port_restores_entries() {
+        let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default())
+            .with_callbacks(Arc::new(MockFdbCallbacks::new()));
+        orch.set_mux_tunnel_bridge_port(MUX_TUNNEL_BRIDGE_PORT);
+        orch.set_mux_port_state("Ethernet0", FdbMuxState::Standby)
+            .unwrap();
+        orch.add_entry(mux_entry(1, "Ethernet0", 0x3a0)).unwrap();
+
+        assert_eq!(orch.remove_mux_port("Ethernet0").unwrap(), 1);
+        assert_eq!(orch.get_mux_state("Ethernet0"), None);
+        assert_eq!(orch.get_by_port("Ethernet0")[0].1.bridge_port_oid, 0x3a0);
+        assert_eq!(orch.remove_mux_port("Ethernet0").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_standby_without_mux_tunnel() {
+        let mut orch: FdbOrch<MockFdbCallbacks> = FdbOrch::new(FdbOrchConfig::default())
+            .with_callbacks(Arc::new(MockFdbCallbacks::new()));
+
+        assert!(matches!(
+            orch.set_mux_port_state("Ethernet0", FdbMuxState::Standby),
+            Err(FdbOrchError::MuxTunnelNotConfigured)
+        ));
+        assert_eq!(orch.get_mux_state("Ethernet0"), None);
+    }
 }
@@ -1,5 +1,6 @@
 //! FDB (Forwarding Database) types.
 
+use sonic_sai::{PortOid, VlanOid};
 use std::sync::atomic::AtomicU32;
 
 pub type RawSaiObjectId = u64;
@@ -17,6 +18,14 @@ pub enum FdbOrigin {
     Advertised,
 }
 
+/// MUX state of a dual-ToR port, as registered by MuxOrch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FdbMuxState {
+    Active,
+    /// Entries on the port point at the IPinIP tunnel to the peer ToR.
+    Standby,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MacAddress {
     bytes: [u8; 6],
@@ -57,6 +66,16 @@ impl MacAddress {
     }
 }
 
+impl crate::mac::MacAddressExt for MacAddress {
+    fn octets(&self) -> [u8; 6] {
+        self.bytes
+    }
+
+    fn from_octets(octets: [u8; 6]) -> Self {
+        Self::new(octets)
+    }
+}
+
 impl std::fmt::Display for MacAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -94,6 +113,9 @@ pub struct FdbEntry {
     pub remote_ip: Option<String>,
     pub esi: Option<String>,
     pub vni: Option<u32>,
+    /// Bridge port of the physical port, saved while `bridge_port_oid`
+    /// points at the MUX tunnel.
+    pub physical_bridge_port_oid: Option<RawSaiObjectId>,
 }
 
 impl FdbEntry {
@@ -107,6 +129,7 @@ impl FdbEntry {
             remote_ip: None,
             esi: None,
             vni: None,
+            physical_bridge_port_oid: None,
         }
     }
 
@@ -117,6 +140,10 @@ impl FdbEntry {
     pub fn is_tunnel(&self) -> bool {
         self.vni.is_some()
     }
+
+    pub fn is_mux_redirected(&self) -> bool {
+        self.physical_bridge_port_oid.is_some()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -133,10 +160,41 @@ pub enum VlanTaggingMode {
     PriorityTagged,
 }
 
+/// Scope of an FDB flush. Only dynamic entries are ever flushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FdbFlushRequest {
+    /// Every dynamic entry.
+    All,
+    /// Dynamic entries learned on a port (e.g. port oper down).
+    ByPort(PortOid),
+    /// Dynamic entries learned in a VLAN.
+    ByVlan(VlanOid),
+    /// Dynamic entries learned on a port in one VLAN (e.g. port leaves VLAN).
+    ByPortVlan(PortOid, VlanOid),
+}
+
+impl FdbFlushRequest {
+    pub fn port(&self) -> Option<PortOid> {
+        match self {
+            Self::ByPort(port) | Self::ByPortVlan(port, _) => Some(*port),
+            Self::All | Self::ByVlan(_) => None,
+        }
+    }
+
+    pub fn vlan(&self) -> Option<VlanOid> {
+        match self {
+            Self::ByVlan(vlan) | Self::ByPortVlan(_, vlan) => Some(*vlan),
+            Self::All | Self::ByPort(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct FdbFlushStats {
     pub port_flushes: AtomicU32,
     pub vlan_flushes: AtomicU32,
+    pub port_vlan_flushes: AtomicU32,
+    pub all_flushes: AtomicU32,
     pub total_entries_flushed: AtomicU32,
 }
 
@@ -149,6 +207,13 @@ impl Clone for FdbFlushStats {
             vlan_flushes: AtomicU32::new(
                 self.vlan_flushes.load(std::sync::atomic::Ordering::Relaxed),
             ),
+            port_vlan_flushes: AtomicU32::new(
+                self.port_vlan_flushes
+                    .load(std::sync::atomic::Ordering::Relaxed),
+            ),
+            all_flushes: AtomicU32::new(
+                self.all_flushes.load(std::sync::atomic::Ordering::Relaxed),
+            ),
             total_entries_flushed: AtomicU32::new(
                 self.total_entries_flushed
                     .load(std::sync::atomic::Ordering::Relaxed),
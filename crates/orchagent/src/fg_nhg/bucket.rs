@@ -0,0 +1,452 @@
+//! Hash-bucket layout for fine-grained next hop groups.
+//!
+//! The buckets of a fine-grained ECMP group are split evenly between its
+//! banks, and each bank spreads its range round-robin across its members.
+//! When a member goes down only the buckets it holds are moved, always to
+//! the least-loaded active member of the same bank (or of any bank when the
+//! whole bank is down). When it comes back it reclaims its original buckets,
+//! so flapping a member restores the exact original layout.
+
+use super::types::FgNextHop;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FgNhgBank {
+    bank_id: u32,
+    /// Indices into `FgNhgBucketMap::members`.
+    members: Vec<usize>,
+    /// Buckets owned by this bank.
+    range: Range<usize>,
+}
+
+/// Bucket to next hop assignment for one fine-grained route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FgNhgBucketMap {
+    members: Vec<String>,
+    banks: Vec<FgNhgBank>,
+    original: Vec<usize>,
+    current: Vec<usize>,
+    down: Vec<bool>,
+}
+
+impl FgNhgBucketMap {
+    /// Builds the original layout for `bucket_size` buckets.
+    ///
+    /// Banks are ordered by bank ID and members by their order in
+    /// `next_hops`, so the same configuration always yields the same layout.
+    pub fn new(bucket_size: u32, next_hops: &[FgNextHop]) -> Self {
+        let mut by_bank: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+        let mut members: Vec<String> = Vec::new();
+        for nh in next_hops {
+            if members.contains(&nh.ip) {
+                continue;
+            }
+            by_bank.entry(nh.bank).or_default().push(members.len());
+            members.push(nh.ip.clone());
+        }
+
+        let size = bucket_size as usize;
+        let bank_count = by_bank.len().max(1);
+        let mut original = vec![0; size];
+        let banks: Vec<FgNhgBank> = by_bank
+            .into_iter()
+            .enumerate()
+            .map(|(i, (bank_id, bank_members))| {
+                let range = (i * size / bank_count)..((i + 1) * size / bank_count);
+                for (n, idx) in range.clone().enumerate() {
+                    original[idx] = bank_members[n % bank_members.len()];
+                }
+                FgNhgBank {
+                    bank_id,
+                    members: bank_members,
+                    range,
+                }
+            })
+            .collect();
+
+        if banks.is_empty() {
+            original.clear();
+        }
+
+        Self {
+            down: vec![false; members.len()],
+            current: original.clone(),
+            members,
+            banks,
+            original,
+        }
+    }
+
+    /// Returns the number of buckets.
+    pub fn bucket_size(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Returns the next hop currently programmed in a bucket.
+    pub fn next_hop(&self, bucket: usize) -> Option<&str> {
+        self.current.get(bucket).map(|&m| self.members[m].as_str())
+    }
+
+    /// Returns the next hop of every bucket, in bucket order.
+    pub fn buckets(&self) -> Vec<&str> {
+        self.current
+            .iter()
+            .map(|&m| self.members[m].as_str())
+            .collect()
+    }
+
+    /// Returns the original (all members up) next hop of every bucket.
+    pub fn original_buckets(&self) -> Vec<&str> {
+        self.original
+            .iter()
+            .map(|&m| self.members[m].as_str())
+            .collect()
+    }
+
+    /// Returns the number of buckets currently held by a next hop.
+    pub fn bucket_count(&self, ip: &str) -> usize {
+        match self.member_index(ip) {
+            Some(m) => self.current.iter().filter(|&&c| c == m).count(),
+            None => 0,
+        }
+    }
+
+    /// Returns the members of a bank in layout order.
+    pub fn bank_members(&self, bank_id: u32) -> Vec<&str> {
+        self.banks
+            .iter()
+            .find(|b| b.bank_id == bank_id)
+            .map(|b| {
+                b.members
+                    .iter()
+                    .map(|&m| self.members[m].as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the next hop is a member of this group.
+    pub fn contains(&self, ip: &str) -> bool {
+        self.member_index(ip).is_some()
+    }
+
+    /// Returns true if the next hop has been marked down.
+    pub fn is_down(&self, ip: &str) -> bool {
+        self.member_index(ip).is_some_and(|m| self.down[m])
+    }
+
+    /// Marks a member down and moves its buckets to active members.
+    ///
+    /// Returns the indices of the buckets whose next hop changed.
+    pub fn set_member_down(&mut self, ip: &str) -> Vec<usize> {
+        let Some(m) = self.member_index(ip) else {
+            return Vec::new();
+        };
+        if self.down[m] {
+            return Vec::new();
+        }
+        self.down[m] = true;
+
+        let old = self.current.clone();
+        self.reassign();
+        self.changed_since(&old)
+    }
+
+    /// Marks a member up and gives it back its original buckets.
+    ///
+    /// Returns the indices of the buckets whose next hop changed.
+    pub fn set_member_up(&mut self, ip: &str) -> Vec<usize> {
+        let Some(m) = self.member_index(ip) else {
+            return Vec::new();
+        };
+        if !self.down[m] {
+            return Vec::new();
+        }
+        self.down[m] = false;
+
+        let old = self.current.clone();
+        for (current, &original) in self.current.iter_mut().zip(&self.original) {
+            if original == m {
+                *current = m;
+            }
+        }
+        self.reassign();
+        self.changed_since(&old)
+    }
+
+    /// Points a single bucket at a next hop, e.g. when a SAI update failed
+    /// and the hardware still holds the previous assignment.
+    pub fn set_bucket(&mut self, bucket: usize, ip: &str) -> bool {
+        match (self.member_index(ip), self.current.get_mut(bucket)) {
+            (Some(m), Some(current)) => {
+                *current = m;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Replaces the current assignment with one read back from STATE_DB.
+    ///
+    /// Fails without modifying the map if the bucket count differs or a
+    /// bucket names a next hop that is not a member of the group.
+    pub fn restore(&mut self, buckets: &[(usize, String)]) -> bool {
+        if buckets.len() != self.current.len() {
+            return false;
+        }
+        let mut restored = self.current.clone();
+        for (bucket, ip) in buckets {
+            match (self.member_index(ip), restored.get_mut(*bucket)) {
+                (Some(m), Some(slot)) => *slot = m,
+                _ => return false,
+            }
+        }
+        self.current = restored;
+        true
+    }
+
+    fn member_index(&self, ip: &str) -> Option<usize> {
+        self.members.iter().position(|m| m == ip)
+    }
+
+    fn changed_since(&self, old: &[usize]) -> Vec<usize> {
+        (0..self.current.len())
+            .filter(|&i| self.current[i] != old[i])
+            .collect()
+    }
+
+    fn reassign(&mut self) {
+        if !self.down.contains(&true) {
+            self.current.clone_from(&self.original);
+            return;
+        }
+
+        let all_active: Vec<usize> = self
+            .banks
+            .iter()
+            .flat_map(|b| b.members.iter().copied())
+            .filter(|&m| !self.down[m])
+            .collect();
+
+        for bank in 0..self.banks.len() {
+            let active: Vec<usize> = self.banks[bank]
+                .members
+                .iter()
+                .copied()
+                .filter(|&m| !self.down[m])
+                .collect();
+            let local = !active.is_empty();
+            let candidates = if local { active } else { all_active.clone() };
+            if candidates.is_empty() {
+                continue;
+            }
+
+            let range = self.banks[bank].range.clone();
+            let mut counts: Vec<usize> = candidates
+                .iter()
+                .map(|&c| {
+                    self.current[range.clone()]
+                        .iter()
+                        .filter(|&&m| m == c)
+                        .count()
+                })
+                .collect();
+
+            // Move buckets held by down members (or borrowed by another bank)
+            // to the least-loaded candidate.
+            for idx in range.clone() {
+                if candidates.contains(&self.current[idx]) {
+                    continue;
+                }
+                let target = least_loaded(&counts);
+                self.current[idx] = candidates[target];
+                counts[target] += 1;
+            }
+
+            if local {
+                self.rebalance(range, &candidates, &mut counts);
+            }
+        }
+    }
+
+    /// Evens out bucket counts within a bank to at most one apart.
+    ///
+    /// Buckets are moved from the most- to the least-loaded member,
+    /// preferring buckets the receiver originally owned and then buckets the
+    /// giver did not originally own, so healthy members keep their own.
+    fn rebalance(&mut self, range: Range<usize>, candidates: &[usize], counts: &mut [usize]) {
+        loop {
+            let min = least_loaded(counts);
+            let max = most_loaded(counts);
+            if counts[max] <= counts[min] + 1 {
+                break;
+            }
+            let (giver, receiver) = (candidates[max], candidates[min]);
+            let held: Vec<usize> = range
+                .clone()
+                .filter(|&i| self.current[i] == giver)
+                .collect();
+            let idx = held
+                .iter()
+                .find(|&&i| self.original[i] == receiver)
+                .or_else(|| held.iter().find(|&&i| self.original[i] != giver))
+                .or_else(|| held.first())
+                .copied();
+            let Some(idx) = idx else {
+                break;
+            };
+            self.current[idx] = receiver;
+            counts[max] -= 1;
+            counts[min] += 1;
+        }
+    }
+}
+
+fn least_loaded(counts: &[usize]) -> usize {
+    let min = counts.iter().copied().min().unwrap_or(0);
+    counts.iter().position(|&c| c == min).unwrap_or(0)
+}
+
+fn most_loaded(counts: &[usize]) -> usize {
+    let max = counts.iter().copied().max().unwrap_or(0);
+    counts.iter().position(|&c| c == max).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next_hops(banks: &[(u32, &[&str])]) -> Vec<FgNextHop> {
+        banks
+            .iter()
+            .flat_map(|(bank, ips)| {
+                ips.iter().map(move |ip| {
+                    FgNextHop::new(ip.to_string(), "Ethernet0".to_string(), 1).with_bank(*bank)
+                })
+            })
+            .collect()
+    }
+
+    fn assert_balanced(map: &FgNhgBucketMap, bank_id: u32) {
+        let counts: Vec<usize> = map
+            .bank_members(bank_id)
+            .iter()
+            .filter(|ip| !map.is_down(ip))
+            .map(|ip| map.bucket_count(ip))
+            .collect();
+        let max = counts.iter().max().unwrap();
+        let min = counts.iter().min().unwrap();
+        assert!(max - min <= 1, "unbalanced bank {}: {:?}", bank_id, counts);
+    }
+
+    #[test]
+    fn test_original_layout() {
+        let hops = next_hops(&[(0, &["10.0.0.1", "10.0.0.2"]), (1, &["10.0.1.1"])]);
+        let map = FgNhgBucketMap::new(8, &hops);
+
+        assert_eq!(map.bucket_size(), 8);
+        assert_eq!(
+            map.buckets(),
+            vec![
+                "10.0.0.1", "10.0.0.2", "10.0.0.1", "10.0.0.2", "10.0.1.1", "10.0.1.1", "10.0.1.1",
+                "10.0.1.1"
+            ]
+        );
+        assert_eq!(map.bank_members(0), vec!["10.0.0.1", "10.0.0.2"]);
+        assert!(map.bank_members(7).is_empty());
+    }
+
+    #[test]
+    fn test_member_down_keeps_healthy_buckets() {
+        let hops = next_hops(&[(0, &["10.0.0.1", "10.0.0.2", "10.0.0.3", "10.0.0.4"])]);
+        let mut map = FgNhgBucketMap::new(127, &hops);
+        let before: Vec<String> = map.buckets().iter().map(|s| s.to_string()).collect();
+
+        let changed = map.set_member_down("10.0.0.2");
+        assert_eq!(changed.len(), 32);
+        assert_eq!(map.bucket_count("10.0.0.2"), 0);
+        assert_balanced(&map, 0);
+        for (i, ip) in before.iter().enumerate() {
+            if ip != "10.0.0.2" {
+                assert_eq!(map.next_hop(i), Some(ip.as_str()));
+            }
+        }
+
+        // Repeated down is a no-op.
+        assert!(map.set_member_down("10.0.0.2").is_empty());
+        assert!(map.set_member_down("192.0.2.1").is_empty());
+    }
+
+    #[test]
+    fn test_flap_restores_original_layout() {
+        let hops = next_hops(&[(0, &["10.0.0.1", "10.0.0.2", "10.0.0.3"])]);
+        let mut map = FgNhgBucketMap::new(64, &hops);
+        let original = map.clone();
+
+        for _ in 0..2 {
+            map.set_member_down("10.0.0.3");
+            assert_balanced(&map, 0);
+            map.set_member_up("10.0.0.3");
+            assert_balanced(&map, 0);
+            assert_eq!(map, original);
+        }
+    }
+
+    #[test]
+    fn test_overlapping_failures_stay_balanced() {
+        let hops = next_hops(&[(
+            0,
+            &["10.0.0.1", "10.0.0.2", "10.0.0.3", "10.0.0.4", "10.0.0.5"],
+        )]);
+        let mut map = FgNhgBucketMap::new(100, &hops);
+        let original = map.clone();
+
+        map.set_member_down("10.0.0.1");
+        map.set_member_down("10.0.0.4");
+        assert_balanced(&map, 0);
+        map.set_member_up("10.0.0.1");
+        assert_balanced(&map, 0);
+        assert_eq!(map.bucket_count("10.0.0.4"), 0);
+        map.set_member_up("10.0.0.4");
+        assert_eq!(map, original);
+    }
+
+    #[test]
+    fn test_whole_bank_down_borrows_other_bank() {
+        let hops = next_hops(&[
+            (0, &["10.0.0.1", "10.0.0.2"]),
+            (1, &["10.0.1.1", "10.0.1.2"]),
+        ]);
+        let mut map = FgNhgBucketMap::new(16, &hops);
+        let original = map.clone();
+
+        map.set_member_down("10.0.1.1");
+        map.set_member_down("10.0.1.2");
+        assert_eq!(map.bucket_count("10.0.0.1"), 8);
+        assert_eq!(map.bucket_count("10.0.0.2"), 8);
+
+        // One member back: the bank takes all its buckets back.
+        map.set_member_up("10.0.1.2");
+        assert_eq!(map.bucket_count("10.0.1.2"), 8);
+        assert_eq!(map.bucket_count("10.0.0.1"), 4);
+
+        map.set_member_up("10.0.1.1");
+        assert_eq!(map, original);
+    }
+
+    #[test]
+    fn test_restore() {
+        let hops = next_hops(&[(0, &["10.0.0.1", "10.0.0.2"])]);
+        let mut map = FgNhgBucketMap::new(4, &hops);
+
+        let saved: Vec<(usize, String)> = (0..4).map(|i| (i, "10.0.0.2".to_string())).collect();
+        assert!(map.restore(&saved));
+        assert_eq!(map.bucket_count("10.0.0.2"), 4);
+
+        assert!(!map.restore(&saved[..3]));
+        let bad: Vec<(usize, String)> = (0..4).map(|i| (i, "192.0.2.1".to_string())).collect();
+        assert!(!map.restore(&bad));
+        assert_eq!(map.bucket_count("10.0.0.2"), 4);
+    }
+}
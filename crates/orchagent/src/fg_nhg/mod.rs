@@ -7,11 +7,14 @@
 //! - Type-safe bank selection modes
 //! - Validated bucket sizes and weights
 //! - HashMap lookups with Option returns
+//! - Deterministic bucket remapping on next hop down/up (see `bucket`)
 
+mod bucket;
 mod ffi;
 mod orch;
 mod types;
 
+pub use bucket::FgNhgBucketMap;
 pub use ffi::{register_fg_nhg_orch, unregister_fg_nhg_orch};
 pub use orch::{FgNhgOrch, FgNhgOrchCallbacks, FgNhgOrchConfig, FgNhgOrchError, FgNhgOrchStats};
 pub use types::{
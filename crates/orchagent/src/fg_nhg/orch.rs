@@ -1,9 +1,11 @@
 //! Fine-Grained Next Hop Group orchestration logic.
 
-use super::types::{FgNhgEntry, FgNhgPrefix, FgNhgStats};
+use super::bucket::FgNhgBucketMap;
+use super::types::{FgNhgEntry, FgNhgPrefix, FgNhgStats, RawSaiObjectId};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum FgNhgOrchError {
@@ -38,12 +40,28 @@ pub trait FgNhgOrchCallbacks: Send + Sync {
     fn on_nhg_removed(&self, prefix: &FgNhgPrefix);
     fn on_member_added(&self, prefix: &FgNhgPrefix, member_ip: &str);
     fn on_member_removed(&self, prefix: &FgNhgPrefix, member_ip: &str);
+
+    /// Points a hash bucket of the group at a next hop.
+    fn set_bucket_next_hop(
+        &self,
+        nhg_oid: RawSaiObjectId,
+        bucket: u32,
+        next_hop_ip: &str,
+    ) -> Result<(), String>;
+
+    /// Writes the bucket assignment to STATE_DB FG_ROUTE_TABLE.
+    fn write_bucket_state(&self, prefix: &FgNhgPrefix, buckets: &[(u32, String)]);
+
+    /// Removes the prefix from STATE_DB FG_ROUTE_TABLE.
+    fn remove_bucket_state(&self, prefix: &FgNhgPrefix);
 }
 
 pub struct FgNhgOrch {
     config: FgNhgOrchConfig,
     stats: FgNhgOrchStats,
+    callbacks: Option<Arc<dyn FgNhgOrchCallbacks>>,
     nhgs: HashMap<FgNhgPrefix, FgNhgEntry>,
+    bucket_maps: HashMap<FgNhgPrefix, FgNhgBucketMap>,
 }
 
 impl FgNhgOrch {
@@ -51,14 +69,24 @@ impl FgNhgOrch {
         Self {
             config,
             stats: FgNhgOrchStats::default(),
+            callbacks: None,
             nhgs: HashMap::new(),
+            bucket_maps: HashMap::new(),
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn FgNhgOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
     pub fn get_nhg(&self, prefix: &FgNhgPrefix) -> Option<&FgNhgEntry> {
         self.nhgs.get(prefix)
     }
 
+    pub fn get_bucket_map(&self, prefix: &FgNhgPrefix) -> Option<&FgNhgBucketMap> {
+        self.bucket_maps.get(prefix)
+    }
+
     pub fn stats(&self) -> &FgNhgOrchStats {
         &self.stats
     }
@@ -86,9 +114,14 @@ impl FgNhgOrch {
 
         let member_count = entry.next_hops.len();
         let total_weight: u32 = entry.next_hops.iter().map(|nh| nh.weight).sum();
+        let bucket_map = self.build_bucket_map(&entry)?;
 
         self.nhgs.insert(prefix.clone(), entry);
         self.stats.stats.nhgs_created += 1;
+        if let Some(map) = bucket_map {
+            self.bucket_maps.insert(prefix.clone(), map);
+            self.persist_buckets(&prefix);
+        }
 
         let record = AuditRecord::new(
             AuditCategory::ResourceCreate,
@@ -121,8 +154,22 @@ impl FgNhgOrch {
 
         let old_member_count = old_entry.next_hops.len();
         let new_member_count = entry.next_hops.len();
+        let bucket_map = self.build_bucket_map(&entry)?;
 
         self.nhgs.insert(prefix.clone(), entry);
+        match bucket_map {
+            Some(map) => {
+                self.bucket_maps.insert(prefix.clone(), map);
+                self.persist_buckets(&prefix);
+            }
+            None => {
+                if self.bucket_maps.remove(&prefix).is_some() {
+                    if let Some(callbacks) = &self.callbacks {
+                        callbacks.remove_bucket_state(&prefix);
+                    }
+                }
+            }
+        }
         self.stats.stats.members_added +=
             (new_member_count - old_member_count.min(new_member_count)) as u64;
 
@@ -162,6 +209,11 @@ impl FgNhgOrch {
 
         self.stats.stats.nhgs_created -= 1;
         self.stats.stats.members_added -= entry.next_hops.len() as u64;
+        if self.bucket_maps.remove(prefix).is_some() {
+            if let Some(callbacks) = &self.callbacks {
+                callbacks.remove_bucket_state(prefix);
+            }
+        }
 
         let record = AuditRecord::new(
             AuditCategory::ResourceDelete,
@@ -178,11 +230,378 @@ impl FgNhgOrch {
 
         Ok(())
     }
+
+    /// Handles a next hop going down (neighbor unresolved or BFD session
+    /// down) by moving its buckets to the remaining members of its bank.
+    pub fn set_next_hop_down(&mut self, ip: &str) -> Result<(), FgNhgOrchError> {
+        self.update_next_hop_state(ip, false)
+    }
+
+    /// Handles a next hop coming back up by restoring its original buckets.
+    pub fn set_next_hop_up(&mut self, ip: &str) -> Result<(), FgNhgOrchError> {
+        self.update_next_hop_state(ip, true)
+    }
+
+    /// Restores the bucket assignment saved in STATE_DB FG_ROUTE_TABLE after
+    /// a warm restart. The hardware already holds this assignment, so
+    /// nothing is programmed.
+    pub fn restore_bucket_state(
+        &mut self,
+        prefix: &FgNhgPrefix,
+        buckets: &[(u32, String)],
+    ) -> Result<(), FgNhgOrchError> {
+        let map = self
+            .bucket_maps
+            .get_mut(prefix)
+            .ok_or_else(|| FgNhgOrchError::NhgNotFound(prefix.clone()))?;
+
+        let buckets: Vec<(usize, String)> = buckets
+            .iter()
+            .map(|(bucket, ip)| (*bucket as usize, ip.clone()))
+            .collect();
+        if !map.restore(&buckets) {
+            return Err(FgNhgOrchError::InvalidBucketSize(buckets.len() as u32));
+        }
+        Ok(())
+    }
+
+    fn build_bucket_map(
+        &self,
+        entry: &FgNhgEntry,
+    ) -> Result<Option<FgNhgBucketMap>, FgNhgOrchError> {
+        let bucket_size = if entry.bucket_size != 0 {
+            entry.bucket_size
+        } else {
+            self.config.default_bucket_size
+        };
+        if bucket_size == 0 || entry.next_hops.is_empty() {
+            return Ok(None);
+        }
+        if (bucket_size as usize) < entry.next_hops.len() {
+            return Err(FgNhgOrchError::InvalidBucketSize(bucket_size));
+        }
+        Ok(Some(FgNhgBucketMap::new(bucket_size, &entry.next_hops)))
+    }
+
+    fn persist_buckets(&self, prefix: &FgNhgPrefix) {
+        let (Some(callbacks), Some(map)) = (&self.callbacks, self.bucket_maps.get(prefix)) else {
+            return;
+        };
+        let buckets: Vec<(u32, String)> = map
+            .buckets()
+            .into_iter()
+            .enumerate()
+            .map(|(i, ip)| (i as u32, ip.to_string()))
+            .collect();
+        callbacks.write_bucket_state(prefix, &buckets);
+    }
+
+    fn update_next_hop_state(&mut self, ip: &str, up: bool) -> Result<(), FgNhgOrchError> {
+        let mut prefixes: Vec<FgNhgPrefix> = self
+            .bucket_maps
+            .iter()
+            .filter(|(_, map)| map.contains(ip))
+            .map(|(prefix, _)| prefix.clone())
+            .collect();
+        prefixes.sort_by(|a, b| a.ip_prefix.cmp(&b.ip_prefix));
+
+        let mut result = Ok(());
+        for prefix in prefixes {
+            let nhg_oid = self.nhgs.get(&prefix).map(|e| e.nhg_oid).unwrap_or(0);
+            let Some(map) = self.bucket_maps.get_mut(&prefix) else {
+                continue;
+            };
+            let before = map.clone();
+            let changed = if up {
+                map.set_member_up(ip)
+            } else {
+                map.set_member_down(ip)
+            };
+            if changed.is_empty() {
+                continue;
+            }
+
+            // Buckets the SAI refused keep their previous next hop so the
+            // map (and STATE_DB) matches what is actually programmed.
+            let mut failed = 0usize;
+            if let Some(callbacks) = &self.callbacks {
+                for &bucket in &changed {
+                    let next_hop = map.next_hop(bucket).unwrap_or_default().to_string();
+                    if let Err(e) = callbacks.set_bucket_next_hop(nhg_oid, bucket as u32, &next_hop)
+                    {
+                        if let Some(previous) = before.next_hop(bucket) {
+                            map.set_bucket(bucket, previous);
+                        }
+                        failed += 1;
+                        if result.is_ok() {
+                            result = Err(FgNhgOrchError::SaiError(e));
+                        }
+                    }
+                }
+            }
+
+            self.stats.stats.rebalances += 1;
+            self.stats.errors += failed as u64;
+            self.persist_buckets(&prefix);
+
+            let record = AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "FgNhgOrch",
+                format!("remap_fg_nhg_buckets: {}", prefix.ip_prefix),
+            )
+            .with_outcome(if failed == 0 {
+                AuditOutcome::Success
+            } else {
+                AuditOutcome::Failure
+            })
+            .with_object_id(&prefix.ip_prefix)
+            .with_object_type("fg_nhg")
+            .with_details(serde_json::json!({
+                "next_hop": ip,
+                "oper_up": up,
+                "buckets_changed": changed.len(),
+                "buckets_failed": failed,
+            }));
+            audit_log!(record);
+        }
+
+        result
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::types::FgNextHop;
     use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        bucket_sets: Mutex<Vec<(u32, String)>>,
+        state: Mutex<HashMap<String, Vec<(u32, String)>>>,
+        fail_next_hop: Mutex<Option<String>>,
+    }
+
+    impl FgNhgOrchCallbacks for MockCallbacks {
+        fn on_nhg_created(&self, _entry: &FgNhgEntry) {}
+        fn on_nhg_removed(&self, _prefix: &FgNhgPrefix) {}
+        fn on_member_added(&self, _prefix: &FgNhgPrefix, _member_ip: &str) {}
+        fn on_member_removed(&self, _prefix: &FgNhgPrefix, _member_ip: &str) {}
+
+        fn set_bucket_next_hop(
+            &self,
+            _nhg_oid: RawSaiObjectId,
+            bucket: u32,
+            next_hop_ip: &str,
+        ) -> Result<(), String> {
+            if self.fail_next_hop.lock().unwrap().as_deref() == Some(next_hop_ip) {
+                return Err("SAI_STATUS_FAILURE".to_string());
+            }
+            self.bucket_sets
+                .lock()
+                .unwrap()
+                .push((bucket, next_hop_ip.to_string()));
+            Ok(())
+        }
+
+        fn write_bucket_state(&self, prefix: &FgNhgPrefix, buckets: &[(u32, String)]) {
+            self.state
+                .lock()
+                .unwrap()
+                .insert(prefix.ip_prefix.clone(), buckets.to_vec());
+        }
+
+        fn remove_bucket_state(&self, prefix: &FgNhgPrefix) {
+            self.state.lock().unwrap().remove(&prefix.ip_prefix);
+        }
+    }
+
+    fn orch_with_route(bucket_size: u32) -> (FgNhgOrch, Arc<MockCallbacks>, FgNhgPrefix) {
+        let callbacks = Arc::new(MockCallbacks::default());
+        let mut orch = FgNhgOrch::new(FgNhgOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let prefix = FgNhgPrefix::new("10.10.0.0/24".to_string());
+        let mut entry = FgNhgEntry::new(prefix.clone(), bucket_size);
+        entry.nhg_oid = 0x5000;
+        for (ip, bank) in [
+            ("10.0.0.1", 0),
+            ("10.0.0.2", 0),
+            ("10.0.0.3", 0),
+            ("10.0.1.1", 1),
+            ("10.0.1.2", 1),
+            ("10.0.1.3", 1),
+        ] {
+            entry.add_next_hop(
+                FgNextHop::new(ip.to_string(), "Ethernet0".to_string(), 1).with_bank(bank),
+            );
+        }
+        orch.create_fg_nhg(prefix.clone(), entry).unwrap();
+        (orch, callbacks, prefix)
+    }
+
+    fn assert_bank_balanced(map: &FgNhgBucketMap, bank_id: u32) {
+        let counts: Vec<usize> = map
+            .bank_members(bank_id)
+            .iter()
+            .filter(|ip| !map.is_down(ip))
+            .map(|ip| map.bucket_count(ip))
+            .collect();
+        let max = counts.iter().max().unwrap();
+        let min = counts.iter().min().unwrap();
+        assert!(max - min <= 1, "bank {} unbalanced: {:?}", bank_id, counts);
+    }
+
+    #[test]
+    fn test_fg_nhg_create_persists_buckets() {
+        let (orch, callbacks, prefix) = orch_with_route(60);
+
+        let map = orch.get_bucket_map(&prefix).unwrap();
+        assert_eq!(map.bucket_size(), 60);
+        assert_bank_balanced(map, 0);
+        assert_bank_balanced(map, 1);
+        assert_eq!(callbacks.state.lock().unwrap()["10.10.0.0/24"].len(), 60);
+    }
+
+    #[test]
+    fn test_fg_nhg_create_bucket_size_too_small() {
+        let mut orch = FgNhgOrch::new(FgNhgOrchConfig::default());
+        let prefix = FgNhgPrefix::new("10.10.0.0/24".to_string());
+        let mut entry = FgNhgEntry::new(prefix.clone(), 1);
+        entry.add_next_hop(FgNextHop::new(
+            "10.0.0.1".to_string(),
+            "Ethernet0".to_string(),
+            1,
+        ));
+        entry.add_next_hop(FgNextHop::new(
+            "10.0.0.2".to_string(),
+            "Ethernet4".to_string(),
+            1,
+        ));
+
+        assert!(matches!(
+            orch.create_fg_nhg(prefix.clone(), entry),
+            Err(FgNhgOrchError::InvalidBucketSize(1))
+        ));
+        assert!(orch.get_nhg(&prefix).is_none());
+    }
+
+    #[test]
+    fn test_fg_nhg_next_hop_down_remaps_within_bank() {
+        let (mut orch, callbacks, prefix) = orch_with_route(60);
+        let before: Vec<String> = orch
+            .get_bucket_map(&prefix)
+            .unwrap()
+            .buckets()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        orch.set_next_hop_down("10.0.0.2").unwrap();
+
+        let map = orch.get_bucket_map(&prefix).unwrap();
+        assert_eq!(map.bucket_count("10.0.0.2"), 0);
+        assert_bank_balanced(map, 0);
+        // Only the down member's buckets moved, and only within bank 0.
+        let sets = callbacks.bucket_sets.lock().unwrap().clone();
+        assert_eq!(sets.len(), 10);
+        for (bucket, ip) in &sets {
+            assert_eq!(before[*bucket as usize], "10.0.0.2");
+            assert!(ip == "10.0.0.1" || ip == "10.0.0.3");
+        }
+        assert_eq!(orch.stats().stats.rebalances, 1);
+
+        let state = callbacks.state.lock().unwrap()["10.10.0.0/24"].clone();
+        assert!(state.iter().all(|(_, ip)| ip != "10.0.0.2"));
+    }
+
+    #[test]
+    fn test_fg_nhg_flap_twice_restores_original() {
+        let (mut orch, callbacks, prefix) = orch_with_route(60);
+        let original = orch.get_bucket_map(&prefix).unwrap().clone();
+        let original_state = callbacks.state.lock().unwrap()["10.10.0.0/24"].clone();
+
+        for _ in 0..2 {
+            orch.set_next_hop_down("10.0.1.3").unwrap();
+            let map = orch.get_bucket_map(&prefix).unwrap();
+            assert_bank_balanced(map, 1);
+            orch.set_next_hop_up("10.0.1.3").unwrap();
+            assert_eq!(orch.get_bucket_map(&prefix).unwrap(), &original);
+        }
+
+        assert_eq!(
+            callbacks.state.lock().unwrap()["10.10.0.0/24"],
+            original_state
+        );
+        assert_eq!(orch.stats().stats.rebalances, 4);
+    }
+
+    #[test]
+    fn test_fg_nhg_unknown_next_hop_is_ignored() {
+        let (mut orch, callbacks, _prefix) = orch_with_route(60);
+
+        orch.set_next_hop_down("192.0.2.1").unwrap();
+        orch.set_next_hop_up("10.0.0.1").unwrap();
+
+        assert!(callbacks.bucket_sets.lock().unwrap().is_empty());
+        assert_eq!(orch.stats().stats.rebalances, 0);
+    }
+
+    #[test]
+    fn test_fg_nhg_remap_sai_failure_keeps_programmed_state() {
+        let (mut orch, callbacks, prefix) = orch_with_route(60);
+        *callbacks.fail_next_hop.lock().unwrap() = Some("10.0.0.3".to_string());
+
+        let result = orch.set_next_hop_down("10.0.0.2");
+        assert!(matches!(result, Err(FgNhgOrchError::SaiError(_))));
+
+        // Buckets that could not be moved to 10.0.0.3 still point at 10.0.0.2.
+        let map = orch.get_bucket_map(&prefix).unwrap();
+        let stuck = map.bucket_count("10.0.0.2");
+        assert!(stuck > 0);
+        assert_eq!(orch.stats().errors, stuck as u64);
+        let state = callbacks.state.lock().unwrap()["10.10.0.0/24"].clone();
+        assert_eq!(
+            state.iter().filter(|(_, ip)| ip == "10.0.0.2").count(),
+            stuck
+        );
+    }
+
+    #[test]
+    fn test_fg_nhg_restore_bucket_state() {
+        let (mut orch, callbacks, prefix) = orch_with_route(60);
+        orch.set_next_hop_down("10.0.0.1").unwrap();
+        let saved = callbacks.state.lock().unwrap()["10.10.0.0/24"].clone();
+
+        // Warm restart: a fresh orch rebuilds the original layout, then
+        // restores the assignment from STATE_DB.
+        let (mut restarted, _, _) = orch_with_route(60);
+        restarted.restore_bucket_state(&prefix, &saved).unwrap();
+        assert_eq!(
+            restarted.get_bucket_map(&prefix).unwrap().buckets(),
+            orch.get_bucket_map(&prefix).unwrap().buckets()
+        );
+
+        assert!(matches!(
+            restarted.restore_bucket_state(&prefix, &saved[..10]),
+            Err(FgNhgOrchError::InvalidBucketSize(10))
+        ));
+        let other = FgNhgPrefix::new("10.20.0.0/24".to_string());
+        assert!(matches!(
+            restarted.restore_bucket_state(&other, &saved),
+            Err(FgNhgOrchError::NhgNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_fg_nhg_remove_clears_bucket_state() {
+        let (mut orch, callbacks, prefix) = orch_with_route(60);
+
+        orch.remove_fg_nhg(&prefix).unwrap();
+
+        assert!(orch.get_bucket_map(&prefix).is_none());
+        assert!(callbacks.state.lock().unwrap().is_empty());
+    }
 
     #[test]
     fn test_fg_nhg_orch_new_default_config() {
@@ -43,6 +43,7 @@ pub struct FgNextHop {
     pub ip: String,
     pub interface: String,
     pub weight: u32,
+    pub bank: u32,
 }
 
 impl FgNextHop {
@@ -51,8 +52,14 @@ impl FgNextHop {
             ip,
             interface,
             weight,
+            bank: 0,
         }
     }
+
+    pub fn with_bank(mut self, bank: u32) -> Self {
+        self.bank = bank;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +26,8 @@ pub mod fields {
     pub const STATUS_DISABLE: &str = "disable";
     pub const BULK_CHUNK_SIZE: &str = "BULK_CHUNK_SIZE";
     pub const BULK_CHUNK_SIZE_PER_PREFIX: &str = "BULK_CHUNK_SIZE_PER_PREFIX";
+    pub const DELAY_STATUS: &str = "FLEX_COUNTER_DELAY_STATUS";
+    pub const DELAY_STATUS_TRUE: &str = "true";
 }
 
 /// Error type for FlexCounterOrch operations.
@@ -132,6 +134,15 @@ pub trait FlexCounterCallbacks: Send + Sync {
 
     /// Sets bulk chunk size for a counter group.
     async fn set_bulk_chunk_size(&self, group: &str, size: Option<u32>) -> Result<()>;
+
+    /// Adds counter ID lists for many objects in a single FLEX_COUNTER_TABLE write.
+    ///
+    /// Each entry is an object key (e.g. a port alias) and its counter IDs.
+    async fn add_counter_id_lists(
+        &self,
+        group: &str,
+        lists: &[(String, Vec<String>)],
+    ) -> Result<()>;
 }
 
 /// Internal state for FlexCounterOrch.
@@ -189,6 +200,16 @@ pub struct FlexCounterOrch {
     /// Buffer PG configurations (port -> PG states)
     /// Loaded from CONFIG_DB BUFFER_PG table
     buffer_pg_configs: HashMap<String, Vec<(usize, usize)>>,
+
+    /// Whether PortsOrch has signalled PortInitDone
+    port_init_done: bool,
+
+    /// Groups with FLEX_COUNTER_DELAY_STATUS set, held until PortInitDone.
+    /// Kept in arrival order; later updates are merged into the pending fields.
+    deferred_groups: Vec<(FlexCounterGroup, HashMap<String, String>)>,
+
+    /// Counter ID lists queued for deferred groups (group -> [(key, ids)])
+    pending_counter_ids: HashMap<FlexCounterGroup, Vec<(String, Vec<String>)>>,
 }
 
 impl FlexCounterOrch {
@@ -211,6 +232,9 @@ impl FlexCounterOrch {
             callbacks: None,
             buffer_queue_configs: HashMap::new(),
             buffer_pg_configs: HashMap::new(),
+            port_init_done: false,
+            deferred_groups: Vec::new(),
+            pending_counter_ids: HashMap::new(),
         }
     }
 
@@ -219,6 +243,21 @@ impl FlexCounterOrch {
         self.callbacks = Some(callbacks);
     }
 
+    /// Returns true once PortInitDone has been handled.
+    pub fn is_port_init_done(&self) -> bool {
+        self.port_init_done
+    }
+
+    /// Returns true if the group is waiting for PortInitDone.
+    pub fn is_group_deferred(&self, group: FlexCounterGroup) -> bool {
+        self.deferred_groups.iter().any(|(g, _)| *g == group)
+    }
+
+    /// Returns the number of groups waiting for PortInitDone.
+    pub fn deferred_group_count(&self) -> usize {
+        self.deferred_groups.len()
+    }
+
     /// Returns true if port counters are enabled.
     pub fn port_counters_enabled(&self) -> bool {
         self.state.port_counter_enabled
@@ -391,12 +430,15 @@ impl FlexCounterOrch {
     }
 
     /// Processes a SET operation for a counter group.
+    ///
+    /// Returns true if the group status was applied and counters need to be
+    /// flushed. Callers flush once per batch rather than once per group.
     async fn process_set(
         &mut self,
         group: FlexCounterGroup,
         fields: &HashMap<String, String>,
         callbacks: &dyn FlexCounterCallbacks,
-    ) -> Result<()> {
+    ) -> Result<bool> {
         let sai_group = group.sai_group_name();
         let gearbox = callbacks.is_gearbox_enabled() && group.supports_gearbox();
 
@@ -406,35 +448,19 @@ impl FlexCounterOrch {
                 .parse()
                 .map_err(|_| FlexCounterError::InvalidPollInterval(interval_str.clone()))?;
 
-            debug!("Setting poll interval for {} to {} ms", group, interval_ms);
-            callbacks
-                .set_poll_interval(sai_group, interval_ms, false)
-                .await?;
-
-            if gearbox {
-                callbacks
-                    .set_poll_interval(sai_group, interval_ms, true)
+            if self.group_map.poll_interval(group) == Some(interval_ms) {
+                debug!(
+                    "Poll interval for {} unchanged at {} ms",
+                    group, interval_ms
+                );
+            } else {
+                self.apply_poll_interval(group, interval_ms, gearbox, callbacks)
                     .await?;
             }
-
-            let record = AuditRecord::new(
-                AuditCategory::ConfigurationChange,
-                "FlexCounterOrch",
-                format!("set_polling_interval: {}", group),
-            )
-            .with_outcome(AuditOutcome::Success)
-            .with_object_id(format!("{}", group))
-            .with_object_type("flex_counter_group")
-            .with_details(serde_json::json!({
-                "poll_interval_ms": interval_ms,
-                "gearbox": gearbox,
-            }));
-            audit_log!(record);
-
-            self.group_map.set_poll_interval(group, interval_ms);
         }
 
         // Process STATUS (enable/disable)
+        let mut needs_flush = false;
         if let Some(status) = fields.get(fields::STATUS) {
             let enable = status == fields::STATUS_ENABLE;
             info!(
@@ -480,9 +506,7 @@ impl FlexCounterOrch {
 
             self.group_map.set_enabled(group, enable);
             self.update_state_flags(group, enable);
-
-            // Flush counters
-            callbacks.flush_counters().await?;
+            needs_flush = true;
         }
 
         // Process BULK_CHUNK_SIZE
@@ -533,9 +557,181 @@ impl FlexCounterOrch {
             self.state.groups_with_bulk_chunk_size.remove(&group);
         }
 
+        Ok(needs_flush)
+    }
+
+    /// Programs a new poll interval for a counter group.
+    async fn apply_poll_interval(
+        &mut self,
+        group: FlexCounterGroup,
+        interval_ms: u64,
+        gearbox: bool,
+        callbacks: &dyn FlexCounterCallbacks,
+    ) -> Result<()> {
+        let sai_group = group.sai_group_name();
+
+        debug!("Setting poll interval for {} to {} ms", group, interval_ms);
+        callbacks
+            .set_poll_interval(sai_group, interval_ms, false)
+            .await?;
+
+        if gearbox {
+            callbacks
+                .set_poll_interval(sai_group, interval_ms, true)
+                .await?;
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "FlexCounterOrch",
+            format!("set_polling_interval: {}", group),
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(format!("{}", group))
+        .with_object_type("flex_counter_group")
+        .with_details(serde_json::json!({
+            "poll_interval_ms": interval_ms,
+            "gearbox": gearbox,
+        }));
+        audit_log!(record);
+
+        self.group_map.set_poll_interval(group, interval_ms);
         Ok(())
     }
 
+    /// Returns true if a SET for this group must wait for PortInitDone.
+    fn should_defer(&self, group: FlexCounterGroup, fields: &HashMap<String, String>) -> bool {
+        if self.port_init_done {
+            return false;
+        }
+        self.is_group_deferred(group)
+            || fields
+                .get(fields::DELAY_STATUS)
+                .is_some_and(|v| v == fields::DELAY_STATUS_TRUE)
+    }
+
+    /// Holds a SET until PortInitDone, merging it with any earlier pending SET.
+    fn defer_group(&mut self, group: FlexCounterGroup, fields: HashMap<String, String>) {
+        match self.deferred_groups.iter_mut().find(|(g, _)| *g == group) {
+            Some((_, pending)) => pending.extend(fields),
+            None => {
+                debug!("Deferring counter group {} until PortInitDone", group);
+                self.deferred_groups.push((group, fields));
+            }
+        }
+    }
+
+    /// Handles PortInitDone by programming all deferred counter groups.
+    ///
+    /// Deferred groups are applied in arrival order in a single pass, followed
+    /// by one bulk counter ID write per group and a single flush. Calling this
+    /// again is a no-op.
+    pub async fn handle_port_init_done(&mut self) {
+        if self.port_init_done {
+            return;
+        }
+
+        let callbacks = match &self.callbacks {
+            Some(cb) => cb.clone(),
+            None => {
+                debug!("FlexCounterOrch: callbacks not set");
+                return;
+            }
+        };
+
+        self.port_init_done = true;
+        let deferred = std::mem::take(&mut self.deferred_groups);
+        info!(
+            "PortInitDone: programming {} deferred counter groups",
+            deferred.len()
+        );
+
+        let mut needs_flush = false;
+        let mut failed = Vec::new();
+        for (group, fields) in &deferred {
+            match self.process_set(*group, fields, callbacks.as_ref()).await {
+                Ok(flush) => needs_flush |= flush,
+                Err(e) => {
+                    error!("Failed to process deferred {} SET: {}", group, e);
+                    failed.push(group.to_string());
+                }
+            }
+        }
+
+        let pending = std::mem::take(&mut self.pending_counter_ids);
+        for (group, lists) in &pending {
+            match callbacks
+                .add_counter_id_lists(group.sai_group_name(), lists)
+                .await
+            {
+                Ok(()) => needs_flush = true,
+                Err(e) => {
+                    error!("Failed to add {} counter ID lists: {}", group, e);
+                    failed.push(group.to_string());
+                }
+            }
+        }
+
+        if needs_flush {
+            if let Err(e) = callbacks.flush_counters().await {
+                error!("Failed to flush counters: {}", e);
+            }
+        }
+
+        let record = AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "FlexCounterOrch",
+            "flush_deferred_groups",
+        )
+        .with_outcome(if failed.is_empty() {
+            AuditOutcome::Success
+        } else {
+            AuditOutcome::Failure
+        })
+        .with_object_type("flex_counter_group")
+        .with_details(serde_json::json!({
+            "groups": deferred.iter().map(|(g, _)| g.to_string()).collect::<Vec<_>>(),
+            "counter_id_groups": pending.len(),
+            "failed": failed,
+        }));
+        audit_log!(record);
+    }
+
+    /// Adds counter ID lists for many objects of a group in one write.
+    ///
+    /// If the group is waiting for PortInitDone the lists are queued and
+    /// written together with the group.
+    pub async fn add_counter_id_lists(
+        &mut self,
+        group: FlexCounterGroup,
+        lists: Vec<(String, Vec<String>)>,
+    ) -> Result<()> {
+        if lists.is_empty() {
+            return Ok(());
+        }
+
+        if !self.port_init_done && self.is_group_deferred(group) {
+            debug!(
+                "Queueing {} counter ID lists for deferred group {}",
+                lists.len(),
+                group
+            );
+            self.pending_counter_ids
+                .entry(group)
+                .or_default()
+                .extend(lists);
+            return Ok(());
+        }
+
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or(FlexCounterError::PortsOrchUnavailable)?;
+        callbacks
+            .add_counter_id_lists(group.sai_group_name(), &lists)
+            .await
+    }
+
     /// Enables a counter group by generating the appropriate counter maps.
     async fn enable_counter_group(
         &self,
@@ -669,6 +865,7 @@ impl Orch for FlexCounterOrch {
 
         // Process pending tasks
         let tasks = self.consumer.drain();
+        let mut needs_flush = false;
 
         for task in tasks {
             match task.op {
@@ -685,20 +882,34 @@ impl Orch for FlexCounterOrch {
                     // Convert field values to HashMap
                     let fields: HashMap<String, String> = task.fvs.into_iter().collect();
 
-                    if let Err(e) = self.process_set(group, &fields, callbacks.as_ref()).await {
-                        error!("Failed to process {} SET: {}", group, e);
+                    if self.should_defer(group, &fields) {
+                        self.defer_group(group, fields);
+                        continue;
+                    }
+
+                    match self.process_set(group, &fields, callbacks.as_ref()).await {
+                        Ok(flush) => needs_flush |= flush,
+                        Err(e) => error!("Failed to process {} SET: {}", group, e),
                     }
                 }
                 Operation::Del => {
                     // Handle DEL by disabling the group
                     if let Ok(group) = task.key.parse::<FlexCounterGroup>() {
                         info!("Disabling counter group {} (deleted)", group);
+                        self.deferred_groups.retain(|(g, _)| *g != group);
+                        self.pending_counter_ids.remove(&group);
                         self.group_map.set_enabled(group, false);
                         self.update_state_flags(group, false);
                     }
                 }
             }
         }
+
+        if needs_flush {
+            if let Err(e) = callbacks.flush_counters().await {
+                error!("Failed to flush counters: {}", e);
+            }
+        }
     }
 
     fn has_pending_tasks(&self) -> bool {
@@ -1490,4 +1701,359 @@ mod tests {
         let enabled_count = orch.group_map.enabled_groups().count();
         assert_eq!(enabled_count, 2);
     }
+
+    // Delayed start tests
+
+    #[derive(Default)]
+    struct RecordingCallbacks {
+        ops: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingCallbacks {
+        fn record(&self, op: String) {
+            self.ops.lock().unwrap().push(op);
+        }
+
+        fn ops(&self) -> Vec<String> {
+            self.ops.lock().unwrap().clone()
+        }
+
+        fn count(&self, op: &str) -> usize {
+            self.ops().iter().filter(|o| o.as_str() == op).count()
+        }
+    }
+
+    #[async_trait]
+    impl FlexCounterCallbacks for RecordingCallbacks {
+        fn all_ports_ready(&self) -> bool {
+            true
+        }
+
+        async fn generate_port_counter_map(&self) -> Result<()> {
+            self.record("generate_port_counter_map".to_string());
+            Ok(())
+        }
+
+        async fn generate_port_buffer_drop_counter_map(&self) -> Result<()> {
+            self.record("generate_port_buffer_drop_counter_map".to_string());
+            Ok(())
+        }
+
+        async fn generate_queue_map(&self, _configs: &QueueConfigurations) -> Result<()> {
+            self.record("generate_queue_map".to_string());
+            Ok(())
+        }
+
+        async fn add_queue_flex_counters(&self, _configs: &QueueConfigurations) -> Result<()> {
+            self.record("add_queue_flex_counters".to_string());
+            Ok(())
+        }
+
+        async fn add_queue_watermark_flex_counters(
+            &self,
+            _configs: &QueueConfigurations,
+        ) -> Result<()> {
+            self.record("add_queue_watermark_flex_counters".to_string());
+            Ok(())
+        }
+
+        async fn generate_pg_map(&self, _configs: &PgConfigurations) -> Result<()> {
+            self.record("generate_pg_map".to_string());
+            Ok(())
+        }
+
+        async fn add_pg_flex_counters(&self, _configs: &PgConfigurations) -> Result<()> {
+            self.record("add_pg_flex_counters".to_string());
+            Ok(())
+        }
+
+        async fn add_pg_watermark_flex_counters(&self, _configs: &PgConfigurations) -> Result<()> {
+            self.record("add_pg_watermark_flex_counters".to_string());
+            Ok(())
+        }
+
+        async fn generate_wred_port_counter_map(&self) -> Result<()> {
+            self.record("generate_wred_port_counter_map".to_string());
+            Ok(())
+        }
+
+        async fn add_wred_queue_flex_counters(&self, _configs: &QueueConfigurations) -> Result<()> {
+            self.record("add_wred_queue_flex_counters".to_string());
+            Ok(())
+        }
+
+        async fn flush_counters(&self) -> Result<()> {
+            self.record("flush_counters".to_string());
+            Ok(())
+        }
+
+        async fn set_poll_interval(
+            &self,
+            group: &str,
+            interval_ms: u64,
+            _gearbox: bool,
+        ) -> Result<()> {
+            self.record(format!("set_poll_interval:{}:{}", group, interval_ms));
+            Ok(())
+        }
+
+        async fn set_group_operation(
+            &self,
+            group: &str,
+            enable: bool,
+            _gearbox: bool,
+        ) -> Result<()> {
+            self.record(format!("set_group_operation:{}:{}", group, enable));
+            Ok(())
+        }
+
+        async fn set_bulk_chunk_size(&self, group: &str, size: Option<u32>) -> Result<()> {
+            self.record(format!("set_bulk_chunk_size:{}:{:?}", group, size));
+            Ok(())
+        }
+
+        async fn add_counter_id_lists(
+            &self,
+            group: &str,
+            lists: &[(String, Vec<String>)],
+        ) -> Result<()> {
+            let keys: Vec<&str> = lists.iter().map(|(k, _)| k.as_str()).collect();
+            self.record(format!("add_counter_id_lists:{}:{}", group, keys.join(",")));
+            Ok(())
+        }
+    }
+
+    fn group_fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_recorder() -> (FlexCounterOrch, Arc<RecordingCallbacks>) {
+        let mut orch = FlexCounterOrch::new(FlexCounterOrchConfig::default());
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        orch.set_callbacks(callbacks.clone());
+        (orch, callbacks)
+    }
+
+    #[tokio::test]
+    async fn test_delayed_groups_wait_for_port_init_done() {
+        let (mut orch, callbacks) = orch_with_recorder();
+
+        orch.add_task(
+            "PORT".to_string(),
+            Operation::Set,
+            group_fields(&[
+                (fields::POLL_INTERVAL, "1000"),
+                (fields::STATUS, fields::STATUS_ENABLE),
+                (fields::DELAY_STATUS, fields::DELAY_STATUS_TRUE),
+            ]),
+        );
+        orch.add_task(
+            "QUEUE".to_string(),
+            Operation::Set,
+            group_fields(&[
+                (fields::STATUS, fields::STATUS_ENABLE),
+                (fields::DELAY_STATUS, fields::DELAY_STATUS_TRUE),
+            ]),
+        );
+        orch.do_task().await;
+
+        // Runtime poll interval update for a deferred group is merged
+        orch.add_task(
+            "PORT".to_string(),
+            Operation::Set,
+            group_fields(&[(fields::POLL_INTERVAL, "2000")]),
+        );
+        orch.do_task().await;
+
+        orch.add_counter_id_lists(
+            FlexCounterGroup::Port,
+            vec![
+                (
+                    "Ethernet0".to_string(),
+                    vec!["SAI_PORT_STAT_IF_IN_OCTETS".to_string()],
+                ),
+                (
+                    "Ethernet4".to_string(),
+                    vec!["SAI_PORT_STAT_IF_IN_OCTETS".to_string()],
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+
+        assert!(callbacks.ops().is_empty());
+        assert_eq!(orch.deferred_group_count(), 2);
+        assert!(orch.is_group_deferred(FlexCounterGroup::Port));
+        assert!(!orch.port_counters_enabled());
+
+        orch.handle_port_init_done().await;
+        orch.handle_port_init_done().await;
+
+        assert!(orch.is_port_init_done());
+        assert_eq!(orch.deferred_group_count(), 0);
+        assert!(orch.port_counters_enabled());
+        assert!(orch.queue_counters_enabled());
+        assert_eq!(
+            orch.group_map.poll_interval(FlexCounterGroup::Port),
+            Some(2000)
+        );
+
+        let ops = callbacks.ops();
+        assert_eq!(
+            callbacks.count("set_poll_interval:PORT_STAT_COUNTER:2000"),
+            1
+        );
+        assert!(!ops.iter().any(|o| o.ends_with(":1000")));
+        assert_eq!(callbacks.count("generate_port_counter_map"), 1);
+        assert_eq!(
+            callbacks.count("set_group_operation:PORT_STAT_COUNTER:true"),
+            1
+        );
+        assert_eq!(callbacks.count("add_queue_flex_counters"), 1);
+        assert_eq!(
+            callbacks.count("set_group_operation:QUEUE_STAT_COUNTER:true"),
+            1
+        );
+        assert_eq!(
+            callbacks.count("add_counter_id_lists:PORT_STAT_COUNTER:Ethernet0,Ethernet4"),
+            1
+        );
+        assert_eq!(callbacks.count("flush_counters"), 1);
+        assert_eq!(ops.last().unwrap(), "flush_counters");
+    }
+
+    #[tokio::test]
+    async fn test_undelayed_groups_programmed_before_port_init_done() {
+        let (mut orch, callbacks) = orch_with_recorder();
+
+        orch.add_task(
+            "RIF".to_string(),
+            Operation::Set,
+            group_fields(&[(fields::STATUS, fields::STATUS_ENABLE)]),
+        );
+        orch.add_task(
+            "PORT".to_string(),
+            Operation::Set,
+            group_fields(&[
+                (fields::STATUS, fields::STATUS_ENABLE),
+                (fields::DELAY_STATUS, fields::DELAY_STATUS_TRUE),
+            ]),
+        );
+        orch.do_task().await;
+
+        assert_eq!(
+            callbacks.ops(),
+            vec![
+                "set_group_operation:RIF_STAT_COUNTER:true".to_string(),
+                "flush_counters".to_string(),
+            ]
+        );
+        assert!(orch.is_group_deferred(FlexCounterGroup::Port));
+    }
+
+    #[tokio::test]
+    async fn test_delay_status_ignored_after_port_init_done() {
+        let (mut orch, callbacks) = orch_with_recorder();
+        orch.handle_port_init_done().await;
+
+        orch.add_task(
+            "PORT".to_string(),
+            Operation::Set,
+            group_fields(&[
+                (fields::STATUS, fields::STATUS_ENABLE),
+                (fields::DELAY_STATUS, fields::DELAY_STATUS_TRUE),
+            ]),
+        );
+        orch.do_task().await;
+
+        assert!(orch.port_counters_enabled());
+        assert_eq!(callbacks.count("generate_port_counter_map"), 1);
+        assert_eq!(orch.deferred_group_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_delete_drops_deferred_group() {
+        let (mut orch, callbacks) = orch_with_recorder();
+
+        orch.add_task(
+            "PORT".to_string(),
+            Operation::Set,
+            group_fields(&[
+                (fields::STATUS, fields::STATUS_ENABLE),
+                (fields::DELAY_STATUS, fields::DELAY_STATUS_TRUE),
+            ]),
+        );
+        orch.do_task().await;
+        orch.add_counter_id_lists(
+            FlexCounterGroup::Port,
+            vec![("Ethernet0".to_string(), vec![])],
+        )
+        .await
+        .unwrap();
+
+        orch.add_task("PORT".to_string(), Operation::Del, HashMap::new());
+        orch.do_task().await;
+        orch.handle_port_init_done().await;
+
+        assert!(callbacks.ops().is_empty());
+        assert!(!orch.port_counters_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_poll_interval_runtime_update() {
+        let (mut orch, callbacks) = orch_with_recorder();
+        orch.handle_port_init_done().await;
+
+        for interval in ["1000", "1000", "5000"] {
+            orch.add_task(
+                "QUEUE".to_string(),
+                Operation::Set,
+                group_fields(&[(fields::POLL_INTERVAL, interval)]),
+            );
+            orch.do_task().await;
+        }
+
+        assert_eq!(
+            callbacks.ops(),
+            vec![
+                "set_poll_interval:QUEUE_STAT_COUNTER:1000".to_string(),
+                "set_poll_interval:QUEUE_STAT_COUNTER:5000".to_string(),
+            ]
+        );
+        assert_eq!(
+            orch.group_map.poll_interval(FlexCounterGroup::Queue),
+            Some(5000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_counter_id_lists_single_write() {
+        let (mut orch, callbacks) = orch_with_recorder();
+
+        let lists: Vec<(String, Vec<String>)> = (0..4)
+            .map(|i| {
+                (
+                    format!("Ethernet{}", i * 4),
+                    vec!["SAI_PORT_STAT_IF_IN_OCTETS".to_string()],
+                )
+            })
+            .collect();
+        orch.add_counter_id_lists(FlexCounterGroup::Port, lists)
+            .await
+            .unwrap();
+        orch.add_counter_id_lists(FlexCounterGroup::Port, Vec::new())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            callbacks.ops(),
+            vec![
+                "add_counter_id_lists:PORT_STAT_COUNTER:Ethernet0,Ethernet4,Ethernet8,Ethernet12"
+                    .to_string()
+            ]
+        );
+    }
 }
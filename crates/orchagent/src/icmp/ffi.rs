@@ -1,7 +1,10 @@
 //! FFI exports for IcmpOrch.
 
 use super::orch::{IcmpOrch, IcmpOrchCallbacks, IcmpOrchConfig, Result};
-use super::types::{IcmpRedirectConfig, IcmpStats, NeighborDiscoveryConfig};
+use super::types::{
+    IcmpRedirectConfig, IcmpSessionConfig, IcmpSessionKey, IcmpSessionState, IcmpStats,
+    NeighborDiscoveryConfig, RawSaiObjectId,
+};
 use std::cell::RefCell;
 
 /// FFI stub callbacks that do nothing (for C++ interop).
@@ -26,6 +29,30 @@ impl IcmpOrchCallbacks for FfiIcmpCallbacks {
 
     fn on_redirect_processed(&self, _src_ip: &str) {}
     fn on_neighbor_discovery_complete(&self, _neighbor_ip: &str) {}
+
+    fn create_echo_session(
+        &self,
+        _key: &IcmpSessionKey,
+        _config: &IcmpSessionConfig,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn remove_echo_session(&self, _sai_oid: RawSaiObjectId) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_echo_session_intervals(
+        &self,
+        _sai_oid: RawSaiObjectId,
+        _tx_interval_ms: u32,
+        _rx_timeout_ms: u32,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_session_state(&self, _key: &IcmpSessionKey, _state: IcmpSessionState) {}
+    fn remove_session_state(&self, _key: &IcmpSessionKey) {}
 }
 
 thread_local! {
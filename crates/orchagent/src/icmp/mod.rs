@@ -7,6 +7,7 @@
 //! - ICMP redirect message management
 //! - Neighbor discovery configuration
 //! - Support for both IPv4 and IPv6
+//! - Hardware ICMP echo session offload (ICMP_ECHO_SESSION_TABLE)
 //!
 //! # Safety Improvements over C++
 //!
@@ -24,5 +25,6 @@ pub mod types;
 pub use ffi::{register_icmp_orch, unregister_icmp_orch};
 pub use orch::{IcmpOrch, IcmpOrchCallbacks, IcmpOrchConfig, IcmpOrchError, IcmpOrchStats, Result};
 pub use types::{
-    IcmpEchoEntry, IcmpEchoKey, IcmpMode, IcmpRedirectConfig, IcmpStats, NeighborDiscoveryConfig,
+    IcmpEchoEntry, IcmpEchoKey, IcmpMode, IcmpRedirectConfig, IcmpSessionConfig, IcmpSessionEntry,
+    IcmpSessionKey, IcmpSessionState, IcmpStats, NeighborDiscoveryConfig,
 };
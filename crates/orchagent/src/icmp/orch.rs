@@ -1,11 +1,15 @@
 //! ICMP echo orchestration logic.
 
 use super::types::{
-    IcmpEchoEntry, IcmpEchoKey, IcmpRedirectConfig, IcmpStats, NeighborDiscoveryConfig,
+    IcmpEchoEntry, IcmpEchoKey, IcmpRedirectConfig, IcmpSessionConfig, IcmpSessionEntry,
+    IcmpSessionKey, IcmpSessionState, IcmpStats, NeighborDiscoveryConfig, RawSaiObjectId,
+    MAX_SESSION_INTERVAL_MS, MIN_SESSION_INTERVAL_MS,
 };
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::{audit_log, debug_log, error_log, info_log, warn_log};
+use sonic_orch_common::{KeyOpFieldsValues, Operation};
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -42,6 +46,11 @@ pub struct IcmpOrchStats {
     pub stats: IcmpStats,
     pub redirects_processed: u64,
     pub nd_solicitations_processed: u64,
+    pub sessions_created: u64,
+    pub sessions_removed: u64,
+    pub session_state_changes: u64,
+    /// State notifications received for sessions that no longer exist
+    pub stale_notifications: u64,
 }
 
 pub trait IcmpOrchCallbacks: Send + Sync {
@@ -51,6 +60,22 @@ pub trait IcmpOrchCallbacks: Send + Sync {
     fn get_icmp_statistics(&self) -> Result<IcmpStats>;
     fn on_redirect_processed(&self, src_ip: &str);
     fn on_neighbor_discovery_complete(&self, neighbor_ip: &str);
+    fn create_echo_session(
+        &self,
+        key: &IcmpSessionKey,
+        config: &IcmpSessionConfig,
+    ) -> Result<RawSaiObjectId>;
+    fn remove_echo_session(&self, sai_oid: RawSaiObjectId) -> Result<()>;
+    fn set_echo_session_intervals(
+        &self,
+        sai_oid: RawSaiObjectId,
+        tx_interval_ms: u32,
+        rx_timeout_ms: u32,
+    ) -> Result<()>;
+    /// Writes session state to STATE_DB ICMP_ECHO_SESSION_TABLE.
+    fn write_session_state(&self, key: &IcmpSessionKey, state: IcmpSessionState);
+    /// Removes session state from STATE_DB ICMP_ECHO_SESSION_TABLE.
+    fn remove_session_state(&self, key: &IcmpSessionKey);
 }
 
 pub struct IcmpOrch<C: IcmpOrchCallbacks> {
@@ -59,6 +84,8 @@ pub struct IcmpOrch<C: IcmpOrchCallbacks> {
     entries: HashMap<IcmpEchoKey, IcmpEchoEntry>,
     redirect_config: Option<IcmpRedirectConfig>,
     nd_config: Option<NeighborDiscoveryConfig>,
+    sessions: HashMap<IcmpSessionKey, IcmpSessionEntry>,
+    session_oids: HashMap<RawSaiObjectId, IcmpSessionKey>,
     callbacks: Option<Arc<C>>,
 }
 
@@ -70,6 +97,8 @@ impl<C: IcmpOrchCallbacks> IcmpOrch<C> {
             entries: HashMap::new(),
             redirect_config: None,
             nd_config: None,
+            sessions: HashMap::new(),
+            session_oids: HashMap::new(),
             callbacks: None,
         }
     }
@@ -390,12 +419,292 @@ impl<C: IcmpOrchCallbacks> IcmpOrch<C> {
     pub fn get_entry_count(&self) -> usize {
         self.entries.len()
     }
+
+    pub fn get_session(&self, key: &IcmpSessionKey) -> Option<&IcmpSessionEntry> {
+        self.sessions.get(key)
+    }
+
+    pub fn get_session_count(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Processes an APPL_DB ICMP_ECHO_SESSION_TABLE entry.
+    pub fn process_session_task(&mut self, task: &KeyOpFieldsValues) -> Result<()> {
+        let key = IcmpSessionKey::parse(&task.key).ok_or_else(|| {
+            IcmpOrchError::InvalidConfig(format!("Invalid ICMP echo session key: {}", task.key))
+        })?;
+
+        match task.op {
+            Operation::Set => {
+                let config = Self::parse_session_config(task)?;
+                self.add_session(key, config)
+            }
+            Operation::Del => self.remove_session(&key),
+        }
+    }
+
+    fn parse_session_config(task: &KeyOpFieldsValues) -> Result<IcmpSessionConfig> {
+        let parse_ip = |field: &str| -> Result<IpAddr> {
+            task.get_field(field)
+                .ok_or_else(|| {
+                    IcmpOrchError::InvalidConfig(format!("Missing {} for {}", field, task.key))
+                })?
+                .parse()
+                .map_err(|e| IcmpOrchError::InvalidConfig(format!("Invalid {}: {}", field, e)))
+        };
+
+        let mut config = IcmpSessionConfig::new(parse_ip("src_ip")?, parse_ip("dst_ip")?);
+        if config.src_ip.is_ipv4() != config.dst_ip.is_ipv4() {
+            return Err(IcmpOrchError::InvalidConfig(format!(
+                "Address family mismatch between {} and {}",
+                config.src_ip, config.dst_ip
+            )));
+        }
+
+        for (field, value) in [
+            ("tx_interval", &mut config.tx_interval_ms),
+            ("rx_timeout", &mut config.rx_timeout_ms),
+        ] {
+            if let Some(raw) = task.get_field(field) {
+                *value = raw.parse().map_err(|e| {
+                    IcmpOrchError::InvalidConfig(format!("Invalid {}: {}", field, e))
+                })?;
+            }
+        }
+        Self::validate_intervals(config.tx_interval_ms, config.rx_timeout_ms)?;
+
+        Ok(config)
+    }
+
+    fn validate_intervals(tx_interval_ms: u32, rx_timeout_ms: u32) -> Result<()> {
+        let range = MIN_SESSION_INTERVAL_MS..=MAX_SESSION_INTERVAL_MS;
+        if !range.contains(&tx_interval_ms) || !range.contains(&rx_timeout_ms) {
+            return Err(IcmpOrchError::InvalidConfig(format!(
+                "tx_interval {} and rx_timeout {} must be within {}-{} ms",
+                tx_interval_ms, rx_timeout_ms, MIN_SESSION_INTERVAL_MS, MAX_SESSION_INTERVAL_MS
+            )));
+        }
+        if rx_timeout_ms < tx_interval_ms {
+            return Err(IcmpOrchError::InvalidConfig(format!(
+                "rx_timeout {} is shorter than tx_interval {}",
+                rx_timeout_ms, tx_interval_ms
+            )));
+        }
+        Ok(())
+    }
+
+    /// Creates an ICMP echo session, or updates the intervals of an existing
+    /// one. Session addresses cannot change once created.
+    pub fn add_session(&mut self, key: IcmpSessionKey, config: IcmpSessionConfig) -> Result<()> {
+        Self::validate_intervals(config.tx_interval_ms, config.rx_timeout_ms)?;
+        let callbacks = Arc::clone(self.callbacks.as_ref().ok_or_else(|| {
+            error_log!("IcmpOrch", "Callbacks not configured");
+            IcmpOrchError::NotInitialized
+        })?);
+
+        if let Some(session) = self.sessions.get_mut(&key) {
+            if session.config == config {
+                return Ok(());
+            }
+            if session.config.src_ip != config.src_ip || session.config.dst_ip != config.dst_ip {
+                warn_log!("IcmpOrch", session = %key, "Session addresses cannot be changed");
+                return Err(IcmpOrchError::InvalidConfig(format!(
+                    "Cannot change addresses of ICMP echo session {}",
+                    key
+                )));
+            }
+
+            if let Err(e) = callbacks.set_echo_session_intervals(
+                session.sai_oid,
+                config.tx_interval_ms,
+                config.rx_timeout_ms,
+            ) {
+                error_log!("IcmpOrch", session = %key, error = %e, "Failed to update ICMP echo session");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::SaiOperation,
+                    "IcmpOrch",
+                    "set_echo_session_intervals"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(key.to_string())
+                .with_object_type("icmp_echo_session")
+                .with_error(e.to_string()));
+                return Err(e);
+            }
+            session.config = config;
+
+            info_log!("IcmpOrch", session = %key, "ICMP echo session intervals updated");
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "IcmpOrch",
+                "update_echo_session"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(key.to_string())
+            .with_object_type("icmp_echo_session")
+            .with_details(serde_json::json!({
+                "tx_interval": session.config.tx_interval_ms,
+                "rx_timeout": session.config.rx_timeout_ms
+            })));
+            return Ok(());
+        }
+
+        let sai_oid = match callbacks.create_echo_session(&key, &config) {
+            Ok(oid) => oid,
+            Err(e) => {
+                error_log!("IcmpOrch", session = %key, error = %e, "Failed to create ICMP echo session");
+                audit_log!(AuditRecord::new(
+                    AuditCategory::SaiOperation,
+                    "IcmpOrch",
+                    "create_echo_session"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(key.to_string())
+                .with_object_type("icmp_echo_session")
+                .with_error(e.to_string()));
+                return Err(e);
+            }
+        };
+
+        callbacks.write_session_state(&key, IcmpSessionState::Down);
+        self.session_oids.insert(sai_oid, key.clone());
+        self.sessions.insert(
+            key.clone(),
+            IcmpSessionEntry {
+                key: key.clone(),
+                config: config.clone(),
+                sai_oid,
+                state: IcmpSessionState::Down,
+            },
+        );
+        self.stats.sessions_created += 1;
+
+        info_log!("IcmpOrch", session = %key, "ICMP echo session created successfully");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "IcmpOrch",
+            "create_echo_session"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key.to_string())
+        .with_object_type("icmp_echo_session")
+        .with_details(serde_json::json!({
+            "sai_oid": format!("0x{:x}", sai_oid),
+            "src_ip": config.src_ip.to_string(),
+            "dst_ip": config.dst_ip.to_string(),
+            "tx_interval": config.tx_interval_ms,
+            "rx_timeout": config.rx_timeout_ms
+        })));
+        Ok(())
+    }
+
+    /// Removes an ICMP echo session. Removing an unknown session succeeds.
+    pub fn remove_session(&mut self, key: &IcmpSessionKey) -> Result<()> {
+        let Some(session) = self.sessions.get(key) else {
+            debug_log!("IcmpOrch", session = %key, "ICMP echo session already removed");
+            return Ok(());
+        };
+        let sai_oid = session.sai_oid;
+
+        let callbacks = Arc::clone(self.callbacks.as_ref().ok_or_else(|| {
+            error_log!("IcmpOrch", "Callbacks not configured");
+            IcmpOrchError::NotInitialized
+        })?);
+
+        if let Err(e) = callbacks.remove_echo_session(sai_oid) {
+            error_log!("IcmpOrch", session = %key, error = %e, "Failed to remove ICMP echo session");
+            audit_log!(AuditRecord::new(
+                AuditCategory::SaiOperation,
+                "IcmpOrch",
+                "remove_echo_session"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(key.to_string())
+            .with_object_type("icmp_echo_session")
+            .with_error(e.to_string()));
+            return Err(e);
+        }
+
+        self.sessions.remove(key);
+        self.session_oids.remove(&sai_oid);
+        callbacks.remove_session_state(key);
+        self.stats.sessions_removed += 1;
+
+        info_log!("IcmpOrch", session = %key, "ICMP echo session removed successfully");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "IcmpOrch",
+            "remove_echo_session"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key.to_string())
+        .with_object_type("icmp_echo_session")
+        .with_details(serde_json::json!({
+            "sai_oid": format!("0x{:x}", sai_oid)
+        })));
+        Ok(())
+    }
+
+    /// Handles a SAI ICMP echo session state change notification.
+    ///
+    /// Returns false if the session no longer exists, e.g. when the
+    /// notification raced with its removal.
+    pub fn handle_session_state_change(
+        &mut self,
+        sai_oid: RawSaiObjectId,
+        state: IcmpSessionState,
+    ) -> bool {
+        let Some(session) = self
+            .session_oids
+            .get(&sai_oid)
+            .and_then(|key| self.sessions.get_mut(key))
+        else {
+            warn_log!(
+                "IcmpOrch",
+                sai_oid = sai_oid,
+                "State notification for unknown ICMP echo session"
+            );
+            self.stats.stale_notifications += 1;
+            return false;
+        };
+
+        if session.state == state {
+            return true;
+        }
+        session.state = state;
+        self.stats.session_state_changes += 1;
+
+        if let Some(callbacks) = &self.callbacks {
+            callbacks.write_session_state(&session.key, state);
+        }
+
+        info_log!(
+            "IcmpOrch",
+            session = %session.key,
+            state = state.as_str(),
+            "ICMP echo session state changed"
+        );
+        audit_log!(AuditRecord::new(
+            AuditCategory::NetworkConfig,
+            "IcmpOrch",
+            "echo_session_state_change"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(session.key.to_string())
+        .with_object_type("icmp_echo_session")
+        .with_details(serde_json::json!({
+            "state": state.as_str()
+        })));
+        true
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::types::{DEFAULT_RX_TIMEOUT_MS, DEFAULT_TX_INTERVAL_MS};
     use super::*;
-    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::sync::Mutex;
 
     struct MockIcmpCallbacks;
 
@@ -418,6 +727,124 @@ mod tests {
 
         fn on_redirect_processed(&self, _src_ip: &str) {}
         fn on_neighbor_discovery_complete(&self, _neighbor_ip: &str) {}
+
+        fn create_echo_session(
+            &self,
+            _key: &IcmpSessionKey,
+            _config: &IcmpSessionConfig,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x1)
+        }
+
+        fn remove_echo_session(&self, _sai_oid: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_echo_session_intervals(
+            &self,
+            _sai_oid: RawSaiObjectId,
+            _tx_interval_ms: u32,
+            _rx_timeout_ms: u32,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn write_session_state(&self, _key: &IcmpSessionKey, _state: IcmpSessionState) {}
+        fn remove_session_state(&self, _key: &IcmpSessionKey) {}
+    }
+
+    /// Records ICMP echo session SAI calls and STATE_DB writes.
+    #[derive(Default)]
+    struct SessionCallbacks {
+        next_oid: Mutex<RawSaiObjectId>,
+        sai_sessions: Mutex<HashMap<RawSaiObjectId, IcmpSessionConfig>>,
+        state_db: Mutex<HashMap<String, IcmpSessionState>>,
+        interval_updates: Mutex<u32>,
+    }
+
+    impl IcmpOrchCallbacks for SessionCallbacks {
+        fn configure_icmp_redirect(&self, _config: &IcmpRedirectConfig) -> Result<()> {
+            Ok(())
+        }
+
+        fn configure_neighbor_discovery(&self, _config: &NeighborDiscoveryConfig) -> Result<()> {
+            Ok(())
+        }
+
+        fn process_redirect(&self, _src_ip: &str, _dst_ip: &str, _gateway_ip: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_icmp_statistics(&self) -> Result<IcmpStats> {
+            Ok(IcmpStats::default())
+        }
+
+        fn on_redirect_processed(&self, _src_ip: &str) {}
+        fn on_neighbor_discovery_complete(&self, _neighbor_ip: &str) {}
+
+        fn create_echo_session(
+            &self,
+            _key: &IcmpSessionKey,
+            config: &IcmpSessionConfig,
+        ) -> Result<RawSaiObjectId> {
+            let mut next_oid = self.next_oid.lock().unwrap();
+            *next_oid += 1;
+            let oid = 0x4c00_0000_0000 + *next_oid;
+            self.sai_sessions
+                .lock()
+                .unwrap()
+                .insert(oid, config.clone());
+            Ok(oid)
+        }
+
+        fn remove_echo_session(&self, sai_oid: RawSaiObjectId) -> Result<()> {
+            self.sai_sessions
+                .lock()
+                .unwrap()
+                .remove(&sai_oid)
+                .map(|_| ())
+                .ok_or_else(|| IcmpOrchError::SaiError(format!("unknown session 0x{:x}", sai_oid)))
+        }
+
+        fn set_echo_session_intervals(
+            &self,
+            sai_oid: RawSaiObjectId,
+            tx_interval_ms: u32,
+            rx_timeout_ms: u32,
+        ) -> Result<()> {
+            let mut sessions = self.sai_sessions.lock().unwrap();
+            let config = sessions.get_mut(&sai_oid).ok_or_else(|| {
+                IcmpOrchError::SaiError(format!("unknown session 0x{:x}", sai_oid))
+            })?;
+            config.tx_interval_ms = tx_interval_ms;
+            config.rx_timeout_ms = rx_timeout_ms;
+            *self.interval_updates.lock().unwrap() += 1;
+            Ok(())
+        }
+
+        fn write_session_state(&self, key: &IcmpSessionKey, state: IcmpSessionState) {
+            self.state_db.lock().unwrap().insert(key.to_string(), state);
+        }
+
+        fn remove_session_state(&self, key: &IcmpSessionKey) {
+            self.state_db.lock().unwrap().remove(&key.to_string());
+        }
+    }
+
+    fn session_orch() -> (IcmpOrch<SessionCallbacks>, Arc<SessionCallbacks>) {
+        let callbacks = Arc::new(SessionCallbacks::default());
+        let orch = IcmpOrch::new(IcmpOrchConfig::default()).with_callbacks(Arc::clone(&callbacks));
+        (orch, callbacks)
+    }
+
+    fn session_task(key: &str, fields: &[(&str, &str)]) -> KeyOpFieldsValues {
+        KeyOpFieldsValues::set(
+            key,
+            fields
+                .iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect(),
+        )
     }
 
     #[test]
@@ -699,4 +1126,174 @@ mod tests {
             .process_icmp_redirect("192.168.1.1", "10.0.0.1", "192.168.1.254")
             .is_err());
     }
+
+    // ===== ICMP echo session tests =====
+
+    #[test]
+    fn test_session_create_with_defaults() {
+        let (mut orch, callbacks) = session_orch();
+        let task = session_task(
+            "default:Ethernet0:5000",
+            &[("src_ip", "10.0.0.1"), ("dst_ip", "10.0.0.2")],
+        );
+
+        assert!(orch.process_session_task(&task).is_ok());
+
+        let key = IcmpSessionKey::parse("default:Ethernet0:5000").unwrap();
+        let session = orch.get_session(&key).unwrap();
+        assert_eq!(session.config.tx_interval_ms, DEFAULT_TX_INTERVAL_MS);
+        assert_eq!(session.config.rx_timeout_ms, DEFAULT_RX_TIMEOUT_MS);
+        assert_eq!(session.state, IcmpSessionState::Down);
+        assert_eq!(callbacks.sai_sessions.lock().unwrap().len(), 1);
+        assert_eq!(
+            callbacks
+                .state_db
+                .lock()
+                .unwrap()
+                .get("default:Ethernet0:5000"),
+            Some(&IcmpSessionState::Down)
+        );
+        assert_eq!(orch.stats().sessions_created, 1);
+    }
+
+    #[test]
+    fn test_session_interval_validation() {
+        let (mut orch, callbacks) = session_orch();
+        let key = "default:Ethernet0:5000";
+        let base = [("src_ip", "10.0.0.1"), ("dst_ip", "10.0.0.2")];
+
+        for fields in [
+            vec![("tx_interval", "0")],
+            vec![("tx_interval", "abc")],
+            vec![("rx_timeout", "2000000")],
+            vec![("tx_interval", "500"), ("rx_timeout", "100")],
+        ] {
+            let fields: Vec<_> = base.iter().copied().chain(fields).collect();
+            assert!(matches!(
+                orch.process_session_task(&session_task(key, &fields)),
+                Err(IcmpOrchError::InvalidConfig(_))
+            ));
+        }
+
+        // Mixed address families and malformed keys are rejected too
+        assert!(orch
+            .process_session_task(&session_task(
+                key,
+                &[("src_ip", "10.0.0.1"), ("dst_ip", "2001:db8::1")]
+            ))
+            .is_err());
+        assert!(orch
+            .process_session_task(&session_task("default:Ethernet0", &base))
+            .is_err());
+
+        assert_eq!(orch.get_session_count(), 0);
+        assert!(callbacks.sai_sessions.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_session_update_intervals() {
+        let (mut orch, callbacks) = session_orch();
+        let key = "Vrf-RED:Ethernet4:6000";
+        let addrs = [("src_ip", "2001:db8::1"), ("dst_ip", "2001:db8::2")];
+        assert!(orch
+            .process_session_task(&session_task(key, &addrs))
+            .is_ok());
+
+        // Same config is a no-op
+        assert!(orch
+            .process_session_task(&session_task(key, &addrs))
+            .is_ok());
+        assert_eq!(*callbacks.interval_updates.lock().unwrap(), 0);
+
+        let mut fields = addrs.to_vec();
+        fields.extend([("tx_interval", "100"), ("rx_timeout", "300")]);
+        assert!(orch
+            .process_session_task(&session_task(key, &fields))
+            .is_ok());
+        assert_eq!(*callbacks.interval_updates.lock().unwrap(), 1);
+
+        let parsed = IcmpSessionKey::parse(key).unwrap();
+        let session = orch.get_session(&parsed).unwrap();
+        assert_eq!(session.config.tx_interval_ms, 100);
+        assert_eq!(
+            callbacks.sai_sessions.lock().unwrap()[&session.sai_oid].rx_timeout_ms,
+            300
+        );
+
+        // Addresses are immutable
+        assert!(orch
+            .process_session_task(&session_task(
+                key,
+                &[("src_ip", "2001:db8::1"), ("dst_ip", "2001:db8::3")]
+            ))
+            .is_err());
+        assert_eq!(orch.stats().sessions_created, 1);
+    }
+
+    #[test]
+    fn test_session_remove_idempotent() {
+        let (mut orch, callbacks) = session_orch();
+        let key = IcmpSessionKey::parse("default:Ethernet0:5000").unwrap();
+        let config = IcmpSessionConfig::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+        );
+        assert!(orch.add_session(key.clone(), config).is_ok());
+
+        let del = KeyOpFieldsValues::del("default:Ethernet0:5000");
+        assert!(orch.process_session_task(&del).is_ok());
+        assert!(orch.process_session_task(&del).is_ok());
+        assert!(orch.remove_session(&key).is_ok());
+
+        assert_eq!(orch.get_session_count(), 0);
+        assert!(callbacks.sai_sessions.lock().unwrap().is_empty());
+        assert!(callbacks.state_db.lock().unwrap().is_empty());
+        assert_eq!(orch.stats().sessions_removed, 1);
+    }
+
+    #[test]
+    fn test_session_state_notification() {
+        let (mut orch, callbacks) = session_orch();
+        let key = IcmpSessionKey::parse("default:Ethernet0:5000").unwrap();
+        let config = IcmpSessionConfig::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+        );
+        assert!(orch.add_session(key.clone(), config).is_ok());
+        let sai_oid = orch.get_session(&key).unwrap().sai_oid;
+
+        assert!(orch.handle_session_state_change(sai_oid, IcmpSessionState::Up));
+        assert_eq!(orch.get_session(&key).unwrap().state, IcmpSessionState::Up);
+        assert_eq!(
+            callbacks
+                .state_db
+                .lock()
+                .unwrap()
+                .get("default:Ethernet0:5000"),
+            Some(&IcmpSessionState::Up)
+        );
+
+        // Repeated state is not counted as a change
+        assert!(orch.handle_session_state_change(sai_oid, IcmpSessionState::Up));
+        assert_eq!(orch.stats().session_state_changes, 1);
+    }
+
+    #[test]
+    fn test_session_notification_after_delete() {
+        let (mut orch, callbacks) = session_orch();
+        let key = IcmpSessionKey::parse("default:Ethernet0:5000").unwrap();
+        let config = IcmpSessionConfig::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+        );
+        assert!(orch.add_session(key.clone(), config).is_ok());
+        let sai_oid = orch.get_session(&key).unwrap().sai_oid;
+        assert!(orch.remove_session(&key).is_ok());
+
+        // A notification queued before removal must not resurrect state
+        assert!(!orch.handle_session_state_change(sai_oid, IcmpSessionState::Up));
+        assert!(callbacks.state_db.lock().unwrap().is_empty());
+        assert_eq!(orch.stats().stale_notifications, 1);
+        assert_eq!(orch.stats().session_state_changes, 0);
+    }
 }
@@ -72,6 +72,97 @@ impl Default for NeighborDiscoveryConfig {
     }
 }
 
+/// Default ICMP echo transmit interval in milliseconds.
+pub const DEFAULT_TX_INTERVAL_MS: u32 = 1000;
+/// Default ICMP echo receive timeout in milliseconds.
+pub const DEFAULT_RX_TIMEOUT_MS: u32 = 3000;
+/// Smallest transmit interval/receive timeout accepted, in milliseconds.
+pub const MIN_SESSION_INTERVAL_MS: u32 = 3;
+/// Largest transmit interval/receive timeout accepted, in milliseconds.
+pub const MAX_SESSION_INTERVAL_MS: u32 = 1_200_000;
+
+/// ICMP_ECHO_SESSION_TABLE key: `<vrf>:<interface>:<guid>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IcmpSessionKey {
+    pub vrf_name: String,
+    pub interface: String,
+    pub guid: String,
+}
+
+impl IcmpSessionKey {
+    pub fn new(vrf_name: String, interface: String, guid: String) -> Self {
+        Self {
+            vrf_name,
+            interface,
+            guid,
+        }
+    }
+
+    /// Parses an APPL_DB key. Returns None unless all three parts are present.
+    pub fn parse(key: &str) -> Option<Self> {
+        let mut parts = key.splitn(3, ':');
+        let vrf_name = parts.next().filter(|s| !s.is_empty())?;
+        let interface = parts.next().filter(|s| !s.is_empty())?;
+        let guid = parts.next().filter(|s| !s.is_empty())?;
+        Some(Self::new(
+            vrf_name.to_string(),
+            interface.to_string(),
+            guid.to_string(),
+        ))
+    }
+}
+
+impl std::fmt::Display for IcmpSessionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.vrf_name, self.interface, self.guid)
+    }
+}
+
+/// ICMP echo session configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcmpSessionConfig {
+    pub src_ip: IpAddr,
+    pub dst_ip: IpAddr,
+    pub tx_interval_ms: u32,
+    pub rx_timeout_ms: u32,
+}
+
+impl IcmpSessionConfig {
+    /// Creates a configuration with default intervals.
+    pub fn new(src_ip: IpAddr, dst_ip: IpAddr) -> Self {
+        Self {
+            src_ip,
+            dst_ip,
+            tx_interval_ms: DEFAULT_TX_INTERVAL_MS,
+            rx_timeout_ms: DEFAULT_RX_TIMEOUT_MS,
+        }
+    }
+}
+
+/// ICMP echo session state as written to STATE_DB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IcmpSessionState {
+    Up,
+    Down,
+}
+
+impl IcmpSessionState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Up => "Up",
+            Self::Down => "Down",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IcmpSessionEntry {
+    pub key: IcmpSessionKey,
+    pub config: IcmpSessionConfig,
+    pub sai_oid: RawSaiObjectId,
+    pub state: IcmpSessionState,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +180,16 @@ mod tests {
         assert!(!config.enabled);
         assert_eq!(config.max_solicitation_delay, 1000);
     }
+
+    #[test]
+    fn test_icmp_session_key_parse() {
+        let key = IcmpSessionKey::parse("default:Ethernet0:5000").unwrap();
+        assert_eq!(key.vrf_name, "default");
+        assert_eq!(key.interface, "Ethernet0");
+        assert_eq!(key.guid, "5000");
+        assert_eq!(key.to_string(), "default:Ethernet0:5000");
+
+        assert!(IcmpSessionKey::parse("default:Ethernet0").is_none());
+        assert!(IcmpSessionKey::parse("default::5000").is_none());
+    }
 }
@@ -1,5 +1,12 @@
 //! IntfsOrch - Router interface orchestration for SONiC.
 //!
+//! Router interfaces are created per INTF_TABLE entry, including loopback
+//! interfaces, with an optional `mac_addr` source MAC override used for
+//! anycast gateways. Interfaces in IPv6 link-local only mode get a router
+//! interface without any global prefix. With `directed_broadcast` enabled,
+//! the broadcast address of each IPv4 subnet shorter than /31 is installed as
+//! a neighbor with the broadcast MAC.
+//!
 //! # Safety Improvements over C++
 //!
 //! The Rust implementation uses:
@@ -14,4 +21,4 @@ mod types;
 
 pub use ffi::{register_intfs_orch, unregister_intfs_orch};
 pub use orch::{IntfsOrch, IntfsOrchCallbacks, IntfsOrchConfig, IntfsOrchError, IntfsOrchStats};
-pub use types::{IntfsEntry, RifType};
+pub use types::{IntfConfig, IntfsEntry, RifType};
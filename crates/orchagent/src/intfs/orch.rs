@@ -1,7 +1,11 @@
 //! Router interface orchestration logic (stub).
 
-use super::types::IntfsEntry;
+use super::types::{subnet_broadcast, IntfConfig, IntfsEntry, RifType};
+use sonic_sai::types::RawSaiObjectId;
+use sonic_types::{IpAddress, IpPrefix, MacAddress};
 use std::collections::HashMap;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
 
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
@@ -11,6 +15,14 @@ use thiserror::Error;
 pub enum IntfsOrchError {
     #[error("Interface not found: {0}")]
     InterfaceNotFound(String),
+    #[error("Interface in use: {0}")]
+    InterfaceInUse(String),
+    #[error("VRF not found: {0}")]
+    VrfNotFound(String),
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("SAI error: {0}")]
+    SaiError(String),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -19,14 +31,58 @@ pub struct IntfsOrchConfig {}
 #[derive(Debug, Clone, Default)]
 pub struct IntfsOrchStats {
     pub interfaces_created: u64,
+    pub interfaces_removed: u64,
+    /// Source MAC updates applied to live router interfaces.
+    pub mac_updates: u64,
 }
 
-pub trait IntfsOrchCallbacks: Send + Sync {}
+/// Callbacks used by IntfsOrch to reach VrfOrch and SAI.
+pub trait IntfsOrchCallbacks: Send + Sync {
+    /// Looks up a VRF ID by name in VrfOrch.
+    fn get_vrf_id(&self, vrf_name: &str) -> Option<RawSaiObjectId>;
+
+    /// Increments the VRF ref count in VrfOrch.
+    fn increase_vrf_ref_count(&self, vrf_id: RawSaiObjectId);
+
+    /// Decrements the VRF ref count in VrfOrch.
+    fn decrease_vrf_ref_count(&self, vrf_id: RawSaiObjectId);
+
+    /// Creates the SAI router interface for an entry.
+    ///
+    /// The port, LAG or VLAN object is looked up from `alias`; loopback
+    /// router interfaces have none.
+    fn create_router_intf(&self, alias: &str, entry: &IntfsEntry)
+        -> Result<RawSaiObjectId, String>;
+
+    /// Removes a SAI router interface.
+    fn remove_router_intf(&self, rif_id: RawSaiObjectId) -> Result<(), String>;
+
+    /// Sets SAI_ROUTER_INTERFACE_ATTR_SRC_MAC_ADDRESS on a live router
+    /// interface. None restores the switch MAC.
+    fn set_router_intf_src_mac(
+        &self,
+        rif_id: RawSaiObjectId,
+        mac: Option<&MacAddress>,
+    ) -> Result<(), String>;
+
+    /// Installs the subnet and ip2me routes for an interface address.
+    fn add_subnet_routes(&self, _alias: &str, _vrf_id: RawSaiObjectId, _prefix: &IpPrefix) {}
+
+    /// Removes the subnet and ip2me routes of an interface address.
+    fn remove_subnet_routes(&self, _alias: &str, _vrf_id: RawSaiObjectId, _prefix: &IpPrefix) {}
+
+    /// Adds a static neighbor entry in NeighOrch.
+    fn add_static_neighbor(&self, _alias: &str, _ip: &IpAddress, _mac: &MacAddress) {}
+
+    /// Removes a static neighbor entry from NeighOrch.
+    fn remove_static_neighbor(&self, _alias: &str, _ip: &IpAddress) {}
+}
 
 pub struct IntfsOrch {
     config: IntfsOrchConfig,
     stats: IntfsOrchStats,
     interfaces: HashMap<String, IntfsEntry>,
+    callbacks: Option<Arc<dyn IntfsOrchCallbacks>>,
 }
 
 impl IntfsOrch {
@@ -35,9 +91,14 @@ impl IntfsOrch {
             config,
             stats: IntfsOrchStats::default(),
             interfaces: HashMap::new(),
+            callbacks: None,
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn IntfsOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
     pub fn stats(&self) -> &IntfsOrchStats {
         &self.stats
     }
@@ -46,11 +107,21 @@ impl IntfsOrch {
         self.interfaces.get(name)
     }
 
+    /// Returns the SAI router interface ID of an interface, if created.
+    pub fn get_rif_id(&self, name: &str) -> Option<RawSaiObjectId> {
+        self.interfaces
+            .get(name)
+            .map(|entry| entry.rif_id)
+            .filter(|&id| id != 0)
+    }
+
     pub fn add_interface(&mut self, name: String, entry: IntfsEntry) {
         let interface_type = if name.starts_with("Vlan") {
             "VLAN"
         } else if name.starts_with("PortChannel") {
             "LAG"
+        } else if name.starts_with("Loopback") {
+            "loopback"
         } else {
             "physical"
         };
@@ -118,6 +189,147 @@ impl IntfsOrch {
         self.interfaces.len()
     }
 
+    /// Creates the router interface for an INTF_TABLE entry, or updates the
+    /// source MAC of an existing one in place.
+    ///
+    /// Loopback interfaces get a loopback router interface keyed by name.
+    /// A router interface in a non-default VRF holds a VRF reference, so the
+    /// VRF cannot be removed before it.
+    ///
+    /// With `ipv6_use_link_local_only` the router interface is created without
+    /// any global prefix. Clearing the flag once no addresses remain tears the
+    /// router interface down again and returns 0; this fails with
+    /// `InterfaceInUse` while neighbors still reference it.
+    pub fn set_intf(
+        &mut self,
+        alias: &str,
+        config: &IntfConfig,
+    ) -> Result<RawSaiObjectId, IntfsOrchError> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| IntfsOrchError::SaiError("Callbacks not set".to_string()))?;
+
+        let vrf_id = match &config.vrf_name {
+            Some(name) => callbacks
+                .get_vrf_id(name)
+                .ok_or_else(|| IntfsOrchError::VrfNotFound(name.clone()))?,
+            None => 0,
+        };
+
+        let link_local_cleared = self.interfaces.get(alias).is_some_and(|e| {
+            e.rif_id != 0
+                && e.ipv6_link_local_only
+                && !config.ipv6_use_link_local_only
+                && e.ip_addresses.is_empty()
+        });
+        if link_local_cleared {
+            // The router interface only existed for link-local mode
+            self.remove_intf(alias)?;
+            return Ok(0);
+        }
+
+        if let Some(entry) = self.interfaces.get_mut(alias).filter(|e| e.rif_id != 0) {
+            if entry.vrf_id != vrf_id {
+                return Err(IntfsOrchError::InvalidConfig(format!(
+                    "{}: VRF cannot change while the router interface exists",
+                    alias
+                )));
+            }
+            if entry.ipv6_link_local_only != config.ipv6_use_link_local_only {
+                entry.ipv6_link_local_only = config.ipv6_use_link_local_only;
+                for prefix in &entry.ip_addresses {
+                    if entry.ipv6_link_local_only {
+                        callbacks.remove_subnet_routes(alias, entry.vrf_id, prefix);
+                    } else {
+                        callbacks.add_subnet_routes(alias, entry.vrf_id, prefix);
+                    }
+                }
+            }
+            if entry.directed_broadcast != config.directed_broadcast {
+                entry.directed_broadcast = config.directed_broadcast;
+                for broadcast in entry.broadcast_addresses() {
+                    if entry.directed_broadcast {
+                        add_broadcast_neighbor(callbacks.as_ref(), alias, broadcast);
+                    } else {
+                        remove_broadcast_neighbor(callbacks.as_ref(), alias, broadcast);
+                    }
+                }
+            }
+            if entry.mac_address != config.mac_address {
+                callbacks
+                    .set_router_intf_src_mac(entry.rif_id, config.mac_address.as_ref())
+                    .map_err(IntfsOrchError::SaiError)?;
+
+                let audit_record =
+                    AuditRecord::new(AuditCategory::ResourceModify, "IntfsOrch", "set_src_mac")
+                        .with_outcome(AuditOutcome::Success)
+                        .with_object_id(alias)
+                        .with_object_type("interface")
+                        .with_details(serde_json::json!({
+                            "interface_name": alias,
+                            "rif_id": format!("0x{:x}", entry.rif_id),
+                            "old_mac": entry.mac_address.map(|m| m.to_string()),
+                            "new_mac": config.mac_address.map(|m| m.to_string()),
+                        }));
+                audit_log!(audit_record);
+
+                entry.mac_address = config.mac_address;
+                self.stats.mac_updates += 1;
+            }
+            return Ok(entry.rif_id);
+        }
+
+        let mut entry = self.interfaces.get(alias).cloned().unwrap_or_default();
+        entry.vrf_id = vrf_id;
+        entry.rif_type = RifType::from_alias(alias);
+        entry.mac_address = config.mac_address;
+        entry.ipv6_link_local_only = config.ipv6_use_link_local_only;
+        entry.directed_broadcast = config.directed_broadcast;
+        entry.rif_id = callbacks
+            .create_router_intf(alias, &entry)
+            .map_err(IntfsOrchError::SaiError)?;
+        if vrf_id != 0 {
+            callbacks.increase_vrf_ref_count(vrf_id);
+        }
+
+        let rif_id = entry.rif_id;
+        self.add_interface(alias.to_string(), entry);
+        Ok(rif_id)
+    }
+
+    /// Removes the router interface of an INTF_TABLE entry.
+    ///
+    /// Fails with `InterfaceInUse` while addresses or references (routes,
+    /// neighbors) remain, so the caller retries once they are gone.
+    pub fn remove_intf(&mut self, alias: &str) -> Result<(), IntfsOrchError> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| IntfsOrchError::SaiError("Callbacks not set".to_string()))?;
+
+        let entry = self
+            .interfaces
+            .get(alias)
+            .ok_or_else(|| IntfsOrchError::InterfaceNotFound(alias.to_string()))?;
+        if entry.ref_count > 0 || !entry.ip_addresses.is_empty() {
+            return Err(IntfsOrchError::InterfaceInUse(alias.to_string()));
+        }
+
+        if entry.rif_id != 0 {
+            callbacks
+                .remove_router_intf(entry.rif_id)
+                .map_err(IntfsOrchError::SaiError)?;
+            if entry.vrf_id != 0 {
+                callbacks.decrease_vrf_ref_count(entry.vrf_id);
+            }
+        }
+
+        self.remove_interface(alias);
+        self.stats.interfaces_removed += 1;
+        Ok(())
+    }
+
     /// Add IP address to an interface
     pub fn add_ip_address(
         &mut self,
@@ -127,7 +339,19 @@ impl IntfsOrch {
         match self.interfaces.get_mut(intf_name) {
             Some(entry) => {
                 let ip_str = ip_prefix.to_string();
-                entry.ip_addresses.insert(ip_prefix);
+                // Another prefix on the interface may share the broadcast
+                let broadcast = subnet_broadcast(&ip_prefix)
+                    .filter(|&b| entry.directed_broadcast && !entry.has_broadcast_address(b));
+                if entry.ip_addresses.insert(ip_prefix.clone()) {
+                    if let Some(callbacks) = &self.callbacks {
+                        if !entry.ipv6_link_local_only {
+                            callbacks.add_subnet_routes(intf_name, entry.vrf_id, &ip_prefix);
+                        }
+                        if let Some(broadcast) = broadcast {
+                            add_broadcast_neighbor(callbacks.as_ref(), intf_name, broadcast);
+                        }
+                    }
+                }
 
                 let audit_record =
                     AuditRecord::new(AuditCategory::ResourceCreate, "IntfsOrch", "add_ip_address")
@@ -168,6 +392,18 @@ impl IntfsOrch {
                 let removed = entry.ip_addresses.remove(&ip_prefix);
 
                 if removed {
+                    if let Some(callbacks) = &self.callbacks {
+                        if !entry.ipv6_link_local_only {
+                            callbacks.remove_subnet_routes(intf_name, entry.vrf_id, &ip_prefix);
+                        }
+                        let broadcast = subnet_broadcast(&ip_prefix).filter(|&b| {
+                            entry.directed_broadcast && !entry.has_broadcast_address(b)
+                        });
+                        if let Some(broadcast) = broadcast {
+                            remove_broadcast_neighbor(callbacks.as_ref(), intf_name, broadcast);
+                        }
+                    }
+
                     let audit_record = AuditRecord::new(
                         AuditCategory::ResourceDelete,
                         "IntfsOrch",
@@ -305,6 +541,21 @@ impl IntfsOrch {
     }
 }
 
+/// Installs a subnet broadcast address as a neighbor with the broadcast MAC,
+/// so directed broadcasts are forwarded onto the subnet.
+fn add_broadcast_neighbor(callbacks: &dyn IntfsOrchCallbacks, alias: &str, broadcast: Ipv4Addr) {
+    callbacks.add_static_neighbor(
+        alias,
+        &IpAddress::V4(broadcast.into()),
+        &MacAddress::BROADCAST,
+    );
+}
+
+/// Removes a subnet broadcast neighbor.
+fn remove_broadcast_neighbor(callbacks: &dyn IntfsOrchCallbacks, alias: &str, broadcast: Ipv4Addr) {
+    callbacks.remove_static_neighbor(alias, &IpAddress::V4(broadcast.into()));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,6 +628,7 @@ mod tests {
     fn test_intfs_orch_stats_clone() {
         let stats1 = IntfsOrchStats {
             interfaces_created: 42,
+            ..Default::default()
         };
         let stats2 = stats1.clone();
 
@@ -391,6 +643,7 @@ mod tests {
             IntfsOrchError::InterfaceNotFound(name) => {
                 assert_eq!(name, "Ethernet0");
             }
+            other => panic!("unexpected error: {:?}", other),
         }
     }
 
@@ -403,6 +656,7 @@ mod tests {
             (IntfsOrchError::InterfaceNotFound(n1), IntfsOrchError::InterfaceNotFound(n2)) => {
                 assert_eq!(n1, n2);
             }
+            other => panic!("unexpected errors: {:?}", other),
         }
     }
 
@@ -416,6 +670,7 @@ mod tests {
             ref_count: 0,
             vrf_id: 0,
             proxy_arp: false,
+            ..Default::default()
         };
         orch.interfaces
             .insert("Ethernet0".to_string(), entry.clone());
@@ -454,6 +709,7 @@ mod tests {
             ref_count: 0,
             vrf_id: 0,
             proxy_arp: false,
+            ..Default::default()
         };
         orch.interfaces.insert("Ethernet0".to_string(), entry);
 
@@ -470,6 +726,7 @@ mod tests {
             ref_count: 0,
             vrf_id: 0x1234,
             proxy_arp: false,
+            ..Default::default()
         };
         orch.interfaces.insert("Ethernet0".to_string(), entry);
 
@@ -486,6 +743,7 @@ mod tests {
             ref_count: 0,
             vrf_id: 0,
             proxy_arp: true,
+            ..Default::default()
         };
         orch.interfaces.insert("Ethernet0".to_string(), entry);
 
@@ -502,6 +760,7 @@ mod tests {
             ref_count: 5,
             vrf_id: 0,
             proxy_arp: false,
+            ..Default::default()
         };
         orch.interfaces.insert("Ethernet0".to_string(), entry);
 
@@ -541,6 +800,7 @@ mod tests {
             IntfsOrchError::InterfaceNotFound(name) => {
                 assert_eq!(name, "Vlan100");
             }
+            other => panic!("unexpected error: {:?}", other),
         }
     }
 
@@ -553,6 +813,7 @@ mod tests {
             (IntfsOrchError::InterfaceNotFound(n1), IntfsOrchError::InterfaceNotFound(n2)) => {
                 assert_ne!(n1, n2);
             }
+            other => panic!("unexpected errors: {:?}", other),
         }
     }
 
@@ -754,6 +1015,7 @@ mod tests {
             ref_count: 0,
             vrf_id: 0,
             proxy_arp: false,
+            ..Default::default()
         };
         orch.interfaces.insert("Ethernet0".to_string(), entry);
 
@@ -780,4 +1042,403 @@ mod tests {
         assert!(orch.get_interface("ethernet0").is_none());
         assert!(orch.get_interface("ETHERNET0").is_none());
     }
+
+    // ===== Router interface tests =====
+
+    use super::super::types::{IntfConfig, RifType};
+    use std::sync::Mutex;
+
+    type CreatedRif = (String, RifType, RawSaiObjectId, Option<MacAddress>);
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        vrfs: Mutex<HashMap<String, RawSaiObjectId>>,
+        vrf_refs: Mutex<HashMap<RawSaiObjectId, u32>>,
+        created: Mutex<Vec<CreatedRif>>,
+        removed: Mutex<Vec<RawSaiObjectId>>,
+        mac_sets: Mutex<Vec<(RawSaiObjectId, Option<MacAddress>)>>,
+        next_rif: Mutex<RawSaiObjectId>,
+        subnet_routes: Mutex<HashSet<(String, IpPrefix)>>,
+        static_neighbors: Mutex<HashMap<(String, IpAddress), MacAddress>>,
+    }
+
+    impl MockCallbacks {
+        fn add_vrf(&self, name: &str, vrf_id: RawSaiObjectId) {
+            self.vrfs.lock().unwrap().insert(name.to_string(), vrf_id);
+        }
+
+        fn vrf_ref_count(&self, vrf_id: RawSaiObjectId) -> u32 {
+            self.vrf_refs
+                .lock()
+                .unwrap()
+                .get(&vrf_id)
+                .copied()
+                .unwrap_or(0)
+        }
+
+        // Mirrors VrfOrch::remove_vrf, which refuses to remove a VRF in use
+        fn remove_vrf(&self, name: &str) -> bool {
+            let mut vrfs = self.vrfs.lock().unwrap();
+            match vrfs.get(name) {
+                Some(&vrf_id) if self.vrf_ref_count(vrf_id) == 0 => {
+                    vrfs.remove(name);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl IntfsOrchCallbacks for MockCallbacks {
+        fn get_vrf_id(&self, vrf_name: &str) -> Option<RawSaiObjectId> {
+            self.vrfs.lock().unwrap().get(vrf_name).copied()
+        }
+
+        fn increase_vrf_ref_count(&self, vrf_id: RawSaiObjectId) {
+            *self.vrf_refs.lock().unwrap().entry(vrf_id).or_insert(0) += 1;
+        }
+
+        fn decrease_vrf_ref_count(&self, vrf_id: RawSaiObjectId) {
+            if let Some(count) = self.vrf_refs.lock().unwrap().get_mut(&vrf_id) {
+                *count = count.saturating_sub(1);
+            }
+        }
+
+        fn create_router_intf(
+            &self,
+            alias: &str,
+            entry: &IntfsEntry,
+        ) -> Result<RawSaiObjectId, String> {
+            self.created.lock().unwrap().push((
+                alias.to_string(),
+                entry.rif_type,
+                entry.vrf_id,
+                entry.mac_address,
+            ));
+            let mut next = self.next_rif.lock().unwrap();
+            *next += 1;
+            Ok(0x6000 + *next)
+        }
+
+        fn remove_router_intf(&self, rif_id: RawSaiObjectId) -> Result<(), String> {
+            self.removed.lock().unwrap().push(rif_id);
+            Ok(())
+        }
+
+        fn set_router_intf_src_mac(
+            &self,
+            rif_id: RawSaiObjectId,
+            mac: Option<&MacAddress>,
+        ) -> Result<(), String> {
+            self.mac_sets.lock().unwrap().push((rif_id, mac.copied()));
+            Ok(())
+        }
+
+        fn add_subnet_routes(&self, alias: &str, _vrf_id: RawSaiObjectId, prefix: &IpPrefix) {
+            self.subnet_routes
+                .lock()
+                .unwrap()
+                .insert((alias.to_string(), prefix.clone()));
+        }
+
+        fn remove_subnet_routes(&self, alias: &str, _vrf_id: RawSaiObjectId, prefix: &IpPrefix) {
+            self.subnet_routes
+                .lock()
+                .unwrap()
+                .remove(&(alias.to_string(), prefix.clone()));
+        }
+
+        fn add_static_neighbor(&self, alias: &str, ip: &IpAddress, mac: &MacAddress) {
+            let previous = self
+                .static_neighbors
+                .lock()
+                .unwrap()
+                .insert((alias.to_string(), *ip), *mac);
+            assert!(previous.is_none(), "duplicate neighbor {} {}", alias, ip);
+        }
+
+        fn remove_static_neighbor(&self, alias: &str, ip: &IpAddress) {
+            let removed = self
+                .static_neighbors
+                .lock()
+                .unwrap()
+                .remove(&(alias.to_string(), *ip));
+            assert!(removed.is_some(), "unknown neighbor {} {}", alias, ip);
+        }
+    }
+
+    impl MockCallbacks {
+        fn broadcast_neighbors(&self) -> Vec<(String, String)> {
+            let mut neighbors: Vec<_> = self
+                .static_neighbors
+                .lock()
+                .unwrap()
+                .iter()
+                .inspect(|(_, mac)| assert_eq!(**mac, MacAddress::BROADCAST))
+                .map(|((alias, ip), _)| (alias.clone(), ip.to_string()))
+                .collect();
+            neighbors.sort();
+            neighbors
+        }
+    }
+
+    fn orch_with_callbacks() -> (IntfsOrch, Arc<MockCallbacks>) {
+        let mut orch = IntfsOrch::new(IntfsOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::default());
+        orch.set_callbacks(callbacks.clone());
+        (orch, callbacks)
+    }
+
+    #[test]
+    fn test_anycast_mac_change_on_live_rif() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        let mac_a = MacAddress::new([0x00, 0x00, 0x5e, 0x00, 0x01, 0x01]);
+        let mac_b = MacAddress::new([0x00, 0x00, 0x5e, 0x00, 0x01, 0x02]);
+
+        let config = IntfConfig {
+            mac_address: Some(mac_a),
+            ..Default::default()
+        };
+        let rif_id = orch.set_intf("Vlan100", &config).unwrap();
+        assert_eq!(
+            callbacks.created.lock().unwrap()[0],
+            ("Vlan100".to_string(), RifType::Vlan, 0, Some(mac_a))
+        );
+
+        // Same MAC again is a no-op
+        assert_eq!(orch.set_intf("Vlan100", &config).unwrap(), rif_id);
+        assert!(callbacks.mac_sets.lock().unwrap().is_empty());
+
+        // A new anycast MAC is set in place on the existing RIF
+        let config = IntfConfig {
+            mac_address: Some(mac_b),
+            ..Default::default()
+        };
+        assert_eq!(orch.set_intf("Vlan100", &config).unwrap(), rif_id);
+        assert_eq!(
+            orch.get_interface("Vlan100").unwrap().mac_address,
+            Some(mac_b)
+        );
+
+        // Dropping mac_addr restores the switch MAC
+        orch.set_intf("Vlan100", &IntfConfig::default()).unwrap();
+        assert_eq!(
+            *callbacks.mac_sets.lock().unwrap(),
+            vec![(rif_id, Some(mac_b)), (rif_id, None)]
+        );
+        assert_eq!(callbacks.created.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().mac_updates, 2);
+        assert_eq!(orch.get_rif_id("Vlan100"), Some(rif_id));
+    }
+
+    #[test]
+    fn test_loopback_in_vrf_deleted_out_of_order() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        let config = IntfConfig {
+            vrf_name: Some("Vrf1".to_string()),
+            ..Default::default()
+        };
+
+        // The VRF is not there yet
+        assert!(matches!(
+            orch.set_intf("Loopback1", &config),
+            Err(IntfsOrchError::VrfNotFound(_))
+        ));
+
+        callbacks.add_vrf("Vrf1", 0x3000);
+        let rif_id = orch.set_intf("Loopback1", &config).unwrap();
+        assert_eq!(
+            callbacks.created.lock().unwrap()[0],
+            ("Loopback1".to_string(), RifType::Loopback, 0x3000, None)
+        );
+        assert_eq!(callbacks.vrf_ref_count(0x3000), 1);
+
+        let prefix = IpPrefix::from_str("10.1.0.1/32").unwrap();
+        orch.add_ip_address("Loopback1", prefix.clone()).unwrap();
+        orch.increase_ref_count("Loopback1").unwrap();
+
+        // VRF delete arrives first: it has to wait for the loopback
+        assert!(!callbacks.remove_vrf("Vrf1"));
+        assert!(matches!(
+            orch.remove_intf("Loopback1"),
+            Err(IntfsOrchError::InterfaceInUse(_))
+        ));
+
+        orch.remove_ip_address("Loopback1", prefix).unwrap();
+        assert!(orch.remove_intf("Loopback1").is_err());
+        orch.decrease_ref_count("Loopback1").unwrap();
+        orch.remove_intf("Loopback1").unwrap();
+
+        assert_eq!(*callbacks.removed.lock().unwrap(), vec![rif_id]);
+        assert_eq!(callbacks.vrf_ref_count(0x3000), 0);
+        assert!(orch.get_interface("Loopback1").is_none());
+        assert_eq!(orch.stats().interfaces_removed, 1);
+        assert!(callbacks.remove_vrf("Vrf1"));
+    }
+
+    #[test]
+    fn test_link_local_only_flag_neighbor_then_remove() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        let link_local = IntfConfig {
+            ipv6_use_link_local_only: true,
+            ..Default::default()
+        };
+
+        // No global prefix, but the RIF is created anyway
+        let rif_id = orch.set_intf("Ethernet8", &link_local).unwrap();
+        assert_ne!(rif_id, 0);
+        assert!(
+            orch.get_interface("Ethernet8")
+                .unwrap()
+                .ipv6_link_local_only
+        );
+
+        // Link-local addresses get no subnet or ip2me routes
+        let fe80 = IpPrefix::from_str("fe80::1/64").unwrap();
+        orch.add_ip_address("Ethernet8", fe80.clone()).unwrap();
+        assert!(callbacks.subnet_routes.lock().unwrap().is_empty());
+        orch.remove_ip_address("Ethernet8", fe80).unwrap();
+
+        // A neighbor resolves on the interface
+        orch.increase_ref_count("Ethernet8").unwrap();
+
+        // Removing the flag has to wait for the neighbor
+        assert!(matches!(
+            orch.set_intf("Ethernet8", &IntfConfig::default()),
+            Err(IntfsOrchError::InterfaceInUse(_))
+        ));
+        assert_eq!(orch.get_rif_id("Ethernet8"), Some(rif_id));
+        assert!(callbacks.removed.lock().unwrap().is_empty());
+
+        orch.decrease_ref_count("Ethernet8").unwrap();
+        assert_eq!(
+            orch.set_intf("Ethernet8", &IntfConfig::default()).unwrap(),
+            0
+        );
+        assert_eq!(*callbacks.removed.lock().unwrap(), vec![rif_id]);
+        assert!(orch.get_interface("Ethernet8").is_none());
+    }
+
+    #[test]
+    fn test_link_local_only_flag_cleared_with_addresses() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        let link_local = IntfConfig {
+            ipv6_use_link_local_only: true,
+            ..Default::default()
+        };
+        let rif_id = orch.set_intf("Ethernet8", &link_local).unwrap();
+        let prefix = IpPrefix::from_str("10.0.0.1/24").unwrap();
+        orch.add_ip_address("Ethernet8", prefix.clone()).unwrap();
+        assert!(callbacks.subnet_routes.lock().unwrap().is_empty());
+
+        // The address keeps the RIF once the flag goes away, and now gets
+        // its routes
+        assert_eq!(
+            orch.set_intf("Ethernet8", &IntfConfig::default()).unwrap(),
+            rif_id
+        );
+        assert!(
+            !orch
+                .get_interface("Ethernet8")
+                .unwrap()
+                .ipv6_link_local_only
+        );
+        assert!(callbacks.removed.lock().unwrap().is_empty());
+        assert!(callbacks
+            .subnet_routes
+            .lock()
+            .unwrap()
+            .contains(&("Ethernet8".to_string(), prefix.clone())));
+
+        // Turning link-local only mode back on withdraws them
+        orch.set_intf("Ethernet8", &link_local).unwrap();
+        assert!(callbacks.subnet_routes.lock().unwrap().is_empty());
+        orch.remove_ip_address("Ethernet8", prefix).unwrap();
+        assert!(callbacks.subnet_routes.lock().unwrap().is_empty());
+    }
+
+    fn directed_broadcast_intf(orch: &mut IntfsOrch, alias: &str) {
+        let config = IntfConfig {
+            directed_broadcast: true,
+            ..Default::default()
+        };
+        orch.set_intf(alias, &config).unwrap();
+    }
+
+    #[test]
+    fn test_directed_broadcast_overlapping_prefixes() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        directed_broadcast_intf(&mut orch, "Vlan100");
+        directed_broadcast_intf(&mut orch, "Vlan200");
+
+        // The same subnet on two interfaces: one neighbor per interface
+        let p1 = IpPrefix::from_str("10.0.0.1/24").unwrap();
+        let p2 = IpPrefix::from_str("10.0.0.2/24").unwrap();
+        orch.add_ip_address("Vlan100", p1.clone()).unwrap();
+        orch.add_ip_address("Vlan200", p2.clone()).unwrap();
+
+        // A second prefix sharing the broadcast adds nothing
+        let p3 = IpPrefix::from_str("10.0.0.3/24").unwrap();
+        orch.add_ip_address("Vlan100", p3.clone()).unwrap();
+
+        // /31 and /32 have no broadcast address
+        orch.add_ip_address("Vlan100", IpPrefix::from_str("10.1.0.0/31").unwrap())
+            .unwrap();
+        orch.add_ip_address("Vlan100", IpPrefix::from_str("10.2.0.1/32").unwrap())
+            .unwrap();
+        assert_eq!(
+            callbacks.broadcast_neighbors(),
+            vec![
+                ("Vlan100".to_string(), "10.0.0.255".to_string()),
+                ("Vlan200".to_string(), "10.0.0.255".to_string()),
+            ]
+        );
+
+        orch.remove_ip_address("Vlan100", p1).unwrap();
+        assert_eq!(callbacks.broadcast_neighbors().len(), 2);
+        orch.remove_ip_address("Vlan100", p3).unwrap();
+        assert_eq!(
+            callbacks.broadcast_neighbors(),
+            vec![("Vlan200".to_string(), "10.0.0.255".to_string())]
+        );
+
+        // Clearing the flag cleans up the remaining entry
+        orch.set_intf("Vlan200", &IntfConfig::default()).unwrap();
+        assert!(callbacks.broadcast_neighbors().is_empty());
+        orch.remove_ip_address("Vlan200", p2).unwrap();
+    }
+
+    #[test]
+    fn test_directed_broadcast_prefix_replacement() {
+        let (mut orch, callbacks) = orch_with_callbacks();
+        directed_broadcast_intf(&mut orch, "Ethernet0");
+
+        let old = IpPrefix::from_str("192.168.1.1/24").unwrap();
+        let new = IpPrefix::from_str("192.168.1.1/25").unwrap();
+        orch.add_ip_address("Ethernet0", old.clone()).unwrap();
+
+        // New prefix added before the old one is removed
+        orch.add_ip_address("Ethernet0", new.clone()).unwrap();
+        orch.remove_ip_address("Ethernet0", old.clone()).unwrap();
+        assert_eq!(
+            callbacks.broadcast_neighbors(),
+            vec![("Ethernet0".to_string(), "192.168.1.127".to_string())]
+        );
+
+        // And back, removing first
+        orch.remove_ip_address("Ethernet0", new).unwrap();
+        assert!(callbacks.broadcast_neighbors().is_empty());
+        orch.add_ip_address("Ethernet0", old).unwrap();
+        assert_eq!(
+            callbacks.broadcast_neighbors(),
+            vec![("Ethernet0".to_string(), "192.168.1.255".to_string())]
+        );
+
+        // Replaced by a /31: nothing left to forward to
+        orch.remove_ip_address("Ethernet0", IpPrefix::from_str("192.168.1.1/24").unwrap())
+            .unwrap();
+        orch.add_ip_address("Ethernet0", IpPrefix::from_str("192.168.1.0/31").unwrap())
+            .unwrap();
+        assert!(callbacks.broadcast_neighbors().is_empty());
+    }
 }
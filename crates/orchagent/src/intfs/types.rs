@@ -1,18 +1,35 @@
 //! Router interface types and structures.
 
 use sonic_sai::types::RawSaiObjectId;
-use sonic_types::IpPrefix;
-use std::collections::HashSet;
+use sonic_types::{IpPrefix, MacAddress};
+use std::collections::{BTreeSet, HashSet};
+use std::net::Ipv4Addr;
 
 /// Router interface type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum RifType {
+    #[default]
     Port,
     Vlan,
     SubPort,
     Loopback,
 }
 
+impl RifType {
+    /// Derives the router interface type from an interface name.
+    pub fn from_alias(alias: &str) -> Self {
+        if alias.starts_with("Vlan") {
+            Self::Vlan
+        } else if alias.starts_with("Loopback") {
+            Self::Loopback
+        } else if alias.contains('.') {
+            Self::SubPort
+        } else {
+            Self::Port
+        }
+    }
+}
+
 /// Interface entry (stub).
 #[derive(Debug, Clone, Default)]
 pub struct IntfsEntry {
@@ -20,9 +37,36 @@ pub struct IntfsEntry {
     pub ref_count: u32,
     pub vrf_id: RawSaiObjectId,
     pub proxy_arp: bool,
+    /// SAI router interface ID (0 until the RIF is created).
+    pub rif_id: RawSaiObjectId,
+    pub rif_type: RifType,
+    /// Source MAC override (anycast gateway MAC); None uses the switch MAC.
+    pub mac_address: Option<MacAddress>,
+    /// IPv6 link-local only mode: the RIF exists without a global prefix
+    /// and no subnet or ip2me routes are installed for it.
+    pub ipv6_link_local_only: bool,
+    /// Forward directed broadcasts by installing each IPv4 subnet broadcast
+    /// address as a neighbor with the broadcast MAC.
+    pub directed_broadcast: bool,
 }
 
 impl IntfsEntry {
+    /// Returns the distinct subnet broadcast addresses of the configured
+    /// prefixes.
+    pub fn broadcast_addresses(&self) -> BTreeSet<Ipv4Addr> {
+        self.ip_addresses
+            .iter()
+            .filter_map(subnet_broadcast)
+            .collect()
+    }
+
+    /// Returns true if any configured prefix has the given broadcast address.
+    pub fn has_broadcast_address(&self, broadcast: Ipv4Addr) -> bool {
+        self.ip_addresses
+            .iter()
+            .any(|prefix| subnet_broadcast(prefix) == Some(broadcast))
+    }
+
     pub fn add_ref(&mut self) -> u32 {
         self.ref_count = self.ref_count.saturating_add(1);
         self.ref_count
@@ -37,6 +81,63 @@ impl IntfsEntry {
     }
 }
 
+/// Returns the subnet broadcast address of an interface prefix.
+///
+/// IPv6 prefixes and IPv4 /31 and /32 prefixes have none.
+pub fn subnet_broadcast(prefix: &IpPrefix) -> Option<Ipv4Addr> {
+    let text = prefix.to_string();
+    let (addr, len) = text.split_once('/')?;
+    let addr: Ipv4Addr = addr.parse().ok()?;
+    let len: u32 = len.parse().ok()?;
+    if len >= 31 {
+        return None;
+    }
+    Some(Ipv4Addr::from(u32::from(addr) | (u32::MAX >> len)))
+}
+
+/// Attributes of an INTF_TABLE interface entry (a key without an IP prefix).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntfConfig {
+    /// VRF the interface is bound to; None for the default VRF.
+    pub vrf_name: Option<String>,
+    /// Source MAC override from the `mac_addr` field.
+    pub mac_address: Option<MacAddress>,
+    /// `ipv6_use_link_local_only` is set to "enable".
+    pub ipv6_use_link_local_only: bool,
+    /// `directed_broadcast` is set to "enable".
+    pub directed_broadcast: bool,
+}
+
+impl IntfConfig {
+    /// Parses the fields of an INTF_TABLE interface entry.
+    pub fn from_fields(fields: &[(String, String)]) -> Result<Self, String> {
+        let mut config = Self::default();
+        let enabled = |field: &str, value: &str| match value {
+            "enable" => Ok(true),
+            "disable" | "" => Ok(false),
+            _ => Err(format!("Invalid {}: {}", field, value)),
+        };
+        for (field, value) in fields {
+            match field.as_str() {
+                "vrf_name" if !value.is_empty() => config.vrf_name = Some(value.clone()),
+                "mac_addr" if !value.is_empty() => {
+                    config.mac_address = Some(
+                        value
+                            .parse::<MacAddress>()
+                            .map_err(|_| format!("Invalid mac_addr: {}", value))?,
+                    )
+                }
+                "ipv6_use_link_local_only" => {
+                    config.ipv6_use_link_local_only = enabled(field, value)?
+                }
+                "directed_broadcast" => config.directed_broadcast = enabled(field, value)?,
+                _ => {}
+            }
+        }
+        Ok(config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +149,57 @@ mod tests {
         assert_eq!(entry.remove_ref().unwrap(), 0);
         assert!(entry.remove_ref().is_err());
     }
+
+    #[test]
+    fn test_rif_type_from_alias() {
+        assert_eq!(RifType::from_alias("Ethernet0"), RifType::Port);
+        assert_eq!(RifType::from_alias("PortChannel1"), RifType::Port);
+        assert_eq!(RifType::from_alias("Vlan100"), RifType::Vlan);
+        assert_eq!(RifType::from_alias("Ethernet0.10"), RifType::SubPort);
+        assert_eq!(RifType::from_alias("Loopback0"), RifType::Loopback);
+    }
+
+    #[test]
+    fn test_intf_config_from_fields() {
+        let fields = vec![
+            ("vrf_name".to_string(), "Vrf1".to_string()),
+            ("mac_addr".to_string(), "00:aa:bb:cc:dd:ee".to_string()),
+        ];
+        let config = IntfConfig::from_fields(&fields).unwrap();
+        assert_eq!(config.vrf_name.as_deref(), Some("Vrf1"));
+        assert_eq!(
+            config.mac_address,
+            Some(MacAddress::new([0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0xee]))
+        );
+
+        assert_eq!(IntfConfig::from_fields(&[]).unwrap(), IntfConfig::default());
+        let bad = vec![("mac_addr".to_string(), "not-a-mac".to_string())];
+        assert!(IntfConfig::from_fields(&bad).is_err());
+
+        let fields = vec![("ipv6_use_link_local_only".to_string(), "enable".to_string())];
+        assert!(
+            IntfConfig::from_fields(&fields)
+                .unwrap()
+                .ipv6_use_link_local_only
+        );
+        let bad = vec![("ipv6_use_link_local_only".to_string(), "yes".to_string())];
+        assert!(IntfConfig::from_fields(&bad).is_err());
+
+        let fields = vec![("directed_broadcast".to_string(), "enable".to_string())];
+        assert!(IntfConfig::from_fields(&fields).unwrap().directed_broadcast);
+    }
+
+    #[test]
+    fn test_subnet_broadcast() {
+        let broadcast = |p: &str| subnet_broadcast(&p.parse().unwrap());
+        assert_eq!(broadcast("10.0.0.1/24"), Some(Ipv4Addr::new(10, 0, 0, 255)));
+        assert_eq!(broadcast("10.0.0.1/25"), Some(Ipv4Addr::new(10, 0, 0, 127)));
+        assert_eq!(
+            broadcast("192.168.4.9/30"),
+            Some(Ipv4Addr::new(192, 168, 4, 11))
+        );
+        assert_eq!(broadcast("10.0.0.0/31"), None);
+        assert_eq!(broadcast("10.0.0.1/32"), None);
+        assert_eq!(broadcast("fc00::1/64"), None);
+    }
 }
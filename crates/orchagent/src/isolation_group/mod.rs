@@ -16,6 +16,8 @@
 //! - Result-based error propagation
 //! - Type-safe IsolationGroupType enum
 //! - Pending operation tracking with safe iteration
+//! - Group type migration that builds the new group before releasing the
+//!   old one, rolling back on any unresolvable member
 //! - Proper cleanup via Drop trait
 
 mod ffi;
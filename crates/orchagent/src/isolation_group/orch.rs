@@ -40,6 +40,8 @@ pub struct IsolationGroupOrchStats {
     pub members_removed: u64,
     pub bindings_added: u64,
     pub bindings_removed: u64,
+    pub type_migrations: u64,
+    pub type_migrations_failed: u64,
 }
 
 pub trait IsolationGroupOrchCallbacks: Send + Sync {
@@ -64,6 +66,13 @@ pub trait IsolationGroupOrchCallbacks: Send + Sync {
     fn get_bridge_port_oid(&self, alias: &str) -> Option<RawSaiObjectId>;
 }
 
+/// SAI state of a group built during type migration.
+struct MigratedGroup {
+    oid: RawSaiObjectId,
+    members: HashMap<String, RawSaiObjectId>,
+    bound: Vec<RawSaiObjectId>,
+}
+
 pub struct IsolationGroupOrch {
     config: IsolationGroupOrchConfig,
     stats: IsolationGroupOrchStats,
@@ -154,6 +163,189 @@ impl IsolationGroupOrch {
         Ok(())
     }
 
+    /// Applies an ISOLATION_GROUP update: creates the group if absent, or
+    /// migrates it when the configured type differs from the programmed one.
+    pub fn set_isolation_group(
+        &mut self,
+        config: IsolationGroupConfig,
+    ) -> Result<(), IsolationGroupOrchError> {
+        let Some(group) = self.isolation_groups.get_mut(&config.name) else {
+            return self.create_isolation_group(config);
+        };
+        if config.description.is_some() {
+            group.description = config.description.clone();
+        }
+        if group.group_type == config.group_type {
+            return Ok(());
+        }
+        self.migrate_isolation_group_type(&config.name, config.group_type)
+    }
+
+    /// Moves a group to a new SAI isolation group of `target` type.
+    ///
+    /// Members and bind points are re-resolved (port OID vs bridge port OID)
+    /// and programmed on the new group before the old one is torn down. If
+    /// any step fails, everything created for the new group is removed and
+    /// the original group is left untouched.
+    pub fn migrate_isolation_group_type(
+        &mut self,
+        name: &str,
+        target: IsolationGroupType,
+    ) -> Result<(), IsolationGroupOrchError> {
+        let group = self
+            .isolation_groups
+            .get(name)
+            .ok_or_else(|| IsolationGroupOrchError::GroupNotFound(name.to_string()))?;
+        if group.group_type == target {
+            return Ok(());
+        }
+
+        let callbacks =
+            Arc::clone(self.callbacks.as_ref().ok_or_else(|| {
+                IsolationGroupOrchError::SaiError("No callbacks set".to_string())
+            })?);
+
+        let source = group.group_type;
+        let old_oid = group.oid;
+        let old_members: Vec<(String, RawSaiObjectId)> = group
+            .members
+            .iter()
+            .map(|(alias, oid)| (alias.clone(), *oid))
+            .collect();
+        let bind_ports = group.bind_ports.clone();
+
+        let migrated =
+            match Self::build_migrated_group(callbacks.as_ref(), target, &old_members, &bind_ports)
+            {
+                Ok(migrated) => migrated,
+                Err(e) => {
+                    let audit_record = AuditRecord::new(
+                        AuditCategory::ResourceModify,
+                        "IsolationGroupOrch",
+                        "migrate_isolation_group_type",
+                    )
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(name)
+                    .with_object_type("isolation_group")
+                    .with_error(e.to_string());
+                    audit_log!(audit_record);
+                    self.stats.type_migrations_failed += 1;
+                    return Err(e);
+                }
+            };
+
+        // The new group is fully programmed; release the old one.
+        for port_alias in &bind_ports {
+            if let Some(port_oid) = Self::resolve_oid(callbacks.as_ref(), source, port_alias) {
+                let _ = callbacks.unbind_isolation_group_from_port(port_oid);
+            }
+        }
+        for (_, member_oid) in &old_members {
+            let _ = callbacks.remove_isolation_group_member(*member_oid);
+        }
+        let _ = callbacks.remove_isolation_group(old_oid);
+
+        let group = self
+            .isolation_groups
+            .get_mut(name)
+            .ok_or_else(|| IsolationGroupOrchError::GroupNotFound(name.to_string()))?;
+        group.group_type = target;
+        group.oid = migrated.oid;
+        group.members = migrated.members;
+
+        let audit_record = AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "IsolationGroupOrch",
+            "migrate_isolation_group_type",
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(name)
+        .with_object_type("isolation_group")
+        .with_details(serde_json::json!({
+            "group_name": name,
+            "from_type": source.as_str(),
+            "to_type": target.as_str(),
+            "old_sai_oid": format!("0x{:x}", old_oid),
+            "new_sai_oid": format!("0x{:x}", migrated.oid),
+            "members": old_members.len(),
+            "bind_ports": bind_ports.len(),
+        }));
+        audit_log!(audit_record);
+
+        self.stats.type_migrations += 1;
+        Ok(())
+    }
+
+    fn resolve_oid(
+        callbacks: &dyn IsolationGroupOrchCallbacks,
+        group_type: IsolationGroupType,
+        alias: &str,
+    ) -> Option<RawSaiObjectId> {
+        match group_type {
+            IsolationGroupType::Port => callbacks.get_port_oid(alias),
+            IsolationGroupType::BridgePort => callbacks.get_bridge_port_oid(alias),
+        }
+    }
+
+    /// Creates a group of `target` type carrying the given members and
+    /// bindings, undoing its own work on failure.
+    fn build_migrated_group(
+        callbacks: &dyn IsolationGroupOrchCallbacks,
+        target: IsolationGroupType,
+        members: &[(String, RawSaiObjectId)],
+        bind_ports: &[String],
+    ) -> Result<MigratedGroup, IsolationGroupOrchError> {
+        let oid = callbacks
+            .create_isolation_group(target)
+            .map_err(IsolationGroupOrchError::SaiError)?;
+        let mut migrated = MigratedGroup {
+            oid,
+            members: HashMap::new(),
+            bound: Vec::new(),
+        };
+
+        let result =
+            Self::program_migrated_group(callbacks, target, members, bind_ports, &mut migrated);
+
+        if let Err(e) = result {
+            for port_oid in &migrated.bound {
+                let _ = callbacks.unbind_isolation_group_from_port(*port_oid);
+            }
+            for member_oid in migrated.members.values() {
+                let _ = callbacks.remove_isolation_group_member(*member_oid);
+            }
+            let _ = callbacks.remove_isolation_group(oid);
+            return Err(e);
+        }
+        Ok(migrated)
+    }
+
+    fn program_migrated_group(
+        callbacks: &dyn IsolationGroupOrchCallbacks,
+        target: IsolationGroupType,
+        members: &[(String, RawSaiObjectId)],
+        bind_ports: &[String],
+        migrated: &mut MigratedGroup,
+    ) -> Result<(), IsolationGroupOrchError> {
+        for (alias, _) in members {
+            let port_oid = Self::resolve_oid(callbacks, target, alias)
+                .ok_or_else(|| IsolationGroupOrchError::PortNotFound(alias.clone()))?;
+            let member_oid = callbacks
+                .add_isolation_group_member(migrated.oid, port_oid)
+                .map_err(IsolationGroupOrchError::SaiError)?;
+            migrated.members.insert(alias.clone(), member_oid);
+        }
+        for alias in bind_ports {
+            let port_oid = Self::resolve_oid(callbacks, target, alias)
+                .ok_or_else(|| IsolationGroupOrchError::PortNotFound(alias.clone()))?;
+            callbacks
+                .bind_isolation_group_to_port(port_oid, migrated.oid)
+                .map_err(IsolationGroupOrchError::SaiError)?;
+            migrated.bound.push(port_oid);
+        }
+        Ok(())
+    }
+
     pub fn remove_isolation_group(&mut self, name: &str) -> Result<(), IsolationGroupOrchError> {
         let entry = self.isolation_groups.remove(name).ok_or_else(|| {
             let audit_record = AuditRecord::new(
@@ -490,6 +682,7 @@ impl IsolationGroupOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
 
     struct MockCallbacks;
 
@@ -541,6 +734,102 @@ mod tests {
         }
     }
 
+    /// Callbacks with a fixed port table that track SAI objects, so tests can
+    /// check what a migration leaves behind.
+    struct RecordingCallbacks {
+        ports: HashMap<String, RawSaiObjectId>,
+        bridge_ports: HashMap<String, RawSaiObjectId>,
+        next_oid: Mutex<RawSaiObjectId>,
+        groups: Mutex<HashMap<RawSaiObjectId, IsolationGroupType>>,
+        /// member OID -> (group OID, port OID)
+        members: Mutex<HashMap<RawSaiObjectId, (RawSaiObjectId, RawSaiObjectId)>>,
+        /// port or bridge port OID -> group OID
+        bindings: Mutex<HashMap<RawSaiObjectId, RawSaiObjectId>>,
+    }
+
+    impl RecordingCallbacks {
+        /// Ethernet0 and Ethernet4 are VLAN members; Ethernet8 has no bridge port.
+        fn new() -> Self {
+            Self {
+                ports: HashMap::from([
+                    ("Ethernet0".to_string(), 0x100),
+                    ("Ethernet4".to_string(), 0x104),
+                    ("Ethernet8".to_string(), 0x108),
+                ]),
+                bridge_ports: HashMap::from([
+                    ("Ethernet0".to_string(), 0x200),
+                    ("Ethernet4".to_string(), 0x204),
+                ]),
+                next_oid: Mutex::new(0x1000),
+                groups: Mutex::new(HashMap::new()),
+                members: Mutex::new(HashMap::new()),
+                bindings: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn alloc(&self) -> RawSaiObjectId {
+            let mut next = self.next_oid.lock().unwrap();
+            *next += 1;
+            *next
+        }
+    }
+
+    impl IsolationGroupOrchCallbacks for RecordingCallbacks {
+        fn create_isolation_group(
+            &self,
+            group_type: IsolationGroupType,
+        ) -> Result<RawSaiObjectId, String> {
+            let oid = self.alloc();
+            self.groups.lock().unwrap().insert(oid, group_type);
+            Ok(oid)
+        }
+
+        fn remove_isolation_group(&self, oid: RawSaiObjectId) -> Result<(), String> {
+            self.groups.lock().unwrap().remove(&oid);
+            Ok(())
+        }
+
+        fn add_isolation_group_member(
+            &self,
+            group_id: RawSaiObjectId,
+            port_oid: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId, String> {
+            let oid = self.alloc();
+            self.members
+                .lock()
+                .unwrap()
+                .insert(oid, (group_id, port_oid));
+            Ok(oid)
+        }
+
+        fn remove_isolation_group_member(&self, member_oid: RawSaiObjectId) -> Result<(), String> {
+            self.members.lock().unwrap().remove(&member_oid);
+            Ok(())
+        }
+
+        fn bind_isolation_group_to_port(
+            &self,
+            port_oid: RawSaiObjectId,
+            group_id: RawSaiObjectId,
+        ) -> Result<(), String> {
+            self.bindings.lock().unwrap().insert(port_oid, group_id);
+            Ok(())
+        }
+
+        fn unbind_isolation_group_from_port(&self, port_oid: RawSaiObjectId) -> Result<(), String> {
+            self.bindings.lock().unwrap().remove(&port_oid);
+            Ok(())
+        }
+
+        fn get_port_oid(&self, alias: &str) -> Option<RawSaiObjectId> {
+            self.ports.get(alias).copied()
+        }
+
+        fn get_bridge_port_oid(&self, alias: &str) -> Option<RawSaiObjectId> {
+            self.bridge_ports.get(alias).copied()
+        }
+    }
+
     #[test]
     fn test_create_isolation_group() {
         let mut orch = IsolationGroupOrch::new(IsolationGroupOrchConfig::default());
@@ -1171,4 +1460,108 @@ mod tests {
         assert_eq!(group.bind_ports.len(), 1);
         assert_eq!(group.group_type, IsolationGroupType::BridgePort);
     }
+
+    // ========== Type Migration ==========
+
+    #[test]
+    fn test_migrate_group_type() {
+        let callbacks = Arc::new(RecordingCallbacks::new());
+        let mut orch = IsolationGroupOrch::new(IsolationGroupOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let config = IsolationGroupConfig::new("group1".to_string(), IsolationGroupType::Port);
+        orch.set_isolation_group(config).unwrap();
+        orch.add_isolation_group_member("group1", "Ethernet0")
+            .unwrap();
+        orch.bind_isolation_group("group1", "Ethernet4").unwrap();
+        let port_group = orch.get_group("group1").unwrap().oid;
+
+        // Same type is a no-op.
+        let config = IsolationGroupConfig::new("group1".to_string(), IsolationGroupType::Port);
+        orch.set_isolation_group(config).unwrap();
+        assert_eq!(orch.stats().type_migrations, 0);
+
+        let config =
+            IsolationGroupConfig::new("group1".to_string(), IsolationGroupType::BridgePort);
+        orch.set_isolation_group(config).unwrap();
+
+        let group = orch.get_group("group1").unwrap();
+        assert_eq!(group.group_type, IsolationGroupType::BridgePort);
+        assert_ne!(group.oid, port_group);
+        assert_eq!(
+            *callbacks.groups.lock().unwrap(),
+            HashMap::from([(group.oid, IsolationGroupType::BridgePort)])
+        );
+        let members: Vec<_> = callbacks
+            .members
+            .lock()
+            .unwrap()
+            .values()
+            .copied()
+            .collect();
+        assert_eq!(members, vec![(group.oid, 0x200)]);
+        assert_eq!(
+            *callbacks.bindings.lock().unwrap(),
+            HashMap::from([(0x204, group.oid)])
+        );
+        assert_eq!(orch.stats().type_migrations, 1);
+
+        // And back again.
+        orch.migrate_isolation_group_type("group1", IsolationGroupType::Port)
+            .unwrap();
+        let group = orch.get_group("group1").unwrap();
+        let members: Vec<_> = callbacks
+            .members
+            .lock()
+            .unwrap()
+            .values()
+            .copied()
+            .collect();
+        assert_eq!(members, vec![(group.oid, 0x100)]);
+        assert_eq!(
+            *callbacks.bindings.lock().unwrap(),
+            HashMap::from([(0x104, group.oid)])
+        );
+        assert_eq!(callbacks.groups.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().type_migrations, 2);
+    }
+
+    #[test]
+    fn test_migrate_group_type_rolls_back_on_missing_bridge_port() {
+        let callbacks = Arc::new(RecordingCallbacks::new());
+        let mut orch = IsolationGroupOrch::new(IsolationGroupOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let config = IsolationGroupConfig::new("group1".to_string(), IsolationGroupType::Port);
+        orch.create_isolation_group(config).unwrap();
+        orch.add_isolation_group_member("group1", "Ethernet0")
+            .unwrap();
+        orch.add_isolation_group_member("group1", "Ethernet8")
+            .unwrap();
+        orch.bind_isolation_group("group1", "Ethernet4").unwrap();
+
+        let before = orch.get_group("group1").unwrap().clone();
+        let groups_before = callbacks.groups.lock().unwrap().clone();
+        let members_before = callbacks.members.lock().unwrap().clone();
+        let bindings_before = callbacks.bindings.lock().unwrap().clone();
+
+        // Ethernet8 is not in a VLAN, so it has no bridge port.
+        let config =
+            IsolationGroupConfig::new("group1".to_string(), IsolationGroupType::BridgePort);
+        let result = orch.set_isolation_group(config);
+        assert!(matches!(
+            result,
+            Err(IsolationGroupOrchError::PortNotFound(ref alias)) if alias == "Ethernet8"
+        ));
+
+        let group = orch.get_group("group1").unwrap();
+        assert_eq!(group.group_type, IsolationGroupType::Port);
+        assert_eq!(group.oid, before.oid);
+        assert_eq!(group.members, before.members);
+        assert_eq!(*callbacks.groups.lock().unwrap(), groups_before);
+        assert_eq!(*callbacks.members.lock().unwrap(), members_before);
+        assert_eq!(*callbacks.bindings.lock().unwrap(), bindings_before);
+        assert_eq!(orch.stats().type_migrations, 0);
+        assert_eq!(orch.stats().type_migrations_failed, 1);
+    }
 }
@@ -64,6 +64,7 @@ pub mod fg_nhg;
 pub mod icmp;
 #[cfg(feature = "mod-intfs")]
 pub mod intfs;
+pub mod mac;
 #[cfg(feature = "mod-neigh")]
 pub mod neigh;
 #[cfg(feature = "mod-nhg")]
@@ -74,6 +75,7 @@ pub mod orch;
 pub mod policer;
 #[cfg(feature = "mod-ports")]
 pub mod ports;
+pub mod prefix_set;
 #[cfg(feature = "mod-qos")]
 pub mod qos;
 #[cfg(feature = "mod-route")]
@@ -169,9 +171,11 @@ pub mod mirror;
 // ============================================================================
 
 // Re-export commonly used types (always available)
+pub use mac::{MacAddressExt, MacOverflowError};
+pub use prefix_set::PrefixSet;
 pub use sonic_orch_common::{
-    Constraint, Consumer, ConsumerConfig, KeyOpFieldsValues, Operation, Orch, OrchContext,
-    RetryCache, SyncMap, TaskResult, TaskStatus,
+    Constraint, Consumer, ConsumerConfig, ConsumerMode, KeyOpFieldsValues, Operation, Orch,
+    OrchContext, RetryCache, SyncMap, TaskResult, TaskStatus,
 };
 pub use sonic_sai::{PortOid, SaiError, SaiResult, SwitchOid};
 pub use sonic_types::{IpAddress, IpPrefix, MacAddress, VlanId};
@@ -191,8 +195,9 @@ pub use flex_counter::{
 #[cfg(feature = "mod-route")]
 pub use route::{
     register_route_orch, unregister_route_orch, NextHopFlags, NextHopGroupEntry, NextHopGroupKey,
-    NextHopGroupTable, NextHopKey, RouteEntry, RouteError, RouteKey, RouteNhg, RouteOrch,
-    RouteOrchCallbacks, RouteOrchConfig, RouteTables,
+    NextHopGroupTable, NextHopKey, RouteBulkOp, RouteEntry, RouteError, RouteKey, RouteNhg,
+    RouteOrch, RouteOrchCallbacks, RouteOrchConfig, RouteOrchStats, RouteResponse,
+    RouteResponseStatus, RouteTables,
 };
 
 #[cfg(feature = "mod-ports")]
@@ -203,8 +208,8 @@ pub use ports::{
 };
 
 pub use intfs::{
-    register_intfs_orch, unregister_intfs_orch, IntfsEntry, IntfsOrch, IntfsOrchCallbacks,
-    IntfsOrchConfig, IntfsOrchError, IntfsOrchStats, RifType,
+    register_intfs_orch, unregister_intfs_orch, IntfConfig, IntfsEntry, IntfsOrch,
+    IntfsOrchCallbacks, IntfsOrchConfig, IntfsOrchError, IntfsOrchStats, RifType,
 };
 
 #[cfg(feature = "mod-acl")]
@@ -218,8 +223,8 @@ pub use acl::{
 
 #[cfg(feature = "mod-vrf")]
 pub use vrf::{
-    register_vrf_orch, unregister_vrf_orch, L3VniEntry, Vni, VrfEntry, VrfId, VrfName, VrfOrch,
-    VrfOrchCallbacks, VrfOrchConfig, VrfOrchError, VrfVlanId,
+    register_vrf_orch, unregister_vrf_orch, L3VniEntry, Vni, VrfEntry, VrfId, VrfName,
+    VrfObjectType, VrfOrch, VrfOrchCallbacks, VrfOrchConfig, VrfOrchError, VrfStats, VrfVlanId,
 };
 
 #[cfg(feature = "mod-policer")]
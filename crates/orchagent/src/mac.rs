@@ -0,0 +1,238 @@
+//! MAC address helpers shared by the orchs.
+//!
+//! `sonic_types::MacAddress` parses and formats addresses. The orchs also
+//! need to classify them (FdbOrch rejects multicast learns) and to derive
+//! new ones from a base (PortsOrch assigns per-port MACs from the switch
+//! MAC). [`MacAddressExt`] adds both to any 48-bit MAC type.
+
+use thiserror::Error;
+
+/// Largest 48-bit MAC address value.
+const MAC_MAX: u64 = 0xffff_ffff_ffff;
+
+/// Offsetting a MAC address went past `ff:ff:ff:ff:ff:ff`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("MAC address {base} + {offset} overflows ff:ff:ff:ff:ff:ff")]
+pub struct MacOverflowError {
+    /// The address that was offset.
+    pub base: String,
+    /// The offset that overflowed.
+    pub offset: u64,
+}
+
+/// Classification, arithmetic and formatting for 48-bit MAC addresses.
+pub trait MacAddressExt: Sized {
+    /// Returns the address bytes, most significant first.
+    fn octets(&self) -> [u8; 6];
+
+    /// Builds an address from its bytes.
+    fn from_octets(octets: [u8; 6]) -> Self;
+
+    /// Returns true for group addresses (I/G bit set), including broadcast.
+    fn is_multicast(&self) -> bool {
+        self.octets()[0] & 0x01 != 0
+    }
+
+    /// Returns true for individual addresses.
+    fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns true for `ff:ff:ff:ff:ff:ff`.
+    fn is_broadcast(&self) -> bool {
+        self.octets() == [0xff; 6]
+    }
+
+    /// Returns true for `00:00:00:00:00:00`.
+    fn is_zero(&self) -> bool {
+        self.octets() == [0; 6]
+    }
+
+    /// Returns true if the U/L bit marks the address as locally administered.
+    fn is_locally_administered(&self) -> bool {
+        self.octets()[0] & 0x02 != 0
+    }
+
+    /// Returns the organizationally unique identifier (first three bytes).
+    fn oui(&self) -> [u8; 3] {
+        let o = self.octets();
+        [o[0], o[1], o[2]]
+    }
+
+    /// Returns the address `n` above this one, treating it as a 48-bit
+    /// integer. Fails instead of wrapping past `ff:ff:ff:ff:ff:ff`.
+    fn offset(&self, n: u64) -> Result<Self, MacOverflowError> {
+        let o = self.octets();
+        let value = o.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        value
+            .checked_add(n)
+            .filter(|v| *v <= MAC_MAX)
+            .map(|v| {
+                let bytes = v.to_be_bytes();
+                Self::from_octets([bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+            })
+            .ok_or_else(|| MacOverflowError {
+                base: self.to_colon_string(),
+                offset: n,
+            })
+    }
+
+    /// Returns the next address.
+    fn successor(&self) -> Result<Self, MacOverflowError> {
+        self.offset(1)
+    }
+
+    /// Formats as `00:11:22:33:44:55`.
+    fn to_colon_string(&self) -> String {
+        let o = self.octets();
+        format!(
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            o[0], o[1], o[2], o[3], o[4], o[5]
+        )
+    }
+
+    /// Formats in the Cisco dot format, `0011.2233.4455`.
+    fn to_dotted_string(&self) -> String {
+        let o = self.octets();
+        format!(
+            "{:02x}{:02x}.{:02x}{:02x}.{:02x}{:02x}",
+            o[0], o[1], o[2], o[3], o[4], o[5]
+        )
+    }
+
+    /// Parses the colon (`00:11:22:33:44:55`), dash (`00-11-22-33-44-55`)
+    /// or Cisco dot (`0011.2233.4455`) format.
+    fn parse_any(s: &str) -> Option<Self> {
+        let hex: String = if s.contains('.') {
+            let groups: Vec<&str> = s.split('.').collect();
+            if groups.len() != 3 || groups.iter().any(|g| g.len() != 4) {
+                return None;
+            }
+            groups.concat()
+        } else {
+            let parts: Vec<&str> = s.split([':', '-']).collect();
+            if parts.len() != 6 || parts.iter().any(|p| p.len() != 2) {
+                return None;
+            }
+            // Don't accept a mix of separators
+            if s.contains(':') && s.contains('-') {
+                return None;
+            }
+            parts.concat()
+        };
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let mut octets = [0u8; 6];
+        for (i, octet) in octets.iter_mut().enumerate() {
+            *octet = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+        }
+        Some(Self::from_octets(octets))
+    }
+}
+
+impl MacAddressExt for sonic_types::MacAddress {
+    fn octets(&self) -> [u8; 6] {
+        let mut octets = [0u8; 6];
+        octets.copy_from_slice(self.as_bytes());
+        octets
+    }
+
+    fn from_octets(octets: [u8; 6]) -> Self {
+        Self::new(octets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonic_types::MacAddress;
+
+    fn mac(s: &str) -> MacAddress {
+        MacAddress::parse_any(s).unwrap()
+    }
+
+    #[test]
+    fn test_classification() {
+        let unicast = mac("00:11:22:33:44:55");
+        assert!(unicast.is_unicast());
+        assert!(!MacAddressExt::is_multicast(&unicast));
+        assert!(!unicast.is_locally_administered());
+        assert_eq!(unicast.oui(), [0x00, 0x11, 0x22]);
+
+        let multicast = mac("01:00:5e:00:00:01");
+        assert!(MacAddressExt::is_multicast(&multicast));
+        assert!(!multicast.is_unicast());
+        assert!(!MacAddressExt::is_broadcast(&multicast));
+
+        let broadcast = mac("ff:ff:ff:ff:ff:ff");
+        assert!(MacAddressExt::is_broadcast(&broadcast));
+        assert!(MacAddressExt::is_multicast(&broadcast));
+
+        assert!(MacAddressExt::is_zero(&mac("00:00:00:00:00:00")));
+        assert!(mac("02:00:00:00:00:01").is_locally_administered());
+    }
+
+    #[test]
+    fn test_offset_carries_and_overflows() {
+        assert_eq!(
+            mac("00:11:22:33:44:ff").successor().unwrap(),
+            mac("00:11:22:33:45:00")
+        );
+        assert_eq!(
+            mac("00:11:22:ff:ff:f0").offset(0x20).unwrap(),
+            mac("00:11:23:00:00:10")
+        );
+        assert_eq!(
+            mac("ff:ff:ff:ff:ff:fe").successor().unwrap(),
+            mac("ff:ff:ff:ff:ff:ff")
+        );
+
+        let err = mac("ff:ff:ff:ff:ff:ff").successor().unwrap_err();
+        assert_eq!(err.base, "ff:ff:ff:ff:ff:ff");
+        assert_eq!(err.offset, 1);
+        assert!(mac("00:00:00:00:00:00").offset(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        for octets in [
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff],
+            [0x00; 6],
+            [0xff; 6],
+        ] {
+            let mac = MacAddress::from_octets(octets);
+            let colon = mac.to_colon_string();
+            let dash = colon.replace(':', "-");
+            let dotted = mac.to_dotted_string();
+
+            for s in [&colon, &dash, &dotted] {
+                assert_eq!(MacAddress::parse_any(s).unwrap().octets(), octets, "{}", s);
+            }
+        }
+        assert_eq!(
+            mac("00:11:22:33:44:55").to_dotted_string(),
+            "0011.2233.4455"
+        );
+        assert_eq!(mac("0011.2233.4455"), mac("00-11-22-33-44-55"));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        for s in [
+            "",
+            "00:11:22:33:44",
+            "00:11:22:33:44:55:66",
+            "00:11-22:33:44:55",
+            "0:11:22:33:44:55",
+            "00:11:22:33:44:gg",
+            "0011.2233",
+            "+0:11:22:33:44:55",
+            "011.2233.44556",
+        ] {
+            assert!(MacAddress::parse_any(s).is_none(), "{}", s);
+        }
+    }
+}
@@ -228,6 +228,7 @@ async fn main() -> ExitCode {
         warm_boot: args.warm_boot,
         redis_host: args.redis_host.clone(),
         redis_port: args.redis_port,
+        ..OrchDaemonConfig::default()
     };
 
     let mut daemon = OrchDaemon::new(daemon_config);
@@ -337,9 +338,34 @@ async fn main() -> ExitCode {
         return ExitCode::FAILURE;
     }
 
+    if args.warm_boot && !daemon.warm_restart().await {
+        error!("Warm restart reconciliation failed");
+        return ExitCode::FAILURE;
+    }
+
     info!("Daemon initialization complete");
     info!("Starting event loop...");
 
+    // Dump per-Orch metrics on SIGUSR1
+    let metrics = daemon.metrics();
+    let metrics_handle = tokio::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut usr1 = match signal(SignalKind::user_defined1()) {
+            Ok(usr1) => usr1,
+            Err(err) => {
+                error!("Failed to listen for SIGUSR1: {}", err);
+                return;
+            }
+        };
+        while usr1.recv().await.is_some() {
+            match serde_json::to_string(&metrics.snapshot()) {
+                Ok(json) => info!("Orch metrics: {}", json),
+                Err(err) => error!("Failed to serialize orch metrics: {}", err),
+            }
+        }
+    });
+
     // Setup signal handling for graceful shutdown
     let daemon_arc = Arc::new(Mutex::new(daemon));
     let daemon_clone = Arc::clone(&daemon_arc);
@@ -364,6 +390,7 @@ async fn main() -> ExitCode {
     }
 
     shutdown_handle.abort();
+    metrics_handle.abort();
 
     info!("====================================================================");
     info!("SONiC orchagent shutdown complete");
@@ -1,7 +1,10 @@
 //! FFI exports for MirrorOrch.
 
 use super::orch::{MirrorOrch, MirrorOrchCallbacks, MirrorOrchConfig, Result};
-use super::types::{MirrorSessionConfig, MirrorSessionType, RawSaiObjectId};
+use super::types::{
+    MirrorNeighbor, MirrorSessionConfig, MirrorSessionStatus, MirrorSessionType, RawSaiObjectId,
+};
+use sonic_types::IpAddress;
 use std::cell::RefCell;
 
 /// FFI stub callbacks that do nothing (for C++ interop).
@@ -33,6 +36,52 @@ impl MirrorOrchCallbacks for FfiMirrorCallbacks {
 
     fn on_session_created(&self, _name: &str, _session_id: RawSaiObjectId) {}
     fn on_session_removed(&self, _name: &str) {}
+
+    fn create_erspan_session(
+        &self,
+        _config: &MirrorSessionConfig,
+        _neighbor: &MirrorNeighbor,
+    ) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn set_erspan_neighbor(
+        &self,
+        _session_id: RawSaiObjectId,
+        _neighbor: &MirrorNeighbor,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn register_route_observer(&self, _name: &str, _dst_ip: &IpAddress) -> Option<IpAddress> {
+        None
+    }
+
+    fn unregister_route_observer(&self, _name: &str, _dst_ip: &IpAddress) {}
+
+    fn register_neighbor_observer(
+        &self,
+        _name: &str,
+        _next_hop: &IpAddress,
+    ) -> Option<MirrorNeighbor> {
+        None
+    }
+
+    fn unregister_neighbor_observer(&self, _name: &str, _next_hop: &IpAddress) {}
+
+    fn write_session_state(
+        &self,
+        _name: &str,
+        _status: MirrorSessionStatus,
+        _neighbor: Option<&MirrorNeighbor>,
+    ) {
+    }
+
+    fn remove_session_state(&self, _name: &str) {}
+
+    fn retarget_acl_rules(&self, _name: &str, _session_id: Option<RawSaiObjectId>) -> Result<()> {
+        Ok(())
+    }
 }
 
 thread_local! {
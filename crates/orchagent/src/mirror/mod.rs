@@ -7,6 +7,8 @@
 //! - Saturating reference counting preventing underflow
 //! - Type-safe IP family matching at compile time
 //! - Validated DSCP and queue range checks
+//! - ERSPAN sessions track route/neighbor resolution and detach ACL rules
+//!   before the SAI session they reference is removed
 
 mod ffi;
 mod orch;
@@ -16,4 +18,7 @@ pub use ffi::{register_mirror_orch, unregister_mirror_orch};
 pub use orch::{
     MirrorOrch, MirrorOrchCallbacks, MirrorOrchConfig, MirrorOrchError, MirrorOrchStats,
 };
-pub use types::{MirrorDirection, MirrorEntry, MirrorSessionConfig, MirrorSessionType};
+pub use types::{
+    MirrorDirection, MirrorEntry, MirrorNeighbor, MirrorResolution, MirrorSessionConfig,
+    MirrorSessionStatus, MirrorSessionType,
+};
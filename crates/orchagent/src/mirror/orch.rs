@@ -1,8 +1,12 @@
 //! Mirror session orchestration logic.
 
-use super::types::{MirrorEntry, MirrorSessionConfig, MirrorSessionType, RawSaiObjectId};
+use super::types::{
+    MirrorEntry, MirrorNeighbor, MirrorResolution, MirrorSessionConfig, MirrorSessionStatus,
+    MirrorSessionType, RawSaiObjectId,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::{audit_log, debug_log, error_log, info_log, warn_log};
+use sonic_types::IpAddress;
 use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
@@ -45,6 +49,9 @@ pub struct MirrorOrchStats {
     pub sessions_created: u64,
     pub sessions_removed: u64,
     pub sessions_active: u64,
+    pub activations: u64,
+    pub deactivations: u64,
+    pub neighbor_updates: u64,
 }
 
 pub trait MirrorOrchCallbacks: Send + Sync {
@@ -61,6 +68,44 @@ pub trait MirrorOrchCallbacks: Send + Sync {
     ) -> Result<Vec<RawSaiObjectId>>;
     fn on_session_created(&self, name: &str, session_id: RawSaiObjectId);
     fn on_session_removed(&self, name: &str);
+
+    /// Creates an ERSPAN session towards a resolved neighbor.
+    fn create_erspan_session(
+        &self,
+        config: &MirrorSessionConfig,
+        neighbor: &MirrorNeighbor,
+    ) -> Result<RawSaiObjectId>;
+    /// Updates dst MAC and monitor port of an active ERSPAN session in place.
+    fn set_erspan_neighbor(
+        &self,
+        session_id: RawSaiObjectId,
+        neighbor: &MirrorNeighbor,
+    ) -> Result<()>;
+
+    /// Subscribes to RouteOrch for the route covering `dst_ip`; returns its
+    /// current next hop, if any.
+    fn register_route_observer(&self, name: &str, dst_ip: &IpAddress) -> Option<IpAddress>;
+    fn unregister_route_observer(&self, name: &str, dst_ip: &IpAddress);
+    /// Subscribes to NeighOrch for `next_hop`; returns the neighbor if resolved.
+    fn register_neighbor_observer(
+        &self,
+        name: &str,
+        next_hop: &IpAddress,
+    ) -> Option<MirrorNeighbor>;
+    fn unregister_neighbor_observer(&self, name: &str, next_hop: &IpAddress);
+
+    /// Writes STATE_DB MIRROR_SESSION_TABLE for the session.
+    fn write_session_state(
+        &self,
+        name: &str,
+        status: MirrorSessionStatus,
+        neighbor: Option<&MirrorNeighbor>,
+    );
+    fn remove_session_state(&self, name: &str);
+
+    /// Points AclOrch rules referencing the session at a new SAI session,
+    /// or detaches their mirror action when `session_id` is None.
+    fn retarget_acl_rules(&self, name: &str, session_id: Option<RawSaiObjectId>) -> Result<()>;
 }
 
 pub struct MirrorOrch<C: MirrorOrchCallbacks> {
@@ -85,6 +130,13 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
         self
     }
 
+    fn callbacks(&self) -> Result<Arc<C>> {
+        self.callbacks.clone().ok_or_else(|| {
+            error_log!("MirrorOrch", "Callbacks not configured");
+            MirrorOrchError::NotInitialized
+        })
+    }
+
     pub fn create_session(
         &mut self,
         name: String,
@@ -105,6 +157,10 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
             return Err(MirrorOrchError::SessionExists(name));
         }
 
+        if config.needs_resolution() {
+            return self.create_resolving_session(name, config);
+        }
+
         let callbacks = self.callbacks.as_ref().ok_or_else(|| {
             error_log!("MirrorOrch", "Callbacks not configured");
             MirrorOrchError::NotInitialized
@@ -127,6 +183,8 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
             session_id: Some(session_id),
             config: config.clone(),
             ref_count: 1,
+            status: MirrorSessionStatus::Active,
+            resolution: MirrorResolution::default(),
         };
 
         self.sessions.insert(name.clone(), entry);
@@ -154,6 +212,228 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
         Ok(session_id)
     }
 
+    /// Creates an ERSPAN session whose destination is resolved through
+    /// RouteOrch and NeighOrch. The session starts inactive and is programmed
+    /// once the neighbor resolves; returns 0 while unresolved.
+    fn create_resolving_session(
+        &mut self,
+        name: String,
+        config: MirrorSessionConfig,
+    ) -> Result<RawSaiObjectId> {
+        let callbacks = self.callbacks()?;
+        let dst_ip = config
+            .dst_ip
+            .as_ref()
+            .map(|ip| ip.to_string())
+            .ok_or_else(|| MirrorOrchError::InvalidConfig(format!("{}: dst_ip required", name)))?;
+
+        let next_hop = config
+            .dst_ip
+            .as_ref()
+            .and_then(|ip| callbacks.register_route_observer(&name, ip));
+        let neighbor = next_hop
+            .as_ref()
+            .and_then(|nh| callbacks.register_neighbor_observer(&name, nh));
+        let next_hop_str = next_hop.as_ref().map(|nh| nh.to_string());
+
+        self.sessions.insert(
+            name.clone(),
+            MirrorEntry {
+                session_id: None,
+                config,
+                ref_count: 1,
+                status: MirrorSessionStatus::Inactive,
+                resolution: MirrorResolution {
+                    next_hop,
+                    neighbor,
+                    applied: None,
+                },
+            },
+        );
+        callbacks.write_session_state(&name, MirrorSessionStatus::Inactive, None);
+
+        if let Err(e) = self.reconcile(&name) {
+            if let Some(entry) = self.sessions.remove(&name) {
+                Self::release_observers(callbacks.as_ref(), &name, &entry);
+            }
+            callbacks.remove_session_state(&name);
+            return Err(e);
+        }
+        self.stats.sessions_created += 1;
+
+        let session_id = self
+            .sessions
+            .get(&name)
+            .and_then(|entry| entry.session_id)
+            .unwrap_or(0);
+        info_log!("MirrorOrch", session_name = %name, dst_ip = %dst_ip, oid = session_id, "ERSPAN session created");
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "MirrorOrch",
+            "create_session"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(&name)
+        .with_object_type("mirror_session")
+        .with_details(serde_json::json!({
+            "dst_ip": dst_ip,
+            "next_hop": next_hop_str,
+            "active": session_id != 0
+        })));
+
+        Ok(session_id)
+    }
+
+    fn release_observers(callbacks: &C, name: &str, entry: &MirrorEntry) {
+        if let Some(next_hop) = &entry.resolution.next_hop {
+            callbacks.unregister_neighbor_observer(name, next_hop);
+        }
+        if let Some(dst_ip) = &entry.config.dst_ip {
+            callbacks.unregister_route_observer(name, dst_ip);
+        }
+    }
+
+    /// RouteOrch notification: the route covering `dst_ip` now resolves via
+    /// `next_hop` (None when withdrawn). Returns the number of sessions whose
+    /// resolution changed.
+    pub fn handle_route_change(
+        &mut self,
+        dst_ip: &IpAddress,
+        next_hop: Option<IpAddress>,
+    ) -> Result<usize> {
+        let callbacks = self.callbacks()?;
+        let names: Vec<String> = self
+            .sessions
+            .iter()
+            .filter(|(_, entry)| {
+                entry.config.needs_resolution()
+                    && entry.config.dst_ip.as_ref() == Some(dst_ip)
+                    && entry.resolution.next_hop != next_hop
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        for name in &names {
+            let Some(entry) = self.sessions.get_mut(name) else {
+                continue;
+            };
+            if let Some(old) = &entry.resolution.next_hop {
+                callbacks.unregister_neighbor_observer(name, old);
+            }
+            debug_log!("MirrorOrch", session_name = %name, old = ?entry.resolution.next_hop, new = ?next_hop, "Mirror session next hop changed");
+            entry.resolution.neighbor = next_hop
+                .as_ref()
+                .and_then(|nh| callbacks.register_neighbor_observer(name, nh));
+            entry.resolution.next_hop.clone_from(&next_hop);
+            self.reconcile_logged(name);
+        }
+        Ok(names.len())
+    }
+
+    /// NeighOrch notification: `next_hop` resolved to `neighbor`, or became
+    /// unresolved. Returns the number of sessions affected.
+    pub fn handle_neighbor_change(
+        &mut self,
+        next_hop: &IpAddress,
+        neighbor: Option<MirrorNeighbor>,
+    ) -> Result<usize> {
+        self.callbacks()?;
+        let names: Vec<String> = self
+            .sessions
+            .iter()
+            .filter(|(_, entry)| {
+                entry.config.needs_resolution()
+                    && entry.resolution.next_hop.as_ref() == Some(next_hop)
+                    && entry.resolution.neighbor != neighbor
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        for name in &names {
+            if let Some(entry) = self.sessions.get_mut(name) {
+                entry.resolution.neighbor = neighbor.clone();
+            }
+            self.reconcile_logged(name);
+        }
+        Ok(names.len())
+    }
+
+    fn reconcile_logged(&mut self, name: &str) {
+        if let Err(e) = self.reconcile(name) {
+            error_log!("MirrorOrch", session_name = %name, error = %e, "Failed to reconcile mirror session");
+            audit_log!(AuditRecord::new(
+                AuditCategory::SaiOperation,
+                "MirrorOrch",
+                "reconcile_session"
+            )
+            .with_object_id(name)
+            .with_object_type("mirror_session")
+            .with_error(e.to_string()));
+        }
+    }
+
+    /// Brings the SAI session in line with its resolution: activates,
+    /// updates in place, or deactivates.
+    fn reconcile(&mut self, name: &str) -> Result<()> {
+        let callbacks = self.callbacks()?;
+        let entry = self
+            .sessions
+            .get_mut(name)
+            .ok_or_else(|| MirrorOrchError::SessionNotFound(name.to_string()))?;
+        let target = if entry.resolution.is_resolved() {
+            entry.resolution.neighbor.clone()
+        } else {
+            None
+        };
+
+        match (entry.session_id, target) {
+            (None, None) => {}
+            (None, Some(neighbor)) => {
+                let session_id = callbacks.create_erspan_session(&entry.config, &neighbor)?;
+                entry.session_id = Some(session_id);
+                entry.status = MirrorSessionStatus::Active;
+                entry.resolution.applied = Some(neighbor.clone());
+                self.stats.activations += 1;
+                self.stats.sessions_active += 1;
+
+                callbacks.write_session_state(name, MirrorSessionStatus::Active, Some(&neighbor));
+                callbacks.on_session_created(name, session_id);
+                if let Err(e) = callbacks.retarget_acl_rules(name, Some(session_id)) {
+                    warn_log!("MirrorOrch", session_name = %name, error = %e, "Failed to retarget ACL rules to mirror session");
+                }
+                info_log!("MirrorOrch", session_name = %name, oid = session_id, port = %neighbor.port, "Mirror session activated");
+            }
+            (Some(session_id), None) => {
+                // Detach ACL rules before the session they point at goes away.
+                callbacks.retarget_acl_rules(name, None)?;
+                callbacks.remove_mirror_session(session_id)?;
+                entry.session_id = None;
+                entry.status = MirrorSessionStatus::Inactive;
+                entry.resolution.applied = None;
+                self.stats.deactivations += 1;
+                self.stats.sessions_active = self.stats.sessions_active.saturating_sub(1);
+
+                callbacks.write_session_state(name, MirrorSessionStatus::Inactive, None);
+                callbacks.on_session_removed(name);
+                info_log!("MirrorOrch", session_name = %name, oid = session_id, "Mirror session deactivated");
+            }
+            (Some(session_id), Some(neighbor)) => {
+                if entry.resolution.applied.as_ref() != Some(&neighbor) {
+                    callbacks.set_erspan_neighbor(session_id, &neighbor)?;
+                    entry.resolution.applied = Some(neighbor.clone());
+                    self.stats.neighbor_updates += 1;
+                    callbacks.write_session_state(
+                        name,
+                        MirrorSessionStatus::Active,
+                        Some(&neighbor),
+                    );
+                    info_log!("MirrorOrch", session_name = %name, oid = session_id, port = %neighbor.port, "Mirror session neighbor updated");
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn remove_session(&mut self, name: &str) -> Result<()> {
         debug_log!("MirrorOrch", session_name = %name, "Removing mirror session");
 
@@ -211,6 +491,13 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
             })));
         }
 
+        if entry.config.needs_resolution() {
+            if let Some(callbacks) = self.callbacks.as_ref() {
+                Self::release_observers(callbacks.as_ref(), name, &entry);
+                callbacks.remove_session_state(name);
+            }
+        }
+
         Ok(())
     }
 
@@ -222,6 +509,17 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
             MirrorOrchError::SessionNotFound(name.to_string())
         })?;
 
+        // Observers are keyed on the destination; changing it needs a re-create.
+        if entry.config.needs_resolution() != config.needs_resolution()
+            || (config.needs_resolution() && entry.config.dst_ip != config.dst_ip)
+        {
+            warn_log!("MirrorOrch", session_name = %name, "ERSPAN destination change not supported");
+            return Err(MirrorOrchError::InvalidConfig(format!(
+                "{}: destination change requires session re-creation",
+                name
+            )));
+        }
+
         if let Some(session_id) = entry.session_id {
             let callbacks = self.callbacks.as_ref().ok_or_else(|| {
                 error_log!("MirrorOrch", "Callbacks not configured");
@@ -323,6 +621,7 @@ impl<C: MirrorOrchCallbacks> MirrorOrch<C> {
 mod tests {
     use super::super::types::MirrorDirection;
     use super::*;
+    use std::sync::Mutex;
 
     struct MockMirrorCallbacks;
 
@@ -352,6 +651,197 @@ mod tests {
 
         fn on_session_created(&self, _name: &str, _session_id: RawSaiObjectId) {}
         fn on_session_removed(&self, _name: &str) {}
+
+        fn create_erspan_session(
+            &self,
+            _config: &MirrorSessionConfig,
+            _neighbor: &MirrorNeighbor,
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x1000)
+        }
+
+        fn set_erspan_neighbor(
+            &self,
+            _session_id: RawSaiObjectId,
+            _neighbor: &MirrorNeighbor,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn register_route_observer(&self, _name: &str, _dst_ip: &IpAddress) -> Option<IpAddress> {
+            None
+        }
+
+        fn unregister_route_observer(&self, _name: &str, _dst_ip: &IpAddress) {}
+
+        fn register_neighbor_observer(
+            &self,
+            _name: &str,
+            _next_hop: &IpAddress,
+        ) -> Option<MirrorNeighbor> {
+            None
+        }
+
+        fn unregister_neighbor_observer(&self, _name: &str, _next_hop: &IpAddress) {}
+
+        fn write_session_state(
+            &self,
+            _name: &str,
+            _status: MirrorSessionStatus,
+            _neighbor: Option<&MirrorNeighbor>,
+        ) {
+        }
+
+        fn remove_session_state(&self, _name: &str) {}
+
+        fn retarget_acl_rules(
+            &self,
+            _name: &str,
+            _session_id: Option<RawSaiObjectId>,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Callbacks backed by a fake route/neighbor table that record SAI,
+    /// STATE_DB and ACL side effects.
+    #[derive(Default)]
+    struct ErspanCallbacks {
+        routes: Mutex<HashMap<IpAddress, IpAddress>>,
+        neighbors: Mutex<HashMap<IpAddress, MirrorNeighbor>>,
+        next_oid: Mutex<RawSaiObjectId>,
+        sai_sessions: Mutex<HashMap<RawSaiObjectId, MirrorNeighbor>>,
+        neighbor_observers: Mutex<Vec<String>>,
+        states: Mutex<HashMap<String, (MirrorSessionStatus, Option<MirrorNeighbor>)>>,
+        acl_targets: Mutex<Vec<Option<RawSaiObjectId>>>,
+    }
+
+    impl MirrorOrchCallbacks for ErspanCallbacks {
+        fn create_mirror_session(&self, _config: &MirrorSessionConfig) -> Result<RawSaiObjectId> {
+            Err(MirrorOrchError::SaiError("unresolved ERSPAN create".into()))
+        }
+
+        fn remove_mirror_session(&self, session_id: RawSaiObjectId) -> Result<()> {
+            self.sai_sessions.lock().unwrap().remove(&session_id);
+            Ok(())
+        }
+
+        fn update_mirror_session(
+            &self,
+            _session_id: RawSaiObjectId,
+            _config: &MirrorSessionConfig,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_mirror_sessions_by_type(
+            &self,
+            _session_type: MirrorSessionType,
+        ) -> Result<Vec<RawSaiObjectId>> {
+            Ok(vec![])
+        }
+
+        fn on_session_created(&self, _name: &str, _session_id: RawSaiObjectId) {}
+        fn on_session_removed(&self, _name: &str) {}
+
+        fn create_erspan_session(
+            &self,
+            _config: &MirrorSessionConfig,
+            neighbor: &MirrorNeighbor,
+        ) -> Result<RawSaiObjectId> {
+            let mut next = self.next_oid.lock().unwrap();
+            *next += 1;
+            let oid = 0x2000 + *next;
+            self.sai_sessions
+                .lock()
+                .unwrap()
+                .insert(oid, neighbor.clone());
+            Ok(oid)
+        }
+
+        fn set_erspan_neighbor(
+            &self,
+            session_id: RawSaiObjectId,
+            neighbor: &MirrorNeighbor,
+        ) -> Result<()> {
+            self.sai_sessions
+                .lock()
+                .unwrap()
+                .insert(session_id, neighbor.clone());
+            Ok(())
+        }
+
+        fn register_route_observer(&self, _name: &str, dst_ip: &IpAddress) -> Option<IpAddress> {
+            self.routes.lock().unwrap().get(dst_ip).cloned()
+        }
+
+        fn unregister_route_observer(&self, _name: &str, _dst_ip: &IpAddress) {}
+
+        fn register_neighbor_observer(
+            &self,
+            _name: &str,
+            next_hop: &IpAddress,
+        ) -> Option<MirrorNeighbor> {
+            self.neighbor_observers
+                .lock()
+                .unwrap()
+                .push(next_hop.to_string());
+            self.neighbors.lock().unwrap().get(next_hop).cloned()
+        }
+
+        fn unregister_neighbor_observer(&self, _name: &str, next_hop: &IpAddress) {
+            let next_hop = next_hop.to_string();
+            self.neighbor_observers
+                .lock()
+                .unwrap()
+                .retain(|nh| *nh != next_hop);
+        }
+
+        fn write_session_state(
+            &self,
+            name: &str,
+            status: MirrorSessionStatus,
+            neighbor: Option<&MirrorNeighbor>,
+        ) {
+            self.states
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), (status, neighbor.cloned()));
+        }
+
+        fn remove_session_state(&self, name: &str) {
+            self.states.lock().unwrap().remove(name);
+        }
+
+        fn retarget_acl_rules(
+            &self,
+            _name: &str,
+            session_id: Option<RawSaiObjectId>,
+        ) -> Result<()> {
+            self.acl_targets.lock().unwrap().push(session_id);
+            Ok(())
+        }
+    }
+
+    fn ip(s: &str) -> IpAddress {
+        s.parse().unwrap()
+    }
+
+    fn neighbor(mac: &str, port: &str) -> MirrorNeighbor {
+        MirrorNeighbor {
+            mac: mac.parse().unwrap(),
+            port: port.to_string(),
+        }
+    }
+
+    fn erspan_config() -> MirrorSessionConfig {
+        MirrorSessionConfig {
+            session_type: MirrorSessionType::Erspan,
+            direction: MirrorDirection::Both,
+            dst_port: None,
+            src_ip: Some(ip("1.1.1.1")),
+            dst_ip: Some(ip("2.2.2.2")),
+        }
     }
 
     #[test]
@@ -560,4 +1050,179 @@ mod tests {
         assert!(orch.create_session("session1".into(), config).is_ok());
         assert!(orch.session_exists("session1"));
     }
+
+    #[test]
+    fn test_erspan_session_waits_for_resolution() {
+        let callbacks = Arc::new(ErspanCallbacks::default());
+        let mut orch =
+            MirrorOrch::new(MirrorOrchConfig::default()).with_callbacks(callbacks.clone());
+
+        assert_eq!(
+            orch.create_session("erspan".into(), erspan_config())
+                .unwrap(),
+            0
+        );
+        let entry = orch.get_session("erspan").unwrap();
+        assert_eq!(entry.status, MirrorSessionStatus::Inactive);
+        assert!(entry.session_id.is_none());
+        assert_eq!(
+            callbacks.states.lock().unwrap()["erspan"],
+            (MirrorSessionStatus::Inactive, None)
+        );
+
+        // Route appears, then its next hop resolves.
+        callbacks
+            .neighbors
+            .lock()
+            .unwrap()
+            .insert(ip("10.0.0.1"), neighbor("00:11:22:33:44:55", "Ethernet0"));
+        assert_eq!(
+            orch.handle_route_change(&ip("2.2.2.2"), Some(ip("10.0.0.1")))
+                .unwrap(),
+            1
+        );
+        let entry = orch.get_session("erspan").unwrap();
+        assert_eq!(entry.status, MirrorSessionStatus::Active);
+        assert_eq!(
+            *callbacks.acl_targets.lock().unwrap(),
+            vec![entry.session_id]
+        );
+        assert_eq!(orch.stats().activations, 1);
+
+        orch.remove_session("erspan").unwrap();
+        assert!(callbacks.sai_sessions.lock().unwrap().is_empty());
+        assert!(callbacks.states.lock().unwrap().is_empty());
+        assert!(callbacks.neighbor_observers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_erspan_neighbor_flap() {
+        let callbacks = Arc::new(ErspanCallbacks::default());
+        callbacks
+            .routes
+            .lock()
+            .unwrap()
+            .insert(ip("2.2.2.2"), ip("10.0.0.1"));
+        callbacks
+            .neighbors
+            .lock()
+            .unwrap()
+            .insert(ip("10.0.0.1"), neighbor("00:11:22:33:44:55", "Ethernet0"));
+        let mut orch =
+            MirrorOrch::new(MirrorOrchConfig::default()).with_callbacks(callbacks.clone());
+
+        let first = orch
+            .create_session("erspan".into(), erspan_config())
+            .unwrap();
+        assert_ne!(first, 0);
+        assert_eq!(
+            orch.get_session("erspan").unwrap().status,
+            MirrorSessionStatus::Active
+        );
+
+        // Neighbor goes away: ACL rules detached first, SAI session removed.
+        assert_eq!(
+            orch.handle_neighbor_change(&ip("10.0.0.1"), None).unwrap(),
+            1
+        );
+        let entry = orch.get_session("erspan").unwrap();
+        assert_eq!(entry.status, MirrorSessionStatus::Inactive);
+        assert!(entry.session_id.is_none());
+        assert!(callbacks.sai_sessions.lock().unwrap().is_empty());
+        assert_eq!(
+            callbacks.states.lock().unwrap()["erspan"],
+            (MirrorSessionStatus::Inactive, None)
+        );
+
+        // Neighbor relearned behind a different port with a new MAC.
+        let relearned = neighbor("00:aa:bb:cc:dd:ee", "Ethernet8");
+        assert_eq!(
+            orch.handle_neighbor_change(&ip("10.0.0.1"), Some(relearned.clone()))
+                .unwrap(),
+            1
+        );
+        let entry = orch.get_session("erspan").unwrap();
+        let second = entry.session_id.unwrap();
+        assert_ne!(second, first);
+        assert_eq!(entry.status, MirrorSessionStatus::Active);
+        assert_eq!(entry.resolution.applied.as_ref(), Some(&relearned));
+        assert_eq!(callbacks.sai_sessions.lock().unwrap()[&second], relearned);
+        assert_eq!(
+            callbacks.states.lock().unwrap()["erspan"],
+            (MirrorSessionStatus::Active, Some(relearned))
+        );
+        assert_eq!(
+            *callbacks.acl_targets.lock().unwrap(),
+            vec![Some(first), None, Some(second)]
+        );
+        assert_eq!(orch.stats().activations, 2);
+        assert_eq!(orch.stats().deactivations, 1);
+        assert_eq!(orch.stats().sessions_active, 1);
+
+        // Unrelated next hop is ignored.
+        assert_eq!(
+            orch.handle_neighbor_change(&ip("10.0.0.9"), None).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_erspan_route_change_updates_in_place() {
+        let callbacks = Arc::new(ErspanCallbacks::default());
+        callbacks
+            .routes
+            .lock()
+            .unwrap()
+            .insert(ip("2.2.2.2"), ip("10.0.0.1"));
+        callbacks
+            .neighbors
+            .lock()
+            .unwrap()
+            .insert(ip("10.0.0.1"), neighbor("00:11:22:33:44:55", "Ethernet0"));
+        callbacks
+            .neighbors
+            .lock()
+            .unwrap()
+            .insert(ip("10.0.1.1"), neighbor("00:11:22:33:44:66", "Ethernet4"));
+        let mut orch =
+            MirrorOrch::new(MirrorOrchConfig::default()).with_callbacks(callbacks.clone());
+
+        let oid = orch
+            .create_session("erspan".into(), erspan_config())
+            .unwrap();
+        assert_eq!(
+            orch.handle_route_change(&ip("2.2.2.2"), Some(ip("10.0.1.1")))
+                .unwrap(),
+            1
+        );
+
+        // Same SAI session, new dst MAC/port; ACL rules untouched.
+        let entry = orch.get_session("erspan").unwrap();
+        assert_eq!(entry.session_id, Some(oid));
+        assert_eq!(
+            callbacks.sai_sessions.lock().unwrap()[&oid].port,
+            "Ethernet4"
+        );
+        assert_eq!(
+            *callbacks.neighbor_observers.lock().unwrap(),
+            vec!["10.0.1.1".to_string()]
+        );
+        assert_eq!(*callbacks.acl_targets.lock().unwrap(), vec![Some(oid)]);
+        assert_eq!(orch.stats().neighbor_updates, 1);
+
+        // Route withdrawn.
+        orch.handle_route_change(&ip("2.2.2.2"), None).unwrap();
+        assert_eq!(
+            orch.get_session("erspan").unwrap().status,
+            MirrorSessionStatus::Inactive
+        );
+        assert!(callbacks.neighbor_observers.lock().unwrap().is_empty());
+
+        let mut moved = erspan_config();
+        moved.dst_ip = Some(ip("3.3.3.3"));
+        assert!(matches!(
+            orch.update_session("erspan", moved),
+            Err(MirrorOrchError::InvalidConfig(_))
+        ));
+    }
 }
@@ -1,7 +1,7 @@
 //! Mirror session types and structures.
 
 pub use sonic_sai::types::RawSaiObjectId;
-use sonic_types::IpAddress;
+use sonic_types::{IpAddress, MacAddress};
 
 /// Mirror session type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -28,12 +28,64 @@ pub struct MirrorSessionConfig {
     pub dst_ip: Option<IpAddress>,
 }
 
+impl MirrorSessionConfig {
+    /// ERSPAN sessions with a destination IP are programmed only once the
+    /// destination resolves to a neighbor.
+    pub fn needs_resolution(&self) -> bool {
+        self.session_type == MirrorSessionType::Erspan && self.dst_ip.is_some()
+    }
+}
+
+/// Session status reported in STATE_DB MIRROR_SESSION_TABLE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MirrorSessionStatus {
+    #[default]
+    Inactive,
+    Active,
+}
+
+impl MirrorSessionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Inactive => "inactive",
+            Self::Active => "active",
+        }
+    }
+}
+
+/// Neighbor an ERSPAN destination resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorNeighbor {
+    pub mac: MacAddress,
+    /// Outgoing (monitor) port.
+    pub port: String,
+}
+
+/// Resolution of an ERSPAN destination through RouteOrch and NeighOrch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MirrorResolution {
+    /// Next hop of the route covering dst_ip.
+    pub next_hop: Option<IpAddress>,
+    /// Neighbor entry of the next hop.
+    pub neighbor: Option<MirrorNeighbor>,
+    /// Neighbor programmed into the active SAI session.
+    pub applied: Option<MirrorNeighbor>,
+}
+
+impl MirrorResolution {
+    pub fn is_resolved(&self) -> bool {
+        self.next_hop.is_some() && self.neighbor.is_some()
+    }
+}
+
 /// Mirror session entry (stub).
 #[derive(Debug, Clone)]
 pub struct MirrorEntry {
     pub session_id: Option<RawSaiObjectId>,
     pub config: MirrorSessionConfig,
     pub ref_count: u32,
+    pub status: MirrorSessionStatus,
+    pub resolution: MirrorResolution,
 }
 
 #[cfg(test)]
@@ -44,4 +96,22 @@ mod tests {
     fn test_session_type() {
         assert_ne!(MirrorSessionType::Span, MirrorSessionType::Erspan);
     }
+
+    #[test]
+    fn test_needs_resolution() {
+        let mut config = MirrorSessionConfig {
+            session_type: MirrorSessionType::Erspan,
+            direction: MirrorDirection::Both,
+            dst_port: None,
+            src_ip: None,
+            dst_ip: None,
+        };
+        assert!(!config.needs_resolution());
+
+        config.dst_ip = Some("10.0.0.1".parse().unwrap());
+        assert!(config.needs_resolution());
+
+        config.session_type = MirrorSessionType::Span;
+        assert!(!config.needs_resolution());
+    }
 }
@@ -6,7 +6,10 @@
 use std::cell::RefCell;
 use std::ffi::{c_char, CStr, CString};
 
+use sonic_ffi_bridge::{publish_cpp_event, CppObserverEvent, CppObserverSubject};
+
 use super::orch::{MlagOrch, MlagOrchConfig};
+use super::types::MlagUpdate;
 
 // Thread-local storage for the MlagOrch instance
 thread_local! {
@@ -241,10 +244,30 @@ pub extern "C" fn rust_mlag_orch_stats_notifications() -> u64 {
     })
 }
 
+/// Converts an MLAG update into the event C++ observers receive.
+fn mlag_update_event(update: &MlagUpdate) -> CppObserverEvent {
+    match update {
+        MlagUpdate::Isl(isl) => CppObserverEvent::new(CppObserverSubject::MlagIslChange)
+            .with_name(&isl.isl_name)
+            .with_add(isl.is_add),
+        MlagUpdate::Intf(intf) => CppObserverEvent::new(CppObserverSubject::MlagIntfChange)
+            .with_name(&intf.if_name)
+            .with_add(intf.is_add),
+    }
+}
+
+/// Forwards an MLAG update to C++ observers registered through the bridge.
+pub(super) fn forward_mlag_update(update: &MlagUpdate) -> usize {
+    publish_cpp_event(mlag_update_event(update))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::ffi::CString;
+    use std::ffi::{c_void, CString};
+    use std::sync::Mutex;
+
+    use sonic_ffi_bridge::{register_cpp_observer, unregister_cpp_observer};
 
     #[test]
     fn test_register_unregister() {
@@ -351,4 +374,59 @@ mod tests {
 
         unregister_mlag_orch();
     }
+
+    unsafe extern "C-unwind" fn record(event: *const CppObserverEvent, user_ctx: *mut c_void) {
+        let events = &*(user_ctx as *const Mutex<Vec<CppObserverEvent>>);
+        events.lock().unwrap().push(*event);
+    }
+
+    #[test]
+    fn test_updates_forwarded_to_cpp_observers() {
+        let events: Mutex<Vec<CppObserverEvent>> = Mutex::new(Vec::new());
+        let ctx = &events as *const _ as *mut c_void;
+        let handles = [
+            CppObserverSubject::MlagIslChange,
+            CppObserverSubject::MlagIntfChange,
+        ]
+        .map(|subject| unsafe { register_cpp_observer(subject as u32, Some(record), ctx) });
+
+        let mut orch = MlagOrch::new(MlagOrchConfig::default());
+        orch.add_isl_interface("PortChannel4001").unwrap();
+        orch.add_mlag_interface("PortChannel4002").unwrap();
+        orch.del_mlag_interface("PortChannel4002").unwrap();
+
+        for handle in handles {
+            assert_eq!(unregister_cpp_observer(handle), 0);
+        }
+        orch.del_isl_interface().unwrap();
+
+        // Other tests publish MLAG updates concurrently
+        let seen: Vec<_> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.name().starts_with("PortChannel400"))
+            .map(|e| (e.subject(), e.name().to_string(), e.is_add))
+            .collect();
+        assert_eq!(
+            seen,
+            vec![
+                (
+                    Some(CppObserverSubject::MlagIslChange),
+                    "PortChannel4001".to_string(),
+                    1
+                ),
+                (
+                    Some(CppObserverSubject::MlagIntfChange),
+                    "PortChannel4002".to_string(),
+                    1
+                ),
+                (
+                    Some(CppObserverSubject::MlagIntfChange),
+                    "PortChannel4002".to_string(),
+                    0
+                ),
+            ]
+        );
+    }
 }
@@ -13,7 +13,8 @@
 //!   MlagOrch ───> Observers (FdbOrch, etc.)
 //!      │
 //!      ├──> ISL (peer-link) tracking
-//!      └──> MLAG interface membership
+//!      ├──> MLAG interface membership
+//!      └──> ISL isolation (IsolationGroupOrch, or AclOrch as fallback)
 //!
 //! CONFIG_DB:MCLAG_INTERFACE
 //!      │
@@ -26,6 +27,9 @@
 //! - `MlagSubjectType::IslChange` - Peer-link changed
 //! - `MlagSubjectType::IntfChange` - MLAG interface membership changed
 //!
+//! The same updates are forwarded to C++ observers registered with
+//! `sonic_ffi_bridge::register_cpp_observer`.
+//!
 //! # Safety Improvements over C++
 //!
 //! The C++ implementation has:
@@ -43,4 +47,7 @@ mod types;
 
 pub use ffi::{register_mlag_orch, unregister_mlag_orch};
 pub use orch::{MlagOrch, MlagOrchCallbacks, MlagOrchConfig, MlagOrchError, MlagOrchStats};
-pub use types::{MlagIfUpdate, MlagIslUpdate, MlagSubjectType, MlagUpdate};
+pub use types::{
+    MlagIfUpdate, MlagIslUpdate, MlagIsolationBackend, MlagIsolationState, MlagSubjectType,
+    MlagUpdate, MLAG_ISOLATION_GROUP_NAME,
+};
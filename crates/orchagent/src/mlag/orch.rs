@@ -3,7 +3,10 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use super::types::{MlagIfUpdate, MlagIslUpdate, MlagSubjectType, MlagUpdate};
+use super::types::{
+    MlagIfUpdate, MlagIslUpdate, MlagIsolationBackend, MlagIsolationState, MlagSubjectType,
+    MlagUpdate, MLAG_ISOLATION_GROUP_NAME,
+};
 
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
@@ -24,6 +27,9 @@ pub enum MlagOrchError {
     /// Ports not ready.
     #[error("Ports not ready")]
     PortsNotReady,
+    /// Installing the peer-link isolation failed.
+    #[error("Isolation update failed: {0}")]
+    IsolationFailed(String),
 }
 
 /// Callbacks for MlagOrch operations.
@@ -33,6 +39,34 @@ pub trait MlagOrchCallbacks: Send + Sync {
 
     /// Returns true if all ports are ready.
     fn all_ports_ready(&self) -> bool;
+
+    /// Returns true if bridge-port isolation groups are supported; otherwise
+    /// the ISL isolation falls back to an ACL rule.
+    fn supports_isolation_group(&self) -> bool;
+
+    /// Creates a bridge-port isolation group (via IsolationGroupOrch).
+    fn create_isolation_group(&self, name: &str) -> Result<(), String>;
+
+    /// Removes an isolation group.
+    fn remove_isolation_group(&self, name: &str) -> Result<(), String>;
+
+    /// Adds a port to an isolation group.
+    fn add_isolation_group_member(&self, name: &str, port: &str) -> Result<(), String>;
+
+    /// Removes a port from an isolation group.
+    fn remove_isolation_group_member(&self, name: &str, port: &str) -> Result<(), String>;
+
+    /// Binds an isolation group to the port whose ingress traffic it filters.
+    fn bind_isolation_group(&self, name: &str, port: &str) -> Result<(), String>;
+
+    /// Unbinds an isolation group from a port.
+    fn unbind_isolation_group(&self, name: &str, port: &str) -> Result<(), String>;
+
+    /// Creates or updates the ACL rule dropping ISL traffic towards `members` (via AclOrch).
+    fn set_isl_acl_rule(&self, isl_name: &str, members: &[String]) -> Result<(), String>;
+
+    /// Removes the ACL isolation rule.
+    fn remove_isl_acl_rule(&self, isl_name: &str) -> Result<(), String>;
 }
 
 /// MLAG orchestrator configuration.
@@ -54,6 +88,10 @@ pub struct MlagOrchStats {
     pub intf_deletes: u64,
     /// Number of notifications sent.
     pub notifications: u64,
+    /// Number of isolation installs and updates.
+    pub isolation_updates: u64,
+    /// Number of failed isolation installs and updates.
+    pub isolation_errors: u64,
 }
 
 /// MLAG orchestrator for Multi-Chassis Link Aggregation.
@@ -64,6 +102,8 @@ pub struct MlagOrch {
     isl_name: Option<String>,
     /// Set of MLAG member interfaces.
     mlag_intfs: HashSet<String>,
+    /// Peer-link isolation currently installed.
+    isolation: Option<MlagIsolationState>,
     /// Callbacks for notifications and port queries.
     callbacks: Option<Arc<dyn MlagOrchCallbacks>>,
     /// Whether the orch is initialized.
@@ -91,6 +131,7 @@ impl MlagOrch {
             config,
             isl_name: None,
             mlag_intfs: HashSet::new(),
+            isolation: None,
             callbacks: None,
             initialized: false,
             stats: MlagOrchStats::default(),
@@ -176,6 +217,7 @@ impl MlagOrch {
 
         // Notify observers
         self.notify(MlagUpdate::Isl(MlagIslUpdate::add(isl_name)));
+        self.sync_isolation_logged();
 
         Ok(true)
     }
@@ -211,6 +253,7 @@ impl MlagOrch {
 
         // Notify observers
         self.notify(MlagUpdate::Isl(MlagIslUpdate::delete(old_isl)));
+        self.sync_isolation_logged();
 
         Ok(true)
     }
@@ -251,6 +294,7 @@ impl MlagOrch {
 
         // Notify observers
         self.notify(MlagUpdate::Intf(MlagIfUpdate::add(if_name)));
+        self.sync_isolation_logged();
 
         Ok(true)
     }
@@ -291,6 +335,7 @@ impl MlagOrch {
 
         // Notify observers
         self.notify(MlagUpdate::Intf(MlagIfUpdate::delete(if_name)));
+        self.sync_isolation_logged();
 
         Ok(true)
     }
@@ -311,11 +356,166 @@ impl MlagOrch {
     /// Sends a notification to observers.
     fn notify(&mut self, update: MlagUpdate) {
         self.stats.notifications += 1;
+        super::ffi::forward_mlag_update(&update);
         if let Some(callbacks) = &self.callbacks {
             callbacks.notify(update);
         }
     }
 
+    /// Returns the peer-link isolation currently installed.
+    pub fn isolation(&self) -> Option<&MlagIsolationState> {
+        self.isolation.as_ref()
+    }
+
+    /// Runs `sync_isolation`, recording a failure instead of failing the
+    /// membership change that triggered it; a later sync retries.
+    fn sync_isolation_logged(&mut self) {
+        if let Err(e) = self.sync_isolation() {
+            self.stats.isolation_errors += 1;
+            let audit_record =
+                AuditRecord::new(AuditCategory::NetworkConfig, "MlagOrch", "sync_isolation")
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(MLAG_ISOLATION_GROUP_NAME)
+                    .with_object_type("mlag_isolation")
+                    .with_error(e.to_string());
+            audit_log!(audit_record);
+        }
+    }
+
+    /// Brings the peer-link isolation in line with the ISL and MLAG members.
+    ///
+    /// The isolation exists while an ISL is configured and blocks traffic
+    /// received on it from egressing MLAG member ports. Membership and ISL
+    /// changes update the installed group in place.
+    pub fn sync_isolation(&mut self) -> Result<(), MlagOrchError> {
+        let Some(callbacks) = self.callbacks.clone() else {
+            return Ok(());
+        };
+        let err = MlagOrchError::IsolationFailed;
+
+        let Some(isl_name) = self.isl_name.clone() else {
+            if let Some(current) = self.isolation.take() {
+                let result = match current.backend {
+                    MlagIsolationBackend::IsolationGroup => callbacks
+                        .unbind_isolation_group(MLAG_ISOLATION_GROUP_NAME, &current.isl_name)
+                        .and_then(|_| callbacks.remove_isolation_group(MLAG_ISOLATION_GROUP_NAME)),
+                    MlagIsolationBackend::Acl => callbacks.remove_isl_acl_rule(&current.isl_name),
+                };
+                if let Err(e) = result {
+                    self.isolation = Some(current);
+                    return Err(err(e));
+                }
+                self.stats.isolation_updates += 1;
+            }
+            return Ok(());
+        };
+
+        let members: HashSet<String> = self
+            .mlag_intfs
+            .iter()
+            .filter(|intf| **intf != isl_name)
+            .cloned()
+            .collect();
+
+        let backend = match &self.isolation {
+            Some(current) => current.backend,
+            None if callbacks.supports_isolation_group() => MlagIsolationBackend::IsolationGroup,
+            None => MlagIsolationBackend::Acl,
+        };
+
+        if backend == MlagIsolationBackend::Acl {
+            if self
+                .isolation
+                .as_ref()
+                .is_some_and(|current| current.isl_name == isl_name && current.members == members)
+            {
+                return Ok(());
+            }
+            if let Some(current) = &self.isolation {
+                if current.isl_name != isl_name {
+                    callbacks
+                        .remove_isl_acl_rule(&current.isl_name)
+                        .map_err(err)?;
+                    self.isolation = None;
+                }
+            }
+            let mut sorted: Vec<String> = members.iter().cloned().collect();
+            sorted.sort();
+            callbacks
+                .set_isl_acl_rule(&isl_name, &sorted)
+                .map_err(err)?;
+            self.isolation = Some(MlagIsolationState {
+                backend,
+                isl_name,
+                members,
+            });
+            self.stats.isolation_updates += 1;
+            return Ok(());
+        }
+
+        let state = match self.isolation.as_mut() {
+            Some(state) => state,
+            None => {
+                callbacks
+                    .create_isolation_group(MLAG_ISOLATION_GROUP_NAME)
+                    .map_err(err)?;
+                self.isolation.insert(MlagIsolationState {
+                    backend,
+                    isl_name: String::new(),
+                    members: HashSet::new(),
+                })
+            }
+        };
+        let mut changed = false;
+
+        let stale: Vec<String> = state.members.difference(&members).cloned().collect();
+        for port in stale {
+            callbacks
+                .remove_isolation_group_member(MLAG_ISOLATION_GROUP_NAME, &port)
+                .map_err(err)?;
+            state.members.remove(&port);
+            changed = true;
+        }
+        let mut added: Vec<String> = members.difference(&state.members).cloned().collect();
+        added.sort();
+        for port in added {
+            callbacks
+                .add_isolation_group_member(MLAG_ISOLATION_GROUP_NAME, &port)
+                .map_err(err)?;
+            state.members.insert(port);
+            changed = true;
+        }
+
+        if state.isl_name != isl_name {
+            if !state.isl_name.is_empty() {
+                callbacks
+                    .unbind_isolation_group(MLAG_ISOLATION_GROUP_NAME, &state.isl_name)
+                    .map_err(err)?;
+                state.isl_name.clear();
+            }
+            callbacks
+                .bind_isolation_group(MLAG_ISOLATION_GROUP_NAME, &isl_name)
+                .map_err(err)?;
+            state.isl_name = isl_name;
+            changed = true;
+        }
+
+        if changed {
+            self.stats.isolation_updates += 1;
+            let audit_record =
+                AuditRecord::new(AuditCategory::NetworkConfig, "MlagOrch", "sync_isolation")
+                    .with_outcome(AuditOutcome::Success)
+                    .with_object_id(MLAG_ISOLATION_GROUP_NAME)
+                    .with_object_type("mlag_isolation")
+                    .with_details(serde_json::json!({
+                        "isl_interface": state.isl_name,
+                        "members": state.members.len(),
+                    }));
+            audit_log!(audit_record);
+        }
+        Ok(())
+    }
+
     /// Returns true if all ports are ready.
     pub fn all_ports_ready(&self) -> bool {
         self.callbacks
@@ -328,11 +528,23 @@ impl MlagOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use std::sync::Mutex;
 
+    /// Isolation group as seen by the mock: members and bound ports.
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    struct MockGroup {
+        members: HashSet<String>,
+        bound: HashSet<String>,
+    }
+
     struct TestCallbacks {
         updates: Mutex<Vec<MlagUpdate>>,
         ports_ready: bool,
+        isolation_group_supported: bool,
+        groups: Mutex<HashMap<String, MockGroup>>,
+        groups_created: Mutex<u32>,
+        acl_rules: Mutex<HashMap<String, Vec<String>>>,
     }
 
     impl TestCallbacks {
@@ -340,15 +552,51 @@ mod tests {
             Self {
                 updates: Mutex::new(Vec::new()),
                 ports_ready: true,
+                isolation_group_supported: true,
+                groups: Mutex::new(HashMap::new()),
+                groups_created: Mutex::new(0),
+                acl_rules: Mutex::new(HashMap::new()),
             }
         }
 
         fn with_ports_ready(ports_ready: bool) -> Self {
             Self {
-                updates: Mutex::new(Vec::new()),
                 ports_ready,
+                ..Self::new()
             }
         }
+
+        fn without_isolation_group() -> Self {
+            Self {
+                isolation_group_supported: false,
+                ..Self::new()
+            }
+        }
+
+        fn group(&self) -> Option<MockGroup> {
+            self.groups
+                .lock()
+                .unwrap()
+                .get(MLAG_ISOLATION_GROUP_NAME)
+                .cloned()
+        }
+
+        fn with_group<R>(
+            &self,
+            name: &str,
+            f: impl FnOnce(&mut MockGroup) -> R,
+        ) -> Result<R, String> {
+            self.groups
+                .lock()
+                .unwrap()
+                .get_mut(name)
+                .map(f)
+                .ok_or_else(|| format!("no group {}", name))
+        }
+    }
+
+    fn set(items: &[&str]) -> HashSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
     }
 
     impl MlagOrchCallbacks for TestCallbacks {
@@ -359,6 +607,56 @@ mod tests {
         fn all_ports_ready(&self) -> bool {
             self.ports_ready
         }
+
+        fn supports_isolation_group(&self) -> bool {
+            self.isolation_group_supported
+        }
+
+        fn create_isolation_group(&self, name: &str) -> Result<(), String> {
+            *self.groups_created.lock().unwrap() += 1;
+            self.groups
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), MockGroup::default());
+            Ok(())
+        }
+
+        fn remove_isolation_group(&self, name: &str) -> Result<(), String> {
+            self.groups.lock().unwrap().remove(name);
+            Ok(())
+        }
+
+        fn add_isolation_group_member(&self, name: &str, port: &str) -> Result<(), String> {
+            self.with_group(name, |g| g.members.insert(port.to_string()))
+                .map(|_| ())
+        }
+
+        fn remove_isolation_group_member(&self, name: &str, port: &str) -> Result<(), String> {
+            self.with_group(name, |g| g.members.remove(port))
+                .map(|_| ())
+        }
+
+        fn bind_isolation_group(&self, name: &str, port: &str) -> Result<(), String> {
+            self.with_group(name, |g| g.bound.insert(port.to_string()))
+                .map(|_| ())
+        }
+
+        fn unbind_isolation_group(&self, name: &str, port: &str) -> Result<(), String> {
+            self.with_group(name, |g| g.bound.remove(port)).map(|_| ())
+        }
+
+        fn set_isl_acl_rule(&self, isl_name: &str, members: &[String]) -> Result<(), String> {
+            self.acl_rules
+                .lock()
+                .unwrap()
+                .insert(isl_name.to_string(), members.to_vec());
+            Ok(())
+        }
+
+        fn remove_isl_acl_rule(&self, isl_name: &str) -> Result<(), String> {
+            self.acl_rules.lock().unwrap().remove(isl_name);
+            Ok(())
+        }
     }
 
     #[test]
@@ -1105,4 +1403,74 @@ mod tests {
         let _err_trait: &dyn Error = &err;
         assert!(format!("{}", err).contains("test"));
     }
+
+    #[test]
+    fn test_isolation_group_tracks_membership_and_isl() {
+        let mut orch = MlagOrch::new(MlagOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.add_isl_interface("PortChannel100").unwrap();
+        orch.add_mlag_interface("PortChannel1").unwrap();
+        orch.add_mlag_interface("PortChannel2").unwrap();
+        assert_eq!(
+            callbacks.group(),
+            Some(MockGroup {
+                members: set(&["PortChannel1", "PortChannel2"]),
+                bound: set(&["PortChannel100"]),
+            })
+        );
+
+        orch.del_mlag_interface("PortChannel1").unwrap();
+        assert_eq!(callbacks.group().unwrap().members, set(&["PortChannel2"]));
+
+        // Changing the ISL rebinds the existing group
+        orch.add_isl_interface("PortChannel200").unwrap();
+        assert_eq!(
+            callbacks.group(),
+            Some(MockGroup {
+                members: set(&["PortChannel2"]),
+                bound: set(&["PortChannel200"]),
+            })
+        );
+        assert_eq!(*callbacks.groups_created.lock().unwrap(), 1);
+        assert_eq!(orch.isolation().unwrap().isl_name, "PortChannel200");
+
+        // Deleting the domain removes the group
+        orch.del_isl_interface().unwrap();
+        assert!(callbacks.group().is_none());
+        assert!(orch.isolation().is_none());
+        assert_eq!(orch.stats().isolation_errors, 0);
+    }
+
+    #[test]
+    fn test_isolation_acl_fallback() {
+        let mut orch = MlagOrch::new(MlagOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::without_isolation_group());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.add_mlag_interface("PortChannel1").unwrap();
+        assert!(callbacks.acl_rules.lock().unwrap().is_empty());
+
+        orch.add_isl_interface("PortChannel100").unwrap();
+        orch.add_mlag_interface("PortChannel2").unwrap();
+        assert_eq!(
+            callbacks.acl_rules.lock().unwrap().get("PortChannel100"),
+            Some(&vec![
+                "PortChannel1".to_string(),
+                "PortChannel2".to_string()
+            ])
+        );
+        assert!(callbacks.group().is_none());
+
+        orch.add_isl_interface("PortChannel200").unwrap();
+        {
+            let rules = callbacks.acl_rules.lock().unwrap();
+            assert_eq!(rules.len(), 1);
+            assert!(rules.contains_key("PortChannel200"));
+        }
+
+        orch.del_isl_interface().unwrap();
+        assert!(callbacks.acl_rules.lock().unwrap().is_empty());
+    }
 }
@@ -1,5 +1,10 @@
 //! MLAG types and data structures.
 
+use std::collections::HashSet;
+
+/// Name of the bridge-port isolation group blocking ISL to MLAG member flooding.
+pub const MLAG_ISOLATION_GROUP_NAME: &str = "MCLAG_ISO_GRP";
+
 /// MLAG interface update notification.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MlagIfUpdate {
@@ -82,6 +87,26 @@ impl MlagUpdate {
     }
 }
 
+/// Mechanism used to install the peer-link isolation rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MlagIsolationBackend {
+    /// Bridge-port isolation group bound to the ISL.
+    IsolationGroup,
+    /// ACL rule on the ISL, used when isolation groups are unsupported.
+    Acl,
+}
+
+/// Peer-link isolation currently installed in hardware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MlagIsolationState {
+    /// How the isolation is installed.
+    pub backend: MlagIsolationBackend,
+    /// ISL the isolation applies to (source of the blocked traffic).
+    pub isl_name: String,
+    /// MLAG member ports that ISL traffic must not egress.
+    pub members: HashSet<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,7 @@
 //! FFI exports for MplsRouteOrch.
 
 use super::orch::{MplsRouteOrch, MplsRouteOrchCallbacks, MplsRouteOrchConfig, Result};
-use super::types::{MplsRouteConfig, RawSaiObjectId};
+use super::types::{MplsInseg, MplsLabel, RawSaiObjectId};
 use std::cell::RefCell;
 use std::sync::Arc;
 
@@ -9,7 +9,7 @@ use std::sync::Arc;
 pub struct FfiMplsRouteCallbacks;
 
 impl MplsRouteOrchCallbacks for FfiMplsRouteCallbacks {
-    fn create_mpls_route(&self, _label: u32, _config: &MplsRouteConfig) -> Result<RawSaiObjectId> {
+    fn create_mpls_route(&self, _label: u32, _inseg: &MplsInseg) -> Result<RawSaiObjectId> {
         Ok(0)
     }
 
@@ -21,12 +21,12 @@ impl MplsRouteOrchCallbacks for FfiMplsRouteCallbacks {
         &self,
         _label: u32,
         _route_oid: RawSaiObjectId,
-        _config: &MplsRouteConfig,
+        _inseg: &MplsInseg,
     ) -> Result<()> {
         Ok(())
     }
 
-    fn create_next_hop(&self, _ip_address: &str) -> Result<RawSaiObjectId> {
+    fn create_next_hop(&self, _ip_address: &str, _labels: &[MplsLabel]) -> Result<RawSaiObjectId> {
         Ok(0)
     }
 
@@ -7,6 +7,7 @@
 //! - Validated label range (0-1048575)
 //! - Type-safe MPLS actions (Pop/Swap/Push)
 //! - Vec for push label stack
+//! - Label next hops shared between routes and reference counted
 //! - Generic callbacks for SAI integration
 //! - Full CRUD operations with error handling
 
@@ -20,6 +21,8 @@ pub use orch::{
     MplsRouteOrchStats, Result,
 };
 pub use types::{
-    MplsAction, MplsLabel, MplsRouteConfig, MplsRouteEntry, MplsRouteKey, MplsRouteStats,
-    RawSaiObjectId,
+    is_explicit_null, LabelStack, MplsAction, MplsInseg, MplsLabel, MplsNextHopKey,
+    MplsPacketAction, MplsRouteConfig, MplsRouteEntry, MplsRouteKey, MplsRouteStats,
+    RawSaiObjectId, MPLS_LABEL_IMPLICIT_NULL, MPLS_LABEL_IPV4_EXPLICIT_NULL,
+    MPLS_LABEL_IPV6_EXPLICIT_NULL, MPLS_LABEL_MAX, MPLS_LABEL_RESERVED_MAX,
 };
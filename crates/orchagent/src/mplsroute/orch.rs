@@ -1,6 +1,10 @@
 //! MPLS route orchestration logic.
 
-use super::types::{MplsRouteConfig, MplsRouteEntry, MplsRouteKey, MplsRouteStats, RawSaiObjectId};
+use super::types::{
+    is_explicit_null, LabelStack, MplsAction, MplsInseg, MplsLabel, MplsNextHopKey,
+    MplsPacketAction, MplsRouteConfig, MplsRouteEntry, MplsRouteKey, MplsRouteStats,
+    RawSaiObjectId, MPLS_LABEL_IMPLICIT_NULL, MPLS_LABEL_MAX,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -34,24 +38,32 @@ pub struct MplsRouteOrchStats {
 }
 
 pub trait MplsRouteOrchCallbacks: Send + Sync {
-    fn create_mpls_route(&self, label: u32, config: &MplsRouteConfig) -> Result<RawSaiObjectId>;
-    fn remove_mpls_route(&self, label: u32, route_oid: RawSaiObjectId) -> Result<()>;
+    fn create_mpls_route(&self, label: MplsLabel, inseg: &MplsInseg) -> Result<RawSaiObjectId>;
+    fn remove_mpls_route(&self, label: MplsLabel, route_oid: RawSaiObjectId) -> Result<()>;
     fn update_mpls_route(
         &self,
-        label: u32,
+        label: MplsLabel,
         route_oid: RawSaiObjectId,
-        config: &MplsRouteConfig,
+        inseg: &MplsInseg,
     ) -> Result<()>;
-    fn create_next_hop(&self, ip_address: &str) -> Result<RawSaiObjectId>;
+    /// Creates a next hop pushing `labels` (outermost first) through NhgOrch.
+    fn create_next_hop(&self, ip_address: &str, labels: &[MplsLabel]) -> Result<RawSaiObjectId>;
     fn remove_next_hop(&self, nh_oid: RawSaiObjectId) -> Result<()>;
-    fn on_route_created(&self, label: u32, route_oid: RawSaiObjectId);
-    fn on_route_removed(&self, label: u32);
+    fn on_route_created(&self, label: MplsLabel, route_oid: RawSaiObjectId);
+    fn on_route_removed(&self, label: MplsLabel);
+}
+
+#[derive(Debug, Clone)]
+struct SharedNextHop {
+    nh_oid: RawSaiObjectId,
+    ref_count: u32,
 }
 
 pub struct MplsRouteOrch<C: MplsRouteOrchCallbacks> {
     _config: MplsRouteOrchConfig,
     stats: MplsRouteOrchStats,
     routes: HashMap<MplsRouteKey, MplsRouteEntry>,
+    next_hops: HashMap<MplsNextHopKey, SharedNextHop>,
     callbacks: Option<Arc<C>>,
 }
 
@@ -61,6 +73,7 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
             _config: config,
             stats: MplsRouteOrchStats::default(),
             routes: HashMap::new(),
+            next_hops: HashMap::new(),
             callbacks: None,
         }
     }
@@ -70,14 +83,156 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
         self
     }
 
+    fn callbacks(&self) -> Result<Arc<C>> {
+        self.callbacks.clone().ok_or(MplsRouteOrchError::SaiError(
+            "No callbacks registered".into(),
+        ))
+    }
+
+    fn validate_key(key: &MplsRouteKey) -> Result<()> {
+        key.validate_label()
+            .map_err(|_| MplsRouteOrchError::InvalidLabel(key.label))?;
+        if !key.is_routable() {
+            return Err(MplsRouteOrchError::InvalidLabel(key.label));
+        }
+        Ok(())
+    }
+
+    /// Builds the outgoing label stack for a route.
+    ///
+    /// Pop yields an empty stack (PHP). Swap to implicit-null is also a pop.
+    /// Push is a swap (if `swap_label` is set) followed by `push_labels`.
+    /// Explicit-null may only sit at the bottom of the stack.
+    fn out_labels(key: &MplsRouteKey, config: &MplsRouteConfig) -> Result<LabelStack> {
+        let mut labels = LabelStack::new();
+        match config.action {
+            MplsAction::Pop => {}
+            MplsAction::Swap | MplsAction::Push => {
+                if is_explicit_null(key.label) {
+                    return Err(MplsRouteOrchError::ConfigurationError(format!(
+                        "Explicit-null label {} can only be popped",
+                        key.label
+                    )));
+                }
+                match config.swap_label {
+                    Some(MPLS_LABEL_IMPLICIT_NULL) => {}
+                    Some(label) => labels.push(label),
+                    None if config.action == MplsAction::Swap => {
+                        return Err(MplsRouteOrchError::ConfigurationError(
+                            "Swap requires a swap label".to_string(),
+                        ))
+                    }
+                    None => {}
+                }
+                if config.action == MplsAction::Push {
+                    if config.push_labels.is_empty() {
+                        return Err(MplsRouteOrchError::ConfigurationError(
+                            "Push requires a label stack".to_string(),
+                        ));
+                    }
+                    labels.extend_from_slice(&config.push_labels);
+                }
+            }
+        }
+
+        for (i, &label) in labels.iter().enumerate() {
+            if label > MPLS_LABEL_MAX {
+                return Err(MplsRouteOrchError::InvalidLabel(label));
+            }
+            if label == MPLS_LABEL_IMPLICIT_NULL
+                || (is_explicit_null(label) && i + 1 != labels.len())
+            {
+                return Err(MplsRouteOrchError::ConfigurationError(format!(
+                    "Reserved label {} not allowed at position {} of {:?}",
+                    label, i, labels
+                )));
+            }
+        }
+        Ok(labels)
+    }
+
+    /// Returns the shared next hop for `key`, creating it on first use.
+    fn acquire_next_hop(&mut self, callbacks: &C, key: &MplsNextHopKey) -> Result<RawSaiObjectId> {
+        if let Some(nh) = self.next_hops.get_mut(key) {
+            nh.ref_count += 1;
+            return Ok(nh.nh_oid);
+        }
+        let nh_oid = callbacks.create_next_hop(&key.ip_address, &key.labels)?;
+        self.next_hops.insert(
+            key.clone(),
+            SharedNextHop {
+                nh_oid,
+                ref_count: 1,
+            },
+        );
+        self.stats.stats.next_hops_created += 1;
+        Ok(nh_oid)
+    }
+
+    /// Drops a reference on a shared next hop, removing it with the last one.
+    fn release_next_hop(&mut self, callbacks: &C, key: &MplsNextHopKey) -> Result<()> {
+        let nh = match self.next_hops.get_mut(key) {
+            Some(nh) => nh,
+            None => return Ok(()),
+        };
+        nh.ref_count = nh.ref_count.saturating_sub(1);
+        if nh.ref_count > 0 {
+            return Ok(());
+        }
+        let nh_oid = nh.nh_oid;
+        callbacks.remove_next_hop(nh_oid)?;
+        self.next_hops.remove(key);
+        self.stats.stats.next_hops_removed += 1;
+        Ok(())
+    }
+
+    /// Resolves the next hop and inseg attributes for a route, taking a
+    /// reference on the shared next hop.
+    fn resolve_inseg(
+        &mut self,
+        callbacks: &C,
+        key: &MplsRouteKey,
+        config: &MplsRouteConfig,
+    ) -> Result<(MplsInseg, Option<MplsNextHopKey>)> {
+        let labels = Self::out_labels(key, config)?;
+        let nh_key = match &config.next_hop {
+            Some(ip_address) => Some(MplsNextHopKey {
+                ip_address: ip_address.clone(),
+                labels,
+            }),
+            None if !labels.is_empty() => {
+                return Err(MplsRouteOrchError::ConfigurationError(format!(
+                    "Label {} pushes {:?} without a next hop",
+                    key.label, labels
+                )))
+            }
+            None => None,
+        };
+
+        let (packet_action, nh_oid) = match &nh_key {
+            Some(nh_key) => (
+                MplsPacketAction::Forward,
+                self.acquire_next_hop(callbacks, nh_key)?,
+            ),
+            // Explicit-null without a next hop: pop and look up the payload
+            None if is_explicit_null(key.label) => (MplsPacketAction::Forward, 0),
+            None => (MplsPacketAction::Drop, 0),
+        };
+
+        let inseg = MplsInseg {
+            pop_count: 1,
+            packet_action,
+            nh_oid,
+        };
+        Ok((inseg, nh_key))
+    }
+
     pub fn add_route(
         &mut self,
         key: MplsRouteKey,
         config: MplsRouteConfig,
     ) -> Result<RawSaiObjectId> {
-        // Validate label
-        key.validate_label()
-            .map_err(|_| MplsRouteOrchError::InvalidLabel(key.label))?;
+        Self::validate_key(&key)?;
 
         if self.routes.contains_key(&key) {
             let audit_record = AuditRecord::new(
@@ -93,18 +248,18 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
             return Err(MplsRouteOrchError::RouteExists(key));
         }
 
-        let callbacks = self.callbacks.as_ref().ok_or(MplsRouteOrchError::SaiError(
-            "No callbacks registered".into(),
-        ))?;
-
-        // Create the MPLS route
-        let route_oid = callbacks.create_mpls_route(key.label, &config)?;
+        let callbacks = self.callbacks()?;
+        let (inseg, nh_key) = self.resolve_inseg(&callbacks, &key, &config)?;
 
-        // Create next hop if specified
-        let mut nh_oid = 0;
-        if let Some(next_hop) = &config.next_hop {
-            nh_oid = callbacks.create_next_hop(next_hop)?;
-        }
+        let route_oid = match callbacks.create_mpls_route(key.label, &inseg) {
+            Ok(oid) => oid,
+            Err(e) => {
+                if let Some(nh_key) = &nh_key {
+                    let _ = self.release_next_hop(&callbacks, nh_key);
+                }
+                return Err(e);
+            }
+        };
 
         let audit_record = AuditRecord::new(
             AuditCategory::ResourceCreate,
@@ -117,14 +272,18 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
         .with_details(serde_json::json!({
             "label": key.label,
             "route_oid": format!("0x{:x}", route_oid),
+            "action": format!("{:?}", config.action),
+            "packet_action": format!("{:?}", inseg.packet_action),
             "next_hop": config.next_hop.as_deref(),
-            "nh_oid": if nh_oid != 0 { Some(format!("0x{:x}", nh_oid)) } else { None },
+            "out_labels": nh_key.as_ref().map(|nh| nh.labels.clone()),
+            "nh_oid": if inseg.nh_oid != 0 { Some(format!("0x{:x}", inseg.nh_oid)) } else { None },
         }));
         audit_log!(audit_record);
 
         let mut entry = MplsRouteEntry::new(key.clone(), config);
         entry.route_oid = route_oid;
-        entry.nh_oid = nh_oid;
+        entry.nh_oid = inseg.nh_oid;
+        entry.nh_key = nh_key;
 
         self.routes.insert(key.clone(), entry);
         self.stats.stats.routes_created += 1;
@@ -149,17 +308,13 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
             MplsRouteOrchError::RouteNotFound(key.clone())
         })?;
 
-        let callbacks = self.callbacks.as_ref().ok_or(MplsRouteOrchError::SaiError(
-            "No callbacks registered".into(),
-        ))?;
+        let callbacks = self.callbacks()?;
 
-        // Remove the next hop if it exists
-        if entry.nh_oid != 0 {
-            callbacks.remove_next_hop(entry.nh_oid)?;
-        }
-
-        // Remove the route
+        // Remove the route before releasing the next hop it points to
         callbacks.remove_mpls_route(key.label, entry.route_oid)?;
+        if let Some(nh_key) = &entry.nh_key {
+            self.release_next_hop(&callbacks, nh_key)?;
+        }
 
         let audit_record = AuditRecord::new(
             AuditCategory::ResourceDelete,
@@ -172,7 +327,8 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
         .with_details(serde_json::json!({
             "label": key.label,
             "route_oid": format!("0x{:x}", entry.route_oid),
-            "nh_oid_removed": entry.nh_oid,
+            "nh_oid": entry.nh_oid,
+            "nh_removed": entry.nh_key.as_ref().is_some_and(|nh| !self.next_hops.contains_key(nh)),
         }));
         audit_log!(audit_record);
 
@@ -183,39 +339,32 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
     }
 
     pub fn update_route(&mut self, key: &MplsRouteKey, config: MplsRouteConfig) -> Result<()> {
-        // Validate label
-        key.validate_label()
-            .map_err(|_| MplsRouteOrchError::InvalidLabel(key.label))?;
-
-        let entry = self
-            .routes
-            .get_mut(key)
-            .ok_or_else(|| MplsRouteOrchError::RouteNotFound(key.clone()))?;
-
-        let callbacks = self.callbacks.as_ref().ok_or(MplsRouteOrchError::SaiError(
-            "No callbacks registered".into(),
-        ))?;
-
-        let old_next_hop = entry.config.next_hop.clone();
-
-        // Update the route through SAI
-        callbacks.update_mpls_route(key.label, entry.route_oid, &config)?;
-
-        // Handle next hop changes
-        if let Some(new_next_hop) = &config.next_hop {
-            if entry.config.next_hop.as_ref() != Some(new_next_hop) {
-                // Remove old next hop if it exists
-                if entry.nh_oid != 0 {
-                    callbacks.remove_next_hop(entry.nh_oid)?;
-                }
-                // Create new next hop
-                let new_nh_oid = callbacks.create_next_hop(new_next_hop)?;
-                entry.nh_oid = new_nh_oid;
+        Self::validate_key(key)?;
+
+        let (route_oid, old_nh_key, old_next_hop) = {
+            let entry = self
+                .routes
+                .get(key)
+                .ok_or_else(|| MplsRouteOrchError::RouteNotFound(key.clone()))?;
+            (
+                entry.route_oid,
+                entry.nh_key.clone(),
+                entry.config.next_hop.clone(),
+            )
+        };
+        let callbacks = self.callbacks()?;
+
+        // Take the new next hop before dropping the old one so a shared
+        // next hop is never removed and recreated underneath the route.
+        let (inseg, nh_key) = self.resolve_inseg(&callbacks, key, &config)?;
+        if let Err(e) = callbacks.update_mpls_route(key.label, route_oid, &inseg) {
+            if let Some(nh_key) = &nh_key {
+                let _ = self.release_next_hop(&callbacks, nh_key);
             }
-        } else if entry.nh_oid != 0 {
-            // Remove next hop if it's being cleared
-            callbacks.remove_next_hop(entry.nh_oid)?;
-            entry.nh_oid = 0;
+            return Err(e);
+        }
+        if let Some(old_nh_key) = &old_nh_key {
+            self.release_next_hop(&callbacks, old_nh_key)?;
         }
 
         let audit_record = AuditRecord::new(
@@ -228,17 +377,31 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
         .with_object_type("mpls_route")
         .with_details(serde_json::json!({
             "label": key.label,
-            "route_oid": format!("0x{:x}", entry.route_oid),
+            "route_oid": format!("0x{:x}", route_oid),
             "old_next_hop": old_next_hop,
             "new_next_hop": config.next_hop,
+            "action": format!("{:?}", config.action),
         }));
         audit_log!(audit_record);
 
-        entry.config = config;
+        if let Some(entry) = self.routes.get_mut(key) {
+            entry.config = config;
+            entry.nh_oid = inseg.nh_oid;
+            entry.nh_key = nh_key;
+        }
 
         Ok(())
     }
 
+    /// Returns the number of routes sharing a next hop.
+    pub fn next_hop_ref_count(&self, key: &MplsNextHopKey) -> u32 {
+        self.next_hops.get(key).map_or(0, |nh| nh.ref_count)
+    }
+
+    pub fn next_hop_count(&self) -> usize {
+        self.next_hops.len()
+    }
+
     pub fn get_route(&self, key: &MplsRouteKey) -> Option<&MplsRouteEntry> {
         self.routes.get(key)
     }
@@ -273,17 +436,14 @@ impl<C: MplsRouteOrchCallbacks> MplsRouteOrch<C> {
 
 #[cfg(test)]
 mod tests {
-    use super::super::types::MplsAction;
+    use super::super::types::{MPLS_LABEL_IPV4_EXPLICIT_NULL, MPLS_LABEL_IPV6_EXPLICIT_NULL};
     use super::*;
+    use std::sync::Mutex;
 
     struct MockMplsCallbacks;
 
     impl MplsRouteOrchCallbacks for MockMplsCallbacks {
-        fn create_mpls_route(
-            &self,
-            _label: u32,
-            _config: &MplsRouteConfig,
-        ) -> Result<RawSaiObjectId> {
+        fn create_mpls_route(&self, _label: u32, _inseg: &MplsInseg) -> Result<RawSaiObjectId> {
             Ok(0x1000)
         }
 
@@ -295,12 +455,12 @@ mod tests {
             &self,
             _label: u32,
             _route_oid: RawSaiObjectId,
-            _config: &MplsRouteConfig,
+            _inseg: &MplsInseg,
         ) -> Result<()> {
             Ok(())
         }
 
-        fn create_next_hop(&self, _ip_address: &str) -> Result<RawSaiObjectId> {
+        fn create_next_hop(&self, _ip_address: &str, _labels: &[u32]) -> Result<RawSaiObjectId> {
             Ok(0x2000)
         }
 
@@ -312,6 +472,97 @@ mod tests {
         fn on_route_removed(&self, _label: u32) {}
     }
 
+    /// Records programmed insegs and live next hops.
+    #[derive(Default)]
+    struct RecordingMplsCallbacks {
+        next_oid: Mutex<RawSaiObjectId>,
+        insegs: Mutex<HashMap<u32, MplsInseg>>,
+        next_hops: Mutex<HashMap<RawSaiObjectId, (String, Vec<u32>)>>,
+    }
+
+    impl RecordingMplsCallbacks {
+        fn alloc(&self) -> RawSaiObjectId {
+            let mut next = self.next_oid.lock().unwrap();
+            *next += 1;
+            0x3000 + *next
+        }
+
+        fn inseg(&self, label: u32) -> MplsInseg {
+            self.insegs.lock().unwrap()[&label]
+        }
+
+        fn live_next_hops(&self) -> usize {
+            self.next_hops.lock().unwrap().len()
+        }
+    }
+
+    impl MplsRouteOrchCallbacks for RecordingMplsCallbacks {
+        fn create_mpls_route(&self, label: u32, inseg: &MplsInseg) -> Result<RawSaiObjectId> {
+            self.insegs.lock().unwrap().insert(label, *inseg);
+            Ok(self.alloc())
+        }
+
+        fn remove_mpls_route(&self, label: u32, _route_oid: RawSaiObjectId) -> Result<()> {
+            self.insegs.lock().unwrap().remove(&label);
+            Ok(())
+        }
+
+        fn update_mpls_route(
+            &self,
+            label: u32,
+            _route_oid: RawSaiObjectId,
+            inseg: &MplsInseg,
+        ) -> Result<()> {
+            self.insegs.lock().unwrap().insert(label, *inseg);
+            Ok(())
+        }
+
+        fn create_next_hop(&self, ip_address: &str, labels: &[u32]) -> Result<RawSaiObjectId> {
+            let oid = self.alloc();
+            self.next_hops
+                .lock()
+                .unwrap()
+                .insert(oid, (ip_address.to_string(), labels.to_vec()));
+            Ok(oid)
+        }
+
+        fn remove_next_hop(&self, nh_oid: RawSaiObjectId) -> Result<()> {
+            self.next_hops
+                .lock()
+                .unwrap()
+                .remove(&nh_oid)
+                .map(|_| ())
+                .ok_or_else(|| MplsRouteOrchError::SaiError(format!("unknown nh {:#x}", nh_oid)))
+        }
+
+        fn on_route_created(&self, _label: u32, _route_oid: RawSaiObjectId) {}
+        fn on_route_removed(&self, _label: u32) {}
+    }
+
+    fn recording_orch() -> (
+        MplsRouteOrch<RecordingMplsCallbacks>,
+        Arc<RecordingMplsCallbacks>,
+    ) {
+        let callbacks = Arc::new(RecordingMplsCallbacks::default());
+        let orch = MplsRouteOrch::new(MplsRouteOrchConfig::default())
+            .with_callbacks(Arc::clone(&callbacks));
+        (orch, callbacks)
+    }
+
+    fn route_config(
+        action: MplsAction,
+        next_hop: Option<&str>,
+        swap_label: Option<u32>,
+        push_labels: Vec<u32>,
+    ) -> MplsRouteConfig {
+        MplsRouteConfig {
+            action,
+            next_hop: next_hop.map(str::to_string),
+            swap_label,
+            push_labels,
+        }
+    }
+
     #[test]
     fn test_mpls_route_orch_new() {
         let orch: MplsRouteOrch<MockMplsCallbacks> =
@@ -675,4 +926,215 @@ mod tests {
             panic!("Failed to get mutable route reference");
         }
     }
+
+    #[test]
+    fn test_swap_and_pop_share_next_hop() {
+        let (mut orch, callbacks) = recording_orch();
+
+        // PHP pop and swap-to-implicit-null both forward unlabelled to 10.0.0.1
+        orch.add_route(
+            MplsRouteKey::new(100),
+            route_config(MplsAction::Pop, Some("10.0.0.1"), None, vec![]),
+        )
+        .unwrap();
+        orch.add_route(
+            MplsRouteKey::new(101),
+            route_config(
+                MplsAction::Swap,
+                Some("10.0.0.1"),
+                Some(MPLS_LABEL_IMPLICIT_NULL),
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        // Two swaps to the same outgoing label share a labelled next hop
+        orch.add_route(
+            MplsRouteKey::new(200),
+            route_config(MplsAction::Swap, Some("10.0.0.1"), Some(500), vec![]),
+        )
+        .unwrap();
+        orch.add_route(
+            MplsRouteKey::new(201),
+            route_config(MplsAction::Swap, Some("10.0.0.1"), Some(500), vec![]),
+        )
+        .unwrap();
+
+        let plain = MplsNextHopKey {
+            ip_address: "10.0.0.1".to_string(),
+            labels: vec![],
+        };
+        let swapped = MplsNextHopKey {
+            ip_address: "10.0.0.1".to_string(),
+            labels: vec![500],
+        };
+        assert_eq!(orch.next_hop_count(), 2);
+        assert_eq!(orch.next_hop_ref_count(&plain), 2);
+        assert_eq!(orch.next_hop_ref_count(&swapped), 2);
+        assert_eq!(callbacks.live_next_hops(), 2);
+        assert_eq!(orch.stats().stats.next_hops_created, 2);
+
+        assert_eq!(callbacks.inseg(100).nh_oid, callbacks.inseg(101).nh_oid);
+        assert_eq!(callbacks.inseg(200).nh_oid, callbacks.inseg(201).nh_oid);
+        assert_ne!(callbacks.inseg(100).nh_oid, callbacks.inseg(200).nh_oid);
+        assert_eq!(callbacks.inseg(100).pop_count, 1);
+        assert_eq!(
+            callbacks.inseg(200).packet_action,
+            MplsPacketAction::Forward
+        );
+
+        // Next hops go away with their last route
+        orch.remove_route(&MplsRouteKey::new(100)).unwrap();
+        assert_eq!(orch.next_hop_ref_count(&plain), 1);
+        assert_eq!(callbacks.live_next_hops(), 2);
+        orch.remove_route(&MplsRouteKey::new(101)).unwrap();
+        assert_eq!(orch.next_hop_ref_count(&plain), 0);
+        assert_eq!(callbacks.live_next_hops(), 1);
+        orch.remove_route(&MplsRouteKey::new(200)).unwrap();
+        orch.remove_route(&MplsRouteKey::new(201)).unwrap();
+        assert_eq!(callbacks.live_next_hops(), 0);
+        assert_eq!(orch.stats().stats.next_hops_removed, 2);
+    }
+
+    #[test]
+    fn test_swap_push_label_stack() {
+        let (mut orch, callbacks) = recording_orch();
+        orch.add_route(
+            MplsRouteKey::new(300),
+            route_config(
+                MplsAction::Push,
+                Some("10.0.0.2"),
+                Some(600),
+                vec![700, MPLS_LABEL_IPV6_EXPLICIT_NULL],
+            ),
+        )
+        .unwrap();
+
+        let nh_oid = callbacks.inseg(300).nh_oid;
+        let (ip, labels) = callbacks.next_hops.lock().unwrap()[&nh_oid].clone();
+        assert_eq!(ip, "10.0.0.2");
+        assert_eq!(labels, vec![600, 700, MPLS_LABEL_IPV6_EXPLICIT_NULL]);
+    }
+
+    #[test]
+    fn test_update_route_moves_shared_next_hop() {
+        let (mut orch, callbacks) = recording_orch();
+        let key = MplsRouteKey::new(400);
+        orch.add_route(
+            key.clone(),
+            route_config(MplsAction::Swap, Some("10.0.0.3"), Some(800), vec![]),
+        )
+        .unwrap();
+        orch.add_route(
+            MplsRouteKey::new(401),
+            route_config(MplsAction::Pop, Some("10.0.0.3"), None, vec![]),
+        )
+        .unwrap();
+
+        // Swap to pop onto the next hop already used by label 401
+        orch.update_route(
+            &key,
+            route_config(MplsAction::Pop, Some("10.0.0.3"), None, vec![]),
+        )
+        .unwrap();
+        assert_eq!(orch.next_hop_count(), 1);
+        assert_eq!(callbacks.live_next_hops(), 1);
+        assert_eq!(callbacks.inseg(400).nh_oid, callbacks.inseg(401).nh_oid);
+        assert!(orch.get_route(&key).unwrap().nh_key.is_some());
+    }
+
+    #[test]
+    fn test_reserved_labels() {
+        let (mut orch, callbacks) = recording_orch();
+
+        // Implicit-null and other reserved labels cannot key a route
+        for label in [MPLS_LABEL_IMPLICIT_NULL, 1, 7, 15] {
+            let result = orch.add_route(
+                MplsRouteKey::new(label),
+                route_config(MplsAction::Pop, Some("10.0.0.1"), None, vec![]),
+            );
+            assert!(matches!(result, Err(MplsRouteOrchError::InvalidLabel(_))));
+        }
+
+        // Explicit-null is popped and the payload looked up
+        orch.add_route(
+            MplsRouteKey::new(MPLS_LABEL_IPV4_EXPLICIT_NULL),
+            route_config(MplsAction::Pop, None, None, vec![]),
+        )
+        .unwrap();
+        let inseg = callbacks.inseg(MPLS_LABEL_IPV4_EXPLICIT_NULL);
+        assert_eq!(inseg.packet_action, MplsPacketAction::Forward);
+        assert_eq!(inseg.nh_oid, 0);
+
+        let result = orch.add_route(
+            MplsRouteKey::new(MPLS_LABEL_IPV6_EXPLICIT_NULL),
+            route_config(MplsAction::Swap, Some("10.0.0.1"), Some(500), vec![]),
+        );
+        assert!(matches!(
+            result,
+            Err(MplsRouteOrchError::ConfigurationError(_))
+        ));
+
+        // A pop without a next hop on a regular label drops
+        orch.add_route(
+            MplsRouteKey::new(900),
+            route_config(MplsAction::Pop, None, None, vec![]),
+        )
+        .unwrap();
+        assert_eq!(callbacks.inseg(900).packet_action, MplsPacketAction::Drop);
+    }
+
+    #[test]
+    fn test_invalid_out_labels() {
+        let (mut orch, callbacks) = recording_orch();
+
+        let result = orch.add_route(
+            MplsRouteKey::new(100),
+            route_config(
+                MplsAction::Swap,
+                Some("10.0.0.1"),
+                Some(MPLS_LABEL_MAX + 1),
+                vec![],
+            ),
+        );
+        assert!(matches!(
+            result,
+            Err(MplsRouteOrchError::InvalidLabel(1_048_576))
+        ));
+
+        let result = orch.add_route(
+            MplsRouteKey::new(100),
+            route_config(MplsAction::Swap, Some("10.0.0.1"), None, vec![]),
+        );
+        assert!(matches!(
+            result,
+            Err(MplsRouteOrchError::ConfigurationError(_))
+        ));
+
+        let result = orch.add_route(
+            MplsRouteKey::new(100),
+            route_config(
+                MplsAction::Push,
+                Some("10.0.0.1"),
+                None,
+                vec![MPLS_LABEL_IPV4_EXPLICIT_NULL, 500],
+            ),
+        );
+        assert!(matches!(
+            result,
+            Err(MplsRouteOrchError::ConfigurationError(_))
+        ));
+
+        let result = orch.add_route(
+            MplsRouteKey::new(100),
+            route_config(MplsAction::Swap, None, Some(500), vec![]),
+        );
+        assert!(matches!(
+            result,
+            Err(MplsRouteOrchError::ConfigurationError(_))
+        ));
+
+        assert_eq!(orch.route_count(), 0);
+        assert_eq!(callbacks.live_next_hops(), 0);
+    }
 }
@@ -2,6 +2,20 @@
 
 pub type RawSaiObjectId = u64;
 pub type MplsLabel = u32;
+/// Outgoing label stack, outermost label first (same layout as `nhg::LabelStack`).
+pub type LabelStack = Vec<MplsLabel>;
+
+/// Largest label value representable in the 20-bit label field.
+pub const MPLS_LABEL_MAX: MplsLabel = 1_048_575;
+pub const MPLS_LABEL_IPV4_EXPLICIT_NULL: MplsLabel = 0;
+pub const MPLS_LABEL_IPV6_EXPLICIT_NULL: MplsLabel = 2;
+pub const MPLS_LABEL_IMPLICIT_NULL: MplsLabel = 3;
+/// Labels 0-15 are reserved (RFC 3032).
+pub const MPLS_LABEL_RESERVED_MAX: MplsLabel = 15;
+
+pub fn is_explicit_null(label: MplsLabel) -> bool {
+    label == MPLS_LABEL_IPV4_EXPLICIT_NULL || label == MPLS_LABEL_IPV6_EXPLICIT_NULL
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MplsRouteKey {
@@ -14,12 +28,23 @@ impl MplsRouteKey {
     }
 
     pub fn validate_label(&self) -> Result<(), String> {
-        if self.label > 1_048_575 {
-            Err(format!("Invalid MPLS label {}, max is 1048575", self.label))
+        if self.label > MPLS_LABEL_MAX {
+            Err(format!(
+                "Invalid MPLS label {}, max is {}",
+                self.label, MPLS_LABEL_MAX
+            ))
         } else {
             Ok(())
         }
     }
+
+    /// Returns true if the label may key an inseg entry.
+    ///
+    /// Explicit-null is accepted (it is popped on arrival); implicit-null
+    /// never appears on the wire and the other reserved labels are not routable.
+    pub fn is_routable(&self) -> bool {
+        self.label > MPLS_LABEL_RESERVED_MAX || is_explicit_null(self.label)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +54,29 @@ pub enum MplsAction {
     Push,
 }
 
+/// SAI inseg packet action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MplsPacketAction {
+    Forward,
+    Drop,
+}
+
+/// Attributes of the SAI inseg entry programmed for a label route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MplsInseg {
+    pub pop_count: u8,
+    pub packet_action: MplsPacketAction,
+    /// Next hop carrying the outgoing label stack, 0 for pop-and-lookup or drop.
+    pub nh_oid: RawSaiObjectId,
+}
+
+/// Shared MPLS next hop: an IP next hop plus the labels it pushes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MplsNextHopKey {
+    pub ip_address: String,
+    pub labels: LabelStack,
+}
+
 #[derive(Debug, Clone)]
 pub struct MplsRouteConfig {
     pub action: MplsAction,
@@ -43,6 +91,8 @@ pub struct MplsRouteEntry {
     pub config: MplsRouteConfig,
     pub route_oid: RawSaiObjectId,
     pub nh_oid: RawSaiObjectId,
+    /// Shared next hop held by this route.
+    pub nh_key: Option<MplsNextHopKey>,
 }
 
 impl MplsRouteEntry {
@@ -52,6 +102,7 @@ impl MplsRouteEntry {
             config,
             route_oid: 0,
             nh_oid: 0,
+            nh_key: None,
         }
     }
 }
@@ -60,4 +111,6 @@ impl MplsRouteEntry {
 pub struct MplsRouteStats {
     pub routes_created: u64,
     pub routes_removed: u64,
+    pub next_hops_created: u64,
+    pub next_hops_removed: u64,
 }
@@ -6,6 +6,7 @@
 //! - Type-safe MUX states (Active/Standby/Unknown)
 //! - Type-safe cable types (ActiveActive/ActiveStandby)
 //! - Safe state transition tracking
+//! - Per-port cable state machine with switchovers serialized behind hardware acks
 //! - Option types for optional IPv4/IPv6 addresses
 
 mod ffi;
@@ -15,6 +16,6 @@ pub mod types;
 pub use ffi::{register_mux_orch, unregister_mux_orch};
 pub use orch::{MuxOrch, MuxOrchCallbacks, MuxOrchConfig, MuxOrchError, MuxOrchStats};
 pub use types::{
-    MuxCableType, MuxNeighborConfig, MuxNeighborEntry, MuxPortConfig, MuxPortEntry, MuxState,
-    MuxStateChange, MuxStats,
+    MuxCable, MuxCableState, MuxCableType, MuxNeighborConfig, MuxNeighborEntry, MuxPortConfig,
+    MuxPortEntry, MuxState, MuxStateChange, MuxStats,
 };
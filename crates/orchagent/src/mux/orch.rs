@@ -1,12 +1,15 @@
 //! MUX cable orchestration logic.
 
 use super::types::{
-    MuxNeighborConfig, MuxNeighborEntry, MuxPortConfig, MuxPortEntry, MuxState, MuxStateChange,
-    MuxStats,
+    MuxCable, MuxCableState, MuxNeighborConfig, MuxNeighborEntry, MuxPortConfig, MuxPortEntry,
+    MuxState, MuxStateChange, MuxStats,
 };
+use chrono::Utc;
+use sonic_orch_common::TaskStatus;
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
@@ -46,6 +49,12 @@ impl MuxOrchConfig {
         self.state_change_timeout_ms = timeout_ms;
         self
     }
+
+    /// Returns how long to wait for a hardware ack; 0 waits indefinitely.
+    pub fn ack_timeout(&self) -> Option<Duration> {
+        (self.state_change_timeout_ms > 0)
+            .then(|| Duration::from_millis(u64::from(self.state_change_timeout_ms)))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -93,6 +102,34 @@ pub trait MuxOrchCallbacks: Send + Sync {
 
     /// State change callback.
     fn on_state_change(&self, port_name: &str, old_state: MuxState, new_state: MuxState);
+
+    /// Asks the cable driver to switch the mux; the result arrives via
+    /// `MuxOrch::handle_hw_state`.
+    fn set_hw_mux_state(&self, port_name: &str, state: MuxState) -> Result<()>;
+
+    /// Returns the next hop of the tunnel to the peer ToR (TunnelDecapOrch).
+    fn get_peer_tunnel_nexthop(&self) -> Option<RawSaiObjectId>;
+
+    /// Removes the neighbor from hardware while NeighOrch keeps it in software.
+    fn disable_neighbor(&self, neighbor: &MuxNeighborEntry) -> Result<()>;
+
+    /// Reprograms a neighbor previously removed by `disable_neighbor`.
+    fn enable_neighbor(&self, neighbor: &MuxNeighborEntry) -> Result<()>;
+
+    /// Installs a host route for a neighbor address via the peer tunnel.
+    fn create_tunnel_route(&self, address: &str, tunnel_nh: RawSaiObjectId) -> Result<()>;
+
+    /// Removes a neighbor's tunnel host route.
+    fn remove_tunnel_route(&self, address: &str) -> Result<()>;
+
+    /// Writes the cable state and last switchover time to STATE_DB MUX_CABLE_TABLE.
+    fn write_cable_state(&self, cable: &MuxCable) -> Result<()>;
+
+    /// Re-points the port's FDB entries after a completed switchover, via
+    /// `FdbOrch::set_mux_port_state`: at the peer tunnel for standby and
+    /// back at the port for active. Also called with active when a port
+    /// leaves MUX control.
+    fn set_fdb_mux_state(&self, port_name: &str, state: MuxState) -> Result<()>;
 }
 
 /// MUX cable orchestrator for dual-tor/MLAG failover support.
@@ -105,7 +142,9 @@ pub struct MuxOrch {
     neighbors: HashMap<String, MuxNeighborEntry>,
     /// Callbacks for SAI and state DB operations.
     callbacks: Option<Arc<dyn MuxOrchCallbacks>>,
-    /// Pending state change operations (port -> new state).
+    /// Switchover state machine per port.
+    cables: HashMap<String, MuxCable>,
+    /// Latest request received while a switchover is in flight (port -> new state).
     pending_state_changes: HashMap<String, MuxState>,
 }
 
@@ -118,6 +157,7 @@ impl MuxOrch {
             ports: HashMap::new(),
             neighbors: HashMap::new(),
             callbacks: None,
+            cables: HashMap::new(),
             pending_state_changes: HashMap::new(),
         }
     }
@@ -127,6 +167,22 @@ impl MuxOrch {
         self.callbacks = Some(callbacks);
     }
 
+    fn callbacks(&self) -> Result<Arc<dyn MuxOrchCallbacks>> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| MuxOrchError::SaiError("No callbacks set".to_string()))
+    }
+
+    /// Gets the cable state machine of a port.
+    pub fn get_cable(&self, port_name: &str) -> Option<&MuxCable> {
+        self.cables.get(port_name)
+    }
+
+    /// Returns the request queued behind an in-flight switchover, if any.
+    pub fn queued_state(&self, port_name: &str) -> Option<MuxState> {
+        self.pending_state_changes.get(port_name).copied()
+    }
+
     /// Gets a mutable reference to a port entry.
     pub fn get_port_mut(&mut self, name: &str) -> Option<&mut MuxPortEntry> {
         self.ports.get_mut(name)
@@ -231,6 +287,8 @@ impl MuxOrch {
                 }));
         audit_log!(audit_record);
 
+        self.cables
+            .insert(port_name.clone(), MuxCable::new(port_name.clone()));
         self.ports.insert(port_name, entry);
         Ok(())
     }
@@ -247,8 +305,14 @@ impl MuxOrch {
             audit_log!(audit_record);
             MuxOrchError::PortNotFound(port_name.to_string())
         })?;
+        self.cables.remove(port_name);
+        self.pending_state_changes.remove(port_name);
+
+        if let Some(callbacks) = self.callbacks.clone() {
+            // Neighbors stay in NeighOrch; move them off the tunnel.
+            let _ = self.apply_neighbors(port_name, MuxState::Active, callbacks.as_ref());
+            let _ = callbacks.set_fdb_mux_state(port_name, MuxState::Active);
 
-        if let Some(ref callbacks) = self.callbacks {
             // Remove SAI objects
             if entry.acl_handler_oid != 0 {
                 if let Err(e) = callbacks.remove_mux_acl(entry.acl_handler_oid) {
@@ -402,20 +466,335 @@ impl MuxOrch {
             )));
         }
 
-        let entry = MuxNeighborEntry::new(config.neighbor.clone(), config);
-
+        let port_name = config.neighbor.clone();
+        let entry = MuxNeighborEntry::new(port_name.clone(), config);
         self.neighbors.insert(neighbor_key, entry);
+
+        // Neighbors learned during a switchover are handled when it completes.
+        let standby = self
+            .cables
+            .get(&port_name)
+            .is_some_and(|cable| cable.state == MuxCableState::Standby);
+        if standby {
+            let callbacks = self.callbacks()?;
+            self.apply_neighbors(&port_name, MuxState::Standby, callbacks.as_ref())?;
+        }
         Ok(())
     }
 
     /// Removes a neighbor entry.
     pub fn remove_neighbor(&mut self, neighbor_key: &str) -> Result<()> {
-        self.neighbors
+        let entry = self
+            .neighbors
             .remove(neighbor_key)
             .ok_or_else(|| MuxOrchError::NeighborNotFound(neighbor_key.to_string()))?;
+        if entry.tunneled {
+            self.callbacks()?
+                .remove_tunnel_route(&entry.config.address)?;
+        }
+        Ok(())
+    }
+
+    /// Handles a MUX_CABLE_TABLE request from APPL_DB.
+    ///
+    /// Switchovers are serialized per port: a request arriving while the
+    /// hardware has not acked the previous one is queued, replacing any
+    /// earlier queued request.
+    pub fn handle_cable_set(
+        &mut self,
+        port_name: &str,
+        fields: &[(String, String)],
+        now: Instant,
+    ) -> Result<TaskStatus> {
+        let Some((_, value)) = fields.iter().find(|(field, _)| field == "state") else {
+            return Ok(TaskStatus::Ignore);
+        };
+        let target = MuxState::parse(value).map_err(MuxOrchError::InvalidState)?;
+        if target == MuxState::Unknown {
+            return Err(MuxOrchError::InvalidState(format!(
+                "{}: cannot switch to unknown",
+                port_name
+            )));
+        }
+        if !self.cables.contains_key(port_name) {
+            return Ok(TaskStatus::NeedRetry);
+        }
+        self.request_state(port_name, target, now)
+    }
+
+    /// Handles a MUX_CABLE_TABLE delete, returning the port's neighbors to
+    /// direct forwarding.
+    pub fn handle_cable_del(&mut self, port_name: &str) -> Result<TaskStatus> {
+        if !self.cables.contains_key(port_name) {
+            return Ok(TaskStatus::Ignore);
+        }
+        let callbacks = self.callbacks()?;
+        self.apply_neighbors(port_name, MuxState::Active, callbacks.as_ref())?;
+        callbacks.set_fdb_mux_state(port_name, MuxState::Active)?;
+        self.pending_state_changes.remove(port_name);
+        let cable = MuxCable::new(port_name.to_string());
+        callbacks.write_cable_state(&cable)?;
+        self.cables.insert(port_name.to_string(), cable);
+        if let Some(entry) = self.ports.get_mut(port_name) {
+            entry.set_state(MuxState::Unknown);
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles the cable driver's response to a switchover request.
+    pub fn handle_hw_state(
+        &mut self,
+        port_name: &str,
+        hw_state: MuxState,
+        now: Instant,
+    ) -> Result<()> {
+        let callbacks = self.callbacks()?;
+        let cable = self
+            .cables
+            .get(port_name)
+            .ok_or_else(|| MuxOrchError::PortNotFound(port_name.to_string()))?;
+        let MuxCableState::PendingAck(target) = cable.state else {
+            // Stale or unsolicited response; switchovers are driven by APPL_DB.
+            return Ok(());
+        };
+
+        let outcome = if hw_state == target {
+            self.apply_neighbors(port_name, target, callbacks.as_ref())
+                .and_then(|()| self.complete_transition(port_name, target, callbacks.as_ref()))
+        } else {
+            Err(MuxOrchError::StateTransitionFailed(format!(
+                "{}: hardware reported {} while switching to {}",
+                port_name,
+                hw_state.as_str(),
+                target.as_str()
+            )))
+        };
+        if let Err(ref e) = outcome {
+            self.fail_transition(port_name, e, callbacks.as_ref());
+        }
+
+        self.drain_queued(port_name, now)?;
+        outcome
+    }
+
+    /// Returns when the oldest unacked switchover times out.
+    pub fn next_ack_deadline(&self) -> Option<Instant> {
+        let timeout = self.config.ack_timeout()?;
+        self.cables
+            .values()
+            .filter_map(|cable| cable.pending_since)
+            .min()
+            .map(|since| since + timeout)
+    }
+
+    /// Fails switchovers the hardware has not acked in time and retries
+    /// queued requests. Returns the ports that timed out.
+    pub fn poll_state_changes(&mut self, now: Instant) -> Result<Vec<String>> {
+        let callbacks = self.callbacks()?;
+        let mut timed_out = Vec::new();
+        if let Some(timeout) = self.config.ack_timeout() {
+            timed_out = self
+                .cables
+                .values()
+                .filter(|cable| {
+                    cable
+                        .pending_since
+                        .is_some_and(|since| now.duration_since(since) >= timeout)
+                })
+                .map(|cable| cable.port_name.clone())
+                .collect();
+            timed_out.sort();
+        }
+        for port_name in &timed_out {
+            let error =
+                MuxOrchError::StateTransitionFailed(format!("{}: no hardware response", port_name));
+            self.fail_transition(port_name, &error, callbacks.as_ref());
+        }
+
+        let mut queued: Vec<String> = self
+            .pending_state_changes
+            .keys()
+            .filter(|port| self.cables.get(*port).is_some_and(|c| !c.is_pending()))
+            .cloned()
+            .collect();
+        queued.sort();
+        for port_name in queued {
+            self.drain_queued(&port_name, now)?;
+        }
+        Ok(timed_out)
+    }
+
+    /// Starts a switchover, or queues it behind the one in flight.
+    fn request_state(
+        &mut self,
+        port_name: &str,
+        target: MuxState,
+        now: Instant,
+    ) -> Result<TaskStatus> {
+        let callbacks = self.callbacks()?;
+        let cable = self
+            .cables
+            .get_mut(port_name)
+            .ok_or_else(|| MuxOrchError::PortNotFound(port_name.to_string()))?;
+
+        if cable.is_pending() {
+            self.pending_state_changes
+                .insert(port_name.to_string(), target);
+            return Ok(TaskStatus::Success);
+        }
+        if cable.state.settled() == Some(target) {
+            return Ok(TaskStatus::Success);
+        }
+        if target == MuxState::Standby && callbacks.get_peer_tunnel_nexthop().is_none() {
+            return Ok(TaskStatus::NeedRetry);
+        }
+
+        if let Err(e) = callbacks.set_hw_mux_state(port_name, target) {
+            self.fail_transition(port_name, &e, callbacks.as_ref());
+            return Err(e);
+        }
+        cable.state = MuxCableState::PendingAck(target);
+        cable.pending_since = Some(now);
+        callbacks.write_cable_state(cable)?;
+        Ok(TaskStatus::Success)
+    }
+
+    /// Issues the request queued behind a finished switchover.
+    fn drain_queued(&mut self, port_name: &str, now: Instant) -> Result<()> {
+        if let Some(next) = self.pending_state_changes.remove(port_name) {
+            if self.request_state(port_name, next, now)? == TaskStatus::NeedRetry {
+                self.pending_state_changes
+                    .insert(port_name.to_string(), next);
+            }
+        }
+        Ok(())
+    }
+
+    /// Points the port's neighbors at the peer tunnel for standby and back
+    /// at the port for active. Neighbors already in place are skipped, so a
+    /// partially applied switchover converges on retry.
+    fn apply_neighbors(
+        &mut self,
+        port_name: &str,
+        target: MuxState,
+        callbacks: &dyn MuxOrchCallbacks,
+    ) -> Result<()> {
+        let tunnel_nh = match target {
+            MuxState::Standby => Some(callbacks.get_peer_tunnel_nexthop().ok_or_else(|| {
+                MuxOrchError::TunnelCreationFailed("Peer tunnel next hop not available".to_string())
+            })?),
+            _ => None,
+        };
+
+        for neighbor in self
+            .neighbors
+            .values_mut()
+            .filter(|neighbor| neighbor.port_name == port_name)
+        {
+            match tunnel_nh {
+                Some(nh) if !neighbor.tunneled => {
+                    callbacks.create_tunnel_route(&neighbor.config.address, nh)?;
+                    callbacks.disable_neighbor(neighbor)?;
+                    neighbor.tunneled = true;
+                }
+                None if neighbor.tunneled => {
+                    callbacks.enable_neighbor(neighbor)?;
+                    callbacks.remove_tunnel_route(&neighbor.config.address)?;
+                    neighbor.tunneled = false;
+                }
+                _ => {}
+            }
+        }
         Ok(())
     }
 
+    fn complete_transition(
+        &mut self,
+        port_name: &str,
+        new_state: MuxState,
+        callbacks: &dyn MuxOrchCallbacks,
+    ) -> Result<()> {
+        let cable = self
+            .cables
+            .get_mut(port_name)
+            .ok_or_else(|| MuxOrchError::PortNotFound(port_name.to_string()))?;
+        let old_state = self
+            .ports
+            .get(port_name)
+            .map(|entry| entry.state)
+            .unwrap_or_default();
+
+        cable.state = match new_state {
+            MuxState::Active => MuxCableState::Active,
+            _ => MuxCableState::Standby,
+        };
+        cable.pending_since = None;
+        let last_switch = Utc::now();
+        cable.last_switch = Some(last_switch);
+        if let Some(entry) = self.ports.get_mut(port_name) {
+            entry.set_state(new_state);
+        }
+
+        if let Err(e) = callbacks.set_fdb_mux_state(port_name, new_state) {
+            self.stats.errors += 1;
+            return Err(e);
+        }
+        if let Err(e) = callbacks.write_cable_state(cable) {
+            self.stats.errors += 1;
+            return Err(e);
+        }
+
+        match new_state {
+            MuxState::Active => self.stats.stats.active_transitions += 1,
+            MuxState::Standby => self.stats.stats.standby_transitions += 1,
+            MuxState::Unknown => {}
+        }
+        self.stats.stats.state_changes += 1;
+
+        let audit_record =
+            AuditRecord::new(AuditCategory::ResourceModify, "MuxOrch", "update_mux_state")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(port_name)
+                .with_object_type("mux_port")
+                .with_details(serde_json::json!({
+                    "port_name": port_name,
+                    "old_state": old_state.as_str(),
+                    "new_state": new_state.as_str(),
+                    "last_switch": last_switch.to_rfc3339(),
+                }));
+        audit_log!(audit_record);
+
+        callbacks.notify_state_change(port_name, old_state, new_state);
+        callbacks.on_state_change(port_name, old_state, new_state);
+        Ok(())
+    }
+
+    fn fail_transition(
+        &mut self,
+        port_name: &str,
+        error: &MuxOrchError,
+        callbacks: &dyn MuxOrchCallbacks,
+    ) {
+        self.stats.errors += 1;
+        self.stats.stats.failed_transitions += 1;
+        if let Some(cable) = self.cables.get_mut(port_name) {
+            cable.state = MuxCableState::Failed;
+            cable.pending_since = None;
+            let _ = callbacks.write_cable_state(cable);
+        }
+        if let Some(entry) = self.ports.get_mut(port_name) {
+            entry.set_state(MuxState::Unknown);
+        }
+
+        let audit_record =
+            AuditRecord::new(AuditCategory::ResourceModify, "MuxOrch", "update_mux_state")
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(port_name)
+                .with_object_type("mux_port")
+                .with_error(error.to_string());
+        audit_log!(audit_record);
+    }
+
     /// Gets a neighbor entry.
     pub fn get_neighbor(&self, neighbor_key: &str) -> Option<&MuxNeighborEntry> {
         self.neighbors.get(neighbor_key)
@@ -465,6 +844,108 @@ impl MuxOrch {
 mod tests {
     use super::*;
     use crate::mux::types::MuxCableType;
+    use std::sync::Mutex;
+
+    const PEER_TUNNEL_NH: RawSaiObjectId = 0x4000;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        calls: Mutex<Vec<String>>,
+        cable_states: Mutex<Vec<(String, MuxCableState)>>,
+    }
+
+    impl MockCallbacks {
+        fn take_calls(&self) -> Vec<String> {
+            std::mem::take(&mut *self.calls.lock().unwrap())
+        }
+
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    impl MuxOrchCallbacks for MockCallbacks {
+        fn create_mux_tunnel(&self, _: &str, _: &str, _: &str) -> Result<RawSaiObjectId> {
+            Ok(0x100)
+        }
+        fn remove_mux_tunnel(&self, _: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+        fn create_mux_acl(&self, _: &str, _: &str) -> Result<RawSaiObjectId> {
+            Ok(0x200)
+        }
+        fn remove_mux_acl(&self, _: RawSaiObjectId) -> Result<()> {
+            Ok(())
+        }
+        fn get_neighbor(&self, _: &str) -> Option<(String, String)> {
+            None
+        }
+        fn write_state_db(&self, _: &str, _: MuxState) -> Result<()> {
+            Ok(())
+        }
+        fn remove_state_db(&self, _: &str) -> Result<()> {
+            Ok(())
+        }
+        fn notify_state_change(&self, _: &str, _: MuxState, _: MuxState) {}
+        fn on_port_added(&self, _: &MuxPortEntry) {}
+        fn on_port_removed(&self, _: &str) {}
+        fn on_state_change(&self, _: &str, _: MuxState, _: MuxState) {}
+        fn set_hw_mux_state(&self, port_name: &str, state: MuxState) -> Result<()> {
+            self.record(format!("hw {} {}", port_name, state.as_str()));
+            Ok(())
+        }
+        fn get_peer_tunnel_nexthop(&self) -> Option<RawSaiObjectId> {
+            Some(PEER_TUNNEL_NH)
+        }
+        fn disable_neighbor(&self, neighbor: &MuxNeighborEntry) -> Result<()> {
+            self.record(format!("disable {}", neighbor.config.address));
+            Ok(())
+        }
+        fn enable_neighbor(&self, neighbor: &MuxNeighborEntry) -> Result<()> {
+            self.record(format!("enable {}", neighbor.config.address));
+            Ok(())
+        }
+        fn create_tunnel_route(&self, address: &str, _: RawSaiObjectId) -> Result<()> {
+            self.record(format!("route+ {}", address));
+            Ok(())
+        }
+        fn remove_tunnel_route(&self, address: &str) -> Result<()> {
+            self.record(format!("route- {}", address));
+            Ok(())
+        }
+        fn write_cable_state(&self, cable: &MuxCable) -> Result<()> {
+            self.cable_states
+                .lock()
+                .unwrap()
+                .push((cable.port_name.clone(), cable.state));
+            Ok(())
+        }
+        fn set_fdb_mux_state(&self, port_name: &str, state: MuxState) -> Result<()> {
+            self.record(format!("fdb {} {}", port_name, state.as_str()));
+            Ok(())
+        }
+    }
+
+    fn mux_orch_with_port(config: MuxOrchConfig) -> (MuxOrch, Arc<MockCallbacks>) {
+        let callbacks = Arc::new(MockCallbacks::default());
+        let mut orch = MuxOrch::new(config);
+        orch.set_callbacks(callbacks.clone());
+        orch.add_port("Ethernet0".to_string(), MuxPortConfig::default())
+            .unwrap();
+        orch.add_neighbor(
+            "Ethernet0:192.168.0.2".to_string(),
+            MuxNeighborConfig {
+                neighbor: "Ethernet0".to_string(),
+                address: "192.168.0.2".to_string(),
+            },
+        )
+        .unwrap();
+        (orch, callbacks)
+    }
+
+    fn state_fields(state: &str) -> Vec<(String, String)> {
+        vec![("state".to_string(), state.to_string())]
+    }
 
     #[test]
     fn test_mux_orch_new_default_config() {
@@ -883,4 +1364,223 @@ mod tests {
             MuxState::Unknown
         ));
     }
+
+    #[test]
+    fn test_cable_switch_waits_for_hw_ack() {
+        let (mut orch, callbacks) = mux_orch_with_port(MuxOrchConfig::default());
+        let now = Instant::now();
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::Init
+        );
+
+        let status = orch
+            .handle_cable_set("Ethernet0", &state_fields("standby"), now)
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::PendingAck(MuxState::Standby)
+        );
+        // Neighbors move only once the hardware confirms.
+        assert_eq!(callbacks.take_calls(), vec!["hw Ethernet0 standby"]);
+
+        orch.handle_hw_state("Ethernet0", MuxState::Standby, now)
+            .unwrap();
+        let cable = orch.get_cable("Ethernet0").unwrap();
+        assert_eq!(cable.state, MuxCableState::Standby);
+        assert!(cable.last_switch.is_some());
+        assert!(orch.get_port("Ethernet0").unwrap().is_standby());
+        assert!(orch.get_neighbor("Ethernet0:192.168.0.2").unwrap().tunneled);
+        assert_eq!(
+            callbacks.take_calls(),
+            vec![
+                "route+ 192.168.0.2",
+                "disable 192.168.0.2",
+                "fdb Ethernet0 standby"
+            ]
+        );
+        assert_eq!(
+            callbacks.cable_states.lock().unwrap().last(),
+            Some(&("Ethernet0".to_string(), MuxCableState::Standby))
+        );
+
+        // Learned while standby: tunneled immediately.
+        orch.add_neighbor(
+            "Ethernet0:192.168.0.3".to_string(),
+            MuxNeighborConfig {
+                neighbor: "Ethernet0".to_string(),
+                address: "192.168.0.3".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            callbacks.take_calls(),
+            vec!["route+ 192.168.0.3", "disable 192.168.0.3"]
+        );
+    }
+
+    #[test]
+    fn test_cable_rapid_flap_serialized() {
+        let (mut orch, callbacks) = mux_orch_with_port(MuxOrchConfig::default());
+        let now = Instant::now();
+
+        orch.handle_cable_set("Ethernet0", &state_fields("standby"), now)
+            .unwrap();
+        orch.handle_hw_state("Ethernet0", MuxState::Standby, now)
+            .unwrap();
+        callbacks.take_calls();
+
+        // standby -> active -> standby before the first ack arrives.
+        orch.handle_cable_set("Ethernet0", &state_fields("active"), now)
+            .unwrap();
+        orch.handle_cable_set("Ethernet0", &state_fields("standby"), now)
+            .unwrap();
+        assert_eq!(orch.queued_state("Ethernet0"), Some(MuxState::Standby));
+        orch.add_neighbor(
+            "Ethernet0:192.168.0.3".to_string(),
+            MuxNeighborConfig {
+                neighbor: "Ethernet0".to_string(),
+                address: "192.168.0.3".to_string(),
+            },
+        )
+        .unwrap();
+        // Only one hardware request in flight; the new neighbor waits.
+        assert_eq!(callbacks.take_calls(), vec!["hw Ethernet0 active"]);
+
+        orch.handle_hw_state("Ethernet0", MuxState::Active, now)
+            .unwrap();
+        assert_eq!(
+            callbacks.take_calls(),
+            vec![
+                "enable 192.168.0.2",
+                "route- 192.168.0.2",
+                "fdb Ethernet0 active",
+                "hw Ethernet0 standby"
+            ]
+        );
+        assert_eq!(orch.queued_state("Ethernet0"), None);
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::PendingAck(MuxState::Standby)
+        );
+
+        orch.handle_hw_state("Ethernet0", MuxState::Standby, now)
+            .unwrap();
+        let mut calls = callbacks.take_calls();
+        calls.sort();
+        assert_eq!(
+            calls,
+            vec![
+                "disable 192.168.0.2",
+                "disable 192.168.0.3",
+                "fdb Ethernet0 standby",
+                "route+ 192.168.0.2",
+                "route+ 192.168.0.3"
+            ]
+        );
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::Standby
+        );
+        let stats = orch.stats();
+        assert_eq!(stats.stats.standby_transitions, 2);
+        assert_eq!(stats.stats.active_transitions, 1);
+    }
+
+    #[test]
+    fn test_cable_hw_mismatch_and_timeout_fail() {
+        let (mut orch, callbacks) = mux_orch_with_port(MuxOrchConfig::default().with_timeout(1000));
+        let now = Instant::now();
+
+        orch.handle_cable_set("Ethernet0", &state_fields("active"), now)
+            .unwrap();
+        assert!(orch
+            .handle_hw_state("Ethernet0", MuxState::Standby, now)
+            .is_err());
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::Failed
+        );
+        assert_eq!(orch.stats().stats.failed_transitions, 1);
+
+        // A failed cable accepts a new request.
+        orch.handle_cable_set("Ethernet0", &state_fields("active"), now)
+            .unwrap();
+        assert_eq!(
+            orch.next_ack_deadline(),
+            Some(now + Duration::from_millis(1000))
+        );
+        assert!(orch
+            .poll_state_changes(now + Duration::from_millis(500))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            orch.poll_state_changes(now + Duration::from_millis(1000))
+                .unwrap(),
+            vec!["Ethernet0"]
+        );
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::Failed
+        );
+        assert_eq!(orch.next_ack_deadline(), None);
+
+        // A late ack for the abandoned request is ignored.
+        callbacks.take_calls();
+        orch.handle_hw_state("Ethernet0", MuxState::Active, now)
+            .unwrap();
+        assert!(callbacks.take_calls().is_empty());
+    }
+
+    #[test]
+    fn test_cable_set_before_port_retries() {
+        let callbacks = Arc::new(MockCallbacks::default());
+        let mut orch = MuxOrch::new(MuxOrchConfig::default());
+        orch.set_callbacks(callbacks);
+        let now = Instant::now();
+
+        assert_eq!(
+            orch.handle_cable_set("Ethernet0", &state_fields("active"), now)
+                .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert!(orch
+            .handle_cable_set("Ethernet0", &state_fields("bogus"), now)
+            .is_err());
+        assert_eq!(
+            orch.handle_cable_set("Ethernet0", &[], now).unwrap(),
+            TaskStatus::Ignore
+        );
+    }
+
+    #[test]
+    fn test_cable_del_restores_neighbors() {
+        let (mut orch, callbacks) = mux_orch_with_port(MuxOrchConfig::default());
+        let now = Instant::now();
+
+        orch.handle_cable_set("Ethernet0", &state_fields("standby"), now)
+            .unwrap();
+        orch.handle_hw_state("Ethernet0", MuxState::Standby, now)
+            .unwrap();
+        callbacks.take_calls();
+
+        assert_eq!(
+            orch.handle_cable_del("Ethernet0").unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(
+            callbacks.take_calls(),
+            vec![
+                "enable 192.168.0.2",
+                "route- 192.168.0.2",
+                "fdb Ethernet0 active"
+            ]
+        );
+        assert_eq!(
+            orch.get_cable("Ethernet0").unwrap().state,
+            MuxCableState::Init
+        );
+        assert!(!orch.get_neighbor("Ethernet0:192.168.0.2").unwrap().tunneled);
+    }
 }
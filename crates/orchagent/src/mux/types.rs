@@ -1,9 +1,17 @@
 //! MUX cable orchestration types.
 
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::time::Instant;
 
 pub type RawSaiObjectId = u64;
 
+/// Table names handled by MuxOrch.
+pub mod tables {
+    /// APPL_DB requests and STATE_DB results share this table name.
+    pub const MUX_CABLE_TABLE: &str = "MUX_CABLE_TABLE";
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MuxState {
     Active,
@@ -17,6 +25,87 @@ impl Default for MuxState {
     }
 }
 
+impl MuxState {
+    /// Parses a MUX_CABLE_TABLE or hardware mux state value.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "active" => Ok(MuxState::Active),
+            "standby" => Ok(MuxState::Standby),
+            "unknown" => Ok(MuxState::Unknown),
+            _ => Err(format!("Invalid mux state: {}", value)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MuxState::Active => "active",
+            MuxState::Standby => "standby",
+            MuxState::Unknown => "unknown",
+        }
+    }
+}
+
+/// Per-port cable state machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MuxCableState {
+    /// No switchover has completed yet.
+    #[default]
+    Init,
+    Active,
+    Standby,
+    /// Waiting for the hardware to confirm a switch to the given state.
+    PendingAck(MuxState),
+    /// The last switchover was rejected or timed out.
+    Failed,
+}
+
+impl MuxCableState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MuxCableState::Init => "init",
+            MuxCableState::Active => "active",
+            MuxCableState::Standby => "standby",
+            MuxCableState::PendingAck(_) => "pending",
+            MuxCableState::Failed => "failed",
+        }
+    }
+
+    /// Returns the settled mux state, if no switchover is in flight.
+    pub fn settled(&self) -> Option<MuxState> {
+        match self {
+            MuxCableState::Active => Some(MuxState::Active),
+            MuxCableState::Standby => Some(MuxState::Standby),
+            _ => None,
+        }
+    }
+}
+
+/// Switchover state of one mux cable.
+#[derive(Debug, Clone)]
+pub struct MuxCable {
+    pub port_name: String,
+    pub state: MuxCableState,
+    /// When the in-flight hardware request was issued.
+    pub pending_since: Option<Instant>,
+    /// Completion time of the last switchover, reported in STATE_DB.
+    pub last_switch: Option<DateTime<Utc>>,
+}
+
+impl MuxCable {
+    pub fn new(port_name: String) -> Self {
+        Self {
+            port_name,
+            state: MuxCableState::Init,
+            pending_since: None,
+            last_switch: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self.state, MuxCableState::PendingAck(_))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MuxCableType {
     ActiveActive,
@@ -92,6 +181,8 @@ pub struct MuxNeighborEntry {
     pub port_name: String,
     pub config: MuxNeighborConfig,
     pub neigh_oid: RawSaiObjectId,
+    /// True while the neighbor is routed via the peer tunnel next hop.
+    pub tunneled: bool,
 }
 
 impl MuxNeighborEntry {
@@ -100,6 +191,7 @@ impl MuxNeighborEntry {
             port_name,
             config,
             neigh_oid: 0,
+            tunneled: false,
         }
     }
 }
@@ -109,6 +201,7 @@ pub struct MuxStats {
     pub state_changes: u64,
     pub active_transitions: u64,
     pub standby_transitions: u64,
+    pub failed_transitions: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -117,3 +210,32 @@ pub enum MuxStateChange {
     ToStandby,
     ToUnknown,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mux_state_parse() {
+        assert_eq!(MuxState::parse("active"), Ok(MuxState::Active));
+        assert_eq!(MuxState::parse("standby"), Ok(MuxState::Standby));
+        assert_eq!(MuxState::parse("unknown"), Ok(MuxState::Unknown));
+        assert!(MuxState::parse("Active").is_err());
+        assert_eq!(MuxState::Standby.as_str(), "standby");
+    }
+
+    #[test]
+    fn test_cable_state_settled() {
+        let mut cable = MuxCable::new("Ethernet0".to_string());
+        assert_eq!(cable.state, MuxCableState::Init);
+        assert_eq!(cable.state.settled(), None);
+
+        cable.state = MuxCableState::PendingAck(MuxState::Active);
+        assert!(cable.is_pending());
+        assert_eq!(cable.state.as_str(), "pending");
+        assert_eq!(cable.state.settled(), None);
+
+        cable.state = MuxCableState::Standby;
+        assert_eq!(cable.state.settled(), Some(MuxState::Standby));
+    }
+}
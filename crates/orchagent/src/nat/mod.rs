@@ -9,6 +9,9 @@
 //! - std::net::Ipv4Addr for IP addresses
 //! - Validated IP and port ranges
 //! - Option types for translated addresses/ports
+//! - Static entries take precedence over dynamic ones for the same key
+//! - Pool deletion deferred while bindings still reference the pool
+//! - Idle-timeout aging driven by an injected clock for deterministic tests
 
 mod ffi;
 mod orch;
@@ -17,6 +20,6 @@ mod types;
 pub use ffi::{register_nat_orch, unregister_nat_orch};
 pub use orch::{NatOrch, NatOrchCallbacks, NatOrchConfig, NatOrchError, NatOrchStats};
 pub use types::{
-    NatAclEntry, NatAclKey, NatEntry, NatEntryConfig, NatEntryKey, NatPoolConfig, NatPoolEntry,
-    NatPoolKey, NatProtocol, NatStats, NatType,
+    NatAclEntry, NatAclKey, NatBindingConfig, NatEntry, NatEntryConfig, NatEntryKey, NatEntryType,
+    NatNotification, NatPoolConfig, NatPoolEntry, NatPoolKey, NatProtocol, NatStats, NatType,
 };
@@ -1,11 +1,17 @@
 //! NAT orchestration logic.
 
-use super::types::{NatEntry, NatEntryKey, NatPoolEntry, NatPoolKey, NatStats};
+use super::types::{
+    NatBindingConfig, NatEntry, NatEntryKey, NatNotification, NatPoolConfig, NatPoolEntry,
+    NatPoolKey, NatProtocol, NatStats, NatType, RawSaiObjectId,
+};
 use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
     audit_log,
 };
+use sonic_orch_common::TaskStatus;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 #[derive(Debug, Clone, Error)]
@@ -20,15 +26,20 @@ pub enum NatOrchError {
     InvalidIpRange(String),
     #[error("Invalid port range: {0}")]
     InvalidPortRange(String),
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
     #[error("SAI error: {0}")]
     SaiError(String),
 }
 
+/// Idle timeouts are in seconds; a timeout of 0 disables aging for that class.
 #[derive(Debug, Clone, Default)]
 pub struct NatOrchConfig {
     pub enable_hairpin: bool,
     pub tcp_timeout: u32,
     pub udp_timeout: u32,
+    /// Timeout for basic NAT entries that carry no L4 protocol.
+    pub nat_timeout: u32,
 }
 
 impl NatOrchConfig {
@@ -37,15 +48,41 @@ impl NatOrchConfig {
         self.udp_timeout = udp;
         self
     }
+
+    pub fn with_nat_timeout(mut self, secs: u32) -> Self {
+        self.nat_timeout = secs;
+        self
+    }
+
+    /// Idle timeout applied to a dynamic entry, or `None` if it never ages.
+    pub fn timeout_for(&self, protocol: NatProtocol) -> Option<Duration> {
+        let secs = match protocol {
+            NatProtocol::Tcp => self.tcp_timeout,
+            NatProtocol::Udp => self.udp_timeout,
+            NatProtocol::All => self.nat_timeout,
+        };
+        (secs > 0).then(|| Duration::from_secs(u64::from(secs)))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct NatOrchStats {
     pub stats: NatStats,
     pub errors: u64,
+    /// Dynamic entries removed after idling past their timeout.
+    pub timeouts: u64,
+    /// Dynamic entries replaced by a static entry with the same key.
+    pub dynamic_replaced: u64,
+    pub bindings_created: u64,
 }
 
 pub trait NatOrchCallbacks: Send + Sync {
+    fn create_nat_entry(&self, entry: &NatEntry) -> Result<RawSaiObjectId, String>;
+    fn remove_nat_entry(&self, entry: &NatEntry) -> Result<(), String>;
+    /// Reads and clears the hit bit of a programmed entry.
+    fn get_nat_entry_hit(&self, entry: &NatEntry) -> Result<bool, String>;
+    /// Publishes a notification to natsyncd.
+    fn notify_natsyncd(&self, notification: &NatNotification);
     fn on_entry_created(&self, entry: &NatEntry);
     fn on_entry_removed(&self, key: &NatEntryKey);
     fn on_pool_created(&self, pool: &NatPoolEntry);
@@ -57,6 +94,8 @@ pub struct NatOrch {
     stats: NatOrchStats,
     entries: HashMap<NatEntryKey, NatEntry>,
     pools: HashMap<NatPoolKey, NatPoolEntry>,
+    bindings: HashMap<String, NatBindingConfig>,
+    callbacks: Option<Arc<dyn NatOrchCallbacks>>,
 }
 
 impl NatOrch {
@@ -66,9 +105,21 @@ impl NatOrch {
             stats: NatOrchStats::default(),
             entries: HashMap::new(),
             pools: HashMap::new(),
+            bindings: HashMap::new(),
+            callbacks: None,
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn NatOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    fn callbacks(&self) -> Result<Arc<dyn NatOrchCallbacks>, NatOrchError> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| NatOrchError::SaiError("No callbacks set".to_string()))
+    }
+
     pub fn get_entry(&self, key: &NatEntryKey) -> Option<&NatEntry> {
         self.entries.get(key)
     }
@@ -287,13 +338,320 @@ impl NatOrch {
     pub fn stats(&self) -> &NatOrchStats {
         &self.stats
     }
+
+    pub fn get_binding(&self, name: &str) -> Option<&NatBindingConfig> {
+        self.bindings.get(name)
+    }
+
+    pub fn binding_count(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Handles a SET on one of the NAT/NAPT/twice-NAT tables.
+    ///
+    /// A static entry replaces a dynamic one with the same key and natsyncd
+    /// is told to drop its conntrack state; a dynamic entry never overrides
+    /// a static one.
+    pub fn handle_entry_set(
+        &mut self,
+        table: &str,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, NatOrchError> {
+        let mut entry =
+            NatEntry::from_appl(table, key, fields).map_err(NatOrchError::InvalidConfig)?;
+        let callbacks = self.callbacks()?;
+
+        if let Some(existing) = self.entries.get(&entry.key) {
+            if !existing.is_dynamic() && entry.is_dynamic() {
+                return Ok(TaskStatus::Ignore);
+            }
+            if existing.entry_type == entry.entry_type && existing.same_translation(&entry) {
+                return Ok(TaskStatus::Success);
+            }
+
+            let replaced_dynamic = existing.is_dynamic() && !entry.is_dynamic();
+            let old = existing.clone();
+            if let Err(e) = callbacks.remove_nat_entry(&old) {
+                self.stats.errors = self.stats.errors.saturating_add(1);
+                return Err(NatOrchError::SaiError(e));
+            }
+            self.remove_entry(&old.key)?;
+            callbacks.on_entry_removed(&old.key);
+            if replaced_dynamic {
+                self.stats.dynamic_replaced = self.stats.dynamic_replaced.saturating_add(1);
+                callbacks.notify_natsyncd(&NatNotification::Replaced(old.key.clone()));
+            }
+        }
+
+        entry.entry_oid = match callbacks.create_nat_entry(&entry) {
+            Ok(oid) => oid,
+            Err(e) => {
+                self.stats.errors = self.stats.errors.saturating_add(1);
+                return Err(NatOrchError::SaiError(e));
+            }
+        };
+        self.add_entry(entry.clone())?;
+        self.stats.stats.translations = self.stats.stats.translations.saturating_add(1);
+        callbacks.on_entry_created(&entry);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a DEL on one of the NAT/NAPT/twice-NAT tables.
+    ///
+    /// NAT_TABLE and NAPT_TABLE keys do not say whether they name a source
+    /// or destination endpoint, so both interpretations are looked up.
+    pub fn handle_entry_del(&mut self, table: &str, key: &str) -> Result<TaskStatus, NatOrchError> {
+        let candidates = [NatType::Source, NatType::Destination, NatType::DoubleNat];
+        let mut found = None;
+        for nat_type in candidates {
+            let entry_key = NatEntryKey::from_appl_key(table, key, nat_type)
+                .map_err(NatOrchError::InvalidConfig)?;
+            if self
+                .entries
+                .get(&entry_key)
+                .is_some_and(|e| e.config.nat_type == nat_type)
+            {
+                found = Some(entry_key);
+                break;
+            }
+        }
+        let Some(entry_key) = found else {
+            return Ok(TaskStatus::Success);
+        };
+
+        let callbacks = self.callbacks()?;
+        let entry = self.entries[&entry_key].clone();
+        if let Err(e) = callbacks.remove_nat_entry(&entry) {
+            self.stats.errors = self.stats.errors.saturating_add(1);
+            return Err(NatOrchError::SaiError(e));
+        }
+        self.remove_entry(&entry_key)?;
+        callbacks.on_entry_removed(&entry_key);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a NAT_POOL SET, replacing the range of an existing pool.
+    pub fn handle_pool_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, NatOrchError> {
+        let config = NatPoolConfig::parse(fields).map_err(NatOrchError::InvalidConfig)?;
+        let callbacks = self.callbacks()?;
+        let key = NatPoolKey::new(name.to_string());
+
+        if let Some(existing) = self.pools.get(&key) {
+            if existing.config.ip_range == config.ip_range
+                && existing.config.port_range == config.port_range
+            {
+                return Ok(TaskStatus::Success);
+            }
+        }
+
+        let entry = NatPoolEntry::new(key.clone(), config);
+        let previous = self.pools.remove(&key);
+        if let Err(e) = self.add_pool(entry.clone()) {
+            if let Some(previous) = previous {
+                self.pools.insert(key, previous);
+            }
+            return Err(e);
+        }
+        callbacks.on_pool_created(&entry);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a NAT_POOL DEL; deferred while any binding still uses the pool.
+    pub fn handle_pool_del(&mut self, name: &str) -> Result<TaskStatus, NatOrchError> {
+        let key = NatPoolKey::new(name.to_string());
+        if !self.pools.contains_key(&key) {
+            return Ok(TaskStatus::Success);
+        }
+        if self.bindings.values().any(|b| b.pool_name == name) {
+            return Ok(TaskStatus::NeedRetry);
+        }
+        let callbacks = self.callbacks()?;
+        self.remove_pool(&key)?;
+        callbacks.on_pool_removed(&key);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a NAT_BINDINGS SET; waits for the referenced pool to exist.
+    pub fn handle_binding_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, NatOrchError> {
+        let mut config = NatBindingConfig::new(name.to_string());
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(NatOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(NatOrchError::InvalidConfig)?;
+
+        if !self
+            .pools
+            .contains_key(&NatPoolKey::new(config.pool_name.clone()))
+        {
+            return Ok(TaskStatus::NeedRetry);
+        }
+
+        if self
+            .bindings
+            .insert(name.to_string(), config.clone())
+            .is_none()
+        {
+            self.stats.bindings_created = self.stats.bindings_created.saturating_add(1);
+        }
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceCreate, "NatOrch", "set_binding")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(name)
+                .with_object_type("nat_binding")
+                .with_details(serde_json::json!({
+                    "nat_pool": config.pool_name,
+                    "access_list": config.acl_name,
+                    "nat_type": format!("{:?}", config.nat_type),
+                }))
+        );
+        Ok(TaskStatus::Success)
+    }
+
+    pub fn handle_binding_del(&mut self, name: &str) -> Result<TaskStatus, NatOrchError> {
+        if let Some(config) = self.bindings.remove(name) {
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "NatOrch",
+                "remove_binding"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(name)
+            .with_object_type("nat_binding")
+            .with_details(serde_json::json!({
+                "nat_pool": config.pool_name,
+            })));
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    /// Earliest time a dynamic entry could expire if its hit bit stays clear.
+    pub fn next_aging_deadline(&self) -> Option<Instant> {
+        self.entries
+            .values()
+            .filter(|e| e.is_dynamic())
+            .filter_map(|e| Some(e.last_active? + self.config.timeout_for(e.key.protocol)?))
+            .min()
+    }
+
+    /// Queries hit bits of dynamic entries and removes those idle past their
+    /// timeout, notifying natsyncd. Returns the keys of the aged entries.
+    pub fn poll_aging(&mut self, now: Instant) -> Result<Vec<NatEntryKey>, NatOrchError> {
+        let callbacks = self.callbacks()?;
+        let mut expired = Vec::new();
+
+        for entry in self.entries.values_mut().filter(|e| e.is_dynamic()) {
+            let Some(timeout) = self.config.timeout_for(entry.key.protocol) else {
+                continue;
+            };
+            let hit = match callbacks.get_nat_entry_hit(entry) {
+                Ok(hit) => hit,
+                Err(_) => {
+                    self.stats.errors = self.stats.errors.saturating_add(1);
+                    continue;
+                }
+            };
+            match entry.last_active {
+                Some(last) if !hit && now.saturating_duration_since(last) >= timeout => {
+                    expired.push(entry.key.clone());
+                }
+                Some(_) if !hit => {}
+                _ => entry.last_active = Some(now),
+            }
+        }
+
+        let mut aged = Vec::new();
+        for key in expired {
+            let entry = self.entries[&key].clone();
+            if callbacks.remove_nat_entry(&entry).is_err() {
+                self.stats.errors = self.stats.errors.saturating_add(1);
+                continue;
+            }
+            self.remove_entry(&key)?;
+            self.stats.timeouts = self.stats.timeouts.saturating_add(1);
+            callbacks.on_entry_removed(&key);
+            callbacks.notify_natsyncd(&NatNotification::Aged(key.clone()));
+            aged.push(key);
+        }
+        Ok(aged)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::types::{NatEntryConfig, NatPoolConfig, NatProtocol, NatType};
+    use super::super::types::{tables, NatEntryConfig, NatPoolConfig, NatProtocol, NatType};
     use super::*;
+    use std::collections::HashSet;
     use std::net::Ipv4Addr;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        next_oid: Mutex<RawSaiObjectId>,
+        live: Mutex<HashSet<NatEntryKey>>,
+        hits: Mutex<HashSet<NatEntryKey>>,
+        notifications: Mutex<Vec<NatNotification>>,
+    }
+
+    impl MockCallbacks {
+        fn hit(&self, key: &NatEntryKey) {
+            self.hits.lock().unwrap().insert(key.clone());
+        }
+    }
+
+    impl NatOrchCallbacks for MockCallbacks {
+        fn create_nat_entry(&self, entry: &NatEntry) -> Result<RawSaiObjectId, String> {
+            let mut oid = self.next_oid.lock().unwrap();
+            *oid += 1;
+            self.live.lock().unwrap().insert(entry.key.clone());
+            Ok(0x2000 + *oid)
+        }
+        fn remove_nat_entry(&self, entry: &NatEntry) -> Result<(), String> {
+            self.live.lock().unwrap().remove(&entry.key);
+            Ok(())
+        }
+        fn get_nat_entry_hit(&self, entry: &NatEntry) -> Result<bool, String> {
+            Ok(self.hits.lock().unwrap().remove(&entry.key))
+        }
+        fn notify_natsyncd(&self, notification: &NatNotification) {
+            self.notifications
+                .lock()
+                .unwrap()
+                .push(notification.clone());
+        }
+        fn on_entry_created(&self, _entry: &NatEntry) {}
+        fn on_entry_removed(&self, _key: &NatEntryKey) {}
+        fn on_pool_created(&self, _pool: &NatPoolEntry) {}
+        fn on_pool_removed(&self, _key: &NatPoolKey) {}
+    }
+
+    fn fv(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_mock(config: NatOrchConfig) -> (NatOrch, Arc<MockCallbacks>) {
+        let mock = Arc::new(MockCallbacks::default());
+        let mut orch = NatOrch::new(config);
+        orch.set_callbacks(mock.clone());
+        (orch, mock)
+    }
+
+    fn napt_key(proto: NatProtocol, ip: &str, port: u16) -> NatEntryKey {
+        NatEntryKey::new(ip.parse().unwrap(), Ipv4Addr::UNSPECIFIED, proto, port, 0)
+    }
 
     fn create_test_nat_entry(
         src_ip: &str,
@@ -610,4 +968,210 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), NatOrchError::PoolNotFound(_)));
     }
+
+    #[test]
+    fn test_static_entry_programs_sai() {
+        let (mut orch, mock) = orch_with_mock(NatOrchConfig::default());
+        let fields = fv(&[("translated_ip", "65.55.45.1"), ("nat_type", "snat")]);
+
+        assert_eq!(
+            orch.handle_entry_set(tables::NAT_TABLE, "10.0.0.1", &fields)
+                .unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(mock.live.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().stats.translations, 1);
+        assert!(orch.get_snat_entries()[0].entry_oid != 0);
+
+        // Re-applying the same translation is a no-op.
+        orch.handle_entry_set(tables::NAT_TABLE, "10.0.0.1", &fields)
+            .unwrap();
+        assert_eq!(orch.stats().stats.translations, 1);
+
+        assert_eq!(
+            orch.handle_entry_del(tables::NAT_TABLE, "10.0.0.1")
+                .unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(orch.entry_count(), 0);
+        assert!(mock.live.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_static_replaces_dynamic() {
+        let (mut orch, mock) = orch_with_mock(NatOrchConfig::default());
+        let key = "TCP:10.0.0.1:1024";
+        orch.handle_entry_set(
+            tables::NAPT_TABLE,
+            key,
+            &fv(&[
+                ("translated_ip", "65.55.45.1"),
+                ("translated_l4_port", "2000"),
+                ("entry_type", "dynamic"),
+            ]),
+        )
+        .unwrap();
+
+        let static_fields = fv(&[
+            ("translated_ip", "65.55.45.2"),
+            ("translated_l4_port", "3000"),
+        ]);
+        orch.handle_entry_set(tables::NAPT_TABLE, key, &static_fields)
+            .unwrap();
+
+        let entry_key = napt_key(NatProtocol::Tcp, "10.0.0.1", 1024);
+        let entry = orch.get_entry(&entry_key).unwrap();
+        assert!(!entry.is_dynamic());
+        assert_eq!(entry.config.translated_src_port, Some(3000));
+        assert_eq!(orch.stats().dynamic_replaced, 1);
+        assert_eq!(mock.live.lock().unwrap().len(), 1);
+        assert_eq!(
+            *mock.notifications.lock().unwrap(),
+            vec![NatNotification::Replaced(entry_key.clone())]
+        );
+
+        // A dynamic entry never overrides the static one.
+        assert_eq!(
+            orch.handle_entry_set(
+                tables::NAPT_TABLE,
+                key,
+                &fv(&[
+                    ("translated_ip", "65.55.45.9"),
+                    ("translated_l4_port", "4000"),
+                    ("entry_type", "dynamic"),
+                ]),
+            )
+            .unwrap(),
+            TaskStatus::Ignore
+        );
+        assert_eq!(
+            orch.get_entry(&entry_key)
+                .unwrap()
+                .config
+                .translated_src_port,
+            Some(3000)
+        );
+    }
+
+    #[test]
+    fn test_dynamic_entry_aging() {
+        let (mut orch, mock) = orch_with_mock(NatOrchConfig::default().with_timeouts(300, 120));
+        for (key, port) in [("TCP:10.0.0.1:1024", "2000"), ("UDP:10.0.0.1:53", "2001")] {
+            orch.handle_entry_set(
+                tables::NAPT_TABLE,
+                key,
+                &fv(&[
+                    ("translated_ip", "65.55.45.1"),
+                    ("translated_l4_port", port),
+                    ("entry_type", "dynamic"),
+                ]),
+            )
+            .unwrap();
+        }
+        let tcp = napt_key(NatProtocol::Tcp, "10.0.0.1", 1024);
+        let udp = napt_key(NatProtocol::Udp, "10.0.0.1", 53);
+
+        let t0 = Instant::now();
+        assert!(orch.next_aging_deadline().is_none());
+        assert!(orch.poll_aging(t0).unwrap().is_empty());
+        assert_eq!(
+            orch.next_aging_deadline(),
+            Some(t0 + Duration::from_secs(120))
+        );
+
+        // TCP traffic keeps its entry alive; UDP idles out at 120s.
+        mock.hit(&tcp);
+        let aged = orch.poll_aging(t0 + Duration::from_secs(120)).unwrap();
+        assert_eq!(aged, vec![udp.clone()]);
+        assert!(orch.get_entry(&udp).is_none());
+        assert_eq!(orch.stats().timeouts, 1);
+        assert_eq!(
+            *mock.notifications.lock().unwrap(),
+            vec![NatNotification::Aged(udp)]
+        );
+
+        assert!(orch
+            .poll_aging(t0 + Duration::from_secs(419))
+            .unwrap()
+            .is_empty());
+        let aged = orch.poll_aging(t0 + Duration::from_secs(420)).unwrap();
+        assert_eq!(aged, vec![tcp]);
+        assert!(mock.live.lock().unwrap().is_empty());
+        assert_eq!(orch.stats().timeouts, 2);
+    }
+
+    #[test]
+    fn test_static_and_untimed_entries_never_age() {
+        let (mut orch, _mock) = orch_with_mock(NatOrchConfig::default().with_timeouts(300, 120));
+        orch.handle_entry_set(
+            tables::NAPT_TABLE,
+            "UDP:10.0.0.1:53",
+            &fv(&[
+                ("translated_ip", "65.55.45.1"),
+                ("translated_l4_port", "2001"),
+            ]),
+        )
+        .unwrap();
+        // Basic NAT entries age only when nat_timeout is configured.
+        orch.handle_entry_set(
+            tables::NAT_TABLE,
+            "10.0.0.2",
+            &fv(&[("translated_ip", "65.55.45.2"), ("entry_type", "dynamic")]),
+        )
+        .unwrap();
+
+        let t0 = Instant::now();
+        orch.poll_aging(t0).unwrap();
+        assert!(orch
+            .poll_aging(t0 + Duration::from_secs(3600))
+            .unwrap()
+            .is_empty());
+        assert_eq!(orch.entry_count(), 2);
+        assert!(orch.next_aging_deadline().is_none());
+    }
+
+    #[test]
+    fn test_binding_waits_for_pool_and_defers_pool_delete() {
+        let (mut orch, _mock) = orch_with_mock(NatOrchConfig::default());
+        let binding = fv(&[("nat_pool", "pool1"), ("access_list", "acl1")]);
+
+        assert_eq!(
+            orch.handle_binding_set("bind1", &binding).unwrap(),
+            TaskStatus::NeedRetry
+        );
+        orch.handle_pool_set("pool1", &fv(&[("nat_ip", "65.55.45.1-65.55.45.5")]))
+            .unwrap();
+        assert_eq!(
+            orch.handle_binding_set("bind1", &binding).unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(orch.binding_count(), 1);
+
+        assert_eq!(
+            orch.handle_pool_del("pool1").unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert_eq!(orch.pool_count(), 1);
+
+        orch.handle_binding_del("bind1").unwrap();
+        assert_eq!(orch.handle_pool_del("pool1").unwrap(), TaskStatus::Success);
+        assert_eq!(orch.pool_count(), 0);
+    }
+
+    #[test]
+    fn test_pool_update_keeps_old_range_on_error() {
+        let (mut orch, _mock) = orch_with_mock(NatOrchConfig::default());
+        orch.handle_pool_set("pool1", &fv(&[("nat_ip", "65.55.45.1-65.55.45.5")]))
+            .unwrap();
+        let result = orch.handle_pool_set("pool1", &fv(&[("nat_ip", "65.55.45.9-65.55.45.1")]));
+        assert!(matches!(result, Err(NatOrchError::InvalidIpRange(_))));
+
+        let pool = orch
+            .get_pool(&NatPoolKey::new("pool1".to_string()))
+            .unwrap();
+        assert_eq!(
+            pool.config.ip_range.1,
+            "65.55.45.5".parse::<Ipv4Addr>().unwrap()
+        );
+    }
 }
@@ -1,9 +1,50 @@
 //! NAT (Network Address Translation) types.
 
 use std::net::{IpAddr, Ipv4Addr};
+use std::time::Instant;
 
 pub type RawSaiObjectId = u64;
 
+/// Table names handled by NatOrch.
+pub mod tables {
+    pub const NAT_TABLE: &str = "NAT_TABLE";
+    pub const NAPT_TABLE: &str = "NAPT_TABLE";
+    pub const NAT_TWICE_TABLE: &str = "NAT_TWICE_TABLE";
+    pub const NAPT_TWICE_TABLE: &str = "NAPT_TWICE_TABLE";
+    pub const NAT_POOL: &str = "NAT_POOL";
+    pub const NAT_BINDINGS: &str = "NAT_BINDINGS";
+    /// Notification channel natsyncd listens on for aged or replaced entries.
+    pub const NAT_NOTIFICATIONS: &str = "NAT_NOTIFICATIONS";
+}
+
+fn parse_ipv4(field: &str, value: &str) -> Result<Ipv4Addr, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", field, value))
+}
+
+fn parse_port(field: &str, value: &str) -> Result<u16, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", field, value))
+}
+
+/// Parses an inclusive `start-end` range, or a single value as `start-start`.
+fn parse_range<T: std::str::FromStr + Copy>(field: &str, value: &str) -> Result<(T, T), String> {
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<T>()
+            .map_err(|_| format!("Invalid {}: {}", field, value))
+    };
+    match value.split_once('-') {
+        Some((start, end)) => Ok((parse(start)?, parse(end)?)),
+        None => {
+            let single = parse(value)?;
+            Ok((single, single))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NatType {
     Source,
@@ -11,6 +52,17 @@ pub enum NatType {
     DoubleNat,
 }
 
+impl NatType {
+    /// Parses the `nat_type` field of NAT_TABLE/NAPT_TABLE and NAT_BINDINGS.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "snat" => Ok(Self::Source),
+            "dnat" => Ok(Self::Destination),
+            _ => Err(format!("Invalid nat_type: {}", value)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NatProtocol {
     Tcp,
@@ -18,6 +70,35 @@ pub enum NatProtocol {
     All,
 }
 
+impl NatProtocol {
+    /// Parses the protocol prefix of a NAPT key.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "TCP" => Ok(Self::Tcp),
+            "UDP" => Ok(Self::Udp),
+            _ => Err(format!("Invalid protocol: {}", value)),
+        }
+    }
+}
+
+/// Origin of a NAT entry; only dynamic entries are aged out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NatEntryType {
+    #[default]
+    Static,
+    Dynamic,
+}
+
+impl NatEntryType {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "static" => Ok(Self::Static),
+            "dynamic" => Ok(Self::Dynamic),
+            _ => Err(format!("Invalid entry_type: {}", value)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NatEntryKey {
     pub src_ip: Ipv4Addr,
@@ -43,6 +124,50 @@ impl NatEntryKey {
             dst_port,
         }
     }
+
+    /// Builds the key for an APPL_DB NAT table entry.
+    ///
+    /// NAT_TABLE and NAPT_TABLE keys name a single endpoint, which is the
+    /// source for SNAT and the destination for DNAT. The twice-NAT tables
+    /// carry both endpoints, source first.
+    pub fn from_appl_key(table: &str, key: &str, nat_type: NatType) -> Result<Self, String> {
+        let invalid = || format!("Invalid {} key: {}", table, key);
+        let parts: Vec<&str> = key.split(':').collect();
+        let unspecified = Ipv4Addr::UNSPECIFIED;
+        match (table, parts.as_slice()) {
+            (tables::NAT_TABLE, [ip]) => {
+                let ip = parse_ipv4("ip", ip)?;
+                Ok(match nat_type {
+                    NatType::Destination => Self::new(unspecified, ip, NatProtocol::All, 0, 0),
+                    _ => Self::new(ip, unspecified, NatProtocol::All, 0, 0),
+                })
+            }
+            (tables::NAPT_TABLE, [proto, ip, port]) => {
+                let protocol = NatProtocol::parse(proto)?;
+                let ip = parse_ipv4("ip", ip)?;
+                let port = parse_port("port", port)?;
+                Ok(match nat_type {
+                    NatType::Destination => Self::new(unspecified, ip, protocol, 0, port),
+                    _ => Self::new(ip, unspecified, protocol, port, 0),
+                })
+            }
+            (tables::NAT_TWICE_TABLE, [src, dst]) => Ok(Self::new(
+                parse_ipv4("src_ip", src)?,
+                parse_ipv4("dst_ip", dst)?,
+                NatProtocol::All,
+                0,
+                0,
+            )),
+            (tables::NAPT_TWICE_TABLE, [proto, src, src_port, dst, dst_port]) => Ok(Self::new(
+                parse_ipv4("src_ip", src)?,
+                parse_ipv4("dst_ip", dst)?,
+                NatProtocol::parse(proto)?,
+                parse_port("src_port", src_port)?,
+                parse_port("dst_port", dst_port)?,
+            )),
+            _ => Err(invalid()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +184,9 @@ pub struct NatEntry {
     pub key: NatEntryKey,
     pub config: NatEntryConfig,
     pub entry_oid: RawSaiObjectId,
+    pub entry_type: NatEntryType,
+    /// Last time the hit bit was seen set; `None` until the first aging poll.
+    pub last_active: Option<Instant>,
 }
 
 impl NatEntry {
@@ -67,7 +195,96 @@ impl NatEntry {
             key,
             config,
             entry_oid: 0,
+            entry_type: NatEntryType::Static,
+            last_active: None,
+        }
+    }
+
+    /// Parses a NAT_TABLE, NAPT_TABLE, NAT_TWICE_TABLE or NAPT_TWICE_TABLE entry.
+    pub fn from_appl(table: &str, key: &str, fields: &[(String, String)]) -> Result<Self, String> {
+        let twice = matches!(table, tables::NAT_TWICE_TABLE | tables::NAPT_TWICE_TABLE);
+        let mut nat_type = if twice {
+            NatType::DoubleNat
+        } else {
+            NatType::Source
+        };
+        let mut entry_type = NatEntryType::Static;
+        let mut translated_ip = None;
+        let mut translated_port = None;
+        let mut config = NatEntryConfig {
+            nat_type,
+            translated_src_ip: None,
+            translated_dst_ip: None,
+            translated_src_port: None,
+            translated_dst_port: None,
+        };
+
+        for (field, value) in fields {
+            match field.as_str() {
+                "nat_type" if !twice => nat_type = NatType::parse(value)?,
+                "entry_type" => entry_type = NatEntryType::parse(value)?,
+                "translated_ip" => translated_ip = Some(parse_ipv4(field, value)?),
+                "translated_l4_port" => translated_port = Some(parse_port(field, value)?),
+                "translated_src_ip" => config.translated_src_ip = Some(parse_ipv4(field, value)?),
+                "translated_dst_ip" => config.translated_dst_ip = Some(parse_ipv4(field, value)?),
+                "translated_src_l4_port" => {
+                    config.translated_src_port = Some(parse_port(field, value)?)
+                }
+                "translated_dst_l4_port" => {
+                    config.translated_dst_port = Some(parse_port(field, value)?)
+                }
+                _ => {}
+            }
         }
+
+        let napt = matches!(table, tables::NAPT_TABLE | tables::NAPT_TWICE_TABLE);
+        config.nat_type = nat_type;
+        if twice {
+            if config.translated_src_ip.is_none() || config.translated_dst_ip.is_none() {
+                return Err(format!(
+                    "{}: translated_src_ip and translated_dst_ip are required",
+                    key
+                ));
+            }
+            if napt
+                && (config.translated_src_port.is_none() || config.translated_dst_port.is_none())
+            {
+                return Err(format!(
+                    "{}: translated_src_l4_port and translated_dst_l4_port are required",
+                    key
+                ));
+            }
+        } else {
+            let ip = translated_ip.ok_or_else(|| format!("{}: translated_ip is required", key))?;
+            if napt && translated_port.is_none() {
+                return Err(format!("{}: translated_l4_port is required", key));
+            }
+            if nat_type == NatType::Destination {
+                config.translated_dst_ip = Some(ip);
+                config.translated_dst_port = translated_port;
+            } else {
+                config.translated_src_ip = Some(ip);
+                config.translated_src_port = translated_port;
+            }
+        }
+
+        let mut entry = Self::new(NatEntryKey::from_appl_key(table, key, nat_type)?, config);
+        entry.entry_type = entry_type;
+        Ok(entry)
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.entry_type == NatEntryType::Dynamic
+    }
+
+    /// Returns true if both entries translate to the same addresses and ports.
+    pub fn same_translation(&self, other: &NatEntry) -> bool {
+        let (a, b) = (&self.config, &other.config);
+        a.nat_type == b.nat_type
+            && a.translated_src_ip == b.translated_src_ip
+            && a.translated_dst_ip == b.translated_dst_ip
+            && a.translated_src_port == b.translated_src_port
+            && a.translated_dst_port == b.translated_dst_port
     }
 
     pub fn is_snat(&self) -> bool {
@@ -100,6 +317,76 @@ pub struct NatPoolConfig {
     pub port_range: Option<(u16, u16)>,
 }
 
+impl NatPoolConfig {
+    /// Parses NAT_POOL fields: `nat_ip` as `a.b.c.d` or `a.b.c.d-e.f.g.h`,
+    /// and an optional `nat_port` as `start-end`.
+    pub fn parse(fields: &[(String, String)]) -> Result<Self, String> {
+        let mut ip_range = None;
+        let mut port_range = None;
+        for (field, value) in fields {
+            match field.as_str() {
+                "nat_ip" => ip_range = Some(parse_range::<Ipv4Addr>(field, value)?),
+                "nat_port" => port_range = Some(parse_range::<u16>(field, value)?),
+                _ => {}
+            }
+        }
+        Ok(Self {
+            ip_range: ip_range.ok_or_else(|| "nat_ip is required".to_string())?,
+            port_range,
+        })
+    }
+}
+
+/// NAT_BINDINGS entry tying an ACL to a pool for dynamic translations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NatBindingConfig {
+    pub name: String,
+    pub pool_name: String,
+    /// ACL selecting the traffic to translate; all traffic when unset.
+    pub acl_name: Option<String>,
+    pub nat_type: NatType,
+    pub twice_nat_id: Option<u16>,
+}
+
+impl NatBindingConfig {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            pool_name: String::new(),
+            acl_name: None,
+            nat_type: NatType::Source,
+            twice_nat_id: None,
+        }
+    }
+
+    /// Applies one NAT_BINDINGS field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "nat_pool" => self.pool_name = value.to_string(),
+            "access_list" => {
+                self.acl_name = (!value.is_empty()).then(|| value.to_string());
+            }
+            "nat_type" => self.nat_type = NatType::parse(value)?,
+            "twice_nat_id" => {
+                self.twice_nat_id = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid twice_nat_id: {}", value))?,
+                )
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pool_name.is_empty() {
+            return Err(format!("{}: nat_pool is required", self.name));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NatPoolEntry {
     pub key: NatPoolKey,
@@ -152,3 +439,164 @@ pub struct NatStats {
     pub acls_created: u64,
     pub translations: u64,
 }
+
+/// Message published to natsyncd on the notification channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NatNotification {
+    /// A dynamic entry idled past its timeout and was removed.
+    Aged(NatEntryKey),
+    /// A dynamic entry was replaced by a static one for the same key.
+    Replaced(NatEntryKey),
+}
+
+impl NatNotification {
+    pub fn op(&self) -> &'static str {
+        match self {
+            Self::Aged(_) => "aged",
+            Self::Replaced(_) => "replaced",
+        }
+    }
+
+    pub fn key(&self) -> &NatEntryKey {
+        match self {
+            Self::Aged(key) | Self::Replaced(key) => key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fv(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_nat_table_snat_and_dnat() {
+        let snat = NatEntry::from_appl(
+            tables::NAT_TABLE,
+            "10.0.0.1",
+            &fv(&[("translated_ip", "65.55.45.1"), ("nat_type", "snat")]),
+        )
+        .unwrap();
+        assert!(snat.is_snat());
+        assert_eq!(snat.key.src_ip, "10.0.0.1".parse::<Ipv4Addr>().unwrap());
+        assert!(snat.key.dst_ip.is_unspecified());
+        assert_eq!(
+            snat.config.translated_src_ip,
+            Some("65.55.45.1".parse().unwrap())
+        );
+        assert_eq!(snat.entry_type, NatEntryType::Static);
+
+        let dnat = NatEntry::from_appl(
+            tables::NAT_TABLE,
+            "65.55.45.1",
+            &fv(&[
+                ("translated_ip", "10.0.0.1"),
+                ("nat_type", "dnat"),
+                ("entry_type", "dynamic"),
+            ]),
+        )
+        .unwrap();
+        assert!(dnat.is_dnat());
+        assert!(dnat.is_dynamic());
+        assert_eq!(dnat.key.dst_ip, "65.55.45.1".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(
+            dnat.config.translated_dst_ip,
+            Some("10.0.0.1".parse().unwrap())
+        );
+
+        assert!(NatEntry::from_appl(tables::NAT_TABLE, "10.0.0.1", &[]).is_err());
+        assert!(NatEntry::from_appl(
+            tables::NAT_TABLE,
+            "10.0.0.1",
+            &fv(&[("translated_ip", "1.1.1.1"), ("nat_type", "xnat")])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_napt_and_twice_keys() {
+        let napt = NatEntry::from_appl(
+            tables::NAPT_TABLE,
+            "UDP:10.0.0.1:5000",
+            &fv(&[
+                ("translated_ip", "65.55.45.1"),
+                ("translated_l4_port", "6000"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(napt.key.protocol, NatProtocol::Udp);
+        assert_eq!(napt.key.src_port, 5000);
+        assert_eq!(napt.config.translated_src_port, Some(6000));
+        assert!(NatEntry::from_appl(
+            tables::NAPT_TABLE,
+            "UDP:10.0.0.1:5000",
+            &fv(&[("translated_ip", "65.55.45.1")])
+        )
+        .is_err());
+        assert!(
+            NatEntryKey::from_appl_key(tables::NAPT_TABLE, "ICMP:10.0.0.1:1", NatType::Source)
+                .is_err()
+        );
+
+        let twice = NatEntry::from_appl(
+            tables::NAPT_TWICE_TABLE,
+            "TCP:10.0.0.1:1024:20.0.0.1:80",
+            &fv(&[
+                ("translated_src_ip", "65.55.45.1"),
+                ("translated_src_l4_port", "2000"),
+                ("translated_dst_ip", "30.0.0.1"),
+                ("translated_dst_l4_port", "8080"),
+            ]),
+        )
+        .unwrap();
+        assert!(twice.is_double_nat());
+        assert_eq!(twice.key.dst_port, 80);
+        assert_eq!(twice.config.translated_dst_port, Some(8080));
+
+        assert!(NatEntry::from_appl(
+            tables::NAT_TWICE_TABLE,
+            "10.0.0.1:20.0.0.1",
+            &fv(&[("translated_src_ip", "65.55.45.1")])
+        )
+        .is_err());
+        assert!(NatEntryKey::from_appl_key(
+            tables::NAT_TWICE_TABLE,
+            "10.0.0.1",
+            NatType::DoubleNat
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_pool_and_binding_parse() {
+        let pool = NatPoolConfig::parse(&fv(&[
+            ("nat_ip", "65.55.45.1-65.55.45.5"),
+            ("nat_port", "1024-65535"),
+        ]))
+        .unwrap();
+        assert_eq!(pool.ip_range.1, "65.55.45.5".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(pool.port_range, Some((1024, 65535)));
+
+        let single = NatPoolConfig::parse(&fv(&[("nat_ip", "65.55.45.1")])).unwrap();
+        assert_eq!(single.ip_range.0, single.ip_range.1);
+        assert!(single.port_range.is_none());
+        assert!(NatPoolConfig::parse(&[]).is_err());
+        assert!(NatPoolConfig::parse(&fv(&[("nat_ip", "bogus")])).is_err());
+
+        let mut binding = NatBindingConfig::new("bind1".to_string());
+        assert!(binding.validate().is_err());
+        binding.parse_field("nat_pool", "pool1").unwrap();
+        binding.parse_field("access_list", "acl1").unwrap();
+        binding.parse_field("twice_nat_id", "7").unwrap();
+        assert!(binding.validate().is_ok());
+        assert_eq!(binding.acl_name.as_deref(), Some("acl1"));
+        assert_eq!(binding.twice_nat_id, Some(7));
+        assert!(binding.parse_field("twice_nat_id", "x").is_err());
+    }
+}
@@ -1,6 +1,8 @@
 //! FFI exports for NeighOrch.
 
 use super::orch::{NeighOrch, NeighOrchConfig};
+use super::types::NeighborUpdate;
+use sonic_ffi_bridge::{publish_cpp_event, CppObserverEvent, CppObserverSubject};
 use std::cell::RefCell;
 
 thread_local! {
@@ -28,3 +30,77 @@ pub extern "C" fn unregister_neigh_orch() -> bool {
         true
     })
 }
+
+/// Converts a neighbor update into the event C++ observers receive.
+fn neighbor_update_event(update: &NeighborUpdate) -> CppObserverEvent {
+    let event = CppObserverEvent::new(CppObserverSubject::NeighChange)
+        .with_name(&update.key.interface)
+        .with_ip(&update.key.ip.to_string())
+        .with_mac(*update.mac.as_bytes())
+        .with_add(update.add);
+    match &update.old_mac {
+        Some(old_mac) => event.with_old_mac(*old_mac.as_bytes()),
+        None => event,
+    }
+}
+
+/// Forwards a neighbor update to C++ observers registered through the bridge.
+pub(super) fn forward_neighbor_update(update: &NeighborUpdate) -> usize {
+    publish_cpp_event(neighbor_update_event(update))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neigh::types::{MacAddress, NeighborEntry, NeighborKey};
+    use sonic_ffi_bridge::{register_cpp_observer, unregister_cpp_observer};
+    use std::ffi::c_void;
+    use std::sync::Mutex;
+
+    unsafe extern "C-unwind" fn record(event: *const CppObserverEvent, user_ctx: *mut c_void) {
+        let events = &*(user_ctx as *const Mutex<Vec<CppObserverEvent>>);
+        events.lock().unwrap().push(*event);
+    }
+
+    #[test]
+    fn test_updates_forwarded_to_cpp_observers() {
+        let events: Mutex<Vec<CppObserverEvent>> = Mutex::new(Vec::new());
+        let handle = unsafe {
+            register_cpp_observer(
+                CppObserverSubject::NeighChange as u32,
+                Some(record),
+                &events as *const _ as *mut c_void,
+            )
+        };
+
+        let key = NeighborKey::new("Ethernet40".to_string(), "2001:db8:40::1".parse().unwrap());
+        let mac = |s: &str| MacAddress::from_str(s).unwrap();
+        let mut orch = NeighOrch::new(NeighOrchConfig::default());
+        orch.add_neighbor(NeighborEntry::new(key.clone(), mac("00:11:22:33:44:55")))
+            .unwrap();
+        orch.update_neighbor(NeighborEntry::new(key.clone(), mac("00:11:22:33:44:66")))
+            .unwrap();
+        orch.remove_neighbor(&key).unwrap();
+
+        assert_eq!(unregister_cpp_observer(handle), 0);
+        orch.add_neighbor(NeighborEntry::new(key.clone(), mac("00:11:22:33:44:55")))
+            .unwrap();
+
+        // Other tests publish neighbor updates concurrently
+        let events = events.lock().unwrap();
+        let seen: Vec<_> = events
+            .iter()
+            .filter(|e| e.ip() == "2001:db8:40::1")
+            .collect();
+        assert_eq!(seen.len(), 3);
+        assert!(seen.iter().all(|e| e.name() == "Ethernet40"));
+        assert_eq!(
+            seen.iter().map(|e| e.is_add).collect::<Vec<_>>(),
+            vec![1, 1, 0]
+        );
+        assert_eq!(seen[1].mac[5], 0x66);
+        assert_eq!(seen[1].has_old_mac, 1);
+        assert_eq!(seen[1].old_mac[5], 0x55);
+        assert_eq!(seen[2].has_old_mac, 0);
+    }
+}
@@ -8,6 +8,7 @@
 //! - Validated MAC address parsing
 //! - Type-safe neighbor types (Dynamic/Static)
 //! - HashMap for O(1) neighbor lookups
+//! - In-place MAC updates that keep the neighbor's next hop OID stable
 
 mod ffi;
 mod orch;
@@ -17,4 +18,5 @@ pub use ffi::{register_neigh_orch, unregister_neigh_orch};
 pub use orch::{NeighOrch, NeighOrchCallbacks, NeighOrchConfig, NeighOrchError, NeighOrchStats};
 pub use types::{
     MacAddress, NeighborConfig, NeighborEntry, NeighborKey, NeighborStats, NeighborType,
+    NeighborUpdate,
 };
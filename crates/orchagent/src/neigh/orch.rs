@@ -1,11 +1,15 @@
 //! Neighbor orchestration logic.
 
-use super::types::{NeighborEntry, NeighborKey, NeighborStats};
+use super::types::{
+    MacAddress, NeighborEntry, NeighborKey, NeighborStats, NeighborUpdate, RawSaiObjectId,
+};
 use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
     audit_log,
 };
+use sonic_orch_common::{NextHopUpdate, Publisher, Subscription};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Error)]
@@ -20,6 +24,8 @@ pub enum NeighOrchError {
     InterfaceNotFound(String),
     #[error("SAI error: {0}")]
     SaiError(String),
+    #[error("Unsupported update of neighbor {0:?}: {1}")]
+    UnsupportedUpdate(NeighborKey, String),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -38,12 +44,23 @@ pub trait NeighOrchCallbacks: Send + Sync {
     fn on_neighbor_added(&self, entry: &NeighborEntry);
     fn on_neighbor_removed(&self, key: &NeighborKey);
     fn on_neighbor_updated(&self, entry: &NeighborEntry);
+
+    /// Sets SAI_NEIGHBOR_ENTRY_ATTR_DST_MAC_ADDRESS on an existing neighbor.
+    fn set_neighbor_mac(&self, key: &NeighborKey, mac: &MacAddress) -> Result<(), String>;
+
+    /// Sets SAI_NEIGHBOR_ENTRY_ATTR_ENCAP_INDEX on an existing neighbor.
+    fn set_neighbor_encap_index(&self, key: &NeighborKey, encap_index: u32) -> Result<(), String>;
+
+    /// Notifies observers (RouteOrch, NhgOrch, MuxOrch, ...) of a change.
+    fn notify(&self, update: NeighborUpdate);
 }
 
 pub struct NeighOrch {
     config: NeighOrchConfig,
     stats: NeighOrchStats,
+    callbacks: Option<Arc<dyn NeighOrchCallbacks>>,
     neighbors: HashMap<NeighborKey, NeighborEntry>,
+    next_hop_updates: Publisher<NextHopUpdate>,
 }
 
 impl NeighOrch {
@@ -51,14 +68,85 @@ impl NeighOrch {
         Self {
             config,
             stats: NeighOrchStats::default(),
+            callbacks: None,
             neighbors: HashMap::new(),
+            next_hop_updates: Publisher::new(),
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn NeighOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    /// Subscribes to next hop resolution changes.
+    ///
+    /// An update is published whenever a neighbor is added, changes MAC, is
+    /// removed or fails to resolve.
+    pub fn subscribe_next_hop_updates(&self) -> Subscription<NextHopUpdate> {
+        self.next_hop_updates.subscribe()
+    }
+
+    fn publish_resolved(&self, entry: &NeighborEntry) {
+        let mac = sonic_types::MacAddress::new(*entry.mac.as_bytes());
+        self.next_hop_updates.publish(NextHopUpdate::resolved(
+            &entry.key.ip,
+            &entry.key.interface,
+            mac,
+        ));
+    }
+
+    fn publish_unresolved(&self, key: &NeighborKey) {
+        self.next_hop_updates
+            .publish(NextHopUpdate::unresolved(&key.ip, &key.interface));
+    }
+
     pub fn get_neighbor(&self, key: &NeighborKey) -> Option<&NeighborEntry> {
         self.neighbors.get(key)
     }
 
+    pub fn has_next_hop(&self, key: &NeighborKey) -> bool {
+        self.get_next_hop_id(key).is_some()
+    }
+
+    /// Returns the next hop OID created for the neighbor, if any.
+    pub fn get_next_hop_id(&self, key: &NeighborKey) -> Option<RawSaiObjectId> {
+        self.neighbors
+            .get(key)
+            .map(|e| e.next_hop_oid)
+            .filter(|&oid| oid != 0)
+    }
+
+    pub fn get_next_hop_ref_count(&self, key: &NeighborKey) -> u32 {
+        self.neighbors
+            .get(key)
+            .map(|e| e.next_hop_ref_count)
+            .unwrap_or(0)
+    }
+
+    pub fn increase_next_hop_ref_count(
+        &mut self,
+        key: &NeighborKey,
+    ) -> Result<u32, NeighOrchError> {
+        let entry = self
+            .neighbors
+            .get_mut(key)
+            .ok_or_else(|| NeighOrchError::NeighborNotFound(key.clone()))?;
+        entry.next_hop_ref_count = entry.next_hop_ref_count.saturating_add(1);
+        Ok(entry.next_hop_ref_count)
+    }
+
+    pub fn decrease_next_hop_ref_count(
+        &mut self,
+        key: &NeighborKey,
+    ) -> Result<u32, NeighOrchError> {
+        let entry = self
+            .neighbors
+            .get_mut(key)
+            .ok_or_else(|| NeighOrchError::NeighborNotFound(key.clone()))?;
+        entry.next_hop_ref_count = entry.next_hop_ref_count.saturating_sub(1);
+        Ok(entry.next_hop_ref_count)
+    }
+
     pub fn add_neighbor(&mut self, entry: NeighborEntry) -> Result<(), NeighOrchError> {
         let key = entry.key.clone();
 
@@ -76,6 +164,14 @@ impl NeighOrch {
         self.stats.stats.neighbors_added = self.stats.stats.neighbors_added.saturating_add(1);
         self.neighbors.insert(key.clone(), entry.clone());
 
+        let update = NeighborUpdate::added(key.clone(), entry.mac.clone());
+        super::ffi::forward_neighbor_update(&update);
+        if let Some(callbacks) = &self.callbacks {
+            callbacks.on_neighbor_added(&entry);
+            callbacks.notify(update);
+        }
+        self.publish_resolved(&entry);
+
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceCreate, "NeighOrch", "add_neighbor")
                 .with_outcome(AuditOutcome::Success)
@@ -122,6 +218,14 @@ impl NeighOrch {
 
         self.stats.stats.neighbors_removed = self.stats.stats.neighbors_removed.saturating_add(1);
 
+        let update = NeighborUpdate::removed(key.clone(), entry.mac.clone());
+        super::ffi::forward_neighbor_update(&update);
+        if let Some(callbacks) = &self.callbacks {
+            callbacks.on_neighbor_removed(key);
+            callbacks.notify(update);
+        }
+        self.publish_unresolved(key);
+
         audit_log!(AuditRecord::new(
             AuditCategory::ResourceDelete,
             "NeighOrch",
@@ -142,10 +246,17 @@ impl NeighOrch {
         Ok(entry)
     }
 
+    /// Updates an existing neighbor.
+    ///
+    /// MAC and encap index changes are applied in place with SAI attribute
+    /// sets rather than a remove + add, so the neighbor keeps its next hop
+    /// OID and nothing that references the next hop (routes, next hop groups)
+    /// is touched. The SAI OIDs and the next hop ref count are owned by
+    /// NeighOrch and kept; an update asking for different OIDs is rejected.
     pub fn update_neighbor(&mut self, entry: NeighborEntry) -> Result<(), NeighOrchError> {
         let key = entry.key.clone();
 
-        if !self.neighbors.contains_key(&key) {
+        let Some(existing) = self.neighbors.get(&key) else {
             let err = NeighOrchError::NeighborNotFound(key.clone());
             audit_log!(AuditRecord::new(
                 AuditCategory::ResourceModify,
@@ -157,10 +268,92 @@ impl NeighOrch {
             .with_object_type("neighbor_entry")
             .with_error(err.to_string()));
             return Err(err);
+        };
+
+        let oid_changed = |new: RawSaiObjectId, old: RawSaiObjectId| new != 0 && new != old;
+        if oid_changed(entry.neigh_oid, existing.neigh_oid)
+            || oid_changed(entry.next_hop_oid, existing.next_hop_oid)
+        {
+            let err = NeighOrchError::UnsupportedUpdate(
+                key.clone(),
+                "SAI object IDs cannot change in place".to_string(),
+            );
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "NeighOrch",
+                "update_neighbor"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(format!("{}/{}", key.interface, key.ip))
+            .with_object_type("neighbor_entry")
+            .with_error(err.to_string()));
+            return Err(err);
+        }
+
+        let mac_changed = existing.mac != entry.mac;
+        let encap_changed = existing.encap_index != entry.encap_index;
+        if !mac_changed && !encap_changed {
+            return Ok(());
+        }
+        let old_mac = existing.mac.clone();
+        let old_encap_index = existing.encap_index;
+
+        if let Some(callbacks) = &self.callbacks {
+            let mut result = Ok(());
+            if mac_changed {
+                result = callbacks.set_neighbor_mac(&key, &entry.mac);
+            }
+            if result.is_ok() && encap_changed {
+                result = callbacks.set_neighbor_encap_index(&key, entry.encap_index);
+                if result.is_err() && mac_changed {
+                    // Leave SAI as it was so it matches the entry we keep
+                    let _ = callbacks.set_neighbor_mac(&key, &old_mac);
+                }
+            }
+            if let Err(e) = result {
+                self.stats.errors = self.stats.errors.saturating_add(1);
+                let err = NeighOrchError::SaiError(e);
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "NeighOrch",
+                    "update_neighbor"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(format!("{}/{}", key.interface, key.ip))
+                .with_object_type("neighbor_entry")
+                .with_error(err.to_string())
+                .with_details(serde_json::json!({
+                    "old_mac_address": old_mac.to_string(),
+                    "mac_address": entry.mac.to_string(),
+                    "old_encap_index": old_encap_index,
+                    "encap_index": entry.encap_index,
+                })));
+                return Err(err);
+            }
         }
 
+        let updated = match self.neighbors.get_mut(&key) {
+            Some(existing) => {
+                existing.mac = entry.mac.clone();
+                existing.encap_index = entry.encap_index;
+                existing.clone()
+            }
+            None => return Err(NeighOrchError::NeighborNotFound(key)),
+        };
         self.stats.stats.neighbors_updated = self.stats.stats.neighbors_updated.saturating_add(1);
-        self.neighbors.insert(key.clone(), entry.clone());
+
+        if let Some(callbacks) = &self.callbacks {
+            callbacks.on_neighbor_updated(&updated);
+        }
+        if mac_changed {
+            let update =
+                NeighborUpdate::mac_changed(key.clone(), old_mac.clone(), updated.mac.clone());
+            super::ffi::forward_neighbor_update(&update);
+            if let Some(callbacks) = &self.callbacks {
+                callbacks.notify(update);
+            }
+            self.publish_resolved(&updated);
+        }
 
         audit_log!(AuditRecord::new(
             AuditCategory::ResourceModify,
@@ -173,15 +366,30 @@ impl NeighOrch {
         .with_details(serde_json::json!({
             "interface": key.interface,
             "ip_address": key.ip.to_string(),
-            "mac_address": format!("{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-                entry.mac.as_bytes()[0], entry.mac.as_bytes()[1], entry.mac.as_bytes()[2],
-                entry.mac.as_bytes()[3], entry.mac.as_bytes()[4], entry.mac.as_bytes()[5]),
-            "state_change": "mac_address_updated",
+            "old_mac_address": old_mac.to_string(),
+            "mac_address": updated.mac.to_string(),
+            "old_encap_index": old_encap_index,
+            "encap_index": updated.encap_index,
+            "next_hop_oid": format!("0x{:x}", updated.next_hop_oid),
+            "state_change": "neighbor_updated",
         })));
 
         Ok(())
     }
 
+    /// Records that a next hop failed to resolve (ARP/NDP gave up on it).
+    ///
+    /// A neighbor previously learned for it is removed. Subscribers are told
+    /// the next hop is unresolved either way, so anything waiting on it stays
+    /// dropped.
+    pub fn neighbor_failed(&mut self, key: &NeighborKey) -> Option<NeighborEntry> {
+        if self.neighbors.contains_key(key) {
+            return self.remove_neighbor(key).ok();
+        }
+        self.publish_unresolved(key);
+        None
+    }
+
     pub fn get_neighbors_by_interface(&self, interface: &str) -> Vec<&NeighborEntry> {
         self.neighbors
             .values()
@@ -438,4 +646,274 @@ mod tests {
         assert_eq!(orch.stats().stats.ipv6_neighbors, 1);
         assert_eq!(orch.neighbor_count(), 3);
     }
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        mac_sets: std::sync::Mutex<Vec<(NeighborKey, MacAddress)>>,
+        encap_sets: std::sync::Mutex<Vec<(NeighborKey, u32)>>,
+        updates: std::sync::Mutex<Vec<NeighborUpdate>>,
+        fail_mac_set: std::sync::atomic::AtomicBool,
+        fail_encap_set: std::sync::atomic::AtomicBool,
+    }
+
+    impl NeighOrchCallbacks for MockCallbacks {
+        fn on_neighbor_added(&self, _entry: &NeighborEntry) {}
+        fn on_neighbor_removed(&self, _key: &NeighborKey) {}
+        fn on_neighbor_updated(&self, _entry: &NeighborEntry) {}
+
+        fn set_neighbor_mac(&self, key: &NeighborKey, mac: &MacAddress) -> Result<(), String> {
+            if self.fail_mac_set.load(std::sync::atomic::Ordering::SeqCst) {
+                return Err("SAI_STATUS_FAILURE".to_string());
+            }
+            self.mac_sets
+                .lock()
+                .unwrap()
+                .push((key.clone(), mac.clone()));
+            Ok(())
+        }
+
+        fn set_neighbor_encap_index(
+            &self,
+            key: &NeighborKey,
+            encap_index: u32,
+        ) -> Result<(), String> {
+            if self
+                .fail_encap_set
+                .load(std::sync::atomic::Ordering::SeqCst)
+            {
+                return Err("SAI_STATUS_FAILURE".to_string());
+            }
+            self.encap_sets
+                .lock()
+                .unwrap()
+                .push((key.clone(), encap_index));
+            Ok(())
+        }
+
+        fn notify(&self, update: NeighborUpdate) {
+            self.updates.lock().unwrap().push(update);
+        }
+    }
+
+    fn mac(s: &str) -> MacAddress {
+        MacAddress::from_str(s).unwrap()
+    }
+
+    fn orch_with_resolved_neighbor() -> (NeighOrch, Arc<MockCallbacks>, NeighborKey) {
+        let callbacks = Arc::new(MockCallbacks::default());
+        let mut orch = NeighOrch::new(NeighOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let mut neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:55");
+        neighbor.neigh_oid = 0x5000;
+        neighbor.next_hop_oid = 0x6000;
+        let key = neighbor.key.clone();
+        orch.add_neighbor(neighbor).unwrap();
+
+        // Two routes resolve through this neighbor
+        orch.increase_next_hop_ref_count(&key).unwrap();
+        orch.increase_next_hop_ref_count(&key).unwrap();
+        (orch, callbacks, key)
+    }
+
+    #[test]
+    fn test_mac_flap_keeps_next_hop() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+
+        for new_mac in ["00:11:22:33:44:66", "00:11:22:33:44:55"] {
+            orch.update_neighbor(create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", new_mac))
+                .unwrap();
+
+            let entry = orch.get_neighbor(&key).unwrap();
+            assert_eq!(entry.mac, mac(new_mac));
+            assert_eq!(entry.neigh_oid, 0x5000);
+            assert_eq!(orch.get_next_hop_id(&key), Some(0x6000));
+            assert_eq!(orch.get_next_hop_ref_count(&key), 2);
+        }
+
+        // In-place SAI sets only; no remove/add churn
+        assert_eq!(callbacks.mac_sets.lock().unwrap().len(), 2);
+        assert_eq!(orch.stats().stats.neighbors_added, 1);
+        assert_eq!(orch.stats().stats.neighbors_removed, 0);
+        assert_eq!(orch.stats().stats.neighbors_updated, 2);
+
+        let updates = callbacks.updates.lock().unwrap();
+        assert_eq!(updates.len(), 3);
+        assert_eq!(
+            updates[1],
+            NeighborUpdate::mac_changed(
+                key.clone(),
+                mac("00:11:22:33:44:55"),
+                mac("00:11:22:33:44:66")
+            )
+        );
+        assert_eq!(updates[2].old_mac, Some(mac("00:11:22:33:44:66")));
+        assert_eq!(updates[2].mac, mac("00:11:22:33:44:55"));
+    }
+
+    #[test]
+    fn test_update_neighbor_same_mac_is_noop() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+
+        orch.update_neighbor(create_test_ipv4_neighbor(
+            "10.0.0.1",
+            "Ethernet0",
+            "00:11:22:33:44:55",
+        ))
+        .unwrap();
+
+        assert!(callbacks.mac_sets.lock().unwrap().is_empty());
+        assert_eq!(callbacks.updates.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().stats.neighbors_updated, 0);
+        assert_eq!(orch.get_next_hop_id(&key), Some(0x6000));
+    }
+
+    #[test]
+    fn test_update_neighbor_applies_encap_index() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+
+        let mut neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:55");
+        neighbor.encap_index = 7;
+        orch.update_neighbor(neighbor).unwrap();
+
+        // Only the encap index changed; the MAC is left alone
+        let entry = orch.get_neighbor(&key).unwrap();
+        assert_eq!(entry.encap_index, 7);
+        assert_eq!(entry.neigh_oid, 0x5000);
+        assert_eq!(orch.get_next_hop_id(&key), Some(0x6000));
+        assert_eq!(orch.get_next_hop_ref_count(&key), 2);
+        assert_eq!(
+            *callbacks.encap_sets.lock().unwrap(),
+            vec![(key.clone(), 7)]
+        );
+        assert!(callbacks.mac_sets.lock().unwrap().is_empty());
+        assert_eq!(callbacks.updates.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().stats.neighbors_updated, 1);
+    }
+
+    #[test]
+    fn test_update_neighbor_rejects_oid_change() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+
+        let mut neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:66");
+        neighbor.next_hop_oid = 0x7000;
+        let result = orch.update_neighbor(neighbor);
+
+        assert!(matches!(
+            result,
+            Err(NeighOrchError::UnsupportedUpdate(_, _))
+        ));
+        let entry = orch.get_neighbor(&key).unwrap();
+        assert_eq!(entry.mac, mac("00:11:22:33:44:55"));
+        assert_eq!(entry.next_hop_oid, 0x6000);
+        assert!(callbacks.mac_sets.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_encap_update_failure_restores_mac() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+        callbacks
+            .fail_encap_set
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let mut neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:66");
+        neighbor.encap_index = 7;
+        let result = orch.update_neighbor(neighbor);
+
+        assert!(matches!(result, Err(NeighOrchError::SaiError(_))));
+        let entry = orch.get_neighbor(&key).unwrap();
+        assert_eq!(entry.mac, mac("00:11:22:33:44:55"));
+        assert_eq!(entry.encap_index, 0);
+        // The MAC set is undone in SAI
+        let mac_sets = callbacks.mac_sets.lock().unwrap();
+        assert_eq!(mac_sets.len(), 2);
+        assert_eq!(mac_sets[1].1, mac("00:11:22:33:44:55"));
+        assert_eq!(orch.stats().errors, 1);
+    }
+
+    #[test]
+    fn test_mac_update_sai_failure_keeps_old_mac() {
+        let (mut orch, callbacks, key) = orch_with_resolved_neighbor();
+        callbacks
+            .fail_mac_set
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let result = orch.update_neighbor(create_test_ipv4_neighbor(
+            "10.0.0.1",
+            "Ethernet0",
+            "00:11:22:33:44:66",
+        ));
+
+        assert!(matches!(result, Err(NeighOrchError::SaiError(_))));
+        let entry = orch.get_neighbor(&key).unwrap();
+        assert_eq!(entry.mac, mac("00:11:22:33:44:55"));
+        assert_eq!(entry.next_hop_oid, 0x6000);
+        assert_eq!(orch.get_next_hop_ref_count(&key), 2);
+        assert_eq!(orch.stats().errors, 1);
+        assert_eq!(callbacks.updates.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_next_hop_ref_count() {
+        let (mut orch, _callbacks, key) = orch_with_resolved_neighbor();
+
+        assert_eq!(orch.decrease_next_hop_ref_count(&key).unwrap(), 1);
+        assert_eq!(orch.decrease_next_hop_ref_count(&key).unwrap(), 0);
+        assert_eq!(orch.decrease_next_hop_ref_count(&key).unwrap(), 0);
+
+        let unknown = NeighborKey::new("Ethernet4".to_string(), "10.0.0.9".parse().unwrap());
+        assert!(!orch.has_next_hop(&unknown));
+        assert!(matches!(
+            orch.increase_next_hop_ref_count(&unknown),
+            Err(NeighOrchError::NeighborNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_mac_address_display() {
+        assert_eq!(mac("00:1A:22:33:44:0f").to_string(), "00:1a:22:33:44:0f");
+    }
+
+    #[test]
+    fn test_next_hop_updates_published() {
+        let mut orch = NeighOrch::new(NeighOrchConfig::default());
+        let updates = orch.subscribe_next_hop_updates();
+        let neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:55");
+        let key = neighbor.key.clone();
+
+        orch.add_neighbor(neighbor).unwrap();
+        orch.add_neighbor(create_test_ipv4_neighbor(
+            "10.0.0.1",
+            "Ethernet0",
+            "00:11:22:33:44:66",
+        ))
+        .unwrap();
+        orch.remove_neighbor(&key).unwrap();
+        assert!(orch.neighbor_failed(&key).is_none());
+
+        let updates = updates.drain();
+        let resolved: Vec<_> = updates.iter().map(|u| u.resolved).collect();
+        assert_eq!(resolved, vec![true, true, false, false]);
+        assert!(updates.iter().all(|u| u.nh_key == "10.0.0.1@Ethernet0"));
+        assert_eq!(
+            updates[1].mac,
+            Some(sonic_types::MacAddress::new([
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x66
+            ]))
+        );
+        assert_eq!(updates[2].mac, None);
+    }
+
+    #[test]
+    fn test_neighbor_failed_removes_entry() {
+        let mut orch = NeighOrch::new(NeighOrchConfig::default());
+        let updates = orch.subscribe_next_hop_updates();
+        let neighbor = create_test_ipv4_neighbor("10.0.0.1", "Ethernet0", "00:11:22:33:44:55");
+        let key = neighbor.key.clone();
+        orch.add_neighbor(neighbor).unwrap();
+
+        assert!(orch.neighbor_failed(&key).is_some());
+        assert_eq!(orch.neighbor_count(), 0);
+        assert_eq!(updates.drain().len(), 2);
+    }
 }
@@ -1,5 +1,6 @@
 //! Neighbor (ARP/NDP) types.
 
+use std::fmt;
 use std::net::IpAddr;
 
 pub type RawSaiObjectId = u64;
@@ -44,11 +45,24 @@ impl MacAddress {
     }
 }
 
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = &self.bytes;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            b[0], b[1], b[2], b[3], b[4], b[5]
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NeighborEntry {
     pub key: NeighborKey,
     pub mac: MacAddress,
     pub neigh_oid: RawSaiObjectId,
+    pub next_hop_oid: RawSaiObjectId,
+    pub next_hop_ref_count: u32,
     pub encap_index: u32,
 }
 
@@ -58,6 +72,8 @@ impl NeighborEntry {
             key,
             mac,
             neigh_oid: 0,
+            next_hop_oid: 0,
+            next_hop_ref_count: 0,
             encap_index: 0,
         }
     }
@@ -71,6 +87,45 @@ impl NeighborEntry {
     }
 }
 
+/// Neighbor change notification sent to observers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeighborUpdate {
+    pub key: NeighborKey,
+    pub mac: MacAddress,
+    /// Previous MAC when an existing neighbor's MAC changed in place.
+    pub old_mac: Option<MacAddress>,
+    pub add: bool,
+}
+
+impl NeighborUpdate {
+    pub fn added(key: NeighborKey, mac: MacAddress) -> Self {
+        Self {
+            key,
+            mac,
+            old_mac: None,
+            add: true,
+        }
+    }
+
+    pub fn removed(key: NeighborKey, mac: MacAddress) -> Self {
+        Self {
+            key,
+            mac,
+            old_mac: None,
+            add: false,
+        }
+    }
+
+    pub fn mac_changed(key: NeighborKey, old_mac: MacAddress, mac: MacAddress) -> Self {
+        Self {
+            key,
+            mac,
+            old_mac: Some(old_mac),
+            add: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NeighborType {
     Dynamic,
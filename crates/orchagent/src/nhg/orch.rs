@@ -5,8 +5,9 @@ use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
     audit_log,
 };
+use sonic_orch_common::{NextHopUpdate, Subscription};
 use sonic_sai::types::RawSaiObjectId;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use thiserror::Error;
@@ -36,16 +37,33 @@ pub struct NhgOrchStats {
     pub nhgs_removed: u64,
     pub nexthops_created: u64,
     pub nexthops_removed: u64,
+    pub members_added: u64,
+    pub members_removed: u64,
+    pub member_weight_updates: u64,
+    /// Members taken out of their group because their next hop went away.
+    pub members_withdrawn: u64,
+    /// Withdrawn members put back once their next hop resolved again.
+    pub members_restored: u64,
 }
 
 pub trait NhgOrchCallbacks: Send + Sync {
     fn create_next_hop(&self, key: &NextHopKey) -> Result<RawSaiObjectId, String>;
     fn remove_next_hop(&self, nh_id: RawSaiObjectId) -> Result<(), String>;
-    fn create_next_hop_group(
+    /// Creates an empty group. Members are added separately with
+    /// [`Self::create_next_hop_group_member`].
+    fn create_next_hop_group(&self) -> Result<RawSaiObjectId, String>;
+    fn remove_next_hop_group(&self, nhg_id: RawSaiObjectId) -> Result<(), String>;
+    fn create_next_hop_group_member(
         &self,
-        members: &[NextHopGroupMember],
+        nhg_id: RawSaiObjectId,
+        member: &NextHopGroupMember,
     ) -> Result<RawSaiObjectId, String>;
-    fn remove_next_hop_group(&self, nhg_id: RawSaiObjectId) -> Result<(), String>;
+    fn remove_next_hop_group_member(&self, gm_id: RawSaiObjectId) -> Result<(), String>;
+    fn set_next_hop_group_member_weight(
+        &self,
+        gm_id: RawSaiObjectId,
+        weight: u32,
+    ) -> Result<(), String>;
 }
 
 #[derive(Debug)]
@@ -62,6 +80,9 @@ pub struct NhgOrch {
     callbacks: Option<Arc<dyn NhgOrchCallbacks>>,
     nhgs: HashMap<String, NhgOrchEntry>,
     nexthops: HashMap<NextHopKey, RawSaiObjectId>,
+    next_hop_updates: Option<Subscription<NextHopUpdate>>,
+    /// Next hops NeighOrch reported unresolved, as `ip@alias`.
+    unresolved_next_hops: HashSet<String>,
 }
 
 impl NhgOrch {
@@ -72,6 +93,8 @@ impl NhgOrch {
             callbacks: None,
             nhgs: HashMap::new(),
             nexthops: HashMap::new(),
+            next_hop_updates: None,
+            unresolved_next_hops: HashSet::new(),
         }
     }
 
@@ -79,6 +102,21 @@ impl NhgOrch {
         self.callbacks = Some(callbacks);
     }
 
+    /// Subscribes to next hop resolution changes from NeighOrch.
+    ///
+    /// Members whose next hop is unresolved are kept in the group's member
+    /// list but not programmed, so the group only forwards to next hops that
+    /// resolve.
+    pub fn subscribe_next_hop_updates(&mut self, updates: Subscription<NextHopUpdate>) {
+        self.next_hop_updates = Some(updates);
+    }
+
+    /// Returns true if NeighOrch reported the next hop unresolved.
+    pub fn is_next_hop_unresolved(&self, key: &NextHopKey) -> bool {
+        self.unresolved_next_hops
+            .contains(&NextHopUpdate::key_for(&key.ip_address, &key.alias))
+    }
+
     pub fn nhg_exists(&self, name: &str) -> bool {
         self.nhgs.contains_key(name)
     }
@@ -142,7 +180,8 @@ impl NhgOrch {
                 .ok_or_else(|| NhgOrchError::InvalidConfig("No callbacks set".to_string()))?,
         );
 
-        let nhg_id = match callbacks.create_next_hop_group(&members) {
+        let mut members = members;
+        let nhg_id = match callbacks.create_next_hop_group() {
             Ok(id) => id,
             Err(e) => {
                 let err = NhgOrchError::SaiError(e);
@@ -162,6 +201,38 @@ impl NhgOrch {
             }
         };
 
+        // The group is created empty and each member is created here, so
+        // their weights can later be updated in place.
+        let mut failure = None;
+        for member in members.iter_mut() {
+            if self.is_next_hop_unresolved(&member.key) {
+                continue;
+            }
+            match callbacks.create_next_hop_group_member(nhg_id, member) {
+                Ok(gm_id) => member.gm_id = gm_id,
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            }
+        }
+        if let Some(e) = failure {
+            for created in members.iter().filter(|m| m.is_synced()) {
+                let _ = callbacks.remove_next_hop_group_member(created.gm_id);
+            }
+            let _ = callbacks.remove_next_hop_group(nhg_id);
+            let err = NhgOrchError::SaiError(e);
+            audit_log!(
+                AuditRecord::new(AuditCategory::ResourceCreate, "NhgOrch", "create_nhg")
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(name)
+                    .with_object_type("next_hop_group")
+                    .with_error(err.to_string())
+            );
+            return Err(err);
+        }
+        self.stats.members_added += members.len() as u64;
+
         let entry = NhgOrchEntry {
             name: name.clone(),
             nhg_id,
@@ -187,6 +258,208 @@ impl NhgOrch {
         Ok(())
     }
 
+    /// Reconciles an existing group with a new member list.
+    ///
+    /// Next hops are matched ignoring their weight: removed next hops are
+    /// deleted, new ones are added, and weight changes are applied to the
+    /// existing member in place so the group itself is never recreated.
+    pub fn update_nhg(
+        &mut self,
+        name: &str,
+        members: Vec<NextHopGroupMember>,
+    ) -> Result<(), NhgOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| NhgOrchError::InvalidConfig("No callbacks set".to_string()))?,
+        );
+
+        let entry = self
+            .nhgs
+            .get_mut(name)
+            .ok_or_else(|| NhgOrchError::NhgNotFound(name.to_string()))?;
+        let nhg_id = entry.nhg_id;
+
+        let mut desired: HashMap<NextHopKey, NextHopGroupMember> = members
+            .into_iter()
+            .map(|m| (m.key.without_weight(), m))
+            .collect();
+
+        let (mut added, mut removed, mut reweighted) = (0u64, 0u64, 0u64);
+        let mut result = Ok(());
+
+        // Remove stale members and update weights in place. On failure the
+        // entry is left reflecting exactly what was programmed so far.
+        let mut i = 0;
+        while i < entry.members.len() {
+            let id = entry.members[i].key.without_weight();
+            let member = &mut entry.members[i];
+            match desired.remove(&id) {
+                None => {
+                    if member.is_synced() {
+                        if let Err(e) = callbacks.remove_next_hop_group_member(member.gm_id) {
+                            result = Err(NhgOrchError::SaiError(e));
+                            break;
+                        }
+                    }
+                    entry.members.swap_remove(i);
+                    removed += 1;
+                    continue;
+                }
+                Some(want) if want.weight != member.weight => {
+                    // Dropping the weight restores the SAI default of 1
+                    if member.is_synced() {
+                        if let Err(e) = callbacks
+                            .set_next_hop_group_member_weight(member.gm_id, want.weight.max(1))
+                        {
+                            result = Err(NhgOrchError::SaiError(e));
+                            break;
+                        }
+                    }
+                    member.weight = want.weight;
+                    member.key.weight = want.weight;
+                    reweighted += 1;
+                }
+                Some(_) => {}
+            }
+            i += 1;
+        }
+
+        // Add next hops that are new to the group
+        if result.is_ok() {
+            for (_, mut member) in desired {
+                let key = NextHopUpdate::key_for(&member.key.ip_address, &member.key.alias);
+                if self.unresolved_next_hops.contains(&key) {
+                    entry.members.push(member);
+                    added += 1;
+                    continue;
+                }
+                match callbacks.create_next_hop_group_member(nhg_id, &member) {
+                    Ok(gm_id) => {
+                        member.gm_id = gm_id;
+                        entry.members.push(member);
+                        added += 1;
+                    }
+                    Err(e) => {
+                        result = Err(NhgOrchError::SaiError(e));
+                        break;
+                    }
+                }
+            }
+        }
+
+        let member_count = entry.members.len();
+        self.stats.members_added += added;
+        self.stats.members_removed += removed;
+        self.stats.member_weight_updates += reweighted;
+
+        let record = AuditRecord::new(AuditCategory::ResourceModify, "NhgOrch", "update_nhg")
+            .with_object_id(name)
+            .with_object_type("next_hop_group")
+            .with_details(serde_json::json!({
+                "nhg_id": format!("{:#x}", nhg_id),
+                "member_count": member_count,
+                "members_added": added,
+                "members_removed": removed,
+                "weights_updated": reweighted,
+            }));
+        match result {
+            Ok(()) => {
+                audit_log!(record.with_outcome(AuditOutcome::Success));
+                Ok(())
+            }
+            Err(err) => {
+                audit_log!(record
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_error(err.to_string()));
+                Err(err)
+            }
+        }
+    }
+
+    /// Applies the next hop resolution changes published since the last call.
+    ///
+    /// Members whose next hop went away are removed from their group in
+    /// SAI; members whose next hop resolved again are re-created. Returns the
+    /// number of members changed.
+    pub fn process_next_hop_updates(&mut self) -> usize {
+        let updates = match &self.next_hop_updates {
+            Some(subscription) => subscription.drain(),
+            None => return 0,
+        };
+        let Some(callbacks) = self.callbacks.clone() else {
+            return 0;
+        };
+
+        let mut changed = 0;
+        for update in updates {
+            if update.resolved {
+                self.unresolved_next_hops.remove(&update.nh_key);
+            } else {
+                self.unresolved_next_hops.insert(update.nh_key.clone());
+            }
+
+            for entry in self.nhgs.values_mut() {
+                for member in entry.members.iter_mut() {
+                    if !update.is_next_hop(&member.key.ip_address, &member.key.alias)
+                        || member.is_synced() == update.resolved
+                    {
+                        continue;
+                    }
+
+                    let result = if update.resolved {
+                        callbacks
+                            .create_next_hop_group_member(entry.nhg_id, member)
+                            .map(|gm_id| member.gm_id = gm_id)
+                    } else {
+                        callbacks
+                            .remove_next_hop_group_member(member.gm_id)
+                            .map(|()| member.gm_id = 0)
+                    };
+
+                    let record = AuditRecord::new(
+                        AuditCategory::ResourceModify,
+                        "NhgOrch",
+                        "update_member_resolution",
+                    )
+                    .with_object_id(&entry.name)
+                    .with_object_type("next_hop_group_member")
+                    .with_details(serde_json::json!({
+                        "nhg_id": format!("{:#x}", entry.nhg_id),
+                        "nexthop": update.nh_key,
+                        "resolved": update.resolved,
+                    }));
+                    match result {
+                        Ok(()) => {
+                            if update.resolved {
+                                self.stats.members_restored += 1;
+                            } else {
+                                self.stats.members_withdrawn += 1;
+                            }
+                            changed += 1;
+                            audit_log!(record.with_outcome(AuditOutcome::Success));
+                        }
+                        Err(e) => {
+                            audit_log!(record.with_outcome(AuditOutcome::Failure).with_error(e));
+                        }
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Returns the members of a group.
+    pub fn get_nhg_members(&self, name: &str) -> Option<&[NextHopGroupMember]> {
+        self.nhgs.get(name).map(|e| e.members.as_slice())
+    }
+
+    /// Returns the SAI object ID of a group.
+    pub fn get_nhg_id(&self, name: &str) -> Option<RawSaiObjectId> {
+        self.nhgs.get(name).map(|e| e.nhg_id)
+    }
+
     pub fn remove_nhg(&mut self, name: &str) -> Result<(), NhgOrchError> {
         let entry = self
             .nhgs
@@ -219,6 +492,23 @@ impl NhgOrch {
             .as_ref()
             .ok_or_else(|| NhgOrchError::InvalidConfig("No callbacks set".to_string()))?;
 
+        for member in entry.members.iter().filter(|m| m.is_synced()) {
+            if let Err(e) = callbacks.remove_next_hop_group_member(member.gm_id) {
+                let err = NhgOrchError::SaiError(e);
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceDelete,
+                    "NhgOrch",
+                    "remove_nhg"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(name)
+                .with_object_type("next_hop_group")
+                .with_error(err.to_string()));
+                return Err(err);
+            }
+        }
+        self.stats.members_removed += entry.members.len() as u64;
+
         if let Err(e) = callbacks.remove_next_hop_group(entry.nhg_id) {
             let err = NhgOrchError::SaiError(e);
             audit_log!(
@@ -310,13 +600,21 @@ impl NhgOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nhg::NextHopGroupKey;
+    use sonic_orch_common::Publisher;
     use sonic_types::{IpAddress, MacAddress};
     use std::str::FromStr;
     use std::sync::atomic::AtomicU64;
+    use std::sync::Mutex;
 
     struct MockCallbacks {
         next_nhg_id: AtomicU64,
         next_nh_id: AtomicU64,
+        next_gm_id: AtomicU64,
+        groups_created: AtomicU64,
+        member_weights: Mutex<HashMap<RawSaiObjectId, u32>>,
+        weight_sets: Mutex<Vec<(RawSaiObjectId, u32)>>,
+        fail_weight_set: Mutex<Option<RawSaiObjectId>>,
     }
 
     impl MockCallbacks {
@@ -324,6 +622,11 @@ mod tests {
             Self {
                 next_nhg_id: AtomicU64::new(0x4000),
                 next_nh_id: AtomicU64::new(0x3000),
+                next_gm_id: AtomicU64::new(0x5000),
+                groups_created: AtomicU64::new(0),
+                member_weights: Mutex::new(HashMap::new()),
+                weight_sets: Mutex::new(Vec::new()),
+                fail_weight_set: Mutex::new(None),
             }
         }
     }
@@ -335,15 +638,45 @@ mod tests {
         fn remove_next_hop(&self, _nh_id: RawSaiObjectId) -> Result<(), String> {
             Ok(())
         }
-        fn create_next_hop_group(
-            &self,
-            _members: &[NextHopGroupMember],
-        ) -> Result<RawSaiObjectId, String> {
+        fn create_next_hop_group(&self) -> Result<RawSaiObjectId, String> {
+            self.groups_created.fetch_add(1, Ordering::SeqCst);
             Ok(self.next_nhg_id.fetch_add(1, Ordering::SeqCst))
         }
         fn remove_next_hop_group(&self, _nhg_id: RawSaiObjectId) -> Result<(), String> {
             Ok(())
         }
+        fn create_next_hop_group_member(
+            &self,
+            _nhg_id: RawSaiObjectId,
+            member: &NextHopGroupMember,
+        ) -> Result<RawSaiObjectId, String> {
+            let gm_id = self.next_gm_id.fetch_add(1, Ordering::SeqCst);
+            self.member_weights
+                .lock()
+                .unwrap()
+                .insert(gm_id, member.weight);
+            Ok(gm_id)
+        }
+        fn remove_next_hop_group_member(&self, gm_id: RawSaiObjectId) -> Result<(), String> {
+            self.member_weights
+                .lock()
+                .unwrap()
+                .remove(&gm_id)
+                .map(|_| ())
+                .ok_or_else(|| format!("Member {:#x} not found", gm_id))
+        }
+        fn set_next_hop_group_member_weight(
+            &self,
+            gm_id: RawSaiObjectId,
+            weight: u32,
+        ) -> Result<(), String> {
+            if *self.fail_weight_set.lock().unwrap() == Some(gm_id) {
+                return Err(format!("Member {:#x} not found", gm_id));
+            }
+            self.weight_sets.lock().unwrap().push((gm_id, weight));
+            self.member_weights.lock().unwrap().insert(gm_id, weight);
+            Ok(())
+        }
     }
 
     struct FailingCallbacks;
@@ -354,15 +687,29 @@ mod tests {
         fn remove_next_hop(&self, _nh_id: RawSaiObjectId) -> Result<(), String> {
             Err("Failed to remove next hop".to_string())
         }
-        fn create_next_hop_group(
-            &self,
-            _members: &[NextHopGroupMember],
-        ) -> Result<RawSaiObjectId, String> {
+        fn create_next_hop_group(&self) -> Result<RawSaiObjectId, String> {
             Err("Failed to create NHG".to_string())
         }
         fn remove_next_hop_group(&self, _nhg_id: RawSaiObjectId) -> Result<(), String> {
             Err("Failed to remove NHG".to_string())
         }
+        fn create_next_hop_group_member(
+            &self,
+            _nhg_id: RawSaiObjectId,
+            _member: &NextHopGroupMember,
+        ) -> Result<RawSaiObjectId, String> {
+            Err("Failed to create NHG member".to_string())
+        }
+        fn remove_next_hop_group_member(&self, _gm_id: RawSaiObjectId) -> Result<(), String> {
+            Err("Failed to remove NHG member".to_string())
+        }
+        fn set_next_hop_group_member_weight(
+            &self,
+            _gm_id: RawSaiObjectId,
+            _weight: u32,
+        ) -> Result<(), String> {
+            Err("Failed to set NHG member weight".to_string())
+        }
     }
 
     fn create_test_nexthop_key(ip: &str, alias: &str) -> NextHopKey {
@@ -384,6 +731,7 @@ mod tests {
             key: create_test_nexthop_key(ip, alias),
             gm_id: 0,
             nh_id: 0,
+            weight: 0,
         }
     }
 
@@ -402,6 +750,7 @@ mod tests {
             },
             gm_id: 0,
             nh_id: 0,
+            weight,
         }
     }
 
@@ -422,7 +771,8 @@ mod tests {
     #[test]
     fn test_create_nhg_multiple_nexthops_ecmp() {
         let mut orch = NhgOrch::new(NhgOrchConfig::default());
-        orch.set_callbacks(Arc::new(MockCallbacks::new()));
+        let callbacks = Arc::new(MockCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
 
         let members = vec![
             create_test_member("10.0.0.1", "Ethernet0"),
@@ -434,6 +784,10 @@ mod tests {
         assert!(orch.create_nhg("ecmp_group".to_string(), members).is_ok());
         assert_eq!(orch.nhg_count(), 1);
         assert!(orch.nhg_exists("ecmp_group"));
+
+        // Each member is created exactly once
+        assert_eq!(callbacks.groups_created.load(Ordering::SeqCst), 1);
+        assert_eq!(callbacks.member_weights.lock().unwrap().len(), 4);
     }
 
     #[test]
@@ -565,6 +919,7 @@ mod tests {
             },
             gm_id: 0,
             nh_id: 0,
+            weight: 0,
         };
 
         assert!(orch
@@ -592,6 +947,7 @@ mod tests {
             },
             gm_id: 0,
             nh_id: 0,
+            weight: 0,
         };
 
         assert!(orch
@@ -619,6 +975,7 @@ mod tests {
             },
             gm_id: 0,
             nh_id: 0,
+            weight: 0,
         };
 
         assert!(orch
@@ -646,6 +1003,7 @@ mod tests {
             },
             gm_id: 0,
             nh_id: 0,
+            weight: 0,
         };
 
         assert!(orch
@@ -699,6 +1057,7 @@ mod tests {
             key: create_test_nexthop_key("10.0.0.1", "Ethernet0"),
             gm_id: 0x5000,
             nh_id: 0x3000,
+            weight: 0,
         };
         assert!(synced_member.is_synced());
     }
@@ -1013,6 +1372,7 @@ mod tests {
                 },
                 gm_id: 0,
                 nh_id: 0,
+                weight: 0,
             },
             NextHopGroupMember {
                 key: NextHopKey {
@@ -1028,10 +1388,246 @@ mod tests {
                 },
                 gm_id: 0,
                 nh_id: 0,
+                weight: 0,
             },
         ];
 
         assert!(orch.create_nhg("ipv6_nhg".to_string(), members).is_ok());
         assert!(orch.nhg_exists("ipv6_nhg"));
     }
+
+    // 9. Weighted ECMP Tests
+
+    fn parse_members(nexthops: &str) -> Vec<NextHopGroupMember> {
+        nexthops.parse::<NextHopGroupKey>().unwrap().to_members()
+    }
+
+    fn member_weight(orch: &NhgOrch, name: &str, alias: &str) -> Option<(RawSaiObjectId, u32)> {
+        orch.get_nhg_members(name)
+            .unwrap()
+            .iter()
+            .find(|m| m.key.alias == alias)
+            .map(|m| (m.gm_id, m.weight))
+    }
+
+    #[test]
+    fn test_create_nhg_mixed_weighted_members() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let members = parse_members("10.0.0.1@Ethernet0!4,10.0.0.2@Ethernet4,10.0.0.3@Ethernet8!1");
+        orch.create_nhg("wcmp".to_string(), members).unwrap();
+
+        let (gm0, w0) = member_weight(&orch, "wcmp", "Ethernet0").unwrap();
+        let (gm4, w4) = member_weight(&orch, "wcmp", "Ethernet4").unwrap();
+        let (gm8, w8) = member_weight(&orch, "wcmp", "Ethernet8").unwrap();
+        assert_eq!((w0, w4, w8), (4, 0, 1));
+
+        // Weights reach the SAI member create call
+        let sai = callbacks.member_weights.lock().unwrap();
+        assert_eq!(sai.get(&gm0), Some(&4));
+        assert_eq!(sai.get(&gm4), Some(&0));
+        assert_eq!(sai.get(&gm8), Some(&1));
+        assert_eq!(orch.stats().members_added, 3);
+    }
+
+    #[test]
+    fn test_update_nhg_weight_in_place() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.create_nhg(
+            "wcmp".to_string(),
+            parse_members("10.0.0.1@Ethernet0!4,10.0.0.2@Ethernet4"),
+        )
+        .unwrap();
+        orch.increment_nhg_ref("wcmp").unwrap();
+        let nhg_id = orch.get_nhg_id("wcmp").unwrap();
+        let (gm0, _) = member_weight(&orch, "wcmp", "Ethernet0").unwrap();
+        let (gm4, _) = member_weight(&orch, "wcmp", "Ethernet4").unwrap();
+
+        orch.update_nhg(
+            "wcmp",
+            parse_members("10.0.0.1@Ethernet0!2,10.0.0.2@Ethernet4!6"),
+        )
+        .unwrap();
+
+        // Same group and member objects, only the weights changed
+        assert_eq!(orch.get_nhg_id("wcmp"), Some(nhg_id));
+        assert_eq!(callbacks.groups_created.load(Ordering::SeqCst), 1);
+        assert_eq!(member_weight(&orch, "wcmp", "Ethernet0"), Some((gm0, 2)));
+        assert_eq!(member_weight(&orch, "wcmp", "Ethernet4"), Some((gm4, 6)));
+        let mut sets = callbacks.weight_sets.lock().unwrap().clone();
+        sets.sort();
+        let mut expected = vec![(gm0, 2), (gm4, 6)];
+        expected.sort();
+        assert_eq!(sets, expected);
+        assert_eq!(orch.stats().member_weight_updates, 2);
+        assert_eq!(orch.stats().members_removed, 0);
+
+        // Unchanged weights are not re-programmed
+        orch.update_nhg(
+            "wcmp",
+            parse_members("10.0.0.1@Ethernet0!2,10.0.0.2@Ethernet4!6"),
+        )
+        .unwrap();
+        assert_eq!(callbacks.weight_sets.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_update_nhg_weight_with_member_removal() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.create_nhg(
+            "wcmp".to_string(),
+            parse_members("10.0.0.1@Ethernet0!1,10.0.0.2@Ethernet4!2,10.0.0.3@Ethernet8!3"),
+        )
+        .unwrap();
+        let (gm0, _) = member_weight(&orch, "wcmp", "Ethernet0").unwrap();
+        let (gm4, _) = member_weight(&orch, "wcmp", "Ethernet4").unwrap();
+
+        // Ethernet4 is withdrawn in the same update that reweights Ethernet0
+        orch.update_nhg(
+            "wcmp",
+            parse_members("10.0.0.1@Ethernet0!5,10.0.0.3@Ethernet8!3"),
+        )
+        .unwrap();
+        assert_eq!(orch.get_nhg_members("wcmp").unwrap().len(), 2);
+        assert_eq!(member_weight(&orch, "wcmp", "Ethernet0"), Some((gm0, 5)));
+        assert!(member_weight(&orch, "wcmp", "Ethernet4").is_none());
+        assert!(!callbacks.member_weights.lock().unwrap().contains_key(&gm4));
+
+        // A late weight update for the withdrawn next hop re-adds it as a new
+        // member instead of touching the stale member object
+        orch.update_nhg(
+            "wcmp",
+            parse_members("10.0.0.1@Ethernet0!5,10.0.0.2@Ethernet4!7,10.0.0.3@Ethernet8!3"),
+        )
+        .unwrap();
+        let (new_gm4, w4) = member_weight(&orch, "wcmp", "Ethernet4").unwrap();
+        assert_ne!(new_gm4, gm4);
+        assert_eq!(w4, 7);
+        assert!(!callbacks
+            .weight_sets
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(gm, _)| *gm == gm4));
+        assert_eq!(callbacks.groups_created.load(Ordering::SeqCst), 1);
+        assert_eq!(orch.stats().members_removed, 1);
+        assert_eq!(orch.stats().members_added, 4);
+    }
+
+    #[test]
+    fn test_update_nhg_weight_failure_keeps_state() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.create_nhg(
+            "wcmp".to_string(),
+            parse_members("10.0.0.1@Ethernet0!1,10.0.0.2@Ethernet4!2"),
+        )
+        .unwrap();
+        let (gm0, _) = member_weight(&orch, "wcmp", "Ethernet0").unwrap();
+
+        // Member removed underneath us (e.g. by a concurrent neighbor flap)
+        *callbacks.fail_weight_set.lock().unwrap() = Some(gm0);
+        let result = orch.update_nhg(
+            "wcmp",
+            parse_members("10.0.0.1@Ethernet0!9,10.0.0.2@Ethernet4!2"),
+        );
+        assert!(matches!(result, Err(NhgOrchError::SaiError(_))));
+        assert_eq!(member_weight(&orch, "wcmp", "Ethernet0"), Some((gm0, 1)));
+        assert_eq!(orch.stats().member_weight_updates, 0);
+    }
+
+    #[test]
+    fn test_update_nonexistent_nhg() {
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(Arc::new(MockCallbacks::new()));
+
+        let result = orch.update_nhg("missing", parse_members("10.0.0.1@Ethernet0!2"));
+        assert!(matches!(result, Err(NhgOrchError::NhgNotFound(_))));
+    }
+
+    fn synced_members(orch: &NhgOrch, name: &str) -> Vec<String> {
+        let mut members: Vec<String> = orch
+            .get_nhg_members(name)
+            .unwrap()
+            .iter()
+            .filter(|m| m.is_synced())
+            .map(|m| m.key.alias.clone())
+            .collect();
+        members.sort();
+        members
+    }
+
+    #[test]
+    fn test_member_withdrawn_and_restored_on_neighbor_flap() {
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        let publisher = Publisher::new();
+        orch.subscribe_next_hop_updates(publisher.subscribe());
+        let mac = MacAddress::from_str("00:11:22:33:44:55").unwrap();
+
+        orch.create_nhg(
+            "nhg1".to_string(),
+            parse_members("10.0.0.1@Ethernet0,10.0.0.2@Ethernet4"),
+        )
+        .unwrap();
+        assert_eq!(callbacks.member_weights.lock().unwrap().len(), 2);
+
+        publisher.publish(NextHopUpdate::unresolved("10.0.0.1", "Ethernet0"));
+        assert_eq!(orch.process_next_hop_updates(), 1);
+        assert_eq!(synced_members(&orch, "nhg1"), vec!["Ethernet4"]);
+        assert_eq!(orch.get_nhg_members("nhg1").unwrap().len(), 2);
+        assert_eq!(callbacks.member_weights.lock().unwrap().len(), 1);
+
+        // A group created while the next hop is down leaves it out
+        orch.create_nhg(
+            "nhg2".to_string(),
+            parse_members("10.0.0.1@Ethernet0,10.0.0.3@Ethernet8"),
+        )
+        .unwrap();
+        assert_eq!(synced_members(&orch, "nhg2"), vec!["Ethernet8"]);
+
+        // Repeated updates are idempotent
+        publisher.publish(NextHopUpdate::unresolved("10.0.0.1", "Ethernet0"));
+        assert_eq!(orch.process_next_hop_updates(), 0);
+
+        publisher.publish(NextHopUpdate::resolved("10.0.0.1", "Ethernet0", mac));
+        assert_eq!(orch.process_next_hop_updates(), 2);
+        assert_eq!(
+            synced_members(&orch, "nhg1"),
+            vec!["Ethernet0", "Ethernet4"]
+        );
+        assert_eq!(
+            synced_members(&orch, "nhg2"),
+            vec!["Ethernet0", "Ethernet8"]
+        );
+        assert_eq!(orch.stats().members_withdrawn, 1);
+        assert_eq!(orch.stats().members_restored, 2);
+
+        // Removing the group only removes members that are programmed
+        orch.remove_nhg("nhg1").unwrap();
+        orch.remove_nhg("nhg2").unwrap();
+        assert!(callbacks.member_weights.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_next_hop_updates_ignored_without_subscription() {
+        let mut orch = NhgOrch::new(NhgOrchConfig::default());
+        orch.set_callbacks(Arc::new(MockCallbacks::new()));
+        orch.create_nhg("nhg1".to_string(), parse_members("10.0.0.1@Ethernet0"))
+            .unwrap();
+
+        assert_eq!(orch.process_next_hop_updates(), 0);
+        assert_eq!(synced_members(&orch, "nhg1"), vec!["Ethernet0"]);
+    }
 }
@@ -36,6 +36,44 @@ impl NextHopKey {
             srv6_vpn_sid: None,
         }
     }
+
+    /// Returns the key with the weight cleared, identifying the next hop itself.
+    pub fn without_weight(&self) -> Self {
+        Self {
+            weight: 0,
+            ..self.clone()
+        }
+    }
+}
+
+impl FromStr for NextHopKey {
+    type Err = String;
+
+    /// Parses `ip@alias`, optionally followed by a `!weight` suffix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (nexthop, weight) = match s.split_once('!') {
+            Some((nexthop, weight_str)) => {
+                let weight = weight_str
+                    .trim()
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid weight: {}", weight_str))?;
+                if weight == 0 {
+                    return Err(format!("Weight must be non-zero: {}", s));
+                }
+                (nexthop, weight)
+            }
+            None => (s, 0),
+        };
+
+        let (ip_str, alias) = nexthop.split_once('@').unwrap_or((nexthop, ""));
+        let ip = IpAddress::from_str(ip_str.trim())
+            .map_err(|_| format!("Invalid IP address: {}", ip_str))?;
+
+        let mut key = Self::new(ip, alias.trim().to_string());
+        key.weight = weight;
+        Ok(key)
+    }
 }
 
 /// Next hop group key.
@@ -68,6 +106,43 @@ impl NextHopGroupKey {
     pub fn size(&self) -> usize {
         self.nexthops.len()
     }
+
+    /// Returns true if any next hop carries an explicit weight.
+    pub fn is_weighted(&self) -> bool {
+        self.nexthops.iter().any(|nh| nh.weight != 0)
+    }
+
+    /// Builds unsynced group members, one per next hop.
+    pub fn to_members(&self) -> Vec<NextHopGroupMember> {
+        self.nexthops
+            .iter()
+            .cloned()
+            .map(NextHopGroupMember::new)
+            .collect()
+    }
+}
+
+impl FromStr for NextHopGroupKey {
+    type Err = String;
+
+    /// Parses a comma-separated list of next hops, e.g.
+    /// `10.0.0.1@Ethernet0!4,10.0.0.2@Ethernet4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut key = Self::new();
+        if s.trim().is_empty() {
+            return Ok(key);
+        }
+
+        let mut seen = HashSet::new();
+        for part in s.split(',') {
+            let nh: NextHopKey = part.parse()?;
+            if !seen.insert(nh.without_weight()) {
+                return Err(format!("Duplicate next hop: {}", part.trim()));
+            }
+            key.add_nexthop(nh);
+        }
+        Ok(key)
+    }
 }
 
 impl Default for NextHopGroupKey {
@@ -82,14 +157,18 @@ pub struct NextHopGroupMember {
     pub key: NextHopKey,
     pub gm_id: RawSaiObjectId,
     pub nh_id: RawSaiObjectId,
+    /// SAI_NEXT_HOP_GROUP_MEMBER_ATTR_WEIGHT; 0 leaves the SAI default.
+    pub weight: u32,
 }
 
 impl NextHopGroupMember {
     pub fn new(key: NextHopKey) -> Self {
+        let weight = key.weight;
         Self {
             key,
             gm_id: 0,
             nh_id: 0,
+            weight,
         }
     }
 
@@ -164,6 +243,44 @@ mod tests {
         assert_eq!(key.size(), 1);
     }
 
+    #[test]
+    fn test_nexthop_key_parse_weight() {
+        let nh: NextHopKey = "10.0.0.1@Ethernet0!4".parse().unwrap();
+        assert_eq!(nh.ip_address, IpAddress::from_str("10.0.0.1").unwrap());
+        assert_eq!(nh.alias, "Ethernet0");
+        assert_eq!(nh.weight, 4);
+
+        let nh: NextHopKey = "10.0.0.2@Ethernet4".parse().unwrap();
+        assert_eq!(nh.weight, 0);
+        assert_eq!(NextHopGroupMember::new(nh).weight, 0);
+
+        assert!("10.0.0.1@Ethernet0!".parse::<NextHopKey>().is_err());
+        assert!("10.0.0.1@Ethernet0!abc".parse::<NextHopKey>().is_err());
+        assert!("10.0.0.1@Ethernet0!0".parse::<NextHopKey>().is_err());
+        assert!("bogus@Ethernet0".parse::<NextHopKey>().is_err());
+    }
+
+    #[test]
+    fn test_nhg_key_parse_mixed_weights() {
+        let key: NextHopGroupKey = "10.0.0.1@Ethernet0!4, 10.0.0.2@Ethernet4".parse().unwrap();
+        assert_eq!(key.size(), 2);
+        assert!(key.is_weighted());
+
+        let mut members = key.to_members();
+        members.sort_by(|a, b| a.key.alias.cmp(&b.key.alias));
+        assert_eq!(members[0].weight, 4);
+        assert_eq!(members[1].weight, 0);
+
+        let key: NextHopGroupKey = "10.0.0.1@Ethernet0,10.0.0.2@Ethernet4".parse().unwrap();
+        assert!(!key.is_weighted());
+
+        // Same next hop listed twice with different weights
+        assert!("10.0.0.1@Ethernet0!1,10.0.0.1@Ethernet0!2"
+            .parse::<NextHopGroupKey>()
+            .is_err());
+        assert!("".parse::<NextHopGroupKey>().unwrap().is_empty());
+    }
+
     #[test]
     fn test_nhg_entry() {
         let nhg = NextHopGroupEntry::new(NextHopGroupKey::new());
@@ -44,6 +44,7 @@ pub struct NvgreOrchStats {
     pub tunnels_removed: u64,
     pub map_entries_created: u64,
     pub map_entries_removed: u64,
+    pub map_entries_updated: u64,
 }
 
 pub trait NvgreOrchCallbacks: Send + Sync {
@@ -77,6 +78,8 @@ pub trait NvgreOrchCallbacks: Send + Sync {
         decap_map_id: RawSaiObjectId,
     ) -> Result<RawSaiObjectId, String>;
     fn remove_tunnel_map_entry(&self, oid: RawSaiObjectId) -> Result<(), String>;
+    /// Rewrites the VLAN of an existing map entry (SAI_TUNNEL_MAP_ENTRY_ATTR_VLAN_ID_VALUE).
+    fn set_tunnel_map_entry_vlan(&self, oid: RawSaiObjectId, vlan_id: u16) -> Result<(), String>;
     fn get_vlan_oid(&self, vlan_id: u16) -> Option<RawSaiObjectId>;
     fn get_underlay_rif(&self) -> RawSaiObjectId;
     fn get_virtual_router_id(&self) -> RawSaiObjectId;
@@ -110,6 +113,12 @@ impl NvgreTunnel {
     pub fn remove_map_entry(&mut self, name: &str) -> Option<NvgreTunnelMapEntry> {
         self.map_entries.remove(name)
     }
+
+    pub fn find_map_entry_by_vsid(&self, vsid: u32) -> Option<(&String, &NvgreTunnelMapEntry)> {
+        self.map_entries
+            .iter()
+            .find(|(_, entry)| entry.vsid == vsid)
+    }
 }
 
 pub struct NvgreOrch {
@@ -462,6 +471,94 @@ impl NvgreOrch {
         Ok(())
     }
 
+    /// Handles a NVGRE_TUNNEL_MAP SET.
+    ///
+    /// If the tunnel already maps `config.vsid`, the VLAN of that entry is
+    /// rewritten in place so traffic is not dropped by a delete+add; setting
+    /// the same VLAN again is a no-op. Otherwise the entry is created.
+    pub fn set_tunnel_map(&mut self, config: NvgreTunnelMapConfig) -> Result<(), NvgreOrchError> {
+        if config.validate_vsid().is_err() {
+            let err = NvgreOrchError::InvalidVsid(config.vsid);
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "NvgreOrch",
+                "set_tunnel_map"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(format!("{}/{}", config.tunnel_name, config.map_entry_name))
+            .with_object_type("nvgre_tunnel_map")
+            .with_error(err.to_string()));
+            return Err(err);
+        }
+
+        let existing = self
+            .tunnels
+            .get(&config.tunnel_name)
+            .and_then(|tunnel| tunnel.find_map_entry_by_vsid(config.vsid))
+            .map(|(name, entry)| (name.clone(), entry.clone()));
+        let (entry_name, entry) = match existing {
+            Some(existing) => existing,
+            None => return self.add_tunnel_map(config),
+        };
+
+        if entry.vlan_id == config.vlan_id {
+            return Ok(());
+        }
+
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or_else(|| NvgreOrchError::SaiError("No callbacks set".to_string()))?;
+
+        let result = if callbacks.get_vlan_oid(config.vlan_id).is_none() {
+            Err(NvgreOrchError::VlanNotFound(config.vlan_id))
+        } else {
+            callbacks
+                .set_tunnel_map_entry_vlan(entry.map_entry_id, config.vlan_id)
+                .map_err(NvgreOrchError::SaiError)
+        };
+        if let Err(err) = result {
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "NvgreOrch",
+                "set_tunnel_map"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(format!("{}/{}", config.tunnel_name, entry_name))
+            .with_object_type("nvgre_tunnel_map")
+            .with_error(err.to_string())
+            .with_details(serde_json::json!({
+                "vsid": config.vsid,
+                "old_vlan_id": entry.vlan_id,
+                "new_vlan_id": config.vlan_id,
+            })));
+            return Err(err);
+        }
+
+        if let Some(mapped) = self
+            .tunnels
+            .get_mut(&config.tunnel_name)
+            .and_then(|tunnel| tunnel.map_entries.get_mut(&entry_name))
+        {
+            mapped.vlan_id = config.vlan_id;
+        }
+        self.stats.map_entries_updated += 1;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "NvgreOrch", "set_tunnel_map")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("{}/{}", config.tunnel_name, entry_name))
+                .with_object_type("nvgre_tunnel_map")
+                .with_details(serde_json::json!({
+                    "vsid": config.vsid,
+                    "old_vlan_id": entry.vlan_id,
+                    "new_vlan_id": config.vlan_id,
+                }))
+        );
+
+        Ok(())
+    }
+
     pub fn remove_tunnel_map(
         &mut self,
         tunnel_name: &str,
@@ -659,6 +756,17 @@ mod tests {
             Ok(())
         }
 
+        fn set_tunnel_map_entry_vlan(
+            &self,
+            _oid: RawSaiObjectId,
+            _vlan_id: u16,
+        ) -> Result<(), String> {
+            if *self.fail_create_map_entry.lock().unwrap() {
+                return Err("Mock map entry update failure".to_string());
+            }
+            Ok(())
+        }
+
         fn get_vlan_oid(&self, vlan_id: u16) -> Option<RawSaiObjectId> {
             self.vlan_exists.lock().unwrap().get(&vlan_id).copied()
         }
@@ -1367,6 +1475,84 @@ mod tests {
         assert_eq!(stats.tunnels_removed, 0);
         assert_eq!(stats.map_entries_created, 0);
         assert_eq!(stats.map_entries_removed, 0);
+        assert_eq!(stats.map_entries_updated, 0);
+    }
+
+    // ========== In-place Update Tests ==========
+
+    #[test]
+    fn test_set_tunnel_map_updates_vlan_in_place() {
+        let mut orch = create_test_orch();
+        let tunnel_config = NvgreTunnelConfig::new("tunnel1".to_string(), test_ip_v4(10, 0, 0, 1));
+        orch.create_tunnel(tunnel_config).unwrap();
+
+        let map_config =
+            NvgreTunnelMapConfig::new("tunnel1".to_string(), "map1".to_string(), 100, 1000);
+        orch.set_tunnel_map(map_config).unwrap();
+        let oid = orch.get_tunnel("tunnel1").unwrap().map_entries["map1"].map_entry_id;
+
+        let map_config =
+            NvgreTunnelMapConfig::new("tunnel1".to_string(), "map1".to_string(), 200, 1000);
+        orch.set_tunnel_map(map_config).unwrap();
+
+        let entry = &orch.get_tunnel("tunnel1").unwrap().map_entries["map1"];
+        assert_eq!(entry.vlan_id, 200);
+        assert_eq!(entry.map_entry_id, oid);
+        assert_eq!(orch.stats().map_entries_created, 1);
+        assert_eq!(orch.stats().map_entries_removed, 0);
+        assert_eq!(orch.stats().map_entries_updated, 1);
+    }
+
+    #[test]
+    fn test_set_tunnel_map_same_vlan_is_noop() {
+        let mut orch = create_test_orch();
+        let tunnel_config = NvgreTunnelConfig::new("tunnel1".to_string(), test_ip_v4(10, 0, 0, 1));
+        orch.create_tunnel(tunnel_config).unwrap();
+
+        let map_config =
+            NvgreTunnelMapConfig::new("tunnel1".to_string(), "map1".to_string(), 100, 1000);
+        orch.set_tunnel_map(map_config.clone()).unwrap();
+        orch.set_tunnel_map(map_config).unwrap();
+
+        assert_eq!(orch.get_tunnel("tunnel1").unwrap().map_entries.len(), 1);
+        assert_eq!(orch.stats().map_entries_created, 1);
+        assert_eq!(orch.stats().map_entries_updated, 0);
+    }
+
+    #[test]
+    fn test_set_tunnel_map_update_to_missing_vlan() {
+        let mut orch = create_test_orch();
+        let tunnel_config = NvgreTunnelConfig::new("tunnel1".to_string(), test_ip_v4(10, 0, 0, 1));
+        orch.create_tunnel(tunnel_config).unwrap();
+
+        let map_config =
+            NvgreTunnelMapConfig::new("tunnel1".to_string(), "map1".to_string(), 100, 1000);
+        orch.set_tunnel_map(map_config).unwrap();
+
+        let map_config =
+            NvgreTunnelMapConfig::new("tunnel1".to_string(), "map1".to_string(), 999, 1000);
+        let result = orch.set_tunnel_map(map_config);
+        assert!(matches!(result, Err(NvgreOrchError::VlanNotFound(999))));
+
+        let entry = &orch.get_tunnel("tunnel1").unwrap().map_entries["map1"];
+        assert_eq!(entry.vlan_id, 100);
+        assert_eq!(orch.stats().map_entries_updated, 0);
+    }
+
+    #[test]
+    fn test_set_tunnel_map_enforces_vsid_bound() {
+        let mut orch = create_test_orch();
+        let tunnel_config = NvgreTunnelConfig::new("tunnel1".to_string(), test_ip_v4(10, 0, 0, 1));
+        orch.create_tunnel(tunnel_config).unwrap();
+
+        let map_config = NvgreTunnelMapConfig::new(
+            "tunnel1".to_string(),
+            "map1".to_string(),
+            100,
+            NVGRE_VSID_MAX_VALUE + 1,
+        );
+        let result = orch.set_tunnel_map(map_config);
+        assert!(matches!(result, Err(NvgreOrchError::InvalidVsid(_))));
     }
 
     #[test]
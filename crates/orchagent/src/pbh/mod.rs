@@ -7,6 +7,7 @@
 //! - Composite key (table_name, rule_name) for rule lookup
 //! - Validated priority and configuration
 //! - Structured packet action types
+//! - Rule edits tracked as explicit swap stages so an interrupted swap can be reconciled
 
 mod ffi;
 mod orch;
@@ -16,5 +17,5 @@ pub use ffi::{register_pbh_orch, unregister_pbh_orch};
 pub use orch::{PbhOrch, PbhOrchCallbacks, PbhOrchConfig, PbhOrchError, PbhOrchStats};
 pub use types::{
     PbhHashConfig, PbhHashEntry, PbhHashField, PbhPacketAction, PbhRuleConfig, PbhRuleEntry,
-    PbhStats, PbhTableConfig, PbhTableEntry,
+    PbhRuleField, PbhRuleSwap, PbhRuleSwapStage, PbhStats, PbhTableConfig, PbhTableEntry,
 };
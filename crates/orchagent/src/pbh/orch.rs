@@ -1,11 +1,16 @@
 //! Policy-Based Hashing orchestration logic.
 
-use super::types::{PbhHashEntry, PbhRuleEntry, PbhStats, PbhTableEntry};
+use super::types::{
+    PbhHashEntry, PbhRuleConfig, PbhRuleEntry, PbhRuleField, PbhRuleSwap, PbhRuleSwapStage,
+    PbhStats, PbhTableEntry, RawSaiObjectId,
+};
 use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
     audit_log,
 };
+use sonic_orch_common::TaskStatus;
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Error)]
@@ -33,9 +38,25 @@ pub struct PbhOrchConfig {
 pub struct PbhOrchStats {
     pub stats: PbhStats,
     pub errors: u64,
+    pub rules_updated_in_place: u64,
+    pub rules_swapped: u64,
 }
 
 pub trait PbhOrchCallbacks: Send + Sync {
+    fn create_rule(&self, rule: &PbhRuleEntry, priority: u32) -> Result<RawSaiObjectId, String>;
+    fn remove_rule(&self, rule_oid: RawSaiObjectId) -> Result<(), String>;
+    /// Applies one changed field of `config` to an installed rule.
+    fn set_rule_attribute(
+        &self,
+        rule_oid: RawSaiObjectId,
+        field: PbhRuleField,
+        config: &PbhRuleConfig,
+    ) -> Result<(), String>;
+    /// Returns true if the SAI supports setting `field` on an existing rule.
+    fn is_rule_attribute_settable(&self, field: PbhRuleField) -> bool;
+    /// Adds the rule's counter to the flex counter group.
+    fn bind_flow_counter(&self, rule: &PbhRuleEntry) -> Result<(), String>;
+    fn unbind_flow_counter(&self, rule: &PbhRuleEntry) -> Result<(), String>;
     fn on_hash_created(&self, hash: &PbhHashEntry);
     fn on_hash_removed(&self, hash_name: &str);
     fn on_table_created(&self, table: &PbhTableEntry);
@@ -50,6 +71,8 @@ pub struct PbhOrch {
     hashes: HashMap<String, PbhHashEntry>,
     tables: HashMap<String, PbhTableEntry>,
     rules: HashMap<(String, String), PbhRuleEntry>,
+    pending_swaps: HashMap<(String, String), PbhRuleSwap>,
+    callbacks: Option<Arc<dyn PbhOrchCallbacks>>,
 }
 
 impl PbhOrch {
@@ -60,9 +83,21 @@ impl PbhOrch {
             hashes: HashMap::new(),
             tables: HashMap::new(),
             rules: HashMap::new(),
+            pending_swaps: HashMap::new(),
+            callbacks: None,
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn PbhOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    fn callbacks(&self) -> Result<Arc<dyn PbhOrchCallbacks>, PbhOrchError> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| PbhOrchError::SaiError("No callbacks set".to_string()))
+    }
+
     pub fn get_hash(&self, name: &str) -> Option<&PbhHashEntry> {
         self.hashes.get(name)
     }
@@ -273,6 +308,255 @@ impl PbhOrch {
 
         Ok(())
     }
+
+    /// Swaps that were interrupted and still hold a shadow rule.
+    pub fn pending_swaps(&self) -> impl Iterator<Item = &PbhRuleSwap> {
+        self.pending_swaps.values()
+    }
+
+    /// Restores swap records, e.g. after a restart, so they can be reconciled.
+    pub fn restore_pending_swap(&mut self, swap: PbhRuleSwap) {
+        let key = (swap.table_name.clone(), swap.rule_name.clone());
+        self.pending_swaps.insert(key, swap);
+    }
+
+    /// Creates or updates a PBH_RULE.
+    ///
+    /// Changed fields are set in place when the SAI supports it for all of
+    /// them. Otherwise the rule is swapped: a clone of the original is
+    /// installed at a shadow priority, the original is removed and then
+    /// recreated with the new config, so traffic is always matched by one
+    /// of the two.
+    pub fn handle_rule_set(
+        &mut self,
+        table_name: &str,
+        rule_name: &str,
+        config: PbhRuleConfig,
+    ) -> Result<TaskStatus, PbhOrchError> {
+        let callbacks = self.callbacks()?;
+        let key = (table_name.to_string(), rule_name.to_string());
+        if self.pending_swaps.contains_key(&key) {
+            self.reconcile_swap(&key)?;
+        }
+
+        let Some(existing) = self.rules.get(&key).cloned() else {
+            let mut entry =
+                PbhRuleEntry::new(table_name.to_string(), rule_name.to_string(), config);
+            entry.sai_oid = self.install_rule(&callbacks, &entry)?;
+            self.stats.stats.rules_created += 1;
+            callbacks.on_rule_created(&entry);
+            self.rules.insert(key, entry);
+            return Ok(TaskStatus::Success);
+        };
+
+        let changed = existing.config.changed_fields(&config);
+        if changed.is_empty() {
+            self.rules.get_mut(&key).unwrap().config = config;
+            return Ok(TaskStatus::Success);
+        }
+
+        if changed
+            .iter()
+            .all(|field| callbacks.is_rule_attribute_settable(*field))
+        {
+            self.update_rule_in_place(&callbacks, existing, config, &changed)?;
+        } else {
+            self.swap_rule(&callbacks, existing, config)?;
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    pub fn handle_rule_del(
+        &mut self,
+        table_name: &str,
+        rule_name: &str,
+    ) -> Result<TaskStatus, PbhOrchError> {
+        let callbacks = self.callbacks()?;
+        let key = (table_name.to_string(), rule_name.to_string());
+        if self.pending_swaps.contains_key(&key) {
+            self.reconcile_swap(&key)?;
+        }
+        let Some(entry) = self.rules.get(&key).cloned() else {
+            return Ok(TaskStatus::Success);
+        };
+
+        if entry.config.flow_counter_enabled() {
+            let _ = callbacks.unbind_flow_counter(&entry);
+        }
+        if let Err(e) = callbacks.remove_rule(entry.sai_oid) {
+            self.stats.errors += 1;
+            return Err(PbhOrchError::SaiError(e));
+        }
+        self.rules.remove(&key);
+        self.stats.stats.rules_created = self.stats.stats.rules_created.saturating_sub(1);
+        callbacks.on_rule_removed(table_name, rule_name);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceDelete, "PbhOrch", "remove_pbh_rule")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("{}/{}", table_name, rule_name))
+                .with_object_type("pbh_rule")
+        );
+        Ok(TaskStatus::Success)
+    }
+
+    /// Completes or rolls back every interrupted swap. Returns how many were resolved.
+    pub fn reconcile_pending_swaps(&mut self) -> Result<usize, PbhOrchError> {
+        let keys: Vec<_> = self.pending_swaps.keys().cloned().collect();
+        for key in &keys {
+            self.reconcile_swap(key)?;
+        }
+        Ok(keys.len())
+    }
+
+    /// Creates a rule in SAI and binds its flow counter if enabled.
+    fn install_rule(
+        &mut self,
+        callbacks: &Arc<dyn PbhOrchCallbacks>,
+        entry: &PbhRuleEntry,
+    ) -> Result<RawSaiObjectId, PbhOrchError> {
+        let oid = callbacks
+            .create_rule(entry, entry.config.priority)
+            .map_err(|e| {
+                self.stats.errors += 1;
+                PbhOrchError::SaiError(e)
+            })?;
+        if entry.config.flow_counter_enabled() {
+            let mut bound = entry.clone();
+            bound.sai_oid = oid;
+            if let Err(e) = callbacks.bind_flow_counter(&bound) {
+                let _ = callbacks.remove_rule(oid);
+                self.stats.errors += 1;
+                return Err(PbhOrchError::SaiError(e));
+            }
+        }
+        Ok(oid)
+    }
+
+    fn update_rule_in_place(
+        &mut self,
+        callbacks: &Arc<dyn PbhOrchCallbacks>,
+        existing: PbhRuleEntry,
+        config: PbhRuleConfig,
+        changed: &[PbhRuleField],
+    ) -> Result<(), PbhOrchError> {
+        let key = (existing.table_name.clone(), existing.rule_name.clone());
+        let mut updated = existing.clone();
+        updated.config = config;
+
+        for field in changed {
+            if let Err(e) = callbacks.set_rule_attribute(existing.sai_oid, *field, &updated.config)
+            {
+                self.stats.errors += 1;
+                return Err(PbhOrchError::SaiError(e));
+            }
+            if *field == PbhRuleField::FlowCounter {
+                let result = if updated.config.flow_counter_enabled() {
+                    callbacks.bind_flow_counter(&updated)
+                } else {
+                    callbacks.unbind_flow_counter(&existing)
+                };
+                if let Err(e) = result {
+                    self.stats.errors += 1;
+                    return Err(PbhOrchError::SaiError(e));
+                }
+            }
+        }
+
+        // A partial failure above leaves the cached config untouched, so a
+        // retry recomputes the same changed fields and reapplies them.
+        self.rules.insert(key.clone(), updated);
+        self.stats.rules_updated_in_place += 1;
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "PbhOrch", "update_pbh_rule")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("{}/{}", key.0, key.1))
+                .with_object_type("pbh_rule")
+                .with_details(serde_json::json!({
+                    "mode": "in_place",
+                    "fields": changed.iter().map(|f| format!("{:?}", f)).collect::<Vec<_>>(),
+                }))
+        );
+        Ok(())
+    }
+
+    fn swap_rule(
+        &mut self,
+        callbacks: &Arc<dyn PbhOrchCallbacks>,
+        existing: PbhRuleEntry,
+        config: PbhRuleConfig,
+    ) -> Result<(), PbhOrchError> {
+        let key = (existing.table_name.clone(), existing.rule_name.clone());
+        let shadow_priority = existing.config.priority.saturating_add(1);
+        let shadow_oid = callbacks
+            .create_rule(&existing, shadow_priority)
+            .map_err(|e| {
+                self.stats.errors += 1;
+                PbhOrchError::SaiError(e)
+            })?;
+        self.pending_swaps.insert(
+            key.clone(),
+            PbhRuleSwap {
+                table_name: key.0.clone(),
+                rule_name: key.1.clone(),
+                shadow_oid,
+                shadow_priority,
+                new_config: config,
+                stage: PbhRuleSwapStage::ShadowInstalled,
+            },
+        );
+
+        if existing.config.flow_counter_enabled() {
+            let _ = callbacks.unbind_flow_counter(&existing);
+        }
+        if let Err(e) = callbacks.remove_rule(existing.sai_oid) {
+            self.stats.errors += 1;
+            return Err(PbhOrchError::SaiError(e));
+        }
+        self.pending_swaps.get_mut(&key).unwrap().stage = PbhRuleSwapStage::OriginalRemoved;
+
+        self.reconcile_swap(&key)?;
+        self.stats.rules_swapped += 1;
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "PbhOrch", "update_pbh_rule")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("{}/{}", key.0, key.1))
+                .with_object_type("pbh_rule")
+                .with_details(serde_json::json!({
+                    "mode": "swap",
+                    "shadow_priority": shadow_priority,
+                }))
+        );
+        Ok(())
+    }
+
+    /// Drives an interrupted swap to a consistent state.
+    ///
+    /// Before the original is removed the shadow is simply dropped, leaving
+    /// the old rule in place for the SET to be retried. After that the swap
+    /// is rolled forward: the rule is recreated with the new config and the
+    /// shadow removed.
+    fn reconcile_swap(&mut self, key: &(String, String)) -> Result<(), PbhOrchError> {
+        let callbacks = self.callbacks()?;
+        let Some(swap) = self.pending_swaps.get(key).cloned() else {
+            return Ok(());
+        };
+
+        if swap.stage == PbhRuleSwapStage::OriginalRemoved {
+            let mut entry =
+                PbhRuleEntry::new(key.0.clone(), key.1.clone(), swap.new_config.clone());
+            entry.sai_oid = self.install_rule(&callbacks, &entry)?;
+            self.rules.insert(key.clone(), entry);
+            self.pending_swaps.get_mut(key).unwrap().stage = PbhRuleSwapStage::NewInstalled;
+        }
+
+        if let Err(e) = callbacks.remove_rule(swap.shadow_oid) {
+            self.stats.errors += 1;
+            return Err(PbhOrchError::SaiError(e));
+        }
+        self.pending_swaps.remove(key);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -281,6 +565,119 @@ mod tests {
         PbhHashConfig, PbhHashField, PbhPacketAction, PbhRuleConfig, PbhTableConfig,
     };
     use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        /// Capability flag: whether rule attributes can be set in place.
+        settable: bool,
+        next_oid: Mutex<RawSaiObjectId>,
+        /// Installed rules: oid -> (priority, hash).
+        installed: Mutex<HashMap<RawSaiObjectId, (u32, String)>>,
+        counters: Mutex<HashSet<RawSaiObjectId>>,
+        ops: Mutex<Vec<String>>,
+        fail_create_hash: Mutex<Option<String>>,
+        /// Smallest number of installed rules seen after any removal.
+        min_installed: Mutex<Option<usize>>,
+    }
+
+    impl MockCallbacks {
+        fn new(settable: bool) -> Arc<Self> {
+            Arc::new(Self {
+                settable,
+                ..Default::default()
+            })
+        }
+
+        fn installed_count(&self) -> usize {
+            self.installed.lock().unwrap().len()
+        }
+    }
+
+    impl PbhOrchCallbacks for MockCallbacks {
+        fn create_rule(
+            &self,
+            rule: &PbhRuleEntry,
+            priority: u32,
+        ) -> Result<RawSaiObjectId, String> {
+            if self.fail_create_hash.lock().unwrap().as_deref() == Some(rule.config.hash.as_str()) {
+                return Err("create failed".to_string());
+            }
+            let mut oid = self.next_oid.lock().unwrap();
+            *oid += 1;
+            self.installed
+                .lock()
+                .unwrap()
+                .insert(*oid, (priority, rule.config.hash.clone()));
+            self.ops
+                .lock()
+                .unwrap()
+                .push(format!("create:{}", priority));
+            Ok(*oid)
+        }
+        fn remove_rule(&self, rule_oid: RawSaiObjectId) -> Result<(), String> {
+            let mut installed = self.installed.lock().unwrap();
+            installed.remove(&rule_oid);
+            let mut min = self.min_installed.lock().unwrap();
+            *min = Some(min.map_or(installed.len(), |m| m.min(installed.len())));
+            self.ops
+                .lock()
+                .unwrap()
+                .push(format!("remove:{}", rule_oid));
+            Ok(())
+        }
+        fn set_rule_attribute(
+            &self,
+            rule_oid: RawSaiObjectId,
+            field: PbhRuleField,
+            config: &PbhRuleConfig,
+        ) -> Result<(), String> {
+            if let Some(rule) = self.installed.lock().unwrap().get_mut(&rule_oid) {
+                rule.1 = config.hash.clone();
+            }
+            self.ops.lock().unwrap().push(format!("set:{:?}", field));
+            Ok(())
+        }
+        fn is_rule_attribute_settable(&self, _field: PbhRuleField) -> bool {
+            self.settable
+        }
+        fn bind_flow_counter(&self, rule: &PbhRuleEntry) -> Result<(), String> {
+            self.counters.lock().unwrap().insert(rule.sai_oid);
+            Ok(())
+        }
+        fn unbind_flow_counter(&self, rule: &PbhRuleEntry) -> Result<(), String> {
+            self.counters.lock().unwrap().remove(&rule.sai_oid);
+            Ok(())
+        }
+        fn on_hash_created(&self, _hash: &PbhHashEntry) {}
+        fn on_hash_removed(&self, _hash_name: &str) {}
+        fn on_table_created(&self, _table: &PbhTableEntry) {}
+        fn on_table_removed(&self, _table_name: &str) {}
+        fn on_rule_created(&self, _rule: &PbhRuleEntry) {}
+        fn on_rule_removed(&self, _table_name: &str, _rule_name: &str) {}
+    }
+
+    fn rule_config(hash: &str, flow_counter: bool) -> PbhRuleConfig {
+        PbhRuleConfig {
+            priority: 10,
+            gre_key: Some("0x2500/0xffffff00".to_string()),
+            ether_type: None,
+            ip_protocol: None,
+            ipv6_next_header: None,
+            l4_dst_port: None,
+            inner_ether_type: Some("0x0800".to_string()),
+            hash: hash.to_string(),
+            packet_action: PbhPacketAction::SetEcmpHash,
+            flow_counter: flow_counter.then(|| "ENABLED".to_string()),
+        }
+    }
+
+    fn orch_with(mock: &Arc<MockCallbacks>) -> PbhOrch {
+        let mut orch = PbhOrch::new(PbhOrchConfig::default());
+        orch.set_callbacks(mock.clone());
+        orch
+    }
 
     #[test]
     fn test_new_pbh_orch_with_default_config() {
@@ -405,4 +802,139 @@ mod tests {
         assert_eq!(orch1.config.enable_flow_counters, true);
         assert_eq!(orch2.config.enable_flow_counters, false);
     }
+
+    #[test]
+    fn test_rule_create_binds_flow_counter() {
+        let mock = MockCallbacks::new(true);
+        let mut orch = orch_with(&mock);
+
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v4", true))
+            .unwrap();
+        let oid = orch.get_rule("pbh_table", "nvgre").unwrap().sai_oid;
+        assert_eq!(mock.installed_count(), 1);
+        assert!(mock.counters.lock().unwrap().contains(&oid));
+
+        orch.handle_rule_del("pbh_table", "nvgre").unwrap();
+        assert_eq!(mock.installed_count(), 0);
+        assert!(mock.counters.lock().unwrap().is_empty());
+        assert!(orch.get_rule("pbh_table", "nvgre").is_none());
+    }
+
+    #[test]
+    fn test_rule_update_in_place() {
+        let mock = MockCallbacks::new(true);
+        let mut orch = orch_with(&mock);
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v4", false))
+            .unwrap();
+        let oid = orch.get_rule("pbh_table", "nvgre").unwrap().sai_oid;
+
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v6", true))
+            .unwrap();
+        let rule = orch.get_rule("pbh_table", "nvgre").unwrap();
+        assert_eq!(rule.sai_oid, oid);
+        assert_eq!(rule.config.hash, "inner_v6");
+        assert_eq!(mock.installed.lock().unwrap()[&oid].1, "inner_v6");
+        assert!(mock.counters.lock().unwrap().contains(&oid));
+        assert_eq!(orch.stats().rules_updated_in_place, 1);
+        assert_eq!(orch.stats().rules_swapped, 0);
+
+        // Disabling the counter unbinds it from the flex counter group.
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v6", false))
+            .unwrap();
+        assert!(mock.counters.lock().unwrap().is_empty());
+        assert_eq!(mock.installed_count(), 1);
+    }
+
+    #[test]
+    fn test_rule_update_swap_never_leaves_gap() {
+        let mock = MockCallbacks::new(false);
+        let mut orch = orch_with(&mock);
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v4", true))
+            .unwrap();
+        let old_oid = orch.get_rule("pbh_table", "nvgre").unwrap().sai_oid;
+        mock.ops.lock().unwrap().clear();
+
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v6", true))
+            .unwrap();
+        let rule = orch.get_rule("pbh_table", "nvgre").unwrap();
+        assert_ne!(rule.sai_oid, old_oid);
+        assert_eq!(
+            *mock.ops.lock().unwrap(),
+            vec![
+                "create:11".to_string(),
+                format!("remove:{}", old_oid),
+                "create:10".to_string(),
+                "remove:2".to_string(),
+            ]
+        );
+        assert_eq!(*mock.min_installed.lock().unwrap(), Some(1));
+        assert_eq!(mock.installed_count(), 1);
+        assert_eq!(
+            *mock.counters.lock().unwrap(),
+            HashSet::from([rule.sai_oid])
+        );
+        assert_eq!(orch.stats().rules_swapped, 1);
+        assert_eq!(orch.pending_swaps().count(), 0);
+    }
+
+    #[test]
+    fn test_interrupted_swap_is_rolled_forward() {
+        let mock = MockCallbacks::new(false);
+        let mut orch = orch_with(&mock);
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v4", false))
+            .unwrap();
+
+        *mock.fail_create_hash.lock().unwrap() = Some("inner_v6".to_string());
+        assert!(orch
+            .handle_rule_set("pbh_table", "nvgre", rule_config("inner_v6", false))
+            .is_err());
+
+        // Only the shadow clone is left matching traffic.
+        let swap = orch.pending_swaps().next().unwrap().clone();
+        assert_eq!(swap.stage, PbhRuleSwapStage::OriginalRemoved);
+        assert_eq!(mock.installed_count(), 1);
+        assert_eq!(
+            mock.installed.lock().unwrap()[&swap.shadow_oid],
+            (11, "inner_v4".to_string())
+        );
+
+        *mock.fail_create_hash.lock().unwrap() = None;
+        assert_eq!(orch.reconcile_pending_swaps().unwrap(), 1);
+        let rule = orch.get_rule("pbh_table", "nvgre").unwrap();
+        assert_eq!(rule.config.hash, "inner_v6");
+        assert_eq!(
+            mock.installed.lock().unwrap()[&rule.sai_oid],
+            (10, "inner_v6".to_string())
+        );
+        assert_eq!(mock.installed_count(), 1);
+        assert_eq!(orch.pending_swaps().count(), 0);
+    }
+
+    #[test]
+    fn test_restored_swap_before_removal_is_rolled_back() {
+        let mock = MockCallbacks::new(false);
+        let mut orch = orch_with(&mock);
+        orch.handle_rule_set("pbh_table", "nvgre", rule_config("inner_v4", false))
+            .unwrap();
+        let oid = orch.get_rule("pbh_table", "nvgre").unwrap().sai_oid;
+
+        // Simulate a restart after only the shadow was installed.
+        let shadow_oid = mock
+            .create_rule(orch.get_rule("pbh_table", "nvgre").unwrap(), 11)
+            .unwrap();
+        orch.restore_pending_swap(PbhRuleSwap {
+            table_name: "pbh_table".to_string(),
+            rule_name: "nvgre".to_string(),
+            shadow_oid,
+            shadow_priority: 11,
+            new_config: rule_config("inner_v6", false),
+            stage: PbhRuleSwapStage::ShadowInstalled,
+        });
+
+        orch.reconcile_pending_swaps().unwrap();
+        assert_eq!(mock.installed_count(), 1);
+        let rule = orch.get_rule("pbh_table", "nvgre").unwrap();
+        assert_eq!(rule.sai_oid, oid);
+        assert_eq!(rule.config.hash, "inner_v4");
+    }
 }
@@ -81,6 +81,63 @@ pub struct PbhRuleConfig {
     pub flow_counter: Option<String>,
 }
 
+impl PbhRuleConfig {
+    pub fn flow_counter_enabled(&self) -> bool {
+        self.flow_counter.as_deref() == Some("ENABLED")
+    }
+
+    /// Lists the fields that differ between this config and `other`.
+    pub fn changed_fields(&self, other: &PbhRuleConfig) -> Vec<PbhRuleField> {
+        let mut changed = Vec::new();
+        if self.priority != other.priority {
+            changed.push(PbhRuleField::Priority);
+        }
+        if self.gre_key != other.gre_key {
+            changed.push(PbhRuleField::GreKey);
+        }
+        if self.ether_type != other.ether_type {
+            changed.push(PbhRuleField::EtherType);
+        }
+        if self.ip_protocol != other.ip_protocol {
+            changed.push(PbhRuleField::IpProtocol);
+        }
+        if self.ipv6_next_header != other.ipv6_next_header {
+            changed.push(PbhRuleField::Ipv6NextHeader);
+        }
+        if self.l4_dst_port != other.l4_dst_port {
+            changed.push(PbhRuleField::L4DstPort);
+        }
+        if self.inner_ether_type != other.inner_ether_type {
+            changed.push(PbhRuleField::InnerEtherType);
+        }
+        if self.hash != other.hash {
+            changed.push(PbhRuleField::Hash);
+        }
+        if self.packet_action != other.packet_action {
+            changed.push(PbhRuleField::PacketAction);
+        }
+        if self.flow_counter_enabled() != other.flow_counter_enabled() {
+            changed.push(PbhRuleField::FlowCounter);
+        }
+        changed
+    }
+}
+
+/// PBH_RULE fields, used to query per-field update capability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PbhRuleField {
+    Priority,
+    GreKey,
+    EtherType,
+    IpProtocol,
+    Ipv6NextHeader,
+    L4DstPort,
+    InnerEtherType,
+    Hash,
+    PacketAction,
+    FlowCounter,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PbhPacketAction {
     SetEcmpHash,
@@ -106,9 +163,72 @@ impl PbhRuleEntry {
     }
 }
 
+/// Last completed step of a rule swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PbhRuleSwapStage {
+    /// Shadow clone installed; the original rule is still in place.
+    ShadowInstalled,
+    /// Original removed; traffic is matched by the shadow only.
+    OriginalRemoved,
+    /// Rule recreated with the new config; the shadow is still installed.
+    NewInstalled,
+}
+
+/// In-flight replacement of a rule whose changed fields cannot be set in place.
+///
+/// Kept until the shadow rule is gone so an interrupted swap can be reconciled.
+#[derive(Debug, Clone)]
+pub struct PbhRuleSwap {
+    pub table_name: String,
+    pub rule_name: String,
+    pub shadow_oid: RawSaiObjectId,
+    pub shadow_priority: u32,
+    pub new_config: PbhRuleConfig,
+    pub stage: PbhRuleSwapStage,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PbhStats {
     pub hashes_created: u64,
     pub tables_created: u64,
     pub rules_created: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_config() -> PbhRuleConfig {
+        PbhRuleConfig {
+            priority: 1,
+            gre_key: Some("0x2500/0xffffff00".to_string()),
+            ether_type: None,
+            ip_protocol: None,
+            ipv6_next_header: None,
+            l4_dst_port: None,
+            inner_ether_type: Some("0x86dd".to_string()),
+            hash: "inner_v6_hash".to_string(),
+            packet_action: PbhPacketAction::SetEcmpHash,
+            flow_counter: None,
+        }
+    }
+
+    #[test]
+    fn test_changed_fields() {
+        let old = rule_config();
+        assert!(old.changed_fields(&old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.hash = "inner_v4_hash".to_string();
+        new.flow_counter = Some("ENABLED".to_string());
+        assert_eq!(
+            old.changed_fields(&new),
+            vec![PbhRuleField::Hash, PbhRuleField::FlowCounter]
+        );
+
+        // DISABLED and unset are the same counter state.
+        let mut disabled = old.clone();
+        disabled.flow_counter = Some("DISABLED".to_string());
+        assert!(old.changed_fields(&disabled).is_empty());
+    }
+}
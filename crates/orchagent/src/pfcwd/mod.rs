@@ -23,6 +23,7 @@ mod types;
 pub use ffi::{register_pfcwd_orch, unregister_pfcwd_orch};
 pub use orch::{PfcWdOrch, PfcWdOrchCallbacks, PfcWdOrchConfig, PfcWdOrchError, PfcWdOrchStats};
 pub use types::{
-    DetectionTime, PfcWdAction, PfcWdConfig, PfcWdEntry, PfcWdHwStats, PfcWdQueueEntry, PfcWdStats,
+    DetectionTime, PfcWdAction, PfcWdConfig, PfcWdEntry, PfcWdHwStats, PfcWdQueueCounters,
+    PfcWdQueueEntry, PfcWdQueueMonitor, PfcWdQueueSample, PfcWdQueueStatus, PfcWdStats,
     RestorationTime,
 };
@@ -1,7 +1,8 @@
 //! PFC Watchdog orchestration logic.
 
 use super::types::{
-    DetectionTime, PfcWdAction, PfcWdConfig, PfcWdEntry, PfcWdStats, RestorationTime,
+    DetectionTime, PfcWdAction, PfcWdConfig, PfcWdEntry, PfcWdQueueSample, PfcWdQueueStatus,
+    PfcWdStats, RestorationTime,
 };
 use crate::{
     audit::{AuditCategory, AuditOutcome, AuditRecord},
@@ -10,6 +11,7 @@ use crate::{
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Error)]
@@ -35,6 +37,7 @@ pub struct PfcWdOrchStats {
     pub queues_unregistered: u64,
     pub storms_detected: u64,
     pub storms_restored: u64,
+    pub action_failures: u64,
 }
 
 pub trait PfcWdOrchCallbacks: Send + Sync {
@@ -42,6 +45,20 @@ pub trait PfcWdOrchCallbacks: Send + Sync {
     fn remove_watchdog(&self, wd_id: RawSaiObjectId) -> Result<(), String>;
     fn start_watchdog(&self, wd_id: RawSaiObjectId) -> Result<(), String>;
     fn stop_watchdog(&self, wd_id: RawSaiObjectId) -> Result<(), String>;
+    /// Reads the PFC and packet counters of a queue.
+    fn read_queue_sample(&self, queue_name: &str) -> Option<PfcWdQueueSample>;
+    /// Binds a zero-buffer profile to the queue so its packets are dropped.
+    fn install_zero_buffer_profile(&self, queue_name: &str) -> Result<(), String>;
+    /// Restores the queue's original buffer profile.
+    fn remove_zero_buffer_profile(&self, queue_name: &str) -> Result<(), String>;
+    /// Installs an ACL rule enforcing `action` on the queue's priority.
+    fn install_action_acl(&self, queue_name: &str, action: PfcWdAction) -> Result<(), String>;
+    /// Removes the ACL rule installed by `install_action_acl`.
+    fn remove_action_acl(&self, queue_name: &str, action: PfcWdAction) -> Result<(), String>;
+    /// Writes PFC_WD fields of the queue to COUNTERS_DB.
+    fn write_counters(&self, queue_name: &str, fields: &[(String, String)]);
+    /// Removes the queue's PFC_WD fields from COUNTERS_DB.
+    fn remove_counters(&self, queue_name: &str);
 }
 
 pub struct PfcWdOrch {
@@ -49,6 +66,7 @@ pub struct PfcWdOrch {
     stats: PfcWdOrchStats,
     callbacks: Option<Arc<dyn PfcWdOrchCallbacks>>,
     queues: HashMap<String, PfcWdEntry>,
+    big_red_switch: bool,
 }
 
 impl PfcWdOrch {
@@ -58,6 +76,7 @@ impl PfcWdOrch {
             stats: PfcWdOrchStats::default(),
             callbacks: None,
             queues: HashMap::new(),
+            big_red_switch: false,
         }
     }
 
@@ -116,6 +135,7 @@ impl PfcWdOrch {
         };
 
         let entry = PfcWdEntry::from_config(config.clone(), wd_id);
+        callbacks.write_counters(&config.queue_name, &entry.monitor.counters.fields());
         self.queues.insert(config.queue_name.clone(), entry);
         self.stats.queues_registered += 1;
 
@@ -125,7 +145,7 @@ impl PfcWdOrch {
             "set_queue_action"
         )
         .with_outcome(AuditOutcome::Success)
-        .with_object_id(config.queue_name)
+        .with_object_id(config.queue_name.clone())
         .with_object_type("pfcwd_queue")
         .with_details(serde_json::json!({
             "action": format!("{:?}", config.action),
@@ -133,6 +153,10 @@ impl PfcWdOrch {
             "restoration_time_ms": config.restoration_time.value(),
         })));
 
+        if self.big_red_switch {
+            let _ = self.storm_queue(&config.queue_name, PfcWdAction::Drop);
+        }
+
         Ok(())
     }
 
@@ -159,6 +183,22 @@ impl PfcWdOrch {
             .as_ref()
             .ok_or_else(|| PfcWdOrchError::InvalidConfig("No callbacks set".to_string()))?;
 
+        if let Some(action) = entry.monitor.installed_action {
+            if let Err(e) = Self::remove_action(callbacks.as_ref(), queue_name, action) {
+                self.stats.action_failures += 1;
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceDelete,
+                    "PfcWdOrch",
+                    "remove_storm_action"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(queue_name)
+                .with_object_type("pfcwd_queue")
+                .with_error(e));
+            }
+        }
+        callbacks.remove_counters(queue_name);
+
         if let Err(e) = callbacks.remove_watchdog(entry.watchdog_id) {
             let err = PfcWdOrchError::SaiError(e);
             audit_log!(AuditRecord::new(
@@ -227,48 +267,269 @@ impl PfcWdOrch {
         Ok(())
     }
 
+    /// Handles a storm notification for a queue.
+    ///
+    /// Applies the queue's configured action; a queue that is already
+    /// stormed is left alone.
     pub fn handle_storm_detected(&mut self, queue_name: &str) {
-        if let Some(entry) = self.queues.get_mut(queue_name) {
-            entry.storm_detected = true;
-            self.stats.storms_detected += 1;
+        let Some(action) = self.queues.get(queue_name).map(|entry| entry.action) else {
+            return;
+        };
+        let _ = self.storm_queue(queue_name, action);
+    }
 
-            audit_log!(AuditRecord::new(
-                AuditCategory::ResourceModify,
-                "PfcWdOrch",
-                "update_detection_time"
-            )
-            .with_outcome(AuditOutcome::Success)
-            .with_object_id(queue_name)
-            .with_object_type("pfcwd_queue")
-            .with_details(serde_json::json!({
-                "event": "storm_detected",
-                "detection_time_ms": entry.detection_time.value(),
-            })));
+    /// Handles a restoration notification for a queue.
+    pub fn handle_storm_restored(&mut self, queue_name: &str) {
+        if self.big_red_switch {
+            return;
         }
+        let _ = self.restore_queue(queue_name);
     }
 
-    pub fn handle_storm_restored(&mut self, queue_name: &str) {
-        if let Some(entry) = self.queues.get_mut(queue_name) {
-            entry.storm_detected = false;
-            self.stats.storms_restored += 1;
+    pub fn is_big_red_switch_enabled(&self) -> bool {
+        self.big_red_switch
+    }
+
+    pub fn queue_status(&self, queue_name: &str) -> Option<PfcWdQueueStatus> {
+        self.queues
+            .get(queue_name)
+            .map(|entry| entry.monitor.status())
+    }
+
+    /// Enables or disables BIG_RED_SWITCH mode.
+    ///
+    /// Enabling it storms every registered queue with the drop action,
+    /// regardless of PFC activity, and suspends detection. Disabling it
+    /// restores every stormed queue and restarts detection from a fresh
+    /// baseline.
+    pub fn set_big_red_switch(&mut self, enabled: bool) -> Result<(), PfcWdOrchError> {
+        if enabled == self.big_red_switch {
+            return Ok(());
+        }
+        self.big_red_switch = enabled;
+
+        let mut names: Vec<String> = self.queues.keys().cloned().collect();
+        names.sort();
+        let mut result = Ok(());
+        for name in &names {
+            let outcome = if enabled {
+                self.storm_queue(name, PfcWdAction::Drop)
+            } else {
+                self.restore_queue(name).map(|_| {
+                    if let Some(entry) = self.queues.get_mut(name) {
+                        entry.monitor.reset_baseline();
+                    }
+                })
+            };
+            if let Err(e) = outcome {
+                result = Err(e);
+            }
+        }
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "PfcWdOrch",
+            "set_big_red_switch"
+        )
+        .with_outcome(if result.is_ok() {
+            AuditOutcome::Success
+        } else {
+            AuditOutcome::Failure
+        })
+        .with_object_id("GLOBAL")
+        .with_object_type("pfcwd_global")
+        .with_details(serde_json::json!({
+            "big_red_switch": enabled,
+            "queues": names.len(),
+        })));
+
+        result
+    }
+
+    /// Samples every enabled queue and advances its state machine.
+    ///
+    /// Meant to be called every `poll_interval_ms`; `now` is the sample
+    /// time. Detection is suspended while BIG_RED_SWITCH is enabled.
+    pub fn poll_queues(&mut self, now: Instant) {
+        if self.big_red_switch {
+            return;
+        }
+        let Some(callbacks) = self.callbacks.clone() else {
+            return;
+        };
+
+        let mut names: Vec<String> = self
+            .queues
+            .iter()
+            .filter(|(_, entry)| entry.enabled)
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+
+        for name in names {
+            let Some(sample) = callbacks.read_queue_sample(&name) else {
+                continue;
+            };
+            let Some(entry) = self.queues.get_mut(&name) else {
+                continue;
+            };
+            let was_stormed = entry.monitor.status() == PfcWdQueueStatus::Stormed;
+            let transition = entry.monitor.observe(
+                now,
+                sample,
+                entry.detection_time.as_duration(),
+                entry.restoration_time.as_duration(),
+            );
+            let action = entry.action;
+
+            match transition {
+                Some(PfcWdQueueStatus::Stormed) => {
+                    let _ = self.storm_queue(&name, action);
+                }
+                Some(PfcWdQueueStatus::Operational) => {
+                    let _ = self.restore_queue(&name);
+                }
+                None if was_stormed => {
+                    callbacks.write_counters(&name, &entry.monitor.counters.fields());
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn install_action(
+        callbacks: &dyn PfcWdOrchCallbacks,
+        queue_name: &str,
+        action: PfcWdAction,
+    ) -> Result<(), String> {
+        match action {
+            PfcWdAction::Drop => callbacks.install_zero_buffer_profile(queue_name),
+            PfcWdAction::Forward => callbacks.install_action_acl(queue_name, action),
+            PfcWdAction::Alert | PfcWdAction::Unknown => Ok(()),
+        }
+    }
+
+    fn remove_action(
+        callbacks: &dyn PfcWdOrchCallbacks,
+        queue_name: &str,
+        action: PfcWdAction,
+    ) -> Result<(), String> {
+        match action {
+            PfcWdAction::Drop => callbacks.remove_zero_buffer_profile(queue_name),
+            PfcWdAction::Forward => callbacks.remove_action_acl(queue_name, action),
+            PfcWdAction::Alert | PfcWdAction::Unknown => Ok(()),
+        }
+    }
+
+    /// Moves a queue into the stormed state and installs `action`.
+    ///
+    /// If the action cannot be installed the queue stays operational, so the
+    /// next poll that still sees the storm retries.
+    fn storm_queue(&mut self, queue_name: &str, action: PfcWdAction) -> Result<(), PfcWdOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| PfcWdOrchError::InvalidConfig("No callbacks set".to_string()))?,
+        );
+        let entry = self
+            .queues
+            .get_mut(queue_name)
+            .ok_or_else(|| PfcWdOrchError::QueueNotFound(queue_name.to_string()))?;
+        if entry.monitor.status() == PfcWdQueueStatus::Stormed {
+            return Ok(());
+        }
 
+        if let Err(e) = Self::install_action(callbacks.as_ref(), queue_name, action) {
+            self.stats.action_failures += 1;
+            let err = PfcWdOrchError::SaiError(e);
             audit_log!(AuditRecord::new(
                 AuditCategory::ResourceModify,
                 "PfcWdOrch",
-                "update_restoration_time"
+                "storm_detected"
             )
-            .with_outcome(AuditOutcome::Success)
+            .with_outcome(AuditOutcome::Failure)
             .with_object_id(queue_name)
             .with_object_type("pfcwd_queue")
-            .with_details(serde_json::json!({
-                "event": "storm_restored",
-                "restoration_time_ms": entry.restoration_time.value(),
-            })));
+            .with_error(err.to_string()));
+            return Err(err);
         }
+
+        entry.monitor.enter_storm(action);
+        entry.storm_detected = true;
+        callbacks.write_counters(queue_name, &entry.monitor.counters.fields());
+        self.stats.storms_detected += 1;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "PfcWdOrch", "storm_detected")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(queue_name)
+                .with_object_type("pfcwd_queue")
+                .with_details(serde_json::json!({
+                    "event": "storm_detected",
+                    "action": action.as_str(),
+                    "detection_time_ms": entry.detection_time.value(),
+                    "big_red_switch": self.big_red_switch,
+                }))
+        );
+
+        Ok(())
+    }
+
+    /// Moves a stormed queue back to operational and removes its action.
+    fn restore_queue(&mut self, queue_name: &str) -> Result<(), PfcWdOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| PfcWdOrchError::InvalidConfig("No callbacks set".to_string()))?,
+        );
+        let entry = self
+            .queues
+            .get_mut(queue_name)
+            .ok_or_else(|| PfcWdOrchError::QueueNotFound(queue_name.to_string()))?;
+        if entry.monitor.status() != PfcWdQueueStatus::Stormed {
+            return Ok(());
+        }
+
+        if let Some(action) = entry.monitor.installed_action {
+            if let Err(e) = Self::remove_action(callbacks.as_ref(), queue_name, action) {
+                self.stats.action_failures += 1;
+                let err = PfcWdOrchError::SaiError(e);
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "PfcWdOrch",
+                    "storm_restored"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(queue_name)
+                .with_object_type("pfcwd_queue")
+                .with_error(err.to_string()));
+                return Err(err);
+            }
+        }
+
+        let action = entry.monitor.exit_storm();
+        entry.storm_detected = false;
+        callbacks.write_counters(queue_name, &entry.monitor.counters.fields());
+        self.stats.storms_restored += 1;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "PfcWdOrch", "storm_restored")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(queue_name)
+                .with_object_type("pfcwd_queue")
+                .with_details(serde_json::json!({
+                    "event": "storm_restored",
+                    "action": action.map(|a| a.as_str()),
+                    "restoration_time_ms": entry.restoration_time.value(),
+                    "tx_drop_pkt_last": entry.monitor.counters.last.tx_drop_pkt,
+                }))
+        );
+
+        Ok(())
     }
 
     pub fn get_hw_stats(&self, queue_name: &str) -> Option<serde_json::Value> {
-        if let Some(_entry) = self.queues.get(queue_name) {
+        if let Some(entry) = self.queues.get(queue_name) {
             audit_log!(
                 AuditRecord::new(AuditCategory::AdminAction, "PfcWdOrch", "get_hw_stats")
                     .with_outcome(AuditOutcome::Success)
@@ -282,6 +543,11 @@ impl PfcWdOrch {
             );
 
             return Some(serde_json::json!({
+                "status": entry.monitor.status().as_str(),
+                "deadlock_detected": entry.monitor.counters.deadlock_detected,
+                "deadlock_restored": entry.monitor.counters.deadlock_restored,
+                "tx_drop_pkt": entry.monitor.counters.total.tx_drop_pkt,
+                "rx_drop_pkt": entry.monitor.counters.total.rx_drop_pkt,
                 "queues_registered": self.stats.queues_registered,
                 "queues_unregistered": self.stats.queues_unregistered,
                 "storms_detected": self.stats.storms_detected,
@@ -295,6 +561,9 @@ impl PfcWdOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pfcwd::types::PfcWdHwStats;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     struct MockCallbacks;
     impl PfcWdOrchCallbacks for MockCallbacks {
@@ -310,6 +579,27 @@ mod tests {
         fn stop_watchdog(&self, _wd_id: RawSaiObjectId) -> Result<(), String> {
             Ok(())
         }
+        fn read_queue_sample(&self, _queue_name: &str) -> Option<PfcWdQueueSample> {
+            None
+        }
+        fn install_zero_buffer_profile(&self, _queue_name: &str) -> Result<(), String> {
+            Ok(())
+        }
+        fn remove_zero_buffer_profile(&self, _queue_name: &str) -> Result<(), String> {
+            Ok(())
+        }
+        fn install_action_acl(
+            &self,
+            _queue_name: &str,
+            _action: PfcWdAction,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+        fn remove_action_acl(&self, _queue_name: &str, _action: PfcWdAction) -> Result<(), String> {
+            Ok(())
+        }
+        fn write_counters(&self, _queue_name: &str, _fields: &[(String, String)]) {}
+        fn remove_counters(&self, _queue_name: &str) {}
     }
 
     #[test]
@@ -929,4 +1219,354 @@ mod tests {
         assert_eq!(orch.stats().queues_registered, 5);
         assert_eq!(orch.stats().queues_unregistered, 5);
     }
+
+    // Watchdog Engine Tests
+
+    #[derive(Default)]
+    struct EngineCallbacks {
+        samples: Mutex<HashMap<String, PfcWdQueueSample>>,
+        actions: Mutex<Vec<String>>,
+        counters: Mutex<HashMap<String, Vec<(String, String)>>>,
+        fail_install: Mutex<bool>,
+    }
+
+    impl EngineCallbacks {
+        fn set_sample(&self, queue_name: &str, pfc_rx_frames: u64, tx_drop_pkt: u64) {
+            self.samples.lock().unwrap().insert(
+                queue_name.to_string(),
+                PfcWdQueueSample {
+                    pfc_rx_frames,
+                    hw: PfcWdHwStats {
+                        tx_drop_pkt,
+                        ..Default::default()
+                    },
+                },
+            );
+        }
+
+        fn actions(&self) -> Vec<String> {
+            self.actions.lock().unwrap().clone()
+        }
+
+        fn counter(&self, queue_name: &str, field: &str) -> Option<String> {
+            self.counters
+                .lock()
+                .unwrap()
+                .get(queue_name)?
+                .iter()
+                .find(|(name, _)| name == field)
+                .map(|(_, value)| value.clone())
+        }
+
+        fn record(&self, event: String) -> Result<(), String> {
+            if event.starts_with("install") && *self.fail_install.lock().unwrap() {
+                return Err("install failed".to_string());
+            }
+            self.actions.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    impl PfcWdOrchCallbacks for EngineCallbacks {
+        fn create_watchdog(&self, _config: &PfcWdConfig) -> Result<RawSaiObjectId, String> {
+            Ok(0x2000)
+        }
+        fn remove_watchdog(&self, _wd_id: RawSaiObjectId) -> Result<(), String> {
+            Ok(())
+        }
+        fn start_watchdog(&self, _wd_id: RawSaiObjectId) -> Result<(), String> {
+            Ok(())
+        }
+        fn stop_watchdog(&self, _wd_id: RawSaiObjectId) -> Result<(), String> {
+            Ok(())
+        }
+        fn read_queue_sample(&self, queue_name: &str) -> Option<PfcWdQueueSample> {
+            self.samples.lock().unwrap().get(queue_name).copied()
+        }
+        fn install_zero_buffer_profile(&self, queue_name: &str) -> Result<(), String> {
+            self.record(format!("install_zero_buffer {}", queue_name))
+        }
+        fn remove_zero_buffer_profile(&self, queue_name: &str) -> Result<(), String> {
+            self.record(format!("remove_zero_buffer {}", queue_name))
+        }
+        fn install_action_acl(&self, queue_name: &str, action: PfcWdAction) -> Result<(), String> {
+            self.record(format!("install_acl {} {}", queue_name, action.as_str()))
+        }
+        fn remove_action_acl(&self, queue_name: &str, action: PfcWdAction) -> Result<(), String> {
+            self.record(format!("remove_acl {} {}", queue_name, action.as_str()))
+        }
+        fn write_counters(&self, queue_name: &str, fields: &[(String, String)]) {
+            self.counters
+                .lock()
+                .unwrap()
+                .insert(queue_name.to_string(), fields.to_vec());
+        }
+        fn remove_counters(&self, queue_name: &str) {
+            self.counters.lock().unwrap().remove(queue_name);
+        }
+    }
+
+    fn engine_orch(queues: &[(&str, PfcWdAction)]) -> (PfcWdOrch, Arc<EngineCallbacks>, Instant) {
+        let callbacks = Arc::new(EngineCallbacks::default());
+        let mut orch = PfcWdOrch::new(PfcWdOrchConfig {
+            poll_interval_ms: 100,
+        });
+        orch.set_callbacks(callbacks.clone());
+        for (name, action) in queues {
+            orch.register_queue(PfcWdConfig::new(
+                name.to_string(),
+                *action,
+                DetectionTime::new(200).unwrap(),
+                RestorationTime::new(400).unwrap(),
+            ))
+            .unwrap();
+            orch.start_watchdog(name).unwrap();
+            callbacks.set_sample(name, 0, 0);
+        }
+        (orch, callbacks, Instant::now())
+    }
+
+    fn ms(start: Instant, ms: u64) -> Instant {
+        start + Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_engine_detect_storm_restore_drop() {
+        let (mut orch, callbacks, start) = engine_orch(&[("Ethernet0:3", PfcWdAction::Drop)]);
+        assert_eq!(
+            callbacks.counter("Ethernet0:3", "PFC_WD_STATUS").as_deref(),
+            Some("operational")
+        );
+
+        // Continuous PFC for the detection time
+        orch.poll_queues(ms(start, 0));
+        callbacks.set_sample("Ethernet0:3", 100, 0);
+        orch.poll_queues(ms(start, 100));
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Operational)
+        );
+        callbacks.set_sample("Ethernet0:3", 200, 0);
+        orch.poll_queues(ms(start, 200));
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        assert_eq!(callbacks.actions(), vec!["install_zero_buffer Ethernet0:3"]);
+
+        // Storm continues; dropped packets are counted
+        callbacks.set_sample("Ethernet0:3", 300, 40);
+        orch.poll_queues(ms(start, 300));
+        assert_eq!(
+            callbacks
+                .counter("Ethernet0:3", "PFC_WD_QUEUE_STATS_TX_DROPPED_PACKETS")
+                .as_deref(),
+            Some("40")
+        );
+
+        // Storm-free for the restoration time
+        for t in [400, 500, 600] {
+            orch.poll_queues(ms(start, t));
+            assert_eq!(
+                orch.queue_status("Ethernet0:3"),
+                Some(PfcWdQueueStatus::Stormed)
+            );
+        }
+        orch.poll_queues(ms(start, 700));
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Operational)
+        );
+        orch.poll_queues(ms(start, 800));
+
+        assert_eq!(
+            callbacks.actions(),
+            vec![
+                "install_zero_buffer Ethernet0:3",
+                "remove_zero_buffer Ethernet0:3"
+            ]
+        );
+        assert_eq!(
+            callbacks.counter("Ethernet0:3", "PFC_WD_STATUS").as_deref(),
+            Some("operational")
+        );
+        assert_eq!(
+            callbacks
+                .counter("Ethernet0:3", "PFC_WD_QUEUE_STATS_DEADLOCK_DETECTED")
+                .as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            callbacks
+                .counter("Ethernet0:3", "PFC_WD_QUEUE_STATS_DEADLOCK_RESTORED")
+                .as_deref(),
+            Some("1")
+        );
+        assert_eq!(orch.stats().storms_detected, 1);
+        assert_eq!(orch.stats().storms_restored, 1);
+    }
+
+    #[test]
+    fn test_engine_forward_and_alert_actions() {
+        let (mut orch, callbacks, start) = engine_orch(&[
+            ("Ethernet4:3", PfcWdAction::Forward),
+            ("Ethernet8:3", PfcWdAction::Alert),
+        ]);
+
+        orch.poll_queues(ms(start, 0));
+        for (i, t) in [100, 200].into_iter().enumerate() {
+            let frames = (i as u64 + 1) * 10;
+            callbacks.set_sample("Ethernet4:3", frames, 0);
+            callbacks.set_sample("Ethernet8:3", frames, 0);
+            orch.poll_queues(ms(start, t));
+        }
+        assert_eq!(
+            orch.queue_status("Ethernet4:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        assert_eq!(
+            orch.queue_status("Ethernet8:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        // Alert installs nothing in hardware
+        assert_eq!(callbacks.actions(), vec!["install_acl Ethernet4:3 forward"]);
+
+        orch.poll_queues(ms(start, 700));
+        assert_eq!(
+            callbacks.actions(),
+            vec![
+                "install_acl Ethernet4:3 forward",
+                "remove_acl Ethernet4:3 forward"
+            ]
+        );
+        assert_eq!(
+            orch.queue_status("Ethernet8:3"),
+            Some(PfcWdQueueStatus::Operational)
+        );
+    }
+
+    #[test]
+    fn test_engine_ignores_disabled_queues() {
+        let (mut orch, callbacks, start) = engine_orch(&[("Ethernet0:3", PfcWdAction::Drop)]);
+        orch.stop_watchdog("Ethernet0:3").unwrap();
+
+        for (i, t) in [0, 100, 200, 300].into_iter().enumerate() {
+            callbacks.set_sample("Ethernet0:3", i as u64 * 10, 0);
+            orch.poll_queues(ms(start, t));
+        }
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Operational)
+        );
+        assert!(callbacks.actions().is_empty());
+    }
+
+    #[test]
+    fn test_engine_install_failure_retries() {
+        let (mut orch, callbacks, start) = engine_orch(&[("Ethernet0:3", PfcWdAction::Drop)]);
+        *callbacks.fail_install.lock().unwrap() = true;
+
+        orch.poll_queues(ms(start, 0));
+        callbacks.set_sample("Ethernet0:3", 10, 0);
+        orch.poll_queues(ms(start, 100));
+        callbacks.set_sample("Ethernet0:3", 20, 0);
+        orch.poll_queues(ms(start, 200));
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Operational)
+        );
+        assert_eq!(orch.stats().action_failures, 1);
+
+        *callbacks.fail_install.lock().unwrap() = false;
+        callbacks.set_sample("Ethernet0:3", 30, 0);
+        orch.poll_queues(ms(start, 300));
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        assert_eq!(callbacks.actions(), vec!["install_zero_buffer Ethernet0:3"]);
+    }
+
+    #[test]
+    fn test_engine_unregister_stormed_queue_removes_action() {
+        let (mut orch, callbacks, _start) = engine_orch(&[("Ethernet0:3", PfcWdAction::Drop)]);
+
+        orch.handle_storm_detected("Ethernet0:3");
+        orch.handle_storm_detected("Ethernet0:3");
+        orch.unregister_queue("Ethernet0:3").unwrap();
+
+        assert_eq!(
+            callbacks.actions(),
+            vec![
+                "install_zero_buffer Ethernet0:3",
+                "remove_zero_buffer Ethernet0:3"
+            ]
+        );
+        assert!(callbacks.counter("Ethernet0:3", "PFC_WD_STATUS").is_none());
+        assert_eq!(orch.stats().storms_detected, 1);
+    }
+
+    #[test]
+    fn test_big_red_switch() {
+        let (mut orch, callbacks, start) = engine_orch(&[
+            ("Ethernet0:3", PfcWdAction::Forward),
+            ("Ethernet0:4", PfcWdAction::Alert),
+        ]);
+
+        orch.set_big_red_switch(true).unwrap();
+        assert!(orch.is_big_red_switch_enabled());
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        assert_eq!(
+            orch.queue_status("Ethernet0:4"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        assert_eq!(
+            callbacks.counter("Ethernet0:4", "PFC_WD_STATUS").as_deref(),
+            Some("stormed")
+        );
+
+        // Queues registered while the switch is on are stormed too
+        orch.register_queue(PfcWdConfig::new(
+            "Ethernet0:5".to_string(),
+            PfcWdAction::Alert,
+            DetectionTime::new(200).unwrap(),
+            RestorationTime::new(200).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(
+            orch.queue_status("Ethernet0:5"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+
+        // Detection and restoration notifications are suspended
+        orch.poll_queues(ms(start, 0));
+        orch.poll_queues(ms(start, 5000));
+        orch.handle_storm_restored("Ethernet0:3");
+        assert_eq!(
+            orch.queue_status("Ethernet0:3"),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+
+        orch.set_big_red_switch(false).unwrap();
+        for queue in ["Ethernet0:3", "Ethernet0:4", "Ethernet0:5"] {
+            assert_eq!(
+                orch.queue_status(queue),
+                Some(PfcWdQueueStatus::Operational)
+            );
+        }
+        assert_eq!(
+            callbacks.actions(),
+            vec![
+                "install_zero_buffer Ethernet0:3",
+                "install_zero_buffer Ethernet0:4",
+                "install_zero_buffer Ethernet0:5",
+                "remove_zero_buffer Ethernet0:3",
+                "remove_zero_buffer Ethernet0:4",
+                "remove_zero_buffer Ethernet0:5",
+            ]
+        );
+    }
 }
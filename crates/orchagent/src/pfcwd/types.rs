@@ -1,6 +1,7 @@
 //! PFC Watchdog types and structures.
 
 use sonic_sai::types::RawSaiObjectId;
+use std::time::{Duration, Instant};
 
 /// PFC watchdog action.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -47,6 +48,10 @@ impl DetectionTime {
     pub fn value(&self) -> u32 {
         self.0
     }
+
+    pub fn as_duration(&self) -> Duration {
+        Duration::from_millis(u64::from(self.0))
+    }
 }
 
 /// Restoration time (0-60000 ms, 0 = disabled).
@@ -65,6 +70,11 @@ impl RestorationTime {
     pub fn value(&self) -> u32 {
         self.0
     }
+
+    /// Returns the restoration time, or None if automatic restoration is disabled.
+    pub fn as_duration(&self) -> Option<Duration> {
+        (self.0 != 0).then(|| Duration::from_millis(u64::from(self.0)))
+    }
 }
 
 /// PFC watchdog configuration.
@@ -111,6 +121,7 @@ pub struct PfcWdEntry {
     pub restoration_time: RestorationTime,
     pub enabled: bool,
     pub storm_detected: bool,
+    pub monitor: PfcWdQueueMonitor,
 }
 
 impl PfcWdEntry {
@@ -123,6 +134,7 @@ impl PfcWdEntry {
             restoration_time: config.restoration_time,
             enabled: false,
             storm_detected: false,
+            monitor: PfcWdQueueMonitor::default(),
         }
     }
 }
@@ -135,7 +147,7 @@ pub struct PfcWdStats {
 }
 
 /// Hardware statistics snapshot.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PfcWdHwStats {
     pub tx_pkt: u64,
     pub tx_drop_pkt: u64,
@@ -143,6 +155,184 @@ pub struct PfcWdHwStats {
     pub rx_drop_pkt: u64,
 }
 
+impl PfcWdHwStats {
+    /// Returns how far each counter advanced since `previous`, allowing for wraparound.
+    pub fn delta(&self, previous: &Self) -> Self {
+        Self {
+            tx_pkt: self.tx_pkt.wrapping_sub(previous.tx_pkt),
+            tx_drop_pkt: self.tx_drop_pkt.wrapping_sub(previous.tx_drop_pkt),
+            rx_pkt: self.rx_pkt.wrapping_sub(previous.rx_pkt),
+            rx_drop_pkt: self.rx_drop_pkt.wrapping_sub(previous.rx_drop_pkt),
+        }
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.tx_pkt = self.tx_pkt.saturating_add(other.tx_pkt);
+        self.tx_drop_pkt = self.tx_drop_pkt.saturating_add(other.tx_drop_pkt);
+        self.rx_pkt = self.rx_pkt.saturating_add(other.rx_pkt);
+        self.rx_drop_pkt = self.rx_drop_pkt.saturating_add(other.rx_drop_pkt);
+    }
+}
+
+/// Counter sample of a watched queue.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PfcWdQueueSample {
+    /// Cumulative PFC pause frames received on the queue's priority.
+    pub pfc_rx_frames: u64,
+    pub hw: PfcWdHwStats,
+}
+
+/// Watchdog status of a queue, as written to PFC_WD_STATUS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PfcWdQueueStatus {
+    #[default]
+    Operational,
+    Stormed,
+}
+
+impl PfcWdQueueStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Operational => "operational",
+            Self::Stormed => "stormed",
+        }
+    }
+}
+
+/// COUNTERS_DB PFC_WD fields of a queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PfcWdQueueCounters {
+    pub status: PfcWdQueueStatus,
+    pub deadlock_detected: u64,
+    pub deadlock_restored: u64,
+    /// Packets handled while stormed, across all storms.
+    pub total: PfcWdHwStats,
+    /// Packets handled during the most recent storm.
+    pub last: PfcWdHwStats,
+}
+
+impl PfcWdQueueCounters {
+    /// Returns the COUNTERS_DB field/value pairs.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = vec![
+            (
+                "PFC_WD_STATUS".to_string(),
+                self.status.as_str().to_string(),
+            ),
+            (
+                "PFC_WD_QUEUE_STATS_DEADLOCK_DETECTED".to_string(),
+                self.deadlock_detected.to_string(),
+            ),
+            (
+                "PFC_WD_QUEUE_STATS_DEADLOCK_RESTORED".to_string(),
+                self.deadlock_restored.to_string(),
+            ),
+        ];
+        for (suffix, stats) in [("", &self.total), ("_LAST", &self.last)] {
+            for (name, value) in [
+                ("TX_PACKETS", stats.tx_pkt),
+                ("TX_DROPPED_PACKETS", stats.tx_drop_pkt),
+                ("RX_PACKETS", stats.rx_pkt),
+                ("RX_DROPPED_PACKETS", stats.rx_drop_pkt),
+            ] {
+                fields.push((
+                    format!("PFC_WD_QUEUE_STATS_{}{}", name, suffix),
+                    value.to_string(),
+                ));
+            }
+        }
+        fields
+    }
+}
+
+/// Per-queue detection/restoration state machine.
+///
+/// A queue is stormed once PFC pause frames have kept arriving for the
+/// detection time, and restored once none have arrived for the restoration
+/// time. Each interval between two samples counts as a whole: PFC seen in a
+/// sample is assumed to have been present since the previous one.
+#[derive(Debug, Clone, Default)]
+pub struct PfcWdQueueMonitor {
+    pub counters: PfcWdQueueCounters,
+    /// Action currently installed in hardware, if stormed.
+    pub installed_action: Option<PfcWdAction>,
+    last_sample: Option<(Instant, PfcWdQueueSample)>,
+    pfc_since: Option<Instant>,
+    quiet_since: Option<Instant>,
+}
+
+impl PfcWdQueueMonitor {
+    pub fn status(&self) -> PfcWdQueueStatus {
+        self.counters.status
+    }
+
+    /// Feeds one sample and returns the status to move to, if it changes.
+    ///
+    /// While stormed, the packets handled since the previous sample are
+    /// added to the storm counters. The first sample only sets the baseline.
+    pub fn observe(
+        &mut self,
+        now: Instant,
+        sample: PfcWdQueueSample,
+        detection_time: Duration,
+        restoration_time: Option<Duration>,
+    ) -> Option<PfcWdQueueStatus> {
+        let (interval_start, previous) = self.last_sample.replace((now, sample))?;
+
+        let pfc_active = sample.pfc_rx_frames.wrapping_sub(previous.pfc_rx_frames) > 0;
+        match self.counters.status {
+            PfcWdQueueStatus::Operational => {
+                if !pfc_active {
+                    self.pfc_since = None;
+                    return None;
+                }
+                let since = *self.pfc_since.get_or_insert(interval_start);
+                (now.duration_since(since) >= detection_time).then_some(PfcWdQueueStatus::Stormed)
+            }
+            PfcWdQueueStatus::Stormed => {
+                let delta = sample.hw.delta(&previous.hw);
+                self.counters.total.add(&delta);
+                self.counters.last.add(&delta);
+
+                if pfc_active {
+                    self.quiet_since = None;
+                    return None;
+                }
+                let restoration_time = restoration_time?;
+                let since = *self.quiet_since.get_or_insert(interval_start);
+                (now.duration_since(since) >= restoration_time)
+                    .then_some(PfcWdQueueStatus::Operational)
+            }
+        }
+    }
+
+    /// Records a transition into the stormed state.
+    pub fn enter_storm(&mut self, action: PfcWdAction) {
+        self.counters.status = PfcWdQueueStatus::Stormed;
+        self.counters.deadlock_detected += 1;
+        self.counters.last = PfcWdHwStats::default();
+        self.installed_action = Some(action);
+        self.pfc_since = None;
+        self.quiet_since = None;
+    }
+
+    /// Records a transition back to operational and returns the action to remove.
+    pub fn exit_storm(&mut self) -> Option<PfcWdAction> {
+        self.counters.status = PfcWdQueueStatus::Operational;
+        self.counters.deadlock_restored += 1;
+        self.pfc_since = None;
+        self.quiet_since = None;
+        self.installed_action.take()
+    }
+
+    /// Forgets the sample baseline so detection starts afresh.
+    pub fn reset_baseline(&mut self) {
+        self.last_sample = None;
+        self.pfc_since = None;
+        self.quiet_since = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +350,116 @@ mod tests {
         assert!(DetectionTime::new(5000).is_ok());
         assert!(DetectionTime::new(5001).is_err());
     }
+
+    fn sample(pfc_rx_frames: u64, tx_drop_pkt: u64) -> PfcWdQueueSample {
+        PfcWdQueueSample {
+            pfc_rx_frames,
+            hw: PfcWdHwStats {
+                tx_drop_pkt,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_monitor_detect_and_restore() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let detection = Duration::from_millis(200);
+        let restoration = Some(Duration::from_millis(300));
+        let mut monitor = PfcWdQueueMonitor::default();
+
+        assert_eq!(
+            monitor.observe(at(0), sample(0, 0), detection, restoration),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(100), sample(10, 0), detection, restoration),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(200), sample(20, 0), detection, restoration),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+        monitor.enter_storm(PfcWdAction::Drop);
+
+        assert_eq!(
+            monitor.observe(at(300), sample(30, 50), detection, restoration),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(400), sample(30, 80), detection, restoration),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(500), sample(30, 80), detection, restoration),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(700), sample(30, 80), detection, restoration),
+            Some(PfcWdQueueStatus::Operational)
+        );
+        assert_eq!(monitor.exit_storm(), Some(PfcWdAction::Drop));
+        assert_eq!(monitor.counters.total.tx_drop_pkt, 80);
+        assert_eq!(monitor.counters.deadlock_detected, 1);
+        assert_eq!(monitor.counters.deadlock_restored, 1);
+    }
+
+    #[test]
+    fn test_monitor_interrupted_pfc_restarts_detection() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let detection = Duration::from_millis(200);
+        let mut monitor = PfcWdQueueMonitor::default();
+
+        monitor.observe(at(0), sample(0, 0), detection, None);
+        assert_eq!(
+            monitor.observe(at(100), sample(5, 0), detection, None),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(200), sample(5, 0), detection, None),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(300), sample(9, 0), detection, None),
+            None
+        );
+        assert_eq!(
+            monitor.observe(at(400), sample(12, 0), detection, None),
+            Some(PfcWdQueueStatus::Stormed)
+        );
+
+        // Restoration disabled: a stormed queue stays stormed
+        monitor.enter_storm(PfcWdAction::Alert);
+        assert_eq!(
+            monitor.observe(at(5000), sample(12, 0), detection, None),
+            None
+        );
+        assert_eq!(monitor.status(), PfcWdQueueStatus::Stormed);
+    }
+
+    #[test]
+    fn test_queue_counters_fields() {
+        let counters = PfcWdQueueCounters {
+            status: PfcWdQueueStatus::Stormed,
+            deadlock_detected: 2,
+            last: PfcWdHwStats {
+                tx_drop_pkt: 7,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let fields = counters.fields();
+        assert_eq!(fields.len(), 11);
+        assert!(fields.contains(&("PFC_WD_STATUS".to_string(), "stormed".to_string())));
+        assert!(fields.contains(&(
+            "PFC_WD_QUEUE_STATS_TX_DROPPED_PACKETS_LAST".to_string(),
+            "7".to_string()
+        )));
+        assert!(fields.contains(&(
+            "PFC_WD_QUEUE_STATS_DEADLOCK_DETECTED".to_string(),
+            "2".to_string()
+        )));
+    }
 }
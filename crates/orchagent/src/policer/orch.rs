@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use sonic_orch_common::{KeyOpFieldsValues, Operation};
 use sonic_sai::types::RawSaiObjectId;
 
 use super::types::{PolicerConfig, PolicerEntry, StormType};
@@ -95,6 +96,8 @@ pub struct PolicerOrch {
     config: PolicerOrchConfig,
     /// Map from policer name to entry.
     policers: HashMap<String, PolicerEntry>,
+    /// Storm control policer name by (port, storm type).
+    storm_bindings: HashMap<(String, StormType), String>,
     /// Callbacks for SAI and port queries.
     callbacks: Option<Arc<dyn PolicerOrchCallbacks>>,
     /// Whether the orch is initialized.
@@ -108,6 +111,7 @@ impl std::fmt::Debug for PolicerOrch {
         f.debug_struct("PolicerOrch")
             .field("config", &self.config)
             .field("policer_count", &self.policers.len())
+            .field("storm_binding_count", &self.storm_bindings.len())
             .field("initialized", &self.initialized)
             .field("stats", &self.stats)
             .finish()
@@ -120,6 +124,7 @@ impl PolicerOrch {
         Self {
             config,
             policers: HashMap::new(),
+            storm_bindings: HashMap::new(),
             callbacks: None,
             initialized: false,
             stats: PolicerOrchStats::default(),
@@ -348,7 +353,60 @@ impl PolicerOrch {
         Ok(())
     }
 
+    /// Returns the name of the storm control policer for a port and storm type.
+    pub fn storm_policer_name(port_name: &str, storm_type: StormType) -> String {
+        format!("_{}_{}", port_name, storm_type.as_str())
+    }
+
+    /// Returns the policer OID bound to a port for a storm type.
+    pub fn get_port_storm_policer(
+        &self,
+        port_name: &str,
+        storm_type: StormType,
+    ) -> Option<RawSaiObjectId> {
+        let policer_name = self
+            .storm_bindings
+            .get(&(port_name.to_string(), storm_type))?;
+        self.get_policer_oid(policer_name)
+    }
+
+    /// Returns the number of storm control bindings.
+    pub fn storm_binding_count(&self) -> usize {
+        self.storm_bindings.len()
+    }
+
+    /// Handles a PORT_STORM_CONTROL table entry keyed by `<port>|<storm_type>`.
+    ///
+    /// A SET carries the rate in the `kbps` field; a DEL removes the binding.
+    pub fn process_storm_control_task(
+        &mut self,
+        task: &KeyOpFieldsValues,
+    ) -> Result<(), PolicerOrchError> {
+        let (port_name, storm_type) = task.key.split_once('|').ok_or_else(|| {
+            PolicerOrchError::InvalidConfig(format!("Invalid storm control key: {}", task.key))
+        })?;
+        let storm_type = StormType::parse(storm_type)
+            .ok_or_else(|| PolicerOrchError::InvalidStormType(storm_type.to_string()))?;
+
+        match task.op {
+            Operation::Set => {
+                let kbps = task
+                    .get_field("kbps")
+                    .ok_or_else(|| {
+                        PolicerOrchError::InvalidConfig(format!("Missing kbps for {}", task.key))
+                    })?
+                    .parse::<u64>()
+                    .map_err(|e| PolicerOrchError::InvalidConfig(format!("Invalid kbps: {}", e)))?;
+                self.set_port_storm_control(port_name, storm_type, kbps)
+            }
+            Operation::Del => self.remove_port_storm_control(port_name, storm_type),
+        }
+    }
+
     /// Configures storm control on a port.
+    ///
+    /// The first binding for a (port, storm type) creates the policer and
+    /// binds it to the port. Subsequent calls update the policer CIR in place.
     pub fn set_port_storm_control(
         &mut self,
         port_name: &str,
@@ -407,14 +465,13 @@ impl PolicerOrch {
             PolicerOrchError::PortNotFound(port_name.to_string())
         })?;
 
-        // Generate policer name: "_<port>_<storm_type>"
-        let policer_name = format!("_{}_{}", port_name, storm_type.as_str());
+        let policer_name = Self::storm_policer_name(port_name, storm_type);
+        let binding_key = (port_name.to_string(), storm_type);
+        let already_bound = self.storm_bindings.contains_key(&binding_key);
+        let policer_existed = self.policer_exists(&policer_name);
 
-        // Create storm control policer config
-        let config = PolicerConfig::storm_control(kbps);
-
-        // Create or update the policer
-        self.set_policer(policer_name.clone(), config)?;
+        // Create the policer, or update its CIR in place
+        self.set_policer(policer_name.clone(), PolicerConfig::storm_control(kbps))?;
 
         // Get the policer OID
         let policer_oid = self.get_policer_oid(&policer_name).ok_or_else(|| {
@@ -430,10 +487,10 @@ impl PolicerOrch {
             PolicerOrchError::PolicerNotFound(policer_name.clone())
         })?;
 
-        // Apply to port
-        callbacks
-            .set_port_storm_policer(port_id, storm_type, Some(policer_oid))
-            .map_err(|e| {
+        if !already_bound {
+            // Apply to port
+            if let Err(e) = callbacks.set_port_storm_policer(port_id, storm_type, Some(policer_oid))
+            {
                 audit_log!(AuditRecord::new(
                     AuditCategory::ResourceModify,
                     "PolicerOrch",
@@ -443,8 +500,15 @@ impl PolicerOrch {
                 .with_object_id(port_name)
                 .with_object_type("port")
                 .with_error(&e));
-                PolicerOrchError::SaiError(e)
-            })?;
+                if !policer_existed {
+                    let _ = self.remove_policer(&policer_name);
+                }
+                return Err(PolicerOrchError::SaiError(e));
+            }
+
+            self.increase_ref_count(&policer_name)?;
+            self.storm_bindings.insert(binding_key, policer_name);
+        }
 
         self.stats.storm_control_applied += 1;
 
@@ -459,13 +523,17 @@ impl PolicerOrch {
         .with_details(serde_json::json!({
             "storm_type": storm_type.as_str(),
             "kbps": kbps,
-            "policer_oid": format!("0x{:x}", policer_oid)
+            "policer_oid": format!("0x{:x}", policer_oid),
+            "operation": if already_bound { "update" } else { "bind" }
         })));
 
         Ok(())
     }
 
     /// Removes storm control from a port.
+    ///
+    /// Unbinds the policer from the port and destroys it once it has no
+    /// remaining references.
     pub fn remove_port_storm_control(
         &mut self,
         port_name: &str,
@@ -477,6 +545,15 @@ impl PolicerOrch {
                 .ok_or_else(|| PolicerOrchError::InvalidConfig("No callbacks set".to_string()))?,
         );
 
+        let binding_key = (port_name.to_string(), storm_type);
+        let policer_name = self
+            .storm_bindings
+            .get(&binding_key)
+            .cloned()
+            .ok_or_else(|| {
+                PolicerOrchError::PolicerNotFound(Self::storm_policer_name(port_name, storm_type))
+            })?;
+
         // Get port ID
         let port_id = callbacks
             .get_port_id(port_name)
@@ -485,12 +562,78 @@ impl PolicerOrch {
         // Detach policer from port
         callbacks
             .set_port_storm_policer(port_id, storm_type, None)
-            .map_err(PolicerOrchError::SaiError)?;
+            .map_err(|e| {
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "PolicerOrch",
+                    "remove_port_storm_control"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(port_name)
+                .with_object_type("port")
+                .with_error(&e));
+                PolicerOrchError::SaiError(e)
+            })?;
+
+        self.release_storm_binding(&binding_key, &policer_name)
+    }
+
+    /// Releases all storm control bindings of a port being removed.
+    ///
+    /// Policers are unbound if the port still has a SAI object, then
+    /// destroyed once unreferenced. Returns the number of bindings released.
+    pub fn remove_port(&mut self, port_name: &str) -> Result<usize, PolicerOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| PolicerOrchError::InvalidConfig("No callbacks set".to_string()))?,
+        );
+
+        let mut bindings: Vec<((String, StormType), String)> = self
+            .storm_bindings
+            .iter()
+            .filter(|((port, _), _)| port == port_name)
+            .map(|(key, name)| (key.clone(), name.clone()))
+            .collect();
+        bindings.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let port_id = callbacks.get_port_id(port_name);
+        for ((_, storm_type), policer_name) in &bindings {
+            if let Some(port_id) = port_id {
+                callbacks
+                    .set_port_storm_policer(port_id, *storm_type, None)
+                    .map_err(PolicerOrchError::SaiError)?;
+            }
+            self.release_storm_binding(&(port_name.to_string(), *storm_type), policer_name)?;
+        }
+
+        if !bindings.is_empty() {
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "PolicerOrch",
+                "remove_port"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(port_name)
+            .with_object_type("port")
+            .with_details(serde_json::json!({
+                "storm_bindings_released": bindings.len()
+            })));
+        }
 
-        // Remove the policer
-        let policer_name = format!("_{}_{}", port_name, storm_type.as_str());
-        self.remove_policer(&policer_name)?;
+        Ok(bindings.len())
+    }
 
+    /// Drops a storm control binding and its policer reference.
+    fn release_storm_binding(
+        &mut self,
+        binding_key: &(String, StormType),
+        policer_name: &str,
+    ) -> Result<(), PolicerOrchError> {
+        self.storm_bindings.remove(binding_key);
+        if self.decrease_ref_count(policer_name)? == 0 {
+            self.remove_policer(policer_name)?;
+        }
         Ok(())
     }
 }
@@ -1220,6 +1363,134 @@ mod tests {
         assert!(matches!(result, Err(PolicerOrchError::PortNotFound(_))));
     }
 
+    #[test]
+    fn test_storm_control_all_types_on_port() {
+        let mut orch = PolicerOrch::new(PolicerOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        let storm_types = [
+            StormType::Broadcast,
+            StormType::UnknownUnicast,
+            StormType::UnknownMulticast,
+        ];
+        for storm_type in storm_types {
+            let task = KeyOpFieldsValues::set(
+                format!("Ethernet0|{}", storm_type.as_str()),
+                vec![("kbps".to_string(), "8000".to_string())],
+            );
+            orch.process_storm_control_task(&task).unwrap();
+        }
+
+        assert_eq!(orch.policer_count(), 3);
+        assert_eq!(orch.storm_binding_count(), 3);
+        let mut oids: Vec<_> = storm_types
+            .iter()
+            .map(|t| orch.get_port_storm_policer("Ethernet0", *t).unwrap())
+            .collect();
+        oids.dedup();
+        assert_eq!(oids.len(), 3);
+        for storm_type in storm_types {
+            let name = PolicerOrch::storm_policer_name("Ethernet0", storm_type);
+            assert_eq!(orch.policers.get(&name).unwrap().ref_count, 1);
+        }
+        assert_eq!(callbacks.storm_policers.lock().unwrap().len(), 3);
+
+        // Removing one storm type leaves the others bound
+        orch.process_storm_control_task(&KeyOpFieldsValues::del("Ethernet0|unknown-unicast"))
+            .unwrap();
+        assert_eq!(orch.policer_count(), 2);
+        assert!(orch
+            .get_port_storm_policer("Ethernet0", StormType::UnknownUnicast)
+            .is_none());
+        assert!(orch
+            .get_port_storm_policer("Ethernet0", StormType::Broadcast)
+            .is_some());
+    }
+
+    #[test]
+    fn test_storm_control_rebind_updates_cir() {
+        let mut orch = PolicerOrch::new(PolicerOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.set_port_storm_control("Ethernet0", StormType::Broadcast, 8000)
+            .unwrap();
+        let oid = orch
+            .get_port_storm_policer("Ethernet0", StormType::Broadcast)
+            .unwrap();
+
+        orch.set_port_storm_control("Ethernet0", StormType::Broadcast, 16000)
+            .unwrap();
+
+        let name = PolicerOrch::storm_policer_name("Ethernet0", StormType::Broadcast);
+        let entry = orch.policers.get(&name).unwrap();
+        assert_eq!(entry.sai_oid, oid);
+        assert_eq!(entry.config.cir, 2_000_000);
+        assert_eq!(entry.ref_count, 1);
+        assert_eq!(*callbacks.updated_policers.lock().unwrap(), vec![oid]);
+        assert_eq!(callbacks.created_policers.lock().unwrap().len(), 1);
+        // Policer is not rebound to the port
+        assert_eq!(callbacks.storm_policers.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_storm_control_port_removed_with_bindings() {
+        let mut orch = PolicerOrch::new(PolicerOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.set_port_storm_control("Ethernet0", StormType::Broadcast, 8000)
+            .unwrap();
+        orch.set_port_storm_control("Ethernet0", StormType::UnknownMulticast, 8000)
+            .unwrap();
+        orch.set_port_storm_control("Ethernet4", StormType::Broadcast, 8000)
+            .unwrap();
+
+        assert_eq!(orch.remove_port("Ethernet0").unwrap(), 2);
+        assert_eq!(orch.storm_binding_count(), 1);
+        assert_eq!(orch.policer_count(), 1);
+        assert!(orch.policer_exists("_Ethernet4_broadcast"));
+        assert_eq!(callbacks.removed_policers.lock().unwrap().len(), 2);
+
+        let storm = callbacks.storm_policers.lock().unwrap();
+        let unbinds: Vec<_> = storm.iter().filter(|(_, _, oid)| oid.is_none()).collect();
+        assert_eq!(unbinds.len(), 2);
+        assert!(unbinds.iter().all(|(port_id, _, _)| *port_id == 0x100));
+        drop(storm);
+
+        // Nothing left to release
+        assert_eq!(orch.remove_port("Ethernet0").unwrap(), 0);
+        assert!(matches!(
+            orch.remove_port_storm_control("Ethernet0", StormType::Broadcast),
+            Err(PolicerOrchError::PolicerNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_storm_control_task_invalid() {
+        let mut orch = PolicerOrch::new(PolicerOrchConfig::default());
+        orch.set_callbacks(Arc::new(TestCallbacks::new()));
+
+        let kbps = vec![("kbps".to_string(), "8000".to_string())];
+        assert!(matches!(
+            orch.process_storm_control_task(&KeyOpFieldsValues::set("Ethernet0", kbps.clone())),
+            Err(PolicerOrchError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            orch.process_storm_control_task(&KeyOpFieldsValues::set("Ethernet0|flood", kbps)),
+            Err(PolicerOrchError::InvalidStormType(_))
+        ));
+        assert!(matches!(
+            orch.process_storm_control_task(&KeyOpFieldsValues::set(
+                "Ethernet0|broadcast",
+                vec![("kbps".to_string(), "fast".to_string())]
+            )),
+            Err(PolicerOrchError::InvalidConfig(_))
+        ));
+        assert_eq!(orch.policer_count(), 0);
+    }
+
     // ==================== Error Handling Tests ====================
 
     #[test]
@@ -1,5 +1,6 @@
 //! PolicerOrch types.
 
+use sonic_orch_common::{FieldErrors, FieldValue, FieldValueParser, FromFieldValues};
 use sonic_sai::types::RawSaiObjectId;
 
 /// Policer meter type (what to measure).
@@ -237,54 +238,59 @@ impl PolicerConfig {
 
     /// Parses a field-value pair and updates the config.
     pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
-        match field {
-            "meter_type" => {
-                self.meter_type = MeterType::parse(value)
-                    .ok_or_else(|| format!("Invalid meter_type: {}", value))?;
-            }
-            "mode" => {
-                self.mode =
-                    PolicerMode::parse(value).ok_or_else(|| format!("Invalid mode: {}", value))?;
-            }
-            "color_source" => {
-                self.color_source = ColorSource::parse(value)
-                    .ok_or_else(|| format!("Invalid color_source: {}", value))?;
-            }
-            "cir" => {
-                self.cir = value
-                    .parse::<u64>()
-                    .map_err(|e| format!("Invalid cir '{}': {}", value, e))?;
-            }
-            "cbs" => {
-                self.cbs = value
-                    .parse::<u64>()
-                    .map_err(|e| format!("Invalid cbs '{}': {}", value, e))?;
-            }
-            "pir" => {
-                self.pir = value
-                    .parse::<u64>()
-                    .map_err(|e| format!("Invalid pir '{}': {}", value, e))?;
-            }
-            "pbs" => {
-                self.pbs = value
-                    .parse::<u64>()
-                    .map_err(|e| format!("Invalid pbs '{}': {}", value, e))?;
-            }
-            "green_packet_action" => {
-                self.green_action = PacketAction::parse(value)
-                    .ok_or_else(|| format!("Invalid green_packet_action: {}", value))?;
-            }
-            "yellow_packet_action" => {
-                self.yellow_action = PacketAction::parse(value)
-                    .ok_or_else(|| format!("Invalid yellow_packet_action: {}", value))?;
-            }
-            "red_packet_action" => {
-                self.red_action = PacketAction::parse(value)
-                    .ok_or_else(|| format!("Invalid red_packet_action: {}", value))?;
-            }
-            _ => {
-                // Unknown field - ignore
-            }
+        self.apply_field_values(&[(field.to_string(), value.to_string())])
+            .map_err(|e| e.to_string())
+    }
+
+    /// Updates the config from the fields present in `fvs`.
+    ///
+    /// Unknown fields are ignored. If any field is invalid, nothing is
+    /// changed and every invalid field is reported.
+    pub fn apply_field_values(&mut self, fvs: &[FieldValue]) -> Result<(), FieldErrors> {
+        fn action(v: &str) -> Result<PacketAction, String> {
+            PacketAction::parse(v).ok_or_else(|| "unknown packet action".to_string())
+        }
+
+        let mut parser = FieldValueParser::new(fvs);
+        let meter_type = parser.optional_with("meter_type", |v| {
+            MeterType::parse(v).ok_or_else(|| "expected PACKETS or BYTES".to_string())
+        });
+        let mode = parser.optional_with("mode", |v| {
+            PolicerMode::parse(v).ok_or_else(|| "unknown policer mode".to_string())
+        });
+        let color_source = parser.optional_with("color_source", |v| {
+            ColorSource::parse(v).ok_or_else(|| "expected AWARE or BLIND".to_string())
+        });
+        let cir = parser.optional::<u64>("cir");
+        let cbs = parser.optional::<u64>("cbs");
+        let pir = parser.optional::<u64>("pir");
+        let pbs = parser.optional::<u64>("pbs");
+        let green_action = parser.optional_with("green_packet_action", action);
+        let yellow_action = parser.optional_with("yellow_packet_action", action);
+        let red_action = parser.optional_with("red_packet_action", action);
+        parser.finish()?;
+
+        if let Some(meter_type) = meter_type {
+            self.meter_type = meter_type;
+        }
+        if let Some(mode) = mode {
+            self.mode = mode;
+        }
+        if let Some(color_source) = color_source {
+            self.color_source = color_source;
+        }
+        self.cir = cir.unwrap_or(self.cir);
+        self.cbs = cbs.unwrap_or(self.cbs);
+        self.pir = pir.unwrap_or(self.pir);
+        self.pbs = pbs.unwrap_or(self.pbs);
+        if let Some(action) = green_action {
+            self.green_action = action;
+        }
+        if let Some(action) = yellow_action {
+            self.yellow_action = action;
+        }
+        if let Some(action) = red_action {
+            self.red_action = action;
         }
         Ok(())
     }
@@ -300,6 +306,14 @@ impl PolicerConfig {
     }
 }
 
+impl FromFieldValues for PolicerConfig {
+    fn from_field_values(fvs: &[FieldValue]) -> Result<Self, FieldErrors> {
+        let mut config = Self::new();
+        config.apply_field_values(fvs)?;
+        Ok(config)
+    }
+}
+
 impl Default for PolicerConfig {
     fn default() -> Self {
         Self::new()
@@ -416,6 +430,43 @@ mod tests {
         assert!(config.parse_field("cir", "invalid").is_err());
     }
 
+    #[test]
+    fn test_policer_config_from_field_values() {
+        let fvs = |pairs: &[(&str, &str)]| -> Vec<FieldValue> {
+            pairs
+                .iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect()
+        };
+
+        let config = PolicerConfig::from_field_values(&fvs(&[
+            ("meter_type", "PACKETS"),
+            ("mode", "TR_TCM"),
+            ("cir", "1000"),
+            ("pir", "2000"),
+            ("red_packet_action", "DROP"),
+            ("description", "ignored"),
+        ]))
+        .unwrap();
+        assert_eq!(config.meter_type, MeterType::Packets);
+        assert_eq!(config.mode, PolicerMode::TrTcm);
+        assert_eq!((config.cir, config.pir), (1000, 2000));
+        assert_eq!(config.red_action, PacketAction::Drop);
+
+        // Every bad field is reported and nothing is applied
+        let mut config = PolicerConfig::new();
+        let err = config
+            .apply_field_values(&fvs(&[
+                ("meter_type", "PACKETS"),
+                ("cir", "fast"),
+                ("pbs", "-1"),
+                ("green_packet_action", "BOUNCE"),
+            ]))
+            .unwrap_err();
+        assert_eq!(err.fields(), vec!["cir", "pbs", "green_packet_action"]);
+        assert_eq!(config.meter_type, MeterType::Bytes);
+    }
+
     #[test]
     fn test_policer_entry_ref_count() {
         let mut entry = PolicerEntry::new(0x1234, PolicerConfig::new());
@@ -3,6 +3,7 @@
 //! This module handles parsing port configuration from CONFIG_DB field-value pairs
 //! into strongly-typed Rust structures.
 
+use sonic_sai::api::PortAttrBuilder;
 use std::fmt;
 use std::str::FromStr;
 
@@ -10,6 +11,7 @@ use super::port::{
     Port, PortAdminState, PortAutoNegMode, PortFecMode, PortInterfaceType, PortLinkTrainingMode,
     PortRole,
 };
+use super::types::PortSupportedSpeeds;
 
 /// Error type for port configuration parsing.
 #[derive(Debug, Clone)]
@@ -256,6 +258,30 @@ impl PortConfig {
         }
     }
 
+    /// Returns the attributes for creating the SAI port.
+    ///
+    /// Link negotiation attributes are not included; they are programmed
+    /// after create so unsupported ones can be skipped individually.
+    pub fn to_sai_create_attrs(&self) -> PortAttrBuilder {
+        let mut builder = PortAttrBuilder::new();
+        if let Some(ref lanes) = self.lanes {
+            builder = builder.lanes(lanes);
+        }
+        if let Some(speed) = self.speed {
+            builder = builder.speed(speed);
+        }
+        if let Some(fec) = self.fec {
+            builder = builder.fec(fec.into());
+        }
+        if let Some(mtu) = self.mtu {
+            builder = builder.mtu(mtu);
+        }
+        if let Some(admin_status) = self.admin_status {
+            builder = builder.admin_state(admin_status == PortAdminState::Up);
+        }
+        builder
+    }
+
     /// Creates a new Port from this configuration.
     ///
     /// Returns an error if required fields (alias, lanes) are missing.
@@ -342,6 +368,36 @@ impl PortConfig {
 
         Ok(())
     }
+
+    /// Validates speed and advertised speeds against the port capabilities.
+    ///
+    /// An empty capability list means SAI did not report any, in which case
+    /// every speed is accepted.
+    pub fn validate_speeds(&self, supported: &PortSupportedSpeeds) -> Result<(), PortConfigError> {
+        if supported.speeds.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(speed) = self.speed {
+            if !supported.supports(speed) {
+                return Err(PortConfigError::new(
+                    "speed",
+                    format!("Speed {} not supported", speed),
+                ));
+            }
+        }
+
+        if let Some(ref adv_speeds) = self.adv_speeds {
+            if let Some(speed) = adv_speeds.iter().find(|s| !supported.supports(**s)) {
+                return Err(PortConfigError::new(
+                    "adv_speeds",
+                    format!("Advertised speed {} not supported", speed),
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Parses a u32 value.
@@ -383,16 +439,22 @@ fn parse_lanes(value: &str) -> Result<Vec<u32>, PortConfigError> {
 }
 
 /// Parses speed values (comma-separated).
+///
+/// "all" (or an empty value) advertises every supported speed and is
+/// represented as an empty list.
 fn parse_speeds(value: &str) -> Result<Vec<u32>, PortConfigError> {
-    if value.is_empty() {
+    let value = value.trim();
+    if value.is_empty() || value.eq_ignore_ascii_case("all") {
         return Ok(Vec::new());
     }
     value
         .split(',')
         .map(|s| {
-            s.trim()
-                .parse()
-                .map_err(|_| PortConfigError::invalid_value("adv_speeds", s))
+            let s = s.trim();
+            match s.parse::<u32>() {
+                Ok(speed) if speed > 0 => Ok(speed),
+                _ => Err(PortConfigError::invalid_value("adv_speeds", s)),
+            }
         })
         .collect()
 }
@@ -673,6 +735,72 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_adv_speeds() {
+        assert_eq!(parse_speeds("10000,25000").unwrap(), vec![10000, 25000]);
+        assert_eq!(parse_speeds(" 10000 , 25000 ").unwrap(), vec![10000, 25000]);
+        assert!(parse_speeds("all").unwrap().is_empty());
+        assert!(parse_speeds("").unwrap().is_empty());
+
+        let err = parse_speeds("10000,25000,badvalue").unwrap_err();
+        assert_eq!(err.field, "adv_speeds");
+        assert!(err.message.contains("badvalue"));
+
+        assert!(parse_speeds("10000,,25000").is_err());
+        assert!(parse_speeds("0").is_err());
+        assert!(parse_speeds("-1000").is_err());
+    }
+
+    #[test]
+    fn test_port_config_autoneg_fields() {
+        let mut config = PortConfig::new();
+        config.parse_field("autoneg", "on").unwrap();
+        config.parse_field("adv_speeds", "10000,25000").unwrap();
+        config.parse_field("interface_type", "CR").unwrap();
+        config.parse_field("adv_interface_types", "CR,CR4").unwrap();
+
+        assert_eq!(config.autoneg, Some(PortAutoNegMode::Enabled));
+        assert_eq!(config.adv_speeds, Some(vec![10000, 25000]));
+        assert_eq!(config.interface_type, Some(PortInterfaceType::Cr));
+        assert_eq!(
+            config.adv_interface_types,
+            Some(vec![PortInterfaceType::Cr, PortInterfaceType::Cr4])
+        );
+
+        config.parse_field("autoneg", "off").unwrap();
+        assert_eq!(config.autoneg, Some(PortAutoNegMode::Disabled));
+
+        assert!(config.parse_field("autoneg", "maybe").is_err());
+        assert!(config
+            .parse_field("adv_speeds", "10000,25000,badvalue")
+            .is_err());
+        assert!(config.parse_field("adv_interface_types", "CR,XX9").is_err());
+    }
+
+    #[test]
+    fn test_port_config_validate_speeds() {
+        let supported = PortSupportedSpeeds::new(vec![10000, 25000, 100000]);
+
+        let mut config = PortConfig::new();
+        config.speed = Some(25000);
+        config.adv_speeds = Some(vec![10000, 25000]);
+        assert!(config.validate_speeds(&supported).is_ok());
+
+        config.adv_speeds = Some(vec![10000, 40000]);
+        let err = config.validate_speeds(&supported).unwrap_err();
+        assert_eq!(err.field, "adv_speeds");
+
+        config.adv_speeds = None;
+        config.speed = Some(50000);
+        let err = config.validate_speeds(&supported).unwrap_err();
+        assert_eq!(err.field, "speed");
+
+        // Unknown capabilities accept everything
+        assert!(config
+            .validate_speeds(&PortSupportedSpeeds::default())
+            .is_ok());
+    }
+
     #[test]
     fn test_parse_bool() {
         assert!(parse_bool("test", "on").unwrap());
@@ -682,4 +810,36 @@ mod tests {
         assert!(!parse_bool("test", "false").unwrap());
         assert!(parse_bool("test", "invalid").is_err());
     }
+
+    #[test]
+    fn test_to_sai_create_attrs() {
+        use sonic_sai::SaiAttributeValue;
+
+        let mut config = PortConfig::with_alias("Ethernet0");
+        config.parse_field("lanes", "0,1,2,3").unwrap();
+        config.parse_field("speed", "100000").unwrap();
+        config.parse_field("fec", "rs").unwrap();
+        config.parse_field("admin_status", "up").unwrap();
+        config.parse_field("autoneg", "on").unwrap();
+
+        let attrs = config.to_sai_create_attrs();
+        assert_eq!(attrs.len(), 4);
+        assert_eq!(
+            attrs.get(PortAttrBuilder::HW_LANE_LIST),
+            Some(&SaiAttributeValue::U32List(vec![0, 1, 2, 3]))
+        );
+        assert_eq!(
+            attrs.get(PortAttrBuilder::ADMIN_STATE),
+            Some(&SaiAttributeValue::Bool(true))
+        );
+        // Negotiation is programmed separately after create
+        assert!(attrs.get(PortAttrBuilder::AUTO_NEG_MODE).is_none());
+
+        // FEC auto is left to the ASIC
+        config.parse_field("fec", "auto").unwrap();
+        assert!(config
+            .to_sai_create_attrs()
+            .get(PortAttrBuilder::FEC_MODE)
+            .is_none());
+    }
 }
@@ -50,6 +50,6 @@ pub use orch::{PortsOrch, PortsOrchCallbacks, PortsOrchConfig, PortsOrchError};
 pub use port::{Port, PortAdminState, PortFecMode, PortOperState, PortRole, PortType};
 pub use queue::{QueueInfo, QueueType, SchedulerInfo};
 pub use types::{
-    GearboxPortTable, LagTable, PortInitState, PortSupportedSpeeds, PortTable, SystemPortTable,
-    VlanTable, VlanTaggingMode,
+    GearboxPortTable, LagTable, PortInitState, PortNegotiationAttr, PortSupportedSpeeds, PortTable,
+    SystemPortTable, VlanTable, VlanTaggingMode,
 };
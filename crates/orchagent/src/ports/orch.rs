@@ -18,18 +18,22 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use sonic_orch_common::{SyncMap, TaskStatus};
+use sonic_orch_common::{Operation, SyncMap, TaskStatus};
 use sonic_sai::types::RawSaiObjectId;
+use sonic_sai::{LagMemberOid, LagOid, PortOid, SaiError, SaiResult};
+use sonic_types::MacAddress;
 
-use super::config::{PortConfig, PortConfigError};
-use super::port::{Port, PortAdminState, PortOperState, PortType};
+use super::config::{LagConfig, PortConfig, PortConfigError};
+use super::port::{Port, PortAdminState, PortAutoNegMode, PortOperState, PortType};
 use super::queue::{PriorityGroupInfo, QueueInfo, SchedulerGroupInfo};
 use super::types::{
-    GearboxPortTable, LagInfo, LagTable, PortInitState, PortSupportedSpeeds, PortTable,
-    PortsOrchStats, SystemPortTable, VlanInfo, VlanMemberInfo, VlanTable, VlanTaggingMode,
+    GearboxPortTable, LagInfo, LagTable, PortInitState, PortNegotiationAttr, PortSupportedSpeeds,
+    PortTable, PortsOrchStats, SystemPortTable, VlanInfo, VlanMemberInfo, VlanTable,
+    VlanTaggingMode,
 };
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use crate::mac::MacAddressExt;
 
 /// Error type for PortsOrch operations.
 #[derive(Debug, Clone)]
@@ -50,6 +54,8 @@ pub enum PortsOrchError {
     InvalidState(String),
     /// Resource exhausted.
     ResourceExhausted(String),
+    /// Attribute not supported by the SAI implementation.
+    NotSupported(String),
     /// Configuration parsing error.
     ConfigError(PortConfigError),
 }
@@ -65,6 +71,7 @@ impl std::fmt::Display for PortsOrchError {
             Self::SaiError(msg) => write!(f, "SAI error: {}", msg),
             Self::InvalidState(msg) => write!(f, "Invalid state: {}", msg),
             Self::ResourceExhausted(msg) => write!(f, "Resource exhausted: {}", msg),
+            Self::NotSupported(msg) => write!(f, "Not supported: {}", msg),
             Self::ConfigError(e) => write!(f, "Config error: {}", e),
         }
     }
@@ -72,6 +79,27 @@ impl std::fmt::Display for PortsOrchError {
 
 impl std::error::Error for PortsOrchError {}
 
+impl PortsOrchError {
+    /// Maps the error to the task status the consumer should act on.
+    ///
+    /// Missing dependencies and exhausted resources are retried; SAI
+    /// failures are dropped and malformed config is rejected.
+    pub fn to_task_status(&self) -> TaskStatus {
+        match self {
+            Self::PortNotFound(_)
+            | Self::LagNotFound(_)
+            | Self::VlanNotFound(_)
+            | Self::InvalidState(_)
+            | Self::ResourceExhausted(_) => TaskStatus::NeedRetry,
+            Self::SaiError(_) => TaskStatus::Failed,
+            Self::PortAlreadyExists(_)
+            | Self::InvalidConfig(_)
+            | Self::NotSupported(_)
+            | Self::ConfigError(_) => TaskStatus::InvalidEntry,
+        }
+    }
+}
+
 impl From<PortConfigError> for PortsOrchError {
     fn from(e: PortConfigError) -> Self {
         Self::ConfigError(e)
@@ -99,6 +127,28 @@ pub struct PortsOrchCallbacks {
     pub on_lag_member_added: Option<Arc<dyn Fn(&str, &str) + Send + Sync>>,
     /// Called when a VLAN is created.
     pub on_vlan_created: Option<Arc<dyn Fn(&VlanInfo) + Send + Sync>>,
+    /// Creates a SAI LAG object for the given alias.
+    pub create_lag: Option<Arc<dyn Fn(&str) -> Result<LagOid> + Send + Sync>>,
+    /// Removes a SAI LAG object.
+    pub remove_lag: Option<Arc<dyn Fn(LagOid) -> Result<()> + Send + Sync>>,
+    /// Creates a SAI LAG member binding a port to a LAG.
+    pub create_lag_member:
+        Option<Arc<dyn Fn(LagOid, PortOid) -> Result<LagMemberOid> + Send + Sync>>,
+    /// Removes a SAI LAG member.
+    pub remove_lag_member: Option<Arc<dyn Fn(LagMemberOid) -> Result<()> + Send + Sync>>,
+    /// Called after a port's SAI object has been removed, with the stale OID.
+    pub on_port_removed: Option<Arc<dyn Fn(&str, PortOid) + Send + Sync>>,
+    /// Creates a SAI port object from a PORT_TABLE entry (see
+    /// [`PortConfig::to_sai_create_attrs`]).
+    pub create_port: Option<Arc<dyn Fn(&PortConfig) -> Result<PortOid> + Send + Sync>>,
+    /// Removes a SAI port object.
+    pub remove_port: Option<Arc<dyn Fn(PortOid) -> Result<()> + Send + Sync>>,
+    /// Queries the queues the SAI created for a port.
+    pub get_port_queues: Option<Arc<dyn Fn(PortOid) -> Vec<QueueInfo> + Send + Sync>>,
+    /// Sets a link negotiation attribute on a SAI port (see
+    /// [`PortNegotiationAttr::to_sai_attrs`]).
+    pub set_port_negotiation_attr:
+        Option<Arc<dyn Fn(PortOid, &PortNegotiationAttr) -> SaiResult<()> + Send + Sync>>,
 }
 
 impl Default for PortsOrchCallbacks {
@@ -110,6 +160,15 @@ impl Default for PortsOrchCallbacks {
             on_lag_created: None,
             on_lag_member_added: None,
             on_vlan_created: None,
+            create_lag: None,
+            remove_lag: None,
+            create_lag_member: None,
+            remove_lag_member: None,
+            on_port_removed: None,
+            create_port: None,
+            remove_port: None,
+            get_port_queues: None,
+            set_port_negotiation_attr: None,
         }
     }
 }
@@ -123,6 +182,18 @@ impl std::fmt::Debug for PortsOrchCallbacks {
             .field("on_lag_created", &self.on_lag_created.is_some())
             .field("on_lag_member_added", &self.on_lag_member_added.is_some())
             .field("on_vlan_created", &self.on_vlan_created.is_some())
+            .field("create_lag", &self.create_lag.is_some())
+            .field("remove_lag", &self.remove_lag.is_some())
+            .field("create_lag_member", &self.create_lag_member.is_some())
+            .field("remove_lag_member", &self.remove_lag_member.is_some())
+            .field("on_port_removed", &self.on_port_removed.is_some())
+            .field("create_port", &self.create_port.is_some())
+            .field("remove_port", &self.remove_port.is_some())
+            .field("get_port_queues", &self.get_port_queues.is_some())
+            .field(
+                "set_port_negotiation_attr",
+                &self.set_port_negotiation_attr.is_some(),
+            )
             .finish()
     }
 }
@@ -146,6 +217,9 @@ pub struct PortsOrchConfig {
     pub gearbox_enabled: bool,
     /// Whether to support system ports (VOQ).
     pub system_port_enabled: bool,
+    /// Switch base MAC. When set, each port discovered from hardware gets
+    /// the base MAC offset by its first lane.
+    pub base_mac: Option<MacAddress>,
 }
 
 impl Default for PortsOrchConfig {
@@ -159,6 +233,7 @@ impl Default for PortsOrchConfig {
             log_state_changes: true,
             gearbox_enabled: false,
             system_port_enabled: false,
+            base_mac: None,
         }
     }
 }
@@ -197,6 +272,12 @@ pub struct PortsOrch {
     /// LAG member mapping: member alias → LAG alias.
     lag_member_to_lag: HashMap<String, String>,
 
+    /// LAG member reverse mapping: LAG member OID → member port alias.
+    lag_member_oid_to_port: HashMap<RawSaiObjectId, String>,
+
+    /// Interface references held by other orchs: port alias → count.
+    port_ref_counts: HashMap<String, u32>,
+
     // ============ VLAN Tables ============
     /// VLANs indexed by alias.
     vlans: VlanTable,
@@ -226,6 +307,9 @@ pub struct PortsOrch {
     /// Supported speeds per port.
     port_supported_speeds: HashMap<String, PortSupportedSpeeds>,
 
+    /// Negotiation attributes the SAI rejected as unsupported, per port.
+    port_attr_failures: HashMap<String, Vec<PortsOrchError>>,
+
     // ============ State ============
     /// Whether initial port discovery is complete.
     initialized: bool,
@@ -257,6 +341,8 @@ impl PortsOrch {
             pending_port_configs: HashMap::new(),
             lags: SyncMap::new(),
             lag_member_to_lag: HashMap::new(),
+            lag_member_oid_to_port: HashMap::new(),
+            port_ref_counts: HashMap::new(),
             vlans: SyncMap::new(),
             vlan_id_to_alias: HashMap::new(),
             gearbox_ports: SyncMap::new(),
@@ -265,6 +351,7 @@ impl PortsOrch {
             port_priority_groups: HashMap::new(),
             port_scheduler_groups: HashMap::new(),
             port_supported_speeds: HashMap::new(),
+            port_attr_failures: HashMap::new(),
             initialized: false,
             expected_port_count: 0,
             stats: PortsOrchStats::default(),
@@ -333,6 +420,13 @@ impl PortsOrch {
 
         let mut port = Port::physical(&alias, lanes.clone());
         port.port_id = port_id;
+        if let Some(base) = &self.config.base_mac {
+            let first_lane = lanes.first().copied().unwrap_or(0);
+            let mac = base
+                .offset(u64::from(first_lane))
+                .map_err(|e| PortsOrchError::InvalidConfig(format!("{}: {}", alias, e)))?;
+            port.mac_address = Some(mac);
+        }
 
         // Register lane mappings
         for lane in &lanes {
@@ -347,13 +441,30 @@ impl PortsOrch {
             .insert(alias.clone(), PortInitState::ConfigMissing);
 
         // Check if we have pending config for this port
-        if let Some(config) = self.pending_port_configs.remove(&alias) {
+        let pending = self.pending_port_configs.remove(&alias);
+        if let Some(ref config) = pending {
             config.apply_to(&mut port);
             self.port_init_states
                 .insert(alias.clone(), PortInitState::ConfigReceived);
         }
 
         self.ports.insert(alias.clone(), port);
+
+        if let Some(config) = pending {
+            if let Err(e) = self.program_port_negotiation(&alias, &config) {
+                // Roll back so the port isn't left half-configured; the
+                // config stays pending for the next attempt
+                self.ports.remove(&alias);
+                self.port_oid_to_alias.remove(&port_id);
+                for lane in &lanes {
+                    self.lane_to_port.remove(lane);
+                }
+                self.port_init_states.remove(&alias);
+                self.port_attr_failures.remove(&alias);
+                self.pending_port_configs.insert(alias, config);
+                return Err(e);
+            }
+        }
         self.stats.ports_created += 1;
 
         // Notify callbacks
@@ -381,9 +492,17 @@ impl PortsOrch {
 
         // Validate config
         config.validate()?;
+        if let Some(supported) = self.port_supported_speeds.get(&alias) {
+            config.validate_speeds(supported)?;
+        }
+
+        // If port exists, apply config once the SAI has accepted it
+        if self.ports.contains_key(&alias) {
+            self.program_port_negotiation(&alias, &config)?;
 
-        // If port exists, apply config
-        if let Some(port) = self.ports.get_mut(&alias) {
+            let Some(port) = self.ports.get_mut(&alias) else {
+                return Err(PortsOrchError::PortNotFound(alias));
+            };
             config.apply_to(port);
             self.port_init_states
                 .insert(alias.clone(), PortInitState::ConfigReceived);
@@ -440,6 +559,7 @@ impl PortsOrch {
         self.port_priority_groups.remove(alias);
         self.port_scheduler_groups.remove(alias);
         self.port_supported_speeds.remove(alias);
+        self.port_attr_failures.remove(alias);
 
         self.stats.ports_deleted += 1;
 
@@ -453,6 +573,101 @@ impl PortsOrch {
         Ok(())
     }
 
+    /// Records the speeds SAI reports as supported for a port.
+    pub fn set_port_supported_speeds(&mut self, alias: &str, speeds: PortSupportedSpeeds) {
+        self.port_supported_speeds.insert(alias.to_string(), speeds);
+    }
+
+    /// Returns the supported speeds for a port, if known.
+    pub fn get_port_supported_speeds(&self, alias: &str) -> Option<&PortSupportedSpeeds> {
+        self.port_supported_speeds.get(alias)
+    }
+
+    /// Returns the negotiation attributes the SAI rejected for a port.
+    pub fn get_port_attr_failures(&self, alias: &str) -> &[PortsOrchError] {
+        self.port_attr_failures
+            .get(alias)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Programs autoneg, advertised speeds and interface types on the SAI port.
+    ///
+    /// Attributes the SAI reports as NOT_SUPPORTED are recorded and skipped so
+    /// the remaining configuration still applies; other SAI errors are returned.
+    fn program_port_negotiation(&mut self, alias: &str, config: &PortConfig) -> Result<()> {
+        let mut attrs = Vec::new();
+        if let Some(autoneg) = config.autoneg {
+            attrs.push(PortNegotiationAttr::AutoNeg(
+                autoneg == PortAutoNegMode::Enabled,
+            ));
+        }
+        if let Some(ref speeds) = config.adv_speeds {
+            attrs.push(PortNegotiationAttr::AdvertisedSpeeds(speeds.clone()));
+        }
+        if let Some(interface_type) = config.interface_type {
+            attrs.push(PortNegotiationAttr::InterfaceType(interface_type));
+        }
+        if let Some(ref types) = config.adv_interface_types {
+            attrs.push(PortNegotiationAttr::AdvertisedInterfaceTypes(types.clone()));
+        }
+        if attrs.is_empty() {
+            return Ok(());
+        }
+
+        let set_attr = match self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.set_port_negotiation_attr.clone())
+        {
+            Some(f) => f,
+            None => return Ok(()),
+        };
+        let port_id = self
+            .ports
+            .get(&alias.to_string())
+            .map(|p| p.port_id)
+            .ok_or_else(|| PortsOrchError::PortNotFound(alias.to_string()))?;
+        let port_oid = PortOid::from_raw_unchecked(port_id);
+
+        let mut failures = Vec::new();
+        for attr in &attrs {
+            match set_attr(port_oid, attr) {
+                Ok(()) => {}
+                Err(SaiError::NotSupported { .. }) => {
+                    let err =
+                        PortsOrchError::NotSupported(format!("{} on {}", attr.sai_name(), alias));
+                    audit_log!(AuditRecord::new(
+                        AuditCategory::ResourceModify,
+                        "PortsOrch",
+                        "set_port_negotiation_attr"
+                    )
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(alias)
+                    .with_object_type("port")
+                    .with_error(&err.to_string()));
+                    failures.push(err);
+                }
+                Err(e) => {
+                    self.stats.sai_errors += 1;
+                    let msg = format!("Failed to set {} on {}: {}", attr.sai_name(), alias, e);
+                    return Err(if e.is_retryable() {
+                        PortsOrchError::ResourceExhausted(msg)
+                    } else {
+                        PortsOrchError::SaiError(msg)
+                    });
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            self.port_attr_failures.remove(alias);
+        } else {
+            self.port_attr_failures.insert(alias.to_string(), failures);
+        }
+        Ok(())
+    }
+
     /// Sets the admin state of a port.
     pub fn set_port_admin_state(&mut self, alias: &str, state: PortAdminState) -> Result<()> {
         let port = self.get_port_mut(alias)?;
@@ -508,6 +723,227 @@ impl PortsOrch {
         self.expected_port_count = count;
     }
 
+    // ============ Port Task Processing ============
+
+    /// Processes an APPL_DB PORT_TABLE entry.
+    ///
+    /// Together with DEL this implements dynamic port breakout: the parent
+    /// port (e.g. Ethernet0 on lanes 0-3) is deleted and the child ports
+    /// (Ethernet0..Ethernet3, one lane each) are SET with their new lanes.
+    /// A child whose lanes are still owned by another port is retried until
+    /// the parent has been removed.
+    pub fn do_port_task(
+        &mut self,
+        key: &str,
+        op: Operation,
+        fvs: &[(String, String)],
+    ) -> TaskStatus {
+        match op {
+            Operation::Set => {
+                let mut config = PortConfig::with_alias(key);
+                for (field, value) in fvs {
+                    if config.parse_field(field, value).is_err() {
+                        return TaskStatus::InvalidEntry;
+                    }
+                }
+                // The "alias" field is the front-panel name; the key is the port name
+                config.alias = Some(key.to_string());
+
+                if let Some(port) = self.get_port(key) {
+                    if let Some(ref lanes) = config.lanes {
+                        if *lanes != port.lanes {
+                            // Lane change requires the old port to be deleted first
+                            return TaskStatus::NeedRetry;
+                        }
+                    }
+                    return self
+                        .configure_port(config)
+                        .unwrap_or_else(|e| e.to_task_status());
+                }
+
+                let has_create = self
+                    .callbacks
+                    .as_ref()
+                    .is_some_and(|cb| cb.create_port.is_some());
+                match config.lanes.clone() {
+                    Some(lanes) if has_create => self.create_port_from_config(config, lanes),
+                    _ => self
+                        .configure_port(config)
+                        .unwrap_or_else(|e| e.to_task_status()),
+                }
+            }
+            Operation::Del => {
+                let Some(port) = self.get_port(key) else {
+                    self.pending_port_configs.remove(key);
+                    return TaskStatus::Success;
+                };
+
+                if let Some(reason) = self.port_reference(&port) {
+                    audit_log!(AuditRecord::new(
+                        AuditCategory::ResourceDelete,
+                        "PortsOrch",
+                        "do_port_task"
+                    )
+                    .with_outcome(AuditOutcome::InProgress)
+                    .with_object_id(key)
+                    .with_object_type("port")
+                    .with_details(serde_json::json!({
+                        "waiting_for": reason
+                    })));
+                    return TaskStatus::NeedRetry;
+                }
+
+                let port_oid = PortOid::from_raw_unchecked(port.port_id);
+                if self.sai_remove_port(port_oid).is_err() {
+                    self.stats.sai_errors += 1;
+                    return TaskStatus::Failed;
+                }
+                if self.remove_port(key).is_err() {
+                    return TaskStatus::Failed;
+                }
+
+                if let Some(callbacks) = &self.callbacks {
+                    if let Some(ref on_removed) = callbacks.on_port_removed {
+                        on_removed(key, port_oid);
+                    }
+                }
+
+                TaskStatus::Success
+            }
+        }
+    }
+
+    /// Returns a description of the first reference that blocks removing
+    /// the port, or `None` if the port can be removed.
+    fn port_reference(&self, port: &Port) -> Option<&'static str> {
+        if !port.vlan_members.is_empty() {
+            Some("vlan_member")
+        } else if port.rif_id != 0 {
+            Some("router_interface")
+        } else if port.ingress_acl_table_id != 0
+            || port.egress_acl_table_id != 0
+            || port.ingress_acl_group_id.is_some()
+            || port.egress_acl_group_id.is_some()
+        {
+            Some("acl_binding")
+        } else if port.lag_id.is_some() {
+            Some("lag_member")
+        } else if self.get_port_ref_count(&port.alias) > 0 {
+            Some("interface_reference")
+        } else {
+            None
+        }
+    }
+
+    /// Creates a SAI port for a PORT_TABLE entry and initializes its
+    /// queue and scheduler state.
+    fn create_port_from_config(&mut self, config: PortConfig, lanes: Vec<u32>) -> TaskStatus {
+        let alias = match config.alias.clone() {
+            Some(alias) => alias,
+            None => return TaskStatus::InvalidEntry,
+        };
+
+        if config.validate().is_err() {
+            return TaskStatus::InvalidEntry;
+        }
+
+        // The parent port still owns one of the lanes
+        if lanes.iter().any(|lane| {
+            self.lane_to_port
+                .get(lane)
+                .is_some_and(|owner| *owner != alias)
+        }) {
+            return TaskStatus::NeedRetry;
+        }
+
+        if self.ports.len() >= self.config.max_ports {
+            return TaskStatus::Failed;
+        }
+
+        let port_oid = match self.sai_create_port(&config) {
+            Ok(oid) => oid,
+            Err(_) => {
+                self.stats.sai_errors += 1;
+                return TaskStatus::Failed;
+            }
+        };
+
+        // Config is applied by add_port_from_hardware via the pending table
+        self.pending_port_configs.insert(alias.clone(), config);
+        if self
+            .add_port_from_hardware(alias.clone(), port_oid.as_raw(), lanes)
+            .is_err()
+        {
+            // Don't leak the SAI port we just created
+            self.pending_port_configs.remove(&alias);
+            if self.sai_remove_port(port_oid).is_err() {
+                self.stats.sai_errors += 1;
+            }
+            return TaskStatus::Failed;
+        }
+        self.init_port_queues(&alias, port_oid);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceCreate, "PortsOrch", "create_port")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(&alias)
+                .with_object_type("port")
+                .with_details(serde_json::json!({
+                    "port_id": format!("0x{:x}", port_oid.as_raw())
+                }))
+        );
+
+        TaskStatus::Success
+    }
+
+    /// Resets queue, priority group and scheduler state for a newly
+    /// created port and re-reads its queues from SAI.
+    fn init_port_queues(&mut self, alias: &str, port_oid: PortOid) {
+        self.port_priority_groups.remove(alias);
+        self.port_scheduler_groups.remove(alias);
+
+        let queues = self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.get_port_queues.clone())
+            .map(|get_queues| get_queues(port_oid))
+            .unwrap_or_default();
+
+        if let Some(port) = self.ports.get_mut(&alias.to_string()) {
+            port.queue_ids = queues.iter().map(|q| q.queue_id).collect();
+            port.num_queues = queues.len() as u32;
+            port.scheduler_group_ids.clear();
+            port.priority_group_ids.clear();
+        }
+        self.port_queues.insert(alias.to_string(), queues);
+    }
+
+    fn sai_create_port(&self, config: &PortConfig) -> Result<PortOid> {
+        match self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.create_port.clone())
+        {
+            Some(create_port) => create_port(config),
+            None => Err(PortsOrchError::SaiError(
+                "create_port callback not set".to_string(),
+            )),
+        }
+    }
+
+    fn sai_remove_port(&self, port: PortOid) -> Result<()> {
+        match self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.remove_port.clone())
+        {
+            Some(remove_port) => remove_port(port),
+            None => Err(PortsOrchError::SaiError(
+                "remove_port callback not set".to_string(),
+            )),
+        }
+    }
+
     // ============ LAG Operations ============
 
     /// Returns true if a LAG exists with the given alias.
@@ -527,7 +963,9 @@ impl PortsOrch {
 
     /// Creates a new LAG.
     pub fn create_lag(&mut self, alias: &str, lag_id: RawSaiObjectId) -> Result<()> {
-        if self.lags.contains_key(&alias.to_string()) {
+        // The LAG also gets a Port entry, which must not replace another port
+        if self.lags.contains_key(&alias.to_string()) || self.ports.contains_key(&alias.to_string())
+        {
             return Err(PortsOrchError::PortAlreadyExists(alias.to_string()));
         }
 
@@ -618,6 +1056,10 @@ impl PortsOrch {
             .ok_or_else(|| PortsOrchError::PortNotFound(member_alias.to_string()))?;
         port.lag_id = Some(lag_id);
 
+        if let Some(lag_port) = self.ports.get_mut(&lag_alias.to_string()) {
+            lag_port.add_lag_member(member_alias);
+        }
+
         // Update mapping
         self.lag_member_to_lag
             .insert(member_alias.to_string(), lag_alias.to_string());
@@ -680,6 +1122,10 @@ impl PortsOrch {
         port.lag_id = None;
         port.lag_member_id = None;
 
+        if let Some(lag_port) = self.ports.get_mut(&lag_alias.to_string()) {
+            lag_port.remove_lag_member(member_alias);
+        }
+
         // Remove mapping
         self.lag_member_to_lag.remove(member_alias);
 
@@ -703,6 +1149,266 @@ impl PortsOrch {
         self.lag_member_to_lag.get(member_alias).cloned()
     }
 
+    /// Gets the member port for a SAI LAG member OID.
+    pub fn get_port_by_lag_member_oid(&self, oid: RawSaiObjectId) -> Option<Port> {
+        self.lag_member_oid_to_port
+            .get(&oid)
+            .and_then(|alias| self.ports.get(alias))
+            .map(|p| p.clone())
+    }
+
+    // ============ Reference Counting ============
+
+    /// Increments the interface reference count of a port or LAG.
+    pub fn increase_port_ref_count(&mut self, alias: &str) {
+        *self.port_ref_counts.entry(alias.to_string()).or_insert(0) += 1;
+    }
+
+    /// Decrements the interface reference count of a port or LAG.
+    pub fn decrease_port_ref_count(&mut self, alias: &str) {
+        if let Some(count) = self.port_ref_counts.get_mut(alias) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.port_ref_counts.remove(alias);
+            }
+        }
+    }
+
+    /// Returns the interface reference count of a port or LAG.
+    pub fn get_port_ref_count(&self, alias: &str) -> u32 {
+        self.port_ref_counts.get(alias).copied().unwrap_or(0)
+    }
+
+    // ============ LAG Task Processing ============
+
+    /// Processes an APPL_DB LAG_TABLE entry.
+    ///
+    /// SET creates the SAI LAG on first sight and applies MTU/admin updates.
+    /// DEL is deferred (NeedRetry) until the last member has been removed and
+    /// no interface holds a reference to the LAG.
+    pub fn do_lag_task(
+        &mut self,
+        key: &str,
+        op: Operation,
+        fvs: &[(String, String)],
+    ) -> TaskStatus {
+        match op {
+            Operation::Set => {
+                let mut config = LagConfig::new();
+                for (field, value) in fvs {
+                    if let Err(e) = config.parse_field(field, value) {
+                        audit_log!(AuditRecord::new(
+                            AuditCategory::ResourceCreate,
+                            "PortsOrch",
+                            "do_lag_task"
+                        )
+                        .with_outcome(AuditOutcome::Failure)
+                        .with_object_id(key)
+                        .with_object_type("lag")
+                        .with_error(&e.to_string()));
+                        return TaskStatus::InvalidEntry;
+                    }
+                }
+
+                if !self.has_lag(key) {
+                    if self.lags.len() >= self.config.max_lags {
+                        return TaskStatus::Failed;
+                    }
+                    let lag_oid = match self.sai_create_lag(key) {
+                        Ok(oid) => oid,
+                        Err(_) => {
+                            self.stats.sai_errors += 1;
+                            return TaskStatus::Failed;
+                        }
+                    };
+                    if self.create_lag(key, lag_oid.as_raw()).is_err() {
+                        // Don't leak the SAI LAG we just created
+                        if self.sai_remove_lag(lag_oid).is_err() {
+                            self.stats.sai_errors += 1;
+                        }
+                        return TaskStatus::Failed;
+                    }
+                }
+
+                self.apply_lag_config(key, &config);
+                TaskStatus::Success
+            }
+            Operation::Del => {
+                let Some(lag) = self.get_lag(key) else {
+                    return TaskStatus::Success;
+                };
+
+                if lag.member_count() > 0 || self.get_port_ref_count(key) > 0 {
+                    return TaskStatus::NeedRetry;
+                }
+
+                if self
+                    .sai_remove_lag(LagOid::from_raw_unchecked(lag.lag_id))
+                    .is_err()
+                {
+                    self.stats.sai_errors += 1;
+                    return TaskStatus::Failed;
+                }
+
+                match self.remove_lag(key) {
+                    Ok(()) => TaskStatus::Success,
+                    Err(_) => TaskStatus::Failed,
+                }
+            }
+        }
+    }
+
+    /// Processes an APPL_DB LAG_MEMBER_TABLE entry keyed by `<lag>:<port>`.
+    ///
+    /// A port that is still a member of a different LAG is not moved; the
+    /// entry is retried until the old membership has been removed.
+    pub fn do_lag_member_task(
+        &mut self,
+        key: &str,
+        op: Operation,
+        _fvs: &[(String, String)],
+    ) -> TaskStatus {
+        let Some((lag_alias, member_alias)) = key.split_once(':') else {
+            return TaskStatus::InvalidEntry;
+        };
+
+        match op {
+            Operation::Set => {
+                let Some(lag) = self.get_lag(lag_alias) else {
+                    return TaskStatus::NeedRetry;
+                };
+                let Some(port) = self.get_port(member_alias) else {
+                    return TaskStatus::NeedRetry;
+                };
+
+                if let Some(current) = self.lag_member_to_lag.get(member_alias) {
+                    if current == lag_alias {
+                        return TaskStatus::Success;
+                    }
+                    return TaskStatus::NeedRetry;
+                }
+
+                let member_oid = match self.sai_create_lag_member(
+                    LagOid::from_raw_unchecked(lag.lag_id),
+                    PortOid::from_raw_unchecked(port.port_id),
+                ) {
+                    Ok(oid) => oid,
+                    Err(_) => {
+                        self.stats.sai_errors += 1;
+                        return TaskStatus::Failed;
+                    }
+                };
+
+                if self.add_lag_member(lag_alias, member_alias).is_err() {
+                    // Don't leak the SAI LAG member we just created
+                    if self.sai_remove_lag_member(member_oid).is_err() {
+                        self.stats.sai_errors += 1;
+                    }
+                    return TaskStatus::Failed;
+                }
+                if let Some(port) = self.ports.get_mut(&member_alias.to_string()) {
+                    port.lag_member_id = Some(member_oid.as_raw());
+                }
+                self.lag_member_oid_to_port
+                    .insert(member_oid.as_raw(), member_alias.to_string());
+
+                TaskStatus::Success
+            }
+            Operation::Del => {
+                if self.lag_member_to_lag.get(member_alias).map(String::as_str) != Some(lag_alias) {
+                    return TaskStatus::Success;
+                }
+
+                let member_oid = self
+                    .ports
+                    .get(&member_alias.to_string())
+                    .and_then(|p| p.lag_member_id);
+                if let Some(member_oid) = member_oid {
+                    if self
+                        .sai_remove_lag_member(LagMemberOid::from_raw_unchecked(member_oid))
+                        .is_err()
+                    {
+                        self.stats.sai_errors += 1;
+                        return TaskStatus::Failed;
+                    }
+                    self.lag_member_oid_to_port.remove(&member_oid);
+                }
+
+                match self.remove_lag_member(lag_alias, member_alias) {
+                    Ok(()) => TaskStatus::Success,
+                    Err(_) => TaskStatus::Failed,
+                }
+            }
+        }
+    }
+
+    /// Applies LAG_TABLE attributes to both the LAG and its Port entry.
+    fn apply_lag_config(&mut self, alias: &str, config: &LagConfig) {
+        if let Some(lag) = self.lags.get_mut(&alias.to_string()) {
+            if let Some(mtu) = config.mtu {
+                lag.mtu = mtu;
+            }
+            if let Some(admin_status) = config.admin_status {
+                lag.admin_status = admin_status.into();
+            }
+        }
+        if let Some(port) = self.ports.get_mut(&alias.to_string()) {
+            if let Some(mtu) = config.mtu {
+                port.set_mtu(mtu);
+            }
+            if let Some(admin_status) = config.admin_status {
+                port.set_admin_state(admin_status);
+            }
+            if let Some(tpid) = config.tpid {
+                port.tpid = tpid;
+            }
+        }
+    }
+
+    fn sai_create_lag(&self, alias: &str) -> Result<LagOid> {
+        match self.callbacks.as_ref().and_then(|cb| cb.create_lag.clone()) {
+            Some(create_lag) => create_lag(alias),
+            None => Err(PortsOrchError::SaiError(
+                "create_lag callback not set".to_string(),
+            )),
+        }
+    }
+
+    fn sai_remove_lag(&self, lag: LagOid) -> Result<()> {
+        match self.callbacks.as_ref().and_then(|cb| cb.remove_lag.clone()) {
+            Some(remove_lag) => remove_lag(lag),
+            None => Err(PortsOrchError::SaiError(
+                "remove_lag callback not set".to_string(),
+            )),
+        }
+    }
+
+    fn sai_create_lag_member(&self, lag: LagOid, port: PortOid) -> Result<LagMemberOid> {
+        match self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.create_lag_member.clone())
+        {
+            Some(create_lag_member) => create_lag_member(lag, port),
+            None => Err(PortsOrchError::SaiError(
+                "create_lag_member callback not set".to_string(),
+            )),
+        }
+    }
+
+    fn sai_remove_lag_member(&self, member: LagMemberOid) -> Result<()> {
+        match self
+            .callbacks
+            .as_ref()
+            .and_then(|cb| cb.remove_lag_member.clone())
+        {
+            Some(remove_lag_member) => remove_lag_member(member),
+            None => Err(PortsOrchError::SaiError(
+                "remove_lag_member callback not set".to_string(),
+            )),
+        }
+    }
+
     // ============ VLAN Operations ============
 
     /// Returns true if a VLAN exists with the given alias.
@@ -1008,7 +1714,7 @@ impl PortsOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ports::port::{PortAutoNegMode, PortFecMode};
+    use crate::ports::port::{PortAutoNegMode, PortFecMode, PortInterfaceType};
     use crate::ports::queue::{PriorityGroupInfo, QueueInfo, QueueType};
 
     #[test]
@@ -1034,6 +1740,43 @@ mod tests {
         assert_eq!(port.alias, "Ethernet0");
         assert_eq!(port.port_id, 0x1234);
         assert_eq!(port.lanes, vec![0, 1, 2, 3]);
+        assert_eq!(port.mac_address, None);
+    }
+
+    #[test]
+    fn test_add_port_from_hardware_derives_mac_from_base() {
+        let config = PortsOrchConfig {
+            base_mac: Some(MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x00])),
+            ..Default::default()
+        };
+        let mut orch = PortsOrch::new(config);
+
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0, 1, 2, 3])
+            .unwrap();
+        orch.add_port_from_hardware("Ethernet4".to_string(), 0x1001, vec![4, 5, 6, 7])
+            .unwrap();
+
+        assert_eq!(
+            orch.get_port("Ethernet0").unwrap().mac_address,
+            Some(MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x00]))
+        );
+        assert_eq!(
+            orch.get_port("Ethernet4").unwrap().mac_address,
+            Some(MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x04]))
+        );
+    }
+
+    #[test]
+    fn test_add_port_from_hardware_rejects_mac_overflow() {
+        let config = PortsOrchConfig {
+            base_mac: Some(MacAddress::new([0xff, 0xff, 0xff, 0xff, 0xff, 0xfe])),
+            ..Default::default()
+        };
+        let mut orch = PortsOrch::new(config);
+
+        let result = orch.add_port_from_hardware("Ethernet8".to_string(), 0x1002, vec![8]);
+        assert!(matches!(result, Err(PortsOrchError::InvalidConfig(_))));
+        assert!(!orch.has_port("Ethernet8"));
     }
 
     #[test]
@@ -1671,6 +2414,458 @@ mod tests {
         assert!(matches!(result, Err(PortsOrchError::ResourceExhausted(_))));
     }
 
+    // ============ LAG Task Tests ============
+
+    fn lag_task_orch() -> PortsOrch {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        let next_lag = Arc::new(AtomicU64::new(0x2000));
+        let next_member = Arc::new(AtomicU64::new(0x6000));
+
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            create_lag: Some(Arc::new(move |_alias| {
+                Ok(LagOid::from_raw_unchecked(
+                    next_lag.fetch_add(1, Ordering::SeqCst),
+                ))
+            })),
+            remove_lag: Some(Arc::new(|_lag| Ok(()))),
+            create_lag_member: Some(Arc::new(move |_lag, _port| {
+                Ok(LagMemberOid::from_raw_unchecked(
+                    next_member.fetch_add(1, Ordering::SeqCst),
+                ))
+            })),
+            remove_lag_member: Some(Arc::new(|_member| Ok(()))),
+            ..Default::default()
+        });
+
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0])
+            .unwrap();
+        orch.add_port_from_hardware("Ethernet4".to_string(), 0x1001, vec![1])
+            .unwrap();
+        orch
+    }
+
+    fn fvs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lag_task_create_and_update() {
+        let mut orch = lag_task_orch();
+
+        let status = orch.do_lag_task(
+            "PortChannel0001",
+            Operation::Set,
+            &fvs(&[("mtu", "9100"), ("admin_status", "up")]),
+        );
+        assert_eq!(status, TaskStatus::Success);
+        assert!(orch.has_lag("PortChannel0001"));
+
+        let port = orch.get_port("PortChannel0001").unwrap();
+        assert_eq!(port.port_type, PortType::Lag);
+        assert_eq!(port.admin_state, PortAdminState::Up);
+
+        // Second SET only updates attributes
+        let status = orch.do_lag_task("PortChannel0001", Operation::Set, &fvs(&[("mtu", "1500")]));
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(orch.get_lag("PortChannel0001").unwrap().mtu, 1500);
+        assert_eq!(orch.stats().lags_created, 1);
+    }
+
+    #[test]
+    fn test_lag_task_invalid_field() {
+        let mut orch = lag_task_orch();
+
+        let status = orch.do_lag_task("PortChannel0001", Operation::Set, &fvs(&[("mtu", "abc")]));
+        assert_eq!(status, TaskStatus::InvalidEntry);
+        assert!(!orch.has_lag("PortChannel0001"));
+    }
+
+    #[test]
+    fn test_lag_task_without_sai_callbacks() {
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+
+        let status = orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::Failed);
+        assert_eq!(orch.stats().sai_errors, 1);
+    }
+
+    #[test]
+    fn test_lag_task_removes_sai_lag_when_setup_fails() {
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = lag_task_orch();
+        let mut callbacks = PortsOrchCallbacks::clone(orch.callbacks.as_ref().unwrap());
+        let removed_clone = Arc::clone(&removed);
+        callbacks.remove_lag = Some(Arc::new(move |lag: LagOid| {
+            removed_clone.lock().unwrap().push(lag.as_raw());
+            Ok(())
+        }));
+        orch.set_callbacks(callbacks);
+
+        // A LAG can't take over a physical port's alias
+        let status = orch.do_lag_task("Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::Failed);
+        assert!(!orch.has_lag("Ethernet0"));
+        assert_eq!(orch.get_port("Ethernet0").unwrap().port_type, PortType::Phy);
+        assert_eq!(*removed.lock().unwrap(), vec![0x2000]);
+    }
+
+    #[test]
+    fn test_lag_member_task_churn() {
+        let mut orch = lag_task_orch();
+        orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+
+        // Add
+        let status = orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        let port = orch.get_port("Ethernet0").unwrap();
+        let member_oid = port.lag_member_id.unwrap();
+        assert_eq!(
+            orch.get_port_by_lag_member_oid(member_oid).unwrap().alias,
+            "Ethernet0"
+        );
+        assert!(orch
+            .get_port("PortChannel0001")
+            .unwrap()
+            .lag_members
+            .contains("Ethernet0"));
+
+        // Remove
+        let status = orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(orch.get_lag_for_member("Ethernet0"), None);
+        assert!(orch.get_port_by_lag_member_oid(member_oid).is_none());
+        assert!(orch.get_port("Ethernet0").unwrap().lag_member_id.is_none());
+
+        // Re-add gets a fresh member OID
+        let status = orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        let new_member_oid = orch.get_port("Ethernet0").unwrap().lag_member_id.unwrap();
+        assert_ne!(new_member_oid, member_oid);
+        assert_eq!(orch.get_lag("PortChannel0001").unwrap().member_count(), 1);
+    }
+
+    #[test]
+    fn test_lag_member_task_waits_for_lag_and_port() {
+        let mut orch = lag_task_orch();
+
+        let status = orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+        let status = orch.do_lag_member_task("PortChannel0001:Ethernet8", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        let status = orch.do_lag_member_task("PortChannel0001", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::InvalidEntry);
+    }
+
+    #[test]
+    fn test_lag_member_task_rejects_member_of_other_lag() {
+        let mut orch = lag_task_orch();
+        orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+        orch.do_lag_task("PortChannel0002", Operation::Set, &[]);
+
+        orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Set, &[]);
+        let status = orch.do_lag_member_task("PortChannel0002:Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert!(status.is_retryable());
+        assert_eq!(
+            orch.get_lag_for_member("Ethernet0"),
+            Some("PortChannel0001".to_string())
+        );
+
+        // Deleting the stale membership from the wrong LAG is a no-op
+        let status = orch.do_lag_member_task("PortChannel0002:Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        assert!(orch
+            .get_lag("PortChannel0001")
+            .unwrap()
+            .has_member("Ethernet0"));
+
+        // Once released, the retried SET succeeds
+        orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Del, &[]);
+        let status = orch.do_lag_member_task("PortChannel0002:Ethernet0", Operation::Set, &[]);
+        assert_eq!(status, TaskStatus::Success);
+    }
+
+    #[test]
+    fn test_lag_delete_before_member_delete() {
+        let mut orch = lag_task_orch();
+        orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+        orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Set, &[]);
+        orch.do_lag_member_task("PortChannel0001:Ethernet4", Operation::Set, &[]);
+
+        // LAG DEL arrives first and must wait for the members
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert!(orch.has_lag("PortChannel0001"));
+
+        orch.do_lag_member_task("PortChannel0001:Ethernet0", Operation::Del, &[]);
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        orch.do_lag_member_task("PortChannel0001:Ethernet4", Operation::Del, &[]);
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        assert!(!orch.has_lag("PortChannel0001"));
+        assert!(!orch.has_port("PortChannel0001"));
+    }
+
+    #[test]
+    fn test_lag_delete_waits_for_interface_refs() {
+        let mut orch = lag_task_orch();
+        orch.do_lag_task("PortChannel0001", Operation::Set, &[]);
+        orch.increase_port_ref_count("PortChannel0001");
+
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        orch.decrease_port_ref_count("PortChannel0001");
+        assert_eq!(orch.get_port_ref_count("PortChannel0001"), 0);
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+
+        // Deleting an unknown LAG is idempotent
+        let status = orch.do_lag_task("PortChannel0001", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+    }
+
+    // ============ Port Breakout Tests ============
+
+    fn breakout_orch(removed: Arc<std::sync::Mutex<Vec<String>>>) -> PortsOrch {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        let next_port = Arc::new(AtomicU64::new(0x1100));
+
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            create_port: Some(Arc::new(move |_config| {
+                Ok(PortOid::from_raw_unchecked(
+                    next_port.fetch_add(1, Ordering::SeqCst),
+                ))
+            })),
+            remove_port: Some(Arc::new(|_port| Ok(()))),
+            get_port_queues: Some(Arc::new(|port| {
+                (0..8)
+                    .map(|i| QueueInfo::new((port.as_raw() << 8) | i, i as u32, QueueType::Unicast))
+                    .collect()
+            })),
+            on_port_removed: Some(Arc::new(move |alias, _oid| {
+                removed.lock().unwrap().push(alias.to_string());
+            })),
+            ..Default::default()
+        });
+
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0, 1, 2, 3])
+            .unwrap();
+        orch
+    }
+
+    #[test]
+    fn test_port_breakout_1x100g_to_4x25g() {
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = breakout_orch(removed.clone());
+
+        // Children arriving before the parent DEL wait for the lanes
+        let status = orch.do_port_task(
+            "Ethernet1",
+            Operation::Set,
+            &fvs(&[("lanes", "1"), ("speed", "25000")]),
+        );
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        let status = orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        assert!(!orch.has_port("Ethernet0"));
+        assert_eq!(*removed.lock().unwrap(), vec!["Ethernet0".to_string()]);
+
+        for (i, alias) in ["Ethernet0", "Ethernet1", "Ethernet2", "Ethernet3"]
+            .iter()
+            .enumerate()
+        {
+            let lane = i.to_string();
+            let status = orch.do_port_task(
+                alias,
+                Operation::Set,
+                &fvs(&[("lanes", lane.as_str()), ("speed", "25000")]),
+            );
+            assert_eq!(status, TaskStatus::Success);
+
+            let port = orch.get_port(alias).unwrap();
+            assert_eq!(port.lanes, vec![i as u32]);
+            assert_eq!(port.speed, 25000);
+            assert_eq!(port.num_queues, 8);
+            assert_eq!(orch.get_port_queues(alias).unwrap().len(), 8);
+        }
+
+        assert_eq!(orch.get_physical_ports().len(), 4);
+        assert!(orch.get_port_by_oid(0x1000).is_none());
+        assert_eq!(orch.stats().ports_deleted, 1);
+    }
+
+    #[test]
+    fn test_port_breakout_notifies_port_hooks() {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = breakout_orch(removed);
+
+        let mut callbacks = orch.callbacks.as_deref().cloned().unwrap();
+        let created = Arc::clone(&events);
+        callbacks.on_port_created = Some(Arc::new(move |port: &Port| {
+            created
+                .lock()
+                .unwrap()
+                .push(format!("created {}", port.alias));
+        }));
+        let deleted = Arc::clone(&events);
+        callbacks.on_port_deleted = Some(Arc::new(move |alias: &str| {
+            deleted.lock().unwrap().push(format!("deleted {}", alias));
+        }));
+        let removed = Arc::clone(&events);
+        callbacks.on_port_removed = Some(Arc::new(move |alias: &str, oid: PortOid| {
+            removed
+                .lock()
+                .unwrap()
+                .push(format!("removed {} {:#x}", alias, oid.as_raw()));
+        }));
+        orch.set_callbacks(callbacks);
+
+        orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        orch.do_port_task(
+            "Ethernet0",
+            Operation::Set,
+            &fvs(&[("lanes", "0"), ("speed", "25000")]),
+        );
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "deleted Ethernet0".to_string(),
+                "removed Ethernet0 0x1000".to_string(),
+                "created Ethernet0".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_port_breakout_blocked_by_references() {
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = breakout_orch(removed.clone());
+
+        orch.create_vlan("Vlan100", 100, 0x3000).unwrap();
+        orch.add_vlan_member(
+            "Vlan100",
+            "Ethernet0",
+            VlanTaggingMode::Tagged,
+            0x4000,
+            0x5000,
+        )
+        .unwrap();
+        let status = orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        orch.remove_vlan_member("Vlan100", "Ethernet0").unwrap();
+
+        orch.get_port_mut("Ethernet0").unwrap().rif_id = 0x7000;
+        let status = orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        orch.get_port_mut("Ethernet0").unwrap().rif_id = 0;
+
+        orch.get_port_mut("Ethernet0").unwrap().ingress_acl_group_id = Some(0x8000);
+        let status = orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        orch.get_port_mut("Ethernet0").unwrap().ingress_acl_group_id = None;
+
+        assert!(orch.has_port("Ethernet0"));
+        assert!(removed.lock().unwrap().is_empty());
+
+        let status = orch.do_port_task("Ethernet0", Operation::Del, &[]);
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(removed.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_port_task_lane_change_requires_delete() {
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = breakout_orch(removed);
+
+        let status = orch.do_port_task("Ethernet0", Operation::Set, &fvs(&[("lanes", "0")]));
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        // Same lanes is a plain config update
+        let status = orch.do_port_task(
+            "Ethernet0",
+            Operation::Set,
+            &fvs(&[("lanes", "0,1,2,3"), ("mtu", "1500")]),
+        );
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(orch.get_port("Ethernet0").unwrap().mtu, 1500);
+    }
+
+    #[test]
+    fn test_port_task_removes_sai_port_when_setup_fails() {
+        let removed_oids = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let removed_clone = Arc::clone(&removed_oids);
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            create_port: Some(Arc::new(|_config| Ok(PortOid::from_raw_unchecked(0x1100)))),
+            remove_port: Some(Arc::new(move |port: PortOid| {
+                removed_clone.lock().unwrap().push(port.as_raw());
+                Ok(())
+            })),
+            set_port_negotiation_attr: Some(Arc::new(|_port, _attr| {
+                Err(SaiError::invalid_parameter("bad autoneg"))
+            })),
+            ..Default::default()
+        });
+
+        let status = orch.do_port_task(
+            "Ethernet8",
+            Operation::Set,
+            &fvs(&[("lanes", "8"), ("autoneg", "on")]),
+        );
+        assert_eq!(status, TaskStatus::Failed);
+        assert_eq!(*removed_oids.lock().unwrap(), vec![0x1100]);
+
+        // Nothing is left behind locally
+        assert!(!orch.has_port("Ethernet8"));
+        assert!(!orch.lane_to_port.contains_key(&8));
+        assert_eq!(orch.stats().ports_created, 0);
+    }
+
+    #[test]
+    fn test_hardware_port_negotiation_failure_rolls_back() {
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            set_port_negotiation_attr: Some(Arc::new(|_port, _attr| {
+                Err(SaiError::invalid_parameter("bad autoneg"))
+            })),
+            ..Default::default()
+        });
+
+        let mut config = PortConfig::with_alias("Ethernet0");
+        config.parse_field("autoneg", "on").unwrap();
+        assert_eq!(orch.configure_port(config).unwrap(), TaskStatus::NeedRetry);
+
+        let result = orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0]);
+        assert!(matches!(result, Err(PortsOrchError::SaiError(_))));
+        assert!(!orch.has_port("Ethernet0"));
+        assert!(orch.get_port_by_oid(0x1000).is_none());
+
+        // The config is kept, so a later discovery applies it
+        orch.set_callbacks(PortsOrchCallbacks::default());
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0])
+            .unwrap();
+        assert_eq!(
+            orch.get_port("Ethernet0").unwrap().autoneg,
+            PortAutoNegMode::Enabled
+        );
+    }
+
     // ============ VLAN Operations Tests ============
 
     #[test]
@@ -2359,4 +3554,166 @@ mod tests {
         let result = config.validate();
         assert!(result.is_err());
     }
+
+    fn negotiation_orch(
+        programmed: Arc<std::sync::Mutex<Vec<PortNegotiationAttr>>>,
+        unsupported: &'static str,
+    ) -> PortsOrch {
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            set_port_negotiation_attr: Some(Arc::new(move |_port, attr| {
+                if attr.sai_name() == unsupported {
+                    return Err(SaiError::not_supported(attr.sai_name()));
+                }
+                programmed.lock().unwrap().push(attr.clone());
+                Ok(())
+            })),
+            ..Default::default()
+        });
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0, 1, 2, 3])
+            .unwrap();
+        orch.set_port_supported_speeds(
+            "Ethernet0",
+            PortSupportedSpeeds::new(vec![10000, 25000, 100000]),
+        );
+        orch
+    }
+
+    #[test]
+    fn test_port_autoneg_programs_sai_attributes() {
+        let programmed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = negotiation_orch(programmed.clone(), "");
+
+        let status = orch.do_port_task(
+            "Ethernet0",
+            Operation::Set,
+            &fvs(&[
+                ("autoneg", "on"),
+                ("adv_speeds", "10000,25000"),
+                ("interface_type", "CR4"),
+                ("adv_interface_types", "CR,CR4"),
+            ]),
+        );
+        assert_eq!(status, TaskStatus::Success);
+
+        let programmed = programmed.lock().unwrap();
+        assert_eq!(
+            *programmed,
+            vec![
+                PortNegotiationAttr::AutoNeg(true),
+                PortNegotiationAttr::AdvertisedSpeeds(vec![10000, 25000]),
+                PortNegotiationAttr::InterfaceType(PortInterfaceType::Cr4),
+                PortNegotiationAttr::AdvertisedInterfaceTypes(vec![
+                    PortInterfaceType::Cr,
+                    PortInterfaceType::Cr4
+                ]),
+            ]
+        );
+        let port = orch.get_port("Ethernet0").unwrap();
+        assert_eq!(port.autoneg, PortAutoNegMode::Enabled);
+        assert_eq!(port.adv_speeds, vec![10000, 25000]);
+        assert!(orch.get_port_attr_failures("Ethernet0").is_empty());
+    }
+
+    #[test]
+    fn test_port_adv_speeds_validation() {
+        let programmed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = negotiation_orch(programmed.clone(), "");
+
+        // Malformed list
+        let status = orch.do_port_task(
+            "Ethernet0",
+            Operation::Set,
+            &fvs(&[("autoneg", "on"), ("adv_speeds", "10000,25000,badvalue")]),
+        );
+        assert_eq!(status, TaskStatus::InvalidEntry);
+
+        // Well-formed but not supported by the port
+        let mut config = PortConfig::with_alias("Ethernet0");
+        config.parse_field("adv_speeds", "10000,40000").unwrap();
+        let result = orch.configure_port(config);
+        assert!(
+            matches!(result, Err(PortsOrchError::ConfigError(ref e)) if e.field == "adv_speeds")
+        );
+
+        assert!(programmed.lock().unwrap().is_empty());
+        assert!(orch.get_port("Ethernet0").unwrap().adv_speeds.is_empty());
+    }
+
+    #[test]
+    fn test_port_autoneg_not_supported_is_recorded() {
+        let programmed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut orch = negotiation_orch(programmed.clone(), "SAI_PORT_ATTR_ADVERTISED_SPEED");
+
+        let mut config = PortConfig::with_alias("Ethernet0");
+        config.parse_field("autoneg", "on").unwrap();
+        config.parse_field("adv_speeds", "25000").unwrap();
+        assert_eq!(orch.configure_port(config).unwrap(), TaskStatus::Success);
+
+        // Remaining attributes still applied
+        assert_eq!(
+            *programmed.lock().unwrap(),
+            vec![PortNegotiationAttr::AutoNeg(true)]
+        );
+        let failures = orch.get_port_attr_failures("Ethernet0");
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(failures[0], PortsOrchError::NotSupported(ref msg)
+            if msg.contains("SAI_PORT_ATTR_ADVERTISED_SPEED")));
+        assert_eq!(orch.stats().sai_errors, 0);
+    }
+
+    #[test]
+    fn test_port_autoneg_sai_failure() {
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            set_port_negotiation_attr: Some(Arc::new(|_port, _attr| {
+                Err(SaiError::invalid_parameter("bad autoneg"))
+            })),
+            ..Default::default()
+        });
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0])
+            .unwrap();
+
+        let mut config = PortConfig::with_alias("Ethernet0");
+        config.parse_field("autoneg", "off").unwrap();
+        assert!(matches!(
+            orch.configure_port(config),
+            Err(PortsOrchError::SaiError(_))
+        ));
+        assert_eq!(orch.stats().sai_errors, 1);
+    }
+
+    #[test]
+    fn test_port_task_sai_failure_keeps_port_state() {
+        let retryable = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let retryable_clone = Arc::clone(&retryable);
+        let mut orch = PortsOrch::new(PortsOrchConfig::default());
+        orch.set_callbacks(PortsOrchCallbacks {
+            set_port_negotiation_attr: Some(Arc::new(move |_port, _attr| {
+                if retryable_clone.load(std::sync::atomic::Ordering::SeqCst) {
+                    Err(SaiError::from_status(
+                        sonic_sai::SaiStatus::InsufficientResources,
+                    ))
+                } else {
+                    Err(SaiError::invalid_parameter("bad autoneg"))
+                }
+            })),
+            ..Default::default()
+        });
+        orch.add_port_from_hardware("Ethernet0".to_string(), 0x1000, vec![0])
+            .unwrap();
+
+        let fields = fvs(&[("autoneg", "on"), ("mtu", "9100")]);
+        let status = orch.do_port_task("Ethernet0", Operation::Set, &fields);
+        assert_eq!(status, TaskStatus::Failed);
+        let port = orch.get_port("Ethernet0").unwrap();
+        assert_ne!(port.autoneg, PortAutoNegMode::Enabled);
+        assert_ne!(port.mtu, 9100);
+        assert_eq!(orch.stats().port_config_changes, 0);
+
+        retryable.store(true, std::sync::atomic::Ordering::SeqCst);
+        let status = orch.do_port_task("Ethernet0", Operation::Set, &fields);
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert_ne!(orch.get_port("Ethernet0").unwrap().mtu, 9100);
+    }
 }
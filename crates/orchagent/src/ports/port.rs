@@ -3,6 +3,7 @@
 //! The Port struct contains all the information for a physical or logical port,
 //! including SAI object IDs, configuration, and operational state.
 
+use sonic_sai::api::FecMode;
 use sonic_sai::types::RawSaiObjectId;
 use sonic_types::MacAddress;
 use std::collections::{HashMap, HashSet};
@@ -150,6 +151,17 @@ impl std::str::FromStr for PortFecMode {
     }
 }
 
+impl From<PortFecMode> for FecMode {
+    fn from(mode: PortFecMode) -> Self {
+        match mode {
+            PortFecMode::None => Self::None,
+            PortFecMode::Rs => Self::Rs,
+            PortFecMode::Fc => Self::Fc,
+            PortFecMode::Auto => Self::Auto,
+        }
+    }
+}
+
 /// Port admin state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PortAdminState {
@@ -333,6 +345,29 @@ impl std::str::FromStr for PortInterfaceType {
     }
 }
 
+impl PortInterfaceType {
+    /// Returns the `sai_port_interface_type_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::None => 0,
+            Self::Cr => 1,
+            Self::Cr2 => 2,
+            Self::Cr4 => 3,
+            Self::Sr => 4,
+            Self::Sr2 => 5,
+            Self::Sr4 => 6,
+            Self::Lr => 7,
+            Self::Lr4 => 8,
+            Self::Kr => 9,
+            Self::Kr4 => 10,
+            Self::Kr2 => 15,
+            Self::Cr8 => 25,
+            Self::Sr8 => 26,
+            Self::Kr8 => 27,
+        }
+    }
+}
+
 /// Link training mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PortLinkTrainingMode {
@@ -879,6 +914,15 @@ mod tests {
         assert_eq!("fc".parse::<PortFecMode>().unwrap(), PortFecMode::Fc);
     }
 
+    #[test]
+    fn test_sai_values() {
+        assert_eq!(FecMode::from(PortFecMode::Rs), FecMode::Rs);
+        assert_eq!(FecMode::from(PortFecMode::Auto), FecMode::Auto);
+        assert_eq!(PortInterfaceType::None.to_sai(), 0);
+        assert_eq!(PortInterfaceType::Cr4.to_sai(), 3);
+        assert_eq!(PortInterfaceType::Kr2.to_sai(), 15);
+    }
+
     #[test]
     fn test_port_physical() {
         let port = Port::physical("Ethernet0", vec![0, 1, 2, 3]);
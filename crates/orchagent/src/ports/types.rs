@@ -3,10 +3,11 @@
 //! This module defines the core types used by PortsOrch for managing
 //! ports, LAGs, VLANs, and related state.
 
+use sonic_sai::api::PortAttrBuilder;
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::{HashMap, HashSet};
 
-use super::port::Port;
+use super::port::{Port, PortInterfaceType};
 
 /// Port initialization state machine states.
 ///
@@ -271,6 +272,46 @@ impl PortSupportedSpeeds {
     }
 }
 
+/// Link negotiation attributes programmed on a SAI port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortNegotiationAttr {
+    /// SAI_PORT_ATTR_AUTO_NEG_MODE.
+    AutoNeg(bool),
+    /// SAI_PORT_ATTR_ADVERTISED_SPEED (Mbps).
+    AdvertisedSpeeds(Vec<u32>),
+    /// SAI_PORT_ATTR_INTERFACE_TYPE.
+    InterfaceType(PortInterfaceType),
+    /// SAI_PORT_ATTR_ADVERTISED_INTERFACE_TYPE.
+    AdvertisedInterfaceTypes(Vec<PortInterfaceType>),
+}
+
+impl PortNegotiationAttr {
+    /// Returns the SAI attribute name.
+    pub fn sai_name(&self) -> &'static str {
+        match self {
+            Self::AutoNeg(_) => "SAI_PORT_ATTR_AUTO_NEG_MODE",
+            Self::AdvertisedSpeeds(_) => "SAI_PORT_ATTR_ADVERTISED_SPEED",
+            Self::InterfaceType(_) => "SAI_PORT_ATTR_INTERFACE_TYPE",
+            Self::AdvertisedInterfaceTypes(_) => "SAI_PORT_ATTR_ADVERTISED_INTERFACE_TYPE",
+        }
+    }
+
+    /// Returns the SAI attribute list for this attribute, for a
+    /// `set_port_attribute` call.
+    pub fn to_sai_attrs(&self) -> PortAttrBuilder {
+        let builder = PortAttrBuilder::new();
+        match self {
+            Self::AutoNeg(enabled) => builder.auto_neg(*enabled),
+            Self::AdvertisedSpeeds(speeds) => builder.advertised_speeds(speeds),
+            Self::InterfaceType(interface_type) => builder.interface_type(interface_type.to_sai()),
+            Self::AdvertisedInterfaceTypes(types) => {
+                let types: Vec<i32> = types.iter().map(|t| t.to_sai()).collect();
+                builder.advertised_interface_types(&types)
+            }
+        }
+    }
+}
+
 /// Port lane mapping information.
 #[derive(Debug, Clone)]
 pub struct PortLaneMapping {
@@ -397,4 +438,29 @@ mod tests {
         assert_eq!(mapping.lane_count(), 4);
         assert_eq!(mapping.total_speed(), 100000);
     }
+
+    #[test]
+    fn test_negotiation_attr_to_sai_attrs() {
+        use sonic_sai::SaiAttributeValue;
+
+        let attrs = PortNegotiationAttr::AdvertisedInterfaceTypes(vec![
+            PortInterfaceType::Cr4,
+            PortInterfaceType::Sr4,
+        ])
+        .to_sai_attrs();
+        assert_eq!(attrs.len(), 1);
+        assert_eq!(
+            attrs.get(PortAttrBuilder::ADVERTISED_INTERFACE_TYPE),
+            Some(&SaiAttributeValue::I32List(vec![3, 6]))
+        );
+
+        let attrs = PortNegotiationAttr::AdvertisedSpeeds(vec![25000, 100000]).to_sai_attrs();
+        assert_eq!(
+            attrs.finish()[0].value,
+            SaiAttributeValue::U32List(vec![25000, 100000])
+        );
+
+        let attrs = PortNegotiationAttr::AutoNeg(true).to_sai_attrs();
+        assert_eq!(attrs.finish()[0].id, PortAttrBuilder::AUTO_NEG_MODE);
+    }
 }
@@ -0,0 +1,409 @@
+//! IP prefix trie for longest-prefix match and overlap checks.
+//!
+//! RouteOrch uses it to find the route covering a next hop address, and
+//! TunnelDecapOrch to look up and validate decap term destinations. IPv4
+//! and IPv6 prefixes live in separate branches of one binary trie whose
+//! nodes are kept in a single arena: a node is an index rather than its own
+//! allocation, and nodes freed by `remove` are reused by later inserts.
+
+use std::net::IpAddr;
+
+/// Marks an absent child.
+const NO_NODE: u32 = u32::MAX;
+
+/// Arena index of the IPv4 root.
+const V4_ROOT: u32 = 0;
+
+/// Arena index of the IPv6 root.
+const V6_ROOT: u32 = 1;
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    children: [u32; 2],
+    value: Option<V>,
+}
+
+impl<V> Node<V> {
+    fn empty() -> Self {
+        Self {
+            children: [NO_NODE; 2],
+            value: None,
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children == [NO_NODE; 2]
+    }
+}
+
+/// A set of IPv4 and IPv6 prefixes, each carrying a value.
+///
+/// Prefixes are given as an address and a length; host bits beyond the
+/// length are ignored. A length above the family's width (32 or 128) is
+/// treated as a host prefix.
+#[derive(Debug, Clone)]
+pub struct PrefixSet<V = ()> {
+    nodes: Vec<Node<V>>,
+    free: Vec<u32>,
+    len: usize,
+}
+
+impl<V> Default for PrefixSet<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> PrefixSet<V> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::empty(), Node::empty()],
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of prefixes in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set holds no prefixes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a prefix, returning the value it replaced.
+    pub fn insert(&mut self, addr: IpAddr, len: u8, value: V) -> Option<V> {
+        let node = self.find_or_create(addr, len);
+        let old = self.nodes[node as usize].value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns the value of a prefix, inserting `default()` if it is absent.
+    pub fn get_or_insert_with(
+        &mut self,
+        addr: IpAddr,
+        len: u8,
+        default: impl FnOnce() -> V,
+    ) -> &mut V {
+        let node = self.find_or_create(addr, len);
+        let value = &mut self.nodes[node as usize].value;
+        if value.is_none() {
+            self.len += 1;
+        }
+        value.get_or_insert_with(default)
+    }
+
+    /// Removes a prefix, returning its value. Nodes left without values
+    /// below them are freed.
+    pub fn remove(&mut self, addr: IpAddr, len: u8) -> Option<V> {
+        let (mut node, bits, len) = Self::walk_start(addr, len);
+        let mut path = [NO_NODE; 128];
+        for depth in 0..len {
+            path[depth as usize] = node;
+            node = self.nodes[node as usize].children[Self::bit(bits, depth)];
+            if node == NO_NODE {
+                return None;
+            }
+        }
+
+        let value = self.nodes[node as usize].value.take()?;
+        self.len -= 1;
+
+        let mut depth = len;
+        while depth > 0
+            && self.nodes[node as usize].value.is_none()
+            && self.nodes[node as usize].is_leaf()
+        {
+            depth -= 1;
+            let parent = path[depth as usize];
+            self.nodes[parent as usize].children[Self::bit(bits, depth)] = NO_NODE;
+            self.free.push(node);
+            node = parent;
+        }
+        Some(value)
+    }
+
+    /// Returns the value of an exact prefix.
+    pub fn get(&self, addr: IpAddr, len: u8) -> Option<&V> {
+        let node = self.find(addr, len)?;
+        self.nodes[node as usize].value.as_ref()
+    }
+
+    /// Returns the value of an exact prefix for modification.
+    pub fn get_mut(&mut self, addr: IpAddr, len: u8) -> Option<&mut V> {
+        let node = self.find(addr, len)?;
+        self.nodes[node as usize].value.as_mut()
+    }
+
+    /// Returns the longest prefix containing `addr`, as its length and
+    /// value.
+    pub fn longest_match(&self, addr: IpAddr) -> Option<(u8, &V)> {
+        let (mut node, bits, max_len) = Self::walk_start(addr, u8::MAX);
+        let mut best = self.nodes[node as usize].value.as_ref().map(|v| (0, v));
+        for depth in 0..max_len {
+            node = self.nodes[node as usize].children[Self::bit(bits, depth)];
+            if node == NO_NODE {
+                break;
+            }
+            if let Some(value) = &self.nodes[node as usize].value {
+                best = Some((depth + 1, value));
+            }
+        }
+        best
+    }
+
+    /// Returns true if some prefix in the set contains the given prefix
+    /// (including the prefix itself).
+    pub fn covers(&self, addr: IpAddr, len: u8) -> bool {
+        let (mut node, bits, len) = Self::walk_start(addr, len);
+        for depth in 0..len {
+            if self.nodes[node as usize].value.is_some() {
+                return true;
+            }
+            node = self.nodes[node as usize].children[Self::bit(bits, depth)];
+            if node == NO_NODE {
+                return false;
+            }
+        }
+        self.nodes[node as usize].value.is_some()
+    }
+
+    /// Returns true if some prefix in the set contains, or is contained
+    /// by, the given prefix.
+    pub fn overlaps(&self, addr: IpAddr, len: u8) -> bool {
+        let (mut node, bits, len) = Self::walk_start(addr, len);
+        for depth in 0..len {
+            if self.nodes[node as usize].value.is_some() {
+                return true;
+            }
+            node = self.nodes[node as usize].children[Self::bit(bits, depth)];
+            if node == NO_NODE {
+                return false;
+            }
+        }
+        // Removal prunes empty branches, so anything below holds a value
+        let node = &self.nodes[node as usize];
+        node.value.is_some() || !node.is_leaf()
+    }
+
+    fn find(&self, addr: IpAddr, len: u8) -> Option<u32> {
+        let (mut node, bits, len) = Self::walk_start(addr, len);
+        for depth in 0..len {
+            node = self.nodes[node as usize].children[Self::bit(bits, depth)];
+            if node == NO_NODE {
+                return None;
+            }
+        }
+        Some(node)
+    }
+
+    fn find_or_create(&mut self, addr: IpAddr, len: u8) -> u32 {
+        let (mut node, bits, len) = Self::walk_start(addr, len);
+        for depth in 0..len {
+            let bit = Self::bit(bits, depth);
+            let child = self.nodes[node as usize].children[bit];
+            node = if child == NO_NODE {
+                let child = self.alloc();
+                self.nodes[node as usize].children[bit] = child;
+                child
+            } else {
+                child
+            };
+        }
+        node
+    }
+
+    fn alloc(&mut self) -> u32 {
+        match self.free.pop() {
+            Some(node) => {
+                self.nodes[node as usize] = Node::empty();
+                node
+            }
+            None => {
+                self.nodes.push(Node::empty());
+                (self.nodes.len() - 1) as u32
+            }
+        }
+    }
+
+    /// Returns the family's root, the address left-aligned in 128 bits, and
+    /// `len` capped at the family's width.
+    fn walk_start(addr: IpAddr, len: u8) -> (u32, u128, u8) {
+        match addr {
+            IpAddr::V4(v4) => (V4_ROOT, u128::from(u32::from(v4)) << 96, len.min(32)),
+            IpAddr::V6(v6) => (V6_ROOT, u128::from(v6), len.min(128)),
+        }
+    }
+
+    fn bit(bits: u128, depth: u8) -> usize {
+        ((bits >> (127 - depth)) & 1) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn set(prefixes: &[(&str, u8)]) -> PrefixSet<String> {
+        let mut set = PrefixSet::new();
+        for (addr, len) in prefixes {
+            set.insert(ip(addr), *len, format!("{}/{}", addr, len));
+        }
+        set
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let set = set(&[
+            ("0.0.0.0", 0),
+            ("10.0.0.0", 8),
+            ("10.1.0.0", 16),
+            ("10.1.2.3", 32),
+        ]);
+        assert_eq!(set.len(), 4);
+
+        let lookup = |addr: &str| {
+            set.longest_match(ip(addr))
+                .map(|(len, v)| (len, v.as_str()))
+        };
+        assert_eq!(lookup("10.1.2.3"), Some((32, "10.1.2.3/32")));
+        assert_eq!(lookup("10.1.2.4"), Some((16, "10.1.0.0/16")));
+        assert_eq!(lookup("10.2.0.1"), Some((8, "10.0.0.0/8")));
+        assert_eq!(lookup("192.168.0.1"), Some((0, "0.0.0.0/0")));
+        // The IPv4 default route does not match IPv6 addresses
+        assert_eq!(lookup("::1"), None);
+    }
+
+    #[test]
+    fn test_ipv6_host_prefixes() {
+        let set = set(&[
+            ("2001:db8::", 32),
+            ("2001:db8::1", 128),
+            ("2001:db8::ffff:ffff:ffff:ffff", 128),
+        ]);
+
+        assert_eq!(set.longest_match(ip("2001:db8::1")).unwrap().0, 128);
+        assert_eq!(set.longest_match(ip("2001:db8::2")).unwrap().0, 32);
+        assert_eq!(
+            set.longest_match(ip("2001:db8::ffff:ffff:ffff:ffff"))
+                .unwrap()
+                .0,
+            128
+        );
+        assert!(set.longest_match(ip("2001:db9::1")).is_none());
+        assert!(set.get(ip("2001:db8::1"), 128).is_some());
+        assert!(set.get(ip("2001:db8::1"), 127).is_none());
+    }
+
+    #[test]
+    fn test_covers_and_overlaps() {
+        let set = set(&[("192.168.0.0", 16), ("10.1.1.0", 24)]);
+
+        assert!(set.covers(ip("192.168.8.0"), 24));
+        assert!(set.covers(ip("192.168.0.0"), 16));
+        assert!(!set.covers(ip("192.0.0.0"), 8));
+        assert!(!set.covers(ip("10.1.0.0"), 16));
+
+        assert!(set.overlaps(ip("192.168.8.0"), 24));
+        assert!(set.overlaps(ip("192.0.0.0"), 8));
+        assert!(set.overlaps(ip("10.1.0.0"), 16));
+        assert!(set.overlaps(ip("0.0.0.0"), 0));
+        assert!(!set.overlaps(ip("10.2.0.0"), 16));
+        assert!(!set.overlaps(ip("::"), 0));
+    }
+
+    #[test]
+    fn test_remove_prunes_and_reuses_nodes() {
+        let mut set = set(&[("10.0.0.0", 8), ("10.1.2.0", 24)]);
+        let nodes = set.nodes.len();
+
+        assert_eq!(
+            set.remove(ip("10.1.2.0"), 24).as_deref(),
+            Some("10.1.2.0/24")
+        );
+        assert_eq!(set.remove(ip("10.1.2.0"), 24), None);
+        assert_eq!(set.remove(ip("10.1.2.0"), 23), None);
+        assert_eq!(set.len(), 1);
+        assert!(set.covers(ip("10.1.2.0"), 24));
+        assert!(!set.overlaps(ip("11.0.0.0"), 8));
+        assert_eq!(set.longest_match(ip("10.1.2.3")).unwrap().0, 8);
+
+        // The /24 branch was freed, so re-adding it reuses its nodes
+        set.insert(ip("10.1.2.0"), 24, "again".to_string());
+        assert_eq!(set.nodes.len(), nodes);
+
+        set.remove(ip("10.0.0.0"), 8);
+        set.remove(ip("10.1.2.0"), 24);
+        assert!(set.is_empty());
+        assert!(!set.overlaps(ip("0.0.0.0"), 0));
+    }
+
+    #[test]
+    fn test_insert_replaces_and_ignores_host_bits() {
+        let mut set = PrefixSet::new();
+        assert_eq!(set.insert(ip("10.1.2.3"), 24, 1), None);
+        assert_eq!(set.insert(ip("10.1.2.0"), 24, 2), Some(1));
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.get(ip("10.1.2.255"), 24), Some(&2));
+
+        *set.get_mut(ip("10.1.2.0"), 24).unwrap() += 1;
+        assert_eq!(set.longest_match(ip("10.1.2.9")), Some((24, &3)));
+
+        *set.get_or_insert_with(ip("10.1.2.0"), 24, || 0) += 1;
+        *set.get_or_insert_with(ip("10.1.3.0"), 24, || 0) += 1;
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get(ip("10.1.2.0"), 24), Some(&4));
+        assert_eq!(set.get(ip("10.1.3.0"), 24), Some(&1));
+    }
+
+    #[test]
+    fn test_random_prefixes_match_linear_scan() {
+        // xorshift keeps the test deterministic without a rand dependency
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut set = PrefixSet::new();
+        let mut prefixes = Vec::new();
+        for _ in 0..2000 {
+            let addr = (next() as u32) & 0xff0f_ffff;
+            let len = (next() % 33) as u8;
+            let mask = u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0);
+            let network = addr & mask;
+            if set
+                .insert(IpAddr::V4(network.into()), len, (network, len))
+                .is_none()
+            {
+                prefixes.push((network, len, mask));
+            }
+        }
+
+        for _ in 0..5000 {
+            let addr = (next() as u32) & 0xff0f_ffff;
+            let expected = prefixes
+                .iter()
+                .filter(|(network, _, mask)| addr & mask == *network)
+                .map(|(_, len, _)| *len)
+                .max();
+            assert_eq!(
+                set.longest_match(IpAddr::V4(addr.into()))
+                    .map(|(len, _)| len),
+                expected
+            );
+        }
+    }
+}
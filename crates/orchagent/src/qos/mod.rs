@@ -8,6 +8,8 @@
 //! - Validated mapping ranges for TC, Queue, DSCP
 //! - Option types for optional WRED thresholds
 //! - HashMap for O(1) map lookups
+//! - Per-map reference counts from PORT_QOS_MAP bindings, so a map still
+//!   bound to a port cannot be deleted
 
 mod ffi;
 mod orch;
@@ -1,8 +1,11 @@
 //! QoS orchestration logic.
 
-use super::types::{QosMapEntry, QosStats, SchedulerEntry, WredProfile};
+use super::types::{
+    QosMapEntry, QosMapType, QosStats, RawSaiObjectId, SchedulerEntry, WredProfile,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use sonic_orch_common::TaskStatus;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -14,6 +17,25 @@ pub enum QosOrchError {
     InvalidWeight(u8),
     InvalidThreshold(u32),
     SaiError(String),
+    /// The map is still bound to ports; retry once they are unbound.
+    MapInUse {
+        name: String,
+        ref_count: u32,
+    },
+    /// A port binding names a map of a different type.
+    MapTypeMismatch {
+        name: String,
+        expected: QosMapType,
+        actual: QosMapType,
+    },
+    PortBindingNotFound(String),
+}
+
+impl QosOrchError {
+    /// Returns true if the operation may succeed when retried later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, QosOrchError::MapInUse { .. })
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -37,12 +59,24 @@ pub trait QosOrchCallbacks: Send + Sync {
     fn on_wred_profile_removed(&self, profile_name: &str);
 }
 
+/// QoS maps bound to a port by its PORT_QOS_MAP entry.
+#[derive(Debug, Clone, Default)]
+struct PortQosMapBinding {
+    /// Map names requested per map type.
+    requested: HashMap<QosMapType, String>,
+    /// OIDs of the maps currently bound per map type.
+    bound: HashMap<QosMapType, RawSaiObjectId>,
+}
+
 pub struct QosOrch {
     config: QosOrchConfig,
     stats: QosOrchStats,
     qos_maps: HashMap<String, QosMapEntry>,
     schedulers: HashMap<String, SchedulerEntry>,
     wred_profiles: HashMap<String, WredProfile>,
+    port_qos_maps: HashMap<String, PortQosMapBinding>,
+    /// Number of port bindings referencing each map, keyed by map OID.
+    map_ref_counts: HashMap<RawSaiObjectId, u32>,
 }
 
 impl QosOrch {
@@ -53,6 +87,8 @@ impl QosOrch {
             qos_maps: HashMap::new(),
             schedulers: HashMap::new(),
             wred_profiles: HashMap::new(),
+            port_qos_maps: HashMap::new(),
+            map_ref_counts: HashMap::new(),
         }
     }
 
@@ -104,10 +140,43 @@ impl QosOrch {
                 .with_object_type("qos_map")
         );
 
+        // Bind the map to ports whose PORT_QOS_MAP arrived first
+        let waiting: Vec<String> = self
+            .port_qos_maps
+            .iter()
+            .filter(|(_, binding)| binding.requested.values().any(|n| *n == name))
+            .map(|(port, _)| port.clone())
+            .collect();
+        for port in waiting {
+            let _ = self.resolve_port_qos_maps(&port);
+        }
+
         Ok(())
     }
 
+    /// Removes a QoS map.
+    ///
+    /// Fails with the retryable [`QosOrchError::MapInUse`] while any port
+    /// binding still references the map.
     pub fn remove_map(&mut self, name: &str) -> Result<QosMapEntry, QosOrchError> {
+        let ref_count = self
+            .qos_maps
+            .get(name)
+            .map_or(0, |map| self.map_ref_count(map.sai_oid));
+        if ref_count > 0 {
+            audit_log!(
+                AuditRecord::new(AuditCategory::ResourceDelete, "QosOrch", "remove_map")
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(name)
+                    .with_object_type("qos_map")
+                    .with_error(format!("QoS map still referenced by {} port(s)", ref_count))
+            );
+            return Err(QosOrchError::MapInUse {
+                name: name.to_string(),
+                ref_count,
+            });
+        }
+
         self.qos_maps
             .remove(name)
             .ok_or_else(|| {
@@ -268,6 +337,141 @@ impl QosOrch {
             .ok_or_else(|| QosOrchError::WredNotFound(name.to_string()))
     }
 
+    /// Sets the QoS maps bound to a port (PORT_QOS_MAP).
+    ///
+    /// Maps that do not exist yet are bound when they are added; until then
+    /// the port keeps any map it was previously bound to for that type and
+    /// `TaskStatus::NeedRetry` is returned.
+    pub fn set_port_qos_map(
+        &mut self,
+        port_name: &str,
+        maps: &[(QosMapType, String)],
+    ) -> Result<TaskStatus, QosOrchError> {
+        self.port_qos_maps
+            .entry(port_name.to_string())
+            .or_default()
+            .requested = maps.iter().cloned().collect();
+
+        let status = self.resolve_port_qos_maps(port_name)?;
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "QosOrch", "set_port_qos_map")
+                .with_outcome(if status == TaskStatus::Success {
+                    AuditOutcome::Success
+                } else {
+                    AuditOutcome::InProgress
+                })
+                .with_object_id(port_name)
+                .with_object_type("port_qos_map")
+        );
+        Ok(status)
+    }
+
+    /// Removes a port's PORT_QOS_MAP binding, releasing its maps.
+    pub fn remove_port_qos_map(&mut self, port_name: &str) -> Result<(), QosOrchError> {
+        let binding = self
+            .port_qos_maps
+            .remove(port_name)
+            .ok_or_else(|| QosOrchError::PortBindingNotFound(port_name.to_string()))?;
+        for oid in binding.bound.into_values() {
+            self.decrease_map_ref_count(oid);
+        }
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "QosOrch",
+            "remove_port_qos_map"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(port_name)
+        .with_object_type("port_qos_map"));
+        Ok(())
+    }
+
+    /// Returns the OID of the map of `map_type` bound to a port.
+    pub fn get_port_qos_map(
+        &self,
+        port_name: &str,
+        map_type: QosMapType,
+    ) -> Option<RawSaiObjectId> {
+        self.port_qos_maps
+            .get(port_name)
+            .and_then(|binding| binding.bound.get(&map_type).copied())
+    }
+
+    /// Returns the number of port bindings referencing a map.
+    pub fn map_ref_count(&self, map_oid: RawSaiObjectId) -> u32 {
+        self.map_ref_counts.get(&map_oid).copied().unwrap_or(0)
+    }
+
+    /// Binds every requested map of a port that exists, replacing the map
+    /// previously bound for its type.
+    fn resolve_port_qos_maps(&mut self, port_name: &str) -> Result<TaskStatus, QosOrchError> {
+        let Some(binding) = self.port_qos_maps.get(port_name) else {
+            return Ok(TaskStatus::Success);
+        };
+
+        let mut wanted = HashMap::new();
+        let mut pending = false;
+        for (&map_type, name) in &binding.requested {
+            match self.qos_maps.get(name) {
+                Some(map) if map.map_type != map_type => {
+                    return Err(QosOrchError::MapTypeMismatch {
+                        name: name.clone(),
+                        expected: map_type,
+                        actual: map.map_type,
+                    });
+                }
+                Some(map) if map.sai_oid != 0 => {
+                    wanted.insert(map_type, map.sai_oid);
+                }
+                _ => pending = true,
+            }
+        }
+
+        let mut changes = Vec::new();
+        for (&map_type, &old) in &binding.bound {
+            if !binding.requested.contains_key(&map_type) {
+                changes.push((map_type, Some(old), None));
+            }
+        }
+        for (&map_type, &new) in &wanted {
+            let old = binding.bound.get(&map_type).copied();
+            if old != Some(new) {
+                changes.push((map_type, old, Some(new)));
+            }
+        }
+
+        for (map_type, old, new) in changes {
+            if let Some(old) = old {
+                self.decrease_map_ref_count(old);
+            }
+            if let Some(new) = new {
+                *self.map_ref_counts.entry(new).or_insert(0) += 1;
+            }
+            if let Some(binding) = self.port_qos_maps.get_mut(port_name) {
+                match new {
+                    Some(new) => binding.bound.insert(map_type, new),
+                    None => binding.bound.remove(&map_type),
+                };
+            }
+        }
+
+        Ok(if pending {
+            TaskStatus::NeedRetry
+        } else {
+            TaskStatus::Success
+        })
+    }
+
+    fn decrease_map_ref_count(&mut self, map_oid: RawSaiObjectId) {
+        if let Some(count) = self.map_ref_counts.get_mut(&map_oid) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.map_ref_counts.remove(&map_oid);
+            }
+        }
+    }
+
     pub fn map_count(&self) -> usize {
         self.qos_maps.len()
     }
@@ -722,4 +926,203 @@ mod tests {
         let orch = QosOrch::new(QosOrchConfig::default());
         assert!(orch.get_wred_profile("nonexistent").is_none());
     }
+
+    fn create_bound_map(name: &str, map_type: QosMapType, oid: RawSaiObjectId) -> QosMapEntry {
+        let mut map = QosMapEntry::new(name.to_string(), map_type);
+        map.add_mapping(0, 0);
+        map.sai_oid = oid;
+        map
+    }
+
+    fn port_maps(dscp_to_tc: &str, tc_to_queue: &str) -> Vec<(QosMapType, String)> {
+        vec![
+            (QosMapType::DscpToTc, dscp_to_tc.to_string()),
+            (QosMapType::TcToQueue, tc_to_queue.to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_remove_map_before_unbind() {
+        let mut orch = QosOrch::new(QosOrchConfig::default());
+        orch.add_map(create_bound_map(
+            "AZURE",
+            QosMapType::DscpToTc,
+            0x1400000000001,
+        ))
+        .unwrap();
+        orch.add_map(create_bound_map(
+            "AZURE_TCQ",
+            QosMapType::TcToQueue,
+            0x1400000000002,
+        ))
+        .unwrap();
+
+        for port in ["Ethernet0", "Ethernet4"] {
+            assert_eq!(
+                orch.set_port_qos_map(port, &port_maps("AZURE", "AZURE_TCQ"))
+                    .unwrap(),
+                TaskStatus::Success
+            );
+        }
+        assert_eq!(orch.map_ref_count(0x1400000000001), 2);
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::TcToQueue),
+            Some(0x1400000000002)
+        );
+
+        let err = orch.remove_map("AZURE").unwrap_err();
+        assert!(err.is_retryable());
+        assert!(matches!(err, QosOrchError::MapInUse { ref_count: 2, .. }));
+        assert!(orch.get_map("AZURE").is_some());
+
+        // Still in use by Ethernet4
+        orch.remove_port_qos_map("Ethernet0").unwrap();
+        assert!(orch.remove_map("AZURE").unwrap_err().is_retryable());
+
+        orch.remove_port_qos_map("Ethernet4").unwrap();
+        assert!(orch.remove_map("AZURE").is_ok());
+        assert!(orch.remove_map("AZURE_TCQ").is_ok());
+    }
+
+    #[test]
+    fn test_unbind_then_remove_map() {
+        let mut orch = QosOrch::new(QosOrchConfig::default());
+        orch.add_map(create_bound_map(
+            "AZURE",
+            QosMapType::DscpToTc,
+            0x1400000000001,
+        ))
+        .unwrap();
+        orch.set_port_qos_map("Ethernet0", &[(QosMapType::DscpToTc, "AZURE".to_string())])
+            .unwrap();
+
+        orch.set_port_qos_map("Ethernet0", &[]).unwrap();
+        assert_eq!(orch.map_ref_count(0x1400000000001), 0);
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::DscpToTc),
+            None
+        );
+        assert!(orch.remove_map("AZURE").is_ok());
+
+        assert!(matches!(
+            orch.remove_port_qos_map("Ethernet8"),
+            Err(QosOrchError::PortBindingNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_replace_map_on_live_port() {
+        let mut orch = QosOrch::new(QosOrchConfig::default());
+        orch.add_map(create_bound_map(
+            "AZURE",
+            QosMapType::DscpToTc,
+            0x1400000000001,
+        ))
+        .unwrap();
+        orch.add_map(create_bound_map(
+            "AZURE_V2",
+            QosMapType::DscpToTc,
+            0x1400000000003,
+        ))
+        .unwrap();
+        orch.set_port_qos_map("Ethernet0", &[(QosMapType::DscpToTc, "AZURE".to_string())])
+            .unwrap();
+
+        orch.set_port_qos_map(
+            "Ethernet0",
+            &[(QosMapType::DscpToTc, "AZURE_V2".to_string())],
+        )
+        .unwrap();
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::DscpToTc),
+            Some(0x1400000000003)
+        );
+        assert_eq!(orch.map_ref_count(0x1400000000001), 0);
+        assert_eq!(orch.map_ref_count(0x1400000000003), 1);
+        assert!(orch.remove_map("AZURE").is_ok());
+        assert!(orch.remove_map("AZURE_V2").unwrap_err().is_retryable());
+
+        // Replacing with a map that does not exist yet keeps the old one bound
+        assert_eq!(
+            orch.set_port_qos_map(
+                "Ethernet0",
+                &[(QosMapType::DscpToTc, "AZURE_V3".to_string())]
+            )
+            .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert_eq!(orch.map_ref_count(0x1400000000003), 1);
+        orch.add_map(create_bound_map(
+            "AZURE_V3",
+            QosMapType::DscpToTc,
+            0x1400000000004,
+        ))
+        .unwrap();
+        assert_eq!(orch.map_ref_count(0x1400000000003), 0);
+        assert_eq!(orch.map_ref_count(0x1400000000004), 1);
+    }
+
+    #[test]
+    fn test_port_binding_before_map_exists() {
+        let mut orch = QosOrch::new(QosOrchConfig::default());
+
+        assert_eq!(
+            orch.set_port_qos_map("Ethernet0", &port_maps("AZURE", "AZURE_TCQ"))
+                .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::DscpToTc),
+            None
+        );
+
+        orch.add_map(create_bound_map(
+            "AZURE",
+            QosMapType::DscpToTc,
+            0x1400000000001,
+        ))
+        .unwrap();
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::DscpToTc),
+            Some(0x1400000000001)
+        );
+        assert_eq!(
+            orch.get_port_qos_map("Ethernet0", QosMapType::TcToQueue),
+            None
+        );
+
+        orch.add_map(create_bound_map(
+            "AZURE_TCQ",
+            QosMapType::TcToQueue,
+            0x1400000000002,
+        ))
+        .unwrap();
+        assert_eq!(orch.map_ref_count(0x1400000000002), 1);
+
+        // A retry of the original task is now a no-op
+        assert_eq!(
+            orch.set_port_qos_map("Ethernet0", &port_maps("AZURE", "AZURE_TCQ"))
+                .unwrap(),
+            TaskStatus::Success
+        );
+        assert_eq!(orch.map_ref_count(0x1400000000001), 1);
+        assert_eq!(orch.map_ref_count(0x1400000000002), 1);
+    }
+
+    #[test]
+    fn test_port_binding_map_type_mismatch() {
+        let mut orch = QosOrch::new(QosOrchConfig::default());
+        orch.add_map(create_bound_map(
+            "AZURE",
+            QosMapType::DscpToTc,
+            0x1400000000001,
+        ))
+        .unwrap();
+
+        assert!(matches!(
+            orch.set_port_qos_map("Ethernet0", &[(QosMapType::TcToQueue, "AZURE".to_string())]),
+            Err(QosOrchError::MapTypeMismatch { .. })
+        ));
+        assert_eq!(orch.map_ref_count(0x1400000000001), 0);
+    }
 }
@@ -0,0 +1,241 @@
+//! Route bulking.
+//!
+//! RouteOrch accumulates route entry operations drained from the consumer
+//! and programs them through the SAI bulk route API in batches, instead of
+//! issuing one SAI call per prefix.
+
+use sonic_orch_common::KeyOpFieldsValues;
+use sonic_sai::types::RawSaiObjectId;
+use sonic_types::IpPrefix;
+use std::collections::HashSet;
+
+use super::nhg::NextHopGroupKey;
+use super::types::RouteKey;
+
+/// A single route entry operation in a bulk request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteBulkOp {
+    /// Create a route entry.
+    Create {
+        vrf_id: RawSaiObjectId,
+        prefix: IpPrefix,
+        nhg_id: Option<RawSaiObjectId>,
+        blackhole: bool,
+    },
+    /// Update the next-hop/action of an existing route entry.
+    Set {
+        vrf_id: RawSaiObjectId,
+        prefix: IpPrefix,
+        nhg_id: Option<RawSaiObjectId>,
+        blackhole: bool,
+    },
+    /// Remove a route entry.
+    Remove {
+        vrf_id: RawSaiObjectId,
+        prefix: IpPrefix,
+    },
+}
+
+impl RouteBulkOp {
+    /// Returns the VRF ID of the route entry.
+    pub fn vrf_id(&self) -> RawSaiObjectId {
+        match self {
+            Self::Create { vrf_id, .. }
+            | Self::Set { vrf_id, .. }
+            | Self::Remove { vrf_id, .. } => *vrf_id,
+        }
+    }
+
+    /// Returns the prefix of the route entry.
+    pub fn prefix(&self) -> &IpPrefix {
+        match self {
+            Self::Create { prefix, .. }
+            | Self::Set { prefix, .. }
+            | Self::Remove { prefix, .. } => prefix,
+        }
+    }
+
+    /// Returns the route key (VRF + prefix).
+    pub fn key(&self) -> RouteKey {
+        RouteKey::new(self.vrf_id(), self.prefix().clone())
+    }
+
+    /// Returns the next-hop ID and blackhole flag programmed by this operation.
+    ///
+    /// Removals return `(None, false)`.
+    pub fn next_hop(&self) -> (Option<RawSaiObjectId>, bool) {
+        match self {
+            Self::Create {
+                nhg_id, blackhole, ..
+            }
+            | Self::Set {
+                nhg_id, blackhole, ..
+            } => (*nhg_id, *blackhole),
+            Self::Remove { .. } => (None, false),
+        }
+    }
+}
+
+/// A route operation ready for SAI, with what is needed to commit it.
+#[derive(Debug, Clone)]
+pub(crate) struct PreparedRoute {
+    /// The SAI operation.
+    pub op: RouteBulkOp,
+    /// Next-hop group the route points to once the operation succeeds.
+    pub nhg_key: NextHopGroupKey,
+    /// VRF the next-hops are resolved in, for leaked routes.
+    pub nexthop_vrf_id: Option<RawSaiObjectId>,
+    /// What the route waits for when `nhg_key` is a fallback: its ECMP group
+    /// when `nhg_key` is a single next hop, or its next hops when `nhg_key`
+    /// is empty because none of them resolves.
+    pub overflow_nhg_key: Option<NextHopGroupKey>,
+}
+
+impl PreparedRoute {
+    /// Wraps a removal, which needs nothing beyond the SAI operation.
+    pub fn removal(op: RouteBulkOp) -> Self {
+        Self {
+            op,
+            nhg_key: NextHopGroupKey::new(),
+            nexthop_vrf_id: None,
+            overflow_nhg_key: None,
+        }
+    }
+
+    /// VRF the next-hops are resolved in.
+    pub fn nh_vrf_id(&self) -> RawSaiObjectId {
+        self.nexthop_vrf_id.unwrap_or(self.op.vrf_id())
+    }
+}
+
+/// A queued route operation together with what is needed to commit or retry it.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingRouteOp {
+    /// The prepared operation.
+    pub route: PreparedRoute,
+    /// The originating task, re-queued if the SAI operation fails.
+    pub task: KeyOpFieldsValues,
+}
+
+/// Accumulates route operations until they are flushed in batches.
+#[derive(Debug)]
+pub(crate) struct RouteBulker {
+    max_bulk_size: usize,
+    pending: Vec<PendingRouteOp>,
+    keys: HashSet<RouteKey>,
+}
+
+impl RouteBulker {
+    /// Creates a bulker that flushes at most `max_bulk_size` entries per call.
+    pub fn new(max_bulk_size: usize) -> Self {
+        Self {
+            max_bulk_size: max_bulk_size.max(1),
+            pending: Vec::new(),
+            keys: HashSet::new(),
+        }
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true if no operations are queued.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns true if a full batch is queued.
+    pub fn is_full(&self) -> bool {
+        self.pending.len() >= self.max_bulk_size
+    }
+
+    /// Returns true if an operation for the route is already queued.
+    ///
+    /// A route may appear at most once per flush, since the commit of each
+    /// entry depends on the table state the operation was prepared against.
+    pub fn contains(&self, key: &RouteKey) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Queues an operation.
+    pub fn push(&mut self, pending: PendingRouteOp) {
+        self.keys.insert(pending.route.op.key());
+        self.pending.push(pending);
+    }
+
+    /// Takes all queued operations, split into batches of at most
+    /// `max_bulk_size` entries.
+    pub fn take_batches(&mut self) -> Vec<Vec<PendingRouteOp>> {
+        self.keys.clear();
+        let mut pending = std::mem::take(&mut self.pending).into_iter();
+        let mut batches = Vec::new();
+        loop {
+            let batch: Vec<_> = pending.by_ref().take(self.max_bulk_size).collect();
+            if batch.is_empty() {
+                break;
+            }
+            batches.push(batch);
+        }
+        batches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remove_op(i: u32) -> PendingRouteOp {
+        let prefix: IpPrefix = format!("10.0.{}.0/24", i).parse().unwrap();
+        PendingRouteOp {
+            route: PreparedRoute::removal(RouteBulkOp::Remove { vrf_id: 0, prefix }),
+            task: KeyOpFieldsValues::del(format!("10.0.{}.0/24", i)),
+        }
+    }
+
+    #[test]
+    fn test_bulker_batches() {
+        let mut bulker = RouteBulker::new(4);
+        for i in 0..10 {
+            bulker.push(remove_op(i));
+        }
+        assert_eq!(bulker.len(), 10);
+        assert!(bulker.is_full());
+        assert!(bulker.contains(&remove_op(3).route.op.key()));
+
+        let batches = bulker.take_batches();
+        assert_eq!(
+            batches.iter().map(|b| b.len()).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert!(bulker.is_empty());
+        assert!(!bulker.contains(&remove_op(3).route.op.key()));
+        assert!(bulker.take_batches().is_empty());
+    }
+
+    #[test]
+    fn test_bulker_zero_size_clamped() {
+        let mut bulker = RouteBulker::new(0);
+        bulker.push(remove_op(0));
+        bulker.push(remove_op(1));
+        assert_eq!(bulker.take_batches().len(), 2);
+    }
+
+    #[test]
+    fn test_bulk_op_accessors() {
+        let prefix: IpPrefix = "10.0.0.0/24".parse().unwrap();
+        let op = RouteBulkOp::Create {
+            vrf_id: 0x10,
+            prefix: prefix.clone(),
+            nhg_id: Some(0x2000),
+            blackhole: false,
+        };
+        assert_eq!(op.vrf_id(), 0x10);
+        assert_eq!(op.prefix(), &prefix);
+        assert_eq!(op.next_hop(), (Some(0x2000), false));
+        assert_eq!(op.key(), RouteKey::new(0x10, prefix.clone()));
+
+        let op = RouteBulkOp::Remove { vrf_id: 0, prefix };
+        assert_eq!(op.next_hop(), (None, false));
+    }
+}
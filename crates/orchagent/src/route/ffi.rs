@@ -6,7 +6,9 @@
 use std::cell::RefCell;
 use std::ffi::{c_char, CStr};
 
+use sonic_ffi_bridge::RouteQuery;
 use sonic_sai::types::RawSaiObjectId;
+use sonic_types::IpPrefix;
 
 use super::nhg::NextHopGroupKey;
 use super::orch::RouteOrch;
@@ -33,6 +35,23 @@ pub fn unregister_route_orch() {
     });
 }
 
+/// Lets C++ callers (NeighOrch) query routes through
+/// `sonic_ffi_bridge::register_route_orch`.
+impl RouteQuery for RouteOrch {
+    fn has_route(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> bool {
+        RouteOrch::has_route(self, vrf_id, prefix)
+    }
+
+    fn nhg_key(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Option<String> {
+        self.get_route(vrf_id, prefix)
+            .map(|route| route.nhg.nhg_key.to_string())
+    }
+
+    fn route_count(&self, vrf_id: RawSaiObjectId) -> u64 {
+        RouteOrch::route_count(self, vrf_id) as u64
+    }
+}
+
 /// Returns true if the RouteOrch is registered.
 #[no_mangle]
 pub extern "C" fn rust_route_orch_is_registered() -> bool {
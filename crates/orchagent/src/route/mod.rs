@@ -4,7 +4,13 @@
 //! - Route entry creation and deletion
 //! - Next-hop group management with safe reference counting
 //! - ECMP (Equal-Cost Multi-Path) routing
-//! - VRF (Virtual Routing and Forwarding) support
+//! - VRF (Virtual Routing and Forwarding) support, including route leaking
+//!   between VRFs via the `nexthop_vrf` field
+//! - Bulk route programming through the SAI bulk route API
+//! - Optional programmed-route responses to APPL_STATE_DB, so BGP can
+//!   suppress advertisement of routes not yet in hardware
+//! - Falling back to a single next hop when the ASIC runs out of ECMP
+//!   groups, and upgrading those routes once groups free up
 //!
 //! # Safety Improvements over C++
 //!
@@ -16,14 +22,16 @@
 //! In Rust, we use `SyncMap` which returns `Err(KeyNotFound)` instead of
 //! silently creating entries.
 
+mod bulk;
 mod ffi;
 mod nexthop;
 mod nhg;
 mod orch;
 mod types;
 
+pub use bulk::RouteBulkOp;
 pub use ffi::{register_route_orch, unregister_route_orch};
 pub use nexthop::{NextHopFlags, NextHopKey};
 pub use nhg::{NextHopGroupEntry, NextHopGroupKey, NextHopGroupTable};
-pub use orch::{RouteError, RouteOrch, RouteOrchCallbacks, RouteOrchConfig};
-pub use types::{RouteEntry, RouteKey, RouteNhg, RouteTables};
+pub use orch::{RouteError, RouteOrch, RouteOrchCallbacks, RouteOrchConfig, RouteOrchStats};
+pub use types::{RouteEntry, RouteKey, RouteNhg, RouteResponse, RouteResponseStatus, RouteTables};
@@ -220,6 +220,8 @@ pub struct NextHopGroupEntry {
     eligible_for_default_route_nh_swap: bool,
     /// Whether this NHG is currently swapped with default route.
     is_default_route_nh_swap: bool,
+    /// VRF the members were resolved in.
+    vrf_id: RawSaiObjectId,
 }
 
 impl NextHopGroupEntry {
@@ -235,6 +237,7 @@ impl NextHopGroupEntry {
             nh_member_install_count: 0,
             eligible_for_default_route_nh_swap: false,
             is_default_route_nh_swap: false,
+            vrf_id: 0,
         }
     }
 
@@ -243,6 +246,16 @@ impl NextHopGroupEntry {
         self.next_hop_group_id
     }
 
+    /// Returns the VRF the members were resolved in.
+    pub fn vrf_id(&self) -> RawSaiObjectId {
+        self.vrf_id
+    }
+
+    /// Sets the VRF the members were resolved in.
+    pub fn set_vrf_id(&mut self, vrf_id: RawSaiObjectId) {
+        self.vrf_id = vrf_id;
+    }
+
     /// Returns the current reference count.
     pub fn ref_count(&self) -> u32 {
         self.ref_count.load(Ordering::SeqCst)
@@ -345,11 +358,13 @@ impl NextHopGroupEntry {
     }
 }
 
-/// Table of next-hop groups indexed by their key.
+/// Table of next-hop groups indexed by the VRF their members resolve in and
+/// their key. The same next hops leaked from two VRFs are two groups.
 ///
 /// This uses `sonic_orch_common::SyncMap` internally to prevent
 /// auto-vivification bugs.
-pub type NextHopGroupTable = sonic_orch_common::SyncMap<NextHopGroupKey, NextHopGroupEntry>;
+pub type NextHopGroupTable =
+    sonic_orch_common::SyncMap<(RawSaiObjectId, NextHopGroupKey), NextHopGroupEntry>;
 
 #[cfg(test)]
 mod tests {
@@ -5,17 +5,26 @@
 
 use async_trait::async_trait;
 use log::{debug, error, info, warn};
-use sonic_orch_common::{Consumer, ConsumerConfig, KeyOpFieldsValues, Operation, Orch, SyncMap};
+use sonic_orch_common::{
+    Constraint, Consumer, ConsumerConfig, KeyOpFieldsValues, NextHopUpdate, Operation, Orch,
+    RetryCache, Subscription, SyncMap, VrfUpdate,
+};
 use sonic_sai::types::RawSaiObjectId;
-use sonic_types::IpPrefix;
+use sonic_types::{IpAddress, IpPrefix};
 use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use super::bulk::{PendingRouteOp, PreparedRoute, RouteBulkOp, RouteBulker};
 use super::nexthop::NextHopKey;
 use super::nhg::{NextHopGroupEntry, NextHopGroupKey, NextHopGroupTable};
-use super::types::{RouteEntry, RouteNhg, RouteTables};
+use super::types::{
+    RouteEntry, RouteKey, RouteNhg, RouteResponse, RouteResponseStatus, RouteTables,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use crate::prefix_set::PrefixSet;
 
 /// Error type for RouteOrch operations.
 #[derive(Debug, thiserror::Error)]
@@ -29,6 +38,9 @@ pub enum RouteError {
     #[error("Max next-hop groups reached ({0})")]
     MaxNhgReached(usize),
 
+    #[error("Next-hop group resources exhausted: {0}")]
+    NhgResourceExhausted(String),
+
     #[error("Route not found: {0}")]
     RouteNotFound(String),
 
@@ -48,6 +60,24 @@ pub enum RouteError {
     RefCountError(String),
 }
 
+impl RouteError {
+    /// Returns true if the error means no further next-hop group can be
+    /// created, either by configuration or because the ASIC ran out.
+    pub fn is_nhg_exhausted(&self) -> bool {
+        matches!(self, Self::MaxNhgReached(_) | Self::NhgResourceExhausted(_))
+    }
+
+    /// Returns true if a route task that failed with this error may succeed
+    /// once SAI resources free up or a dependency appears.
+    pub fn is_retryable(&self) -> bool {
+        self.is_nhg_exhausted()
+            || matches!(
+                self,
+                Self::VrfNotFound(_) | Self::NextHopNotResolved(_) | Self::SaiError(_)
+            )
+    }
+}
+
 /// Result type for RouteOrch operations.
 pub type Result<T> = std::result::Result<T, RouteError>;
 
@@ -60,6 +90,16 @@ pub struct RouteOrchConfig {
     pub ordered_ecmp: bool,
     /// Default packet action for routes.
     pub default_action_drop: bool,
+    /// Maximum number of route entries per SAI bulk call.
+    pub max_bulk_size: usize,
+    /// Whether to publish the final status of each route operation to the
+    /// APPL_STATE_DB ROUTE_TABLE (BGP suppress-fib-pending).
+    pub publish_programmed_routes: bool,
+    /// Delay before route tasks that SAI rejected are retried, doubled for
+    /// each consecutive failure.
+    pub sai_retry_backoff: Duration,
+    /// Upper bound for the SAI retry delay.
+    pub max_sai_retry_backoff: Duration,
 }
 
 impl Default for RouteOrchConfig {
@@ -68,10 +108,37 @@ impl Default for RouteOrchConfig {
             max_nhg_count: 1024,
             ordered_ecmp: false,
             default_action_drop: true,
+            max_bulk_size: 512,
+            publish_programmed_routes: false,
+            sai_retry_backoff: Duration::from_millis(100),
+            max_sai_retry_backoff: Duration::from_secs(10),
         }
     }
 }
 
+/// Constraint of route tasks waiting for SAI to accept them again.
+fn sai_retry_constraint() -> Constraint {
+    Constraint::resource("ROUTE")
+}
+
+/// Statistics for RouteOrch operations.
+#[derive(Debug, Clone, Default)]
+pub struct RouteOrchStats {
+    /// Routes programmed with a single next hop because no ECMP group
+    /// could be created.
+    pub nhg_overflows: u64,
+    /// Overflow routes moved back onto their full ECMP group.
+    pub nhg_overflow_upgrades: u64,
+    /// Routes currently waiting in the overflow set.
+    pub overflow_routes: usize,
+    /// Next-hop groups still available according to the last CRM report.
+    pub crm_nhg_available: Option<u32>,
+    /// Routes currently dropped because none of their next hops resolves.
+    pub unresolved_routes: usize,
+    /// Dropped routes programmed once a next hop resolved.
+    pub unresolved_upgrades: u64,
+}
+
 /// Callback trait for RouteOrch to interact with other Orchs.
 #[async_trait]
 pub trait RouteOrchCallbacks: Send + Sync {
@@ -81,12 +148,39 @@ pub trait RouteOrchCallbacks: Send + Sync {
     /// Gets the SAI ID for a next-hop from NeighOrch.
     fn get_next_hop_id(&self, nexthop: &NextHopKey) -> Option<RawSaiObjectId>;
 
+    /// Gets the SAI ID for a next-hop resolved in the given VRF.
+    ///
+    /// Leaked routes resolve their next hops in the next-hop VRF rather than
+    /// their own. The default implementation ignores the VRF.
+    fn get_next_hop_id_in_vrf(
+        &self,
+        _vrf_id: RawSaiObjectId,
+        nexthop: &NextHopKey,
+    ) -> Option<RawSaiObjectId> {
+        self.get_next_hop_id(nexthop)
+    }
+
     /// Gets the router interface ID for an interface from IntfsOrch.
     fn get_router_intf_id(&self, alias: &str) -> Option<RawSaiObjectId>;
 
     /// Checks if a VRF exists.
     fn vrf_exists(&self, vrf_id: RawSaiObjectId) -> bool;
 
+    /// Returns the number of next-hop groups CrmOrch reports as still
+    /// available in the ASIC.
+    ///
+    /// Returns None if CRM has not polled the resource yet.
+    fn crm_nhg_available(&self) -> Option<u32> {
+        None
+    }
+
+    /// Looks up a VRF ID by name in VrfOrch.
+    ///
+    /// Returns None if the VRF has not been created yet.
+    fn get_vrf_id(&self, _vrf_name: &str) -> Option<RawSaiObjectId> {
+        None
+    }
+
     /// Increments next-hop ref count in NeighOrch.
     fn increase_next_hop_ref_count(&self, nexthop: &NextHopKey);
 
@@ -105,8 +199,16 @@ pub trait RouteOrchCallbacks: Send + Sync {
     /// Decrements VRF ref count.
     fn decrease_vrf_ref_count(&self, vrf_id: RawSaiObjectId);
 
-    /// Creates a next-hop group in SAI.
-    async fn sai_create_nhg(&self, nhg_key: &NextHopGroupKey) -> Result<RawSaiObjectId>;
+    /// Creates a next-hop group in SAI, with its members resolved in
+    /// `vrf_id`.
+    ///
+    /// Returns `NhgResourceExhausted` when SAI reports insufficient resources
+    /// or a full table.
+    async fn sai_create_nhg(
+        &self,
+        vrf_id: RawSaiObjectId,
+        nhg_key: &NextHopGroupKey,
+    ) -> Result<RawSaiObjectId>;
 
     /// Removes a next-hop group from SAI.
     async fn sai_remove_nhg(&self, nhg_id: RawSaiObjectId) -> Result<()>;
@@ -131,6 +233,46 @@ pub trait RouteOrchCallbacks: Send + Sync {
         nhg_id: Option<RawSaiObjectId>,
         blackhole: bool,
     ) -> Result<()>;
+
+    /// Programs a batch of route entry operations in SAI.
+    ///
+    /// Returns one result per operation, in order. The default implementation
+    /// falls back to the single-entry calls; implementations backed by the SAI
+    /// bulk route API should override it.
+    async fn sai_bulk_route(&self, ops: &[RouteBulkOp]) -> Vec<Result<()>> {
+        let mut results = Vec::with_capacity(ops.len());
+        for op in ops {
+            let result = match op {
+                RouteBulkOp::Create {
+                    vrf_id,
+                    prefix,
+                    nhg_id,
+                    blackhole,
+                } => {
+                    self.sai_create_route(*vrf_id, prefix, *nhg_id, *blackhole)
+                        .await
+                }
+                RouteBulkOp::Set {
+                    vrf_id,
+                    prefix,
+                    nhg_id,
+                    blackhole,
+                } => {
+                    self.sai_set_route(*vrf_id, prefix, *nhg_id, *blackhole)
+                        .await
+                }
+                RouteBulkOp::Remove { vrf_id, prefix } => {
+                    self.sai_remove_route(*vrf_id, prefix).await
+                }
+            };
+            results.push(result);
+        }
+        results
+    }
+
+    /// Publishes the route responses gathered during one drain cycle to the
+    /// APPL_STATE_DB ROUTE_TABLE.
+    fn publish_route_responses(&self, _responses: &[RouteResponse]) {}
 }
 
 /// RouteOrch - Manages IP route programming.
@@ -147,7 +289,10 @@ pub struct RouteOrch {
     /// Synced routes indexed by VRF ID and prefix.
     synced_routes: RouteTables,
 
-    /// Synced next-hop groups.
+    /// Prefixes of `synced_routes` per VRF, for longest-prefix match.
+    route_prefixes: HashMap<RawSaiObjectId, PrefixSet<IpPrefix>>,
+
+    /// Synced next-hop groups, per VRF their members resolve in.
     /// Using SyncMap to prevent auto-vivification!
     synced_nhgs: NextHopGroupTable,
 
@@ -158,20 +303,70 @@ pub struct RouteOrch {
     callbacks: Option<Arc<dyn RouteOrchCallbacks>>,
 
     /// Pending NHG removals (deferred until ref_count == 0).
-    pending_nhg_removals: HashSet<NextHopGroupKey>,
+    pending_nhg_removals: HashSet<(RawSaiObjectId, NextHopGroupKey)>,
+
+    /// Route operations waiting to be flushed to SAI.
+    route_bulker: RouteBulker,
+
+    /// Tasks waiting for a dependency or for SAI resources.
+    retry_cache: RetryCache<String, KeyOpFieldsValues>,
+
+    /// When tasks parked on [`sai_retry_constraint`] are next woken.
+    sai_retry_at: Option<Instant>,
+
+    /// Delay to use for the next SAI retry.
+    sai_retry_backoff: Duration,
+
+    /// Final outcomes of this drain cycle, published at its end.
+    pending_responses: Vec<RouteResponse>,
+
+    /// Routes programmed with a fallback next hop, keyed to the ECMP group
+    /// they should be upgraded to once group resources free up.
+    overflow_routes: HashMap<RouteKey, RouteNhg>,
+
+    /// Next hop resolution changes published by NeighOrch.
+    next_hop_updates: Option<Subscription<NextHopUpdate>>,
+
+    /// VRF creation and removal published by VrfOrch.
+    vrf_updates: Option<Subscription<VrfUpdate>>,
+
+    /// Routes programmed as blackholes until one of their next hops
+    /// resolves, keyed to the next hops they should be programmed with.
+    unresolved_routes: HashMap<RouteKey, RouteNhg>,
+
+    /// Routes programmed with or waiting for each neighbor next hop, keyed
+    /// by the next hop's `ip@alias` as published in next hop updates.
+    next_hop_routes: HashMap<String, HashSet<RouteKey>>,
+
+    /// Statistics.
+    stats: RouteOrchStats,
 }
 
 impl RouteOrch {
     /// Creates a new RouteOrch with the given configuration.
     pub fn new(config: RouteOrchConfig) -> Self {
+        let route_bulker = RouteBulker::new(config.max_bulk_size);
+        let sai_retry_backoff = config.sai_retry_backoff;
         Self {
             config,
             consumer: Consumer::new(ConsumerConfig::new("ROUTE_TABLE")),
             synced_routes: HashMap::new(),
+            route_prefixes: HashMap::new(),
             synced_nhgs: SyncMap::new(),
             nhg_count: 0,
             callbacks: None,
             pending_nhg_removals: HashSet::new(),
+            route_bulker,
+            retry_cache: RetryCache::new(),
+            sai_retry_at: None,
+            sai_retry_backoff,
+            pending_responses: Vec::new(),
+            overflow_routes: HashMap::new(),
+            next_hop_updates: None,
+            vrf_updates: None,
+            unresolved_routes: HashMap::new(),
+            next_hop_routes: HashMap::new(),
+            stats: RouteOrchStats::default(),
         }
     }
 
@@ -180,6 +375,22 @@ impl RouteOrch {
         self.callbacks = Some(callbacks);
     }
 
+    /// Subscribes to next hop resolution changes from NeighOrch.
+    ///
+    /// Once subscribed, a route none of whose next hops resolves is
+    /// programmed as a blackhole instead of failing, and is reprogrammed when
+    /// a next hop resolves. Routes whose next hops all go away are dropped
+    /// the same way.
+    pub fn subscribe_next_hop_updates(&mut self, updates: Subscription<NextHopUpdate>) {
+        self.next_hop_updates = Some(updates);
+    }
+
+    /// Subscribes to VRF creation from VrfOrch, which wakes route tasks
+    /// parked on their next hop VRF.
+    pub fn subscribe_vrf_updates(&mut self, updates: Subscription<VrfUpdate>) {
+        self.vrf_updates = Some(updates);
+    }
+
     /// Returns the current count of next-hop groups.
     pub fn nhg_count(&self) -> usize {
         self.nhg_count
@@ -190,30 +401,96 @@ impl RouteOrch {
         self.config.max_nhg_count
     }
 
-    /// Checks if a next-hop group exists.
+    /// Returns the number of route tasks waiting to be retried.
+    pub fn retry_count(&self) -> usize {
+        self.retry_cache.len()
+    }
+
+    /// Wakes the route tasks waiting for a VRF. Called for each VRF VrfOrch
+    /// reports as added.
+    pub fn on_vrf_added(&mut self, name: &str) {
+        let ready = self.retry_cache.resolve(&Constraint::vrf(name));
+        if ready > 0 {
+            debug!("RouteOrch: VRF {} added, retrying {} routes", name, ready);
+        }
+    }
+
+    /// Returns the statistics.
+    pub fn stats(&self) -> &RouteOrchStats {
+        &self.stats
+    }
+
+    /// Returns true if the route is programmed with a fallback next hop while
+    /// it waits for its ECMP group.
+    pub fn is_overflow_route(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> bool {
+        self.overflow_routes
+            .contains_key(&RouteKey::new(vrf_id, prefix.clone()))
+    }
+
+    /// Returns the routes in the overflow set with the ECMP group each is
+    /// waiting for.
+    pub fn overflow_routes(&self) -> impl Iterator<Item = (&RouteKey, &NextHopGroupKey)> {
+        self.overflow_routes
+            .iter()
+            .map(|(k, nhg)| (k, &nhg.nhg_key))
+    }
+
+    /// Returns true if the route is dropped waiting for a next hop to resolve.
+    pub fn is_unresolved_route(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> bool {
+        self.unresolved_routes
+            .contains_key(&RouteKey::new(vrf_id, prefix.clone()))
+    }
+
+    /// Checks if a next-hop group resolved in the default VRF exists.
     pub fn has_nhg(&self, key: &NextHopGroupKey) -> bool {
-        self.synced_nhgs.contains_key(key)
+        self.has_nhg_in_vrf(0, key)
+    }
+
+    /// Checks if a next-hop group resolved in `vrf_id` exists.
+    pub fn has_nhg_in_vrf(&self, vrf_id: RawSaiObjectId, key: &NextHopGroupKey) -> bool {
+        self.synced_nhgs.contains_key(&(vrf_id, key.clone()))
     }
 
-    /// Gets a reference to a next-hop group entry.
+    /// Gets a reference to a next-hop group entry in the default VRF.
     ///
     /// Returns None if the group doesn't exist - does NOT create it.
     pub fn get_nhg(&self, key: &NextHopGroupKey) -> Option<&NextHopGroupEntry> {
-        self.synced_nhgs.get(key)
+        self.get_nhg_in_vrf(0, key)
+    }
+
+    /// Gets a reference to a next-hop group entry resolved in `vrf_id`.
+    ///
+    /// Returns None if the group doesn't exist - does NOT create it.
+    pub fn get_nhg_in_vrf(
+        &self,
+        vrf_id: RawSaiObjectId,
+        key: &NextHopGroupKey,
+    ) -> Option<&NextHopGroupEntry> {
+        self.synced_nhgs.get(&(vrf_id, key.clone()))
     }
 
-    /// Gets a mutable reference to a next-hop group entry.
+    /// Gets a mutable reference to a next-hop group entry in the default VRF.
     ///
     /// Returns None if the group doesn't exist - does NOT create it.
     pub fn get_nhg_mut(&mut self, key: &NextHopGroupKey) -> Option<&mut NextHopGroupEntry> {
-        self.synced_nhgs.get_mut(key)
+        self.synced_nhgs.get_mut(&(0, key.clone()))
     }
 
     /// Returns true if the next-hop group's ref count is zero.
     ///
     /// Returns true if the group doesn't exist (safe default).
     pub fn is_nhg_ref_count_zero(&self, key: &NextHopGroupKey) -> bool {
-        match self.synced_nhgs.get(key) {
+        self.is_nhg_ref_count_zero_in_vrf(0, key)
+    }
+
+    /// Returns true if the ref count of the group resolved in `vrf_id` is
+    /// zero, or if there is no such group.
+    pub fn is_nhg_ref_count_zero_in_vrf(
+        &self,
+        vrf_id: RawSaiObjectId,
+        key: &NextHopGroupKey,
+    ) -> bool {
+        match self.get_nhg_in_vrf(vrf_id, key) {
             Some(entry) => entry.is_ref_count_zero(),
             None => true,
         }
@@ -228,6 +505,15 @@ impl RouteOrch {
     /// For single next-hops, delegates to NeighOrch/IntfsOrch.
     /// For ECMP groups, increments the ref count in synced_nhgs.
     pub fn increase_nhg_ref_count(&mut self, key: &NextHopGroupKey) -> Result<()> {
+        self.increase_nhg_ref_count_in_vrf(0, key)
+    }
+
+    /// Increases the reference count of a group resolved in `vrf_id`.
+    pub fn increase_nhg_ref_count_in_vrf(
+        &mut self,
+        vrf_id: RawSaiObjectId,
+        key: &NextHopGroupKey,
+    ) -> Result<()> {
         // Clone the Arc to avoid borrowing self.callbacks while we mutate self
         let callbacks = self
             .callbacks
@@ -252,12 +538,15 @@ impl RouteOrch {
 
         // ECMP group: increment ref count in our table
         // This is the key safety improvement - we use get_mut instead of []
-        let entry = self.synced_nhgs.get_mut(key).ok_or_else(|| {
-            RouteError::NhgNotFound(format!(
-                "Cannot increment ref count for non-existent NHG: {}",
-                key
-            ))
-        })?;
+        let entry = self
+            .synced_nhgs
+            .get_mut(&(vrf_id, key.clone()))
+            .ok_or_else(|| {
+                RouteError::NhgNotFound(format!(
+                    "Cannot increment ref count for non-existent NHG: {}",
+                    key
+                ))
+            })?;
 
         let new_count = entry.increment_ref();
         debug!(
@@ -272,6 +561,15 @@ impl RouteOrch {
     ///
     /// This is the SAFE replacement for C++ `m_syncdNextHopGroups[key].ref_count--`.
     pub fn decrease_nhg_ref_count(&mut self, key: &NextHopGroupKey) -> Result<()> {
+        self.decrease_nhg_ref_count_in_vrf(0, key)
+    }
+
+    /// Decreases the reference count of a group resolved in `vrf_id`.
+    pub fn decrease_nhg_ref_count_in_vrf(
+        &mut self,
+        vrf_id: RawSaiObjectId,
+        key: &NextHopGroupKey,
+    ) -> Result<()> {
         // Clone the Arc to avoid borrowing self.callbacks while we mutate self
         let callbacks = self
             .callbacks
@@ -293,12 +591,15 @@ impl RouteOrch {
         }
 
         // ECMP group
-        let entry = self.synced_nhgs.get_mut(key).ok_or_else(|| {
-            RouteError::NhgNotFound(format!(
-                "Cannot decrement ref count for non-existent NHG: {}",
-                key
-            ))
-        })?;
+        let entry = self
+            .synced_nhgs
+            .get_mut(&(vrf_id, key.clone()))
+            .ok_or_else(|| {
+                RouteError::NhgNotFound(format!(
+                    "Cannot decrement ref count for non-existent NHG: {}",
+                    key
+                ))
+            })?;
 
         let new_count = entry.decrement_ref();
         debug!(
@@ -308,18 +609,27 @@ impl RouteOrch {
 
         // If ref count is now zero, mark for removal
         if new_count == 0 {
-            self.pending_nhg_removals.insert(key.clone());
+            self.pending_nhg_removals.insert((vrf_id, key.clone()));
         }
 
         Ok(())
     }
 
-    /// Adds a next-hop group.
+    /// Adds a next-hop group resolved in the default VRF.
     ///
     /// Creates the NHG in SAI and adds it to synced_nhgs with ref_count = 0.
     pub async fn add_nhg(&mut self, key: NextHopGroupKey) -> Result<RawSaiObjectId> {
+        self.add_nhg_in_vrf(key, 0).await
+    }
+
+    /// Adds a next-hop group whose members are resolved in `vrf_id`.
+    pub async fn add_nhg_in_vrf(
+        &mut self,
+        key: NextHopGroupKey,
+        vrf_id: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId> {
         // Check if already exists
-        if self.synced_nhgs.contains_key(&key) {
+        if self.has_nhg_in_vrf(vrf_id, &key) {
             let error_msg = format!("NHG already exists: {}", key);
             audit_log!(
                 AuditRecord::new(AuditCategory::ResourceCreate, "RouteOrch", "add_nhg")
@@ -346,15 +656,24 @@ impl RouteOrch {
 
         let callbacks = self
             .callbacks
-            .as_ref()
+            .clone()
             .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
 
+        // Don't bother SAI if CRM already reports the table as full
+        self.stats.crm_nhg_available = callbacks.crm_nhg_available();
+        if self.stats.crm_nhg_available == Some(0) {
+            return Err(RouteError::NhgResourceExhausted(
+                "CRM reports no next-hop groups available".to_string(),
+            ));
+        }
+
         // Create in SAI
-        let nhg_id = callbacks.sai_create_nhg(&key).await?;
+        let nhg_id = callbacks.sai_create_nhg(vrf_id, &key).await?;
 
         // Add to our table with ref_count = 0
-        let entry = NextHopGroupEntry::new(nhg_id);
-        self.synced_nhgs.insert(key.clone(), entry);
+        let mut entry = NextHopGroupEntry::new(nhg_id);
+        entry.set_vrf_id(vrf_id);
+        self.synced_nhgs.insert((vrf_id, key.clone()), entry);
         self.nhg_count += 1;
 
         audit_log!(
@@ -372,12 +691,23 @@ impl RouteOrch {
         Ok(nhg_id)
     }
 
-    /// Removes a next-hop group.
+    /// Removes a next-hop group resolved in the default VRF.
     ///
     /// Only succeeds if ref_count == 0.
     pub async fn remove_nhg(&mut self, key: &NextHopGroupKey) -> Result<()> {
+        self.remove_nhg_in_vrf(0, key).await
+    }
+
+    /// Removes a next-hop group resolved in `vrf_id`.
+    ///
+    /// Only succeeds if ref_count == 0.
+    pub async fn remove_nhg_in_vrf(
+        &mut self,
+        vrf_id: RawSaiObjectId,
+        key: &NextHopGroupKey,
+    ) -> Result<()> {
         // Get the entry and check ref count
-        let entry = self.synced_nhgs.get(key).ok_or_else(|| {
+        let entry = self.get_nhg_in_vrf(vrf_id, key).ok_or_else(|| {
             audit_log!(
                 AuditRecord::new(AuditCategory::ResourceDelete, "RouteOrch", "remove_nhg")
                     .with_outcome(AuditOutcome::Failure)
@@ -415,9 +745,10 @@ impl RouteOrch {
         callbacks.sai_remove_nhg(nhg_id).await?;
 
         // Remove from our table
-        self.synced_nhgs.remove(key);
+        let table_key = (vrf_id, key.clone());
+        self.synced_nhgs.remove(&table_key);
         self.nhg_count -= 1;
-        self.pending_nhg_removals.remove(key);
+        self.pending_nhg_removals.remove(&table_key);
 
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceDelete, "RouteOrch", "remove_nhg")
@@ -435,9 +766,9 @@ impl RouteOrch {
     pub async fn process_pending_nhg_removals(&mut self) -> Result<()> {
         let to_remove: Vec<_> = self.pending_nhg_removals.iter().cloned().collect();
 
-        for key in to_remove {
-            if self.is_nhg_ref_count_zero(&key) {
-                if let Err(e) = self.remove_nhg(&key).await {
+        for (vrf_id, key) in to_remove {
+            if self.is_nhg_ref_count_zero_in_vrf(vrf_id, &key) {
+                if let Err(e) = self.remove_nhg_in_vrf(vrf_id, &key).await {
                     warn!("Failed to remove pending NHG {}: {}", key, e);
                 }
             }
@@ -446,6 +777,16 @@ impl RouteOrch {
         Ok(())
     }
 
+    /// Queues an ECMP group for removal if no route references it.
+    ///
+    /// Used when the route operation that created the group fails, so the
+    /// group does not stay in SAI until a route happens to use it again.
+    fn release_unused_nhg(&mut self, vrf_id: RawSaiObjectId, key: &NextHopGroupKey) {
+        if self.has_nhg_in_vrf(vrf_id, key) && self.is_nhg_ref_count_zero_in_vrf(vrf_id, key) {
+            self.pending_nhg_removals.insert((vrf_id, key.clone()));
+        }
+    }
+
     /// Checks if a route exists.
     pub fn has_route(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> bool {
         self.synced_routes
@@ -454,6 +795,13 @@ impl RouteOrch {
             .unwrap_or(false)
     }
 
+    /// Returns the number of routes in a VRF.
+    pub fn route_count(&self, vrf_id: RawSaiObjectId) -> usize {
+        self.synced_routes
+            .get(&vrf_id)
+            .map_or(0, |table| table.len())
+    }
+
     /// Gets a reference to a route entry.
     pub fn get_route(&self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Option<&RouteEntry> {
         self.synced_routes
@@ -461,6 +809,20 @@ impl RouteOrch {
             .and_then(|table| table.get(prefix))
     }
 
+    /// Returns the most specific route in a VRF covering `addr`, as used to
+    /// resolve a recursive next hop.
+    pub fn longest_match_route(
+        &self,
+        vrf_id: RawSaiObjectId,
+        addr: &IpAddress,
+    ) -> Option<(&IpPrefix, &RouteEntry)> {
+        let (_, prefix) = self
+            .route_prefixes
+            .get(&vrf_id)?
+            .longest_match(std_addr(addr))?;
+        Some((prefix, self.get_route(vrf_id, prefix)?))
+    }
+
     /// Adds a route.
     pub async fn add_route(
         &mut self,
@@ -468,6 +830,63 @@ impl RouteOrch {
         prefix: IpPrefix,
         nhg_key: NextHopGroupKey,
     ) -> Result<()> {
+        self.add_leaked_route(vrf_id, prefix, nhg_key, None).await
+    }
+
+    /// Adds a route whose next-hops are resolved in `nexthop_vrf_id`.
+    ///
+    /// The route is created in `vrf_id`. While it exists, the next-hop VRF
+    /// holds a reference so it cannot be removed from under the route.
+    pub async fn add_leaked_route(
+        &mut self,
+        vrf_id: RawSaiObjectId,
+        prefix: IpPrefix,
+        nhg_key: NextHopGroupKey,
+        nexthop_vrf_id: Option<RawSaiObjectId>,
+    ) -> Result<()> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
+
+        let route = self
+            .prepare_route_add(vrf_id, prefix.clone(), &nhg_key, nexthop_vrf_id)
+            .await?;
+        let (nhg_id, blackhole) = route.op.next_hop();
+
+        let programmed = if matches!(route.op, RouteBulkOp::Create { .. }) {
+            callbacks
+                .sai_create_route(vrf_id, &prefix, nhg_id, blackhole)
+                .await
+        } else {
+            callbacks
+                .sai_set_route(vrf_id, &prefix, nhg_id, blackhole)
+                .await
+        };
+        if let Err(e) = programmed {
+            self.release_unused_nhg(route.nh_vrf_id(), &nhg_key);
+            self.process_pending_nhg_removals().await?;
+            return Err(e);
+        }
+
+        self.commit_route_add(route)
+    }
+
+    /// Resolves the next-hops of a route and builds the SAI operation for it.
+    ///
+    /// Creates the ECMP group in SAI if needed. If no group can be created
+    /// because resources are exhausted, the route is programmed with its
+    /// first resolved next hop instead, and `nhg_key` is kept as the overflow
+    /// key. If next hop updates are subscribed and no next hop resolves yet,
+    /// the route is programmed as a blackhole with an empty key.
+    /// The route table itself is not modified until [`Self::commit_route_add`].
+    async fn prepare_route_add(
+        &mut self,
+        vrf_id: RawSaiObjectId,
+        prefix: IpPrefix,
+        nhg_key: &NextHopGroupKey,
+        nexthop_vrf_id: Option<RawSaiObjectId>,
+    ) -> Result<PreparedRoute> {
         // Clone callbacks Arc to avoid borrowing self
         let callbacks = self
             .callbacks
@@ -478,10 +897,39 @@ impl RouteOrch {
         if vrf_id != 0 && !callbacks.vrf_exists(vrf_id) {
             return Err(RouteError::VrfNotFound(vrf_id));
         }
+        if let Some(nh_vrf) = nexthop_vrf_id {
+            if nh_vrf != 0 && !callbacks.vrf_exists(nh_vrf) {
+                return Err(RouteError::VrfNotFound(nh_vrf));
+            }
+        }
+
+        // Next hops are resolved in the next-hop VRF of a leaked route
+        let nh_vrf_id = nexthop_vrf_id.unwrap_or(vrf_id);
+
+        // A single next hop that is not a neighbor may still resolve through
+        // the route covering it
+        let recursive_nh_id = nhg_key
+            .iter()
+            .next()
+            .filter(|nh| nhg_key.len() == 1 && !nh.is_interface_nexthop())
+            .filter(|nh| callbacks.get_next_hop_id_in_vrf(nh_vrf_id, nh).is_none())
+            .and_then(|nh| {
+                self.resolve_recursive_nexthop(&*callbacks, vrf_id, &prefix, nh_vrf_id, nh)
+            });
 
         // Determine the NHG ID to use
+        let mut fallback = None;
         let (nhg_id, blackhole) = if nhg_key.is_empty() {
             (None, true)
+        } else if recursive_nh_id.is_none()
+            && self.waits_for_next_hop(&*callbacks, nh_vrf_id, nhg_key)
+        {
+            debug!(
+                "RouteOrch: No next hop of {} resolved yet, dropping until one is",
+                prefix
+            );
+            fallback = Some(NextHopGroupKey::new());
+            (None, true)
         } else if nhg_key.len() == 1 {
             // Single next-hop
             let nexthop = nhg_key.iter().next().unwrap();
@@ -492,44 +940,178 @@ impl RouteOrch {
                 (Some(rif_id), false)
             } else {
                 let nh_id = callbacks
-                    .get_next_hop_id(nexthop)
+                    .get_next_hop_id_in_vrf(nh_vrf_id, nexthop)
+                    .or(recursive_nh_id)
                     .ok_or_else(|| RouteError::NextHopNotResolved(nexthop.to_string()))?;
                 (Some(nh_id), false)
             }
         } else {
             // ECMP group
-            let nhg_id = if self.has_nhg(&nhg_key) {
-                self.synced_nhgs.get(&nhg_key).unwrap().sai_id()
+            let nhg_id = if let Some(entry) = self.get_nhg_in_vrf(nh_vrf_id, nhg_key) {
+                entry.sai_id()
             } else {
                 // Create the NHG
-                self.add_nhg(nhg_key.clone()).await?
+                match self.add_nhg_in_vrf(nhg_key.clone(), nh_vrf_id).await {
+                    Ok(id) => id,
+                    Err(e) if e.is_nhg_exhausted() => {
+                        let (nexthop, nh_id) =
+                            Self::first_resolved_nexthop(&*callbacks, nh_vrf_id, nhg_key)
+                                .ok_or(e)?;
+                        warn!(
+                            "RouteOrch: No NHG resources for {}, using {} until they free up",
+                            prefix, nexthop
+                        );
+                        fallback = Some(NextHopGroupKey::single(nexthop));
+                        nh_id
+                    }
+                    Err(e) => return Err(e),
+                }
             };
             (Some(nhg_id), false)
         };
 
+        let op = if self.has_route(vrf_id, &prefix) {
+            RouteBulkOp::Set {
+                vrf_id,
+                prefix,
+                nhg_id,
+                blackhole,
+            }
+        } else {
+            RouteBulkOp::Create {
+                vrf_id,
+                prefix,
+                nhg_id,
+                blackhole,
+            }
+        };
+        let (nhg_key, overflow_nhg_key) = match fallback {
+            Some(fallback_key) => (fallback_key, Some(nhg_key.clone())),
+            None => (nhg_key.clone(), None),
+        };
+        Ok(PreparedRoute {
+            op,
+            nhg_key,
+            nexthop_vrf_id,
+            overflow_nhg_key,
+        })
+    }
+
+    /// Returns true if a route to the group must be dropped until a neighbor
+    /// resolves: next hop updates are subscribed, the group only has
+    /// neighbor next hops, and none of them resolves.
+    fn waits_for_next_hop(
+        &self,
+        callbacks: &dyn RouteOrchCallbacks,
+        nh_vrf_id: RawSaiObjectId,
+        nhg_key: &NextHopGroupKey,
+    ) -> bool {
+        self.next_hop_updates.is_some()
+            && nhg_key.iter().all(|nh| !nh.is_interface_nexthop())
+            && Self::first_resolved_nexthop(callbacks, nh_vrf_id, nhg_key).is_none()
+    }
+
+    /// Resolves a next hop that is not a neighbor through the most specific
+    /// route in `nh_vrf_id` covering its address.
+    ///
+    /// Only resolves through a covering route other than the default route
+    /// and the route being programmed, and only if that route forwards to a
+    /// single neighbor. The route is not reprogrammed if the covering route
+    /// changes later.
+    fn resolve_recursive_nexthop(
+        &self,
+        callbacks: &dyn RouteOrchCallbacks,
+        vrf_id: RawSaiObjectId,
+        prefix: &IpPrefix,
+        nh_vrf_id: RawSaiObjectId,
+        nexthop: &NextHopKey,
+    ) -> Option<RawSaiObjectId> {
+        let (covering, entry) = self.longest_match_route(nh_vrf_id, nexthop.ip_address())?;
+        if covering.is_default() || (nh_vrf_id == vrf_id && covering == prefix) {
+            return None;
+        }
+        let via = entry.nhg.nhg_key.iter().next()?;
+        if entry.nhg.nhg_key.len() != 1 || via.is_interface_nexthop() {
+            return None;
+        }
+        let via_vrf_id = entry.nhg.nexthop_vrf_id.unwrap_or(nh_vrf_id);
+        let nh_id = callbacks.get_next_hop_id_in_vrf(via_vrf_id, via)?;
+        debug!(
+            "RouteOrch: Resolved {} for {} through {} via {}",
+            nexthop, prefix, covering, via
+        );
+        Some(nh_id)
+    }
+
+    /// Returns the first member of a group that resolves to a SAI object in
+    /// `nh_vrf_id`.
+    fn first_resolved_nexthop(
+        callbacks: &dyn RouteOrchCallbacks,
+        nh_vrf_id: RawSaiObjectId,
+        nhg_key: &NextHopGroupKey,
+    ) -> Option<(NextHopKey, RawSaiObjectId)> {
+        nhg_key.iter().find_map(|nexthop| {
+            let id = if nexthop.is_interface_nexthop() {
+                callbacks.get_router_intf_id(nexthop.alias())
+            } else {
+                callbacks.get_next_hop_id_in_vrf(nh_vrf_id, nexthop)
+            };
+            id.map(|id| (nexthop.clone(), id))
+        })
+    }
+
+    /// Records a route whose SAI create/set succeeded.
+    fn commit_route_add(&mut self, route: PreparedRoute) -> Result<()> {
+        let vrf_id = route.op.vrf_id();
+        let prefix = route.op.prefix().clone();
+        let (nhg_id, blackhole) = route.op.next_hop();
+        let PreparedRoute {
+            nhg_key,
+            nexthop_vrf_id,
+            overflow_nhg_key,
+            ..
+        } = route;
+
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
+
+        // Leaking into the route's own VRF is an ordinary route
+        let nexthop_vrf_id = nexthop_vrf_id.filter(|&id| id != vrf_id);
+        let mut route_nhg = RouteNhg::new(nhg_key.clone());
+        route_nhg.nexthop_vrf_id = nexthop_vrf_id;
+
         // Check if route already exists
         let existing = self.get_route(vrf_id, &prefix);
         let is_update = existing.is_some();
         let old_nhg_key = existing.map(|e| e.nhg.nhg_key.clone());
+        let old_nexthop_vrf_id = existing.and_then(|e| e.nhg.nexthop_vrf_id);
+        let nh_vrf_id = nexthop_vrf_id.unwrap_or(vrf_id);
 
         if is_update {
-            // Update existing route
-            callbacks
-                .sai_set_route(vrf_id, &prefix, nhg_id, blackhole)
-                .await?;
-
             // Update ref counts
             if let Some(ref old_key) = old_nhg_key {
-                if old_key != &nhg_key {
-                    self.decrease_nhg_ref_count(old_key)?;
-                    self.increase_nhg_ref_count(&nhg_key)?;
+                let old_nh_vrf_id = old_nexthop_vrf_id.unwrap_or(vrf_id);
+                if old_key != &nhg_key || old_nh_vrf_id != nh_vrf_id {
+                    self.decrease_nhg_ref_count_in_vrf(old_nh_vrf_id, old_key)?;
+                    self.increase_nhg_ref_count_in_vrf(nh_vrf_id, &nhg_key)?;
+                }
+            }
+            if old_nexthop_vrf_id != nexthop_vrf_id {
+                if let Some(id) = nexthop_vrf_id {
+                    callbacks.increase_vrf_ref_count(id);
+                }
+                if let Some(id) = old_nexthop_vrf_id {
+                    callbacks.decrease_vrf_ref_count(id);
                 }
             }
 
             // Update our table
+            self.unindex_route(&RouteKey::new(vrf_id, prefix.clone()));
             let table = self.synced_routes.entry(vrf_id).or_default();
             if let Some(entry) = table.get_mut(&prefix) {
-                entry.nhg = RouteNhg::new(nhg_key.clone());
+                entry.nhg = route_nhg;
             }
 
             audit_log!(
@@ -539,6 +1121,7 @@ impl RouteOrch {
                     .with_object_type("route")
                     .with_details(serde_json::json!({
                         "vrf_id": format!("0x{:x}", vrf_id),
+                        "nexthop_vrf_id": nexthop_vrf_id.map(|id| format!("0x{:x}", id)),
                         "nhg_id": nhg_id.map(|id| format!("0x{:x}", id)),
                         "blackhole": blackhole
                     }))
@@ -546,23 +1129,23 @@ impl RouteOrch {
 
             debug!("RouteOrch: Updated route {}/{}", vrf_id, prefix);
         } else {
-            // Create new route
-            callbacks
-                .sai_create_route(vrf_id, &prefix, nhg_id, blackhole)
-                .await?;
-
             // Increase ref counts
-            self.increase_nhg_ref_count(&nhg_key)?;
+            self.increase_nhg_ref_count_in_vrf(nh_vrf_id, &nhg_key)?;
             if vrf_id != 0 {
                 callbacks.increase_vrf_ref_count(vrf_id);
             }
+            if let Some(id) = nexthop_vrf_id {
+                callbacks.increase_vrf_ref_count(id);
+            }
 
             // Add to our table
             let table = self.synced_routes.entry(vrf_id).or_default();
-            table.insert(
-                prefix.clone(),
-                RouteEntry::new(RouteNhg::new(nhg_key.clone())),
-            );
+            table.insert(prefix.clone(), RouteEntry::new(route_nhg));
+            let (addr, len) = prefix_key(&prefix);
+            self.route_prefixes
+                .entry(vrf_id)
+                .or_default()
+                .insert(addr, len, prefix.clone());
 
             audit_log!(
                 AuditRecord::new(AuditCategory::ResourceCreate, "RouteOrch", "add_route")
@@ -571,6 +1154,7 @@ impl RouteOrch {
                     .with_object_type("route")
                     .with_details(serde_json::json!({
                         "vrf_id": format!("0x{:x}", vrf_id),
+                        "nexthop_vrf_id": nexthop_vrf_id.map(|id| format!("0x{:x}", id)),
                         "nhg_id": nhg_id.map(|id| format!("0x{:x}", id)),
                         "blackhole": blackhole
                     }))
@@ -579,71 +1163,371 @@ impl RouteOrch {
             info!("RouteOrch: Added route {}/{}", vrf_id, prefix);
         }
 
+        let waiting = overflow_nhg_key.map(|key| {
+            let mut route_nhg = RouteNhg::new(key);
+            route_nhg.nexthop_vrf_id = nexthop_vrf_id;
+            route_nhg
+        });
+        let route_key = RouteKey::new(vrf_id, prefix);
+        if nhg_key.is_empty() && waiting.is_some() {
+            self.set_overflow(route_key.clone(), None);
+            self.set_unresolved(route_key.clone(), waiting);
+        } else {
+            self.set_unresolved(route_key.clone(), None);
+            self.set_overflow(route_key.clone(), waiting);
+        }
+        self.index_route(&route_key);
+
         Ok(())
     }
 
-    /// Removes a route.
-    pub async fn remove_route(&mut self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Result<()> {
-        // Clone the Arc to avoid borrowing self.callbacks while we mutate self
-        let callbacks = self
-            .callbacks
-            .clone()
-            .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
-
-        // Get the existing route
-        let entry = self
-            .synced_routes
-            .get(&vrf_id)
-            .and_then(|table| table.get(prefix))
-            .ok_or_else(|| {
-                audit_log!(AuditRecord::new(
-                    AuditCategory::ResourceDelete,
-                    "RouteOrch",
-                    "remove_route"
-                )
-                .with_outcome(AuditOutcome::Failure)
-                .with_object_id(&prefix.to_string())
-                .with_object_type("route")
-                .with_error(&format!("Route not found: {}/{}", vrf_id, prefix)));
-                RouteError::RouteNotFound(format!("{}/{}", vrf_id, prefix))
-            })?;
+    /// Returns the `ip@alias` of every neighbor next hop a route is
+    /// programmed with or waiting for.
+    fn route_next_hop_keys(&self, key: &RouteKey) -> HashSet<String> {
+        let programmed = self
+            .get_route(key.vrf_id, &key.prefix)
+            .map(|entry| &entry.nhg.nhg_key);
+        let waiting = self
+            .unresolved_routes
+            .get(key)
+            .or_else(|| self.overflow_routes.get(key))
+            .map(|route_nhg| &route_nhg.nhg_key);
+        programmed
+            .into_iter()
+            .chain(waiting)
+            .flat_map(|nhg_key| nhg_key.iter())
+            .filter(|nh| !nh.is_interface_nexthop())
+            .map(|nh| NextHopUpdate::key_for(nh.ip_address(), nh.alias()))
+            .collect()
+    }
 
-        let nhg_key = entry.nhg.nhg_key.clone();
+    /// Adds a route to the next hop index under its current next hops.
+    fn index_route(&mut self, key: &RouteKey) {
+        for nh in self.route_next_hop_keys(key) {
+            self.next_hop_routes
+                .entry(nh)
+                .or_default()
+                .insert(key.clone());
+        }
+    }
 
-        // Check if this is a default route
-        let is_default = prefix.is_default();
+    /// Drops a route from the next hop index. Must run before the route's
+    /// next hops change.
+    fn unindex_route(&mut self, key: &RouteKey) {
+        for nh in self.route_next_hop_keys(key) {
+            if let Some(routes) = self.next_hop_routes.get_mut(&nh) {
+                routes.remove(key);
+                if routes.is_empty() {
+                    self.next_hop_routes.remove(&nh);
+                }
+            }
+        }
+    }
 
-        if is_default && self.config.default_action_drop {
-            // For default routes, just set to DROP instead of removing
-            callbacks.sai_set_route(vrf_id, prefix, None, true).await?;
+    /// Adds a route to, or drops it from, the overflow set.
+    fn set_overflow(&mut self, key: RouteKey, overflow: Option<RouteNhg>) {
+        match overflow {
+            Some(route_nhg) => {
+                if self.overflow_routes.insert(key, route_nhg).is_none() {
+                    self.stats.nhg_overflows += 1;
+                }
+            }
+            None => {
+                self.overflow_routes.remove(&key);
+            }
+        }
+        self.stats.overflow_routes = self.overflow_routes.len();
+    }
 
-            // Update our table
-            let table = self.synced_routes.get_mut(&vrf_id).unwrap();
-            if let Some(entry) = table.get_mut(prefix) {
-                let old_nhg_key = std::mem::take(&mut entry.nhg.nhg_key);
-                self.decrease_nhg_ref_count(&old_nhg_key)?;
+    /// Adds a route to, or drops it from, the unresolved set.
+    fn set_unresolved(&mut self, key: RouteKey, unresolved: Option<RouteNhg>) {
+        match unresolved {
+            Some(route_nhg) => {
+                self.unresolved_routes.insert(key, route_nhg);
+            }
+            None => {
+                self.unresolved_routes.remove(&key);
             }
+        }
+        self.stats.unresolved_routes = self.unresolved_routes.len();
+    }
 
-            audit_log!(AuditRecord::new(
-                AuditCategory::ResourceModify,
-                "RouteOrch",
-                "remove_route"
-            )
-            .with_outcome(AuditOutcome::Success)
-            .with_object_id(&prefix.to_string())
-            .with_object_type("route")
-            .with_details(serde_json::json!({
-                "action": "set_to_drop",
-                "vrf_id": format!("0x{:x}", vrf_id)
-            })));
+    /// Applies the next hop resolution changes published since the last
+    /// drain cycle.
+    ///
+    /// Dropped routes waiting for a next hop that resolved are reprogrammed.
+    /// Routes using a next hop that went away are reprogrammed too if
+    /// nothing else in their group still resolves, which drops them until it
+    /// comes back.
+    async fn process_next_hop_updates(&mut self) {
+        let updates = match &self.next_hop_updates {
+            Some(subscription) => subscription.drain(),
+            None => return,
+        };
+        if updates.is_empty() {
+            return;
+        }
 
-            debug!("RouteOrch: Set default route {} to DROP", prefix);
-        } else {
-            // Remove the route
-            callbacks.sai_remove_route(vrf_id, prefix).await?;
+        // Wake route tasks parked on a neighbor that resolved
+        for update in updates.iter().filter(|update| update.resolved) {
+            self.retry_cache
+                .resolve(&Constraint::neighbor(update.nh_key.clone()));
+        }
 
-            // Decrease ref counts
-            self.decrease_nhg_ref_count(&nhg_key)?;
+        let callbacks = match self.callbacks.clone() {
+            Some(cb) => cb,
+            None => return,
+        };
+
+        // Only routes using or waiting for an updated next hop can change
+        let mut candidates: Vec<RouteKey> = updates
+            .iter()
+            .filter_map(|update| self.next_hop_routes.get(&update.nh_key))
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        candidates.sort_by_cached_key(|key| key.to_string());
+
+        let mut affected: Vec<(RouteKey, RouteNhg)> = Vec::new();
+        for key in candidates {
+            let Some(entry) = self.get_route(key.vrf_id, &key.prefix) else {
+                continue;
+            };
+            let programmed = &entry.nhg.nhg_key;
+            let wanted = self
+                .unresolved_routes
+                .get(&key)
+                .or_else(|| self.overflow_routes.get(&key))
+                .unwrap_or(&entry.nhg);
+            let hit = updates.iter().any(|update| {
+                if update.resolved {
+                    self.unresolved_routes.contains_key(&key)
+                        && has_next_hop(&wanted.nhg_key, update)
+                } else {
+                    has_next_hop(programmed, update)
+                        && Self::first_resolved_nexthop(
+                            &*callbacks,
+                            entry.nhg.nexthop_vrf_id.unwrap_or(key.vrf_id),
+                            programmed,
+                        )
+                        .is_none()
+                }
+            });
+            if hit {
+                let wanted = wanted.clone();
+                affected.push((key, wanted));
+            }
+        }
+
+        for (key, route_nhg) in affected {
+            let was_unresolved = self.unresolved_routes.contains_key(&key);
+            let result = self
+                .add_leaked_route(
+                    key.vrf_id,
+                    key.prefix.clone(),
+                    route_nhg.nhg_key.clone(),
+                    route_nhg.nexthop_vrf_id,
+                )
+                .await;
+            let is_unresolved = self.unresolved_routes.contains_key(&key);
+            match result {
+                Ok(()) if was_unresolved && !is_unresolved => {
+                    self.stats.unresolved_upgrades += 1;
+                    info!(
+                        "RouteOrch: Next hop resolved, programmed route {} to {}",
+                        key, route_nhg.nhg_key
+                    );
+                }
+                Ok(()) if !was_unresolved && is_unresolved => {
+                    warn!(
+                        "RouteOrch: No next hop of {} resolves, dropping route {}",
+                        route_nhg.nhg_key, key
+                    );
+                }
+                Ok(()) => {}
+                Err(e) => warn!("RouteOrch: Failed to reprogram route {}: {}", key, e),
+            }
+        }
+
+        if let Err(e) = self.process_pending_nhg_removals().await {
+            warn!("Failed to process pending NHG removals: {}", e);
+        }
+    }
+
+    /// Moves overflow routes back onto their full ECMP group, for as long as
+    /// group resources last.
+    ///
+    /// Called at the end of each drain cycle and whenever a route removal may
+    /// have freed a group.
+    async fn upgrade_overflow_routes(&mut self) {
+        let keys: Vec<RouteKey> = self.overflow_routes.keys().cloned().collect();
+        for key in keys {
+            let Some(route_nhg) = self.overflow_routes.get(&key).cloned() else {
+                continue;
+            };
+            let nh_vrf_id = route_nhg.nexthop_vrf_id.unwrap_or(key.vrf_id);
+            let created = !self.has_nhg_in_vrf(nh_vrf_id, &route_nhg.nhg_key);
+            if created {
+                match self
+                    .add_nhg_in_vrf(route_nhg.nhg_key.clone(), nh_vrf_id)
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(e) if e.is_nhg_exhausted() => break,
+                    Err(e) => {
+                        warn!(
+                            "RouteOrch: Failed to create NHG for overflow route {}: {}",
+                            key, e
+                        );
+                        continue;
+                    }
+                }
+            }
+
+            match self
+                .add_leaked_route(
+                    key.vrf_id,
+                    key.prefix.clone(),
+                    route_nhg.nhg_key.clone(),
+                    route_nhg.nexthop_vrf_id,
+                )
+                .await
+            {
+                Ok(()) => {
+                    self.stats.nhg_overflow_upgrades += 1;
+                    info!(
+                        "RouteOrch: Upgraded overflow route {} to NHG {}",
+                        key, route_nhg.nhg_key
+                    );
+                }
+                Err(e) => {
+                    warn!("RouteOrch: Failed to upgrade overflow route {}: {}", key, e);
+                    if created {
+                        self.pending_nhg_removals
+                            .insert((nh_vrf_id, route_nhg.nhg_key));
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = self.process_pending_nhg_removals().await {
+            warn!("Failed to process pending NHG removals: {}", e);
+        }
+    }
+
+    /// Removes a route.
+    pub async fn remove_route(&mut self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Result<()> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
+
+        match self.prepare_route_remove(vrf_id, prefix)? {
+            RouteBulkOp::Remove { .. } => callbacks.sai_remove_route(vrf_id, prefix).await?,
+            op => {
+                let (nhg_id, blackhole) = op.next_hop();
+                callbacks
+                    .sai_set_route(vrf_id, prefix, nhg_id, blackhole)
+                    .await?
+            }
+        }
+
+        self.commit_route_remove(vrf_id, prefix)?;
+
+        // Process any pending NHG removals
+        self.process_pending_nhg_removals().await?;
+
+        // A freed group may let an overflow route get its ECMP group back
+        self.upgrade_overflow_routes().await;
+
+        Ok(())
+    }
+
+    /// Builds the SAI operation for removing a route.
+    ///
+    /// Default routes are set to DROP instead of being removed when
+    /// `default_action_drop` is configured.
+    fn prepare_route_remove(
+        &self,
+        vrf_id: RawSaiObjectId,
+        prefix: &IpPrefix,
+    ) -> Result<RouteBulkOp> {
+        if !self.has_route(vrf_id, prefix) {
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "RouteOrch",
+                "remove_route"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(&prefix.to_string())
+            .with_object_type("route")
+            .with_error(&format!("Route not found: {}/{}", vrf_id, prefix)));
+            return Err(RouteError::RouteNotFound(format!("{}/{}", vrf_id, prefix)));
+        }
+
+        if prefix.is_default() && self.config.default_action_drop {
+            Ok(RouteBulkOp::Set {
+                vrf_id,
+                prefix: prefix.clone(),
+                nhg_id: None,
+                blackhole: true,
+            })
+        } else {
+            Ok(RouteBulkOp::Remove {
+                vrf_id,
+                prefix: prefix.clone(),
+            })
+        }
+    }
+
+    /// Records a route whose SAI removal (or set to DROP) succeeded.
+    fn commit_route_remove(&mut self, vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Result<()> {
+        // Clone the Arc to avoid borrowing self.callbacks while we mutate self
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| RouteError::SaiError("Callbacks not set".to_string()))?;
+
+        let (nhg_key, nexthop_vrf_id) = self
+            .get_route(vrf_id, prefix)
+            .map(|entry| (entry.nhg.nhg_key.clone(), entry.nhg.nexthop_vrf_id))
+            .ok_or_else(|| RouteError::RouteNotFound(format!("{}/{}", vrf_id, prefix)))?;
+        let route_key = RouteKey::new(vrf_id, prefix.clone());
+        self.unindex_route(&route_key);
+
+        // Release the leaked-from VRF before the route goes away or drops
+        if let Some(id) = nexthop_vrf_id {
+            callbacks.decrease_vrf_ref_count(id);
+        }
+
+        if prefix.is_default() && self.config.default_action_drop {
+            // Update our table
+            let table = self.synced_routes.get_mut(&vrf_id).unwrap();
+            if let Some(entry) = table.get_mut(prefix) {
+                let old_nhg_key = std::mem::take(&mut entry.nhg.nhg_key);
+                entry.nhg.nexthop_vrf_id = None;
+                self.decrease_nhg_ref_count_in_vrf(nexthop_vrf_id.unwrap_or(vrf_id), &old_nhg_key)?;
+            }
+
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "RouteOrch",
+                "remove_route"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(&prefix.to_string())
+            .with_object_type("route")
+            .with_details(serde_json::json!({
+                "action": "set_to_drop",
+                "vrf_id": format!("0x{:x}", vrf_id)
+            })));
+
+            debug!("RouteOrch: Set default route {} to DROP", prefix);
+        } else {
+            // Decrease ref counts
+            self.decrease_nhg_ref_count_in_vrf(nexthop_vrf_id.unwrap_or(vrf_id), &nhg_key)?;
             if vrf_id != 0 {
                 callbacks.decrease_vrf_ref_count(vrf_id);
             }
@@ -656,6 +1540,13 @@ impl RouteOrch {
             if table.is_empty() && vrf_id != 0 {
                 self.synced_routes.remove(&vrf_id);
             }
+            if let Some(prefixes) = self.route_prefixes.get_mut(&vrf_id) {
+                let (addr, len) = prefix_key(prefix);
+                prefixes.remove(addr, len);
+                if prefixes.is_empty() {
+                    self.route_prefixes.remove(&vrf_id);
+                }
+            }
 
             audit_log!(AuditRecord::new(
                 AuditCategory::ResourceDelete,
@@ -672,12 +1563,291 @@ impl RouteOrch {
             info!("RouteOrch: Removed route {}/{}", vrf_id, prefix);
         }
 
-        // Process any pending NHG removals
-        self.process_pending_nhg_removals().await?;
+        self.set_overflow(route_key.clone(), None);
+        self.set_unresolved(route_key, None);
 
         Ok(())
     }
 
+    /// Prepares a route task and queues its SAI operation in the bulker.
+    async fn queue_route_task(&mut self, task: KeyOpFieldsValues) {
+        // Parse VRF and prefix from key
+        // Key format: "vrf_id:prefix" or just "prefix" for default VRF
+        let (vrf_id, prefix) = match parse_route_key(&task.key) {
+            Ok((v, p)) => (v, p),
+            Err(e) => {
+                warn!("Invalid route key {}: {}", task.key, e);
+                self.record_response(&task, RouteResponseStatus::Failed(e.to_string()));
+                return;
+            }
+        };
+
+        // A newer task supersedes any failed attempt still waiting for retry
+        self.retry_cache.remove(&task.key);
+
+        // Each route may only appear once per flush
+        if self
+            .route_bulker
+            .contains(&RouteKey::new(vrf_id, prefix.clone()))
+        {
+            self.flush_routes().await;
+        }
+
+        let prepared = match task.op {
+            Operation::Set => {
+                // Parse next-hops from fields
+                let fields: HashMap<String, String> = task.fvs.iter().cloned().collect();
+                let nhg_key = match parse_nexthops(&fields) {
+                    Ok(key) => key,
+                    Err(e) => {
+                        warn!("Invalid nexthops for {}: {}", task.key, e);
+                        self.record_response(&task, RouteResponseStatus::Failed(e.to_string()));
+                        return;
+                    }
+                };
+
+                // Leaked route: the next-hops live in another VRF, which
+                // may not have been created yet
+                let nexthop_vrf_id = match fields.get("nexthop_vrf").filter(|v| !v.is_empty()) {
+                    Some(name) => {
+                        match self.callbacks.as_ref().and_then(|cb| cb.get_vrf_id(name)) {
+                            Some(id) => Some(id),
+                            None => {
+                                debug!(
+                                    "RouteOrch: nexthop VRF {} for {} not ready, will retry",
+                                    name, task.key
+                                );
+                                self.retry_cache.add(
+                                    task.key.clone(),
+                                    task,
+                                    [Constraint::vrf(name.as_str())],
+                                );
+                                return;
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                match self
+                    .prepare_route_add(vrf_id, prefix, &nhg_key, nexthop_vrf_id)
+                    .await
+                {
+                    Ok(route) => Some(route),
+                    Err(e) => {
+                        let waiting = match e {
+                            RouteError::NextHopNotResolved(_) => {
+                                let nh_vrf_id = nexthop_vrf_id.unwrap_or(vrf_id);
+                                self.unresolved_neighbors(nh_vrf_id, &nhg_key)
+                            }
+                            _ => Vec::new(),
+                        };
+                        self.park_or_fail(task, e, waiting);
+                        return;
+                    }
+                }
+            }
+            Operation::Del => match self.prepare_route_remove(vrf_id, &prefix) {
+                Ok(op) => Some(PreparedRoute::removal(op)),
+                Err(e) => {
+                    self.park_or_fail(task, e, Vec::new());
+                    return;
+                }
+            },
+        };
+
+        if let Some(route) = prepared {
+            self.route_bulker.push(PendingRouteOp { route, task });
+            if self.route_bulker.is_full() {
+                self.flush_routes().await;
+            }
+        }
+    }
+
+    /// Wakes the route tasks parked on VRFs added since the last drain cycle.
+    fn process_vrf_updates(&mut self) {
+        let updates = match &self.vrf_updates {
+            Some(subscription) => subscription.drain(),
+            None => return,
+        };
+        for update in updates.iter().filter(|update| update.added) {
+            self.on_vrf_added(&update.name);
+        }
+    }
+
+    /// Returns a constraint for each neighbor next hop of the group that
+    /// does not resolve in `nh_vrf_id`.
+    ///
+    /// Empty unless next hop updates are subscribed, since nothing else
+    /// announces a neighbor resolving. The task is then retried with the SAI
+    /// retry backoff instead.
+    fn unresolved_neighbors(
+        &self,
+        nh_vrf_id: RawSaiObjectId,
+        nhg_key: &NextHopGroupKey,
+    ) -> Vec<Constraint> {
+        let Some(callbacks) = self
+            .callbacks
+            .as_ref()
+            .filter(|_| self.next_hop_updates.is_some())
+        else {
+            return Vec::new();
+        };
+        nhg_key
+            .iter()
+            .filter(|nh| {
+                !nh.is_interface_nexthop()
+                    && callbacks.get_next_hop_id_in_vrf(nh_vrf_id, nh).is_none()
+            })
+            .map(|nh| Constraint::neighbor(NextHopUpdate::key_for(nh.ip_address(), nh.alias())))
+            .collect()
+    }
+
+    /// Parks a task that could not be programmed until what it waits for is
+    /// resolved, or records it as failed if retrying cannot help.
+    ///
+    /// `waiting` names the dependencies the task is known to wait for. If it
+    /// is empty, a retryable error parks the task on
+    /// [`sai_retry_constraint`], which is resolved once routes are removed
+    /// or the SAI retry backoff expires.
+    fn park_or_fail(
+        &mut self,
+        task: KeyOpFieldsValues,
+        err: RouteError,
+        mut waiting: Vec<Constraint>,
+    ) {
+        if waiting.is_empty() {
+            if !err.is_retryable() {
+                error!("RouteOrch: {} failed: {}", task.key, err);
+                self.record_response(&task, RouteResponseStatus::Failed(err.to_string()));
+                return;
+            }
+            waiting.push(sai_retry_constraint());
+            self.schedule_sai_retry();
+        }
+        debug!("RouteOrch: {} for {}, will retry", err, task.key);
+        self.retry_cache.add(task.key.clone(), task, waiting);
+    }
+
+    /// Arms the SAI retry timer unless it is already running, and doubles
+    /// the delay for the next failure.
+    fn schedule_sai_retry(&mut self) {
+        if self.sai_retry_at.is_some() {
+            return;
+        }
+        self.sai_retry_at = Some(Instant::now() + self.sai_retry_backoff);
+        self.sai_retry_backoff = self
+            .sai_retry_backoff
+            .saturating_mul(2)
+            .min(self.config.max_sai_retry_backoff);
+    }
+
+    /// Wakes the tasks parked on [`sai_retry_constraint`].
+    fn resolve_sai_retries(&mut self) {
+        self.sai_retry_at = None;
+        let ready = self.retry_cache.resolve(&sai_retry_constraint());
+        if ready > 0 {
+            debug!("RouteOrch: retrying {} routes rejected by SAI", ready);
+        }
+    }
+
+    /// Returns true if the SAI retry backoff has expired.
+    fn sai_retry_due(&self) -> bool {
+        self.sai_retry_at.is_some_and(|at| at <= Instant::now())
+    }
+
+    /// Flushes queued route operations to SAI in bulk.
+    ///
+    /// Entries that succeed are committed to the route tables; entries that
+    /// fail are parked until SAI resources free up or the retry backoff
+    /// expires.
+    async fn flush_routes(&mut self) {
+        let callbacks = match self.callbacks.clone() {
+            Some(cb) => cb,
+            None => return,
+        };
+
+        let nhg_count = self.nhg_count;
+        let mut freed = false;
+
+        for batch in self.route_bulker.take_batches() {
+            let ops: Vec<RouteBulkOp> = batch.iter().map(|p| p.route.op.clone()).collect();
+            let mut results = callbacks.sai_bulk_route(&ops).await.into_iter();
+
+            for pending in batch {
+                let result = results.next().unwrap_or_else(|| {
+                    Err(RouteError::SaiError("Missing bulk status".to_string()))
+                });
+                if let Err(e) = result {
+                    warn!(
+                        "RouteOrch: SAI failed for route {}: {}",
+                        pending.task.key, e
+                    );
+                    self.release_unused_nhg(pending.route.nh_vrf_id(), &pending.route.nhg_key);
+                    self.park_or_fail(pending.task, e, Vec::new());
+                    continue;
+                }
+
+                let committed = match pending.task.op {
+                    Operation::Set => self.commit_route_add(pending.route),
+                    Operation::Del => {
+                        let route = &pending.route.op;
+                        freed |= matches!(route, RouteBulkOp::Remove { .. });
+                        self.commit_route_remove(route.vrf_id(), route.prefix())
+                    }
+                };
+                let status = match committed {
+                    Ok(()) => RouteResponseStatus::Success,
+                    Err(e) => {
+                        error!("Failed to commit route {}: {}", pending.task.key, e);
+                        RouteResponseStatus::Failed(e.to_string())
+                    }
+                };
+                self.record_response(&pending.task, status);
+            }
+        }
+
+        if let Err(e) = self.process_pending_nhg_removals().await {
+            warn!("Failed to process pending NHG removals: {}", e);
+        }
+
+        // Removed routes and groups may make room for rejected ones
+        if freed || self.nhg_count < nhg_count {
+            self.resolve_sai_retries();
+        }
+    }
+
+    /// Records the final outcome of a route task for the response channel.
+    ///
+    /// Only called once a task is done with: entries parked in the retry
+    /// cache respond when their retry completes.
+    fn record_response(&mut self, task: &KeyOpFieldsValues, status: RouteResponseStatus) {
+        if !self.config.publish_programmed_routes {
+            return;
+        }
+        let fvs = match task.op {
+            Operation::Set => task.fvs.clone(),
+            Operation::Del => Vec::new(),
+        };
+        self.pending_responses.push(RouteResponse {
+            key: task.key.clone(),
+            op: task.op,
+            fvs,
+            status,
+        });
+    }
+
+    /// Publishes the responses gathered during this drain cycle.
+    fn publish_responses(&mut self) {
+        if self.pending_responses.is_empty() {
+            return;
+        }
+        let responses = std::mem::take(&mut self.pending_responses);
+        if let Some(cb) = self.callbacks.as_ref() {
+            cb.publish_route_responses(&responses);
+        }
+    }
+
     /// Adds a task to the consumer for processing.
     pub fn add_task(&mut self, key: String, op: Operation, fields: HashMap<String, String>) {
         let fvs: Vec<(String, String)> = fields.into_iter().collect();
@@ -707,47 +1877,51 @@ impl Orch for RouteOrch {
             }
         };
 
-        // Process pending tasks
-        let tasks = self.consumer.drain();
+        // Next hop and VRF updates may wake parked tasks, so they go first
+        self.process_next_hop_updates().await;
+        self.process_vrf_updates();
+        if self.sai_retry_due() {
+            self.resolve_sai_retries();
+        }
+
+        // Retried tasks go first so newer updates for the same key win
+        let mut tasks: Vec<KeyOpFieldsValues> = self
+            .retry_cache
+            .drain_ready()
+            .into_iter()
+            .map(|(_, task)| task)
+            .collect();
+        tasks.extend(self.consumer.drain());
 
         for task in tasks {
-            // Parse VRF and prefix from key
-            // Key format: "vrf_id:prefix" or just "prefix" for default VRF
-            let (vrf_id, prefix) = match parse_route_key(&task.key) {
-                Ok((v, p)) => (v, p),
-                Err(e) => {
-                    warn!("Invalid route key {}: {}", task.key, e);
-                    continue;
-                }
-            };
+            self.queue_route_task(task).await;
+        }
 
-            match task.op {
-                Operation::Set => {
-                    // Parse next-hops from fields
-                    let fields: HashMap<String, String> = task.fvs.into_iter().collect();
-                    let nhg_key = match parse_nexthops(&fields) {
-                        Ok(key) => key,
-                        Err(e) => {
-                            warn!("Invalid nexthops for {}: {}", task.key, e);
-                            continue;
-                        }
-                    };
+        self.flush_routes().await;
+        self.upgrade_overflow_routes().await;
+        self.publish_responses();
 
-                    if let Err(e) = self.add_route(vrf_id, prefix, nhg_key).await {
-                        error!("Failed to add route {}: {}", task.key, e);
-                    }
-                }
-                Operation::Del => {
-                    if let Err(e) = self.remove_route(vrf_id, &prefix).await {
-                        error!("Failed to remove route {}: {}", task.key, e);
-                    }
-                }
-            }
+        // Back off from the minimum again once SAI accepts everything
+        if self.retry_cache.pending_count(&sai_retry_constraint()) == 0 {
+            self.sai_retry_at = None;
+            self.sai_retry_backoff = self.config.sai_retry_backoff;
         }
     }
 
     fn has_pending_tasks(&self) -> bool {
+        // Tasks parked on a constraint wait for it to resolve, not for a
+        // drain cycle
         self.consumer.has_pending()
+            || self.retry_cache.ready_len() > 0
+            || self.sai_retry_due()
+            || self
+                .next_hop_updates
+                .as_ref()
+                .is_some_and(|updates| !updates.is_empty())
+            || self
+                .vrf_updates
+                .as_ref()
+                .is_some_and(|updates| !updates.is_empty())
     }
 
     fn bake(&mut self) -> bool {
@@ -760,10 +1934,40 @@ impl Orch for RouteOrch {
         self.consumer
             .peek()
             .map(|t| format!("{}:{:?}", t.key, t.op))
+            .chain(
+                self.overflow_routes
+                    .iter()
+                    .map(|(key, nhg)| format!("{}:OVERFLOW:{}", key, nhg.nhg_key)),
+            )
+            .chain(
+                self.unresolved_routes
+                    .iter()
+                    .map(|(key, nhg)| format!("{}:UNRESOLVED:{}", key, nhg.nhg_key)),
+            )
             .collect()
     }
 }
 
+/// Returns true if the group has the neighbor next hop an update is about.
+fn has_next_hop(nhg_key: &NextHopGroupKey, update: &NextHopUpdate) -> bool {
+    nhg_key
+        .iter()
+        .any(|nh| !nh.is_interface_nexthop() && update.is_next_hop(nh.ip_address(), nh.alias()))
+}
+
+/// Converts an address to the std type a `PrefixSet` is keyed by.
+fn std_addr(addr: &IpAddress) -> IpAddr {
+    match addr {
+        IpAddress::V4(v4) => IpAddr::V4((*v4).into()),
+        IpAddress::V6(v6) => IpAddr::V6((*v6).into()),
+    }
+}
+
+/// Splits a prefix into the address and length a `PrefixSet` is keyed by.
+fn prefix_key(prefix: &IpPrefix) -> (IpAddr, u8) {
+    (std_addr(prefix.address()), prefix.prefix_len())
+}
+
 /// Parses a route key into VRF ID and prefix.
 fn parse_route_key(key: &str) -> Result<(RawSaiObjectId, IpPrefix)> {
     if let Some((vrf_str, prefix_str)) = key.split_once(':') {
@@ -809,6 +2013,8 @@ fn parse_nexthops(fields: &HashMap<String, String>) -> Result<NextHopGroupKey> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vrf::{VrfConfig, VrfOrch, VrfOrchConfig};
+    use sonic_orch_common::Publisher;
     use std::net::Ipv4Addr;
     use std::sync::{Arc, Mutex};
 
@@ -833,14 +2039,27 @@ mod tests {
     #[derive(Default)]
     struct MockCallbacks {
         next_hop_ids: Arc<Mutex<HashMap<NextHopKey, RawSaiObjectId>>>,
+        vrf_next_hop_ids: Arc<Mutex<HashMap<(RawSaiObjectId, NextHopKey), RawSaiObjectId>>>,
         router_intf_ids: Arc<Mutex<HashMap<String, RawSaiObjectId>>>,
         next_hop_refs: Arc<Mutex<HashMap<NextHopKey, u32>>>,
         router_intf_refs: Arc<Mutex<HashMap<String, u32>>>,
         vrf_refs: Arc<Mutex<HashMap<RawSaiObjectId, u32>>>,
         vrfs: Arc<Mutex<HashSet<RawSaiObjectId>>>,
+        vrf_names: Arc<Mutex<HashMap<String, RawSaiObjectId>>>,
         nhg_counter: Arc<Mutex<u64>>,
+        bulk_calls: Arc<Mutex<usize>>,
+        failing_prefixes: Arc<Mutex<HashSet<String>>>,
+        response_batches: Arc<Mutex<Vec<Vec<RouteResponse>>>>,
+        crm_nhg_available: Arc<Mutex<Option<u32>>>,
+        nhg_table_full: Arc<Mutex<bool>>,
+        // Like SAI, refuse groups with a member that has no next hop object
+        reject_unresolved_members: Arc<Mutex<bool>>,
+        sai_routes: Arc<Mutex<HashMap<String, SaiNextHop>>>,
     }
 
+    /// Next hop ID and blackhole flag of a route as last programmed.
+    type SaiNextHop = (Option<RawSaiObjectId>, bool);
+
     impl MockCallbacks {
         fn new() -> Self {
             Self::default()
@@ -850,6 +2069,27 @@ mod tests {
             self.next_hop_ids.lock().unwrap().insert(nh, id);
         }
 
+        // A next hop that only resolves in one VRF
+        fn add_vrf_next_hop(&self, vrf_id: RawSaiObjectId, nh: NextHopKey, id: RawSaiObjectId) {
+            self.vrf_next_hop_ids
+                .lock()
+                .unwrap()
+                .insert((vrf_id, nh), id);
+        }
+
+        // Mirrors NeighOrch removing a neighbor and its next hop
+        fn remove_next_hop(&self, nh: &NextHopKey) {
+            self.next_hop_ids.lock().unwrap().remove(nh);
+        }
+
+        fn sai_route(&self, prefix: &IpPrefix) -> Option<SaiNextHop> {
+            self.sai_routes
+                .lock()
+                .unwrap()
+                .get(&prefix.to_string())
+                .copied()
+        }
+
         fn add_router_intf(&self, alias: String, id: RawSaiObjectId) {
             self.router_intf_ids.lock().unwrap().insert(alias, id);
         }
@@ -857,6 +2097,64 @@ mod tests {
         fn add_vrf(&self, vrf_id: RawSaiObjectId) {
             self.vrfs.lock().unwrap().insert(vrf_id);
         }
+
+        fn add_named_vrf(&self, name: &str, vrf_id: RawSaiObjectId) {
+            self.add_vrf(vrf_id);
+            self.vrf_names
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), vrf_id);
+        }
+
+        // Mirrors VrfOrch::remove_vrf, which refuses to remove a VRF in use
+        fn remove_named_vrf(&self, name: &str) -> bool {
+            let mut names = self.vrf_names.lock().unwrap();
+            let Some(&vrf_id) = names.get(name) else {
+                return false;
+            };
+            if self.vrf_ref_count(vrf_id) > 0 {
+                return false;
+            }
+            names.remove(name);
+            self.vrfs.lock().unwrap().remove(&vrf_id);
+            true
+        }
+
+        fn vrf_ref_count(&self, vrf_id: RawSaiObjectId) -> u32 {
+            self.vrf_refs
+                .lock()
+                .unwrap()
+                .get(&vrf_id)
+                .copied()
+                .unwrap_or(0)
+        }
+
+        fn fail_prefix(&self, prefix: &str) {
+            self.failing_prefixes
+                .lock()
+                .unwrap()
+                .insert(prefix.to_string());
+        }
+
+        fn bulk_calls(&self) -> usize {
+            *self.bulk_calls.lock().unwrap()
+        }
+
+        fn take_response_batches(&self) -> Vec<Vec<RouteResponse>> {
+            std::mem::take(&mut *self.response_batches.lock().unwrap())
+        }
+
+        fn set_crm_nhg_available(&self, available: Option<u32>) {
+            *self.crm_nhg_available.lock().unwrap() = available;
+        }
+
+        fn set_nhg_table_full(&self, full: bool) {
+            *self.nhg_table_full.lock().unwrap() = full;
+        }
+
+        fn nhgs_created(&self) -> u64 {
+            *self.nhg_counter.lock().unwrap()
+        }
     }
 
     #[async_trait]
@@ -869,6 +2167,19 @@ mod tests {
             self.next_hop_ids.lock().unwrap().get(nexthop).copied()
         }
 
+        fn get_next_hop_id_in_vrf(
+            &self,
+            vrf_id: RawSaiObjectId,
+            nexthop: &NextHopKey,
+        ) -> Option<RawSaiObjectId> {
+            self.vrf_next_hop_ids
+                .lock()
+                .unwrap()
+                .get(&(vrf_id, nexthop.clone()))
+                .copied()
+                .or_else(|| self.get_next_hop_id(nexthop))
+        }
+
         fn get_router_intf_id(&self, alias: &str) -> Option<RawSaiObjectId> {
             self.router_intf_ids.lock().unwrap().get(alias).copied()
         }
@@ -877,6 +2188,14 @@ mod tests {
             vrf_id == 0 || self.vrfs.lock().unwrap().contains(&vrf_id)
         }
 
+        fn crm_nhg_available(&self) -> Option<u32> {
+            *self.crm_nhg_available.lock().unwrap()
+        }
+
+        fn get_vrf_id(&self, vrf_name: &str) -> Option<RawSaiObjectId> {
+            self.vrf_names.lock().unwrap().get(vrf_name).copied()
+        }
+
         fn increase_next_hop_ref_count(&self, nexthop: &NextHopKey) {
             *self
                 .next_hop_refs
@@ -917,7 +2236,23 @@ mod tests {
             }
         }
 
-        async fn sai_create_nhg(&self, _nhg_key: &NextHopGroupKey) -> Result<RawSaiObjectId> {
+        async fn sai_create_nhg(
+            &self,
+            vrf_id: RawSaiObjectId,
+            nhg_key: &NextHopGroupKey,
+        ) -> Result<RawSaiObjectId> {
+            if *self.nhg_table_full.lock().unwrap() {
+                return Err(RouteError::NhgResourceExhausted(
+                    "SAI_STATUS_TABLE_FULL".to_string(),
+                ));
+            }
+            if *self.reject_unresolved_members.lock().unwrap() {
+                if let Some(nh) = nhg_key.iter().find(|nh| {
+                    !nh.is_interface_nexthop() && self.get_next_hop_id_in_vrf(vrf_id, nh).is_none()
+                }) {
+                    return Err(RouteError::NextHopNotResolved(nh.to_string()));
+                }
+            }
             let mut counter = self.nhg_counter.lock().unwrap();
             *counter += 1;
             Ok(*counter)
@@ -930,31 +2265,63 @@ mod tests {
         async fn sai_create_route(
             &self,
             _vrf_id: RawSaiObjectId,
-            _prefix: &IpPrefix,
-            _nhg_id: Option<RawSaiObjectId>,
-            _blackhole: bool,
+            prefix: &IpPrefix,
+            nhg_id: Option<RawSaiObjectId>,
+            blackhole: bool,
         ) -> Result<()> {
+            self.sai_routes
+                .lock()
+                .unwrap()
+                .insert(prefix.to_string(), (nhg_id, blackhole));
             Ok(())
         }
 
-        async fn sai_remove_route(
-            &self,
-            _vrf_id: RawSaiObjectId,
-            _prefix: &IpPrefix,
-        ) -> Result<()> {
+        async fn sai_remove_route(&self, _vrf_id: RawSaiObjectId, prefix: &IpPrefix) -> Result<()> {
+            self.sai_routes.lock().unwrap().remove(&prefix.to_string());
             Ok(())
         }
 
         async fn sai_set_route(
             &self,
             _vrf_id: RawSaiObjectId,
-            _prefix: &IpPrefix,
-            _nhg_id: Option<RawSaiObjectId>,
-            _blackhole: bool,
+            prefix: &IpPrefix,
+            nhg_id: Option<RawSaiObjectId>,
+            blackhole: bool,
         ) -> Result<()> {
+            self.sai_routes
+                .lock()
+                .unwrap()
+                .insert(prefix.to_string(), (nhg_id, blackhole));
             Ok(())
         }
-    }
+
+        async fn sai_bulk_route(&self, ops: &[RouteBulkOp]) -> Vec<Result<()>> {
+            *self.bulk_calls.lock().unwrap() += 1;
+            let failing = self.failing_prefixes.lock().unwrap();
+            let mut routes = self.sai_routes.lock().unwrap();
+            ops.iter()
+                .map(|op| {
+                    let prefix = op.prefix().to_string();
+                    if failing.contains(&prefix) {
+                        return Err(RouteError::SaiError("SAI_STATUS_FAILURE".to_string()));
+                    }
+                    if matches!(op, RouteBulkOp::Remove { .. }) {
+                        routes.remove(&prefix);
+                    } else {
+                        routes.insert(prefix, op.next_hop());
+                    }
+                    Ok(())
+                })
+                .collect()
+        }
+
+        fn publish_route_responses(&self, responses: &[RouteResponse]) {
+            self.response_batches
+                .lock()
+                .unwrap()
+                .push(responses.to_vec());
+        }
+    }
 
     // ===== Basic parsing tests =====
 
@@ -1078,6 +2445,8 @@ mod tests {
             max_nhg_count: 512,
             ordered_ecmp: true,
             default_action_drop: false,
+            max_bulk_size: 128,
+            publish_programmed_routes: false,
         };
         let orch = RouteOrch::new(config);
         assert_eq!(orch.max_nhg_count(), 512);
@@ -1257,7 +2626,7 @@ mod tests {
 
         // Manually add NHG entry for testing
         let entry = NextHopGroupEntry::new(0x1234);
-        orch.synced_nhgs.insert(key.clone(), entry);
+        orch.synced_nhgs.insert((0, key.clone()), entry);
         orch.nhg_count += 1;
 
         // Initially ref count is 0
@@ -1369,7 +2738,7 @@ mod tests {
 
         // Decrease to zero should mark for pending removal
         orch.decrease_nhg_ref_count(&key).unwrap();
-        assert!(orch.pending_nhg_removals.contains(&key));
+        assert!(orch.pending_nhg_removals.contains(&(0, key.clone())));
 
         // Process pending removals
         orch.process_pending_nhg_removals().await.unwrap();
@@ -1425,6 +2794,50 @@ mod tests {
         assert_eq!(orch.get_nhg(&nhg_key).unwrap().ref_count(), 1);
     }
 
+    #[tokio::test]
+    async fn test_route_queries_through_ffi_bridge() {
+        use std::ffi::{CStr, CString};
+
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.add_next_hop(make_nexthop("192.168.1.2", "Ethernet4"), 0x1001);
+        orch.set_callbacks(callbacks);
+
+        let nhg_key = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.1.1", "Ethernet0"),
+            make_nexthop("192.168.1.2", "Ethernet4"),
+        ]);
+        orch.add_route(0, make_prefix("10.0.0.0", 24), nhg_key.clone())
+            .await
+            .unwrap();
+        assert_eq!(orch.route_count(0), 1);
+        assert_eq!(orch.route_count(0x1234), 0);
+
+        let orch = Arc::new(Mutex::new(orch));
+        sonic_ffi_bridge::register_route_orch(orch.clone());
+
+        let prefix = CString::new("10.0.0.0/24").unwrap();
+        let mut buf = [0 as std::ffi::c_char; 128];
+        let written = unsafe {
+            sonic_ffi_bridge::route_orch_get_nhg_key(
+                0,
+                prefix.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        let expected = nhg_key.to_string();
+        assert_eq!(written, expected.len() as i32);
+        let key = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(key.to_str().unwrap(), expected);
+        assert!(unsafe { sonic_ffi_bridge::route_orch_has_route(0, prefix.as_ptr()) });
+        assert_eq!(sonic_ffi_bridge::route_orch_route_count(0), 1);
+
+        sonic_ffi_bridge::unregister_route_orch();
+        assert_eq!(sonic_ffi_bridge::route_orch_route_count(0), 0);
+    }
+
     #[tokio::test]
     async fn test_add_route_blackhole() {
         let mut orch = RouteOrch::new(RouteOrchConfig::default());
@@ -1853,6 +3266,98 @@ mod tests {
         assert_eq!(route2.nhg.nhg_key, nhg_key2);
     }
 
+    #[tokio::test]
+    async fn test_longest_match_route() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_vrf(0x1234);
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.add_next_hop(make_nexthop("192.168.2.1", "Ethernet4"), 0x1001);
+        orch.set_callbacks(callbacks);
+
+        let wide = make_prefix("10.0.0.0", 8);
+        let narrow = make_prefix("10.1.0.0", 16);
+        let wide_nhg = NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0"));
+        let narrow_nhg = NextHopGroupKey::single(make_nexthop("192.168.2.1", "Ethernet4"));
+        orch.add_route(0, wide.clone(), wide_nhg.clone())
+            .await
+            .unwrap();
+        orch.add_route(0, narrow.clone(), narrow_nhg.clone())
+            .await
+            .unwrap();
+        orch.add_route(0x1234, wide.clone(), wide_nhg.clone())
+            .await
+            .unwrap();
+
+        let addr = |s: &str| sonic_types::IpAddress::V4(s.parse::<Ipv4Addr>().unwrap().into());
+        let (prefix, entry) = orch.longest_match_route(0, &addr("10.1.2.3")).unwrap();
+        assert_eq!(prefix, &narrow);
+        assert_eq!(entry.nhg.nhg_key, narrow_nhg);
+        assert_eq!(
+            orch.longest_match_route(0, &addr("10.2.0.1")).unwrap().0,
+            &wide
+        );
+        assert_eq!(
+            orch.longest_match_route(0x1234, &addr("10.1.2.3"))
+                .unwrap()
+                .0,
+            &wide
+        );
+        assert!(orch.longest_match_route(0, &addr("11.0.0.1")).is_none());
+
+        orch.remove_route(0, &narrow).await.unwrap();
+        assert_eq!(
+            orch.longest_match_route(0, &addr("10.1.2.3")).unwrap().0,
+            &wide
+        );
+
+        orch.remove_route(0x1234, &wide).await.unwrap();
+        assert!(!orch.route_prefixes.contains_key(&0x1234));
+        assert!(orch
+            .longest_match_route(0x1234, &addr("10.1.2.3"))
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_route_recursive_nexthop() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        // 10.1.0.5 is not a neighbor, but 10.1.0.0/24 forwards to one
+        let recursive = NextHopGroupKey::single(make_nexthop("10.1.0.5", "Ethernet0"));
+        let prefix = make_prefix("20.0.0.0", 24);
+        assert!(matches!(
+            orch.add_route(0, prefix.clone(), recursive.clone()).await,
+            Err(RouteError::NextHopNotResolved(_))
+        ));
+
+        orch.add_route(
+            0,
+            make_prefix("10.1.0.0", 24),
+            NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0")),
+        )
+        .await
+        .unwrap();
+        orch.add_route(0, prefix.clone(), recursive).await.unwrap();
+        assert_eq!(callbacks.sai_route(&prefix), Some((Some(0x1000), false)));
+
+        // Never resolved through the default route
+        orch.add_route(
+            0,
+            make_prefix("0.0.0.0", 0),
+            NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0")),
+        )
+        .await
+        .unwrap();
+        let via_default = NextHopGroupKey::single(make_nexthop("30.0.0.1", "Ethernet0"));
+        assert!(orch
+            .add_route(0, make_prefix("40.0.0.0", 24), via_default)
+            .await
+            .is_err());
+    }
+
     // ===== Empty VRF table cleanup test =====
 
     #[tokio::test]
@@ -1893,4 +3398,962 @@ mod tests {
         // Default VRF table might be cleaned up - implementation allows it
         // This test verifies no crash occurs
     }
+
+    // ===== Bulk programming tests =====
+
+    fn route_task(prefix: &str, nexthop: &str) -> (String, HashMap<String, String>) {
+        let mut fields = HashMap::new();
+        fields.insert("nexthop".to_string(), nexthop.to_string());
+        (prefix.to_string(), fields)
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_programming_batches() {
+        const ROUTES: usize = 100_000;
+
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        let nh = make_nexthop("192.168.1.1", "Ethernet0");
+        callbacks.add_next_hop(nh.clone(), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        for i in 0..ROUTES {
+            let prefix = format!("10.{}.{}.{}/32", i >> 16, (i >> 8) & 0xff, i & 0xff);
+            let (key, fields) = route_task(&prefix, "192.168.1.1@Ethernet0");
+            orch.add_task(key, Operation::Set, fields);
+        }
+        orch.do_task().await;
+
+        let batch = orch.config.max_bulk_size;
+        assert_eq!(callbacks.bulk_calls(), ROUTES.div_ceil(batch));
+        assert_eq!(orch.synced_routes.get(&0).unwrap().len(), ROUTES);
+        assert_eq!(
+            callbacks.next_hop_refs.lock().unwrap().get(&nh),
+            Some(&(ROUTES as u32))
+        );
+        assert_eq!(orch.retry_count(), 0);
+        assert!(!orch.has_pending_tasks());
+    }
+
+    /// Retries routes SAI rejected on the next drain cycle.
+    fn sai_retry_now_config() -> RouteOrchConfig {
+        RouteOrchConfig {
+            sai_retry_backoff: Duration::ZERO,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_partial_failure_retries() {
+        let config = RouteOrchConfig {
+            max_bulk_size: 4,
+            sai_retry_backoff: Duration::ZERO,
+            ..Default::default()
+        };
+        let mut orch = RouteOrch::new(config);
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.fail_prefix("10.0.3.0/24");
+        callbacks.fail_prefix("10.0.7.0/24");
+        orch.set_callbacks(callbacks.clone());
+
+        for i in 0..10 {
+            let (key, fields) = route_task(&format!("10.0.{}.0/24", i), "192.168.1.1@Ethernet0");
+            orch.add_task(key, Operation::Set, fields);
+        }
+        orch.do_task().await;
+
+        assert_eq!(callbacks.bulk_calls(), 3);
+        assert_eq!(orch.synced_routes.get(&0).unwrap().len(), 8);
+        assert!(!orch.has_route(0, &make_prefix("10.0.3.0", 24)));
+        assert!(!orch.has_route(0, &make_prefix("10.0.7.0", 24)));
+        assert_eq!(orch.retry_count(), 2);
+        assert!(orch.has_pending_tasks());
+
+        // SAI recovers: the failed entries are retried in a single batch
+        callbacks.failing_prefixes.lock().unwrap().clear();
+        orch.do_task().await;
+
+        assert_eq!(callbacks.bulk_calls(), 4);
+        assert_eq!(orch.synced_routes.get(&0).unwrap().len(), 10);
+        assert_eq!(orch.retry_count(), 0);
+        assert!(!orch.has_pending_tasks());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_failure_releases_new_nhg() {
+        let mut orch = RouteOrch::new(sai_retry_now_config());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.add_next_hop(make_nexthop("192.168.1.2", "Ethernet4"), 0x1001);
+        callbacks.fail_prefix("10.0.0.0/24");
+        orch.set_callbacks(callbacks.clone());
+
+        let nhg_key = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.1.1", "Ethernet0"),
+            make_nexthop("192.168.1.2", "Ethernet4"),
+        ]);
+        let (key, fields) =
+            route_task("10.0.0.0/24", "192.168.1.1@Ethernet0,192.168.1.2@Ethernet4");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // The group created for the failed route is not leaked
+        assert!(!orch.has_route(0, &make_prefix("10.0.0.0", 24)));
+        assert!(!orch.has_nhg(&nhg_key));
+        assert_eq!(orch.nhg_count(), 0);
+        assert_eq!(orch.retry_count(), 1);
+
+        callbacks.failing_prefixes.lock().unwrap().clear();
+        orch.do_task().await;
+
+        assert!(orch.has_route(0, &make_prefix("10.0.0.0", 24)));
+        assert_eq!(orch.get_nhg(&nhg_key).unwrap().ref_count(), 1);
+        assert_eq!(orch.retry_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_remove_and_readd_same_prefix() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.add_next_hop(make_nexthop("192.168.1.2", "Ethernet4"), 0x1001);
+        orch.set_callbacks(callbacks.clone());
+
+        let prefix = make_prefix("10.0.0.0", 24);
+        orch.add_route(
+            0,
+            prefix.clone(),
+            NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0")),
+        )
+        .await
+        .unwrap();
+
+        // DEL followed by SET for the same key must not share a batch
+        orch.add_task("10.0.0.0/24".to_string(), Operation::Del, HashMap::new());
+        let (key, fields) = route_task("10.0.0.0/24", "192.168.1.2@Ethernet4");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        assert_eq!(callbacks.bulk_calls(), 2);
+        let route = orch.get_route(0, &prefix).unwrap();
+        assert_eq!(
+            route.nhg.nhg_key,
+            NextHopGroupKey::single(make_nexthop("192.168.1.2", "Ethernet4"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_failed_delete_keeps_route() {
+        let mut orch = RouteOrch::new(sai_retry_now_config());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let prefix = make_prefix("10.0.0.0", 24);
+        orch.add_route(
+            0,
+            prefix.clone(),
+            NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0")),
+        )
+        .await
+        .unwrap();
+
+        callbacks.fail_prefix("10.0.0.0/24");
+        orch.add_task("10.0.0.0/24".to_string(), Operation::Del, HashMap::new());
+        orch.do_task().await;
+
+        assert!(orch.has_route(0, &prefix));
+        assert_eq!(orch.retry_count(), 1);
+
+        callbacks.failing_prefixes.lock().unwrap().clear();
+        orch.do_task().await;
+
+        assert!(!orch.has_route(0, &prefix));
+        assert_eq!(orch.retry_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_route_failure_backs_off() {
+        let config = RouteOrchConfig {
+            sai_retry_backoff: Duration::from_secs(3600),
+            ..Default::default()
+        };
+        let mut orch = RouteOrch::new(config);
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.fail_prefix("10.0.1.0/24");
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) = route_task("10.0.0.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        let (key, fields) = route_task("10.0.1.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+        assert_eq!(callbacks.bulk_calls(), 1);
+        assert_eq!(orch.retry_count(), 1);
+
+        // Parked until the backoff expires: no busy retry
+        assert!(!orch.has_pending_tasks());
+        orch.do_task().await;
+        assert_eq!(callbacks.bulk_calls(), 1);
+
+        // Removing a route may free the space SAI ran out of
+        callbacks.failing_prefixes.lock().unwrap().clear();
+        orch.add_task("10.0.0.0/24".to_string(), Operation::Del, HashMap::new());
+        orch.do_task().await;
+        assert!(orch.has_pending_tasks());
+        orch.do_task().await;
+
+        assert!(orch.has_route(0, &make_prefix("10.0.1.0", 24)));
+        assert_eq!(orch.retry_count(), 0);
+        assert_eq!(orch.sai_retry_backoff, Duration::from_secs(3600));
+    }
+
+    #[tokio::test]
+    async fn test_route_prepare_failure_parked_for_retry() {
+        let mut orch = RouteOrch::new(sai_retry_now_config());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        // The VRF object does not exist yet
+        let (key, fields) = route_task("0x300:10.0.0.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+        assert!(!orch.has_route(0x300, &make_prefix("10.0.0.0", 24)));
+        assert_eq!(orch.retry_count(), 1);
+
+        callbacks.add_vrf(0x300);
+        orch.do_task().await;
+        assert!(orch.has_route(0x300, &make_prefix("10.0.0.0", 24)));
+        assert_eq!(orch.retry_count(), 0);
+    }
+
+    fn leaked_route_task(
+        prefix: &str,
+        nexthop: &str,
+        nexthop_vrf: &str,
+    ) -> (String, HashMap<String, String>) {
+        let (key, mut fields) = route_task(prefix, nexthop);
+        fields.insert("nexthop_vrf".to_string(), nexthop_vrf.to_string());
+        (key, fields)
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_route_refcounts_nexthop_vrf() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_named_vrf("Vrf-shared", 0x200);
+        callbacks.add_next_hop(make_nexthop("172.16.0.1", "Ethernet8"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        let prefix = make_prefix("10.1.0.0", 24);
+        let route = orch.get_route(0x100, &prefix).unwrap();
+        assert!(route.nhg.is_vrf_leak());
+        assert_eq!(route.nhg.nexthop_vrf_id, Some(0x200));
+        assert_eq!(callbacks.vrf_ref_count(0x100), 1);
+        assert_eq!(callbacks.vrf_ref_count(0x200), 1);
+
+        // Re-pointing the route at its own VRF releases the shared VRF
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-red");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        assert!(!orch.get_route(0x100, &prefix).unwrap().nhg.is_vrf_leak());
+        assert_eq!(callbacks.vrf_ref_count(0x100), 1);
+        assert_eq!(callbacks.vrf_ref_count(0x200), 0);
+
+        orch.add_task(
+            "0x100:10.1.0.0/24".to_string(),
+            Operation::Del,
+            HashMap::new(),
+        );
+        orch.do_task().await;
+
+        assert!(!orch.has_route(0x100, &prefix));
+        assert_eq!(callbacks.vrf_ref_count(0x100), 0);
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_resolves_next_hops_in_nexthop_vrf() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_named_vrf("Vrf-shared", 0x200);
+        // The same neighbor IP exists in both VRFs
+        let nh = make_nexthop("172.16.0.1", "Ethernet8");
+        callbacks.add_vrf_next_hop(0x100, nh.clone(), 0x1000);
+        callbacks.add_vrf_next_hop(0x200, nh, 0x2000);
+        callbacks.add_vrf_next_hop(0x200, make_nexthop("172.16.0.2", "Ethernet12"), 0x2001);
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        let (key, fields) =
+            leaked_route_task("0x100:10.2.0.0/24", "172.16.0.1@Ethernet8", "Vrf-red");
+        orch.add_task(key, Operation::Set, fields);
+        let (key, fields) = leaked_route_task(
+            "0x100:10.3.0.0/24",
+            "172.16.0.1@Ethernet8,172.16.0.2@Ethernet12",
+            "Vrf-shared",
+        );
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // The leaked route uses the next hop of the shared VRF
+        assert_eq!(
+            callbacks.sai_route(&make_prefix("10.1.0.0", 24)),
+            Some((Some(0x2000), false))
+        );
+        assert_eq!(
+            callbacks.sai_route(&make_prefix("10.2.0.0", 24)),
+            Some((Some(0x1000), false))
+        );
+
+        // Its ECMP group is resolved there too
+        let nhg_key = NextHopGroupKey::from_nexthops([
+            make_nexthop("172.16.0.1", "Ethernet8"),
+            make_nexthop("172.16.0.2", "Ethernet12"),
+        ]);
+        assert!(orch.has_route(0x100, &make_prefix("10.3.0.0", 24)));
+        assert_eq!(
+            orch.get_nhg_in_vrf(0x200, &nhg_key).unwrap().vrf_id(),
+            0x200
+        );
+        assert!(!orch.has_nhg(&nhg_key));
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_same_next_hops_from_two_vrfs() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_named_vrf("Vrf-blue", 0x200);
+        callbacks.add_named_vrf("Vrf-shared", 0x300);
+        for vrf_id in [0x200, 0x300] {
+            callbacks.add_vrf_next_hop(vrf_id, make_nexthop("172.16.0.1", "Ethernet8"), vrf_id);
+            callbacks.add_vrf_next_hop(
+                vrf_id,
+                make_nexthop("172.16.0.2", "Ethernet12"),
+                vrf_id + 1,
+            );
+        }
+        orch.set_callbacks(callbacks.clone());
+
+        let nexthops = "172.16.0.1@Ethernet8,172.16.0.2@Ethernet12";
+        let (key, fields) = leaked_route_task("0x100:10.1.0.0/24", nexthops, "Vrf-blue");
+        orch.add_task(key, Operation::Set, fields);
+        let (key, fields) = leaked_route_task("0x100:10.2.0.0/24", nexthops, "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        let (key, fields) = leaked_route_task("0x100:10.3.0.0/24", nexthops, "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // One group per next-hop VRF, shared by the routes leaked from it
+        let nhg_key = NextHopGroupKey::from_nexthops([
+            make_nexthop("172.16.0.1", "Ethernet8"),
+            make_nexthop("172.16.0.2", "Ethernet12"),
+        ]);
+        assert_eq!(orch.retry_count(), 0);
+        assert_eq!(orch.nhg_count(), 2);
+        assert_eq!(orch.get_nhg_in_vrf(0x200, &nhg_key).unwrap().ref_count(), 1);
+        assert_eq!(orch.get_nhg_in_vrf(0x300, &nhg_key).unwrap().ref_count(), 2);
+
+        orch.remove_route(0x100, &make_prefix("10.1.0.0", 24))
+            .await
+            .unwrap();
+        assert!(!orch.has_nhg_in_vrf(0x200, &nhg_key));
+        assert_eq!(orch.get_nhg_in_vrf(0x300, &nhg_key).unwrap().ref_count(), 2);
+
+        for prefix in ["10.2.0.0", "10.3.0.0"] {
+            orch.remove_route(0x100, &make_prefix(prefix, 24))
+                .await
+                .unwrap();
+        }
+        assert_eq!(orch.nhg_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_route_waits_for_nexthop_vrf() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_next_hop(make_nexthop("172.16.0.1", "Ethernet8"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // Vrf-shared does not exist yet: parked until it is added
+        let prefix = make_prefix("10.1.0.0", 24);
+        assert!(!orch.has_route(0x100, &prefix));
+        assert_eq!(orch.retry_count(), 1);
+        assert!(!orch.has_pending_tasks());
+        assert_eq!(callbacks.vrf_ref_count(0x100), 0);
+
+        // Another VRF does not wake it
+        orch.on_vrf_added("Vrf-blue");
+        assert!(!orch.has_pending_tasks());
+
+        callbacks.add_named_vrf("Vrf-shared", 0x200);
+        orch.on_vrf_added("Vrf-shared");
+        assert!(orch.has_pending_tasks());
+        orch.do_task().await;
+
+        assert_eq!(
+            orch.get_route(0x100, &prefix).unwrap().nhg.nexthop_vrf_id,
+            Some(0x200)
+        );
+        assert_eq!(orch.retry_count(), 0);
+        assert_eq!(callbacks.vrf_ref_count(0x200), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_route_woken_by_vrf_orch() {
+        let mut vrf_orch = VrfOrch::new(VrfOrchConfig::default());
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        orch.subscribe_vrf_updates(vrf_orch.subscribe_vrf_updates());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_next_hop(make_nexthop("172.16.0.1", "Ethernet8"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+        assert_eq!(orch.retry_count(), 1);
+
+        let vrf_id = vrf_orch.add_vrf(&VrfConfig::new("Vrf-shared")).unwrap();
+        callbacks.add_named_vrf("Vrf-shared", vrf_id);
+        assert!(orch.has_pending_tasks());
+        orch.do_task().await;
+
+        let prefix = make_prefix("10.1.0.0", 24);
+        assert_eq!(
+            orch.get_route(0x100, &prefix).unwrap().nhg.nexthop_vrf_id,
+            Some(vrf_id)
+        );
+        assert_eq!(orch.retry_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_nexthop_vrf_delete_blocked_while_leaked() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_named_vrf("Vrf-red", 0x100);
+        callbacks.add_named_vrf("Vrf-blue", 0x300);
+        callbacks.add_named_vrf("Vrf-shared", 0x200);
+        callbacks.add_next_hop(make_nexthop("172.16.0.1", "Ethernet8"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        for key in ["0x100:10.1.0.0/24", "0x300:10.1.0.0/24"] {
+            let (key, fields) = leaked_route_task(key, "172.16.0.1@Ethernet8", "Vrf-shared");
+            orch.add_task(key, Operation::Set, fields);
+        }
+        orch.do_task().await;
+        assert_eq!(callbacks.vrf_ref_count(0x200), 2);
+
+        // The shared VRF is deleted while leaked routes remain: refused
+        assert!(!callbacks.remove_named_vrf("Vrf-shared"));
+
+        let prefix = make_prefix("10.1.0.0", 24);
+        orch.remove_route(0x100, &prefix).await.unwrap();
+        assert_eq!(callbacks.vrf_ref_count(0x200), 1);
+        assert!(!callbacks.remove_named_vrf("Vrf-shared"));
+
+        orch.remove_route(0x300, &prefix).await.unwrap();
+        assert_eq!(callbacks.vrf_ref_count(0x200), 0);
+        assert!(callbacks.remove_named_vrf("Vrf-shared"));
+
+        // A leaked route towards the deleted VRF is retried until it returns
+        let (key, fields) =
+            leaked_route_task("0x100:10.1.0.0/24", "172.16.0.1@Ethernet8", "Vrf-shared");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+        assert!(!orch.has_route(0x100, &prefix));
+        assert_eq!(orch.retry_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vrf_leak_add_leaked_route_missing_vrf() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_vrf(0x100);
+        callbacks.add_next_hop(make_nexthop("172.16.0.1", "Ethernet8"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let nhg_key = NextHopGroupKey::single(make_nexthop("172.16.0.1", "Ethernet8"));
+        let result = orch
+            .add_leaked_route(0x100, make_prefix("10.1.0.0", 24), nhg_key, Some(0x200))
+            .await;
+
+        assert!(matches!(result, Err(RouteError::VrfNotFound(0x200))));
+        assert_eq!(callbacks.vrf_ref_count(0x100), 0);
+    }
+
+    fn response_orch(callbacks: &Arc<MockCallbacks>) -> RouteOrch {
+        let config = RouteOrchConfig {
+            publish_programmed_routes: true,
+            sai_retry_backoff: Duration::ZERO,
+            ..Default::default()
+        };
+        let mut orch = RouteOrch::new(config);
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+        orch
+    }
+
+    #[tokio::test]
+    async fn test_route_responses_once_per_operation_across_retry() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = response_orch(&callbacks);
+        callbacks.fail_prefix("10.0.1.0/24");
+
+        for i in 0..3 {
+            let (key, fields) = route_task(&format!("10.0.{}.0/24", i), "192.168.1.1@Ethernet0");
+            orch.add_task(key, Operation::Set, fields);
+        }
+        orch.do_task().await;
+
+        // One batch per drain cycle; the failed entry waits in the retry cache
+        let batches = callbacks.take_response_batches();
+        assert_eq!(batches.len(), 1);
+        let keys: HashSet<_> = batches[0].iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, HashSet::from(["10.0.0.0/24", "10.0.2.0/24"]));
+        assert!(batches[0]
+            .iter()
+            .all(|r| r.status == RouteResponseStatus::Success && r.op == Operation::Set));
+        assert_eq!(orch.retry_count(), 1);
+
+        // Still failing: no response yet
+        orch.do_task().await;
+        assert!(callbacks.take_response_batches().is_empty());
+
+        callbacks.failing_prefixes.lock().unwrap().clear();
+        orch.do_task().await;
+        let batches = callbacks.take_response_batches();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(batches[0][0].key, "10.0.1.0/24");
+        assert_eq!(batches[0][0].status, RouteResponseStatus::Success);
+        assert_eq!(
+            batches[0][0].fvs,
+            vec![("nexthop".to_string(), "192.168.1.1@Ethernet0".to_string())]
+        );
+
+        // Nothing left to report
+        orch.do_task().await;
+        assert!(callbacks.take_response_batches().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_route_responses_cover_deletions_and_failures() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let mut orch = response_orch(&callbacks);
+
+        let (key, fields) = route_task("10.0.0.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+        callbacks.take_response_batches();
+
+        orch.add_task("10.0.0.0/24".to_string(), Operation::Del, HashMap::new());
+        orch.add_task("10.9.9.0/24".to_string(), Operation::Del, HashMap::new());
+        let (key, fields) = route_task("10.0.5.0/24", "192.168.9.9@Ethernet4");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        let batches = callbacks.take_response_batches();
+        assert_eq!(batches.len(), 1);
+        let by_key: HashMap<_, _> = batches[0].iter().map(|r| (r.key.as_str(), r)).collect();
+        assert_eq!(by_key.len(), 2);
+
+        let removed = by_key["10.0.0.0/24"];
+        assert_eq!(removed.op, Operation::Del);
+        assert_eq!(removed.status, RouteResponseStatus::Success);
+        assert!(removed.fvs.is_empty());
+
+        // Unknown route fails without a retry
+        assert!(matches!(
+            by_key["10.9.9.0/24"].status,
+            RouteResponseStatus::Failed(_)
+        ));
+
+        // Unresolved next hop is parked and answered once it resolves
+        assert_eq!(orch.retry_count(), 1);
+        callbacks.add_next_hop(make_nexthop("192.168.9.9", "Ethernet4"), 0x1001);
+        orch.do_task().await;
+        let batches = callbacks.take_response_batches();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(batches[0][0].key, "10.0.5.0/24");
+        assert_eq!(batches[0][0].status, RouteResponseStatus::Success);
+    }
+
+    #[tokio::test]
+    async fn test_route_responses_disabled_by_default() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        orch.set_callbacks(callbacks.clone());
+
+        let (key, fields) = route_task("10.0.0.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        assert!(orch.has_route(0, &make_prefix("10.0.0.0", 24)));
+        assert!(callbacks.take_response_batches().is_empty());
+    }
+
+    // ===== NHG exhaustion fallback tests =====
+
+    fn ecmp_callbacks() -> Arc<MockCallbacks> {
+        let callbacks = Arc::new(MockCallbacks::new());
+        callbacks.add_next_hop(make_nexthop("192.168.1.1", "Ethernet0"), 0x1000);
+        callbacks.add_next_hop(make_nexthop("192.168.1.2", "Ethernet4"), 0x1001);
+        callbacks.add_next_hop(make_nexthop("192.168.1.3", "Ethernet8"), 0x1002);
+        callbacks
+    }
+
+    #[tokio::test]
+    async fn test_nhg_exhaustion_falls_back_and_upgrades_on_removal() {
+        let config = RouteOrchConfig {
+            max_nhg_count: 1,
+            ..Default::default()
+        };
+        let mut orch = RouteOrch::new(config);
+        let callbacks = ecmp_callbacks();
+        orch.set_callbacks(callbacks.clone());
+
+        let first = make_prefix("10.0.1.0", 24);
+        let first_nhg = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.1.1", "Ethernet0"),
+            make_nexthop("192.168.1.2", "Ethernet4"),
+        ]);
+        orch.add_route(0, first.clone(), first_nhg.clone())
+            .await
+            .unwrap();
+
+        // No room for a second group: program the first resolved next hop
+        let second = make_prefix("10.0.2.0", 24);
+        let second_nhg = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.1.2", "Ethernet4"),
+            make_nexthop("192.168.1.3", "Ethernet8"),
+        ]);
+        orch.add_route(0, second.clone(), second_nhg.clone())
+            .await
+            .unwrap();
+
+        let fallback = make_nexthop("192.168.1.2", "Ethernet4");
+        assert_eq!(
+            orch.get_route(0, &second).unwrap().nhg.nhg_key,
+            NextHopGroupKey::single(fallback.clone())
+        );
+        assert!(orch.is_overflow_route(0, &second));
+        assert!(!orch.has_nhg(&second_nhg));
+        assert_eq!(orch.stats().nhg_overflows, 1);
+        assert_eq!(orch.stats().overflow_routes, 1);
+        assert_eq!(callbacks.next_hop_refs.lock().unwrap()[&fallback], 1);
+
+        // Freeing the first group lets the second route take its place
+        orch.remove_route(0, &first).await.unwrap();
+
+        assert!(!orch.has_nhg(&first_nhg));
+        assert!(!orch.is_overflow_route(0, &second));
+        assert_eq!(orch.get_route(0, &second).unwrap().nhg.nhg_key, second_nhg);
+        assert_eq!(orch.get_nhg(&second_nhg).unwrap().ref_count(), 1);
+        assert_eq!(orch.nhg_count(), 1);
+        assert_eq!(callbacks.next_hop_refs.lock().unwrap()[&fallback], 0);
+        assert_eq!(orch.stats().nhg_overflow_upgrades, 1);
+        assert_eq!(orch.stats().overflow_routes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_nhg_overflow_upgraded_on_drain_cycle() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = ecmp_callbacks();
+        orch.set_callbacks(callbacks.clone());
+        callbacks.set_nhg_table_full(true);
+
+        let (key, fields) =
+            route_task("10.0.0.0/24", "192.168.1.1@Ethernet0,192.168.1.2@Ethernet4");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        let prefix = make_prefix("10.0.0.0", 24);
+        assert!(orch.has_route(0, &prefix));
+        assert!(orch.is_overflow_route(0, &prefix));
+        assert_eq!(orch.retry_count(), 0);
+        assert_eq!(
+            orch.dump_pending_tasks(),
+            vec!["10.0.0.0/24:OVERFLOW:192.168.1.1@Ethernet0,192.168.1.2@Ethernet4".to_string()]
+        );
+
+        // Still exhausted: the route stays on its fallback next hop
+        orch.do_task().await;
+        assert!(orch.is_overflow_route(0, &prefix));
+
+        callbacks.set_nhg_table_full(false);
+        orch.do_task().await;
+
+        let nhg_key = orch.get_route(0, &prefix).unwrap().nhg.nhg_key.clone();
+        assert!(nhg_key.is_ecmp());
+        assert!(orch.has_nhg(&nhg_key));
+        assert!(!orch.is_overflow_route(0, &prefix));
+        assert!(orch.dump_pending_tasks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nhg_crm_exhaustion_skips_sai() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        let callbacks = ecmp_callbacks();
+        orch.set_callbacks(callbacks.clone());
+        callbacks.set_crm_nhg_available(Some(0));
+
+        // Nothing resolves: the exhaustion error is reported as-is
+        let unresolved = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.9.1", "Ethernet0"),
+            make_nexthop("192.168.9.2", "Ethernet4"),
+        ]);
+        let result = orch
+            .add_route(0, make_prefix("10.0.9.0", 24), unresolved)
+            .await;
+        assert!(matches!(result, Err(RouteError::NhgResourceExhausted(_))));
+
+        let prefix = make_prefix("10.0.0.0", 24);
+        let nhg_key = NextHopGroupKey::from_nexthops([
+            make_nexthop("192.168.1.1", "Ethernet0"),
+            make_nexthop("192.168.1.3", "Ethernet8"),
+        ]);
+        orch.add_route(0, prefix.clone(), nhg_key.clone())
+            .await
+            .unwrap();
+        assert!(orch.is_overflow_route(0, &prefix));
+        assert_eq!(callbacks.nhgs_created(), 0);
+        assert_eq!(orch.stats().crm_nhg_available, Some(0));
+
+        callbacks.set_crm_nhg_available(Some(16));
+        orch.do_task().await;
+
+        assert!(!orch.is_overflow_route(0, &prefix));
+        assert_eq!(orch.get_route(0, &prefix).unwrap().nhg.nhg_key, nhg_key);
+        assert_eq!(callbacks.nhgs_created(), 1);
+        assert_eq!(orch.stats().crm_nhg_available, Some(16));
+    }
+
+    fn subscribed_orch(callbacks: &Arc<MockCallbacks>) -> (RouteOrch, Publisher<NextHopUpdate>) {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+        let publisher = Publisher::new();
+        orch.subscribe_next_hop_updates(publisher.subscribe());
+        (orch, publisher)
+    }
+
+    fn neighbor_mac() -> sonic_types::MacAddress {
+        sonic_types::MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+    }
+
+    #[tokio::test]
+    async fn test_route_before_neighbor_dropped_until_resolved() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        let (mut orch, publisher) = subscribed_orch(&callbacks);
+        let prefix = make_prefix("10.1.0.0", 24);
+        let nh = make_nexthop("192.168.1.1", "Ethernet0");
+
+        let (key, fields) = route_task("10.1.0.0/24", "192.168.1.1@Ethernet0");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // Programmed as a blackhole rather than failing
+        assert!(orch.get_route(0, &prefix).unwrap().nhg.nhg_key.is_empty());
+        assert!(orch.is_unresolved_route(0, &prefix));
+        assert_eq!(callbacks.sai_route(&prefix), Some((None, true)));
+        assert_eq!(orch.stats().unresolved_routes, 1);
+        assert_eq!(
+            orch.dump_pending_tasks(),
+            vec!["10.1.0.0/24:UNRESOLVED:192.168.1.1@Ethernet0".to_string()]
+        );
+
+        // Another neighbor resolving leaves the route alone
+        callbacks.add_next_hop(make_nexthop("192.168.1.9", "Ethernet0"), 0x1009);
+        publisher.publish(NextHopUpdate::resolved(
+            "192.168.1.9",
+            "Ethernet0",
+            neighbor_mac(),
+        ));
+        orch.do_task().await;
+        assert!(orch.is_unresolved_route(0, &prefix));
+
+        callbacks.add_next_hop(nh.clone(), 0x1000);
+        publisher.publish(NextHopUpdate::resolved(
+            "192.168.1.1",
+            "Ethernet0",
+            neighbor_mac(),
+        ));
+        assert!(orch.has_pending_tasks());
+        orch.do_task().await;
+
+        assert_eq!(
+            orch.get_route(0, &prefix).unwrap().nhg.nhg_key,
+            NextHopGroupKey::single(nh.clone())
+        );
+        assert!(!orch.is_unresolved_route(0, &prefix));
+        assert_eq!(callbacks.sai_route(&prefix), Some((Some(0x1000), false)));
+        assert_eq!(callbacks.next_hop_refs.lock().unwrap()[&nh], 1);
+        assert_eq!(orch.stats().unresolved_routes, 0);
+        assert_eq!(orch.stats().unresolved_upgrades, 1);
+        assert!(!orch.has_pending_tasks());
+    }
+
+    #[tokio::test]
+    async fn test_route_parked_until_neighbor_resolves() {
+        let callbacks = Arc::new(MockCallbacks::new());
+        *callbacks.reject_unresolved_members.lock().unwrap() = true;
+        let nh1 = make_nexthop("192.168.1.1", "Ethernet0");
+        let nh2 = make_nexthop("192.168.1.2", "Ethernet4");
+        callbacks.add_next_hop(nh1.clone(), 0x1000);
+        let (mut orch, publisher) = subscribed_orch(&callbacks);
+
+        let (key, fields) =
+            route_task("10.1.0.0/24", "192.168.1.1@Ethernet0,192.168.1.2@Ethernet4");
+        orch.add_task(key, Operation::Set, fields);
+        orch.do_task().await;
+
+        // The group needs 192.168.1.2: parked on that neighbor alone
+        let prefix = make_prefix("10.1.0.0", 24);
+        assert!(!orch.has_route(0, &prefix));
+        assert_eq!(orch.retry_count(), 1);
+        assert_eq!(
+            orch.retry_cache.constraints(&"10.1.0.0/24".to_string()),
+            Some(&HashSet::from([Constraint::neighbor(
+                "192.168.1.2@Ethernet4"
+            )]))
+        );
+        assert!(!orch.has_pending_tasks());
+
+        callbacks.add_next_hop(make_nexthop("192.168.1.9", "Ethernet0"), 0x1009);
+        publisher.publish(NextHopUpdate::resolved(
+            "192.168.1.9",
+            "Ethernet0",
+            neighbor_mac(),
+        ));
+        orch.do_task().await;
+        assert_eq!(orch.retry_count(), 1);
+
+        callbacks.add_next_hop(nh2.clone(), 0x1001);
+        publisher.publish(NextHopUpdate::resolved(
+            "192.168.1.2",
+            "Ethernet4",
+            neighbor_mac(),
+        ));
+        assert!(orch.has_pending_tasks());
+        orch.do_task().await;
+
+        assert_eq!(
+            orch.get_route(0, &prefix).unwrap().nhg.nhg_key,
+            NextHopGroupKey::from_nexthops([nh1, nh2])
+        );
+        assert_eq!(orch.retry_count(), 0);
+        assert!(!orch.has_pending_tasks());
+    }
+
+    #[tokio::test]
+    async fn test_neighbor_flap_after_route_programmed() {
+        let callbacks = ecmp_callbacks();
+        let (mut orch, publisher) = subscribed_orch(&callbacks);
+        let nh1 = make_nexthop("192.168.1.1", "Ethernet0");
+        let nh2 = make_nexthop("192.168.1.2", "Ethernet4");
+
+        let single = make_prefix("10.1.0.0", 24);
+        let ecmp = make_prefix("10.2.0.0", 24);
+        let ecmp_nhg = NextHopGroupKey::from_nexthops([nh1.clone(), nh2.clone()]);
+        orch.add_route(0, single.clone(), NextHopGroupKey::single(nh1.clone()))
+            .await
+            .unwrap();
+        orch.add_route(0, ecmp.clone(), ecmp_nhg.clone())
+            .await
+            .unwrap();
+
+        // nh1 goes away: the ECMP route still has nh2
+        callbacks.remove_next_hop(&nh1);
+        publisher.publish(NextHopUpdate::unresolved("192.168.1.1", "Ethernet0"));
+        orch.do_task().await;
+
+        assert!(orch.is_unresolved_route(0, &single));
+        assert_eq!(callbacks.sai_route(&single), Some((None, true)));
+        assert_eq!(callbacks.next_hop_refs.lock().unwrap()[&nh1], 0);
+        assert!(!orch.is_unresolved_route(0, &ecmp));
+        assert_eq!(orch.get_route(0, &ecmp).unwrap().nhg.nhg_key, ecmp_nhg);
+
+        // And nh2 too: nothing left to forward to
+        callbacks.remove_next_hop(&nh2);
+        publisher.publish(NextHopUpdate::unresolved("192.168.1.2", "Ethernet4"));
+        orch.do_task().await;
+
+        assert!(orch.is_unresolved_route(0, &ecmp));
+        assert_eq!(callbacks.sai_route(&ecmp), Some((None, true)));
+        assert!(!orch.has_nhg(&ecmp_nhg));
+        assert_eq!(orch.stats().unresolved_routes, 2);
+
+        // nh1 comes back: both routes are programmed again
+        callbacks.add_next_hop(nh1.clone(), 0x1000);
+        publisher.publish(NextHopUpdate::resolved(
+            "192.168.1.1",
+            "Ethernet0",
+            neighbor_mac(),
+        ));
+        orch.do_task().await;
+
+        assert_eq!(callbacks.sai_route(&single), Some((Some(0x1000), false)));
+        assert_eq!(orch.get_route(0, &ecmp).unwrap().nhg.nhg_key, ecmp_nhg);
+        assert_eq!(orch.get_nhg(&ecmp_nhg).unwrap().ref_count(), 1);
+        assert_eq!(callbacks.next_hop_refs.lock().unwrap()[&nh1], 1);
+        assert_eq!(orch.stats().unresolved_routes, 0);
+        assert_eq!(orch.stats().unresolved_upgrades, 2);
+
+        // Removing a dropped route clears its unresolved state
+        callbacks.remove_next_hop(&nh1);
+        publisher.publish(NextHopUpdate::unresolved("192.168.1.1", "Ethernet0"));
+        orch.do_task().await;
+        orch.remove_route(0, &single).await.unwrap();
+        assert!(!orch.is_unresolved_route(0, &single));
+        assert_eq!(orch.stats().unresolved_routes, 1);
+
+        // The next hop index follows the remaining route
+        let ecmp_key = RouteKey::new(0, ecmp.clone());
+        for nh in ["192.168.1.1@Ethernet0", "192.168.1.2@Ethernet4"] {
+            assert_eq!(
+                orch.next_hop_routes.get(nh),
+                Some(&HashSet::from([ecmp_key.clone()]))
+            );
+        }
+        orch.remove_route(0, &ecmp).await.unwrap();
+        assert!(orch.next_hop_routes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unresolved_next_hop_fails_without_subscription() {
+        let mut orch = RouteOrch::new(RouteOrchConfig::default());
+        orch.set_callbacks(Arc::new(MockCallbacks::new()));
+
+        let result = orch
+            .add_route(
+                0,
+                make_prefix("10.1.0.0", 24),
+                NextHopGroupKey::single(make_nexthop("192.168.1.1", "Ethernet0")),
+            )
+            .await;
+        assert!(matches!(result, Err(RouteError::NextHopNotResolved(_))));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module defines the route entry types and storage structures.
 
+use sonic_orch_common::Operation;
 use sonic_sai::types::RawSaiObjectId;
 use sonic_types::IpPrefix;
 use std::collections::HashMap;
@@ -62,6 +63,9 @@ pub struct RouteNhg {
     pub nhg_index: Option<String>,
     /// SRv6 context index (if applicable).
     pub context_index: Option<String>,
+    /// VRF the next-hops are resolved in, when it differs from the route's
+    /// own VRF (route leaking).
+    pub nexthop_vrf_id: Option<RawSaiObjectId>,
 }
 
 impl RouteNhg {
@@ -71,6 +75,7 @@ impl RouteNhg {
             nhg_key,
             nhg_index: None,
             context_index: None,
+            nexthop_vrf_id: None,
         }
     }
 
@@ -86,6 +91,12 @@ impl RouteNhg {
         self
     }
 
+    /// Creates a RouteNhg whose next-hops live in another VRF.
+    pub fn with_nexthop_vrf(mut self, vrf_id: RawSaiObjectId) -> Self {
+        self.nexthop_vrf_id = Some(vrf_id);
+        self
+    }
+
     /// Returns true if this NHG is owned by NhgOrch.
     pub fn is_nhg_orch_owned(&self) -> bool {
         self.nhg_index.is_some()
@@ -96,6 +107,11 @@ impl RouteNhg {
         self.context_index.is_some()
     }
 
+    /// Returns true if the next-hops are leaked from another VRF.
+    pub fn is_vrf_leak(&self) -> bool {
+        self.nexthop_vrf_id.is_some()
+    }
+
     /// Returns true if this is a blackhole/dropped route.
     pub fn is_blackhole(&self) -> bool {
         self.nhg_key.is_empty() && self.nhg_index.is_none()
@@ -195,6 +211,52 @@ pub type LabelRouteTable = HashMap<Label, LabelRouteEntry>;
 /// Table of label routes indexed by VRF ID.
 pub type LabelRouteTables = HashMap<RawSaiObjectId, LabelRouteTable>;
 
+/// Final programming status of a ROUTE_TABLE operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteResponseStatus {
+    /// The route was programmed (or removed) in SAI.
+    Success,
+    /// The operation was rejected; carries the error message.
+    Failed(String),
+}
+
+impl RouteResponseStatus {
+    /// Returns the SWSS return code string published with the response.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "SWSS_RC_SUCCESS",
+            Self::Failed(_) => "SWSS_RC_FAILED",
+        }
+    }
+}
+
+/// Response written to the APPL_STATE_DB ROUTE_TABLE once a route operation
+/// has reached hardware (or failed for good), so that BGP can hold back
+/// advertisement of routes that are not yet programmed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteResponse {
+    /// ROUTE_TABLE key as received from APPL_DB.
+    pub key: String,
+    /// Operation the response is for.
+    pub op: Operation,
+    /// Fields of the original request (empty for deletions).
+    pub fvs: Vec<(String, String)>,
+    /// Outcome.
+    pub status: RouteResponseStatus,
+}
+
+impl RouteResponse {
+    /// Returns the field/value pairs to publish for this response.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = self.fvs.clone();
+        fields.push(("err_str".to_string(), self.status.as_str().to_string()));
+        if let RouteResponseStatus::Failed(ref msg) = self.status {
+            fields.push(("err_msg".to_string(), msg.clone()));
+        }
+        fields
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,6 +317,14 @@ mod tests {
         assert!(!nhg.is_blackhole()); // Has nhg_index, so not blackhole
     }
 
+    #[test]
+    fn test_route_nhg_with_nexthop_vrf() {
+        let nhg = RouteNhg::new(NextHopGroupKey::new()).with_nexthop_vrf(0x3000);
+        assert!(nhg.is_vrf_leak());
+        assert_eq!(nhg.nexthop_vrf_id, Some(0x3000));
+        assert!(!RouteNhg::default().is_vrf_leak());
+    }
+
     #[test]
     fn test_route_entry() {
         let nhg = RouteNhg::default();
@@ -289,4 +359,30 @@ mod tests {
         assert!(tables.contains_key(&0));
         assert!(tables.get(&0).unwrap().contains_key(&prefix));
     }
+
+    #[test]
+    fn test_route_response_fields() {
+        let ok = RouteResponse {
+            key: "10.0.0.0/24".to_string(),
+            op: Operation::Set,
+            fvs: vec![("nexthop".to_string(), "192.168.1.1".to_string())],
+            status: RouteResponseStatus::Success,
+        };
+        assert_eq!(
+            ok.fields(),
+            vec![
+                ("nexthop".to_string(), "192.168.1.1".to_string()),
+                ("err_str".to_string(), "SWSS_RC_SUCCESS".to_string()),
+            ]
+        );
+
+        let failed = RouteResponse {
+            key: "10.0.0.0/24".to_string(),
+            op: Operation::Del,
+            fvs: Vec::new(),
+            status: RouteResponseStatus::Failed("Route not found".to_string()),
+        };
+        assert_eq!(failed.fields()[0].1, "SWSS_RC_FAILED");
+        assert_eq!(failed.fields()[1].1, "Route not found");
+    }
 }
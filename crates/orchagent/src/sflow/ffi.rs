@@ -5,6 +5,7 @@ use std::ffi::{c_char, CStr};
 
 use super::orch::{SflowOrch, SflowOrchConfig};
 use super::types::SflowConfig;
+use sonic_orch_common::FromFieldValues;
 
 thread_local! {
     static SFLOW_ORCH: RefCell<Option<Box<SflowOrch>>> = const { RefCell::new(None) };
@@ -82,19 +83,19 @@ pub extern "C" fn sflow_orch_configure_port(
 
     SFLOW_ORCH.with(|orch| {
         if let Some(ref mut o) = *orch.borrow_mut() {
-            let mut config = SflowConfig::new();
+            let fvs = [
+                ("sample_rate".to_string(), rate.to_string()),
+                ("sample_direction".to_string(), direction_str.to_string()),
+            ];
+            let mut config = match SflowConfig::from_field_values(&fvs) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to parse sflow config: {}", e);
+                    return false;
+                }
+            };
             config.admin_state = admin_state;
 
-            if let Err(e) = config.parse_field("sample_rate", &rate.to_string()) {
-                eprintln!("Failed to parse sample_rate: {}", e);
-                return false;
-            }
-
-            if let Err(e) = config.parse_field("sample_direction", direction_str) {
-                eprintln!("Failed to parse sample_direction: {}", e);
-                return false;
-            }
-
             match o.configure_port(alias_str, config) {
                 Ok(()) => true,
                 Err(e) => {
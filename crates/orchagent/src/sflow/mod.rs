@@ -27,6 +27,15 @@
 //! - When the first port at rate R is configured, a session is created
 //! - Subsequent ports at rate R reuse the existing session
 //! - When the last port using rate R is removed, the session is destroyed
+//! - Changing a port's sample direction only updates its ingress/egress
+//!   samplepacket attributes; the shared session is left untouched
+//!
+//! # Egress Capability
+//!
+//! Some ASICs cannot sample transmitted packets. The egress capability is
+//! queried at init and again after warm restart; on platforms without it,
+//! tx and both are programmed as rx only and the degraded direction is
+//! published to STATE_DB.
 //!
 //! # Safety Improvements over C++
 //!
@@ -53,4 +62,4 @@ mod types;
 
 pub use ffi::{register_sflow_orch, unregister_sflow_orch};
 pub use orch::{SflowOrch, SflowOrchCallbacks, SflowOrchConfig, SflowOrchError, SflowOrchStats};
-pub use types::{PortSflowInfo, SampleDirection, SflowConfig, SflowSession};
+pub use types::{PortSflowInfo, SampleDirection, SflowCapability, SflowConfig, SflowSession};
@@ -6,9 +6,9 @@ use std::sync::Arc;
 
 use sonic_sai::types::RawSaiObjectId;
 
-use super::types::{PortSflowInfo, SampleDirection, SflowConfig, SflowSession};
+use super::types::{PortSflowInfo, SampleDirection, SflowCapability, SflowConfig, SflowSession};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
-use crate::audit_log;
+use crate::{audit_log, warn_log};
 
 /// Sflow orchestrator error type.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -72,6 +72,15 @@ pub trait SflowOrchCallbacks: Send + Sync {
 
     /// Returns true if all ports are ready.
     fn all_ports_ready(&self) -> bool;
+
+    /// Queries the ASIC's samplepacket capabilities.
+    fn query_capability(&self) -> Result<SflowCapability, String>;
+
+    /// Writes a port's configured and programmed direction to STATE_DB.
+    fn write_state_db(&self, alias: &str, configured: SampleDirection, programmed: SampleDirection);
+
+    /// Removes a port's entry from STATE_DB.
+    fn remove_state_db(&self, alias: &str);
 }
 
 /// Sflow orchestrator configuration.
@@ -95,6 +104,10 @@ pub struct SflowOrchStats {
     pub rate_updates: u64,
     /// Number of direction updates.
     pub direction_updates: u64,
+    /// Number of port configurations programmed rx-only for lack of egress support.
+    pub degraded_directions: u64,
+    /// Number of failed capability queries.
+    pub capability_query_failures: u64,
 }
 
 /// Sflow orchestrator for packet sampling.
@@ -109,6 +122,8 @@ pub struct SflowOrch {
     sessions: HashMap<NonZeroU32, SflowSession>,
     /// Reverse index: session ID -> rate (for O(1) lookups).
     session_to_rate: HashMap<RawSaiObjectId, NonZeroU32>,
+    /// ASIC samplepacket capabilities.
+    capability: SflowCapability,
     /// Callbacks for SAI and port queries.
     callbacks: Option<Arc<dyn SflowOrchCallbacks>>,
     /// Whether the orch is initialized.
@@ -124,6 +139,7 @@ impl std::fmt::Debug for SflowOrch {
             .field("enabled", &self.enabled)
             .field("port_count", &self.port_info.len())
             .field("session_count", &self.sessions.len())
+            .field("capability", &self.capability)
             .field("initialized", &self.initialized)
             .field("stats", &self.stats)
             .finish()
@@ -139,6 +155,7 @@ impl SflowOrch {
             port_info: HashMap::new(),
             sessions: HashMap::new(),
             session_to_rate: HashMap::new(),
+            capability: SflowCapability::default(),
             callbacks: None,
             initialized: false,
             stats: SflowOrchStats::default(),
@@ -170,6 +187,76 @@ impl SflowOrch {
         self.initialized = true;
     }
 
+    /// Queries ASIC capabilities and marks the orch as initialized.
+    pub fn init(&mut self) {
+        self.refresh_capability();
+        self.initialized = true;
+    }
+
+    /// Re-queries ASIC capabilities once warm restart has completed.
+    pub fn on_warm_boot_end(&mut self) {
+        self.refresh_capability();
+    }
+
+    /// Returns the ASIC samplepacket capabilities.
+    pub fn capability(&self) -> SflowCapability {
+        self.capability
+    }
+
+    /// Queries the ASIC capabilities; a failed query disables egress sampling.
+    fn refresh_capability(&mut self) {
+        let Some(callbacks) = self.callbacks.as_ref() else {
+            return;
+        };
+
+        match callbacks.query_capability() {
+            Ok(capability) => {
+                self.capability = capability;
+                audit_log!(AuditRecord::new(
+                    AuditCategory::SaiOperation,
+                    "SflowOrch",
+                    "query_capability"
+                )
+                .with_outcome(AuditOutcome::Success)
+                .with_object_type("samplepacket")
+                .with_details(serde_json::json!({
+                    "egress_supported": capability.egress_supported,
+                })));
+            }
+            Err(e) => {
+                self.capability = SflowCapability {
+                    egress_supported: false,
+                };
+                self.stats.capability_query_failures += 1;
+                audit_log!(AuditRecord::new(
+                    AuditCategory::SaiOperation,
+                    "SflowOrch",
+                    "query_capability"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_type("samplepacket")
+                .with_error(&e));
+            }
+        }
+    }
+
+    /// Returns the direction to program for `direction`, degrading to rx
+    /// when the ASIC lacks egress sampling.
+    fn effective_direction(&mut self, alias: &str, direction: SampleDirection) -> SampleDirection {
+        let effective = self.capability.effective_direction(direction);
+        if effective != direction {
+            self.stats.degraded_directions += 1;
+            warn_log!(
+                "SflowOrch",
+                port = %alias,
+                requested = direction.as_str(),
+                programmed = effective.as_str(),
+                "Egress sampling not supported, programming rx only"
+            );
+        }
+        effective
+    }
+
     /// Returns true if sflow is globally enabled.
     pub fn is_enabled(&self) -> bool {
         self.enabled
@@ -295,55 +382,55 @@ impl SflowOrch {
         Ok(())
     }
 
-    /// Applies sflow sampling to a port.
-    fn apply_port_sampling(
-        &self,
+    /// Programs a port's ingress/egress samplepacket attributes.
+    ///
+    /// Only attributes that differ from the port's `programmed_direction` are
+    /// touched, so a direction change leaves the shared session alone. With
+    /// `rebind`, directions that stay enabled are re-pointed at `session_id`.
+    /// `programmed_direction` is updated after each step so a failed update
+    /// can be retried.
+    fn program_port_sampling(
+        &mut self,
         port_id: RawSaiObjectId,
         session_id: RawSaiObjectId,
-        direction: SampleDirection,
+        target: Option<SampleDirection>,
+        rebind: bool,
     ) -> Result<(), SflowOrchError> {
         let callbacks = self
             .callbacks
-            .as_ref()
+            .clone()
             .ok_or_else(|| SflowOrchError::InvalidConfig("No callbacks set".to_string()))?;
+        let info = self
+            .port_info
+            .get_mut(&port_id)
+            .ok_or_else(|| SflowOrchError::PortNotFound(format!("0x{:x}", port_id)))?;
+
+        let want_ingress = target.is_some_and(|d| d.has_ingress());
+        let want_egress = target.is_some_and(|d| d.has_egress());
+        let has_ingress = info.programmed_direction.is_some_and(|d| d.has_ingress());
+        let has_egress = info.programmed_direction.is_some_and(|d| d.has_egress());
 
-        if direction.has_ingress() {
+        if want_ingress && (!has_ingress || rebind) {
             callbacks
                 .enable_port_ingress_sample(port_id, session_id)
                 .map_err(SflowOrchError::SaiError)?;
-        }
-
-        if direction.has_egress() {
+        } else if !want_ingress && has_ingress {
             callbacks
-                .enable_port_egress_sample(port_id, session_id)
+                .disable_port_ingress_sample(port_id)
                 .map_err(SflowOrchError::SaiError)?;
         }
+        info.programmed_direction = SampleDirection::from_flags(want_ingress, has_egress);
 
-        Ok(())
-    }
-
-    /// Removes sflow sampling from a port.
-    fn remove_port_sampling(
-        &self,
-        port_id: RawSaiObjectId,
-        direction: SampleDirection,
-    ) -> Result<(), SflowOrchError> {
-        let callbacks = self
-            .callbacks
-            .as_ref()
-            .ok_or_else(|| SflowOrchError::InvalidConfig("No callbacks set".to_string()))?;
-
-        if direction.has_ingress() {
+        if want_egress && (!has_egress || rebind) {
             callbacks
-                .disable_port_ingress_sample(port_id)
+                .enable_port_egress_sample(port_id, session_id)
                 .map_err(SflowOrchError::SaiError)?;
-        }
-
-        if direction.has_egress() {
+        } else if !want_egress && has_egress {
             callbacks
                 .disable_port_egress_sample(port_id)
                 .map_err(SflowOrchError::SaiError)?;
         }
+        info.programmed_direction = target;
 
         Ok(())
     }
@@ -416,70 +503,43 @@ impl SflowOrch {
             .ok_or_else(|| SflowOrchError::InvalidConfig("Session should exist".to_string()))?
             .session_id;
 
-        // Check if port already configured
-        let is_existing = self.port_info.contains_key(&port_id);
+        let programmed = self.effective_direction(alias, config.direction);
 
-        if is_existing {
+        if let Some(info) = self.port_info.get(&port_id) {
             // Update existing configuration
-            let old_session_id = self
-                .port_info
-                .get(&port_id)
-                .ok_or_else(|| SflowOrchError::PortNotFound(alias.to_string()))?
-                .session_id;
-
-            let old_direction = self
-                .port_info
-                .get(&port_id)
-                .ok_or_else(|| SflowOrchError::PortNotFound(alias.to_string()))?
-                .direction;
-
+            let old_session_id = info.session_id;
+            let old_direction = info.direction;
             let old_rate = self
                 .get_session_rate(old_session_id)
-                .ok_or_else(|| SflowOrchError::SessionNotFound(old_session_id))?;
+                .ok_or(SflowOrchError::SessionNotFound(old_session_id))?;
+            let rate_changed = old_rate != rate;
 
-            // Handle rate change
-            if old_rate != rate {
-                // Remove from old session
+            // Direction-only changes just flip the port attributes; the shared
+            // session and its ref count are left untouched.
+            self.program_port_sampling(port_id, session_id, Some(programmed), rate_changed)?;
+
+            if let Some(info) = self.port_info.get_mut(&port_id) {
+                info.session_id = session_id;
+                info.direction = config.direction;
+                info.admin_state = config.admin_state;
+            }
+
+            // Handle rate change once the port points at the new session
+            if rate_changed {
+                if let Some(new_session) = self.sessions.get_mut(&rate) {
+                    new_session.add_ref();
+                }
                 if let Some(old_session) = self.sessions.get_mut(&old_rate) {
-                    let new_ref_count = old_session.remove_ref();
-                    if new_ref_count == 0 {
+                    if old_session.remove_ref() == 0 {
                         // Destroy unused session
                         self.destroy_session(old_rate)?;
                     }
                 }
-
-                // Add to new session
-                if let Some(new_session) = self.sessions.get_mut(&rate) {
-                    new_session.add_ref();
-                }
-
-                // Reapply sampling with new session
-                self.apply_port_sampling(port_id, session_id, config.direction)?;
                 self.stats.rate_updates += 1;
-
-                // Update session_id in port_info
-                if let Some(info) = self.port_info.get_mut(&port_id) {
-                    info.session_id = session_id;
-                }
             }
 
-            // Handle direction change
             if old_direction != config.direction {
-                // Remove old direction
-                self.remove_port_sampling(port_id, old_direction)?;
-                // Apply new direction
-                self.apply_port_sampling(port_id, session_id, config.direction)?;
                 self.stats.direction_updates += 1;
-
-                // Update direction in port_info
-                if let Some(info) = self.port_info.get_mut(&port_id) {
-                    info.direction = config.direction;
-                }
-            }
-
-            // Update admin state
-            if let Some(info) = self.port_info.get_mut(&port_id) {
-                info.admin_state = config.admin_state;
             }
 
             audit_log!(AuditRecord::new(
@@ -493,15 +553,20 @@ impl SflowOrch {
             .with_details(serde_json::json!({
                 "operation": "update",
                 "rate": rate.get(),
-                "direction": format!("{:?}", config.direction)
+                "direction": format!("{:?}", config.direction),
+                "programmed_direction": format!("{:?}", programmed)
             })));
         } else {
             // New port configuration
-            self.apply_port_sampling(port_id, session_id, config.direction)?;
-
             let info = PortSflowInfo::new(config.admin_state, config.direction, session_id);
             self.port_info.insert(port_id, info);
 
+            if let Err(e) = self.program_port_sampling(port_id, session_id, Some(programmed), false)
+            {
+                self.port_info.remove(&port_id);
+                return Err(e);
+            }
+
             // Increment ref count
             if let Some(session) = self.sessions.get_mut(&rate) {
                 session.add_ref();
@@ -520,10 +585,15 @@ impl SflowOrch {
                 "operation": "create",
                 "rate": rate.get(),
                 "direction": format!("{:?}", config.direction),
+                "programmed_direction": format!("{:?}", programmed),
                 "session_id": format!("0x{:x}", session_id)
             })));
         }
 
+        if let Some(callbacks) = self.callbacks.as_ref() {
+            callbacks.write_state_db(alias, config.direction, programmed);
+        }
+
         Ok(())
     }
 
@@ -547,19 +617,29 @@ impl SflowOrch {
         })?;
 
         // Get existing info
-        let info = self.port_info.remove(&port_id).ok_or_else(|| {
-            audit_log!(
-                AuditRecord::new(AuditCategory::ResourceDelete, "SflowOrch", "remove_port")
-                    .with_outcome(AuditOutcome::Failure)
-                    .with_object_id(alias)
-                    .with_object_type("port")
-                    .with_error(&format!("Port not configured for sampling: {}", alias))
-            );
-            SflowOrchError::PortNotFound(alias.to_string())
-        })?;
+        let session_id = self
+            .port_info
+            .get(&port_id)
+            .map(|info| info.session_id)
+            .ok_or_else(|| {
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceDelete,
+                    "SflowOrch",
+                    "remove_port"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(alias)
+                .with_object_type("port")
+                .with_error(&format!("Port not configured for sampling: {}", alias)));
+                SflowOrchError::PortNotFound(alias.to_string())
+            })?;
 
         // Remove sampling from port
-        self.remove_port_sampling(port_id, info.direction)?;
+        self.program_port_sampling(port_id, session_id, None, false)?;
+        let info = self
+            .port_info
+            .remove(&port_id)
+            .ok_or_else(|| SflowOrchError::PortNotFound(alias.to_string()))?;
 
         // Decrement session ref count
         let rate = self
@@ -575,6 +655,9 @@ impl SflowOrch {
         }
 
         self.stats.ports_unconfigured += 1;
+        if let Some(callbacks) = self.callbacks.as_ref() {
+            callbacks.remove_state_db(alias);
+        }
 
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceDelete, "SflowOrch", "remove_port")
@@ -602,6 +685,9 @@ mod tests {
         port_ops: Mutex<Vec<String>>,
         next_session_id: Mutex<RawSaiObjectId>,
         ports_ready: bool,
+        capability: Mutex<Result<SflowCapability, String>>,
+        capability_queries: Mutex<u32>,
+        state: Mutex<HashMap<String, (SampleDirection, SampleDirection)>>,
     }
 
     impl TestCallbacks {
@@ -612,6 +698,16 @@ mod tests {
                 port_ops: Mutex::new(Vec::new()),
                 next_session_id: Mutex::new(0x1000),
                 ports_ready: true,
+                capability: Mutex::new(Ok(SflowCapability::default())),
+                capability_queries: Mutex::new(0),
+                state: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn with_capability(capability: Result<SflowCapability, String>) -> Self {
+            Self {
+                capability: Mutex::new(capability),
+                ..Self::new()
             }
         }
 
@@ -692,6 +788,27 @@ mod tests {
         fn all_ports_ready(&self) -> bool {
             self.ports_ready
         }
+
+        fn query_capability(&self) -> Result<SflowCapability, String> {
+            *self.capability_queries.lock().unwrap() += 1;
+            self.capability.lock().unwrap().clone()
+        }
+
+        fn write_state_db(
+            &self,
+            alias: &str,
+            configured: SampleDirection,
+            programmed: SampleDirection,
+        ) {
+            self.state
+                .lock()
+                .unwrap()
+                .insert(alias.to_string(), (configured, programmed));
+        }
+
+        fn remove_state_db(&self, alias: &str) {
+            self.state.lock().unwrap().remove(alias);
+        }
     }
 
     #[test]
@@ -868,15 +985,15 @@ mod tests {
 
         assert_eq!(orch.stats().direction_updates, 1);
 
-        // Check port ops: disable rx, then enable both rx+tx
+        // Check port ops: ingress stays programmed, only egress is added
         let ops = callbacks.port_ops.lock().unwrap();
-        assert!(ops.iter().any(|s| s.starts_with("disable_ingress:")));
-        assert!(
+        assert!(!ops.iter().any(|s| s.starts_with("disable_ingress:")));
+        assert_eq!(
             ops.iter()
                 .filter(|s| s.starts_with("enable_ingress:"))
-                .count()
-                == 2
-        ); // Initial + update
+                .count(),
+            1
+        ); // Initial only
         assert!(ops.iter().any(|s| s.starts_with("enable_egress:")));
     }
 
@@ -1504,7 +1621,7 @@ mod tests {
         orch.configure_port("Ethernet0", config).unwrap();
 
         let ops = callbacks.port_ops.lock().unwrap();
-        assert!(ops.iter().any(|s| s.starts_with("disable_ingress:")));
+        assert!(!ops.iter().any(|s| s.starts_with("disable_ingress:")));
         assert!(ops.iter().any(|s| s.starts_with("disable_egress:")));
     }
 
@@ -1609,4 +1726,237 @@ mod tests {
         let rate = orch.get_session_rate(0x9999);
         assert!(rate.is_none());
     }
+
+    fn port_config(rate: u32, direction: SampleDirection) -> SflowConfig {
+        SflowConfig {
+            admin_state: true,
+            rate: NonZeroU32::new(rate),
+            direction,
+        }
+    }
+
+    #[test]
+    fn test_direction_change_keeps_shared_session() {
+        let mut orch = SflowOrch::new(SflowOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        orch.set_enabled(true);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Rx))
+            .unwrap();
+        orch.configure_port("Ethernet4", port_config(4000, SampleDirection::Rx))
+            .unwrap();
+        let session_id = orch.get_port_info(0x100).unwrap().session_id;
+        callbacks.created_sessions.lock().unwrap().clear();
+        callbacks.port_ops.lock().unwrap().clear();
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Both))
+            .unwrap();
+
+        assert!(callbacks.created_sessions.lock().unwrap().is_empty());
+        assert!(callbacks.removed_sessions.lock().unwrap().is_empty());
+        assert_eq!(
+            *callbacks.port_ops.lock().unwrap(),
+            vec![format!("enable_egress:{}:{}", 0x100, session_id)]
+        );
+
+        let session = &orch.sessions[&NonZeroU32::new(4000).unwrap()];
+        assert_eq!(session.session_id, session_id);
+        assert_eq!(session.ref_count, 2);
+
+        let info = orch.get_port_info(0x100).unwrap();
+        assert_eq!(info.direction, SampleDirection::Both);
+        assert_eq!(info.programmed_direction, Some(SampleDirection::Both));
+        assert_eq!(
+            orch.get_port_info(0x104).unwrap().programmed_direction,
+            Some(SampleDirection::Rx)
+        );
+        assert_eq!(orch.stats().direction_updates, 1);
+    }
+
+    #[test]
+    fn test_reapplying_same_config_is_idempotent() {
+        let mut orch = SflowOrch::new(SflowOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        orch.set_enabled(true);
+
+        let config = port_config(4000, SampleDirection::Both);
+        orch.configure_port("Ethernet0", config.clone()).unwrap();
+        callbacks.port_ops.lock().unwrap().clear();
+
+        orch.configure_port("Ethernet0", config).unwrap();
+
+        assert!(callbacks.port_ops.lock().unwrap().is_empty());
+        assert_eq!(orch.stats().direction_updates, 0);
+        assert_eq!(orch.stats().rate_updates, 0);
+    }
+
+    #[test]
+    fn test_rate_and_direction_change_rebinds_port() {
+        let mut orch = SflowOrch::new(SflowOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        orch.set_enabled(true);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Both))
+            .unwrap();
+        let old_session_id = orch.get_port_info(0x100).unwrap().session_id;
+        callbacks.port_ops.lock().unwrap().clear();
+
+        orch.configure_port("Ethernet0", port_config(8000, SampleDirection::Tx))
+            .unwrap();
+        let new_session_id = orch.get_port_info(0x100).unwrap().session_id;
+
+        assert_ne!(old_session_id, new_session_id);
+        assert_eq!(
+            *callbacks.port_ops.lock().unwrap(),
+            vec![
+                format!("disable_ingress:{}", 0x100),
+                format!("enable_egress:{}:{}", 0x100, new_session_id),
+            ]
+        );
+        assert_eq!(
+            *callbacks.removed_sessions.lock().unwrap(),
+            vec![old_session_id]
+        );
+        assert_eq!(orch.session_count(), 1);
+    }
+
+    #[test]
+    fn test_remove_port_disables_programmed_direction() {
+        let mut orch = SflowOrch::new(SflowOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        orch.set_enabled(true);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Tx))
+            .unwrap();
+        callbacks.port_ops.lock().unwrap().clear();
+
+        orch.remove_port("Ethernet0").unwrap();
+
+        assert_eq!(
+            *callbacks.port_ops.lock().unwrap(),
+            vec![format!("disable_egress:{}", 0x100)]
+        );
+        assert_eq!(orch.port_count(), 0);
+    }
+
+    const NO_EGRESS: SflowCapability = SflowCapability {
+        egress_supported: false,
+    };
+
+    fn init_orch(callbacks: &Arc<TestCallbacks>) -> SflowOrch {
+        let mut orch = SflowOrch::new(SflowOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+        orch.set_enabled(true);
+        orch.init();
+        orch
+    }
+
+    #[test]
+    fn test_egress_supported_programs_requested_direction() {
+        let callbacks = Arc::new(TestCallbacks::new());
+        let mut orch = init_orch(&callbacks);
+        assert!(orch.is_initialized());
+        assert!(orch.capability().egress_supported);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Both))
+            .unwrap();
+
+        let ops = callbacks.port_ops.lock().unwrap();
+        assert!(ops.iter().any(|s| s.starts_with("enable_ingress:")));
+        assert!(ops.iter().any(|s| s.starts_with("enable_egress:")));
+        assert_eq!(
+            callbacks.state.lock().unwrap()["Ethernet0"],
+            (SampleDirection::Both, SampleDirection::Both)
+        );
+        assert_eq!(orch.stats().degraded_directions, 0);
+    }
+
+    #[test]
+    fn test_egress_unsupported_degrades_to_rx() {
+        let callbacks = Arc::new(TestCallbacks::with_capability(Ok(NO_EGRESS)));
+        let mut orch = init_orch(&callbacks);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Both))
+            .unwrap();
+        orch.configure_port("Ethernet4", port_config(4000, SampleDirection::Tx))
+            .unwrap();
+
+        assert!(callbacks
+            .port_ops
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|s| s.starts_with("enable_ingress:")));
+        for (alias, port_id, requested) in [
+            ("Ethernet0", 0x100, SampleDirection::Both),
+            ("Ethernet4", 0x104, SampleDirection::Tx),
+        ] {
+            let info = orch.get_port_info(port_id).unwrap();
+            assert_eq!(info.direction, requested);
+            assert_eq!(info.programmed_direction, Some(SampleDirection::Rx));
+            assert_eq!(
+                callbacks.state.lock().unwrap()[alias],
+                (requested, SampleDirection::Rx)
+            );
+        }
+        assert_eq!(orch.stats().degraded_directions, 2);
+
+        orch.remove_port("Ethernet4").unwrap();
+        assert!(!callbacks.state.lock().unwrap().contains_key("Ethernet4"));
+        assert!(callbacks
+            .port_ops
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|s| !s.contains("egress")));
+    }
+
+    #[test]
+    fn test_capability_query_error_disables_egress() {
+        let callbacks = Arc::new(TestCallbacks::with_capability(Err(
+            "SAI_STATUS_NOT_IMPLEMENTED".to_string(),
+        )));
+        let mut orch = init_orch(&callbacks);
+
+        assert!(!orch.capability().egress_supported);
+        assert_eq!(orch.stats().capability_query_failures, 1);
+
+        orch.configure_port("Ethernet0", port_config(4000, SampleDirection::Tx))
+            .unwrap();
+        assert_eq!(
+            orch.get_port_info(0x100).unwrap().programmed_direction,
+            Some(SampleDirection::Rx)
+        );
+        assert_eq!(orch.stats().degraded_directions, 1);
+    }
+
+    #[test]
+    fn test_capability_requeried_on_warm_restart() {
+        let callbacks = Arc::new(TestCallbacks::with_capability(Ok(NO_EGRESS)));
+        let mut orch = init_orch(&callbacks);
+        let config = port_config(4000, SampleDirection::Both);
+        orch.configure_port("Ethernet0", config.clone()).unwrap();
+
+        *callbacks.capability.lock().unwrap() = Ok(SflowCapability::default());
+        orch.on_warm_boot_end();
+        assert_eq!(*callbacks.capability_queries.lock().unwrap(), 2);
+        assert!(orch.capability().egress_supported);
+
+        // Reapplying the config now adds egress without touching ingress
+        callbacks.port_ops.lock().unwrap().clear();
+        orch.configure_port("Ethernet0", config).unwrap();
+        let session_id = orch.get_port_info(0x100).unwrap().session_id;
+        assert_eq!(
+            *callbacks.port_ops.lock().unwrap(),
+            vec![format!("enable_egress:{}:{}", 0x100, session_id)]
+        );
+        assert_eq!(
+            callbacks.state.lock().unwrap()["Ethernet0"],
+            (SampleDirection::Both, SampleDirection::Both)
+        );
+    }
 }
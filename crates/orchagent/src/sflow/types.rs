@@ -1,5 +1,6 @@
 //! SflowOrch types.
 
+use sonic_orch_common::{FieldErrors, FieldValue, FieldValueParser, FromFieldValues};
 use sonic_sai::types::RawSaiObjectId;
 use std::num::NonZeroU32;
 
@@ -43,6 +44,16 @@ impl SampleDirection {
     pub fn has_egress(&self) -> bool {
         matches!(self, Self::Tx | Self::Both)
     }
+
+    /// Builds a direction from ingress/egress flags (None if neither is set).
+    pub fn from_flags(ingress: bool, egress: bool) -> Option<Self> {
+        match (ingress, egress) {
+            (true, true) => Some(Self::Both),
+            (true, false) => Some(Self::Rx),
+            (false, true) => Some(Self::Tx),
+            (false, false) => None,
+        }
+    }
 }
 
 /// Port sflow configuration.
@@ -54,6 +65,8 @@ pub struct PortSflowInfo {
     pub direction: SampleDirection,
     /// SAI sample session ID associated with this port.
     pub session_id: RawSaiObjectId,
+    /// Direction currently programmed on the port's SAI attributes.
+    pub programmed_direction: Option<SampleDirection>,
 }
 
 impl PortSflowInfo {
@@ -63,6 +76,35 @@ impl PortSflowInfo {
             admin_state,
             direction,
             session_id,
+            programmed_direction: None,
+        }
+    }
+}
+
+/// Samplepacket capabilities reported by the ASIC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SflowCapability {
+    /// Whether egress (tx) samplepacket is supported.
+    pub egress_supported: bool,
+}
+
+impl SflowCapability {
+    /// Returns the direction that can actually be programmed for `direction`.
+    ///
+    /// Without egress support, tx and both degrade to rx only.
+    pub fn effective_direction(&self, direction: SampleDirection) -> SampleDirection {
+        if !self.egress_supported && direction.has_egress() {
+            SampleDirection::Rx
+        } else {
+            direction
+        }
+    }
+}
+
+impl Default for SflowCapability {
+    fn default() -> Self {
+        Self {
+            egress_supported: true,
         }
     }
 }
@@ -123,36 +165,55 @@ impl SflowConfig {
 
     /// Parses a field-value pair and updates the config.
     pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
-        match field {
-            "admin_state" => {
-                self.admin_state = match value {
-                    "up" => true,
-                    "down" => false,
-                    _ => return Err(format!("Invalid admin_state: {}", value)),
-                };
-            }
-            "sample_rate" => {
-                if value == "error" {
-                    self.rate = None;
-                } else {
-                    let rate = value
-                        .parse::<u32>()
-                        .map_err(|e| format!("Invalid sample_rate '{}': {}", value, e))?;
-                    self.rate = NonZeroU32::new(rate);
-                }
-            }
-            "sample_direction" => {
-                self.direction = SampleDirection::parse(value)
-                    .ok_or_else(|| format!("Invalid sample_direction: {}", value))?;
-            }
-            _ => {
-                // Unknown field - ignore
+        self.apply_field_values(&[(field.to_string(), value.to_string())])
+            .map_err(|e| e.to_string())
+    }
+
+    /// Updates the config from the fields present in `fvs`.
+    ///
+    /// Unknown fields are ignored. If any field is invalid, nothing is
+    /// changed and every invalid field is reported.
+    pub fn apply_field_values(&mut self, fvs: &[FieldValue]) -> Result<(), FieldErrors> {
+        let mut parser = FieldValueParser::new(fvs);
+        let admin_state = parser.optional_with("admin_state", |v| match v {
+            "up" => Ok(true),
+            "down" => Ok(false),
+            _ => Err("expected up or down".to_string()),
+        });
+        let rate = parser.optional_with("sample_rate", |v| {
+            if v == "error" {
+                return Ok(None);
             }
+            v.parse::<u32>()
+                .map(NonZeroU32::new)
+                .map_err(|e| e.to_string())
+        });
+        let direction = parser.optional_with("sample_direction", |v| {
+            SampleDirection::parse(v).ok_or_else(|| "expected rx, tx or both".to_string())
+        });
+        parser.finish()?;
+
+        if let Some(admin_state) = admin_state {
+            self.admin_state = admin_state;
+        }
+        if let Some(rate) = rate {
+            self.rate = rate;
+        }
+        if let Some(direction) = direction {
+            self.direction = direction;
         }
         Ok(())
     }
 }
 
+impl FromFieldValues for SflowConfig {
+    fn from_field_values(fvs: &[FieldValue]) -> Result<Self, FieldErrors> {
+        let mut config = Self::new();
+        config.apply_field_values(fvs)?;
+        Ok(config)
+    }
+}
+
 impl Default for SflowConfig {
     fn default() -> Self {
         Self::new()
@@ -186,6 +247,40 @@ mod tests {
         assert!(SampleDirection::Both.has_egress());
     }
 
+    #[test]
+    fn test_sample_direction_from_flags() {
+        for direction in [
+            SampleDirection::Rx,
+            SampleDirection::Tx,
+            SampleDirection::Both,
+        ] {
+            assert_eq!(
+                SampleDirection::from_flags(direction.has_ingress(), direction.has_egress()),
+                Some(direction)
+            );
+        }
+        assert_eq!(SampleDirection::from_flags(false, false), None);
+    }
+
+    #[test]
+    fn test_capability_effective_direction() {
+        let supported = SflowCapability::default();
+        let unsupported = SflowCapability {
+            egress_supported: false,
+        };
+        for direction in [
+            SampleDirection::Rx,
+            SampleDirection::Tx,
+            SampleDirection::Both,
+        ] {
+            assert_eq!(supported.effective_direction(direction), direction);
+            assert_eq!(
+                unsupported.effective_direction(direction),
+                SampleDirection::Rx
+            );
+        }
+    }
+
     #[test]
     fn test_sflow_session() {
         let rate = NonZeroU32::new(4096).unwrap();
@@ -223,6 +318,39 @@ mod tests {
         assert!(config.parse_field("sample_rate", "invalid").is_err());
     }
 
+    #[test]
+    fn test_sflow_config_from_field_values() {
+        let fvs = |pairs: &[(&str, &str)]| -> Vec<FieldValue> {
+            pairs
+                .iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect()
+        };
+
+        let config = SflowConfig::from_field_values(&fvs(&[
+            ("admin_state", "up"),
+            ("sample_rate", "4096"),
+            ("sample_direction", "tx"),
+            ("description", "ignored"),
+        ]))
+        .unwrap();
+        assert!(config.admin_state);
+        assert_eq!(config.rate, NonZeroU32::new(4096));
+        assert_eq!(config.direction, SampleDirection::Tx);
+
+        // Every bad field is reported and nothing is applied
+        let mut config = SflowConfig::new();
+        let err = config
+            .apply_field_values(&fvs(&[
+                ("admin_state", "enabled"),
+                ("sample_rate", "4096"),
+                ("sample_direction", "sideways"),
+            ]))
+            .unwrap_err();
+        assert_eq!(err.fields(), vec!["admin_state", "sample_direction"]);
+        assert_eq!(config.rate, None);
+    }
+
     #[test]
     fn test_sflow_config_zero_rate() {
         let mut config = SflowConfig::new();
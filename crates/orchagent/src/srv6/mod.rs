@@ -8,6 +8,7 @@
 //! - Type-safe encapsulation mode enum
 //! - Vec for SID lists instead of raw arrays
 //! - Validated IPv6 format for SIDs
+//! - Reference-counted SID lists that cannot be removed while routes use them
 
 mod ffi;
 mod orch;
@@ -17,5 +18,5 @@ pub use ffi::{register_srv6_orch, unregister_srv6_orch};
 pub use orch::{Srv6Orch, Srv6OrchCallbacks, Srv6OrchConfig, Srv6OrchError, Srv6OrchStats};
 pub use types::{
     Srv6EncapMode, Srv6EndpointBehavior, Srv6LocalSidConfig, Srv6LocalSidEntry, Srv6NextHopConfig,
-    Srv6NextHopEntry, Srv6Sid, Srv6SidListConfig, Srv6SidListEntry, Srv6Stats,
+    Srv6NextHopEntry, Srv6Sid, Srv6SidFormat, Srv6SidListConfig, Srv6SidListEntry, Srv6Stats,
 };
@@ -1,9 +1,14 @@
 //! SRv6 orchestration logic.
 
-use super::types::{Srv6LocalSidEntry, Srv6Sid, Srv6SidListEntry, Srv6Stats};
+use super::types::{
+    RawSaiObjectId, Srv6LocalSidConfig, Srv6LocalSidEntry, Srv6Sid, Srv6SidFormat,
+    Srv6SidListConfig, Srv6SidListEntry, Srv6Stats,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use sonic_orch_common::TaskStatus;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum Srv6OrchError {
@@ -11,6 +16,7 @@ pub enum Srv6OrchError {
     SidListNotFound(String),
     InvalidSid(String),
     InvalidEndpointBehavior(String),
+    SidListInUse(String),
     SaiError(String),
 }
 
@@ -27,6 +33,20 @@ pub struct Srv6OrchStats {
 }
 
 pub trait Srv6OrchCallbacks: Send + Sync {
+    /// Looks up a VRF in VrfOrch.
+    fn get_vrf_oid(&self, vrf_name: &str) -> Option<RawSaiObjectId>;
+    /// Resolves an adjacency (`adj` field) to a next hop through NeighOrch.
+    fn resolve_adjacency(&self, adj: &str) -> Option<RawSaiObjectId>;
+    fn create_my_sid_entry(&self, entry: &Srv6LocalSidEntry) -> Result<RawSaiObjectId, String>;
+    fn remove_my_sid_entry(&self, sid_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_sid_list(&self, config: &Srv6SidListConfig) -> Result<RawSaiObjectId, String>;
+    /// Replaces the segment list of an existing SID list in place.
+    fn set_sid_list_segments(
+        &self,
+        sidlist_oid: RawSaiObjectId,
+        sids: &[Srv6Sid],
+    ) -> Result<(), String>;
+    fn remove_sid_list(&self, sidlist_oid: RawSaiObjectId) -> Result<(), String>;
     fn on_local_sid_created(&self, entry: &Srv6LocalSidEntry);
     fn on_local_sid_removed(&self, sid: &Srv6Sid);
     fn on_sidlist_created(&self, entry: &Srv6SidListEntry);
@@ -36,6 +56,7 @@ pub trait Srv6OrchCallbacks: Send + Sync {
 pub struct Srv6Orch {
     config: Srv6OrchConfig,
     stats: Srv6OrchStats,
+    callbacks: Option<Arc<dyn Srv6OrchCallbacks>>,
     local_sids: HashMap<Srv6Sid, Srv6LocalSidEntry>,
     sidlists: HashMap<String, Srv6SidListEntry>,
 }
@@ -45,11 +66,233 @@ impl Srv6Orch {
         Self {
             config,
             stats: Srv6OrchStats::default(),
+            callbacks: None,
             local_sids: HashMap::new(),
             sidlists: HashMap::new(),
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn Srv6OrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    fn callbacks(&self) -> Result<Arc<dyn Srv6OrchCallbacks>, Srv6OrchError> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| Srv6OrchError::SaiError("No callbacks set".to_string()))
+    }
+
+    /// Handles a SRV6_MY_SID_TABLE SET.
+    ///
+    /// DT behaviors whose VRF does not exist yet and End.X/uA behaviors whose
+    /// adjacency is unresolved return `NeedRetry`. A changed behavior on an
+    /// existing SID replaces the SAI entry; an identical SET is a no-op.
+    pub fn handle_my_sid_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, Srv6OrchError> {
+        let (format, sid) =
+            Srv6SidFormat::parse_my_sid_key(key).map_err(Srv6OrchError::InvalidSid)?;
+        let mut config = Srv6LocalSidConfig::new(sid.clone());
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(Srv6OrchError::InvalidEndpointBehavior)?;
+        }
+        config
+            .validate()
+            .map_err(Srv6OrchError::InvalidEndpointBehavior)?;
+        if config.endpoint_behavior.is_micro_sid() && format.is_none() {
+            return Err(Srv6OrchError::InvalidSid(format!(
+                "{:?} requires a SID structure in key {}",
+                config.endpoint_behavior, key
+            )));
+        }
+
+        let existing = self.local_sids.get(&sid);
+        if existing.is_some_and(|entry| entry.config == config && entry.format == format) {
+            return Ok(TaskStatus::Success);
+        }
+        let old_behavior = existing.map(|entry| entry.config.endpoint_behavior);
+
+        let callbacks = self.callbacks()?;
+        let mut entry = Srv6LocalSidEntry::new(config);
+        entry.format = format;
+        if let Some(vrf) = entry.config.vrf.as_deref() {
+            match callbacks.get_vrf_oid(vrf) {
+                Some(oid) => entry.vrf_oid = oid,
+                None if entry.config.endpoint_behavior.requires_vrf() => {
+                    return Ok(TaskStatus::NeedRetry)
+                }
+                None => {}
+            }
+        }
+        if entry.config.endpoint_behavior.requires_adjacency() {
+            let adj = entry.config.next_hop.as_deref().unwrap_or_default();
+            match callbacks.resolve_adjacency(adj) {
+                Some(oid) => entry.nexthop_oid = oid,
+                None => return Ok(TaskStatus::NeedRetry),
+            }
+        }
+
+        if let Some(old) = self.local_sids.get(&sid) {
+            callbacks
+                .remove_my_sid_entry(old.sid_oid)
+                .map_err(Srv6OrchError::SaiError)?;
+            self.local_sids.remove(&sid);
+        }
+
+        entry.sid_oid = match callbacks.create_my_sid_entry(&entry) {
+            Ok(oid) => oid,
+            Err(e) => {
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceCreate,
+                    "Srv6Orch",
+                    "set_my_sid"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(&sid.to_string())
+                .with_object_type("local_sid")
+                .with_error(&e));
+                return Err(Srv6OrchError::SaiError(e));
+            }
+        };
+
+        if old_behavior.is_some() {
+            self.stats.stats.local_sids_updated =
+                self.stats.stats.local_sids_updated.saturating_add(1);
+        } else {
+            self.stats.stats.local_sids_created =
+                self.stats.stats.local_sids_created.saturating_add(1);
+        }
+        callbacks.on_local_sid_created(&entry);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceCreate, "Srv6Orch", "set_my_sid")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(&sid.to_string())
+                .with_object_type("local_sid")
+                .with_details(serde_json::json!({
+                    "endpoint_behavior": format!("{:?}", entry.config.endpoint_behavior),
+                    "old_endpoint_behavior": old_behavior.map(|b| format!("{:?}", b)),
+                    "vrf": entry.config.vrf,
+                    "adj": entry.config.next_hop,
+                }))
+        );
+
+        self.local_sids.insert(sid, entry);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a SRV6_MY_SID_TABLE DEL.
+    pub fn handle_my_sid_del(&mut self, key: &str) -> Result<(), Srv6OrchError> {
+        let (_, sid) = Srv6SidFormat::parse_my_sid_key(key).map_err(Srv6OrchError::InvalidSid)?;
+        let sid_oid = self
+            .local_sids
+            .get(&sid)
+            .map(|entry| entry.sid_oid)
+            .ok_or_else(|| Srv6OrchError::LocalSidNotFound(sid.clone()))?;
+        let callbacks = self.callbacks()?;
+        callbacks
+            .remove_my_sid_entry(sid_oid)
+            .map_err(Srv6OrchError::SaiError)?;
+        self.remove_local_sid(&sid)?;
+        callbacks.on_local_sid_removed(&sid);
+        Ok(())
+    }
+
+    /// Handles a SRV6_SID_LIST SET.
+    ///
+    /// A changed `path` on an existing list is written to SAI in place so
+    /// routes referencing the list keep their next hops.
+    pub fn handle_sid_list_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), Srv6OrchError> {
+        let path = fields
+            .iter()
+            .find(|(field, _)| field == "path")
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| Srv6OrchError::InvalidSid(format!("{}: path is required", name)))?;
+        let config =
+            Srv6SidListConfig::parse_path(name, path).map_err(Srv6OrchError::InvalidSid)?;
+        let callbacks = self.callbacks()?;
+
+        if let Some(entry) = self.sidlists.get_mut(name) {
+            if entry.config == config {
+                return Ok(());
+            }
+            callbacks
+                .set_sid_list_segments(entry.sidlist_oid, &config.sids)
+                .map_err(Srv6OrchError::SaiError)?;
+
+            audit_log!(
+                AuditRecord::new(AuditCategory::ResourceModify, "Srv6Orch", "set_sidlist")
+                    .with_outcome(AuditOutcome::Success)
+                    .with_object_id(name)
+                    .with_object_type("sid_list")
+                    .with_details(serde_json::json!({
+                        "old_sid_count": entry.config.sids.len(),
+                        "sid_count": config.sids.len(),
+                        "ref_count": entry.ref_count,
+                    }))
+            );
+            entry.config = config;
+            return Ok(());
+        }
+
+        let mut entry = Srv6SidListEntry::new(config);
+        entry.sidlist_oid = callbacks
+            .create_sid_list(&entry.config)
+            .map_err(Srv6OrchError::SaiError)?;
+        self.add_sidlist(entry)?;
+        if let Some(entry) = self.sidlists.get(name) {
+            callbacks.on_sidlist_created(entry);
+        }
+        Ok(())
+    }
+
+    /// Handles a SRV6_SID_LIST DEL; fails while routes reference the list.
+    pub fn handle_sid_list_del(&mut self, name: &str) -> Result<(), Srv6OrchError> {
+        let entry = self
+            .sidlists
+            .get(name)
+            .ok_or_else(|| Srv6OrchError::SidListNotFound(name.to_string()))?;
+        if entry.ref_count > 0 {
+            return Err(Srv6OrchError::SidListInUse(name.to_string()));
+        }
+        let callbacks = self.callbacks()?;
+        callbacks
+            .remove_sid_list(entry.sidlist_oid)
+            .map_err(Srv6OrchError::SaiError)?;
+        self.remove_sidlist(name)?;
+        callbacks.on_sidlist_removed(name);
+        Ok(())
+    }
+
+    /// Takes a reference on a SID list for a route or next hop.
+    pub fn increase_sidlist_ref(&mut self, name: &str) -> Result<RawSaiObjectId, Srv6OrchError> {
+        let entry = self
+            .sidlists
+            .get_mut(name)
+            .ok_or_else(|| Srv6OrchError::SidListNotFound(name.to_string()))?;
+        entry.ref_count += 1;
+        Ok(entry.sidlist_oid)
+    }
+
+    /// Drops a reference taken with `increase_sidlist_ref`.
+    pub fn decrease_sidlist_ref(&mut self, name: &str) -> Result<(), Srv6OrchError> {
+        let entry = self
+            .sidlists
+            .get_mut(name)
+            .filter(|entry| entry.ref_count > 0)
+            .ok_or_else(|| Srv6OrchError::SidListNotFound(name.to_string()))?;
+        entry.ref_count -= 1;
+        Ok(())
+    }
+
     pub fn get_local_sid(&self, sid: &Srv6Sid) -> Option<&Srv6LocalSidEntry> {
         self.local_sids.get(sid)
     }
@@ -171,6 +414,22 @@ impl Srv6Orch {
     }
 
     pub fn remove_sidlist(&mut self, name: &str) -> Result<Srv6SidListEntry, Srv6OrchError> {
+        if let Some(entry) = self.sidlists.get(name).filter(|e| e.ref_count > 0) {
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "Srv6Orch",
+                "remove_sidlist"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(name)
+            .with_object_type("sid_list")
+            .with_error(&format!(
+                "SID list {} still referenced by {} routes",
+                name, entry.ref_count
+            )));
+            return Err(Srv6OrchError::SidListInUse(name.to_string()));
+        }
+
         self.sidlists
             .remove(name)
             .ok_or_else(|| {
@@ -225,6 +484,108 @@ impl Srv6Orch {
 mod tests {
     use super::super::types::{Srv6EndpointBehavior, Srv6LocalSidConfig, Srv6SidListConfig};
     use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        next_oid: Mutex<RawSaiObjectId>,
+        vrfs: Mutex<HashMap<String, RawSaiObjectId>>,
+        adjacencies: Mutex<HashMap<String, RawSaiObjectId>>,
+        live: Mutex<HashSet<RawSaiObjectId>>,
+        my_sids: Mutex<Vec<Srv6LocalSidEntry>>,
+        segments: Mutex<HashMap<RawSaiObjectId, Vec<Srv6Sid>>>,
+    }
+
+    impl MockCallbacks {
+        fn alloc(&self) -> RawSaiObjectId {
+            let mut next = self.next_oid.lock().unwrap();
+            *next += 1;
+            let oid = 0x5000 + *next;
+            self.live.lock().unwrap().insert(oid);
+            oid
+        }
+
+        fn free(&self, oid: RawSaiObjectId) -> Result<(), String> {
+            if self.live.lock().unwrap().remove(&oid) {
+                Ok(())
+            } else {
+                Err(format!("unknown oid {:#x}", oid))
+            }
+        }
+
+        fn add_vrf(&self, name: &str, oid: RawSaiObjectId) {
+            self.vrfs.lock().unwrap().insert(name.to_string(), oid);
+        }
+
+        fn add_adjacency(&self, adj: &str, oid: RawSaiObjectId) {
+            self.adjacencies
+                .lock()
+                .unwrap()
+                .insert(adj.to_string(), oid);
+        }
+
+        fn live_count(&self) -> usize {
+            self.live.lock().unwrap().len()
+        }
+    }
+
+    impl Srv6OrchCallbacks for MockCallbacks {
+        fn get_vrf_oid(&self, vrf_name: &str) -> Option<RawSaiObjectId> {
+            self.vrfs.lock().unwrap().get(vrf_name).copied()
+        }
+        fn resolve_adjacency(&self, adj: &str) -> Option<RawSaiObjectId> {
+            self.adjacencies.lock().unwrap().get(adj).copied()
+        }
+        fn create_my_sid_entry(&self, entry: &Srv6LocalSidEntry) -> Result<RawSaiObjectId, String> {
+            self.my_sids.lock().unwrap().push(entry.clone());
+            Ok(self.alloc())
+        }
+        fn remove_my_sid_entry(&self, sid_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(sid_oid)
+        }
+        fn create_sid_list(&self, config: &Srv6SidListConfig) -> Result<RawSaiObjectId, String> {
+            let oid = self.alloc();
+            self.segments
+                .lock()
+                .unwrap()
+                .insert(oid, config.sids.clone());
+            Ok(oid)
+        }
+        fn set_sid_list_segments(
+            &self,
+            sidlist_oid: RawSaiObjectId,
+            sids: &[Srv6Sid],
+        ) -> Result<(), String> {
+            self.segments
+                .lock()
+                .unwrap()
+                .insert(sidlist_oid, sids.to_vec());
+            Ok(())
+        }
+        fn remove_sid_list(&self, sidlist_oid: RawSaiObjectId) -> Result<(), String> {
+            self.segments.lock().unwrap().remove(&sidlist_oid);
+            self.free(sidlist_oid)
+        }
+        fn on_local_sid_created(&self, _entry: &Srv6LocalSidEntry) {}
+        fn on_local_sid_removed(&self, _sid: &Srv6Sid) {}
+        fn on_sidlist_created(&self, _entry: &Srv6SidListEntry) {}
+        fn on_sidlist_removed(&self, _name: &str) {}
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_mock() -> (Srv6Orch, Arc<MockCallbacks>) {
+        let mock = Arc::new(MockCallbacks::default());
+        let mut orch = Srv6Orch::new(Srv6OrchConfig::default());
+        orch.set_callbacks(mock.clone());
+        (orch, mock)
+    }
 
     fn create_test_local_sid(sid_str: &str, behavior: Srv6EndpointBehavior) -> Srv6LocalSidEntry {
         Srv6LocalSidEntry::new(Srv6LocalSidConfig {
@@ -554,4 +915,179 @@ mod tests {
             Srv6EndpointBehavior::EndDt4
         ));
     }
+
+    #[test]
+    fn test_my_sid_set_end() {
+        let (mut orch, mock) = orch_with_mock();
+        let status = orch
+            .handle_my_sid_set("fc00:0:1:1::", &fields(&[("action", "end")]))
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+
+        let entry = orch
+            .get_local_sid(&Srv6Sid::new("fc00:0:1:1::".to_string()))
+            .unwrap();
+        assert_eq!(entry.config.endpoint_behavior, Srv6EndpointBehavior::End);
+        assert_ne!(entry.sid_oid, 0);
+        assert_eq!(mock.live_count(), 1);
+
+        // Identical SET is a no-op
+        orch.handle_my_sid_set("fc00:0:1:1::", &fields(&[("action", "end")]))
+            .unwrap();
+        assert_eq!(mock.my_sids.lock().unwrap().len(), 1);
+        assert_eq!(orch.stats().stats.local_sids_created, 1);
+    }
+
+    #[test]
+    fn test_my_sid_behavior_change_replaces_entry() {
+        let (mut orch, mock) = orch_with_mock();
+        mock.add_vrf("Vrf10", 0x100);
+        let sid = Srv6Sid::new("fc00:0:1:1::".to_string());
+
+        orch.handle_my_sid_set("fc00:0:1:1::", &fields(&[("action", "end")]))
+            .unwrap();
+        let old_oid = orch.get_local_sid(&sid).unwrap().sid_oid;
+
+        let status = orch
+            .handle_my_sid_set(
+                "fc00:0:1:1::",
+                &fields(&[("action", "end.dt4"), ("vrf", "Vrf10")]),
+            )
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+
+        let entry = orch.get_local_sid(&sid).unwrap();
+        assert_eq!(entry.config.endpoint_behavior, Srv6EndpointBehavior::EndDt4);
+        assert_eq!(entry.vrf_oid, 0x100);
+        assert_ne!(entry.sid_oid, old_oid);
+        assert_eq!(orch.local_sid_count(), 1);
+        assert_eq!(mock.live_count(), 1);
+        assert_eq!(orch.stats().stats.local_sids_created, 1);
+        assert_eq!(orch.stats().stats.local_sids_updated, 1);
+    }
+
+    #[test]
+    fn test_my_sid_dt46_waits_for_vrf() {
+        let (mut orch, mock) = orch_with_mock();
+        let fv = fields(&[("action", "end.dt46"), ("vrf", "Vrf20")]);
+
+        let status = orch.handle_my_sid_set("fc00:0:1:2::", &fv).unwrap();
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert_eq!(orch.local_sid_count(), 0);
+        assert_eq!(mock.live_count(), 0);
+
+        mock.add_vrf("Vrf20", 0x200);
+        let status = orch.handle_my_sid_set("fc00:0:1:2::", &fv).unwrap();
+        assert_eq!(status, TaskStatus::Success);
+        let entry = orch
+            .get_local_sid(&Srv6Sid::new("fc00:0:1:2::".to_string()))
+            .unwrap();
+        assert_eq!(entry.vrf_oid, 0x200);
+    }
+
+    #[test]
+    fn test_my_sid_dt_requires_vrf_field() {
+        let (mut orch, _mock) = orch_with_mock();
+        let result = orch.handle_my_sid_set("fc00:0:1:2::", &fields(&[("action", "end.dt6")]));
+        assert!(matches!(
+            result,
+            Err(Srv6OrchError::InvalidEndpointBehavior(_))
+        ));
+    }
+
+    #[test]
+    fn test_my_sid_end_x_resolves_adjacency() {
+        let (mut orch, mock) = orch_with_mock();
+        let fv = fields(&[("action", "end.x"), ("adj", "fe80::1")]);
+
+        let status = orch.handle_my_sid_set("fc00:0:1:3::", &fv).unwrap();
+        assert_eq!(status, TaskStatus::NeedRetry);
+
+        mock.add_adjacency("fe80::1", 0x300);
+        let status = orch.handle_my_sid_set("fc00:0:1:3::", &fv).unwrap();
+        assert_eq!(status, TaskStatus::Success);
+        let entry = orch
+            .get_local_sid(&Srv6Sid::new("fc00:0:1:3::".to_string()))
+            .unwrap();
+        assert_eq!(entry.nexthop_oid, 0x300);
+    }
+
+    #[test]
+    fn test_my_sid_micro_sid_requires_structure() {
+        let (mut orch, mock) = orch_with_mock();
+        let result = orch.handle_my_sid_set("fc00:0:1::", &fields(&[("action", "un")]));
+        assert!(matches!(result, Err(Srv6OrchError::InvalidSid(_))));
+
+        let status = orch
+            .handle_my_sid_set("32:16:0:0:fc00:0:1::", &fields(&[("action", "un")]))
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+        let sid = Srv6Sid::new("fc00:0:1::".to_string());
+        let format = orch.get_local_sid(&sid).unwrap().format.unwrap();
+        assert_eq!(format.block_len, 32);
+        assert_eq!(format.node_len, 16);
+
+        mock.add_adjacency("Ethernet0", 0x400);
+        let status = orch
+            .handle_my_sid_set(
+                "32:16:16:0:fc00:0:1:e001::",
+                &fields(&[("action", "ua"), ("adj", "Ethernet0")]),
+            )
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+
+        orch.handle_my_sid_del("32:16:0:0:fc00:0:1::").unwrap();
+        assert!(orch.get_local_sid(&sid).is_none());
+        assert_eq!(mock.live_count(), 1);
+    }
+
+    #[test]
+    fn test_sid_list_set_and_update_in_place() {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_sid_list_set("seg1", &fields(&[("path", "fc00:0:2::,fc00:0:3::")]))
+            .unwrap();
+        let oid = orch.get_sidlist("seg1").unwrap().sidlist_oid;
+        assert_eq!(mock.segments.lock().unwrap()[&oid].len(), 2);
+
+        orch.handle_sid_list_set(
+            "seg1",
+            &fields(&[("path", "fc00:0:2::,fc00:0:3::,fc00:0:4::")]),
+        )
+        .unwrap();
+        let entry = orch.get_sidlist("seg1").unwrap();
+        assert_eq!(entry.sidlist_oid, oid);
+        assert_eq!(entry.config.sids.len(), 3);
+        assert_eq!(mock.segments.lock().unwrap()[&oid].len(), 3);
+        assert_eq!(orch.stats().stats.sidlists_created, 1);
+    }
+
+    #[test]
+    fn test_sid_list_in_use_cannot_be_removed() {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_sid_list_set("seg1", &fields(&[("path", "fc00:0:2::")]))
+            .unwrap();
+        orch.handle_sid_list_set("seg2", &fields(&[("path", "fc00:0:5::,fc00:0:6::")]))
+            .unwrap();
+
+        orch.increase_sidlist_ref("seg1").unwrap();
+        orch.increase_sidlist_ref("seg1").unwrap();
+        assert!(matches!(
+            orch.handle_sid_list_del("seg1"),
+            Err(Srv6OrchError::SidListInUse(_))
+        ));
+        assert!(matches!(
+            orch.remove_sidlist("seg1"),
+            Err(Srv6OrchError::SidListInUse(_))
+        ));
+
+        orch.decrease_sidlist_ref("seg1").unwrap();
+        assert!(orch.handle_sid_list_del("seg1").is_err());
+        orch.decrease_sidlist_ref("seg1").unwrap();
+        assert!(orch.decrease_sidlist_ref("seg1").is_err());
+
+        orch.handle_sid_list_del("seg1").unwrap();
+        assert!(orch.get_sidlist("seg1").is_none());
+        assert!(orch.get_sidlist("seg2").is_some());
+        assert_eq!(mock.live_count(), 1);
+    }
 }
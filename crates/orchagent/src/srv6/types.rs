@@ -1,6 +1,7 @@
 //! SRv6 (Segment Routing over IPv6) types.
 
 use std::collections::HashMap;
+use std::net::Ipv6Addr;
 
 pub type RawSaiObjectId = u64;
 
@@ -48,9 +49,97 @@ pub enum Srv6EndpointBehavior {
     EndB6Encaps,
     Usp,
     Usd,
+    /// uN: micro-SID node behavior (End with NEXT-CSID flavor).
+    Un,
+    /// uA: micro-SID adjacency behavior (End.X with NEXT-CSID flavor).
+    Ua,
 }
 
-#[derive(Debug, Clone)]
+impl Srv6EndpointBehavior {
+    /// Parses the `action` field of SRV6_MY_SID_TABLE.
+    pub fn parse(action: &str) -> Result<Self, String> {
+        match action {
+            "end" => Ok(Self::End),
+            "end.x" => Ok(Self::EndX),
+            "end.t" => Ok(Self::EndT),
+            "end.dx6" => Ok(Self::EndDx6),
+            "end.dx4" => Ok(Self::EndDx4),
+            "end.dt6" => Ok(Self::EndDt6),
+            "end.dt4" => Ok(Self::EndDt4),
+            "end.dt46" => Ok(Self::EndDt46),
+            "end.b6.encaps" => Ok(Self::EndB6Encaps),
+            "end.b6.insert" => Ok(Self::EndB6),
+            "usp" => Ok(Self::Usp),
+            "usd" => Ok(Self::Usd),
+            "un" => Ok(Self::Un),
+            "ua" => Ok(Self::Ua),
+            _ => Err(format!("Unknown SRv6 action: {}", action)),
+        }
+    }
+
+    /// Returns true for behaviors that look up a VRF table (End.T, End.DT*).
+    pub fn requires_vrf(&self) -> bool {
+        matches!(
+            self,
+            Self::EndT | Self::EndDt4 | Self::EndDt6 | Self::EndDt46
+        )
+    }
+
+    /// Returns true for behaviors that forward to an adjacency (End.X, uA, End.DX*).
+    pub fn requires_adjacency(&self) -> bool {
+        matches!(self, Self::EndX | Self::Ua | Self::EndDx4 | Self::EndDx6)
+    }
+
+    /// Returns true for micro-SID (uSID) behaviors.
+    pub fn is_micro_sid(&self) -> bool {
+        matches!(self, Self::Un | Self::Ua)
+    }
+}
+
+/// SID structure carried in a SRV6_MY_SID_TABLE key (`block:node:func:arg:sid`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Srv6SidFormat {
+    pub block_len: u8,
+    pub node_len: u8,
+    pub func_len: u8,
+    pub arg_len: u8,
+}
+
+impl Srv6SidFormat {
+    /// Splits a SRV6_MY_SID_TABLE key into its optional SID structure and SID.
+    ///
+    /// Both `32:16:16:0:fc00:0:1:e000::` and a bare `fc00:0:1:e000::` are accepted.
+    pub fn parse_my_sid_key(key: &str) -> Result<(Option<Self>, Srv6Sid), String> {
+        let parts: Vec<&str> = key.splitn(5, ':').collect();
+        if parts.len() == 5 {
+            let lens: Result<Vec<u8>, _> = parts[..4].iter().map(|p| p.parse::<u8>()).collect();
+            if let (Ok(lens), Ok(_)) = (lens, parts[4].parse::<Ipv6Addr>()) {
+                let format = Self {
+                    block_len: lens[0],
+                    node_len: lens[1],
+                    func_len: lens[2],
+                    arg_len: lens[3],
+                };
+                if format.total_len() > 128 {
+                    return Err(format!("SID structure longer than 128 bits: {}", key));
+                }
+                return Ok((Some(format), Srv6Sid::new(parts[4].to_string())));
+            }
+        }
+        key.parse::<Ipv6Addr>()
+            .map_err(|_| format!("Invalid SRv6 SID format: {}", key))?;
+        Ok((None, Srv6Sid::new(key.to_string())))
+    }
+
+    pub fn total_len(&self) -> u32 {
+        u32::from(self.block_len)
+            + u32::from(self.node_len)
+            + u32::from(self.func_len)
+            + u32::from(self.arg_len)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Srv6LocalSidConfig {
     pub sid: Srv6Sid,
     pub endpoint_behavior: Srv6EndpointBehavior,
@@ -58,28 +147,96 @@ pub struct Srv6LocalSidConfig {
     pub vrf: Option<String>,
 }
 
+impl Srv6LocalSidConfig {
+    /// Creates an End config, to be filled by `parse_field`.
+    pub fn new(sid: Srv6Sid) -> Self {
+        Self {
+            sid,
+            endpoint_behavior: Srv6EndpointBehavior::End,
+            next_hop: None,
+            vrf: None,
+        }
+    }
+
+    /// Applies one SRV6_MY_SID_TABLE field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "action" => self.endpoint_behavior = Srv6EndpointBehavior::parse(value)?,
+            "vrf" => self.vrf = Some(value.to_string()),
+            "adj" => self.next_hop = Some(value.to_string()),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Checks that the fields required by the behavior are present.
+    pub fn validate(&self) -> Result<(), String> {
+        let behavior = self.endpoint_behavior;
+        if behavior.requires_vrf() && self.vrf.is_none() {
+            return Err(format!("{:?} requires a vrf", behavior));
+        }
+        if behavior.requires_adjacency() && self.next_hop.is_none() {
+            return Err(format!("{:?} requires an adj", behavior));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Srv6LocalSidEntry {
     pub config: Srv6LocalSidConfig,
     pub sid_oid: RawSaiObjectId,
+    /// SID structure from the table key, required for micro-SID behaviors.
+    pub format: Option<Srv6SidFormat>,
+    /// VRF resolved for End.T/End.DT* behaviors.
+    pub vrf_oid: RawSaiObjectId,
+    /// Next hop resolved for End.X/uA/End.DX* behaviors.
+    pub nexthop_oid: RawSaiObjectId,
 }
 
 impl Srv6LocalSidEntry {
     pub fn new(config: Srv6LocalSidConfig) -> Self {
-        Self { config, sid_oid: 0 }
+        Self {
+            config,
+            sid_oid: 0,
+            format: None,
+            vrf_oid: 0,
+            nexthop_oid: 0,
+        }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Srv6SidListConfig {
     pub name: String,
     pub sids: Vec<Srv6Sid>,
 }
 
+impl Srv6SidListConfig {
+    /// Parses the comma-separated `path` field of SRV6_SID_LIST, outermost segment first.
+    pub fn parse_path(name: &str, path: &str) -> Result<Self, String> {
+        let sids = path
+            .split(',')
+            .map(|sid| {
+                let sid = sid.trim();
+                sid.parse::<Ipv6Addr>()
+                    .map(|_| Srv6Sid::new(sid.to_string()))
+                    .map_err(|_| format!("Invalid SID in path: {}", sid))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            name: name.to_string(),
+            sids,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Srv6SidListEntry {
     pub config: Srv6SidListConfig,
     pub sidlist_oid: RawSaiObjectId,
+    /// Routes and next hops using this SID list.
+    pub ref_count: u32,
 }
 
 impl Srv6SidListEntry {
@@ -87,6 +244,7 @@ impl Srv6SidListEntry {
         Self {
             config,
             sidlist_oid: 0,
+            ref_count: 0,
         }
     }
 }
@@ -120,6 +278,70 @@ impl Srv6NextHopEntry {
 #[derive(Debug, Clone, Default)]
 pub struct Srv6Stats {
     pub local_sids_created: u64,
+    pub local_sids_updated: u64,
     pub sidlists_created: u64,
     pub nexthops_created: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_endpoint_behavior() {
+        assert_eq!(
+            Srv6EndpointBehavior::parse("end.dt46"),
+            Ok(Srv6EndpointBehavior::EndDt46)
+        );
+        assert_eq!(
+            Srv6EndpointBehavior::parse("ua"),
+            Ok(Srv6EndpointBehavior::Ua)
+        );
+        assert!(Srv6EndpointBehavior::parse("end.bogus").is_err());
+
+        assert!(Srv6EndpointBehavior::EndDt4.requires_vrf());
+        assert!(Srv6EndpointBehavior::Ua.requires_adjacency());
+        assert!(Srv6EndpointBehavior::Un.is_micro_sid());
+        assert!(!Srv6EndpointBehavior::End.requires_vrf());
+    }
+
+    #[test]
+    fn test_parse_my_sid_key() {
+        let (format, sid) = Srv6SidFormat::parse_my_sid_key("32:16:16:0:fc00:0:1:e000::").unwrap();
+        let format = format.unwrap();
+        assert_eq!(format.block_len, 32);
+        assert_eq!(format.node_len, 16);
+        assert_eq!(format.total_len(), 64);
+        assert_eq!(sid.as_str(), "fc00:0:1:e000::");
+
+        let (format, sid) = Srv6SidFormat::parse_my_sid_key("fc00:0:1:1::").unwrap();
+        assert!(format.is_none());
+        assert_eq!(sid.as_str(), "fc00:0:1:1::");
+
+        assert!(Srv6SidFormat::parse_my_sid_key("100:100:0:0:fc00::").is_err());
+        assert!(Srv6SidFormat::parse_my_sid_key("not-a-sid").is_err());
+    }
+
+    #[test]
+    fn test_local_sid_config_validate() {
+        let mut config = Srv6LocalSidConfig::new(Srv6Sid::new("fc00:0:1:1::".to_string()));
+        config.parse_field("action", "end.dt46").unwrap();
+        assert!(config.validate().is_err());
+        config.parse_field("vrf", "Vrf10").unwrap();
+        assert!(config.validate().is_ok());
+
+        config.parse_field("action", "end.x").unwrap();
+        assert!(config.validate().is_err());
+        config.parse_field("adj", "fe80::1").unwrap();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_sid_list_path() {
+        let config = Srv6SidListConfig::parse_path("seg1", "fc00:0:2::, fc00:0:3::").unwrap();
+        assert_eq!(config.sids.len(), 2);
+        assert_eq!(config.sids[1].as_str(), "fc00:0:3::");
+
+        assert!(Srv6SidListConfig::parse_path("seg1", "fc00:0:2::,bogus").is_err());
+    }
+}
@@ -19,6 +19,8 @@
 //! - All C++ logic bugs fixed (correct return values, proper iterator handling)
 //! - Type-safe STP state conversions
 //! - Validated instance ID ranges
+//! - Bulk port state programming grouped by SAI state, with one scoped FDB
+//!   flush per port leaving forwarding
 
 mod ffi;
 mod orch;
@@ -26,4 +28,6 @@ mod types;
 
 pub use ffi::{register_stp_orch, unregister_stp_orch};
 pub use orch::{StpOrch, StpOrchCallbacks, StpOrchConfig, StpOrchError, StpOrchStats};
-pub use types::{SaiStpPortState, StpInstanceEntry, StpPortIds, StpState};
+pub use types::{
+    tables, SaiStpPortState, StpInstanceEntry, StpPortIds, StpPortStateUpdate, StpState,
+};
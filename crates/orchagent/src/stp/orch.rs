@@ -1,10 +1,10 @@
 //! STP orchestration logic.
 
-use super::types::{SaiStpPortState, StpInstanceEntry, StpPortIds, StpState};
+use super::types::{SaiStpPortState, StpInstanceEntry, StpPortIds, StpPortStateUpdate, StpState};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use sonic_sai::types::RawSaiObjectId;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 /// STP orchestrator error types.
@@ -44,6 +44,7 @@ pub struct StpOrchStats {
     pub ports_created: u64,
     pub ports_removed: u64,
     pub state_updates: u64,
+    pub bulk_state_calls: u64,
     pub fdb_flushes: u64,
 }
 
@@ -70,7 +71,17 @@ pub trait StpOrchCallbacks: Send + Sync {
         stp_port_oid: RawSaiObjectId,
         state: SaiStpPortState,
     ) -> Result<(), String>;
+    /// Sets one state on many STP ports in a single bulk call, returning
+    /// a per-object result in input order.
+    fn set_stp_port_states(
+        &self,
+        stp_port_oids: &[RawSaiObjectId],
+        state: SaiStpPortState,
+    ) -> Vec<Result<(), String>>;
     fn flush_fdb_by_vlan(&self, vlan_alias: &str) -> Result<(), String>;
+    /// Flushes dynamic FDB entries learned on a port in the given VLANs
+    /// (FdbOrch scoped flush); all VLANs if the list is empty.
+    fn flush_fdb_by_port(&self, port_alias: &str, vlan_aliases: &[String]) -> Result<(), String>;
     fn ensure_bridge_port(&self, port_alias: &str) -> Result<RawSaiObjectId, String>;
 }
 
@@ -88,6 +99,8 @@ pub struct StpOrch {
     default_stp_id: RawSaiObjectId,
     /// Maximum STP instances supported
     max_stp_instance: u16,
+    /// Map: STP port OID → last programmed state
+    port_states: HashMap<RawSaiObjectId, StpState>,
 }
 
 impl StpOrch {
@@ -101,6 +114,7 @@ impl StpOrch {
             vlan_to_instance_map: HashMap::new(),
             default_stp_id: 0,
             max_stp_instance: 0,
+            port_states: HashMap::new(),
         }
     }
 
@@ -238,6 +252,13 @@ impl StpOrch {
             .set_vlan_stp_instance(vlan_alias, stp_inst_oid)
             .map_err(StpOrchError::SaiError)?;
 
+        // A VLAN belongs to one instance; the SAI set above moved it
+        for (other, entry) in self.vlan_to_instance_map.iter_mut() {
+            if *other != instance {
+                entry.remove_vlan(vlan_alias);
+            }
+        }
+
         // Track VLAN in instance
         self.vlan_to_instance_map
             .entry(instance)
@@ -271,6 +292,46 @@ impl StpOrch {
         Ok(())
     }
 
+    /// Replaces the VLAN membership of an instance, moving only the VLANs
+    /// that changed.
+    pub fn set_instance_vlans(
+        &mut self,
+        instance: u16,
+        vlans: &HashSet<String>,
+    ) -> Result<(), StpOrchError> {
+        let mut stale: Vec<String> = self
+            .vlan_to_instance_map
+            .get(&instance)
+            .map(|entry| entry.vlan_list.difference(vlans).cloned().collect())
+            .unwrap_or_default();
+        stale.sort();
+        for vlan in &stale {
+            self.remove_vlan_from_instance(vlan, instance)?;
+        }
+
+        let current = self.instance_vlans(instance);
+        let mut added: Vec<&String> = vlans
+            .iter()
+            .filter(|vlan| !current.contains(vlan))
+            .collect();
+        added.sort();
+        for vlan in added {
+            self.add_vlan_to_instance(vlan, instance)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the VLANs of an instance, sorted.
+    pub fn instance_vlans(&self, instance: u16) -> Vec<String> {
+        let mut vlans: Vec<String> = self
+            .vlan_to_instance_map
+            .get(&instance)
+            .map(|entry| entry.vlan_list.iter().cloned().collect())
+            .unwrap_or_default();
+        vlans.sort();
+        vlans
+    }
+
     /// Adds STP port.
     pub fn add_stp_port(
         &mut self,
@@ -329,6 +390,7 @@ impl StpOrch {
             .map_err(StpOrchError::SaiError)?;
 
         stp_port_ids.remove(&instance);
+        self.port_states.remove(&stp_port_oid);
         self.stats.ports_removed += 1;
 
         Ok(())
@@ -359,6 +421,7 @@ impl StpOrch {
             .set_stp_port_state(stp_port_oid, sai_state)
             .map_err(StpOrchError::SaiError)?;
 
+        self.port_states.insert(stp_port_oid, state);
         self.stats.state_updates += 1;
 
         audit_log!(AuditRecord::new(
@@ -398,6 +461,151 @@ impl StpOrch {
         Ok(())
     }
 
+    /// Programs the STP_PORT_STATE updates collected in one drain cycle.
+    ///
+    /// The last update per (port, instance) wins. Updates are grouped by
+    /// SAI state and programmed with one bulk call per state. Ports leaving
+    /// forwarding get one FDB flush each, scoped to the VLANs of the
+    /// instances they left forwarding in. Returns the updates that failed
+    /// and should be retried.
+    pub fn apply_port_states(
+        &mut self,
+        updates: Vec<StpPortStateUpdate>,
+        port_ids: &mut HashMap<String, StpPortIds>,
+    ) -> Result<Vec<StpPortStateUpdate>, StpOrchError> {
+        let callbacks = self
+            .callbacks
+            .clone()
+            .ok_or_else(|| StpOrchError::SaiError("No callbacks set".to_string()))?;
+
+        let mut latest: Vec<StpPortStateUpdate> = Vec::with_capacity(updates.len());
+        let mut index: HashMap<(String, u16), usize> = HashMap::new();
+        for update in updates {
+            let key = (update.port_alias.clone(), update.instance);
+            match index.get(&key) {
+                Some(&i) => latest[i] = update,
+                None => {
+                    index.insert(key, latest.len());
+                    latest.push(update);
+                }
+            }
+        }
+
+        let mut failed = Vec::new();
+        let mut groups: BTreeMap<SaiStpPortState, Vec<(RawSaiObjectId, StpPortStateUpdate)>> =
+            BTreeMap::new();
+        for update in latest {
+            let ids = port_ids.entry(update.port_alias.clone()).or_default();
+            let stp_port_oid = match ids.get(&update.instance) {
+                Some(oid) => *oid,
+                None => match self.add_stp_port(&update.port_alias, update.instance, ids) {
+                    Ok(oid) => oid,
+                    Err(e) => {
+                        self.audit_state_failure(&update, &e);
+                        failed.push(update);
+                        continue;
+                    }
+                },
+            };
+            groups
+                .entry(update.state.to_sai_state())
+                .or_default()
+                .push((stp_port_oid, update));
+        }
+
+        // Port -> VLANs to flush, for ports that left forwarding.
+        let mut flushes: BTreeMap<String, HashSet<String>> = BTreeMap::new();
+        for (sai_state, members) in groups {
+            let oids: Vec<RawSaiObjectId> = members.iter().map(|(oid, _)| *oid).collect();
+            let results = callbacks.set_stp_port_states(&oids, sai_state);
+            self.stats.bulk_state_calls += 1;
+
+            for (i, (stp_port_oid, update)) in members.into_iter().enumerate() {
+                let result = results
+                    .get(i)
+                    .cloned()
+                    .unwrap_or_else(|| Err("Missing bulk status".to_string()));
+                if let Err(e) = result {
+                    self.audit_state_failure(&update, &StpOrchError::SaiError(e));
+                    failed.push(update);
+                    continue;
+                }
+
+                let previous = self.port_states.insert(stp_port_oid, update.state);
+                self.stats.state_updates += 1;
+                let left_forwarding = previous
+                    .is_some_and(|prev| prev.to_sai_state() == SaiStpPortState::Forwarding)
+                    && sai_state != SaiStpPortState::Forwarding;
+                if left_forwarding {
+                    flushes
+                        .entry(update.port_alias.clone())
+                        .or_default()
+                        .extend(self.instance_vlans(update.instance));
+                }
+            }
+        }
+
+        for (port_alias, vlans) in flushes {
+            let mut vlans: Vec<String> = vlans.into_iter().collect();
+            vlans.sort();
+            if let Err(e) = self.flush_fdb_port(&port_alias, &vlans) {
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "StpOrch",
+                    "flush_fdb_port"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(port_alias)
+                .with_object_type("stp_port")
+                .with_error(e.to_string()));
+            }
+        }
+
+        Ok(failed)
+    }
+
+    /// Handles STP_INST_PORT_FLUSH_TABLE: flushes a port's FDB entries in
+    /// the VLANs of an instance.
+    pub fn flush_instance_port(
+        &mut self,
+        instance: u16,
+        port_alias: &str,
+    ) -> Result<(), StpOrchError> {
+        let vlans = self.instance_vlans(instance);
+        self.flush_fdb_port(port_alias, &vlans)
+    }
+
+    fn flush_fdb_port(&mut self, port_alias: &str, vlans: &[String]) -> Result<(), StpOrchError> {
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or_else(|| StpOrchError::SaiError("No callbacks set".to_string()))?;
+
+        callbacks
+            .flush_fdb_by_port(port_alias, vlans)
+            .map_err(StpOrchError::SaiError)?;
+
+        self.stats.fdb_flushes += 1;
+
+        Ok(())
+    }
+
+    fn audit_state_failure(&self, update: &StpPortStateUpdate, error: &StpOrchError) {
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "StpOrch",
+            "update_port_state"
+        )
+        .with_outcome(AuditOutcome::Failure)
+        .with_object_id(update.port_alias.clone())
+        .with_object_type("stp_port")
+        .with_error(error.to_string())
+        .with_details(serde_json::json!({
+            "instance": update.instance,
+            "state": update.state.as_str(),
+        })));
+    }
+
     /// Gets statistics.
     pub fn stats(&self) -> &StpOrchStats {
         &self.stats
@@ -418,6 +626,9 @@ mod tests {
         ports_ready: bool,
         created_instances: Mutex<Vec<RawSaiObjectId>>,
         created_ports: Mutex<Vec<(RawSaiObjectId, RawSaiObjectId, SaiStpPortState)>>,
+        bulk_calls: Mutex<Vec<(SaiStpPortState, Vec<RawSaiObjectId>)>>,
+        port_flushes: Mutex<Vec<(String, Vec<String>)>>,
+        failing_oids: Mutex<HashSet<RawSaiObjectId>>,
         next_oid: Mutex<RawSaiObjectId>,
     }
 
@@ -427,6 +638,9 @@ mod tests {
                 ports_ready: true,
                 created_instances: Mutex::new(Vec::new()),
                 created_ports: Mutex::new(Vec::new()),
+                bulk_calls: Mutex::new(Vec::new()),
+                port_flushes: Mutex::new(Vec::new()),
+                failing_oids: Mutex::new(HashSet::new()),
                 next_oid: Mutex::new(0x1000),
             }
         }
@@ -491,10 +705,44 @@ mod tests {
             Ok(())
         }
 
+        fn set_stp_port_states(
+            &self,
+            stp_port_oids: &[RawSaiObjectId],
+            state: SaiStpPortState,
+        ) -> Vec<Result<(), String>> {
+            self.bulk_calls
+                .lock()
+                .unwrap()
+                .push((state, stp_port_oids.to_vec()));
+            let failing = self.failing_oids.lock().unwrap();
+            stp_port_oids
+                .iter()
+                .map(|oid| {
+                    if failing.contains(oid) {
+                        Err("SAI_STATUS_FAILURE".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .collect()
+        }
+
         fn flush_fdb_by_vlan(&self, _vlan_alias: &str) -> Result<(), String> {
             Ok(())
         }
 
+        fn flush_fdb_by_port(
+            &self,
+            port_alias: &str,
+            vlan_aliases: &[String],
+        ) -> Result<(), String> {
+            self.port_flushes
+                .lock()
+                .unwrap()
+                .push((port_alias.to_string(), vlan_aliases.to_vec()));
+            Ok(())
+        }
+
         fn ensure_bridge_port(&self, _port_alias: &str) -> Result<RawSaiObjectId, String> {
             Ok(0x2000)
         }
@@ -1153,4 +1401,159 @@ mod tests {
         orch.remove_instance(3).unwrap();
         assert_eq!(orch.instance_count(), 1); // Back to default only
     }
+
+    // Batch Programming Tests
+
+    fn batch_orch() -> (StpOrch, Arc<TestCallbacks>) {
+        let mut orch = StpOrch::new(StpOrchConfig::default());
+        let callbacks = Arc::new(TestCallbacks::new());
+        orch.set_callbacks(callbacks.clone());
+        orch.initialize(0x100, 256);
+        orch.add_vlan_to_instance("Vlan100", 1).unwrap();
+        orch.add_vlan_to_instance("Vlan200", 2).unwrap();
+        (orch, callbacks)
+    }
+
+    #[test]
+    fn test_batch_groups_by_sai_state() {
+        let (mut orch, callbacks) = batch_orch();
+        let mut port_ids = HashMap::new();
+
+        let failed = orch
+            .apply_port_states(
+                vec![
+                    StpPortStateUpdate::new("Ethernet0", 1, StpState::Forwarding),
+                    StpPortStateUpdate::new("Ethernet4", 1, StpState::Listening),
+                    StpPortStateUpdate::new("Ethernet8", 1, StpState::Forwarding),
+                    StpPortStateUpdate::new("Ethernet4", 2, StpState::Blocking),
+                    StpPortStateUpdate::new("Ethernet12", 2, StpState::Learning),
+                    // Superseded within the same drain cycle.
+                    StpPortStateUpdate::new("Ethernet12", 2, StpState::Forwarding),
+                ],
+                &mut port_ids,
+            )
+            .unwrap();
+        assert!(failed.is_empty());
+
+        let bulk_calls = callbacks.bulk_calls.lock().unwrap();
+        let sizes: Vec<(SaiStpPortState, usize)> = bulk_calls
+            .iter()
+            .map(|(state, oids)| (*state, oids.len()))
+            .collect();
+        assert_eq!(
+            sizes,
+            vec![
+                (SaiStpPortState::Blocking, 2),
+                (SaiStpPortState::Forwarding, 3)
+            ]
+        );
+        assert_eq!(orch.stats().bulk_state_calls, 2);
+        assert_eq!(orch.stats().state_updates, 5);
+        assert_eq!(port_ids.len(), 4);
+        assert_eq!(port_ids["Ethernet4"].len(), 2);
+        // First programming never flushes.
+        assert!(callbacks.port_flushes.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_blocking_transition_flushes_once_per_port() {
+        let (mut orch, callbacks) = batch_orch();
+        let mut port_ids = HashMap::new();
+
+        orch.apply_port_states(
+            vec![
+                StpPortStateUpdate::new("Ethernet0", 1, StpState::Forwarding),
+                StpPortStateUpdate::new("Ethernet0", 2, StpState::Forwarding),
+                StpPortStateUpdate::new("Ethernet4", 1, StpState::Forwarding),
+                StpPortStateUpdate::new("Ethernet8", 1, StpState::Learning),
+            ],
+            &mut port_ids,
+        )
+        .unwrap();
+
+        orch.apply_port_states(
+            vec![
+                StpPortStateUpdate::new("Ethernet0", 1, StpState::Blocking),
+                StpPortStateUpdate::new("Ethernet0", 2, StpState::Disabled),
+                StpPortStateUpdate::new("Ethernet4", 1, StpState::Forwarding),
+                StpPortStateUpdate::new("Ethernet8", 1, StpState::Blocking),
+            ],
+            &mut port_ids,
+        )
+        .unwrap();
+
+        // Ethernet0 left forwarding in both instances: one flush covering
+        // both VLANs. Ethernet8 was never forwarding.
+        assert_eq!(
+            *callbacks.port_flushes.lock().unwrap(),
+            vec![(
+                "Ethernet0".to_string(),
+                vec!["Vlan100".to_string(), "Vlan200".to_string()]
+            )]
+        );
+        assert_eq!(orch.stats().fdb_flushes, 1);
+    }
+
+    #[test]
+    fn test_batch_returns_failed_updates() {
+        let (mut orch, callbacks) = batch_orch();
+        let mut port_ids = HashMap::new();
+
+        orch.apply_port_states(
+            vec![StpPortStateUpdate::new(
+                "Ethernet0",
+                1,
+                StpState::Forwarding,
+            )],
+            &mut port_ids,
+        )
+        .unwrap();
+        let oid = port_ids["Ethernet0"][&1];
+        callbacks.failing_oids.lock().unwrap().insert(oid);
+
+        let failed = orch
+            .apply_port_states(
+                vec![
+                    StpPortStateUpdate::new("Ethernet0", 1, StpState::Blocking),
+                    StpPortStateUpdate::new("Ethernet4", 1, StpState::Blocking),
+                    // Instance never created.
+                    StpPortStateUpdate::new("Ethernet8", 9, StpState::Blocking),
+                ],
+                &mut port_ids,
+            )
+            .unwrap();
+        assert_eq!(
+            failed,
+            vec![
+                StpPortStateUpdate::new("Ethernet8", 9, StpState::Blocking),
+                StpPortStateUpdate::new("Ethernet0", 1, StpState::Blocking),
+            ]
+        );
+        // Failed transition keeps the old state, so no flush yet.
+        assert!(callbacks.port_flushes.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_set_instance_vlans_moves_vlans() {
+        let (mut orch, callbacks) = batch_orch();
+
+        let vlans: HashSet<String> = ["Vlan200", "Vlan300"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        orch.set_instance_vlans(1, &vlans).unwrap();
+
+        assert_eq!(orch.instance_vlans(1), vec!["Vlan200", "Vlan300"]);
+        // Vlan200 moved out of instance 2.
+        assert!(orch.instance_vlans(2).is_empty());
+
+        orch.flush_instance_port(1, "Ethernet0").unwrap();
+        assert_eq!(
+            callbacks.port_flushes.lock().unwrap().last(),
+            Some(&(
+                "Ethernet0".to_string(),
+                vec!["Vlan200".to_string(), "Vlan300".to_string()]
+            ))
+        );
+    }
 }
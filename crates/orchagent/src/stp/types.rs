@@ -3,6 +3,14 @@
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::{HashMap, HashSet};
 
+/// Table names handled by StpOrch.
+pub mod tables {
+    pub const STP_VLAN_INSTANCE_TABLE: &str = "STP_VLAN_INSTANCE_TABLE";
+    pub const STP_PORT_STATE_TABLE: &str = "STP_PORT_STATE_TABLE";
+    pub const STP_FASTAGEING_FLUSH_TABLE: &str = "STP_FASTAGEING_FLUSH_TABLE";
+    pub const STP_INST_PORT_FLUSH_TABLE: &str = "STP_INST_PORT_FLUSH_TABLE";
+}
+
 /// STP port state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -49,7 +57,7 @@ impl StpState {
 }
 
 /// SAI STP port states (subset used by SAI).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SaiStpPortState {
     Blocking,
     Learning,
@@ -93,6 +101,43 @@ impl StpInstanceEntry {
 /// STP port identifiers map: instance ID → STP port OID.
 pub type StpPortIds = HashMap<u16, RawSaiObjectId>;
 
+/// One STP_PORT_STATE_TABLE update, keyed `<port>:<instance>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StpPortStateUpdate {
+    pub port_alias: String,
+    pub instance: u16,
+    pub state: StpState,
+}
+
+impl StpPortStateUpdate {
+    pub fn new(port_alias: impl Into<String>, instance: u16, state: StpState) -> Self {
+        Self {
+            port_alias: port_alias.into(),
+            instance,
+            state,
+        }
+    }
+
+    /// Parses an APPL_DB key and its `state` field.
+    pub fn from_appl(key: &str, fields: &[(String, String)]) -> Result<Self, String> {
+        let (port_alias, instance) = key
+            .rsplit_once(':')
+            .filter(|(port, _)| !port.is_empty())
+            .ok_or_else(|| format!("Invalid STP port state key: {}", key))?;
+        let instance = instance
+            .parse::<u16>()
+            .map_err(|_| format!("Invalid STP instance: {}", instance))?;
+        let state = fields
+            .iter()
+            .find(|(field, _)| field == "state")
+            .ok_or_else(|| format!("{}: missing state", key))
+            .and_then(|(_, value)| {
+                StpState::parse(value).ok_or_else(|| format!("Invalid STP state: {}", value))
+            })?;
+        Ok(Self::new(port_alias, instance, state))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,6 +168,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_port_state_update_from_appl() {
+        let fields = vec![("state".to_string(), "4".to_string())];
+        let update = StpPortStateUpdate::from_appl("Ethernet0:1", &fields).unwrap();
+        assert_eq!(
+            update,
+            StpPortStateUpdate::new("Ethernet0", 1, StpState::Forwarding)
+        );
+
+        assert!(StpPortStateUpdate::from_appl("Ethernet0", &fields).is_err());
+        assert!(StpPortStateUpdate::from_appl("Ethernet0:x", &fields).is_err());
+        assert!(StpPortStateUpdate::from_appl("Ethernet0:1", &[]).is_err());
+        let bad = vec![("state".to_string(), "9".to_string())];
+        assert!(StpPortStateUpdate::from_appl("Ethernet0:1", &bad).is_err());
+    }
+
     #[test]
     fn test_stp_instance_entry() {
         let mut entry = StpInstanceEntry::new(0x1234);
@@ -1,7 +1,8 @@
 //! FFI exports for SwitchOrch.
 
 use super::orch::{Result, SwitchOrch, SwitchOrchCallbacks, SwitchOrchConfig};
-use super::types::{SwitchCapabilities, SwitchHashConfig, SwitchState};
+use super::types::{RawSaiObjectId, SwitchCapabilities, SwitchHashConfig, SwitchState};
+use sonic_sai::api::NativeHashField;
 use std::cell::RefCell;
 
 /// FFI stub callbacks that do nothing (for C++ interop).
@@ -16,6 +17,22 @@ impl SwitchOrchCallbacks for FfiSwitchCallbacks {
         Ok(())
     }
 
+    fn create_hash(&self, _is_ecmp: bool, _fields: &[NativeHashField]) -> Result<RawSaiObjectId> {
+        Ok(0)
+    }
+
+    fn set_hash_fields(
+        &self,
+        _hash_oid: RawSaiObjectId,
+        _fields: &[NativeHashField],
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_hash_seed(&self, _is_ecmp: bool, _seed: u32) -> Result<()> {
+        Ok(())
+    }
+
     fn get_capabilities(&self) -> Result<SwitchCapabilities> {
         Ok(SwitchCapabilities::default())
     }
@@ -1,10 +1,12 @@
 //! Switch orchestration logic.
 
 use super::types::{
-    RawSaiObjectId, SwitchCapabilities, SwitchConfig, SwitchHashConfig, SwitchState,
+    RawSaiObjectId, SwitchCapabilities, SwitchConfig, SwitchHashAlgorithm, SwitchHashConfig,
+    SwitchHashField, SwitchState,
 };
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::{audit_log, debug_log, error_log, info_log, security_audit, warn_log};
+use sonic_sai::api::NativeHashField;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -25,6 +27,10 @@ pub enum SwitchOrchError {
     #[error("Invalid hash field: {0}")]
     InvalidHashField(String),
 
+    /// Hash field not supported by the switch
+    #[error("Unsupported hash field: {0}")]
+    UnsupportedHashField(String),
+
     /// SAI operation failed
     #[error("SAI operation failed: {0}")]
     SaiError(String),
@@ -55,6 +61,9 @@ pub struct SwitchOrchStats {
 pub trait SwitchOrchCallbacks: Send + Sync {
     fn initialize_switch(&self, capabilities: &SwitchCapabilities) -> Result<SwitchState>;
     fn set_hash_algorithm(&self, is_ecmp: bool, config: &SwitchHashConfig) -> Result<()>;
+    fn create_hash(&self, is_ecmp: bool, fields: &[NativeHashField]) -> Result<RawSaiObjectId>;
+    fn set_hash_fields(&self, hash_oid: RawSaiObjectId, fields: &[NativeHashField]) -> Result<()>;
+    fn set_hash_seed(&self, is_ecmp: bool, seed: u32) -> Result<()>;
     fn get_capabilities(&self) -> Result<SwitchCapabilities>;
     fn set_switch_attribute(&self, attr_name: &str, attr_value: &str) -> Result<()>;
     fn get_switch_attribute(&self, attr_name: &str) -> Result<String>;
@@ -69,6 +78,8 @@ pub struct SwitchOrch<C: SwitchOrchCallbacks> {
     stats: SwitchOrchStats,
     state: Option<SwitchState>,
     switch_config: SwitchConfig,
+    ecmp_hash_oid: Option<RawSaiObjectId>,
+    lag_hash_oid: Option<RawSaiObjectId>,
     callbacks: Option<Arc<C>>,
 }
 
@@ -79,6 +90,8 @@ impl<C: SwitchOrchCallbacks> SwitchOrch<C> {
             stats: SwitchOrchStats::default(),
             state: None,
             switch_config: SwitchConfig::default(),
+            ecmp_hash_oid: None,
+            lag_hash_oid: None,
             callbacks: None,
         }
     }
@@ -439,6 +452,190 @@ impl<C: SwitchOrchCallbacks> SwitchOrch<C> {
         &self.switch_config.lag_hash
     }
 
+    pub fn get_hash_oid(&self, is_ecmp: bool) -> Option<RawSaiObjectId> {
+        if is_ecmp {
+            self.ecmp_hash_oid
+        } else {
+            self.lag_hash_oid
+        }
+    }
+
+    /// Handles a SWITCH_HASH table entry.
+    ///
+    /// Recognized fields are `ecmp_hash`/`lag_hash` (comma-separated field
+    /// names), `ecmp_hash_algorithm`/`lag_hash_algorithm` and
+    /// `ecmp_hash_seed`/`lag_hash_seed`. Invalid or unsupported values are
+    /// skipped and returned per field; the remaining values are applied.
+    pub fn handle_switch_hash(&mut self, fvs: &[(String, String)]) -> Result<Vec<SwitchOrchError>> {
+        if self.state.is_none() {
+            error_log!(
+                "SwitchOrch",
+                "Cannot set switch hash: switch not initialized"
+            );
+            return Err(SwitchOrchError::NotInitialized);
+        }
+
+        let mut errors = Vec::new();
+        for is_ecmp in [true, false] {
+            let prefix = if is_ecmp { "ecmp" } else { "lag" };
+            let mut fields = None;
+            let mut algorithm = None;
+            let mut seed = None;
+
+            for (field, value) in fvs {
+                match field.strip_prefix(prefix) {
+                    Some("_hash") => fields = Some(self.parse_hash_fields(value, &mut errors)),
+                    Some("_hash_algorithm") => match self.parse_hash_algorithm(value) {
+                        Ok(parsed) => algorithm = Some(parsed),
+                        Err(e) => errors.push(e),
+                    },
+                    Some("_hash_seed") => match value.parse::<u32>() {
+                        Ok(parsed) => seed = Some(parsed),
+                        Err(_) => errors.push(SwitchOrchError::ConfigurationError(format!(
+                            "Invalid {}: {}",
+                            field, value
+                        ))),
+                    },
+                    _ => {}
+                }
+            }
+
+            self.apply_hash(is_ecmp, fields, algorithm, seed)?;
+        }
+
+        for e in &errors {
+            warn_log!("SwitchOrch", error = %e, "Skipped invalid switch hash value");
+        }
+        Ok(errors)
+    }
+
+    fn parse_hash_fields(
+        &self,
+        value: &str,
+        errors: &mut Vec<SwitchOrchError>,
+    ) -> Vec<SwitchHashField> {
+        let supported = self
+            .state
+            .as_ref()
+            .map(|s| s.capabilities.supported_hash_fields.as_slice())
+            .unwrap_or_default();
+
+        let mut fields = Vec::new();
+        for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            match SwitchHashField::parse(name) {
+                Some(field) if !supported.contains(&field) => {
+                    errors.push(SwitchOrchError::UnsupportedHashField(name.to_string()))
+                }
+                Some(field) => {
+                    if !fields.contains(&field) {
+                        fields.push(field);
+                    }
+                }
+                None => errors.push(SwitchOrchError::InvalidHashField(name.to_string())),
+            }
+        }
+        fields
+    }
+
+    fn parse_hash_algorithm(&self, value: &str) -> Result<SwitchHashAlgorithm> {
+        let algorithm = SwitchHashAlgorithm::parse(value)
+            .ok_or_else(|| SwitchOrchError::InvalidHashAlgorithm(value.to_string()))?;
+        let supported = self.state.as_ref().is_some_and(|s| {
+            s.capabilities
+                .supported_hash_algorithms
+                .contains(&algorithm)
+        });
+        if !supported {
+            return Err(SwitchOrchError::InvalidHashAlgorithm(value.to_string()));
+        }
+        Ok(algorithm)
+    }
+
+    /// Programs the changed parts of the ECMP or LAG hash.
+    ///
+    /// The hash object is created on first use and its field list updated in
+    /// place afterwards; algorithm and seed are switch attributes and never
+    /// touch the hash object.
+    fn apply_hash(
+        &mut self,
+        is_ecmp: bool,
+        fields: Option<Vec<SwitchHashField>>,
+        algorithm: Option<SwitchHashAlgorithm>,
+        seed: Option<u32>,
+    ) -> Result<()> {
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or(SwitchOrchError::NotInitialized)?;
+        let (current, hash_oid) = if is_ecmp {
+            (&mut self.switch_config.ecmp_hash, &mut self.ecmp_hash_oid)
+        } else {
+            (&mut self.switch_config.lag_hash, &mut self.lag_hash_oid)
+        };
+        let object_type = if is_ecmp { "ecmp_hash" } else { "lag_hash" };
+        let mut updated = false;
+
+        let fields = fields.filter(|f| !f.is_empty());
+        if let Some(fields) = fields {
+            if hash_oid.is_none() || current.fields != fields {
+                let sai_fields: Vec<_> = fields.iter().map(|f| f.to_sai()).collect();
+                let result = match *hash_oid {
+                    Some(oid) => callbacks.set_hash_fields(oid, &sai_fields),
+                    None => callbacks
+                        .create_hash(is_ecmp, &sai_fields)
+                        .map(|oid| *hash_oid = Some(oid)),
+                };
+                if let Err(e) = result {
+                    error_log!("SwitchOrch", error = %e, "SAI hash field update failed");
+                    audit_log!(AuditRecord::new(
+                        AuditCategory::SaiOperation,
+                        "SwitchOrch",
+                        "set_hash_fields"
+                    )
+                    .with_object_type(object_type)
+                    .with_error(e.to_string()));
+                    return Err(e);
+                }
+                current.fields = fields;
+                updated = true;
+            }
+        }
+
+        if let Some(algorithm) = algorithm.filter(|a| *a != current.algorithm) {
+            let mut config = current.clone();
+            config.algorithm = algorithm;
+            callbacks.set_hash_algorithm(is_ecmp, &config)?;
+            current.algorithm = algorithm;
+            updated = true;
+        }
+
+        if let Some(seed) = seed.filter(|s| *s != current.seed) {
+            callbacks.set_hash_seed(is_ecmp, seed)?;
+            current.seed = seed;
+            updated = true;
+        }
+
+        if updated {
+            self.stats.hash_updates += 1;
+            callbacks.on_hash_updated(is_ecmp);
+
+            audit_log!(AuditRecord::new(
+                AuditCategory::ConfigurationChange,
+                "SwitchOrch",
+                "set_switch_hash"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_type(object_type)
+            .with_details(serde_json::json!({
+                "algorithm": current.algorithm.as_str(),
+                "seed": current.seed,
+                "fields": current.fields.iter().map(SwitchHashField::as_str).collect::<Vec<_>>()
+            })));
+        }
+
+        Ok(())
+    }
+
     pub fn stats(&self) -> &SwitchOrchStats {
         &self.stats
     }
@@ -470,6 +667,22 @@ mod tests {
             Ok(())
         }
 
+        fn create_hash(
+            &self,
+            _is_ecmp: bool,
+            _fields: &[NativeHashField],
+        ) -> Result<RawSaiObjectId> {
+            Ok(0x1000)
+        }
+
+        fn set_hash_fields(&self, _oid: RawSaiObjectId, _fields: &[NativeHashField]) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_hash_seed(&self, _is_ecmp: bool, _seed: u32) -> Result<()> {
+            Ok(())
+        }
+
         fn get_capabilities(&self) -> Result<SwitchCapabilities> {
             Ok(SwitchCapabilities::default())
         }
@@ -677,4 +890,201 @@ mod tests {
         assert_eq!(err1.to_string(), "Switch orchestrator not initialized");
         assert_eq!(err6.to_string(), "Switch already initialized");
     }
+
+    #[derive(Default)]
+    struct HashRecordingCallbacks {
+        unsupported_fields: Vec<SwitchHashField>,
+        created_hashes: std::sync::Mutex<Vec<(bool, Vec<NativeHashField>)>>,
+        hash_field_updates: std::sync::Mutex<Vec<(RawSaiObjectId, Vec<NativeHashField>)>>,
+        seeds: std::sync::Mutex<Vec<(bool, u32)>>,
+        algorithms: std::sync::Mutex<Vec<(bool, SwitchHashAlgorithm)>>,
+    }
+
+    impl SwitchOrchCallbacks for HashRecordingCallbacks {
+        fn initialize_switch(&self, _caps: &SwitchCapabilities) -> Result<SwitchState> {
+            Ok(SwitchState::default())
+        }
+
+        fn set_hash_algorithm(&self, is_ecmp: bool, config: &SwitchHashConfig) -> Result<()> {
+            self.algorithms
+                .lock()
+                .unwrap()
+                .push((is_ecmp, config.algorithm));
+            Ok(())
+        }
+
+        fn create_hash(&self, is_ecmp: bool, fields: &[NativeHashField]) -> Result<RawSaiObjectId> {
+            let mut created = self.created_hashes.lock().unwrap();
+            created.push((is_ecmp, fields.to_vec()));
+            Ok(0x1000 + created.len() as RawSaiObjectId)
+        }
+
+        fn set_hash_fields(&self, oid: RawSaiObjectId, fields: &[NativeHashField]) -> Result<()> {
+            self.hash_field_updates
+                .lock()
+                .unwrap()
+                .push((oid, fields.to_vec()));
+            Ok(())
+        }
+
+        fn set_hash_seed(&self, is_ecmp: bool, seed: u32) -> Result<()> {
+            self.seeds.lock().unwrap().push((is_ecmp, seed));
+            Ok(())
+        }
+
+        fn get_capabilities(&self) -> Result<SwitchCapabilities> {
+            let mut caps = SwitchCapabilities::default();
+            caps.supported_hash_fields
+                .retain(|f| !self.unsupported_fields.contains(f));
+            Ok(caps)
+        }
+
+        fn set_switch_attribute(&self, _name: &str, _value: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_switch_attribute(&self, _name: &str) -> Result<String> {
+            Ok(String::new())
+        }
+
+        fn on_switch_initialized(&self, _state: &SwitchState) {}
+        fn on_hash_updated(&self, _is_ecmp: bool) {}
+        fn on_warm_restart_begin(&self) {}
+        fn on_warm_restart_end(&self, _success: bool) {}
+    }
+
+    fn hash_orch(
+        callbacks: HashRecordingCallbacks,
+    ) -> (
+        SwitchOrch<HashRecordingCallbacks>,
+        Arc<HashRecordingCallbacks>,
+    ) {
+        let callbacks = Arc::new(callbacks);
+        let mut orch =
+            SwitchOrch::new(SwitchOrchConfig::default()).with_callbacks(callbacks.clone());
+        orch.initialize().unwrap();
+        (orch, callbacks)
+    }
+
+    fn fv(field: &str, value: &str) -> (String, String) {
+        (field.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_switch_hash_field_update() {
+        let (mut orch, callbacks) = hash_orch(HashRecordingCallbacks::default());
+
+        let errors = orch
+            .handle_switch_hash(&[fv("ecmp_hash", "SRC_IP,DST_IP,L4_SRC_PORT")])
+            .unwrap();
+        assert!(errors.is_empty());
+        let hash_oid = orch.get_hash_oid(true).unwrap();
+        assert!(orch.get_hash_oid(false).is_none());
+
+        // Drop L4_SRC_PORT, add INNER_SRC_IP
+        let errors = orch
+            .handle_switch_hash(&[fv("ecmp_hash", "src_ip,dst_ip,inner_src_ip")])
+            .unwrap();
+        assert!(errors.is_empty());
+
+        assert_eq!(
+            orch.get_ecmp_hash().fields,
+            vec![
+                SwitchHashField::SrcIp,
+                SwitchHashField::DstIp,
+                SwitchHashField::InnerSrcIp
+            ]
+        );
+        assert_eq!(orch.get_hash_oid(true), Some(hash_oid));
+        assert_eq!(callbacks.created_hashes.lock().unwrap().len(), 1);
+        assert_eq!(
+            *callbacks.hash_field_updates.lock().unwrap(),
+            vec![(
+                hash_oid,
+                vec![
+                    NativeHashField::SrcIp,
+                    NativeHashField::DstIp,
+                    NativeHashField::InnerSrcIp
+                ]
+            )]
+        );
+        assert_eq!(orch.stats().hash_updates, 2);
+    }
+
+    #[test]
+    fn test_switch_hash_seed_only_keeps_hash_object() {
+        let (mut orch, callbacks) = hash_orch(HashRecordingCallbacks::default());
+
+        orch.handle_switch_hash(&[
+            fv("lag_hash", "SRC_MAC,DST_MAC"),
+            fv("lag_hash_algorithm", "XOR"),
+            fv("lag_hash_seed", "10"),
+        ])
+        .unwrap();
+        let hash_oid = orch.get_hash_oid(false).unwrap();
+
+        orch.handle_switch_hash(&[
+            fv("lag_hash", "SRC_MAC,DST_MAC"),
+            fv("lag_hash_algorithm", "XOR"),
+            fv("lag_hash_seed", "20"),
+        ])
+        .unwrap();
+
+        assert_eq!(orch.get_hash_oid(false), Some(hash_oid));
+        assert_eq!(callbacks.created_hashes.lock().unwrap().len(), 1);
+        assert!(callbacks.hash_field_updates.lock().unwrap().is_empty());
+        assert_eq!(callbacks.algorithms.lock().unwrap().len(), 1);
+        assert_eq!(
+            *callbacks.seeds.lock().unwrap(),
+            vec![(false, 10), (false, 20)]
+        );
+        assert_eq!(orch.get_lag_hash().seed, 20);
+        assert_eq!(orch.get_lag_hash().algorithm, SwitchHashAlgorithm::Xor);
+    }
+
+    #[test]
+    fn test_switch_hash_per_field_errors() {
+        let (mut orch, callbacks) = hash_orch(HashRecordingCallbacks {
+            unsupported_fields: vec![SwitchHashField::InnerL4DstPort],
+            ..Default::default()
+        });
+
+        let errors = orch
+            .handle_switch_hash(&[
+                fv("ecmp_hash", "SRC_IP,BOGUS,INNER_L4_DST_PORT,DST_IP"),
+                fv("ecmp_hash_algorithm", "CRC_CCITT"),
+                fv("ecmp_hash_seed", "abc"),
+            ])
+            .unwrap();
+
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(&errors[0], SwitchOrchError::InvalidHashField(f) if f == "BOGUS"));
+        assert!(
+            matches!(&errors[1], SwitchOrchError::UnsupportedHashField(f) if f == "INNER_L4_DST_PORT")
+        );
+        assert!(matches!(
+            &errors[2],
+            SwitchOrchError::InvalidHashAlgorithm(_)
+        ));
+        assert!(matches!(&errors[3], SwitchOrchError::ConfigurationError(_)));
+
+        // Valid fields are still applied
+        assert_eq!(
+            callbacks.created_hashes.lock().unwrap()[0],
+            (true, vec![NativeHashField::SrcIp, NativeHashField::DstIp])
+        );
+        assert_eq!(orch.get_ecmp_hash().algorithm, SwitchHashAlgorithm::Crc);
+        assert!(callbacks.seeds.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_switch_hash_requires_initialization() {
+        let mut orch: SwitchOrch<HashRecordingCallbacks> =
+            SwitchOrch::new(SwitchOrchConfig::default())
+                .with_callbacks(Arc::new(HashRecordingCallbacks::default()));
+        assert!(matches!(
+            orch.handle_switch_hash(&[fv("ecmp_hash", "SRC_IP")]),
+            Err(SwitchOrchError::NotInitialized)
+        ));
+    }
 }
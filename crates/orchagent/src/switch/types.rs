@@ -1,5 +1,6 @@
 //! Switch-level configuration and capability types.
 
+use sonic_sai::api::{HashAlgorithm, NativeHashField};
 use std::collections::HashMap;
 
 pub type RawSaiObjectId = u64;
@@ -13,6 +14,40 @@ pub enum SwitchHashAlgorithm {
     Crc32,
 }
 
+impl SwitchHashAlgorithm {
+    /// Parses a SWITCH_HASH algorithm value (e.g. "CRC", "CRC_32LO").
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "CRC" => Some(Self::Crc),
+            "XOR" => Some(Self::Xor),
+            "RANDOM" => Some(Self::Random),
+            "CRC_CCITT" => Some(Self::CrcCcitt),
+            "CRC_32LO" => Some(Self::Crc32),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Crc => "CRC",
+            Self::Xor => "XOR",
+            Self::Random => "RANDOM",
+            Self::CrcCcitt => "CRC_CCITT",
+            Self::Crc32 => "CRC_32LO",
+        }
+    }
+
+    pub fn to_sai(self) -> HashAlgorithm {
+        match self {
+            Self::Crc => HashAlgorithm::Crc,
+            Self::Xor => HashAlgorithm::Xor,
+            Self::Random => HashAlgorithm::Random,
+            Self::CrcCcitt => HashAlgorithm::CrcCcitt,
+            Self::Crc32 => HashAlgorithm::Crc32Lo,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SwitchHashField {
     SrcMac,
@@ -23,6 +58,92 @@ pub enum SwitchHashField {
     L4DstPort,
     IpProtocol,
     InPort,
+    EtherType,
+    VlanId,
+    InnerSrcMac,
+    InnerDstMac,
+    InnerSrcIp,
+    InnerDstIp,
+    InnerL4SrcPort,
+    InnerL4DstPort,
+    InnerIpProtocol,
+    InnerEtherType,
+}
+
+impl SwitchHashField {
+    pub const ALL: [SwitchHashField; 18] = [
+        Self::SrcMac,
+        Self::DstMac,
+        Self::SrcIp,
+        Self::DstIp,
+        Self::L4SrcPort,
+        Self::L4DstPort,
+        Self::IpProtocol,
+        Self::InPort,
+        Self::EtherType,
+        Self::VlanId,
+        Self::InnerSrcMac,
+        Self::InnerDstMac,
+        Self::InnerSrcIp,
+        Self::InnerDstIp,
+        Self::InnerL4SrcPort,
+        Self::InnerL4DstPort,
+        Self::InnerIpProtocol,
+        Self::InnerEtherType,
+    ];
+
+    /// Parses a SWITCH_HASH field name (e.g. "SRC_IP", "INNER_L4_DST_PORT").
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str().eq_ignore_ascii_case(s.trim()))
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SrcMac => "SRC_MAC",
+            Self::DstMac => "DST_MAC",
+            Self::SrcIp => "SRC_IP",
+            Self::DstIp => "DST_IP",
+            Self::L4SrcPort => "L4_SRC_PORT",
+            Self::L4DstPort => "L4_DST_PORT",
+            Self::IpProtocol => "IP_PROTOCOL",
+            Self::InPort => "IN_PORT",
+            Self::EtherType => "ETHERTYPE",
+            Self::VlanId => "VLAN_ID",
+            Self::InnerSrcMac => "INNER_SRC_MAC",
+            Self::InnerDstMac => "INNER_DST_MAC",
+            Self::InnerSrcIp => "INNER_SRC_IP",
+            Self::InnerDstIp => "INNER_DST_IP",
+            Self::InnerL4SrcPort => "INNER_L4_SRC_PORT",
+            Self::InnerL4DstPort => "INNER_L4_DST_PORT",
+            Self::InnerIpProtocol => "INNER_IP_PROTOCOL",
+            Self::InnerEtherType => "INNER_ETHERTYPE",
+        }
+    }
+
+    pub fn to_sai(self) -> NativeHashField {
+        match self {
+            Self::SrcMac => NativeHashField::SrcMac,
+            Self::DstMac => NativeHashField::DstMac,
+            Self::SrcIp => NativeHashField::SrcIp,
+            Self::DstIp => NativeHashField::DstIp,
+            Self::L4SrcPort => NativeHashField::L4SrcPort,
+            Self::L4DstPort => NativeHashField::L4DstPort,
+            Self::IpProtocol => NativeHashField::IpProtocol,
+            Self::InPort => NativeHashField::InPort,
+            Self::EtherType => NativeHashField::EtherType,
+            Self::VlanId => NativeHashField::VlanId,
+            Self::InnerSrcMac => NativeHashField::InnerSrcMac,
+            Self::InnerDstMac => NativeHashField::InnerDstMac,
+            Self::InnerSrcIp => NativeHashField::InnerSrcIp,
+            Self::InnerDstIp => NativeHashField::InnerDstIp,
+            Self::InnerL4SrcPort => NativeHashField::InnerL4SrcPort,
+            Self::InnerL4DstPort => NativeHashField::InnerL4DstPort,
+            Self::InnerIpProtocol => NativeHashField::InnerIpProtocol,
+            Self::InnerEtherType => NativeHashField::InnerEtherType,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +180,7 @@ pub struct SwitchCapabilities {
     pub max_acl_tables: u32,
     pub max_acl_entries: u32,
     pub supported_hash_algorithms: Vec<SwitchHashAlgorithm>,
+    pub supported_hash_fields: Vec<SwitchHashField>,
 }
 
 impl Default for SwitchCapabilities {
@@ -77,6 +199,7 @@ impl Default for SwitchCapabilities {
                 SwitchHashAlgorithm::Xor,
                 SwitchHashAlgorithm::Random,
             ],
+            supported_hash_fields: SwitchHashField::ALL.to_vec(),
         }
     }
 }
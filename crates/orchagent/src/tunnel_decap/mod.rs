@@ -18,6 +18,7 @@
 //! - Validated reference counting (cannot underflow)
 //! - Transactional updates with rollback
 //! - Bounds-checked string parsing
+//! - Term entry replacement that removes the old SAI OID before creating the new one
 
 mod ffi;
 mod orch;
@@ -29,6 +30,7 @@ pub use orch::{
     TunnelDecapOrchStats,
 };
 pub use types::{
-    EcnMode, NexthopTunnel, SubnetType, TunnelConfig, TunnelDecapConfig, TunnelDecapEntry,
-    TunnelEntry, TunnelMode, TunnelTermEntry, TunnelTermType,
+    tables, EcnMode, NexthopTunnel, SubnetType, TermPrefix, TunnelConfig, TunnelDecapConfig,
+    TunnelDecapEntry, TunnelDecapTermConfig, TunnelDecapTermEntry, TunnelEntry, TunnelMode,
+    TunnelTermEntry, TunnelTermType,
 };
@@ -1,11 +1,17 @@
 //! Tunnel decapsulation orchestration logic.
 
-use super::types::{TunnelDecapConfig, TunnelDecapEntry, TunnelTermType};
+use super::types::{
+    TermPrefix, TunnelDecapConfig, TunnelDecapEntry, TunnelDecapTermConfig, TunnelDecapTermEntry,
+    TunnelTermType,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use crate::prefix_set::PrefixSet;
+use sonic_orch_common::TaskStatus;
 use sonic_sai::types::RawSaiObjectId;
 use sonic_types::IpAddress;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -35,6 +41,7 @@ pub struct TunnelDecapOrchStats {
     pub tunnels_removed: u64,
     pub term_entries_created: u64,
     pub term_entries_removed: u64,
+    pub term_entries_updated: u64,
 }
 
 pub trait TunnelDecapOrchCallbacks: Send + Sync {
@@ -48,6 +55,14 @@ pub trait TunnelDecapOrchCallbacks: Send + Sync {
         dst_ip: IpAddress,
     ) -> Result<RawSaiObjectId, String>;
     fn remove_tunnel_term_entry(&self, term_entry_id: RawSaiObjectId) -> Result<(), String>;
+    /// Creates a term entry for a TUNNEL_DECAP_TERM_TABLE entry; `priority`
+    /// orders entries whose destination subnets overlap.
+    fn create_decap_term_entry(
+        &self,
+        tunnel_id: RawSaiObjectId,
+        config: &TunnelDecapTermConfig,
+        priority: u32,
+    ) -> Result<RawSaiObjectId, String>;
 }
 
 pub struct TunnelDecapOrch {
@@ -55,6 +70,9 @@ pub struct TunnelDecapOrch {
     stats: TunnelDecapOrchStats,
     callbacks: Option<Arc<dyn TunnelDecapOrchCallbacks>>,
     tunnels: HashMap<String, TunnelDecapEntry>,
+    /// Decap term destinations, each mapped to the `(tunnel, dst_key)`
+    /// entries that terminate it.
+    term_prefixes: PrefixSet<Vec<(String, String)>>,
 }
 
 impl TunnelDecapOrch {
@@ -64,6 +82,7 @@ impl TunnelDecapOrch {
             stats: TunnelDecapOrchStats::default(),
             callbacks: None,
             tunnels: HashMap::new(),
+            term_prefixes: PrefixSet::new(),
         }
     }
 
@@ -137,11 +156,11 @@ impl TunnelDecapOrch {
             .get(tunnel_name)
             .ok_or_else(|| TunnelDecapOrchError::TunnelNotFound(tunnel_name.to_string()))?;
 
-        if !entry.term_entries.is_empty() {
+        let term_count = entry.term_entries.len() + entry.decap_terms.len();
+        if term_count > 0 {
             let error = TunnelDecapOrchError::InvalidConfig(format!(
                 "Tunnel {} has {} term entries, remove them first",
-                tunnel_name,
-                entry.term_entries.len()
+                tunnel_name, term_count
             ));
             audit_log!(AuditRecord::new(
                 AuditCategory::ResourceDelete,
@@ -294,12 +313,267 @@ impl TunnelDecapOrch {
 
         Ok(())
     }
+    pub fn get_decap_term(&self, tunnel_name: &str, dst: &str) -> Option<&TunnelDecapTermEntry> {
+        self.tunnels
+            .get(tunnel_name)
+            .and_then(|tunnel| tunnel.decap_terms.get(dst))
+    }
+
+    pub fn decap_term_count(&self) -> usize {
+        self.tunnels
+            .values()
+            .map(|tunnel| tunnel.decap_terms.len())
+            .sum()
+    }
+
+    /// Returns the tunnel and term entry that decapsulate packets sent to
+    /// `dst`, choosing the highest priority among overlapping subnets.
+    pub fn lookup_decap_term(&self, dst: &IpAddr) -> Option<(&str, &TunnelDecapTermEntry)> {
+        let (_, owners) = self.term_prefixes.longest_match(*dst)?;
+        owners
+            .iter()
+            .filter_map(|(tunnel_name, dst_key)| {
+                let tunnel = self.tunnels.get(tunnel_name)?;
+                let term = tunnel.decap_terms.get(dst_key)?;
+                Some((tunnel.tunnel_name.as_str(), term))
+            })
+            .max_by_key(|(_, term)| term.priority)
+    }
+
+    /// Handles a TUNNEL_DECAP_TERM_TABLE SET for key `<tunnel>|<dst_ip>`.
+    ///
+    /// Waits for the tunnel to be created. Any change to an existing entry
+    /// (typically its src_ip) replaces the SAI term entry, since its match
+    /// fields are create-only; the old OID is removed before the new one is
+    /// created and restored if creation fails.
+    pub fn handle_decap_term_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, TunnelDecapOrchError> {
+        let mut config =
+            TunnelDecapTermConfig::from_key(key).map_err(TunnelDecapOrchError::InvalidConfig)?;
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(TunnelDecapOrchError::InvalidConfig)?;
+        }
+        config
+            .validate()
+            .map_err(TunnelDecapOrchError::InvalidConfig)?;
+        let dst_key = Self::decap_term_dst(key);
+
+        let overlaps = self.term_prefixes.overlaps(config.dst.addr, config.dst.len);
+        if overlaps && self.term_conflicts(&config, dst_key) {
+            let error = TunnelDecapOrchError::TermEntryExists(config.dst.to_string());
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceCreate,
+                "TunnelDecapOrch",
+                "add_decap_term"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(key)
+            .with_object_type("tunnel_term_entry")
+            .with_error(error.to_string()));
+            return Err(error);
+        }
+
+        let Some(tunnel) = self.tunnels.get(&config.tunnel_name) else {
+            return Ok(TaskStatus::NeedRetry);
+        };
+        let tunnel_id = tunnel.tunnel_id;
+        let existing = tunnel.decap_terms.get(dst_key);
+        if existing.is_some_and(|term| term.config == config) {
+            return Ok(TaskStatus::Success);
+        }
+        let old = existing.cloned();
+
+        let callbacks =
+            Arc::clone(self.callbacks.as_ref().ok_or_else(|| {
+                TunnelDecapOrchError::InvalidConfig("No callbacks set".to_string())
+            })?);
+
+        if let Some(old) = &old {
+            callbacks
+                .remove_tunnel_term_entry(old.term_id)
+                .map_err(TunnelDecapOrchError::SaiError)?;
+        }
+
+        let priority = config.priority();
+        let term_id = match callbacks.create_decap_term_entry(tunnel_id, &config, priority) {
+            Ok(term_id) => term_id,
+            Err(e) => {
+                let tunnel = self
+                    .tunnels
+                    .get_mut(&config.tunnel_name)
+                    .expect("tunnel checked above");
+                match old.and_then(|mut old| {
+                    old.term_id = callbacks
+                        .create_decap_term_entry(tunnel_id, &old.config, old.priority)
+                        .ok()?;
+                    Some(old)
+                }) {
+                    Some(restored) => {
+                        tunnel.decap_terms.insert(dst_key.to_string(), restored);
+                    }
+                    None => {
+                        tunnel.decap_terms.remove(dst_key);
+                        Self::unindex_term(
+                            &mut self.term_prefixes,
+                            &config.dst,
+                            &config.tunnel_name,
+                            dst_key,
+                        );
+                    }
+                }
+                return Err(TunnelDecapOrchError::SaiError(e));
+            }
+        };
+
+        let tunnel = self
+            .tunnels
+            .get_mut(&config.tunnel_name)
+            .expect("tunnel checked above");
+        tunnel.decap_terms.insert(
+            dst_key.to_string(),
+            TunnelDecapTermEntry {
+                config: config.clone(),
+                term_id,
+                priority,
+            },
+        );
+        if old.is_some() {
+            self.stats.term_entries_updated += 1;
+        } else {
+            self.term_prefixes
+                .get_or_insert_with(config.dst.addr, config.dst.len, Vec::new)
+                .push((config.tunnel_name.clone(), dst_key.to_string()));
+            self.stats.term_entries_created += 1;
+        }
+
+        audit_log!(AuditRecord::new(
+            if old.is_some() {
+                AuditCategory::ResourceModify
+            } else {
+                AuditCategory::ResourceCreate
+            },
+            "TunnelDecapOrch",
+            "add_decap_term"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("tunnel_term_entry")
+        .with_details(serde_json::json!({
+            "tunnel_name": config.tunnel_name,
+            "dst_ip": config.dst.to_string(),
+            "src_ip": config.src.map(|src| src.to_string()),
+            "term_type": config.term_type.as_str(),
+            "priority": priority,
+            "overlaps_existing": overlaps && old.is_none(),
+            "term_entry_id": term_id,
+            "replaced_term_entry_id": old.map(|old| old.term_id),
+        })));
+
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a TUNNEL_DECAP_TERM_TABLE DEL for key `<tunnel>|<dst_ip>`.
+    pub fn handle_decap_term_del(&mut self, key: &str) -> Result<(), TunnelDecapOrchError> {
+        let (tunnel_name, _) = key
+            .split_once('|')
+            .ok_or_else(|| TunnelDecapOrchError::InvalidConfig(format!("Invalid key: {}", key)))?;
+        let dst_key = Self::decap_term_dst(key);
+        let tunnel = self
+            .tunnels
+            .get_mut(tunnel_name)
+            .ok_or_else(|| TunnelDecapOrchError::TunnelNotFound(tunnel_name.to_string()))?;
+        let (term_id, dst) = tunnel
+            .decap_terms
+            .get(dst_key)
+            .map(|term| (term.term_id, term.config.dst))
+            .ok_or_else(|| TunnelDecapOrchError::TermEntryNotFound(key.to_string()))?;
+
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or_else(|| TunnelDecapOrchError::InvalidConfig("No callbacks set".to_string()))?;
+        callbacks
+            .remove_tunnel_term_entry(term_id)
+            .map_err(TunnelDecapOrchError::SaiError)?;
+        tunnel.decap_terms.remove(dst_key);
+        Self::unindex_term(&mut self.term_prefixes, &dst, tunnel_name, dst_key);
+        self.stats.term_entries_removed += 1;
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "TunnelDecapOrch",
+            "remove_decap_term"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("tunnel_term_entry")
+        .with_details(serde_json::json!({
+            "tunnel_name": tunnel_name,
+            "term_entry_id": term_id,
+            "stats": {
+                "term_entries_removed": self.stats.term_entries_removed
+            }
+        })));
+
+        Ok(())
+    }
+
+    /// Returns true if a term cannot be told apart from another term with
+    /// the same destination.
+    ///
+    /// Overlapping terms are allowed since priority decides which one
+    /// matches, but the same destination cannot terminate on two tunnels, nor
+    /// twice on one tunnel with the same priority.
+    fn term_conflicts(&self, config: &TunnelDecapTermConfig, dst_key: &str) -> bool {
+        let Some(owners) = self.term_prefixes.get(config.dst.addr, config.dst.len) else {
+            return false;
+        };
+        let priority = config.priority();
+        owners.iter().any(|(tunnel_name, term_key)| {
+            if *tunnel_name != config.tunnel_name {
+                return true;
+            }
+            term_key != dst_key
+                && self
+                    .get_decap_term(tunnel_name, term_key)
+                    .is_some_and(|term| term.priority == priority)
+        })
+    }
+
+    /// Drops a term from `term_prefixes`, and its destination once no term
+    /// uses it.
+    fn unindex_term(
+        term_prefixes: &mut PrefixSet<Vec<(String, String)>>,
+        dst: &TermPrefix,
+        tunnel_name: &str,
+        dst_key: &str,
+    ) {
+        let Some(owners) = term_prefixes.get_mut(dst.addr, dst.len) else {
+            return;
+        };
+        owners.retain(|(tunnel, key)| tunnel != tunnel_name || key != dst_key);
+        if owners.is_empty() {
+            term_prefixes.remove(dst.addr, dst.len);
+        }
+    }
+
+    /// The dst_ip part of a `<tunnel>|<dst_ip>` key.
+    fn decap_term_dst(key: &str) -> &str {
+        key.split_once('|').map_or(key, |(_, dst)| dst)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::str::FromStr;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::Mutex;
 
     struct MockCallbacks;
     impl TunnelDecapOrchCallbacks for MockCallbacks {
@@ -321,6 +595,87 @@ mod tests {
         fn remove_tunnel_term_entry(&self, _term_entry_id: RawSaiObjectId) -> Result<(), String> {
             Ok(())
         }
+        fn create_decap_term_entry(
+            &self,
+            _tunnel_id: RawSaiObjectId,
+            _config: &TunnelDecapTermConfig,
+            _priority: u32,
+        ) -> Result<RawSaiObjectId, String> {
+            Ok(0x7000)
+        }
+    }
+
+    /// Callbacks that track live term entry OIDs.
+    #[derive(Default)]
+    struct TrackingCallbacks {
+        next_oid: AtomicU64,
+        live_terms: Mutex<HashMap<RawSaiObjectId, (String, u32)>>,
+        fail_create: AtomicBool,
+    }
+
+    impl TrackingCallbacks {
+        fn live_term_count(&self) -> usize {
+            self.live_terms.lock().unwrap().len()
+        }
+    }
+
+    impl TunnelDecapOrchCallbacks for TrackingCallbacks {
+        fn create_tunnel(&self, _config: &TunnelDecapConfig) -> Result<RawSaiObjectId, String> {
+            Ok(0x5000)
+        }
+        fn remove_tunnel(&self, _tunnel_id: RawSaiObjectId) -> Result<(), String> {
+            Ok(())
+        }
+        fn create_tunnel_term_entry(
+            &self,
+            _tunnel_id: RawSaiObjectId,
+            _term_type: TunnelTermType,
+            _src_ip: IpAddress,
+            _dst_ip: IpAddress,
+        ) -> Result<RawSaiObjectId, String> {
+            Err("not used".to_string())
+        }
+        fn remove_tunnel_term_entry(&self, term_entry_id: RawSaiObjectId) -> Result<(), String> {
+            self.live_terms
+                .lock()
+                .unwrap()
+                .remove(&term_entry_id)
+                .map(|_| ())
+                .ok_or_else(|| format!("unknown term entry {:#x}", term_entry_id))
+        }
+        fn create_decap_term_entry(
+            &self,
+            _tunnel_id: RawSaiObjectId,
+            config: &TunnelDecapTermConfig,
+            priority: u32,
+        ) -> Result<RawSaiObjectId, String> {
+            if self.fail_create.swap(false, Ordering::SeqCst) {
+                return Err("create failed".to_string());
+            }
+            let oid = self.next_oid.fetch_add(1, Ordering::SeqCst) + 0x8000;
+            let src = config.src.map(|src| src.to_string()).unwrap_or_default();
+            self.live_terms.lock().unwrap().insert(oid, (src, priority));
+            Ok(oid)
+        }
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_tracking() -> (TunnelDecapOrch, Arc<TrackingCallbacks>) {
+        let mock = Arc::new(TrackingCallbacks::default());
+        let mut orch = TunnelDecapOrch::new(TunnelDecapOrchConfig::default());
+        orch.set_callbacks(mock.clone());
+        orch.create_tunnel(TunnelDecapConfig::new(
+            "IPINIP_TUNNEL".to_string(),
+            "IPINIP".to_string(),
+        ))
+        .unwrap();
+        (orch, mock)
     }
 
     #[test]
@@ -795,6 +1150,14 @@ mod tests {
         fn remove_tunnel_term_entry(&self, _term_entry_id: RawSaiObjectId) -> Result<(), String> {
             Err("SAI term entry removal failed".to_string())
         }
+        fn create_decap_term_entry(
+            &self,
+            _tunnel_id: RawSaiObjectId,
+            _config: &TunnelDecapTermConfig,
+            _priority: u32,
+        ) -> Result<RawSaiObjectId, String> {
+            Err("SAI term entry creation failed".to_string())
+        }
     }
 
     #[test]
@@ -1086,4 +1449,219 @@ mod tests {
 
         assert_eq!(orch.tunnel_count(), 0);
     }
+
+    // ========================================================================
+    // TUNNEL_DECAP_TERM_TABLE Tests
+    // ========================================================================
+
+    #[test]
+    fn test_decap_term_waits_for_tunnel() {
+        let (mut orch, mock) = orch_with_tracking();
+
+        let status = orch
+            .handle_decap_term_set("OTHER_TUNNEL|10.1.0.32", &fields(&[("term_type", "P2MP")]))
+            .unwrap();
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert_eq!(mock.live_term_count(), 0);
+
+        let status = orch
+            .handle_decap_term_set("IPINIP_TUNNEL|10.1.0.32", &fields(&[("term_type", "P2MP")]))
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+        assert_eq!(orch.decap_term_count(), 1);
+        assert_eq!(mock.live_term_count(), 1);
+        assert!(orch.get_decap_term("IPINIP_TUNNEL", "10.1.0.32").is_some());
+
+        // Terms block tunnel removal until deleted.
+        assert!(orch.remove_tunnel("IPINIP_TUNNEL").is_err());
+        orch.handle_decap_term_del("IPINIP_TUNNEL|10.1.0.32")
+            .unwrap();
+        assert_eq!(mock.live_term_count(), 0);
+        assert!(orch.remove_tunnel("IPINIP_TUNNEL").is_ok());
+    }
+
+    #[test]
+    fn test_decap_term_src_ip_changes_do_not_leak() {
+        let (mut orch, mock) = orch_with_tracking();
+        let key = "IPINIP_TUNNEL|10.1.0.32";
+
+        orch.handle_decap_term_set(
+            key,
+            &fields(&[("term_type", "P2P"), ("src_ip", "10.1.0.33")]),
+        )
+        .unwrap();
+        let first = orch
+            .get_decap_term("IPINIP_TUNNEL", "10.1.0.32")
+            .unwrap()
+            .term_id;
+
+        for src in ["10.1.0.34", "10.1.0.35", "10.1.0.36"] {
+            orch.handle_decap_term_set(key, &fields(&[("term_type", "P2P"), ("src_ip", src)]))
+                .unwrap();
+            assert_eq!(mock.live_term_count(), 1);
+        }
+
+        let term = orch.get_decap_term("IPINIP_TUNNEL", "10.1.0.32").unwrap();
+        assert_ne!(term.term_id, first);
+        let live = mock.live_terms.lock().unwrap();
+        assert_eq!(live.keys().copied().collect::<Vec<_>>(), vec![term.term_id]);
+        assert_eq!(live[&term.term_id].0, "10.1.0.36/32");
+        drop(live);
+
+        assert_eq!(orch.stats().term_entries_created, 1);
+        assert_eq!(orch.stats().term_entries_updated, 3);
+
+        // Re-applying the same config leaves the entry alone.
+        orch.handle_decap_term_set(
+            key,
+            &fields(&[("term_type", "P2P"), ("src_ip", "10.1.0.36")]),
+        )
+        .unwrap();
+        assert_eq!(orch.stats().term_entries_updated, 3);
+    }
+
+    #[test]
+    fn test_decap_term_update_failure_restores_old_entry() {
+        let (mut orch, mock) = orch_with_tracking();
+        let key = "IPINIP_TUNNEL|10.1.0.32";
+
+        orch.handle_decap_term_set(
+            key,
+            &fields(&[("term_type", "P2P"), ("src_ip", "10.1.0.33")]),
+        )
+        .unwrap();
+
+        mock.fail_create.store(true, Ordering::SeqCst);
+        let result = orch.handle_decap_term_set(
+            key,
+            &fields(&[("term_type", "P2P"), ("src_ip", "10.1.0.34")]),
+        );
+        assert!(matches!(result, Err(TunnelDecapOrchError::SaiError(_))));
+
+        let term = orch.get_decap_term("IPINIP_TUNNEL", "10.1.0.32").unwrap();
+        assert_eq!(term.config.src.unwrap().to_string(), "10.1.0.33/32");
+        let live = mock.live_terms.lock().unwrap();
+        assert_eq!(live.len(), 1);
+        assert!(live.contains_key(&term.term_id));
+    }
+
+    #[test]
+    fn test_overlapping_subnet_terms_ordered_by_prefix() {
+        let (mut orch, mock) = orch_with_tracking();
+        orch.create_tunnel(TunnelDecapConfig::new(
+            "PEER_TUNNEL".to_string(),
+            "IPINIP".to_string(),
+        ))
+        .unwrap();
+
+        orch.handle_decap_term_set(
+            "IPINIP_TUNNEL|192.168.0.0/16",
+            &fields(&[("term_type", "MP2MP"), ("subnet_type", "vlan")]),
+        )
+        .unwrap();
+        orch.handle_decap_term_set(
+            "PEER_TUNNEL|192.168.8.0/24",
+            &fields(&[("term_type", "MP2MP"), ("subnet_type", "vip")]),
+        )
+        .unwrap();
+        orch.handle_decap_term_set("IPINIP_TUNNEL|192.168.8.1", &fields(&[]))
+            .unwrap();
+        assert_eq!(mock.live_term_count(), 3);
+
+        let wide = orch
+            .get_decap_term("IPINIP_TUNNEL", "192.168.0.0/16")
+            .unwrap();
+        let narrow = orch
+            .get_decap_term("PEER_TUNNEL", "192.168.8.0/24")
+            .unwrap();
+        assert!(narrow.priority > wide.priority);
+
+        let (tunnel, term) = orch
+            .lookup_decap_term(&"192.168.8.1".parse().unwrap())
+            .unwrap();
+        assert_eq!(tunnel, "IPINIP_TUNNEL");
+        assert_eq!(term.config.term_type, TunnelTermType::P2MP);
+
+        let (tunnel, _) = orch
+            .lookup_decap_term(&"192.168.8.9".parse().unwrap())
+            .unwrap();
+        assert_eq!(tunnel, "PEER_TUNNEL");
+
+        let (tunnel, _) = orch
+            .lookup_decap_term(&"192.168.1.1".parse().unwrap())
+            .unwrap();
+        assert_eq!(tunnel, "IPINIP_TUNNEL");
+
+        assert!(orch
+            .lookup_decap_term(&"10.0.0.1".parse().unwrap())
+            .is_none());
+
+        // The same subnet cannot terminate on two tunnels.
+        assert!(matches!(
+            orch.handle_decap_term_set(
+                "IPINIP_TUNNEL|192.168.8.0/24",
+                &fields(&[("term_type", "MP2MP")]),
+            ),
+            Err(TunnelDecapOrchError::TermEntryExists(_))
+        ));
+
+        // Once the peer's term is gone its subnet falls back to the wider
+        // term and is free to move to the other tunnel.
+        orch.handle_decap_term_del("PEER_TUNNEL|192.168.8.0/24")
+            .unwrap();
+        let (tunnel, term) = orch
+            .lookup_decap_term(&"192.168.8.9".parse().unwrap())
+            .unwrap();
+        assert_eq!(tunnel, "IPINIP_TUNNEL");
+        assert_eq!(term.config.dst.to_string(), "192.168.0.0/16");
+        orch.handle_decap_term_set(
+            "IPINIP_TUNNEL|192.168.8.0/24",
+            &fields(&[("term_type", "MP2MP")]),
+        )
+        .unwrap();
+        let (_, term) = orch
+            .lookup_decap_term(&"192.168.8.9".parse().unwrap())
+            .unwrap();
+        assert_eq!(term.config.dst.to_string(), "192.168.8.0/24");
+    }
+
+    #[test]
+    fn test_decap_term_same_dst_needs_distinct_priority() {
+        let (mut orch, mock) = orch_with_tracking();
+
+        orch.handle_decap_term_set("IPINIP_TUNNEL|10.1.0.32", &fields(&[]))
+            .unwrap();
+        // Same destination under another key and the same priority
+        assert!(matches!(
+            orch.handle_decap_term_set("IPINIP_TUNNEL|10.1.0.32/32", &fields(&[])),
+            Err(TunnelDecapOrchError::TermEntryExists(_))
+        ));
+        assert_eq!(mock.live_term_count(), 1);
+
+        // A source prefix sets it apart
+        orch.handle_decap_term_set(
+            "IPINIP_TUNNEL|10.1.0.32/32",
+            &fields(&[("term_type", "P2P"), ("src_ip", "10.1.0.33")]),
+        )
+        .unwrap();
+        assert_eq!(mock.live_term_count(), 2);
+    }
+
+    #[test]
+    fn test_decap_term_invalid_config() {
+        let (mut orch, _mock) = orch_with_tracking();
+
+        assert!(matches!(
+            orch.handle_decap_term_set("IPINIP_TUNNEL|192.168.0.0/24", &fields(&[])),
+            Err(TunnelDecapOrchError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            orch.handle_decap_term_set("IPINIP_TUNNEL|10.1.0.32", &fields(&[("term_type", "P2P")])),
+            Err(TunnelDecapOrchError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            orch.handle_decap_term_del("IPINIP_TUNNEL|10.1.0.32"),
+            Err(TunnelDecapOrchError::TermEntryNotFound(_))
+        ));
+    }
 }
@@ -3,6 +3,13 @@
 use sonic_sai::types::RawSaiObjectId;
 use sonic_types::IpPrefix;
 use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+
+/// Table names handled by TunnelDecapOrch.
+pub mod tables {
+    pub const TUNNEL_DECAP_TERM_TABLE: &str = "TUNNEL_DECAP_TERM_TABLE";
+}
 
 /// Tunnel termination type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -89,6 +96,154 @@ impl SubnetType {
     }
 }
 
+/// Address prefix matched by a decap term entry, with host bits cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TermPrefix {
+    pub addr: IpAddr,
+    pub len: u8,
+}
+
+impl TermPrefix {
+    /// Parses `addr` or `addr/len`; a bare address is a host prefix.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (addr, len) = match s.split_once('/') {
+            Some((addr, len)) => (addr, Some(len)),
+            None => (s, None),
+        };
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|_| format!("Invalid address: {}", s))?;
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let len = match len {
+            Some(len) => len
+                .parse::<u8>()
+                .ok()
+                .filter(|len| *len <= max_len)
+                .ok_or_else(|| format!("Invalid prefix length: {}", s))?,
+            None => max_len,
+        };
+        Ok(Self {
+            addr: Self::mask(addr, len),
+            len,
+        })
+    }
+
+    fn mask(addr: IpAddr, len: u8) -> IpAddr {
+        match addr {
+            IpAddr::V4(v4) => {
+                let bits = u32::from(v4) & u32::MAX.checked_shl(32 - len as u32).unwrap_or(0);
+                IpAddr::V4(bits.into())
+            }
+            IpAddr::V6(v6) => {
+                let bits = u128::from(v6) & u128::MAX.checked_shl(128 - len as u32).unwrap_or(0);
+                IpAddr::V6(bits.into())
+            }
+        }
+    }
+
+    pub fn is_host(&self) -> bool {
+        self.len == if self.addr.is_ipv4() { 32 } else { 128 }
+    }
+
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        addr.is_ipv4() == self.addr.is_ipv4() && Self::mask(*addr, self.len) == self.addr
+    }
+
+    /// Returns true if either prefix contains the other.
+    pub fn overlaps(&self, other: &TermPrefix) -> bool {
+        self.contains(&other.addr) || other.contains(&self.addr)
+    }
+}
+
+impl fmt::Display for TermPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.len)
+    }
+}
+
+/// TUNNEL_DECAP_TERM_TABLE entry, keyed `<tunnel>|<dst_ip>`.
+///
+/// P2P and P2MP terminate on a single local address; MP2MP matches a
+/// destination subnet (optionally restricted to a source subnet), as
+/// dual-ToR uses for the peer's VLAN subnet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TunnelDecapTermConfig {
+    pub tunnel_name: String,
+    pub dst: TermPrefix,
+    pub term_type: TunnelTermType,
+    pub src: Option<TermPrefix>,
+    pub subnet_type: Option<SubnetType>,
+}
+
+impl TunnelDecapTermConfig {
+    pub fn from_key(key: &str) -> Result<Self, String> {
+        let (tunnel_name, dst) = key
+            .split_once('|')
+            .filter(|(tunnel, _)| !tunnel.is_empty())
+            .ok_or_else(|| format!("Invalid decap term key: {}", key))?;
+        Ok(Self {
+            tunnel_name: tunnel_name.to_string(),
+            dst: TermPrefix::parse(dst)?,
+            term_type: TunnelTermType::P2MP,
+            src: None,
+            subnet_type: None,
+        })
+    }
+
+    /// Applies one TUNNEL_DECAP_TERM_TABLE field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "term_type" => {
+                self.term_type = TunnelTermType::parse(value)
+                    .ok_or_else(|| format!("Invalid term_type: {}", value))?
+            }
+            "src_ip" => self.src = Some(TermPrefix::parse(value)?),
+            "subnet_type" => {
+                self.subnet_type = Some(
+                    SubnetType::parse(value)
+                        .ok_or_else(|| format!("Invalid subnet_type: {}", value))?,
+                )
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(src) = &self.src {
+            if src.addr.is_ipv4() != self.dst.addr.is_ipv4() {
+                return Err(format!("{}: src_ip and dst_ip family mismatch", self.dst));
+            }
+        }
+        match self.term_type {
+            TunnelTermType::P2P if !self.src.as_ref().is_some_and(TermPrefix::is_host) => {
+                Err(format!("{}: P2P requires a host src_ip", self.dst))
+            }
+            TunnelTermType::P2P | TunnelTermType::P2MP if !self.dst.is_host() => {
+                Err(format!("{}: only MP2MP may terminate a subnet", self.dst))
+            }
+            TunnelTermType::P2MP if self.src.is_some() => {
+                Err(format!("{}: P2MP does not take src_ip", self.dst))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Priority among overlapping entries: longer destination prefixes
+    /// first, then longer source prefixes.
+    pub fn priority(&self) -> u32 {
+        ((self.dst.len as u32) << 8) | self.src.map_or(0, |src| src.len as u32)
+    }
+}
+
+/// Programmed TUNNEL_DECAP_TERM_TABLE entry.
+#[derive(Debug, Clone)]
+pub struct TunnelDecapTermEntry {
+    pub config: TunnelDecapTermConfig,
+    pub term_id: RawSaiObjectId,
+    pub priority: u32,
+}
+
 /// Tunnel decap configuration (simplified for orchestration).
 #[derive(Debug, Clone)]
 pub struct TunnelDecapConfig {
@@ -112,6 +267,8 @@ pub struct TunnelDecapEntry {
     pub tunnel_id: RawSaiObjectId,
     pub tunnel_type: String,
     pub term_entries: HashMap<String, RawSaiObjectId>,
+    /// TUNNEL_DECAP_TERM_TABLE entries keyed by their dst_ip key part.
+    pub decap_terms: HashMap<String, TunnelDecapTermEntry>,
 }
 
 impl TunnelDecapEntry {
@@ -121,6 +278,7 @@ impl TunnelDecapEntry {
             tunnel_id,
             tunnel_type: config.tunnel_type,
             term_entries: HashMap::new(),
+            decap_terms: HashMap::new(),
         }
     }
 }
@@ -259,4 +417,70 @@ mod tests {
         );
         assert_eq!(EcnMode::parse("standard"), Some(EcnMode::Standard));
     }
+
+    #[test]
+    fn test_term_prefix() {
+        let host = TermPrefix::parse("192.168.0.1").unwrap();
+        assert!(host.is_host());
+        assert_eq!(host.to_string(), "192.168.0.1/32");
+
+        let subnet = TermPrefix::parse("192.168.0.77/24").unwrap();
+        assert_eq!(subnet.to_string(), "192.168.0.0/24");
+        assert!(subnet.contains(&"192.168.0.200".parse().unwrap()));
+        assert!(!subnet.contains(&"192.168.1.1".parse().unwrap()));
+        assert!(subnet.overlaps(&host));
+        assert!(host.overlaps(&subnet));
+
+        let v6 = TermPrefix::parse("fc00::/64").unwrap();
+        assert!(!v6.overlaps(&subnet));
+        assert!(TermPrefix::parse("0.0.0.0/0")
+            .unwrap()
+            .contains(&"8.8.8.8".parse().unwrap()));
+
+        assert!(TermPrefix::parse("10.0.0.0/33").is_err());
+        assert!(TermPrefix::parse("bogus").is_err());
+    }
+
+    #[test]
+    fn test_decap_term_config() {
+        let mut config = TunnelDecapTermConfig::from_key("IPINIP_TUNNEL|10.1.0.32").unwrap();
+        assert_eq!(config.tunnel_name, "IPINIP_TUNNEL");
+        assert_eq!(config.term_type, TunnelTermType::P2MP);
+        assert!(config.validate().is_ok());
+
+        config.parse_field("term_type", "P2P").unwrap();
+        assert!(config.validate().is_err());
+        config.parse_field("src_ip", "10.1.0.33").unwrap();
+        assert!(config.validate().is_ok());
+
+        let mut subnet = TunnelDecapTermConfig::from_key("IPINIP_TUNNEL|192.168.0.0/21").unwrap();
+        subnet.parse_field("term_type", "MP2MP").unwrap();
+        subnet.parse_field("subnet_type", "vlan").unwrap();
+        assert!(subnet.validate().is_ok());
+        assert_eq!(subnet.subnet_type, Some(SubnetType::Vlan));
+
+        subnet.parse_field("term_type", "P2MP").unwrap();
+        assert!(subnet.validate().is_err());
+
+        subnet.parse_field("term_type", "MP2MP").unwrap();
+        subnet.parse_field("src_ip", "fc00::1").unwrap();
+        assert!(subnet.validate().is_err());
+
+        assert!(TunnelDecapTermConfig::from_key("10.1.0.32").is_err());
+        assert!(subnet.parse_field("term_type", "bogus").is_err());
+    }
+
+    #[test]
+    fn test_decap_term_priority() {
+        let mut wide = TunnelDecapTermConfig::from_key("t|192.168.0.0/16").unwrap();
+        wide.term_type = TunnelTermType::MP2MP;
+        let mut narrow = TunnelDecapTermConfig::from_key("t|192.168.1.0/24").unwrap();
+        narrow.term_type = TunnelTermType::MP2MP;
+        assert!(narrow.priority() > wide.priority());
+
+        let mut sourced = wide.clone();
+        sourced.src = Some(TermPrefix::parse("10.0.0.0/8").unwrap());
+        assert!(sourced.priority() > wide.priority());
+        assert!(narrow.priority() > sourced.priority());
+    }
 }
@@ -27,6 +27,7 @@ mod types;
 pub use ffi::{register_twamp_orch, unregister_twamp_orch};
 pub use orch::{TwampOrch, TwampOrchCallbacks, TwampOrchConfig, TwampOrchError, TwampOrchStats};
 pub use types::{
-    Dscp, SessionTimeout, TimestampFormat, TwampMode, TwampRole, TwampSessionConfig,
-    TwampSessionEntry, TwampSessionStatus, TwampStats, TwampUdpPort, TxMode,
+    Dscp, SessionTimeout, TimestampFormat, TwampMode, TwampProbeTimestamps, TwampRole,
+    TwampSessionConfig, TwampSessionEntry, TwampSessionStatus, TwampStats, TwampStatsEvent,
+    TwampUdpPort, TxMode,
 };
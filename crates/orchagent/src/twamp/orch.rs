@@ -1,6 +1,9 @@
 //! TWAMP session orchestration logic (stub implementation).
 
-use super::types::{TwampMode, TwampRole, TwampSessionConfig, TwampSessionEntry, TwampStats};
+use super::types::{
+    TwampMode, TwampRole, TwampSessionConfig, TwampSessionEntry, TwampSessionStatus, TwampStats,
+    TwampStatsEvent,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use sonic_sai::types::RawSaiObjectId;
@@ -17,6 +20,8 @@ pub enum TwampOrchError {
     ResourceExhausted,
     #[error("VRF not found: {0}")]
     VrfNotFound(String),
+    #[error("Invalid session: {0}")]
+    InvalidSession(String),
     #[error("SAI error: {0}")]
     SaiError(String),
 }
@@ -30,6 +35,9 @@ pub struct TwampOrchConfig {
 pub struct TwampOrchStats {
     pub sessions_created: u64,
     pub sessions_removed: u64,
+    pub sessions_started: u64,
+    pub sessions_completed: u64,
+    pub stats_events: u64,
 }
 
 pub trait TwampOrchCallbacks: Send + Sync {
@@ -37,6 +45,12 @@ pub trait TwampOrchCallbacks: Send + Sync {
     fn remove_twamp_session(&self, session_id: RawSaiObjectId) -> Result<(), String>;
     fn set_session_transmit(&self, session_id: RawSaiObjectId, enabled: bool)
         -> Result<(), String>;
+    /// Writes the session status to STATE_DB.
+    fn write_session_state(&self, name: &str, status: TwampSessionStatus);
+    /// Writes the aggregated session statistics to COUNTERS_DB.
+    fn write_session_stats(&self, name: &str, stats: &TwampStats);
+    /// Removes the session's STATE_DB entry.
+    fn remove_session_state(&self, name: &str);
 }
 
 pub struct TwampOrch {
@@ -72,6 +86,167 @@ impl TwampOrch {
         &self.stats
     }
 
+    pub fn session_status(&self, name: &str) -> Option<TwampSessionStatus> {
+        self.sessions.get(name).map(|entry| entry.status)
+    }
+
+    pub fn session_stats(&self, name: &str) -> Option<&TwampStats> {
+        self.sessions.get(name).map(|entry| &entry.stats)
+    }
+
+    /// Starts transmitting test packets on a sender session.
+    ///
+    /// Statistics are reset so a restarted packet-count session sends its
+    /// full count again. Starting an active session is a no-op.
+    pub fn start_session(&mut self, name: &str) -> Result<(), TwampOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| TwampOrchError::SaiError("No callbacks set".to_string()))?,
+        );
+        let entry = self
+            .sessions
+            .get_mut(name)
+            .ok_or_else(|| TwampOrchError::SessionNotFound(name.to_string()))?;
+
+        if entry.role != TwampRole::Sender {
+            return Err(TwampOrchError::InvalidSession(format!(
+                "{} is not a sender session",
+                name
+            )));
+        }
+        let tx_mode = entry.tx_mode.clone().ok_or_else(|| {
+            TwampOrchError::InvalidSession(format!("{} has no tx mode configured", name))
+        })?;
+        if entry.status == TwampSessionStatus::Active {
+            return Ok(());
+        }
+
+        callbacks
+            .set_session_transmit(entry.session_id, true)
+            .map_err(TwampOrchError::SaiError)?;
+
+        entry.stats = TwampStats::default();
+        entry.status = TwampSessionStatus::Active;
+        callbacks.write_session_state(name, entry.status);
+        self.stats.sessions_started += 1;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "TwampOrch", "start_session")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(name.to_string())
+                .with_object_type("twamp_session")
+                .with_details(serde_json::json!({
+                    "session_name": name,
+                    "tx_mode": format!("{:?}", tx_mode),
+                    "timestamp_format": entry.timestamp_format.as_str(),
+                }))
+        );
+
+        Ok(())
+    }
+
+    /// Stops transmitting test packets and marks the session inactive.
+    ///
+    /// Continuous sessions only end this way. Stopping a session that is not
+    /// active leaves its status (e.g. completed) untouched.
+    pub fn stop_session(&mut self, name: &str) -> Result<(), TwampOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| TwampOrchError::SaiError("No callbacks set".to_string()))?,
+        );
+        let entry = self
+            .sessions
+            .get_mut(name)
+            .ok_or_else(|| TwampOrchError::SessionNotFound(name.to_string()))?;
+
+        if entry.status != TwampSessionStatus::Active {
+            return Ok(());
+        }
+
+        callbacks
+            .set_session_transmit(entry.session_id, false)
+            .map_err(TwampOrchError::SaiError)?;
+
+        entry.status = TwampSessionStatus::Inactive;
+        callbacks.write_session_state(name, entry.status);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "TwampOrch", "stop_session")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(name.to_string())
+                .with_object_type("twamp_session")
+                .with_details(serde_json::json!({
+                    "session_name": name,
+                    "tx_packets": entry.stats.tx_packets,
+                    "rx_packets": entry.stats.rx_packets,
+                }))
+        );
+
+        Ok(())
+    }
+
+    /// Handles a SAI session event notification carrying statistics.
+    ///
+    /// Events for sessions that are not active are dropped, since a stopped
+    /// or completed session may still have notifications in flight. A
+    /// packet-count session is stopped and marked completed once its
+    /// transmit count is reached.
+    pub fn handle_session_event(
+        &mut self,
+        session_id: RawSaiObjectId,
+        event: &TwampStatsEvent,
+    ) -> Result<(), TwampOrchError> {
+        let callbacks = Arc::clone(
+            self.callbacks
+                .as_ref()
+                .ok_or_else(|| TwampOrchError::SaiError("No callbacks set".to_string()))?,
+        );
+        let (name, entry) = self
+            .sessions
+            .iter_mut()
+            .find(|(_, entry)| entry.session_id == session_id)
+            .ok_or_else(|| TwampOrchError::SessionNotFound(format!("0x{:x}", session_id)))?;
+
+        if entry.status != TwampSessionStatus::Active {
+            return Ok(());
+        }
+
+        entry.stats.accumulate(event, entry.timestamp_format);
+        callbacks.write_session_stats(name, &entry.stats);
+        self.stats.stats_events += 1;
+
+        if !entry.tx_count_reached() {
+            return Ok(());
+        }
+
+        callbacks
+            .set_session_transmit(entry.session_id, false)
+            .map_err(TwampOrchError::SaiError)?;
+        entry.status = TwampSessionStatus::Completed;
+        callbacks.write_session_state(name, entry.status);
+        self.stats.sessions_completed += 1;
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "TwampOrch",
+            "complete_session"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(name.clone())
+        .with_object_type("twamp_session")
+        .with_details(serde_json::json!({
+            "session_name": name,
+            "tx_packets": entry.stats.tx_packets,
+            "rx_packets": entry.stats.rx_packets,
+            "avg_latency_ns": entry.stats.avg_latency,
+            "avg_jitter_ns": entry.stats.avg_jitter,
+        })));
+
+        Ok(())
+    }
+
     pub fn create_session(&mut self, config: TwampSessionConfig) -> Result<(), TwampOrchError> {
         if self.sessions.contains_key(&config.name) {
             let error = TwampOrchError::SessionExists(config.name.clone());
@@ -100,6 +275,7 @@ impl TwampOrch {
         let entry = TwampSessionEntry::from_config(config.clone(), session_id);
         self.sessions.insert(config.name.clone(), entry);
         self.stats.sessions_created += 1;
+        callbacks.write_session_state(&config.name, TwampSessionStatus::Inactive);
 
         audit_log!(
             AuditRecord::new(AuditCategory::ResourceCreate, "TwampOrch", "create_session")
@@ -119,6 +295,10 @@ impl TwampOrch {
                 }))
         );
 
+        if config.admin_state && config.role == TwampRole::Sender && config.tx_mode.is_some() {
+            self.start_session(&config.name)?;
+        }
+
         Ok(())
     }
 
@@ -136,6 +316,7 @@ impl TwampOrch {
         callbacks
             .remove_twamp_session(entry.session_id)
             .map_err(TwampOrchError::SaiError)?;
+        callbacks.remove_session_state(name);
 
         self.stats.sessions_removed += 1;
 
@@ -160,9 +341,10 @@ impl TwampOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::twamp::types::{Dscp, TwampUdpPort};
+    use crate::twamp::types::{Dscp, TimestampFormat, TwampProbeTimestamps, TwampUdpPort, TxMode};
     use sonic_types::IpAddress;
     use std::str::FromStr;
+    use std::sync::Mutex;
 
     struct MockCallbacks;
     impl TwampOrchCallbacks for MockCallbacks {
@@ -182,6 +364,9 @@ mod tests {
         ) -> Result<(), String> {
             Ok(())
         }
+        fn write_session_state(&self, _name: &str, _status: TwampSessionStatus) {}
+        fn write_session_stats(&self, _name: &str, _stats: &TwampStats) {}
+        fn remove_session_state(&self, _name: &str) {}
     }
 
     #[test]
@@ -483,6 +668,9 @@ mod tests {
         ) -> Result<(), String> {
             Err("SAI transmit set failed".to_string())
         }
+        fn write_session_state(&self, _name: &str, _status: TwampSessionStatus) {}
+        fn write_session_stats(&self, _name: &str, _stats: &TwampStats) {}
+        fn remove_session_state(&self, _name: &str) {}
     }
 
     #[test]
@@ -630,4 +818,243 @@ mod tests {
         assert!(orch.create_session(config).is_ok());
         assert_eq!(orch.session_count(), 1);
     }
+
+    // ========== Transmit Mode Tests ==========
+
+    #[derive(Default)]
+    struct RecordingCallbacks {
+        transmit: Mutex<Vec<(RawSaiObjectId, bool)>>,
+        states: Mutex<Vec<(String, TwampSessionStatus)>>,
+        stats_writes: Mutex<Vec<(String, u64)>>,
+    }
+
+    impl TwampOrchCallbacks for RecordingCallbacks {
+        fn create_twamp_session(
+            &self,
+            _config: &TwampSessionConfig,
+        ) -> Result<RawSaiObjectId, String> {
+            Ok(0x2000)
+        }
+        fn remove_twamp_session(&self, _session_id: RawSaiObjectId) -> Result<(), String> {
+            Ok(())
+        }
+        fn set_session_transmit(
+            &self,
+            session_id: RawSaiObjectId,
+            enabled: bool,
+        ) -> Result<(), String> {
+            self.transmit.lock().unwrap().push((session_id, enabled));
+            Ok(())
+        }
+        fn write_session_state(&self, name: &str, status: TwampSessionStatus) {
+            self.states.lock().unwrap().push((name.to_string(), status));
+        }
+        fn write_session_stats(&self, name: &str, stats: &TwampStats) {
+            self.stats_writes
+                .lock()
+                .unwrap()
+                .push((name.to_string(), stats.tx_packets));
+        }
+        fn remove_session_state(&self, _name: &str) {}
+    }
+
+    fn sender_config(tx_mode: TxMode, format: TimestampFormat) -> TwampSessionConfig {
+        let mut config =
+            TwampSessionConfig::new("sender".to_string(), TwampMode::Light, TwampRole::Sender);
+        config.src_ip = IpAddress::from_str("10.0.0.1").unwrap();
+        config.dst_ip = IpAddress::from_str("10.0.0.2").unwrap();
+        config.timestamp_format = format;
+        config.tx_mode = Some(tx_mode);
+        config
+    }
+
+    fn stats_event(packets: u64, latencies: &[u64]) -> TwampStatsEvent {
+        TwampStatsEvent {
+            tx_packets: packets,
+            rx_packets: packets,
+            probes: latencies
+                .iter()
+                .map(|&latency| TwampProbeTimestamps {
+                    sender_rx: latency,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_packet_num_session_completes() {
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        let mut orch = TwampOrch::new(TwampOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let mut config = sender_config(TxMode::PacketNum(10), TimestampFormat::Ptp);
+        config.admin_state = true;
+        orch.create_session(config).unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Active)
+        );
+
+        orch.handle_session_event(0x2000, &stats_event(6, &[100, 200]))
+            .unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Active)
+        );
+
+        orch.handle_session_event(0x2000, &stats_event(4, &[300]))
+            .unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Completed)
+        );
+        assert_eq!(
+            *callbacks.transmit.lock().unwrap(),
+            vec![(0x2000, true), (0x2000, false)]
+        );
+        assert_eq!(
+            callbacks.states.lock().unwrap().last(),
+            Some(&("sender".to_string(), TwampSessionStatus::Completed))
+        );
+
+        let stats = orch.session_stats("sender").unwrap();
+        assert_eq!(stats.tx_packets, 10);
+        assert_eq!(stats.avg_latency, 200);
+        assert_eq!(orch.stats().sessions_completed, 1);
+
+        // Late notifications after completion are ignored
+        orch.handle_session_event(0x2000, &stats_event(1, &[900]))
+            .unwrap();
+        assert_eq!(orch.session_stats("sender").unwrap().tx_packets, 10);
+        assert_eq!(callbacks.stats_writes.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_continuous_session_runs_until_stopped() {
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        let mut orch = TwampOrch::new(TwampOrchConfig::default());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.create_session(sender_config(TxMode::Continuous(0), TimestampFormat::Ntp))
+            .unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Inactive)
+        );
+
+        orch.start_session("sender").unwrap();
+        for _ in 0..5 {
+            orch.handle_session_event(0x2000, &stats_event(1000, &[]))
+                .unwrap();
+        }
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Active)
+        );
+        assert_eq!(orch.session_stats("sender").unwrap().tx_packets, 5000);
+
+        orch.stop_session("sender").unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Inactive)
+        );
+        assert_eq!(
+            *callbacks.transmit.lock().unwrap(),
+            vec![(0x2000, true), (0x2000, false)]
+        );
+        assert_eq!(orch.stats().sessions_completed, 0);
+    }
+
+    #[test]
+    fn test_restart_resets_stats() {
+        let mut orch = TwampOrch::new(TwampOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        orch.create_session(sender_config(TxMode::PacketNum(2), TimestampFormat::Ptp))
+            .unwrap();
+        orch.start_session("sender").unwrap();
+        orch.handle_session_event(0x2000, &stats_event(2, &[50]))
+            .unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Completed)
+        );
+
+        // Stopping a completed session leaves it completed
+        orch.stop_session("sender").unwrap();
+        assert_eq!(
+            orch.session_status("sender"),
+            Some(TwampSessionStatus::Completed)
+        );
+
+        orch.start_session("sender").unwrap();
+        assert_eq!(orch.session_stats("sender").unwrap().tx_packets, 0);
+        assert_eq!(orch.stats().sessions_started, 2);
+    }
+
+    #[test]
+    fn test_session_event_ntp_rollover_latency() {
+        let mut orch = TwampOrch::new(TwampOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        orch.create_session(sender_config(TxMode::Continuous(0), TimestampFormat::Ntp))
+            .unwrap();
+        orch.start_session("sender").unwrap();
+
+        // T1 in the last second of the NTP era, T4 a quarter second into the next
+        let last_second = 0xFFFF_FFFFu64 << 32;
+        let event = TwampStatsEvent {
+            tx_packets: 1,
+            rx_packets: 1,
+            probes: vec![TwampProbeTimestamps {
+                sender_tx: last_second,
+                reflector_rx: last_second | 0x4000_0000,
+                reflector_tx: last_second | 0x8000_0000,
+                sender_rx: 0x4000_0000,
+            }],
+            ..Default::default()
+        };
+        orch.handle_session_event(0x2000, &event).unwrap();
+
+        let stats = orch.session_stats("sender").unwrap();
+        assert_eq!(stats.min_latency, 1_000_000_000);
+        assert_eq!(stats.max_latency, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_start_session_validation() {
+        let mut orch = TwampOrch::new(TwampOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        let mut reflector = TwampSessionConfig::new(
+            "reflector".to_string(),
+            TwampMode::Light,
+            TwampRole::Reflector,
+        );
+        reflector.tx_mode = Some(TxMode::PacketNum(10));
+        orch.create_session(reflector).unwrap();
+        assert!(matches!(
+            orch.start_session("reflector"),
+            Err(TwampOrchError::InvalidSession(_))
+        ));
+
+        let mut no_mode = sender_config(TxMode::PacketNum(1), TimestampFormat::Ntp);
+        no_mode.tx_mode = None;
+        orch.create_session(no_mode).unwrap();
+        assert!(matches!(
+            orch.start_session("sender"),
+            Err(TwampOrchError::InvalidSession(_))
+        ));
+
+        assert!(matches!(
+            orch.start_session("missing"),
+            Err(TwampOrchError::SessionNotFound(_))
+        ));
+        assert!(matches!(
+            orch.handle_session_event(0xdead, &TwampStatsEvent::default()),
+            Err(TwampOrchError::SessionNotFound(_))
+        ));
+    }
 }
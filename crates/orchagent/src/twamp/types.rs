@@ -74,8 +74,49 @@ impl TimestampFormat {
             _ => None,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ntp => "NTP",
+            Self::Ptp => "PTP",
+        }
+    }
+
+    /// Converts a raw 64-bit timestamp to nanoseconds within its 2^32 second era.
+    ///
+    /// Both formats carry whole seconds in the upper 32 bits. NTP stores a
+    /// binary fraction of a second in the lower 32 bits, PTP stores
+    /// nanoseconds.
+    pub fn to_nanos(&self, raw: u64) -> u64 {
+        let secs = raw >> 32;
+        let low = raw & 0xFFFF_FFFF;
+        let nanos = match self {
+            Self::Ntp => (low * NANOS_PER_SEC) >> 32,
+            Self::Ptp => low.min(NANOS_PER_SEC - 1),
+        };
+        secs * NANOS_PER_SEC + nanos
+    }
+
+    /// Returns the nanoseconds elapsed from `start` to `end`.
+    ///
+    /// The seconds field wraps every 2^32 seconds (NTP era 1 begins in 2036),
+    /// so the difference is taken modulo one era rather than subtracted.
+    pub fn elapsed_nanos(&self, start: u64, end: u64) -> u64 {
+        let start = self.to_nanos(start);
+        let end = self.to_nanos(end);
+        if end >= start {
+            end - start
+        } else {
+            TIMESTAMP_ERA_NANOS - start + end
+        }
+    }
 }
 
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Length of one timestamp era (2^32 seconds) in nanoseconds.
+const TIMESTAMP_ERA_NANOS: u64 = (1u64 << 32) * NANOS_PER_SEC;
+
 /// TWAMP transmission mode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TxMode {
@@ -212,6 +253,8 @@ pub struct TwampSessionEntry {
     pub statistics_interval: Option<u32>,
     pub timeout: Option<SessionTimeout>,
     pub session_id: RawSaiObjectId,
+    pub status: TwampSessionStatus,
+    pub stats: TwampStats,
 }
 
 impl TwampSessionEntry {
@@ -236,10 +279,53 @@ impl TwampSessionEntry {
             statistics_interval: config.statistics_interval,
             timeout: config.timeout,
             session_id,
+            status: TwampSessionStatus::Inactive,
+            stats: TwampStats::default(),
+        }
+    }
+
+    /// Returns true if the session has sent its configured packet count.
+    pub fn tx_count_reached(&self) -> bool {
+        match self.tx_mode {
+            Some(TxMode::PacketNum(count)) => self.stats.tx_packets >= u64::from(count),
+            _ => false,
         }
     }
 }
 
+/// Timestamps of one TWAMP-Light test packet round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TwampProbeTimestamps {
+    /// Sender transmit time (T1).
+    pub sender_tx: u64,
+    /// Reflector receive time (T2).
+    pub reflector_rx: u64,
+    /// Reflector transmit time (T3).
+    pub reflector_tx: u64,
+    /// Sender receive time (T4).
+    pub sender_rx: u64,
+}
+
+impl TwampProbeTimestamps {
+    /// Round-trip latency excluding reflector residence time: (T4 - T1) - (T3 - T2).
+    pub fn latency_nanos(&self, format: TimestampFormat) -> u64 {
+        let round_trip = format.elapsed_nanos(self.sender_tx, self.sender_rx);
+        let residence = format.elapsed_nanos(self.reflector_rx, self.reflector_tx);
+        round_trip.saturating_sub(residence)
+    }
+}
+
+/// Statistics reported by a TWAMP session event notification.
+#[derive(Debug, Clone, Default)]
+pub struct TwampStatsEvent {
+    pub tx_packets: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_bytes: u64,
+    pub drop_packets: u64,
+    pub probes: Vec<TwampProbeTimestamps>,
+}
+
 /// TWAMP session statistics.
 #[derive(Debug, Clone, Default)]
 pub struct TwampStats {
@@ -256,6 +342,46 @@ pub struct TwampStats {
     pub avg_jitter: u64,
     pub avg_latency_total: u64,
     pub avg_jitter_total: u64,
+    pub latency_samples: u64,
+    pub jitter_samples: u64,
+    pub last_latency: u64,
+}
+
+impl TwampStats {
+    /// Folds a notification into the running totals.
+    ///
+    /// Latency and jitter are in nanoseconds; jitter is the absolute latency
+    /// difference between consecutive probes, carried across notifications.
+    pub fn accumulate(&mut self, event: &TwampStatsEvent, format: TimestampFormat) {
+        self.tx_packets = self.tx_packets.saturating_add(event.tx_packets);
+        self.tx_bytes = self.tx_bytes.saturating_add(event.tx_bytes);
+        self.rx_packets = self.rx_packets.saturating_add(event.rx_packets);
+        self.rx_bytes = self.rx_bytes.saturating_add(event.rx_bytes);
+        self.drop_packets = self.drop_packets.saturating_add(event.drop_packets);
+
+        for probe in &event.probes {
+            let latency = probe.latency_nanos(format);
+            if self.latency_samples == 0 {
+                self.min_latency = latency;
+            } else {
+                let jitter = latency.abs_diff(self.last_latency);
+                if self.jitter_samples == 0 {
+                    self.min_jitter = jitter;
+                }
+                self.min_jitter = self.min_jitter.min(jitter);
+                self.max_jitter = self.max_jitter.max(jitter);
+                self.avg_jitter_total = self.avg_jitter_total.saturating_add(jitter);
+                self.jitter_samples += 1;
+                self.avg_jitter = self.avg_jitter_total / self.jitter_samples;
+            }
+            self.min_latency = self.min_latency.min(latency);
+            self.max_latency = self.max_latency.max(latency);
+            self.avg_latency_total = self.avg_latency_total.saturating_add(latency);
+            self.latency_samples += 1;
+            self.avg_latency = self.avg_latency_total / self.latency_samples;
+            self.last_latency = latency;
+        }
+    }
 }
 
 /// TWAMP session status.
@@ -263,6 +389,8 @@ pub struct TwampStats {
 pub enum TwampSessionStatus {
     Inactive,
     Active,
+    /// Packet-count session that has sent all of its packets.
+    Completed,
 }
 
 impl TwampSessionStatus {
@@ -270,6 +398,7 @@ impl TwampSessionStatus {
         match self {
             Self::Inactive => "inactive",
             Self::Active => "active",
+            Self::Completed => "completed",
         }
     }
 }
@@ -324,4 +453,80 @@ mod tests {
         assert!(matches!(packet_mode, TxMode::PacketNum(100)));
         assert!(matches!(continuous_mode, TxMode::Continuous(60)));
     }
+
+    #[test]
+    fn test_timestamp_to_nanos() {
+        // 1.5 seconds in each encoding
+        assert_eq!(
+            TimestampFormat::Ntp.to_nanos((1 << 32) | 0x8000_0000),
+            1_500_000_000
+        );
+        assert_eq!(
+            TimestampFormat::Ptp.to_nanos((1 << 32) | 500_000_000),
+            1_500_000_000
+        );
+        assert_eq!(TimestampFormat::parse("ptp").unwrap().as_str(), "PTP");
+    }
+
+    #[test]
+    fn test_elapsed_nanos_across_rollover() {
+        let last_second = 0xFFFF_FFFFu64 << 32;
+        let ntp_end = 1u64 << 31; // 0.5 s into the next era
+        assert_eq!(
+            TimestampFormat::Ntp.elapsed_nanos(last_second, ntp_end),
+            1_500_000_000
+        );
+
+        let ptp_start = last_second | 999_999_000;
+        assert_eq!(TimestampFormat::Ptp.elapsed_nanos(ptp_start, 2_000), 3_000);
+    }
+
+    #[test]
+    fn test_probe_latency_excludes_reflector_time() {
+        let probe = TwampProbeTimestamps {
+            sender_tx: 10 << 32,
+            reflector_rx: (10 << 32) | 100_000,
+            reflector_tx: (10 << 32) | 400_000,
+            sender_rx: (10 << 32) | 1_000_000,
+        };
+        assert_eq!(probe.latency_nanos(TimestampFormat::Ptp), 700_000);
+    }
+
+    #[test]
+    fn test_stats_accumulate() {
+        let probe = |latency: u64| TwampProbeTimestamps {
+            sender_tx: 0,
+            reflector_rx: 0,
+            reflector_tx: 0,
+            sender_rx: latency,
+        };
+        let mut stats = TwampStats::default();
+        stats.accumulate(
+            &TwampStatsEvent {
+                tx_packets: 2,
+                rx_packets: 2,
+                probes: vec![probe(100), probe(300)],
+                ..Default::default()
+            },
+            TimestampFormat::Ptp,
+        );
+        stats.accumulate(
+            &TwampStatsEvent {
+                tx_packets: 1,
+                rx_packets: 1,
+                probes: vec![probe(200)],
+                ..Default::default()
+            },
+            TimestampFormat::Ptp,
+        );
+
+        assert_eq!(stats.tx_packets, 3);
+        assert_eq!(stats.rx_packets, 3);
+        assert_eq!(stats.min_latency, 100);
+        assert_eq!(stats.max_latency, 300);
+        assert_eq!(stats.avg_latency, 200);
+        assert_eq!(stats.min_jitter, 100);
+        assert_eq!(stats.max_jitter, 200);
+        assert_eq!(stats.avg_jitter, 150);
+    }
 }
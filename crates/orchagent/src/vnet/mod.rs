@@ -8,6 +8,8 @@
 //! - std::net::IpAddr for endpoint addresses
 //! - Vec for peer lists instead of raw arrays
 //! - Option types for optional configuration
+//! - Reference-counted tunnel next hops shared across routes and ECMP groups
+//! - Peer references checked before a VNET's virtual router is removed
 
 mod ffi;
 mod orch;
@@ -16,6 +18,7 @@ mod types;
 pub use ffi::{register_vnet_orch, unregister_vnet_orch};
 pub use orch::{VnetOrch, VnetOrchCallbacks, VnetOrchConfig, VnetOrchError, VnetOrchStats};
 pub use types::{
-    VnetBridgePortEntry, VnetBridgePortKey, VnetConfig, VnetEntry, VnetKey, VnetRouteConfig,
-    VnetRouteEntry, VnetRouteKey, VnetRouteType, VnetStats, Vni,
+    tables, VnetBridgePortEntry, VnetBridgePortKey, VnetConfig, VnetEndpoint, VnetEntry, VnetKey,
+    VnetNextHopGroup, VnetRouteConfig, VnetRouteEntry, VnetRouteKey, VnetRouteTunnelConfig,
+    VnetRouteType, VnetStats, VnetTunnelNextHop, VnetTunnelNextHopKey, Vni, MAX_VNI,
 };
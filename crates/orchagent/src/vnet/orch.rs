@@ -1,9 +1,16 @@
 //! VNET orchestration logic.
 
-use super::types::{VnetEntry, VnetKey, VnetRouteEntry, VnetRouteKey, VnetStats};
+use super::types::{
+    RawSaiObjectId, VnetConfig, VnetEndpoint, VnetEntry, VnetKey, VnetNextHopGroup,
+    VnetRouteConfig, VnetRouteEntry, VnetRouteKey, VnetRouteTunnelConfig, VnetRouteType, VnetStats,
+    VnetTunnelNextHop, VnetTunnelNextHopKey,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
+use sonic_orch_common::TaskStatus;
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum VnetOrchError {
@@ -19,6 +26,10 @@ pub enum VnetOrchError {
     VniNotFound(u32),
     #[error("Tunnel not found: {0}")]
     TunnelNotFound(String),
+    #[error("VNET {0} is still referenced by peer VNETs")]
+    VnetInUse(String),
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
     #[error("SAI error: {0}")]
     SaiError(String),
 }
@@ -36,6 +47,33 @@ pub struct VnetOrchStats {
 }
 
 pub trait VnetOrchCallbacks: Send + Sync {
+    /// Looks up a VXLAN tunnel created by VxlanOrch.
+    fn get_vxlan_tunnel_oid(&self, tunnel_name: &str) -> Option<RawSaiObjectId>;
+    fn create_virtual_router(&self, config: &VnetConfig) -> Result<RawSaiObjectId, String>;
+    fn remove_virtual_router(&self, vrf_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_tunnel_next_hop(&self, key: &VnetTunnelNextHopKey) -> Result<RawSaiObjectId, String>;
+    fn remove_tunnel_next_hop(&self, nh_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_next_hop_group(&self) -> Result<RawSaiObjectId, String>;
+    fn remove_next_hop_group(&self, nhg_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_next_hop_group_member(
+        &self,
+        nhg_oid: RawSaiObjectId,
+        nh_oid: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId, String>;
+    fn remove_next_hop_group_member(&self, member_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_route(
+        &self,
+        vrf_oid: RawSaiObjectId,
+        prefix: &str,
+        nh_oid: RawSaiObjectId,
+    ) -> Result<(), String>;
+    fn set_route_next_hop(
+        &self,
+        vrf_oid: RawSaiObjectId,
+        prefix: &str,
+        nh_oid: RawSaiObjectId,
+    ) -> Result<(), String>;
+    fn remove_route(&self, vrf_oid: RawSaiObjectId, prefix: &str) -> Result<(), String>;
     fn on_vnet_created(&self, entry: &VnetEntry);
     fn on_vnet_removed(&self, key: &VnetKey);
     fn on_route_created(&self, entry: &VnetRouteEntry);
@@ -45,8 +83,12 @@ pub trait VnetOrchCallbacks: Send + Sync {
 pub struct VnetOrch {
     config: VnetOrchConfig,
     stats: VnetOrchStats,
+    callbacks: Option<Arc<dyn VnetOrchCallbacks>>,
     vnets: HashMap<VnetKey, VnetEntry>,
     routes: HashMap<VnetRouteKey, VnetRouteEntry>,
+    next_hops: HashMap<VnetTunnelNextHopKey, VnetTunnelNextHop>,
+    /// Last reported state per monitored endpoint address.
+    endpoint_health: HashMap<IpAddr, bool>,
 }
 
 impl VnetOrch {
@@ -54,15 +96,549 @@ impl VnetOrch {
         Self {
             config,
             stats: VnetOrchStats::default(),
+            callbacks: None,
             vnets: HashMap::new(),
             routes: HashMap::new(),
+            next_hops: HashMap::new(),
+            endpoint_health: HashMap::new(),
         }
     }
 
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn VnetOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    fn callbacks(&self) -> Result<Arc<dyn VnetOrchCallbacks>, VnetOrchError> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| VnetOrchError::SaiError("No callbacks set".to_string()))
+    }
+
     pub fn get_vnet(&self, key: &VnetKey) -> Option<&VnetEntry> {
         self.vnets.get(key)
     }
 
+    pub fn next_hop_count(&self) -> usize {
+        self.next_hops.len()
+    }
+
+    pub fn next_hop_ref_count(&self, key: &VnetTunnelNextHopKey) -> u32 {
+        self.next_hops.get(key).map_or(0, |nh| nh.ref_count)
+    }
+
+    /// Handles a VNET SET.
+    ///
+    /// Creates the VNET's virtual router once its VXLAN tunnel exists. Peers
+    /// need not exist yet, so mutually peered VNETs can be created in any
+    /// order; their routes wait for them instead. Only `peer_list`, `scope`
+    /// and `advertise_prefix` may change on an existing VNET.
+    pub fn handle_vnet_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, VnetOrchError> {
+        let mut config = VnetConfig::new(name.to_string());
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(VnetOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(VnetOrchError::InvalidConfig)?;
+
+        let key = VnetKey::new(name.to_string());
+        if let Some(existing) = self.vnets.get(&key) {
+            if existing.config.vni != config.vni
+                || existing.config.vxlan_tunnel != config.vxlan_tunnel
+            {
+                return Err(VnetOrchError::InvalidConfig(format!(
+                    "{}: vni and vxlan_tunnel cannot change on an existing VNET",
+                    name
+                )));
+            }
+            return self.update_vnet(config);
+        }
+
+        let callbacks = self.callbacks()?;
+        let tunnel_name = config.vxlan_tunnel.clone().unwrap_or_default();
+        let Some(tunnel_oid) = callbacks.get_vxlan_tunnel_oid(&tunnel_name) else {
+            return Ok(TaskStatus::NeedRetry);
+        };
+        let vrf_oid = callbacks
+            .create_virtual_router(&config)
+            .map_err(VnetOrchError::SaiError)?;
+
+        let mut entry = VnetEntry::new(config);
+        entry.vrf_oid = vrf_oid;
+        entry.tunnel_oid = tunnel_oid;
+        callbacks.on_vnet_created(&entry);
+        self.add_vnet(entry)?;
+        Ok(TaskStatus::Success)
+    }
+
+    fn update_vnet(&mut self, config: VnetConfig) -> Result<TaskStatus, VnetOrchError> {
+        let key = VnetKey::new(config.vnet_name.clone());
+        let route_keys: Vec<VnetRouteKey> = self
+            .routes
+            .values()
+            .filter(|route| route.key.vnet_name == key.vnet_name && route.is_tunnel_route())
+            .map(|route| route.key.clone())
+            .collect();
+        if !route_keys.is_empty()
+            && config
+                .peer_list
+                .iter()
+                .any(|peer| !self.vnets.contains_key(&VnetKey::new(peer.clone())))
+        {
+            return Ok(TaskStatus::NeedRetry);
+        }
+
+        let entry = self
+            .vnets
+            .get_mut(&key)
+            .ok_or_else(|| VnetOrchError::VnetNotFound(key.clone()))?;
+        let peers_changed = entry.config.peer_list != config.peer_list;
+        entry.config = config;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "VnetOrch", "update_vnet")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(key.vnet_name.clone())
+                .with_object_type("vnet")
+                .with_details(serde_json::json!({
+                    "vnet_name": key.vnet_name,
+                    "peer_list": entry.config.peer_list,
+                    "advertise_prefix": entry.config.advertise_prefix,
+                }))
+        );
+
+        if peers_changed {
+            for route_key in &route_keys {
+                self.reprogram_route(route_key)?;
+            }
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a VNET DEL.
+    ///
+    /// Fails while the VNET has routes or is listed in another VNET's
+    /// `peer_list`; mutually peered VNETs must drop the peering first.
+    pub fn handle_vnet_del(&mut self, name: &str) -> Result<TaskStatus, VnetOrchError> {
+        let key = VnetKey::new(name.to_string());
+        let vrf_oid = self
+            .vnets
+            .get(&key)
+            .map(|vnet| vnet.vrf_oid)
+            .ok_or_else(|| VnetOrchError::VnetNotFound(key.clone()))?;
+        self.check_vnet_removable(&key)?;
+
+        let callbacks = self.callbacks()?;
+        callbacks
+            .remove_virtual_router(vrf_oid)
+            .map_err(VnetOrchError::SaiError)?;
+        self.remove_vnet(&key)?;
+        callbacks.on_vnet_removed(&key);
+        Ok(TaskStatus::Success)
+    }
+
+    /// Handles a VNET_ROUTE_TUNNEL SET for key `<vnet>|<prefix>`.
+    ///
+    /// The route is installed in the VNET's virtual router and in those of
+    /// its peers, and retried until all of them exist. One healthy endpoint
+    /// is used directly; several are load-balanced through a next hop group
+    /// whose members are updated in place as endpoints come and go.
+    pub fn handle_tunnel_route_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<TaskStatus, VnetOrchError> {
+        let route_key = Self::parse_route_key(key)?;
+        let mut config = VnetRouteTunnelConfig::new();
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(VnetOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(VnetOrchError::InvalidConfig)?;
+        let endpoints = config.endpoints();
+
+        if !self
+            .vnets
+            .contains_key(&VnetKey::new(route_key.vnet_name.clone()))
+        {
+            return Ok(TaskStatus::NeedRetry);
+        }
+
+        match self.routes.get_mut(&route_key) {
+            Some(existing) if !existing.is_tunnel_route() => {
+                Err(VnetOrchError::InvalidConfig(format!(
+                    "{}: route exists with type {:?}",
+                    key, existing.config.route_type
+                )))
+            }
+            Some(existing) => {
+                if existing.endpoints == endpoints {
+                    return Ok(TaskStatus::Success);
+                }
+                existing.config = Self::tunnel_route_config(&endpoints);
+                existing.endpoints = endpoints;
+                self.reprogram_route(&route_key)
+            }
+            None => {
+                if self.route_vrfs(&route_key.vnet_name)?.is_none() {
+                    return Ok(TaskStatus::NeedRetry);
+                }
+                let mut entry =
+                    VnetRouteEntry::new(route_key.clone(), Self::tunnel_route_config(&endpoints));
+                entry.endpoints = endpoints;
+                self.add_route(entry)?;
+                if let Err(e) = self.reprogram_route(&route_key) {
+                    // Undo whatever was programmed so a retry starts clean.
+                    if let Some(mut route) = self.routes.remove(&route_key) {
+                        route.endpoints.clear();
+                        let _ = self.program_tunnel_route(&mut route, &[]);
+                    }
+                    return Err(e);
+                }
+                if let (Some(callbacks), Some(route)) =
+                    (&self.callbacks, self.routes.get(&route_key))
+                {
+                    callbacks.on_route_created(route);
+                }
+                Ok(TaskStatus::Success)
+            }
+        }
+    }
+
+    /// Handles a VNET_ROUTE_TUNNEL DEL, withdrawing the route from every
+    /// virtual router and releasing its next hops.
+    pub fn handle_tunnel_route_del(&mut self, key: &str) -> Result<TaskStatus, VnetOrchError> {
+        let route_key = Self::parse_route_key(key)?;
+        let mut route = self
+            .routes
+            .remove(&route_key)
+            .ok_or_else(|| VnetOrchError::RouteNotFound(route_key.clone()))?;
+
+        route.endpoints.clear();
+        let result = self.program_tunnel_route(&mut route, &[]);
+        self.routes.insert(route_key.clone(), route);
+        result?;
+
+        self.remove_route(&route_key)?;
+        if let Some(callbacks) = &self.callbacks {
+            callbacks.on_route_removed(&route_key);
+        }
+        Ok(TaskStatus::Success)
+    }
+
+    /// Records the health of a monitored endpoint and reprograms every
+    /// tunnel route using it. Monitored endpoints start out down.
+    pub fn update_endpoint_health(
+        &mut self,
+        monitor: IpAddr,
+        up: bool,
+    ) -> Result<(), VnetOrchError> {
+        if self.endpoint_health.insert(monitor, up) == Some(up) {
+            return Ok(());
+        }
+
+        let route_keys: Vec<VnetRouteKey> = self
+            .routes
+            .values()
+            .filter(|route| route.endpoints.iter().any(|ep| ep.monitor == Some(monitor)))
+            .map(|route| route.key.clone())
+            .collect();
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceModify,
+            "VnetOrch",
+            "update_endpoint_health"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(monitor.to_string())
+        .with_object_type("vnet_endpoint")
+        .with_details(serde_json::json!({
+            "monitor": monitor.to_string(),
+            "up": up,
+            "routes": route_keys.len(),
+        })));
+
+        let mut result = Ok(());
+        for route_key in &route_keys {
+            if let Err(e) = self.reprogram_route(route_key) {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    fn parse_route_key(key: &str) -> Result<VnetRouteKey, VnetOrchError> {
+        let (vnet_name, prefix) = key
+            .split_once('|')
+            .filter(|(vnet, _)| !vnet.is_empty())
+            .ok_or_else(|| VnetOrchError::InvalidConfig(format!("Invalid route key: {}", key)))?;
+        let valid = prefix.split_once('/').is_some_and(|(addr, len)| {
+            match (addr.parse::<IpAddr>(), len.parse::<u8>()) {
+                (Ok(IpAddr::V4(_)), Ok(len)) => len <= 32,
+                (Ok(IpAddr::V6(_)), Ok(len)) => len <= 128,
+                _ => false,
+            }
+        });
+        if !valid {
+            return Err(VnetOrchError::InvalidPrefix(prefix.to_string()));
+        }
+        Ok(VnetRouteKey::new(vnet_name.to_string(), prefix.to_string()))
+    }
+
+    /// Summarizes the first endpoint into the legacy single-endpoint config.
+    fn tunnel_route_config(endpoints: &[VnetEndpoint]) -> VnetRouteConfig {
+        let first = endpoints.first();
+        VnetRouteConfig {
+            route_type: VnetRouteType::Tunnel,
+            endpoint: first.map(|ep| ep.ip),
+            endpoint_monitor: first.and_then(|ep| ep.monitor),
+            mac_address: first.and_then(|ep| ep.mac_address.clone()),
+            vni: first.and_then(|ep| ep.vni),
+            peer_list: vec![],
+        }
+    }
+
+    /// Virtual routers a route of `vnet_name` belongs in: the VNET's own,
+    /// then each peer's. Returns None while a peer does not exist yet.
+    fn route_vrfs(&self, vnet_name: &str) -> Result<Option<Vec<RawSaiObjectId>>, VnetOrchError> {
+        let key = VnetKey::new(vnet_name.to_string());
+        let vnet = self
+            .vnets
+            .get(&key)
+            .ok_or_else(|| VnetOrchError::VnetNotFound(key.clone()))?;
+        let mut vrfs = vec![vnet.vrf_oid];
+        for peer in &vnet.config.peer_list {
+            match self.vnets.get(&VnetKey::new(peer.clone())) {
+                Some(peer) => vrfs.push(peer.vrf_oid),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(vrfs))
+    }
+
+    fn reprogram_route(&mut self, key: &VnetRouteKey) -> Result<TaskStatus, VnetOrchError> {
+        let Some(vrfs) = self.route_vrfs(&key.vnet_name)? else {
+            return Ok(TaskStatus::NeedRetry);
+        };
+        let mut route = self
+            .routes
+            .remove(key)
+            .ok_or_else(|| VnetOrchError::RouteNotFound(key.clone()))?;
+        let result = self.program_tunnel_route(&mut route, &vrfs);
+        self.routes.insert(key.clone(), route);
+        result.map(|_| TaskStatus::Success)
+    }
+
+    fn is_endpoint_healthy(&self, endpoint: &VnetEndpoint) -> bool {
+        match endpoint.monitor {
+            Some(monitor) => self.endpoint_health.get(&monitor) == Some(&true),
+            None => true,
+        }
+    }
+
+    /// Points `route` at its healthy endpoints in every router of `vrfs`,
+    /// withdrawing it where it no longer belongs or when nothing is healthy.
+    fn program_tunnel_route(
+        &mut self,
+        route: &mut VnetRouteEntry,
+        vrfs: &[RawSaiObjectId],
+    ) -> Result<(), VnetOrchError> {
+        let callbacks = self.callbacks()?;
+        let vnet_key = VnetKey::new(route.key.vnet_name.clone());
+        let vnet = self
+            .vnets
+            .get(&vnet_key)
+            .ok_or_else(|| VnetOrchError::VnetNotFound(vnet_key.clone()))?;
+        let healthy: Vec<VnetTunnelNextHopKey> = route
+            .endpoints
+            .iter()
+            .filter(|ep| self.is_endpoint_healthy(ep))
+            .map(|ep| VnetTunnelNextHopKey {
+                tunnel_oid: vnet.tunnel_oid,
+                endpoint: ep.ip,
+                vni: ep.vni.or(vnet.config.vni).unwrap_or_default(),
+                mac_address: ep.mac_address.clone(),
+            })
+            .collect();
+        let old_nh_oid = route.nh_oid;
+
+        if healthy.len() > 1 {
+            if let Some(nhg) = route.nhg.as_mut() {
+                self.sync_group_members(callbacks.as_ref(), nhg, &healthy)?;
+                return Self::install_route(callbacks.as_ref(), route, vrfs, old_nh_oid);
+            }
+        }
+
+        let (nh_oid, nh_key, nhg) = match healthy.as_slice() {
+            [] => (0, None, None),
+            [key] => (
+                self.acquire_next_hop(callbacks.as_ref(), key)?,
+                Some(key.clone()),
+                None,
+            ),
+            _ => {
+                let nhg = self.create_group(callbacks.as_ref(), &healthy)?;
+                (nhg.nhg_oid, None, Some(nhg))
+            }
+        };
+        let old_nh_key = std::mem::replace(&mut route.nh_key, nh_key);
+        let old_nhg = std::mem::replace(&mut route.nhg, nhg);
+        route.nh_oid = nh_oid;
+
+        Self::install_route(callbacks.as_ref(), route, vrfs, old_nh_oid)?;
+
+        if let Some(key) = old_nh_key {
+            self.release_next_hop(callbacks.as_ref(), &key);
+        }
+        if let Some(nhg) = old_nhg {
+            self.remove_group(callbacks.as_ref(), nhg);
+        }
+        Ok(())
+    }
+
+    fn install_route(
+        callbacks: &dyn VnetOrchCallbacks,
+        route: &mut VnetRouteEntry,
+        vrfs: &[RawSaiObjectId],
+        old_nh_oid: RawSaiObjectId,
+    ) -> Result<(), VnetOrchError> {
+        let prefix = route.key.prefix.clone();
+        for vrf_oid in route.vrf_oids.clone() {
+            if route.nh_oid == 0 || !vrfs.contains(&vrf_oid) {
+                callbacks
+                    .remove_route(vrf_oid, &prefix)
+                    .map_err(VnetOrchError::SaiError)?;
+                route.vrf_oids.retain(|oid| *oid != vrf_oid);
+            }
+        }
+        if route.nh_oid == 0 {
+            return Ok(());
+        }
+        for &vrf_oid in vrfs {
+            if !route.vrf_oids.contains(&vrf_oid) {
+                callbacks
+                    .create_route(vrf_oid, &prefix, route.nh_oid)
+                    .map_err(VnetOrchError::SaiError)?;
+                route.vrf_oids.push(vrf_oid);
+            } else if route.nh_oid != old_nh_oid {
+                callbacks
+                    .set_route_next_hop(vrf_oid, &prefix, route.nh_oid)
+                    .map_err(VnetOrchError::SaiError)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn acquire_next_hop(
+        &mut self,
+        callbacks: &dyn VnetOrchCallbacks,
+        key: &VnetTunnelNextHopKey,
+    ) -> Result<RawSaiObjectId, VnetOrchError> {
+        if let Some(nh) = self.next_hops.get_mut(key) {
+            nh.ref_count += 1;
+            return Ok(nh.nh_oid);
+        }
+        let nh_oid = callbacks
+            .create_tunnel_next_hop(key)
+            .map_err(VnetOrchError::SaiError)?;
+        self.next_hops.insert(
+            key.clone(),
+            VnetTunnelNextHop {
+                nh_oid,
+                ref_count: 1,
+            },
+        );
+        self.stats.stats.next_hops_created = self.stats.stats.next_hops_created.saturating_add(1);
+        Ok(nh_oid)
+    }
+
+    fn release_next_hop(&mut self, callbacks: &dyn VnetOrchCallbacks, key: &VnetTunnelNextHopKey) {
+        let Some(nh) = self.next_hops.get_mut(key) else {
+            return;
+        };
+        nh.ref_count = nh.ref_count.saturating_sub(1);
+        if nh.ref_count == 0 {
+            let nh_oid = nh.nh_oid;
+            self.next_hops.remove(key);
+            let _ = callbacks.remove_tunnel_next_hop(nh_oid);
+        }
+    }
+
+    fn create_group(
+        &mut self,
+        callbacks: &dyn VnetOrchCallbacks,
+        members: &[VnetTunnelNextHopKey],
+    ) -> Result<VnetNextHopGroup, VnetOrchError> {
+        let nhg_oid = callbacks
+            .create_next_hop_group()
+            .map_err(VnetOrchError::SaiError)?;
+        let mut nhg = VnetNextHopGroup {
+            nhg_oid,
+            members: HashMap::new(),
+        };
+        if let Err(e) = self.sync_group_members(callbacks, &mut nhg, members) {
+            self.remove_group(callbacks, nhg);
+            return Err(e);
+        }
+        self.stats.stats.next_hop_groups_created =
+            self.stats.stats.next_hop_groups_created.saturating_add(1);
+        Ok(nhg)
+    }
+
+    /// Adds missing members before removing stale ones so the group never
+    /// goes empty while routes point at it.
+    fn sync_group_members(
+        &mut self,
+        callbacks: &dyn VnetOrchCallbacks,
+        nhg: &mut VnetNextHopGroup,
+        members: &[VnetTunnelNextHopKey],
+    ) -> Result<(), VnetOrchError> {
+        for key in members {
+            if nhg.members.contains_key(key) {
+                continue;
+            }
+            let nh_oid = self.acquire_next_hop(callbacks, key)?;
+            match callbacks.create_next_hop_group_member(nhg.nhg_oid, nh_oid) {
+                Ok(member_oid) => {
+                    nhg.members.insert(key.clone(), member_oid);
+                }
+                Err(e) => {
+                    self.release_next_hop(callbacks, key);
+                    return Err(VnetOrchError::SaiError(e));
+                }
+            }
+        }
+
+        let stale: Vec<VnetTunnelNextHopKey> = nhg
+            .members
+            .keys()
+            .filter(|key| !members.contains(key))
+            .cloned()
+            .collect();
+        for key in stale {
+            if let Some(member_oid) = nhg.members.remove(&key) {
+                let _ = callbacks.remove_next_hop_group_member(member_oid);
+            }
+            self.release_next_hop(callbacks, &key);
+        }
+        Ok(())
+    }
+
+    fn remove_group(&mut self, callbacks: &dyn VnetOrchCallbacks, nhg: VnetNextHopGroup) {
+        for (key, member_oid) in nhg.members {
+            let _ = callbacks.remove_next_hop_group_member(member_oid);
+            self.release_next_hop(callbacks, &key);
+        }
+        let _ = callbacks.remove_next_hop_group(nhg.nhg_oid);
+    }
+
     pub fn add_vnet(&mut self, entry: VnetEntry) -> Result<(), VnetOrchError> {
         let key = entry.key.clone();
 
@@ -100,23 +676,7 @@ impl VnetOrch {
     }
 
     pub fn remove_vnet(&mut self, key: &VnetKey) -> Result<VnetEntry, VnetOrchError> {
-        // Check if any routes exist for this VNET
-        let has_routes = self
-            .routes
-            .keys()
-            .any(|route_key| route_key.vnet_name == key.vnet_name);
-
-        if has_routes {
-            let error = VnetOrchError::SaiError(format!("VNET {} still has routes", key.vnet_name));
-            audit_log!(
-                AuditRecord::new(AuditCategory::ResourceDelete, "VnetOrch", "remove_vnet")
-                    .with_outcome(AuditOutcome::Failure)
-                    .with_object_id(key.vnet_name.clone())
-                    .with_object_type("vnet")
-                    .with_error(error.to_string())
-            );
-            return Err(error);
-        }
+        self.check_vnet_removable(key)?;
 
         let entry = self
             .vnets
@@ -137,6 +697,41 @@ impl VnetOrch {
         Ok(entry)
     }
 
+    /// Fails while routes exist in the VNET or another VNET lists it as a peer.
+    fn check_vnet_removable(&self, key: &VnetKey) -> Result<(), VnetOrchError> {
+        // Check if any routes exist for this VNET
+        let has_routes = self
+            .routes
+            .keys()
+            .any(|route_key| route_key.vnet_name == key.vnet_name);
+        let peered_by: Vec<&str> = self
+            .vnets
+            .values()
+            .filter(|vnet| vnet.key != *key && vnet.config.peer_list.contains(&key.vnet_name))
+            .map(|vnet| vnet.config.vnet_name.as_str())
+            .collect();
+
+        if has_routes || !peered_by.is_empty() {
+            let error = if has_routes {
+                VnetOrchError::SaiError(format!("VNET {} still has routes", key.vnet_name))
+            } else {
+                VnetOrchError::VnetInUse(key.vnet_name.clone())
+            };
+            audit_log!(
+                AuditRecord::new(AuditCategory::ResourceDelete, "VnetOrch", "remove_vnet")
+                    .with_outcome(AuditOutcome::Failure)
+                    .with_object_id(key.vnet_name.clone())
+                    .with_object_type("vnet")
+                    .with_error(error.to_string())
+                    .with_details(serde_json::json!({
+                        "peered_by": peered_by,
+                    }))
+            );
+            return Err(error);
+        }
+        Ok(())
+    }
+
     pub fn get_route(&self, key: &VnetRouteKey) -> Option<&VnetRouteEntry> {
         self.routes.get(key)
     }
@@ -244,9 +839,186 @@ impl VnetOrch {
 
 #[cfg(test)]
 mod tests {
-    use super::super::types::{VnetConfig, VnetRouteConfig, VnetRouteType};
     use super::*;
-    use std::net::IpAddr;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        next_oid: AtomicU64,
+        live: Mutex<HashSet<RawSaiObjectId>>,
+        tunnels: Mutex<HashMap<String, RawSaiObjectId>>,
+        /// Installed routes: (vrf, prefix) -> next hop or group.
+        routes: Mutex<HashMap<(RawSaiObjectId, String), RawSaiObjectId>>,
+        /// Group member -> owning group.
+        members: Mutex<HashMap<RawSaiObjectId, RawSaiObjectId>>,
+    }
+
+    impl MockCallbacks {
+        fn alloc(&self) -> RawSaiObjectId {
+            let oid = self.next_oid.fetch_add(1, Ordering::SeqCst) + 0x1000;
+            self.live.lock().unwrap().insert(oid);
+            oid
+        }
+
+        fn free(&self, oid: RawSaiObjectId) -> Result<(), String> {
+            if self.live.lock().unwrap().remove(&oid) {
+                Ok(())
+            } else {
+                Err(format!("unknown oid {:#x}", oid))
+            }
+        }
+
+        fn add_tunnel(&self, name: &str) {
+            let oid = self.alloc();
+            self.tunnels.lock().unwrap().insert(name.to_string(), oid);
+        }
+
+        fn live_count(&self) -> usize {
+            self.live.lock().unwrap().len()
+        }
+
+        fn route(&self, vrf_oid: RawSaiObjectId, prefix: &str) -> Option<RawSaiObjectId> {
+            self.routes
+                .lock()
+                .unwrap()
+                .get(&(vrf_oid, prefix.to_string()))
+                .copied()
+        }
+
+        fn group_size(&self, nhg_oid: RawSaiObjectId) -> usize {
+            self.members
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|group| **group == nhg_oid)
+                .count()
+        }
+    }
+
+    impl VnetOrchCallbacks for MockCallbacks {
+        fn get_vxlan_tunnel_oid(&self, tunnel_name: &str) -> Option<RawSaiObjectId> {
+            self.tunnels.lock().unwrap().get(tunnel_name).copied()
+        }
+        fn create_virtual_router(&self, _: &VnetConfig) -> Result<RawSaiObjectId, String> {
+            Ok(self.alloc())
+        }
+        fn remove_virtual_router(&self, vrf_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(vrf_oid)
+        }
+        fn create_tunnel_next_hop(
+            &self,
+            _: &VnetTunnelNextHopKey,
+        ) -> Result<RawSaiObjectId, String> {
+            Ok(self.alloc())
+        }
+        fn remove_tunnel_next_hop(&self, nh_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(nh_oid)
+        }
+        fn create_next_hop_group(&self) -> Result<RawSaiObjectId, String> {
+            Ok(self.alloc())
+        }
+        fn remove_next_hop_group(&self, nhg_oid: RawSaiObjectId) -> Result<(), String> {
+            if self.group_size(nhg_oid) > 0 {
+                return Err(format!("group {:#x} still has members", nhg_oid));
+            }
+            self.free(nhg_oid)
+        }
+        fn create_next_hop_group_member(
+            &self,
+            nhg_oid: RawSaiObjectId,
+            _: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId, String> {
+            let oid = self.alloc();
+            self.members.lock().unwrap().insert(oid, nhg_oid);
+            Ok(oid)
+        }
+        fn remove_next_hop_group_member(&self, member_oid: RawSaiObjectId) -> Result<(), String> {
+            self.members.lock().unwrap().remove(&member_oid);
+            self.free(member_oid)
+        }
+        fn create_route(
+            &self,
+            vrf_oid: RawSaiObjectId,
+            prefix: &str,
+            nh_oid: RawSaiObjectId,
+        ) -> Result<(), String> {
+            let mut routes = self.routes.lock().unwrap();
+            if routes
+                .insert((vrf_oid, prefix.to_string()), nh_oid)
+                .is_some()
+            {
+                return Err(format!("route {} already exists", prefix));
+            }
+            Ok(())
+        }
+        fn set_route_next_hop(
+            &self,
+            vrf_oid: RawSaiObjectId,
+            prefix: &str,
+            nh_oid: RawSaiObjectId,
+        ) -> Result<(), String> {
+            match self
+                .routes
+                .lock()
+                .unwrap()
+                .get_mut(&(vrf_oid, prefix.to_string()))
+            {
+                Some(nh) => {
+                    *nh = nh_oid;
+                    Ok(())
+                }
+                None => Err(format!("route {} not found", prefix)),
+            }
+        }
+        fn remove_route(&self, vrf_oid: RawSaiObjectId, prefix: &str) -> Result<(), String> {
+            self.routes
+                .lock()
+                .unwrap()
+                .remove(&(vrf_oid, prefix.to_string()))
+                .map(|_| ())
+                .ok_or_else(|| format!("route {} not found", prefix))
+        }
+        fn on_vnet_created(&self, _: &VnetEntry) {}
+        fn on_vnet_removed(&self, _: &VnetKey) {}
+        fn on_route_created(&self, _: &VnetRouteEntry) {}
+        fn on_route_removed(&self, _: &VnetRouteKey) {}
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_mock() -> (VnetOrch, Arc<MockCallbacks>) {
+        let mock = Arc::new(MockCallbacks::default());
+        mock.add_tunnel("vtep");
+        let mut orch = VnetOrch::new(VnetOrchConfig::default());
+        orch.set_callbacks(mock.clone());
+        (orch, mock)
+    }
+
+    fn set_vnet(orch: &mut VnetOrch, name: &str, vni: &str, peers: &str) -> TaskStatus {
+        orch.handle_vnet_set(
+            name,
+            &fields(&[("vni", vni), ("vxlan_tunnel", "vtep"), ("peer_list", peers)]),
+        )
+        .unwrap()
+    }
+
+    fn vrf_of(orch: &VnetOrch, name: &str) -> RawSaiObjectId {
+        orch.get_vnet(&VnetKey::new(name.to_string()))
+            .unwrap()
+            .vrf_oid
+    }
+
+    fn tunnel_route<'a>(orch: &'a VnetOrch, vnet: &str, prefix: &str) -> &'a VnetRouteEntry {
+        orch.get_route(&VnetRouteKey::new(vnet.to_string(), prefix.to_string()))
+            .unwrap()
+    }
 
     fn create_test_vnet(name: &str, vni: Option<u32>) -> VnetEntry {
         VnetEntry::new(VnetConfig {
@@ -255,6 +1027,7 @@ mod tests {
             vxlan_tunnel: Some("tunnel0".to_string()),
             scope: None,
             advertise_prefix: false,
+            peer_list: vec![],
         })
     }
 
@@ -559,4 +1332,305 @@ mod tests {
             assert_eq!(route.config.route_type, VnetRouteType::Vnet);
         }
     }
+
+    #[test]
+    fn test_handle_vnet_set_creates_virtual_router() {
+        let (mut orch, mock) = orch_with_mock();
+        let baseline = mock.live_count();
+
+        let status = orch
+            .handle_vnet_set(
+                "Vnet1",
+                &fields(&[("vni", "1000"), ("vxlan_tunnel", "missing")]),
+            )
+            .unwrap();
+        assert_eq!(status, TaskStatus::NeedRetry);
+        assert_eq!(orch.vnet_count(), 0);
+
+        assert_eq!(
+            set_vnet(&mut orch, "Vnet1", "1000", ""),
+            TaskStatus::Success
+        );
+        let vnet = orch.get_vnet(&VnetKey::new("Vnet1".to_string())).unwrap();
+        assert_ne!(vnet.vrf_oid, 0);
+        assert_eq!(vnet.tunnel_oid, mock.get_vxlan_tunnel_oid("vtep").unwrap());
+        assert_eq!(mock.live_count(), baseline + 1);
+
+        // Re-applying is fine, changing the VNI is not.
+        assert_eq!(
+            set_vnet(&mut orch, "Vnet1", "1000", ""),
+            TaskStatus::Success
+        );
+        assert!(matches!(
+            orch.handle_vnet_set(
+                "Vnet1",
+                &fields(&[("vni", "2000"), ("vxlan_tunnel", "vtep")]),
+            ),
+            Err(VnetOrchError::InvalidConfig(_))
+        ));
+
+        assert_eq!(orch.handle_vnet_del("Vnet1").unwrap(), TaskStatus::Success);
+        assert_eq!(orch.vnet_count(), 0);
+        assert_eq!(mock.live_count(), baseline);
+    }
+
+    #[test]
+    fn test_tunnel_route_single_endpoint() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        let vrf = vrf_of(&orch, "Vnet1");
+
+        let status = orch
+            .handle_tunnel_route_set("Vnet1|10.10.0.0/24", &fields(&[("endpoint", "1.1.1.1")]))
+            .unwrap();
+        assert_eq!(status, TaskStatus::Success);
+
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert!(route.is_tunnel_route());
+        assert!(route.nhg.is_none());
+        assert_eq!(route.nh_key.as_ref().unwrap().vni, 1000);
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(route.nh_oid));
+        assert_eq!(orch.next_hop_count(), 1);
+    }
+
+    #[test]
+    fn test_tunnel_route_requires_vnet_and_valid_key() {
+        let (mut orch, _mock) = orch_with_mock();
+        let endpoint = fields(&[("endpoint", "1.1.1.1")]);
+
+        assert_eq!(
+            orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &endpoint)
+                .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert!(matches!(
+            orch.handle_tunnel_route_set("Vnet1|10.10.0.0/33", &endpoint),
+            Err(VnetOrchError::InvalidPrefix(_))
+        ));
+        assert!(matches!(
+            orch.handle_tunnel_route_set("Vnet1", &endpoint),
+            Err(VnetOrchError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &[]),
+            Err(VnetOrchError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_tunnel_route_ecmp_shrinks_from_three_to_one() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        let vrf = vrf_of(&orch, "Vnet1");
+        let baseline = mock.live_count();
+
+        orch.handle_tunnel_route_set(
+            "Vnet1|10.10.0.0/24",
+            &fields(&[("endpoint", "1.1.1.1,2.2.2.2,3.3.3.3")]),
+        )
+        .unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        let nhg_oid = route.nhg.as_ref().unwrap().nhg_oid;
+        assert_eq!(route.nh_oid, nhg_oid);
+        assert_eq!(route.active_endpoint_count(), 3);
+        assert_eq!(mock.group_size(nhg_oid), 3);
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(nhg_oid));
+        // 3 next hops, the group and its 3 members.
+        assert_eq!(mock.live_count(), baseline + 7);
+
+        orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &fields(&[("endpoint", "2.2.2.2")]))
+            .unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert!(route.nhg.is_none());
+        assert_eq!(route.active_endpoint_count(), 1);
+        assert_eq!(
+            route.nh_key.as_ref().unwrap().endpoint,
+            "2.2.2.2".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(route.nh_oid));
+        assert_eq!(mock.group_size(nhg_oid), 0);
+        assert_eq!(orch.next_hop_count(), 1);
+        assert_eq!(mock.live_count(), baseline + 1);
+        assert_eq!(orch.stats().stats.next_hop_groups_created, 1);
+    }
+
+    #[test]
+    fn test_tunnel_route_group_updated_in_place() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        let vrf = vrf_of(&orch, "Vnet1");
+
+        orch.handle_tunnel_route_set(
+            "Vnet1|10.10.0.0/24",
+            &fields(&[("endpoint", "1.1.1.1,2.2.2.2,3.3.3.3")]),
+        )
+        .unwrap();
+        let nhg_oid = tunnel_route(&orch, "Vnet1", "10.10.0.0/24")
+            .nhg
+            .as_ref()
+            .unwrap()
+            .nhg_oid;
+
+        orch.handle_tunnel_route_set(
+            "Vnet1|10.10.0.0/24",
+            &fields(&[("endpoint", "2.2.2.2,3.3.3.3,4.4.4.4")]),
+        )
+        .unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert_eq!(route.nhg.as_ref().unwrap().nhg_oid, nhg_oid);
+        assert_eq!(mock.group_size(nhg_oid), 3);
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(nhg_oid));
+        assert_eq!(orch.next_hop_count(), 3);
+        assert_eq!(orch.stats().stats.next_hop_groups_created, 1);
+    }
+
+    #[test]
+    fn test_tunnel_routes_share_next_hops() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        let baseline = mock.live_count();
+
+        orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &fields(&[("endpoint", "1.1.1.1")]))
+            .unwrap();
+        orch.handle_tunnel_route_set(
+            "Vnet1|10.20.0.0/24",
+            &fields(&[("endpoint", "1.1.1.1,2.2.2.2")]),
+        )
+        .unwrap();
+        let key = tunnel_route(&orch, "Vnet1", "10.10.0.0/24")
+            .nh_key
+            .clone()
+            .unwrap();
+        assert_eq!(orch.next_hop_ref_count(&key), 2);
+        assert_eq!(orch.next_hop_count(), 2);
+
+        orch.handle_tunnel_route_del("Vnet1|10.20.0.0/24").unwrap();
+        assert_eq!(orch.next_hop_ref_count(&key), 1);
+        orch.handle_tunnel_route_del("Vnet1|10.10.0.0/24").unwrap();
+        assert_eq!(orch.next_hop_count(), 0);
+        assert_eq!(orch.route_count(), 0);
+        assert_eq!(mock.live_count(), baseline);
+        assert!(mock.routes.lock().unwrap().is_empty());
+
+        assert!(matches!(
+            orch.handle_tunnel_route_del("Vnet1|10.10.0.0/24"),
+            Err(VnetOrchError::RouteNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_monitor_health() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        let vrf = vrf_of(&orch, "Vnet1");
+        let mon1: IpAddr = "9.1.1.1".parse().unwrap();
+        let mon2: IpAddr = "9.2.2.2".parse().unwrap();
+
+        orch.handle_tunnel_route_set(
+            "Vnet1|10.10.0.0/24",
+            &fields(&[
+                ("endpoint", "1.1.1.1,2.2.2.2"),
+                ("endpoint_monitor", "9.1.1.1,9.2.2.2"),
+            ]),
+        )
+        .unwrap();
+        // Monitored endpoints start down, so nothing is installed yet.
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), None);
+        assert_eq!(orch.next_hop_count(), 0);
+
+        orch.update_endpoint_health(mon1, true).unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert_eq!(route.active_endpoint_count(), 1);
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(route.nh_oid));
+
+        orch.update_endpoint_health(mon2, true).unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert_eq!(route.active_endpoint_count(), 2);
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), Some(route.nh_oid));
+
+        orch.update_endpoint_health(mon1, false).unwrap();
+        orch.update_endpoint_health(mon2, false).unwrap();
+        let route = tunnel_route(&orch, "Vnet1", "10.10.0.0/24");
+        assert_eq!(route.active_endpoint_count(), 0);
+        assert!(route.vrf_oids.is_empty());
+        assert_eq!(mock.route(vrf, "10.10.0.0/24"), None);
+        assert_eq!(orch.next_hop_count(), 0);
+        assert_eq!(orch.route_count(), 1);
+    }
+
+    #[test]
+    fn test_peered_vnet_routes_installed_in_peer() {
+        let (mut orch, mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "Vnet2");
+
+        // The peer does not exist yet.
+        let endpoint = fields(&[("endpoint", "1.1.1.1")]);
+        assert_eq!(
+            orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &endpoint)
+                .unwrap(),
+            TaskStatus::NeedRetry
+        );
+        assert_eq!(orch.route_count(), 0);
+
+        set_vnet(&mut orch, "Vnet2", "2000", "");
+        orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &endpoint)
+            .unwrap();
+        let nh_oid = tunnel_route(&orch, "Vnet1", "10.10.0.0/24").nh_oid;
+        assert_eq!(
+            mock.route(vrf_of(&orch, "Vnet1"), "10.10.0.0/24"),
+            Some(nh_oid)
+        );
+        assert_eq!(
+            mock.route(vrf_of(&orch, "Vnet2"), "10.10.0.0/24"),
+            Some(nh_oid)
+        );
+
+        // Dropping the peering withdraws the route from the former peer.
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        assert_eq!(mock.route(vrf_of(&orch, "Vnet2"), "10.10.0.0/24"), None);
+        assert_eq!(
+            mock.route(vrf_of(&orch, "Vnet1"), "10.10.0.0/24"),
+            Some(nh_oid)
+        );
+    }
+
+    #[test]
+    fn test_delete_peered_vnet_while_referenced() {
+        let (mut orch, mock) = orch_with_mock();
+        let baseline = mock.live_count();
+        set_vnet(&mut orch, "Vnet2", "2000", "");
+        set_vnet(&mut orch, "Vnet1", "1000", "Vnet2");
+        let vrf2 = vrf_of(&orch, "Vnet2");
+
+        let result = orch.handle_vnet_del("Vnet2");
+        assert!(matches!(result, Err(VnetOrchError::VnetInUse(ref name)) if name == "Vnet2"));
+        assert_eq!(orch.vnet_count(), 2);
+        assert!(mock.live.lock().unwrap().contains(&vrf2));
+        assert!(matches!(
+            orch.remove_vnet(&VnetKey::new("Vnet2".to_string())),
+            Err(VnetOrchError::VnetInUse(_))
+        ));
+
+        // Once the peering is dropped both VNETs can go.
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        orch.handle_vnet_del("Vnet2").unwrap();
+        orch.handle_vnet_del("Vnet1").unwrap();
+        assert_eq!(orch.vnet_count(), 0);
+        assert_eq!(mock.live_count(), baseline);
+    }
+
+    #[test]
+    fn test_delete_vnet_with_tunnel_routes() {
+        let (mut orch, _mock) = orch_with_mock();
+        set_vnet(&mut orch, "Vnet1", "1000", "");
+        orch.handle_tunnel_route_set("Vnet1|10.10.0.0/24", &fields(&[("endpoint", "1.1.1.1")]))
+            .unwrap();
+
+        assert!(orch.handle_vnet_del("Vnet1").is_err());
+        assert_eq!(orch.vnet_count(), 1);
+
+        orch.handle_tunnel_route_del("Vnet1|10.10.0.0/24").unwrap();
+        orch.handle_vnet_del("Vnet1").unwrap();
+        assert_eq!(orch.vnet_count(), 0);
+    }
 }
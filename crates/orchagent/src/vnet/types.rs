@@ -6,6 +6,33 @@ use std::net::IpAddr;
 pub type RawSaiObjectId = u64;
 pub type Vni = u32;
 
+/// Largest VNI representable in the 24-bit VXLAN header field.
+pub const MAX_VNI: Vni = 0x00FF_FFFF;
+
+/// Table names handled by VnetOrch.
+pub mod tables {
+    pub const VNET: &str = "VNET";
+    pub const VNET_ROUTE_TUNNEL: &str = "VNET_ROUTE_TUNNEL";
+}
+
+/// Splits a comma-separated list, skipping empty items.
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn parse_vni(value: &str) -> Result<Vni, String> {
+    let vni: Vni = value
+        .parse()
+        .map_err(|_| format!("Invalid VNI: {}", value))?;
+    if vni == 0 || vni > MAX_VNI {
+        return Err(format!("VNI {} out of range 1-{}", vni, MAX_VNI));
+    }
+    Ok(vni)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VnetKey {
     pub vnet_name: String,
@@ -24,6 +51,51 @@ pub struct VnetConfig {
     pub vxlan_tunnel: Option<String>,
     pub scope: Option<String>,
     pub advertise_prefix: bool,
+    /// VNETs whose virtual routers also receive this VNET's tunnel routes.
+    pub peer_list: Vec<String>,
+}
+
+impl VnetConfig {
+    pub fn new(vnet_name: String) -> Self {
+        Self {
+            vnet_name,
+            vni: None,
+            vxlan_tunnel: None,
+            scope: None,
+            advertise_prefix: false,
+            peer_list: Vec::new(),
+        }
+    }
+
+    /// Applies one VNET field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "vni" => self.vni = Some(parse_vni(value)?),
+            "vxlan_tunnel" => self.vxlan_tunnel = Some(value.to_string()),
+            "scope" => self.scope = Some(value.to_string()),
+            "advertise_prefix" => {
+                self.advertise_prefix = value
+                    .parse()
+                    .map_err(|_| format!("Invalid advertise_prefix: {}", value))?
+            }
+            "peer_list" => self.peer_list = split_list(value).map(str::to_string).collect(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.vni.is_none() {
+            return Err(format!("{}: vni is required", self.vnet_name));
+        }
+        if self.vxlan_tunnel.is_none() {
+            return Err(format!("{}: vxlan_tunnel is required", self.vnet_name));
+        }
+        if self.peer_list.contains(&self.vnet_name) {
+            return Err(format!("{}: VNET cannot peer with itself", self.vnet_name));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +104,8 @@ pub struct VnetEntry {
     pub config: VnetConfig,
     pub vrf_oid: RawSaiObjectId,
     pub vnet_oid: RawSaiObjectId,
+    /// VXLAN tunnel used to reach this VNET's endpoints.
+    pub tunnel_oid: RawSaiObjectId,
 }
 
 impl VnetEntry {
@@ -42,6 +116,7 @@ impl VnetEntry {
             config,
             vrf_oid: 0,
             vnet_oid: 0,
+            tunnel_oid: 0,
         }
     }
 }
@@ -75,12 +150,130 @@ pub struct VnetRouteConfig {
     pub peer_list: Vec<IpAddr>,
 }
 
+/// One remote endpoint of a VNET_ROUTE_TUNNEL entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VnetEndpoint {
+    pub ip: IpAddr,
+    /// Address probed to decide whether the endpoint is healthy.
+    pub monitor: Option<IpAddr>,
+    pub mac_address: Option<String>,
+    /// Overrides the VNET's VNI when set.
+    pub vni: Option<Vni>,
+}
+
+/// VNET_ROUTE_TUNNEL entry, keyed `<vnet>|<prefix>`.
+///
+/// `endpoint` is a comma-separated list; `endpoint_monitor`, `mac_address`
+/// and `vni`, when present, must list one value per endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VnetRouteTunnelConfig {
+    pub endpoint: Vec<IpAddr>,
+    pub endpoint_monitor: Vec<IpAddr>,
+    pub mac_address: Vec<String>,
+    pub vni: Vec<Vni>,
+}
+
+impl VnetRouteTunnelConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies one VNET_ROUTE_TUNNEL field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "endpoint" => self.endpoint = parse_ip_list(field, value)?,
+            "endpoint_monitor" => self.endpoint_monitor = parse_ip_list(field, value)?,
+            "mac_address" => self.mac_address = split_list(value).map(str::to_string).collect(),
+            "vni" => self.vni = split_list(value).map(parse_vni).collect::<Result<_, _>>()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.endpoint.is_empty() {
+            return Err("endpoint is required".to_string());
+        }
+        for (i, ip) in self.endpoint.iter().enumerate() {
+            if self.endpoint[..i].contains(ip) {
+                return Err(format!("Duplicate endpoint: {}", ip));
+            }
+        }
+        let count = self.endpoint.len();
+        for (field, len) in [
+            ("endpoint_monitor", self.endpoint_monitor.len()),
+            ("mac_address", self.mac_address.len()),
+            ("vni", self.vni.len()),
+        ] {
+            if len != 0 && len != count {
+                return Err(format!(
+                    "{} has {} entries but endpoint has {}",
+                    field, len, count
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Zips the per-endpoint lists together.
+    pub fn endpoints(&self) -> Vec<VnetEndpoint> {
+        self.endpoint
+            .iter()
+            .enumerate()
+            .map(|(i, ip)| VnetEndpoint {
+                ip: *ip,
+                monitor: self.endpoint_monitor.get(i).copied(),
+                mac_address: self.mac_address.get(i).cloned(),
+                vni: self.vni.get(i).copied(),
+            })
+            .collect()
+    }
+}
+
+fn parse_ip_list(field: &str, value: &str) -> Result<Vec<IpAddr>, String> {
+    split_list(value)
+        .map(|ip| ip.parse().map_err(|_| format!("Invalid {}: {}", field, ip)))
+        .collect()
+}
+
+/// Identity of a VXLAN tunnel next hop, shared by all routes using it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VnetTunnelNextHopKey {
+    pub tunnel_oid: RawSaiObjectId,
+    pub endpoint: IpAddr,
+    pub vni: Vni,
+    pub mac_address: Option<String>,
+}
+
+/// Reference-counted VXLAN tunnel next hop.
+#[derive(Debug, Clone)]
+pub struct VnetTunnelNextHop {
+    pub nh_oid: RawSaiObjectId,
+    pub ref_count: u32,
+}
+
+/// ECMP group over the healthy endpoints of a tunnel route.
+#[derive(Debug, Clone)]
+pub struct VnetNextHopGroup {
+    pub nhg_oid: RawSaiObjectId,
+    /// Group member OIDs keyed by the member next hop.
+    pub members: HashMap<VnetTunnelNextHopKey, RawSaiObjectId>,
+}
+
 #[derive(Debug, Clone)]
 pub struct VnetRouteEntry {
     pub key: VnetRouteKey,
     pub config: VnetRouteConfig,
     pub route_oid: RawSaiObjectId,
     pub nh_oid: RawSaiObjectId,
+    /// Endpoints configured through VNET_ROUTE_TUNNEL.
+    pub endpoints: Vec<VnetEndpoint>,
+    /// Next hop behind `nh_oid` when exactly one endpoint is healthy.
+    pub nh_key: Option<VnetTunnelNextHopKey>,
+    /// Group behind `nh_oid` when several endpoints are healthy.
+    pub nhg: Option<VnetNextHopGroup>,
+    /// Virtual routers the route is installed in.
+    pub vrf_oids: Vec<RawSaiObjectId>,
 }
 
 impl VnetRouteEntry {
@@ -90,6 +283,19 @@ impl VnetRouteEntry {
             config,
             route_oid: 0,
             nh_oid: 0,
+            endpoints: Vec::new(),
+            nh_key: None,
+            nhg: None,
+            vrf_oids: Vec::new(),
+        }
+    }
+
+    /// Number of endpoints currently carrying traffic.
+    pub fn active_endpoint_count(&self) -> usize {
+        match (&self.nhg, &self.nh_key) {
+            (Some(nhg), _) => nhg.members.len(),
+            (None, Some(_)) => 1,
+            (None, None) => 0,
         }
     }
 
@@ -137,4 +343,68 @@ pub struct VnetStats {
     pub vnets_created: u64,
     pub routes_created: u64,
     pub bridge_ports_created: u64,
+    pub next_hops_created: u64,
+    pub next_hop_groups_created: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vnet_config_parse() {
+        let mut config = VnetConfig::new("Vnet1".to_string());
+        assert!(config.validate().is_err());
+
+        config.parse_field("vni", "1000").unwrap();
+        config.parse_field("vxlan_tunnel", "vtep").unwrap();
+        config.parse_field("peer_list", "Vnet2, Vnet3").unwrap();
+        config.parse_field("advertise_prefix", "true").unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.peer_list, vec!["Vnet2", "Vnet3"]);
+        assert!(config.advertise_prefix);
+
+        assert!(config.parse_field("vni", "0").is_err());
+        assert!(config.parse_field("vni", "16777216").is_err());
+
+        config.parse_field("peer_list", "Vnet1").unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_route_tunnel_config_parse() {
+        let mut config = VnetRouteTunnelConfig::new();
+        assert!(config.validate().is_err());
+
+        config.parse_field("endpoint", "10.0.0.1,10.0.0.2").unwrap();
+        config
+            .parse_field("endpoint_monitor", "10.1.0.1,10.1.0.2")
+            .unwrap();
+        config.parse_field("vni", "2000,3000").unwrap();
+        assert!(config.validate().is_ok());
+
+        let endpoints = config.endpoints();
+        assert_eq!(endpoints.len(), 2);
+        assert_eq!(endpoints[1].ip, "10.0.0.2".parse::<IpAddr>().unwrap());
+        assert_eq!(
+            endpoints[1].monitor,
+            Some("10.1.0.2".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(endpoints[1].vni, Some(3000));
+        assert_eq!(endpoints[1].mac_address, None);
+
+        config
+            .parse_field("mac_address", "00:11:22:33:44:55")
+            .unwrap();
+        assert!(config.validate().is_err());
+
+        assert!(config.parse_field("endpoint", "10.0.0.1,bogus").is_err());
+    }
+
+    #[test]
+    fn test_route_tunnel_config_duplicate_endpoint() {
+        let mut config = VnetRouteTunnelConfig::new();
+        config.parse_field("endpoint", "10.0.0.1,10.0.0.1").unwrap();
+        assert!(config.validate().is_err());
+    }
 }
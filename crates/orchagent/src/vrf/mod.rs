@@ -3,6 +3,9 @@
 //! This module manages VRF (Virtual Routing and Forwarding) instances, enabling
 //! network segmentation and multi-tenancy support. Each VRF maintains its own
 //! routing table and can be associated with VXLAN VNI for overlay networking.
+//! A VRF's VNI can be changed in place, and each VNI maps to at most one VRF.
+//! Route, router interface and neighbor counts per VRF are published to
+//! STATE_DB.
 //!
 //! # Architecture
 //!
@@ -33,5 +36,7 @@ mod orch;
 mod types;
 
 pub use ffi::{register_vrf_orch, unregister_vrf_orch};
-pub use orch::{VrfOrch, VrfOrchCallbacks, VrfOrchConfig, VrfOrchError};
-pub use types::{L3VniEntry, Vni, VrfConfig, VrfEntry, VrfId, VrfName, VrfVlanId};
+pub use orch::{VrfOrch, VrfOrchCallbacks, VrfOrchConfig, VrfOrchError, VrfOrchStats};
+pub use types::{
+    L3VniEntry, Vni, VrfConfig, VrfEntry, VrfId, VrfName, VrfObjectType, VrfStats, VrfVlanId,
+};
@@ -2,10 +2,19 @@
 //!
 //! Manages Virtual Routing and Forwarding instances in SAI.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use super::types::{L3VniEntry, Vni, VrfConfig, VrfEntry, VrfId, VrfName, VrfVlanId};
+use async_trait::async_trait;
+use log::{debug, warn};
+use sonic_orch_common::{
+    Consumer, ConsumerConfig, KeyOpFieldsValues, Operation, Orch, Publisher, Subscription,
+    VrfUpdate,
+};
+
+use super::types::{
+    L3VniEntry, Vni, VrfConfig, VrfEntry, VrfId, VrfName, VrfObjectType, VrfStats, VrfVlanId,
+};
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 
@@ -27,6 +36,9 @@ pub enum VrfOrchError {
     /// VNI not found.
     #[error("VNI not found: {0}")]
     VniNotFound(u32),
+    /// VNI is already mapped to another VRF.
+    #[error("VNI {0} already mapped to VRF {1}")]
+    VniInUse(u32, String),
     /// Invalid configuration.
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
@@ -65,6 +77,24 @@ pub trait VrfOrchCallbacks: Send + Sync {
 
     /// Called when a VRF is removed from FlowCounterRouteOrch.
     fn on_remove_vr(&self, _vrf_id: VrfId) {}
+
+    /// Called to re-point the VxlanOrch tunnel map entry of a VRF from one
+    /// VNI to another.
+    fn update_vrf_vni_map(
+        &self,
+        _vrf_name: &str,
+        _vrf_id: VrfId,
+        _old_vni: Vni,
+        _new_vni: Vni,
+    ) -> bool {
+        true
+    }
+
+    /// Called to write the per-VRF object counts to STATE_DB VRF_TABLE.
+    fn publish_vrf_stats(&self, _name: &str, _stats: &VrfStats) {}
+
+    /// Called to delete the per-VRF object counts from STATE_DB VRF_TABLE.
+    fn remove_vrf_stats(&self, _name: &str) {}
 }
 
 /// Default no-op callbacks.
@@ -104,6 +134,8 @@ pub struct VrfOrchStats {
     pub vni_mappings_created: u64,
     /// Number of VNI mappings removed.
     pub vni_mappings_removed: u64,
+    /// Number of VNI mappings moved in place to a new VNI.
+    pub vni_mappings_updated: u64,
 }
 
 /// VRFOrch - manages Virtual Routing and Forwarding instances.
@@ -120,6 +152,12 @@ pub struct VrfOrch {
     vrf_vni_map: HashMap<VrfName, Vni>,
     /// L3 VNI table: VNI -> L3VniEntry.
     l3vni_table: HashMap<Vni, L3VniEntry>,
+    /// Consumer for VRF_TABLE updates.
+    consumer: Consumer,
+    /// VRFs whose object counts changed since the last STATE_DB publish.
+    dirty_vrf_stats: HashSet<VrfName>,
+    /// VRF creation and removal, published to RouteOrch.
+    vrf_updates: Publisher<VrfUpdate>,
     /// Statistics.
     stats: VrfOrchStats,
     /// Initialized flag.
@@ -146,6 +184,9 @@ impl VrfOrch {
             vrf_id_to_name: HashMap::new(),
             vrf_vni_map: HashMap::new(),
             l3vni_table: HashMap::new(),
+            consumer: Consumer::new(ConsumerConfig::new("VRF_TABLE")),
+            dirty_vrf_stats: HashSet::new(),
+            vrf_updates: Publisher::new(),
             stats: VrfOrchStats::default(),
             initialized: false,
         }
@@ -156,6 +197,14 @@ impl VrfOrch {
         self.callbacks = Some(callbacks);
     }
 
+    /// Subscribes to VRF creation and removal.
+    ///
+    /// An update is published once a new VRF is created and once it is
+    /// removed; updating an existing VRF publishes nothing.
+    pub fn subscribe_vrf_updates(&self) -> Subscription<VrfUpdate> {
+        self.vrf_updates.subscribe()
+    }
+
     /// Returns the configuration.
     pub fn config(&self) -> &VrfOrchConfig {
         &self.config
@@ -277,6 +326,102 @@ impl VrfOrch {
         self.decrease_vrf_ref_count(&name)
     }
 
+    /// Increases the reference count for a VRF by name on behalf of an
+    /// object of the given type, and counts it in the VRF's statistics.
+    pub fn increase_vrf_object_count(
+        &mut self,
+        name: &str,
+        object_type: VrfObjectType,
+    ) -> Result<i32, VrfOrchError> {
+        let entry = self
+            .vrf_table
+            .get_mut(name)
+            .ok_or_else(|| VrfOrchError::VrfNotFound(name.to_string()))?;
+        entry.incr_ref_count();
+        entry.stats.increment(object_type);
+        let ref_count = entry.ref_count;
+        self.dirty_vrf_stats.insert(name.to_string());
+        Ok(ref_count)
+    }
+
+    /// Increases the reference count for a VRF by ID on behalf of an object
+    /// of the given type.
+    ///
+    /// Does nothing for the global VRF.
+    pub fn increase_vrf_object_count_by_id(
+        &mut self,
+        vrf_id: VrfId,
+        object_type: VrfObjectType,
+    ) -> Result<i32, VrfOrchError> {
+        if vrf_id == self.config.global_vrf_id {
+            return Ok(0);
+        }
+        let name = self.vrf_name_for_id(vrf_id)?;
+        self.increase_vrf_object_count(&name, object_type)
+    }
+
+    /// Decreases the reference count for a VRF by name on behalf of an
+    /// object of the given type, and uncounts it in the VRF's statistics.
+    pub fn decrease_vrf_object_count(
+        &mut self,
+        name: &str,
+        object_type: VrfObjectType,
+    ) -> Result<i32, VrfOrchError> {
+        let ref_count = self.decrease_vrf_ref_count(name)?;
+        if let Some(entry) = self.vrf_table.get_mut(name) {
+            if !entry.stats.decrement(object_type) {
+                warn!("VrfOrch: {} count underflow on VRF {}", object_type, name);
+            }
+        }
+        self.dirty_vrf_stats.insert(name.to_string());
+        Ok(ref_count)
+    }
+
+    /// Decreases the reference count for a VRF by ID on behalf of an object
+    /// of the given type.
+    ///
+    /// Does nothing for the global VRF.
+    pub fn decrease_vrf_object_count_by_id(
+        &mut self,
+        vrf_id: VrfId,
+        object_type: VrfObjectType,
+    ) -> Result<i32, VrfOrchError> {
+        if vrf_id == self.config.global_vrf_id {
+            return Ok(0);
+        }
+        let name = self.vrf_name_for_id(vrf_id)?;
+        self.decrease_vrf_object_count(&name, object_type)
+    }
+
+    fn vrf_name_for_id(&self, vrf_id: VrfId) -> Result<VrfName, VrfOrchError> {
+        self.vrf_id_to_name
+            .get(&vrf_id)
+            .cloned()
+            .ok_or_else(|| VrfOrchError::VrfNotFound(format!("id=0x{:x}", vrf_id)))
+    }
+
+    /// Gets the object counts of a VRF.
+    pub fn vrf_stats(&self, name: &str) -> Option<&VrfStats> {
+        self.vrf_table.get(name).map(|e| &e.stats)
+    }
+
+    /// Publishes the object counts of every VRF that changed since the last
+    /// flush. Returns the number of VRFs published.
+    pub fn flush_vrf_stats(&mut self) -> usize {
+        let dirty = std::mem::take(&mut self.dirty_vrf_stats);
+        let Some(callbacks) = &self.callbacks else {
+            return 0;
+        };
+        let mut published = 0;
+        for name in dirty {
+            if let Some(entry) = self.vrf_table.get(&name) {
+                callbacks.publish_vrf_stats(&name, &entry.stats);
+                published += 1;
+            }
+        }
+        published
+    }
+
     /// Gets the reference count for a VRF.
     ///
     /// Returns -1 if not found (matching C++ behavior).
@@ -291,6 +436,14 @@ impl VrfOrch {
         self.vrf_vni_map.get(vrf_name).copied().unwrap_or(0)
     }
 
+    /// Returns the VRF the VNI is mapped to, if any.
+    pub fn get_vni_mapped_vrf(&self, vni: Vni) -> Option<&str> {
+        self.vrf_vni_map
+            .iter()
+            .find(|(_, v)| **v == vni)
+            .map(|(name, _)| name.as_str())
+    }
+
     /// Gets the VLAN ID for an L3 VNI.
     ///
     /// Returns None if not found.
@@ -312,6 +465,10 @@ impl VrfOrch {
     pub fn add_vrf(&mut self, config: &VrfConfig) -> Result<VrfId, VrfOrchError> {
         let name = &config.name;
 
+        if let Some(vni) = config.vni {
+            self.check_vni_available(name, vni)?;
+        }
+
         if self.vrf_table.contains_key(name) {
             // Update existing VRF
             return self.update_vrf(config);
@@ -345,6 +502,7 @@ impl VrfOrch {
         // Store entry
         self.vrf_table.insert(name.clone(), entry);
         self.vrf_id_to_name.insert(vrf_id, name.clone());
+        self.dirty_vrf_stats.insert(name.clone());
 
         // Handle VNI mapping
         if let Some(vni) = config.vni {
@@ -358,6 +516,7 @@ impl VrfOrch {
             callbacks.on_vrf_created(name, vrf_id);
             callbacks.on_add_vr(vrf_id);
         }
+        self.vrf_updates.publish(VrfUpdate::added(name.clone()));
 
         self.stats.vrfs_created += 1;
 
@@ -454,12 +613,15 @@ impl VrfOrch {
         // Remove from tables
         self.vrf_table.remove(name);
         self.vrf_id_to_name.remove(&vrf_id);
+        self.dirty_vrf_stats.remove(name);
 
         // Notify callbacks
         if let Some(callbacks) = &self.callbacks {
             callbacks.on_remove_vr(vrf_id);
             callbacks.on_vrf_removed(name, vrf_id);
+            callbacks.remove_vrf_stats(name);
         }
+        self.vrf_updates.publish(VrfUpdate::removed(name));
 
         self.stats.vrfs_removed += 1;
 
@@ -480,6 +642,16 @@ impl VrfOrch {
         Ok(())
     }
 
+    /// Returns an error if the VNI is mapped to a VRF other than `vrf_name`.
+    fn check_vni_available(&self, vrf_name: &str, vni: Vni) -> Result<(), VrfOrchError> {
+        match self.get_vni_mapped_vrf(vni) {
+            Some(owner) if vni != 0 && owner != vrf_name => {
+                Err(VrfOrchError::VniInUse(vni, owner.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Updates the VRF to VNI mapping.
     fn update_vrf_vni_map(&mut self, vrf_name: &str, vni: Vni) -> Result<(), VrfOrchError> {
         let old_vni = self.get_vrf_mapped_vni(vrf_name);
@@ -493,6 +665,8 @@ impl VrfOrch {
             return self.del_vrf_vni_map(vrf_name, old_vni);
         }
 
+        self.check_vni_available(vrf_name, vni)?;
+
         // Check for EVPN VTEP - required for VNI mapping
         let has_vtep = self
             .callbacks
@@ -505,6 +679,10 @@ impl VrfOrch {
             ));
         }
 
+        if old_vni != 0 {
+            return self.move_vrf_vni_map(vrf_name, old_vni, vni);
+        }
+
         // Update L3 VNI table
         self.l3vni_table.insert(vni, L3VniEntry::pending());
         self.vrf_vni_map.insert(vrf_name.to_string(), vni);
@@ -540,6 +718,77 @@ impl VrfOrch {
         Ok(())
     }
 
+    /// Moves the VRF to VNI mapping to a new VNI in place.
+    ///
+    /// VxlanOrch re-points the VRF's tunnel map entry first; if it refuses,
+    /// the old mapping is left untouched.
+    fn move_vrf_vni_map(
+        &mut self,
+        vrf_name: &str,
+        old_vni: Vni,
+        new_vni: Vni,
+    ) -> Result<(), VrfOrchError> {
+        let vrf_id = self.get_vrf_id(vrf_name);
+
+        if let Some(callbacks) = &self.callbacks {
+            if !callbacks.update_vrf_vni_map(vrf_name, vrf_id, old_vni, new_vni) {
+                let error = VrfOrchError::CallbackError(format!(
+                    "Failed to move VRF {} tunnel map from VNI {} to {}",
+                    vrf_name, old_vni, new_vni
+                ));
+                audit_log!(AuditRecord::new(
+                    AuditCategory::ResourceModify,
+                    "VrfOrch",
+                    "update_l3_vni"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(format!("vrf_vni_{}_{}", vrf_name, new_vni))
+                .with_object_type("l3_vni")
+                .with_error(error.to_string()));
+                return Err(error);
+            }
+        }
+
+        let old_vlan = self
+            .l3vni_table
+            .remove(&old_vni)
+            .map(|e| e.vlan_id)
+            .unwrap_or(0);
+        let mut entry = L3VniEntry::pending();
+        if let Some(callbacks) = &self.callbacks {
+            if old_vlan != 0 {
+                callbacks.update_l3_vni_status(old_vlan, false);
+            }
+            if let Some(vlan_id) = callbacks.get_vlan_mapped_to_vni(new_vni) {
+                entry.vlan_id = vlan_id;
+                if vlan_id != 0 {
+                    callbacks.update_l3_vni_status(vlan_id, true);
+                }
+            }
+        }
+        self.l3vni_table.insert(new_vni, entry);
+        self.vrf_vni_map.insert(vrf_name.to_string(), new_vni);
+
+        self.stats.vni_mappings_updated += 1;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceModify, "VrfOrch", "update_l3_vni")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(format!("vrf_vni_{}_{}", vrf_name, new_vni))
+                .with_object_type("l3_vni")
+                .with_details(serde_json::json!({
+                    "vrf_name": vrf_name,
+                    "old_vni": old_vni,
+                    "vni": new_vni,
+                    "stats": {
+                        "vni_mappings_updated": self.stats.vni_mappings_updated
+                    }
+                }))
+        );
+
+        Ok(())
+    }
+
     /// Removes the VRF to VNI mapping.
     fn del_vrf_vni_map(&mut self, vrf_name: &str, mut vni: Vni) -> Result<(), VrfOrchError> {
         if vni == 0 {
@@ -615,6 +864,93 @@ impl VrfOrch {
     pub fn vrfs(&self) -> impl Iterator<Item = (&String, &VrfEntry)> {
         self.vrf_table.iter()
     }
+
+    /// Adds a task to the consumer for processing.
+    pub fn add_task(&mut self, key: String, op: Operation, fields: HashMap<String, String>) {
+        let fvs: Vec<(String, String)> = fields.into_iter().collect();
+        self.consumer
+            .add_to_sync(vec![KeyOpFieldsValues::new(key, op, fvs)]);
+    }
+
+    /// Processes all queued VRF_TABLE tasks.
+    ///
+    /// A SET that claims a VNI still mapped to another VRF is retried after
+    /// the rest of the batch, so a VNI released and reclaimed within the same
+    /// batch moves regardless of task order. Tasks that still conflict stay
+    /// queued for the next cycle. Changed VRF statistics are published last.
+    pub fn drain_tasks(&mut self) {
+        let mut tasks = self.consumer.drain();
+
+        loop {
+            let mut blocked = Vec::new();
+            let mut progress = false;
+
+            for task in tasks {
+                match self.process_task(&task) {
+                    Ok(()) => progress = true,
+                    Err(VrfOrchError::VniInUse(vni, owner)) => {
+                        debug!(
+                            "VrfOrch: {} waiting for VNI {} held by {}",
+                            task.key, vni, owner
+                        );
+                        blocked.push(task);
+                    }
+                    Err(VrfOrchError::VrfInUse(name, refs)) => {
+                        debug!("VrfOrch: {} still has {} references", name, refs);
+                        blocked.push(task);
+                    }
+                    Err(e) => warn!("VrfOrch: failed to process {}: {}", task.key, e),
+                }
+            }
+
+            if blocked.is_empty() || !progress {
+                for task in blocked {
+                    self.consumer.retry(task);
+                }
+                break;
+            }
+            tasks = blocked;
+        }
+
+        self.flush_vrf_stats();
+    }
+
+    fn process_task(&mut self, task: &KeyOpFieldsValues) -> Result<(), VrfOrchError> {
+        match task.op {
+            Operation::Set => {
+                let mut config = VrfConfig::new(task.key.clone());
+                for (field, value) in &task.fvs {
+                    config
+                        .parse_field(field, value)
+                        .map_err(VrfOrchError::InvalidConfig)?;
+                }
+                self.add_vrf(&config).map(|_| ())
+            }
+            Operation::Del => self.remove_vrf(&task.key),
+        }
+    }
+}
+
+#[async_trait]
+impl Orch for VrfOrch {
+    fn name(&self) -> &str {
+        "VrfOrch"
+    }
+
+    async fn do_task(&mut self) {
+        self.drain_tasks();
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        self.consumer.has_pending()
+    }
+
+    fn dump_pending_tasks(&self) -> Vec<String> {
+        self.consumer
+            .peek()
+            .map(|t| format!("{}:{:?}", t.key, t.op))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -711,6 +1047,22 @@ mod tests {
         assert_eq!(orch.stats().vrfs_removed, 1);
     }
 
+    #[test]
+    fn test_vrf_updates_published() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        let updates = orch.subscribe_vrf_updates();
+
+        orch.add_vrf(&VrfConfig::new("Vrf1")).unwrap();
+        orch.add_vrf(&VrfConfig::new("Vrf1").with_v4(false))
+            .unwrap();
+        orch.remove_vrf("Vrf1").unwrap();
+
+        assert_eq!(
+            updates.drain(),
+            vec![VrfUpdate::added("Vrf1"), VrfUpdate::removed("Vrf1")]
+        );
+    }
+
     #[test]
     fn test_remove_vrf_in_use() {
         let mut orch = VrfOrch::new(VrfOrchConfig::default());
@@ -1115,7 +1467,7 @@ mod tests {
         assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 10000);
         assert_eq!(orch.stats().vni_mappings_created, 1);
 
-        // Update to new VNI - VRF-to-VNI map updated but old L3VNI entry remains
+        // Update to new VNI - mapping is moved in place
         orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(20000))
             .unwrap();
         assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 20000);
@@ -1123,11 +1475,225 @@ mod tests {
         // The VRF should now be mapped to new VNI
         assert!(orch.is_l3_vni(20000));
 
-        // Old L3VNI entry still exists (not automatically cleaned up)
+        // Old L3VNI entry is released
+        assert!(!orch.is_l3_vni(10000));
+        assert_eq!(orch.get_vni_mapped_vrf(10000), None);
+
+        // Statistics show an update rather than a second mapping
+        assert_eq!(orch.stats().vni_mappings_created, 1);
+        assert_eq!(orch.stats().vni_mappings_updated, 1);
+        assert_eq!(orch.stats().vni_mappings_removed, 0);
+    }
+
+    /// Records the calls VrfOrch makes towards VxlanOrch, PortsOrch and
+    /// STATE_DB.
+    #[derive(Default)]
+    struct RecordingCallbacks {
+        refuse_vni_update: bool,
+        vni_updates: std::sync::Mutex<Vec<(String, Vni, Vni)>>,
+        l3_vni_status: std::sync::Mutex<Vec<(VrfVlanId, bool)>>,
+        published: std::sync::Mutex<Vec<(String, VrfStats)>>,
+        removed: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl VrfOrchCallbacks for RecordingCallbacks {
+        fn has_evpn_vtep(&self) -> bool {
+            true
+        }
+        fn get_vlan_mapped_to_vni(&self, vni: Vni) -> Option<VrfVlanId> {
+            Some((vni / 100) as VrfVlanId)
+        }
+        fn update_l3_vni_status(&self, vlan_id: VrfVlanId, enable: bool) -> bool {
+            self.l3_vni_status.lock().unwrap().push((vlan_id, enable));
+            true
+        }
+        fn update_vrf_vni_map(
+            &self,
+            vrf_name: &str,
+            _vrf_id: VrfId,
+            old_vni: Vni,
+            new_vni: Vni,
+        ) -> bool {
+            self.vni_updates
+                .lock()
+                .unwrap()
+                .push((vrf_name.to_string(), old_vni, new_vni));
+            !self.refuse_vni_update
+        }
+        fn publish_vrf_stats(&self, name: &str, stats: &VrfStats) {
+            self.published
+                .lock()
+                .unwrap()
+                .push((name.to_string(), *stats));
+        }
+        fn remove_vrf_stats(&self, name: &str) {
+            self.removed.lock().unwrap().push(name.to_string());
+        }
+    }
+
+    fn vni_task(orch: &mut VrfOrch, name: &str, vni: Vni) {
+        let fields = HashMap::from([("vni".to_string(), vni.to_string())]);
+        orch.add_task(name.to_string(), Operation::Set, fields);
+    }
+
+    #[test]
+    fn test_vni_update_repoints_tunnel_map() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        orch.set_callbacks(callbacks.clone());
+
+        orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(10000))
+            .unwrap();
+        callbacks.l3_vni_status.lock().unwrap().clear();
+
+        orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(20000))
+            .unwrap();
+
+        assert_eq!(
+            *callbacks.vni_updates.lock().unwrap(),
+            vec![("Vrf1".to_string(), 10000, 20000)]
+        );
+        // Old VLAN loses L3 VNI status, new VLAN gains it
+        assert_eq!(
+            *callbacks.l3_vni_status.lock().unwrap(),
+            vec![(100, false), (200, true)]
+        );
+        assert_eq!(orch.get_l3_vni_vlan(20000), Some(200));
+    }
+
+    #[test]
+    fn test_vni_update_refused_keeps_mapping() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks {
+            refuse_vni_update: true,
+            ..Default::default()
+        }));
+
+        orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(10000))
+            .unwrap();
+        let result = orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(20000));
+
+        assert!(matches!(result, Err(VrfOrchError::CallbackError(_))));
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 10000);
         assert!(orch.is_l3_vni(10000));
+        assert!(!orch.is_l3_vni(20000));
+        assert_eq!(orch.stats().vni_mappings_updated, 0);
+    }
+
+    #[test]
+    fn test_vni_in_use_by_other_vrf() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        orch.add_vrf(&VrfConfig::new("Vrf1").with_vni(10000))
+            .unwrap();
+
+        // New VRF claiming the VNI is rejected before it is created
+        let result = orch.add_vrf(&VrfConfig::new("Vrf2").with_vni(10000));
+        assert!(matches!(result, Err(VrfOrchError::VniInUse(10000, ref owner)) if owner == "Vrf1"));
+        assert!(!orch.vrf_exists("Vrf2"));
+
+        // Existing VRF claiming the VNI keeps its own mapping
+        orch.add_vrf(&VrfConfig::new("Vrf2").with_vni(20000))
+            .unwrap();
+        let result = orch.add_vrf(&VrfConfig::new("Vrf2").with_vni(10000));
+        assert!(matches!(result, Err(VrfOrchError::VniInUse(10000, _))));
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf2"), 20000);
+        assert_eq!(orch.get_vni_mapped_vrf(10000), Some("Vrf1"));
+    }
 
-        // Statistics show new mapping created (old not removed by update)
-        assert_eq!(orch.stats().vni_mappings_created, 2);
+    #[test]
+    fn test_vni_reassignment_in_one_drain_cycle() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        vni_task(&mut orch, "Vrf1", 10000);
+        vni_task(&mut orch, "Vrf2", 20000);
+        orch.drain_tasks();
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 10000);
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf2"), 20000);
+
+        // Vrf2 takes 10000 while Vrf1 moves on to 30000, in the same batch
+        vni_task(&mut orch, "Vrf2", 10000);
+        vni_task(&mut orch, "Vrf1", 30000);
+        orch.drain_tasks();
+
+        assert!(!orch.has_pending_tasks());
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 30000);
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf2"), 10000);
+        assert_eq!(orch.get_vni_mapped_vrf(10000), Some("Vrf2"));
+        assert!(!orch.is_l3_vni(20000));
+        assert_eq!(orch.stats().vni_mappings_updated, 2);
+    }
+
+    #[test]
+    fn test_vni_swap_stays_pending() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::default());
+        orch.set_callbacks(Arc::new(RecordingCallbacks::default()));
+
+        vni_task(&mut orch, "Vrf1", 10000);
+        vni_task(&mut orch, "Vrf2", 20000);
+        orch.drain_tasks();
+
+        // Neither side can move until the other releases its VNI
+        vni_task(&mut orch, "Vrf1", 20000);
+        vni_task(&mut orch, "Vrf2", 10000);
+        orch.drain_tasks();
+
+        assert!(orch.has_pending_tasks());
+        assert_eq!(orch.dump_pending_tasks().len(), 2);
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf1"), 10000);
+        assert_eq!(orch.get_vrf_mapped_vni("Vrf2"), 20000);
+    }
+
+    #[test]
+    fn test_vrf_object_counts_published() {
+        let mut orch = VrfOrch::new(VrfOrchConfig::new(0x1000));
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        orch.set_callbacks(callbacks.clone());
+
+        let vrf_id = orch.add_vrf(&VrfConfig::new("Vrf1")).unwrap();
+        assert_eq!(orch.flush_vrf_stats(), 1);
+
+        orch.increase_vrf_object_count("Vrf1", VrfObjectType::RouterInterface)
+            .unwrap();
+        orch.increase_vrf_object_count_by_id(vrf_id, VrfObjectType::Route)
+            .unwrap();
+        orch.increase_vrf_object_count_by_id(vrf_id, VrfObjectType::Route)
+            .unwrap();
+        orch.increase_vrf_object_count("Vrf1", VrfObjectType::Neighbor)
+            .unwrap();
+        orch.decrease_vrf_object_count_by_id(vrf_id, VrfObjectType::Route)
+            .unwrap();
+
+        // Global VRF is not tracked
+        orch.increase_vrf_object_count_by_id(0x1000, VrfObjectType::Route)
+            .unwrap();
+
+        assert_eq!(orch.get_vrf_ref_count("Vrf1"), 3);
+        let expected = VrfStats {
+            route_count: 1,
+            rif_count: 1,
+            neighbor_count: 1,
+        };
+        assert_eq!(orch.vrf_stats("Vrf1"), Some(&expected));
+
+        // One publish per changed VRF, carrying the latest counts
+        assert_eq!(orch.flush_vrf_stats(), 1);
+        assert_eq!(orch.flush_vrf_stats(), 0);
+        let published = callbacks.published.lock().unwrap().clone();
+        assert_eq!(published.last(), Some(&("Vrf1".to_string(), expected)));
+
+        // Counts drop back to zero and the STATE_DB entry is removed with the VRF
+        orch.decrease_vrf_object_count("Vrf1", VrfObjectType::Route)
+            .unwrap();
+        orch.decrease_vrf_object_count("Vrf1", VrfObjectType::Neighbor)
+            .unwrap();
+        orch.decrease_vrf_object_count("Vrf1", VrfObjectType::RouterInterface)
+            .unwrap();
+        orch.remove_vrf("Vrf1").unwrap();
+        assert_eq!(orch.flush_vrf_stats(), 0);
+        assert_eq!(*callbacks.removed.lock().unwrap(), vec!["Vrf1".to_string()]);
     }
 
     #[test]
@@ -40,6 +40,8 @@ pub struct VrfEntry {
     pub fallback: bool,
     /// Associated VNI (for EVPN).
     pub vni: Option<Vni>,
+    /// Objects bound to this VRF, published to STATE_DB.
+    pub stats: VrfStats,
 }
 
 impl VrfEntry {
@@ -56,6 +58,7 @@ impl VrfEntry {
             l3_mc_action: None,
             fallback: false,
             vni: None,
+            stats: VrfStats::default(),
         }
     }
 
@@ -91,6 +94,88 @@ impl fmt::Display for VrfEntry {
     }
 }
 
+/// Kind of object holding a reference on a VRF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VrfObjectType {
+    /// Route programmed in the VRF (RouteOrch).
+    Route,
+    /// Router interface bound to the VRF (IntfsOrch).
+    RouterInterface,
+    /// Neighbor learned on a router interface in the VRF (NeighOrch).
+    Neighbor,
+}
+
+impl fmt::Display for VrfObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Route => write!(f, "route"),
+            Self::RouterInterface => write!(f, "rif"),
+            Self::Neighbor => write!(f, "neighbor"),
+        }
+    }
+}
+
+/// Per-VRF object counts, published to STATE_DB VRF_TABLE.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VrfStats {
+    /// Number of routes in the VRF.
+    pub route_count: u32,
+    /// Number of router interfaces bound to the VRF.
+    pub rif_count: u32,
+    /// Number of neighbors on the VRF's router interfaces.
+    pub neighbor_count: u32,
+}
+
+impl VrfStats {
+    fn count_mut(&mut self, object_type: VrfObjectType) -> &mut u32 {
+        match object_type {
+            VrfObjectType::Route => &mut self.route_count,
+            VrfObjectType::RouterInterface => &mut self.rif_count,
+            VrfObjectType::Neighbor => &mut self.neighbor_count,
+        }
+    }
+
+    /// Returns the count for an object type.
+    pub fn count(&self, object_type: VrfObjectType) -> u32 {
+        match object_type {
+            VrfObjectType::Route => self.route_count,
+            VrfObjectType::RouterInterface => self.rif_count,
+            VrfObjectType::Neighbor => self.neighbor_count,
+        }
+    }
+
+    /// Increments the count for an object type.
+    pub fn increment(&mut self, object_type: VrfObjectType) {
+        let count = self.count_mut(object_type);
+        *count = count.saturating_add(1);
+    }
+
+    /// Decrements the count for an object type.
+    /// Returns false, leaving the count at zero, if it would underflow.
+    pub fn decrement(&mut self, object_type: VrfObjectType) -> bool {
+        let count = self.count_mut(object_type);
+        match count.checked_sub(1) {
+            Some(c) => {
+                *count = c;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the STATE_DB field-value pairs for these counts.
+    pub fn to_field_values(&self) -> Vec<(String, String)> {
+        vec![
+            ("route_count".to_string(), self.route_count.to_string()),
+            ("rif_count".to_string(), self.rif_count.to_string()),
+            (
+                "neighbor_count".to_string(),
+                self.neighbor_count.to_string(),
+            ),
+        ]
+    }
+}
+
 /// L3 VNI entry for EVPN mapping.
 #[derive(Debug, Clone)]
 pub struct L3VniEntry {
@@ -356,6 +441,24 @@ mod tests {
         assert_eq!(entry.decr_ref_count(), None); // Underflow protection
     }
 
+    #[test]
+    fn test_vrf_stats_counts() {
+        let mut stats = VrfStats::default();
+        stats.increment(VrfObjectType::Route);
+        stats.increment(VrfObjectType::Route);
+        stats.increment(VrfObjectType::Neighbor);
+        assert_eq!(stats.count(VrfObjectType::Route), 2);
+        assert_eq!(stats.count(VrfObjectType::RouterInterface), 0);
+
+        assert!(stats.decrement(VrfObjectType::Neighbor));
+        assert!(!stats.decrement(VrfObjectType::Neighbor)); // Underflow protection
+        assert_eq!(stats.neighbor_count, 0);
+
+        let fvs = stats.to_field_values();
+        assert!(fvs.contains(&("route_count".to_string(), "2".to_string())));
+        assert!(fvs.contains(&("rif_count".to_string(), "0".to_string())));
+    }
+
     #[test]
     fn test_l3vni_entry() {
         let entry = L3VniEntry::new(100, true);
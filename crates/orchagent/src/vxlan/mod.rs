@@ -8,6 +8,7 @@
 //! - std::net::IpAddr for IPv4/IPv6 tunnel endpoints
 //! - Composite keys for VRF and VLAN mappings
 //! - Type-safe encapsulation types (L2/L3)
+//! - Reference-counted EVPN remote VTEP tunnels keyed by IpAddr
 
 mod ffi;
 mod orch;
@@ -16,6 +17,8 @@ mod types;
 pub use ffi::{register_vxlan_orch, unregister_vxlan_orch};
 pub use orch::{VxlanOrch, VxlanOrchCallbacks, VxlanOrchConfig, VxlanOrchError, VxlanOrchStats};
 pub use types::{
-    Vni, VxlanEncapType, VxlanStats, VxlanTunnelConfig, VxlanTunnelEntry, VxlanTunnelKey,
-    VxlanVlanMapEntry, VxlanVlanMapKey, VxlanVrfMapEntry, VxlanVrfMapKey,
+    parse_vlan_name, parse_vni, tables, EvpnNvoConfig, Vni, VxlanEncapType, VxlanRemoteVtep,
+    VxlanStats, VxlanTunnelConfig, VxlanTunnelEntry, VxlanTunnelKey, VxlanTunnelMapConfig,
+    VxlanTunnelMapEntry, VxlanTunnelMapType, VxlanTunnelState, VxlanVlanMapEntry, VxlanVlanMapKey,
+    VxlanVrfMapEntry, VxlanVrfMapKey, MAX_VNI,
 };
@@ -1,12 +1,16 @@
 //! VXLAN orchestration logic.
 
 use super::types::{
-    VxlanStats, VxlanTunnelEntry, VxlanTunnelKey, VxlanVlanMapEntry, VxlanVlanMapKey,
-    VxlanVrfMapEntry, VxlanVrfMapKey,
+    parse_vlan_name, parse_vni, EvpnNvoConfig, RawSaiObjectId, Vni, VxlanRemoteVtep, VxlanStats,
+    VxlanTunnelConfig, VxlanTunnelEntry, VxlanTunnelKey, VxlanTunnelMapConfig, VxlanTunnelMapEntry,
+    VxlanTunnelMapType, VxlanTunnelState, VxlanVlanMapEntry, VxlanVlanMapKey, VxlanVrfMapEntry,
+    VxlanVrfMapKey,
 };
 use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
 use crate::audit_log;
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum VxlanOrchError {
@@ -20,6 +24,22 @@ pub enum VxlanOrchError {
     InvalidVni(u32),
     #[error("Invalid IP: {0}")]
     InvalidIp(String),
+    #[error("Tunnel {0} not found")]
+    TunnelNameNotFound(String),
+    #[error("Tunnel {0} is still referenced")]
+    TunnelInUse(String),
+    #[error("Tunnel map not found: {0}")]
+    TunnelMapNotFound(String),
+    #[error("EVPN NVO not configured")]
+    NvoNotConfigured,
+    #[error("EVPN NVO {0} is still referenced by remote VTEPs")]
+    NvoInUse(String),
+    #[error("Cannot change source IP of tunnel {0} while it is the EVPN NVO source")]
+    NvoSourceIpChange(String),
+    #[error("Remote VTEP not found: {0}")]
+    RemoteVtepNotFound(String),
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
     #[error("SAI error: {0}")]
     SaiError(String),
 }
@@ -36,6 +56,30 @@ pub struct VxlanOrchStats {
 }
 
 pub trait VxlanOrchCallbacks: Send + Sync {
+    fn create_tunnel_map(&self, map_type: VxlanTunnelMapType) -> Result<RawSaiObjectId, String>;
+    fn remove_tunnel_map(&self, map_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_tunnel_map_entry(
+        &self,
+        map_oid: RawSaiObjectId,
+        map_type: VxlanTunnelMapType,
+        vlan_id: u16,
+        vni: Vni,
+    ) -> Result<RawSaiObjectId, String>;
+    fn remove_tunnel_map_entry(&self, entry_oid: RawSaiObjectId) -> Result<(), String>;
+    fn create_tunnel(
+        &self,
+        config: &VxlanTunnelConfig,
+        encap_mapper_oid: RawSaiObjectId,
+        decap_mapper_oid: RawSaiObjectId,
+    ) -> Result<RawSaiObjectId, String>;
+    fn remove_tunnel(&self, tunnel_oid: RawSaiObjectId) -> Result<(), String>;
+    /// Creates a P2MP termination entry matching packets sent to `local_ip`.
+    fn create_tunnel_termination(
+        &self,
+        tunnel_oid: RawSaiObjectId,
+        local_ip: IpAddr,
+    ) -> Result<RawSaiObjectId, String>;
+    fn remove_tunnel_termination(&self, term_oid: RawSaiObjectId) -> Result<(), String>;
     fn on_tunnel_created(&self, entry: &VxlanTunnelEntry);
     fn on_tunnel_removed(&self, key: &VxlanTunnelKey);
     fn on_vrf_map_created(&self, entry: &VxlanVrfMapEntry);
@@ -47,9 +91,14 @@ pub trait VxlanOrchCallbacks: Send + Sync {
 pub struct VxlanOrch {
     config: VxlanOrchConfig,
     stats: VxlanOrchStats,
+    callbacks: Option<Arc<dyn VxlanOrchCallbacks>>,
     tunnels: HashMap<VxlanTunnelKey, VxlanTunnelEntry>,
     vrf_maps: HashMap<VxlanVrfMapKey, VxlanVrfMapEntry>,
     vlan_maps: HashMap<VxlanVlanMapKey, VxlanVlanMapEntry>,
+    named_tunnels: HashMap<String, VxlanTunnelState>,
+    tunnel_maps: HashMap<String, VxlanTunnelMapEntry>,
+    nvo: Option<EvpnNvoConfig>,
+    remote_vteps: HashMap<IpAddr, VxlanRemoteVtep>,
 }
 
 impl VxlanOrch {
@@ -57,9 +106,597 @@ impl VxlanOrch {
         Self {
             config,
             stats: VxlanOrchStats::default(),
+            callbacks: None,
             tunnels: HashMap::new(),
             vrf_maps: HashMap::new(),
             vlan_maps: HashMap::new(),
+            named_tunnels: HashMap::new(),
+            tunnel_maps: HashMap::new(),
+            nvo: None,
+            remote_vteps: HashMap::new(),
+        }
+    }
+
+    pub fn set_callbacks(&mut self, callbacks: Arc<dyn VxlanOrchCallbacks>) {
+        self.callbacks = Some(callbacks);
+    }
+
+    fn callbacks(&self) -> Result<Arc<dyn VxlanOrchCallbacks>, VxlanOrchError> {
+        self.callbacks
+            .clone()
+            .ok_or_else(|| VxlanOrchError::SaiError("No callbacks set".to_string()))
+    }
+
+    pub fn get_named_tunnel(&self, name: &str) -> Option<&VxlanTunnelState> {
+        self.named_tunnels.get(name)
+    }
+
+    pub fn get_tunnel_map(&self, key: &str) -> Option<&VxlanTunnelMapEntry> {
+        self.tunnel_maps.get(key)
+    }
+
+    pub fn evpn_nvo(&self) -> Option<&EvpnNvoConfig> {
+        self.nvo.as_ref()
+    }
+
+    pub fn get_remote_vtep(&self, remote_ip: &IpAddr) -> Option<&VxlanRemoteVtep> {
+        self.remote_vteps.get(remote_ip)
+    }
+
+    pub fn remote_vtep_count(&self) -> usize {
+        self.remote_vteps.len()
+    }
+
+    /// Returns true if `name` is the source tunnel of the EVPN NVO.
+    fn is_nvo_source(&self, name: &str) -> bool {
+        self.nvo.as_ref().is_some_and(|nvo| nvo.source_vtep == name)
+    }
+
+    /// Handles a VXLAN_TUNNEL SET.
+    ///
+    /// Creates the encap/decap mappers, the tunnel and its P2MP termination.
+    /// Re-applying the same config is a no-op. Changing the source IP of the
+    /// EVPN NVO source tunnel is rejected, since every remote VTEP tunnel is
+    /// built on it; other tunnels may only be re-addressed once unreferenced.
+    pub fn handle_tunnel_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), VxlanOrchError> {
+        let mut config = VxlanTunnelConfig::new(name.to_string());
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(VxlanOrchError::InvalidIp)?;
+        }
+        if config.src_ip.is_unspecified() {
+            return Err(VxlanOrchError::InvalidConfig(format!(
+                "{}: src_ip is required",
+                name
+            )));
+        }
+
+        if let Some(existing) = self.named_tunnels.get(name) {
+            if existing.entry.config == config {
+                return Ok(());
+            }
+            let error = if self.is_nvo_source(name) {
+                VxlanOrchError::NvoSourceIpChange(name.to_string())
+            } else if existing.map_refs > 0 {
+                VxlanOrchError::TunnelInUse(name.to_string())
+            } else {
+                self.handle_tunnel_del(name)?;
+                return self.handle_tunnel_set(name, fields);
+            };
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceModify,
+                "VxlanOrch",
+                "update_tunnel"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(name)
+            .with_object_type("vxlan_tunnel")
+            .with_error(error.to_string()));
+            return Err(error);
+        }
+
+        let callbacks = self.callbacks()?;
+        let entry = Self::create_tunnel_objects(callbacks.as_ref(), config, None)?;
+        let term_oid =
+            match callbacks.create_tunnel_termination(entry.tunnel_oid, entry.config.src_ip) {
+                Ok(oid) => oid,
+                Err(e) => {
+                    Self::remove_tunnel_objects(callbacks.as_ref(), &entry, true);
+                    return Err(VxlanOrchError::SaiError(e));
+                }
+            };
+
+        callbacks.on_tunnel_created(&entry);
+        self.stats.stats.tunnels_created = self.stats.stats.tunnels_created.saturating_add(1);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceCreate, "VxlanOrch", "create_tunnel")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(name)
+                .with_object_type("vxlan_tunnel")
+                .with_details(serde_json::json!({
+                    "tunnel_name": name,
+                    "src_ip": entry.config.src_ip.to_string(),
+                    "dst_ip": entry.config.dst_ip.to_string(),
+                    "tunnel_oid": entry.tunnel_oid,
+                    "term_oid": term_oid,
+                }))
+        );
+
+        self.named_tunnels.insert(
+            name.to_string(),
+            VxlanTunnelState {
+                entry,
+                term_oid,
+                map_refs: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Handles a VXLAN_TUNNEL DEL.
+    ///
+    /// Fails with `TunnelInUse` while tunnel maps, the EVPN NVO or remote
+    /// VTEPs still reference the tunnel; the caller retries later.
+    pub fn handle_tunnel_del(&mut self, name: &str) -> Result<(), VxlanOrchError> {
+        let state = self
+            .named_tunnels
+            .get(name)
+            .ok_or_else(|| VxlanOrchError::TunnelNameNotFound(name.to_string()))?;
+
+        if state.map_refs > 0 || self.is_nvo_source(name) {
+            let error = VxlanOrchError::TunnelInUse(name.to_string());
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceDelete,
+                "VxlanOrch",
+                "remove_tunnel"
+            )
+            .with_outcome(AuditOutcome::Failure)
+            .with_object_id(name)
+            .with_object_type("vxlan_tunnel")
+            .with_error(error.to_string())
+            .with_details(serde_json::json!({
+                "map_refs": state.map_refs,
+                "remote_vteps": self.remote_vteps.len(),
+            })));
+            return Err(error);
+        }
+
+        let callbacks = self.callbacks()?;
+        callbacks
+            .remove_tunnel_termination(state.term_oid)
+            .map_err(VxlanOrchError::SaiError)?;
+        let state = self
+            .named_tunnels
+            .remove(name)
+            .expect("tunnel checked above");
+        Self::remove_tunnel_objects(callbacks.as_ref(), &state.entry, true);
+        callbacks.on_tunnel_removed(&state.entry.key);
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::ResourceDelete, "VxlanOrch", "remove_tunnel")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(name)
+                .with_object_type("vxlan_tunnel")
+                .with_details(serde_json::json!({
+                    "tunnel_name": name,
+                    "tunnel_oid": state.entry.tunnel_oid,
+                }))
+        );
+        Ok(())
+    }
+
+    /// Handles a VXLAN_TUNNEL_MAP SET for key `<tunnel>|<map_name>`.
+    ///
+    /// Returns `TunnelNameNotFound` if the tunnel does not exist yet so the
+    /// entry can be retried. A changed VLAN or VNI replaces the map entries.
+    pub fn handle_tunnel_map_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), VxlanOrchError> {
+        let mut config =
+            VxlanTunnelMapConfig::from_key(key).map_err(VxlanOrchError::InvalidConfig)?;
+        for (field, value) in fields {
+            config
+                .parse_field(field, value)
+                .map_err(VxlanOrchError::InvalidConfig)?;
+        }
+        config.validate().map_err(VxlanOrchError::InvalidConfig)?;
+
+        if let Some(existing) = self.tunnel_maps.get(key) {
+            if existing.config == config {
+                return Ok(());
+            }
+            self.handle_tunnel_map_del(key)?;
+        }
+
+        let duplicate = self.tunnel_maps.values().any(|entry| {
+            entry.config.tunnel_name == config.tunnel_name
+                && (entry.config.vni == config.vni || entry.config.vlan_id == config.vlan_id)
+        });
+        if duplicate {
+            return Err(VxlanOrchError::InvalidConfig(format!(
+                "{}: VNI {} or Vlan{} already mapped on {}",
+                key, config.vni, config.vlan_id, config.tunnel_name
+            )));
+        }
+
+        let callbacks = self.callbacks()?;
+        let state = self
+            .named_tunnels
+            .get_mut(&config.tunnel_name)
+            .ok_or_else(|| VxlanOrchError::TunnelNameNotFound(config.tunnel_name.clone()))?;
+
+        let encap_entry_oid = callbacks
+            .create_tunnel_map_entry(
+                state.entry.encap_mapper_oid,
+                VxlanTunnelMapType::VlanToVni,
+                config.vlan_id,
+                config.vni,
+            )
+            .map_err(VxlanOrchError::SaiError)?;
+        let decap_entry_oid = match callbacks.create_tunnel_map_entry(
+            state.entry.decap_mapper_oid,
+            VxlanTunnelMapType::VniToVlan,
+            config.vlan_id,
+            config.vni,
+        ) {
+            Ok(oid) => oid,
+            Err(e) => {
+                let _ = callbacks.remove_tunnel_map_entry(encap_entry_oid);
+                return Err(VxlanOrchError::SaiError(e));
+            }
+        };
+        state.map_refs += 1;
+
+        let vlan_map = VxlanVlanMapEntry::new(VxlanVlanMapKey::new(config.vni, config.vlan_id));
+        callbacks.on_vlan_map_created(&vlan_map);
+        self.stats.stats.vlan_maps_created = self.stats.stats.vlan_maps_created.saturating_add(1);
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceCreate,
+            "VxlanOrch",
+            "create_tunnel_map"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("vxlan_tunnel_map")
+        .with_details(serde_json::json!({
+            "tunnel_name": config.tunnel_name,
+            "vni": config.vni,
+            "vlan_id": config.vlan_id,
+            "map_refs": state.map_refs,
+        })));
+
+        self.tunnel_maps.insert(
+            key.to_string(),
+            VxlanTunnelMapEntry {
+                config,
+                encap_entry_oid,
+                decap_entry_oid,
+            },
+        );
+        Ok(())
+    }
+
+    /// Handles a VXLAN_TUNNEL_MAP DEL and releases its tunnel reference.
+    pub fn handle_tunnel_map_del(&mut self, key: &str) -> Result<(), VxlanOrchError> {
+        let entry = self
+            .tunnel_maps
+            .get(key)
+            .ok_or_else(|| VxlanOrchError::TunnelMapNotFound(key.to_string()))?;
+        let callbacks = self.callbacks()?;
+
+        callbacks
+            .remove_tunnel_map_entry(entry.encap_entry_oid)
+            .map_err(VxlanOrchError::SaiError)?;
+        callbacks
+            .remove_tunnel_map_entry(entry.decap_entry_oid)
+            .map_err(VxlanOrchError::SaiError)?;
+        let entry = self.tunnel_maps.remove(key).expect("map checked above");
+        if let Some(state) = self.named_tunnels.get_mut(&entry.config.tunnel_name) {
+            state.map_refs = state.map_refs.saturating_sub(1);
+        }
+        callbacks.on_vlan_map_removed(entry.config.vni, entry.config.vlan_id);
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "VxlanOrch",
+            "remove_tunnel_map"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("vxlan_tunnel_map")
+        .with_details(serde_json::json!({
+            "tunnel_name": entry.config.tunnel_name,
+            "vni": entry.config.vni,
+            "vlan_id": entry.config.vlan_id,
+        })));
+        Ok(())
+    }
+
+    /// Handles an EVPN_NVO SET designating the EVPN source tunnel.
+    ///
+    /// Moving the NVO to another tunnel is rejected while remote VTEPs exist.
+    pub fn handle_evpn_nvo_set(
+        &mut self,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), VxlanOrchError> {
+        let source_vtep = fields
+            .iter()
+            .find(|(field, _)| field == "source_vtep")
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                VxlanOrchError::InvalidConfig(format!("{}: source_vtep is required", name))
+            })?;
+        if !self.named_tunnels.contains_key(&source_vtep) {
+            return Err(VxlanOrchError::TunnelNameNotFound(source_vtep));
+        }
+
+        let nvo = EvpnNvoConfig {
+            name: name.to_string(),
+            source_vtep,
+        };
+        if self.nvo.as_ref() == Some(&nvo) {
+            return Ok(());
+        }
+        if let Some(current) = &self.nvo {
+            if !self.remote_vteps.is_empty() {
+                return Err(VxlanOrchError::NvoInUse(current.name.clone()));
+            }
+        }
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "VxlanOrch",
+            "set_evpn_nvo"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(name)
+        .with_object_type("evpn_nvo")
+        .with_details(serde_json::json!({
+            "source_vtep": nvo.source_vtep,
+        })));
+
+        self.config.evpn_nvo_name = Some(nvo.name.clone());
+        self.nvo = Some(nvo);
+        Ok(())
+    }
+
+    /// Handles an EVPN_NVO DEL; fails while remote VTEPs exist.
+    pub fn handle_evpn_nvo_del(&mut self, name: &str) -> Result<(), VxlanOrchError> {
+        match &self.nvo {
+            Some(nvo) if nvo.name == name => {}
+            _ => return Err(VxlanOrchError::NvoNotConfigured),
+        }
+        if !self.remote_vteps.is_empty() {
+            return Err(VxlanOrchError::NvoInUse(name.to_string()));
+        }
+
+        self.nvo = None;
+        self.config.evpn_nvo_name = None;
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "VxlanOrch",
+            "remove_evpn_nvo"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(name)
+        .with_object_type("evpn_nvo"));
+        Ok(())
+    }
+
+    /// Handles a VXLAN_REMOTE_VNI_TABLE SET (IMET route) for key `Vlan<id>|<remote_ip>`.
+    pub fn handle_remote_vni_set(
+        &mut self,
+        key: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), VxlanOrchError> {
+        let (vlan_id, remote_ip) = Self::parse_remote_vni_key(key)?;
+        let vni = fields
+            .iter()
+            .find(|(field, _)| field == "vni")
+            .ok_or_else(|| VxlanOrchError::InvalidConfig(format!("{}: vni is required", key)))
+            .and_then(|(_, value)| parse_vni(value).map_err(VxlanOrchError::InvalidConfig))?;
+
+        let vtep = self.acquire_remote_vtep(remote_ip)?;
+        vtep.vnis.insert(vlan_id, vni);
+        Ok(())
+    }
+
+    /// Handles a VXLAN_REMOTE_VNI_TABLE DEL; the VTEP tunnel goes with its last reference.
+    pub fn handle_remote_vni_del(&mut self, key: &str) -> Result<(), VxlanOrchError> {
+        let (vlan_id, remote_ip) = Self::parse_remote_vni_key(key)?;
+        let vtep = self
+            .remote_vteps
+            .get_mut(&remote_ip)
+            .ok_or_else(|| VxlanOrchError::RemoteVtepNotFound(remote_ip.to_string()))?;
+        if vtep.vnis.remove(&vlan_id).is_none() {
+            return Err(VxlanOrchError::RemoteVtepNotFound(key.to_string()));
+        }
+        self.release_remote_vtep(remote_ip)
+    }
+
+    /// Takes a reference on the remote VTEP tunnel for a MAC route.
+    pub fn add_remote_mac_ref(&mut self, remote_ip: IpAddr) -> Result<(), VxlanOrchError> {
+        let vtep = self.acquire_remote_vtep(remote_ip)?;
+        vtep.mac_refs += 1;
+        Ok(())
+    }
+
+    /// Drops a MAC route reference on the remote VTEP tunnel.
+    pub fn remove_remote_mac_ref(&mut self, remote_ip: IpAddr) -> Result<(), VxlanOrchError> {
+        let vtep = self
+            .remote_vteps
+            .get_mut(&remote_ip)
+            .filter(|vtep| vtep.mac_refs > 0)
+            .ok_or_else(|| VxlanOrchError::RemoteVtepNotFound(remote_ip.to_string()))?;
+        vtep.mac_refs -= 1;
+        self.release_remote_vtep(remote_ip)
+    }
+
+    fn parse_remote_vni_key(key: &str) -> Result<(u16, IpAddr), VxlanOrchError> {
+        let (vlan, ip) = key
+            .split_once('|')
+            .ok_or_else(|| VxlanOrchError::InvalidConfig(format!("Invalid key: {}", key)))?;
+        let vlan_id = parse_vlan_name(vlan).map_err(VxlanOrchError::InvalidConfig)?;
+        let remote_ip = ip
+            .parse()
+            .map_err(|_| VxlanOrchError::InvalidIp(ip.to_string()))?;
+        Ok((vlan_id, remote_ip))
+    }
+
+    /// Returns the tunnel to `remote_ip`, creating it from the NVO source tunnel if needed.
+    fn acquire_remote_vtep(
+        &mut self,
+        remote_ip: IpAddr,
+    ) -> Result<&mut VxlanRemoteVtep, VxlanOrchError> {
+        if !self.remote_vteps.contains_key(&remote_ip) {
+            let nvo = self.nvo.as_ref().ok_or(VxlanOrchError::NvoNotConfigured)?;
+            let source = self
+                .named_tunnels
+                .get(&nvo.source_vtep)
+                .ok_or_else(|| VxlanOrchError::TunnelNameNotFound(nvo.source_vtep.clone()))?;
+            let callbacks = self.callbacks()?;
+
+            let config = VxlanTunnelConfig {
+                src_ip: source.entry.config.src_ip,
+                dst_ip: remote_ip,
+                tunnel_name: format!("EVPN_{}", remote_ip),
+            };
+            let entry = Self::create_tunnel_objects(
+                callbacks.as_ref(),
+                config,
+                Some((source.entry.encap_mapper_oid, source.entry.decap_mapper_oid)),
+            )?;
+            callbacks.on_tunnel_created(&entry);
+            self.stats.stats.remote_vteps_created =
+                self.stats.stats.remote_vteps_created.saturating_add(1);
+
+            audit_log!(AuditRecord::new(
+                AuditCategory::ResourceCreate,
+                "VxlanOrch",
+                "create_remote_vtep"
+            )
+            .with_outcome(AuditOutcome::Success)
+            .with_object_id(remote_ip.to_string())
+            .with_object_type("vxlan_remote_vtep")
+            .with_details(serde_json::json!({
+                "tunnel_name": entry.config.tunnel_name,
+                "src_ip": entry.config.src_ip.to_string(),
+                "tunnel_oid": entry.tunnel_oid,
+            })));
+
+            self.remote_vteps.insert(
+                remote_ip,
+                VxlanRemoteVtep {
+                    entry,
+                    vnis: HashMap::new(),
+                    mac_refs: 0,
+                },
+            );
+        }
+        Ok(self
+            .remote_vteps
+            .get_mut(&remote_ip)
+            .expect("remote VTEP inserted above"))
+    }
+
+    /// Removes the tunnel to `remote_ip` once nothing references it.
+    fn release_remote_vtep(&mut self, remote_ip: IpAddr) -> Result<(), VxlanOrchError> {
+        if self
+            .remote_vteps
+            .get(&remote_ip)
+            .is_none_or(|vtep| vtep.ref_count() > 0)
+        {
+            return Ok(());
+        }
+        let callbacks = self.callbacks()?;
+        let vtep = self
+            .remote_vteps
+            .remove(&remote_ip)
+            .expect("remote VTEP checked above");
+        Self::remove_tunnel_objects(callbacks.as_ref(), &vtep.entry, false);
+        callbacks.on_tunnel_removed(&vtep.entry.key);
+        self.stats.stats.remote_vteps_removed =
+            self.stats.stats.remote_vteps_removed.saturating_add(1);
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ResourceDelete,
+            "VxlanOrch",
+            "remove_remote_vtep"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(remote_ip.to_string())
+        .with_object_type("vxlan_remote_vtep")
+        .with_details(serde_json::json!({
+            "tunnel_oid": vtep.entry.tunnel_oid,
+        })));
+        Ok(())
+    }
+
+    /// Creates a tunnel, with its own mappers unless `shared_mappers` is given.
+    ///
+    /// Objects already created are removed again if a later step fails.
+    fn create_tunnel_objects(
+        callbacks: &dyn VxlanOrchCallbacks,
+        config: VxlanTunnelConfig,
+        shared_mappers: Option<(RawSaiObjectId, RawSaiObjectId)>,
+    ) -> Result<VxlanTunnelEntry, VxlanOrchError> {
+        let (encap_mapper_oid, decap_mapper_oid) = match shared_mappers {
+            Some(mappers) => mappers,
+            None => {
+                let encap = callbacks
+                    .create_tunnel_map(VxlanTunnelMapType::VlanToVni)
+                    .map_err(VxlanOrchError::SaiError)?;
+                let decap = match callbacks.create_tunnel_map(VxlanTunnelMapType::VniToVlan) {
+                    Ok(oid) => oid,
+                    Err(e) => {
+                        let _ = callbacks.remove_tunnel_map(encap);
+                        return Err(VxlanOrchError::SaiError(e));
+                    }
+                };
+                (encap, decap)
+            }
+        };
+
+        let tunnel_oid = match callbacks.create_tunnel(&config, encap_mapper_oid, decap_mapper_oid)
+        {
+            Ok(oid) => oid,
+            Err(e) => {
+                if shared_mappers.is_none() {
+                    let _ = callbacks.remove_tunnel_map(decap_mapper_oid);
+                    let _ = callbacks.remove_tunnel_map(encap_mapper_oid);
+                }
+                return Err(VxlanOrchError::SaiError(e));
+            }
+        };
+
+        let mut entry = VxlanTunnelEntry::new(config);
+        entry.tunnel_oid = tunnel_oid;
+        entry.encap_mapper_oid = encap_mapper_oid;
+        entry.decap_mapper_oid = decap_mapper_oid;
+        Ok(entry)
+    }
+
+    /// Removes a tunnel and, if it owns them, its mappers.
+    fn remove_tunnel_objects(
+        callbacks: &dyn VxlanOrchCallbacks,
+        entry: &VxlanTunnelEntry,
+        owns_mappers: bool,
+    ) {
+        let _ = callbacks.remove_tunnel(entry.tunnel_oid);
+        if owns_mappers {
+            let _ = callbacks.remove_tunnel_map(entry.decap_mapper_oid);
+            let _ = callbacks.remove_tunnel_map(entry.encap_mapper_oid);
         }
     }
 
@@ -307,6 +944,112 @@ impl VxlanOrch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockCallbacks {
+        next_oid: AtomicU64,
+        live: Mutex<HashSet<RawSaiObjectId>>,
+        tunnels: Mutex<Vec<VxlanTunnelConfig>>,
+        fail_termination: AtomicBool,
+    }
+
+    impl MockCallbacks {
+        fn alloc(&self) -> RawSaiObjectId {
+            let oid = self.next_oid.fetch_add(1, Ordering::SeqCst) + 0x1000;
+            self.live.lock().unwrap().insert(oid);
+            oid
+        }
+
+        fn free(&self, oid: RawSaiObjectId) -> Result<(), String> {
+            if self.live.lock().unwrap().remove(&oid) {
+                Ok(())
+            } else {
+                Err(format!("unknown oid {:#x}", oid))
+            }
+        }
+
+        fn live_count(&self) -> usize {
+            self.live.lock().unwrap().len()
+        }
+    }
+
+    impl VxlanOrchCallbacks for MockCallbacks {
+        fn create_tunnel_map(&self, _: VxlanTunnelMapType) -> Result<RawSaiObjectId, String> {
+            Ok(self.alloc())
+        }
+        fn remove_tunnel_map(&self, map_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(map_oid)
+        }
+        fn create_tunnel_map_entry(
+            &self,
+            _: RawSaiObjectId,
+            _: VxlanTunnelMapType,
+            _: u16,
+            _: Vni,
+        ) -> Result<RawSaiObjectId, String> {
+            Ok(self.alloc())
+        }
+        fn remove_tunnel_map_entry(&self, entry_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(entry_oid)
+        }
+        fn create_tunnel(
+            &self,
+            config: &VxlanTunnelConfig,
+            _: RawSaiObjectId,
+            _: RawSaiObjectId,
+        ) -> Result<RawSaiObjectId, String> {
+            self.tunnels.lock().unwrap().push(config.clone());
+            Ok(self.alloc())
+        }
+        fn remove_tunnel(&self, tunnel_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(tunnel_oid)
+        }
+        fn create_tunnel_termination(
+            &self,
+            _: RawSaiObjectId,
+            _: IpAddr,
+        ) -> Result<RawSaiObjectId, String> {
+            if self.fail_termination.load(Ordering::SeqCst) {
+                return Err("termination failed".to_string());
+            }
+            Ok(self.alloc())
+        }
+        fn remove_tunnel_termination(&self, term_oid: RawSaiObjectId) -> Result<(), String> {
+            self.free(term_oid)
+        }
+        fn on_tunnel_created(&self, _: &VxlanTunnelEntry) {}
+        fn on_tunnel_removed(&self, _: &VxlanTunnelKey) {}
+        fn on_vrf_map_created(&self, _: &VxlanVrfMapEntry) {}
+        fn on_vrf_map_removed(&self, _: u32, _: &str) {}
+        fn on_vlan_map_created(&self, _: &VxlanVlanMapEntry) {}
+        fn on_vlan_map_removed(&self, _: u32, _: u16) {}
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn orch_with_mock() -> (VxlanOrch, Arc<MockCallbacks>) {
+        let mock = Arc::new(MockCallbacks::default());
+        let mut orch = VxlanOrch::new(VxlanOrchConfig::default());
+        orch.set_callbacks(mock.clone());
+        (orch, mock)
+    }
+
+    fn orch_with_nvo() -> (VxlanOrch, Arc<MockCallbacks>) {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        orch.handle_evpn_nvo_set("nvo1", &fields(&[("source_vtep", "vtep1")]))
+            .unwrap();
+        (orch, mock)
+    }
 
     fn create_test_tunnel(tunnel_name: &str, src_ip: &str, dst_ip: &str) -> VxlanTunnelEntry {
         let src_addr: std::net::IpAddr = src_ip.parse().unwrap();
@@ -499,4 +1242,220 @@ mod tests {
         assert_eq!(vrf_maps.len(), 0);
         assert_eq!(vlan_maps.len(), 0);
     }
+
+    #[test]
+    fn test_handle_tunnel_set_creates_sai_objects() {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+
+        let state = orch.get_named_tunnel("vtep1").unwrap();
+        assert!(state.entry.config.is_p2mp());
+        assert_ne!(state.entry.tunnel_oid, 0);
+        assert_ne!(state.entry.encap_mapper_oid, state.entry.decap_mapper_oid);
+        assert_ne!(state.term_oid, 0);
+        // encap mapper, decap mapper, tunnel, termination
+        assert_eq!(mock.live_count(), 4);
+        assert_eq!(orch.stats().stats.tunnels_created, 1);
+
+        // Re-applying the same config is a no-op
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        assert_eq!(mock.live_count(), 4);
+    }
+
+    #[test]
+    fn test_handle_tunnel_set_requires_src_ip() {
+        let (mut orch, _mock) = orch_with_mock();
+        let result = orch.handle_tunnel_set("vtep1", &fields(&[("dst_ip", "10.0.0.2")]));
+        assert!(matches!(result, Err(VxlanOrchError::InvalidConfig(_))));
+
+        let result = orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "bogus")]));
+        assert!(matches!(result, Err(VxlanOrchError::InvalidIp(_))));
+    }
+
+    #[test]
+    fn test_handle_tunnel_set_rolls_back_on_failure() {
+        let (mut orch, mock) = orch_with_mock();
+        mock.fail_termination.store(true, Ordering::SeqCst);
+
+        let result = orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]));
+        assert!(matches!(result, Err(VxlanOrchError::SaiError(_))));
+        assert!(orch.get_named_tunnel("vtep1").is_none());
+        assert_eq!(mock.live_count(), 0);
+    }
+
+    #[test]
+    fn test_tunnel_map_refs_block_tunnel_removal() {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        orch.handle_tunnel_map_set(
+            "vtep1|map_1000_Vlan100",
+            &fields(&[("vni", "1000"), ("vlan", "Vlan100")]),
+        )
+        .unwrap();
+        assert_eq!(orch.get_named_tunnel("vtep1").unwrap().map_refs, 1);
+        assert_eq!(mock.live_count(), 6);
+
+        let result = orch.handle_tunnel_del("vtep1");
+        assert!(matches!(result, Err(VxlanOrchError::TunnelInUse(_))));
+
+        orch.handle_tunnel_map_del("vtep1|map_1000_Vlan100")
+            .unwrap();
+        assert_eq!(orch.get_named_tunnel("vtep1").unwrap().map_refs, 0);
+        orch.handle_tunnel_del("vtep1").unwrap();
+        assert_eq!(mock.live_count(), 0);
+    }
+
+    #[test]
+    fn test_tunnel_map_requires_tunnel() {
+        let (mut orch, _mock) = orch_with_mock();
+        let result = orch.handle_tunnel_map_set(
+            "vtep1|map_1000_Vlan100",
+            &fields(&[("vni", "1000"), ("vlan", "Vlan100")]),
+        );
+        assert!(matches!(result, Err(VxlanOrchError::TunnelNameNotFound(_))));
+
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        let result = orch.handle_tunnel_map_set("vtep1|map_bad", &fields(&[("vni", "0")]));
+        assert!(matches!(result, Err(VxlanOrchError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_tunnel_map_rejects_duplicate_vni() {
+        let (mut orch, _mock) = orch_with_mock();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        orch.handle_tunnel_map_set(
+            "vtep1|map_a",
+            &fields(&[("vni", "1000"), ("vlan", "Vlan100")]),
+        )
+        .unwrap();
+
+        let result = orch.handle_tunnel_map_set(
+            "vtep1|map_b",
+            &fields(&[("vni", "1000"), ("vlan", "Vlan200")]),
+        );
+        assert!(matches!(result, Err(VxlanOrchError::InvalidConfig(_))));
+        assert_eq!(orch.get_named_tunnel("vtep1").unwrap().map_refs, 1);
+    }
+
+    #[test]
+    fn test_evpn_nvo_requires_source_tunnel() {
+        let (mut orch, _mock) = orch_with_mock();
+        let result = orch.handle_evpn_nvo_set("nvo1", &fields(&[("source_vtep", "vtep1")]));
+        assert!(matches!(result, Err(VxlanOrchError::TunnelNameNotFound(_))));
+
+        let result = orch.handle_remote_vni_set("Vlan100|10.0.0.2", &fields(&[("vni", "1000")]));
+        assert!(matches!(result, Err(VxlanOrchError::NvoNotConfigured)));
+    }
+
+    #[test]
+    fn test_nvo_source_ip_change_rejected() {
+        let (mut orch, _mock) = orch_with_nvo();
+        let result = orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.9")]));
+        assert!(matches!(result, Err(VxlanOrchError::NvoSourceIpChange(_))));
+
+        let src: IpAddr = "10.0.0.1".parse().unwrap();
+        assert_eq!(
+            orch.get_named_tunnel("vtep1").unwrap().entry.config.src_ip,
+            src
+        );
+    }
+
+    #[test]
+    fn test_tunnel_src_change_without_refs_recreates() {
+        let (mut orch, mock) = orch_with_mock();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.1")]))
+            .unwrap();
+        orch.handle_tunnel_set("vtep1", &fields(&[("src_ip", "10.0.0.9")]))
+            .unwrap();
+
+        let src: IpAddr = "10.0.0.9".parse().unwrap();
+        assert_eq!(
+            orch.get_named_tunnel("vtep1").unwrap().entry.config.src_ip,
+            src
+        );
+        assert_eq!(mock.live_count(), 4);
+    }
+
+    #[test]
+    fn test_remote_vtep_created_from_imet() {
+        let (mut orch, mock) = orch_with_nvo();
+        let remote: IpAddr = "10.0.0.2".parse().unwrap();
+
+        orch.handle_remote_vni_set("Vlan100|10.0.0.2", &fields(&[("vni", "1000")]))
+            .unwrap();
+        let vtep = orch.get_remote_vtep(&remote).unwrap();
+        assert_eq!(vtep.entry.config.tunnel_name, "EVPN_10.0.0.2");
+        assert_eq!(vtep.entry.config.dst_ip, remote);
+        assert_eq!(vtep.vnis.get(&100), Some(&1000));
+
+        // Remote VTEP tunnels share the source tunnel's mappers
+        let source = orch.get_named_tunnel("vtep1").unwrap();
+        assert_eq!(vtep.entry.encap_mapper_oid, source.entry.encap_mapper_oid);
+        assert_eq!(mock.live_count(), 5);
+
+        let result = orch.handle_tunnel_del("vtep1");
+        assert!(matches!(result, Err(VxlanOrchError::TunnelInUse(_))));
+        let result = orch.handle_evpn_nvo_del("nvo1");
+        assert!(matches!(result, Err(VxlanOrchError::NvoInUse(_))));
+    }
+
+    #[test]
+    fn test_remote_vtep_held_by_mac_routes() {
+        let (mut orch, mock) = orch_with_nvo();
+        let remote: IpAddr = "10.0.0.2".parse().unwrap();
+
+        orch.handle_remote_vni_set("Vlan100|10.0.0.2", &fields(&[("vni", "1000")]))
+            .unwrap();
+        orch.add_remote_mac_ref(remote).unwrap();
+        orch.handle_remote_vni_del("Vlan100|10.0.0.2").unwrap();
+        assert_eq!(orch.get_remote_vtep(&remote).unwrap().ref_count(), 1);
+
+        orch.remove_remote_mac_ref(remote).unwrap();
+        assert!(orch.get_remote_vtep(&remote).is_none());
+        assert_eq!(mock.live_count(), 4);
+        assert!(matches!(
+            orch.remove_remote_mac_ref(remote),
+            Err(VxlanOrchError::RemoteVtepNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_vtep_churn() {
+        let (mut orch, mock) = orch_with_nvo();
+        let base = mock.live_count();
+
+        for round in 0..5 {
+            for host in 2..6 {
+                for vlan in [100, 200] {
+                    let key = format!("Vlan{}|10.0.0.{}", vlan, host);
+                    let vni = (vlan as u32 * 10).to_string();
+                    orch.handle_remote_vni_set(&key, &fields(&[("vni", vni.as_str())]))
+                        .unwrap();
+                }
+            }
+            assert_eq!(orch.remote_vtep_count(), 4);
+            assert_eq!(mock.live_count(), base + 4);
+
+            for host in 2..6 {
+                for vlan in [100, 200] {
+                    let key = format!("Vlan{}|10.0.0.{}", vlan, host);
+                    orch.handle_remote_vni_del(&key).unwrap();
+                }
+            }
+            assert_eq!(orch.remote_vtep_count(), 0, "round {}", round);
+            assert_eq!(mock.live_count(), base);
+        }
+
+        assert_eq!(orch.stats().stats.remote_vteps_created, 20);
+        assert_eq!(orch.stats().stats.remote_vteps_removed, 20);
+
+        orch.handle_evpn_nvo_del("nvo1").unwrap();
+        orch.handle_tunnel_del("vtep1").unwrap();
+        assert_eq!(mock.live_count(), 0);
+    }
 }
@@ -1,11 +1,42 @@
 //! VXLAN tunnel types.
 
 use std::collections::HashMap;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr};
 
 pub type RawSaiObjectId = u64;
 pub type Vni = u32;
 
+/// Largest VNI representable in the 24-bit VXLAN header field.
+pub const MAX_VNI: Vni = 0x00FF_FFFF;
+
+/// Table names handled by VxlanOrch.
+pub mod tables {
+    pub const VXLAN_TUNNEL: &str = "VXLAN_TUNNEL";
+    pub const VXLAN_TUNNEL_MAP: &str = "VXLAN_TUNNEL_MAP";
+    pub const EVPN_NVO: &str = "VXLAN_EVPN_NVO";
+    pub const VXLAN_REMOTE_VNI: &str = "VXLAN_REMOTE_VNI_TABLE";
+}
+
+/// Parses a VNI, rejecting 0 and values wider than 24 bits.
+pub fn parse_vni(value: &str) -> Result<Vni, String> {
+    let vni: Vni = value
+        .parse()
+        .map_err(|_| format!("Invalid VNI: {}", value))?;
+    if vni == 0 || vni > MAX_VNI {
+        return Err(format!("VNI {} out of range 1-{}", vni, MAX_VNI));
+    }
+    Ok(vni)
+}
+
+/// Parses a VLAN interface name such as `Vlan100`.
+pub fn parse_vlan_name(value: &str) -> Result<u16, String> {
+    value
+        .strip_prefix("Vlan")
+        .and_then(|id| id.parse::<u16>().ok())
+        .filter(|id| (1..=4094).contains(id))
+        .ok_or_else(|| format!("Invalid VLAN: {}", value))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VxlanTunnelKey {
     pub src_ip: IpAddr,
@@ -18,13 +49,47 @@ impl VxlanTunnelKey {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VxlanTunnelConfig {
     pub src_ip: IpAddr,
     pub dst_ip: IpAddr,
     pub tunnel_name: String,
 }
 
+impl VxlanTunnelConfig {
+    /// Creates a config with unspecified endpoints, to be filled by `parse_field`.
+    pub fn new(tunnel_name: String) -> Self {
+        Self {
+            src_ip: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            dst_ip: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            tunnel_name,
+        }
+    }
+
+    /// Applies one VXLAN_TUNNEL field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "src_ip" => {
+                self.src_ip = value
+                    .parse()
+                    .map_err(|_| format!("Invalid src_ip: {}", value))?
+            }
+            "dst_ip" => {
+                self.dst_ip = value
+                    .parse()
+                    .map_err(|_| format!("Invalid dst_ip: {}", value))?
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns true for a point-to-multipoint tunnel (no fixed remote endpoint).
+    pub fn is_p2mp(&self) -> bool {
+        self.dst_ip.is_unspecified()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VxlanTunnelEntry {
     pub key: VxlanTunnelKey,
@@ -100,6 +165,99 @@ impl VxlanVlanMapEntry {
     }
 }
 
+/// SAI tunnel map kinds used for VLAN/VNI translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VxlanTunnelMapType {
+    /// Encapsulation: VLAN to VNI.
+    VlanToVni,
+    /// Decapsulation: VNI to VLAN.
+    VniToVlan,
+}
+
+/// A tunnel created from VXLAN_TUNNEL, with its termination and references.
+#[derive(Debug, Clone)]
+pub struct VxlanTunnelState {
+    pub entry: VxlanTunnelEntry,
+    pub term_oid: RawSaiObjectId,
+    /// VXLAN_TUNNEL_MAP entries on this tunnel.
+    pub map_refs: u32,
+}
+
+/// VXLAN_TUNNEL_MAP entry, keyed `<tunnel>|<map_name>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VxlanTunnelMapConfig {
+    pub tunnel_name: String,
+    pub map_name: String,
+    pub vni: Vni,
+    pub vlan_id: u16,
+}
+
+impl VxlanTunnelMapConfig {
+    pub fn from_key(key: &str) -> Result<Self, String> {
+        let (tunnel_name, map_name) = key
+            .split_once('|')
+            .filter(|(tunnel, map)| !tunnel.is_empty() && !map.is_empty())
+            .ok_or_else(|| format!("Invalid tunnel map key: {}", key))?;
+        Ok(Self {
+            tunnel_name: tunnel_name.to_string(),
+            map_name: map_name.to_string(),
+            vni: 0,
+            vlan_id: 0,
+        })
+    }
+
+    /// Applies one VXLAN_TUNNEL_MAP field; unknown fields are ignored.
+    pub fn parse_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        match field {
+            "vni" => self.vni = parse_vni(value)?,
+            "vlan" => self.vlan_id = parse_vlan_name(value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.vni == 0 {
+            return Err(format!("{}: vni is required", self.map_name));
+        }
+        if self.vlan_id == 0 {
+            return Err(format!("{}: vlan is required", self.map_name));
+        }
+        Ok(())
+    }
+}
+
+/// Programmed VXLAN_TUNNEL_MAP entry.
+#[derive(Debug, Clone)]
+pub struct VxlanTunnelMapEntry {
+    pub config: VxlanTunnelMapConfig,
+    pub encap_entry_oid: RawSaiObjectId,
+    pub decap_entry_oid: RawSaiObjectId,
+}
+
+/// EVPN_NVO entry designating the EVPN source tunnel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvpnNvoConfig {
+    pub name: String,
+    pub source_vtep: String,
+}
+
+/// Tunnel to a remote VTEP, created on demand from IMET and MAC routes.
+#[derive(Debug, Clone)]
+pub struct VxlanRemoteVtep {
+    pub entry: VxlanTunnelEntry,
+    /// VNIs learned from IMET routes, keyed by VLAN.
+    pub vnis: HashMap<u16, Vni>,
+    /// MAC routes pointing at this VTEP.
+    pub mac_refs: u32,
+}
+
+impl VxlanRemoteVtep {
+    pub fn ref_count(&self) -> usize {
+        self.vnis.len() + self.mac_refs as usize
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VxlanEncapType {
     L2,
@@ -111,4 +269,55 @@ pub struct VxlanStats {
     pub tunnels_created: u64,
     pub vrf_maps_created: u64,
     pub vlan_maps_created: u64,
+    pub remote_vteps_created: u64,
+    pub remote_vteps_removed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vni() {
+        assert_eq!(parse_vni("1000"), Ok(1000));
+        assert_eq!(parse_vni("16777215"), Ok(MAX_VNI));
+        assert!(parse_vni("0").is_err());
+        assert!(parse_vni("16777216").is_err());
+        assert!(parse_vni("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_vlan_name() {
+        assert_eq!(parse_vlan_name("Vlan100"), Ok(100));
+        assert!(parse_vlan_name("Vlan0").is_err());
+        assert!(parse_vlan_name("Vlan4095").is_err());
+        assert!(parse_vlan_name("Ethernet0").is_err());
+    }
+
+    #[test]
+    fn test_tunnel_config_parse() {
+        let mut config = VxlanTunnelConfig::new("vtep".to_string());
+        config.parse_field("src_ip", "10.1.0.1").unwrap();
+        assert_eq!(config.src_ip, "10.1.0.1".parse::<IpAddr>().unwrap());
+        assert!(config.is_p2mp());
+        assert!(config.parse_field("src_ip", "bogus").is_err());
+
+        config.parse_field("dst_ip", "10.2.0.1").unwrap();
+        assert!(!config.is_p2mp());
+    }
+
+    #[test]
+    fn test_tunnel_map_config() {
+        let mut config = VxlanTunnelMapConfig::from_key("vtep|map_1000_Vlan100").unwrap();
+        assert_eq!(config.tunnel_name, "vtep");
+        assert!(config.validate().is_err());
+
+        config.parse_field("vni", "1000").unwrap();
+        config.parse_field("vlan", "Vlan100").unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.vlan_id, 100);
+
+        assert!(VxlanTunnelMapConfig::from_key("vtep").is_err());
+        assert!(VxlanTunnelMapConfig::from_key("|map").is_err());
+    }
 }
@@ -7,7 +7,7 @@ use crate::audit_log;
 use sonic_sai::types::RawSaiObjectId;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use super::types::{
     ClearRequest, QueueIds, QueueType, WatermarkConfig, WatermarkGroup, WatermarkStatus,
@@ -25,6 +25,8 @@ pub enum WatermarkOrchError {
     PortsNotReady,
     /// Callback error.
     CallbackError(String),
+    /// Invalid configuration value.
+    InvalidConfig(String),
 }
 
 impl std::fmt::Display for WatermarkOrchError {
@@ -34,6 +36,7 @@ impl std::fmt::Display for WatermarkOrchError {
             Self::UnknownTable(table) => write!(f, "Unknown watermark table: {}", table),
             Self::PortsNotReady => write!(f, "Ports not ready"),
             Self::CallbackError(msg) => write!(f, "Callback error: {}", msg),
+            Self::InvalidConfig(msg) => write!(f, "Invalid watermark config: {}", msg),
         }
     }
 }
@@ -57,6 +60,18 @@ pub trait WatermarkOrchCallbacks: Send + Sync {
     fn get_buffer_pool_oids(&self) -> HashMap<String, RawSaiObjectId> {
         HashMap::new()
     }
+
+    /// Copies the current watermark of an object into a COUNTERS_DB table.
+    fn snapshot_watermark(
+        &self,
+        _table: WatermarkTable,
+        _stat_name: &str,
+        _obj_id: RawSaiObjectId,
+    ) {
+    }
+
+    /// Clears the hardware watermark of an object.
+    fn clear_hw_watermark(&self, _stat_name: &str, _obj_id: RawSaiObjectId) {}
 }
 
 /// Configuration for WatermarkOrch.
@@ -97,6 +112,8 @@ pub struct WatermarkOrchStats {
     pub timer_expirations: u64,
     /// Number of config updates.
     pub config_updates: u64,
+    /// Number of objects snapshotted by periodic runs.
+    pub periodic_snapshots: u64,
 }
 
 /// WatermarkOrch - manages buffer watermark statistics.
@@ -109,6 +126,8 @@ pub struct WatermarkOrch {
     status: WatermarkStatus,
     /// Whether timer interval changed and needs reset.
     timer_changed: bool,
+    /// Deadline of the next periodic clear (None while disarmed).
+    next_periodic_clear: Option<Instant>,
     /// Priority Group IDs.
     pg_ids: Vec<RawSaiObjectId>,
     /// Queue IDs by type.
@@ -124,6 +143,7 @@ impl std::fmt::Debug for WatermarkOrch {
         f.debug_struct("WatermarkOrch")
             .field("config", &self.config)
             .field("status", &self.status)
+            .field("next_periodic_clear", &self.next_periodic_clear)
             .field("pg_ids_count", &self.pg_ids.len())
             .field("initialized", &self.initialized)
             .finish()
@@ -138,6 +158,7 @@ impl WatermarkOrch {
             callbacks: None,
             status: WatermarkStatus::new(),
             timer_changed: false,
+            next_periodic_clear: None,
             pg_ids: Vec::new(),
             queue_ids: QueueIds::new(),
             stats: WatermarkOrchStats::default(),
@@ -209,6 +230,76 @@ impl WatermarkOrch {
         self.timer_changed = false;
     }
 
+    /// Handles a CONFIG_DB WATERMARK_TABLE field.
+    ///
+    /// Only `TELEMETRY_INTERVAL|interval` is recognized; the new interval
+    /// takes effect on the next `poll_timer()` call.
+    pub fn handle_config_field(
+        &mut self,
+        key: &str,
+        field: &str,
+        value: &str,
+    ) -> Result<(), WatermarkOrchError> {
+        if key != "TELEMETRY_INTERVAL" || field != "interval" {
+            return Ok(());
+        }
+
+        let secs = value.parse::<u64>().map_err(|e| {
+            WatermarkOrchError::InvalidConfig(format!("interval '{}': {}", value, e))
+        })?;
+        self.set_telemetry_interval_secs(secs);
+
+        audit_log!(AuditRecord::new(
+            AuditCategory::ConfigurationChange,
+            "WatermarkOrch",
+            "set_telemetry_interval"
+        )
+        .with_outcome(AuditOutcome::Success)
+        .with_object_id(key)
+        .with_object_type("watermark_config")
+        .with_details(serde_json::json!({
+            "interval_seconds": secs,
+        })));
+
+        Ok(())
+    }
+
+    /// Returns the deadline of the next periodic clear, if armed.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.next_periodic_clear
+    }
+
+    /// Drives the periodic clear timer.
+    ///
+    /// Arms the timer when watermarks get enabled, re-arms it from `now` after
+    /// an interval change and runs the periodic clear once the deadline has
+    /// passed. A zero interval or no enabled group disarms the timer. Returns
+    /// true if a periodic run happened.
+    pub fn poll_timer(&mut self, now: Instant) -> bool {
+        let interval = self.config.telemetry_interval;
+        if interval.is_zero() || !self.status.any_enabled() {
+            self.next_periodic_clear = None;
+            self.timer_changed = false;
+            return false;
+        }
+
+        match self.next_periodic_clear {
+            Some(deadline) if !self.timer_changed => {
+                if now < deadline {
+                    return false;
+                }
+                self.handle_timer_expiration();
+                self.next_periodic_clear = Some(now + interval);
+                true
+            }
+            _ => {
+                self.timer_changed = false;
+                self.next_periodic_clear = Some(now + interval);
+                false
+            }
+        }
+    }
+
     /// Handles flex counter status update.
     pub fn handle_flex_counter_status(&mut self, group: WatermarkGroup, enabled: bool) -> bool {
         let was_enabled = self.status.any_enabled();
@@ -324,6 +415,9 @@ impl WatermarkOrch {
     }
 
     /// Handles timer expiration (periodic watermark clearing).
+    ///
+    /// For every enabled group, snapshots the current watermarks into the
+    /// periodic COUNTERS_DB table and then clears them in hardware.
     pub fn handle_timer_expiration(&mut self) {
         // Reset timer if interval changed
         if self.timer_changed {
@@ -335,37 +429,33 @@ impl WatermarkOrch {
             return;
         }
 
-        let table = WatermarkTable::Periodic;
-
-        // Clear PG watermarks
-        let pg_ids = self.pg_ids.clone();
-        self.clear_watermarks(
-            table,
-            "SAI_INGRESS_PRIORITY_GROUP_STAT_XOFF_ROOM_WATERMARK_BYTES",
-            &pg_ids,
-        );
-        self.clear_watermarks(
-            table,
-            "SAI_INGRESS_PRIORITY_GROUP_STAT_SHARED_WATERMARK_BYTES",
-            &pg_ids,
-        );
-
-        // Clear queue watermarks
-        let unicast_ids = self.queue_ids.unicast.clone();
-        let multicast_ids = self.queue_ids.multicast.clone();
-        let all_ids = self.queue_ids.all.clone();
-
-        self.clear_watermarks(table, "SAI_QUEUE_STAT_SHARED_WATERMARK_BYTES", &unicast_ids);
-        self.clear_watermarks(
-            table,
-            "SAI_QUEUE_STAT_SHARED_WATERMARK_BYTES",
-            &multicast_ids,
-        );
-        self.clear_watermarks(table, "SAI_QUEUE_STAT_SHARED_WATERMARK_BYTES", &all_ids);
+        let pool_ids: Vec<RawSaiObjectId> = self
+            .callbacks
+            .as_ref()
+            .map(|callbacks| callbacks.get_buffer_pool_oids().into_values().collect())
+            .unwrap_or_default();
+
+        for request in ClearRequest::ALL {
+            if let Some(group) = request.group() {
+                if !self.status.is_enabled(group) {
+                    continue;
+                }
+            }
 
-        // Clear buffer pool watermarks
-        self.clear_buffer_pool_watermarks(table, "SAI_BUFFER_POOL_STAT_WATERMARK_BYTES");
-        self.clear_buffer_pool_watermarks(table, "SAI_BUFFER_POOL_STAT_XOFF_ROOM_WATERMARK_BYTES");
+            let ids = match request {
+                ClearRequest::BufferPool | ClearRequest::HeadroomPool => pool_ids.as_slice(),
+                ClearRequest::PgHeadroom | ClearRequest::PgShared => self.pg_ids.as_slice(),
+                _ => self.queue_ids.get_for_clear(request),
+            };
+            self.stats.periodic_snapshots += ids.len() as u64;
+
+            if let Some(callbacks) = &self.callbacks {
+                for &id in ids {
+                    callbacks.snapshot_watermark(WatermarkTable::Periodic, request.stat_name(), id);
+                    callbacks.clear_hw_watermark(request.stat_name(), id);
+                }
+            }
+        }
 
         self.stats.timer_expirations += 1;
     }
@@ -1177,4 +1267,188 @@ mod tests {
         orch.set_initialized(false);
         assert!(!orch.is_initialized());
     }
+
+    /// Records snapshot, hardware clear and table clear calls in order.
+    #[derive(Default)]
+    struct RecordingCallbacks {
+        ops: Mutex<Vec<String>>,
+    }
+
+    impl RecordingCallbacks {
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut *self.ops.lock().unwrap())
+        }
+    }
+
+    impl WatermarkOrchCallbacks for RecordingCallbacks {
+        fn clear_watermark(&self, table: WatermarkTable, _stat_name: &str, obj_id: RawSaiObjectId) {
+            self.ops
+                .lock()
+                .unwrap()
+                .push(format!("clear:{}:{}", table, obj_id));
+        }
+
+        fn get_buffer_pool_oids(&self) -> HashMap<String, RawSaiObjectId> {
+            HashMap::from([("ingress_lossless_pool".to_string(), 500)])
+        }
+
+        fn snapshot_watermark(
+            &self,
+            table: WatermarkTable,
+            stat_name: &str,
+            obj_id: RawSaiObjectId,
+        ) {
+            self.ops
+                .lock()
+                .unwrap()
+                .push(format!("snapshot:{}:{}:{}", table, stat_name, obj_id));
+        }
+
+        fn clear_hw_watermark(&self, stat_name: &str, obj_id: RawSaiObjectId) {
+            self.ops
+                .lock()
+                .unwrap()
+                .push(format!("clear_hw:{}:{}", stat_name, obj_id));
+        }
+    }
+
+    fn periodic_orch(interval_secs: u64) -> (WatermarkOrch, Arc<RecordingCallbacks>) {
+        let callbacks = Arc::new(RecordingCallbacks::default());
+        let mut orch = WatermarkOrch::new(WatermarkOrchConfig::with_interval_secs(interval_secs));
+        orch.set_callbacks(callbacks.clone());
+        orch.add_pg_id(100);
+        orch.add_pg_id(101);
+        orch.add_queue_id(QueueType::Unicast, 200);
+        orch.handle_flex_counter_status(WatermarkGroup::PriorityGroup, true);
+        (orch, callbacks)
+    }
+
+    #[test]
+    fn test_periodic_clear_snapshots_enabled_groups() {
+        let (mut orch, callbacks) = periodic_orch(120);
+        orch.handle_timer_expiration();
+
+        let ops = callbacks.take();
+        // 2 PGs x (headroom, shared) + 1 pool x (buffer, headroom); queues are disabled
+        assert_eq!(
+            ops.iter().filter(|op| op.starts_with("snapshot:")).count(),
+            6
+        );
+        assert_eq!(
+            ops.iter().filter(|op| op.starts_with("clear_hw:")).count(),
+            6
+        );
+        assert!(ops.iter().all(|op| !op.ends_with(":200")));
+        assert!(ops
+            .iter()
+            .all(|op| !op.starts_with("snapshot:") || op.starts_with("snapshot:PERIODIC:")));
+        assert_eq!(
+            ops[..2],
+            [
+                "snapshot:PERIODIC:SAI_INGRESS_PRIORITY_GROUP_STAT_XOFF_ROOM_WATERMARK_BYTES:100",
+                "clear_hw:SAI_INGRESS_PRIORITY_GROUP_STAT_XOFF_ROOM_WATERMARK_BYTES:100",
+            ]
+        );
+        assert_eq!(orch.stats().periodic_snapshots, 6);
+
+        orch.handle_flex_counter_status(WatermarkGroup::Queue, true);
+        orch.handle_timer_expiration();
+        assert!(callbacks
+            .take()
+            .contains(&"clear_hw:SAI_QUEUE_STAT_SHARED_WATERMARK_BYTES:200".to_string()));
+    }
+
+    #[test]
+    fn test_interval_change_mid_cycle() {
+        let (mut orch, callbacks) = periodic_orch(120);
+        let t0 = Instant::now();
+        let secs = Duration::from_secs;
+
+        assert!(!orch.poll_timer(t0));
+        assert_eq!(orch.next_deadline(), Some(t0 + secs(120)));
+        assert!(!orch.poll_timer(t0 + secs(60)));
+
+        // Shorten the interval halfway through the cycle
+        orch.handle_config_field("TELEMETRY_INTERVAL", "interval", "30")
+            .unwrap();
+        assert!(!orch.poll_timer(t0 + secs(60)));
+        assert_eq!(orch.next_deadline(), Some(t0 + secs(90)));
+        assert!(callbacks.take().is_empty());
+
+        assert!(!orch.poll_timer(t0 + secs(89)));
+        assert!(orch.poll_timer(t0 + secs(90)));
+        assert_eq!(orch.next_deadline(), Some(t0 + secs(120)));
+        assert!(!callbacks.take().is_empty());
+        assert_eq!(orch.stats().timer_expirations, 1);
+
+        // The original 120s deadline no longer applies
+        assert!(orch.poll_timer(t0 + secs(120)));
+        assert_eq!(orch.stats().timer_expirations, 2);
+    }
+
+    #[test]
+    fn test_zero_interval_disables_periodic_clear() {
+        let (mut orch, callbacks) = periodic_orch(60);
+        let t0 = Instant::now();
+        assert!(!orch.poll_timer(t0));
+
+        orch.handle_config_field("TELEMETRY_INTERVAL", "interval", "0")
+            .unwrap();
+        assert!(!orch.poll_timer(t0 + Duration::from_secs(3600)));
+        assert_eq!(orch.next_deadline(), None);
+        assert!(callbacks.take().is_empty());
+
+        orch.set_telemetry_interval_secs(60);
+        assert!(!orch.poll_timer(t0 + Duration::from_secs(3600)));
+        assert!(orch.poll_timer(t0 + Duration::from_secs(3660)));
+        assert_eq!(orch.stats().timer_expirations, 1);
+    }
+
+    #[test]
+    fn test_clear_request_during_periodic_cycle() {
+        let (mut orch, callbacks) = periodic_orch(60);
+        let t0 = Instant::now();
+        orch.poll_timer(t0);
+
+        // A user clear mid-cycle is applied at once and leaves the timer alone
+        orch.handle_clear_request(WatermarkTable::User, ClearRequest::PgShared)
+            .unwrap();
+        assert_eq!(callbacks.take(), vec!["clear:USER:100", "clear:USER:101"]);
+        assert_eq!(orch.next_deadline(), Some(t0 + Duration::from_secs(60)));
+        assert_eq!(orch.stats().timer_expirations, 0);
+
+        // A periodic-table clear in the same tick as the expiry follows the run
+        assert!(orch.poll_timer(t0 + Duration::from_secs(60)));
+        orch.handle_clear_request(WatermarkTable::Periodic, ClearRequest::PgShared)
+            .unwrap();
+        let ops = callbacks.take();
+        let last_snapshot = ops
+            .iter()
+            .rposition(|op| op.starts_with("snapshot:"))
+            .unwrap();
+        assert_eq!(
+            ops[last_snapshot + 2..],
+            ["clear:PERIODIC:100", "clear:PERIODIC:101"]
+        );
+        assert_eq!(orch.stats().clears_processed, 2);
+        assert_eq!(orch.stats().timer_expirations, 1);
+        assert_eq!(orch.next_deadline(), Some(t0 + Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_handle_config_field() {
+        let mut orch = WatermarkOrch::new(WatermarkOrchConfig::default());
+
+        orch.handle_config_field("TELEMETRY_INTERVAL", "interval", "45")
+            .unwrap();
+        assert_eq!(orch.telemetry_interval(), Duration::from_secs(45));
+        assert!(orch.timer_changed());
+
+        assert!(matches!(
+            orch.handle_config_field("TELEMETRY_INTERVAL", "interval", "abc"),
+            Err(WatermarkOrchError::InvalidConfig(_))
+        ));
+        orch.handle_config_field("OTHER", "interval", "10").unwrap();
+        assert_eq!(orch.telemetry_interval(), Duration::from_secs(45));
+    }
 }
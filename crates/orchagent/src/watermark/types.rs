@@ -167,6 +167,28 @@ pub enum ClearRequest {
 }
 
 impl ClearRequest {
+    /// All clear requests, in the order a periodic run processes them.
+    pub const ALL: [ClearRequest; 7] = [
+        Self::PgHeadroom,
+        Self::PgShared,
+        Self::QueueSharedUnicast,
+        Self::QueueSharedMulticast,
+        Self::QueueSharedAll,
+        Self::BufferPool,
+        Self::HeadroomPool,
+    ];
+
+    /// Returns the flex counter group gating this request (None for buffer pools).
+    pub fn group(&self) -> Option<WatermarkGroup> {
+        match self {
+            Self::PgHeadroom | Self::PgShared => Some(WatermarkGroup::PriorityGroup),
+            Self::QueueSharedUnicast | Self::QueueSharedMulticast | Self::QueueSharedAll => {
+                Some(WatermarkGroup::Queue)
+            }
+            Self::BufferPool | Self::HeadroomPool => None,
+        }
+    }
+
     /// Returns the SAI stat name for this clear request.
     pub fn stat_name(&self) -> &'static str {
         match self {
@@ -389,6 +411,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clear_request_group() {
+        for request in ClearRequest::ALL {
+            assert_eq!(
+                request.request_name().parse::<ClearRequest>().unwrap(),
+                request
+            );
+        }
+        assert_eq!(
+            ClearRequest::PgHeadroom.group(),
+            Some(WatermarkGroup::PriorityGroup)
+        );
+        assert_eq!(
+            ClearRequest::QueueSharedMulticast.group(),
+            Some(WatermarkGroup::Queue)
+        );
+        assert_eq!(ClearRequest::BufferPool.group(), None);
+    }
+
     #[test]
     fn test_queue_ids() {
         let mut ids = QueueIds::new();
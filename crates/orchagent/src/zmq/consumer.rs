@@ -0,0 +1,655 @@
+//! ZMQ consumer path for high-rate tables.
+//!
+//! Mirrors the C++ ZmqServer/ZmqConsumerStateTable pair used for DASH: a PULL
+//! socket is bound on a dedicated receiver thread, every frame is decoded into
+//! a [`KeyOpFieldsValues`] and queued per table. The daemon drains those
+//! queues into the same [`Consumer`] that the Redis path feeds, so both
+//! sources share deduplication and per-key ordering.
+//!
+//! # Wire Format
+//!
+//! Frames use the swss-common `BinarySerializer` layout, so a C++
+//! `ZmqProducerStateTable` can feed this consumer directly. The frame is a
+//! pair count followed by length-prefixed pairs; counts and lengths are
+//! native-endian `size_t`, as swss-common writes them:
+//!
+//! ```text
+//! pair_count (db table) (key fv_count (field value){fv_count})*
+//! ```
+//!
+//! `fv_count` is a decimal string. A key without fields is a DEL, so as in
+//! swss-common a SET must carry at least one field.
+
+use super::orch::ZmqOrchError;
+use super::types::{ZmqConsumerStats, ZmqOverflowPolicy};
+use crate::audit::{AuditCategory, AuditOutcome, AuditRecord};
+use crate::audit_log;
+use log::{debug, warn};
+use sonic_orch_common::{Consumer, KeyOpFieldsValues, Operation};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Receive timeout used to periodically check for shutdown.
+const RECV_TIMEOUT_MS: i32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct ZmqConsumerConfig {
+    /// Endpoint to bind, e.g. `tcp://*:8100` or `inproc://dash`
+    pub endpoint: String,
+    /// Tables accepted on this endpoint
+    pub tables: Vec<String>,
+    /// Maximum queued entries per table
+    pub queue_capacity: usize,
+    /// Behavior when a table queue is full
+    pub overflow_policy: ZmqOverflowPolicy,
+}
+
+impl ZmqConsumerConfig {
+    pub fn new(endpoint: impl Into<String>, tables: Vec<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            tables,
+            queue_capacity: 4096,
+            overflow_policy: ZmqOverflowPolicy::default(),
+        }
+    }
+}
+
+/// Size of the length and count prefixes, swss-common's `size_t`.
+const SIZE_LEN: usize = std::mem::size_of::<usize>();
+
+/// Encodes table updates into the swss-common ZMQ wire format.
+pub fn encode_message(db: &str, table: &str, entries: &[KeyOpFieldsValues]) -> Vec<u8> {
+    fn put_str(buf: &mut Vec<u8>, s: &str) {
+        buf.extend_from_slice(&s.len().to_ne_bytes());
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    // The pair count is filled in once all pairs are written
+    let mut buf = vec![0; SIZE_LEN];
+    let mut pairs: usize = 1;
+    put_str(&mut buf, db);
+    put_str(&mut buf, table);
+    for entry in entries {
+        let fvs: &[(String, String)] = match entry.op {
+            Operation::Set => &entry.fvs,
+            Operation::Del => &[],
+        };
+        put_str(&mut buf, &entry.key);
+        put_str(&mut buf, &fvs.len().to_string());
+        for (field, value) in fvs {
+            put_str(&mut buf, field);
+            put_str(&mut buf, value);
+        }
+        pairs += 1 + fvs.len();
+    }
+    buf[..SIZE_LEN].copy_from_slice(&pairs.to_ne_bytes());
+    buf
+}
+
+/// Decodes a swss-common ZMQ frame into its database name, table name and
+/// entries.
+pub fn decode_message(
+    data: &[u8],
+) -> Result<(String, String, Vec<KeyOpFieldsValues>), ZmqOrchError> {
+    struct Reader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl Reader<'_> {
+        fn take(&mut self, len: usize) -> Result<&[u8], ZmqOrchError> {
+            let end = self
+                .pos
+                .checked_add(len)
+                .filter(|end| *end <= self.data.len())
+                .ok_or_else(|| {
+                    ZmqOrchError::InvalidMessage(format!("truncated at offset {}", self.pos))
+                })?;
+            let bytes = &self.data[self.pos..end];
+            self.pos = end;
+            Ok(bytes)
+        }
+
+        fn size(&mut self) -> Result<usize, ZmqOrchError> {
+            let mut bytes = [0; SIZE_LEN];
+            bytes.copy_from_slice(self.take(SIZE_LEN)?);
+            Ok(usize::from_ne_bytes(bytes))
+        }
+
+        fn string(&mut self) -> Result<String, ZmqOrchError> {
+            let len = self.size()?;
+            let bytes = self.take(len)?;
+            String::from_utf8(bytes.to_vec())
+                .map_err(|e| ZmqOrchError::InvalidMessage(format!("invalid UTF-8: {}", e)))
+        }
+    }
+
+    let mut reader = Reader { data, pos: 0 };
+    let pairs = reader.size()?;
+    // Each pair needs at least its two length prefixes
+    if pairs == 0 || pairs > (data.len() - SIZE_LEN) / (2 * SIZE_LEN) {
+        return Err(ZmqOrchError::InvalidMessage(format!(
+            "pair count {} does not match payload",
+            pairs
+        )));
+    }
+    let db = reader.string()?;
+    let table = reader.string()?;
+
+    let mut remaining = pairs - 1;
+    let mut entries = Vec::new();
+    while remaining > 0 {
+        let key = reader.string()?;
+        let count = reader.string()?;
+        remaining -= 1;
+        let count: usize = count
+            .parse()
+            .ok()
+            .filter(|count| *count <= remaining)
+            .ok_or_else(|| {
+                ZmqOrchError::InvalidMessage(format!("bad field count {:?} for {}", count, key))
+            })?;
+        if count == 0 {
+            entries.push(KeyOpFieldsValues::del(key));
+            continue;
+        }
+        let mut fvs = Vec::with_capacity(count);
+        for _ in 0..count {
+            let field = reader.string()?;
+            let value = reader.string()?;
+            fvs.push((field, value));
+        }
+        remaining -= count;
+        entries.push(KeyOpFieldsValues::new(key, Operation::Set, fvs));
+    }
+    if reader.pos != data.len() {
+        return Err(ZmqOrchError::InvalidMessage(format!(
+            "{} trailing bytes",
+            data.len() - reader.pos
+        )));
+    }
+
+    Ok((db, table, entries))
+}
+
+/// Bounded per-table queue between the receiver thread and the daemon.
+struct TableQueue {
+    entries: Mutex<VecDeque<KeyOpFieldsValues>>,
+    not_full: Condvar,
+}
+
+struct Shared {
+    queues: HashMap<String, TableQueue>,
+    capacity: usize,
+    policy: ZmqOverflowPolicy,
+    stop: AtomicBool,
+    messages_received: AtomicU64,
+    messages_dropped: AtomicU64,
+    decode_errors: AtomicU64,
+}
+
+impl Shared {
+    /// Queues an entry, applying the overflow policy. Returns false if the
+    /// consumer is shutting down while blocked on a full queue.
+    fn push(&self, queue: &TableQueue, entry: KeyOpFieldsValues) -> bool {
+        let mut entries = queue.entries.lock().unwrap();
+        while entries.len() >= self.capacity {
+            match self.policy {
+                ZmqOverflowPolicy::DropOldest => {
+                    entries.pop_front();
+                    self.messages_dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                ZmqOverflowPolicy::Block => {
+                    if self.stop.load(Ordering::Relaxed) {
+                        return false;
+                    }
+                    entries = queue
+                        .not_full
+                        .wait_timeout(entries, Duration::from_millis(RECV_TIMEOUT_MS as u64))
+                        .unwrap()
+                        .0;
+                }
+            }
+        }
+        entries.push_back(entry);
+        true
+    }
+}
+
+/// Receives table updates over ZMQ and feeds them into Consumers.
+pub struct ZmqConsumer {
+    endpoint: String,
+    shared: Arc<Shared>,
+    receiver: Option<JoinHandle<()>>,
+}
+
+impl ZmqConsumer {
+    /// Binds the configured endpoint and starts the receiver thread.
+    pub fn bind(context: &zmq::Context, config: ZmqConsumerConfig) -> Result<Self, ZmqOrchError> {
+        let socket = context
+            .socket(zmq::PULL)
+            .and_then(|socket| {
+                socket.set_rcvtimeo(RECV_TIMEOUT_MS)?;
+                socket.bind(&config.endpoint)?;
+                Ok(socket)
+            })
+            .map_err(|e| {
+                let error = ZmqOrchError::ConnectionFailed(format!(
+                    "Failed to bind {}: {}",
+                    config.endpoint, e
+                ));
+                audit_log!(AuditRecord::new(
+                    AuditCategory::AdminAction,
+                    "ZmqOrch",
+                    "bind_consumer"
+                )
+                .with_outcome(AuditOutcome::Failure)
+                .with_object_id(config.endpoint.clone())
+                .with_object_type("zmq_consumer")
+                .with_error(error.to_string()));
+                error
+            })?;
+
+        let shared = Arc::new(Shared {
+            queues: config
+                .tables
+                .iter()
+                .map(|table| {
+                    (
+                        table.clone(),
+                        TableQueue {
+                            entries: Mutex::new(VecDeque::new()),
+                            not_full: Condvar::new(),
+                        },
+                    )
+                })
+                .collect(),
+            capacity: config.queue_capacity.max(1),
+            policy: config.overflow_policy,
+            stop: AtomicBool::new(false),
+            messages_received: AtomicU64::new(0),
+            messages_dropped: AtomicU64::new(0),
+            decode_errors: AtomicU64::new(0),
+        });
+
+        let thread_shared = Arc::clone(&shared);
+        let receiver = std::thread::Builder::new()
+            .name("zmq-consumer".to_string())
+            .spawn(move || Self::receive_loop(socket, thread_shared))
+            .map_err(|e| ZmqOrchError::ConnectionFailed(e.to_string()))?;
+
+        audit_log!(
+            AuditRecord::new(AuditCategory::AdminAction, "ZmqOrch", "bind_consumer")
+                .with_outcome(AuditOutcome::Success)
+                .with_object_id(config.endpoint.clone())
+                .with_object_type("zmq_consumer")
+                .with_details(serde_json::json!({
+                    "tables": config.tables,
+                    "queue_capacity": config.queue_capacity,
+                    "overflow_policy": format!("{:?}", config.overflow_policy),
+                }))
+        );
+
+        Ok(Self {
+            endpoint: config.endpoint,
+            shared,
+            receiver: Some(receiver),
+        })
+    }
+
+    fn receive_loop(socket: zmq::Socket, shared: Arc<Shared>) {
+        while !shared.stop.load(Ordering::Relaxed) {
+            let frame = match socket.recv_bytes(0) {
+                Ok(frame) => frame,
+                Err(zmq::Error::EAGAIN) => continue,
+                Err(e) => {
+                    warn!("ZMQ receive failed: {}", e);
+                    continue;
+                }
+            };
+
+            let (_db, table, entries) = match decode_message(&frame) {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    warn!("Dropping ZMQ message: {}", e);
+                    shared.decode_errors.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+            };
+
+            let Some(queue) = shared.queues.get(&table) else {
+                debug!("Dropping ZMQ message for unregistered table {}", table);
+                shared.decode_errors.fetch_add(1, Ordering::Relaxed);
+                continue;
+            };
+
+            shared.messages_received.fetch_add(1, Ordering::Relaxed);
+            if !entries.into_iter().all(|entry| shared.push(queue, entry)) {
+                break;
+            }
+        }
+    }
+
+    /// Returns the bound endpoint.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the number of queued entries for a table.
+    pub fn pending_count(&self, table: &str) -> usize {
+        self.shared
+            .queues
+            .get(table)
+            .map(|queue| queue.entries.lock().unwrap().len())
+            .unwrap_or(0)
+    }
+
+    /// Removes up to `max` queued entries for a table, oldest first.
+    pub fn pop_batch(&self, table: &str, max: usize) -> Vec<KeyOpFieldsValues> {
+        let Some(queue) = self.shared.queues.get(table) else {
+            return Vec::new();
+        };
+        let mut entries = queue.entries.lock().unwrap();
+        let count = entries.len().min(max);
+        let batch: Vec<_> = entries.drain(..count).collect();
+        drop(entries);
+        if !batch.is_empty() {
+            queue.not_full.notify_all();
+        }
+        batch
+    }
+
+    /// Feeds up to `max` queued entries of the Consumer's table into it.
+    ///
+    /// Returns the number of entries added.
+    pub fn drain_into(&self, consumer: &mut Consumer, max: usize) -> usize {
+        let batch = self.pop_batch(consumer.table_name(), max);
+        let count = batch.len();
+        if count > 0 {
+            consumer.add_to_sync(batch);
+        }
+        count
+    }
+
+    pub fn stats(&self) -> ZmqConsumerStats {
+        ZmqConsumerStats {
+            messages_received: self.shared.messages_received.load(Ordering::Relaxed),
+            messages_dropped: self.shared.messages_dropped.load(Ordering::Relaxed),
+            decode_errors: self.shared.decode_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Drop for ZmqConsumer {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        for queue in self.shared.queues.values() {
+            queue.not_full.notify_all();
+        }
+        if let Some(receiver) = self.receiver.take() {
+            let _ = receiver.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonic_orch_common::ConsumerConfig;
+    use std::time::Instant;
+
+    const DB: &str = "APPL_DB";
+    const TABLE: &str = "DASH_ROUTE_TABLE";
+    const MESSAGE_COUNT: usize = 10_000;
+    const KEY_COUNT: usize = 16;
+
+    fn seq_entry(i: usize) -> KeyOpFieldsValues {
+        KeyOpFieldsValues::set(
+            format!("key{}", i % KEY_COUNT),
+            vec![("seq".to_string(), i.to_string())],
+        )
+    }
+
+    fn spawn_sender(
+        context: &zmq::Context,
+        endpoint: &str,
+        entries: Vec<KeyOpFieldsValues>,
+    ) -> JoinHandle<()> {
+        let socket = context.socket(zmq::PUSH).unwrap();
+        socket.connect(endpoint).unwrap();
+        std::thread::spawn(move || {
+            for entry in &entries {
+                socket
+                    .send(encode_message(DB, TABLE, std::slice::from_ref(entry)), 0)
+                    .unwrap();
+            }
+        })
+    }
+
+    fn collect(consumer: &ZmqConsumer, count: usize) -> Vec<KeyOpFieldsValues> {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        let mut received = Vec::with_capacity(count);
+        while received.len() < count {
+            assert!(Instant::now() < deadline, "timed out waiting for messages");
+            let batch = consumer.pop_batch(TABLE, 128);
+            if batch.is_empty() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            received.extend(batch);
+        }
+        received
+    }
+
+    /// Serializes raw pairs the way swss-common's BinarySerializer does.
+    fn swss_frame(pairs: &[(&str, &str)]) -> Vec<u8> {
+        let mut buf = pairs.len().to_ne_bytes().to_vec();
+        for (key, value) in pairs {
+            for s in [key, value] {
+                buf.extend_from_slice(&s.len().to_ne_bytes());
+                buf.extend_from_slice(s.as_bytes());
+            }
+        }
+        buf
+    }
+
+    #[test]
+    fn test_encode_matches_swss_common_layout() {
+        let entries = vec![
+            KeyOpFieldsValues::set(
+                "Vnet1:10.0.0.0/24",
+                vec![
+                    ("action_type".to_string(), "vnet".to_string()),
+                    ("vnet".to_string(), "Vnet1".to_string()),
+                ],
+            ),
+            KeyOpFieldsValues::del("Vnet1:10.1.0.0/24"),
+        ];
+        let expected = swss_frame(&[
+            (DB, TABLE),
+            ("Vnet1:10.0.0.0/24", "2"),
+            ("action_type", "vnet"),
+            ("vnet", "Vnet1"),
+            ("Vnet1:10.1.0.0/24", "0"),
+        ]);
+        assert_eq!(encode_message(DB, TABLE, &entries), expected);
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let entries = vec![
+            KeyOpFieldsValues::set(
+                "Vnet1:10.0.0.0/24",
+                vec![
+                    ("action_type".to_string(), "vnet".to_string()),
+                    ("vnet".to_string(), "Vnet1".to_string()),
+                ],
+            ),
+            KeyOpFieldsValues::del("Vnet1:10.0.0.0/24"),
+        ];
+        let (db, table, decoded) = decode_message(&encode_message(DB, TABLE, &entries)).unwrap();
+        assert_eq!(db, DB);
+        assert_eq!(table, TABLE);
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].key, entries[0].key);
+        assert_eq!(decoded[0].op, Operation::Set);
+        assert_eq!(decoded[0].fvs, entries[0].fvs);
+        assert_eq!(decoded[1].op, Operation::Del);
+        assert!(decoded[1].fvs.is_empty());
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed() {
+        let encoded = encode_message(DB, TABLE, &[seq_entry(1)]);
+
+        assert!(decode_message(&encoded[..encoded.len() - 1]).is_err());
+        assert!(decode_message(&[encoded.as_slice(), &[0]].concat()).is_err());
+
+        // Field count larger than the pairs that follow
+        let bad_count = swss_frame(&[(DB, TABLE), ("key0", "2"), ("seq", "1")]);
+        assert!(matches!(
+            decode_message(&bad_count),
+            Err(ZmqOrchError::InvalidMessage(_))
+        ));
+        let not_a_count = swss_frame(&[(DB, TABLE), ("key0", "one"), ("seq", "1")]);
+        assert!(decode_message(&not_a_count).is_err());
+    }
+
+    #[test]
+    fn test_bind_invalid_endpoint() {
+        let context = zmq::Context::new();
+        let config = ZmqConsumerConfig::new("bogus://endpoint", vec![TABLE.to_string()]);
+        assert!(matches!(
+            ZmqConsumer::bind(&context, config),
+            Err(ZmqOrchError::ConnectionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_blocking_policy_preserves_order_without_loss() {
+        let context = zmq::Context::new();
+        let endpoint = "inproc://zmq-consumer-block";
+        let mut config = ZmqConsumerConfig::new(endpoint, vec![TABLE.to_string()]);
+        // Small queue so the receiver blocks on backpressure
+        config.queue_capacity = 64;
+        config.overflow_policy = ZmqOverflowPolicy::Block;
+        let consumer = ZmqConsumer::bind(&context, config).unwrap();
+
+        let sender = spawn_sender(
+            &context,
+            endpoint,
+            (0..MESSAGE_COUNT).map(seq_entry).collect(),
+        );
+        let received = collect(&consumer, MESSAGE_COUNT);
+        sender.join().unwrap();
+
+        let seqs: Vec<usize> = received
+            .iter()
+            .map(|entry| entry.get_field("seq").unwrap().parse().unwrap())
+            .collect();
+        assert_eq!(seqs, (0..MESSAGE_COUNT).collect::<Vec<_>>());
+        for entry in &received {
+            let seq: usize = entry.get_field("seq").unwrap().parse().unwrap();
+            assert_eq!(entry.key, format!("key{}", seq % KEY_COUNT));
+        }
+
+        let stats = consumer.stats();
+        assert_eq!(stats.messages_received, MESSAGE_COUNT as u64);
+        assert_eq!(stats.messages_dropped, 0);
+        assert_eq!(stats.decode_errors, 0);
+    }
+
+    #[test]
+    fn test_drop_oldest_policy_keeps_newest() {
+        let context = zmq::Context::new();
+        let endpoint = "inproc://zmq-consumer-drop";
+        let mut config = ZmqConsumerConfig::new(endpoint, vec![TABLE.to_string()]);
+        config.queue_capacity = 10;
+        config.overflow_policy = ZmqOverflowPolicy::DropOldest;
+        let consumer = ZmqConsumer::bind(&context, config).unwrap();
+
+        spawn_sender(&context, endpoint, (0..100).map(seq_entry).collect())
+            .join()
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while consumer.stats().messages_received < 100 {
+            assert!(Instant::now() < deadline, "timed out waiting for messages");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let seqs: Vec<usize> = consumer
+            .pop_batch(TABLE, usize::MAX)
+            .iter()
+            .map(|entry| entry.get_field("seq").unwrap().parse().unwrap())
+            .collect();
+        assert_eq!(seqs, (90..100).collect::<Vec<_>>());
+        assert_eq!(consumer.stats().messages_dropped, 90);
+    }
+
+    #[test]
+    fn test_unregistered_table_counted_as_error() {
+        let context = zmq::Context::new();
+        let endpoint = "inproc://zmq-consumer-unknown";
+        let consumer = ZmqConsumer::bind(
+            &context,
+            ZmqConsumerConfig::new(endpoint, vec!["OTHER_TABLE".to_string()]),
+        )
+        .unwrap();
+
+        spawn_sender(&context, endpoint, vec![seq_entry(0)])
+            .join()
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while consumer.stats().decode_errors == 0 {
+            assert!(Instant::now() < deadline, "timed out waiting for message");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(consumer.pending_count("OTHER_TABLE"), 0);
+        assert_eq!(consumer.stats().messages_received, 0);
+    }
+
+    #[test]
+    fn test_drain_into_coexists_with_redis_entries() {
+        let context = zmq::Context::new();
+        let endpoint = "inproc://zmq-consumer-drain";
+        let zmq_consumer = ZmqConsumer::bind(
+            &context,
+            ZmqConsumerConfig::new(endpoint, vec![TABLE.to_string()]),
+        )
+        .unwrap();
+
+        // Entry already delivered through the Redis path
+        let mut consumer = Consumer::new(ConsumerConfig::new(TABLE));
+        consumer.add_to_sync(vec![KeyOpFieldsValues::set(
+            "key0",
+            vec![("seq".to_string(), "redis".to_string())],
+        )]);
+
+        spawn_sender(
+            &context,
+            endpoint,
+            vec![KeyOpFieldsValues::del("key0"), seq_entry(0), seq_entry(1)],
+        )
+        .join()
+        .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while zmq_consumer.pending_count(TABLE) < 3 {
+            assert!(Instant::now() < deadline, "timed out waiting for messages");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(zmq_consumer.drain_into(&mut consumer, 128), 3);
+        let entries = consumer.drain();
+        let key0: Vec<_> = entries.iter().filter(|e| e.key == "key0").collect();
+        assert_eq!(key0.len(), 2);
+        assert_eq!(key0[0].op, Operation::Del);
+        assert_eq!(key0[1].get_field("seq"), Some("0"));
+        assert!(entries.iter().any(|e| e.key == "key1"));
+    }
+}
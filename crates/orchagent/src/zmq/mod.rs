@@ -6,11 +6,15 @@
 //! - Vec for message payloads instead of raw buffers
 //! - String for topics and endpoints
 //! - Option for optional endpoint configuration
+//! - Bounded per-table queues between the ZMQ receiver and Consumers
 
+mod consumer;
 mod ffi;
 mod orch;
 mod types;
 
+pub use consumer::{decode_message, encode_message, ZmqConsumer, ZmqConsumerConfig};
+
 pub use ffi::{register_zmq_orch, unregister_zmq_orch};
 pub use orch::{ZmqOrch, ZmqOrchCallbacks, ZmqOrchConfig, ZmqOrchError, ZmqOrchStats};
-pub use types::{ZmqEndpoint, ZmqMessage, ZmqStats};
+pub use types::{ZmqConsumerStats, ZmqEndpoint, ZmqMessage, ZmqOverflowPolicy, ZmqStats};
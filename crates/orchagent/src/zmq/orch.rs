@@ -11,6 +11,8 @@ pub enum ZmqOrchError {
     ConnectionFailed(String),
     #[error("Send failed: {0}")]
     SendFailed(String),
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -31,3 +31,20 @@ pub struct ZmqStats {
     pub messages_received: u64,
     pub errors: u64,
 }
+
+/// Behavior when a ZMQ consumer queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZmqOverflowPolicy {
+    /// Stop receiving until the queue drains, pushing back on the sender
+    #[default]
+    Block,
+    /// Discard the oldest queued entry to make room
+    DropOldest,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZmqConsumerStats {
+    pub messages_received: u64,
+    pub messages_dropped: u64,
+    pub decode_errors: u64,
+}
@@ -416,6 +416,7 @@ mod integration_tests {
                     threshold_mode: ThresholdMode::Dynamic,
                     xoff_threshold: None,
                     xon_threshold: None,
+                    size_percent: None,
                 },
                 sai_oid: 0,
                 ref_count: 0,
@@ -1401,6 +1402,7 @@ mod integration_tests {
                 vxlan_tunnel: Some("tunnel0".to_string()),
                 scope: None,
                 advertise_prefix: false,
+                peer_list: vec![],
             };
 
             let mut vnet = VnetEntry::new(config);
@@ -1798,6 +1800,7 @@ mod integration_tests {
 
             async fn sai_create_nhg(
                 &self,
+                _vrf_id: u64,
                 _nhg_key: &NextHopGroupKey,
             ) -> Result<u64, sonic_orchagent::route::RouteError> {
                 let oid = self
@@ -3620,7 +3623,8 @@ mod integration_tests {
     mod sflow_orch_tests {
         use super::*;
         use sonic_orchagent::sflow::{
-            SampleDirection, SflowConfig, SflowOrch, SflowOrchCallbacks, SflowOrchConfig,
+            SampleDirection, SflowCapability, SflowConfig, SflowOrch, SflowOrchCallbacks,
+            SflowOrchConfig,
         };
         use std::num::NonZeroU32;
 
@@ -3687,6 +3691,20 @@ mod integration_tests {
             fn all_ports_ready(&self) -> bool {
                 self.ports_ready
             }
+
+            fn query_capability(&self) -> Result<SflowCapability, String> {
+                Ok(SflowCapability::default())
+            }
+
+            fn write_state_db(
+                &self,
+                _alias: &str,
+                _configured: SampleDirection,
+                _programmed: SampleDirection,
+            ) {
+            }
+
+            fn remove_state_db(&self, _alias: &str) {}
         }
 
         /// Helper function to create a sflow session configuration
@@ -4094,6 +4112,15 @@ mod integration_tests {
                 self.track_operation(format!("set_bulk_chunk_size:{}:{:?}", group, size));
                 Ok(())
             }
+
+            async fn add_counter_id_lists(
+                &self,
+                group: &str,
+                lists: &[(String, Vec<String>)],
+            ) -> Result<(), FlexCounterError> {
+                self.track_operation(format!("add_counter_id_lists:{}:{}", group, lists.len()));
+                Ok(())
+            }
         }
 
         fn create_flex_counter_entry(
@@ -5124,7 +5151,7 @@ mod integration_tests {
         use super::*;
         use sonic_orchagent::twamp::{
             TwampMode, TwampOrch, TwampOrchCallbacks, TwampOrchConfig, TwampRole,
-            TwampSessionConfig,
+            TwampSessionConfig, TwampSessionStatus, TwampStats,
         };
         use sonic_types::IpAddress;
         use std::str::FromStr;
@@ -5184,6 +5211,12 @@ mod integration_tests {
             fn set_session_transmit(&self, _session_id: u64, _enabled: bool) -> Result<(), String> {
                 Ok(())
             }
+
+            fn write_session_state(&self, _name: &str, _status: TwampSessionStatus) {}
+
+            fn write_session_stats(&self, _name: &str, _stats: &TwampStats) {}
+
+            fn remove_session_state(&self, _name: &str) {}
         }
 
         /// Helper to create a TWAMP session with SAI integration
@@ -5931,8 +5964,11 @@ mod integration_tests {
             sai: Arc<MockSai>,
             created_nexthops: Mutex<Vec<NextHopKey>>,
             removed_nexthops: Mutex<Vec<u64>>,
+            nhg_ids: Mutex<Vec<u64>>,
             created_nhgs: Mutex<Vec<Vec<NextHopGroupMember>>>,
             removed_nhgs: Mutex<Vec<u64>>,
+            group_members: Mutex<Vec<u64>>,
+            next_gm_id: Mutex<u64>,
         }
 
         impl MockNhgCallbacks {
@@ -5941,8 +5977,11 @@ mod integration_tests {
                     sai,
                     created_nexthops: Mutex::new(Vec::new()),
                     removed_nexthops: Mutex::new(Vec::new()),
+                    nhg_ids: Mutex::new(Vec::new()),
                     created_nhgs: Mutex::new(Vec::new()),
                     removed_nhgs: Mutex::new(Vec::new()),
+                    group_members: Mutex::new(Vec::new()),
+                    next_gm_id: Mutex::new(0x2d000000000001),
                 }
             }
         }
@@ -5967,13 +6006,13 @@ mod integration_tests {
                 self.sai.remove_object(nh_id)
             }
 
-            fn create_next_hop_group(&self, members: &[NextHopGroupMember]) -> Result<u64, String> {
-                let oid = self.sai.create_object(
-                    SaiObjectType::NextHopGroup,
-                    vec![("member_count".to_string(), members.len().to_string())],
-                )?;
+            fn create_next_hop_group(&self) -> Result<u64, String> {
+                let oid = self
+                    .sai
+                    .create_object(SaiObjectType::NextHopGroup, Vec::new())?;
 
-                self.created_nhgs.lock().unwrap().push(members.to_vec());
+                self.nhg_ids.lock().unwrap().push(oid);
+                self.created_nhgs.lock().unwrap().push(Vec::new());
                 Ok(oid)
             }
 
@@ -5981,6 +6020,49 @@ mod integration_tests {
                 self.removed_nhgs.lock().unwrap().push(nhg_id);
                 self.sai.remove_object(nhg_id)
             }
+
+            fn create_next_hop_group_member(
+                &self,
+                nhg_id: u64,
+                member: &NextHopGroupMember,
+            ) -> Result<u64, String> {
+                let group = self
+                    .nhg_ids
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .position(|id| *id == nhg_id)
+                    .ok_or_else(|| format!("NHG {:#x} not found", nhg_id))?;
+                self.created_nhgs.lock().unwrap()[group].push(member.clone());
+
+                let mut next = self.next_gm_id.lock().unwrap();
+                let gm_id = *next;
+                *next += 1;
+                self.group_members.lock().unwrap().push(gm_id);
+                Ok(gm_id)
+            }
+
+            fn remove_next_hop_group_member(&self, gm_id: u64) -> Result<(), String> {
+                let mut members = self.group_members.lock().unwrap();
+                let pos = members
+                    .iter()
+                    .position(|id| *id == gm_id)
+                    .ok_or_else(|| format!("Member {:#x} not found", gm_id))?;
+                members.remove(pos);
+                Ok(())
+            }
+
+            fn set_next_hop_group_member_weight(
+                &self,
+                gm_id: u64,
+                _weight: u32,
+            ) -> Result<(), String> {
+                if self.group_members.lock().unwrap().contains(&gm_id) {
+                    Ok(())
+                } else {
+                    Err(format!("Member {:#x} not found", gm_id))
+                }
+            }
         }
 
         /// Helper to create a basic next-hop group member
@@ -6071,13 +6153,8 @@ mod integration_tests {
             assert_eq!(sai_objs.len(), 1);
             assert_eq!(sai_objs[0].object_type, SaiObjectType::NextHopGroup);
 
-            // Verify attributes contain member count
-            let member_count_attr = sai_objs[0]
-                .attributes
-                .iter()
-                .find(|(k, _)| k == "member_count")
-                .map(|(_, v)| v.clone());
-            assert_eq!(member_count_attr, Some("4".to_string()));
+            // Verify each member was created once, separately from the group
+            assert_eq!(callbacks.group_members.lock().unwrap().len(), 4);
         }
 
         #[test]
@@ -6469,6 +6546,10 @@ mod crm_orch_tests {
             None
         }
 
+        fn query_dash_acl_rule_availability(&self, _group_id: u64) -> Option<u32> {
+            None
+        }
+
         fn write_counters(&self, resource: &str, key: &str, used: u32, available: u32) {
             self.counter_writes.lock().unwrap().push(CounterWrite {
                 resource: resource.to_string(),
@@ -7248,6 +7329,42 @@ mod mlag_orch_tests {
         fn all_ports_ready(&self) -> bool {
             self.ports_ready
         }
+
+        fn supports_isolation_group(&self) -> bool {
+            true
+        }
+
+        fn create_isolation_group(&self, _name: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn remove_isolation_group(&self, _name: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn add_isolation_group_member(&self, _name: &str, _port: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn remove_isolation_group_member(&self, _name: &str, _port: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn bind_isolation_group(&self, _name: &str, _port: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn unbind_isolation_group(&self, _name: &str, _port: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn set_isl_acl_rule(&self, _isl_name: &str, _members: &[String]) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn remove_isl_acl_rule(&self, _isl_name: &str) -> Result<(), String> {
+            Ok(())
+        }
     }
 
     /// Helper function to create MLAG domain with ISL
@@ -8394,7 +8511,7 @@ mod pfcwd_orch_tests {
     use super::*;
     use sonic_orchagent::pfcwd::{
         DetectionTime, PfcWdAction, PfcWdConfig, PfcWdOrch, PfcWdOrchCallbacks, PfcWdOrchConfig,
-        RestorationTime,
+        PfcWdQueueSample, RestorationTime,
     };
     use std::sync::{Arc, Mutex};
 
@@ -8449,6 +8566,34 @@ mod pfcwd_orch_tests {
             self.stopped_watchdogs.lock().unwrap().push(wd_id);
             Ok(())
         }
+
+        fn read_queue_sample(&self, _queue_name: &str) -> Option<PfcWdQueueSample> {
+            None
+        }
+
+        fn install_zero_buffer_profile(&self, _queue_name: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn remove_zero_buffer_profile(&self, _queue_name: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn install_action_acl(
+            &self,
+            _queue_name: &str,
+            _action: PfcWdAction,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn remove_action_acl(&self, _queue_name: &str, _action: PfcWdAction) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn write_counters(&self, _queue_name: &str, _fields: &[(String, String)]) {}
+
+        fn remove_counters(&self, _queue_name: &str) {}
     }
 
     /// Helper function to create a PFC watchdog configuration
@@ -8750,7 +8895,7 @@ mod tunnel_decap_orch_tests {
     use super::*;
     use sonic_orchagent::tunnel_decap::{
         TunnelDecapConfig, TunnelDecapOrch, TunnelDecapOrchCallbacks, TunnelDecapOrchConfig,
-        TunnelTermType,
+        TunnelDecapTermConfig, TunnelTermType,
     };
     use sonic_sai::types::RawSaiObjectId;
     use sonic_types::IpAddress;
@@ -8803,6 +8948,26 @@ mod tunnel_decap_orch_tests {
         fn remove_tunnel_term_entry(&self, term_entry_id: RawSaiObjectId) -> Result<(), String> {
             self.sai.remove_object(term_entry_id)
         }
+
+        fn create_decap_term_entry(
+            &self,
+            tunnel_id: RawSaiObjectId,
+            config: &TunnelDecapTermConfig,
+            priority: u32,
+        ) -> Result<RawSaiObjectId, String> {
+            self.sai.create_object(
+                SaiObjectType::TunnelTermEntry,
+                vec![
+                    ("tunnel_id".to_string(), tunnel_id.to_string()),
+                    (
+                        "term_type".to_string(),
+                        config.term_type.as_str().to_string(),
+                    ),
+                    ("dst_ip".to_string(), config.dst.to_string()),
+                    ("priority".to_string(), priority.to_string()),
+                ],
+            )
+        }
     }
 
     /// Helper function to create a tunnel decap entry with SAI synchronization
@@ -9202,6 +9367,13 @@ mod nvgre_orch_tests {
             self.sai.remove_object(oid)
         }
 
+        fn set_tunnel_map_entry_vlan(&self, oid: u64, _vlan_id: u16) -> Result<(), String> {
+            self.sai
+                .get_object(oid)
+                .map(|_| ())
+                .ok_or_else(|| format!("Object {} not found", oid))
+        }
+
         fn get_vlan_oid(&self, vlan_id: u16) -> Option<u64> {
             self.vlans.lock().unwrap().get(&vlan_id).copied()
         }
@@ -9988,6 +10160,7 @@ mod isolation_group_orch_tests {
             SwitchCapabilities, SwitchHashConfig, SwitchOrch, SwitchOrchCallbacks,
             SwitchOrchConfig, SwitchState,
         };
+        use sonic_sai::api::NativeHashField;
 
         type Result<T> = std::result::Result<T, sonic_orchagent::switch::SwitchOrchError>;
 
@@ -9999,6 +10172,15 @@ mod isolation_group_orch_tests {
             fn set_hash_algorithm(&self, _is_ecmp: bool, _config: &SwitchHashConfig) -> Result<()> {
                 Ok(())
             }
+            fn create_hash(&self, _is_ecmp: bool, _fields: &[NativeHashField]) -> Result<u64> {
+                Ok(0)
+            }
+            fn set_hash_fields(&self, _hash_oid: u64, _fields: &[NativeHashField]) -> Result<()> {
+                Ok(())
+            }
+            fn set_hash_seed(&self, _is_ecmp: bool, _seed: u32) -> Result<()> {
+                Ok(())
+            }
             fn get_capabilities(&self) -> Result<SwitchCapabilities> {
                 Ok(SwitchCapabilities::default())
             }
@@ -10685,7 +10867,8 @@ mod isolation_group_orch_tests {
         use super::*;
         use sonic_orchagent::chassis::{
             ChassisOrch, ChassisOrchCallbacks, ChassisOrchConfig, ChassisOrchStats, FabricPortKey,
-            RawSaiObjectId, Result, SystemPortConfig, SystemPortEntry, SystemPortKey,
+            RawSaiObjectId, Result, SystemNeighEntry, SystemNeighKey, SystemPortConfig,
+            SystemPortEntry, SystemPortKey,
         };
 
         /// Mock callbacks for testing.
@@ -10724,6 +10907,52 @@ mod isolation_group_orch_tests {
             fn on_system_port_created(&self, _entry: &SystemPortEntry) {}
             fn on_system_port_removed(&self, _key: &SystemPortKey) {}
             fn on_fabric_port_isolate_changed(&self, _key: &FabricPortKey, _isolate: bool) {}
+            fn create_system_lag(
+                &self,
+                _name: &str,
+                _system_lag_id: u32,
+            ) -> Result<RawSaiObjectId> {
+                Ok(0)
+            }
+            fn remove_system_lag(&self, _oid: RawSaiObjectId) -> Result<()> {
+                Ok(())
+            }
+            fn add_system_lag_member(
+                &self,
+                _lag_oid: RawSaiObjectId,
+                _system_port_oid: RawSaiObjectId,
+            ) -> Result<RawSaiObjectId> {
+                Ok(0)
+            }
+            fn remove_system_lag_member(&self, _member_oid: RawSaiObjectId) -> Result<()> {
+                Ok(())
+            }
+            fn create_remote_neighbor(
+                &self,
+                _interface_oid: RawSaiObjectId,
+                _neigh: &SystemNeighEntry,
+            ) -> Result<()> {
+                Ok(())
+            }
+            fn remove_remote_neighbor(
+                &self,
+                _interface_oid: RawSaiObjectId,
+                _key: &SystemNeighKey,
+            ) -> Result<()> {
+                Ok(())
+            }
+            fn publish_system_neigh(&self, _neigh: &SystemNeighEntry) -> Result<()> {
+                Ok(())
+            }
+            fn unpublish_system_neigh(&self, _key: &SystemNeighKey) -> Result<()> {
+                Ok(())
+            }
+            fn write_encap_index(&self, _key: &SystemNeighKey, _encap_index: u32) -> Result<()> {
+                Ok(())
+            }
+            fn remove_encap_index(&self, _key: &SystemNeighKey) -> Result<()> {
+                Ok(())
+            }
         }
 
         /// Test system port configuration and initialization
@@ -11024,8 +11253,8 @@ mod isolation_group_orch_tests {
     mod copp_orch_tests {
         use super::*;
         use sonic_orchagent::copp::{
-            CoppOrch, CoppOrchCallbacks, CoppOrchConfig, CoppTrapAction, CoppTrapConfig,
-            CoppTrapEntry, CoppTrapKey,
+            CoppOrch, CoppOrchCallbacks, CoppOrchConfig, CoppPolicerConfig, CoppTrapAction,
+            CoppTrapConfig, CoppTrapEntry, CoppTrapGroupConfig, CoppTrapKey,
         };
 
         struct MockCoppCallbacks {
@@ -11075,6 +11304,79 @@ mod isolation_group_orch_tests {
 
             fn on_trap_created(&self, _key: &CoppTrapKey, _trap_id: u64) {}
             fn on_trap_removed(&self, _key: &CoppTrapKey) {}
+
+            fn create_trap_group(
+                &self,
+                _name: &str,
+                _config: &CoppTrapGroupConfig,
+            ) -> CoppResult<u64> {
+                Ok(0x2000)
+            }
+
+            fn update_trap_group(
+                &self,
+                _group_id: u64,
+                _config: &CoppTrapGroupConfig,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn remove_trap_group(&self, _group_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn set_trap_group(&self, _trap_id: u64, _group_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn update_trap_action(
+                &self,
+                _trap_id: u64,
+                _action: CoppTrapAction,
+                _priority: Option<u32>,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_policer(&self, _config: &CoppPolicerConfig) -> CoppResult<u64> {
+                Ok(0x3000)
+            }
+
+            fn update_policer(
+                &self,
+                _policer_id: u64,
+                _config: &CoppPolicerConfig,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn remove_policer(&self, _policer_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn set_trap_group_policer(&self, _group_id: u64, _policer_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_genetlink_hostif(
+                &self,
+                _name: &str,
+                _mcgrp_name: Option<&str>,
+            ) -> CoppResult<u64> {
+                Ok(0x4000)
+            }
+
+            fn remove_genetlink_hostif(&self, _hostif_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_hostif_table_entry(&self, _trap_id: u64, _hostif_id: u64) -> CoppResult<u64> {
+                Ok(0x5000)
+            }
+
+            fn remove_hostif_table_entry(&self, _entry_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
         }
 
         struct DummyCoppCallbacks;
@@ -11098,6 +11400,79 @@ mod isolation_group_orch_tests {
 
             fn on_trap_created(&self, _key: &CoppTrapKey, _trap_id: u64) {}
             fn on_trap_removed(&self, _key: &CoppTrapKey) {}
+
+            fn create_trap_group(
+                &self,
+                _name: &str,
+                _config: &CoppTrapGroupConfig,
+            ) -> CoppResult<u64> {
+                Ok(0x2000)
+            }
+
+            fn update_trap_group(
+                &self,
+                _group_id: u64,
+                _config: &CoppTrapGroupConfig,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn remove_trap_group(&self, _group_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn set_trap_group(&self, _trap_id: u64, _group_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn update_trap_action(
+                &self,
+                _trap_id: u64,
+                _action: CoppTrapAction,
+                _priority: Option<u32>,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_policer(&self, _config: &CoppPolicerConfig) -> CoppResult<u64> {
+                Ok(0x3000)
+            }
+
+            fn update_policer(
+                &self,
+                _policer_id: u64,
+                _config: &CoppPolicerConfig,
+            ) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn remove_policer(&self, _policer_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn set_trap_group_policer(&self, _group_id: u64, _policer_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_genetlink_hostif(
+                &self,
+                _name: &str,
+                _mcgrp_name: Option<&str>,
+            ) -> CoppResult<u64> {
+                Ok(0x4000)
+            }
+
+            fn remove_genetlink_hostif(&self, _hostif_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
+
+            fn create_hostif_table_entry(&self, _trap_id: u64, _hostif_id: u64) -> CoppResult<u64> {
+                Ok(0x5000)
+            }
+
+            fn remove_hostif_table_entry(&self, _entry_id: u64) -> CoppResult<()> {
+                Ok(())
+            }
         }
 
         fn create_trap_config(
@@ -11398,7 +11773,8 @@ mod isolation_group_orch_tests {
         use super::*;
         use sonic_orchagent::icmp::{
             IcmpEchoEntry, IcmpEchoKey, IcmpMode, IcmpOrch, IcmpOrchCallbacks, IcmpOrchConfig,
-            IcmpRedirectConfig, IcmpStats, NeighborDiscoveryConfig,
+            IcmpRedirectConfig, IcmpSessionConfig, IcmpSessionKey, IcmpSessionState, IcmpStats,
+            NeighborDiscoveryConfig,
         };
         use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
@@ -11433,6 +11809,30 @@ mod isolation_group_orch_tests {
 
             fn on_redirect_processed(&self, _src_ip: &str) {}
             fn on_neighbor_discovery_complete(&self, _neighbor_ip: &str) {}
+
+            fn create_echo_session(
+                &self,
+                _key: &IcmpSessionKey,
+                _config: &IcmpSessionConfig,
+            ) -> IcmpResult<u64> {
+                Ok(0)
+            }
+
+            fn remove_echo_session(&self, _sai_oid: u64) -> IcmpResult<()> {
+                Ok(())
+            }
+
+            fn set_echo_session_intervals(
+                &self,
+                _sai_oid: u64,
+                _tx_interval_ms: u32,
+                _rx_timeout_ms: u32,
+            ) -> IcmpResult<()> {
+                Ok(())
+            }
+
+            fn write_session_state(&self, _key: &IcmpSessionKey, _state: IcmpSessionState) {}
+            fn remove_session_state(&self, _key: &IcmpSessionKey) {}
         }
 
         fn create_icmp_echo_session_with_sai(
@@ -13137,10 +13537,11 @@ mod isolation_group_orch_tests {
     mod mirror_orch_tests {
         use super::*;
         use sonic_orchagent::mirror::{
-            MirrorDirection, MirrorOrch, MirrorOrchCallbacks, MirrorOrchConfig,
-            MirrorSessionConfig, MirrorSessionType,
+            MirrorDirection, MirrorNeighbor, MirrorOrch, MirrorOrchCallbacks, MirrorOrchConfig,
+            MirrorSessionConfig, MirrorSessionStatus, MirrorSessionType,
         };
         use sonic_sai::types::RawSaiObjectId;
+        use sonic_types::IpAddress;
 
         type Result<T> = std::result::Result<T, sonic_orchagent::mirror::MirrorOrchError>;
 
@@ -13192,6 +13593,60 @@ mod isolation_group_orch_tests {
             }
             fn on_session_created(&self, _name: &str, _session_id: RawSaiObjectId) {}
             fn on_session_removed(&self, _name: &str) {}
+            fn create_erspan_session(
+                &self,
+                _config: &MirrorSessionConfig,
+                neighbor: &MirrorNeighbor,
+            ) -> Result<RawSaiObjectId> {
+                self.sai
+                    .create_object(
+                        SaiObjectType::MirrorSession,
+                        vec![
+                            ("type".to_string(), "ERSPAN".to_string()),
+                            ("monitor_port".to_string(), neighbor.port.clone()),
+                            ("dst_mac".to_string(), neighbor.mac.to_string()),
+                        ],
+                    )
+                    .map_err(|e| sonic_orchagent::mirror::MirrorOrchError::SaiError(e))
+            }
+            fn set_erspan_neighbor(
+                &self,
+                _session_id: RawSaiObjectId,
+                _neighbor: &MirrorNeighbor,
+            ) -> Result<()> {
+                Ok(())
+            }
+            fn register_route_observer(
+                &self,
+                _name: &str,
+                _dst_ip: &IpAddress,
+            ) -> Option<IpAddress> {
+                None
+            }
+            fn unregister_route_observer(&self, _name: &str, _dst_ip: &IpAddress) {}
+            fn register_neighbor_observer(
+                &self,
+                _name: &str,
+                _next_hop: &IpAddress,
+            ) -> Option<MirrorNeighbor> {
+                None
+            }
+            fn unregister_neighbor_observer(&self, _name: &str, _next_hop: &IpAddress) {}
+            fn write_session_state(
+                &self,
+                _name: &str,
+                _status: MirrorSessionStatus,
+                _neighbor: Option<&MirrorNeighbor>,
+            ) {
+            }
+            fn remove_session_state(&self, _name: &str) {}
+            fn retarget_acl_rules(
+                &self,
+                _name: &str,
+                _session_id: Option<RawSaiObjectId>,
+            ) -> Result<()> {
+                Ok(())
+            }
         }
 
         #[test]
@@ -13308,8 +13763,8 @@ mod isolation_group_orch_tests {
     mod fdb_orch_tests {
         use super::*;
         use sonic_orchagent::fdb::{
-            FdbEntry, FdbEntryType, FdbKey, FdbOrch, FdbOrchCallbacks, FdbOrchConfig, FdbOrigin,
-            MacAddress,
+            FdbEntry, FdbEntryType, FdbFlushRequest, FdbKey, FdbOrch, FdbOrchCallbacks,
+            FdbOrchConfig, FdbOrigin, MacAddress,
         };
 
         type Result<T> = std::result::Result<T, sonic_orchagent::fdb::FdbOrchError>;
@@ -13363,6 +13818,9 @@ mod isolation_group_orch_tests {
             fn flush_entries_by_vlan(&self, _vlan: Option<u16>) -> Result<u32> {
                 Ok(0)
             }
+            fn flush_fdb_entries(&self, _request: &FdbFlushRequest) -> Result<()> {
+                Ok(())
+            }
             fn on_fdb_entry_added(&self, _entry: &FdbEntry) {}
             fn on_fdb_entry_removed(&self, _key: &FdbKey) {}
             fn on_fdb_flush(&self, _port: Option<&str>, _vlan: Option<u16>, _count: u32) {}
@@ -13378,6 +13836,7 @@ mod isolation_group_orch_tests {
                 remote_ip: None,
                 esi: None,
                 vni: None,
+                physical_bridge_port_oid: None,
             }
         }
 
@@ -13480,3 +13939,161 @@ mod isolation_group_orch_tests {
         }
     }
 }
+
+// OrchDaemon warm restart integration tests
+mod warm_restart_tests {
+    use async_trait::async_trait;
+    use sonic_orchagent::daemon::{
+        OrchDaemon, OrchDaemonConfig, WarmRestartState, WarmRestartStatusWriter,
+    };
+    use sonic_orchagent::{KeyOpFieldsValues, Operation, Orch};
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::{Arc, Mutex};
+
+    const TABLE: &str = "FAKE_TABLE";
+
+    /// Fake Orch that restores saved state on bake and pushes only the
+    /// difference between the restored state and replayed intent.
+    struct FakeOrch {
+        saved: BTreeMap<String, String>,
+        restored: BTreeMap<String, String>,
+        intent: BTreeMap<String, String>,
+        baked: bool,
+        pushed: Arc<Mutex<Vec<(String, Operation)>>>,
+    }
+
+    impl FakeOrch {
+        fn new(saved: &[(&str, &str)], pushed: Arc<Mutex<Vec<(String, Operation)>>>) -> Self {
+            Self {
+                saved: saved
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                restored: BTreeMap::new(),
+                intent: BTreeMap::new(),
+                baked: false,
+                pushed,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Orch for FakeOrch {
+        fn name(&self) -> &str {
+            "FakeOrch"
+        }
+
+        async fn do_task(&mut self) {}
+
+        fn bake(&mut self) -> bool {
+            self.restored = self.saved.clone();
+            self.baked = true;
+            true
+        }
+
+        fn warm_restart_tables(&self) -> Vec<String> {
+            vec![TABLE.to_string()]
+        }
+
+        fn replay_tasks(&mut self, table: &str, tasks: Vec<KeyOpFieldsValues>) {
+            assert_eq!(table, TABLE);
+            assert!(self.baked, "replay before bake");
+            for task in tasks {
+                if task.op == Operation::Set {
+                    let value = task
+                        .fvs
+                        .iter()
+                        .find(|(f, _)| f == "value")
+                        .map(|(_, v)| v.clone())
+                        .unwrap_or_default();
+                    self.intent.insert(task.key, value);
+                }
+            }
+        }
+
+        async fn reconcile(&mut self) -> bool {
+            let mut pushed = self.pushed.lock().unwrap();
+            for (key, value) in &self.intent {
+                if self.restored.get(key) != Some(value) {
+                    pushed.push((key.clone(), Operation::Set));
+                }
+            }
+            for key in self.restored.keys() {
+                if !self.intent.contains_key(key) {
+                    pushed.push((key.clone(), Operation::Del));
+                }
+            }
+            self.restored = self.intent.clone();
+            true
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingWriter {
+        states: Mutex<Vec<(String, WarmRestartState)>>,
+    }
+
+    #[async_trait]
+    impl WarmRestartStatusWriter for RecordingWriter {
+        async fn write_state(&self, orch_name: &str, state: WarmRestartState) {
+            self.states
+                .lock()
+                .unwrap()
+                .push((orch_name.to_string(), state));
+        }
+    }
+
+    fn set(key: &str, value: &str) -> KeyOpFieldsValues {
+        KeyOpFieldsValues::new(
+            key,
+            Operation::Set,
+            vec![("value".to_string(), value.to_string())],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_warm_restart_pushes_only_diff() {
+        let pushed = Arc::new(Mutex::new(Vec::new()));
+        let writer = Arc::new(RecordingWriter::default());
+
+        let config = OrchDaemonConfig {
+            warm_boot: true,
+            ..OrchDaemonConfig::default()
+        };
+        let mut daemon = OrchDaemon::new(config);
+        daemon.set_warm_restart_writer(writer.clone());
+        daemon.register_orch(Box::new(FakeOrch::new(
+            &[("A", "1"), ("B", "2"), ("C", "3")],
+            pushed.clone(),
+        )));
+
+        let mut appl_state = HashMap::new();
+        appl_state.insert(
+            TABLE.to_string(),
+            vec![set("A", "1"), set("B", "20"), set("D", "4")],
+        );
+
+        assert!(daemon.reconcile_warm_restart(appl_state).await);
+        assert!(!daemon.is_frozen());
+
+        let pushed = pushed.lock().unwrap();
+        assert_eq!(
+            *pushed,
+            vec![
+                ("B".to_string(), Operation::Set),
+                ("D".to_string(), Operation::Set),
+                ("C".to_string(), Operation::Del),
+            ]
+        );
+
+        let states = writer.states.lock().unwrap();
+        assert_eq!(
+            *states,
+            vec![
+                ("FakeOrch".to_string(), WarmRestartState::Initialized),
+                ("FakeOrch".to_string(), WarmRestartState::Restored),
+                ("FakeOrch".to_string(), WarmRestartState::Reconciled),
+            ]
+        );
+    }
+}
@@ -5,8 +5,9 @@
 //!
 //! # Responsibilities
 //!
-//! - Set port MTU via `ip link set dev <port> mtu <mtu>`
-//! - Set port admin status via `ip link set dev <port> up|down`
+//! - Set port MTU and admin status through a
+//!   [`CfgBackend`](sonic_cfgmgr_common::CfgBackend) (`ip link set` or
+//!   rtnetlink, chosen by `CFGMGR_BACKEND`)
 //! - Propagate configuration to APPL_DB for orchagent
 //! - Handle SendToIngress port configuration
 //!
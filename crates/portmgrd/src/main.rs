@@ -8,11 +8,11 @@ use std::process::ExitCode;
 use tracing::{error, info, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use sonic_cfgmgr_common::{CfgMgr, Orch};
+use sonic_cfgmgr_common::{BackendKind, CfgMgr, CfgMgrRunner};
 use sonic_portmgrd::PortMgr;
 
-/// Select timeout in milliseconds (matches C++ SELECT_TIMEOUT).
-const SELECT_TIMEOUT_MS: u64 = 1000;
+/// Redis server holding CONFIG_DB, APPL_DB and STATE_DB.
+const DEFAULT_REDIS_URL: &str = "redis://127.0.0.1:6379";
 
 /// Initialize tracing/logging.
 fn init_logging() {
@@ -27,29 +27,14 @@ fn init_logging() {
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set tracing subscriber");
 }
 
-/// Main event loop (placeholder for real Redis integration).
-async fn run_event_loop(mut mgr: PortMgr) -> Result<(), Box<dyn std::error::Error>> {
-    info!("Starting event loop with {}ms timeout", SELECT_TIMEOUT_MS);
+/// Runs the port manager until SIGTERM.
+async fn run_event_loop(mgr: PortMgr) -> Result<(), Box<dyn std::error::Error>> {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| DEFAULT_REDIS_URL.to_string());
 
-    // In the real implementation, this would:
-    // 1. Create Redis connections to CONFIG_DB, APPL_DB, STATE_DB
-    // 2. Subscribe to CONFIG_DB tables (PORT, SEND_TO_INGRESS_PORT)
-    // 3. Subscribe to STATE_DB (PORT_TABLE) for port readiness
-    // 4. Run Select loop with timeout
+    let runner = CfgMgrRunner::new(mgr).with_redis(&redis_url).await?;
+    let mgr = runner.run().await?;
 
-    // For now, just demonstrate the structure
-    // Simulate select timeout
-    tokio::time::sleep(tokio::time::Duration::from_millis(SELECT_TIMEOUT_MS)).await;
-
-    // Process any pending tasks
-    mgr.do_task().await;
-
-    // In real implementation, would check for shutdown signal
-    // For now, just run once for testing
-    info!(
-        "Event loop iteration complete, {} pending tasks",
-        mgr.pending_count()
-    );
+    info!("Event loop stopped, {} pending tasks", mgr.pending_count());
 
     Ok(())
 }
@@ -63,8 +48,23 @@ async fn main() -> ExitCode {
     // Check for warm restart (would read from command line or environment)
     let warm_restart = std::env::var("WARM_RESTART").is_ok();
 
+    let backend_kind = BackendKind::from_env();
+    let backend = match backend_kind.create() {
+        Ok(backend) => backend,
+        Err(e) => {
+            error!("Failed to create {} backend: {}", backend_kind, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    info!(
+        "Applying kernel changes through the {} backend",
+        backend.name()
+    );
+
     // Create the port manager
-    let mgr = PortMgr::new().with_warm_restart(warm_restart);
+    let mgr = PortMgr::new()
+        .with_warm_restart(warm_restart)
+        .with_backend(backend);
 
     if warm_restart {
         info!("Warm restart mode enabled");
@@ -1,12 +1,15 @@
 //! PortMgr implementation - the core port configuration manager.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 
 use async_trait::async_trait;
-use tracing::{debug, info, instrument, warn};
+use tracing::{debug, error, info, instrument, warn};
 
+use sonic_cfgmgr_common::shell::{Executor, ShellExecutor};
 use sonic_cfgmgr_common::{
-    defaults, shell, CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, Orch, WarmRestartState,
+    defaults, shell, CfgBackend, CfgMgr, CfgMgrResult, DbId, FieldValues, FieldValuesExt,
+    KeyOpFieldsValues, Orch, ShellBackend, WarmRestartState, WarmRestartStore,
 };
 
 use crate::tables::{self, fields};
@@ -35,7 +38,7 @@ pub struct PendingTask {
 ///
 /// Manages port MTU and admin status configuration by:
 /// 1. Reading configuration from CONFIG_DB
-/// 2. Executing `ip link` commands to configure the network stack
+/// 2. Applying MTU and admin status to the kernel through a [`CfgBackend`]
 /// 3. Writing configuration to APPL_DB for orchagent
 pub struct PortMgr {
     /// Daemon name for logging and warm restart.
@@ -53,6 +56,30 @@ pub struct PortMgr {
     /// Pending tasks to retry (port not ready yet).
     pending_tasks: HashMap<String, PendingTask>,
 
+    /// CONFIG_DB entries queued for the next `do_task`, with their table.
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
+    /// Ports whose STATE_DB PORT_TABLE entry has `state` set to `ok`.
+    ready_ports: HashSet<String>,
+
+    /// LAG membership from PORTCHANNEL_MEMBER (port -> port channel).
+    lag_members: HashMap<String, String>,
+
+    /// Last configured MTU of each port, reapplied when it leaves a LAG.
+    port_mtu: HashMap<String, String>,
+
+    /// DHCP rate limits installed as tc ingress filters (port -> pps).
+    dhcp_rate_limits: HashMap<String, u32>,
+
+    /// Store the APPL_DB entries are published to, if attached.
+    store: Option<Arc<dyn WarmRestartStore>>,
+
+    /// Applies MTU and admin status changes to the kernel.
+    backend: Arc<dyn CfgBackend>,
+
+    /// Runs the `tc` commands for DHCP rate limiting.
+    executor: Arc<dyn Executor>,
+
     /// Mock mode for testing (don't execute shell commands).
     #[cfg(test)]
     mock_mode: bool,
@@ -82,6 +109,14 @@ impl PortMgr {
             warm_restart_state: WarmRestartState::Disabled,
             port_list: HashSet::new(),
             pending_tasks: HashMap::new(),
+            tasks: VecDeque::new(),
+            ready_ports: HashSet::new(),
+            lag_members: HashMap::new(),
+            port_mtu: HashMap::new(),
+            dhcp_rate_limits: HashMap::new(),
+            store: None,
+            backend: Arc::new(ShellBackend),
+            executor: Arc::new(ShellExecutor),
             #[cfg(test)]
             mock_mode: false,
             #[cfg(test)]
@@ -102,7 +137,25 @@ impl PortMgr {
         self
     }
 
-    /// Sets the port MTU using `ip link set`.
+    /// Publishes APPL_DB entries through `store`.
+    pub fn with_store(mut self, store: Arc<dyn WarmRestartStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Applies kernel changes through `backend` instead of the shell.
+    pub fn with_backend(mut self, backend: Arc<dyn CfgBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Runs shell commands through `executor`.
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Sets the port MTU through the backend.
     ///
     /// # Arguments
     ///
@@ -112,49 +165,41 @@ impl PortMgr {
     /// # Returns
     ///
     /// * `Ok(true)` - MTU was set successfully
-    /// * `Ok(false)` - Port not ready, should retry
-    /// * `Err(_)` - Command failed fatally
+    /// * `Ok(false)` - Port not ready or MTU not a number, should retry
+    /// * `Err(_)` - Writing APPL_DB failed
     #[instrument(skip(self), fields(port = %alias, mtu = %mtu))]
     pub async fn set_port_mtu(&mut self, alias: &str, mtu: &str) -> CfgMgrResult<bool> {
-        let cmd = format!(
-            "{} link set dev {} mtu {}",
-            shell::IP_CMD,
-            shell::shellquote(alias),
-            shell::shellquote(mtu)
-        );
+        let Ok(mtu_value) = mtu.parse::<u32>() else {
+            error!("Invalid MTU '{}' for {}", mtu, alias);
+            return Ok(false);
+        };
 
         #[cfg(test)]
         if self.mock_mode {
-            self.captured_commands.push(cmd.clone());
+            self.captured_commands
+                .push(ShellBackend::set_link_mtu_cmd(alias, mtu_value));
             return Ok(true);
         }
 
-        let result = shell::exec(&cmd).await?;
-
-        if result.success() {
-            // Also write to app DB
-            self.write_config_to_app_db(alias, fields::MTU, mtu).await?;
-            info!("Set MTU for {} to {}", alias, mtu);
-            Ok(true)
-        } else if !self.is_port_state_ok(alias).await? {
-            // Port not ready yet - this is expected during startup
-            warn!(
-                "Setting MTU for {} failed - port not ready: {}",
-                alias, result.stderr
-            );
-            Ok(false)
-        } else {
-            // Port is ready but command still failed - this could happen
-            // for port channel members during startup
-            warn!(
-                "Setting MTU for {} failed (port is ready): {}",
-                alias, result.stderr
-            );
-            Ok(false)
+        if let Err(e) = self.backend.set_link_mtu(alias, mtu_value).await {
+            if !self.is_port_state_ok(alias).await? {
+                // Port not ready yet - this is expected during startup
+                warn!("Setting MTU for {} failed - port not ready: {}", alias, e);
+            } else {
+                // Port is ready but the change still failed - this could
+                // happen for port channel members during startup
+                warn!("Setting MTU for {} failed (port is ready): {}", alias, e);
+            }
+            return Ok(false);
         }
+
+        // Also write to app DB
+        self.write_config_to_app_db(alias, fields::MTU, mtu).await?;
+        info!("Set MTU for {} to {}", alias, mtu);
+        Ok(true)
     }
 
-    /// Sets the port admin status using `ip link set`.
+    /// Sets the port admin status through the backend.
     ///
     /// # Arguments
     ///
@@ -169,42 +214,95 @@ impl PortMgr {
     #[instrument(skip(self), fields(port = %alias, up = %up))]
     pub async fn set_port_admin_status(&mut self, alias: &str, up: bool) -> CfgMgrResult<bool> {
         let status = if up { "up" } else { "down" };
-        let cmd = format!(
-            "{} link set dev {} {}",
-            shell::IP_CMD,
-            shell::shellquote(alias),
-            status
-        );
 
         #[cfg(test)]
         if self.mock_mode {
-            self.captured_commands.push(cmd.clone());
+            self.captured_commands
+                .push(ShellBackend::set_link_admin_cmd(alias, up));
+            return Ok(true);
+        }
+
+        if let Err(e) = self.backend.set_link_admin(alias, up).await {
+            if !self.is_port_state_ok(alias).await? {
+                warn!(
+                    "Setting admin status for {} failed - port not ready: {}",
+                    alias, e
+                );
+                return Ok(false);
+            }
+            // Port is ready but the change failed - this is a real error
+            return Err(e);
+        }
+
+        self.write_config_to_app_db(alias, fields::ADMIN_STATUS, status)
+            .await?;
+        info!("Set admin status for {} to {}", alias, status);
+        Ok(true)
+    }
+
+    /// Installs, replaces or removes the DHCP rate limit of a port.
+    ///
+    /// A non-zero `rate` (packets per second) installs an ingress qdisc with
+    /// a policing filter on DHCP requests; `0` removes it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - The kernel matches the requested limit
+    /// * `Ok(false)` - The rate is not a number; nothing was changed
+    /// * `Err(_)` - The tc command failed
+    #[instrument(skip(self), fields(port = %alias, rate = %rate))]
+    pub async fn set_port_dhcp_rate_limit(
+        &mut self,
+        alias: &str,
+        rate: &str,
+    ) -> CfgMgrResult<bool> {
+        let Ok(rate) = rate.parse::<u32>() else {
+            error!("Invalid DHCP rate limit '{}' for {}", rate, alias);
+            return Ok(false);
+        };
+
+        let installed = self.dhcp_rate_limits.get(alias).copied();
+        if installed.unwrap_or(0) == rate {
+            return Ok(true);
+        }
+
+        let mut cmds = Vec::new();
+        if installed.is_some() {
+            cmds.push(dhcp_qdisc_del_cmd(alias));
+        }
+        if rate > 0 {
+            cmds.push(dhcp_filter_add_cmd(alias, rate));
+        }
+        let cmd = cmds.join(" && ");
+
+        #[cfg(test)]
+        if self.mock_mode {
+            self.captured_commands.push(cmd);
+            self.record_dhcp_rate_limit(alias, rate);
             return Ok(true);
         }
 
-        let result = shell::exec(&cmd).await?;
-
-        if result.success() {
-            self.write_config_to_app_db(alias, fields::ADMIN_STATUS, status)
-                .await?;
-            info!("Set admin status for {} to {}", alias, status);
-            Ok(true)
-        } else if !self.is_port_state_ok(alias).await? {
-            warn!(
-                "Setting admin status for {} failed - port not ready: {}",
-                alias, result.stderr
-            );
-            Ok(false)
+        self.executor.exec_or_throw(&cmd).await?;
+
+        self.record_dhcp_rate_limit(alias, rate);
+        Ok(true)
+    }
+
+    fn record_dhcp_rate_limit(&mut self, alias: &str, rate: u32) {
+        if rate > 0 {
+            self.dhcp_rate_limits.insert(alias.to_string(), rate);
+            info!("Set DHCP rate limit for {} to {} pps", alias, rate);
         } else {
-            // Port is ready but command failed - this is a real error
-            Err(CfgMgrError::ShellCommandFailed {
-                command: cmd,
-                exit_code: result.exit_code,
-                output: result.combined_output(),
-            })
+            self.dhcp_rate_limits.remove(alias);
+            info!("Removed DHCP rate limit from {}", alias);
         }
     }
 
+    /// Returns the installed DHCP rate limit of a port, if any.
+    pub fn dhcp_rate_limit(&self, alias: &str) -> Option<u32> {
+        self.dhcp_rate_limits.get(alias).copied()
+    }
+
     /// Checks if a port is ready (exists in STATE_DB with state).
     ///
     /// # Arguments
@@ -221,10 +319,7 @@ impl PortMgr {
             return Ok(self.mock_port_states.get(alias).copied().unwrap_or(false));
         }
 
-        // In real implementation, this would query STATE_DB
-        // For now, return false to indicate we need the real DB connection
-        debug!("Checking port state for {} (stub)", alias);
-        Ok(false)
+        Ok(self.ready_ports.contains(alias))
     }
 
     /// Writes configuration to APPL_DB.
@@ -244,18 +339,28 @@ impl PortMgr {
         alias: &str,
         fvs: FieldValues,
     ) -> CfgMgrResult<()> {
-        #[cfg(test)]
-        {
-            self.app_db_writes.push((alias.to_string(), fvs));
-            Ok(())
+        self.publish(tables::APP_PORT_TABLE_NAME, alias, fvs).await
+    }
+
+    /// Writes an entry to an APPL_DB table.
+    async fn publish(&mut self, table: &str, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        debug!("Writing to APPL_DB: {}:{}:{:?}", table, key, fvs);
+        if let Some(store) = &self.store {
+            store.set(DbId::ApplDb, table, key, &fvs).await?;
         }
 
-        #[cfg(not(test))]
-        {
-            // In real implementation, this would write to APPL_DB
-            debug!("Writing to APPL_DB: {}:{:?}", alias, fvs);
-            Ok(())
+        #[cfg(test)]
+        self.app_db_writes.push((key.to_string(), fvs));
+        Ok(())
+    }
+
+    /// Deletes an entry from an APPL_DB table.
+    async fn unpublish(&self, table: &str, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting from APPL_DB: {}:{}", table, key);
+        if let Some(store) = &self.store {
+            store.del(DbId::ApplDb, table, key).await?;
         }
+        Ok(())
     }
 
     /// Processes a SET operation for a port.
@@ -276,6 +381,8 @@ impl PortMgr {
             None
         };
 
+        let mut dhcp_rate_limit = None;
+
         // Collect other field-values to pass through
         let mut other_fvs: FieldValues = Vec::new();
 
@@ -283,6 +390,13 @@ impl PortMgr {
             match field.as_str() {
                 fields::MTU => mtu = Some(value.clone()),
                 fields::ADMIN_STATUS => admin_status = Some(value.clone()),
+                fields::DHCP_RATE_LIMIT => dhcp_rate_limit = Some(value.clone()),
+                fields::TPID if !tables::is_valid_tpid(value) => {
+                    error!(
+                        "Invalid TPID '{}' for {}, supported: 0x8100, 0x9100, 0x9200, 0x88a8",
+                        value, alias
+                    );
+                }
                 _ => other_fvs.push((field.clone(), value.clone())),
             }
         }
@@ -314,7 +428,7 @@ impl PortMgr {
             info!("Port {} not ready, pending ip commands", alias);
 
             // Save pending task for retry
-            let pending = PendingTask {
+            let mut pending = PendingTask {
                 key: alias.to_string(),
                 op: Operation::Set,
                 fvs: vec![
@@ -325,6 +439,11 @@ impl PortMgr {
                     ),
                 ],
             };
+            if let Some(rate) = dhcp_rate_limit {
+                pending
+                    .fvs
+                    .push((fields::DHCP_RATE_LIMIT.to_string(), rate));
+            }
             self.pending_tasks.insert(alias.to_string(), pending);
 
             return Ok(());
@@ -338,8 +457,18 @@ impl PortMgr {
         // Execute ip commands for MTU and admin status
         if let Some(m) = mtu {
             if !m.is_empty() {
-                self.set_port_mtu(alias, &m).await?;
-                info!("Configured {} MTU to {}", alias, m);
+                self.port_mtu.insert(alias.to_string(), m.clone());
+                if let Some(lag) = self.lag_members.get(alias).cloned() {
+                    // The port channel owns the member MTU; only publish it
+                    self.write_config_to_app_db(alias, fields::MTU, &m).await?;
+                    info!(
+                        "{} is a member of {}, not setting kernel MTU {}",
+                        alias, lag, m
+                    );
+                } else {
+                    self.set_port_mtu(alias, &m).await?;
+                    info!("Configured {} MTU to {}", alias, m);
+                }
             }
         }
 
@@ -351,6 +480,10 @@ impl PortMgr {
             }
         }
 
+        if let Some(rate) = dhcp_rate_limit {
+            self.set_port_dhcp_rate_limit(alias, &rate).await?;
+        }
+
         // Remove from pending if it was there
         self.pending_tasks.remove(alias);
 
@@ -362,7 +495,13 @@ impl PortMgr {
     pub async fn process_port_del(&mut self, alias: &str) -> CfgMgrResult<()> {
         info!("Deleting port {}", alias);
 
-        // In real implementation, would delete from APPL_DB
+        if self.dhcp_rate_limits.contains_key(alias) {
+            if let Err(e) = self.set_port_dhcp_rate_limit(alias, "0").await {
+                warn!("Failed to remove DHCP rate limit from {}: {}", alias, e);
+            }
+        }
+
+        self.unpublish(tables::APP_PORT_TABLE_NAME, alias).await?;
         #[cfg(test)]
         {
             // For testing, we just track the deletion
@@ -372,7 +511,59 @@ impl PortMgr {
 
         self.port_list.remove(alias);
         self.pending_tasks.remove(alias);
+        self.port_mtu.remove(alias);
+
+        Ok(())
+    }
 
+    /// Processes a PORTCHANNEL_MEMBER SET (`<lag>|<port>`).
+    #[instrument(skip(self))]
+    pub async fn process_lag_member_set(&mut self, key: &str) -> CfgMgrResult<()> {
+        let Some((lag, alias)) = key.split_once('|') else {
+            warn!("Invalid PORTCHANNEL_MEMBER key: {}", key);
+            return Ok(());
+        };
+
+        info!("{} joined {}", alias, lag);
+        self.lag_members.insert(alias.to_string(), lag.to_string());
+        Ok(())
+    }
+
+    /// Processes a PORTCHANNEL_MEMBER DEL (`<lag>|<port>`).
+    ///
+    /// The port's standalone MTU is reapplied to the kernel, or deferred
+    /// until the port is ready.
+    #[instrument(skip(self))]
+    pub async fn process_lag_member_del(&mut self, key: &str) -> CfgMgrResult<()> {
+        let Some((lag, alias)) = key.split_once('|') else {
+            warn!("Invalid PORTCHANNEL_MEMBER key: {}", key);
+            return Ok(());
+        };
+
+        if self.lag_members.get(alias).map(String::as_str) != Some(lag) {
+            debug!("{} is not a member of {}", alias, lag);
+            return Ok(());
+        }
+        self.lag_members.remove(alias);
+        info!("{} left {}", alias, lag);
+
+        let Some(mtu) = self.port_mtu.get(alias).cloned() else {
+            return Ok(());
+        };
+        if self.is_port_state_ok(alias).await? && self.set_port_mtu(alias, &mtu).await? {
+            info!("Restored {} MTU to {}", alias, mtu);
+        } else {
+            let task = self
+                .pending_tasks
+                .entry(alias.to_string())
+                .or_insert_with(|| PendingTask {
+                    key: alias.to_string(),
+                    op: Operation::Set,
+                    fvs: Vec::new(),
+                });
+            task.fvs.retain(|(field, _)| field != fields::MTU);
+            task.fvs.push((fields::MTU.to_string(), mtu));
+        }
         Ok(())
     }
 
@@ -386,7 +577,8 @@ impl PortMgr {
         info!("Adding SendToIngress port: {}", alias);
 
         // Simply pass through to APPL_DB
-        self.write_config_to_app_db_multi(alias, fvs).await?;
+        self.publish(tables::APP_SEND_TO_INGRESS_PORT_TABLE_NAME, alias, fvs)
+            .await?;
 
         Ok(())
     }
@@ -396,7 +588,8 @@ impl PortMgr {
     pub async fn process_send_to_ingress_del(&mut self, alias: &str) -> CfgMgrResult<()> {
         info!("Removing SendToIngress port: {}", alias);
 
-        // In real implementation, would delete from APPL_DB
+        self.unpublish(tables::APP_SEND_TO_INGRESS_PORT_TABLE_NAME, alias)
+            .await?;
         #[cfg(test)]
         {
             self.app_db_writes
@@ -406,6 +599,54 @@ impl PortMgr {
         Ok(())
     }
 
+    /// Processes one queued CONFIG_DB entry.
+    async fn process_task(&mut self, table: &str, entry: KeyOpFieldsValues) -> CfgMgrResult<()> {
+        let alias = entry.key;
+        match (table, entry.op.is_set()) {
+            (tables::CFG_PORT_TABLE_NAME, true) => {
+                let mut fvs = entry.fvs;
+                // The field was removed from CONFIG_DB: drop the tc filter
+                if fvs.get_field(fields::DHCP_RATE_LIMIT).is_none()
+                    && self.dhcp_rate_limits.contains_key(&alias)
+                {
+                    fvs.push((fields::DHCP_RATE_LIMIT.to_string(), "0".to_string()));
+                }
+                self.process_port_set(&alias, fvs).await
+            }
+            (tables::CFG_PORT_TABLE_NAME, false) => self.process_port_del(&alias).await,
+            (tables::CFG_SEND_TO_INGRESS_PORT_TABLE_NAME, true) => {
+                self.process_send_to_ingress_set(&alias, entry.fvs).await
+            }
+            (tables::CFG_SEND_TO_INGRESS_PORT_TABLE_NAME, false) => {
+                self.process_send_to_ingress_del(&alias).await
+            }
+            (tables::CFG_LAG_MEMBER_TABLE_NAME, true) => self.process_lag_member_set(&alias).await,
+            (tables::CFG_LAG_MEMBER_TABLE_NAME, false) => self.process_lag_member_del(&alias).await,
+            _ => {
+                warn!("Ignoring {}|{} from unexpected table", table, alias);
+                Ok(())
+            }
+        }
+    }
+
+    /// Re-runs pending port configuration for ports that became ready.
+    async fn retry_pending_tasks(&mut self) {
+        let mut ready = Vec::new();
+        for alias in self.pending_tasks.keys() {
+            if let Ok(true) = self.is_port_state_ok(alias).await {
+                ready.push(alias.clone());
+            }
+        }
+
+        for alias in ready {
+            if let Some(task) = self.pending_tasks.remove(&alias) {
+                if let Err(e) = self.process_port_set(&alias, task.fvs).await {
+                    error!("Retrying port {} failed: {}", alias, e);
+                }
+            }
+        }
+    }
+
     /// Returns the number of pending tasks.
     pub fn pending_count(&self) -> usize {
         self.pending_tasks.len()
@@ -417,6 +658,30 @@ impl PortMgr {
     }
 }
 
+/// Command removing the ingress qdisc (and its DHCP filter) of a port.
+fn dhcp_qdisc_del_cmd(alias: &str) -> String {
+    format!(
+        "{} qdisc del dev {} handle ffff: ingress",
+        shell::TC_CMD,
+        shell::shellquote(alias)
+    )
+}
+
+/// Command installing an ingress qdisc that polices DHCP requests
+/// (UDP port 67) to `rate` packets per second.
+fn dhcp_filter_add_cmd(alias: &str, rate: u32) -> String {
+    // tc polices bytes; assume maximum-size DHCP packets of 406 bytes
+    let bytes = u64::from(rate) * 406;
+    let dev = shell::shellquote(alias);
+    format!(
+        "{tc} qdisc add dev {dev} handle ffff: ingress && \
+         {tc} filter add dev {dev} protocol ip parent ffff: prio 1 u32 \
+         match ip protocol 17 0xff match ip dport 67 0xffff \
+         police rate {bytes}bps burst {bytes}b conform-exceed drop",
+        tc = shell::TC_CMD,
+    )
+}
+
 impl Default for PortMgr {
     fn default() -> Self {
         Self::new()
@@ -430,13 +695,18 @@ impl Orch for PortMgr {
     }
 
     async fn do_task(&mut self) {
-        // In real implementation, this would drain the consumer queue
-        // and call process_port_set/del for each entry
-        debug!("PortMgr::do_task called");
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            let key = entry.key.clone();
+            if let Err(e) = self.process_task(&table, entry).await {
+                error!("Failed to process {}|{}: {}", table, key, e);
+            }
+        }
+
+        self.retry_pending_tasks().await;
     }
 
     fn has_pending_tasks(&self) -> bool {
-        !self.pending_tasks.is_empty()
+        !self.tasks.is_empty() || !self.pending_tasks.is_empty()
     }
 
     fn dump_pending_tasks(&self) -> Vec<String> {
@@ -473,17 +743,45 @@ impl CfgMgr for PortMgr {
         &[
             tables::CFG_PORT_TABLE_NAME,
             tables::CFG_SEND_TO_INGRESS_PORT_TABLE_NAME,
+            tables::CFG_LAG_MEMBER_TABLE_NAME,
         ]
     }
 
     fn state_table_names(&self) -> &[&str] {
         &[tables::STATE_PORT_TABLE_NAME]
     }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        match (db, table) {
+            (DbId::ConfigDb, _) => {
+                self.tasks
+                    .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+            }
+            (DbId::StateDb, tables::STATE_PORT_TABLE_NAME) => {
+                for entry in entries {
+                    let ok = entry.op.is_set() && entry.fvs.get_field(fields::STATE) == Some("ok");
+                    if ok {
+                        self.ready_ports.insert(entry.key);
+                    } else {
+                        self.ready_ports.remove(&entry.key);
+                    }
+                }
+            }
+            _ => debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sonic_cfgmgr_common::CfgMgrError;
+    use sonic_cfgmgr_test::{CannedResponse, CommandHarness};
 
     fn test_mgr() -> PortMgr {
         let mut mgr = PortMgr::new();
@@ -525,6 +823,38 @@ mod tests {
         assert!(mgr.captured_commands[0].contains(" down"));
     }
 
+    #[tokio::test]
+    async fn test_invalid_mtu_not_applied() {
+        let mut mgr = test_mgr();
+
+        assert!(!mgr.set_port_mtu("Ethernet0", "jumbo").await.unwrap());
+        assert!(mgr.captured_commands.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_backend_failures() {
+        let harness = Arc::new(CommandHarness::new());
+        harness.stub(r"Ethernet0", CannedResponse::exit(2, "Cannot find device"));
+        let mut mgr = PortMgr::new().with_backend(harness.clone());
+
+        // Not ready: both retry
+        assert!(!mgr.set_port_mtu("Ethernet0", "9100").await.unwrap());
+        assert!(!mgr.set_port_admin_status("Ethernet0", true).await.unwrap());
+
+        // Ready: MTU still retries, admin status is an error
+        mgr.ready_ports.insert("Ethernet0".to_string());
+        assert!(!mgr.set_port_mtu("Ethernet0", "9100").await.unwrap());
+        assert!(matches!(
+            mgr.set_port_admin_status("Ethernet0", true).await,
+            Err(CfgMgrError::ShellCommandFailed { exit_code: 2, .. })
+        ));
+
+        assert!(mgr.set_port_mtu("Ethernet4", "9100").await.unwrap());
+        assert!(mgr.app_db_writes.iter().all(|(key, _)| key == "Ethernet4"));
+        harness.assert_ran_matching(r#"link set dev "Ethernet4" mtu 9100$"#);
+        assert_eq!(harness.count_matching(r"Ethernet0"), 4);
+    }
+
     #[tokio::test]
     async fn test_process_port_set_first_time() {
         let mut mgr = test_mgr();
@@ -594,6 +924,330 @@ mod tests {
         assert!(!mgr.app_db_writes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_store_receives_app_db_entries() {
+        use sonic_cfgmgr_common::InMemoryStore;
+
+        let store = Arc::new(InMemoryStore::new());
+        let mut mgr = test_mgr().with_store(store.clone());
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+
+        let fvs = vec![("speed".to_string(), "100000".to_string())];
+        mgr.process_port_set("Ethernet0", fvs).await.unwrap();
+        mgr.process_send_to_ingress_set("IngressPort1", Vec::new())
+            .await
+            .unwrap();
+
+        let port = store
+            .get_all(DbId::ApplDb, tables::APP_PORT_TABLE_NAME, "Ethernet0")
+            .await
+            .unwrap();
+        assert_eq!(port.get_field("speed"), Some("100000"));
+
+        mgr.process_send_to_ingress_del("IngressPort1")
+            .await
+            .unwrap();
+        mgr.process_port_del("Ethernet0").await.unwrap();
+        assert_eq!(
+            store.journal(),
+            [
+                "SET APPL_DB:PORT_TABLE:Ethernet0",
+                "SET APPL_DB:SEND_TO_INGRESS_PORT_TABLE:IngressPort1",
+                "DEL APPL_DB:SEND_TO_INGRESS_PORT_TABLE:IngressPort1",
+                "DEL APPL_DB:PORT_TABLE:Ethernet0",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_do_task_drains_config_and_retries_when_ready() {
+        let mut mgr = test_mgr();
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            vec![KeyOpFieldsValues::set(
+                "Ethernet0",
+                vec![("mtu".to_string(), "1500".to_string())],
+            )],
+        );
+        assert!(mgr.has_pending_tasks());
+
+        // Port not ready yet: APPL_DB is written, ip commands are deferred
+        mgr.do_task().await;
+        assert!(mgr.captured_commands.is_empty());
+        assert_eq!(mgr.pending_count(), 1);
+
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+        mgr.do_task().await;
+        assert_eq!(mgr.captured_commands.len(), 2);
+        assert!(mgr.captured_commands[0].contains("1500"));
+        assert!(!mgr.has_pending_tasks());
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("Ethernet0")],
+        );
+        mgr.do_task().await;
+        assert!(mgr.ports().is_empty());
+    }
+
+    #[test]
+    fn test_add_to_sync_tracks_port_state() {
+        let mut mgr = PortMgr::new();
+        let ok = |state: &str| vec![(fields::STATE.to_string(), state.to_string())];
+
+        mgr.add_to_sync(
+            DbId::StateDb,
+            tables::STATE_PORT_TABLE_NAME,
+            vec![
+                KeyOpFieldsValues::set("Ethernet0", ok("ok")),
+                KeyOpFieldsValues::set("Ethernet4", ok("")),
+            ],
+        );
+        assert!(mgr.ready_ports.contains("Ethernet0"));
+        assert!(!mgr.ready_ports.contains("Ethernet4"));
+
+        mgr.add_to_sync(
+            DbId::StateDb,
+            tables::STATE_PORT_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("Ethernet0")],
+        );
+        assert!(mgr.ready_ports.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lag_member_mtu_deferred_until_removed() {
+        let mut mgr = test_mgr();
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+        let mtu_cmds = |mgr: &PortMgr| {
+            mgr.captured_commands
+                .iter()
+                .filter(|c| c.contains(" mtu "))
+                .count()
+        };
+
+        mgr.process_lag_member_set("PortChannel01|Ethernet0")
+            .await
+            .unwrap();
+        let fvs = vec![("mtu".to_string(), "9000".to_string())];
+        mgr.process_port_set("Ethernet0", fvs).await.unwrap();
+
+        // No kernel MTU on the member, but APPL_DB still gets it
+        assert_eq!(mtu_cmds(&mgr), 0);
+        assert!(mgr
+            .app_db_writes
+            .iter()
+            .any(|(alias, fvs)| alias == "Ethernet0"
+                && fvs.contains(&("mtu".to_string(), "9000".to_string()))));
+
+        // Leaving the LAG reapplies the cached MTU exactly once
+        mgr.process_lag_member_del("PortChannel01|Ethernet0")
+            .await
+            .unwrap();
+        mgr.process_lag_member_del("PortChannel01|Ethernet0")
+            .await
+            .unwrap();
+        assert_eq!(mtu_cmds(&mgr), 1);
+        assert!(mgr
+            .captured_commands
+            .iter()
+            .any(|c| c.contains("Ethernet0") && c.contains("9000")));
+    }
+
+    #[tokio::test]
+    async fn test_lag_member_del_through_do_task_before_ready() {
+        let mut mgr = test_mgr();
+        mgr.mock_port_states.insert("Ethernet4".to_string(), true);
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_LAG_MEMBER_TABLE_NAME,
+            vec![KeyOpFieldsValues::set("PortChannel02|Ethernet4", vec![])],
+        );
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            vec![KeyOpFieldsValues::set(
+                "Ethernet4",
+                vec![("mtu".to_string(), "1500".to_string())],
+            )],
+        );
+        mgr.do_task().await;
+        assert!(!mgr.captured_commands.iter().any(|c| c.contains(" mtu ")));
+
+        // Port goes down before the member is removed: MTU waits for it
+        mgr.mock_port_states.insert("Ethernet4".to_string(), false);
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_LAG_MEMBER_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("PortChannel02|Ethernet4")],
+        );
+        mgr.do_task().await;
+        assert_eq!(mgr.pending_count(), 1);
+
+        mgr.mock_port_states.insert("Ethernet4".to_string(), true);
+        mgr.do_task().await;
+        let mtu_cmds: Vec<_> = mgr
+            .captured_commands
+            .iter()
+            .filter(|c| c.contains(" mtu "))
+            .collect();
+        assert_eq!(mtu_cmds.len(), 1);
+        assert!(mtu_cmds[0].contains("1500"));
+        assert_eq!(mgr.pending_count(), 0);
+    }
+
+    fn port_set(fvs: &[(&str, &str)]) -> Vec<KeyOpFieldsValues> {
+        vec![KeyOpFieldsValues::set(
+            "Ethernet0",
+            fvs.iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect(),
+        )]
+    }
+
+    #[tokio::test]
+    async fn test_dhcp_rate_limit_add_update_remove() {
+        let mut mgr = test_mgr();
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+
+        // Add
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            port_set(&[("dhcp_rate_limit", "300")]),
+        );
+        mgr.do_task().await;
+        let cmd = mgr.captured_commands.last().unwrap().clone();
+        assert!(cmd.contains("/sbin/tc qdisc add dev \"Ethernet0\" handle ffff: ingress"));
+        assert!(cmd.contains("match ip dport 67 0xffff police rate 121800bps"));
+        assert!(!cmd.contains("qdisc del"));
+        assert_eq!(mgr.dhcp_rate_limit("Ethernet0"), Some(300));
+        assert!(!mgr
+            .app_db_writes
+            .iter()
+            .any(|(_, fvs)| fvs.get_field(fields::DHCP_RATE_LIMIT).is_some()));
+
+        // Same value again is a no-op
+        let count = mgr.captured_commands.len();
+        mgr.set_port_dhcp_rate_limit("Ethernet0", "300")
+            .await
+            .unwrap();
+        assert_eq!(mgr.captured_commands.len(), count);
+
+        // Update replaces the filter
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            port_set(&[("dhcp_rate_limit", "100")]),
+        );
+        mgr.do_task().await;
+        let cmd = mgr.captured_commands.last().unwrap().clone();
+        assert!(cmd.starts_with("/sbin/tc qdisc del dev \"Ethernet0\" handle ffff: ingress && "));
+        assert!(cmd.contains("police rate 40600bps"));
+        assert_eq!(mgr.dhcp_rate_limit("Ethernet0"), Some(100));
+
+        // Field removed from CONFIG_DB
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            tables::CFG_PORT_TABLE_NAME,
+            port_set(&[("mtu", "9100")]),
+        );
+        mgr.do_task().await;
+        assert!(mgr
+            .captured_commands
+            .contains(&"/sbin/tc qdisc del dev \"Ethernet0\" handle ffff: ingress".to_string()));
+        assert_eq!(mgr.dhcp_rate_limit("Ethernet0"), None);
+    }
+
+    #[tokio::test]
+    async fn test_dhcp_rate_limit_removed_with_port() {
+        let mut mgr = test_mgr();
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+
+        mgr.set_port_dhcp_rate_limit("Ethernet0", "300")
+            .await
+            .unwrap();
+        mgr.process_port_del("Ethernet0").await.unwrap();
+
+        assert_eq!(
+            mgr.captured_commands.last().unwrap(),
+            "/sbin/tc qdisc del dev \"Ethernet0\" handle ffff: ingress"
+        );
+        assert_eq!(mgr.dhcp_rate_limit("Ethernet0"), None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_dhcp_rate_limit() {
+        let mut mgr = test_mgr();
+
+        assert!(!mgr
+            .set_port_dhcp_rate_limit("Ethernet0", "fast")
+            .await
+            .unwrap());
+        assert!(mgr.captured_commands.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dhcp_rate_limit_through_executor() {
+        let harness = Arc::new(CommandHarness::new());
+        let mut mgr = PortMgr::new().with_executor(harness.clone());
+
+        assert!(mgr
+            .set_port_dhcp_rate_limit("Ethernet0", "300")
+            .await
+            .unwrap());
+        harness.assert_ran_matching(r"tc filter add dev .Ethernet0.");
+
+        harness.stub(r"tc", CannedResponse::exit(2, "Cannot find device"));
+        assert!(matches!(
+            mgr.set_port_dhcp_rate_limit("Ethernet0", "0").await,
+            Err(CfgMgrError::ShellCommandFailed { exit_code: 2, .. })
+        ));
+        assert_eq!(mgr.dhcp_rate_limits.get("Ethernet0"), Some(&300));
+    }
+
+    #[tokio::test]
+    async fn test_tpid() {
+        let mut mgr = test_mgr();
+        mgr.mock_port_states.insert("Ethernet0".to_string(), true);
+
+        mgr.process_port_set("Ethernet0", port_set(&[("tpid", "0x9100")]).remove(0).fvs)
+            .await
+            .unwrap();
+        assert!(mgr
+            .app_db_writes
+            .iter()
+            .any(|(_, fvs)| fvs.get_field(fields::TPID) == Some("0x9100")));
+
+        mgr.app_db_writes.clear();
+        mgr.process_port_set(
+            "Ethernet0",
+            port_set(&[("tpid", "0x1234"), ("fec", "rs")]).remove(0).fvs,
+        )
+        .await
+        .unwrap();
+        assert!(!mgr
+            .app_db_writes
+            .iter()
+            .any(|(_, fvs)| fvs.get_field(fields::TPID).is_some()));
+        assert!(mgr
+            .app_db_writes
+            .iter()
+            .any(|(_, fvs)| fvs.get_field("fec") == Some("rs")));
+    }
+
+    #[test]
+    fn test_is_valid_tpid() {
+        assert!(tables::is_valid_tpid("0x8100"));
+        assert!(tables::is_valid_tpid("0x88A8"));
+        assert!(!tables::is_valid_tpid("0x8808"));
+        assert!(!tables::is_valid_tpid("8100"));
+        assert!(!tables::is_valid_tpid("0xnope"));
+    }
+
     #[test]
     fn test_orch_trait() {
         let mgr = test_mgr();
@@ -608,7 +1262,10 @@ mod tests {
 
         assert_eq!(mgr.daemon_name(), "portmgrd");
         assert!(!mgr.is_warm_restart());
-        assert_eq!(mgr.config_table_names(), &["PORT", "SEND_TO_INGRESS_PORT"]);
+        assert_eq!(
+            mgr.config_table_names(),
+            &["PORT", "SEND_TO_INGRESS_PORT", "PORTCHANNEL_MEMBER"]
+        );
     }
 
     #[test]
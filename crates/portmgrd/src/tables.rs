@@ -30,4 +30,22 @@ pub mod fields {
 
     /// Port state field in STATE_DB.
     pub const STATE: &str = "state";
+
+    /// DHCP packet rate limit in packets per second (0 disables it).
+    pub const DHCP_RATE_LIMIT: &str = "dhcp_rate_limit";
+
+    /// Port TPID (e.g. `0x8100`).
+    pub const TPID: &str = "tpid";
+}
+
+/// TPID values orchagent accepts on a port.
+pub const SUPPORTED_TPIDS: &[u16] = &[0x8100, 0x9100, 0x9200, 0x88a8];
+
+/// Returns true if `value` is a supported TPID such as `0x8100`.
+pub fn is_valid_tpid(value: &str) -> bool {
+    value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .and_then(|hex| u16::from_str_radix(hex, 16).ok())
+        .is_some_and(|tpid| SUPPORTED_TPIDS.contains(&tpid))
 }
@@ -0,0 +1,86 @@
+//! End-to-end test of portmgrd driven by `CfgMgrRunner` over Redis.
+//!
+//! Shell commands run in dry-run mode, so the test checks the `ip` commands
+//! portmgrd would have issued.
+
+use std::time::Duration;
+
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use tokio::sync::oneshot;
+
+use sonic_cfgmgr_common::{shell, CfgMgrRunner, DbId};
+use sonic_cfgmgr_test::RedisTestEnv;
+use sonic_portmgrd::PortMgr;
+
+async fn db_connection(env: &RedisTestEnv, db: DbId) -> MultiplexedConnection {
+    redis::Client::open(format!("{}/{}", env.connection_url(), db.id()))
+        .expect("Invalid Redis URL")
+        .get_multiplexed_tokio_connection()
+        .await
+        .expect("Failed to connect to Redis")
+}
+
+#[tokio::test]
+#[ignore = "Requires Docker"]
+async fn test_portmgrd_through_runner() {
+    let env = RedisTestEnv::start().await.expect("Failed to start Redis");
+    let mut conn = env.get_async_connection().await.unwrap();
+    redis::cmd("CONFIG")
+        .arg("SET")
+        .arg("notify-keyspace-events")
+        .arg("KEA")
+        .query_async::<()>(&mut conn)
+        .await
+        .expect("Failed to enable keyspace events");
+
+    let mut config_db = db_connection(&env, DbId::ConfigDb).await;
+    let mut state_db = db_connection(&env, DbId::StateDb).await;
+
+    // Present before start: picked up by the initial CONFIG_DB load
+    config_db
+        .hset::<_, _, _, ()>("PORT|Ethernet0", "mtu", "1500")
+        .await
+        .unwrap();
+
+    shell::set_dry_run(true);
+    shell::take_dry_run_journal();
+
+    let runner = CfgMgrRunner::new(PortMgr::new())
+        .with_select_timeout(Duration::from_millis(50))
+        .with_redis(&env.connection_url())
+        .await
+        .expect("Failed to subscribe");
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let handle = tokio::spawn(runner.run_until(async {
+        let _ = stop_rx.await;
+    }));
+
+    // Live changes: a second port, then both ports come up
+    config_db
+        .hset::<_, _, _, ()>("PORT|Ethernet4", "admin_status", "up")
+        .await
+        .unwrap();
+    for port in ["Ethernet0", "Ethernet4"] {
+        state_db
+            .hset::<_, _, _, ()>(format!("PORT_TABLE|{}", port), "state", "ok")
+            .await
+            .unwrap();
+    }
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    stop_tx.send(()).unwrap();
+    let mgr = handle.await.unwrap().expect("Runner failed");
+
+    assert!(mgr.ports().contains("Ethernet0"));
+    assert!(mgr.ports().contains("Ethernet4"));
+    assert_eq!(mgr.pending_count(), 0);
+
+    let journal = shell::take_dry_run_journal();
+    assert!(journal
+        .iter()
+        .any(|cmd| cmd.contains("\"Ethernet0\" mtu \"1500\"")));
+    assert!(journal
+        .iter()
+        .any(|cmd| cmd.contains("\"Ethernet4\"") && cmd.ends_with(" up")));
+}
@@ -123,6 +123,8 @@ pub struct AlertingEngine {
     metric_history: HashMap<String, Vec<MetricSample>>,
     /// Max samples to keep per metric (for memory efficiency)
     max_history_samples: usize,
+    /// Metrics reported by other subsystems (e.g. carrier debounce)
+    external_metrics: HashMap<String, f64>,
 }
 
 impl AlertingEngine {
@@ -133,6 +135,7 @@ impl AlertingEngine {
             alerts: HashMap::new(),
             metric_history: HashMap::new(),
             max_history_samples: 1000,
+            external_metrics: HashMap::new(),
         }
     }
 
@@ -179,6 +182,11 @@ impl AlertingEngine {
             .collect()
     }
 
+    /// Report a metric not carried by WarmRestartMetrics
+    pub fn set_metric_value(&mut self, metric_name: &str, value: f64) {
+        self.external_metrics.insert(metric_name.to_string(), value);
+    }
+
     /// Evaluate all rules against current metrics
     pub fn evaluate(&mut self, metrics: &WarmRestartMetrics) -> Vec<&Alert> {
         let now = current_timestamp_secs();
@@ -260,7 +268,11 @@ impl AlertingEngine {
             "health_score" => metrics.health_score(),
             "recovery_success_rate" => metrics.recovery_success_rate(),
             "eoiu_timeout_rate" => metrics.eoiu_timeout_rate(),
-            _ => f64::NAN,
+            other => self
+                .external_metrics
+                .get(other)
+                .copied()
+                .unwrap_or(f64::NAN),
         }
     }
 
@@ -581,6 +593,33 @@ pub fn create_default_alert_rules() -> Vec<AlertRule> {
     ]
 }
 
+/// Metric name for the busiest port's carrier flaps in the last minute
+pub const MAX_PORT_FLAPS_PER_MINUTE: &str = "max_port_flaps_per_minute";
+
+/// Create the carrier flap alert rule
+///
+/// Fires when any port's carrier flaps more than `threshold_per_minute`
+/// times within a minute (see CarrierDebouncer).
+pub fn create_port_flap_alert_rule(threshold_per_minute: u32) -> AlertRule {
+    AlertRule {
+        rule_id: "port_flap_rate_high".to_string(),
+        name: "High Port Flap Rate".to_string(),
+        description: format!(
+            "Port carrier flapping more than {} times per minute",
+            threshold_per_minute
+        ),
+        metric_name: MAX_PORT_FLAPS_PER_MINUTE.to_string(),
+        condition: AlertCondition::Above,
+        threshold: threshold_per_minute as f64,
+        threshold_range: None,
+        evaluation_window_secs: 60,
+        for_duration_secs: 0,
+        enabled: true,
+        severity: AlertSeverity::Warning,
+        actions: vec![AlertAction::Log, AlertAction::Notify],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -993,4 +1032,27 @@ mod tests {
         let rules = engine.rules();
         assert_eq!(rules.len(), 0);
     }
+
+    #[test]
+    fn test_port_flap_alert_rule() {
+        let mut engine = AlertingEngine::new();
+        engine.add_rule(create_port_flap_alert_rule(10));
+        let metrics = WarmRestartMetrics::new();
+
+        // Unreported metric is skipped
+        engine.evaluate(&metrics);
+        assert!(engine.alerts().is_empty());
+
+        engine.set_metric_value(MAX_PORT_FLAPS_PER_MINUTE, 10.0);
+        engine.evaluate(&metrics);
+        assert!(engine.alerts_by_state(AlertState::Firing).is_empty());
+
+        engine.set_metric_value(MAX_PORT_FLAPS_PER_MINUTE, 11.0);
+        engine.evaluate(&metrics);
+        assert_eq!(engine.alerts_by_state(AlertState::Firing).len(), 1);
+
+        engine.set_metric_value(MAX_PORT_FLAPS_PER_MINUTE, 0.0);
+        engine.evaluate(&metrics);
+        assert_eq!(engine.alerts_by_state(AlertState::Resolved).len(), 1);
+    }
 }
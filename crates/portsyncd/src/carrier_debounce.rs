@@ -0,0 +1,378 @@
+//! Carrier debounce for host interface oper-state
+//!
+//! A bad optic can bounce carrier many times a second, and every bounce
+//! would otherwise become a STATE_DB write and an orchagent reaction. The
+//! debouncer holds each oper-state transition for a per-direction interval
+//! and only publishes it once the link has stayed in the new state for the
+//! whole window; a flap back to the published state inside the window
+//! cancels the transition.
+//!
+//! Time is passed in explicitly so callers (and tests) control the clock.
+//!
+//! NIST 800-53 Rev5 [SC-5]: Denial of Service Protection - rate limiting of
+//! link flap propagation
+
+use crate::config_file::DebounceConfig;
+use crate::port_sync::LinkStatus;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Window over which flap rates are measured
+const FLAP_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Debounce state for one port
+#[derive(Debug, Default)]
+struct PortDebounce {
+    /// Last state published to STATE_DB
+    published: Option<LinkStatus>,
+    /// Last state reported by the kernel
+    observed: Option<LinkStatus>,
+    /// Transition waiting for its hold interval to expire
+    pending: Option<(LinkStatus, Instant)>,
+    /// Total observed carrier transitions
+    flaps: u64,
+    /// Timestamps of transitions inside the rate window
+    recent_flaps: VecDeque<Instant>,
+}
+
+/// Result of observing a kernel oper-state report
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebounceOutcome {
+    /// State to publish now, if any
+    pub publish: Option<LinkStatus>,
+    /// The report was a carrier transition (counts as a flap)
+    pub flapped: bool,
+}
+
+/// Per-port carrier debouncer
+#[derive(Debug)]
+pub struct CarrierDebouncer {
+    up_hold: Duration,
+    down_hold: Duration,
+    ports: BTreeMap<String, PortDebounce>,
+}
+
+impl CarrierDebouncer {
+    /// Create debouncer from configuration
+    pub fn new(config: &DebounceConfig) -> Self {
+        Self {
+            up_hold: config.up_hold(),
+            down_hold: config.down_hold(),
+            ports: BTreeMap::new(),
+        }
+    }
+
+    /// Apply new hold intervals (hot reload)
+    ///
+    /// Transitions already pending keep their original deadline.
+    pub fn update_config(&mut self, config: &DebounceConfig) {
+        self.up_hold = config.up_hold();
+        self.down_hold = config.down_hold();
+    }
+
+    /// Get hold interval for a transition into `status`
+    pub fn hold_for(&self, status: &LinkStatus) -> Duration {
+        match status {
+            LinkStatus::Up => self.up_hold,
+            LinkStatus::Down => self.down_hold,
+        }
+    }
+
+    /// Record a kernel oper-state report for a port
+    ///
+    /// The first report for a port is published immediately so the initial
+    /// kernel dump is never delayed.
+    pub fn observe(&mut self, port: &str, oper: LinkStatus, now: Instant) -> DebounceOutcome {
+        let hold = self.hold_for(&oper);
+        let state = self.ports.entry(port.to_string()).or_default();
+
+        let flapped = state.observed.as_ref().is_some_and(|prev| *prev != oper);
+        if flapped {
+            state.flaps += 1;
+            state.recent_flaps.push_back(now);
+            prune_flaps(&mut state.recent_flaps, now);
+        }
+        state.observed = Some(oper.clone());
+
+        let publish = match state.published.clone() {
+            None => {
+                state.published = Some(oper.clone());
+                Some(oper)
+            }
+            Some(published) if published == oper => {
+                // Flapped back inside the window; the transition never happened
+                state.pending = None;
+                None
+            }
+            Some(_) if hold.is_zero() => {
+                state.pending = None;
+                state.published = Some(oper.clone());
+                Some(oper)
+            }
+            Some(_) => {
+                if !matches!(&state.pending, Some((target, _)) if *target == oper) {
+                    state.pending = Some((oper, now + hold));
+                }
+                None
+            }
+        };
+
+        DebounceOutcome { publish, flapped }
+    }
+
+    /// Publish every pending transition whose hold interval has expired
+    pub fn poll(&mut self, now: Instant) -> Vec<(String, LinkStatus)> {
+        let mut ready = Vec::new();
+        for (port, state) in &mut self.ports {
+            if let Some((target, deadline)) = &state.pending
+                && *deadline <= now
+            {
+                let target = target.clone();
+                state.published = Some(target.clone());
+                state.pending = None;
+                ready.push((port.clone(), target));
+            }
+        }
+        ready
+    }
+
+    /// Get the earliest pending deadline
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.ports
+            .values()
+            .filter_map(|state| state.pending.as_ref().map(|(_, deadline)| *deadline))
+            .min()
+    }
+
+    /// Get the state last published for a port
+    pub fn published(&self, port: &str) -> Option<&LinkStatus> {
+        self.ports
+            .get(port)
+            .and_then(|state| state.published.as_ref())
+    }
+
+    /// Get total carrier flaps observed on a port
+    pub fn flap_count(&self, port: &str) -> u64 {
+        self.ports.get(port).map(|state| state.flaps).unwrap_or(0)
+    }
+
+    /// Get carrier flaps on a port during the last minute
+    pub fn flaps_per_minute(&self, port: &str, now: Instant) -> usize {
+        self.ports
+            .get(port)
+            .map(|state| count_recent(&state.recent_flaps, now))
+            .unwrap_or(0)
+    }
+
+    /// Get the highest per-port flap rate during the last minute
+    pub fn max_flaps_per_minute(&self, now: Instant) -> usize {
+        self.ports
+            .values()
+            .map(|state| count_recent(&state.recent_flaps, now))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Drop all state for a port (e.g. on RTM_DELLINK)
+    pub fn forget(&mut self, port: &str) {
+        self.ports.remove(port);
+    }
+}
+
+impl Default for CarrierDebouncer {
+    fn default() -> Self {
+        Self::new(&DebounceConfig::default())
+    }
+}
+
+fn prune_flaps(flaps: &mut VecDeque<Instant>, now: Instant) {
+    while flaps
+        .front()
+        .is_some_and(|t| now.duration_since(*t) >= FLAP_RATE_WINDOW)
+    {
+        flaps.pop_front();
+    }
+}
+
+fn count_recent(flaps: &VecDeque<Instant>, now: Instant) -> usize {
+    flaps
+        .iter()
+        .filter(|t| now.duration_since(**t) < FLAP_RATE_WINDOW)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn debouncer(up_hold_ms: u64, down_hold_ms: u64) -> CarrierDebouncer {
+        CarrierDebouncer::new(&DebounceConfig {
+            up_hold_ms,
+            down_hold_ms,
+            ..DebounceConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_first_report_published_immediately() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+
+        let outcome = d.observe("Ethernet0", LinkStatus::Down, t0);
+        assert_eq!(outcome.publish, Some(LinkStatus::Down));
+        assert!(!outcome.flapped);
+        assert_eq!(d.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_up_held_until_stable() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Down, t0);
+
+        assert_eq!(
+            d.observe("Ethernet0", LinkStatus::Up, t0 + ms(10)).publish,
+            None
+        );
+        assert_eq!(d.next_deadline(), Some(t0 + ms(210)));
+        assert!(d.poll(t0 + ms(209)).is_empty());
+        assert_eq!(
+            d.poll(t0 + ms(210)),
+            vec![("Ethernet0".to_string(), LinkStatus::Up)]
+        );
+        assert_eq!(d.published("Ethernet0"), Some(&LinkStatus::Up));
+    }
+
+    #[test]
+    fn test_down_immediate_by_default() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Up, t0);
+
+        let outcome = d.observe("Ethernet0", LinkStatus::Down, t0 + ms(5));
+        assert_eq!(outcome.publish, Some(LinkStatus::Down));
+        assert!(outcome.flapped);
+    }
+
+    #[test]
+    fn test_flap_storm_coalesced_into_final_state() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Up, t0);
+
+        // Carrier goes down (published at once), then bounces every 20ms
+        let mut emitted = Vec::new();
+        let mut now = t0;
+        for i in 0..10 {
+            now += ms(20);
+            let oper = if i % 2 == 0 {
+                LinkStatus::Down
+            } else {
+                LinkStatus::Up
+            };
+            if let Some(status) = d.observe("Ethernet0", oper, now).publish {
+                emitted.push(status);
+            }
+            emitted.extend(d.poll(now).into_iter().map(|(_, s)| s));
+        }
+
+        // Storm ends up; it must stay up for a full hold before publishing
+        assert_eq!(emitted, vec![LinkStatus::Down]);
+        assert!(d.poll(now + ms(199)).is_empty());
+        assert_eq!(
+            d.poll(now + ms(200)),
+            vec![("Ethernet0".to_string(), LinkStatus::Up)]
+        );
+        assert_eq!(d.flap_count("Ethernet0"), 10);
+    }
+
+    #[test]
+    fn test_symmetric_hold_cancels_short_outage() {
+        let mut d = debouncer(100, 100);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Up, t0);
+
+        assert_eq!(
+            d.observe("Ethernet0", LinkStatus::Down, t0 + ms(10))
+                .publish,
+            None
+        );
+        assert_eq!(
+            d.observe("Ethernet0", LinkStatus::Up, t0 + ms(50)).publish,
+            None
+        );
+        assert!(d.poll(t0 + ms(500)).is_empty());
+        assert_eq!(d.published("Ethernet0"), Some(&LinkStatus::Up));
+    }
+
+    #[test]
+    fn test_repeated_report_keeps_deadline() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Down, t0);
+        d.observe("Ethernet0", LinkStatus::Up, t0 + ms(10));
+        // MTU change re-reports the same carrier state
+        let outcome = d.observe("Ethernet0", LinkStatus::Up, t0 + ms(100));
+
+        assert!(!outcome.flapped);
+        assert_eq!(d.next_deadline(), Some(t0 + ms(210)));
+    }
+
+    #[test]
+    fn test_flap_rate_window() {
+        let mut d = debouncer(0, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Up, t0);
+        d.observe("Ethernet0", LinkStatus::Down, t0 + ms(100));
+        d.observe("Ethernet0", LinkStatus::Up, t0 + ms(200));
+        d.observe("Ethernet4", LinkStatus::Up, t0);
+
+        assert_eq!(d.flaps_per_minute("Ethernet0", t0 + ms(300)), 2);
+        assert_eq!(d.max_flaps_per_minute(t0 + ms(300)), 2);
+        assert_eq!(d.max_flaps_per_minute(t0 + Duration::from_secs(61)), 0);
+        assert_eq!(d.flap_count("Ethernet0"), 2);
+    }
+
+    #[test]
+    fn test_update_config_applies_to_new_transitions() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Up, t0);
+
+        d.update_config(&DebounceConfig {
+            up_hold_ms: 0,
+            down_hold_ms: 50,
+            ..DebounceConfig::default()
+        });
+
+        assert_eq!(
+            d.observe("Ethernet0", LinkStatus::Down, t0 + ms(1)).publish,
+            None
+        );
+        assert_eq!(
+            d.poll(t0 + ms(51)),
+            vec![("Ethernet0".to_string(), LinkStatus::Down)]
+        );
+        assert_eq!(
+            d.observe("Ethernet0", LinkStatus::Up, t0 + ms(60)).publish,
+            Some(LinkStatus::Up)
+        );
+    }
+
+    #[test]
+    fn test_forget_resets_port() {
+        let mut d = debouncer(200, 0);
+        let t0 = Instant::now();
+        d.observe("Ethernet0", LinkStatus::Down, t0);
+        d.observe("Ethernet0", LinkStatus::Up, t0 + ms(10));
+        d.forget("Ethernet0");
+
+        assert_eq!(d.next_deadline(), None);
+        assert_eq!(d.published("Ethernet0"), None);
+        assert_eq!(d.flap_count("Ethernet0"), 0);
+    }
+}
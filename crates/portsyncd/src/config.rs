@@ -4,7 +4,11 @@
 //! Handles port attributes like lanes, speed, MTU, and admin status.
 
 use crate::error::{PortsyncError, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// How often CONFIG_DB is re-read to expire metrics of removed ports
+pub const PORT_CONFIG_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 
 /// Common interface for database adapters (DatabaseConnection, RedisAdapter)
 /// Allows load_port_config to work with both mock and real implementations
@@ -246,6 +250,16 @@ pub async fn load_port_config(
     Ok(ports)
 }
 
+/// Names of the ports currently in the CONFIG_DB PORT table
+pub async fn configured_port_names(config_db: &dyn DatabaseAdapter) -> Result<HashSet<String>> {
+    Ok(config_db
+        .keys("PORT|*")
+        .await?
+        .into_iter()
+        .filter_map(|key| key.strip_prefix("PORT|").map(str::to_string))
+        .collect())
+}
+
 /// Validate port configuration
 pub fn validate_port_config(port: &PortConfig) -> Result<()> {
     port.validate()
@@ -374,6 +388,28 @@ mod tests {
         assert!(ports.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_configured_port_names() {
+        let mut config_db = DatabaseConnection::new("CONFIG_DB".to_string());
+        let fields = vec![("lanes".to_string(), "0".to_string())];
+        config_db.hset("PORT|Ethernet0", &fields).await.unwrap();
+        config_db.hset("PORT|Ethernet4", &fields).await.unwrap();
+        config_db
+            .hset("PORTCHANNEL|PortChannel1", &fields)
+            .await
+            .unwrap();
+
+        let names = configured_port_names(&config_db).await.unwrap();
+        assert_eq!(
+            names,
+            HashSet::from(["Ethernet0".to_string(), "Ethernet4".to_string()])
+        );
+
+        config_db.delete("PORT|Ethernet4").await.unwrap();
+        let names = configured_port_names(&config_db).await.unwrap();
+        assert_eq!(names, HashSet::from(["Ethernet0".to_string()]));
+    }
+
     #[tokio::test]
     async fn test_send_port_config_done() {
         let mut app_db = DatabaseConnection::new("APP_DB".to_string());
@@ -6,6 +6,7 @@
 //! Phase 5 Week 5 implementation.
 
 use crate::error::{PortsyncError, Result};
+use crate::metrics_exporter::{DEFAULT_MAX_PORT_LABELS, DEFAULT_PORT_LATENCY_BUCKETS};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -83,6 +84,25 @@ pub struct HealthConfig {
     pub watchdog_interval_secs: u64,
 }
 
+/// Carrier debounce configuration
+///
+/// Oper-state transitions are held for a per-direction interval before they
+/// reach STATE_DB; flaps inside the window collapse into the final state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DebounceConfig {
+    /// How long carrier must stay up before publishing oper up (ms)
+    #[serde(default = "default_debounce_up_hold_ms")]
+    pub up_hold_ms: u64,
+
+    /// How long carrier must stay down before publishing oper down (ms)
+    #[serde(default = "default_debounce_down_hold_ms")]
+    pub down_hold_ms: u64,
+
+    /// Per-port carrier flaps per minute that trigger the flap alert
+    #[serde(default = "default_flap_alert_threshold")]
+    pub flap_alert_threshold_per_minute: u32,
+}
+
 /// Export format for metrics
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -132,6 +152,14 @@ pub struct MetricsConfig {
     /// Storage path for metrics files
     #[serde(default = "default_metrics_storage_path")]
     pub storage_path: String,
+
+    /// Bucket bounds in seconds of `portsyncd_port_event_latency_seconds`
+    #[serde(default = "default_port_latency_buckets")]
+    pub port_latency_buckets: Vec<f64>,
+
+    /// Distinct `port` labels exported before aggregating into "other"
+    #[serde(default = "default_max_port_labels")]
+    pub max_port_labels: usize,
 }
 
 /// Complete portsyncd configuration
@@ -152,6 +180,10 @@ pub struct PortsyncConfig {
     /// Metrics configuration (Week 4)
     #[serde(default)]
     pub metrics: MetricsConfig,
+
+    /// Carrier debounce configuration
+    #[serde(default)]
+    pub debounce: DebounceConfig,
 }
 
 // Default functions
@@ -215,6 +247,18 @@ fn default_watchdog_interval() -> u64 {
     15
 }
 
+fn default_debounce_up_hold_ms() -> u64 {
+    200
+}
+
+fn default_debounce_down_hold_ms() -> u64 {
+    0 // Report carrier loss immediately
+}
+
+fn default_flap_alert_threshold() -> u32 {
+    10
+}
+
 fn default_metrics_enabled() -> bool {
     true
 }
@@ -235,6 +279,14 @@ fn default_metrics_storage_path() -> String {
     "/var/lib/sonic/portsyncd/metrics".to_string()
 }
 
+fn default_port_latency_buckets() -> Vec<f64> {
+    DEFAULT_PORT_LATENCY_BUCKETS.to_vec()
+}
+
+fn default_max_port_labels() -> usize {
+    DEFAULT_MAX_PORT_LABELS
+}
+
 // Default implementations
 impl Default for DatabaseConfig {
     fn default() -> Self {
@@ -272,6 +324,45 @@ impl Default for HealthConfig {
     }
 }
 
+impl Default for DebounceConfig {
+    fn default() -> Self {
+        Self {
+            up_hold_ms: default_debounce_up_hold_ms(),
+            down_hold_ms: default_debounce_down_hold_ms(),
+            flap_alert_threshold_per_minute: default_flap_alert_threshold(),
+        }
+    }
+}
+
+impl DebounceConfig {
+    /// Get up hold interval as Duration
+    pub fn up_hold(&self) -> Duration {
+        Duration::from_millis(self.up_hold_ms)
+    }
+
+    /// Get down hold interval as Duration
+    pub fn down_hold(&self) -> Duration {
+        Duration::from_millis(self.down_hold_ms)
+    }
+
+    /// Validate debounce configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.up_hold_ms > 60_000 || self.down_hold_ms > 60_000 {
+            return Err(PortsyncError::Configuration(
+                "debounce hold intervals must be <= 60000 ms".to_string(),
+            ));
+        }
+
+        if self.flap_alert_threshold_per_minute == 0 {
+            return Err(PortsyncError::Configuration(
+                "debounce flap_alert_threshold_per_minute must be > 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for MetricsConfig {
     fn default() -> Self {
         Self {
@@ -281,6 +372,8 @@ impl Default for MetricsConfig {
             max_file_size_mb: default_metrics_max_file_size(),
             export_format: MetricsExportFormat::default(),
             storage_path: default_metrics_storage_path(),
+            port_latency_buckets: default_port_latency_buckets(),
+            max_port_labels: default_max_port_labels(),
         }
     }
 }
@@ -306,6 +399,23 @@ impl MetricsConfig {
             ));
         }
 
+        if self.port_latency_buckets.is_empty()
+            || self
+                .port_latency_buckets
+                .iter()
+                .any(|b| !b.is_finite() || *b <= 0.0)
+        {
+            return Err(PortsyncError::Configuration(
+                "metrics port_latency_buckets must be non-empty, finite and > 0".to_string(),
+            ));
+        }
+
+        if self.max_port_labels == 0 {
+            return Err(PortsyncError::Configuration(
+                "metrics max_port_labels must be > 0".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -400,6 +510,8 @@ impl PortsyncConfig {
         // Validate metrics config
         self.metrics.validate()?;
 
+        self.debounce.validate()?;
+
         Ok(())
     }
 }
@@ -522,6 +634,8 @@ max_event_queue = 2000
         assert_eq!(config.max_file_size_mb, 100);
         assert_eq!(config.export_format, MetricsExportFormat::Prometheus);
         assert_eq!(config.storage_path, "/var/lib/sonic/portsyncd/metrics");
+        assert_eq!(config.port_latency_buckets, DEFAULT_PORT_LATENCY_BUCKETS);
+        assert_eq!(config.max_port_labels, DEFAULT_MAX_PORT_LABELS);
     }
 
     #[test]
@@ -557,6 +671,27 @@ max_event_queue = 2000
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_metrics_config_validate_port_labels() {
+        let config = MetricsConfig {
+            max_port_labels: 0,
+            ..MetricsConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = MetricsConfig {
+            port_latency_buckets: vec![0.01, -1.0],
+            ..MetricsConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = MetricsConfig {
+            port_latency_buckets: Vec::new(),
+            ..MetricsConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_portsyncd_config_validate_includes_metrics() {
         let mut config = PortsyncConfig::default();
@@ -581,6 +716,8 @@ retention_days = 60
 max_file_size_mb = 200
 export_format = "json"
 storage_path = "/custom/path/metrics"
+port_latency_buckets = [0.01, 0.1, 1.0]
+max_port_labels = 64
 "#;
         let config: PortsyncConfig = toml::from_str(toml_str).unwrap();
         assert!(config.metrics.enabled);
@@ -589,5 +726,36 @@ storage_path = "/custom/path/metrics"
         assert_eq!(config.metrics.max_file_size_mb, 200);
         assert_eq!(config.metrics.export_format, MetricsExportFormat::Json);
         assert_eq!(config.metrics.storage_path, "/custom/path/metrics");
+        assert_eq!(config.metrics.port_latency_buckets, vec![0.01, 0.1, 1.0]);
+        assert_eq!(config.metrics.max_port_labels, 64);
+    }
+
+    #[test]
+    fn test_debounce_config_defaults() {
+        let config = DebounceConfig::default();
+        assert_eq!(config.up_hold(), Duration::from_millis(200));
+        assert_eq!(config.down_hold(), Duration::ZERO);
+        assert_eq!(config.flap_alert_threshold_per_minute, 10);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_debounce_config_toml_parsing() {
+        let toml_str = r#"
+            [debounce]
+            up_hold_ms = 500
+            down_hold_ms = 50
+        "#;
+        let config: PortsyncConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.debounce.up_hold_ms, 500);
+        assert_eq!(config.debounce.down_hold_ms, 50);
+        assert_eq!(config.debounce.flap_alert_threshold_per_minute, 10);
+    }
+
+    #[test]
+    fn test_debounce_config_validate_rejects_zero_threshold() {
+        let mut config = PortsyncConfig::default();
+        config.debounce.flap_alert_threshold_per_minute = 0;
+        assert!(config.validate().is_err());
     }
 }
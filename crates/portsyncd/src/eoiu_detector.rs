@@ -14,10 +14,18 @@
 //! the kernel has finished sending all initial port state and it's safe to accept
 //! APP_DB updates again.
 //!
+//! ## LAG Readiness
+//!
+//! PortChannel netdevs are created by teamd, not by the kernel dump. When the
+//! set of configured PortChannels is registered with `expect_lags`, an EOIU
+//! seen before all of them exist is deferred until the last one appears.
+//!
 //! NIST 800-53 SC-24: Fail-secure - if EOIU detection fails, keep initial sync locked
 //!
 //! Phase 6 Week 2 implementation.
 
+use std::collections::HashSet;
+
 /// EOIU detection state machine
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EoiuDetectionState {
@@ -35,6 +43,10 @@ pub struct EoiuDetector {
     state: EoiuDetectionState,
     messages_seen: u32,
     dumped_interfaces: u32,
+    /// Configured PortChannels whose netdev has not appeared yet
+    pending_lags: HashSet<String>,
+    /// EOIU arrived while LAGs were still pending
+    eoiu_deferred: bool,
 }
 
 impl EoiuDetector {
@@ -44,6 +56,8 @@ impl EoiuDetector {
             state: EoiuDetectionState::Waiting,
             messages_seen: 0,
             dumped_interfaces: 0,
+            pending_lags: HashSet::new(),
+            eoiu_deferred: false,
         }
     }
 
@@ -82,6 +96,15 @@ impl EoiuDetector {
         let is_eoiu = ifi_change == 0;
 
         if is_eoiu && self.state == EoiuDetectionState::Waiting {
+            if !self.pending_lags.is_empty() {
+                self.eoiu_deferred = true;
+                eprintln!(
+                    "portsyncd: EOIU deferred on interface '{}', waiting for {} LAG(s)",
+                    interface_name,
+                    self.pending_lags.len()
+                );
+                return false;
+            }
             self.state = EoiuDetectionState::Detected;
             eprintln!(
                 "portsyncd: EOIU signal detected on interface '{}' (messages_seen={})",
@@ -109,6 +132,40 @@ impl EoiuDetector {
         self.state = EoiuDetectionState::Waiting;
         self.messages_seen = 0;
         self.dumped_interfaces = 0;
+        self.pending_lags.clear();
+        self.eoiu_deferred = false;
+    }
+
+    /// Register configured PortChannels that must exist before EOIU
+    pub fn expect_lags(&mut self, lag_names: impl IntoIterator<Item = String>) {
+        if self.state == EoiuDetectionState::Waiting {
+            self.pending_lags.extend(lag_names);
+        }
+    }
+
+    /// Record that a PortChannel netdev exists
+    ///
+    /// Returns true if this completes a deferred EOIU.
+    pub fn mark_lag_ready(&mut self, lag_name: &str) -> bool {
+        if !self.pending_lags.remove(lag_name) || !self.pending_lags.is_empty() {
+            return false;
+        }
+
+        if self.eoiu_deferred && self.state == EoiuDetectionState::Waiting {
+            self.state = EoiuDetectionState::Detected;
+            eprintln!(
+                "portsyncd: EOIU signal detected after LAG '{}' became ready",
+                lag_name
+            );
+            return true;
+        }
+
+        false
+    }
+
+    /// Get number of configured PortChannels not yet seen
+    pub fn pending_lag_count(&self) -> usize {
+        self.pending_lags.len()
     }
 
     /// Increment dumped interfaces counter
@@ -245,4 +302,42 @@ mod tests {
         assert_eq!(detector.state(), EoiuDetectionState::Waiting);
         assert!(!detector.is_detected());
     }
+
+    #[test]
+    fn test_eoiu_deferred_until_lags_ready() {
+        let mut detector = EoiuDetector::new();
+        detector.expect_lags(vec![
+            "PortChannel0001".to_string(),
+            "PortChannel0002".to_string(),
+        ]);
+
+        assert!(!detector.check_eoiu("lo", 0, 0));
+        assert_eq!(detector.state(), EoiuDetectionState::Waiting);
+
+        assert!(!detector.mark_lag_ready("PortChannel0001"));
+        assert_eq!(detector.pending_lag_count(), 1);
+        assert!(detector.mark_lag_ready("PortChannel0002"));
+        assert!(detector.is_detected());
+    }
+
+    #[test]
+    fn test_lags_ready_before_eoiu() {
+        let mut detector = EoiuDetector::new();
+        detector.expect_lags(vec!["PortChannel0001".to_string()]);
+
+        assert!(!detector.mark_lag_ready("PortChannel0001"));
+        assert!(!detector.is_detected());
+        assert!(detector.check_eoiu("lo", 0, 0));
+    }
+
+    #[test]
+    fn test_reset_clears_pending_lags() {
+        let mut detector = EoiuDetector::new();
+        detector.expect_lags(vec!["PortChannel0001".to_string()]);
+        detector.check_eoiu("lo", 0, 0);
+        detector.reset();
+
+        assert_eq!(detector.pending_lag_count(), 0);
+        assert!(detector.check_eoiu("lo", 0, 0));
+    }
 }
@@ -0,0 +1,331 @@
+//! PortChannel (team/bond) netdev tracking
+//!
+//! teamd creates a team master netdev per PortChannel and enslaves the
+//! member ports to it. The kernel reports both through RTM_NEWLINK: the
+//! master carries IFLA_INFO_KIND "team" (or "bond"), and each member carries
+//! IFLA_MASTER with the master's ifindex. This module mirrors that topology
+//! into STATE_DB:
+//!
+//! - `LAG_TABLE|<lag>`: master oper/admin status and MTU
+//! - `LAG_MEMBER_TABLE|<lag>|<port>`: member carrier state
+//!
+//! Members can be reported before their master (the initial dump is in
+//! ifindex order), so membership is keyed by master ifindex and published
+//! once the master is known.
+
+use crate::config::DatabaseAdapter;
+use crate::error::Result;
+use crate::link_event::LinkEvent;
+use crate::port_sync::{LinkStatus, PortLinkState};
+use std::collections::{BTreeMap, HashMap};
+
+/// STATE_DB table for PortChannel state
+pub const STATE_LAG_TABLE: &str = "LAG_TABLE";
+/// STATE_DB table for PortChannel member state
+pub const STATE_LAG_MEMBER_TABLE: &str = "LAG_MEMBER_TABLE";
+
+/// Tracked PortChannel master netdev
+#[derive(Clone, Debug)]
+struct LagMaster {
+    name: String,
+    oper: LinkStatus,
+}
+
+/// Tracked PortChannel member
+#[derive(Clone, Debug)]
+struct LagMember {
+    master_ifindex: u32,
+    oper: LinkStatus,
+}
+
+/// Tracks PortChannel masters and their members
+#[derive(Debug, Default)]
+pub struct LagTracker {
+    /// Masters by ifindex
+    masters: HashMap<u32, LagMaster>,
+    /// Members by port name
+    members: BTreeMap<String, LagMember>,
+}
+
+impl LagTracker {
+    /// Create empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if a link event describes a PortChannel master netdev
+    pub fn is_lag_master_event(event: &LinkEvent) -> bool {
+        event.is_lag_master() && event.ifname.starts_with("PortChannel")
+    }
+
+    /// Check if a PortChannel master is currently present
+    pub fn has_lag(&self, name: &str) -> bool {
+        self.masters.values().any(|master| master.name == name)
+    }
+
+    /// Get oper status of a PortChannel master
+    pub fn lag_oper_status(&self, name: &str) -> Option<&LinkStatus> {
+        self.masters
+            .values()
+            .find(|master| master.name == name)
+            .map(|master| &master.oper)
+    }
+
+    /// Get the PortChannel a port is enslaved to, if its master is known
+    pub fn master_of(&self, port: &str) -> Option<&str> {
+        self.members
+            .get(port)
+            .and_then(|member| self.masters.get(&member.master_ifindex))
+            .map(|master| master.name.as_str())
+    }
+
+    /// Get members of a PortChannel, sorted by name
+    pub fn members_of(&self, lag: &str) -> Vec<String> {
+        self.members
+            .iter()
+            .filter(|(_, member)| {
+                self.masters
+                    .get(&member.master_ifindex)
+                    .is_some_and(|master| master.name == lag)
+            })
+            .map(|(port, _)| port.clone())
+            .collect()
+    }
+
+    /// Handle RTM_NEWLINK / RTM_DELLINK for a PortChannel master
+    pub async fn handle_master_event(
+        &mut self,
+        event: &LinkEvent,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        if !event.is_new_link() {
+            return self.remove_master(event.ifindex, state_db).await;
+        }
+
+        let state = PortLinkState::new(
+            event.ifname.clone(),
+            event.oper.clone(),
+            event.admin.clone(),
+            event.mtu.unwrap_or(9100),
+        );
+        state_db
+            .hset(&lag_key(&event.ifname), &state.to_field_values())
+            .await?;
+
+        let is_new = self
+            .masters
+            .insert(
+                event.ifindex,
+                LagMaster {
+                    name: event.ifname.clone(),
+                    oper: event.oper.clone(),
+                },
+            )
+            .is_none();
+
+        // Members reported before their master can be published now
+        if is_new {
+            let waiting: Vec<(String, LinkStatus)> = self
+                .members
+                .iter()
+                .filter(|(_, member)| member.master_ifindex == event.ifindex)
+                .map(|(port, member)| (port.clone(), member.oper.clone()))
+                .collect();
+            for (port, oper) in waiting {
+                write_member(&event.ifname, &port, &oper, state_db).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update membership and carrier state of a front-panel port
+    ///
+    /// `master_ifindex` is the port's IFLA_MASTER; None means the port is
+    /// not (or no longer) enslaved.
+    pub async fn handle_member_event(
+        &mut self,
+        port: &str,
+        master_ifindex: Option<u32>,
+        oper: LinkStatus,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        let previous = self.members.get(port).cloned();
+
+        if let Some(prev) = &previous
+            && Some(prev.master_ifindex) != master_ifindex
+        {
+            // Released from (or moved off) its previous PortChannel
+            self.members.remove(port);
+            if let Some(master) = self.masters.get(&prev.master_ifindex) {
+                state_db.delete(&member_key(&master.name, port)).await?;
+            }
+        }
+
+        let Some(master_ifindex) = master_ifindex else {
+            return Ok(());
+        };
+
+        let unchanged =
+            previous.is_some_and(|prev| prev.master_ifindex == master_ifindex && prev.oper == oper);
+        self.members.insert(
+            port.to_string(),
+            LagMember {
+                master_ifindex,
+                oper: oper.clone(),
+            },
+        );
+
+        if !unchanged && let Some(master) = self.masters.get(&master_ifindex) {
+            write_member(&master.name, port, &oper, state_db).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Update carrier state of an already-enslaved port
+    pub async fn update_member_oper(
+        &mut self,
+        port: &str,
+        oper: LinkStatus,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        match self.members.get(port) {
+            Some(member) => {
+                let master_ifindex = member.master_ifindex;
+                self.handle_member_event(port, Some(master_ifindex), oper, state_db)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Forget a port whose netdev was deleted
+    pub async fn remove_member(
+        &mut self,
+        port: &str,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        self.handle_member_event(port, None, LinkStatus::Down, state_db)
+            .await
+    }
+
+    /// Remove a deleted master and every member entry under it
+    async fn remove_master(
+        &mut self,
+        ifindex: u32,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        let Some(master) = self.masters.remove(&ifindex) else {
+            return Ok(());
+        };
+
+        let members: Vec<String> = self
+            .members
+            .iter()
+            .filter(|(_, member)| member.master_ifindex == ifindex)
+            .map(|(port, _)| port.clone())
+            .collect();
+        for port in members {
+            self.members.remove(&port);
+            state_db.delete(&member_key(&master.name, &port)).await?;
+        }
+
+        state_db.delete(&lag_key(&master.name)).await?;
+        Ok(())
+    }
+}
+
+fn lag_key(lag: &str) -> String {
+    format!("{}|{}", STATE_LAG_TABLE, lag)
+}
+
+fn member_key(lag: &str, port: &str) -> String {
+    format!("{}|{}|{}", STATE_LAG_MEMBER_TABLE, lag, port)
+}
+
+async fn write_member(
+    lag: &str,
+    port: &str,
+    oper: &LinkStatus,
+    state_db: &mut dyn DatabaseAdapter,
+) -> Result<()> {
+    let field_values = vec![
+        ("state".to_string(), "ok".to_string()),
+        ("netdev_oper_status".to_string(), oper.as_str().to_string()),
+    ];
+    state_db.hset(&member_key(lag, port), &field_values).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DatabaseConnection;
+    use crate::link_event::{
+        IFF_LOWER_UP, IFF_UP, RTM_NEWLINK, encode_lag_link_message, parse_link_messages,
+    };
+
+    fn master_event(name: &str, kind: &str) -> LinkEvent {
+        let buffer = encode_lag_link_message(
+            RTM_NEWLINK,
+            100,
+            IFF_UP | IFF_LOWER_UP,
+            name,
+            Some(9100),
+            Some(kind),
+            None,
+        );
+        parse_link_messages(&buffer).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_is_lag_master_event() {
+        assert!(LagTracker::is_lag_master_event(&master_event(
+            "PortChannel0001",
+            "team"
+        )));
+        assert!(LagTracker::is_lag_master_event(&master_event(
+            "PortChannel0002",
+            "bond"
+        )));
+        assert!(!LagTracker::is_lag_master_event(&master_event(
+            "bond0", "bond"
+        )));
+        assert!(!LagTracker::is_lag_master_event(&master_event(
+            "PortChannel0003",
+            "vlan"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_master_written_with_port_fields() {
+        let mut tracker = LagTracker::new();
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        tracker
+            .handle_master_event(&master_event("PortChannel0001", "team"), &mut state_db)
+            .await
+            .unwrap();
+
+        let fields = state_db.hgetall("LAG_TABLE|PortChannel0001").await.unwrap();
+        assert_eq!(fields.get("state"), Some(&"ok".to_string()));
+        assert_eq!(fields.get("admin_status"), Some(&"up".to_string()));
+        assert_eq!(fields.get("mtu"), Some(&"9100".to_string()));
+        assert_eq!(
+            tracker.lag_oper_status("PortChannel0001"),
+            Some(&LinkStatus::Up)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_member_oper_ignores_non_members() {
+        let mut tracker = LagTracker::new();
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        tracker
+            .update_member_oper("Ethernet0", LinkStatus::Up, &mut state_db)
+            .await
+            .unwrap();
+        assert!(state_db.keys("*").await.unwrap().is_empty());
+    }
+}
@@ -8,10 +8,13 @@
 
 pub mod alerting;
 pub mod audit_integration;
+pub mod carrier_debounce;
 pub mod config;
 pub mod config_file;
 pub mod eoiu_detector;
 pub mod error;
+pub mod lag_tracker;
+pub mod link_event;
 pub mod metrics;
 pub mod metrics_exporter;
 pub mod metrics_server;
@@ -27,19 +30,22 @@ pub mod warm_restart;
 
 pub use alerting::{
     Alert, AlertAction, AlertCondition, AlertRule, AlertSeverity, AlertState, AlertingEngine,
-    create_default_alert_rules,
+    MAX_PORT_FLAPS_PER_MINUTE, create_default_alert_rules, create_port_flap_alert_rule,
 };
 pub use audit_integration::{
     audit_database_operation, audit_error, audit_port_config_change, audit_port_init,
     audit_port_init_done, audit_port_state_change, audit_shutdown, init_portsyncd_auditing,
 };
+pub use carrier_debounce::{CarrierDebouncer, DebounceOutcome};
 pub use config::*;
-pub use config_file::{HealthConfig, PerformanceConfig, PortsyncConfig};
+pub use config_file::{DebounceConfig, HealthConfig, PerformanceConfig, PortsyncConfig};
 pub use eoiu_detector::{EoiuDetectionState, EoiuDetector};
 pub use error::*;
+pub use lag_tracker::LagTracker;
+pub use link_event::{LinkEvent, build_link_dump_request, parse_link_messages};
 pub use metrics::MetricsCollector;
-pub use metrics_exporter::PrometheusExporter;
-pub use metrics_server::{MetricsServer, MetricsServerConfig, spawn_metrics_server};
+pub use metrics_exporter::{PortMetrics, PrometheusExporter};
+pub use metrics_server::{HealthProbes, MetricsServer, MetricsServerConfig, spawn_metrics_server};
 pub use netlink_socket::NetlinkSocket;
 pub use performance::{BenchmarkConfig, BenchmarkResult, PerformanceMetrics};
 pub use port_sync::*;
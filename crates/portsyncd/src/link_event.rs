@@ -0,0 +1,525 @@
+//! Typed kernel link events decoded from rtnetlink messages
+//!
+//! The kernel reports link changes on the RTNLGRP_LINK multicast group as
+//! RTM_NEWLINK / RTM_DELLINK messages, and answers an RTM_GETLINK dump
+//! request with one RTM_NEWLINK per interface followed by NLMSG_DONE.
+//! This module decodes those byte buffers into [`LinkEvent`] values without
+//! depending on a live socket, so the same parser serves the daemon's
+//! receive path and the unit tests' canned buffers.
+//!
+//! NIST 800-53 Rev5 [SI-10]: Information Input Validation - malformed or
+//! truncated kernel messages are rejected rather than partially applied
+
+use crate::error::{PortsyncError, Result};
+use crate::port_sync::{LinkStatus, NetlinkEventType};
+
+/// Netlink message header length (struct nlmsghdr)
+const NLMSG_HDRLEN: usize = 16;
+/// Interface info header length (struct ifinfomsg)
+const IFINFOMSG_LEN: usize = 16;
+/// Route attribute header length (struct rtattr)
+const RTA_HDRLEN: usize = 4;
+
+/// NLMSG_NOOP: message to be ignored
+const NLMSG_NOOP: u16 = 1;
+/// NLMSG_ERROR: error or ACK
+const NLMSG_ERROR: u16 = 2;
+/// NLMSG_DONE: end of a multipart dump
+const NLMSG_DONE: u16 = 3;
+
+/// RTM_NEWLINK message type
+pub const RTM_NEWLINK: u16 = 16;
+/// RTM_DELLINK message type
+pub const RTM_DELLINK: u16 = 17;
+/// RTM_GETLINK message type
+pub const RTM_GETLINK: u16 = 18;
+
+/// Legacy multicast group mask for RTNLGRP_LINK
+pub const RTMGRP_LINK: u32 = 0x1;
+
+/// NLM_F_REQUEST: message is a request
+const NLM_F_REQUEST: u16 = 0x1;
+/// NLM_F_DUMP: NLM_F_ROOT | NLM_F_MATCH
+const NLM_F_DUMP: u16 = 0x300;
+
+/// IFLA_IFNAME attribute: interface name
+const IFLA_IFNAME: u16 = 3;
+/// IFLA_MTU attribute: interface MTU
+const IFLA_MTU: u16 = 4;
+/// IFLA_MASTER attribute: ifindex of the enslaving master device
+const IFLA_MASTER: u16 = 10;
+/// IFLA_LINKINFO attribute: nested link type information
+const IFLA_LINKINFO: u16 = 18;
+/// IFLA_INFO_KIND attribute (nested in IFLA_LINKINFO): link type name
+const IFLA_INFO_KIND: u16 = 1;
+
+/// Interface is administratively up
+pub const IFF_UP: u32 = 0x1;
+/// Driver signals L1 up (carrier)
+pub const IFF_LOWER_UP: u32 = 0x10000;
+
+/// Link change decoded from an RTM_NEWLINK or RTM_DELLINK message
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkEvent {
+    /// Event type (RTM_NEWLINK or RTM_DELLINK)
+    pub event_type: NetlinkEventType,
+    /// Interface name (IFLA_IFNAME)
+    pub ifname: String,
+    /// Kernel interface index (ifi_index)
+    pub ifindex: u32,
+    /// Administrative status (IFF_UP)
+    pub admin: LinkStatus,
+    /// Operational status (IFF_LOWER_UP)
+    pub oper: LinkStatus,
+    /// MTU (IFLA_MTU), if reported
+    pub mtu: Option<u32>,
+    /// Speed in Mb/s, if known
+    ///
+    /// rtnetlink does not carry link speed; the socket layer fills this in
+    /// from the netdev when one is available.
+    pub speed: Option<u32>,
+    /// Raw interface flags (ifi_flags)
+    pub flags: u32,
+    /// Changed-flags mask (ifi_change), used for EOIU detection
+    pub change: u32,
+    /// Link type (IFLA_INFO_KIND), e.g. "team" or "bond"
+    pub kind: Option<String>,
+    /// Ifindex of the master this link is enslaved to (IFLA_MASTER)
+    pub master_ifindex: Option<u32>,
+}
+
+impl LinkEvent {
+    /// Check if this is an RTM_NEWLINK event
+    pub fn is_new_link(&self) -> bool {
+        self.event_type == NetlinkEventType::NewLink
+    }
+
+    /// Check if this link is a team or bond master device
+    pub fn is_lag_master(&self) -> bool {
+        matches!(self.kind.as_deref(), Some("team") | Some("bond"))
+    }
+}
+
+/// Parse a receive buffer that may hold several netlink messages
+///
+/// Link messages are decoded in order; NLMSG_NOOP, ACKs and other message
+/// types are skipped, and NLMSG_DONE ends the batch. A non-zero NLMSG_ERROR
+/// or a truncated message fails the whole buffer.
+pub fn parse_link_messages(buffer: &[u8]) -> Result<Vec<LinkEvent>> {
+    let mut events = Vec::new();
+    let mut offset = 0;
+
+    while offset + NLMSG_HDRLEN <= buffer.len() {
+        let msg_len = read_u32(buffer, offset) as usize;
+        let msg_type = read_u16(buffer, offset + 4);
+
+        if msg_len < NLMSG_HDRLEN || offset + msg_len > buffer.len() {
+            return Err(PortsyncError::Netlink(format!(
+                "Truncated netlink message at offset {} (length {})",
+                offset, msg_len
+            )));
+        }
+
+        let payload = &buffer[offset + NLMSG_HDRLEN..offset + msg_len];
+        match msg_type {
+            NLMSG_DONE => break,
+            NLMSG_NOOP => {}
+            NLMSG_ERROR => {
+                if payload.len() < 4 {
+                    return Err(PortsyncError::Netlink(
+                        "Truncated NLMSG_ERROR payload".to_string(),
+                    ));
+                }
+                let errno = read_u32(payload, 0) as i32;
+                if errno != 0 {
+                    return Err(PortsyncError::Netlink(format!(
+                        "Kernel returned netlink error {}",
+                        -errno
+                    )));
+                }
+            }
+            RTM_NEWLINK => events.push(parse_ifinfomsg(payload, NetlinkEventType::NewLink)?),
+            RTM_DELLINK => events.push(parse_ifinfomsg(payload, NetlinkEventType::DelLink)?),
+            _ => {}
+        }
+
+        offset += align4(msg_len);
+    }
+
+    Ok(events)
+}
+
+/// Build an RTM_GETLINK dump request for all interfaces
+pub fn build_link_dump_request(seq: u32) -> Vec<u8> {
+    let len = NLMSG_HDRLEN + IFINFOMSG_LEN;
+    let mut buffer = Vec::with_capacity(len);
+    buffer.extend_from_slice(&(len as u32).to_ne_bytes());
+    buffer.extend_from_slice(&RTM_GETLINK.to_ne_bytes());
+    buffer.extend_from_slice(&(NLM_F_REQUEST | NLM_F_DUMP).to_ne_bytes());
+    buffer.extend_from_slice(&seq.to_ne_bytes());
+    buffer.extend_from_slice(&0u32.to_ne_bytes());
+    // ifinfomsg with AF_UNSPEC selects every interface
+    buffer.resize(len, 0);
+    buffer
+}
+
+/// Decode the ifinfomsg header and IFLA attributes of a link message
+fn parse_ifinfomsg(payload: &[u8], event_type: NetlinkEventType) -> Result<LinkEvent> {
+    if payload.len() < IFINFOMSG_LEN {
+        return Err(PortsyncError::Netlink(
+            "Truncated ifinfomsg header".to_string(),
+        ));
+    }
+
+    let ifindex = read_u32(payload, 4);
+    let flags = read_u32(payload, 8);
+    let change = read_u32(payload, 12);
+
+    let mut ifname = None;
+    let mut mtu = None;
+    let mut kind = None;
+    let mut master_ifindex = None;
+
+    for_each_attr(&payload[IFINFOMSG_LEN..], |rta_type, data| {
+        match rta_type {
+            IFLA_IFNAME => ifname = Some(read_string(data)),
+            IFLA_MTU if data.len() >= 4 => mtu = Some(read_u32(data, 0)),
+            IFLA_MASTER if data.len() >= 4 => master_ifindex = Some(read_u32(data, 0)),
+            IFLA_LINKINFO => {
+                for_each_attr(data, |info_type, info| {
+                    if info_type == IFLA_INFO_KIND {
+                        kind = Some(read_string(info));
+                    }
+                    Ok(())
+                })?;
+            }
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    let ifname = ifname.ok_or_else(|| {
+        PortsyncError::Netlink(format!("Link message for ifindex {} has no name", ifindex))
+    })?;
+
+    Ok(LinkEvent {
+        event_type,
+        ifname,
+        ifindex,
+        admin: status_from_flag(flags, IFF_UP),
+        oper: status_from_flag(flags, IFF_LOWER_UP),
+        mtu,
+        speed: None,
+        flags,
+        change,
+        kind,
+        master_ifindex: master_ifindex.filter(|&index| index != 0),
+    })
+}
+
+/// Walk a run of rtattr TLVs, rejecting truncated attributes
+fn for_each_attr(mut data: &[u8], mut visit: impl FnMut(u16, &[u8]) -> Result<()>) -> Result<()> {
+    while data.len() >= RTA_HDRLEN {
+        let rta_len = read_u16(data, 0) as usize;
+        let rta_type = read_u16(data, 2) & 0x3fff;
+
+        if rta_len < RTA_HDRLEN || rta_len > data.len() {
+            return Err(PortsyncError::Netlink(format!(
+                "Truncated link attribute {} (length {})",
+                rta_type, rta_len
+            )));
+        }
+
+        visit(rta_type, &data[RTA_HDRLEN..rta_len])?;
+        data = &data[align4(rta_len).min(data.len())..];
+    }
+    Ok(())
+}
+
+/// Read a NUL-terminated attribute string
+fn read_string(data: &[u8]) -> String {
+    let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).into_owned()
+}
+
+fn status_from_flag(flags: u32, flag: u32) -> LinkStatus {
+    if flags & flag != 0 {
+        LinkStatus::Up
+    } else {
+        LinkStatus::Down
+    }
+}
+
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn read_u16(buffer: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes([buffer[offset], buffer[offset + 1]])
+}
+
+fn read_u32(buffer: &[u8], offset: usize) -> u32 {
+    u32::from_ne_bytes([
+        buffer[offset],
+        buffer[offset + 1],
+        buffer[offset + 2],
+        buffer[offset + 3],
+    ])
+}
+
+/// Encode a link message the way the kernel does (test fixture builder)
+#[cfg(test)]
+pub(crate) fn encode_link_message(
+    msg_type: u16,
+    ifindex: u32,
+    flags: u32,
+    ifname: &str,
+    mtu: Option<u32>,
+) -> Vec<u8> {
+    encode_lag_link_message(msg_type, ifindex, flags, ifname, mtu, None, None)
+}
+
+/// Encode a link message carrying IFLA_LINKINFO kind and IFLA_MASTER
+#[cfg(test)]
+pub(crate) fn encode_lag_link_message(
+    msg_type: u16,
+    ifindex: u32,
+    flags: u32,
+    ifname: &str,
+    mtu: Option<u32>,
+    kind: Option<&str>,
+    master_ifindex: Option<u32>,
+) -> Vec<u8> {
+    let mut attrs = Vec::new();
+    let mut name = ifname.as_bytes().to_vec();
+    name.push(0);
+    push_attr(&mut attrs, IFLA_IFNAME, &name);
+    if let Some(mtu) = mtu {
+        push_attr(&mut attrs, IFLA_MTU, &mtu.to_ne_bytes());
+    }
+    if let Some(master) = master_ifindex {
+        push_attr(&mut attrs, IFLA_MASTER, &master.to_ne_bytes());
+    }
+    if let Some(kind) = kind {
+        let mut info = Vec::new();
+        let mut kind = kind.as_bytes().to_vec();
+        kind.push(0);
+        push_attr(&mut info, IFLA_INFO_KIND, &kind);
+        push_attr(&mut attrs, IFLA_LINKINFO, &info);
+    }
+
+    let len = NLMSG_HDRLEN + IFINFOMSG_LEN + attrs.len();
+    let mut buffer = Vec::with_capacity(len);
+    buffer.extend_from_slice(&(len as u32).to_ne_bytes());
+    buffer.extend_from_slice(&msg_type.to_ne_bytes());
+    buffer.extend_from_slice(&0u16.to_ne_bytes());
+    buffer.extend_from_slice(&0u32.to_ne_bytes());
+    buffer.extend_from_slice(&0u32.to_ne_bytes());
+    buffer.extend_from_slice(&[0, 0, 1, 0]); // AF_UNSPEC, pad, ARPHRD_ETHER
+    buffer.extend_from_slice(&ifindex.to_ne_bytes());
+    buffer.extend_from_slice(&flags.to_ne_bytes());
+    buffer.extend_from_slice(&0xffff_ffffu32.to_ne_bytes());
+    buffer.extend_from_slice(&attrs);
+    buffer
+}
+
+/// Encode an NLMSG_DONE terminator (test fixture builder)
+#[cfg(test)]
+pub(crate) fn encode_done_message() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(&20u32.to_ne_bytes());
+    buffer.extend_from_slice(&NLMSG_DONE.to_ne_bytes());
+    buffer.extend_from_slice(&0x2u16.to_ne_bytes()); // NLM_F_MULTI
+    buffer.extend_from_slice(&[0u8; 8]);
+    buffer.extend_from_slice(&0u32.to_ne_bytes());
+    buffer
+}
+
+#[cfg(test)]
+fn push_attr(buffer: &mut Vec<u8>, rta_type: u16, data: &[u8]) {
+    let rta_len = RTA_HDRLEN + data.len();
+    buffer.extend_from_slice(&(rta_len as u16).to_ne_bytes());
+    buffer.extend_from_slice(&rta_type.to_ne_bytes());
+    buffer.extend_from_slice(data);
+    buffer.resize(buffer.len() + align4(rta_len) - rta_len, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_newlink_up() {
+        let buffer = encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP | IFF_LOWER_UP,
+            "Ethernet0",
+            Some(9100),
+        );
+        let events = parse_link_messages(&buffer).unwrap();
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert!(event.is_new_link());
+        assert_eq!(event.ifname, "Ethernet0");
+        assert_eq!(event.ifindex, 5);
+        assert_eq!(event.admin, LinkStatus::Up);
+        assert_eq!(event.oper, LinkStatus::Up);
+        assert_eq!(event.mtu, Some(9100));
+        assert_eq!(event.speed, None);
+        assert_eq!(event.change, 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_parse_newlink_admin_up_carrier_down() {
+        let buffer = encode_link_message(RTM_NEWLINK, 6, IFF_UP, "Ethernet4", None);
+        let events = parse_link_messages(&buffer).unwrap();
+
+        assert_eq!(events[0].admin, LinkStatus::Up);
+        assert_eq!(events[0].oper, LinkStatus::Down);
+        assert_eq!(events[0].mtu, None);
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_canned_dellink_bytes() {
+        // RTM_DELLINK for "Ethernet8", ifindex 7, flags 0, as captured from the kernel
+        let buffer: [u8; 48] = [
+            0x30, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, // len 48, RTM_DELLINK
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // seq, pid
+            0x00, 0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00, // ifinfomsg: ether, ifindex 7
+            0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, // flags, change
+            0x0e, 0x00, 0x03, 0x00, b'E', b't', b'h', b'e', // IFLA_IFNAME (len 14)
+            b'r', b'n', b'e', b't', b'8', 0x00, 0x00, 0x00,
+        ];
+        let events = parse_link_messages(&buffer).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, NetlinkEventType::DelLink);
+        assert_eq!(events[0].ifname, "Ethernet8");
+        assert_eq!(events[0].ifindex, 7);
+        assert_eq!(events[0].admin, LinkStatus::Down);
+    }
+
+    #[test]
+    fn test_parse_dump_stops_at_done() {
+        let mut buffer = encode_link_message(RTM_NEWLINK, 1, IFF_UP, "lo", Some(65536));
+        buffer.extend(encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP,
+            "Ethernet0",
+            Some(9100),
+        ));
+        buffer.extend(encode_done_message());
+        buffer.extend(encode_link_message(
+            RTM_NEWLINK,
+            6,
+            IFF_UP,
+            "Ethernet4",
+            Some(9100),
+        ));
+
+        let events = parse_link_messages(&buffer).unwrap();
+        let names: Vec<&str> = events.iter().map(|e| e.ifname.as_str()).collect();
+        assert_eq!(names, vec!["lo", "Ethernet0"]);
+    }
+
+    #[test]
+    fn test_parse_skips_unrelated_messages() {
+        // RTM_NEWADDR (20) shares the socket but is not a link message
+        let mut buffer = encode_link_message(20, 5, 0, "Ethernet0", None);
+        buffer.extend(encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP,
+            "Ethernet0",
+            None,
+        ));
+
+        let events = parse_link_messages(&buffer).unwrap();
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_truncated_message_rejected() {
+        let buffer = encode_link_message(RTM_NEWLINK, 5, IFF_UP, "Ethernet0", Some(9100));
+        assert!(parse_link_messages(&buffer[..buffer.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn test_parse_missing_ifname_rejected() {
+        let mut buffer = encode_link_message(RTM_NEWLINK, 5, IFF_UP, "", None);
+        // Drop the IFLA_IFNAME attribute entirely
+        buffer.truncate(NLMSG_HDRLEN + IFINFOMSG_LEN);
+        buffer[..4].copy_from_slice(&((NLMSG_HDRLEN + IFINFOMSG_LEN) as u32).to_ne_bytes());
+        assert!(parse_link_messages(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_parse_netlink_error_rejected() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&20u32.to_ne_bytes());
+        buffer.extend_from_slice(&NLMSG_ERROR.to_ne_bytes());
+        buffer.extend_from_slice(&[0u8; 10]);
+        buffer.extend_from_slice(&(-22i32).to_ne_bytes());
+        assert!(parse_link_messages(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_build_link_dump_request() {
+        let request = build_link_dump_request(42);
+        assert_eq!(request.len(), 32);
+        assert_eq!(read_u32(&request, 0), 32);
+        assert_eq!(read_u16(&request, 4), RTM_GETLINK);
+        assert_eq!(read_u16(&request, 6), NLM_F_REQUEST | NLM_F_DUMP);
+        assert_eq!(read_u32(&request, 8), 42);
+    }
+
+    #[test]
+    fn test_parse_team_master_kind() {
+        let buffer = encode_lag_link_message(
+            RTM_NEWLINK,
+            100,
+            IFF_UP | IFF_LOWER_UP,
+            "PortChannel0001",
+            Some(9100),
+            Some("team"),
+            None,
+        );
+        let event = &parse_link_messages(&buffer).unwrap()[0];
+
+        assert_eq!(event.kind.as_deref(), Some("team"));
+        assert!(event.is_lag_master());
+        assert_eq!(event.master_ifindex, None);
+    }
+
+    #[test]
+    fn test_parse_enslaved_member() {
+        let buffer = encode_lag_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP | IFF_LOWER_UP,
+            "Ethernet0",
+            Some(9100),
+            None,
+            Some(100),
+        );
+        let event = &parse_link_messages(&buffer).unwrap()[0];
+
+        assert!(!event.is_lag_master());
+        assert_eq!(event.master_ifindex, Some(100));
+        assert_eq!(event.mtu, Some(9100));
+    }
+
+    #[test]
+    fn test_parse_non_lag_kind() {
+        let buffer =
+            encode_lag_link_message(RTM_NEWLINK, 8, IFF_UP, "Vlan1000", None, Some("vlan"), None);
+        let event = &parse_link_messages(&buffer).unwrap()[0];
+
+        assert_eq!(event.kind.as_deref(), Some("vlan"));
+        assert!(!event.is_lag_master());
+    }
+}
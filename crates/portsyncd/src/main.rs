@@ -3,13 +3,18 @@
 //! Main entry point for the portsyncd daemon.
 //! Listens for kernel netlink events and synchronizes port status to SONiC databases.
 
+use sonic_portsyncd::production_features::HealthCheckConfig;
 use sonic_portsyncd::{
-    LinkSync, MetricsCollector, MetricsServer, MetricsServerConfig, PortsyncError, RedisAdapter,
-    audit_error, audit_port_init, audit_port_init_done, audit_shutdown, init_portsyncd_auditing,
-    load_port_config, send_port_config_done, send_port_init_done,
+    AlertState, AlertingEngine, HealthProbes, LinkSync, MAX_PORT_FLAPS_PER_MINUTE,
+    MetricsCollector, MetricsServer, MetricsServerConfig, NetlinkSocket,
+    PORT_CONFIG_REFRESH_INTERVAL, PortMetrics, PortsyncConfig, PortsyncError, RedisAdapter,
+    WarmRestartMetrics, audit_error, audit_port_init, audit_port_init_done, audit_shutdown,
+    configured_port_names, create_port_flap_alert_rule, init_portsyncd_auditing, load_port_config,
+    send_port_config_done, send_port_init_done,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use tokio::signal;
 
 #[tokio::main]
@@ -67,8 +72,13 @@ async fn run_daemon() -> Result<(), PortsyncError> {
         .map_err(|e| PortsyncError::Other(format!("Failed to initialize audit logging: {}", e)))?;
     eprintln!("portsyncd: NIST audit logging initialized");
 
-    // Setup signal handlers for graceful shutdown
+    // Setup signal handlers for graceful shutdown and config reload
     let shutdown = setup_signal_handlers();
+    let reload = setup_reload_handler();
+
+    // Load daemon configuration (debounce settings are hot-reloadable)
+    let config = PortsyncConfig::load()?;
+    config.validate()?;
 
     // Initialize metrics collector
     let metrics = Arc::new(
@@ -77,6 +87,12 @@ async fn run_daemon() -> Result<(), PortsyncError> {
     );
     eprintln!("portsyncd: Initialized metrics collector");
 
+    // Per-port labeled series, capped at metrics.max_port_labels
+    let port_metrics = PortMetrics::from(&config.metrics);
+
+    // Liveness/readiness state for /healthz and /readyz
+    let probes = HealthProbes::new(HealthCheckConfig::from(&config.health));
+
     // Spawn metrics server with mandatory mTLS on IPv6 [::1]:9090
     // Certificate paths can be configured via environment variables or config file
     let cert_path = std::env::var("PORTSYNCD_METRICS_CERT")
@@ -88,9 +104,13 @@ async fn run_daemon() -> Result<(), PortsyncError> {
 
     let metrics_server_handle = tokio::spawn({
         let metrics_clone = metrics.clone();
+        let probes_clone = probes.clone();
+        let port_metrics_clone = port_metrics.clone();
         async move {
             let config = MetricsServerConfig::new(cert_path, key_path, ca_cert_path);
-            let server = MetricsServer::new(config, metrics_clone)?;
+            let server = MetricsServer::new(config, metrics_clone)?
+                .with_health_probes(probes_clone)
+                .with_port_metrics(port_metrics_clone);
             server.start().await
         }
     });
@@ -98,23 +118,27 @@ async fn run_daemon() -> Result<(), PortsyncError> {
 
     // Connect to databases via Redis adapter
     #[cfg(not(test))]
-    let (config_db, mut app_db) = {
+    let (config_db, mut app_db, mut state_db) = {
         let mut c = RedisAdapter::config_db("127.0.0.1", 6379);
         let mut a = RedisAdapter::app_db("127.0.0.1", 6379);
+        let mut s = RedisAdapter::state_db("127.0.0.1", 6379);
         c.connect().await?;
         a.connect().await?;
-        (c, a)
+        s.connect().await?;
+        (c, a, s)
     };
 
     #[cfg(test)]
-    let (config_db, mut app_db) = {
+    let (config_db, mut app_db, mut state_db) = {
         (
             RedisAdapter::config_db("127.0.0.1", 6379),
             RedisAdapter::app_db("127.0.0.1", 6379),
+            RedisAdapter::state_db("127.0.0.1", 6379),
         )
     };
 
     eprintln!("portsyncd: Connected to databases");
+    metrics.set_redis_connected(true);
 
     // Load port configuration from CONFIG_DB
     let port_configs = load_port_config(&config_db, &mut app_db, false).await?;
@@ -136,23 +160,132 @@ async fn run_daemon() -> Result<(), PortsyncError> {
         link_sync.uninitialized_count()
     );
 
+    // Carrier debounce between kernel events and STATE_DB
+    link_sync.set_debounce_config(&config.debounce);
+    link_sync.set_metrics_collector((*metrics).clone());
+    link_sync.set_port_metrics(port_metrics.clone());
+
+    let mut alerting = AlertingEngine::new();
+    alerting.add_rule(create_port_flap_alert_rule(
+        config.debounce.flap_alert_threshold_per_minute,
+    ));
+    let alert_metrics = WarmRestartMetrics::new();
+    let mut flap_alert_firing = false;
+
     // Log port initialization start (NIST: AU-12, SI-4)
     audit_port_init(port_names.len());
 
-    // Main event loop - simulate receiving netlink events
-    // In production, this would connect to kernel netlink socket
+    // Subscribe to kernel link notifications, then dump existing links so
+    // the initial state is seeded before any change arrives
+    let mut netlink = NetlinkSocket::new()?;
+    netlink.connect()?;
+    netlink.request_link_dump()?;
+    metrics.set_netlink_connected(true);
+
+    // EOIU waits for configured PortChannels to be created by teamd
+    let lag_names: Vec<String> = config_db
+        .keys("PORTCHANNEL|*")
+        .await?
+        .into_iter()
+        .filter_map(|key| key.strip_prefix("PORTCHANNEL|").map(str::to_string))
+        .collect();
+    netlink.eoiu_detector_mut().expect_lags(lag_names);
+    eprintln!("portsyncd: Subscribed to RTNLGRP_LINK and requested link dump");
+
     eprintln!("portsyncd: Starting event processing loop");
 
+    let mut next_port_refresh = Instant::now() + PORT_CONFIG_REFRESH_INTERVAL;
+
     loop {
         // Check for shutdown signal
         if shutdown.load(Ordering::Relaxed) {
             eprintln!("portsyncd: Received shutdown signal");
             break;
         }
+        probes.record_loop_iteration();
+
+        let events = match netlink.receive_link_events() {
+            Ok(events) => {
+                metrics.set_netlink_connected(true);
+                events
+            }
+            Err(e) => {
+                metrics.set_netlink_connected(false);
+                eprintln!("portsyncd: Failed to receive link events: {}", e);
+                audit_error(&e.to_string(), "netlink_receive_failed");
+                Vec::new()
+            }
+        };
+
+        if events.is_empty() {
+            // Non-blocking socket has nothing pending; avoid a busy loop
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+
+        for event in &events {
+            let timer = metrics.start_event_latency();
+            let started = Instant::now();
+            match link_sync.handle_link_event(event, &mut state_db).await {
+                Ok(handled) => {
+                    metrics.record_event_success();
+                    if handled && link_sync.is_known_port(&event.ifname) {
+                        port_metrics
+                            .observe_event_latency(&event.ifname, started.elapsed().as_secs_f64());
+                    }
+                }
+                Err(e) => {
+                    metrics.record_event_failure();
+                    eprintln!(
+                        "portsyncd: Failed to update STATE_DB for {}: {}",
+                        event.ifname, e
+                    );
+                    audit_error(&e.to_string(), "state_db_update_failed");
+                }
+            }
+            drop(timer);
+        }
+
+        // Hot-reload debounce settings on SIGHUP
+        if reload.swap(false, Ordering::Relaxed) {
+            reload_debounce_config(&mut link_sync, &mut alerting);
+        }
+
+        // Publish debounced carrier transitions whose hold expired
+        if let Err(e) = link_sync.poll_debounced(&mut state_db).await {
+            eprintln!("portsyncd: Failed to publish debounced link state: {}", e);
+            audit_error(&e.to_string(), "state_db_update_failed");
+        }
 
-        // TODO: In production, receive actual netlink events from kernel socket
-        // For now, simulate a simple delay to prevent busy loop
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // Expire per-port metrics of ports removed from CONFIG_DB
+        if Instant::now() >= next_port_refresh {
+            next_port_refresh = Instant::now() + PORT_CONFIG_REFRESH_INTERVAL;
+            match configured_port_names(&config_db).await {
+                Ok(configured) => {
+                    let expired = port_metrics.retain_ports(&configured);
+                    if expired > 0 {
+                        eprintln!("portsyncd: Expired metrics of {} removed ports", expired);
+                    }
+                }
+                Err(e) => eprintln!("portsyncd: Failed to refresh CONFIG_DB ports: {}", e),
+            }
+        }
+
+        // Evaluate the carrier flap alert
+        let max_flaps = link_sync.debouncer().max_flaps_per_minute(Instant::now());
+        alerting.set_metric_value(MAX_PORT_FLAPS_PER_MINUTE, max_flaps as f64);
+        alerting.evaluate(&alert_metrics);
+        let firing = !alerting.alerts_by_state(AlertState::Firing).is_empty();
+        if firing && !flap_alert_firing {
+            eprintln!(
+                "portsyncd: ALERT: port carrier flapping {} times per minute",
+                max_flaps
+            );
+            audit_error(
+                &format!("{} carrier flaps per minute", max_flaps),
+                "port_flap_rate_high",
+            );
+        }
+        flap_alert_firing = firing;
 
         // Check if all ports have been initialized and send signal
         if link_sync.should_send_port_init_done() {
@@ -162,6 +295,7 @@ async fn run_daemon() -> Result<(), PortsyncError> {
                     metrics.record_event_success();
                     drop(timer);
                     link_sync.set_port_init_done();
+                    probes.set_reconciled(true);
                     eprintln!("portsyncd: Sent PortInitDone signal");
                     // Log port initialization completion (NIST: AU-12, SI-4)
                     audit_port_init_done();
@@ -205,6 +339,52 @@ fn setup_signal_handlers() -> Arc<AtomicBool> {
     shutdown_flag
 }
 
+/// Setup SIGHUP handler and return atomic flag for config reload requests
+fn setup_reload_handler() -> Arc<AtomicBool> {
+    let reload_flag = Arc::new(AtomicBool::new(false));
+    let reload_flag_clone = reload_flag.clone();
+
+    tokio::spawn(async move {
+        let Ok(mut hangup) = signal::unix::signal(signal::unix::SignalKind::hangup()) else {
+            eprintln!("portsyncd: Failed to install SIGHUP handler");
+            return;
+        };
+        while hangup.recv().await.is_some() {
+            eprintln!("portsyncd: Received SIGHUP, reloading configuration");
+            reload_flag_clone.store(true, Ordering::Relaxed);
+        }
+    });
+
+    reload_flag
+}
+
+/// Re-read debounce settings, keeping the current ones if the file is invalid
+fn reload_debounce_config(link_sync: &mut LinkSync, alerting: &mut AlertingEngine) {
+    let config = PortsyncConfig::load().and_then(|config| {
+        config.validate()?;
+        Ok(config)
+    });
+
+    match config {
+        Ok(config) => {
+            link_sync.set_debounce_config(&config.debounce);
+            alerting.add_rule(create_port_flap_alert_rule(
+                config.debounce.flap_alert_threshold_per_minute,
+            ));
+            eprintln!(
+                "portsyncd: Reloaded debounce settings (up {}ms, down {}ms)",
+                config.debounce.up_hold_ms, config.debounce.down_hold_ms
+            );
+        }
+        Err(e) => {
+            eprintln!(
+                "portsyncd: Config reload failed, keeping current settings: {}",
+                e
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
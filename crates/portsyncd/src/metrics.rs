@@ -156,6 +156,16 @@ impl MetricsCollector {
             .set(if connected { 1.0 } else { 0.0 });
     }
 
+    /// Check Redis connection status
+    pub fn is_redis_connected(&self) -> bool {
+        self.redis_connected.get() >= 1.0
+    }
+
+    /// Check netlink socket status
+    pub fn is_netlink_connected(&self) -> bool {
+        self.netlink_connected.get() >= 1.0
+    }
+
     /// Start event latency timer
     pub fn start_event_latency(&self) -> prometheus::HistogramTimer {
         self.event_latency_seconds.start_timer()
@@ -4,9 +4,26 @@
 //! Provides methods to convert WarmRestartMetrics to Prometheus exposition format
 //! and JSON for monitoring and alerting.
 //!
+//! Per-port series (oper state, flaps, event latency) are tracked by
+//! [`PortMetrics`]. The number of distinct `port` labels is capped; ports
+//! beyond the cap are folded into a single `port="other"` series, and a
+//! port's series are dropped once it is removed from CONFIG_DB.
+//!
 //! Phase 6 Week 4 implementation.
 
+use crate::config_file::MetricsConfig;
 use crate::warm_restart::WarmRestartMetrics;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// Default `port_event_latency_seconds` histogram buckets in seconds
+pub const DEFAULT_PORT_LATENCY_BUCKETS: [f64; 7] = [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0];
+
+/// Default cap on distinct `port` label values
+pub const DEFAULT_MAX_PORT_LABELS: usize = 512;
+
+/// Label value of the series aggregating ports beyond the cap
+pub const OTHER_PORT_LABEL: &str = "other";
 
 /// Prometheus metrics exporter for warm restart tracking
 #[derive(Debug, Clone)]
@@ -181,6 +198,83 @@ impl PrometheusExporter {
         output
     }
 
+    /// Export per-port metrics in Prometheus text format
+    ///
+    /// Produces `portsyncd_port_oper_state`, `portsyncd_port_flap_total`
+    /// and the `portsyncd_port_event_latency_seconds` histogram, one series
+    /// per labeled port plus the aggregated `port="other"` series once any
+    /// port has overflowed the label cap.
+    pub fn export_port_metrics(ports: &PortMetrics) -> String {
+        let Ok(inner) = ports.inner.lock() else {
+            return String::new();
+        };
+        let other = inner.other_in_use().then_some(&inner.other);
+        let mut output = String::new();
+
+        output.push_str(
+            "# HELP portsyncd_port_oper_state Port oper state (1=up, 0=down; up count when aggregated)\n",
+        );
+        output.push_str("# TYPE portsyncd_port_oper_state gauge\n");
+        for (port, series) in &inner.ports {
+            if let Some(up) = series.oper_up {
+                output.push_str(&format!(
+                    "portsyncd_port_oper_state{{port=\"{}\"}} {}\n",
+                    port,
+                    u8::from(up)
+                ));
+            }
+        }
+        if other.is_some() {
+            let up = inner
+                .overflow
+                .values()
+                .filter(|up| **up == Some(true))
+                .count();
+            output.push_str(&format!(
+                "portsyncd_port_oper_state{{port=\"{}\"}} {}\n",
+                OTHER_PORT_LABEL, up
+            ));
+        }
+
+        output.push_str("# HELP portsyncd_port_flap_total Carrier flaps per port\n");
+        output.push_str("# TYPE portsyncd_port_flap_total counter\n");
+        let labeled = inner.ports.iter().map(|(port, s)| (port.as_str(), s));
+        for (port, series) in labeled.clone().chain(other.map(|s| (OTHER_PORT_LABEL, s))) {
+            output.push_str(&format!(
+                "portsyncd_port_flap_total{{port=\"{}\"}} {}\n",
+                port, series.flaps
+            ));
+        }
+
+        output.push_str(
+            "# HELP portsyncd_port_event_latency_seconds Link event processing latency per port\n",
+        );
+        output.push_str("# TYPE portsyncd_port_event_latency_seconds histogram\n");
+        for (port, series) in labeled.chain(other.map(|s| (OTHER_PORT_LABEL, s))) {
+            let latency = &series.latency;
+            for (le, count) in inner.buckets.iter().zip(&latency.buckets) {
+                output.push_str(&format!(
+                    "portsyncd_port_event_latency_seconds_bucket{{port=\"{}\",le=\"{}\"}} {}\n",
+                    port, le, count
+                ));
+            }
+            output.push_str(&format!(
+                "portsyncd_port_event_latency_seconds_bucket{{port=\"{}\",le=\"+Inf\"}} {}\n",
+                port, latency.count
+            ));
+            output.push_str(&format!(
+                "portsyncd_port_event_latency_seconds_sum{{port=\"{}\"}} {}\n",
+                port, latency.sum
+            ));
+            output.push_str(&format!(
+                "portsyncd_port_event_latency_seconds_count{{port=\"{}\"}} {}\n",
+                port, latency.count
+            ));
+        }
+
+        output
+    }
+
     /// Export warm restart metrics in JSON format
     ///
     /// # Arguments
@@ -209,6 +303,206 @@ impl PrometheusExporter {
     }
 }
 
+/// Cumulative latency histogram for one port series
+#[derive(Debug, Clone, Default)]
+struct LatencyHistogram {
+    /// Cumulative count per configured bucket
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl LatencyHistogram {
+    fn new(bucket_count: usize) -> Self {
+        Self {
+            buckets: vec![0; bucket_count],
+            ..Default::default()
+        }
+    }
+
+    /// Count a sample falling into bucket `first_bucket` and every bucket above
+    fn observe(&mut self, first_bucket: usize, secs: f64) {
+        for count in self.buckets.iter_mut().skip(first_bucket) {
+            *count += 1;
+        }
+        self.sum += secs;
+        self.count += 1;
+    }
+}
+
+/// Metric values of one `port` label
+#[derive(Debug, Clone)]
+struct PortSeries {
+    oper_up: Option<bool>,
+    flaps: u64,
+    latency: LatencyHistogram,
+}
+
+impl PortSeries {
+    fn new(bucket_count: usize) -> Self {
+        Self {
+            oper_up: None,
+            flaps: 0,
+            latency: LatencyHistogram::new(bucket_count),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PortMetricsInner {
+    buckets: Vec<f64>,
+    max_labels: usize,
+    /// Ports holding their own label
+    ports: BTreeMap<String, PortSeries>,
+    /// Ports beyond the cap with their last oper state
+    overflow: BTreeMap<String, Option<bool>>,
+    /// Aggregated series of all overflow ports
+    other: PortSeries,
+}
+
+impl PortMetricsInner {
+    /// Series a port reports into, assigning a label slot on first sight
+    ///
+    /// A port that overflowed once stays in `other` until it is removed, so
+    /// its series never moves between labels.
+    fn series_mut(&mut self, port: &str) -> &mut PortSeries {
+        if !self.ports.contains_key(port) && !self.overflow.contains_key(port) {
+            if self.ports.len() < self.max_labels {
+                self.ports
+                    .insert(port.to_string(), PortSeries::new(self.buckets.len()));
+            } else {
+                self.overflow.insert(port.to_string(), None);
+            }
+        }
+
+        match self.ports.get_mut(port) {
+            Some(series) => series,
+            None => &mut self.other,
+        }
+    }
+
+    /// Check whether the `other` series has anything to report
+    ///
+    /// Once written, `other` keeps being exported so its counters stay
+    /// monotonic after the overflow ports are removed.
+    fn other_in_use(&self) -> bool {
+        !self.overflow.is_empty() || self.other.flaps > 0 || self.other.latency.count > 0
+    }
+}
+
+/// Per-port labeled metrics with a cardinality guard
+///
+/// Cloning shares the underlying series, so the handle held by the event
+/// loop and the one held by the metrics server see the same values.
+#[derive(Debug, Clone)]
+pub struct PortMetrics {
+    inner: Arc<Mutex<PortMetricsInner>>,
+}
+
+impl Default for PortMetrics {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_PORT_LATENCY_BUCKETS.to_vec(),
+            DEFAULT_MAX_PORT_LABELS,
+        )
+    }
+}
+
+impl From<&MetricsConfig> for PortMetrics {
+    fn from(config: &MetricsConfig) -> Self {
+        Self::new(config.port_latency_buckets.clone(), config.max_port_labels)
+    }
+}
+
+impl PortMetrics {
+    /// Create with latency bucket bounds (seconds) and a port label cap
+    ///
+    /// Non-finite bounds are dropped and the rest sorted and de-duplicated.
+    pub fn new(mut buckets: Vec<f64>, max_labels: usize) -> Self {
+        buckets.retain(|b| b.is_finite());
+        buckets.sort_by(f64::total_cmp);
+        buckets.dedup();
+
+        Self {
+            inner: Arc::new(Mutex::new(PortMetricsInner {
+                other: PortSeries::new(buckets.len()),
+                buckets,
+                max_labels,
+                ports: BTreeMap::new(),
+                overflow: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Record the published oper state of a port
+    pub fn set_oper_state(&self, port: &str, up: bool) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.series_mut(port);
+            if let Some(series) = inner.ports.get_mut(port) {
+                series.oper_up = Some(up);
+            } else if let Some(state) = inner.overflow.get_mut(port) {
+                *state = Some(up);
+            }
+        }
+    }
+
+    /// Count a carrier flap on a port
+    pub fn record_flap(&self, port: &str) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.series_mut(port).flaps += 1;
+        }
+    }
+
+    /// Observe the processing latency of a link event on a port
+    pub fn observe_event_latency(&self, port: &str, secs: f64) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let first_bucket = inner.buckets.partition_point(|bound| *bound < secs);
+            inner.series_mut(port).latency.observe(first_bucket, secs);
+        }
+    }
+
+    /// Expire a port's series after it was removed from CONFIG_DB
+    ///
+    /// A labeled port frees its slot for the next new port. An overflow
+    /// port stops counting towards `other`'s oper state; what it already
+    /// added to `other`'s counters stays. Returns false for unknown ports.
+    pub fn remove_port(&self, port: &str) -> bool {
+        let Ok(mut inner) = self.inner.lock() else {
+            return false;
+        };
+        inner.ports.remove(port).is_some() || inner.overflow.remove(port).is_some()
+    }
+
+    /// Expire every port not in the CONFIG_DB port set
+    ///
+    /// Returns the number of ports removed.
+    pub fn retain_ports(&self, configured: &HashSet<String>) -> usize {
+        let Ok(mut inner) = self.inner.lock() else {
+            return 0;
+        };
+        let before = inner.ports.len() + inner.overflow.len();
+        inner.ports.retain(|port, _| configured.contains(port));
+        inner.overflow.retain(|port, _| configured.contains(port));
+        before - inner.ports.len() - inner.overflow.len()
+    }
+
+    /// Ports exported under their own label, sorted
+    pub fn labeled_ports(&self) -> Vec<String> {
+        self.inner
+            .lock()
+            .map(|inner| inner.ports.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of ports currently aggregated into `other`
+    pub fn overflow_count(&self) -> usize {
+        self.inner
+            .lock()
+            .map(|inner| inner.overflow.len())
+            .unwrap_or_default()
+    }
+}
+
 /// Helper function to count metrics within a duration threshold
 fn count_within_duration(metrics: &WarmRestartMetrics, duration_secs: f64) -> f64 {
     let max = metrics.max_initial_sync_duration_secs as f64;
@@ -418,4 +712,128 @@ mod tests {
             elapsed.as_millis()
         );
     }
+
+    fn port_series<'a>(output: &'a str, metric: &str) -> Vec<&'a str> {
+        output
+            .lines()
+            .filter(|l| l.starts_with(&format!("{}{{", metric)))
+            .collect()
+    }
+
+    #[test]
+    fn test_export_port_metrics() {
+        let ports = PortMetrics::new(vec![0.01, 0.1], 8);
+        ports.set_oper_state("Ethernet0", true);
+        ports.set_oper_state("Ethernet4", false);
+        ports.record_flap("Ethernet0");
+        ports.record_flap("Ethernet0");
+        ports.observe_event_latency("Ethernet0", 0.005);
+        ports.observe_event_latency("Ethernet0", 0.05);
+
+        let output = PrometheusExporter::export_port_metrics(&ports);
+
+        assert!(output.contains("# TYPE portsyncd_port_oper_state gauge"));
+        assert!(output.contains("# TYPE portsyncd_port_flap_total counter"));
+        assert!(output.contains("# TYPE portsyncd_port_event_latency_seconds histogram"));
+        assert!(output.contains("portsyncd_port_oper_state{port=\"Ethernet0\"} 1"));
+        assert!(output.contains("portsyncd_port_oper_state{port=\"Ethernet4\"} 0"));
+        assert!(output.contains("portsyncd_port_flap_total{port=\"Ethernet0\"} 2"));
+        assert!(output.contains("portsyncd_port_flap_total{port=\"Ethernet4\"} 0"));
+        assert!(output.contains(
+            "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"0.01\"} 1"
+        ));
+        assert!(output.contains(
+            "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"0.1\"} 2"
+        ));
+        assert!(output.contains(
+            "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"+Inf\"} 2"
+        ));
+        assert!(
+            output.contains("portsyncd_port_event_latency_seconds_count{port=\"Ethernet0\"} 2")
+        );
+        assert!(!output.contains(OTHER_PORT_LABEL));
+    }
+
+    #[test]
+    fn test_port_latency_buckets_are_configurable() {
+        let ports = PortMetrics::new(vec![2.0, f64::NAN, 0.5, 2.0], 8);
+        ports.observe_event_latency("Ethernet0", 1.0);
+
+        let output = PrometheusExporter::export_port_metrics(&ports);
+        let buckets = port_series(&output, "portsyncd_port_event_latency_seconds_bucket");
+
+        assert_eq!(
+            buckets,
+            vec![
+                "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"0.5\"} 0",
+                "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"2\"} 1",
+                "portsyncd_port_event_latency_seconds_bucket{port=\"Ethernet0\",le=\"+Inf\"} 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_port_labels_capped_into_other() {
+        let ports = PortMetrics::new(DEFAULT_PORT_LATENCY_BUCKETS.to_vec(), 2);
+        for port in ["Ethernet0", "Ethernet4", "Ethernet8", "Ethernet12"] {
+            ports.set_oper_state(port, true);
+            ports.record_flap(port);
+        }
+        ports.set_oper_state("Ethernet12", false);
+        ports.record_flap("Ethernet8");
+
+        assert_eq!(ports.labeled_ports(), vec!["Ethernet0", "Ethernet4"]);
+        assert_eq!(ports.overflow_count(), 2);
+
+        let output = PrometheusExporter::export_port_metrics(&ports);
+        let flaps = port_series(&output, "portsyncd_port_flap_total");
+
+        // Two labeled ports plus the aggregate, never more
+        assert_eq!(
+            flaps,
+            vec![
+                "portsyncd_port_flap_total{port=\"Ethernet0\"} 1",
+                "portsyncd_port_flap_total{port=\"Ethernet4\"} 1",
+                "portsyncd_port_flap_total{port=\"other\"} 3",
+            ]
+        );
+        // Only Ethernet8 of the overflow ports is up
+        assert!(output.contains("portsyncd_port_oper_state{port=\"other\"} 1"));
+        assert!(!output.contains("Ethernet8"));
+        assert!(!output.contains("Ethernet12"));
+    }
+
+    #[test]
+    fn test_removed_port_labels_expire() {
+        let ports = PortMetrics::new(DEFAULT_PORT_LATENCY_BUCKETS.to_vec(), 2);
+        ports.set_oper_state("Ethernet0", true);
+        ports.observe_event_latency("Ethernet0", 0.002);
+        ports.record_flap("Ethernet4");
+        ports.set_oper_state("Ethernet8", true);
+
+        // Ethernet0 deleted from CONFIG_DB
+        let configured: HashSet<String> = ["Ethernet4", "Ethernet8"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(ports.retain_ports(&configured), 1);
+
+        let output = PrometheusExporter::export_port_metrics(&ports);
+        assert!(!output.contains("port=\"Ethernet0\""));
+        assert!(output.contains("portsyncd_port_flap_total{port=\"Ethernet4\"} 1"));
+
+        // The freed slot goes to the next new port; Ethernet8 stays in other
+        ports.set_oper_state("Ethernet12", true);
+        assert_eq!(ports.labeled_ports(), vec!["Ethernet12", "Ethernet4"]);
+        assert_eq!(ports.overflow_count(), 1);
+
+        let output = PrometheusExporter::export_port_metrics(&ports);
+        assert!(output.contains("portsyncd_port_oper_state{port=\"other\"} 1"));
+
+        // With no overflow ports and nothing counted, other expires too
+        assert!(ports.remove_port("Ethernet8"));
+        assert!(!ports.remove_port("Ethernet8"));
+        let output = PrometheusExporter::export_port_metrics(&ports);
+        assert!(!output.contains("port=\"other\""));
+    }
 }
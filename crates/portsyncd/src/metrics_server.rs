@@ -22,13 +22,24 @@
 //! CNSA 2.0 Compliance: Commercial National Security Algorithm Suite 2.0
 //!
 //! Phase 6 Week 1 implementation with TLS 1.3 & CNSA 2.0 enforcement.
+//!
+//! Probe endpoints for systemd and orchestration, same contract as neighsyncd:
+//! - `/healthz`: liveness; 503 once the event loop has not iterated within
+//!   `health.max_stall_seconds`
+//! - `/readyz`: readiness; 503 until netlink and Redis are connected and
+//!   initial port sync (PortInitDone) has finished
+//!
+//! Both return a JSON body with `status` and a per-check `checks` map.
 
 use crate::error::{PortsyncError, Result};
 use crate::metrics::MetricsCollector;
-use axum::{Router, routing::get};
+use crate::metrics_exporter::{PortMetrics, PrometheusExporter};
+use crate::production_features::{HealthCheckConfig, HealthMonitor};
+use axum::{Json, Router, http::StatusCode, routing::get};
 use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Configuration for metrics server with mandatory mTLS
 #[derive(Debug, Clone)]
@@ -154,10 +165,103 @@ impl MetricsServerConfig {
     }
 }
 
+/// Liveness and readiness state shared between the event loop and the
+/// `/healthz` and `/readyz` endpoints
+///
+/// Event loop progress is tracked by a [`HealthMonitor`]; Redis and netlink
+/// state come from the [`MetricsCollector`] connection gauges.
+#[derive(Clone, Debug)]
+pub struct HealthProbes {
+    monitor: HealthMonitor,
+    reconciled: Arc<AtomicBool>,
+}
+
+impl Default for HealthProbes {
+    fn default() -> Self {
+        Self::new(HealthCheckConfig::default())
+    }
+}
+
+impl HealthProbes {
+    /// Create probes using the stall threshold from `config`
+    pub fn new(config: HealthCheckConfig) -> Self {
+        Self {
+            monitor: HealthMonitor::new(config),
+            reconciled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Record that the event loop completed an iteration
+    pub fn record_loop_iteration(&self) {
+        self.monitor.record_event();
+    }
+
+    /// Mark initial port sync as finished
+    pub fn set_reconciled(&self, reconciled: bool) {
+        self.reconciled.store(reconciled, Ordering::Relaxed);
+    }
+
+    /// Check if initial port sync has finished
+    pub fn is_reconciled(&self) -> bool {
+        self.reconciled.load(Ordering::Relaxed)
+    }
+
+    /// Evaluate liveness
+    fn liveness(&self) -> (StatusCode, serde_json::Value) {
+        let idle = self.monitor.time_since_last_event();
+        let max_stall = self.monitor.max_stall_duration();
+        let alive = idle <= max_stall;
+
+        let body = serde_json::json!({
+            "status": check_status(alive),
+            "checks": {
+                "event_loop": {
+                    "status": check_status(alive),
+                    "seconds_since_iteration": idle.as_secs(),
+                    "fail_after_seconds": max_stall.as_secs(),
+                },
+            },
+        });
+        (probe_status_code(alive), body)
+    }
+
+    /// Evaluate readiness against the collector's connection state
+    fn readiness(&self, metrics: &MetricsCollector) -> (StatusCode, serde_json::Value) {
+        let redis = metrics.is_redis_connected();
+        let netlink = metrics.is_netlink_connected();
+        let reconciled = self.is_reconciled();
+        let ready = redis && netlink && reconciled;
+
+        let body = serde_json::json!({
+            "status": check_status(ready),
+            "checks": {
+                "redis": { "status": check_status(redis) },
+                "netlink": { "status": check_status(netlink) },
+                "warm_restart": { "status": check_status(reconciled) },
+            },
+        });
+        (probe_status_code(ready), body)
+    }
+}
+
+fn check_status(ok: bool) -> &'static str {
+    if ok { "pass" } else { "fail" }
+}
+
+fn probe_status_code(ok: bool) -> StatusCode {
+    if ok {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
 /// Metrics HTTP server with mandatory mTLS and IPv6-only support
 pub struct MetricsServer {
     pub config: MetricsServerConfig,
     metrics: Arc<MetricsCollector>,
+    probes: HealthProbes,
+    port_metrics: PortMetrics,
 }
 
 impl MetricsServer {
@@ -171,7 +275,58 @@ impl MetricsServer {
     /// Result<MetricsServer> after validating all certificate paths
     pub fn new(config: MetricsServerConfig, metrics: Arc<MetricsCollector>) -> Result<Self> {
         config.validate()?;
-        Ok(Self { config, metrics })
+        Ok(Self {
+            config,
+            metrics,
+            probes: HealthProbes::default(),
+            port_metrics: PortMetrics::default(),
+        })
+    }
+
+    /// Serve `/healthz` and `/readyz` from probes updated by the event loop
+    pub fn with_health_probes(mut self, probes: HealthProbes) -> Self {
+        self.probes = probes;
+        self
+    }
+
+    /// Append the per-port labeled series to `/metrics`
+    pub fn with_port_metrics(mut self, port_metrics: PortMetrics) -> Self {
+        self.port_metrics = port_metrics;
+        self
+    }
+
+    /// Build the metrics and probe routes
+    fn router(
+        metrics: Arc<MetricsCollector>,
+        probes: HealthProbes,
+        port_metrics: PortMetrics,
+    ) -> Router {
+        let liveness = probes.clone();
+        let readiness_metrics = metrics.clone();
+
+        Router::new()
+            .route(
+                "/metrics",
+                get(move || {
+                    let mut metrics_text = metrics.gather_metrics();
+                    metrics_text.push_str(&PrometheusExporter::export_port_metrics(&port_metrics));
+                    async { axum::response::IntoResponse::into_response(metrics_text) }
+                }),
+            )
+            .route(
+                "/healthz",
+                get(move || {
+                    let (code, body) = liveness.liveness();
+                    async move { (code, Json(body)) }
+                }),
+            )
+            .route(
+                "/readyz",
+                get(move || {
+                    let (code, body) = probes.readiness(&readiness_metrics);
+                    async move { (code, Json(body)) }
+                }),
+            )
     }
 
     /// Start the metrics server with mTLS
@@ -182,15 +337,11 @@ impl MetricsServer {
     /// # Returns
     /// Result handling any startup errors
     pub async fn start(self) -> Result<()> {
-        let metrics = self.metrics.clone();
-
         // Create router
-        let app = Router::new().route(
-            "/metrics",
-            get(move || {
-                let metrics_text = metrics.gather_metrics();
-                async { axum::response::IntoResponse::into_response(metrics_text) }
-            }),
+        let app = Self::router(
+            self.metrics.clone(),
+            self.probes.clone(),
+            self.port_metrics.clone(),
         );
 
         // For now, bind plain HTTP with warning about mTLS requirement
@@ -374,4 +525,56 @@ mod tests {
         let result = MetricsServer::new(config, metrics);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_readyz_transitions() {
+        let metrics = MetricsCollector::new().unwrap();
+        let probes = HealthProbes::default();
+
+        let (code, body) = probes.readiness(&metrics);
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["redis"]["status"], "fail");
+        assert_eq!(body["checks"]["netlink"]["status"], "fail");
+
+        // Connected, but ports not initialized yet
+        metrics.set_redis_connected(true);
+        metrics.set_netlink_connected(true);
+        let (code, body) = probes.readiness(&metrics);
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["warm_restart"]["status"], "fail");
+
+        probes.set_reconciled(true);
+        let (code, body) = probes.readiness(&metrics);
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(body["status"], "pass");
+
+        // Netlink failure makes the daemon unready again
+        metrics.set_netlink_connected(false);
+        let (code, body) = probes.readiness(&metrics);
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["checks"]["netlink"]["status"], "fail");
+        assert_eq!(body["checks"]["redis"]["status"], "pass");
+    }
+
+    #[test]
+    fn test_healthz_fails_after_stall() {
+        let probes = HealthProbes::new(HealthCheckConfig {
+            max_stall_duration: std::time::Duration::from_millis(50),
+            ..HealthCheckConfig::default()
+        });
+
+        probes.record_loop_iteration();
+        let (code, body) = probes.liveness();
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(body["checks"]["event_loop"]["status"], "pass");
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let (code, body) = probes.liveness();
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "fail");
+
+        probes.record_loop_iteration();
+        let (code, _) = probes.liveness();
+        assert_eq!(code, StatusCode::OK);
+    }
 }
@@ -11,6 +11,7 @@
 
 use crate::eoiu_detector::EoiuDetector;
 use crate::error::{PortsyncError, Result};
+use crate::link_event::LinkEvent;
 use crate::port_sync::NetlinkEvent;
 
 #[cfg(target_os = "linux")]
@@ -18,6 +19,10 @@ use nix::sys::socket::{AddressFamily, SockFlag, SockProtocol, SockType, socket};
 #[cfg(target_os = "linux")]
 use std::os::unix::io::RawFd;
 
+/// Receive buffer size; link dump datagrams can exceed a single page
+#[cfg(target_os = "linux")]
+const RECV_BUFFER_SIZE: usize = 32 * 1024;
+
 /// Netlink socket for kernel RTM_LINK events
 ///
 /// Linux: Receives RTM_NEWLINK and RTM_DELLINK messages from kernel via netlink socket.
@@ -37,6 +42,9 @@ pub struct NetlinkSocket {
     #[cfg(not(target_os = "linux"))]
     mock_events: Vec<NetlinkEvent>,
 
+    /// Sequence number for kernel requests
+    seq: u32,
+
     /// EOIU detector for warm restart coordination
     eoiu_detector: EoiuDetector,
 }
@@ -49,7 +57,8 @@ impl NetlinkSocket {
             Ok(Self {
                 connected: false,
                 fd: None,
-                buffer: vec![0u8; 8192],
+                buffer: vec![0u8; RECV_BUFFER_SIZE],
+                seq: 0,
                 eoiu_detector: EoiuDetector::new(),
             })
         }
@@ -59,6 +68,7 @@ impl NetlinkSocket {
             Ok(Self {
                 connected: false,
                 mock_events: Vec::new(),
+                seq: 0,
                 eoiu_detector: EoiuDetector::new(),
             })
         }
@@ -83,6 +93,13 @@ impl NetlinkSocket {
         )
         .map_err(|e| PortsyncError::Netlink(format!("Failed to set non-blocking: {}", e)))?;
 
+        // Subscribe to RTNLGRP_LINK for RTM_NEWLINK/RTM_DELLINK notifications
+        nix::sys::socket::bind(
+            fd,
+            &nix::sys::socket::NetlinkAddr::new(0, crate::link_event::RTMGRP_LINK),
+        )
+        .map_err(|e| PortsyncError::Netlink(format!("Failed to subscribe to link group: {}", e)))?;
+
         eprintln!("portsyncd: Connected to netlink socket");
         self.fd = Some(fd);
         self.connected = true;
@@ -171,6 +188,91 @@ impl NetlinkSocket {
         Ok(self.mock_events.pop())
     }
 
+    /// Ask the kernel to dump every link (Linux only)
+    ///
+    /// The replies arrive through `receive_link_events` like any other
+    /// RTM_NEWLINK and seed the initial port state.
+    #[cfg(target_os = "linux")]
+    pub fn request_link_dump(&mut self) -> Result<()> {
+        let fd = self
+            .fd
+            .ok_or_else(|| PortsyncError::Netlink("Not connected to netlink socket".to_string()))?;
+
+        self.seq = self.seq.wrapping_add(1);
+        let request = crate::link_event::build_link_dump_request(self.seq);
+        nix::sys::socket::send(fd, &request, nix::sys::socket::MsgFlags::empty())
+            .map_err(|e| PortsyncError::Netlink(format!("Failed to request link dump: {}", e)))?;
+        Ok(())
+    }
+
+    /// Ask the kernel to dump every link (mock for non-Linux)
+    #[cfg(not(target_os = "linux"))]
+    pub fn request_link_dump(&mut self) -> Result<()> {
+        if !self.connected {
+            return Err(PortsyncError::Netlink(
+                "Not connected to netlink socket".to_string(),
+            ));
+        }
+        self.seq = self.seq.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Receive all link events available in the next netlink datagram
+    ///
+    /// Returns an empty list when no data is pending. Speed is filled in
+    /// from sysfs for links that are operationally up.
+    #[cfg(target_os = "linux")]
+    pub fn receive_link_events(&mut self) -> Result<Vec<LinkEvent>> {
+        let fd = match (self.connected, self.fd) {
+            (true, Some(fd)) => fd,
+            _ => {
+                return Err(PortsyncError::Netlink(
+                    "Not connected to netlink socket".to_string(),
+                ));
+            }
+        };
+
+        let n =
+            match nix::sys::socket::recv(fd, &mut self.buffer, nix::sys::socket::MsgFlags::empty())
+            {
+                Ok(n) => n,
+                Err(nix::Error::EAGAIN) => return Ok(Vec::new()),
+                Err(e) => {
+                    return Err(PortsyncError::Netlink(format!(
+                        "Failed to receive from netlink: {}",
+                        e
+                    )));
+                }
+            };
+
+        let mut events = crate::link_event::parse_link_messages(&self.buffer[..n])?;
+        for event in &mut events {
+            if event.is_new_link() {
+                if event.is_lag_master() {
+                    let _ = self.eoiu_detector.mark_lag_ready(&event.ifname);
+                }
+                let _ = self
+                    .eoiu_detector
+                    .check_eoiu(&event.ifname, event.change, event.flags);
+                if event.oper == crate::port_sync::LinkStatus::Up {
+                    event.speed = read_link_speed(&event.ifname);
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// Receive link events (mock for non-Linux)
+    #[cfg(not(target_os = "linux"))]
+    pub fn receive_link_events(&mut self) -> Result<Vec<LinkEvent>> {
+        if !self.connected {
+            return Err(PortsyncError::Netlink(
+                "Not connected to netlink socket".to_string(),
+            ));
+        }
+        Ok(Vec::new())
+    }
+
     /// Close netlink socket
     pub fn close(&mut self) -> Result<()> {
         #[cfg(target_os = "linux")]
@@ -199,6 +301,16 @@ impl NetlinkSocket {
     }
 }
 
+/// Read link speed in Mb/s from sysfs; the kernel reports -1 when unknown
+#[cfg(target_os = "linux")]
+fn read_link_speed(ifname: &str) -> Option<u32> {
+    std::fs::read_to_string(format!("/sys/class/net/{}/speed", ifname))
+        .ok()
+        .and_then(|s| s.trim().parse::<i64>().ok())
+        .and_then(|speed| u32::try_from(speed).ok())
+        .filter(|&speed| speed > 0)
+}
+
 /// Parse netlink message buffer into NetlinkEvent with ifi_change for EOIU detection (Linux only)
 #[cfg(target_os = "linux")]
 fn parse_netlink_message(buffer: &[u8]) -> Result<(NetlinkEvent, u32)> {
@@ -270,7 +382,8 @@ impl Default for NetlinkSocket {
                 Self {
                     connected: false,
                     fd: None,
-                    buffer: vec![0u8; 8192],
+                    buffer: vec![0u8; RECV_BUFFER_SIZE],
+                    seq: 0,
                     eoiu_detector: EoiuDetector::new(),
                 }
             }
@@ -280,6 +393,7 @@ impl Default for NetlinkSocket {
                 Self {
                     connected: false,
                     mock_events: Vec::new(),
+                    seq: 0,
                     eoiu_detector: EoiuDetector::new(),
                 }
             }
@@ -371,6 +485,18 @@ mod tests {
         // For now, returns None (no events in mock mode)
     }
 
+    #[test]
+    fn test_netlink_receive_link_events_not_connected() {
+        let mut socket = NetlinkSocket::new().unwrap();
+        assert!(socket.receive_link_events().is_err());
+    }
+
+    #[test]
+    fn test_netlink_request_link_dump_not_connected() {
+        let mut socket = NetlinkSocket::new().unwrap();
+        assert!(socket.request_link_dump().is_err());
+    }
+
     #[test]
     fn test_parse_newlink_not_implemented() {
         let buffer = vec![0u8; 64];
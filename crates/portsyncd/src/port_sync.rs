@@ -6,11 +6,18 @@
 //! Supports warm restart via WarmRestartManager, which gates APP_DB updates
 //! during initial synchronization after a warm restart.
 
-use crate::config::DatabaseConnection;
+use crate::carrier_debounce::CarrierDebouncer;
+use crate::config::{DatabaseAdapter, DatabaseConnection};
+use crate::config_file::DebounceConfig;
 use crate::error::Result;
+use crate::lag_tracker::LagTracker;
+use crate::link_event::LinkEvent;
+use crate::metrics::MetricsCollector;
+use crate::metrics_exporter::PortMetrics;
 use crate::warm_restart::{PortState, WarmRestartManager, WarmRestartMetrics, WarmRestartState};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// Link status values
 #[derive(Clone, Debug, PartialEq)]
@@ -113,10 +120,22 @@ pub struct NetlinkEvent {
 pub struct LinkSync {
     /// Uninitialized ports awaiting their first netlink event
     uninitialized_ports: HashSet<String>,
+    /// Front-panel ports known from CONFIG_DB
+    known_ports: HashSet<String>,
     /// Flag: have we sent PortInitDone yet?
     port_init_done: bool,
     /// Warm restart manager for coordinating warm restarts
     warm_restart: Option<WarmRestartManager>,
+    /// Carrier debounce stage between the kernel and STATE_DB
+    debouncer: CarrierDebouncer,
+    /// Last PORT_TABLE fields written per port, to suppress no-op writes
+    last_written: HashMap<String, Vec<(String, String)>>,
+    /// Metrics sink for per-port flap counters
+    metrics: Option<MetricsCollector>,
+    /// Per-port labeled oper state and flap series
+    port_metrics: Option<PortMetrics>,
+    /// PortChannel masters and members seen in the kernel
+    lags: LagTracker,
 }
 
 impl LinkSync {
@@ -124,8 +143,14 @@ impl LinkSync {
     pub fn new() -> Result<Self> {
         Ok(Self {
             uninitialized_ports: HashSet::new(),
+            known_ports: HashSet::new(),
             port_init_done: false,
             warm_restart: None,
+            debouncer: CarrierDebouncer::default(),
+            last_written: HashMap::new(),
+            metrics: None,
+            port_metrics: None,
+            lags: LagTracker::new(),
         })
     }
 
@@ -133,8 +158,14 @@ impl LinkSync {
     pub fn with_warm_restart(state_file_path: PathBuf) -> Result<Self> {
         Ok(Self {
             uninitialized_ports: HashSet::new(),
+            known_ports: HashSet::new(),
             port_init_done: false,
             warm_restart: Some(WarmRestartManager::with_state_file(state_file_path)),
+            debouncer: CarrierDebouncer::default(),
+            last_written: HashMap::new(),
+            metrics: None,
+            port_metrics: None,
+            lags: LagTracker::new(),
         })
     }
 
@@ -295,9 +326,189 @@ impl LinkSync {
     /// Initialize port list from port names
     /// Used to pre-populate the set of ports we're waiting for
     pub fn initialize_ports(&mut self, port_names: Vec<String>) {
+        self.known_ports = port_names.iter().cloned().collect();
         self.uninitialized_ports = port_names.into_iter().collect();
     }
 
+    /// Check if a kernel link belongs to a configured front-panel port
+    ///
+    /// Before any port configuration is loaded, falls back to the
+    /// name-based filter in `should_ignore`.
+    pub fn is_known_port(&self, name: &str) -> bool {
+        if self.known_ports.is_empty() {
+            !self.should_ignore(name)
+        } else {
+            self.known_ports.contains(name)
+        }
+    }
+
+    /// Apply carrier debounce settings (hot reload)
+    pub fn set_debounce_config(&mut self, config: &DebounceConfig) {
+        self.debouncer.update_config(config);
+    }
+
+    /// Attach the metrics collector that receives per-port flap counts
+    pub fn set_metrics_collector(&mut self, metrics: MetricsCollector) {
+        self.metrics = Some(metrics);
+    }
+
+    /// Attach the per-port exporter series updated on flaps and oper changes
+    pub fn set_port_metrics(&mut self, port_metrics: PortMetrics) {
+        self.port_metrics = Some(port_metrics);
+    }
+
+    /// Get the carrier debouncer
+    pub fn debouncer(&self) -> &CarrierDebouncer {
+        &self.debouncer
+    }
+
+    /// Get the PortChannel tracker
+    pub fn lags(&self) -> &LagTracker {
+        &self.lags
+    }
+
+    /// Get the earliest pending debounce deadline
+    pub fn next_debounce_deadline(&self) -> Option<Instant> {
+        self.debouncer.next_deadline()
+    }
+
+    /// Handle a typed kernel link event (RTM_NEWLINK or RTM_DELLINK)
+    ///
+    /// Updates STATE_DB PORT_TABLE for configured ports and tracks port
+    /// initialization for PortInitDone. Returns false if the link was
+    /// filtered out.
+    pub async fn handle_link_event(
+        &mut self,
+        event: &LinkEvent,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<bool> {
+        self.handle_link_event_at(event, state_db, Instant::now())
+            .await
+    }
+
+    /// Handle a typed kernel link event observed at `now`
+    ///
+    /// Oper-state changes pass through the carrier debouncer; while a
+    /// transition is held, STATE_DB keeps the last published state.
+    pub async fn handle_link_event_at(
+        &mut self,
+        event: &LinkEvent,
+        state_db: &mut dyn DatabaseAdapter,
+        now: Instant,
+    ) -> Result<bool> {
+        if LagTracker::is_lag_master_event(event) {
+            self.lags.handle_master_event(event, state_db).await?;
+            return Ok(true);
+        }
+
+        if !self.is_known_port(&event.ifname) {
+            return Ok(false);
+        }
+
+        if !event.is_new_link() {
+            self.debouncer.forget(&event.ifname);
+            self.last_written.remove(&event.ifname);
+            self.lags.remove_member(&event.ifname, state_db).await?;
+            state_db
+                .delete(&format!("PORT_TABLE|{}", event.ifname))
+                .await?;
+            return Ok(true);
+        }
+
+        let outcome = self
+            .debouncer
+            .observe(&event.ifname, event.oper.clone(), now);
+        if outcome.flapped
+            && let Some(ref metrics) = self.metrics
+        {
+            metrics.record_port_flap(&event.ifname);
+        }
+        let oper = self
+            .debouncer
+            .published(&event.ifname)
+            .cloned()
+            .unwrap_or_else(|| event.oper.clone());
+        if let Some(ref port_metrics) = self.port_metrics {
+            if outcome.flapped {
+                port_metrics.record_flap(&event.ifname);
+            }
+            port_metrics.set_oper_state(&event.ifname, oper == LinkStatus::Up);
+        }
+
+        let mtu = event.mtu.unwrap_or(9100);
+        self.record_port_for_warm_restart(event.ifname.clone(), event.flags, mtu);
+
+        if !self.should_skip_app_db_updates() {
+            let port_state =
+                PortLinkState::new(event.ifname.clone(), oper.clone(), event.admin.clone(), mtu);
+            let mut field_values = port_state.to_field_values();
+            if let Some(speed) = event.speed {
+                field_values.push(("speed".to_string(), speed.to_string()));
+            }
+            self.write_port_state(&event.ifname, field_values, state_db)
+                .await?;
+        }
+
+        self.lags
+            .handle_member_event(&event.ifname, event.master_ifindex, oper, state_db)
+            .await?;
+
+        self.mark_port_initialized(&event.ifname);
+
+        Ok(true)
+    }
+
+    /// Publish debounced oper-state transitions whose hold has expired
+    ///
+    /// Returns the number of ports updated in STATE_DB.
+    pub async fn poll_debounced(&mut self, state_db: &mut dyn DatabaseAdapter) -> Result<usize> {
+        self.poll_debounced_at(state_db, Instant::now()).await
+    }
+
+    /// Publish debounced transitions whose hold has expired at `now`
+    pub async fn poll_debounced_at(
+        &mut self,
+        state_db: &mut dyn DatabaseAdapter,
+        now: Instant,
+    ) -> Result<usize> {
+        let mut updated = 0;
+        for (port, oper) in self.debouncer.poll(now) {
+            let Some(mut field_values) = self.last_written.get(&port).cloned() else {
+                continue;
+            };
+            for (field, value) in field_values.iter_mut() {
+                if field == "netdev_oper_status" {
+                    *value = oper.as_str().to_string();
+                }
+            }
+            self.write_port_state(&port, field_values, state_db).await?;
+            if let Some(ref port_metrics) = self.port_metrics {
+                port_metrics.set_oper_state(&port, oper == LinkStatus::Up);
+            }
+            self.lags.update_member_oper(&port, oper, state_db).await?;
+            updated += 1;
+        }
+        Ok(updated)
+    }
+
+    /// Write PORT_TABLE fields unless they match the last write
+    async fn write_port_state(
+        &mut self,
+        port: &str,
+        field_values: Vec<(String, String)>,
+        state_db: &mut dyn DatabaseAdapter,
+    ) -> Result<()> {
+        if self.last_written.get(port) == Some(&field_values) {
+            return Ok(());
+        }
+
+        state_db
+            .hset(&format!("PORT_TABLE|{}", port), &field_values)
+            .await?;
+        self.last_written.insert(port.to_string(), field_values);
+        Ok(())
+    }
+
     /// Check if we should send PortInitDone signal
     pub fn should_send_port_init_done(&self) -> bool {
         self.are_all_ports_initialized() && !self.port_init_done
@@ -323,6 +534,7 @@ impl Default for LinkSync {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::metrics_exporter::PrometheusExporter;
 
     #[test]
     fn test_link_status_up() {
@@ -818,4 +1030,483 @@ mod tests {
 
         // Verify saved - state file path is used (in temp dir for testing)
     }
+
+    fn parse_one(buffer: &[u8]) -> LinkEvent {
+        crate::link_event::parse_link_messages(buffer)
+            .expect("Failed to parse link message")
+            .remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_handle_link_event_writes_state_db() {
+        use crate::link_event::{IFF_LOWER_UP, IFF_UP, RTM_NEWLINK, encode_link_message};
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        let buffer = encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP | IFF_LOWER_UP,
+            "Ethernet0",
+            Some(9000),
+        );
+        let mut event = parse_one(&buffer);
+        event.speed = Some(100000);
+
+        assert!(sync.handle_link_event(&event, &mut state_db).await.unwrap());
+
+        let fields = state_db.hgetall("PORT_TABLE|Ethernet0").await.unwrap();
+        assert_eq!(fields.get("netdev_oper_status"), Some(&"up".to_string()));
+        assert_eq!(fields.get("admin_status"), Some(&"up".to_string()));
+        assert_eq!(fields.get("mtu"), Some(&"9000".to_string()));
+        assert_eq!(fields.get("speed"), Some(&"100000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_handle_link_event_carrier_down() {
+        use crate::link_event::{IFF_UP, RTM_NEWLINK, encode_link_message};
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        let event = parse_one(&encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP,
+            "Ethernet0",
+            Some(9100),
+        ));
+        sync.handle_link_event(&event, &mut state_db).await.unwrap();
+
+        let fields = state_db.hgetall("PORT_TABLE|Ethernet0").await.unwrap();
+        assert_eq!(fields.get("netdev_oper_status"), Some(&"down".to_string()));
+        assert!(!fields.contains_key("speed"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_link_event_filters_unknown_ports() {
+        use crate::link_event::{IFF_UP, RTM_NEWLINK, encode_link_message};
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        for name in ["Ethernet100", "eth0", "docker0"] {
+            let event = parse_one(&encode_link_message(RTM_NEWLINK, 9, IFF_UP, name, None));
+            assert!(!sync.handle_link_event(&event, &mut state_db).await.unwrap());
+        }
+
+        assert!(state_db.keys("PORT_TABLE|*").await.unwrap().is_empty());
+        assert_eq!(sync.uninitialized_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_handle_link_event_dellink_removes_entry() {
+        use crate::link_event::{IFF_UP, RTM_DELLINK, RTM_NEWLINK, encode_link_message};
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        let new_link = parse_one(&encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP,
+            "Ethernet0",
+            None,
+        ));
+        let del_link = parse_one(&encode_link_message(RTM_DELLINK, 5, 0, "Ethernet0", None));
+        sync.handle_link_event(&new_link, &mut state_db)
+            .await
+            .unwrap();
+        sync.handle_link_event(&del_link, &mut state_db)
+            .await
+            .unwrap();
+
+        assert!(
+            state_db
+                .hgetall("PORT_TABLE|Ethernet0")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kernel_dump_seeds_state_and_gates_port_init_done() {
+        use crate::link_event::{
+            IFF_LOWER_UP, IFF_UP, RTM_NEWLINK, encode_done_message, encode_link_message,
+            parse_link_messages,
+        };
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string(), "Ethernet4".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        // First dump chunk: loopback and one of the two configured ports
+        let mut chunk = encode_link_message(RTM_NEWLINK, 1, IFF_UP, "lo", Some(65536));
+        chunk.extend(encode_link_message(
+            RTM_NEWLINK,
+            5,
+            IFF_UP | IFF_LOWER_UP,
+            "Ethernet0",
+            Some(9100),
+        ));
+        for event in parse_link_messages(&chunk).unwrap() {
+            sync.handle_link_event(&event, &mut state_db).await.unwrap();
+        }
+        assert!(!sync.should_send_port_init_done());
+
+        // Second chunk completes the dump
+        let mut chunk = encode_link_message(RTM_NEWLINK, 6, IFF_UP, "Ethernet4", Some(9100));
+        chunk.extend(encode_done_message());
+        for event in parse_link_messages(&chunk).unwrap() {
+            sync.handle_link_event(&event, &mut state_db).await.unwrap();
+        }
+        assert!(sync.should_send_port_init_done());
+
+        let mut keys = state_db.keys("PORT_TABLE|*").await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["PORT_TABLE|Ethernet0", "PORT_TABLE|Ethernet4"]);
+
+        sync.set_port_init_done();
+        assert!(!sync.should_send_port_init_done());
+    }
+
+    /// STATE_DB wrapper that counts PORT_TABLE writes
+    struct CountingDb {
+        inner: DatabaseConnection,
+        hsets: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl DatabaseAdapter for CountingDb {
+        async fn hgetall(&self, key: &str) -> Result<std::collections::HashMap<String, String>> {
+            self.inner.hgetall(key).await
+        }
+
+        async fn hset(&mut self, key: &str, fields: &[(String, String)]) -> Result<()> {
+            self.hsets += 1;
+            self.inner.hset(key, fields).await
+        }
+
+        async fn delete(&mut self, key: &str) -> Result<()> {
+            self.inner.delete(key).await
+        }
+
+        async fn keys(&self, pattern: &str) -> Result<Vec<String>> {
+            self.inner.keys(pattern).await
+        }
+    }
+
+    fn carrier_event(up: bool) -> LinkEvent {
+        use crate::link_event::{IFF_LOWER_UP, IFF_UP, RTM_NEWLINK, encode_link_message};
+
+        let flags = if up { IFF_UP | IFF_LOWER_UP } else { IFF_UP };
+        parse_one(&encode_link_message(
+            RTM_NEWLINK,
+            5,
+            flags,
+            "Ethernet0",
+            Some(9100),
+        ))
+    }
+
+    async fn oper_status(db: &CountingDb) -> String {
+        db.hgetall("PORT_TABLE|Ethernet0")
+            .await
+            .unwrap()
+            .get("netdev_oper_status")
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_link_flap_storm_debounced() {
+        use std::time::Duration;
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let metrics = MetricsCollector::new().unwrap();
+        sync.set_metrics_collector(metrics.clone());
+        let port_metrics = PortMetrics::default();
+        sync.set_port_metrics(port_metrics.clone());
+        let mut db = CountingDb {
+            inner: DatabaseConnection::new("STATE_DB".to_string()),
+            hsets: 0,
+        };
+
+        let t0 = Instant::now();
+        sync.handle_link_event_at(&carrier_event(true), &mut db, t0)
+            .await
+            .unwrap();
+        assert_eq!(oper_status(&db).await, "up");
+
+        // Down is published immediately, then nine bounces inside the window
+        let mut now = t0;
+        for i in 0..10 {
+            now += Duration::from_millis(20);
+            sync.handle_link_event_at(&carrier_event(i % 2 == 1), &mut db, now)
+                .await
+                .unwrap();
+            sync.poll_debounced_at(&mut db, now).await.unwrap();
+        }
+        assert_eq!(oper_status(&db).await, "down");
+        assert_eq!(db.hsets, 2);
+
+        // Final state is up; it lands once the up hold expires
+        let deadline = sync.next_debounce_deadline().unwrap();
+        assert_eq!(deadline, now + Duration::from_millis(200));
+        assert_eq!(
+            sync.poll_debounced_at(&mut db, deadline - Duration::from_millis(1))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(sync.poll_debounced_at(&mut db, deadline).await.unwrap(), 1);
+        assert_eq!(oper_status(&db).await, "up");
+        assert_eq!(db.hsets, 3);
+
+        assert_eq!(sync.debouncer().flap_count("Ethernet0"), 10);
+        assert!(
+            metrics
+                .gather_metrics()
+                .contains("portsyncd_port_flaps_total{port=\"Ethernet0\"} 10")
+        );
+        let exported = PrometheusExporter::export_port_metrics(&port_metrics);
+        assert!(exported.contains("portsyncd_port_flap_total{port=\"Ethernet0\"} 10"));
+        assert!(exported.contains("portsyncd_port_oper_state{port=\"Ethernet0\"} 1"));
+    }
+
+    #[tokio::test]
+    async fn test_link_debounce_hot_reload() {
+        use std::time::Duration;
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut db = CountingDb {
+            inner: DatabaseConnection::new("STATE_DB".to_string()),
+            hsets: 0,
+        };
+
+        let t0 = Instant::now();
+        sync.handle_link_event_at(&carrier_event(false), &mut db, t0)
+            .await
+            .unwrap();
+
+        sync.set_debounce_config(&DebounceConfig {
+            up_hold_ms: 0,
+            ..DebounceConfig::default()
+        });
+        sync.handle_link_event_at(&carrier_event(true), &mut db, t0 + Duration::from_millis(5))
+            .await
+            .unwrap();
+
+        assert_eq!(oper_status(&db).await, "up");
+        assert_eq!(sync.next_debounce_deadline(), None);
+    }
+
+    #[tokio::test]
+    async fn test_link_event_unchanged_state_not_rewritten() {
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut db = CountingDb {
+            inner: DatabaseConnection::new("STATE_DB".to_string()),
+            hsets: 0,
+        };
+
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            sync.handle_link_event_at(&carrier_event(true), &mut db, t0)
+                .await
+                .unwrap();
+        }
+        assert_eq!(db.hsets, 1);
+    }
+
+    fn lag_event(
+        msg_type: u16,
+        ifindex: u32,
+        flags: u32,
+        name: &str,
+        master: Option<u32>,
+    ) -> LinkEvent {
+        use crate::link_event::encode_lag_link_message;
+
+        let kind = name.starts_with("PortChannel").then_some("team");
+        parse_one(&encode_lag_link_message(
+            msg_type,
+            ifindex,
+            flags,
+            name,
+            Some(9100),
+            kind,
+            master,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_lag_master_member_lifecycle() {
+        use crate::link_event::{IFF_LOWER_UP, IFF_UP, RTM_DELLINK, RTM_NEWLINK};
+
+        let up = IFF_UP | IFF_LOWER_UP;
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string(), "Ethernet4".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+        let t0 = Instant::now();
+
+        // Master creation by teamd (no carrier until a member is up)
+        let master = lag_event(RTM_NEWLINK, 100, IFF_UP, "PortChannel0001", None);
+        assert!(
+            sync.handle_link_event_at(&master, &mut state_db, t0)
+                .await
+                .unwrap()
+        );
+        let lag = state_db.hgetall("LAG_TABLE|PortChannel0001").await.unwrap();
+        assert_eq!(lag.get("netdev_oper_status"), Some(&"down".to_string()));
+        assert!(sync.lags().has_lag("PortChannel0001"));
+
+        // Member enslave, then master gains carrier
+        let member = lag_event(RTM_NEWLINK, 5, up, "Ethernet0", Some(100));
+        sync.handle_link_event_at(&member, &mut state_db, t0)
+            .await
+            .unwrap();
+        let master = lag_event(RTM_NEWLINK, 100, up, "PortChannel0001", None);
+        sync.handle_link_event_at(&master, &mut state_db, t0)
+            .await
+            .unwrap();
+
+        assert_eq!(sync.lags().master_of("Ethernet0"), Some("PortChannel0001"));
+        let entry = state_db
+            .hgetall("LAG_MEMBER_TABLE|PortChannel0001|Ethernet0")
+            .await
+            .unwrap();
+        assert_eq!(entry.get("netdev_oper_status"), Some(&"up".to_string()));
+        let lag = state_db.hgetall("LAG_TABLE|PortChannel0001").await.unwrap();
+        assert_eq!(lag.get("netdev_oper_status"), Some(&"up".to_string()));
+        // The member is still a front-panel port
+        assert!(
+            !state_db
+                .hgetall("PORT_TABLE|Ethernet0")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Member carrier loss (down is not held by default)
+        let member = lag_event(RTM_NEWLINK, 5, IFF_UP, "Ethernet0", Some(100));
+        sync.handle_link_event_at(&member, &mut state_db, t0)
+            .await
+            .unwrap();
+        let entry = state_db
+            .hgetall("LAG_MEMBER_TABLE|PortChannel0001|Ethernet0")
+            .await
+            .unwrap();
+        assert_eq!(entry.get("netdev_oper_status"), Some(&"down".to_string()));
+
+        // Master deletion cleans up the LAG and its members
+        let master = lag_event(RTM_DELLINK, 100, 0, "PortChannel0001", None);
+        sync.handle_link_event_at(&master, &mut state_db, t0)
+            .await
+            .unwrap();
+        assert!(state_db.keys("LAG_TABLE|*").await.unwrap().is_empty());
+        assert!(
+            state_db
+                .keys("LAG_MEMBER_TABLE|*")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!sync.lags().has_lag("PortChannel0001"));
+        assert_eq!(sync.lags().master_of("Ethernet0"), None);
+    }
+
+    #[tokio::test]
+    async fn test_lag_member_reported_before_master() {
+        use crate::link_event::{IFF_LOWER_UP, IFF_UP, RTM_NEWLINK};
+
+        let up = IFF_UP | IFF_LOWER_UP;
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+        let t0 = Instant::now();
+
+        // Kernel dump runs in ifindex order: member first
+        let member = lag_event(RTM_NEWLINK, 5, up, "Ethernet0", Some(100));
+        sync.handle_link_event_at(&member, &mut state_db, t0)
+            .await
+            .unwrap();
+        assert!(
+            state_db
+                .keys("LAG_MEMBER_TABLE|*")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let master = lag_event(RTM_NEWLINK, 100, up, "PortChannel0001", None);
+        sync.handle_link_event_at(&master, &mut state_db, t0)
+            .await
+            .unwrap();
+        assert_eq!(
+            state_db.keys("LAG_MEMBER_TABLE|*").await.unwrap(),
+            vec!["LAG_MEMBER_TABLE|PortChannel0001|Ethernet0"]
+        );
+        assert_eq!(sync.lags().members_of("PortChannel0001"), vec!["Ethernet0"]);
+    }
+
+    #[tokio::test]
+    async fn test_lag_member_release() {
+        use crate::link_event::{IFF_LOWER_UP, IFF_UP, RTM_NEWLINK};
+
+        let up = IFF_UP | IFF_LOWER_UP;
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+        let t0 = Instant::now();
+
+        for event in [
+            lag_event(RTM_NEWLINK, 100, up, "PortChannel0001", None),
+            lag_event(RTM_NEWLINK, 5, up, "Ethernet0", Some(100)),
+            lag_event(RTM_NEWLINK, 5, up, "Ethernet0", None),
+        ] {
+            sync.handle_link_event_at(&event, &mut state_db, t0)
+                .await
+                .unwrap();
+        }
+
+        assert!(
+            state_db
+                .keys("LAG_MEMBER_TABLE|*")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(sync.lags().members_of("PortChannel0001").is_empty());
+        assert!(sync.lags().has_lag("PortChannel0001"));
+    }
+
+    #[tokio::test]
+    async fn test_non_portchannel_bond_ignored() {
+        use crate::link_event::{IFF_UP, RTM_NEWLINK, encode_lag_link_message};
+
+        let mut sync = LinkSync::new().expect("Failed to create LinkSync");
+        sync.initialize_ports(vec!["Ethernet0".to_string()]);
+        let mut state_db = DatabaseConnection::new("STATE_DB".to_string());
+
+        let bond = parse_one(&encode_lag_link_message(
+            RTM_NEWLINK,
+            50,
+            IFF_UP,
+            "bond0",
+            None,
+            Some("bond"),
+            None,
+        ));
+        assert!(!sync.handle_link_event(&bond, &mut state_db).await.unwrap());
+        assert!(state_db.keys("LAG_TABLE|*").await.unwrap().is_empty());
+    }
 }
@@ -3,6 +3,7 @@
 //! Includes systemd integration, health checks, and graceful shutdown.
 //! Phase 4 Week 2 Day 5 implementation.
 
+use crate::config_file::HealthConfig;
 use crate::error::{PortsyncError, Result};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -51,6 +52,16 @@ impl Default for HealthCheckConfig {
     }
 }
 
+impl From<&HealthConfig> for HealthCheckConfig {
+    fn from(config: &HealthConfig) -> Self {
+        Self {
+            max_stall_duration: Duration::from_secs(config.max_stall_seconds),
+            max_failure_rate: config.max_failure_rate_percent,
+            min_port_sync_rate: config.min_port_sync_rate,
+        }
+    }
+}
+
 /// Health monitor for portsyncd
 #[derive(Clone, Debug)]
 pub struct HealthMonitor {
@@ -106,6 +117,19 @@ impl HealthMonitor {
     pub fn status(&self) -> HealthStatus {
         self.check_health()
     }
+
+    /// Time since the last recorded event
+    pub fn time_since_last_event(&self) -> Duration {
+        self.last_event
+            .lock()
+            .map(|last| last.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// Stall duration after which the daemon is unhealthy
+    pub fn max_stall_duration(&self) -> Duration {
+        self.config.max_stall_duration
+    }
 }
 
 impl Default for HealthMonitor {
@@ -273,6 +297,17 @@ mod tests {
         assert_eq!(monitor.check_health(), HealthStatus::Healthy);
     }
 
+    #[test]
+    fn test_health_check_config_from_file_config() {
+        let file_config = HealthConfig {
+            max_stall_seconds: 30,
+            ..HealthConfig::default()
+        };
+        let monitor = HealthMonitor::new(HealthCheckConfig::from(&file_config));
+        assert_eq!(monitor.max_stall_duration(), Duration::from_secs(30));
+        assert!(monitor.time_since_last_event() < Duration::from_secs(30));
+    }
+
     #[test]
     fn test_health_monitor_status_update() {
         let monitor = HealthMonitor::new(HealthCheckConfig::default());
@@ -362,6 +362,67 @@ impl PromQLBuilder {
         }
     }
 
+    // Per-port queries (labeled by `port`, overflow ports aggregate as "other")
+
+    /// Number of labeled ports currently oper down
+    pub fn ports_oper_down() -> PromQLQuery {
+        PromQLQuery {
+            query: "count(portsyncd_port_oper_state{port!=\"other\"} == 0) or vector(0)"
+                .to_string(),
+            category: QueryCategory::HealthMetrics,
+            description: "Number of ports operationally down".to_string(),
+        }
+    }
+
+    /// Carrier flap rate per port
+    pub fn port_flap_rate(window: TimeWindow) -> PromQLQuery {
+        PromQLQuery {
+            query: format!(
+                "sum by (port) (rate(portsyncd_port_flap_total[{}]))",
+                window.to_promql_duration()
+            ),
+            category: QueryCategory::Reliability,
+            description: format!(
+                "Per-port carrier flaps per second over {}",
+                window.to_promql_duration()
+            ),
+        }
+    }
+
+    /// Ports with the most carrier flaps in the window
+    pub fn top_flapping_ports(count: usize, window: TimeWindow) -> PromQLQuery {
+        PromQLQuery {
+            query: format!(
+                "topk({}, increase(portsyncd_port_flap_total[{}]))",
+                count,
+                window.to_promql_duration()
+            ),
+            category: QueryCategory::TrendAnalysis,
+            description: format!(
+                "Top {} flapping ports over {}",
+                count,
+                window.to_promql_duration()
+            ),
+        }
+    }
+
+    /// Per-port link event latency percentile
+    pub fn port_event_latency_percentile(percentile: u32, window: TimeWindow) -> PromQLQuery {
+        PromQLQuery {
+            query: format!(
+                "histogram_quantile({}, sum by (port, le) (rate(portsyncd_port_event_latency_seconds_bucket[{}])))",
+                f64::from(percentile) / 100.0,
+                window.to_promql_duration()
+            ),
+            category: QueryCategory::Latency,
+            description: format!(
+                "Per-port P{} link event latency over {}",
+                percentile,
+                window.to_promql_duration()
+            ),
+        }
+    }
+
     /// Get all pre-defined queries for a category
     pub fn queries_for_category(category: QueryCategory) -> Vec<PromQLQuery> {
         match category {
@@ -384,11 +445,13 @@ impl PromQLBuilder {
                 Self::health_score(),
                 Self::warm_restart_success_rate(),
                 Self::reliability_score(),
+                Self::ports_oper_down(),
             ],
             QueryCategory::TrendAnalysis => vec![
                 Self::restart_trend(TimeWindow::FiveMinutes),
                 Self::corruption_trend(TimeWindow::FiveMinutes),
                 Self::recovery_trend(TimeWindow::FiveMinutes),
+                Self::top_flapping_ports(10, TimeWindow::OneHour),
             ],
             QueryCategory::Throughput => vec![
                 Self::event_throughput(TimeWindow::FiveMinutes),
@@ -399,11 +462,13 @@ impl PromQLBuilder {
                 Self::p50_sync_duration(),
                 Self::sync_duration_percentile(95),
                 Self::sync_duration_percentile(99),
+                Self::port_event_latency_percentile(99, TimeWindow::FiveMinutes),
             ],
             QueryCategory::Reliability => vec![
                 Self::system_availability(TimeWindow::OneHour),
                 Self::backup_success_rate(),
                 Self::time_since_last_warm_restart(),
+                Self::port_flap_rate(TimeWindow::FiveMinutes),
             ],
         }
     }
@@ -427,26 +492,30 @@ impl PromQLBuilder {
             Self::eoiu_timeout_rate(),
             Self::cold_start_rate(),
             Self::error_rate(five_min),
-            // Health metrics (3 queries)
+            // Health metrics (4 queries)
             Self::health_score(),
             Self::warm_restart_success_rate(),
             Self::reliability_score(),
-            // Trends (3 queries)
+            Self::ports_oper_down(),
+            // Trends (4 queries)
             Self::restart_trend(five_min),
             Self::corruption_trend(five_min),
             Self::recovery_trend(five_min),
+            Self::top_flapping_ports(10, one_hour),
             // Throughput (3 queries)
             Self::event_throughput(five_min),
             Self::backup_throughput(five_min),
             Self::eoiu_throughput(five_min),
-            // Latency (3 queries)
+            // Latency (4 queries)
             Self::p50_sync_duration(),
             Self::sync_duration_percentile(95),
             Self::sync_duration_percentile(99),
-            // Reliability (3 queries)
+            Self::port_event_latency_percentile(99, five_min),
+            // Reliability (4 queries)
             Self::system_availability(one_hour),
             Self::backup_success_rate(),
             Self::time_since_last_warm_restart(),
+            Self::port_flap_rate(five_min),
         ]
     }
 }
@@ -555,6 +624,39 @@ mod tests {
         assert_eq!(query.category, QueryCategory::Reliability);
     }
 
+    #[test]
+    fn test_per_port_queries() {
+        let down = PromQLBuilder::ports_oper_down();
+        assert_eq!(down.category, QueryCategory::HealthMetrics);
+        assert!(down.query.contains("portsyncd_port_oper_state"));
+        assert!(down.query.contains("port!=\"other\""));
+
+        let flaps = PromQLBuilder::port_flap_rate(TimeWindow::FiveMinutes);
+        assert_eq!(flaps.category, QueryCategory::Reliability);
+        assert_eq!(
+            flaps.query,
+            "sum by (port) (rate(portsyncd_port_flap_total[5m]))"
+        );
+
+        let top = PromQLBuilder::top_flapping_ports(5, TimeWindow::OneHour);
+        assert_eq!(
+            top.query,
+            "topk(5, increase(portsyncd_port_flap_total[1h]))"
+        );
+
+        let p99 = PromQLBuilder::port_event_latency_percentile(99, TimeWindow::FiveMinutes);
+        assert_eq!(p99.category, QueryCategory::Latency);
+        assert!(p99.query.starts_with("histogram_quantile(0.99, "));
+        assert!(p99.query.contains("sum by (port, le)"));
+        assert!(
+            p99.query
+                .contains("portsyncd_port_event_latency_seconds_bucket[5m]")
+        );
+
+        let p50 = PromQLBuilder::port_event_latency_percentile(50, TimeWindow::OneMinute);
+        assert!(p50.query.starts_with("histogram_quantile(0.5, "));
+    }
+
     #[test]
     fn test_queries_for_category() {
         let recovery_queries = PromQLBuilder::queries_for_category(QueryCategory::RecoveryRates);
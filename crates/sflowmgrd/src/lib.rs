@@ -14,19 +14,23 @@
 //! ## Configuration Sources
 //! - `SFLOW` table: Global configuration
 //! - `SFLOW_SESSION` table: Per-interface or "all" configuration
+//! - `SFLOW_COLLECTOR` table: Up to two collectors
 //! - `PORT` table: Port speed information
 //! - `PORT_TABLE` (STATE_DB): Operational speed updates
 //!
 //! ## Key Features
 //! - No shell commands for configuration (pure DB pass-through)
-//! - Service control via systemd (hsflowd start/stop/restart)
+//! - Service control via systemd (hsflowd start/stop/restart) behind
+//!   [`SystemdController`]
 //! - Default sampling rate equals port speed
 //! - Local per-port configuration overrides global configuration
 
 mod sflow_mgr;
+mod systemd;
 mod tables;
 mod types;
 
 pub use sflow_mgr::SflowMgr;
+pub use systemd::{ShellSystemdController, SystemdController, HSFLOWD_UNIT};
 pub use tables::*;
 pub use types::*;
@@ -6,8 +6,12 @@ use std::process::ExitCode;
 use tracing::{error, info, Level};
 use tracing_subscriber::FmtSubscriber;
 
+use sonic_cfgmgr_common::CfgMgrRunner;
 use sonic_sflowmgrd::SflowMgr;
 
+/// Redis server holding CONFIG_DB, APPL_DB and STATE_DB.
+const DEFAULT_REDIS_URL: &str = "redis://127.0.0.1:6379";
+
 /// Initializes tracing/logging subsystem
 fn init_logging() {
     let subscriber = FmtSubscriber::builder()
@@ -18,28 +22,35 @@ fn init_logging() {
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set tracing subscriber");
 }
 
+/// Runs the sFlow manager until SIGTERM.
+async fn run_event_loop(mgr: SflowMgr) -> Result<(), Box<dyn std::error::Error>> {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| DEFAULT_REDIS_URL.to_string());
+
+    CfgMgrRunner::new(mgr)
+        .with_redis(&redis_url)
+        .await?
+        .run()
+        .await?;
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> ExitCode {
     init_logging();
 
     info!("--- Starting sflowmgrd (Rust) ---");
 
-    let _mgr = SflowMgr::new();
-
-    // TODO: Implement event loop when swss-common bindings are ready
-    // For now, this is a placeholder that demonstrates the daemon structure
-
-    info!("sflowmgrd initialization complete (placeholder mode)");
-    info!("Full implementation pending swss-common Consumer/Producer integration");
-
-    // In production, this would run the event loop:
-    // match run_event_loop(mgr).await {
-    //     Ok(()) => ExitCode::SUCCESS,
-    //     Err(e) => {
-    //         error!("sflowmgrd failed: {}", e);
-    //         ExitCode::FAILURE
-    //     }
-    // }
-
-    ExitCode::SUCCESS
+    let mgr = SflowMgr::new();
+
+    match run_event_loop(mgr).await {
+        Ok(()) => {
+            info!("sflowmgrd exiting normally");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("sflowmgrd failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
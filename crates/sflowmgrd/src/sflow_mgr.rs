@@ -1,16 +1,23 @@
 //! SflowMgr - Core sFlow configuration manager implementation
 
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
 use tracing::{debug, error, info, instrument, warn};
 
-use sonic_cfgmgr_common::{shell, CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, Orch};
+use sonic_cfgmgr_common::shell::Executor;
+use sonic_cfgmgr_common::{
+    CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, FieldValuesExt, KeyOpFieldsValues, Orch,
+};
 
 use crate::constants::*;
 use crate::fields;
-use crate::types::SflowPortInfo;
+use crate::systemd::{ShellSystemdController, SystemdController, HSFLOWD_UNIT};
+use crate::types::{SflowCollector, SflowLocalConfig, SflowPortInfo};
 use crate::{
-    CFG_PORT_TABLE_NAME, CFG_SFLOW_SESSION_TABLE_NAME, CFG_SFLOW_TABLE_NAME, STATE_PORT_TABLE_NAME,
+    is_valid_direction, APP_SFLOW_COLLECTOR_TABLE_NAME, APP_SFLOW_SESSION_TABLE_NAME,
+    APP_SFLOW_TABLE_NAME, CFG_PORT_TABLE_NAME, CFG_SFLOW_COLLECTOR_TABLE_NAME,
+    CFG_SFLOW_SESSION_TABLE_NAME, CFG_SFLOW_TABLE_NAME, STATE_PORT_TABLE_NAME,
 };
 
 /// SflowMgr manages sFlow sampling configuration
@@ -18,12 +25,19 @@ use crate::{
 /// Configuration flow:
 /// 1. Global config: SFLOW table → APP_SFLOW_TABLE + service control
 /// 2. Session config: SFLOW_SESSION table → APP_SFLOW_SESSION_TABLE
-/// 3. Port speed: PORT table → updates sampling rates
-/// 4. Oper speed: PORT_TABLE (STATE_DB) → updates sampling rates
+/// 3. Collectors: SFLOW_COLLECTOR table → APP_SFLOW_COLLECTOR_TABLE + service control
+/// 4. Port speed: PORT table → updates sampling rates
+/// 5. Oper speed: PORT_TABLE (STATE_DB) → updates sampling rates
 pub struct SflowMgr {
     /// Per-port configuration map
     port_config_map: HashMap<String, SflowPortInfo>,
 
+    /// SFLOW_SESSION_TABLE entries currently written to APPL_DB
+    applied_sessions: HashMap<String, FieldValues>,
+
+    /// Queued entries from the subscribed tables: (table, entry)
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
     /// Global sFlow enable/disable
     global_enable: bool,
 
@@ -36,13 +50,18 @@ pub struct SflowMgr {
     /// Direction for "all interfaces" configuration
     intf_all_dir: String,
 
-    /// Mock mode for testing (capture commands instead of executing)
-    #[cfg(test)]
-    mock_mode: bool,
+    /// Configured collectors by name
+    collectors: BTreeMap<String, SflowCollector>,
 
-    /// Captured service commands in mock mode
+    /// Agent interface from the global configuration
+    agent_id: Option<String>,
+
+    /// hsflowd unit control
+    systemd: Arc<dyn SystemdController>,
+
+    /// APPL_DB operations for testing: (table, key, fvs or None for DEL)
     #[cfg(test)]
-    captured_service_commands: Vec<String>,
+    app_db_ops: Vec<(String, String, Option<FieldValues>)>,
 }
 
 impl SflowMgr {
@@ -50,28 +69,34 @@ impl SflowMgr {
     pub fn new() -> Self {
         Self {
             port_config_map: HashMap::new(),
+            applied_sessions: HashMap::new(),
+            tasks: VecDeque::new(),
             global_enable: false,
             global_direction: DEFAULT_DIRECTION.to_string(),
             intf_all_conf: true,
             intf_all_dir: DEFAULT_DIRECTION.to_string(),
+            collectors: BTreeMap::new(),
+            agent_id: None,
+            systemd: Arc::new(ShellSystemdController::new()),
             #[cfg(test)]
-            mock_mode: false,
-            #[cfg(test)]
-            captured_service_commands: Vec::new(),
+            app_db_ops: Vec::new(),
         }
     }
 
-    /// Enables mock mode for testing
-    #[cfg(test)]
-    pub fn with_mock_mode(mut self) -> Self {
-        self.mock_mode = true;
+    /// Sets the controller used to restart and stop hsflowd
+    pub fn with_systemd(mut self, systemd: Arc<dyn SystemdController>) -> Self {
+        self.systemd = systemd;
         self
     }
 
-    /// Gets captured service commands (for testing)
-    #[cfg(test)]
-    pub fn captured_service_commands(&self) -> &[String] {
-        &self.captured_service_commands
+    /// Runs `systemctl` for hsflowd through the given executor
+    pub fn with_executor(self, executor: Arc<dyn Executor>) -> Self {
+        self.with_systemd(Arc::new(ShellSystemdController::with_executor(executor)))
+    }
+
+    /// Returns the configured collectors by name
+    pub fn collectors(&self) -> &BTreeMap<String, SflowCollector> {
+        &self.collectors
     }
 
     /// Checks if a port is enabled for sFlow sampling
@@ -87,10 +112,9 @@ impl SflowMgr {
             None => return false,
         };
 
-        let local_admin = port_info.local_admin_cfg;
-        let status = port_info.admin == "up";
+        let local_admin_up = port_info.local.admin.as_deref() == Some("up");
 
-        self.global_enable && (self.intf_all_conf || (local_admin && status))
+        self.global_enable && (self.intf_all_conf || local_admin_up)
     }
 
     /// Finds the appropriate sampling rate for a port
@@ -121,305 +145,378 @@ impl SflowMgr {
 
     /// Handles hsflowd service lifecycle
     ///
-    /// Commands:
-    /// - `enable=true`: systemctl restart hsflowd
-    /// - `enable=false`: systemctl stop hsflowd
+    /// - `enable=true`: restart hsflowd
+    /// - `enable=false`: stop hsflowd
     #[instrument(skip(self))]
     pub async fn handle_service(&mut self, enable: bool) -> CfgMgrResult<()> {
-        let cmd = if enable {
-            "systemctl restart hsflowd"
+        let result = if enable {
+            self.systemd.restart(HSFLOWD_UNIT).await
         } else {
-            "systemctl stop hsflowd"
+            self.systemd.stop(HSFLOWD_UNIT).await
         };
 
-        #[cfg(test)]
-        if self.mock_mode {
-            self.captured_service_commands.push(cmd.to_string());
-            info!("Mock mode: captured service command: {}", cmd);
-            return Ok(());
-        }
-
-        match shell::exec(cmd).await {
-            Ok(result) if result.success() => {
-                info!("Service command succeeded: {}", cmd);
-                Ok(())
-            }
-            Ok(result) => {
-                warn!(
-                    "Service command failed: {} (exit code: {})",
-                    cmd, result.exit_code
-                );
-                Err(CfgMgrError::ShellCommandFailed {
-                    command: cmd.to_string(),
-                    exit_code: result.exit_code,
-                    output: result.stderr,
-                })
-            }
-            Err(e) => {
-                error!("Failed to execute service command: {}", e);
-                Err(e)
-            }
+        if let Err(e) = &result {
+            error!("Failed to control {}: {}", HSFLOWD_UNIT, e);
         }
+        result
     }
 
-    /// Builds field-value tuples for global sFlow session configuration
-    fn build_global_session_fvs(&self, alias: &str, direction: &str) -> FieldValues {
+    /// Builds the SFLOW_SESSION_TABLE entry of a port
+    ///
+    /// Local overrides win; fields without one fall back to the "all"
+    /// session (admin up, its direction) and the speed-based rate.
+    fn build_port_session_fvs(&self, alias: &str, port_info: &SflowPortInfo) -> FieldValues {
+        let local = &port_info.local;
         vec![
             (
                 fields::ADMIN_STATE.to_string(),
-                DEFAULT_ADMIN_STATE.to_string(),
+                local
+                    .admin
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_ADMIN_STATE.to_string()),
             ),
             (
                 fields::SAMPLE_RATE.to_string(),
-                self.find_sampling_rate(alias),
+                local
+                    .rate
+                    .clone()
+                    .unwrap_or_else(|| self.find_sampling_rate(alias)),
+            ),
+            (
+                fields::SAMPLE_DIRECTION.to_string(),
+                local
+                    .dir
+                    .clone()
+                    .unwrap_or_else(|| self.intf_all_dir.clone()),
             ),
-            (fields::SAMPLE_DIRECTION.to_string(), direction.to_string()),
         ]
     }
 
-    /// Builds field-value tuples for port-specific sFlow session configuration
-    fn build_port_session_fvs(&self, port_info: &SflowPortInfo) -> FieldValues {
-        let mut fvs = Vec::new();
+    /// Computes the SFLOW_SESSION_TABLE entry a port should have, if any
+    ///
+    /// Nothing is sampled while sFlow is globally disabled. With the "all"
+    /// session disabled only ports with a local admin state get an entry.
+    fn desired_session(&self, alias: &str) -> Option<FieldValues> {
+        let port_info = self.port_config_map.get(alias)?;
 
-        if port_info.local_admin_cfg {
-            fvs.push((fields::ADMIN_STATE.to_string(), port_info.admin.clone()));
+        if !self.global_enable || (!self.intf_all_conf && port_info.local.admin.is_none()) {
+            return None;
         }
 
-        fvs.push((fields::SAMPLE_RATE.to_string(), port_info.rate.clone()));
+        let fvs = self.build_port_session_fvs(alias, port_info);
+        if fvs.get_field(fields::SAMPLE_RATE) == Some(ERROR_SPEED) {
+            debug!("Speed of {} is unknown, not sampling it yet", alias);
+            return None;
+        }
+        Some(fvs)
+    }
 
-        if port_info.local_dir_cfg {
-            fvs.push((fields::SAMPLE_DIRECTION.to_string(), port_info.dir.clone()));
+    /// Brings the APPL_DB session of a port in line with its configuration
+    ///
+    /// Only writes when the entry actually changes.
+    async fn sync_port_session(&mut self, alias: &str) -> CfgMgrResult<()> {
+        let desired = self.desired_session(alias);
+        if self.applied_sessions.get(alias) == desired.as_ref() {
+            return Ok(());
         }
 
-        fvs
+        match desired {
+            Some(fvs) => {
+                self.write_to_app_db_session(alias, fvs.clone()).await?;
+                self.applied_sessions.insert(alias.to_string(), fvs);
+            }
+            None => {
+                self.delete_from_app_db_session(alias).await?;
+                self.applied_sessions.remove(alias);
+            }
+        }
+        Ok(())
     }
 
-    /// Handles session configuration for all ports
+    /// Re-syncs the sessions of every known port
     ///
-    /// Called when global "all interfaces" configuration changes
+    /// Used after global or "all" session changes; also cleans up sessions
+    /// of ports that no longer exist.
     #[instrument(skip(self))]
-    pub async fn handle_session_all(&mut self, enable: bool, direction: &str) -> CfgMgrResult<()> {
-        for (alias, port_info) in &self.port_config_map {
-            if enable {
-                let fvs = if port_info.has_local_config() {
-                    let mut fvs = self.build_port_session_fvs(port_info);
-
-                    // Use global admin state if not locally configured
-                    if !port_info.local_admin_cfg {
-                        fvs.push((
-                            fields::ADMIN_STATE.to_string(),
-                            DEFAULT_ADMIN_STATE.to_string(),
-                        ));
-                    }
-
-                    // Use global direction if not locally configured
-                    if !port_info.local_dir_cfg {
-                        fvs.push((fields::SAMPLE_DIRECTION.to_string(), direction.to_string()));
-                    }
-
-                    fvs
-                } else {
-                    self.build_global_session_fvs(alias, direction)
-                };
-
-                self.write_to_app_db_session(alias, fvs).await?;
-            } else if !port_info.local_admin_cfg {
-                self.delete_from_app_db_session(alias).await?;
-            }
+    pub async fn sync_all_sessions(&mut self) -> CfgMgrResult<()> {
+        let mut aliases: Vec<String> = self
+            .port_config_map
+            .keys()
+            .chain(self.applied_sessions.keys())
+            .cloned()
+            .collect();
+        aliases.sort();
+        aliases.dedup();
+
+        for alias in aliases {
+            self.sync_port_session(&alias).await?;
         }
+        Ok(())
+    }
 
+    /// Writes an entry to an APPL_DB table
+    #[instrument(skip(self, fvs))]
+    async fn write_to_app_db(
+        &mut self,
+        table: &str,
+        key: &str,
+        fvs: FieldValues,
+    ) -> CfgMgrResult<()> {
+        debug!("Writing {}:{} {:?}", table, key, fvs);
+        #[cfg(test)]
+        self.app_db_ops
+            .push((table.to_string(), key.to_string(), Some(fvs)));
         Ok(())
     }
 
-    /// Handles session configuration for ports with local configuration
+    /// Deletes an entry from an APPL_DB table
     #[instrument(skip(self))]
-    pub async fn handle_session_local(&mut self, enable: bool) -> CfgMgrResult<()> {
-        for (alias, port_info) in &self.port_config_map {
-            if port_info.has_local_config() {
-                let fvs = self.build_port_session_fvs(port_info);
-
-                if enable {
-                    self.write_to_app_db_session(alias, fvs).await?;
-                } else {
-                    self.delete_from_app_db_session(alias).await?;
-                }
+    async fn delete_from_app_db(&mut self, table: &str, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", table, key);
+        #[cfg(test)]
+        self.app_db_ops
+            .push((table.to_string(), key.to_string(), None));
+        Ok(())
+    }
+
+    /// Writes configuration to APPL_DB SFLOW_TABLE
+    async fn write_to_app_db_sflow(&mut self, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        self.write_to_app_db(APP_SFLOW_TABLE_NAME, key, fvs).await
+    }
+
+    /// Writes configuration to APPL_DB SFLOW_SESSION_TABLE
+    async fn write_to_app_db_session(&mut self, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        self.write_to_app_db(APP_SFLOW_SESSION_TABLE_NAME, key, fvs)
+            .await
+    }
+
+    /// Deletes entry from APPL_DB SFLOW_TABLE
+    async fn delete_from_app_db_sflow(&mut self, key: &str) -> CfgMgrResult<()> {
+        self.delete_from_app_db(APP_SFLOW_TABLE_NAME, key).await
+    }
+
+    /// Deletes entry from APPL_DB SFLOW_SESSION_TABLE
+    async fn delete_from_app_db_session(&mut self, key: &str) -> CfgMgrResult<()> {
+        self.delete_from_app_db(APP_SFLOW_SESSION_TABLE_NAME, key)
+            .await
+    }
+
+    /// Checks the sample direction of an entry, logging an invalid one
+    fn check_direction(key: &str, values: Option<&FieldValues>) -> bool {
+        match values.and_then(|fvs| fvs.get_field(fields::SAMPLE_DIRECTION)) {
+            Some(dir) if !is_valid_direction(dir) => {
+                error!(
+                    "Invalid sample_direction '{}' for {}, expected one of {:?}",
+                    dir, key, SAMPLE_DIRECTIONS
+                );
+                false
             }
+            _ => true,
         }
-
-        Ok(())
     }
 
-    /// Processes and fills missing configuration values for a port session
-    ///
-    /// This handles the logic where:
-    /// - Local config values are used when present
-    /// - Global/default values are filled in when local config is absent
+    /// Processes SFLOW table updates (global configuration)
     #[instrument(skip(self, values))]
-    pub fn check_and_fill_values(
+    pub async fn process_global(
         &mut self,
-        alias: &str,
-        values: &FieldValues,
-    ) -> CfgMgrResult<FieldValues> {
-        // First pass: collect values and determine what's present
-        let mut rate_present = false;
-        let mut admin_present = false;
-        let mut dir_present = false;
-        let mut fvs = Vec::new();
-
-        // Extract alias clone for find_sampling_rate call
-        let alias_owned = alias.to_string();
-
-        // Process provided values
-        for (field, value) in values {
-            match field.as_str() {
-                fields::SAMPLE_RATE => {
-                    rate_present = true;
-                    fvs.push((field.clone(), value.clone()));
-                }
-                fields::ADMIN_STATE => {
-                    admin_present = true;
-                    fvs.push((field.clone(), value.clone()));
-                }
-                fields::SAMPLE_DIRECTION => {
-                    dir_present = true;
-                    fvs.push((field.clone(), value.clone()));
-                }
-                "NULL" => continue,
-                _ => {}
-            }
+        key: &str,
+        values: Option<&FieldValues>,
+    ) -> CfgMgrResult<()> {
+        if key != GLOBAL_KEY {
+            warn!("Ignoring unknown SFLOW key {}", key);
+            return Ok(());
         }
 
-        // Get or create port_info and update it
-        let port_info = self.port_config_map.entry(alias_owned.clone()).or_default();
-
-        // Update port_info based on what was present
-        for (field, value) in values {
-            match field.as_str() {
-                fields::SAMPLE_RATE => {
-                    port_info.rate = value.clone();
-                    port_info.local_rate_cfg = true;
-                }
-                fields::ADMIN_STATE => {
-                    port_info.admin = value.clone();
-                    port_info.local_admin_cfg = true;
-                }
-                fields::SAMPLE_DIRECTION => {
-                    port_info.dir = value.clone();
-                    port_info.local_dir_cfg = true;
-                }
-                _ => {}
-            }
+        if !Self::check_direction(key, values) {
+            return Ok(());
         }
 
-        // Fill missing values with defaults
-        if !rate_present {
-            let default_rate = if port_info.rate.is_empty() || port_info.local_rate_cfg {
-                self.find_sampling_rate(&alias_owned)
-            } else {
-                port_info.rate.clone()
-            };
+        let enable = values
+            .and_then(|fvs| fvs.get_field(fields::ADMIN_STATE))
+            .is_some_and(|admin| admin == "up");
+        self.global_direction = values
+            .and_then(|fvs| fvs.get_field(fields::SAMPLE_DIRECTION))
+            .unwrap_or(DEFAULT_DIRECTION)
+            .to_string();
+        let agent_id = values
+            .and_then(|fvs| fvs.get_field(fields::AGENT_ID))
+            .map(str::to_string);
+        let agent_changed = agent_id != self.agent_id;
+        self.agent_id = agent_id;
+
+        if enable != self.global_enable {
+            self.global_enable = enable;
+            self.handle_service(enable).await?;
+        } else if enable && agent_changed {
+            info!("sFlow agent changed to {:?}", self.agent_id);
+            self.handle_service(true).await?;
+        }
 
-            let port_info_mut = self.port_config_map.get_mut(&alias_owned).unwrap();
-            port_info_mut.rate = default_rate.clone();
-            port_info_mut.local_rate_cfg = false;
-            fvs.push((fields::SAMPLE_RATE.to_string(), default_rate));
+        match values {
+            Some(fvs) => self.write_to_app_db_sflow(key, fvs.clone()).await?,
+            None => self.delete_from_app_db_sflow(key).await?,
         }
 
-        if !admin_present {
-            let port_info_mut = self.port_config_map.get_mut(&alias_owned).unwrap();
-            if port_info_mut.admin.is_empty() {
-                port_info_mut.admin = DEFAULT_ADMIN_STATE.to_string();
-            }
-            let admin_value = port_info_mut.admin.clone();
-            port_info_mut.local_admin_cfg = false;
-            fvs.push((fields::ADMIN_STATE.to_string(), admin_value));
+        self.sync_all_sessions().await
+    }
+
+    /// Processes SFLOW_SESSION table updates ("all" or a single port)
+    ///
+    /// The "all" direction is inherited by every port without a local
+    /// direction; only ports whose entry changes are rewritten. Entries
+    /// with an unknown direction are dropped without touching APPL_DB.
+    #[instrument(skip(self, values))]
+    pub async fn process_session(
+        &mut self,
+        key: &str,
+        values: Option<&FieldValues>,
+    ) -> CfgMgrResult<()> {
+        if !Self::check_direction(key, values) {
+            return Ok(());
         }
 
-        if !dir_present {
-            let port_info_mut = self.port_config_map.get_mut(&alias_owned).unwrap();
-            if port_info_mut.dir.is_empty() {
-                port_info_mut.dir = self.global_direction.clone();
-            }
-            let dir_value = port_info_mut.dir.clone();
-            port_info_mut.local_dir_cfg = false;
-            fvs.push((fields::SAMPLE_DIRECTION.to_string(), dir_value));
+        if key == ALL_INTERFACES {
+            self.intf_all_conf = values
+                .and_then(|fvs| fvs.get_field(fields::ADMIN_STATE))
+                .map_or(true, |admin| admin == "up");
+            self.intf_all_dir = values
+                .and_then(|fvs| fvs.get_field(fields::SAMPLE_DIRECTION))
+                .unwrap_or(&self.global_direction)
+                .to_string();
+            return self.sync_all_sessions().await;
         }
 
-        Ok(fvs)
+        // A SET carries the whole CONFIG_DB entry, so absent fields are
+        // overrides that were removed
+        let local = SflowLocalConfig {
+            rate: values
+                .and_then(|fvs| fvs.get_field(fields::SAMPLE_RATE))
+                .map(str::to_string),
+            admin: values
+                .and_then(|fvs| fvs.get_field(fields::ADMIN_STATE))
+                .map(str::to_string),
+            dir: values
+                .and_then(|fvs| fvs.get_field(fields::SAMPLE_DIRECTION))
+                .map(str::to_string),
+        };
+
+        self.port_config_map
+            .entry(key.to_string())
+            .or_insert_with(SflowPortInfo::new)
+            .local = local;
+
+        self.sync_port_session(key).await
     }
 
-    /// Stub: Writes configuration to APPL_DB SFLOW_TABLE
+    /// Processes SFLOW_COLLECTOR table updates
     ///
-    /// In production, this would use ProducerStateTable
-    #[instrument(skip(self, _fvs))]
-    async fn write_to_app_db_sflow(&self, _key: &str, _fvs: FieldValues) -> CfgMgrResult<()> {
-        // TODO: Implement with real ProducerStateTable
-        debug!("Would write to APP_SFLOW_TABLE");
-        Ok(())
-    }
+    /// hsflowd is restarted whenever the collector set changes, and stopped
+    /// once the last collector is gone while sFlow is globally disabled.
+    /// At most [`MAX_COLLECTORS`] collectors are accepted.
+    #[instrument(skip(self, values))]
+    pub async fn process_collector(
+        &mut self,
+        key: &str,
+        values: Option<&FieldValues>,
+    ) -> CfgMgrResult<()> {
+        let Some(values) = values else {
+            if self.collectors.remove(key).is_none() {
+                return Ok(());
+            }
+            info!("Removed sFlow collector {}", key);
+            self.delete_from_app_db(APP_SFLOW_COLLECTOR_TABLE_NAME, key)
+                .await?;
 
-    /// Stub: Writes configuration to APPL_DB SFLOW_SESSION_TABLE
-    #[instrument(skip(self, _fvs))]
-    async fn write_to_app_db_session(&self, _key: &str, _fvs: FieldValues) -> CfgMgrResult<()> {
-        // TODO: Implement with real ProducerStateTable
-        debug!("Would write to APP_SFLOW_SESSION_TABLE");
-        Ok(())
-    }
+            return if self.collectors.is_empty() && !self.global_enable {
+                self.handle_service(false).await
+            } else {
+                self.handle_service(true).await
+            };
+        };
 
-    /// Stub: Deletes entry from APPL_DB SFLOW_TABLE
-    #[instrument(skip(self))]
-    async fn delete_from_app_db_sflow(&self, _key: &str) -> CfgMgrResult<()> {
-        // TODO: Implement with real ProducerStateTable
-        debug!("Would delete from APP_SFLOW_TABLE");
-        Ok(())
-    }
+        let collector = SflowCollector::from_fvs(values)?;
+        if self.collectors.get(key) == Some(&collector) {
+            return Ok(());
+        }
+        if !self.collectors.contains_key(key) && self.collectors.len() >= MAX_COLLECTORS {
+            return Err(CfgMgrError::invalid_config(
+                CFG_SFLOW_COLLECTOR_TABLE_NAME,
+                format!(
+                    "cannot add {}: at most {} collectors are supported",
+                    key, MAX_COLLECTORS
+                ),
+            ));
+        }
 
-    /// Stub: Deletes entry from APPL_DB SFLOW_SESSION_TABLE
-    #[instrument(skip(self))]
-    async fn delete_from_app_db_session(&self, _key: &str) -> CfgMgrResult<()> {
-        // TODO: Implement with real ProducerStateTable
-        debug!("Would delete from APP_SFLOW_SESSION_TABLE");
-        Ok(())
+        info!(
+            "sFlow collector {} is {}:{} (vrf {})",
+            key, collector.ip, collector.port, collector.vrf
+        );
+        self.write_to_app_db(APP_SFLOW_COLLECTOR_TABLE_NAME, key, collector.to_fvs())
+            .await?;
+        self.collectors.insert(key.to_string(), collector);
+
+        self.handle_service(true).await
     }
 
     /// Processes PORT table updates (port speed changes)
-    #[instrument(skip(self, _key, _op, values))]
+    #[instrument(skip(self, values))]
     pub async fn process_port_update(
         &mut self,
-        _key: &str,
-        _op: &str,
-        values: &FieldValues,
+        key: &str,
+        values: Option<&FieldValues>,
     ) -> CfgMgrResult<()> {
-        // Extract speed from values
-        let new_speed = values
-            .iter()
-            .find(|(field, _)| field == fields::SPEED)
-            .map(|(_, value)| value.clone())
-            .unwrap_or_else(|| ERROR_SPEED.to_string());
+        let Some(values) = values else {
+            self.port_config_map.remove(key);
+            return self.sync_port_session(key).await;
+        };
 
-        debug!("Port speed update: {}", new_speed);
-        // TODO: Implement full port update logic from C++
-        Ok(())
+        let new_speed = values.get_field_or(fields::SPEED, ERROR_SPEED).to_string();
+        debug!("Port {} speed update: {}", key, new_speed);
+
+        self.port_config_map
+            .entry(key.to_string())
+            .or_insert_with(SflowPortInfo::new)
+            .speed = new_speed;
+
+        self.sync_port_session(key).await
     }
 
     /// Processes STATE_DB PORT_TABLE updates (operational speed)
-    #[instrument(skip(self, _key, _op, values))]
+    #[instrument(skip(self, values))]
     pub async fn process_oper_speed(
         &mut self,
-        _key: &str,
-        _op: &str,
-        values: &FieldValues,
+        key: &str,
+        values: Option<&FieldValues>,
     ) -> CfgMgrResult<()> {
-        // Extract oper_speed from values
+        let Some(port_info) = self.port_config_map.get_mut(key) else {
+            return Ok(());
+        };
+
         let oper_speed = values
-            .iter()
-            .find(|(field, _)| field == fields::SPEED)
-            .map(|(_, value)| value.clone())
-            .unwrap_or_default();
+            .and_then(|fvs| fvs.get_field(fields::SPEED))
+            .unwrap_or(NA_SPEED)
+            .to_string();
+        debug!("Port {} operational speed update: {}", key, oper_speed);
+        port_info.oper_speed = oper_speed;
 
-        debug!("Operational speed update: {}", oper_speed);
-        // TODO: Implement full oper speed processing from C++
-        Ok(())
+        self.sync_port_session(key).await
+    }
+
+    /// Processes one queued entry
+    async fn process_task(&mut self, table: &str, entry: KeyOpFieldsValues) -> CfgMgrResult<()> {
+        let values = entry.op.is_set().then_some(&entry.fvs);
+        match table {
+            CFG_SFLOW_TABLE_NAME => self.process_global(&entry.key, values).await,
+            CFG_SFLOW_SESSION_TABLE_NAME => self.process_session(&entry.key, values).await,
+            CFG_SFLOW_COLLECTOR_TABLE_NAME => self.process_collector(&entry.key, values).await,
+            CFG_PORT_TABLE_NAME => self.process_port_update(&entry.key, values).await,
+            STATE_PORT_TABLE_NAME => self.process_oper_speed(&entry.key, values).await,
+            _ => {
+                warn!("Ignoring {}|{} from unexpected table", table, entry.key);
+                Ok(())
+            }
+        }
     }
 }
 
@@ -437,11 +534,16 @@ impl Orch for SflowMgr {
     }
 
     async fn do_task(&mut self) {
-        // Placeholder - actual implementation would:
-        // 1. Drain consumers for each subscribed table
-        // 2. Process entries based on table type
-        // 3. Update APPL_DB via producers
-        debug!("do_task called (placeholder)");
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            let key = entry.key.clone();
+            if let Err(e) = self.process_task(&table, entry).await {
+                error!("Failed to process {}|{}: {}", table, key, e);
+            }
+        }
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        !self.tasks.is_empty()
     }
 }
 
@@ -468,6 +570,7 @@ impl CfgMgr for SflowMgr {
         &[
             CFG_SFLOW_TABLE_NAME,
             CFG_SFLOW_SESSION_TABLE_NAME,
+            CFG_SFLOW_COLLECTOR_TABLE_NAME,
             CFG_PORT_TABLE_NAME,
         ]
     }
@@ -475,11 +578,61 @@ impl CfgMgr for SflowMgr {
     fn state_table_names(&self) -> &[&str] {
         &[STATE_PORT_TABLE_NAME]
     }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        match (db, table) {
+            (DbId::ConfigDb, _) | (DbId::StateDb, STATE_PORT_TABLE_NAME) => {
+                self.tasks
+                    .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+            }
+            _ => debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    /// Records systemd actions as "restart <unit>" / "stop <unit>"
+    #[derive(Default)]
+    struct RecordingSystemd {
+        actions: Mutex<Vec<String>>,
+    }
+
+    impl RecordingSystemd {
+        fn actions(&self) -> Vec<String> {
+            self.actions.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl SystemdController for RecordingSystemd {
+        async fn restart(&self, unit: &str) -> CfgMgrResult<()> {
+            self.actions
+                .lock()
+                .unwrap()
+                .push(format!("restart {}", unit));
+            Ok(())
+        }
+
+        async fn stop(&self, unit: &str) -> CfgMgrResult<()> {
+            self.actions.lock().unwrap().push(format!("stop {}", unit));
+            Ok(())
+        }
+    }
+
+    fn mock_mgr() -> (SflowMgr, Arc<RecordingSystemd>) {
+        let systemd = Arc::new(RecordingSystemd::default());
+        let mgr = SflowMgr::new().with_systemd(systemd.clone());
+        (mgr, systemd)
+    }
 
     #[test]
     fn test_sflow_mgr_new() {
@@ -497,8 +650,7 @@ mod tests {
         mgr.global_enable = false;
 
         let mut port_info = SflowPortInfo::new();
-        port_info.local_admin_cfg = true;
-        port_info.admin = "up".to_string();
+        port_info.local.admin = Some("up".to_string());
         mgr.port_config_map
             .insert("Ethernet0".to_string(), port_info);
 
@@ -525,8 +677,7 @@ mod tests {
         mgr.intf_all_conf = false;
 
         let mut port_info = SflowPortInfo::new();
-        port_info.local_admin_cfg = true;
-        port_info.admin = "up".to_string();
+        port_info.local.admin = Some("up".to_string());
         mgr.port_config_map
             .insert("Ethernet0".to_string(), port_info);
 
@@ -567,34 +718,30 @@ mod tests {
 
     #[tokio::test]
     async fn test_handle_service_enable() {
-        let mut mgr = SflowMgr::new().with_mock_mode();
+        let (mut mgr, systemd) = mock_mgr();
         mgr.handle_service(true).await.unwrap();
 
-        let commands = mgr.captured_service_commands();
-        assert_eq!(commands.len(), 1);
-        assert_eq!(commands[0], "systemctl restart hsflowd");
+        assert_eq!(systemd.actions(), ["restart hsflowd"]);
     }
 
     #[tokio::test]
     async fn test_handle_service_disable() {
-        let mut mgr = SflowMgr::new().with_mock_mode();
+        let (mut mgr, systemd) = mock_mgr();
         mgr.handle_service(false).await.unwrap();
 
-        let commands = mgr.captured_service_commands();
-        assert_eq!(commands.len(), 1);
-        assert_eq!(commands[0], "systemctl stop hsflowd");
+        assert_eq!(systemd.actions(), ["stop hsflowd"]);
     }
 
     #[test]
-    fn test_build_global_session_fvs() {
+    fn test_build_port_session_fvs_defaults() {
         let mut mgr = SflowMgr::new();
 
         let mut port_info = SflowPortInfo::new();
         port_info.speed = "100000".to_string();
         mgr.port_config_map
-            .insert("Ethernet0".to_string(), port_info);
+            .insert("Ethernet0".to_string(), port_info.clone());
 
-        let fvs = mgr.build_global_session_fvs("Ethernet0", "rx");
+        let fvs = mgr.build_port_session_fvs("Ethernet0", &port_info);
 
         assert_eq!(fvs.len(), 3);
         assert!(fvs.contains(&("admin_state".to_string(), "up".to_string())));
@@ -607,14 +754,11 @@ mod tests {
         let mgr = SflowMgr::new();
 
         let mut port_info = SflowPortInfo::new();
-        port_info.local_admin_cfg = true;
-        port_info.admin = "down".to_string();
-        port_info.local_rate_cfg = true;
-        port_info.rate = "5000".to_string();
-        port_info.local_dir_cfg = true;
-        port_info.dir = "both".to_string();
+        port_info.local.admin = Some("down".to_string());
+        port_info.local.rate = Some("5000".to_string());
+        port_info.local.dir = Some("both".to_string());
 
-        let fvs = mgr.build_port_session_fvs(&port_info);
+        let fvs = mgr.build_port_session_fvs("Ethernet0", &port_info);
 
         assert_eq!(fvs.len(), 3);
         assert!(fvs.contains(&("admin_state".to_string(), "down".to_string())));
@@ -622,6 +766,479 @@ mod tests {
         assert!(fvs.contains(&("sample_direction".to_string(), "both".to_string())));
     }
 
+    fn entry(key: &str, fvs: &[(&str, &str)]) -> KeyOpFieldsValues {
+        KeyOpFieldsValues::set(
+            key,
+            fvs.iter()
+                .map(|(f, v)| (f.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    async fn sync(mgr: &mut SflowMgr, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        mgr.add_to_sync(db, table, entries);
+        mgr.do_task().await;
+    }
+
+    /// Returns the last SFLOW_SESSION_TABLE operation on a port
+    fn last_session(mgr: &SflowMgr, alias: &str) -> Option<Option<FieldValues>> {
+        mgr.app_db_ops
+            .iter()
+            .rev()
+            .find(|(table, key, _)| table == APP_SFLOW_SESSION_TABLE_NAME && key == alias)
+            .map(|(_, _, fvs)| fvs.clone())
+    }
+
+    async fn enabled_mgr() -> (SflowMgr, Arc<RecordingSystemd>) {
+        let (mut mgr, systemd) = mock_mgr();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_PORT_TABLE_NAME,
+            vec![
+                entry("Ethernet0", &[("speed", "100000")]),
+                entry("Ethernet4", &[("speed", "100000")]),
+            ],
+        )
+        .await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_TABLE_NAME,
+            vec![entry("global", &[("admin_state", "up")])],
+        )
+        .await;
+        (mgr, systemd)
+    }
+
+    #[tokio::test]
+    async fn test_global_enable_writes_sessions() {
+        let (mgr, systemd) = enabled_mgr().await;
+
+        assert_eq!(systemd.actions(), ["restart hsflowd"]);
+        let fvs = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_rate"), Some("100000"));
+        assert_eq!(fvs.get_field("admin_state"), Some("up"));
+        assert_eq!(fvs.get_field("sample_direction"), Some("rx"));
+    }
+
+    #[tokio::test]
+    async fn test_overrides_survive_disable_speed_change_enable() {
+        let (mut mgr, systemd) = enabled_mgr().await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![entry(
+                "Ethernet0",
+                &[("sample_rate", "4000"), ("sample_direction", "both")],
+            )],
+        )
+        .await;
+
+        // Disable: every session goes away
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_TABLE_NAME,
+            vec![entry("global", &[("admin_state", "down")])],
+        )
+        .await;
+        assert_eq!(last_session(&mgr, "Ethernet0"), Some(None));
+        assert_eq!(last_session(&mgr, "Ethernet4"), Some(None));
+
+        // Speeds change while disabled, nothing is written
+        let ops = mgr.app_db_ops.len();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_PORT_TABLE_NAME,
+            vec![
+                entry("Ethernet0", &[("speed", "40000")]),
+                entry("Ethernet4", &[("speed", "25000")]),
+            ],
+        )
+        .await;
+        assert_eq!(mgr.app_db_ops.len(), ops);
+
+        // Re-enable: overrides intact, defaults follow the new speed
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_TABLE_NAME,
+            vec![entry("global", &[("admin_state", "up")])],
+        )
+        .await;
+        let eth0 = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(eth0.get_field("sample_rate"), Some("4000"));
+        assert_eq!(eth0.get_field("sample_direction"), Some("both"));
+        assert_eq!(eth0.get_field("admin_state"), Some("up"));
+        let eth4 = last_session(&mgr, "Ethernet4").unwrap().unwrap();
+        assert_eq!(eth4.get_field("sample_rate"), Some("25000"));
+        assert_eq!(
+            systemd.actions(),
+            ["restart hsflowd", "stop hsflowd", "restart hsflowd"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_override_removal_falls_back_to_speed() {
+        let (mut mgr, _) = enabled_mgr().await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![entry("Ethernet0", &[("sample_rate", "4000")])],
+        )
+        .await;
+        let fvs = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_rate"), Some("4000"));
+
+        // Operational speed is ignored while the override exists
+        let ops = mgr.app_db_ops.len();
+        sync(
+            &mut mgr,
+            DbId::StateDb,
+            STATE_PORT_TABLE_NAME,
+            vec![entry("Ethernet0", &[("speed", "50000")])],
+        )
+        .await;
+        assert_eq!(mgr.app_db_ops.len(), ops);
+
+        // Removing the field reverts to the operational speed
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![entry("Ethernet0", &[("admin_state", "up")])],
+        )
+        .await;
+        let fvs = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_rate"), Some("50000"));
+
+        // Deleting the session entry keeps the port sampled at the default
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("Ethernet0")],
+        )
+        .await;
+        assert!(!mgr.port_config_map["Ethernet0"].has_local_config());
+        assert_eq!(
+            mgr.applied_sessions["Ethernet0"].get_field("sample_rate"),
+            Some("50000")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_session_disable_keeps_local_admin_ports() {
+        let (mut mgr, _) = enabled_mgr().await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![
+                entry("Ethernet0", &[("admin_state", "up")]),
+                entry("all", &[("admin_state", "down")]),
+            ],
+        )
+        .await;
+
+        assert!(mgr.applied_sessions.contains_key("Ethernet0"));
+        assert_eq!(last_session(&mgr, "Ethernet4"), Some(None));
+    }
+
+    fn session_writes(mgr: &SflowMgr) -> Vec<String> {
+        mgr.app_db_ops
+            .iter()
+            .filter(|(table, _, _)| table == APP_SFLOW_SESSION_TABLE_NAME)
+            .map(|(_, key, _)| key.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_all_direction_rewrites_only_inherited_ports() {
+        let (mut mgr, _) = mock_mgr();
+        let ports: Vec<String> = (0..8).map(|i| format!("Ethernet{}", i * 4)).collect();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_PORT_TABLE_NAME,
+            ports
+                .iter()
+                .map(|p| entry(p, &[("speed", "100000")]))
+                .collect(),
+        )
+        .await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            ports[..3]
+                .iter()
+                .map(|p| entry(p, &[("sample_direction", "tx")]))
+                .collect(),
+        )
+        .await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_TABLE_NAME,
+            vec![entry("global", &[("admin_state", "up")])],
+        )
+        .await;
+        assert_eq!(session_writes(&mgr).len(), 8);
+
+        mgr.app_db_ops.clear();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![entry(
+                "all",
+                &[("admin_state", "up"), ("sample_direction", "both")],
+            )],
+        )
+        .await;
+
+        assert_eq!(session_writes(&mgr), ports[3..].to_vec());
+        for port in &ports[3..] {
+            let fvs = last_session(&mgr, port).unwrap().unwrap();
+            assert_eq!(fvs.get_field("sample_direction"), Some("both"));
+        }
+        for port in &ports[..3] {
+            assert_eq!(
+                mgr.applied_sessions[port].get_field("sample_direction"),
+                Some("tx")
+            );
+        }
+
+        // Same direction again rewrites nothing
+        mgr.app_db_ops.clear();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![entry(
+                "all",
+                &[("admin_state", "up"), ("sample_direction", "both")],
+            )],
+        )
+        .await;
+        assert!(mgr.app_db_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_local_direction_removal_inherits_all() {
+        let (mut mgr, _) = enabled_mgr().await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![
+                entry("all", &[("sample_direction", "tx")]),
+                entry("Ethernet0", &[("sample_direction", "both")]),
+            ],
+        )
+        .await;
+        let fvs = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_direction"), Some("both"));
+
+        mgr.app_db_ops.clear();
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("Ethernet0")],
+        )
+        .await;
+        assert_eq!(session_writes(&mgr), ["Ethernet0"]);
+        let fvs = last_session(&mgr, "Ethernet0").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_direction"), Some("tx"));
+
+        // Removing the "all" session falls back to the global direction
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("all")],
+        )
+        .await;
+        let fvs = last_session(&mgr, "Ethernet4").unwrap().unwrap();
+        assert_eq!(fvs.get_field("sample_direction"), Some("rx"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_direction_rejected() {
+        let (mut mgr, _) = enabled_mgr().await;
+        mgr.app_db_ops.clear();
+
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_SESSION_TABLE_NAME,
+            vec![
+                entry("all", &[("sample_direction", "ingress")]),
+                entry("Ethernet0", &[("sample_direction", "sideways")]),
+            ],
+        )
+        .await;
+
+        assert!(mgr.app_db_ops.is_empty());
+        assert_eq!(mgr.intf_all_dir, "rx");
+        assert!(!mgr.port_config_map["Ethernet0"].has_local_config());
+    }
+
+    #[test]
+    fn test_is_valid_direction() {
+        assert!(is_valid_direction("rx"));
+        assert!(is_valid_direction("tx"));
+        assert!(is_valid_direction("both"));
+        assert!(!is_valid_direction("RX"));
+        assert!(!is_valid_direction(""));
+    }
+
+    fn collector(ip: &str, port: &str) -> KeyOpFieldsValues {
+        entry(
+            "collector",
+            &[("collector_ip", ip), ("collector_port", port)],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_collector_lifecycle() {
+        let (mut mgr, systemd) = mock_mgr();
+
+        // Add the first collector
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_COLLECTOR_TABLE_NAME,
+            vec![collector("10.0.0.1", "6343")],
+        )
+        .await;
+        assert_eq!(systemd.actions(), ["restart hsflowd"]);
+        assert_eq!(
+            mgr.app_db_ops.last().unwrap(),
+            &(
+                APP_SFLOW_COLLECTOR_TABLE_NAME.to_string(),
+                "collector".to_string(),
+                Some(vec![
+                    ("collector_ip".to_string(), "10.0.0.1".to_string()),
+                    ("collector_port".to_string(), "6343".to_string()),
+                    ("collector_vrf".to_string(), "default".to_string()),
+                ])
+            )
+        );
+
+        // Unchanged entry does nothing, a port change restarts
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_COLLECTOR_TABLE_NAME,
+            vec![collector("10.0.0.1", "6343"), collector("10.0.0.1", "6400")],
+        )
+        .await;
+        assert_eq!(systemd.actions(), ["restart hsflowd", "restart hsflowd"]);
+        assert_eq!(mgr.collectors()["collector"].port, 6400);
+
+        // Removing the last one with sFlow disabled stops hsflowd
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_COLLECTOR_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("collector")],
+        )
+        .await;
+        assert_eq!(
+            systemd.actions(),
+            ["restart hsflowd", "restart hsflowd", "stop hsflowd"]
+        );
+        assert!(mgr.collectors().is_empty());
+        assert_eq!(
+            mgr.app_db_ops.last().unwrap(),
+            &(
+                APP_SFLOW_COLLECTOR_TABLE_NAME.to_string(),
+                "collector".to_string(),
+                None
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_collector_removed_while_enabled_restarts() {
+        let (mut mgr, systemd) = enabled_mgr().await;
+        mgr.process_collector("c1", Some(&collector("10.0.0.1", "6343").fvs))
+            .await
+            .unwrap();
+        mgr.process_collector("c1", None).await.unwrap();
+
+        assert_eq!(
+            systemd.actions(),
+            ["restart hsflowd", "restart hsflowd", "restart hsflowd"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_third_collector_rejected() {
+        let (mut mgr, systemd) = mock_mgr();
+        let fvs = collector("10.0.0.1", "6343").fvs;
+        mgr.process_collector("c1", Some(&fvs)).await.unwrap();
+        mgr.process_collector("c2", Some(&fvs)).await.unwrap();
+        let ops = mgr.app_db_ops.len();
+
+        let err = mgr.process_collector("c3", Some(&fvs)).await.unwrap_err();
+        assert!(matches!(err, CfgMgrError::InvalidConfig { .. }));
+        assert_eq!(mgr.app_db_ops.len(), ops);
+        assert_eq!(mgr.collectors().len(), 2);
+        assert_eq!(systemd.actions().len(), 2);
+
+        // Updating an existing collector is still allowed
+        mgr.process_collector("c2", Some(&collector("10.0.0.2", "6343").fvs))
+            .await
+            .unwrap();
+        assert_eq!(mgr.collectors()["c2"].ip.to_string(), "10.0.0.2");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_collector_rejected() {
+        let (mut mgr, systemd) = mock_mgr();
+
+        let err = mgr
+            .process_collector("c1", Some(&collector("not-an-ip", "6343").fvs))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CfgMgrError::InvalidConfig { .. }));
+        assert!(mgr.app_db_ops.is_empty());
+        assert!(systemd.actions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_agent_id_change_restarts() {
+        let (mut mgr, systemd) = enabled_mgr().await;
+        sync(
+            &mut mgr,
+            DbId::ConfigDb,
+            CFG_SFLOW_TABLE_NAME,
+            vec![entry(
+                "global",
+                &[("admin_state", "up"), ("agent_id", "Loopback0")],
+            )],
+        )
+        .await;
+
+        assert_eq!(systemd.actions(), ["restart hsflowd", "restart hsflowd"]);
+        let fvs = mgr
+            .app_db_ops
+            .iter()
+            .rev()
+            .find(|(table, _, _)| table == APP_SFLOW_TABLE_NAME)
+            .and_then(|(_, _, fvs)| fvs.clone())
+            .unwrap();
+        assert_eq!(fvs.get_field("agent_id"), Some("Loopback0"));
+    }
+
     #[test]
     fn test_cfgmgr_trait() {
         let mgr = SflowMgr::new();
@@ -629,9 +1246,10 @@ mod tests {
         assert!(!mgr.is_warm_restart());
 
         let tables = mgr.config_table_names();
-        assert_eq!(tables.len(), 3);
+        assert_eq!(tables.len(), 4);
         assert!(tables.contains(&"SFLOW"));
         assert!(tables.contains(&"SFLOW_SESSION"));
+        assert!(tables.contains(&"SFLOW_COLLECTOR"));
         assert!(tables.contains(&"PORT"));
 
         let state_tables = mgr.state_table_names();
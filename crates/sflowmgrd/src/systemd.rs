@@ -0,0 +1,10 @@
+//! systemd unit control for hsflowd
+//!
+//! [`SystemdController`] is how SflowMgr starts and stops the sampling
+//! agent. [`ShellSystemdController`] runs `systemctl`; tests substitute a
+//! recording implementation.
+
+pub use sonic_cfgmgr_common::systemd::{ShellSystemdController, SystemdController};
+
+/// The sFlow agent unit
+pub const HSFLOWD_UNIT: &str = "hsflowd";
@@ -6,6 +6,9 @@ pub const CFG_SFLOW_TABLE_NAME: &str = "SFLOW";
 /// CONFIG_DB SFLOW_SESSION table
 pub const CFG_SFLOW_SESSION_TABLE_NAME: &str = "SFLOW_SESSION";
 
+/// CONFIG_DB SFLOW_COLLECTOR table
+pub const CFG_SFLOW_COLLECTOR_TABLE_NAME: &str = "SFLOW_COLLECTOR";
+
 /// CONFIG_DB PORT table (for port speed)
 pub const CFG_PORT_TABLE_NAME: &str = "PORT";
 
@@ -18,12 +21,19 @@ pub const APP_SFLOW_TABLE_NAME: &str = "SFLOW_TABLE";
 /// APPL_DB SFLOW_SESSION_TABLE
 pub const APP_SFLOW_SESSION_TABLE_NAME: &str = "SFLOW_SESSION_TABLE";
 
+/// APPL_DB SFLOW_COLLECTOR_TABLE
+pub const APP_SFLOW_COLLECTOR_TABLE_NAME: &str = "SFLOW_COLLECTOR_TABLE";
+
 /// Field names used in sFlow tables
 pub mod fields {
     pub const ADMIN_STATE: &str = "admin_state";
     pub const SAMPLE_RATE: &str = "sample_rate";
     pub const SAMPLE_DIRECTION: &str = "sample_direction";
     pub const SPEED: &str = "speed";
+    pub const AGENT_ID: &str = "agent_id";
+    pub const COLLECTOR_IP: &str = "collector_ip";
+    pub const COLLECTOR_PORT: &str = "collector_port";
+    pub const COLLECTOR_VRF: &str = "collector_vrf";
 }
 
 /// Special constants
@@ -37,9 +47,29 @@ pub mod constants {
     /// Default sampling direction
     pub const DEFAULT_DIRECTION: &str = "rx";
 
+    /// Accepted sampling directions
+    pub const SAMPLE_DIRECTIONS: &[&str] = &["rx", "tx", "both"];
+
     /// Default admin state
     pub const DEFAULT_ADMIN_STATE: &str = "up";
 
     /// Special key for "all interfaces" configuration
     pub const ALL_INTERFACES: &str = "all";
+
+    /// Default sFlow collector UDP port
+    pub const DEFAULT_COLLECTOR_PORT: u16 = 6343;
+
+    /// Default collector VRF
+    pub const DEFAULT_COLLECTOR_VRF: &str = "default";
+
+    /// Maximum number of collectors hsflowd supports
+    pub const MAX_COLLECTORS: usize = 2;
+
+    /// Key of the global configuration in the SFLOW table
+    pub const GLOBAL_KEY: &str = "global";
+}
+
+/// Returns true if `dir` is a supported sample direction
+pub fn is_valid_direction(dir: &str) -> bool {
+    constants::SAMPLE_DIRECTIONS.contains(&dir)
 }
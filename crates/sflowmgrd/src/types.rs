@@ -1,63 +1,130 @@
 //! Type definitions for sflowmgrd
 
+use std::net::IpAddr;
+
 use serde::{Deserialize, Serialize};
+use sonic_cfgmgr_common::{CfgMgrError, CfgMgrResult, FieldValues, FieldValuesExt};
 
-/// Per-port sFlow configuration information
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct SflowPortInfo {
-    /// Whether local rate configuration is present
-    pub local_rate_cfg: bool,
+use crate::constants::{DEFAULT_COLLECTOR_PORT, DEFAULT_COLLECTOR_VRF};
+use crate::fields;
+
+/// Per-port overrides configured in `SFLOW_SESSION|<port>`
+///
+/// Kept apart from the speeds and the applied APPL_DB state so that global
+/// disable/enable and speed changes never lose them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SflowLocalConfig {
+    /// Sampling rate override (packets per sample)
+    pub rate: Option<String>,
 
-    /// Whether local admin configuration is present
-    pub local_admin_cfg: bool,
+    /// Admin state override ("up" or "down")
+    pub admin: Option<String>,
 
-    /// Whether local direction configuration is present
-    pub local_dir_cfg: bool,
+    /// Sample direction override ("rx", "tx", or "both")
+    pub dir: Option<String>,
+}
+
+impl SflowLocalConfig {
+    /// Checks if no override is configured
+    pub fn is_empty(&self) -> bool {
+        self.rate.is_none() && self.admin.is_none() && self.dir.is_none()
+    }
 
+    /// Removes all overrides
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Per-port sFlow configuration information
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SflowPortInfo {
     /// Configured port speed from CONFIG_DB
     pub speed: String,
 
     /// Operational port speed from STATE_DB
     pub oper_speed: String,
 
-    /// Configured sampling rate (packets per sample)
-    pub rate: String,
-
-    /// Admin state ("up" or "down")
-    pub admin: String,
-
-    /// Sample direction ("rx", "tx", or "both")
-    pub dir: String,
+    /// Local overrides from SFLOW_SESSION
+    pub local: SflowLocalConfig,
 }
 
 impl SflowPortInfo {
     /// Creates a new SflowPortInfo with default values
     pub fn new() -> Self {
         Self {
-            local_rate_cfg: false,
-            local_admin_cfg: false,
-            local_dir_cfg: false,
             speed: crate::constants::ERROR_SPEED.to_string(),
             oper_speed: crate::constants::NA_SPEED.to_string(),
-            rate: String::new(),
-            admin: String::new(),
-            dir: String::new(),
+            local: SflowLocalConfig::default(),
         }
     }
 
     /// Checks if this port has any local configuration
     pub fn has_local_config(&self) -> bool {
-        self.local_rate_cfg || self.local_admin_cfg || self.local_dir_cfg
+        !self.local.is_empty()
     }
 
-    /// Clears all local configuration flags and values
+    /// Clears all local configuration
     pub fn clear_local_config(&mut self) {
-        self.local_rate_cfg = false;
-        self.local_admin_cfg = false;
-        self.local_dir_cfg = false;
-        self.rate = String::new();
-        self.admin = String::new();
-        self.dir = String::new();
+        self.local.clear();
+    }
+}
+
+/// An sFlow collector from `SFLOW_COLLECTOR|<name>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SflowCollector {
+    /// Collector address
+    pub ip: IpAddr,
+
+    /// Collector UDP port
+    pub port: u16,
+
+    /// VRF the collector is reached through ("default", "mgmt" or "Vrf*")
+    pub vrf: String,
+}
+
+impl SflowCollector {
+    /// Parses and validates a CONFIG_DB collector entry
+    pub fn from_fvs(fvs: &FieldValues) -> CfgMgrResult<Self> {
+        let ip = fvs
+            .get_field(fields::COLLECTOR_IP)
+            .ok_or_else(|| CfgMgrError::invalid_config(fields::COLLECTOR_IP, "missing"))?;
+        let ip = ip.parse().map_err(|_| {
+            CfgMgrError::invalid_config(fields::COLLECTOR_IP, format!("invalid address '{}'", ip))
+        })?;
+
+        let port = match fvs.get_field(fields::COLLECTOR_PORT) {
+            Some(port) => port.parse().ok().filter(|port| *port != 0).ok_or_else(|| {
+                CfgMgrError::invalid_config(
+                    fields::COLLECTOR_PORT,
+                    format!("invalid port '{}'", port),
+                )
+            })?,
+            None => DEFAULT_COLLECTOR_PORT,
+        };
+
+        let vrf = fvs.get_field_or(fields::COLLECTOR_VRF, DEFAULT_COLLECTOR_VRF);
+        if vrf != DEFAULT_COLLECTOR_VRF && vrf != "mgmt" && !vrf.starts_with("Vrf") {
+            return Err(CfgMgrError::invalid_config(
+                fields::COLLECTOR_VRF,
+                format!("invalid VRF '{}'", vrf),
+            ));
+        }
+
+        Ok(Self {
+            ip,
+            port,
+            vrf: vrf.to_string(),
+        })
+    }
+
+    /// Builds the APPL_DB entry of the collector
+    pub fn to_fvs(&self) -> FieldValues {
+        vec![
+            (fields::COLLECTOR_IP.to_string(), self.ip.to_string()),
+            (fields::COLLECTOR_PORT.to_string(), self.port.to_string()),
+            (fields::COLLECTOR_VRF.to_string(), self.vrf.clone()),
+        ]
     }
 }
 
@@ -68,14 +135,11 @@ mod tests {
     #[test]
     fn test_sflow_port_info_new() {
         let info = SflowPortInfo::new();
-        assert!(!info.local_rate_cfg);
-        assert!(!info.local_admin_cfg);
-        assert!(!info.local_dir_cfg);
         assert_eq!(info.speed, "error");
         assert_eq!(info.oper_speed, "N/A");
-        assert!(info.rate.is_empty());
-        assert!(info.admin.is_empty());
-        assert!(info.dir.is_empty());
+        assert!(info.local.rate.is_none());
+        assert!(info.local.admin.is_none());
+        assert!(info.local.dir.is_none());
     }
 
     #[test]
@@ -83,35 +147,74 @@ mod tests {
         let mut info = SflowPortInfo::new();
         assert!(!info.has_local_config());
 
-        info.local_rate_cfg = true;
+        info.local.rate = Some("1000".to_string());
         assert!(info.has_local_config());
 
-        info.local_rate_cfg = false;
-        info.local_admin_cfg = true;
+        info.local.rate = None;
+        info.local.admin = Some("up".to_string());
         assert!(info.has_local_config());
 
-        info.local_admin_cfg = false;
-        info.local_dir_cfg = true;
+        info.local.admin = None;
+        info.local.dir = Some("rx".to_string());
         assert!(info.has_local_config());
     }
 
     #[test]
     fn test_clear_local_config() {
         let mut info = SflowPortInfo::new();
-        info.local_rate_cfg = true;
-        info.local_admin_cfg = true;
-        info.local_dir_cfg = true;
-        info.rate = "1000".to_string();
-        info.admin = "up".to_string();
-        info.dir = "rx".to_string();
+        info.speed = "100000".to_string();
+        info.local.rate = Some("1000".to_string());
+        info.local.admin = Some("up".to_string());
+        info.local.dir = Some("rx".to_string());
 
         info.clear_local_config();
 
-        assert!(!info.local_rate_cfg);
-        assert!(!info.local_admin_cfg);
-        assert!(!info.local_dir_cfg);
-        assert!(info.rate.is_empty());
-        assert!(info.admin.is_empty());
-        assert!(info.dir.is_empty());
+        assert!(!info.has_local_config());
+        assert_eq!(info.local, SflowLocalConfig::default());
+        assert_eq!(info.speed, "100000");
+    }
+
+    fn collector_fvs(fvs: &[(&str, &str)]) -> FieldValues {
+        fvs.iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_collector_defaults() {
+        let collector =
+            SflowCollector::from_fvs(&collector_fvs(&[("collector_ip", "10.0.0.1")])).unwrap();
+        assert_eq!(collector.ip, "10.0.0.1".parse::<IpAddr>().unwrap());
+        assert_eq!(collector.port, 6343);
+        assert_eq!(collector.vrf, "default");
+        assert_eq!(
+            collector.to_fvs(),
+            collector_fvs(&[
+                ("collector_ip", "10.0.0.1"),
+                ("collector_port", "6343"),
+                ("collector_vrf", "default"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_collector_validation() {
+        let ok = SflowCollector::from_fvs(&collector_fvs(&[
+            ("collector_ip", "2001:db8::1"),
+            ("collector_port", "6400"),
+            ("collector_vrf", "mgmt"),
+        ]))
+        .unwrap();
+        assert_eq!(ok.port, 6400);
+
+        for fvs in [
+            collector_fvs(&[]),
+            collector_fvs(&[("collector_ip", "10.0.0.256")]),
+            collector_fvs(&[("collector_ip", "10.0.0.1"), ("collector_port", "0")]),
+            collector_fvs(&[("collector_ip", "10.0.0.1"), ("collector_port", "70000")]),
+            collector_fvs(&[("collector_ip", "10.0.0.1"), ("collector_vrf", "red")]),
+        ] {
+            assert!(SflowCollector::from_fvs(&fvs).is_err(), "{:?}", fvs);
+        }
     }
 }
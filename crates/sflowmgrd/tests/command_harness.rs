@@ -0,0 +1,81 @@
+//! SflowMgr driven against the fake shell/systemd harness.
+//!
+//! No Redis or systemd is needed: entries are fed through `add_to_sync`
+//! and hsflowd control lands in the harness journal.
+
+use std::sync::Arc;
+
+use sonic_cfgmgr_common::{CfgMgr, DbId, KeyOpFieldsValues, Orch};
+use sonic_cfgmgr_test::{CannedResponse, CommandHarness};
+use sonic_sflowmgrd::{
+    SflowMgr, CFG_PORT_TABLE_NAME, CFG_SFLOW_COLLECTOR_TABLE_NAME, CFG_SFLOW_TABLE_NAME,
+    HSFLOWD_UNIT,
+};
+
+fn set(key: &str, fvs: &[(&str, &str)]) -> KeyOpFieldsValues {
+    KeyOpFieldsValues::set(
+        key,
+        fvs.iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect(),
+    )
+}
+
+async fn apply(mgr: &mut SflowMgr, table: &str, entry: KeyOpFieldsValues) {
+    mgr.add_to_sync(DbId::ConfigDb, table, vec![entry]);
+    mgr.do_task().await;
+}
+
+/// Ports, then sFlow enabled, then a collector added
+async fn enable_then_add_collector(mgr: &mut SflowMgr) {
+    apply(
+        mgr,
+        CFG_PORT_TABLE_NAME,
+        set("Ethernet0", &[("speed", "100000")]),
+    )
+    .await;
+    apply(
+        mgr,
+        CFG_SFLOW_TABLE_NAME,
+        set("global", &[("admin_state", "up")]),
+    )
+    .await;
+    apply(
+        mgr,
+        CFG_SFLOW_COLLECTOR_TABLE_NAME,
+        set("collector0", &[("collector_ip", "10.0.0.1")]),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_enable_and_collector_add_restart_hsflowd() {
+    let harness = Arc::new(CommandHarness::new());
+    let mut mgr = SflowMgr::new().with_systemd(harness.clone());
+
+    enable_then_add_collector(&mut mgr).await;
+
+    // Once for the global enable, once for the new collector
+    harness.assert_unit_restarted_times(HSFLOWD_UNIT, 2);
+    harness.assert_unit_stopped_times(HSFLOWD_UNIT, 0);
+    assert!(mgr.collectors().contains_key("collector0"));
+}
+
+#[tokio::test]
+async fn test_systemctl_runs_through_injected_executor() {
+    let harness = Arc::new(CommandHarness::new());
+    harness.stub_once(
+        r"^systemctl restart",
+        CannedResponse::exit(1, "Job for hsflowd.service failed"),
+    );
+    let mut mgr = SflowMgr::new().with_executor(harness.clone());
+
+    enable_then_add_collector(&mut mgr).await;
+
+    // The failed restart on enable is logged; the collector restart retries
+    assert_eq!(
+        harness.count_matching(r#"^systemctl restart "hsflowd"$"#),
+        2
+    );
+    harness.assert_not_ran_matching(r"^systemctl stop");
+}
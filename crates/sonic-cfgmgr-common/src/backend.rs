@@ -0,0 +1,470 @@
+//! Kernel configuration backends.
+//!
+//! [`CfgBackend`] is the set of interface operations shared by portmgrd,
+//! vlanmgrd and intfmgrd. [`ShellBackend`] runs the `ip`/`bridge` commands
+//! the C++ daemons use; [`NetlinkBackend`] sends the equivalent rtnetlink
+//! requests directly. Both fail with an error when the kernel rejects the
+//! change, so a manager behaves the same whichever backend it is given.
+//!
+//! The backend is chosen with `CFGMGR_BACKEND=shell|netlink`
+//! ([`BackendKind::from_env`]); shell is the default.
+
+use async_trait::async_trait;
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::CfgMgrResult;
+use crate::nl::BridgeVlan;
+use crate::shell::{self, shellquote, ExecRequest};
+
+/// Environment variable that selects the backend.
+pub const BACKEND_ENV: &str = "CFGMGR_BACKEND";
+
+/// Interface configuration operations.
+#[async_trait]
+pub trait CfgBackend: Send + Sync {
+    /// Returns the backend name for logging.
+    fn name(&self) -> &'static str;
+
+    /// Sets the MTU of an interface.
+    async fn set_link_mtu(&self, name: &str, mtu: u32) -> CfgMgrResult<()>;
+
+    /// Sets the admin state of an interface.
+    async fn set_link_admin(&self, name: &str, up: bool) -> CfgMgrResult<()>;
+
+    /// Creates VLAN interface `name` with ID `vid` on `parent`.
+    async fn add_link_vlan(&self, parent: &str, name: &str, vid: u16) -> CfgMgrResult<()>;
+
+    /// Adds a VLAN to a bridge port or the bridge itself.
+    async fn bridge_vlan_add(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()>;
+
+    /// Removes a VLAN from a bridge port or the bridge itself.
+    async fn bridge_vlan_del(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()>;
+
+    /// Adds an address to an interface.
+    async fn addr_add(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()>;
+
+    /// Removes an address from an interface.
+    async fn addr_del(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()>;
+
+    /// Binds an interface to a VRF, or unbinds it when `vrf` is `None`.
+    async fn vrf_bind(&self, name: &str, vrf: Option<&str>) -> CfgMgrResult<()>;
+}
+
+/// Which backend a manager uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackendKind {
+    /// `ip`/`bridge` commands.
+    #[default]
+    Shell,
+    /// rtnetlink requests.
+    Netlink,
+}
+
+impl BackendKind {
+    /// Reads [`BACKEND_ENV`], falling back to shell if it is unset or
+    /// invalid.
+    pub fn from_env() -> Self {
+        match std::env::var(BACKEND_ENV) {
+            Ok(value) => value.parse().unwrap_or_else(|e| {
+                tracing::warn!("{}, using shell backend", e);
+                Self::Shell
+            }),
+            Err(_) => Self::Shell,
+        }
+    }
+
+    /// Creates a backend of this kind.
+    pub fn create(self) -> CfgMgrResult<Arc<dyn CfgBackend>> {
+        match self {
+            Self::Shell => Ok(Arc::new(ShellBackend)),
+            Self::Netlink => Ok(Arc::new(NetlinkBackend::new()?)),
+        }
+    }
+}
+
+impl fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shell => write!(f, "shell"),
+            Self::Netlink => write!(f, "netlink"),
+        }
+    }
+}
+
+impl FromStr for BackendKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "shell" => Ok(Self::Shell),
+            "netlink" => Ok(Self::Netlink),
+            _ => Err(format!("Unknown cfgmgr backend: {}", s)),
+        }
+    }
+}
+
+/// Backend that runs `ip` and `bridge` commands.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShellBackend;
+
+impl ShellBackend {
+    /// `ip link set dev <name> mtu <mtu>`
+    pub fn set_link_mtu_cmd(name: &str, mtu: u32) -> String {
+        format!(
+            "{} link set dev {} mtu {}",
+            shell::IP_CMD,
+            shellquote(name),
+            mtu
+        )
+    }
+
+    /// `ip link set dev <name> up|down`
+    pub fn set_link_admin_cmd(name: &str, up: bool) -> String {
+        format!(
+            "{} link set dev {} {}",
+            shell::IP_CMD,
+            shellquote(name),
+            if up { "up" } else { "down" }
+        )
+    }
+
+    /// `ip link add link <parent> name <name> type vlan id <vid>`
+    pub fn add_link_vlan_cmd(parent: &str, name: &str, vid: u16) -> String {
+        format!(
+            "{} link add link {} name {} type vlan id {}",
+            shell::IP_CMD,
+            shellquote(parent),
+            shellquote(name),
+            vid
+        )
+    }
+
+    /// `bridge vlan add|del vid <vid> dev <dev> [pvid] [untagged] [self]`
+    pub fn bridge_vlan_cmd(dev: &str, vlan: &BridgeVlan, add: bool) -> String {
+        let mut cmd = format!(
+            "{} vlan {} vid {} dev {}",
+            shell::BRIDGE_CMD,
+            if add { "add" } else { "del" },
+            vlan.vid,
+            shellquote(dev)
+        );
+        if add && vlan.pvid {
+            cmd.push_str(" pvid");
+        }
+        if add && vlan.untagged {
+            cmd.push_str(" untagged");
+        }
+        if vlan.bridge_self {
+            cmd.push_str(" self");
+        }
+        cmd
+    }
+
+    /// `ip [-6] address add|del <addr>/<prefix_len> dev <dev>`
+    pub fn addr_cmd(dev: &str, addr: IpAddr, prefix_len: u8, add: bool) -> String {
+        format!(
+            "{}{} address {} {} dev {}",
+            shell::IP_CMD,
+            if addr.is_ipv6() { " -6" } else { "" },
+            if add { "add" } else { "del" },
+            shellquote(&format!("{}/{}", addr, prefix_len)),
+            shellquote(dev)
+        )
+    }
+
+    /// `ip link set <name> master <vrf>` or `ip link set <name> nomaster`
+    pub fn vrf_bind_cmd(name: &str, vrf: Option<&str>) -> String {
+        match vrf {
+            Some(vrf) => format!(
+                "{} link set {} master {}",
+                shell::IP_CMD,
+                shellquote(name),
+                shellquote(vrf)
+            ),
+            None => format!("{} link set {} nomaster", shell::IP_CMD, shellquote(name)),
+        }
+    }
+
+    async fn run(cmd: String) -> CfgMgrResult<()> {
+        ExecRequest::new(cmd).run_or_throw().await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl CfgBackend for ShellBackend {
+    fn name(&self) -> &'static str {
+        "shell"
+    }
+
+    async fn set_link_mtu(&self, name: &str, mtu: u32) -> CfgMgrResult<()> {
+        Self::run(Self::set_link_mtu_cmd(name, mtu)).await
+    }
+
+    async fn set_link_admin(&self, name: &str, up: bool) -> CfgMgrResult<()> {
+        Self::run(Self::set_link_admin_cmd(name, up)).await
+    }
+
+    async fn add_link_vlan(&self, parent: &str, name: &str, vid: u16) -> CfgMgrResult<()> {
+        Self::run(Self::add_link_vlan_cmd(parent, name, vid)).await
+    }
+
+    async fn bridge_vlan_add(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        Self::run(Self::bridge_vlan_cmd(dev, vlan, true)).await
+    }
+
+    async fn bridge_vlan_del(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        Self::run(Self::bridge_vlan_cmd(dev, vlan, false)).await
+    }
+
+    async fn addr_add(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        Self::run(Self::addr_cmd(dev, addr, prefix_len, true)).await
+    }
+
+    async fn addr_del(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        Self::run(Self::addr_cmd(dev, addr, prefix_len, false)).await
+    }
+
+    async fn vrf_bind(&self, name: &str, vrf: Option<&str>) -> CfgMgrResult<()> {
+        Self::run(Self::vrf_bind_cmd(name, vrf)).await
+    }
+}
+
+/// Backend that sends rtnetlink requests.
+///
+/// In dry-run mode ([`shell::is_dry_run`]) requests are not sent; the
+/// equivalent `ip`/`bridge` command is recorded in the dry-run journal
+/// instead, as [`ShellBackend`] does.
+#[cfg(target_os = "linux")]
+pub struct NetlinkBackend {
+    handle: Arc<crate::nl::NetlinkHandle>,
+}
+
+#[cfg(target_os = "linux")]
+impl NetlinkBackend {
+    /// Opens the netlink socket.
+    pub fn new() -> CfgMgrResult<Self> {
+        Ok(Self {
+            handle: Arc::new(crate::nl::NetlinkHandle::new()?),
+        })
+    }
+
+    /// Sends a request on the blocking pool, since each one waits for the
+    /// kernel's acknowledgement. `command` is what the dry-run journal
+    /// records in its place.
+    async fn request<F>(&self, command: String, send: F) -> CfgMgrResult<()>
+    where
+        F: FnOnce(&crate::nl::NetlinkHandle) -> CfgMgrResult<()> + Send + 'static,
+    {
+        if shell::is_dry_run() {
+            tracing::info!(command = %command, "Dry run, not sending netlink request");
+            shell::record_dry_run(command);
+            return Ok(());
+        }
+        let handle = Arc::clone(&self.handle);
+        tokio::task::spawn_blocking(move || send(&handle))
+            .await
+            .map_err(|e| crate::error::CfgMgrError::Netlink {
+                operation: command,
+                message: e.to_string(),
+            })?
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[async_trait]
+impl CfgBackend for NetlinkBackend {
+    fn name(&self) -> &'static str {
+        "netlink"
+    }
+
+    async fn set_link_mtu(&self, name: &str, mtu: u32) -> CfgMgrResult<()> {
+        let name = name.to_string();
+        self.request(ShellBackend::set_link_mtu_cmd(&name, mtu), move |nl| {
+            nl.set_link_mtu(&name, mtu)
+        })
+        .await
+    }
+
+    async fn set_link_admin(&self, name: &str, up: bool) -> CfgMgrResult<()> {
+        let name = name.to_string();
+        self.request(ShellBackend::set_link_admin_cmd(&name, up), move |nl| {
+            nl.set_link_admin(&name, up)
+        })
+        .await
+    }
+
+    async fn add_link_vlan(&self, parent: &str, name: &str, vid: u16) -> CfgMgrResult<()> {
+        let (parent, name) = (parent.to_string(), name.to_string());
+        self.request(
+            ShellBackend::add_link_vlan_cmd(&parent, &name, vid),
+            move |nl| nl.add_link_vlan(&parent, &name, vid),
+        )
+        .await
+    }
+
+    async fn bridge_vlan_add(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        let (dev, vlan) = (dev.to_string(), *vlan);
+        self.request(
+            ShellBackend::bridge_vlan_cmd(&dev, &vlan, true),
+            move |nl| nl.bridge_vlan_add(&dev, &vlan),
+        )
+        .await
+    }
+
+    async fn bridge_vlan_del(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        let (dev, vlan) = (dev.to_string(), *vlan);
+        self.request(
+            ShellBackend::bridge_vlan_cmd(&dev, &vlan, false),
+            move |nl| nl.bridge_vlan_del(&dev, &vlan),
+        )
+        .await
+    }
+
+    async fn addr_add(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        let dev = dev.to_string();
+        self.request(
+            ShellBackend::addr_cmd(&dev, addr, prefix_len, true),
+            move |nl| nl.addr_add(&dev, addr, prefix_len),
+        )
+        .await
+    }
+
+    async fn addr_del(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        let dev = dev.to_string();
+        self.request(
+            ShellBackend::addr_cmd(&dev, addr, prefix_len, false),
+            move |nl| nl.addr_del(&dev, addr, prefix_len),
+        )
+        .await
+    }
+
+    async fn vrf_bind(&self, name: &str, vrf: Option<&str>) -> CfgMgrResult<()> {
+        let (name, vrf) = (name.to_string(), vrf.map(str::to_string));
+        self.request(
+            ShellBackend::vrf_bind_cmd(&name, vrf.as_deref()),
+            move |nl| nl.vrf_bind(&name, vrf.as_deref()),
+        )
+        .await
+    }
+}
+
+/// Netlink is only available on Linux; elsewhere creating it fails.
+#[cfg(not(target_os = "linux"))]
+pub struct NetlinkBackend;
+
+#[cfg(not(target_os = "linux"))]
+impl NetlinkBackend {
+    /// Always fails: netlink requires Linux.
+    pub fn new() -> CfgMgrResult<Self> {
+        Err(crate::error::CfgMgrError::Netlink {
+            operation: "socket".to_string(),
+            message: "netlink is only supported on Linux".to_string(),
+        })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+#[async_trait]
+impl CfgBackend for NetlinkBackend {
+    fn name(&self) -> &'static str {
+        "netlink"
+    }
+
+    async fn set_link_mtu(&self, _name: &str, _mtu: u32) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn set_link_admin(&self, _name: &str, _up: bool) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn add_link_vlan(&self, _parent: &str, _name: &str, _vid: u16) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn bridge_vlan_add(&self, _dev: &str, _vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn bridge_vlan_del(&self, _dev: &str, _vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn addr_add(&self, _dev: &str, _addr: IpAddr, _prefix_len: u8) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn addr_del(&self, _dev: &str, _addr: IpAddr, _prefix_len: u8) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+
+    async fn vrf_bind(&self, _name: &str, _vrf: Option<&str>) -> CfgMgrResult<()> {
+        Self::new().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_kind_parse() {
+        assert_eq!("shell".parse::<BackendKind>(), Ok(BackendKind::Shell));
+        assert_eq!("Netlink".parse::<BackendKind>(), Ok(BackendKind::Netlink));
+        assert!("ioctl".parse::<BackendKind>().is_err());
+        assert_eq!(BackendKind::default(), BackendKind::Shell);
+        assert_eq!(BackendKind::Netlink.to_string(), "netlink");
+    }
+
+    #[test]
+    fn test_shell_link_commands() {
+        assert_eq!(
+            ShellBackend::set_link_mtu_cmd("Ethernet0", 9100),
+            "/sbin/ip link set dev \"Ethernet0\" mtu 9100"
+        );
+        assert_eq!(
+            ShellBackend::set_link_admin_cmd("Ethernet0", false),
+            "/sbin/ip link set dev \"Ethernet0\" down"
+        );
+        assert_eq!(
+            ShellBackend::add_link_vlan_cmd("Bridge", "Vlan100", 100),
+            "/sbin/ip link add link \"Bridge\" name \"Vlan100\" type vlan id 100"
+        );
+        assert_eq!(
+            ShellBackend::vrf_bind_cmd("Ethernet0", Some("Vrf_red")),
+            "/sbin/ip link set \"Ethernet0\" master \"Vrf_red\""
+        );
+        assert_eq!(
+            ShellBackend::vrf_bind_cmd("Ethernet0", None),
+            "/sbin/ip link set \"Ethernet0\" nomaster"
+        );
+    }
+
+    #[test]
+    fn test_shell_bridge_and_addr_commands() {
+        assert_eq!(
+            ShellBackend::bridge_vlan_cmd("Ethernet0", &BridgeVlan::untagged(100), true),
+            "/sbin/bridge vlan add vid 100 dev \"Ethernet0\" pvid untagged"
+        );
+        let vlan = BridgeVlan {
+            bridge_self: true,
+            ..BridgeVlan::tagged(1)
+        };
+        assert_eq!(
+            ShellBackend::bridge_vlan_cmd("Bridge", &vlan, false),
+            "/sbin/bridge vlan del vid 1 dev \"Bridge\" self"
+        );
+
+        assert_eq!(
+            ShellBackend::addr_cmd("Ethernet0", "10.0.0.1".parse().unwrap(), 31, true),
+            "/sbin/ip address add \"10.0.0.1/31\" dev \"Ethernet0\""
+        );
+        assert_eq!(
+            ShellBackend::addr_cmd("Ethernet0", "fc00::1".parse().unwrap(), 126, false),
+            "/sbin/ip -6 address del \"fc00::1/126\" dev \"Ethernet0\""
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! All errors implement `std::error::Error` via `thiserror`.
 
 use std::io;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for cfgmgr operations.
@@ -33,6 +34,35 @@ pub enum CfgMgrError {
         output: String,
     },
 
+    /// Shell command was killed after running past its timeout.
+    #[error("Shell command timed out after {timeout:?}: '{command}'")]
+    ShellTimeout {
+        /// The command that timed out.
+        command: String,
+        /// The timeout that expired.
+        timeout: Duration,
+    },
+
+    /// A command in a batch failed; later commands were not run.
+    #[error("Shell command {index} in batch failed: {source}")]
+    ShellBatchFailed {
+        /// Index of the failing command in the batch.
+        index: usize,
+        /// The failure.
+        #[source]
+        source: Box<CfgMgrError>,
+    },
+
+    /// Writing a kernel parameter failed.
+    #[error("Failed to write sysctl {path}: {source}")]
+    Sysctl {
+        /// The `/proc/sys` file.
+        path: String,
+        /// The underlying IO error.
+        #[source]
+        source: io::Error,
+    },
+
     /// Redis/database operation failed.
     #[error("Database operation failed: {operation}: {message}")]
     Database {
@@ -90,6 +120,15 @@ pub enum CfgMgrError {
         message: String,
     },
 
+    /// The kernel rejected a netlink request.
+    #[error("Netlink operation failed: {operation}: {}", io::Error::from_raw_os_error(*errno))]
+    NetlinkErrno {
+        /// The operation that failed.
+        operation: String,
+        /// The errno from the kernel's reply.
+        errno: i32,
+    },
+
     /// Internal error (unexpected state).
     #[error("Internal error: {message}")]
     Internal {
@@ -128,6 +167,22 @@ impl CfgMgrError {
         }
     }
 
+    /// Creates a netlink error carrying the kernel errno.
+    pub fn netlink_errno(operation: impl Into<String>, errno: i32) -> Self {
+        Self::NetlinkErrno {
+            operation: operation.into(),
+            errno,
+        }
+    }
+
+    /// Returns the errno of a failed netlink request.
+    pub fn errno(&self) -> Option<i32> {
+        match self {
+            Self::NetlinkErrno { errno, .. } => Some(*errno),
+            _ => None,
+        }
+    }
+
     /// Creates an internal error.
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
@@ -143,6 +198,7 @@ impl CfgMgrError {
             CfgMgrError::PortNotReady { .. }
                 | CfgMgrError::Database { .. }
                 | CfgMgrError::ShellCommandFailed { .. }
+                | CfgMgrError::ShellTimeout { .. }
         )
     }
 }
@@ -183,4 +239,15 @@ mod tests {
         assert!(CfgMgrError::database("get", "timeout").is_retryable());
         assert!(!CfgMgrError::internal("bug").is_retryable());
     }
+
+    #[test]
+    fn test_netlink_errno() {
+        // ENODEV
+        let err = CfgMgrError::netlink_errno("set_link_mtu", 19);
+        assert_eq!(err.errno(), Some(19));
+        assert!(err
+            .to_string()
+            .starts_with("Netlink operation failed: set_link_mtu: "));
+        assert_eq!(CfgMgrError::internal("bug").errno(), None);
+    }
 }
@@ -3,8 +3,18 @@
 //! This crate provides shared functionality for all cfgmgr daemons
 //! (portmgrd, vlanmgrd, intfmgrd, etc.) in the Rust rewrite:
 //!
-//! - [`shell`]: Safe shell command execution with proper quoting
+//! - [`shell`]: Safe shell command execution with proper quoting, timeouts
+//!   and dry-run mode
+//! - [`nl`]: rtnetlink requests replacing common `ip`/`bridge` commands
+//! - [`backend`]: [`CfgBackend`] trait with shell and netlink implementations
+//! - [`sysctl`]: [`Sysctl`] trait for `/proc/sys` parameters
+//! - [`systemd`]: [`SystemdController`] trait for unit restart/stop
+//! - [`vlan_range`]: [`VlanRangeList`] for VLAN ID range strings
 //! - [`CfgMgr`]: Base trait extending `Orch` for config managers
+//! - [`runner`]: [`CfgMgrRunner`] event loop over the subscribed tables,
+//!   with Redis subscriptions in [`redis_source`] (feature-gated)
+//! - [`warm_restart`]: [`WarmRestartHelper`] state machine with STATE_DB
+//!   persistence and stale APPL_DB cleanup
 //! - [`error`]: Error types for cfgmgr operations
 //!
 //! # Architecture
@@ -44,17 +54,38 @@
 //! | `swss::exec()` | [`shell::exec()`] |
 //! | `shellquote()` | [`shell::shellquote()`] |
 //! | `EXEC_WITH_ERROR_THROW` | [`shell::exec_or_throw()`] |
-//! | `WarmStart` class | [`WarmRestartState`] enum |
+//! | `WarmStart` class | [`WarmRestartState`] enum + [`WarmRestartHelper`] |
+//! | `Select` loop in `main()` | [`CfgMgrRunner`] |
+//! | `SubscriberStateTable` | [`TableSource`] |
 
+pub mod backend;
 pub mod error;
 pub mod manager;
+pub mod nl;
+pub mod runner;
 pub mod shell;
+pub mod sysctl;
+pub mod systemd;
+pub mod vlan_range;
+pub mod warm_restart;
+
+#[cfg(feature = "redis")]
+pub mod redis_source;
 
 // Re-export commonly used items at crate root
+pub use backend::{BackendKind, CfgBackend, NetlinkBackend, ShellBackend};
 pub use error::{CfgMgrError, CfgMgrResult};
 pub use manager::{
     defaults, CfgMgr, DbId, FieldValue, FieldValues, FieldValuesExt, WarmRestartState,
 };
+pub use runner::{CfgMgrRunner, TableSource};
+pub use sysctl::{InMemorySysctl, ProcSysctl, Sysctl};
+pub use systemd::{ShellSystemdController, SystemdController};
+pub use vlan_range::{VlanRangeError, VlanRangeList};
+pub use warm_restart::{InMemoryStore, WarmRestartHelper, WarmRestartStore};
+
+#[cfg(feature = "redis")]
+pub use redis_source::{RedisStore, RedisTableSource};
 
 // Re-export the Orch trait for convenience
-pub use sonic_orch_common::Orch;
+pub use sonic_orch_common::{KeyOpFieldsValues, Orch};
@@ -5,7 +5,8 @@
 //! functionality.
 
 use async_trait::async_trait;
-use sonic_orch_common::Orch;
+use sonic_orch_common::{KeyOpFieldsValues, Orch};
+use tracing::warn;
 
 /// Database identifiers used by cfgmgr daemons.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -51,9 +52,10 @@ pub mod defaults {
 }
 
 /// Warm restart states matching the C++ WarmStart enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum WarmRestartState {
     /// Warm restart is disabled.
+    #[default]
     Disabled,
     /// System is initializing.
     Initialized,
@@ -160,6 +162,25 @@ pub trait CfgMgr: Orch {
         &[]
     }
 
+    /// Returns the subscribed APPL_DB table names.
+    fn appl_table_names(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Queues entries read from a subscribed table for the next `do_task`.
+    ///
+    /// The [`CfgMgrRunner`](crate::runner::CfgMgrRunner) calls this once per
+    /// ready batch, in the order the entries were read from `table`.
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        warn!(
+            "{} dropped {} entries from {}:{} (add_to_sync not implemented)",
+            self.daemon_name(),
+            entries.len(),
+            db.name(),
+            table
+        );
+    }
+
     /// Called when a port becomes ready in STATE_DB.
     ///
     /// Managers can override this to handle deferred configuration
@@ -0,0 +1,630 @@
+//! Netlink (rtnetlink) interface configuration.
+//!
+//! Typed replacements for the `ip` and `bridge` commands the cfgmgr daemons
+//! run most often. Request messages are built by plain functions so their
+//! encoding can be checked byte for byte; [`NetlinkHandle`] sends them on a
+//! `NETLINK_ROUTE` socket and waits for the kernel's acknowledgement.
+//!
+//! Kernel errors are reported as [`CfgMgrError::NetlinkErrno`] with the
+//! errno from the `NLMSG_ERROR` reply.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use sonic_cfgmgr_common::nl::NetlinkHandle;
+//!
+//! let nl = NetlinkHandle::new()?;
+//! nl.set_link_mtu("Ethernet0", 9100)?;
+//! nl.set_link_admin("Ethernet0", true)?;
+//! ```
+
+use std::net::IpAddr;
+
+/// Netlink message header length (`struct nlmsghdr`).
+const NLMSG_HDRLEN: usize = 16;
+
+/// Netlink attribute header length (`struct nlattr`).
+const NLA_HDRLEN: usize = 4;
+
+/// `NLMSG_ERROR`
+const NLMSG_ERROR: u16 = 2;
+/// `NLMSG_DONE`
+const NLMSG_DONE: u16 = 3;
+
+/// `NLM_F_REQUEST`
+pub const NLM_F_REQUEST: u16 = 0x1;
+/// `NLM_F_ACK`
+pub const NLM_F_ACK: u16 = 0x4;
+/// `NLM_F_EXCL`
+pub const NLM_F_EXCL: u16 = 0x200;
+/// `NLM_F_CREATE`
+pub const NLM_F_CREATE: u16 = 0x400;
+
+/// `NLA_F_NESTED`
+const NLA_F_NESTED: u16 = 0x8000;
+
+/// `RTM_NEWLINK`
+pub const RTM_NEWLINK: u16 = 16;
+/// `RTM_DELLINK`
+pub const RTM_DELLINK: u16 = 17;
+/// `RTM_SETLINK`
+pub const RTM_SETLINK: u16 = 19;
+/// `RTM_NEWADDR`
+pub const RTM_NEWADDR: u16 = 20;
+/// `RTM_DELADDR`
+pub const RTM_DELADDR: u16 = 21;
+
+const AF_UNSPEC: u8 = 0;
+const AF_INET: u8 = 2;
+const AF_BRIDGE: u8 = 7;
+const AF_INET6: u8 = 10;
+
+const IFF_UP: u32 = 0x1;
+
+const IFLA_IFNAME: u16 = 3;
+const IFLA_MTU: u16 = 4;
+const IFLA_LINK: u16 = 5;
+const IFLA_MASTER: u16 = 10;
+const IFLA_LINKINFO: u16 = 18;
+const IFLA_AF_SPEC: u16 = 26;
+
+const IFLA_INFO_KIND: u16 = 1;
+const IFLA_INFO_DATA: u16 = 2;
+const IFLA_VLAN_ID: u16 = 1;
+
+const IFLA_BRIDGE_FLAGS: u16 = 0;
+const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+const BRIDGE_FLAGS_SELF: u16 = 0x2;
+const BRIDGE_VLAN_INFO_PVID: u16 = 0x2;
+const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 0x4;
+
+const IFA_ADDRESS: u16 = 1;
+const IFA_LOCAL: u16 = 2;
+
+/// Rounds a length up to the 4-byte netlink alignment.
+const fn nla_align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// A VLAN on a bridge port, as in `bridge vlan add vid <vid> dev <dev>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeVlan {
+    /// VLAN ID.
+    pub vid: u16,
+    /// Use this VLAN for untagged ingress (`pvid`).
+    pub pvid: bool,
+    /// Send egress frames untagged (`untagged`).
+    pub untagged: bool,
+    /// Configure the bridge device itself (`self`) rather than a port.
+    pub bridge_self: bool,
+}
+
+impl BridgeVlan {
+    /// A tagged VLAN on a bridge port.
+    pub fn tagged(vid: u16) -> Self {
+        Self {
+            vid,
+            pvid: false,
+            untagged: false,
+            bridge_self: false,
+        }
+    }
+
+    /// An untagged VLAN that is also the port's PVID.
+    pub fn untagged(vid: u16) -> Self {
+        Self {
+            vid,
+            pvid: true,
+            untagged: true,
+            bridge_self: false,
+        }
+    }
+
+    fn flags(&self) -> u16 {
+        let mut flags = 0;
+        if self.pvid {
+            flags |= BRIDGE_VLAN_INFO_PVID;
+        }
+        if self.untagged {
+            flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+        }
+        flags
+    }
+}
+
+/// A netlink request message under construction.
+///
+/// The header length and sequence number are filled in by
+/// [`finish`](Self::finish).
+#[derive(Debug, Clone)]
+pub struct NlRequest {
+    buf: Vec<u8>,
+    nests: Vec<usize>,
+}
+
+impl NlRequest {
+    /// Starts a message with the given type and flags.
+    pub fn new(msg_type: u16, flags: u16) -> Self {
+        let mut buf = Vec::with_capacity(128);
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&msg_type.to_ne_bytes());
+        buf.extend_from_slice(&flags.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        Self {
+            buf,
+            nests: Vec::new(),
+        }
+    }
+
+    /// Returns the message type.
+    pub fn msg_type(&self) -> u16 {
+        u16::from_ne_bytes([self.buf[4], self.buf[5]])
+    }
+
+    /// Appends an `ifinfomsg` header.
+    fn ifinfo(mut self, family: u8, index: u32, flags: u32, change: u32) -> Self {
+        self.buf.push(family);
+        self.buf.push(0);
+        self.buf.extend_from_slice(&0u16.to_ne_bytes());
+        self.buf.extend_from_slice(&index.to_ne_bytes());
+        self.buf.extend_from_slice(&flags.to_ne_bytes());
+        self.buf.extend_from_slice(&change.to_ne_bytes());
+        self
+    }
+
+    /// Appends an `ifaddrmsg` header.
+    fn ifaddr(mut self, family: u8, prefix_len: u8, index: u32) -> Self {
+        self.buf.extend_from_slice(&[family, prefix_len, 0, 0]);
+        self.buf.extend_from_slice(&index.to_ne_bytes());
+        self
+    }
+
+    /// Appends an attribute, padded to the netlink alignment.
+    fn attr(mut self, attr_type: u16, data: &[u8]) -> Self {
+        let len = NLA_HDRLEN + data.len();
+        self.buf.extend_from_slice(&(len as u16).to_ne_bytes());
+        self.buf.extend_from_slice(&attr_type.to_ne_bytes());
+        self.buf.extend_from_slice(data);
+        self.buf.resize(nla_align(self.buf.len()), 0);
+        self
+    }
+
+    fn attr_u16(self, attr_type: u16, value: u16) -> Self {
+        self.attr(attr_type, &value.to_ne_bytes())
+    }
+
+    fn attr_u32(self, attr_type: u16, value: u32) -> Self {
+        self.attr(attr_type, &value.to_ne_bytes())
+    }
+
+    /// Appends a NUL-terminated string attribute.
+    fn attr_str(self, attr_type: u16, value: &str) -> Self {
+        let mut data = Vec::with_capacity(value.len() + 1);
+        data.extend_from_slice(value.as_bytes());
+        data.push(0);
+        self.attr(attr_type, &data)
+    }
+
+    /// Opens a nested attribute; close it with [`end_nested`](Self::end_nested).
+    fn begin_nested(mut self, attr_type: u16) -> Self {
+        self.nests.push(self.buf.len());
+        self.buf.extend_from_slice(&0u16.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&(attr_type | NLA_F_NESTED).to_ne_bytes());
+        self
+    }
+
+    fn end_nested(mut self) -> Self {
+        let start = self.nests.pop().expect("end_nested without begin_nested");
+        let len = (self.buf.len() - start) as u16;
+        self.buf[start..start + 2].copy_from_slice(&len.to_ne_bytes());
+        self
+    }
+
+    /// Completes the message with the given sequence number.
+    pub fn finish(mut self, seq: u32) -> Vec<u8> {
+        debug_assert!(self.nests.is_empty(), "unclosed nested attribute");
+        let len = self.buf.len() as u32;
+        self.buf[0..4].copy_from_slice(&len.to_ne_bytes());
+        self.buf[8..12].copy_from_slice(&seq.to_ne_bytes());
+        self.buf
+    }
+}
+
+/// `ip link set dev <name> mtu <mtu>`
+pub fn set_link_mtu_request(name: &str, mtu: u32) -> NlRequest {
+    NlRequest::new(RTM_SETLINK, NLM_F_REQUEST | NLM_F_ACK)
+        .ifinfo(AF_UNSPEC, 0, 0, 0)
+        .attr_str(IFLA_IFNAME, name)
+        .attr_u32(IFLA_MTU, mtu)
+}
+
+/// `ip link set dev <name> up|down`
+pub fn set_link_admin_request(name: &str, up: bool) -> NlRequest {
+    let flags = if up { IFF_UP } else { 0 };
+    NlRequest::new(RTM_SETLINK, NLM_F_REQUEST | NLM_F_ACK)
+        .ifinfo(AF_UNSPEC, 0, flags, IFF_UP)
+        .attr_str(IFLA_IFNAME, name)
+}
+
+/// `ip link add link <parent> name <name> type vlan id <vid>`
+pub fn add_link_vlan_request(parent_index: u32, name: &str, vid: u16) -> NlRequest {
+    NlRequest::new(
+        RTM_NEWLINK,
+        NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_EXCL,
+    )
+    .ifinfo(AF_UNSPEC, 0, 0, 0)
+    .attr_u32(IFLA_LINK, parent_index)
+    .attr_str(IFLA_IFNAME, name)
+    .begin_nested(IFLA_LINKINFO)
+    .attr_str(IFLA_INFO_KIND, "vlan")
+    .begin_nested(IFLA_INFO_DATA)
+    .attr_u16(IFLA_VLAN_ID, vid)
+    .end_nested()
+    .end_nested()
+}
+
+/// `bridge vlan add|del vid <vid> dev <dev> [pvid] [untagged] [self]`
+pub fn bridge_vlan_request(index: u32, vlan: &BridgeVlan, add: bool) -> NlRequest {
+    let msg_type = if add { RTM_SETLINK } else { RTM_DELLINK };
+    let mut info = Vec::with_capacity(4);
+    info.extend_from_slice(&vlan.flags().to_ne_bytes());
+    info.extend_from_slice(&vlan.vid.to_ne_bytes());
+
+    let mut req = NlRequest::new(msg_type, NLM_F_REQUEST | NLM_F_ACK)
+        .ifinfo(AF_BRIDGE, index, 0, 0)
+        .begin_nested(IFLA_AF_SPEC);
+    if vlan.bridge_self {
+        req = req.attr_u16(IFLA_BRIDGE_FLAGS, BRIDGE_FLAGS_SELF);
+    }
+    req.attr(IFLA_BRIDGE_VLAN_INFO, &info).end_nested()
+}
+
+/// `ip address add|del <addr>/<prefix_len> dev <dev>`
+pub fn addr_request(index: u32, addr: IpAddr, prefix_len: u8, add: bool) -> NlRequest {
+    let (msg_type, flags) = if add {
+        (
+            RTM_NEWADDR,
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_EXCL,
+        )
+    } else {
+        (RTM_DELADDR, NLM_F_REQUEST | NLM_F_ACK)
+    };
+    let (family, bytes) = match addr {
+        IpAddr::V4(a) => (AF_INET, a.octets().to_vec()),
+        IpAddr::V6(a) => (AF_INET6, a.octets().to_vec()),
+    };
+    NlRequest::new(msg_type, flags)
+        .ifaddr(family, prefix_len, index)
+        .attr(IFA_LOCAL, &bytes)
+        .attr(IFA_ADDRESS, &bytes)
+}
+
+/// `ip link set <name> master <vrf>`, or `nomaster` when `master_index` is 0.
+pub fn set_link_master_request(name: &str, master_index: u32) -> NlRequest {
+    NlRequest::new(RTM_SETLINK, NLM_F_REQUEST | NLM_F_ACK)
+        .ifinfo(AF_UNSPEC, 0, 0, 0)
+        .attr_str(IFLA_IFNAME, name)
+        .attr_u32(IFLA_MASTER, master_index)
+}
+
+/// Looks for the acknowledgement of request `seq` in a receive buffer.
+///
+/// Returns `Ok(true)` once the request is acknowledged, `Ok(false)` if the
+/// buffer holds only unrelated messages, and `Err(errno)` if the kernel
+/// rejected the request.
+pub fn parse_ack(buf: &[u8], seq: u32) -> Result<bool, i32> {
+    let mut offset = 0;
+    while offset + NLMSG_HDRLEN <= buf.len() {
+        let hdr = &buf[offset..];
+        let len = u32::from_ne_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]) as usize;
+        let msg_type = u16::from_ne_bytes([hdr[4], hdr[5]]);
+        let msg_seq = u32::from_ne_bytes([hdr[8], hdr[9], hdr[10], hdr[11]]);
+        if len < NLMSG_HDRLEN || offset + len > buf.len() {
+            break;
+        }
+
+        if msg_seq == seq {
+            match msg_type {
+                NLMSG_ERROR if len >= NLMSG_HDRLEN + 4 => {
+                    let p = &hdr[NLMSG_HDRLEN..];
+                    let error = i32::from_ne_bytes([p[0], p[1], p[2], p[3]]);
+                    return if error == 0 { Ok(true) } else { Err(-error) };
+                }
+                NLMSG_DONE => return Ok(true),
+                _ => {}
+            }
+        }
+        offset += nla_align(len);
+    }
+    Ok(false)
+}
+
+#[cfg(target_os = "linux")]
+mod socket {
+    use super::*;
+    use crate::error::{CfgMgrError, CfgMgrResult};
+    use netlink_sys::{protocols::NETLINK_ROUTE, Socket, SocketAddr};
+    use std::ffi::CString;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    /// Receive buffer size; acknowledgements are small.
+    const RECV_BUF_SIZE: usize = 8192;
+
+    fn io_error(operation: &str, e: std::io::Error) -> CfgMgrError {
+        match e.raw_os_error() {
+            Some(errno) => CfgMgrError::netlink_errno(operation, errno),
+            None => CfgMgrError::Netlink {
+                operation: operation.to_string(),
+                message: e.to_string(),
+            },
+        }
+    }
+
+    /// A `NETLINK_ROUTE` socket for configuration requests.
+    ///
+    /// Requests are synchronous: each one blocks until the kernel
+    /// acknowledges it, which for link and address changes is immediate.
+    pub struct NetlinkHandle {
+        socket: Mutex<Socket>,
+        seq: AtomicU32,
+    }
+
+    impl NetlinkHandle {
+        /// Opens and connects a netlink socket.
+        pub fn new() -> CfgMgrResult<Self> {
+            let mut socket = Socket::new(NETLINK_ROUTE).map_err(|e| io_error("socket", e))?;
+            socket.bind_auto().map_err(|e| io_error("bind", e))?;
+            socket
+                .connect(&SocketAddr::new(0, 0))
+                .map_err(|e| io_error("connect", e))?;
+            Ok(Self {
+                socket: Mutex::new(socket),
+                seq: AtomicU32::new(1),
+            })
+        }
+
+        /// Returns the ifindex of an interface (`ENODEV` if it does not exist).
+        pub fn ifindex(&self, name: &str) -> CfgMgrResult<u32> {
+            let cname = CString::new(name)
+                .map_err(|_| CfgMgrError::invalid_config("ifname", name.to_string()))?;
+            // SAFETY: cname is a valid NUL-terminated string
+            let index = unsafe { libc::if_nametoindex(cname.as_ptr()) };
+            if index == 0 {
+                Err(CfgMgrError::netlink_errno(
+                    format!("ifindex {}", name),
+                    libc::ENODEV,
+                ))
+            } else {
+                Ok(index)
+            }
+        }
+
+        /// Sends a request and waits for its acknowledgement.
+        pub fn request(&self, operation: &str, request: NlRequest) -> CfgMgrResult<()> {
+            let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+            let msg = request.finish(seq);
+            let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+
+            tracing::debug!(operation, seq, len = msg.len(), "Sending netlink request");
+            socket.send(&msg, 0).map_err(|e| io_error(operation, e))?;
+
+            let mut buf = Vec::with_capacity(RECV_BUF_SIZE);
+            loop {
+                buf.clear();
+                socket
+                    .recv(&mut buf, 0)
+                    .map_err(|e| io_error(operation, e))?;
+                match parse_ack(&buf, seq) {
+                    Ok(true) => return Ok(()),
+                    Ok(false) => continue,
+                    Err(errno) => return Err(CfgMgrError::netlink_errno(operation, errno)),
+                }
+            }
+        }
+
+        /// Sets the MTU of an interface.
+        pub fn set_link_mtu(&self, name: &str, mtu: u32) -> CfgMgrResult<()> {
+            self.request("set_link_mtu", set_link_mtu_request(name, mtu))
+        }
+
+        /// Sets the admin state of an interface.
+        pub fn set_link_admin(&self, name: &str, up: bool) -> CfgMgrResult<()> {
+            self.request("set_link_admin", set_link_admin_request(name, up))
+        }
+
+        /// Creates a VLAN interface on a parent link.
+        pub fn add_link_vlan(&self, parent: &str, name: &str, vid: u16) -> CfgMgrResult<()> {
+            let parent_index = self.ifindex(parent)?;
+            self.request(
+                "add_link_vlan",
+                add_link_vlan_request(parent_index, name, vid),
+            )
+        }
+
+        /// Adds a VLAN to a bridge port or the bridge itself.
+        pub fn bridge_vlan_add(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+            let index = self.ifindex(dev)?;
+            self.request("bridge_vlan_add", bridge_vlan_request(index, vlan, true))
+        }
+
+        /// Removes a VLAN from a bridge port or the bridge itself.
+        pub fn bridge_vlan_del(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+            let index = self.ifindex(dev)?;
+            self.request("bridge_vlan_del", bridge_vlan_request(index, vlan, false))
+        }
+
+        /// Adds an address to an interface.
+        pub fn addr_add(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+            let index = self.ifindex(dev)?;
+            self.request("addr_add", addr_request(index, addr, prefix_len, true))
+        }
+
+        /// Removes an address from an interface.
+        pub fn addr_del(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+            let index = self.ifindex(dev)?;
+            self.request("addr_del", addr_request(index, addr, prefix_len, false))
+        }
+
+        /// Binds an interface to a VRF, or unbinds it when `vrf` is `None`.
+        pub fn vrf_bind(&self, name: &str, vrf: Option<&str>) -> CfgMgrResult<()> {
+            let master_index = match vrf {
+                Some(vrf) => self.ifindex(vrf)?,
+                None => 0,
+            };
+            self.request("vrf_bind", set_link_master_request(name, master_index))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use socket::NetlinkHandle;
+
+#[cfg(all(test, target_endian = "little"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_link_mtu_golden() {
+        let msg = set_link_mtu_request("Ethernet0", 9100).finish(1);
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            // nlmsghdr: len 56, RTM_SETLINK, REQUEST|ACK, seq 1, pid 0
+            56, 0, 0, 0, 19, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            // ifinfomsg: AF_UNSPEC, index 0, flags 0, change 0
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            // IFLA_IFNAME "Ethernet0\0" + 2 bytes padding
+            14, 0, 3, 0, b'E', b't', b'h', b'e', b'r', b'n', b'e', b't', b'0', 0, 0, 0,
+            // IFLA_MTU 9100
+            8, 0, 4, 0, 0x8c, 0x23, 0, 0,
+        ];
+        assert_eq!(msg, expected);
+    }
+
+    #[test]
+    fn test_set_link_admin_golden() {
+        let msg = set_link_admin_request("eth1", true).finish(7);
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            44, 0, 0, 0, 19, 0, 5, 0, 7, 0, 0, 0, 0, 0, 0, 0,
+            // ifinfomsg: flags IFF_UP, change IFF_UP
+            0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
+            // IFLA_IFNAME "eth1\0" + 3 bytes padding
+            9, 0, 3, 0, b'e', b't', b'h', b'1', 0, 0, 0, 0,
+        ];
+        assert_eq!(msg, expected);
+
+        let down = set_link_admin_request("eth1", false).finish(7);
+        assert_eq!(&down[24..28], &[0, 0, 0, 0]);
+        assert_eq!(&down[28..32], &[1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_add_link_vlan_golden() {
+        let msg = add_link_vlan_request(2, "Vlan100", 100).finish(3);
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            // RTM_NEWLINK, REQUEST|ACK|EXCL|CREATE
+            80, 0, 0, 0, 16, 0, 0x05, 0x06, 3, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            // IFLA_LINK 2
+            8, 0, 5, 0, 2, 0, 0, 0,
+            // IFLA_IFNAME "Vlan100\0"
+            12, 0, 3, 0, b'V', b'l', b'a', b'n', b'1', b'0', b'0', 0,
+            // IFLA_LINKINFO (nested, 28 bytes)
+            28, 0, 18, 0x80,
+            // IFLA_INFO_KIND "vlan\0" + padding
+            9, 0, 1, 0, b'v', b'l', b'a', b'n', 0, 0, 0, 0,
+            // IFLA_INFO_DATA (nested) { IFLA_VLAN_ID 100 + padding }
+            12, 0, 2, 0x80, 6, 0, 1, 0, 100, 0, 0, 0,
+        ];
+        assert_eq!(msg, expected);
+    }
+
+    #[test]
+    fn test_bridge_vlan_golden() {
+        let msg = bridge_vlan_request(5, &BridgeVlan::untagged(100), true).finish(1);
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            44, 0, 0, 0, 19, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            // ifinfomsg: AF_BRIDGE, index 5
+            7, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            // IFLA_AF_SPEC (nested) { IFLA_BRIDGE_VLAN_INFO pvid|untagged, vid 100 }
+            12, 0, 26, 0x80, 8, 0, 2, 0, 6, 0, 100, 0,
+        ];
+        assert_eq!(msg, expected);
+
+        let vlan = BridgeVlan {
+            bridge_self: true,
+            ..BridgeVlan::tagged(1)
+        };
+        let msg = bridge_vlan_request(9, &vlan, false).finish(1);
+        assert_eq!(NlRequest::new(RTM_DELLINK, 0).msg_type(), RTM_DELLINK);
+        assert_eq!(&msg[4..6], &RTM_DELLINK.to_ne_bytes());
+        // IFLA_BRIDGE_FLAGS self precedes the VLAN info
+        assert_eq!(&msg[36..44], &[6, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(&msg[44..52], &[8, 0, 2, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_addr_golden() {
+        let msg = addr_request(4, "10.0.0.1".parse().unwrap(), 31, true).finish(2);
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            // RTM_NEWADDR, REQUEST|ACK|EXCL|CREATE
+            40, 0, 0, 0, 20, 0, 0x05, 0x06, 2, 0, 0, 0, 0, 0, 0, 0,
+            // ifaddrmsg: AF_INET, /31, index 4
+            2, 31, 0, 0, 4, 0, 0, 0,
+            // IFA_LOCAL, IFA_ADDRESS
+            8, 0, 2, 0, 10, 0, 0, 1,
+            8, 0, 1, 0, 10, 0, 0, 1,
+        ];
+        assert_eq!(msg, expected);
+
+        let msg = addr_request(4, "fc00::1".parse().unwrap(), 64, false).finish(2);
+        assert_eq!(msg.len(), 16 + 8 + 2 * 20);
+        assert_eq!(&msg[4..8], &[21, 0, 5, 0]);
+        assert_eq!(&msg[16..18], &[10, 64]);
+    }
+
+    #[test]
+    fn test_set_link_master_golden() {
+        let msg = set_link_master_request("eth1", 12).finish(1);
+        assert_eq!(msg.len(), 52);
+        assert_eq!(&msg[44..52], &[8, 0, 10, 0, 12, 0, 0, 0]);
+
+        let msg = set_link_master_request("eth1", 0).finish(1);
+        assert_eq!(&msg[48..52], &[0, 0, 0, 0]);
+    }
+
+    fn ack(seq: u32, error: i32) -> Vec<u8> {
+        let mut msg = Vec::new();
+        msg.extend_from_slice(&36u32.to_ne_bytes());
+        msg.extend_from_slice(&NLMSG_ERROR.to_ne_bytes());
+        msg.extend_from_slice(&0u16.to_ne_bytes());
+        msg.extend_from_slice(&seq.to_ne_bytes());
+        msg.extend_from_slice(&0u32.to_ne_bytes());
+        msg.extend_from_slice(&error.to_ne_bytes());
+        // Echoed request header
+        msg.extend_from_slice(&[0; 16]);
+        msg
+    }
+
+    #[test]
+    fn test_parse_ack() {
+        assert_eq!(parse_ack(&ack(5, 0), 5), Ok(true));
+        // -ENODEV
+        assert_eq!(parse_ack(&ack(5, -19), 5), Err(19));
+        // Reply to another request is skipped
+        assert_eq!(parse_ack(&ack(4, -1), 5), Ok(false));
+
+        let mut buf = ack(4, 0);
+        buf.extend_from_slice(&ack(5, -17));
+        assert_eq!(parse_ack(&buf, 5), Err(17));
+
+        // Truncated buffers are ignored
+        assert_eq!(parse_ack(&ack(5, 0)[..10], 5), Ok(false));
+    }
+}
@@ -0,0 +1,261 @@
+//! Redis-backed [`TableSource`] and [`WarmRestartStore`].
+//!
+//! [`RedisTableSource`] behaves like the C++ `SubscriberStateTable`: it
+//! subscribes to keyspace notifications for `TABLE<sep>*`, and for every
+//! notified key reads the whole hash, producing a SET with the current
+//! fields or a DEL when the key is gone. The Redis server must have keyspace
+//! events enabled (`notify-keyspace-events` including `K` and `h`/`g`), as
+//! on every SONiC switch.
+//!
+//! [`RedisStore`] gives the [`WarmRestartHelper`](crate::WarmRestartHelper)
+//! access to STATE_DB and APPL_DB.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use sonic_orch_common::KeyOpFieldsValues;
+use tracing::debug;
+
+use crate::error::{CfgMgrError, CfgMgrResult};
+use crate::manager::{CfgMgr, DbId, FieldValues};
+use crate::runner::{CfgMgrRunner, TableSource};
+use crate::warm_restart::WarmRestartStore;
+
+/// Returns the key separator used by tables of a database.
+pub fn table_separator(db: DbId) -> char {
+    match db {
+        DbId::ApplDb => ':',
+        DbId::ConfigDb | DbId::StateDb => '|',
+    }
+}
+
+/// A table subscribed through Redis keyspace notifications.
+pub struct RedisTableSource {
+    db: DbId,
+    table: String,
+    prefix: String,
+    conn: MultiplexedConnection,
+    messages: Pin<Box<dyn Stream<Item = redis::Msg> + Send>>,
+}
+
+impl RedisTableSource {
+    /// Subscribes to `table` of `db` on the server at `url`
+    /// (`redis://host:port`).
+    ///
+    /// The subscription is active when this returns, so changes made after
+    /// it are delivered by [`TableSource::pop`] even if they happen before
+    /// [`TableSource::load`].
+    pub async fn connect(url: &str, db: DbId, table: &str) -> CfgMgrResult<Self> {
+        let client = redis::Client::open(format!("{}/{}", url.trim_end_matches('/'), db.id()))
+            .map_err(|e| CfgMgrError::database("connect", e.to_string()))?;
+
+        let prefix = format!("{}{}", table, table_separator(db));
+        let mut pubsub = client
+            .get_async_pubsub()
+            .await
+            .map_err(|e| CfgMgrError::database("connect", e.to_string()))?;
+        pubsub
+            .psubscribe(format!("__keyspace@{}__:{}*", db.id(), prefix))
+            .await
+            .map_err(|e| CfgMgrError::database("psubscribe", e.to_string()))?;
+
+        let conn = client
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_err(|e| CfgMgrError::database("connect", e.to_string()))?;
+
+        Ok(Self {
+            db,
+            table: table.to_string(),
+            prefix,
+            conn,
+            messages: Box::pin(pubsub.into_on_message()),
+        })
+    }
+
+    /// Reads the current state of `key` as a SET, or a DEL if it is gone.
+    async fn read_entry(&mut self, key: &str) -> CfgMgrResult<KeyOpFieldsValues> {
+        let redis_key = format!("{}{}", self.prefix, key);
+        let fvs: Vec<(String, String)> = self
+            .conn
+            .hgetall(&redis_key)
+            .await
+            .map_err(|e| CfgMgrError::database("HGETALL", e.to_string()))?;
+
+        if fvs.is_empty() {
+            Ok(KeyOpFieldsValues::del(key))
+        } else {
+            Ok(KeyOpFieldsValues::set(key, fvs))
+        }
+    }
+}
+
+#[async_trait]
+impl TableSource for RedisTableSource {
+    fn db(&self) -> DbId {
+        self.db
+    }
+
+    fn table_name(&self) -> &str {
+        &self.table
+    }
+
+    async fn load(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>> {
+        let pattern = format!("{}*", self.prefix);
+        let mut keys: Vec<String> = self
+            .conn
+            .keys(&pattern)
+            .await
+            .map_err(|e| CfgMgrError::database("KEYS", e.to_string()))?;
+        keys.sort();
+
+        let mut entries = Vec::with_capacity(keys.len());
+        for redis_key in keys {
+            let key = redis_key[self.prefix.len()..].to_string();
+            let entry = self.read_entry(&key).await?;
+            if entry.op.is_set() {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    async fn pop(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>> {
+        loop {
+            let msg = self.messages.next().await.ok_or_else(|| {
+                CfgMgrError::database("keyspace", format!("{} subscription closed", self.table))
+            })?;
+
+            // Channel is "__keyspace@<db>__:<TABLE><sep><key>"
+            let channel = msg.get_channel_name();
+            let Some(key) = channel
+                .split_once(':')
+                .and_then(|(_, redis_key)| redis_key.strip_prefix(&self.prefix))
+            else {
+                continue;
+            };
+            let key = key.to_string();
+            debug!(
+                "{}:{} changed ({:?})",
+                self.table,
+                key,
+                msg.get_payload::<String>().ok()
+            );
+
+            return Ok(vec![self.read_entry(&key).await?]);
+        }
+    }
+}
+
+impl<M: CfgMgr> CfgMgrRunner<M> {
+    /// Subscribes to every table the manager declares on the server at `url`.
+    pub async fn with_redis(mut self, url: &str) -> CfgMgrResult<Self> {
+        for (db, table) in Self::subscriptions(self.manager()) {
+            let source = RedisTableSource::connect(url, db, &table).await?;
+            self = self.with_source(source);
+        }
+        Ok(self)
+    }
+}
+
+/// [`WarmRestartStore`] over CONFIG_DB, APPL_DB and STATE_DB on one server.
+pub struct RedisStore {
+    config_db: MultiplexedConnection,
+    appl_db: MultiplexedConnection,
+    state_db: MultiplexedConnection,
+}
+
+impl RedisStore {
+    /// Connects to the databases on the server at `url` (`redis://host:port`).
+    pub async fn connect(url: &str) -> CfgMgrResult<Self> {
+        Ok(Self {
+            config_db: connect_db(url, DbId::ConfigDb).await?,
+            appl_db: connect_db(url, DbId::ApplDb).await?,
+            state_db: connect_db(url, DbId::StateDb).await?,
+        })
+    }
+
+    fn conn(&self, db: DbId) -> MultiplexedConnection {
+        match db {
+            DbId::ConfigDb => self.config_db.clone(),
+            DbId::ApplDb => self.appl_db.clone(),
+            DbId::StateDb => self.state_db.clone(),
+        }
+    }
+}
+
+async fn connect_db(url: &str, db: DbId) -> CfgMgrResult<MultiplexedConnection> {
+    redis::Client::open(format!("{}/{}", url.trim_end_matches('/'), db.id()))
+        .map_err(|e| CfgMgrError::database("connect", e.to_string()))?
+        .get_multiplexed_tokio_connection()
+        .await
+        .map_err(|e| CfgMgrError::database("connect", e.to_string()))
+}
+
+fn redis_key(db: DbId, table: &str, key: &str) -> String {
+    format!("{}{}{}", table, table_separator(db), key)
+}
+
+#[async_trait]
+impl WarmRestartStore for RedisStore {
+    async fn get_all(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<FieldValues> {
+        self.conn(db)
+            .hgetall(redis_key(db, table, key))
+            .await
+            .map_err(|e| CfgMgrError::database("HGETALL", e.to_string()))
+    }
+
+    async fn set(&self, db: DbId, table: &str, key: &str, fvs: &FieldValues) -> CfgMgrResult<()> {
+        self.conn(db)
+            .hset_multiple(redis_key(db, table, key), fvs)
+            .await
+            .map_err(|e| CfgMgrError::database("HSET", e.to_string()))
+    }
+
+    async fn keys(&self, db: DbId, table: &str) -> CfgMgrResult<Vec<String>> {
+        let prefix = format!("{}{}", table, table_separator(db));
+        let keys: Vec<String> = self
+            .conn(db)
+            .keys(format!("{}*", prefix))
+            .await
+            .map_err(|e| CfgMgrError::database("KEYS", e.to_string()))?;
+        Ok(keys
+            .into_iter()
+            .map(|key| key[prefix.len()..].to_string())
+            .collect())
+    }
+
+    async fn del(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<()> {
+        self.conn(db)
+            .del(redis_key(db, table, key))
+            .await
+            .map_err(|e| CfgMgrError::database("DEL", e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_separator() {
+        assert_eq!(table_separator(DbId::ConfigDb), '|');
+        assert_eq!(table_separator(DbId::StateDb), '|');
+        assert_eq!(table_separator(DbId::ApplDb), ':');
+    }
+
+    #[test]
+    fn test_redis_key() {
+        assert_eq!(
+            redis_key(DbId::StateDb, "WARM_RESTART_TABLE", "vlanmgrd"),
+            "WARM_RESTART_TABLE|vlanmgrd"
+        );
+        assert_eq!(
+            redis_key(DbId::ApplDb, "VLAN_MEMBER_TABLE", "Vlan100:Ethernet0"),
+            "VLAN_MEMBER_TABLE:Vlan100:Ethernet0"
+        );
+    }
+}
@@ -0,0 +1,540 @@
+//! Event loop shared by the cfgmgr daemons.
+//!
+//! [`CfgMgrRunner`] is the Rust counterpart of the `Select` loop in the
+//! `main()` of every C++ cfgmgr. It owns one [`TableSource`] per subscribed
+//! table, waits on all of them with a timeout, hands each ready batch to the
+//! manager through [`CfgMgr::add_to_sync`] and then calls `do_task`. When the
+//! timeout fires with nothing to read, `do_task` still runs if the manager
+//! has pending work, which is how parked tasks get retried.
+//!
+//! # Startup and warm restart
+//!
+//! Before any live change is delivered, the current contents of every
+//! CONFIG_DB table are loaded and processed. On warm restart this is the
+//! replay: `build_replay_list` runs after the CONFIG_DB load, and the state
+//! moves to [`WarmRestartState::Replayed`] once the replayed entries have
+//! been through `do_task`, then to [`WarmRestartState::Reconciled`] as soon
+//! as [`CfgMgr::is_replay_done`] reports true. STATE_DB and APPL_DB tables
+//! are loaded after the replay.
+//!
+//! Sources must start buffering changes when they are created, so nothing
+//! written between the initial load and the start of the loop is lost.
+//!
+//! # Shutdown
+//!
+//! [`CfgMgrRunner::run`] stops on SIGTERM (or Ctrl-C). Batches that were
+//! already read are flushed through one last `do_task` before the manager is
+//! handed back.
+
+use std::future::Future;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sonic_orch_common::KeyOpFieldsValues;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tracing::{debug, error, info};
+
+use crate::error::CfgMgrResult;
+use crate::manager::{defaults, CfgMgr, DbId, WarmRestartState};
+
+/// A subscribed table, the equivalent of a C++ `SubscriberStateTable`.
+#[async_trait]
+pub trait TableSource: Send {
+    /// Returns the database the table lives in.
+    fn db(&self) -> DbId;
+
+    /// Returns the table name.
+    fn table_name(&self) -> &str;
+
+    /// Returns the current contents of the table as SET entries.
+    async fn load(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>>;
+
+    /// Waits for the next changes to the table.
+    async fn pop(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>>;
+}
+
+/// A batch read by a source, tagged with the index of the source.
+type Batch = (usize, CfgMgrResult<Vec<KeyOpFieldsValues>>);
+
+/// Runs a [`CfgMgr`] against its subscribed tables.
+pub struct CfgMgrRunner<M> {
+    mgr: M,
+    sources: Vec<Box<dyn TableSource>>,
+    select_timeout: Duration,
+}
+
+impl<M: CfgMgr> CfgMgrRunner<M> {
+    /// Creates a runner with no sources.
+    pub fn new(mgr: M) -> Self {
+        Self {
+            mgr,
+            sources: Vec::new(),
+            select_timeout: Duration::from_millis(defaults::SELECT_TIMEOUT_MS),
+        }
+    }
+
+    /// Adds a subscribed table.
+    pub fn with_source(mut self, source: impl TableSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// Sets how long to wait for changes before calling `do_task` anyway.
+    pub fn with_select_timeout(mut self, timeout: Duration) -> Self {
+        self.select_timeout = timeout;
+        self
+    }
+
+    /// Returns the tables the manager declares, CONFIG_DB first.
+    pub fn subscriptions(mgr: &M) -> Vec<(DbId, String)> {
+        let config = mgr.config_table_names().iter().map(|t| (DbId::ConfigDb, t));
+        let state = mgr.state_table_names().iter().map(|t| (DbId::StateDb, t));
+        let appl = mgr.appl_table_names().iter().map(|t| (DbId::ApplDb, t));
+        config
+            .chain(state)
+            .chain(appl)
+            .map(|(db, table)| (db, table.to_string()))
+            .collect()
+    }
+
+    /// Returns the manager.
+    pub fn manager(&self) -> &M {
+        &self.mgr
+    }
+
+    /// Runs until SIGTERM or Ctrl-C and returns the manager.
+    pub async fn run(self) -> CfgMgrResult<M> {
+        self.run_until(shutdown_signal()).await
+    }
+
+    /// Runs until `shutdown` completes and returns the manager.
+    ///
+    /// Fails if a source fails; the error is returned without a final flush.
+    pub async fn run_until(mut self, shutdown: impl Future<Output = ()>) -> CfgMgrResult<M> {
+        info!(
+            "{}: starting event loop over {} tables ({:?} timeout)",
+            self.mgr.daemon_name(),
+            self.sources.len(),
+            self.select_timeout
+        );
+
+        self.replay().await?;
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<Batch>();
+        let mut pumps = JoinSet::new();
+        let mut tables = Vec::with_capacity(self.sources.len());
+        for (index, source) in self.sources.drain(..).enumerate() {
+            tables.push((source.db(), source.table_name().to_string()));
+            pumps.spawn(pump(index, source, tx.clone()));
+        }
+        drop(tx);
+
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                batch = rx.recv() => match batch {
+                    Some(batch) => {
+                        add_batch(&mut self.mgr, &tables, batch)?;
+                        while let Ok(batch) = rx.try_recv() {
+                            add_batch(&mut self.mgr, &tables, batch)?;
+                        }
+                        self.mgr.do_task().await;
+                    }
+                    // Every source has ended; keep running on the timeout only
+                    None => {
+                        tokio::select! {
+                            _ = &mut shutdown => break,
+                            _ = tokio::time::sleep(self.select_timeout) => {}
+                        }
+                        self.on_timeout().await;
+                    }
+                },
+                _ = tokio::time::sleep(self.select_timeout) => self.on_timeout().await,
+            }
+            self.check_reconciled().await;
+        }
+
+        info!("{}: shutting down", self.mgr.daemon_name());
+        pumps.abort_all();
+        let mut flushed = false;
+        while let Ok(batch) = rx.try_recv() {
+            add_batch(&mut self.mgr, &tables, batch)?;
+            flushed = true;
+        }
+        if flushed || self.mgr.has_pending_tasks() {
+            self.mgr.do_task().await;
+        }
+        Ok(self.mgr)
+    }
+
+    /// Loads CONFIG_DB (the warm restart replay), then the other tables.
+    async fn replay(&mut self) -> CfgMgrResult<()> {
+        let (config, others): (Vec<usize>, Vec<usize>) =
+            (0..self.sources.len()).partition(|&i| self.sources[i].db() == DbId::ConfigDb);
+
+        for index in config {
+            self.load_source(index).await?;
+        }
+        if self.mgr.is_warm_restart() {
+            self.mgr.build_replay_list().await;
+        }
+        self.mgr.do_task().await;
+        if self.mgr.is_warm_restart() {
+            self.mgr
+                .set_warm_restart_state(WarmRestartState::Replayed)
+                .await;
+            self.check_reconciled().await;
+        }
+
+        if !others.is_empty() {
+            for index in others {
+                self.load_source(index).await?;
+            }
+            self.mgr.do_task().await;
+        }
+        Ok(())
+    }
+
+    async fn load_source(&mut self, index: usize) -> CfgMgrResult<()> {
+        let source = &mut self.sources[index];
+        let entries = source.load().await?;
+        debug!(
+            "Loaded {} entries from {}:{}",
+            entries.len(),
+            source.db().name(),
+            source.table_name()
+        );
+        if !entries.is_empty() {
+            let db = source.db();
+            let table = source.table_name().to_string();
+            self.mgr.add_to_sync(db, &table, entries);
+        }
+        Ok(())
+    }
+
+    async fn on_timeout(&mut self) {
+        if self.mgr.has_pending_tasks() {
+            self.mgr.do_task().await;
+        }
+    }
+
+    async fn check_reconciled(&mut self) {
+        if self.mgr.warm_restart_state() == WarmRestartState::Replayed && self.mgr.is_replay_done()
+        {
+            self.mgr
+                .set_warm_restart_state(WarmRestartState::Reconciled)
+                .await;
+        }
+    }
+}
+
+/// Forwards every batch of a source until it fails or the runner stops.
+async fn pump(index: usize, mut source: Box<dyn TableSource>, tx: mpsc::UnboundedSender<Batch>) {
+    loop {
+        let batch = source.pop().await;
+        let failed = batch.is_err();
+        if tx.send((index, batch)).is_err() || failed {
+            return;
+        }
+    }
+}
+
+fn add_batch<M: CfgMgr>(mgr: &mut M, tables: &[(DbId, String)], batch: Batch) -> CfgMgrResult<()> {
+    let (index, entries) = batch;
+    let (db, table) = &tables[index];
+    match entries {
+        Ok(entries) if entries.is_empty() => Ok(()),
+        Ok(entries) => {
+            mgr.add_to_sync(*db, table, entries);
+            Ok(())
+        }
+        Err(e) => {
+            error!(
+                "{}: reading {}:{} failed: {}",
+                mgr.daemon_name(),
+                db.name(),
+                table,
+                e
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Completes on SIGTERM or Ctrl-C.
+pub async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = sigterm.recv() => info!("Received SIGTERM"),
+                    _ = tokio::signal::ctrl_c() => info!("Received Ctrl-C"),
+                }
+                return;
+            }
+            Err(e) => error!("Failed to install SIGTERM handler: {}", e),
+        }
+    }
+
+    if tokio::signal::ctrl_c().await.is_ok() {
+        info!("Received Ctrl-C");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CfgMgrError;
+    use sonic_orch_common::Orch;
+    use tokio::sync::oneshot;
+
+    /// Source fed by the test through a channel.
+    struct ChannelSource {
+        db: DbId,
+        table: String,
+        initial: Vec<KeyOpFieldsValues>,
+        rx: mpsc::UnboundedReceiver<CfgMgrResult<Vec<KeyOpFieldsValues>>>,
+    }
+
+    fn source(
+        db: DbId,
+        table: &str,
+        initial: Vec<KeyOpFieldsValues>,
+    ) -> (
+        ChannelSource,
+        mpsc::UnboundedSender<CfgMgrResult<Vec<KeyOpFieldsValues>>>,
+    ) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let source = ChannelSource {
+            db,
+            table: table.to_string(),
+            initial,
+            rx,
+        };
+        (source, tx)
+    }
+
+    #[async_trait]
+    impl TableSource for ChannelSource {
+        fn db(&self) -> DbId {
+            self.db
+        }
+
+        fn table_name(&self) -> &str {
+            &self.table
+        }
+
+        async fn load(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>> {
+            Ok(std::mem::take(&mut self.initial))
+        }
+
+        async fn pop(&mut self) -> CfgMgrResult<Vec<KeyOpFieldsValues>> {
+            match self.rx.recv().await {
+                Some(batch) => batch,
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    /// Manager that records every call the runner makes.
+    #[derive(Default)]
+    struct RecordingMgr {
+        warm: bool,
+        state: WarmRestartState,
+        queued: Vec<String>,
+        events: Vec<String>,
+        replay_left: usize,
+        retries: usize,
+    }
+
+    #[async_trait]
+    impl Orch for RecordingMgr {
+        fn name(&self) -> &str {
+            "RecordingMgr"
+        }
+
+        async fn do_task(&mut self) {
+            let queued = std::mem::take(&mut self.queued);
+            self.replay_left = self.replay_left.saturating_sub(queued.len());
+            self.events.push(format!("do_task[{}]", queued.join(",")));
+            self.retries = self.retries.saturating_sub(1);
+        }
+
+        fn has_pending_tasks(&self) -> bool {
+            self.retries > 0
+        }
+    }
+
+    #[async_trait]
+    impl CfgMgr for RecordingMgr {
+        fn daemon_name(&self) -> &str {
+            "recmgrd"
+        }
+
+        fn is_warm_restart(&self) -> bool {
+            self.warm
+        }
+
+        fn warm_restart_state(&self) -> WarmRestartState {
+            self.state
+        }
+
+        async fn set_warm_restart_state(&mut self, state: WarmRestartState) {
+            self.state = state;
+            self.events.push(state.as_str().to_string());
+        }
+
+        fn is_replay_done(&self) -> bool {
+            self.replay_left == 0
+        }
+
+        async fn build_replay_list(&mut self) {
+            self.replay_left = self.queued.len();
+            self.events.push("build_replay_list".to_string());
+        }
+
+        fn config_table_names(&self) -> &[&str] {
+            &["PORT"]
+        }
+
+        fn state_table_names(&self) -> &[&str] {
+            &["PORT_TABLE"]
+        }
+
+        fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+            for entry in entries {
+                self.queued
+                    .push(format!("{}:{}:{}", db.name(), table, entry.key));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subscriptions() {
+        let subs = CfgMgrRunner::subscriptions(&RecordingMgr::default());
+        assert_eq!(
+            subs,
+            vec![
+                (DbId::ConfigDb, "PORT".to_string()),
+                (DbId::StateDb, "PORT_TABLE".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_warm_restart_replays_config_first() {
+        // STATE_DB is added first but must load after the CONFIG_DB replay
+        let (state, _state_tx) = source(
+            DbId::StateDb,
+            "PORT_TABLE",
+            vec![KeyOpFieldsValues::set("Ethernet0", vec![])],
+        );
+        let (config, _config_tx) = source(
+            DbId::ConfigDb,
+            "PORT",
+            vec![
+                KeyOpFieldsValues::set("Ethernet0", vec![]),
+                KeyOpFieldsValues::set("Ethernet4", vec![]),
+            ],
+        );
+        let mgr = RecordingMgr {
+            warm: true,
+            state: WarmRestartState::Initialized,
+            ..Default::default()
+        };
+
+        let mgr = CfgMgrRunner::new(mgr)
+            .with_source(state)
+            .with_source(config)
+            .run_until(async {})
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mgr.events,
+            vec![
+                "build_replay_list",
+                "do_task[CONFIG_DB:PORT:Ethernet0,CONFIG_DB:PORT:Ethernet4]",
+                "replayed",
+                "reconciled",
+                "do_task[STATE_DB:PORT_TABLE:Ethernet0]",
+            ]
+        );
+        assert_eq!(mgr.state, WarmRestartState::Reconciled);
+    }
+
+    #[tokio::test]
+    async fn test_live_batches_and_shutdown() {
+        let (config, config_tx) = source(DbId::ConfigDb, "PORT", vec![]);
+        let (state, state_tx) = source(DbId::StateDb, "PORT_TABLE", vec![]);
+        let (stop_tx, stop_rx) = oneshot::channel::<()>();
+
+        let runner = CfgMgrRunner::new(RecordingMgr::default())
+            .with_source(config)
+            .with_source(state)
+            .with_select_timeout(Duration::from_secs(3600));
+        let handle = tokio::spawn(runner.run_until(async {
+            let _ = stop_rx.await;
+        }));
+
+        config_tx
+            .send(Ok(vec![KeyOpFieldsValues::set("Ethernet8", vec![])]))
+            .unwrap();
+        state_tx
+            .send(Ok(vec![KeyOpFieldsValues::del("Ethernet8")]))
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        stop_tx.send(()).unwrap();
+
+        let mgr = handle.await.unwrap().unwrap();
+        let processed: Vec<&str> = mgr
+            .events
+            .iter()
+            .map(String::as_str)
+            .filter(|e| *e != "do_task[]")
+            .collect();
+        assert_eq!(processed.concat().matches("Ethernet8").count(), 2);
+        assert!(processed.concat().contains("CONFIG_DB:PORT:Ethernet8"));
+        assert!(processed.concat().contains("STATE_DB:PORT_TABLE:Ethernet8"));
+        assert_eq!(mgr.state, WarmRestartState::Disabled);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_retries_pending_tasks() {
+        let (config, _config_tx) = source(DbId::ConfigDb, "PORT", vec![]);
+        let mgr = RecordingMgr {
+            retries: 2,
+            ..Default::default()
+        };
+
+        let mgr = CfgMgrRunner::new(mgr)
+            .with_source(config)
+            .with_select_timeout(Duration::from_millis(5))
+            .run_until(tokio::time::sleep(Duration::from_millis(100)))
+            .await
+            .unwrap();
+
+        // Initial pass, then one retry once the timeout fires
+        assert_eq!(mgr.events, vec!["do_task[]", "do_task[]"]);
+        assert_eq!(mgr.retries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_source_error_stops_runner() {
+        let (config, config_tx) = source(DbId::ConfigDb, "PORT", vec![]);
+        config_tx
+            .send(Err(CfgMgrError::database("psubscribe", "connection reset")))
+            .unwrap();
+
+        let result = CfgMgrRunner::new(RecordingMgr::default())
+            .with_source(config)
+            .run_until(std::future::pending::<()>())
+            .await;
+        assert!(matches!(result, Err(CfgMgrError::Database { .. })));
+    }
+}
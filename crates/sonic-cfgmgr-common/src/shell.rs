@@ -15,10 +15,29 @@
 //!     IP_CMD, shellquote(alias), shellquote(mtu));
 //! let result = shell::exec(&cmd).await?;
 //! ```
+//!
+//! # Dry run
+//!
+//! Setting `CFGMGR_DRY_RUN=1` in a daemon's environment makes every command
+//! go through [`ExecRequest`] without running: the command is logged and
+//! appended to a journal ([`dry_run_journal`]) and reported as successful.
+//! This lets a daemon be pointed at a live CONFIG_DB to validate what it
+//! would change.
+//!
+//! # Injection
+//!
+//! Managers that take an [`Executor`] run their commands through it rather
+//! than calling [`exec`] directly. [`ShellExecutor`] is the production
+//! implementation; tests substitute one that records commands and returns
+//! canned output.
 
+use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tokio::process::Command;
 
 use crate::error::{CfgMgrError, CfgMgrResult};
@@ -53,6 +72,54 @@ pub const IPTABLES_CMD: &str = "/sbin/iptables";
 /// Path to the `conntrack` command for connection tracking.
 pub const CONNTRACK_CMD: &str = "/usr/sbin/conntrack";
 
+/// Path to the `tc` command for traffic control (qdiscs and filters).
+pub const TC_CMD: &str = "/sbin/tc";
+
+/// Environment variable that enables dry-run mode for every command.
+pub const DRY_RUN_ENV: &str = "CFGMGR_DRY_RUN";
+
+/// Process-wide dry-run default, read from [`DRY_RUN_ENV`] on first use.
+static DRY_RUN: Lazy<AtomicBool> = Lazy::new(|| {
+    let enabled = std::env::var(DRY_RUN_ENV)
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    AtomicBool::new(enabled)
+});
+
+/// Commands recorded instead of executed in dry-run mode.
+static DRY_RUN_JOURNAL: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Returns true if commands are recorded rather than executed by default.
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Sets the process-wide dry-run default, overriding [`DRY_RUN_ENV`].
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// Records an operation in the dry-run journal instead of performing it.
+pub fn record_dry_run(entry: impl Into<String>) {
+    DRY_RUN_JOURNAL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(entry.into());
+}
+
+/// Returns the commands recorded in dry-run mode, oldest first.
+pub fn dry_run_journal() -> Vec<String> {
+    DRY_RUN_JOURNAL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Returns and clears the commands recorded in dry-run mode.
+pub fn take_dry_run_journal() -> Vec<String> {
+    std::mem::take(&mut *DRY_RUN_JOURNAL.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
 /// Regex for characters that need escaping in shell double-quotes.
 /// Matches: $, `, ", \, and newline
 static SHELL_ESCAPE_RE: Lazy<Regex> =
@@ -91,7 +158,7 @@ pub fn shellquote(s: &str) -> String {
 }
 
 /// Result of a shell command execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ExecResult {
     /// The exit code of the command (0 = success).
     pub exit_code: i32,
@@ -99,6 +166,8 @@ pub struct ExecResult {
     pub stdout: String,
     /// The combined stderr output.
     pub stderr: String,
+    /// How long the command ran (zero in dry-run mode).
+    pub duration: Duration,
 }
 
 impl ExecResult {
@@ -119,6 +188,141 @@ impl ExecResult {
     }
 }
 
+/// A shell command with execution options.
+///
+/// # Example
+///
+/// ```ignore
+/// use sonic_cfgmgr_common::shell::ExecRequest;
+/// use std::time::Duration;
+///
+/// let result = ExecRequest::new("/sbin/ip link show")
+///     .timeout(Duration::from_secs(5))
+///     .run()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecRequest {
+    command: String,
+    timeout: Option<Duration>,
+    dry_run: bool,
+}
+
+impl ExecRequest {
+    /// Creates a request for a command, using the process-wide dry-run
+    /// default and no timeout.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            timeout: None,
+            dry_run: is_dry_run(),
+        }
+    }
+
+    /// Kills the command if it runs longer than `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Records the command instead of running it.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Returns the command string.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Runs the command through `/bin/sh -c`.
+    ///
+    /// A non-zero exit is not an error; check [`ExecResult::success`].
+    /// Returns [`CfgMgrError::ShellTimeout`] if the timeout expires, after
+    /// the command has been killed.
+    pub async fn run(&self) -> CfgMgrResult<ExecResult> {
+        let cmd = self.command.as_str();
+
+        if self.dry_run {
+            tracing::info!(command = %cmd, "Dry run, not executing shell command");
+            record_dry_run(cmd);
+            return Ok(ExecResult::default());
+        }
+
+        tracing::debug!(command = %cmd, "Executing shell command");
+
+        let start = Instant::now();
+        let child = Command::new("/bin/sh")
+            .arg("-c")
+            .arg(cmd)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| CfgMgrError::ShellExec {
+                command: cmd.to_string(),
+                source: e,
+            })?;
+
+        // Dropping the wait future on timeout drops the child, which kills it
+        let output = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, child.wait_with_output())
+                .await
+                .map_err(|_| {
+                    tracing::warn!(command = %cmd, ?timeout, "Command timed out, killed");
+                    CfgMgrError::ShellTimeout {
+                        command: cmd.to_string(),
+                        timeout,
+                    }
+                })?,
+            None => child.wait_with_output().await,
+        }
+        .map_err(|e| CfgMgrError::ShellExec {
+            command: cmd.to_string(),
+            source: e,
+        })?;
+
+        let exit_code = output.status.code().unwrap_or(-1);
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+        let result = ExecResult {
+            exit_code,
+            stdout,
+            stderr,
+            duration: start.elapsed(),
+        };
+
+        if result.success() {
+            tracing::trace!(command = %cmd, exit_code = exit_code, "Command succeeded");
+        } else {
+            tracing::warn!(
+                command = %cmd,
+                exit_code = exit_code,
+                stderr = %result.stderr,
+                "Command failed"
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Runs the command, turning a non-zero exit into an error.
+    pub async fn run_or_throw(&self) -> CfgMgrResult<ExecResult> {
+        let result = self.run().await?;
+        if result.success() {
+            Ok(result)
+        } else {
+            Err(CfgMgrError::ShellCommandFailed {
+                command: self.command.clone(),
+                exit_code: result.exit_code,
+                output: result.combined_output(),
+            })
+        }
+    }
+}
+
 /// Executes a shell command asynchronously.
 ///
 /// This function runs the command through `/bin/sh -c` to support
@@ -146,42 +350,7 @@ impl ExecResult {
 /// }
 /// ```
 pub async fn exec(cmd: &str) -> CfgMgrResult<ExecResult> {
-    tracing::debug!(command = %cmd, "Executing shell command");
-
-    let output = Command::new("/bin/sh")
-        .arg("-c")
-        .arg(cmd)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .output()
-        .await
-        .map_err(|e| CfgMgrError::ShellExec {
-            command: cmd.to_string(),
-            source: e,
-        })?;
-
-    let exit_code = output.status.code().unwrap_or(-1);
-    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-
-    let result = ExecResult {
-        exit_code,
-        stdout,
-        stderr,
-    };
-
-    if result.success() {
-        tracing::trace!(command = %cmd, exit_code = exit_code, "Command succeeded");
-    } else {
-        tracing::warn!(
-            command = %cmd,
-            exit_code = exit_code,
-            stderr = %result.stderr,
-            "Command failed"
-        );
-    }
-
-    Ok(result)
+    ExecRequest::new(cmd).run().await
 }
 
 /// Executes a shell command and throws an error on non-zero exit.
@@ -197,15 +366,60 @@ pub async fn exec(cmd: &str) -> CfgMgrResult<ExecResult> {
 /// * `Ok(String)` - The stdout output on success
 /// * `Err(CfgMgrError)` - If the command fails or returns non-zero
 pub async fn exec_or_throw(cmd: &str) -> CfgMgrResult<String> {
-    let result = exec(cmd).await?;
-    if result.success() {
-        Ok(result.stdout)
-    } else {
-        Err(CfgMgrError::ShellCommandFailed {
-            command: cmd.to_string(),
-            exit_code: result.exit_code,
-            output: result.combined_output(),
-        })
+    Ok(ExecRequest::new(cmd).run_or_throw().await?.stdout)
+}
+
+/// Runs commands in order, stopping at the first one that fails.
+///
+/// Returns the results of every command on success. A spawn error, timeout
+/// or non-zero exit stops the batch with [`CfgMgrError::ShellBatchFailed`],
+/// which carries the index of the failing command.
+pub async fn exec_all(requests: &[ExecRequest]) -> CfgMgrResult<Vec<ExecResult>> {
+    let mut results = Vec::with_capacity(requests.len());
+    for (index, request) in requests.iter().enumerate() {
+        match request.run_or_throw().await {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                return Err(CfgMgrError::ShellBatchFailed {
+                    index,
+                    source: Box::new(e),
+                })
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// Runs shell commands on behalf of a manager.
+#[async_trait]
+pub trait Executor: Send + Sync {
+    /// Runs `cmd`. A non-zero exit is not an error; check
+    /// [`ExecResult::success`].
+    async fn exec(&self, cmd: &str) -> CfgMgrResult<ExecResult>;
+
+    /// Runs `cmd`, turning a non-zero exit into an error.
+    async fn exec_or_throw(&self, cmd: &str) -> CfgMgrResult<String> {
+        let result = self.exec(cmd).await?;
+        if result.success() {
+            Ok(result.stdout)
+        } else {
+            Err(CfgMgrError::ShellCommandFailed {
+                command: cmd.to_string(),
+                exit_code: result.exit_code,
+                output: result.combined_output(),
+            })
+        }
+    }
+}
+
+/// Runs commands through `/bin/sh -c`, honoring the dry-run default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShellExecutor;
+
+#[async_trait]
+impl Executor for ShellExecutor {
+    async fn exec(&self, cmd: &str) -> CfgMgrResult<ExecResult> {
+        exec(cmd).await
     }
 }
 
@@ -257,6 +471,7 @@ mod tests {
             exit_code: 0,
             stdout: "output".to_string(),
             stderr: "".to_string(),
+            duration: Duration::ZERO,
         };
         assert!(result.success());
         assert_eq!(result.combined_output(), "output");
@@ -268,6 +483,7 @@ mod tests {
             exit_code: 1,
             stdout: "".to_string(),
             stderr: "error message".to_string(),
+            duration: Duration::ZERO,
         };
         assert!(!result.success());
         assert_eq!(result.combined_output(), "error message");
@@ -279,6 +495,7 @@ mod tests {
             exit_code: 0,
             stdout: "stdout".to_string(),
             stderr: "stderr".to_string(),
+            duration: Duration::ZERO,
         };
         assert_eq!(result.combined_output(), "stdout\nstderr");
     }
@@ -314,4 +531,90 @@ mod tests {
             _ => panic!("Expected ShellCommandFailed error"),
         }
     }
+
+    #[tokio::test]
+    async fn test_shell_executor() {
+        let executor: &dyn Executor = &ShellExecutor;
+        assert_eq!(executor.exec("echo hi").await.unwrap().stdout, "hi");
+        assert!(matches!(
+            executor.exec_or_throw("exit 5").await,
+            Err(CfgMgrError::ShellCommandFailed { exit_code: 5, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exec_request_captures_output() {
+        let result = ExecRequest::new("echo out; echo err >&2; exit 3")
+            .dry_run(false)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(result.exit_code, 3);
+        assert_eq!(result.stdout, "out");
+        assert_eq!(result.stderr, "err");
+    }
+
+    #[tokio::test]
+    async fn test_exec_request_timeout_kills_command() {
+        let start = Instant::now();
+        let result = ExecRequest::new("sleep 10")
+            .dry_run(false)
+            .timeout(Duration::from_millis(100))
+            .run()
+            .await;
+        assert!(matches!(result, Err(CfgMgrError::ShellTimeout { .. })));
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let result = ExecRequest::new("sleep 0.05")
+            .dry_run(false)
+            .timeout(Duration::from_secs(5))
+            .run()
+            .await
+            .unwrap();
+        assert!(result.success());
+        assert!(result.duration >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_exec_request_dry_run_journal() {
+        let cmds = [
+            "/sbin/ip link set dev dryrun-test0 up",
+            "/sbin/ip link set dev dryrun-test0 mtu 9100",
+        ];
+        for cmd in cmds {
+            let result = ExecRequest::new(cmd).dry_run(true).run().await.unwrap();
+            assert!(result.success());
+            assert!(result.stdout.is_empty());
+        }
+
+        // Other tests may share the journal; check the relative order
+        let recorded: Vec<String> = dry_run_journal()
+            .into_iter()
+            .filter(|c| c.contains("dryrun-test0"))
+            .collect();
+        assert_eq!(recorded, cmds);
+    }
+
+    #[tokio::test]
+    async fn test_exec_all_stops_on_first_failure() {
+        let requests = vec![
+            ExecRequest::new("echo one").dry_run(false),
+            ExecRequest::new("exit 7").dry_run(false),
+            ExecRequest::new("echo never").dry_run(false),
+        ];
+        match exec_all(&requests).await {
+            Err(CfgMgrError::ShellBatchFailed { index, source }) => {
+                assert_eq!(index, 1);
+                assert!(matches!(
+                    *source,
+                    CfgMgrError::ShellCommandFailed { exit_code: 7, .. }
+                ));
+            }
+            other => panic!("Expected ShellBatchFailed, got {:?}", other),
+        }
+
+        let results = exec_all(&requests[..1]).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].stdout, "one");
+    }
 }
@@ -0,0 +1,229 @@
+//! Kernel parameter (sysctl) access.
+//!
+//! [`Sysctl`] writes `/proc/sys` entries by slash-separated path, e.g.
+//! `net/ipv4/conf/Ethernet0/proxy_arp`. The dotted `sysctl -w` form is not
+//! used because interface names such as `Ethernet0.100` contain dots.
+//! [`ProcSysctl`] writes the files directly; [`InMemorySysctl`] records
+//! writes for tests.
+
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use tracing::debug;
+
+use crate::error::{CfgMgrError, CfgMgrResult};
+
+/// Mount point of the sysctl tree.
+pub const PROC_SYS_ROOT: &str = "/proc/sys";
+
+/// Path of a per-interface IPv4 parameter, e.g. `net/ipv4/conf/Vlan100/proxy_arp`.
+pub fn ipv4_conf_path(intf: &str, param: &str) -> String {
+    format!("net/ipv4/conf/{}/{}", intf, param)
+}
+
+/// Path of a per-interface IPv6 parameter, e.g. `net/ipv6/conf/Ethernet0/disable_ipv6`.
+pub fn ipv6_conf_path(intf: &str, param: &str) -> String {
+    format!("net/ipv6/conf/{}/{}", intf, param)
+}
+
+/// Writes kernel parameters.
+#[async_trait]
+pub trait Sysctl: Send + Sync {
+    /// Sets the parameter at `path` (relative to `/proc/sys`) to `value`.
+    async fn write(&self, path: &str, value: &str) -> CfgMgrResult<()>;
+}
+
+/// Writes parameters under `/proc/sys`.
+#[derive(Debug, Clone)]
+pub struct ProcSysctl {
+    root: PathBuf,
+}
+
+impl ProcSysctl {
+    /// Creates a writer for [`PROC_SYS_ROOT`].
+    pub fn new() -> Self {
+        Self::with_root(PROC_SYS_ROOT)
+    }
+
+    /// Creates a writer for a different tree, e.g. a test directory.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Resolves `path` below the root, refusing anything that would leave it.
+    fn resolve(&self, path: &str) -> CfgMgrResult<PathBuf> {
+        let relative = Path::new(path);
+        let escapes = relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_)));
+        if path.is_empty() || escapes {
+            return Err(CfgMgrError::invalid_config(
+                "sysctl",
+                format!("Invalid path '{}'", path),
+            ));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+impl Default for ProcSysctl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Sysctl for ProcSysctl {
+    async fn write(&self, path: &str, value: &str) -> CfgMgrResult<()> {
+        let file = self.resolve(path)?;
+        tokio::fs::write(&file, value)
+            .await
+            .map_err(|source| CfgMgrError::Sysctl {
+                path: file.display().to_string(),
+                source,
+            })?;
+        debug!("Set {} = {}", file.display(), value);
+        Ok(())
+    }
+}
+
+/// In-memory [`Sysctl`] that journals every write, for tests.
+#[derive(Debug, Default)]
+pub struct InMemorySysctl {
+    values: Mutex<BTreeMap<String, String>>,
+    journal: Mutex<Vec<String>>,
+}
+
+impl InMemorySysctl {
+    /// Creates an empty parameter tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current value of a parameter, if it was ever written.
+    pub fn get(&self, path: &str) -> Option<String> {
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(path)
+            .cloned()
+    }
+
+    /// Returns the writes made so far, e.g. `net/ipv4/conf/Vlan100/proxy_arp=1`.
+    pub fn journal(&self) -> Vec<String> {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Forgets the writes made so far, keeping the values.
+    pub fn clear_journal(&self) {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Forgets an interface's parameters, as when the kernel recreates it.
+    pub fn remove_intf(&self, intf: &str) {
+        let marker = format!("/conf/{}/", intf);
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|path, _| !path.contains(&marker));
+    }
+}
+
+#[async_trait]
+impl Sysctl for InMemorySysctl {
+    async fn write(&self, path: &str, value: &str) -> CfgMgrResult<()> {
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(path.to_string(), value.to_string());
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(format!("{}={}", path, value));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conf_paths() {
+        assert_eq!(
+            ipv4_conf_path("Ethernet0.100", "proxy_arp"),
+            "net/ipv4/conf/Ethernet0.100/proxy_arp"
+        );
+        assert_eq!(
+            ipv6_conf_path("Vlan100", "disable_ipv6"),
+            "net/ipv6/conf/Vlan100/disable_ipv6"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proc_sysctl_writes_below_root() {
+        let root = std::env::temp_dir().join(format!("sysctl-test-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("net/ipv4/conf/Vlan100")).unwrap();
+
+        let sysctl = ProcSysctl::with_root(&root);
+        sysctl
+            .write(&ipv4_conf_path("Vlan100", "proxy_arp"), "1")
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.join("net/ipv4/conf/Vlan100/proxy_arp")).unwrap(),
+            "1"
+        );
+
+        // A missing interface directory is reported, not created
+        assert!(matches!(
+            sysctl
+                .write(&ipv4_conf_path("Vlan200", "proxy_arp"), "1")
+                .await,
+            Err(CfgMgrError::Sysctl { .. })
+        ));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_proc_sysctl_rejects_escaping_paths() {
+        let sysctl = ProcSysctl::with_root("/nonexistent");
+        for path in ["", "/etc/passwd", "net/ipv4/conf/../../../etc/passwd"] {
+            assert!(matches!(
+                sysctl.write(path, "1").await,
+                Err(CfgMgrError::InvalidConfig { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_sysctl() {
+        let sysctl = InMemorySysctl::new();
+        sysctl
+            .write(&ipv4_conf_path("Vlan100", "proxy_arp"), "1")
+            .await
+            .unwrap();
+        sysctl
+            .write(&ipv4_conf_path("Vlan200", "proxy_arp"), "1")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sysctl.get("net/ipv4/conf/Vlan100/proxy_arp").as_deref(),
+            Some("1")
+        );
+        assert_eq!(sysctl.journal().len(), 2);
+
+        sysctl.remove_intf("Vlan100");
+        assert_eq!(sysctl.get("net/ipv4/conf/Vlan100/proxy_arp"), None);
+        assert!(sysctl.get("net/ipv4/conf/Vlan200/proxy_arp").is_some());
+    }
+}
@@ -0,0 +1,129 @@
+//! systemd unit control.
+//!
+//! [`SystemdController`] is how managers start, restart and stop the
+//! daemons they own (e.g. sflowmgrd and hsflowd). [`ShellSystemdController`]
+//! runs `systemctl` through an [`Executor`]; tests substitute a recording
+//! implementation.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::error::{CfgMgrError, CfgMgrResult};
+use crate::shell::{shellquote, Executor, ShellExecutor};
+
+/// systemd unit lifecycle operations
+#[async_trait]
+pub trait SystemdController: Send + Sync {
+    /// Restarts (or starts) a unit
+    async fn restart(&self, unit: &str) -> CfgMgrResult<()>;
+
+    /// Stops a unit
+    async fn stop(&self, unit: &str) -> CfgMgrResult<()>;
+}
+
+/// Controls units with `systemctl`
+#[derive(Clone)]
+pub struct ShellSystemdController {
+    executor: Arc<dyn Executor>,
+}
+
+impl ShellSystemdController {
+    /// Runs `systemctl` with [`ShellExecutor`]
+    pub fn new() -> Self {
+        Self::with_executor(Arc::new(ShellExecutor))
+    }
+
+    /// Runs `systemctl` with the given executor
+    pub fn with_executor(executor: Arc<dyn Executor>) -> Self {
+        Self { executor }
+    }
+
+    /// `systemctl <action> <unit>`
+    pub fn systemctl_cmd(action: &str, unit: &str) -> String {
+        format!("systemctl {} {}", action, shellquote(unit))
+    }
+
+    async fn systemctl(&self, action: &str, unit: &str) -> CfgMgrResult<()> {
+        let cmd = Self::systemctl_cmd(action, unit);
+        let result = self.executor.exec(&cmd).await?;
+
+        if result.success() {
+            info!("Service command succeeded: {}", cmd);
+            Ok(())
+        } else {
+            warn!(
+                "Service command failed: {} (exit code: {})",
+                cmd, result.exit_code
+            );
+            Err(CfgMgrError::ShellCommandFailed {
+                command: cmd,
+                exit_code: result.exit_code,
+                output: result.stderr,
+            })
+        }
+    }
+}
+
+impl Default for ShellSystemdController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SystemdController for ShellSystemdController {
+    async fn restart(&self, unit: &str) -> CfgMgrResult<()> {
+        self.systemctl("restart", unit).await
+    }
+
+    async fn stop(&self, unit: &str) -> CfgMgrResult<()> {
+        self.systemctl("stop", unit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell::ExecResult;
+    use std::sync::Mutex;
+
+    /// Records commands and fails those containing "broken"
+    #[derive(Default)]
+    struct RecordingExecutor {
+        commands: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Executor for RecordingExecutor {
+        async fn exec(&self, cmd: &str) -> CfgMgrResult<ExecResult> {
+            self.commands.lock().unwrap().push(cmd.to_string());
+            Ok(ExecResult {
+                exit_code: if cmd.contains("broken") { 1 } else { 0 },
+                ..Default::default()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_systemctl_goes_through_executor() {
+        let executor = Arc::new(RecordingExecutor::default());
+        let systemd = ShellSystemdController::with_executor(executor.clone());
+
+        systemd.restart("hsflowd").await.unwrap();
+        systemd.stop("hsflowd").await.unwrap();
+        assert!(matches!(
+            systemd.restart("broken").await,
+            Err(CfgMgrError::ShellCommandFailed { exit_code: 1, .. })
+        ));
+
+        assert_eq!(
+            *executor.commands.lock().unwrap(),
+            [
+                "systemctl restart \"hsflowd\"",
+                "systemctl stop \"hsflowd\"",
+                "systemctl restart \"broken\"",
+            ]
+        );
+    }
+}
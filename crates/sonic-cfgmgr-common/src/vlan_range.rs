@@ -0,0 +1,288 @@
+//! VLAN ID range lists.
+//!
+//! Config sources give trunk memberships as range strings such as
+//! `100-200,300,400-410`. [`VlanRangeList`] parses them into a set of VLAN
+//! IDs and serializes the set back into the most compact range string.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Lowest usable VLAN ID.
+pub const VLAN_ID_MIN: u16 = 1;
+
+/// Highest usable VLAN ID.
+pub const VLAN_ID_MAX: u16 = 4094;
+
+/// Number of 64-bit words covering VLAN IDs 0..=4095.
+const WORDS: usize = 4096 / 64;
+
+/// Errors from building a [`VlanRangeList`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum VlanRangeError {
+    /// A VLAN ID is not a number in `1..=4094`.
+    #[error("Invalid VLAN ID: {0}")]
+    InvalidVlanId(String),
+
+    /// A range is malformed or its bounds are reversed.
+    #[error("Invalid VLAN range: {0}")]
+    InvalidRange(String),
+}
+
+/// A set of VLAN IDs, stored as a 4096-bit map.
+#[derive(Clone, PartialEq, Eq)]
+pub struct VlanRangeList {
+    bits: [u64; WORDS],
+}
+
+impl VlanRangeList {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self { bits: [0; WORDS] }
+    }
+
+    /// Returns true if `vlan_id` is in `1..=4094`.
+    pub fn is_valid_id(vlan_id: u16) -> bool {
+        (VLAN_ID_MIN..=VLAN_ID_MAX).contains(&vlan_id)
+    }
+
+    /// Adds a VLAN ID, returning true if it was not already present.
+    pub fn insert(&mut self, vlan_id: u16) -> Result<bool, VlanRangeError> {
+        if !Self::is_valid_id(vlan_id) {
+            return Err(VlanRangeError::InvalidVlanId(vlan_id.to_string()));
+        }
+        let (word, mask) = Self::slot(vlan_id);
+        let added = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        Ok(added)
+    }
+
+    /// Removes a VLAN ID, returning true if it was present.
+    pub fn remove(&mut self, vlan_id: u16) -> bool {
+        let present = self.contains(vlan_id);
+        if present {
+            let (word, mask) = Self::slot(vlan_id);
+            self.bits[word] &= !mask;
+        }
+        present
+    }
+
+    /// Returns true if the list holds `vlan_id`.
+    pub fn contains(&self, vlan_id: u16) -> bool {
+        if !Self::is_valid_id(vlan_id) {
+            return false;
+        }
+        let (word, mask) = Self::slot(vlan_id);
+        self.bits[word] & mask != 0
+    }
+
+    /// Returns the number of VLAN IDs in the list.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns true if the list holds no VLAN IDs.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|w| *w == 0)
+    }
+
+    /// Iterates over the VLAN IDs in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        (VLAN_ID_MIN..=VLAN_ID_MAX).filter(|id| self.contains(*id))
+    }
+
+    /// Returns the VLAN IDs in either list.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut bits = self.bits;
+        for (word, other) in bits.iter_mut().zip(other.bits.iter()) {
+            *word |= other;
+        }
+        Self { bits }
+    }
+
+    /// Returns the VLAN IDs in this list but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut bits = self.bits;
+        for (word, other) in bits.iter_mut().zip(other.bits.iter()) {
+            *word &= !other;
+        }
+        Self { bits }
+    }
+
+    /// Returns the list as maximal `(first, last)` runs in ascending order.
+    pub fn ranges(&self) -> Vec<(u16, u16)> {
+        let mut ranges: Vec<(u16, u16)> = Vec::new();
+        for id in self.iter() {
+            match ranges.last_mut() {
+                Some((_, last)) if *last + 1 == id => *last = id,
+                _ => ranges.push((id, id)),
+            }
+        }
+        ranges
+    }
+
+    fn slot(vlan_id: u16) -> (usize, u64) {
+        (usize::from(vlan_id / 64), 1 << (vlan_id % 64))
+    }
+
+    fn parse_id(s: &str) -> Result<u16, VlanRangeError> {
+        s.parse::<u16>()
+            .ok()
+            .filter(|id| Self::is_valid_id(*id))
+            .ok_or_else(|| VlanRangeError::InvalidVlanId(s.to_string()))
+    }
+}
+
+impl Default for VlanRangeList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for VlanRangeList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VlanRangeList({})", self)
+    }
+}
+
+/// Parses a comma-separated list of IDs and `first-last` ranges.
+///
+/// Overlapping and repeated entries are merged; an empty string is an
+/// empty list.
+impl FromStr for VlanRangeList {
+    type Err = VlanRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut list = Self::new();
+        for part in s.split(',').map(str::trim) {
+            if part.is_empty() {
+                if s.trim().is_empty() {
+                    continue;
+                }
+                return Err(VlanRangeError::InvalidRange(s.to_string()));
+            }
+            let (first, last) = match part.split_once('-') {
+                Some((first, last)) => {
+                    (Self::parse_id(first.trim())?, Self::parse_id(last.trim())?)
+                }
+                None => {
+                    let id = Self::parse_id(part)?;
+                    (id, id)
+                }
+            };
+            if first > last {
+                return Err(VlanRangeError::InvalidRange(part.to_string()));
+            }
+            for id in first..=last {
+                list.insert(id)?;
+            }
+        }
+        Ok(list)
+    }
+}
+
+/// Formats as the most compact range string, e.g. `100-200,300`.
+impl fmt::Display for VlanRangeList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (first, last)) in self.ranges().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if first == last {
+                write!(f, "{}", first)?;
+            } else {
+                write!(f, "{}-{}", first, last)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(s: &str) -> VlanRangeList {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format_compact() {
+        let vlans = list("100-200,300,400-410");
+        assert_eq!(vlans.len(), 101 + 1 + 11);
+        assert!(vlans.contains(100) && vlans.contains(200) && vlans.contains(405));
+        assert!(!vlans.contains(201) && !vlans.contains(299));
+        assert_eq!(vlans.to_string(), "100-200,300,400-410");
+
+        assert!(list("").is_empty());
+        assert_eq!(list(" 10 , 12-13 ").to_string(), "10,12-13");
+        assert_eq!(vlans.ranges(), vec![(100, 200), (300, 300), (400, 410)]);
+    }
+
+    #[test]
+    fn test_overlapping_ranges_merge() {
+        assert_eq!(list("100-150,120-200,201,50").to_string(), "50,100-201");
+        assert_eq!(list("5,5,5-5").to_string(), "5");
+        assert_eq!(list("10-20,21-30").to_string(), "10-30");
+    }
+
+    #[test]
+    fn test_reversed_bounds_rejected() {
+        assert_eq!(
+            "200-100".parse::<VlanRangeList>(),
+            Err(VlanRangeError::InvalidRange("200-100".to_string()))
+        );
+        assert_eq!(list("100-100").to_string(), "100");
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let all = list("1-4094");
+        assert_eq!(all.len(), 4094);
+        assert_eq!(all.iter().next(), Some(1));
+        assert_eq!(all.iter().last(), Some(4094));
+        assert_eq!(all.to_string(), "1-4094");
+        assert_eq!(list("4094,1").to_string(), "1,4094");
+
+        for bad in [
+            "0",
+            "4095",
+            "0-10",
+            "4000-4095",
+            "65536",
+            "-1",
+            "abc",
+            "1-",
+            "1,,2",
+        ] {
+            assert!(bad.parse::<VlanRangeList>().is_err(), "{}", bad);
+        }
+        assert_eq!(
+            "4095".parse::<VlanRangeList>(),
+            Err(VlanRangeError::InvalidVlanId("4095".to_string()))
+        );
+
+        let mut vlans = VlanRangeList::new();
+        assert!(vlans.insert(0).is_err());
+        assert!(vlans.insert(4095).is_err());
+        assert_eq!(vlans.insert(4094), Ok(true));
+        assert_eq!(vlans.insert(4094), Ok(false));
+        assert!(!vlans.contains(4095));
+    }
+
+    #[test]
+    fn test_union_difference_and_remove() {
+        let a = list("100-200");
+        let b = list("150-250,300");
+
+        assert_eq!(a.union(&b).to_string(), "100-250,300");
+        assert_eq!(a.difference(&b).to_string(), "100-149");
+        assert_eq!(b.difference(&a).to_string(), "201-250,300");
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = a.clone();
+        assert!(c.remove(150));
+        assert!(!c.remove(150));
+        assert_eq!(c.to_string(), "100-149,151-200");
+    }
+}
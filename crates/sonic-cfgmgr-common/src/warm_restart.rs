@@ -0,0 +1,469 @@
+//! Warm restart state machine shared by the cfgmgr daemons.
+//!
+//! [`WarmRestartHelper`] is the Rust counterpart of the C++ `WarmStart`
+//! class plus the reconcile logic each daemon used to carry itself:
+//!
+//! 1. [`initialize`](WarmRestartHelper::initialize) reads
+//!    `WARM_RESTART_ENABLE_TABLE` and records `initialized`.
+//! 2. [`restore`](WarmRestartHelper::restore) caches the keys the previous
+//!    run left in APPL_DB and records `restored`.
+//! 3. While CONFIG_DB is replayed the daemon reports every APPL_DB key it
+//!    writes with [`record`](WarmRestartHelper::record), then records
+//!    `replayed`.
+//! 4. [`reconcile`](WarmRestartHelper::reconcile) deletes the cached keys
+//!    that were not produced again and only then records `reconciled`.
+//!
+//! Every transition is written to `WARM_RESTART_TABLE|<app>` in STATE_DB
+//! with the time it happened. Database access goes through
+//! [`WarmRestartStore`] so the flow can be tested with [`InMemoryStore`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::error::CfgMgrResult;
+use crate::manager::{DbId, FieldValues, FieldValuesExt, WarmRestartState};
+
+/// STATE_DB table holding the warm restart enable flags.
+pub const STATE_WARM_RESTART_ENABLE_TABLE: &str = "WARM_RESTART_ENABLE_TABLE";
+
+/// STATE_DB table holding the warm restart state of each application.
+pub const STATE_WARM_RESTART_TABLE: &str = "WARM_RESTART_TABLE";
+
+/// Key of the system-wide entry in `WARM_RESTART_ENABLE_TABLE`.
+pub const WARM_RESTART_SYSTEM_KEY: &str = "system";
+
+/// Field names used in the warm restart tables.
+pub mod fields {
+    /// Enable flag (`true`/`false`) in `WARM_RESTART_ENABLE_TABLE`.
+    pub const ENABLE: &str = "enable";
+
+    /// Current state in `WARM_RESTART_TABLE`.
+    pub const STATE: &str = "state";
+
+    /// Suffix of the per-state timestamp fields, e.g. `reconciled_timestamp`.
+    pub const TIMESTAMP_SUFFIX: &str = "_timestamp";
+}
+
+/// Database operations needed by [`WarmRestartHelper`].
+#[async_trait]
+pub trait WarmRestartStore: Send + Sync {
+    /// Reads all fields of `table|key`; empty if the key does not exist.
+    async fn get_all(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<FieldValues>;
+
+    /// Sets fields of `table|key`, creating it if needed.
+    async fn set(&self, db: DbId, table: &str, key: &str, fvs: &FieldValues) -> CfgMgrResult<()>;
+
+    /// Returns every key of `table`.
+    async fn keys(&self, db: DbId, table: &str) -> CfgMgrResult<Vec<String>>;
+
+    /// Deletes `table|key`.
+    async fn del(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<()>;
+}
+
+/// Tracks warm restart progress for one application and persists it.
+pub struct WarmRestartHelper {
+    app: String,
+    store: Arc<dyn WarmRestartStore>,
+    enabled: bool,
+    state: WarmRestartState,
+    /// APPL_DB keys left by the previous run, per table.
+    cached: BTreeMap<String, BTreeSet<String>>,
+    /// APPL_DB keys written since the restore, per table.
+    produced: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl WarmRestartHelper {
+    /// Creates a helper for `app` (the daemon name, e.g. `vlanmgrd`).
+    pub fn new(app: impl Into<String>, store: Arc<dyn WarmRestartStore>) -> Self {
+        Self {
+            app: app.into(),
+            store,
+            enabled: false,
+            state: WarmRestartState::Disabled,
+            cached: BTreeMap::new(),
+            produced: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the application name.
+    pub fn app(&self) -> &str {
+        &self.app
+    }
+
+    /// Returns true if warm restart is enabled for `app` or system-wide.
+    pub async fn is_enabled(&self, app: &str) -> CfgMgrResult<bool> {
+        for key in [WARM_RESTART_SYSTEM_KEY, app] {
+            let fvs = self
+                .store
+                .get_all(DbId::StateDb, STATE_WARM_RESTART_ENABLE_TABLE, key)
+                .await?;
+            if fvs.get_field(fields::ENABLE) == Some("true") {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Checks whether this start is a warm restart and records `initialized`
+    /// if it is. `container` is the enable-table key of the daemon's
+    /// container (e.g. `swss`).
+    pub async fn initialize(&mut self, container: &str) -> CfgMgrResult<bool> {
+        self.enabled = self.is_enabled(container).await?;
+        if self.enabled {
+            self.set_state(WarmRestartState::Initialized).await?;
+        }
+        Ok(self.enabled)
+    }
+
+    /// Returns true if [`initialize`](Self::initialize) found warm restart
+    /// enabled.
+    pub fn is_warm_start(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the last recorded state.
+    pub fn state(&self) -> WarmRestartState {
+        self.state
+    }
+
+    /// Records a state transition in STATE_DB.
+    pub async fn set_state(&mut self, state: WarmRestartState) -> CfgMgrResult<()> {
+        let fvs = vec![
+            (fields::STATE.to_string(), state.as_str().to_string()),
+            (
+                format!("{}{}", state.as_str(), fields::TIMESTAMP_SUFFIX),
+                unix_timestamp(),
+            ),
+        ];
+        self.store
+            .set(DbId::StateDb, STATE_WARM_RESTART_TABLE, &self.app, &fvs)
+            .await?;
+
+        info!("{} warm restart state: {}", self.app, state.as_str());
+        self.state = state;
+        Ok(())
+    }
+
+    /// Caches the current keys of the APPL_DB tables the daemon writes and
+    /// records `restored`.
+    pub async fn restore(&mut self, appl_tables: &[&str]) -> CfgMgrResult<()> {
+        for table in appl_tables {
+            let keys = self.store.keys(DbId::ApplDb, table).await?;
+            self.cached
+                .entry(table.to_string())
+                .or_default()
+                .extend(keys);
+        }
+        self.produced.clear();
+        self.set_state(WarmRestartState::Restored).await
+    }
+
+    /// Notes that the daemon wrote `table:key` to APPL_DB during the replay.
+    pub fn record(&mut self, table: &str, key: &str) {
+        if matches!(
+            self.state,
+            WarmRestartState::Restored | WarmRestartState::Replayed
+        ) {
+            self.produced
+                .entry(table.to_string())
+                .or_default()
+                .insert(key.to_string());
+        }
+    }
+
+    /// Returns the cached keys that were not written again, as
+    /// `(table, key)` pairs.
+    pub fn stale_keys(&self) -> Vec<(String, String)> {
+        let empty = BTreeSet::new();
+        self.cached
+            .iter()
+            .flat_map(|(table, keys)| {
+                let produced = self.produced.get(table).unwrap_or(&empty);
+                keys.difference(produced)
+                    .map(move |key| (table.clone(), key.clone()))
+            })
+            .collect()
+    }
+
+    /// Deletes the stale APPL_DB keys, then records `reconciled`.
+    ///
+    /// Returns the number of keys deleted. If a deletion fails the state is
+    /// left unchanged so the reconcile can be retried.
+    pub async fn reconcile(&mut self) -> CfgMgrResult<usize> {
+        let stale = self.stale_keys();
+        for (table, key) in &stale {
+            self.store.del(DbId::ApplDb, table, key).await?;
+            info!("{} removed stale {}:{}", self.app, table, key);
+        }
+
+        self.cached.clear();
+        self.produced.clear();
+        self.set_state(WarmRestartState::Reconciled).await?;
+        Ok(stale.len())
+    }
+}
+
+fn unix_timestamp() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// In-memory [`WarmRestartStore`] that journals every write, for tests.
+#[derive(Debug, Default)]
+pub struct InMemoryStore {
+    tables: Mutex<HashMap<(DbId, String), BTreeMap<String, BTreeMap<String, String>>>>,
+    journal: Mutex<Vec<String>>,
+}
+
+impl InMemoryStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the writes made so far, e.g. `DEL APPL_DB:VLAN_TABLE:Vlan100`.
+    pub fn journal(&self) -> Vec<String> {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn log(&self, entry: String) {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(entry);
+    }
+}
+
+#[async_trait]
+impl WarmRestartStore for InMemoryStore {
+    async fn get_all(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<FieldValues> {
+        let tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(tables
+            .get(&(db, table.to_string()))
+            .and_then(|rows| rows.get(key))
+            .map(|hash| hash.iter().map(|(f, v)| (f.clone(), v.clone())).collect())
+            .unwrap_or_default())
+    }
+
+    async fn set(&self, db: DbId, table: &str, key: &str, fvs: &FieldValues) -> CfgMgrResult<()> {
+        {
+            let mut tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+            let hash = tables
+                .entry((db, table.to_string()))
+                .or_default()
+                .entry(key.to_string())
+                .or_default();
+            for (field, value) in fvs {
+                hash.insert(field.clone(), value.clone());
+            }
+        }
+        self.log(format!("SET {}:{}:{}", db.name(), table, key));
+        Ok(())
+    }
+
+    async fn keys(&self, db: DbId, table: &str) -> CfgMgrResult<Vec<String>> {
+        let tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(tables
+            .get(&(db, table.to_string()))
+            .map(|rows| rows.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn del(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<()> {
+        {
+            let mut tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(rows) = tables.get_mut(&(db, table.to_string())) {
+                rows.remove(key);
+            }
+        }
+        self.log(format!("DEL {}:{}:{}", db.name(), table, key));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field_values;
+
+    async fn warm_store() -> Arc<InMemoryStore> {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .set(
+                DbId::StateDb,
+                STATE_WARM_RESTART_ENABLE_TABLE,
+                "swss",
+                &field_values! { fields::ENABLE => "true" },
+            )
+            .await
+            .unwrap();
+        for key in ["Vlan100", "Vlan200"] {
+            store
+                .set(
+                    DbId::ApplDb,
+                    "VLAN_TABLE",
+                    key,
+                    &field_values! { "admin_status" => "up" },
+                )
+                .await
+                .unwrap();
+        }
+        store
+    }
+
+    #[tokio::test]
+    async fn test_is_enabled() {
+        let store = warm_store().await;
+        let helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+
+        assert!(helper.is_enabled("swss").await.unwrap());
+        assert!(!helper.is_enabled("bgp").await.unwrap());
+
+        store
+            .set(
+                DbId::StateDb,
+                STATE_WARM_RESTART_ENABLE_TABLE,
+                WARM_RESTART_SYSTEM_KEY,
+                &field_values! { fields::ENABLE => "true" },
+            )
+            .await
+            .unwrap();
+        assert!(helper.is_enabled("bgp").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cold_start_records_nothing() {
+        let store = Arc::new(InMemoryStore::new());
+        let mut helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+
+        assert!(!helper.initialize("swss").await.unwrap());
+        assert!(!helper.is_warm_start());
+        assert_eq!(helper.state(), WarmRestartState::Disabled);
+        assert!(store.journal().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_transitions_persisted_with_timestamps() {
+        let store = warm_store().await;
+        let mut helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+
+        assert!(helper.initialize("swss").await.unwrap());
+        helper.restore(&["VLAN_TABLE"]).await.unwrap();
+        helper.set_state(WarmRestartState::Replayed).await.unwrap();
+
+        let fvs = store
+            .get_all(DbId::StateDb, STATE_WARM_RESTART_TABLE, "vlanmgrd")
+            .await
+            .unwrap();
+        assert_eq!(fvs.get_field(fields::STATE), Some("replayed"));
+        for state in ["initialized", "restored", "replayed"] {
+            let field = format!("{}{}", state, fields::TIMESTAMP_SUFFIX);
+            assert!(fvs.get_field(&field).unwrap().parse::<u64>().unwrap() > 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deletes_only_stale_keys() {
+        let store = warm_store().await;
+        let mut helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+        helper.initialize("swss").await.unwrap();
+
+        // Recorded before the restore: not part of the replay
+        helper.record("VLAN_TABLE", "Vlan200");
+
+        helper.restore(&["VLAN_TABLE"]).await.unwrap();
+        helper.record("VLAN_TABLE", "Vlan100");
+        helper.record("VLAN_TABLE", "Vlan300");
+        helper.set_state(WarmRestartState::Replayed).await.unwrap();
+
+        assert_eq!(
+            helper.stale_keys(),
+            vec![("VLAN_TABLE".to_string(), "Vlan200".to_string())]
+        );
+        assert_eq!(helper.reconcile().await.unwrap(), 1);
+
+        let keys = store.keys(DbId::ApplDb, "VLAN_TABLE").await.unwrap();
+        assert_eq!(keys, vec!["Vlan100"]);
+
+        // The only deletion precedes the reconciled write
+        let journal = store.journal();
+        let deletes: Vec<&String> = journal.iter().filter(|e| e.starts_with("DEL")).collect();
+        assert_eq!(deletes, vec!["DEL APPL_DB:VLAN_TABLE:Vlan200"]);
+        let del_pos = journal.iter().position(|e| e.starts_with("DEL")).unwrap();
+        assert_eq!(
+            journal.last().unwrap(),
+            "SET STATE_DB:WARM_RESTART_TABLE:vlanmgrd"
+        );
+        assert!(del_pos < journal.len() - 1);
+        assert_eq!(helper.state(), WarmRestartState::Reconciled);
+    }
+
+    /// Store whose deletions always fail.
+    struct FailingDelStore(InMemoryStore);
+
+    #[async_trait]
+    impl WarmRestartStore for FailingDelStore {
+        async fn get_all(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<FieldValues> {
+            self.0.get_all(db, table, key).await
+        }
+
+        async fn set(
+            &self,
+            db: DbId,
+            table: &str,
+            key: &str,
+            fvs: &FieldValues,
+        ) -> CfgMgrResult<()> {
+            self.0.set(db, table, key, fvs).await
+        }
+
+        async fn keys(&self, db: DbId, table: &str) -> CfgMgrResult<Vec<String>> {
+            self.0.keys(db, table).await
+        }
+
+        async fn del(&self, _db: DbId, _table: &str, _key: &str) -> CfgMgrResult<()> {
+            Err(crate::error::CfgMgrError::database(
+                "DEL",
+                "connection reset",
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconciled_not_written_when_diff_fails() {
+        let inner = InMemoryStore::new();
+        inner
+            .set(
+                DbId::ApplDb,
+                "VLAN_TABLE",
+                "Vlan100",
+                &field_values! { "admin_status" => "up" },
+            )
+            .await
+            .unwrap();
+        let store = Arc::new(FailingDelStore(inner));
+        let mut helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+
+        helper.restore(&["VLAN_TABLE"]).await.unwrap();
+        helper.set_state(WarmRestartState::Replayed).await.unwrap();
+        assert!(helper.reconcile().await.is_err());
+
+        assert_eq!(helper.state(), WarmRestartState::Replayed);
+        let fvs = store
+            .get_all(DbId::StateDb, STATE_WARM_RESTART_TABLE, "vlanmgrd")
+            .await
+            .unwrap();
+        assert_eq!(fvs.get_field(fields::STATE), Some("replayed"));
+        assert_eq!(helper.stale_keys().len(), 1);
+    }
+}
@@ -0,0 +1,515 @@
+//! Fake shell and systemd for testing configuration managers
+//!
+//! [`RedisTestEnv`](crate::RedisTestEnv) covers the databases, but managers
+//! also run `ip`/`bridge` commands and restart systemd units. A
+//! [`CommandHarness`] stands in for both: it implements
+//! [`shell::Executor`](sonic_cfgmgr_common::shell::Executor),
+//! [`CfgBackend`] and [`SystemdController`], journals every invocation in
+//! memory instead of touching the host, and answers with canned responses.
+//!
+//! Backend operations are matched and journaled as the command
+//! [`ShellBackend`] would run for them.
+//!
+//! Unit actions are matched and journaled as the `systemctl <action> <unit>`
+//! command they stand for, so one set of patterns covers both interfaces.
+//!
+//! ```ignore
+//! let harness = Arc::new(CommandHarness::new());
+//! harness.stub(r"^/sbin/bridge vlan show", CannedResponse::ok("Bridge 100"));
+//! harness.stub_once(r"systemctl restart hsflowd", CannedResponse::exit(1, "busy"));
+//!
+//! let mut mgr = SflowMgr::new().with_systemd(harness.clone());
+//! // ... drive the manager ...
+//! harness.assert_unit_restarted_times("hsflowd", 2);
+//! ```
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use sonic_cfgmgr_common::nl::BridgeVlan;
+use sonic_cfgmgr_common::shell::{ExecResult, Executor};
+use sonic_cfgmgr_common::{CfgBackend, CfgMgrError, CfgMgrResult, ShellBackend, SystemdController};
+
+/// systemd unit action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitAction {
+    /// `systemctl restart`
+    Restart,
+    /// `systemctl stop`
+    Stop,
+}
+
+impl UnitAction {
+    /// The `systemctl` verb
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitAction::Restart => "restart",
+            UnitAction::Stop => "stop",
+        }
+    }
+}
+
+/// One journaled invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Invocation {
+    /// A shell command passed to the executor
+    Command(String),
+    /// A unit action passed to the systemd controller
+    Unit { action: UnitAction, unit: String },
+}
+
+impl fmt::Display for Invocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Invocation::Command(cmd) => write!(f, "{}", cmd),
+            Invocation::Unit { action, unit } => {
+                write!(f, "systemctl {} {}", action.as_str(), unit)
+            }
+        }
+    }
+}
+
+/// Programmed answer to a matching invocation
+#[derive(Debug, Clone)]
+pub enum CannedResponse {
+    /// The command ran with this exit code and output
+    Output {
+        exit_code: i32,
+        stdout: String,
+        stderr: String,
+    },
+    /// The command could not be spawned
+    SpawnError(String),
+}
+
+impl CannedResponse {
+    /// Successful exit with `stdout`
+    pub fn ok(stdout: impl Into<String>) -> Self {
+        CannedResponse::Output {
+            exit_code: 0,
+            stdout: stdout.into(),
+            stderr: String::new(),
+        }
+    }
+
+    /// Non-zero (or zero) exit with `stderr`
+    pub fn exit(exit_code: i32, stderr: impl Into<String>) -> Self {
+        CannedResponse::Output {
+            exit_code,
+            stdout: String::new(),
+            stderr: stderr.into(),
+        }
+    }
+
+    /// Spawn failure, surfaced as [`CfgMgrError::ShellExec`]
+    pub fn spawn_error(message: impl Into<String>) -> Self {
+        CannedResponse::SpawnError(message.into())
+    }
+
+    fn to_result(&self, command: &str) -> CfgMgrResult<ExecResult> {
+        match self {
+            CannedResponse::Output {
+                exit_code,
+                stdout,
+                stderr,
+            } => Ok(ExecResult {
+                exit_code: *exit_code,
+                stdout: stdout.clone(),
+                stderr: stderr.clone(),
+                duration: Duration::ZERO,
+            }),
+            CannedResponse::SpawnError(message) => Err(CfgMgrError::ShellExec {
+                command: command.to_string(),
+                source: std::io::Error::other(message.clone()),
+            }),
+        }
+    }
+}
+
+/// A canned response and how often it may still be used
+struct Stub {
+    pattern: Regex,
+    response: CannedResponse,
+    /// `None` for unlimited
+    remaining: Option<usize>,
+}
+
+#[derive(Default)]
+struct HarnessState {
+    journal: Vec<Invocation>,
+    stubs: Vec<Stub>,
+}
+
+/// In-memory fake of the shell and systemd
+///
+/// Invocations without a matching stub succeed with empty output. When
+/// several stubs match, the most recently added one wins, so a test can
+/// override a broad stub with a narrower one.
+#[derive(Default)]
+pub struct CommandHarness {
+    state: Mutex<HarnessState>,
+}
+
+impl CommandHarness {
+    /// Creates a harness with an empty journal and no stubs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, HarnessState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Answers invocations matching the regex `pattern` with `response`
+    ///
+    /// # Panics
+    /// If `pattern` is not a valid regex
+    pub fn stub(&self, pattern: &str, response: CannedResponse) {
+        self.add_stub(pattern, response, None);
+    }
+
+    /// Like [`stub`](Self::stub), but only for the next matching invocation
+    pub fn stub_once(&self, pattern: &str, response: CannedResponse) {
+        self.add_stub(pattern, response, Some(1));
+    }
+
+    fn add_stub(&self, pattern: &str, response: CannedResponse, remaining: Option<usize>) {
+        let pattern = Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("Invalid stub pattern '{}': {}", pattern, e));
+        self.state().stubs.push(Stub {
+            pattern,
+            response,
+            remaining,
+        });
+    }
+
+    /// Journals an invocation and returns its canned result
+    fn invoke(&self, invocation: Invocation) -> CfgMgrResult<ExecResult> {
+        let command = invocation.to_string();
+        let mut state = self.state();
+        state.journal.push(invocation);
+
+        let Some(stub) = state
+            .stubs
+            .iter_mut()
+            .rev()
+            .find(|stub| stub.remaining != Some(0) && stub.pattern.is_match(&command))
+        else {
+            return Ok(ExecResult::default());
+        };
+
+        if let Some(remaining) = stub.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        stub.response.to_result(&command)
+    }
+
+    /// Runs a unit action; a non-zero canned exit fails like `systemctl`
+    fn unit_action(&self, action: UnitAction, unit: &str) -> CfgMgrResult<()> {
+        let invocation = Invocation::Unit {
+            action,
+            unit: unit.to_string(),
+        };
+        let command = invocation.to_string();
+        let result = self.invoke(invocation)?;
+        if result.success() {
+            Ok(())
+        } else {
+            Err(CfgMgrError::ShellCommandFailed {
+                command,
+                exit_code: result.exit_code,
+                output: result.stderr,
+            })
+        }
+    }
+
+    /// Returns every invocation so far, oldest first
+    pub fn journal(&self) -> Vec<Invocation> {
+        self.state().journal.clone()
+    }
+
+    /// Returns every invocation rendered as a command line
+    pub fn commands(&self) -> Vec<String> {
+        self.state().journal.iter().map(|i| i.to_string()).collect()
+    }
+
+    /// Clears the journal, keeping the stubs
+    pub fn clear_journal(&self) {
+        self.state().journal.clear();
+    }
+
+    /// Number of invocations matching the regex `pattern`
+    pub fn count_matching(&self, pattern: &str) -> usize {
+        let pattern =
+            Regex::new(pattern).unwrap_or_else(|e| panic!("Invalid pattern '{}': {}", pattern, e));
+        self.commands()
+            .iter()
+            .filter(|cmd| pattern.is_match(cmd))
+            .count()
+    }
+
+    /// Actions applied to `unit`, oldest first
+    pub fn unit_actions(&self, unit: &str) -> Vec<UnitAction> {
+        self.state()
+            .journal
+            .iter()
+            .filter_map(|invocation| match invocation {
+                Invocation::Unit { action, unit: u } if u == unit => Some(*action),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Asserts that at least one invocation matches the regex `pattern`
+    #[track_caller]
+    pub fn assert_ran_matching(&self, pattern: &str) {
+        assert!(
+            self.count_matching(pattern) > 0,
+            "No invocation matches '{}'. Journal:\n{}",
+            pattern,
+            self.journal_dump()
+        );
+    }
+
+    /// Asserts that no invocation matches the regex `pattern`
+    #[track_caller]
+    pub fn assert_not_ran_matching(&self, pattern: &str) {
+        assert!(
+            self.count_matching(pattern) == 0,
+            "Unexpected invocation matching '{}'. Journal:\n{}",
+            pattern,
+            self.journal_dump()
+        );
+    }
+
+    /// Asserts that `unit` was restarted exactly `times` times
+    #[track_caller]
+    pub fn assert_unit_restarted_times(&self, unit: &str, times: usize) {
+        self.assert_unit_action_times(unit, UnitAction::Restart, times);
+    }
+
+    /// Asserts that `unit` was stopped exactly `times` times
+    #[track_caller]
+    pub fn assert_unit_stopped_times(&self, unit: &str, times: usize) {
+        self.assert_unit_action_times(unit, UnitAction::Stop, times);
+    }
+
+    #[track_caller]
+    fn assert_unit_action_times(&self, unit: &str, action: UnitAction, times: usize) {
+        let actual = self
+            .unit_actions(unit)
+            .into_iter()
+            .filter(|a| *a == action)
+            .count();
+        assert_eq!(
+            actual,
+            times,
+            "Expected {} to be {}ed {} times, got {}. Journal:\n{}",
+            unit,
+            action.as_str(),
+            times,
+            actual,
+            self.journal_dump()
+        );
+    }
+
+    fn journal_dump(&self) -> String {
+        self.commands()
+            .iter()
+            .map(|cmd| format!("  {}", cmd))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[async_trait]
+impl Executor for CommandHarness {
+    async fn exec(&self, cmd: &str) -> CfgMgrResult<ExecResult> {
+        self.invoke(Invocation::Command(cmd.to_string()))
+    }
+}
+
+#[async_trait]
+impl CfgBackend for CommandHarness {
+    fn name(&self) -> &'static str {
+        "harness"
+    }
+
+    async fn set_link_mtu(&self, name: &str, mtu: u32) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::set_link_mtu_cmd(name, mtu))
+            .await
+            .map(|_| ())
+    }
+
+    async fn set_link_admin(&self, name: &str, up: bool) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::set_link_admin_cmd(name, up))
+            .await
+            .map(|_| ())
+    }
+
+    async fn add_link_vlan(&self, parent: &str, name: &str, vid: u16) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::add_link_vlan_cmd(parent, name, vid))
+            .await
+            .map(|_| ())
+    }
+
+    async fn bridge_vlan_add(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::bridge_vlan_cmd(dev, vlan, true))
+            .await
+            .map(|_| ())
+    }
+
+    async fn bridge_vlan_del(&self, dev: &str, vlan: &BridgeVlan) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::bridge_vlan_cmd(dev, vlan, false))
+            .await
+            .map(|_| ())
+    }
+
+    async fn addr_add(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::addr_cmd(dev, addr, prefix_len, true))
+            .await
+            .map(|_| ())
+    }
+
+    async fn addr_del(&self, dev: &str, addr: IpAddr, prefix_len: u8) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::addr_cmd(dev, addr, prefix_len, false))
+            .await
+            .map(|_| ())
+    }
+
+    async fn vrf_bind(&self, name: &str, vrf: Option<&str>) -> CfgMgrResult<()> {
+        self.exec_or_throw(&ShellBackend::vrf_bind_cmd(name, vrf))
+            .await
+            .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl SystemdController for CommandHarness {
+    async fn restart(&self, unit: &str) -> CfgMgrResult<()> {
+        self.unit_action(UnitAction::Restart, unit)
+    }
+
+    async fn stop(&self, unit: &str) -> CfgMgrResult<()> {
+        self.unit_action(UnitAction::Stop, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unstubbed_commands_succeed_and_are_journaled() {
+        let harness = CommandHarness::new();
+        let result = harness.exec("/sbin/ip link set dev Vlan100 up").await;
+
+        assert!(result.unwrap().success());
+        assert_eq!(
+            harness.journal(),
+            [Invocation::Command(
+                "/sbin/ip link set dev Vlan100 up".to_string()
+            )]
+        );
+        harness.assert_ran_matching(r"link set dev Vlan100");
+        harness.assert_not_ran_matching(r"^/sbin/bridge");
+    }
+
+    #[tokio::test]
+    async fn test_canned_output_latest_stub_wins() {
+        let harness = CommandHarness::new();
+        harness.stub(r"^/sbin/bridge", CannedResponse::ok("all"));
+        harness.stub(r"vlan show", CannedResponse::ok("vlans"));
+
+        let shown = harness.exec("/sbin/bridge vlan show").await.unwrap();
+        let other = harness.exec("/sbin/bridge fdb show").await.unwrap();
+        assert_eq!(shown.stdout, "vlans");
+        assert_eq!(other.stdout, "all");
+    }
+
+    #[tokio::test]
+    async fn test_stub_once_then_default() {
+        let harness = CommandHarness::new();
+        harness.stub_once(r"mtu", CannedResponse::exit(2, "RTNETLINK answers: busy"));
+
+        let first = harness.exec("/sbin/ip link set dev Eth0 mtu 9100").await;
+        let second = harness.exec("/sbin/ip link set dev Eth0 mtu 9100").await;
+        assert_eq!(first.unwrap().exit_code, 2);
+        assert!(second.unwrap().success());
+        assert_eq!(harness.count_matching("mtu 9100"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_error() {
+        let harness = CommandHarness::new();
+        harness.stub(r"teamd", CannedResponse::spawn_error("no such file"));
+
+        let result = harness.exec("/usr/bin/teamd -d").await;
+        assert!(matches!(result, Err(CfgMgrError::ShellExec { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_unit_actions() {
+        let harness = CommandHarness::new();
+        harness.stub_once(
+            r"^systemctl restart hsflowd$",
+            CannedResponse::exit(1, "failed"),
+        );
+
+        assert!(harness.restart("hsflowd").await.is_err());
+        harness.restart("hsflowd").await.unwrap();
+        harness.stop("hsflowd").await.unwrap();
+
+        assert_eq!(
+            harness.unit_actions("hsflowd"),
+            [UnitAction::Restart, UnitAction::Restart, UnitAction::Stop]
+        );
+        harness.assert_unit_restarted_times("hsflowd", 2);
+        harness.assert_unit_stopped_times("hsflowd", 1);
+        harness.assert_ran_matching(r"^systemctl stop hsflowd$");
+
+        harness.clear_journal();
+        harness.assert_unit_restarted_times("hsflowd", 0);
+    }
+
+    #[tokio::test]
+    async fn test_backend_ops_journaled_as_shell_commands() {
+        let harness = CommandHarness::new();
+        harness.stub_once(
+            r"mtu 9100$",
+            CannedResponse::exit(2, "RTNETLINK answers: busy"),
+        );
+
+        let err = harness.set_link_mtu("Ethernet0", 9100).await.unwrap_err();
+        assert!(matches!(
+            err,
+            CfgMgrError::ShellCommandFailed { exit_code: 2, .. }
+        ));
+        harness.set_link_mtu("Ethernet0", 9100).await.unwrap();
+        harness
+            .bridge_vlan_add("Ethernet4", &BridgeVlan::tagged(100))
+            .await
+            .unwrap();
+        harness.vrf_bind("Ethernet8", Some("Vrf1")).await.unwrap();
+
+        assert_eq!(
+            harness.commands(),
+            [
+                ShellBackend::set_link_mtu_cmd("Ethernet0", 9100),
+                ShellBackend::set_link_mtu_cmd("Ethernet0", 9100),
+                ShellBackend::bridge_vlan_cmd("Ethernet4", &BridgeVlan::tagged(100), true),
+                ShellBackend::vrf_bind_cmd("Ethernet8", Some("Vrf1")),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "No invocation matches")]
+    fn test_assert_ran_matching_panics() {
+        CommandHarness::new().assert_ran_matching("anything");
+    }
+}
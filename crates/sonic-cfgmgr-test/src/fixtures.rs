@@ -172,6 +172,16 @@ pub mod vlan_fixtures {
             .with_field("tagging_mode", tagging_mode)
     }
 
+    /// Remove member from VLAN
+    pub fn delete_vlan_member(vlan_id: u16, port: &str) -> ConfigChange {
+        ConfigChange::del("VLAN_MEMBER", format!("Vlan{}|{}", vlan_id, port))
+    }
+
+    /// Assign an IP prefix to a VLAN interface
+    pub fn vlan_interface_ip(vlan_id: u16, prefix: &str) -> ConfigChange {
+        ConfigChange::set("VLAN_INTERFACE", format!("Vlan{}|{}", vlan_id, prefix))
+    }
+
     /// Delete VLAN
     pub fn delete_vlan(vlan_id: u16) -> ConfigChange {
         ConfigChange::del("VLAN", format!("Vlan{}", vlan_id))
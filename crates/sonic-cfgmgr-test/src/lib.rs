@@ -5,12 +5,21 @@
 //! - Test fixtures for common patterns
 //! - CONFIG_DB change simulation
 //! - APPL_DB verification helpers
+//! - Fake shell executor, kernel backend and systemd controller ([`CommandHarness`])
+//! - Scripted multi-manager scenarios ([`ScenarioRunner`])
 //! - Multi-manager interaction tests
 
+mod command_harness;
 pub mod fixtures;
 mod redis_env;
+mod scenario;
 mod verification;
 
+pub use command_harness::{CannedResponse, CommandHarness, Invocation, UnitAction};
 pub use fixtures::*;
 pub use redis_env::RedisTestEnv;
+pub use scenario::{
+    builtin as scenarios, Expectation, Mutation, Scenario, ScenarioError, ScenarioResult,
+    ScenarioRunner, Step, StepReport, DEFAULT_ROUND_BUDGET,
+};
 pub use verification::*;
@@ -0,0 +1,621 @@
+//! Scripted multi-manager scenarios
+//!
+//! A [`Scenario`] is a sequence of [`Step`]s. Each step writes CONFIG_DB and
+//! STATE_DB mutations, then [`ScenarioRunner`] runs `do_task` on every
+//! manager, round after round, until the step's APPL_DB expectations hold
+//! or the round budget is spent. Steps are barriers: a step's mutations are
+//! only applied once the previous step has converged.
+//!
+//! Between rounds, the STATE_DB and APPL_DB writes the managers made are
+//! delivered to the managers subscribed to those tables, as
+//! `SubscriberStateTable` notifications would be on a switch. Managers must
+//! publish through the store returned by [`ScenarioRunner::store`] for this
+//! to work.
+
+use crate::fixtures::{ConfigChange, ConfigOp};
+use crate::RedisTestEnv;
+use async_trait::async_trait;
+use sonic_cfgmgr_common::{
+    CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, KeyOpFieldsValues, Orch, RedisStore,
+    WarmRestartStore,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+use tracing::{debug, info};
+
+/// Rounds a step may take before it is reported as not converged
+pub const DEFAULT_ROUND_BUDGET: usize = 8;
+
+/// Scenario error types
+#[derive(Error, Debug)]
+pub enum ScenarioError {
+    #[error("Store error: {0}")]
+    Store(#[from] CfgMgrError),
+
+    #[error(
+        "Step '{step}' did not converge within {rounds} rounds: {}; pending tasks: {:?}",
+        .unmet.join("; "),
+        .pending
+    )]
+    NotConverged {
+        step: String,
+        rounds: usize,
+        unmet: Vec<String>,
+        pending: Vec<String>,
+    },
+}
+
+/// Result type for scenario operations
+pub type ScenarioResult<T> = Result<T, ScenarioError>;
+
+/// A CONFIG_DB or STATE_DB write made by a step
+#[derive(Debug, Clone)]
+pub struct Mutation {
+    /// Database written to
+    pub db: DbId,
+    /// Table, key and fields of the write
+    pub change: ConfigChange,
+}
+
+impl Mutation {
+    /// A CONFIG_DB write
+    pub fn config(change: ConfigChange) -> Self {
+        Self {
+            db: DbId::ConfigDb,
+            change,
+        }
+    }
+
+    /// A STATE_DB write, as made by another daemon or orchagent
+    pub fn state(change: ConfigChange) -> Self {
+        Self {
+            db: DbId::StateDb,
+            change,
+        }
+    }
+}
+
+/// An APPL_DB condition a step must reach
+#[derive(Debug, Clone)]
+pub enum Expectation {
+    /// `table:key` exists with at least these field values
+    Entry {
+        table: String,
+        key: String,
+        fields: HashMap<String, String>,
+    },
+    /// `table:key` does not exist
+    Absent { table: String, key: String },
+}
+
+impl Expectation {
+    /// Expect `table:key` to exist with the given field values
+    pub fn entry<I, K, V>(table: impl Into<String>, key: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self::Entry {
+            table: table.into(),
+            key: key.into(),
+            fields: fields
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+
+    /// Expect `table:key` not to exist
+    pub fn absent(table: impl Into<String>, key: impl Into<String>) -> Self {
+        Self::Absent {
+            table: table.into(),
+            key: key.into(),
+        }
+    }
+
+    /// Checks the expectation, describing the mismatch if it does not hold
+    pub async fn check(&self, store: &dyn WarmRestartStore) -> CfgMgrResult<Option<String>> {
+        match self {
+            Self::Entry { table, key, fields } => {
+                let actual = store.get_all(DbId::ApplDb, table, key).await?;
+                if actual.is_empty() {
+                    return Ok(Some(format!("{}:{} not found", table, key)));
+                }
+                let mut expected: Vec<_> = fields.iter().collect();
+                expected.sort();
+                for (field, value) in expected {
+                    let found = actual.iter().find(|(f, _)| f == field).map(|(_, v)| v);
+                    if found != Some(value) {
+                        return Ok(Some(format!(
+                            "{}:{} {}: expected '{}', got {:?}",
+                            table, key, field, value, found
+                        )));
+                    }
+                }
+                Ok(None)
+            }
+            Self::Absent { table, key } => {
+                let actual = store.get_all(DbId::ApplDb, table, key).await?;
+                Ok((!actual.is_empty()).then(|| format!("{}:{} still present", table, key)))
+            }
+        }
+    }
+}
+
+/// One barrier of a scenario: mutations, then expectations to converge to
+#[derive(Debug, Clone)]
+pub struct Step {
+    /// Step name, used in reports and errors
+    pub name: String,
+    /// Writes applied at the start of the step, in order
+    pub mutations: Vec<Mutation>,
+    /// APPL_DB state the step must converge to
+    pub expectations: Vec<Expectation>,
+}
+
+impl Step {
+    /// Create an empty step
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            mutations: Vec::new(),
+            expectations: Vec::new(),
+        }
+    }
+
+    /// Add a CONFIG_DB write
+    pub fn config(mut self, change: ConfigChange) -> Self {
+        self.mutations.push(Mutation::config(change));
+        self
+    }
+
+    /// Add a STATE_DB write
+    pub fn state(mut self, change: ConfigChange) -> Self {
+        self.mutations.push(Mutation::state(change));
+        self
+    }
+
+    /// Add an APPL_DB expectation
+    pub fn expect(mut self, expectation: Expectation) -> Self {
+        self.expectations.push(expectation);
+        self
+    }
+}
+
+/// A named sequence of steps
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// Scenario name
+    pub name: String,
+    /// Steps, run in order
+    pub steps: Vec<Step>,
+}
+
+impl Scenario {
+    /// Create an empty scenario
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Append a step
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+}
+
+/// Outcome of a converged step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepReport {
+    /// Step name
+    pub name: String,
+    /// `do_task` rounds it took to converge
+    pub rounds: usize,
+}
+
+/// Store handed to managers: forwards to the backing store and notes the
+/// STATE_DB and APPL_DB keys written, to be delivered to subscribers
+struct NotifyingStore {
+    inner: Arc<dyn WarmRestartStore>,
+    written: Mutex<Vec<(DbId, String, String)>>,
+}
+
+impl NotifyingStore {
+    fn note(&self, db: DbId, table: &str, key: &str) {
+        if db == DbId::ConfigDb {
+            return;
+        }
+        let mut written = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = (db, table.to_string(), key.to_string());
+        if !written.contains(&entry) {
+            written.push(entry);
+        }
+    }
+
+    fn take_written(&self) -> Vec<(DbId, String, String)> {
+        std::mem::take(&mut *self.written.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+#[async_trait]
+impl WarmRestartStore for NotifyingStore {
+    async fn get_all(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<FieldValues> {
+        self.inner.get_all(db, table, key).await
+    }
+
+    async fn set(&self, db: DbId, table: &str, key: &str, fvs: &FieldValues) -> CfgMgrResult<()> {
+        self.inner.set(db, table, key, fvs).await?;
+        self.note(db, table, key);
+        Ok(())
+    }
+
+    async fn keys(&self, db: DbId, table: &str) -> CfgMgrResult<Vec<String>> {
+        self.inner.keys(db, table).await
+    }
+
+    async fn del(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<()> {
+        self.inner.del(db, table, key).await?;
+        self.note(db, table, key);
+        Ok(())
+    }
+}
+
+/// Runs several managers against one store, step by step
+pub struct ScenarioRunner {
+    store: Arc<NotifyingStore>,
+    managers: Vec<Box<dyn CfgMgr>>,
+    round_budget: usize,
+}
+
+impl ScenarioRunner {
+    /// Create a runner over `store` (e.g. an `InMemoryStore`)
+    pub fn new(store: Arc<dyn WarmRestartStore>) -> Self {
+        Self {
+            store: Arc::new(NotifyingStore {
+                inner: store,
+                written: Mutex::new(Vec::new()),
+            }),
+            managers: Vec::new(),
+            round_budget: DEFAULT_ROUND_BUDGET,
+        }
+    }
+
+    /// Create a runner over the databases of a Redis test container
+    pub async fn with_redis(env: &RedisTestEnv) -> ScenarioResult<Self> {
+        let store = RedisStore::connect(&env.connection_url()).await?;
+        Ok(Self::new(Arc::new(store)))
+    }
+
+    /// Set the rounds a step may take to converge
+    pub fn with_round_budget(mut self, rounds: usize) -> Self {
+        self.round_budget = rounds;
+        self
+    }
+
+    /// Add a manager; managers run in the order they were added
+    pub fn with_manager(mut self, manager: Box<dyn CfgMgr>) -> Self {
+        self.managers.push(manager);
+        self
+    }
+
+    /// The store managers must publish through
+    pub fn store(&self) -> Arc<dyn WarmRestartStore> {
+        self.store.clone()
+    }
+
+    /// Run every step of a scenario in order
+    pub async fn run(&mut self, scenario: &Scenario) -> ScenarioResult<Vec<StepReport>> {
+        info!("Running scenario '{}'", scenario.name);
+        let mut reports = Vec::with_capacity(scenario.steps.len());
+        for step in &scenario.steps {
+            reports.push(self.step(step).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Apply a step's mutations and wait for its expectations
+    pub async fn step(&mut self, step: &Step) -> ScenarioResult<StepReport> {
+        for mutation in &step.mutations {
+            self.apply(mutation).await?;
+        }
+
+        let rounds = self.converge(&step.name, &step.expectations).await?;
+        info!("Step '{}' converged after {} rounds", step.name, rounds);
+        Ok(StepReport {
+            name: step.name.clone(),
+            rounds,
+        })
+    }
+
+    /// Run rounds until the expectations hold, within the round budget
+    ///
+    /// At least one round runs, so managers see every change made before
+    /// the call. Returns the number of rounds run.
+    pub async fn converge(
+        &mut self,
+        name: &str,
+        expectations: &[Expectation],
+    ) -> ScenarioResult<usize> {
+        let mut unmet = Vec::new();
+        for round in 1..=self.round_budget {
+            self.run_round().await?;
+            unmet = self.unmet(expectations).await?;
+            if unmet.is_empty() {
+                return Ok(round);
+            }
+            debug!("Step '{}' round {}: {:?}", name, round, unmet);
+        }
+
+        Err(ScenarioError::NotConverged {
+            step: name.to_string(),
+            rounds: self.round_budget,
+            unmet,
+            pending: self.dump_pending_tasks(),
+        })
+    }
+
+    /// Describes the expectations that do not hold
+    pub async fn unmet(&self, expectations: &[Expectation]) -> ScenarioResult<Vec<String>> {
+        let mut unmet = Vec::new();
+        for expectation in expectations {
+            if let Some(mismatch) = expectation.check(self.store.inner.as_ref()).await? {
+                unmet.push(mismatch);
+            }
+        }
+        Ok(unmet)
+    }
+
+    /// Pending tasks of every manager, prefixed with its name
+    pub fn dump_pending_tasks(&self) -> Vec<String> {
+        self.managers
+            .iter()
+            .flat_map(|mgr| {
+                mgr.dump_pending_tasks()
+                    .into_iter()
+                    .map(move |task| format!("{}: {}", mgr.daemon_name(), task))
+            })
+            .collect()
+    }
+
+    /// Runs `do_task` on every manager, then delivers what they wrote
+    async fn run_round(&mut self) -> ScenarioResult<()> {
+        for mgr in &mut self.managers {
+            mgr.do_task().await;
+        }
+        for (db, table, key) in self.store.take_written() {
+            self.notify(db, &table, &key).await?;
+        }
+        Ok(())
+    }
+
+    /// Writes a mutation to the store and notifies subscribers
+    async fn apply(&mut self, mutation: &Mutation) -> ScenarioResult<()> {
+        let change = &mutation.change;
+        match change.op {
+            ConfigOp::Set => {
+                let mut fvs: FieldValues = change
+                    .fields
+                    .iter()
+                    .map(|(f, v)| (f.clone(), v.clone()))
+                    .collect();
+                fvs.sort();
+                // An entry without fields is stored as NULL, like sonic-cfggen does
+                if fvs.is_empty() {
+                    fvs.push(("NULL".to_string(), "NULL".to_string()));
+                }
+                self.store
+                    .inner
+                    .set(mutation.db, &change.table, &change.key, &fvs)
+                    .await?;
+            }
+            ConfigOp::Del => {
+                self.store
+                    .inner
+                    .del(mutation.db, &change.table, &change.key)
+                    .await?;
+            }
+        }
+        self.notify(mutation.db, &change.table, &change.key).await
+    }
+
+    /// Delivers the current contents of `table|key` to its subscribers
+    ///
+    /// Like `SubscriberStateTable`, the whole entry is read back: a SET
+    /// carries every field, and an entry that is gone becomes a DEL.
+    async fn notify(&mut self, db: DbId, table: &str, key: &str) -> ScenarioResult<()> {
+        let fvs = self.store.inner.get_all(db, table, key).await?;
+        let entry = if fvs.is_empty() {
+            KeyOpFieldsValues::del(key)
+        } else {
+            KeyOpFieldsValues::set(key, fvs)
+        };
+
+        for mgr in &mut self.managers {
+            let tables = match db {
+                DbId::ConfigDb => mgr.config_table_names(),
+                DbId::StateDb => mgr.state_table_names(),
+                DbId::ApplDb => mgr.appl_table_names(),
+            };
+            if tables.contains(&table) {
+                mgr.add_to_sync(db, table, vec![entry.clone()]);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Built-in scenarios
+pub mod builtin {
+    use super::*;
+    use crate::fixtures::vlan_fixtures;
+
+    /// Port speed change regenerating the lossless buffer profile
+    ///
+    /// Needs portmgrd and buffermgrd, with a PG lookup covering 40000 and
+    /// 100000 at 5m. The speed lands in APPL_DB PORT_TABLE and the port's
+    /// lossless PGs move to the new profile; the old profile is removed.
+    pub fn port_speed_buffer_regeneration() -> Scenario {
+        let profile_40g = "pg_lossless_40000_5m_profile";
+        let profile_100g = "pg_lossless_100000_5m_profile";
+
+        Scenario::new("port speed change regenerates buffer profile")
+            .step(
+                Step::new("port up at 40G with lossless PGs 3-4")
+                    .state(ConfigChange::set("PORT_TABLE", "Ethernet0").with_field("state", "ok"))
+                    .config(
+                        ConfigChange::set("PORT", "Ethernet0")
+                            .with_field("speed", "40000")
+                            .with_field("admin_status", "up")
+                            .with_field("mtu", "9100"),
+                    )
+                    .config(
+                        ConfigChange::set("CABLE_LENGTH", "AZURE").with_field("Ethernet0", "5m"),
+                    )
+                    .config(
+                        ConfigChange::set("PORT_QOS_MAP", "Ethernet0")
+                            .with_field("pfc_enable", "3,4"),
+                    )
+                    .expect(Expectation::entry(
+                        "PORT_TABLE",
+                        "Ethernet0",
+                        [("speed", "40000")],
+                    ))
+                    .expect(Expectation::entry(
+                        "BUFFER_PG_TABLE",
+                        "Ethernet0:3-4",
+                        [("profile", profile_40g)],
+                    ))
+                    .expect(Expectation::entry(
+                        "BUFFER_PROFILE_TABLE",
+                        profile_40g,
+                        [("pool", "ingress_lossless_pool")],
+                    )),
+            )
+            .step(
+                Step::new("speed changed to 100G")
+                    .config(ConfigChange::set("PORT", "Ethernet0").with_field("speed", "100000"))
+                    .expect(Expectation::entry(
+                        "PORT_TABLE",
+                        "Ethernet0",
+                        [("speed", "100000")],
+                    ))
+                    .expect(Expectation::entry(
+                        "BUFFER_PG_TABLE",
+                        "Ethernet0:3-4",
+                        [("profile", profile_100g)],
+                    ))
+                    .expect(Expectation::entry(
+                        "BUFFER_PROFILE_TABLE",
+                        profile_100g,
+                        [("pool", "ingress_lossless_pool")],
+                    ))
+                    .expect(Expectation::absent("BUFFER_PROFILE_TABLE", profile_40g)),
+            )
+    }
+
+    /// VLAN member add racing the VLAN interface IP
+    ///
+    /// Needs vlanmgrd and intfmgrd. The IP and the member are written
+    /// before the VLAN itself, so intfmgrd must hold the IP until vlanmgrd
+    /// marks the VLAN ok in STATE_DB, and vlanmgrd must hold the member
+    /// until the VLAN exists. Removing the member leaves the IP in place.
+    pub fn vlan_member_races_intf_ip() -> Scenario {
+        Scenario::new("VLAN member add races interface IP")
+            .step(
+                Step::new("IP, member and VLAN in one batch")
+                    .config(vlan_fixtures::vlan_interface_ip(100, "192.168.0.1/24"))
+                    .config(vlan_fixtures::vlan_member(100, "Ethernet0", "untagged"))
+                    .config(vlan_fixtures::vlan(100).with_field("admin_status", "up"))
+                    .expect(Expectation::entry(
+                        "VLAN_TABLE",
+                        "Vlan100",
+                        [("admin_status", "up")],
+                    ))
+                    .expect(Expectation::entry(
+                        "VLAN_MEMBER_TABLE",
+                        "Vlan100:Ethernet0",
+                        [("tagging_mode", "untagged")],
+                    ))
+                    .expect(Expectation::entry(
+                        "INTF_TABLE",
+                        "Vlan100:192.168.0.1/24",
+                        [("scope", "global"), ("family", "IPv4")],
+                    )),
+            )
+            .step(
+                Step::new("member removed")
+                    .config(vlan_fixtures::delete_vlan_member(100, "Ethernet0"))
+                    .expect(Expectation::absent(
+                        "VLAN_MEMBER_TABLE",
+                        "Vlan100:Ethernet0",
+                    ))
+                    .expect(Expectation::entry(
+                        "INTF_TABLE",
+                        "Vlan100:192.168.0.1/24",
+                        [("family", "IPv4")],
+                    )),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonic_cfgmgr_common::InMemoryStore;
+
+    #[tokio::test]
+    async fn test_expectation_check() {
+        let store = InMemoryStore::new();
+        let fvs = vec![("speed".to_string(), "40000".to_string())];
+        store
+            .set(DbId::ApplDb, "PORT_TABLE", "Ethernet0", &fvs)
+            .await
+            .unwrap();
+
+        let met = Expectation::entry("PORT_TABLE", "Ethernet0", [("speed", "40000")]);
+        assert_eq!(met.check(&store).await.unwrap(), None);
+
+        let wrong = Expectation::entry("PORT_TABLE", "Ethernet0", [("speed", "100000")]);
+        assert!(wrong
+            .check(&store)
+            .await
+            .unwrap()
+            .unwrap()
+            .contains("speed"));
+
+        let absent = Expectation::absent("PORT_TABLE", "Ethernet0");
+        assert!(absent.check(&store).await.unwrap().is_some());
+        assert_eq!(
+            Expectation::absent("PORT_TABLE", "Ethernet4")
+                .check(&store)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_step_without_managers_reports_unmet() {
+        let mut runner = ScenarioRunner::new(Arc::new(InMemoryStore::new())).with_round_budget(2);
+        let step = Step::new("nothing publishes")
+            .config(ConfigChange::set("PORT", "Ethernet0").with_field("mtu", "9100"))
+            .expect(Expectation::entry(
+                "PORT_TABLE",
+                "Ethernet0",
+                [("mtu", "9100")],
+            ));
+
+        match runner.step(&step).await {
+            Err(ScenarioError::NotConverged { rounds, unmet, .. }) => {
+                assert_eq!(rounds, 2);
+                assert_eq!(unmet, vec!["PORT_TABLE:Ethernet0 not found"]);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
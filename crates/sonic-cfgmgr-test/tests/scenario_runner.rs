@@ -0,0 +1,124 @@
+//! Built-in multi-manager scenarios
+//!
+//! Each scenario runs against an in-memory store, and against Redis when
+//! Docker is available.
+
+use std::sync::Arc;
+
+use sonic_buffermgrd::types::PgProfileLookup;
+use sonic_buffermgrd::{BufferMgr, PgProfile};
+use sonic_cfgmgr_common::{InMemoryStore, InMemorySysctl};
+use sonic_cfgmgr_test::{scenarios, CommandHarness, RedisTestEnv, ScenarioRunner};
+use sonic_intfmgrd::{IntfMgr, SwitchType};
+use sonic_portmgrd::PortMgr;
+use sonic_vlanmgrd::VlanMgr;
+
+fn pg_lookup() -> PgProfileLookup {
+    let mut lookup = PgProfileLookup::new();
+    for line in [
+        "40000 5m 34816 18432 16384 1 2496",
+        "100000 5m 51200 18432 32768 1 2496",
+    ] {
+        let (speed, cable, profile) = PgProfile::from_line(line).unwrap();
+        lookup.entry(speed).or_default().insert(cable, profile);
+    }
+    lookup
+}
+
+/// portmgrd + buffermgrd
+fn port_buffer_runner(runner: ScenarioRunner) -> ScenarioRunner {
+    let store = runner.store();
+    let harness = Arc::new(CommandHarness::new());
+    let port_mgr = PortMgr::new()
+        .with_executor(harness.clone())
+        .with_backend(harness)
+        .with_store(store.clone());
+    runner
+        .with_manager(Box::new(port_mgr))
+        .with_manager(Box::new(BufferMgr::new(pg_lookup()).with_store(store)))
+}
+
+/// intfmgrd + vlanmgrd, intfmgrd first so its IP has to wait for the VLAN
+fn vlan_intf_runner(runner: ScenarioRunner) -> (ScenarioRunner, Arc<CommandHarness>) {
+    let store = runner.store();
+    let harness = Arc::new(CommandHarness::new());
+    let mut vlan_mgr = VlanMgr::new()
+        .with_executor(harness.clone())
+        .with_backend(harness.clone())
+        .with_store(store.clone());
+    vlan_mgr.set_global_mac("00:11:22:33:44:55");
+    let intf_mgr = IntfMgr::new(SwitchType::Normal)
+        .with_sysctl(Arc::new(InMemorySysctl::new()))
+        .with_executor(harness.clone())
+        .with_backend(harness.clone())
+        .with_store(store);
+
+    let runner = runner
+        .with_manager(Box::new(intf_mgr))
+        .with_manager(Box::new(vlan_mgr));
+    (runner, harness)
+}
+
+#[tokio::test]
+async fn test_port_speed_change_regenerates_buffer_profile() {
+    let mut runner = port_buffer_runner(ScenarioRunner::new(Arc::new(InMemoryStore::new())));
+
+    let reports = runner
+        .run(&scenarios::port_speed_buffer_regeneration())
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(reports.len(), 2);
+}
+
+#[tokio::test]
+async fn test_vlan_member_races_intf_ip() {
+    let (mut runner, harness) =
+        vlan_intf_runner(ScenarioRunner::new(Arc::new(InMemoryStore::new())));
+
+    let reports = runner
+        .run(&scenarios::vlan_member_races_intf_ip())
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    // The IP and the member both waited for the VLAN
+    assert!(reports[0].rounds > 1, "{:?}", reports);
+    harness.assert_ran_matching("Ethernet0");
+    harness.assert_ran_matching(r#"link set dev "Vlan100" up$"#);
+    assert!(runner.dump_pending_tasks().is_empty());
+}
+
+#[tokio::test]
+#[ignore = "Requires Docker"]
+async fn test_port_speed_change_regenerates_buffer_profile_redis() {
+    let env = RedisTestEnv::start().await.expect("Failed to start Redis");
+    let runner = ScenarioRunner::with_redis(&env).await.unwrap();
+    let mut runner = port_buffer_runner(runner);
+
+    runner
+        .run(&scenarios::port_speed_buffer_regeneration())
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(
+        env.hget("BUFFER_PG_TABLE:Ethernet0:3-4", "profile")
+            .await
+            .unwrap()
+            .as_deref(),
+        Some("pg_lossless_100000_5m_profile")
+    );
+}
+
+#[tokio::test]
+#[ignore = "Requires Docker"]
+async fn test_vlan_member_races_intf_ip_redis() {
+    let env = RedisTestEnv::start().await.expect("Failed to start Redis");
+    let (mut runner, _harness) = vlan_intf_runner(ScenarioRunner::with_redis(&env).await.unwrap());
+
+    runner
+        .run(&scenarios::vlan_member_races_intf_ip())
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert!(env
+        .exists("INTF_TABLE:Vlan100:192.168.0.1/24")
+        .await
+        .unwrap());
+}
@@ -0,0 +1,599 @@
+//! Rust orch notifications delivered to C++ observers.
+//!
+//! C++ orchs that still depend on a migrated orch subscribe to its
+//! notifications with [`register_cpp_observer`], passing a subject kind, a
+//! callback and an opaque context pointer. Rust orchs publish with
+//! [`publish_cpp_event`]; each matching callback receives a flat
+//! [`CppObserverEvent`] (no JSON, no heap pointers) together with its
+//! context pointer.
+//!
+//! Delivery is synchronous, like `Subject::notify` in C++:
+//!
+//! - Events are delivered one publish at a time, to observers in
+//!   registration order, and carry a process-wide sequence number.
+//! - Once [`unregister_cpp_observer`] returns, the callback is not called
+//!   again, so its context may be freed. Unregistering from inside a
+//!   callback is allowed, as are registering and nested publishes.
+//! - A panic in a callback is caught by the trampoline and logged; it never
+//!   unwinds into the publishing orch or C++.
+//!
+//! Callbacks must not throw C++ exceptions.
+
+use std::cell::Cell;
+use std::ffi::{c_char, c_void, CStr};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
+use log::error;
+
+use crate::sync::lock;
+
+/// The callback pointer was null.
+pub const CPP_OBSERVER_ERR_NULL_POINTER: i64 = -1;
+/// The subject kind is unknown.
+pub const CPP_OBSERVER_ERR_INVALID_SUBJECT: i64 = -2;
+/// No observer is registered under the handle.
+pub const CPP_OBSERVER_ERR_NOT_FOUND: i64 = -3;
+
+/// Size of [`CppObserverEvent::name`], including the terminator.
+pub const CPP_OBSERVER_NAME_LEN: usize = 128;
+/// Size of [`CppObserverEvent::ip`], including the terminator
+/// (`INET6_ADDRSTRLEN`).
+pub const CPP_OBSERVER_IP_LEN: usize = 46;
+
+/// Subjects C++ observers can subscribe to.
+///
+/// The values match the C++ `SubjectType` entries in `observer.h`.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CppObserverSubject {
+    /// Neighbor added, removed or changed MAC (NeighOrch).
+    NeighChange = 1,
+    /// MLAG interface membership changed (MlagOrch).
+    MlagIntfChange = 13,
+    /// MLAG peer-link (ISL) changed (MlagOrch).
+    MlagIslChange = 14,
+    /// BFD session state changed (BfdOrch).
+    BfdSessionStateChange = 16,
+}
+
+impl CppObserverSubject {
+    /// Creates a subject from its C value.
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::NeighChange),
+            13 => Some(Self::MlagIntfChange),
+            14 => Some(Self::MlagIslChange),
+            16 => Some(Self::BfdSessionStateChange),
+            _ => None,
+        }
+    }
+}
+
+/// A notification as seen by C++ observers.
+///
+/// Unused fields are zero. Strings are null-terminated and truncated to fit.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CppObserverEvent {
+    /// Sequence number, increasing across all subjects; set on publish.
+    pub seq: u64,
+    /// [`CppObserverSubject`] value.
+    pub subject: u32,
+    /// Subject-specific state (e.g. the SAI BFD session state).
+    pub state: i32,
+    /// 1 for add, 0 for delete.
+    pub is_add: u8,
+    /// 1 if `old_mac` is set.
+    pub has_old_mac: u8,
+    /// MAC address.
+    pub mac: [u8; 6],
+    /// Previous MAC address when it changed in place.
+    pub old_mac: [u8; 6],
+    /// Interface, ISL or peer name.
+    pub name: [c_char; CPP_OBSERVER_NAME_LEN],
+    /// IP address in text form.
+    pub ip: [c_char; CPP_OBSERVER_IP_LEN],
+}
+
+impl CppObserverEvent {
+    /// Creates an empty event for a subject.
+    pub fn new(subject: CppObserverSubject) -> Self {
+        Self {
+            seq: 0,
+            subject: subject as u32,
+            state: 0,
+            is_add: 0,
+            has_old_mac: 0,
+            mac: [0; 6],
+            old_mac: [0; 6],
+            name: [0; CPP_OBSERVER_NAME_LEN],
+            ip: [0; CPP_OBSERVER_IP_LEN],
+        }
+    }
+
+    /// Sets the add/delete flag.
+    pub fn with_add(mut self, is_add: bool) -> Self {
+        self.is_add = u8::from(is_add);
+        self
+    }
+
+    /// Sets the subject-specific state.
+    pub fn with_state(mut self, state: i32) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        copy_str(&mut self.name, name);
+        self
+    }
+
+    /// Sets the IP address.
+    pub fn with_ip(mut self, ip: &str) -> Self {
+        copy_str(&mut self.ip, ip);
+        self
+    }
+
+    /// Sets the MAC address.
+    pub fn with_mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = mac;
+        self
+    }
+
+    /// Sets the previous MAC address.
+    pub fn with_old_mac(mut self, old_mac: [u8; 6]) -> Self {
+        self.old_mac = old_mac;
+        self.has_old_mac = 1;
+        self
+    }
+
+    /// Returns the subject, if the value is known.
+    pub fn subject(&self) -> Option<CppObserverSubject> {
+        CppObserverSubject::from_raw(self.subject)
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> &str {
+        read_str(&self.name)
+    }
+
+    /// Returns the IP address.
+    pub fn ip(&self) -> &str {
+        read_str(&self.ip)
+    }
+}
+
+/// Copies `src` into `dst`, truncated and null-terminated.
+fn copy_str(dst: &mut [c_char], src: &str) {
+    // SAFETY: c_char and u8 have the same size and alignment
+    let dst = unsafe { std::slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), dst.len()) };
+    // Names and addresses are ASCII, so truncating at any byte is fine
+    let len = src.len().min(dst.len() - 1);
+    dst[..len].copy_from_slice(&src.as_bytes()[..len]);
+    dst[len..].fill(0);
+}
+
+/// Reads a null-terminated string written by `copy_str`.
+fn read_str(buf: &[c_char]) -> &str {
+    // SAFETY: c_char and u8 have the same size and alignment
+    let bytes = unsafe { std::slice::from_raw_parts(buf.as_ptr().cast::<u8>(), buf.len()) };
+    CStr::from_bytes_until_nul(bytes)
+        .ok()
+        .and_then(|s| s.to_str().ok())
+        .unwrap_or("")
+}
+
+/// Observer callback.
+///
+/// `event` is valid only for the duration of the call. The "C-unwind" ABI
+/// lets the trampoline catch panics from callbacks implemented in Rust; for
+/// C++ it is the plain C calling convention.
+pub type CppObserverFn =
+    unsafe extern "C-unwind" fn(event: *const CppObserverEvent, user_ctx: *mut c_void);
+
+/// Opaque C++ context pointer, only ever handed back to C++.
+#[derive(Clone, Copy)]
+struct UserCtx(*mut c_void);
+
+// SAFETY: the bridge never dereferences the pointer; the observer owns it
+// and must accept calls from any publishing thread.
+unsafe impl Send for UserCtx {}
+
+struct Observer {
+    handle: i64,
+    subject: CppObserverSubject,
+    callback: CppObserverFn,
+    user_ctx: UserCtx,
+}
+
+struct Registry {
+    next_handle: i64,
+    next_seq: u64,
+    observers: Vec<Observer>,
+}
+
+impl Registry {
+    fn find(&self, handle: i64) -> Option<(CppObserverFn, UserCtx)> {
+        self.observers
+            .iter()
+            .find(|o| o.handle == handle)
+            .map(|o| (o.callback, o.user_ctx))
+    }
+}
+
+static OBSERVERS: Mutex<Registry> = Mutex::new(Registry {
+    next_handle: 1,
+    next_seq: 1,
+    observers: Vec::new(),
+});
+
+/// Held for the whole of a publish, so publishes don't interleave and
+/// unregistering can wait for one in flight.
+static DELIVERY: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// True while this thread holds `DELIVERY`.
+    static DELIVERING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Registers a C++ observer for a subject.
+///
+/// Returns a positive handle for [`unregister_cpp_observer`], or
+/// `CPP_OBSERVER_ERR_NULL_POINTER` / `CPP_OBSERVER_ERR_INVALID_SUBJECT`.
+///
+/// # Safety
+///
+/// - `callback` must be null or a function safe to call with any event and
+///   `user_ctx`, from any thread, until it is unregistered
+#[no_mangle]
+pub unsafe extern "C" fn register_cpp_observer(
+    subject_kind: u32,
+    callback: Option<CppObserverFn>,
+    user_ctx: *mut c_void,
+) -> i64 {
+    let Some(callback) = callback else {
+        return CPP_OBSERVER_ERR_NULL_POINTER;
+    };
+    let Some(subject) = CppObserverSubject::from_raw(subject_kind) else {
+        return CPP_OBSERVER_ERR_INVALID_SUBJECT;
+    };
+
+    let mut registry = lock(&OBSERVERS);
+    let handle = registry.next_handle;
+    registry.next_handle += 1;
+    registry.observers.push(Observer {
+        handle,
+        subject,
+        callback,
+        user_ctx: UserCtx(user_ctx),
+    });
+    handle
+}
+
+/// Unregisters a C++ observer.
+///
+/// Returns 0, or `CPP_OBSERVER_ERR_NOT_FOUND` for an unknown handle. When
+/// called outside a callback, waits for an in-flight publish to finish, so
+/// the callback is never running once this returns.
+#[no_mangle]
+pub extern "C" fn unregister_cpp_observer(handle: i64) -> i64 {
+    {
+        let mut registry = lock(&OBSERVERS);
+        let Some(index) = registry.observers.iter().position(|o| o.handle == handle) else {
+            return CPP_OBSERVER_ERR_NOT_FOUND;
+        };
+        registry.observers.remove(index);
+    }
+
+    // Inside a callback this thread already holds DELIVERY; the publish
+    // re-checks the registry before each call instead
+    if !DELIVERING.with(Cell::get) {
+        drop(lock(&DELIVERY));
+    }
+    0
+}
+
+/// Returns the number of C++ observers registered for a subject.
+pub fn cpp_observer_count(subject: CppObserverSubject) -> usize {
+    lock(&OBSERVERS)
+        .observers
+        .iter()
+        .filter(|o| o.subject == subject)
+        .count()
+}
+
+/// Delivers an event to the C++ observers of its subject.
+///
+/// Assigns the event's sequence number and returns the number of callbacks
+/// that completed without panicking.
+pub fn publish_cpp_event(mut event: CppObserverEvent) -> usize {
+    let nested = DELIVERING.with(Cell::get);
+    let _delivery = (!nested).then(|| lock(&DELIVERY));
+    DELIVERING.with(|d| d.set(true));
+
+    let handles: Vec<i64> = {
+        let mut registry = lock(&OBSERVERS);
+        event.seq = registry.next_seq;
+        registry.next_seq += 1;
+        registry
+            .observers
+            .iter()
+            .filter(|o| o.subject as u32 == event.subject)
+            .map(|o| o.handle)
+            .collect()
+    };
+
+    let mut delivered = 0;
+    for handle in handles {
+        // Skip observers unregistered by an earlier callback
+        let Some((callback, user_ctx)) = lock(&OBSERVERS).find(handle) else {
+            continue;
+        };
+        if trampoline(handle, callback, &event, user_ctx) {
+            delivered += 1;
+        }
+    }
+
+    DELIVERING.with(|d| d.set(nested));
+    delivered
+}
+
+/// Calls one observer, catching panics. Returns false if it panicked.
+fn trampoline(
+    handle: i64,
+    callback: CppObserverFn,
+    event: &CppObserverEvent,
+    user_ctx: UserCtx,
+) -> bool {
+    // SAFETY: the registrant guaranteed the callback accepts any event and
+    // its own context until unregistered, which has not happened yet
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        callback(event, user_ctx.0);
+    }));
+    if result.is_err() {
+        error!(
+            "C++ observer {} panicked handling event {} (subject {})",
+            handle, event.seq, event.subject
+        );
+    }
+    result.is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::REGISTRY_LOCK;
+
+    type RegisterFn = unsafe extern "C" fn(u32, Option<CppObserverFn>, *mut c_void) -> i64;
+    type UnregisterFn = extern "C" fn(i64) -> i64;
+
+    // Called through C function pointers, the way C++ sees them
+    const REGISTER: RegisterFn = register_cpp_observer;
+    const UNREGISTER: UnregisterFn = unregister_cpp_observer;
+
+    /// Per-observer context: a tag and the events it received.
+    struct Recorder {
+        tag: &'static str,
+        log: Mutex<Vec<(&'static str, u64, String)>>,
+    }
+
+    impl Recorder {
+        fn new(tag: &'static str) -> Self {
+            Self {
+                tag,
+                log: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn ctx(&self) -> *mut c_void {
+            self as *const Self as *mut c_void
+        }
+
+        fn names(&self) -> Vec<String> {
+            lock(&self.log).iter().map(|(_, _, n)| n.clone()).collect()
+        }
+    }
+
+    unsafe extern "C-unwind" fn record(event: *const CppObserverEvent, user_ctx: *mut c_void) {
+        let event = &*event;
+        let recorder = &*(user_ctx as *const Recorder);
+        lock(&recorder.log).push((recorder.tag, event.seq, event.name().to_string()));
+    }
+
+    /// Shared log for checking order across observers.
+    static ORDER: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+    unsafe extern "C-unwind" fn record_order(
+        _event: *const CppObserverEvent,
+        user_ctx: *mut c_void,
+    ) {
+        let recorder = &*(user_ctx as *const Recorder);
+        lock(&ORDER).push(recorder.tag);
+    }
+
+    unsafe extern "C-unwind" fn explode(_event: *const CppObserverEvent, _user_ctx: *mut c_void) {
+        panic!("observer bug");
+    }
+
+    /// Unregisters the handle stored in its context.
+    unsafe extern "C-unwind" fn unregister_self(
+        _event: *const CppObserverEvent,
+        user_ctx: *mut c_void,
+    ) {
+        let handle = &*(user_ctx as *const Mutex<i64>);
+        assert_eq!(UNREGISTER(*lock(handle)), 0);
+    }
+
+    fn isl_event(name: &str) -> CppObserverEvent {
+        CppObserverEvent::new(CppObserverSubject::MlagIslChange)
+            .with_add(true)
+            .with_name(name)
+    }
+
+    #[test]
+    fn test_delivery_order() {
+        let _guard = lock(&REGISTRY_LOCK);
+        let recorder = Recorder::new("isl");
+        let handle = unsafe {
+            REGISTER(
+                CppObserverSubject::MlagIslChange as u32,
+                Some(record),
+                recorder.ctx(),
+            )
+        };
+        assert!(handle > 0);
+        assert_eq!(cpp_observer_count(CppObserverSubject::MlagIslChange), 1);
+
+        for name in ["PortChannel1", "PortChannel2", "PortChannel3"] {
+            assert_eq!(publish_cpp_event(isl_event(name)), 1);
+        }
+        // Other subjects are not delivered
+        let bfd = CppObserverEvent::new(CppObserverSubject::BfdSessionStateChange);
+        assert_eq!(publish_cpp_event(bfd), 0);
+
+        assert_eq!(
+            recorder.names(),
+            vec!["PortChannel1", "PortChannel2", "PortChannel3"]
+        );
+        let seqs: Vec<u64> = lock(&recorder.log).iter().map(|(_, s, _)| *s).collect();
+        assert!(seqs.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(UNREGISTER(handle), 0);
+    }
+
+    #[test]
+    fn test_observers_called_in_registration_order() {
+        let _guard = lock(&REGISTRY_LOCK);
+        lock(&ORDER).clear();
+        let first = Recorder::new("first");
+        let second = Recorder::new("second");
+        let subject = CppObserverSubject::NeighChange as u32;
+
+        let h1 = unsafe { REGISTER(subject, Some(record_order), first.ctx()) };
+        let h2 = unsafe { REGISTER(subject, Some(record_order), second.ctx()) };
+        assert!(h2 > h1);
+
+        let event = CppObserverEvent::new(CppObserverSubject::NeighChange);
+        assert_eq!(publish_cpp_event(event), 2);
+        assert_eq!(publish_cpp_event(event), 2);
+        assert_eq!(*lock(&ORDER), vec!["first", "second", "first", "second"]);
+
+        assert_eq!(UNREGISTER(h1), 0);
+        assert_eq!(UNREGISTER(h2), 0);
+    }
+
+    #[test]
+    fn test_no_delivery_after_unregister() {
+        let _guard = lock(&REGISTRY_LOCK);
+        let recorder = Recorder::new("isl");
+        let handle = unsafe {
+            REGISTER(
+                CppObserverSubject::MlagIslChange as u32,
+                Some(record),
+                recorder.ctx(),
+            )
+        };
+
+        publish_cpp_event(isl_event("PortChannel1"));
+        assert_eq!(UNREGISTER(handle), 0);
+        assert_eq!(publish_cpp_event(isl_event("PortChannel2")), 0);
+        assert_eq!(recorder.names(), vec!["PortChannel1"]);
+
+        // Handles are not reused and unregister is not idempotent
+        assert_eq!(UNREGISTER(handle), CPP_OBSERVER_ERR_NOT_FOUND);
+        assert_eq!(UNREGISTER(0), CPP_OBSERVER_ERR_NOT_FOUND);
+        assert_eq!(cpp_observer_count(CppObserverSubject::MlagIslChange), 0);
+    }
+
+    #[test]
+    fn test_unregister_from_callback() {
+        let _guard = lock(&REGISTRY_LOCK);
+        let subject = CppObserverSubject::MlagIntfChange as u32;
+        let own_handle = Mutex::new(0);
+        let later = Recorder::new("later");
+
+        let h1 = unsafe {
+            REGISTER(
+                subject,
+                Some(unregister_self),
+                &own_handle as *const Mutex<i64> as *mut c_void,
+            )
+        };
+        *lock(&own_handle) = h1;
+        let h2 = unsafe { REGISTER(subject, Some(record), later.ctx()) };
+
+        let event =
+            CppObserverEvent::new(CppObserverSubject::MlagIntfChange).with_name("Ethernet0");
+        assert_eq!(publish_cpp_event(event), 2);
+        // Only the remaining observer sees the second event
+        assert_eq!(publish_cpp_event(event), 1);
+        assert_eq!(later.names(), vec!["Ethernet0", "Ethernet0"]);
+
+        assert_eq!(UNREGISTER(h2), 0);
+    }
+
+    #[test]
+    fn test_panicking_observer_is_contained() {
+        let _guard = lock(&REGISTRY_LOCK);
+        let subject = CppObserverSubject::BfdSessionStateChange as u32;
+        let after = Recorder::new("after");
+
+        let bad = unsafe { REGISTER(subject, Some(explode), std::ptr::null_mut()) };
+        let good = unsafe { REGISTER(subject, Some(record), after.ctx()) };
+
+        let event = CppObserverEvent::new(CppObserverSubject::BfdSessionStateChange)
+            .with_name("default|default|10.0.0.1")
+            .with_state(3);
+        assert_eq!(publish_cpp_event(event), 1);
+        assert_eq!(after.names(), vec!["default|default|10.0.0.1"]);
+
+        assert_eq!(UNREGISTER(bad), 0);
+        assert_eq!(UNREGISTER(good), 0);
+    }
+
+    #[test]
+    fn test_register_invalid_arguments() {
+        let _guard = lock(&REGISTRY_LOCK);
+        unsafe {
+            assert_eq!(
+                REGISTER(
+                    CppObserverSubject::NeighChange as u32,
+                    None,
+                    std::ptr::null_mut()
+                ),
+                CPP_OBSERVER_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                REGISTER(42, Some(record), std::ptr::null_mut()),
+                CPP_OBSERVER_ERR_INVALID_SUBJECT
+            );
+        }
+        assert_eq!(cpp_observer_count(CppObserverSubject::NeighChange), 0);
+    }
+
+    #[test]
+    fn test_event_fields() {
+        let event = CppObserverEvent::new(CppObserverSubject::NeighChange)
+            .with_add(true)
+            .with_name("Ethernet0")
+            .with_ip("2001:db8::1")
+            .with_mac([0, 0x11, 0x22, 0x33, 0x44, 0x55])
+            .with_old_mac([0, 0x11, 0x22, 0x33, 0x44, 0x66]);
+
+        assert_eq!(event.subject(), Some(CppObserverSubject::NeighChange));
+        assert_eq!(event.name(), "Ethernet0");
+        assert_eq!(event.ip(), "2001:db8::1");
+        assert_eq!(event.is_add, 1);
+        assert_eq!(event.has_old_mac, 1);
+        assert_eq!(event.old_mac[5], 0x66);
+
+        // Long names are truncated and stay null-terminated
+        let long = "x".repeat(CPP_OBSERVER_NAME_LEN * 2);
+        let event = CppObserverEvent::new(CppObserverSubject::MlagIntfChange).with_name(&long);
+        assert_eq!(event.name().len(), CPP_OBSERVER_NAME_LEN - 1);
+        assert_eq!(event.name[CPP_OBSERVER_NAME_LEN - 1], 0);
+        assert_eq!(event.ip(), "");
+    }
+}
@@ -34,7 +34,12 @@
 //! 4. Thread safety is ensured via appropriate synchronization
 
 mod cpp_bridge;
+mod cpp_observer;
+mod route_query;
 mod rust_exports;
+mod sync;
 
 pub use cpp_bridge::*;
+pub use cpp_observer::*;
+pub use route_query::*;
 pub use rust_exports::*;
@@ -0,0 +1,356 @@
+//! Route queries exported for C++ callers.
+//!
+//! During phase 2 the C++ NeighOrch needs to look up routes owned by the
+//! Rust RouteOrch. The RouteOrch is shared with the orchdaemon through an
+//! `Arc<Mutex<_>>` registered with [`register_route_orch`]; unlike the
+//! thread-local registrations in `rust_exports`, the registry is process
+//! wide, since NeighOrch does not run on the thread that registered it.
+//!
+//! Functions that can fail return one of the negative `ROUTE_QUERY_ERR_*`
+//! codes.
+
+use std::ffi::{c_char, CStr};
+use std::sync::{Arc, Mutex};
+
+use sonic_types::IpPrefix;
+
+use crate::cpp_bridge::{FfiError, FfiResult};
+use crate::sync::lock;
+
+/// A pointer argument was null.
+pub const ROUTE_QUERY_ERR_NULL_POINTER: i32 = -1;
+/// A string argument was not valid UTF-8.
+pub const ROUTE_QUERY_ERR_INVALID_UTF8: i32 = -2;
+/// The prefix was not in CIDR format.
+pub const ROUTE_QUERY_ERR_INVALID_PREFIX: i32 = -3;
+/// No RouteOrch is registered.
+pub const ROUTE_QUERY_ERR_NOT_REGISTERED: i32 = -4;
+/// The route does not exist.
+pub const ROUTE_QUERY_ERR_NOT_FOUND: i32 = -5;
+/// The output buffer is too small; it holds a truncated, null-terminated
+/// result.
+pub const ROUTE_QUERY_ERR_BUFFER_TOO_SMALL: i32 = -6;
+
+/// Route lookups the Rust RouteOrch answers for C++ callers.
+pub trait RouteQuery: Send {
+    /// Returns true if the route exists in the VRF.
+    fn has_route(&self, vrf_id: u64, prefix: &IpPrefix) -> bool;
+
+    /// Returns the next-hop group key of a route (e.g.
+    /// "10.0.0.1@Ethernet0,10.0.0.3@Ethernet4"), empty for a blackhole.
+    fn nhg_key(&self, vrf_id: u64, prefix: &IpPrefix) -> Option<String>;
+
+    /// Returns the number of routes in the VRF.
+    fn route_count(&self, vrf_id: u64) -> u64;
+}
+
+type SharedRouteQuery = Arc<Mutex<dyn RouteQuery>>;
+
+static ROUTE_ORCH: Mutex<Option<SharedRouteQuery>> = Mutex::new(None);
+
+/// Registers the Rust RouteOrch for C++ route queries.
+pub fn register_route_orch<T: RouteQuery + 'static>(orch: Arc<Mutex<T>>) {
+    let orch: SharedRouteQuery = orch;
+    *lock(&ROUTE_ORCH) = Some(orch);
+}
+
+/// Unregisters the Rust RouteOrch.
+pub fn unregister_route_orch() {
+    *lock(&ROUTE_ORCH) = None;
+}
+
+/// Runs `f` on the registered RouteOrch, if any.
+fn with_route_orch<R>(f: impl FnOnce(&dyn RouteQuery) -> R) -> Option<R> {
+    let orch = lock(&ROUTE_ORCH).clone()?;
+    let guard = lock(&orch);
+    Some(f(&*guard))
+}
+
+/// Reads a null-terminated UTF-8 string argument.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid null-terminated C string.
+unsafe fn str_arg<'a>(ptr: *const c_char) -> FfiResult<&'a str> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| FfiError::InvalidUtf8)
+}
+
+/// Reads and parses a CIDR prefix argument.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a valid null-terminated C string.
+unsafe fn prefix_arg(ptr: *const c_char) -> Result<IpPrefix, i32> {
+    let prefix = str_arg(ptr).map_err(|e| match e {
+        FfiError::NullPointer => ROUTE_QUERY_ERR_NULL_POINTER,
+        _ => ROUTE_QUERY_ERR_INVALID_UTF8,
+    })?;
+    prefix.parse().map_err(|_| ROUTE_QUERY_ERR_INVALID_PREFIX)
+}
+
+/// Returns true if the route exists in the VRF.
+///
+/// Returns false for a null, malformed or unknown prefix, or if no RouteOrch
+/// is registered.
+///
+/// # Safety
+///
+/// - `prefix` must be null or a valid null-terminated C string in CIDR
+///   format (e.g., "10.0.0.0/24")
+#[no_mangle]
+pub unsafe extern "C" fn route_orch_has_route(vrf_id: u64, prefix: *const c_char) -> bool {
+    let Ok(prefix) = prefix_arg(prefix) else {
+        return false;
+    };
+    with_route_orch(|orch| orch.has_route(vrf_id, &prefix)).unwrap_or(false)
+}
+
+/// Copies the next-hop group key of a route into `out_buf`.
+///
+/// On success the key is written null-terminated and its length (without
+/// the terminator) is returned. If it does not fit, as much as fits is
+/// written, still null-terminated, and `ROUTE_QUERY_ERR_BUFFER_TOO_SMALL`
+/// is returned. Other failures return a negative `ROUTE_QUERY_ERR_*` code
+/// and leave the buffer untouched.
+///
+/// # Safety
+///
+/// - `prefix` must be null or a valid null-terminated C string
+/// - `out_buf` must be null or valid for writes of `buf_len` bytes
+#[no_mangle]
+pub unsafe extern "C" fn route_orch_get_nhg_key(
+    vrf_id: u64,
+    prefix: *const c_char,
+    out_buf: *mut c_char,
+    buf_len: usize,
+) -> i32 {
+    if out_buf.is_null() {
+        return ROUTE_QUERY_ERR_NULL_POINTER;
+    }
+    let prefix = match prefix_arg(prefix) {
+        Ok(prefix) => prefix,
+        Err(code) => return code,
+    };
+    let key = match with_route_orch(|orch| orch.nhg_key(vrf_id, &prefix)) {
+        None => return ROUTE_QUERY_ERR_NOT_REGISTERED,
+        Some(None) => return ROUTE_QUERY_ERR_NOT_FOUND,
+        Some(Some(key)) => key,
+    };
+
+    if buf_len == 0 {
+        return ROUTE_QUERY_ERR_BUFFER_TOO_SMALL;
+    }
+    // Keys are ASCII, so truncating at any byte keeps the output valid
+    let len = key.len().min(buf_len - 1);
+    let out = std::slice::from_raw_parts_mut(out_buf.cast::<u8>(), buf_len);
+    out[..len].copy_from_slice(&key.as_bytes()[..len]);
+    out[len] = 0;
+
+    if len < key.len() {
+        return ROUTE_QUERY_ERR_BUFFER_TOO_SMALL;
+    }
+    i32::try_from(len).unwrap_or(ROUTE_QUERY_ERR_BUFFER_TOO_SMALL)
+}
+
+/// Returns the number of routes in the VRF.
+///
+/// Returns 0 if no RouteOrch is registered.
+#[no_mangle]
+pub extern "C" fn route_orch_route_count(vrf_id: u64) -> u64 {
+    with_route_orch(|orch| orch.route_count(vrf_id)).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::REGISTRY_LOCK;
+    use std::collections::HashMap;
+    use std::ffi::CString;
+    use std::sync::MutexGuard;
+
+    type HasRouteFn = unsafe extern "C" fn(u64, *const c_char) -> bool;
+    type GetNhgKeyFn = unsafe extern "C" fn(u64, *const c_char, *mut c_char, usize) -> i32;
+    type RouteCountFn = extern "C" fn(u64) -> u64;
+
+    // Called through C function pointers, the way C++ sees them
+    const HAS_ROUTE: HasRouteFn = route_orch_has_route;
+    const GET_NHG_KEY: GetNhgKeyFn = route_orch_get_nhg_key;
+    const ROUTE_COUNT: RouteCountFn = route_orch_route_count;
+
+    const NHG_KEY: &str = "10.0.0.1@Ethernet0,10.0.0.3@Ethernet4";
+
+    #[derive(Default)]
+    struct MockRouteOrch {
+        routes: HashMap<(u64, IpPrefix), String>,
+    }
+
+    impl RouteQuery for MockRouteOrch {
+        fn has_route(&self, vrf_id: u64, prefix: &IpPrefix) -> bool {
+            self.nhg_key(vrf_id, prefix).is_some()
+        }
+
+        fn nhg_key(&self, vrf_id: u64, prefix: &IpPrefix) -> Option<String> {
+            self.routes.get(&(vrf_id, prefix.clone())).cloned()
+        }
+
+        fn route_count(&self, vrf_id: u64) -> u64 {
+            self.routes.keys().filter(|(vrf, _)| *vrf == vrf_id).count() as u64
+        }
+    }
+
+    fn registered() -> (MutexGuard<'static, ()>, Arc<Mutex<MockRouteOrch>>) {
+        let guard = lock(&REGISTRY_LOCK);
+        let mut orch = MockRouteOrch::default();
+        for (vrf_id, prefix, key) in [
+            (0, "10.1.0.0/24", NHG_KEY),
+            (0, "10.2.0.0/24", ""),
+            (7, "10.1.0.0/24", NHG_KEY),
+        ] {
+            orch.routes
+                .insert((vrf_id, prefix.parse().unwrap()), key.to_string());
+        }
+        let orch = Arc::new(Mutex::new(orch));
+        register_route_orch(orch.clone());
+        (guard, orch)
+    }
+
+    fn cstr(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn test_has_route_and_count() {
+        let (_guard, orch) = registered();
+        let prefix = cstr("10.1.0.0/24");
+
+        unsafe {
+            assert!(HAS_ROUTE(0, prefix.as_ptr()));
+            assert!(HAS_ROUTE(7, prefix.as_ptr()));
+            assert!(!HAS_ROUTE(8, prefix.as_ptr()));
+            assert!(!HAS_ROUTE(0, cstr("10.9.0.0/24").as_ptr()));
+        }
+        assert_eq!(ROUTE_COUNT(0), 2);
+        assert_eq!(ROUTE_COUNT(7), 1);
+
+        // The registry shares the orch, so later changes are visible
+        lock(&orch).routes.clear();
+        assert_eq!(ROUTE_COUNT(0), 0);
+
+        unregister_route_orch();
+    }
+
+    #[test]
+    fn test_get_nhg_key() {
+        let (_guard, _orch) = registered();
+        let prefix = cstr("10.1.0.0/24");
+        let mut buf = [0x7f as c_char; 64];
+
+        let written = unsafe { GET_NHG_KEY(0, prefix.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(written, NHG_KEY.len() as i32);
+        let key = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(key.to_str().unwrap(), NHG_KEY);
+
+        // Blackhole routes have an empty key
+        let blackhole = cstr("10.2.0.0/24");
+        let written = unsafe { GET_NHG_KEY(0, blackhole.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(written, 0);
+        assert_eq!(buf[0], 0);
+
+        unregister_route_orch();
+    }
+
+    #[test]
+    fn test_get_nhg_key_truncation() {
+        let (_guard, _orch) = registered();
+        let prefix = cstr("10.1.0.0/24");
+
+        // Exactly one byte short for the terminator
+        let mut buf = vec![0x7f as c_char; NHG_KEY.len()];
+        let code = unsafe { GET_NHG_KEY(0, prefix.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(code, ROUTE_QUERY_ERR_BUFFER_TOO_SMALL);
+        let key = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(key.to_str().unwrap(), &NHG_KEY[..NHG_KEY.len() - 1]);
+
+        let mut one = [0x7f as c_char; 1];
+        let code = unsafe { GET_NHG_KEY(0, prefix.as_ptr(), one.as_mut_ptr(), 1) };
+        assert_eq!(code, ROUTE_QUERY_ERR_BUFFER_TOO_SMALL);
+        assert_eq!(one[0], 0);
+
+        let code = unsafe { GET_NHG_KEY(0, prefix.as_ptr(), one.as_mut_ptr(), 0) };
+        assert_eq!(code, ROUTE_QUERY_ERR_BUFFER_TOO_SMALL);
+
+        unregister_route_orch();
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let (_guard, _orch) = registered();
+        let prefix = cstr("10.1.0.0/24");
+        let mut buf = [0 as c_char; 64];
+
+        unsafe {
+            assert!(!HAS_ROUTE(0, std::ptr::null()));
+            assert_eq!(
+                GET_NHG_KEY(0, std::ptr::null(), buf.as_mut_ptr(), buf.len()),
+                ROUTE_QUERY_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                GET_NHG_KEY(0, prefix.as_ptr(), std::ptr::null_mut(), buf.len()),
+                ROUTE_QUERY_ERR_NULL_POINTER
+            );
+        }
+
+        unregister_route_orch();
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let (_guard, _orch) = registered();
+        let invalid_utf8 = CString::new(vec![0x31, 0x30, 0xff, 0x2f, 0x38]).unwrap();
+        let not_a_prefix = cstr("Ethernet0");
+        let mut buf = [0x7f as c_char; 64];
+
+        unsafe {
+            assert!(!HAS_ROUTE(0, invalid_utf8.as_ptr()));
+            assert!(!HAS_ROUTE(0, not_a_prefix.as_ptr()));
+            assert_eq!(
+                GET_NHG_KEY(0, invalid_utf8.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                ROUTE_QUERY_ERR_INVALID_UTF8
+            );
+            assert_eq!(
+                GET_NHG_KEY(0, not_a_prefix.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                ROUTE_QUERY_ERR_INVALID_PREFIX
+            );
+            assert_eq!(
+                GET_NHG_KEY(0, cstr("10.9.0.0/24").as_ptr(), buf.as_mut_ptr(), buf.len()),
+                ROUTE_QUERY_ERR_NOT_FOUND
+            );
+        }
+        // Failures leave the buffer untouched
+        assert_eq!(buf[0], 0x7f);
+
+        unregister_route_orch();
+    }
+
+    #[test]
+    fn test_not_registered() {
+        let _guard = lock(&REGISTRY_LOCK);
+        unregister_route_orch();
+        let prefix = cstr("10.1.0.0/24");
+        let mut buf = [0 as c_char; 64];
+
+        unsafe {
+            assert!(!HAS_ROUTE(0, prefix.as_ptr()));
+            assert_eq!(
+                GET_NHG_KEY(0, prefix.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                ROUTE_QUERY_ERR_NOT_REGISTERED
+            );
+        }
+        assert_eq!(ROUTE_COUNT(0), 0);
+    }
+}
@@ -0,0 +1,13 @@
+//! Locking for the process-wide registries.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Locks a mutex, recovering from poisoning: a panic elsewhere must not
+/// turn into a panic across the FFI boundary.
+pub(crate) fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The registries are process wide, so tests using them take turns.
+#[cfg(test)]
+pub(crate) static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
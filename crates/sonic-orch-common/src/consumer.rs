@@ -1,5 +1,8 @@
 //! Consumer trait and implementations for Redis table consumption.
 
+use crate::keyspace::KeyspaceSource;
+use crate::metrics::ConsumerMetrics;
+use log::warn;
 use std::collections::{BTreeMap, VecDeque};
 
 /// Operation type from Redis.
@@ -73,6 +76,18 @@ impl KeyOpFieldsValues {
     }
 }
 
+/// How a Consumer learns about table changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConsumerMode {
+    /// Producer-pushed SET/DEL operations (ConsumerStateTable). A SET carries
+    /// only the fields written, so pending SETs are merged.
+    #[default]
+    StateTable,
+    /// Keyspace notifications on a table (SubscriberStateTable). Every SET
+    /// carries the full hash, so a later SET replaces a pending one.
+    Subscriber,
+}
+
 /// Configuration for a Consumer.
 #[derive(Debug, Clone)]
 pub struct ConsumerConfig {
@@ -82,6 +97,8 @@ pub struct ConsumerConfig {
     pub priority: i32,
     /// Pop batch size
     pub batch_size: usize,
+    /// How table changes are delivered
+    pub mode: ConsumerMode,
 }
 
 impl ConsumerConfig {
@@ -91,6 +108,7 @@ impl ConsumerConfig {
             table_name: table_name.into(),
             priority: 0,
             batch_size: 128,
+            mode: ConsumerMode::StateTable,
         }
     }
 
@@ -105,6 +123,12 @@ impl ConsumerConfig {
         self.batch_size = batch_size;
         self
     }
+
+    /// Sets the consumer mode.
+    pub fn with_mode(mut self, mode: ConsumerMode) -> Self {
+        self.mode = mode;
+        self
+    }
 }
 
 /// Consumer for Redis table entries.
@@ -120,7 +144,8 @@ impl ConsumerConfig {
 ///
 /// When multiple operations arrive for the same key:
 /// - Multiple DEL: Keep only the latest
-/// - Multiple SET: Merge field-values (newer overwrites older)
+/// - Multiple SET: Merge field-values (newer overwrites older); in
+///   [`ConsumerMode::Subscriber`] the newer SET replaces the older one
 /// - DEL then SET: Keep both (maintain ordering)
 pub struct Consumer {
     config: ConsumerConfig,
@@ -128,6 +153,8 @@ pub struct Consumer {
     to_sync: BTreeMap<String, VecDeque<KeyOpFieldsValues>>,
     /// Total count of pending entries
     pending_count: usize,
+    /// Highest pending count since the watermark was last cleared
+    high_watermark: usize,
 }
 
 impl Consumer {
@@ -137,6 +164,7 @@ impl Consumer {
             config,
             to_sync: BTreeMap::new(),
             pending_count: 0,
+            high_watermark: 0,
         }
     }
 
@@ -150,6 +178,11 @@ impl Consumer {
         self.config.priority
     }
 
+    /// Returns the consumer mode.
+    pub fn mode(&self) -> ConsumerMode {
+        self.config.mode
+    }
+
     /// Returns true if there are pending entries.
     pub fn has_pending(&self) -> bool {
         self.pending_count > 0
@@ -160,6 +193,40 @@ impl Consumer {
         self.pending_count
     }
 
+    /// Returns the number of pending entries.
+    pub fn len(&self) -> usize {
+        self.pending_count
+    }
+
+    /// Returns true if there are no pending entries.
+    pub fn is_empty(&self) -> bool {
+        self.pending_count == 0
+    }
+
+    /// Returns the highest pending count seen since the last
+    /// `clear_high_watermark()`. Draining does not lower it.
+    pub fn high_watermark(&self) -> usize {
+        self.high_watermark
+    }
+
+    /// Resets the high watermark to the current pending count.
+    pub fn clear_high_watermark(&mut self) {
+        self.high_watermark = self.pending_count;
+    }
+
+    /// Returns depth metrics for this consumer.
+    pub fn metrics(&self) -> ConsumerMetrics {
+        ConsumerMetrics {
+            table_name: self.config.table_name.clone(),
+            pending: self.pending_count,
+            high_watermark: self.high_watermark,
+        }
+    }
+
+    fn update_high_watermark(&mut self) {
+        self.high_watermark = self.high_watermark.max(self.pending_count);
+    }
+
     /// Adds entries to the sync queue with deduplication.
     ///
     /// This implements the C++ merging logic safely:
@@ -169,6 +236,7 @@ impl Consumer {
         for entry in entries {
             self.add_single_entry(entry);
         }
+        self.update_high_watermark();
     }
 
     fn add_single_entry(&mut self, entry: KeyOpFieldsValues) {
@@ -187,6 +255,11 @@ impl Consumer {
             Operation::Set => {
                 // SET merges with existing SET or appends
                 if let Some(last) = queue.back_mut() {
+                    if last.op == Operation::Set && self.config.mode == ConsumerMode::Subscriber {
+                        // Full hash: last write wins
+                        last.fvs = entry.fvs;
+                        return;
+                    }
                     if last.op == Operation::Set {
                         // Merge: newer values override
                         for (field, value) in entry.fvs {
@@ -223,6 +296,61 @@ impl Consumer {
         result
     }
 
+    /// Queues a SET for every key in the table.
+    ///
+    /// This is the initial full-table load of a subscriber-mode consumer.
+    /// Returns the number of entries queued.
+    pub fn load_table(&mut self, source: &impl KeyspaceSource) -> usize {
+        if !self.is_subscriber("table load") {
+            return 0;
+        }
+        let table = &self.config.table_name;
+        let entries: Vec<KeyOpFieldsValues> = source
+            .keys(table)
+            .into_iter()
+            .filter_map(|key| {
+                let fvs = source.get_all(table, &key)?;
+                Some(KeyOpFieldsValues::set(key, fvs))
+            })
+            .collect();
+        let count = entries.len();
+        self.add_to_sync(entries);
+        count
+    }
+
+    /// Takes the table's keyspace notifications and queues a SET with the
+    /// key's full hash, or a DEL if the key no longer exists, for each.
+    ///
+    /// Returns the number of notifications handled.
+    pub fn poll_keyspace(&mut self, source: &mut impl KeyspaceSource) -> usize {
+        if !self.is_subscriber("keyspace notifications") {
+            return 0;
+        }
+        let table = self.config.table_name.clone();
+        let entries: Vec<KeyOpFieldsValues> = source
+            .take_notifications(&table)
+            .into_iter()
+            .map(|key| match source.get_all(&table, &key) {
+                Some(fvs) if !fvs.is_empty() => KeyOpFieldsValues::set(key, fvs),
+                _ => KeyOpFieldsValues::del(key),
+            })
+            .collect();
+        let count = entries.len();
+        self.add_to_sync(entries);
+        count
+    }
+
+    fn is_subscriber(&self, what: &str) -> bool {
+        if self.config.mode != ConsumerMode::Subscriber {
+            warn!(
+                "Consumer {} is not in subscriber mode, ignoring {}",
+                self.config.table_name, what
+            );
+            return false;
+        }
+        true
+    }
+
     /// Peeks at pending entries without removing them.
     pub fn peek(&self) -> impl Iterator<Item = &KeyOpFieldsValues> {
         self.to_sync.values().flat_map(|q| q.iter())
@@ -236,6 +364,7 @@ impl Consumer {
         let queue = self.to_sync.entry(entry.key.clone()).or_default();
         queue.push_front(entry);
         self.pending_count += 1;
+        self.update_high_watermark();
     }
 
     /// Clears all pending entries.
@@ -265,6 +394,7 @@ impl Consumer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keyspace::InMemoryKeyspace;
 
     #[test]
     fn test_key_op_fields_values() {
@@ -367,6 +497,44 @@ mod tests {
         assert!(entries[1].op.is_set());
     }
 
+    #[test]
+    fn test_consumer_high_watermark_monotonic_within_drain_cycle() {
+        let mut consumer = Consumer::new(ConsumerConfig::new("ROUTE_TABLE"));
+        let set = |key: &str| KeyOpFieldsValues::set(key, vec![]);
+
+        consumer.add_to_sync(vec![
+            set("10.0.0.0/24"),
+            set("10.0.1.0/24"),
+            set("10.0.2.0/24"),
+        ]);
+        assert_eq!(consumer.len(), 3);
+        assert_eq!(consumer.high_watermark(), 3);
+
+        let mut last = consumer.high_watermark();
+        for entry in consumer.drain() {
+            assert!(consumer.high_watermark() >= last);
+            last = consumer.high_watermark();
+            consumer.retry(entry);
+            consumer.drain();
+        }
+        assert!(consumer.is_empty());
+        assert_eq!(consumer.high_watermark(), 3);
+
+        // A smaller burst does not lower the watermark
+        consumer.add_to_sync(vec![set("10.0.3.0/24")]);
+        assert_eq!(consumer.high_watermark(), 3);
+        consumer.clear();
+        assert_eq!(consumer.high_watermark(), 3);
+
+        // Only an explicit clear resets it
+        consumer.add_to_sync(vec![set("10.0.4.0/24")]);
+        consumer.clear_high_watermark();
+        assert_eq!(consumer.high_watermark(), 1);
+        assert_eq!(consumer.metrics().high_watermark, 1);
+        assert_eq!(consumer.metrics().pending, 1);
+        assert_eq!(consumer.metrics().table_name, "ROUTE_TABLE");
+    }
+
     #[test]
     fn test_consumer_retry() {
         let config = ConsumerConfig::new("PORT_TABLE");
@@ -377,4 +545,107 @@ mod tests {
 
         assert_eq!(consumer.pending_count(), 1);
     }
+
+    fn subscriber(table: &str) -> Consumer {
+        Consumer::new(ConsumerConfig::new(table).with_mode(ConsumerMode::Subscriber))
+    }
+
+    fn fields(entry: &KeyOpFieldsValues) -> Vec<(&str, &str)> {
+        let mut fvs: Vec<(&str, &str)> = entry
+            .fvs
+            .iter()
+            .map(|(f, v)| (f.as_str(), v.as_str()))
+            .collect();
+        fvs.sort();
+        fvs
+    }
+
+    #[test]
+    fn test_subscriber_initial_load() {
+        let mut keyspace = InMemoryKeyspace::new();
+        keyspace.hset("VLAN", "Vlan100", &[("vlanid", "100")]);
+        keyspace.hset("VLAN", "Vlan200", &[("vlanid", "200"), ("mtu", "9100")]);
+        keyspace.hset("PORT", "Ethernet0", &[("mtu", "9100")]);
+
+        let mut consumer = subscriber("VLAN");
+        assert_eq!(consumer.mode(), ConsumerMode::Subscriber);
+        assert_eq!(consumer.load_table(&keyspace), 2);
+
+        let entries = consumer.drain();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.op.is_set()));
+        assert_eq!(entries[1].key, "Vlan200");
+        assert_eq!(
+            fields(&entries[1]),
+            vec![("mtu", "9100"), ("vlanid", "200")]
+        );
+    }
+
+    #[test]
+    fn test_subscriber_rapid_set_del_set() {
+        let mut keyspace = InMemoryKeyspace::new();
+        let mut consumer = subscriber("VLAN");
+
+        // Each write seen separately: the DEL supersedes the first SET
+        keyspace.hset("VLAN", "Vlan100", &[("vlanid", "100")]);
+        consumer.poll_keyspace(&mut keyspace);
+        keyspace.del("VLAN", "Vlan100");
+        consumer.poll_keyspace(&mut keyspace);
+        keyspace.hset("VLAN", "Vlan100", &[("mtu", "1500")]);
+        consumer.poll_keyspace(&mut keyspace);
+
+        let entries = consumer.drain();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].op.is_del());
+        assert!(entries[1].op.is_set());
+        assert_eq!(fields(&entries[1]), vec![("mtu", "1500")]);
+
+        // All writes land before the poll: every read sees the final hash
+        keyspace.hset("VLAN", "Vlan100", &[("vlanid", "100")]);
+        keyspace.del("VLAN", "Vlan100");
+        keyspace.hset("VLAN", "Vlan100", &[("mtu", "9100")]);
+        assert_eq!(consumer.poll_keyspace(&mut keyspace), 3);
+
+        let entries = consumer.drain();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].op.is_set());
+        assert_eq!(fields(&entries[0]), vec![("mtu", "9100")]);
+
+        // Ending on a delete leaves a single DEL
+        keyspace.hset("VLAN", "Vlan100", &[("vlanid", "100")]);
+        keyspace.del("VLAN", "Vlan100");
+        consumer.poll_keyspace(&mut keyspace);
+        let entries = consumer.drain();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].op.is_del());
+    }
+
+    #[test]
+    fn test_subscriber_set_replaces_pending_set() {
+        let mut keyspace = InMemoryKeyspace::new();
+        let mut consumer = subscriber("PORT");
+
+        keyspace.hset("PORT", "Ethernet0", &[("mtu", "9100"), ("speed", "100000")]);
+        consumer.poll_keyspace(&mut keyspace);
+        keyspace.hdel("PORT", "Ethernet0", &["speed"]);
+        consumer.poll_keyspace(&mut keyspace);
+
+        // A removed field must not survive coalescing
+        assert_eq!(consumer.pending_count(), 1);
+        let entries = consumer.drain();
+        assert_eq!(fields(&entries[0]), vec![("mtu", "9100")]);
+    }
+
+    #[test]
+    fn test_state_table_ignores_keyspace() {
+        let mut keyspace = InMemoryKeyspace::new();
+        keyspace.hset("PORT", "Ethernet0", &[("mtu", "9100")]);
+
+        let mut consumer = Consumer::new(ConsumerConfig::new("PORT"));
+        assert_eq!(consumer.mode(), ConsumerMode::StateTable);
+        assert_eq!(consumer.load_table(&keyspace), 0);
+        assert_eq!(consumer.poll_keyspace(&mut keyspace), 0);
+        assert!(consumer.is_empty());
+        assert_eq!(keyspace.pending_notifications("PORT"), 1);
+    }
 }
@@ -0,0 +1,355 @@
+//! Typed parsing of field-value pairs.
+//!
+//! Orchs receive table entries as `Vec<(String, String)>`. Instead of
+//! matching field names by hand, a config type implements
+//! [`FromFieldValues`] with a [`FieldValueParser`], which converts each field
+//! to its typed value and collects every bad field before reporting.
+//!
+//! # Example
+//!
+//! ```
+//! use sonic_orch_common::{FieldErrors, FieldValueParser, FromFieldValues};
+//!
+//! struct QueueConfig {
+//!     weight: u32,
+//!     enabled: bool,
+//! }
+//!
+//! impl FromFieldValues for QueueConfig {
+//!     fn from_field_values(fvs: &[(String, String)]) -> Result<Self, FieldErrors> {
+//!         let mut parser = FieldValueParser::new(fvs);
+//!         let weight = parser.required("weight");
+//!         let enabled = parser.optional_bool("enabled").unwrap_or(true);
+//!         parser.finish()?;
+//!         Ok(Self {
+//!             weight: weight.unwrap_or_default(),
+//!             enabled,
+//!         })
+//!     }
+//! }
+//!
+//! let fvs = vec![("weight".to_string(), "x".to_string())];
+//! let err = QueueConfig::from_field_values(&fvs).err().unwrap();
+//! assert_eq!(err.fields(), vec!["weight"]);
+//! ```
+
+use crate::consumer::FieldValue;
+use std::fmt;
+use std::str::FromStr;
+
+/// A field whose value could not be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Field name.
+    pub field: String,
+    /// What was wrong with it.
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Every field error found while parsing one entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldErrors(pub Vec<FieldError>);
+
+impl FieldErrors {
+    /// Returns the names of the fields in error, in the order they were
+    /// checked.
+    pub fn fields(&self) -> Vec<&str> {
+        self.0.iter().map(|e| e.field.as_str()).collect()
+    }
+
+    /// Returns the number of errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FieldErrors {}
+
+/// Types that can be built from the field-value pairs of a table entry.
+pub trait FromFieldValues: Sized {
+    /// Parses the field-value pairs, reporting every bad field.
+    fn from_field_values(fvs: &[FieldValue]) -> Result<Self, FieldErrors>;
+}
+
+/// Parses a boolean written as `true`/`false`, `on`/`off`, `yes`/`no`,
+/// `enable`/`disable` or `1`/`0`.
+pub fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "on" | "yes" | "enable" | "enabled" | "1" => Ok(true),
+        "false" | "off" | "no" | "disable" | "disabled" | "0" => Ok(false),
+        _ => Err(format!("invalid boolean '{}'", value)),
+    }
+}
+
+/// Reads typed fields out of field-value pairs, collecting errors.
+///
+/// Each getter returns `None` when the field is absent or invalid; invalid
+/// values and missing required fields are recorded and reported together
+/// by [`finish`](Self::finish). Fields nobody asked for are ignored and can
+/// be listed with [`unknown_fields`](Self::unknown_fields). If a field
+/// appears more than once, the last value wins.
+#[derive(Debug)]
+pub struct FieldValueParser<'a> {
+    fvs: &'a [FieldValue],
+    used: Vec<bool>,
+    errors: Vec<FieldError>,
+}
+
+impl<'a> FieldValueParser<'a> {
+    /// Creates a parser over the given field-value pairs.
+    pub fn new(fvs: &'a [FieldValue]) -> Self {
+        Self {
+            fvs,
+            used: vec![false; fvs.len()],
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns the raw value of a field and marks it as known.
+    pub fn value(&mut self, field: &str) -> Option<&'a str> {
+        let mut found = None;
+        for (i, (f, v)) in self.fvs.iter().enumerate() {
+            if f == field {
+                self.used[i] = true;
+                found = Some(v.as_str());
+            }
+        }
+        found
+    }
+
+    /// Parses an optional field with `FromStr`.
+    pub fn optional<T>(&mut self, field: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.optional_with(field, |v| v.parse::<T>().map_err(|e| e.to_string()))
+    }
+
+    /// Parses a required field with `FromStr`.
+    pub fn required<T>(&mut self, field: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.required_with(field, |v| v.parse::<T>().map_err(|e| e.to_string()))
+    }
+
+    /// Parses an optional boolean field (see [`parse_bool`]).
+    pub fn optional_bool(&mut self, field: &str) -> Option<bool> {
+        self.optional_with(field, parse_bool)
+    }
+
+    /// Parses a required boolean field (see [`parse_bool`]).
+    pub fn required_bool(&mut self, field: &str) -> Option<bool> {
+        self.required_with(field, parse_bool)
+    }
+
+    /// Parses an optional field with a custom parser.
+    pub fn optional_with<T, F>(&mut self, field: &str, parse: F) -> Option<T>
+    where
+        F: FnOnce(&str) -> Result<T, String>,
+    {
+        let value = self.value(field)?;
+        match parse(value) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                self.error(field, format!("invalid value '{}': {}", value, e));
+                None
+            }
+        }
+    }
+
+    /// Parses a required field with a custom parser.
+    pub fn required_with<T, F>(&mut self, field: &str, parse: F) -> Option<T>
+    where
+        F: FnOnce(&str) -> Result<T, String>,
+    {
+        if self.fvs.iter().all(|(f, _)| f != field) {
+            self.error(field, "missing required field".to_string());
+            return None;
+        }
+        self.optional_with(field, parse)
+    }
+
+    /// Records an error for a field, e.g. from a cross-field check.
+    pub fn error(&mut self, field: &str, message: String) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message,
+        });
+    }
+
+    /// Returns the fields that were never asked for.
+    pub fn unknown_fields(&self) -> Vec<&'a str> {
+        self.fvs
+            .iter()
+            .zip(&self.used)
+            .filter(|(_, used)| !**used)
+            .map(|((f, _), _)| f.as_str())
+            .collect()
+    }
+
+    /// Returns every error recorded, or `Ok` if there were none.
+    pub fn finish(self) -> Result<(), FieldErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(FieldErrors(self.errors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sonic_types::{IpAddress, MacAddress};
+
+    fn fvs(pairs: &[(&str, &str)]) -> Vec<FieldValue> {
+        pairs
+            .iter()
+            .map(|(f, v)| (f.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    impl FromStr for Mode {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "fast" => Ok(Self::Fast),
+                "slow" => Ok(Self::Slow),
+                _ => Err(format!("unknown mode {}", s)),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct Session {
+        peer: IpAddress,
+        mac: Option<MacAddress>,
+        mtu: u32,
+        enabled: bool,
+        mode: Mode,
+    }
+
+    impl FromFieldValues for Session {
+        fn from_field_values(fvs: &[FieldValue]) -> Result<Self, FieldErrors> {
+            let mut parser = FieldValueParser::new(fvs);
+            let peer = parser.required("peer");
+            let mac = parser.optional("mac");
+            let mtu = parser.optional("mtu").unwrap_or(9100);
+            let enabled = parser.optional_bool("enabled").unwrap_or(false);
+            let mode = parser.optional("mode").unwrap_or(Mode::Slow);
+            parser.finish()?;
+            Ok(Self {
+                peer: peer.expect("required field checked by finish"),
+                mac,
+                mtu,
+                enabled,
+                mode,
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse_typed_fields() {
+        let session = Session::from_field_values(&fvs(&[
+            ("peer", "10.0.0.1"),
+            ("mac", "00:11:22:33:44:55"),
+            ("enabled", "on"),
+            ("mode", "fast"),
+        ]))
+        .unwrap();
+
+        assert_eq!(session.peer, "10.0.0.1".parse().unwrap());
+        assert_eq!(session.mac, Some("00:11:22:33:44:55".parse().unwrap()));
+        assert_eq!(session.mtu, 9100);
+        assert!(session.enabled);
+        assert_eq!(session.mode, Mode::Fast);
+    }
+
+    #[test]
+    fn test_unknown_fields_tolerated() {
+        let fvs = fvs(&[
+            ("peer", "fc00::1"),
+            ("description", "uplink"),
+            ("mtu", "1500"),
+            ("NULL", "NULL"),
+        ]);
+        let session = Session::from_field_values(&fvs).unwrap();
+        assert_eq!(session.mtu, 1500);
+
+        let mut parser = FieldValueParser::new(&fvs);
+        parser.required::<IpAddress>("peer");
+        parser.optional::<u32>("mtu");
+        assert_eq!(parser.unknown_fields(), vec!["description", "NULL"]);
+        assert!(parser.finish().is_ok());
+    }
+
+    #[test]
+    fn test_errors_aggregated() {
+        let err = Session::from_field_values(&fvs(&[
+            ("mac", "not-a-mac"),
+            ("mtu", "-1"),
+            ("enabled", "maybe"),
+            ("mode", "fast"),
+        ]))
+        .unwrap_err();
+
+        assert_eq!(err.fields(), vec!["peer", "mac", "mtu", "enabled"]);
+        assert_eq!(err.0[0].message, "missing required field");
+        assert!(err.0[3].message.starts_with("invalid value 'maybe'"));
+        assert!(err
+            .to_string()
+            .starts_with("peer: missing required field; mac: "));
+    }
+
+    #[test]
+    fn test_last_value_wins() {
+        let fvs = fvs(&[("mtu", "1500"), ("mtu", "9100")]);
+        let mut parser = FieldValueParser::new(&fvs);
+        assert_eq!(parser.optional::<u32>("mtu"), Some(9100));
+        assert!(parser.unknown_fields().is_empty());
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        for value in ["true", "on", "yes", "enable", "1", "TRUE"] {
+            assert_eq!(parse_bool(value), Ok(true));
+        }
+        for value in ["false", "off", "no", "disable", "0"] {
+            assert_eq!(parse_bool(value), Ok(false));
+        }
+        assert!(parse_bool("up").is_err());
+    }
+}
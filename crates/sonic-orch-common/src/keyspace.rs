@@ -0,0 +1,150 @@
+//! Keyspace notification sources for subscriber-mode consumers.
+//!
+//! A [`Consumer`](crate::Consumer) in [`ConsumerMode::Subscriber`](crate::ConsumerMode)
+//! mirrors the C++ SubscriberStateTable: it is told which keys of a table
+//! changed, reads the full hash of each, and synthesizes SET or DEL entries.
+//! [`KeyspaceSource`] abstracts where those notifications and hashes come
+//! from; [`InMemoryKeyspace`] is a fake for tests.
+
+use crate::consumer::FieldValue;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Table contents plus keyspace notifications for those tables.
+pub trait KeyspaceSource {
+    /// Returns every key currently in the table.
+    fn keys(&self, table: &str) -> Vec<String>;
+
+    /// Reads the full hash of a key, or `None` if the key does not exist.
+    fn get_all(&self, table: &str, key: &str) -> Option<Vec<FieldValue>>;
+
+    /// Takes the keys of the table notified since the last call, oldest
+    /// first. A key appears once per notification.
+    fn take_notifications(&mut self, table: &str) -> Vec<String>;
+}
+
+/// In-memory [`KeyspaceSource`] that records a notification for every write,
+/// like Redis keyspace events on CONFIG_DB.
+#[derive(Debug, Default)]
+pub struct InMemoryKeyspace {
+    tables: HashMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+    notifications: HashMap<String, VecDeque<String>>,
+}
+
+impl InMemoryKeyspace {
+    /// Creates an empty keyspace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets fields of a key, creating it if needed.
+    pub fn hset(&mut self, table: &str, key: &str, fvs: &[(&str, &str)]) {
+        let hash = self
+            .tables
+            .entry(table.to_string())
+            .or_default()
+            .entry(key.to_string())
+            .or_default();
+        for (field, value) in fvs {
+            hash.insert(field.to_string(), value.to_string());
+        }
+        self.notify(table, key);
+    }
+
+    /// Removes fields of a key. The key is deleted once it has no fields.
+    pub fn hdel(&mut self, table: &str, key: &str, fields: &[&str]) {
+        let Some(rows) = self.tables.get_mut(table) else {
+            return;
+        };
+        let Some(hash) = rows.get_mut(key) else {
+            return;
+        };
+        for field in fields {
+            hash.remove(*field);
+        }
+        if hash.is_empty() {
+            rows.remove(key);
+        }
+        self.notify(table, key);
+    }
+
+    /// Deletes a key.
+    pub fn del(&mut self, table: &str, key: &str) {
+        let removed = self
+            .tables
+            .get_mut(table)
+            .and_then(|rows| rows.remove(key))
+            .is_some();
+        if removed {
+            self.notify(table, key);
+        }
+    }
+
+    /// Returns the number of notifications not yet taken for a table.
+    pub fn pending_notifications(&self, table: &str) -> usize {
+        self.notifications.get(table).map_or(0, VecDeque::len)
+    }
+
+    fn notify(&mut self, table: &str, key: &str) {
+        self.notifications
+            .entry(table.to_string())
+            .or_default()
+            .push_back(key.to_string());
+    }
+}
+
+impl KeyspaceSource for InMemoryKeyspace {
+    fn keys(&self, table: &str) -> Vec<String> {
+        self.tables
+            .get(table)
+            .map(|rows| rows.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn get_all(&self, table: &str, key: &str) -> Option<Vec<FieldValue>> {
+        self.tables
+            .get(table)?
+            .get(key)
+            .map(|hash| hash.iter().map(|(f, v)| (f.clone(), v.clone())).collect())
+    }
+
+    fn take_notifications(&mut self, table: &str) -> Vec<String> {
+        self.notifications
+            .remove(table)
+            .map(Vec::from)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_keyspace() {
+        let mut keyspace = InMemoryKeyspace::new();
+        keyspace.hset("PORT", "Ethernet0", &[("mtu", "9100"), ("speed", "100000")]);
+        keyspace.hset("VLAN", "Vlan100", &[("vlanid", "100")]);
+        keyspace.hdel("PORT", "Ethernet0", &["speed"]);
+
+        assert_eq!(keyspace.keys("PORT"), vec!["Ethernet0".to_string()]);
+        assert_eq!(
+            keyspace.get_all("PORT", "Ethernet0"),
+            Some(vec![("mtu".to_string(), "9100".to_string())])
+        );
+        assert_eq!(keyspace.pending_notifications("PORT"), 2);
+        assert_eq!(
+            keyspace.take_notifications("PORT"),
+            vec!["Ethernet0".to_string(), "Ethernet0".to_string()]
+        );
+        assert!(keyspace.take_notifications("PORT").is_empty());
+
+        // Removing the last field deletes the key
+        keyspace.hdel("PORT", "Ethernet0", &["mtu"]);
+        assert_eq!(keyspace.get_all("PORT", "Ethernet0"), None);
+
+        // Deleting a missing key is not notified
+        keyspace.del("PORT", "Ethernet4");
+        assert_eq!(keyspace.pending_notifications("PORT"), 1);
+        assert_eq!(keyspace.pending_notifications("VLAN"), 1);
+    }
+}
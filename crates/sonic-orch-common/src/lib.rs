@@ -5,8 +5,15 @@
 //!
 //! - [`Orch`]: Base trait for orchestration agents
 //! - [`Consumer`]: Trait for consuming table entries from Redis
-//! - [`SyncMap`]: Type-safe map that prevents auto-vivification bugs
+//! - [`KeyspaceSource`]: Keyspace notifications for [`ConsumerMode::Subscriber`] consumers
+//! - [`FromFieldValues`]: Typed parsing of field-value pairs with aggregated errors
+//! - [`SyncMap`]: Type-safe map that prevents auto-vivification bugs, with an
+//!   optional change journal for warm-restart diffs
 //! - [`TaskStatus`]: Result type for task processing
+//! - [`OrchMetrics`]: Shared registry of per-Orch task latency and queue depth
+//! - [`Publisher`]: Change notifications between Orchs, e.g. [`NextHopUpdate`]
+//!   and [`VrfUpdate`]
+//! - [`RetryCache`]: Tasks parked on a [`Constraint`] until its resource is resolved
 //! - [`redis_backend`]: Redis database connectivity (feature-gated)
 //!
 //! # Architecture
@@ -46,6 +53,10 @@
 //! ```
 
 mod consumer;
+mod field_values;
+mod keyspace;
+mod metrics;
+mod observer;
 mod orch;
 mod retry;
 mod sync_map;
@@ -54,10 +65,16 @@ mod task;
 #[cfg(feature = "redis")]
 pub mod redis_backend;
 
-pub use consumer::{Consumer, ConsumerConfig, KeyOpFieldsValues, Operation};
+pub use consumer::{
+    Consumer, ConsumerConfig, ConsumerMode, FieldValue, KeyOpFieldsValues, Operation,
+};
+pub use field_values::{parse_bool, FieldError, FieldErrors, FieldValueParser, FromFieldValues};
+pub use keyspace::{InMemoryKeyspace, KeyspaceSource};
+pub use metrics::{ConsumerMetrics, OrchMetrics, OrchMetricsSnapshot, OrchTaskMetrics};
+pub use observer::{NextHopUpdate, Publisher, Subscription, VrfUpdate};
 pub use orch::{Orch, OrchContext};
 pub use retry::{Constraint, RetryCache};
-pub use sync_map::SyncMap;
+pub use sync_map::{ChangeKind, MapChanges, SyncMap};
 pub use task::{TaskResult, TaskStatus};
 
 #[cfg(feature = "redis")]
@@ -0,0 +1,159 @@
+//! Per-Orch instrumentation.
+//!
+//! [`OrchMetrics`] is a shared registry the OrchDaemon fills while it runs
+//! Orchs: `do_task()` wall time, pending depth of each Consumer and the size
+//! of each Orch's retry cache. Handles are cheap to clone, so a snapshot can
+//! be taken from outside the event loop (e.g. a signal handler).
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Pending depth of a single Consumer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ConsumerMetrics {
+    /// Table the Consumer reads from
+    pub table_name: String,
+    /// Entries currently pending
+    pub pending: usize,
+    /// Highest pending count since the watermark was last cleared
+    pub high_watermark: usize,
+}
+
+/// Metrics collected for one Orch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OrchTaskMetrics {
+    /// Number of `do_task()` calls
+    pub task_count: u64,
+    /// Total wall time spent in `do_task()`, in microseconds
+    pub total_task_time_us: u64,
+    /// Longest single `do_task()` call, in microseconds
+    pub max_task_time_us: u64,
+    /// Most recent `do_task()` call, in microseconds
+    pub last_task_time_us: u64,
+    /// Consumer depths keyed by table name
+    pub consumers: BTreeMap<String, ConsumerMetrics>,
+    /// Entries waiting in the retry cache
+    pub retry_cache_size: usize,
+}
+
+impl OrchTaskMetrics {
+    /// Returns the mean `do_task()` wall time in microseconds.
+    pub fn avg_task_time_us(&self) -> u64 {
+        self.total_task_time_us
+            .checked_div(self.task_count)
+            .unwrap_or(0)
+    }
+}
+
+/// Point-in-time copy of the registry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OrchMetricsSnapshot {
+    /// Metrics keyed by Orch name
+    pub orchs: BTreeMap<String, OrchTaskMetrics>,
+}
+
+/// Shared registry of per-Orch metrics.
+#[derive(Debug, Clone, Default)]
+pub struct OrchMetrics {
+    inner: Arc<Mutex<BTreeMap<String, OrchTaskMetrics>>>,
+}
+
+impl OrchMetrics {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_orch<R>(&self, orch: &str, f: impl FnOnce(&mut OrchTaskMetrics) -> R) -> R {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        f(inner.entry(orch.to_string()).or_default())
+    }
+
+    /// Records the wall time of one `do_task()` call.
+    pub fn record_task(&self, orch: &str, elapsed: Duration) {
+        let us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        self.with_orch(orch, |m| {
+            m.task_count += 1;
+            m.total_task_time_us = m.total_task_time_us.saturating_add(us);
+            m.max_task_time_us = m.max_task_time_us.max(us);
+            m.last_task_time_us = us;
+        });
+    }
+
+    /// Replaces the Consumer depths reported for an Orch.
+    pub fn record_consumers(&self, orch: &str, consumers: Vec<ConsumerMetrics>) {
+        self.with_orch(orch, |m| {
+            m.consumers = consumers
+                .into_iter()
+                .map(|c| (c.table_name.clone(), c))
+                .collect();
+        });
+    }
+
+    /// Records the current retry cache size of an Orch.
+    pub fn record_retry_cache_size(&self, orch: &str, size: usize) {
+        self.with_orch(orch, |m| m.retry_cache_size = size);
+    }
+
+    /// Returns a copy of all collected metrics.
+    pub fn snapshot(&self) -> OrchMetricsSnapshot {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        OrchMetricsSnapshot {
+            orchs: inner.clone(),
+        }
+    }
+
+    /// Discards all collected metrics.
+    pub fn clear(&self) {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_task_aggregates() {
+        let metrics = OrchMetrics::new();
+        metrics.record_task("RouteOrch", Duration::from_micros(100));
+        metrics.record_task("RouteOrch", Duration::from_micros(300));
+        metrics.record_task("PortsOrch", Duration::from_micros(50));
+
+        let snapshot = metrics.snapshot();
+        let route = &snapshot.orchs["RouteOrch"];
+        assert_eq!(route.task_count, 2);
+        assert_eq!(route.total_task_time_us, 400);
+        assert_eq!(route.max_task_time_us, 300);
+        assert_eq!(route.last_task_time_us, 300);
+        assert_eq!(route.avg_task_time_us(), 200);
+        assert_eq!(snapshot.orchs["PortsOrch"].task_count, 1);
+    }
+
+    #[test]
+    fn test_shared_handle_and_clear() {
+        let metrics = OrchMetrics::new();
+        let handle = metrics.clone();
+
+        metrics.record_consumers(
+            "RouteOrch",
+            vec![ConsumerMetrics {
+                table_name: "ROUTE_TABLE".to_string(),
+                pending: 5,
+                high_watermark: 12,
+            }],
+        );
+        metrics.record_retry_cache_size("RouteOrch", 3);
+
+        let snapshot = handle.snapshot();
+        let route = &snapshot.orchs["RouteOrch"];
+        assert_eq!(route.consumers["ROUTE_TABLE"].high_watermark, 12);
+        assert_eq!(route.retry_cache_size, 3);
+        assert_eq!(route.avg_task_time_us(), 0);
+
+        handle.clear();
+        assert!(metrics.snapshot().orchs.is_empty());
+    }
+}
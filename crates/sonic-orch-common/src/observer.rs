@@ -0,0 +1,235 @@
+//! Publish/subscribe channel for change notifications between Orchs.
+//!
+//! An Orch that owns some state (e.g. NeighOrch and its neighbors) holds a
+//! [`Publisher`]. Orchs that depend on that state take a [`Subscription`]
+//! from it and drain the queued events at the start of their `do_task`, so
+//! no Orch ever calls into another while it is borrowed.
+
+use sonic_types::MacAddress;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, Weak};
+
+type EventQueue<T> = Mutex<VecDeque<T>>;
+
+/// Sending side of a notification channel.
+///
+/// Every event is delivered to each live subscription. Subscriptions that
+/// have been dropped are pruned on the next publish.
+#[derive(Debug)]
+pub struct Publisher<T> {
+    subscribers: Mutex<Vec<Weak<EventQueue<T>>>>,
+}
+
+impl<T: Clone> Publisher<T> {
+    /// Creates a publisher with no subscribers.
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers a new subscriber. Only events published afterwards are seen.
+    pub fn subscribe(&self) -> Subscription<T> {
+        let queue = Arc::new(Mutex::new(VecDeque::new()));
+        self.lock().push(Arc::downgrade(&queue));
+        Subscription { queue }
+    }
+
+    /// Queues an event for every live subscriber and returns how many
+    /// received it.
+    pub fn publish(&self, event: T) -> usize {
+        let mut subscribers = self.lock();
+        subscribers.retain(|s| s.strong_count() > 0);
+        for queue in subscribers.iter().filter_map(Weak::upgrade) {
+            queue
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push_back(event.clone());
+        }
+        subscribers.len()
+    }
+
+    /// Returns the number of live subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.lock().iter().filter(|s| s.strong_count() > 0).count()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Weak<EventQueue<T>>>> {
+        self.subscribers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: Clone> Default for Publisher<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receiving side of a notification channel.
+///
+/// Dropping the subscription unsubscribes it.
+#[derive(Debug)]
+pub struct Subscription<T> {
+    queue: Arc<EventQueue<T>>,
+}
+
+impl<T> Subscription<T> {
+    /// Takes all queued events, oldest first.
+    pub fn drain(&self) -> Vec<T> {
+        self.lock().drain(..).collect()
+    }
+
+    /// Returns the number of queued events.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no events are queued.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<T>> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Resolution change of a neighbor next hop, published by NeighOrch.
+///
+/// A next hop is resolved while a neighbor entry exists for it. Routes and
+/// next hop groups use these events to stop forwarding to a next hop that
+/// went away and to pick it up again once it comes back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextHopUpdate {
+    /// The next hop, as `ip@alias`.
+    pub nh_key: String,
+    /// Whether the next hop is now resolved.
+    pub resolved: bool,
+    /// The neighbor MAC when resolved.
+    pub mac: Option<MacAddress>,
+}
+
+impl NextHopUpdate {
+    /// Returns the `ip@alias` key of a next hop.
+    pub fn key_for(ip: &(impl fmt::Display + ?Sized), alias: &str) -> String {
+        format!("{}@{}", ip, alias)
+    }
+
+    /// Creates an update for a next hop that has been resolved.
+    pub fn resolved(ip: &(impl fmt::Display + ?Sized), alias: &str, mac: MacAddress) -> Self {
+        Self {
+            nh_key: Self::key_for(ip, alias),
+            resolved: true,
+            mac: Some(mac),
+        }
+    }
+
+    /// Creates an update for a next hop that is no longer resolved.
+    pub fn unresolved(ip: &(impl fmt::Display + ?Sized), alias: &str) -> Self {
+        Self {
+            nh_key: Self::key_for(ip, alias),
+            resolved: false,
+            mac: None,
+        }
+    }
+
+    /// Returns true if the update is about the next hop `ip@alias`.
+    pub fn is_next_hop(&self, ip: &(impl fmt::Display + ?Sized), alias: &str) -> bool {
+        self.nh_key == Self::key_for(ip, alias)
+    }
+}
+
+/// Creation or removal of a VRF, published by VrfOrch.
+///
+/// Route tasks that reference a VRF before it exists are parked until the
+/// VRF is added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VrfUpdate {
+    /// The VRF name.
+    pub name: String,
+    /// Whether the VRF was added, as opposed to removed.
+    pub added: bool,
+}
+
+impl VrfUpdate {
+    /// Creates an update for a VRF that has been added.
+    pub fn added(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            added: true,
+        }
+    }
+
+    /// Creates an update for a VRF that has been removed.
+    pub fn removed(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            added: false,
+        }
+    }
+}
+
+impl fmt::Display for NextHopUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.resolved {
+            "resolved"
+        } else {
+            "unresolved"
+        };
+        write!(f, "{} {}", self.nh_key, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_reaches_every_subscriber() {
+        let publisher = Publisher::new();
+        let first = publisher.subscribe();
+        publisher.publish(1);
+        let second = publisher.subscribe();
+        publisher.publish(2);
+
+        assert_eq!(first.drain(), vec![1, 2]);
+        assert_eq!(second.drain(), vec![2]);
+        assert!(first.is_empty());
+    }
+
+    #[test]
+    fn test_dropped_subscription_is_pruned() {
+        let publisher = Publisher::new();
+        let kept = publisher.subscribe();
+        let dropped = publisher.subscribe();
+        assert_eq!(publisher.subscriber_count(), 2);
+
+        drop(dropped);
+        assert_eq!(publisher.subscriber_count(), 1);
+        assert_eq!(publisher.publish("event"), 1);
+        assert_eq!(kept.len(), 1);
+    }
+
+    #[test]
+    fn test_next_hop_update_key() {
+        let mac = MacAddress::new([0, 1, 2, 3, 4, 5]);
+        let update = NextHopUpdate::resolved("10.0.0.1", "Ethernet0", mac);
+        assert_eq!(update.nh_key, "10.0.0.1@Ethernet0");
+        assert!(update.is_next_hop("10.0.0.1", "Ethernet0"));
+        assert!(!update.is_next_hop("10.0.0.1", "Ethernet4"));
+        assert!(!update.is_next_hop("10.0.0.10", "Ethernet0"));
+
+        let update = NextHopUpdate::unresolved("fc00::1", "Vlan100");
+        assert!(update.is_next_hop("fc00::1", "Vlan100"));
+        assert_eq!(update.to_string(), "fc00::1@Vlan100 unresolved");
+    }
+
+    #[test]
+    fn test_vrf_update() {
+        let update = VrfUpdate::added("Vrf-red");
+        assert_eq!(update.name, "Vrf-red");
+        assert!(update.added);
+        assert!(!VrfUpdate::removed("Vrf-red").added);
+    }
+}
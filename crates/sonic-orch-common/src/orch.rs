@@ -2,6 +2,8 @@
 
 use async_trait::async_trait;
 
+use crate::{ConsumerMetrics, KeyOpFieldsValues};
+
 /// Context shared across all Orch modules.
 ///
 /// This provides access to shared state and coordination primitives
@@ -37,7 +39,8 @@ impl Default for OrchContext {
 /// 1. Construction: Orch is created with database connections
 /// 2. Registration: Orch registers its consumers with the daemon
 /// 3. Event Loop: `do_task()` is called when data is available
-/// 4. Warm Boot: `bake()` and `on_warm_boot_end()` handle state recovery
+/// 4. Warm Boot: `bake()`, `replay_tasks()`, `reconcile()` and
+///    `on_warm_boot_end()` handle state recovery
 /// 5. Shutdown: Orch is dropped (cleanup via Drop trait)
 ///
 /// # Thread Safety
@@ -78,6 +81,27 @@ pub trait Orch: Send + Sync {
         // Default: no-op
     }
 
+    /// Returns the APPL_DB tables replayed into this Orch on warm boot.
+    fn warm_restart_tables(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Receives APPL_DB entries replayed during warm boot.
+    ///
+    /// Called while consumer execution is frozen. The entries describe the
+    /// intended state and must not be programmed until `reconcile()`.
+    fn replay_tasks(&mut self, _table: &str, _tasks: Vec<KeyOpFieldsValues>) {
+        // Default: no-op
+    }
+
+    /// Reconciles restored state against the replayed intent.
+    ///
+    /// Implementations push only the differences to SAI. Returns `true`
+    /// once reconciliation is complete.
+    async fn reconcile(&mut self) -> bool {
+        true
+    }
+
     /// Returns the priority of this Orch (lower = higher priority).
     ///
     /// Orchs with lower priority values are processed first.
@@ -100,6 +124,19 @@ pub trait Orch: Send + Sync {
         vec![]
     }
 
+    /// Reports the pending depth of each of this Orch's consumers.
+    ///
+    /// Sampled by the daemon after each scheduling iteration; see
+    /// [`Consumer::metrics`](crate::Consumer::metrics).
+    fn consumer_metrics(&self) -> Vec<ConsumerMetrics> {
+        vec![]
+    }
+
+    /// Returns the number of entries parked in this Orch's retry cache.
+    fn retry_cache_size(&self) -> usize {
+        0
+    }
+
     /// Called periodically by the daemon's timer.
     ///
     /// Orchs can use this for periodic maintenance tasks.
@@ -170,6 +207,10 @@ mod tests {
         assert_eq!(orch.name(), "test");
         assert!(orch.has_pending_tasks());
         assert!(orch.bake());
+        assert!(orch.warm_restart_tables().is_empty());
+        assert!(orch.reconcile().await);
+        assert!(orch.consumer_metrics().is_empty());
+        assert_eq!(orch.retry_cache_size(), 0);
 
         orch.do_task().await;
         assert_eq!(orch.task_count, 1);
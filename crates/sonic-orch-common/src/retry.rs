@@ -2,6 +2,11 @@
 //!
 //! The retry cache tracks tasks that failed due to unmet dependencies
 //! and allows them to be retried when the dependency is satisfied.
+//!
+//! A task waits on one or more [`Constraint`]s, each naming a resource such
+//! as a neighbor, port or VRF. The Orch that owns the resource announces it
+//! with [`RetryCache::resolve`], which wakes only the tasks waiting on that
+//! resource; blocked tasks are never looked at by [`RetryCache::drain_ready`].
 
 use std::collections::{HashMap, HashSet};
 
@@ -15,6 +20,15 @@ pub struct Constraint {
 }
 
 impl Constraint {
+    /// Table of neighbor constraints.
+    pub const NEIGH_TABLE: &'static str = "NEIGH_TABLE";
+    /// Table of port constraints.
+    pub const PORT_TABLE: &'static str = "PORT_TABLE";
+    /// Table of VRF constraints.
+    pub const VRF_TABLE: &'static str = "VRF_TABLE";
+    /// Table of SAI resource constraints.
+    pub const RESOURCE_TABLE: &'static str = "RESOURCE_TABLE";
+
     /// Creates a new constraint.
     pub fn new(table: impl Into<String>, key: impl Into<String>) -> Self {
         Self {
@@ -23,6 +37,28 @@ impl Constraint {
         }
     }
 
+    /// Creates a constraint on a neighbor next hop, keyed as `ip@alias`
+    /// like [`NextHopUpdate`](crate::NextHopUpdate).
+    pub fn neighbor(nh_key: impl Into<String>) -> Self {
+        Self::new(Self::NEIGH_TABLE, nh_key)
+    }
+
+    /// Creates a constraint on a port.
+    pub fn port(alias: impl Into<String>) -> Self {
+        Self::new(Self::PORT_TABLE, alias)
+    }
+
+    /// Creates a constraint on a VRF.
+    pub fn vrf(name: impl Into<String>) -> Self {
+        Self::new(Self::VRF_TABLE, name)
+    }
+
+    /// Creates a constraint on a SAI resource, such as an object table that
+    /// rejected a create, which is resolved when space may have freed up.
+    pub fn resource(name: impl Into<String>) -> Self {
+        Self::new(Self::RESOURCE_TABLE, name)
+    }
+
     /// Creates a constraint from a "table:key" string.
     pub fn from_str(s: &str) -> Option<Self> {
         let (table, key) = s.split_once(':')?;
@@ -76,6 +112,8 @@ pub struct RetryCache<K, T> {
     entries: HashMap<K, RetryEntry<T>>,
     /// Reverse index: constraint -> keys waiting on it
     waiters: HashMap<Constraint, HashSet<K>>,
+    /// Keys of tasks with no constraints left
+    ready: HashSet<K>,
 }
 
 impl<K, T> RetryCache<K, T>
@@ -87,6 +125,7 @@ where
         Self {
             entries: HashMap::new(),
             waiters: HashMap::new(),
+            ready: HashSet::new(),
         }
     }
 
@@ -100,10 +139,23 @@ where
         self.entries.is_empty()
     }
 
+    /// Returns the number of tasks ready to retry.
+    pub fn ready_len(&self) -> usize {
+        self.ready.len()
+    }
+
     /// Adds a task to the retry cache with its constraints.
+    ///
+    /// A task without constraints is ready immediately. Adding a key that is
+    /// already cached replaces the task and its constraints.
     pub fn add(&mut self, key: K, data: T, constraints: impl IntoIterator<Item = Constraint>) {
+        self.remove(&key);
         let entry = RetryEntry::new(data, constraints);
 
+        if entry.is_ready() {
+            self.ready.insert(key.clone());
+        }
+
         // Update reverse index
         for constraint in &entry.constraints {
             self.waiters
@@ -118,6 +170,7 @@ where
     /// Removes a task from the cache.
     pub fn remove(&mut self, key: &K) -> Option<T> {
         if let Some(entry) = self.entries.remove(key) {
+            self.ready.remove(key);
             // Clean up reverse index
             for constraint in &entry.constraints {
                 if let Some(waiters) = self.waiters.get_mut(constraint) {
@@ -144,6 +197,7 @@ where
                 if let Some(entry) = self.entries.get_mut(&key) {
                     entry.satisfy(constraint);
                     if entry.is_ready() {
+                        self.ready.insert(key.clone());
                         ready.push(key);
                     }
                 }
@@ -153,21 +207,44 @@ where
         ready
     }
 
+    /// Announces that the resource behind a constraint is available.
+    ///
+    /// Only tasks waiting on the constraint are touched. Returns how many of
+    /// them are now ready to retry.
+    pub fn resolve(&mut self, constraint: &Constraint) -> usize {
+        self.satisfy(constraint).len()
+    }
+
     /// Returns all tasks that are ready to retry (no constraints).
+    ///
+    /// Tasks still waiting on a constraint are not visited.
     pub fn drain_ready(&mut self) -> Vec<(K, T)> {
-        let ready_keys: Vec<K> = self
-            .entries
-            .iter()
-            .filter(|(_, e)| e.is_ready())
-            .map(|(k, _)| k.clone())
-            .collect();
-
-        ready_keys
+        std::mem::take(&mut self.ready)
             .into_iter()
             .filter_map(|k| self.remove(&k).map(|data| (k, data)))
             .collect()
     }
 
+    /// Returns the number of tasks waiting on a constraint.
+    pub fn pending_count(&self, constraint: &Constraint) -> usize {
+        self.waiters.get(constraint).map_or(0, HashSet::len)
+    }
+
+    /// Returns the number of waiting tasks per constraint, most waited-on
+    /// first.
+    pub fn pending_counts(&self) -> Vec<(Constraint, usize)> {
+        let mut counts: Vec<(Constraint, usize)> = self
+            .waiters
+            .iter()
+            .map(|(c, keys)| (c.clone(), keys.len()))
+            .collect();
+        counts.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+        });
+        counts
+    }
+
     /// Returns true if the cache contains the given key.
     pub fn contains(&self, key: &K) -> bool {
         self.entries.contains_key(key)
@@ -182,6 +259,7 @@ where
     pub fn clear(&mut self) {
         self.entries.clear();
         self.waiters.clear();
+        self.ready.clear();
     }
 }
 
@@ -288,4 +366,83 @@ mod tests {
         assert_eq!(removed, Some("10.0.0.0/24".to_string()));
         assert!(cache.is_empty());
     }
+
+    #[test]
+    fn test_typed_constraints() {
+        assert_eq!(
+            Constraint::neighbor("10.0.0.1@Ethernet4").to_string(),
+            "NEIGH_TABLE:10.0.0.1@Ethernet4"
+        );
+        assert_eq!(Constraint::port("Ethernet8").table, Constraint::PORT_TABLE);
+        assert_eq!(
+            Constraint::vrf("Vrf_red"),
+            Constraint::new("VRF_TABLE", "Vrf_red")
+        );
+        assert_eq!(
+            Constraint::resource("ROUTE").to_string(),
+            "RESOURCE_TABLE:ROUTE"
+        );
+    }
+
+    #[test]
+    fn test_readd_replaces_constraints() {
+        let mut cache: RetryCache<String, u32> = RetryCache::new();
+        let port = Constraint::port("Ethernet0");
+
+        cache.add("route1".to_string(), 1, vec![port.clone()]);
+        cache.add("route1".to_string(), 2, std::iter::empty());
+        assert_eq!(cache.pending_count(&port), 0);
+        assert_eq!(cache.resolve(&port), 0);
+        assert_eq!(cache.drain_ready(), vec![("route1".to_string(), 2)]);
+
+        cache.add("route1".to_string(), 3, vec![port.clone()]);
+        assert_eq!(cache.ready_len(), 0);
+        assert!(cache.remove(&"route1".to_string()).is_some());
+        assert!(cache.pending_counts().is_empty());
+    }
+
+    #[test]
+    fn test_blocked_tasks_wait_for_resolve() {
+        let mut cache: RetryCache<u32, u32> = RetryCache::new();
+        let neighbor = Constraint::neighbor("10.0.0.1@Ethernet4");
+        let port = Constraint::port("Ethernet8");
+
+        for i in 0..10_000 {
+            cache.add(i, i, vec![neighbor.clone()]);
+        }
+        cache.add(10_000, 0, vec![port.clone(), neighbor.clone()]);
+        cache.add(10_001, 0, std::iter::empty());
+
+        assert_eq!(cache.pending_count(&neighbor), 10_001);
+        assert_eq!(
+            cache.pending_counts(),
+            vec![(neighbor.clone(), 10_001), (port.clone(), 1)]
+        );
+
+        // Only the unconstrained task is handed out, cycle after cycle
+        assert_eq!(cache.ready_len(), 1);
+        assert_eq!(cache.drain_ready(), vec![(10_001, 0)]);
+        for _ in 0..100 {
+            assert_eq!(cache.ready_len(), 0);
+            assert!(cache.drain_ready().is_empty());
+        }
+        assert_eq!(cache.len(), 10_001);
+
+        // Unrelated resources do not wake anything
+        assert_eq!(
+            cache.resolve(&Constraint::neighbor("10.0.0.2@Ethernet4")),
+            0
+        );
+        assert_eq!(cache.ready_len(), 0);
+
+        // The neighbor event wakes the tasks waiting only on it
+        assert_eq!(cache.resolve(&neighbor), 10_000);
+        assert_eq!(cache.pending_count(&neighbor), 0);
+        assert_eq!(cache.drain_ready().len(), 10_000);
+        assert_eq!(cache.pending_counts(), vec![(port.clone(), 1)]);
+
+        assert_eq!(cache.resolve(&port), 1);
+        assert_eq!(cache.drain_ready(), vec![(10_000, 0)]);
+        assert!(cache.is_empty());
+    }
 }
@@ -17,7 +17,14 @@
 //! - `get()` returns `Option<&V>`
 //! - `get_mut()` returns `Option<&mut V>`
 //! - `increment_ref()` returns `Result<u32, Error>`
+//!
+//! # Change Journal
+//!
+//! A map can optionally journal which keys were inserted, updated or removed
+//! since the journal was last taken, so an Orch can compute warm-restart
+//! diffs in `bake()`. Journaling is off by default and costs nothing then.
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::hash::Hash;
 use thiserror::Error;
@@ -32,6 +39,87 @@ pub enum SyncMapError {
     RefCountUnderflow,
 }
 
+/// Net change to a key since the journal was last taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The key did not exist before and exists now.
+    Inserted,
+    /// The key existed before and its value was replaced or modified.
+    Updated,
+    /// The key existed before and does not exist now.
+    Removed,
+}
+
+/// Keys changed since the journal was last taken, grouped by change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapChanges<K> {
+    /// Keys that were inserted.
+    pub inserted: Vec<K>,
+    /// Keys whose values changed.
+    pub updated: Vec<K>,
+    /// Keys that were removed.
+    pub removed: Vec<K>,
+}
+
+impl<K> MapChanges<K> {
+    /// Returns true if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    /// Returns the total number of changed keys.
+    pub fn len(&self) -> usize {
+        self.inserted.len() + self.updated.len() + self.removed.len()
+    }
+}
+
+impl<K> Default for MapChanges<K> {
+    fn default() -> Self {
+        Self {
+            inserted: Vec::new(),
+            updated: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+/// Folds a new change into the change already journaled for a key.
+///
+/// Returns `None` when the two cancel out (inserted, then removed).
+fn merge_change(previous: ChangeKind, next: ChangeKind) -> Option<ChangeKind> {
+    match (previous, next) {
+        (ChangeKind::Inserted, ChangeKind::Removed) => None,
+        (ChangeKind::Inserted, _) => Some(ChangeKind::Inserted),
+        (ChangeKind::Removed, ChangeKind::Inserted | ChangeKind::Updated) => {
+            Some(ChangeKind::Updated)
+        }
+        (_, next) => Some(next),
+    }
+}
+
+/// Journals a change to a key, if journaling is enabled.
+fn record_change<K>(journal: &mut Option<HashMap<K, ChangeKind>>, key: &K, kind: ChangeKind)
+where
+    K: Eq + Hash + Clone,
+{
+    let Some(journal) = journal.as_mut() else {
+        return;
+    };
+    match journal.entry(key.clone()) {
+        Entry::Vacant(e) => {
+            e.insert(kind);
+        }
+        Entry::Occupied(mut e) => match merge_change(*e.get(), kind) {
+            Some(merged) => {
+                e.insert(merged);
+            }
+            None => {
+                e.remove();
+            }
+        },
+    }
+}
+
 /// Trait for types that have a reference count.
 pub trait HasRefCount {
     /// Increments the reference count and returns the new value.
@@ -68,16 +156,19 @@ pub trait HasRefCount {
 #[derive(Debug, Clone)]
 pub struct SyncMap<K, V> {
     inner: HashMap<K, V>,
+    /// Net change per key since the journal was last taken, if enabled
+    journal: Option<HashMap<K, ChangeKind>>,
 }
 
 impl<K, V> SyncMap<K, V>
 where
-    K: Eq + Hash,
+    K: Eq + Hash + Clone,
 {
     /// Creates a new empty map.
     pub fn new() -> Self {
         Self {
             inner: HashMap::new(),
+            journal: None,
         }
     }
 
@@ -85,9 +176,55 @@ where
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: HashMap::with_capacity(capacity),
+            journal: None,
         }
     }
 
+    /// Enables the change journal.
+    pub fn with_journal(mut self) -> Self {
+        self.enable_journal();
+        self
+    }
+
+    /// Starts journaling changes. Does nothing if already enabled.
+    pub fn enable_journal(&mut self) {
+        self.journal.get_or_insert_with(HashMap::new);
+    }
+
+    /// Stops journaling changes and discards the journal.
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+
+    /// Returns true if changes are being journaled.
+    pub fn is_journaling(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    /// Takes the changes journaled since the last call.
+    ///
+    /// Changes made through `get_mut()`, `values_mut()` and the reference
+    /// count helpers are not journaled; use `modify()` for tracked updates.
+    /// Returns no changes if journaling is disabled.
+    pub fn take_changes(&mut self) -> MapChanges<K> {
+        let mut changes = MapChanges::default();
+        let Some(journal) = self.journal.as_mut() else {
+            return changes;
+        };
+        for (key, kind) in journal.drain() {
+            match kind {
+                ChangeKind::Inserted => changes.inserted.push(key),
+                ChangeKind::Updated => changes.updated.push(key),
+                ChangeKind::Removed => changes.removed.push(key),
+            }
+        }
+        changes
+    }
+
+    fn record(&mut self, key: &K, kind: ChangeKind) {
+        record_change(&mut self.journal, key, kind);
+    }
+
     /// Returns the number of entries in the map.
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -123,18 +260,55 @@ where
     ///
     /// Returns the old value if the key was already present.
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.journal.is_none() {
+            return self.inner.insert(key, value);
+        }
+        self.record_insert(&key);
         self.inner.insert(key, value)
     }
 
+    fn record_insert(&mut self, key: &K) {
+        let kind = if self.inner.contains_key(key) {
+            ChangeKind::Updated
+        } else {
+            ChangeKind::Inserted
+        };
+        self.record(key, kind);
+    }
+
     /// Removes a key from the map.
     ///
     /// Returns the removed value if the key was present.
     pub fn remove(&mut self, key: &K) -> Option<V> {
-        self.inner.remove(key)
+        let removed = self.inner.remove(key);
+        if removed.is_some() {
+            self.record(key, ChangeKind::Removed);
+        }
+        removed
+    }
+
+    /// Applies `f` to the value for the given key and returns its result.
+    ///
+    /// Returns `KeyNotFound` if the key is not present.
+    /// **This never creates entries.**
+    pub fn modify<R, F>(&mut self, key: &K, f: F) -> Result<R, SyncMapError>
+    where
+        F: FnOnce(&mut V) -> R,
+    {
+        let value = self.inner.get_mut(key).ok_or(SyncMapError::KeyNotFound)?;
+        let result = f(value);
+        self.record(key, ChangeKind::Updated);
+        Ok(result)
     }
 
     /// Clears all entries from the map.
     pub fn clear(&mut self) {
+        if self.journal.is_some() {
+            let keys: Vec<K> = self.inner.keys().cloned().collect();
+            for key in &keys {
+                self.record(key, ChangeKind::Removed);
+            }
+        }
         self.inner.clear();
     }
 
@@ -166,18 +340,42 @@ where
     where
         F: FnOnce() -> V,
     {
-        self.inner.entry(key).or_insert_with(f)
+        match self.inner.entry(key) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => {
+                record_change(&mut self.journal, e.key(), ChangeKind::Inserted);
+                e.insert(f())
+            }
+        }
     }
 
     /// Gets the value for a key, or inserts a default value if not present.
     pub fn get_or_insert(&mut self, key: K, value: V) -> &mut V {
-        self.inner.entry(key).or_insert(value)
+        self.get_or_insert_with(key, || value)
+    }
+
+    /// Gets the value for a key, or inserts the value returned by `f` if not
+    /// present.
+    ///
+    /// If `f` fails, nothing is inserted and its error is returned.
+    pub fn get_or_try_insert_with<E, F>(&mut self, key: K, f: F) -> Result<&mut V, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        match self.inner.entry(key) {
+            Entry::Occupied(e) => Ok(e.into_mut()),
+            Entry::Vacant(e) => {
+                let value = f()?;
+                record_change(&mut self.journal, e.key(), ChangeKind::Inserted);
+                Ok(e.insert(value))
+            }
+        }
     }
 }
 
 impl<K, V> SyncMap<K, V>
 where
-    K: Eq + Hash,
+    K: Eq + Hash + Clone,
     V: HasRefCount,
 {
     /// Increments the reference count for the given key.
@@ -214,7 +412,7 @@ where
 
 impl<K, V> Default for SyncMap<K, V>
 where
-    K: Eq + Hash,
+    K: Eq + Hash + Clone,
 {
     fn default() -> Self {
         Self::new()
@@ -228,6 +426,7 @@ where
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
         Self {
             inner: iter.into_iter().collect(),
+            journal: None,
         }
     }
 }
@@ -341,4 +540,150 @@ mod tests {
         let value = map.get_or_insert("key".to_string(), 100);
         assert_eq!(*value, 42); // Not 100
     }
+
+    #[test]
+    fn test_get_or_try_insert_with() {
+        let mut map: SyncMap<String, i32> = SyncMap::new().with_journal();
+
+        let result: Result<&mut i32, &str> =
+            map.get_or_try_insert_with("key".to_string(), || Err("no SAI object"));
+        assert_eq!(result, Err("no SAI object"));
+        assert!(map.is_empty());
+        assert!(map.take_changes().is_empty());
+
+        *map.get_or_try_insert_with("key".to_string(), || Ok::<_, ()>(1))
+            .unwrap() += 1;
+        // Existing entry: the closure is not called
+        let value = map
+            .get_or_try_insert_with("key".to_string(), || Err("unused"))
+            .unwrap();
+        assert_eq!(*value, 2);
+        assert_eq!(map.take_changes().inserted, vec!["key".to_string()]);
+    }
+
+    #[test]
+    fn test_modify() {
+        let mut map: SyncMap<String, RefCountedValue> = SyncMap::new();
+        assert!(matches!(
+            map.modify(&"missing".to_string(), |v| v.ref_count),
+            Err(SyncMapError::KeyNotFound)
+        ));
+        assert!(map.is_empty());
+
+        map.insert("key".to_string(), RefCountedValue::new("old"));
+        let len = map
+            .modify(&"key".to_string(), |v| {
+                v.data = "new".to_string();
+                v.data.len()
+            })
+            .unwrap();
+        assert_eq!(len, 3);
+        assert_eq!(map.get(&"key".to_string()).unwrap().data, "new");
+    }
+
+    #[test]
+    fn test_journal_after_mixed_operations() {
+        let mut map: SyncMap<String, i32> = SyncMap::new();
+        map.insert("existing".to_string(), 1);
+        map.insert("doomed".to_string(), 2);
+        map.insert("replaced".to_string(), 3);
+        map.enable_journal();
+        assert!(map.is_journaling());
+
+        map.insert("new".to_string(), 10);
+        map.modify(&"new".to_string(), |v| *v += 1).unwrap(); // still an insert
+        map.modify(&"existing".to_string(), |v| *v += 1).unwrap();
+        map.remove(&"doomed".to_string());
+        map.remove(&"replaced".to_string());
+        map.insert("replaced".to_string(), 30); // removed then re-added
+        map.insert("transient".to_string(), 0);
+        map.remove(&"transient".to_string()); // cancels out
+        map.get_or_insert("lazy".to_string(), 5);
+        map.get_or_insert("lazy".to_string(), 6); // already present
+        map.remove(&"missing".to_string()); // no-op
+
+        let mut changes = map.take_changes();
+        changes.inserted.sort();
+        changes.updated.sort();
+        assert_eq!(
+            changes.inserted,
+            vec!["lazy".to_string(), "new".to_string()]
+        );
+        assert_eq!(
+            changes.updated,
+            vec!["existing".to_string(), "replaced".to_string()]
+        );
+        assert_eq!(changes.removed, vec!["doomed".to_string()]);
+        assert_eq!(changes.len(), 5);
+
+        // Taking the journal starts a new cycle
+        assert!(map.take_changes().is_empty());
+        map.clear();
+        let mut changes = map.take_changes();
+        changes.removed.sort();
+        assert_eq!(changes.removed.len(), 4);
+        assert!(changes.inserted.is_empty());
+
+        map.disable_journal();
+        map.insert("after".to_string(), 1);
+        assert!(map.take_changes().is_empty());
+    }
+
+    mod alloc_count {
+        use std::alloc::{GlobalAlloc, Layout, System};
+        use std::cell::Cell;
+
+        thread_local! {
+            static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        /// Counts allocations made by the current thread.
+        pub struct CountingAllocator;
+
+        unsafe impl GlobalAlloc for CountingAllocator {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+                System.alloc(layout)
+            }
+
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                System.dealloc(ptr, layout)
+            }
+
+            unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+                let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+                System.realloc(ptr, layout, new_size)
+            }
+        }
+
+        /// Returns the number of allocations `f` made on this thread.
+        pub fn count(f: impl FnOnce()) -> usize {
+            let before = ALLOCATIONS.with(Cell::get);
+            f();
+            ALLOCATIONS.with(Cell::get) - before
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: alloc_count::CountingAllocator = alloc_count::CountingAllocator;
+
+    fn hot_path(map: &mut SyncMap<u32, u32>) {
+        for key in 0..64 {
+            map.insert(key, key);
+            map.modify(&key, |v| *v += 1).unwrap();
+            map.remove(&key);
+            *map.get_or_insert_with(key, || 0) += 1;
+        }
+    }
+
+    #[test]
+    fn test_journal_disabled_does_not_allocate() {
+        let mut map: SyncMap<u32, u32> = SyncMap::with_capacity(128);
+        hot_path(&mut map);
+        assert_eq!(alloc_count::count(|| hot_path(&mut map)), 0);
+
+        // The same work does allocate once journaled
+        map.enable_journal();
+        assert!(alloc_count::count(|| hot_path(&mut map)) > 0);
+    }
 }
@@ -0,0 +1,194 @@
+//! Safe wrapper for SAI ACL API.
+//!
+//! This module currently covers the ACL capability query used to find out
+//! which table match fields and actions an ASIC implements before a table is
+//! created.
+
+use std::collections::HashSet;
+
+use crate::error::{SaiError, SaiResult};
+use crate::types::SwitchOid;
+
+/// ACL pipeline stage (`sai_acl_stage_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclStage {
+    /// Ingress stage
+    Ingress,
+    /// Egress stage
+    Egress,
+}
+
+impl AclStage {
+    /// Returns the `sai_acl_stage_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::Ingress => 0,
+            Self::Egress => 1,
+        }
+    }
+}
+
+/// ACL bind point type (`sai_acl_bind_point_type_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclBindPoint {
+    /// Port
+    Port,
+    /// LAG
+    Lag,
+    /// VLAN
+    Vlan,
+    /// Router interface
+    RouterInterface,
+    /// Switch
+    Switch,
+}
+
+impl AclBindPoint {
+    /// Returns the `sai_acl_bind_point_type_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::Port => 0,
+            Self::Lag => 1,
+            Self::Vlan => 2,
+            Self::RouterInterface => 3,
+            Self::Switch => 4,
+        }
+    }
+}
+
+/// ACL entry packet action (`sai_packet_action_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AclPacketAction {
+    /// Drop the packet
+    Drop,
+    /// Forward the packet
+    #[default]
+    Forward,
+    /// Copy the packet to CPU and forward
+    Copy,
+    /// Cancel a copy to CPU made by an earlier stage
+    CopyCancel,
+    /// Send to CPU and drop
+    Trap,
+    /// Forward and copy to CPU
+    Log,
+    /// Drop, even if a later stage forwards
+    Deny,
+    /// Forward, unless a later stage drops
+    Transit,
+}
+
+impl AclPacketAction {
+    /// Returns the `sai_packet_action_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::Drop => 0,
+            Self::Forward => 1,
+            Self::Copy => 2,
+            Self::CopyCancel => 3,
+            Self::Trap => 4,
+            Self::Log => 5,
+            Self::Deny => 6,
+            Self::Transit => 7,
+        }
+    }
+}
+
+/// ACL table capability reported by the ASIC for one stage and bind point.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AclCapability {
+    /// Implemented table match fields (`SAI_ACL_TABLE_ATTR_FIELD_*` IDs).
+    pub match_fields: HashSet<i32>,
+    /// Implemented action types (`sai_acl_action_type_t` values).
+    pub action_types: HashSet<i32>,
+    /// Whether the action type list must be supplied on table creation.
+    pub action_list_mandatory: bool,
+}
+
+impl AclCapability {
+    /// Returns true if the given table match field attribute is implemented.
+    pub fn supports_match_field(&self, attr_id: i32) -> bool {
+        self.match_fields.contains(&attr_id)
+    }
+
+    /// Returns true if the given action type is implemented.
+    pub fn supports_action_type(&self, action_type: i32) -> bool {
+        self.action_types.contains(&action_type)
+    }
+}
+
+/// Safe wrapper for SAI ACL API.
+pub struct AclApi {
+    switch_id: SwitchOid,
+    // When FFI is enabled:
+    // api: *const sai_acl_api_t,
+}
+
+impl AclApi {
+    /// Creates a new AclApi instance.
+    pub fn new(switch_id: SwitchOid) -> Self {
+        Self { switch_id }
+    }
+
+    /// Returns the switch ID this API is associated with.
+    pub fn switch_id(&self) -> SwitchOid {
+        self.switch_id
+    }
+
+    /// Queries which ACL table match fields and actions the ASIC implements
+    /// for a stage and bind point.
+    ///
+    /// Match fields come from `sai_query_attribute_capability()` on each
+    /// `SAI_ACL_TABLE_ATTR_FIELD_*` attribute, and actions from
+    /// `SAI_SWITCH_ATTR_ACL_STAGE_INGRESS/EGRESS`.
+    pub fn query_capability(
+        &self,
+        stage: AclStage,
+        bind_point: AclBindPoint,
+    ) -> SaiResult<AclCapability> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_query_attribute_capability() and
+        // sai_switch_api->get_switch_attribute()
+        let _ = (stage, bind_point);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acl_api_null_validation() {
+        let api = AclApi::new(SwitchOid::NULL);
+        assert!(api
+            .query_capability(AclStage::Ingress, AclBindPoint::Port)
+            .is_err());
+    }
+
+    #[test]
+    fn test_acl_enum_values() {
+        assert_eq!(AclStage::Ingress.to_sai(), 0);
+        assert_eq!(AclStage::Egress.to_sai(), 1);
+        assert_eq!(AclBindPoint::Port.to_sai(), 0);
+        assert_eq!(AclBindPoint::Switch.to_sai(), 4);
+        assert_eq!(AclPacketAction::Drop.to_sai(), 0);
+        assert_eq!(AclPacketAction::Transit.to_sai(), 7);
+    }
+
+    #[test]
+    fn test_acl_capability_lookup() {
+        let cap = AclCapability {
+            match_fields: [4096].into(),
+            action_types: [0].into(),
+            action_list_mandatory: true,
+        };
+        assert!(cap.supports_match_field(4096));
+        assert!(!cap.supports_match_field(4097));
+        assert!(cap.supports_action_type(0));
+        assert!(!cap.supports_action_type(1));
+    }
+}
@@ -0,0 +1,465 @@
+//! Typed attribute list builders.
+//!
+//! SAI create and set calls take a `sai_attribute_t` array whose list
+//! values point into caller-owned memory. The builders here collect typed
+//! attributes for one object type and own every list they reference, so the
+//! slice returned by `finish()` stays valid for as long as the builder is
+//! borrowed. Moving a builder does not move list contents, which live on the
+//! heap.
+//!
+//! # Example
+//!
+//! ```
+//! use sonic_sai::api::{FecMode, PortAttrBuilder};
+//!
+//! let attrs = PortAttrBuilder::new()
+//!     .lanes(&[0, 1, 2, 3])
+//!     .speed(100_000)
+//!     .fec(FecMode::Rs)
+//!     .admin_state(true);
+//! assert_eq!(attrs.finish().len(), 4);
+//! ```
+
+use crate::attribute::{SaiAttribute, SaiAttributeValue};
+use crate::types::RawSaiObjectId;
+
+use super::acl::AclPacketAction;
+use super::port::FecMode;
+use super::route::RouteAction;
+
+/// Attribute list shared by the builders.
+///
+/// Setting an attribute twice replaces the earlier value, so each ID appears
+/// at most once in the list handed to SAI.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct AttrList {
+    attrs: Vec<SaiAttribute>,
+}
+
+impl AttrList {
+    fn set(&mut self, id: i32, value: SaiAttributeValue) {
+        match self.attrs.iter_mut().find(|attr| attr.id == id) {
+            Some(attr) => attr.value = value,
+            None => self.attrs.push(SaiAttribute::new(id, value)),
+        }
+    }
+
+    fn get(&self, id: i32) -> Option<&SaiAttributeValue> {
+        self.attrs
+            .iter()
+            .find(|attr| attr.id == id)
+            .map(|attr| &attr.value)
+    }
+}
+
+macro_rules! attr_list_accessors {
+    ($builder:ty) => {
+        impl $builder {
+            /// Returns the attributes in the order they were first set.
+            ///
+            /// The slice and any list it contains borrow from the builder and
+            /// must not outlive it.
+            pub fn finish(&self) -> &[SaiAttribute] {
+                &self.list.attrs
+            }
+
+            /// Returns the value set for an attribute ID.
+            pub fn get(&self, id: i32) -> Option<&SaiAttributeValue> {
+                self.list.get(id)
+            }
+
+            /// Returns the number of attributes.
+            pub fn len(&self) -> usize {
+                self.list.attrs.len()
+            }
+
+            /// Returns true if no attribute has been set.
+            pub fn is_empty(&self) -> bool {
+                self.list.attrs.is_empty()
+            }
+
+            /// Consumes the builder, returning the owned attributes.
+            pub fn into_attrs(self) -> Vec<SaiAttribute> {
+                self.list.attrs
+            }
+        }
+    };
+}
+
+/// Attributes of a port object (`SAI_OBJECT_TYPE_PORT`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortAttrBuilder {
+    list: AttrList,
+}
+
+impl PortAttrBuilder {
+    /// SAI_PORT_ATTR_HW_LANE_LIST
+    pub const HW_LANE_LIST: i32 = 0x1d;
+    /// SAI_PORT_ATTR_SPEED
+    pub const SPEED: i32 = 0x1e;
+    /// SAI_PORT_ATTR_AUTO_NEG_MODE
+    pub const AUTO_NEG_MODE: i32 = 0x20;
+    /// SAI_PORT_ATTR_ADMIN_STATE
+    pub const ADMIN_STATE: i32 = 0x21;
+    /// SAI_PORT_ATTR_ADVERTISED_SPEED
+    pub const ADVERTISED_SPEED: i32 = 0x23;
+    /// SAI_PORT_ATTR_FEC_MODE
+    pub const FEC_MODE: i32 = 0x24;
+    /// SAI_PORT_ATTR_MTU
+    pub const MTU: i32 = 0x4c;
+    /// SAI_PORT_ATTR_INTERFACE_TYPE
+    pub const INTERFACE_TYPE: i32 = 0x8e;
+    /// SAI_PORT_ATTR_ADVERTISED_INTERFACE_TYPE
+    pub const ADVERTISED_INTERFACE_TYPE: i32 = 0x8f;
+
+    /// Creates an empty attribute list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the hardware lanes (create only).
+    pub fn lanes(mut self, lanes: &[u32]) -> Self {
+        self.list.set(
+            Self::HW_LANE_LIST,
+            SaiAttributeValue::U32List(lanes.to_vec()),
+        );
+        self
+    }
+
+    /// Sets the speed in Mbps.
+    pub fn speed(mut self, mbps: u32) -> Self {
+        self.list.set(Self::SPEED, SaiAttributeValue::U32(mbps));
+        self
+    }
+
+    /// Sets the admin state.
+    pub fn admin_state(mut self, up: bool) -> Self {
+        self.list
+            .set(Self::ADMIN_STATE, SaiAttributeValue::Bool(up));
+        self
+    }
+
+    /// Sets the MTU in bytes.
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.list.set(Self::MTU, SaiAttributeValue::U32(mtu));
+        self
+    }
+
+    /// Sets the FEC mode.
+    ///
+    /// [`FecMode::Auto`] has no SAI value; the attribute is left unset so the
+    /// ASIC keeps its negotiated default.
+    pub fn fec(mut self, mode: FecMode) -> Self {
+        if let Some(value) = mode.to_sai() {
+            self.list.set(Self::FEC_MODE, SaiAttributeValue::I32(value));
+        }
+        self
+    }
+
+    /// Enables or disables auto-negotiation.
+    pub fn auto_neg(mut self, enabled: bool) -> Self {
+        self.list
+            .set(Self::AUTO_NEG_MODE, SaiAttributeValue::Bool(enabled));
+        self
+    }
+
+    /// Sets the speeds advertised during auto-negotiation, in Mbps.
+    pub fn advertised_speeds(mut self, speeds: &[u32]) -> Self {
+        self.list.set(
+            Self::ADVERTISED_SPEED,
+            SaiAttributeValue::U32List(speeds.to_vec()),
+        );
+        self
+    }
+
+    /// Sets the interface type (`sai_port_interface_type_t`).
+    pub fn interface_type(mut self, interface_type: i32) -> Self {
+        self.list
+            .set(Self::INTERFACE_TYPE, SaiAttributeValue::I32(interface_type));
+        self
+    }
+
+    /// Sets the interface types advertised during auto-negotiation.
+    pub fn advertised_interface_types(mut self, types: &[i32]) -> Self {
+        self.list.set(
+            Self::ADVERTISED_INTERFACE_TYPE,
+            SaiAttributeValue::I32List(types.to_vec()),
+        );
+        self
+    }
+}
+
+attr_list_accessors!(PortAttrBuilder);
+
+/// Attributes of an ACL entry (`SAI_OBJECT_TYPE_ACL_ENTRY`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AclEntryAttrBuilder {
+    list: AttrList,
+}
+
+impl AclEntryAttrBuilder {
+    /// SAI_ACL_ENTRY_ATTR_TABLE_ID
+    pub const TABLE_ID: i32 = 0x0;
+    /// SAI_ACL_ENTRY_ATTR_PRIORITY
+    pub const PRIORITY: i32 = 0x1;
+    /// SAI_ACL_ENTRY_ATTR_ADMIN_STATE
+    pub const ADMIN_STATE: i32 = 0x2;
+    /// SAI_ACL_ENTRY_ATTR_FIELD_IN_PORTS
+    pub const FIELD_IN_PORTS: i32 = 0x1007;
+    /// SAI_ACL_ENTRY_ATTR_ACTION_REDIRECT
+    pub const ACTION_REDIRECT: i32 = 0x2000;
+    /// SAI_ACL_ENTRY_ATTR_ACTION_PACKET_ACTION
+    pub const ACTION_PACKET_ACTION: i32 = 0x2003;
+    /// SAI_ACL_ENTRY_ATTR_ACTION_COUNTER
+    pub const ACTION_COUNTER: i32 = 0x2005;
+    /// SAI_ACL_ENTRY_ATTR_ACTION_MIRROR_INGRESS
+    pub const ACTION_MIRROR_INGRESS: i32 = 0x2006;
+    /// SAI_ACL_ENTRY_ATTR_ACTION_MIRROR_EGRESS
+    pub const ACTION_MIRROR_EGRESS: i32 = 0x2007;
+
+    /// Creates an empty attribute list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the ACL table the entry belongs to (create only).
+    pub fn table_id(mut self, table: RawSaiObjectId) -> Self {
+        self.list
+            .set(Self::TABLE_ID, SaiAttributeValue::ObjectId(table));
+        self
+    }
+
+    /// Sets the entry priority.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.list
+            .set(Self::PRIORITY, SaiAttributeValue::U32(priority));
+        self
+    }
+
+    /// Enables or disables the entry.
+    pub fn admin_state(mut self, enabled: bool) -> Self {
+        self.list
+            .set(Self::ADMIN_STATE, SaiAttributeValue::Bool(enabled));
+        self
+    }
+
+    /// Matches packets received on any of the given ports.
+    pub fn in_ports(mut self, ports: &[RawSaiObjectId]) -> Self {
+        self.list.set(
+            Self::FIELD_IN_PORTS,
+            SaiAttributeValue::ObjectList(ports.to_vec()),
+        );
+        self
+    }
+
+    /// Sets the packet action.
+    pub fn packet_action(mut self, action: AclPacketAction) -> Self {
+        self.list.set(
+            Self::ACTION_PACKET_ACTION,
+            SaiAttributeValue::I32(action.to_sai()),
+        );
+        self
+    }
+
+    /// Redirects matching packets to a port, LAG, next hop or group.
+    pub fn redirect(mut self, target: RawSaiObjectId) -> Self {
+        self.list
+            .set(Self::ACTION_REDIRECT, SaiAttributeValue::ObjectId(target));
+        self
+    }
+
+    /// Attaches an ACL counter.
+    pub fn counter(mut self, counter: RawSaiObjectId) -> Self {
+        self.list
+            .set(Self::ACTION_COUNTER, SaiAttributeValue::ObjectId(counter));
+        self
+    }
+
+    /// Mirrors matching ingress packets to the given sessions.
+    pub fn mirror_ingress(mut self, sessions: &[RawSaiObjectId]) -> Self {
+        self.list.set(
+            Self::ACTION_MIRROR_INGRESS,
+            SaiAttributeValue::ObjectList(sessions.to_vec()),
+        );
+        self
+    }
+
+    /// Mirrors matching egress packets to the given sessions.
+    pub fn mirror_egress(mut self, sessions: &[RawSaiObjectId]) -> Self {
+        self.list.set(
+            Self::ACTION_MIRROR_EGRESS,
+            SaiAttributeValue::ObjectList(sessions.to_vec()),
+        );
+        self
+    }
+}
+
+attr_list_accessors!(AclEntryAttrBuilder);
+
+/// Attributes of a route entry (`SAI_OBJECT_TYPE_ROUTE_ENTRY`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteAttrBuilder {
+    list: AttrList,
+}
+
+impl RouteAttrBuilder {
+    /// SAI_ROUTE_ENTRY_ATTR_PACKET_ACTION
+    pub const PACKET_ACTION: i32 = 0x0;
+    /// SAI_ROUTE_ENTRY_ATTR_NEXT_HOP_ID
+    pub const NEXT_HOP_ID: i32 = 0x2;
+    /// SAI_ROUTE_ENTRY_ATTR_META_DATA
+    pub const META_DATA: i32 = 0x3;
+    /// SAI_ROUTE_ENTRY_ATTR_COUNTER_ID
+    pub const COUNTER_ID: i32 = 0x5;
+
+    /// Creates an empty attribute list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the packet action.
+    pub fn packet_action(mut self, action: RouteAction) -> Self {
+        self.list
+            .set(Self::PACKET_ACTION, SaiAttributeValue::I32(action.to_sai()));
+        self
+    }
+
+    /// Sets the next hop, next-hop group, router interface or CPU port.
+    pub fn next_hop(mut self, next_hop: RawSaiObjectId) -> Self {
+        self.list
+            .set(Self::NEXT_HOP_ID, SaiAttributeValue::ObjectId(next_hop));
+        self
+    }
+
+    /// Sets the user metadata used by ACL lookups.
+    pub fn meta_data(mut self, meta_data: u32) -> Self {
+        self.list
+            .set(Self::META_DATA, SaiAttributeValue::U32(meta_data));
+        self
+    }
+
+    /// Attaches a route counter.
+    pub fn counter(mut self, counter: RawSaiObjectId) -> Self {
+        self.list
+            .set(Self::COUNTER_ID, SaiAttributeValue::ObjectId(counter));
+        self
+    }
+}
+
+attr_list_accessors!(RouteAttrBuilder);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_builder_lists() {
+        let lanes = vec![4, 5, 6, 7];
+        let builder = PortAttrBuilder::new()
+            .lanes(&lanes)
+            .speed(400_000)
+            .advertised_speeds(&[100_000, 200_000, 400_000])
+            .advertised_interface_types(&[3, 6]);
+        drop(lanes);
+
+        let attrs = builder.finish();
+        assert_eq!(attrs.len(), 4);
+        assert_eq!(attrs[0].id, PortAttrBuilder::HW_LANE_LIST);
+        assert_eq!(attrs[0].value, SaiAttributeValue::U32List(vec![4, 5, 6, 7]));
+        assert_eq!(attrs[2].value.list_len(), Some(3));
+        assert_eq!(
+            builder.get(PortAttrBuilder::ADVERTISED_INTERFACE_TYPE),
+            Some(&SaiAttributeValue::I32List(vec![3, 6]))
+        );
+    }
+
+    #[test]
+    fn test_port_builder_replaces_and_skips_auto_fec() {
+        let builder = PortAttrBuilder::new()
+            .admin_state(false)
+            .fec(FecMode::Rs)
+            .mtu(9100)
+            .admin_state(true)
+            .fec(FecMode::Auto);
+
+        // Re-setting keeps the first position; FEC auto leaves RS in place
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.finish()[0].value, SaiAttributeValue::Bool(true));
+        assert_eq!(
+            builder.get(PortAttrBuilder::FEC_MODE),
+            Some(&SaiAttributeValue::I32(1))
+        );
+
+        assert!(PortAttrBuilder::new().fec(FecMode::Auto).is_empty());
+    }
+
+    #[test]
+    fn test_acl_entry_builder() {
+        let builder = AclEntryAttrBuilder::new()
+            .table_id(0x7000)
+            .priority(100)
+            .admin_state(true)
+            .in_ports(&[0x1001, 0x1002])
+            .packet_action(AclPacketAction::Drop)
+            .mirror_ingress(&[0x9001]);
+
+        assert_eq!(builder.len(), 6);
+        assert_eq!(
+            builder.get(AclEntryAttrBuilder::ACTION_PACKET_ACTION),
+            Some(&SaiAttributeValue::I32(0))
+        );
+        assert_eq!(
+            builder.get(AclEntryAttrBuilder::FIELD_IN_PORTS),
+            Some(&SaiAttributeValue::ObjectList(vec![0x1001, 0x1002]))
+        );
+        assert_eq!(
+            builder
+                .get(AclEntryAttrBuilder::ACTION_MIRROR_INGRESS)
+                .and_then(|v| v.list_len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_route_builder() {
+        let attrs = RouteAttrBuilder::new()
+            .packet_action(RouteAction::Forward)
+            .next_hop(0x4000)
+            .into_attrs();
+
+        assert_eq!(
+            attrs,
+            vec![
+                SaiAttribute::new(RouteAttrBuilder::PACKET_ACTION, SaiAttributeValue::I32(1)),
+                SaiAttribute::new(
+                    RouteAttrBuilder::NEXT_HOP_ID,
+                    SaiAttributeValue::ObjectId(0x4000)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_storage_survives_move() {
+        fn lane_ptr(builder: &PortAttrBuilder) -> *const u32 {
+            match builder.get(PortAttrBuilder::HW_LANE_LIST) {
+                Some(SaiAttributeValue::U32List(lanes)) => lanes.as_ptr(),
+                other => panic!("unexpected lane value {:?}", other),
+            }
+        }
+
+        let builder = PortAttrBuilder::new().lanes(&[0, 1, 2, 3]);
+        let before = lane_ptr(&builder);
+
+        // Growing the attribute vector and moving the builder must not move
+        // the list a SAI call would point at
+        let moved = Box::new(builder.speed(100_000).mtu(9100).admin_state(true));
+        assert_eq!(lane_ptr(&moved), before);
+
+        let attrs = moved.finish();
+        match &attrs[0].value {
+            SaiAttributeValue::U32List(lanes) => assert_eq!(lanes.as_slice(), &[0, 1, 2, 3]),
+            other => panic!("unexpected lane value {:?}", other),
+        }
+    }
+}
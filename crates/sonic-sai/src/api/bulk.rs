@@ -0,0 +1,679 @@
+//! Safe wrapper for the SAI bulk object API.
+//!
+//! Bulk calls program many objects of one type at once and report a status
+//! per object. [`BulkApi`] splits a request into chunks of at most
+//! `max_batch` objects, issues each chunk through a [`BulkBackend`], and
+//! returns the statuses in request order, so a caller can retry or roll back
+//! only the objects that failed.
+
+use crate::api::route::{BulkOpErrorMode, RouteEntry};
+use crate::attribute::SaiAttribute;
+use crate::error::{SaiError, SaiResult, SaiStatus};
+use crate::types::{RawSaiObjectId, SwitchOid};
+use log::warn;
+use sonic_types::MacAddress;
+
+/// Default maximum number of objects per bulk call.
+pub const DEFAULT_MAX_BULK_SIZE: usize = 1000;
+
+/// Object types supported by the bulk wrappers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkObjectType {
+    /// Route entry, keyed by [`RouteEntry`]
+    RouteEntry,
+    /// Next-hop group member, keyed by OID
+    NextHopGroupMember,
+    /// FDB entry, keyed by [`FdbEntry`]
+    FdbEntry,
+    /// ACL entry, keyed by OID
+    AclEntry,
+}
+
+impl BulkObjectType {
+    /// Returns the `sai_object_type_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::AclEntry => 8,
+            Self::FdbEntry => 32,
+            Self::RouteEntry => 37,
+            Self::NextHopGroupMember => 45,
+        }
+    }
+
+    /// Returns true if objects of this type are keyed by an entry struct
+    /// rather than by an OID allocated on create.
+    pub const fn is_entry(self) -> bool {
+        matches!(self, Self::RouteEntry | Self::FdbEntry)
+    }
+}
+
+/// FDB entry key (`sai_fdb_entry_t`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FdbEntry {
+    /// Bridge or VLAN the MAC belongs to
+    pub bv_id: RawSaiObjectId,
+    /// MAC address
+    pub mac: MacAddress,
+}
+
+/// Identifies one object of a bulk call.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BulkObjectKey {
+    /// Route entry
+    Route(RouteEntry),
+    /// FDB entry
+    Fdb(FdbEntry),
+    /// Object ID
+    Oid(RawSaiObjectId),
+}
+
+impl BulkObjectKey {
+    fn matches(&self, object_type: BulkObjectType) -> bool {
+        match self {
+            Self::Route(_) => object_type == BulkObjectType::RouteEntry,
+            Self::Fdb(_) => object_type == BulkObjectType::FdbEntry,
+            Self::Oid(oid) => !object_type.is_entry() && *oid != 0,
+        }
+    }
+}
+
+/// One object to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkCreateEntry {
+    /// Entry key for entry types; `None` for OID types, whose OID is
+    /// allocated by the create
+    pub key: Option<BulkObjectKey>,
+    /// Create attributes
+    pub attrs: Vec<SaiAttribute>,
+}
+
+/// Per-object result of a bulk create.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkCreateStatus {
+    /// Status of this object
+    pub status: SaiStatus,
+    /// OID allocated for a created OID-type object, otherwise 0
+    pub oid: RawSaiObjectId,
+}
+
+impl BulkCreateStatus {
+    fn failed(status: SaiStatus) -> Self {
+        Self { status, oid: 0 }
+    }
+}
+
+/// Attribute lists of one bulk create chunk, laid out the way the C bulk API
+/// takes them (`attr_count[]` and `attr_list[]`).
+///
+/// The lists borrow from the [`BulkCreateEntry`] values, so the pointers from
+/// [`as_ptrs`](Self::as_ptrs) stay valid while this value is alive. Keep it
+/// alive until the bulk call returns.
+#[derive(Debug)]
+pub struct BulkAttrLists<'a> {
+    counts: Vec<u32>,
+    lists: Vec<&'a [SaiAttribute]>,
+}
+
+impl<'a> BulkAttrLists<'a> {
+    /// Collects the attribute lists of the given entries.
+    pub fn new(entries: &'a [BulkCreateEntry]) -> Self {
+        Self {
+            counts: entries.iter().map(|e| e.attrs.len() as u32).collect(),
+            lists: entries.iter().map(|e| e.attrs.as_slice()).collect(),
+        }
+    }
+
+    /// Returns the attribute count of each object.
+    pub fn counts(&self) -> &[u32] {
+        &self.counts
+    }
+
+    /// Returns the attribute list of each object.
+    pub fn lists(&self) -> &[&'a [SaiAttribute]] {
+        &self.lists
+    }
+
+    /// Returns a pointer to the first attribute of each object.
+    pub fn as_ptrs(&self) -> Vec<*const SaiAttribute> {
+        self.lists.iter().map(|list| list.as_ptr()).collect()
+    }
+}
+
+/// Issues one chunk of a bulk operation.
+///
+/// Implementations return one status per object, in order, and honor the
+/// error mode within the chunk. [`FfiBulkBackend`] calls the SAI library;
+/// tests substitute a mock.
+pub trait BulkBackend {
+    /// Creates a chunk of objects.
+    fn bulk_create(
+        &mut self,
+        switch_id: SwitchOid,
+        object_type: BulkObjectType,
+        entries: &[BulkCreateEntry],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<BulkCreateStatus>>;
+
+    /// Sets one attribute on each object of a chunk.
+    fn bulk_set(
+        &mut self,
+        object_type: BulkObjectType,
+        items: &[(BulkObjectKey, SaiAttribute)],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiStatus>>;
+
+    /// Removes a chunk of objects.
+    fn bulk_remove(
+        &mut self,
+        object_type: BulkObjectType,
+        keys: &[BulkObjectKey],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiStatus>>;
+}
+
+/// [`BulkBackend`] that calls the SAI bulk API.
+#[derive(Debug, Default)]
+pub struct FfiBulkBackend;
+
+impl BulkBackend for FfiBulkBackend {
+    fn bulk_create(
+        &mut self,
+        switch_id: SwitchOid,
+        object_type: BulkObjectType,
+        entries: &[BulkCreateEntry],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<BulkCreateStatus>> {
+        let attrs = BulkAttrLists::new(entries);
+
+        // TODO: When FFI is enabled, convert `attrs` to sai_attribute_t arrays
+        // and call sai_bulk_object_create() or the entry-type bulk create
+        let _ = (switch_id, object_type, mode, attrs);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    fn bulk_set(
+        &mut self,
+        object_type: BulkObjectType,
+        items: &[(BulkObjectKey, SaiAttribute)],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiStatus>> {
+        // TODO: When FFI is enabled, call sai_bulk_object_set_attribute() or
+        // the entry-type bulk set
+        let _ = (object_type, items, mode);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    fn bulk_remove(
+        &mut self,
+        object_type: BulkObjectType,
+        keys: &[BulkObjectKey],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiStatus>> {
+        // TODO: When FFI is enabled, call sai_bulk_object_remove() or the
+        // entry-type bulk remove
+        let _ = (object_type, keys, mode);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+}
+
+/// Chunked bulk create, set and remove for one switch.
+///
+/// The outer error of each call is reserved for invalid requests, which are
+/// rejected before anything is programmed. Failures while programming are
+/// reported per object: if a chunk call fails as a whole, each of its
+/// objects gets the call's status, and with [`BulkOpErrorMode::StopOnError`]
+/// the objects of later chunks get [`SaiStatus::NotExecuted`].
+pub struct BulkApi<B: BulkBackend = FfiBulkBackend> {
+    switch_id: SwitchOid,
+    backend: B,
+    max_batch: usize,
+    mode: BulkOpErrorMode,
+}
+
+impl BulkApi<FfiBulkBackend> {
+    /// Creates a BulkApi that calls the SAI library.
+    pub fn new(switch_id: SwitchOid) -> Self {
+        Self::with_backend(switch_id, FfiBulkBackend)
+    }
+}
+
+impl<B: BulkBackend> BulkApi<B> {
+    /// Creates a BulkApi that issues chunks through the given backend.
+    pub fn with_backend(switch_id: SwitchOid, backend: B) -> Self {
+        Self {
+            switch_id,
+            backend,
+            max_batch: DEFAULT_MAX_BULK_SIZE,
+            mode: BulkOpErrorMode::default(),
+        }
+    }
+
+    /// Sets the maximum number of objects per bulk call (at least 1).
+    pub fn max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = max_batch.max(1);
+        self
+    }
+
+    /// Sets the error mode of every bulk call.
+    pub fn error_mode(mut self, mode: BulkOpErrorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the switch ID this API is associated with.
+    pub fn switch_id(&self) -> SwitchOid {
+        self.switch_id
+    }
+
+    /// Returns the backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Creates objects in bulk.
+    ///
+    /// Entry types need a matching key on every entry; OID types must have
+    /// none. Returns one result per entry, in the same order as `entries`.
+    pub fn bulk_create_objects(
+        &mut self,
+        object_type: BulkObjectType,
+        entries: &[BulkCreateEntry],
+    ) -> SaiResult<Vec<BulkCreateStatus>> {
+        for entry in entries {
+            let valid = match &entry.key {
+                Some(key) => object_type.is_entry() && key.matches(object_type),
+                None => !object_type.is_entry(),
+            };
+            if !valid {
+                return Err(SaiError::invalid_parameter(format!(
+                    "bad key for bulk {:?} create: {:?}",
+                    object_type, entry.key
+                )));
+            }
+        }
+
+        let (switch_id, mode) = (self.switch_id, self.mode);
+        let backend = &mut self.backend;
+        Ok(run_chunked(
+            entries,
+            self.max_batch,
+            mode,
+            |chunk| backend.bulk_create(switch_id, object_type, chunk, mode),
+            BulkCreateStatus::failed,
+            |result| result.status,
+        ))
+    }
+
+    /// Sets one attribute on each object in bulk.
+    ///
+    /// Returns one status per item, in the same order as `items`.
+    pub fn bulk_set_attributes(
+        &mut self,
+        object_type: BulkObjectType,
+        items: &[(BulkObjectKey, SaiAttribute)],
+    ) -> SaiResult<Vec<SaiStatus>> {
+        check_keys(object_type, items.iter().map(|(key, _)| key))?;
+
+        let mode = self.mode;
+        let backend = &mut self.backend;
+        Ok(run_chunked(
+            items,
+            self.max_batch,
+            mode,
+            |chunk| backend.bulk_set(object_type, chunk, mode),
+            |status| status,
+            |status| *status,
+        ))
+    }
+
+    /// Removes objects in bulk.
+    ///
+    /// Returns one status per key, in the same order as `keys`.
+    pub fn bulk_remove_objects(
+        &mut self,
+        object_type: BulkObjectType,
+        keys: &[BulkObjectKey],
+    ) -> SaiResult<Vec<SaiStatus>> {
+        check_keys(object_type, keys.iter())?;
+
+        let mode = self.mode;
+        let backend = &mut self.backend;
+        Ok(run_chunked(
+            keys,
+            self.max_batch,
+            mode,
+            |chunk| backend.bulk_remove(object_type, chunk, mode),
+            |status| status,
+            |status| *status,
+        ))
+    }
+}
+
+fn check_keys<'a>(
+    object_type: BulkObjectType,
+    mut keys: impl Iterator<Item = &'a BulkObjectKey>,
+) -> SaiResult<()> {
+    match keys.find(|key| !key.matches(object_type)) {
+        Some(key) => Err(SaiError::invalid_parameter(format!(
+            "bad key for bulk {:?}: {:?}",
+            object_type, key
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Issues `items` in chunks and collects one result per item, in order.
+fn run_chunked<I, R>(
+    items: &[I],
+    max_batch: usize,
+    mode: BulkOpErrorMode,
+    mut call: impl FnMut(&[I]) -> SaiResult<Vec<R>>,
+    from_status: impl Fn(SaiStatus) -> R,
+    status_of: impl Fn(&R) -> SaiStatus,
+) -> Vec<R> {
+    let mut results = Vec::with_capacity(items.len());
+    let mut failed = false;
+
+    for chunk in items.chunks(max_batch) {
+        if failed && mode == BulkOpErrorMode::StopOnError {
+            results.extend(chunk.iter().map(|_| from_status(SaiStatus::NotExecuted)));
+            continue;
+        }
+
+        let mut statuses = match call(chunk) {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                warn!("Bulk call for {} objects failed: {}", chunk.len(), e);
+                let status = e.to_status();
+                chunk.iter().map(|_| from_status(status)).collect()
+            }
+        };
+        if statuses.len() != chunk.len() {
+            warn!(
+                "Bulk call returned {} statuses for {} objects",
+                statuses.len(),
+                chunk.len()
+            );
+            statuses.resize_with(chunk.len(), || from_status(SaiStatus::Failure));
+        }
+
+        failed |= statuses.iter().any(|r| status_of(r).is_error());
+        results.extend(statuses);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attribute::SaiAttributeValue;
+    use crate::types::VirtualRouterOid;
+    use std::collections::HashSet;
+
+    /// Attribute ID that makes the mock fail the create of its object.
+    const FAIL_ATTR: i32 = -1;
+
+    /// Mock SAI bulk layer that records the size of every call.
+    #[derive(Default)]
+    struct MockBulk {
+        calls: Vec<(&'static str, usize)>,
+        failing: HashSet<BulkObjectKey>,
+        failing_call: Option<usize>,
+        next_oid: RawSaiObjectId,
+    }
+
+    impl MockBulk {
+        fn begin(&mut self, op: &'static str, len: usize) -> SaiResult<()> {
+            self.calls.push((op, len));
+            if self.failing_call == Some(self.calls.len()) {
+                return Err(SaiError::from_status(SaiStatus::InsufficientResources));
+            }
+            Ok(())
+        }
+
+        fn statuses<T>(
+            items: &[T],
+            mode: BulkOpErrorMode,
+            fails: impl Fn(&T) -> bool,
+        ) -> Vec<SaiStatus> {
+            let mut stopped = false;
+            items
+                .iter()
+                .map(|item| {
+                    if stopped {
+                        SaiStatus::NotExecuted
+                    } else if fails(item) {
+                        stopped = mode == BulkOpErrorMode::StopOnError;
+                        SaiStatus::Failure
+                    } else {
+                        SaiStatus::Success
+                    }
+                })
+                .collect()
+        }
+    }
+
+    impl BulkBackend for MockBulk {
+        fn bulk_create(
+            &mut self,
+            _switch_id: SwitchOid,
+            object_type: BulkObjectType,
+            entries: &[BulkCreateEntry],
+            mode: BulkOpErrorMode,
+        ) -> SaiResult<Vec<BulkCreateStatus>> {
+            self.begin("create", entries.len())?;
+            let statuses = Self::statuses(entries, mode, |e| {
+                e.attrs.iter().any(|attr| attr.id == FAIL_ATTR)
+            });
+            Ok(statuses
+                .into_iter()
+                .map(|status| {
+                    let mut oid = 0;
+                    if status.is_success() && !object_type.is_entry() {
+                        self.next_oid += 1;
+                        oid = self.next_oid;
+                    }
+                    BulkCreateStatus { status, oid }
+                })
+                .collect())
+        }
+
+        fn bulk_set(
+            &mut self,
+            _object_type: BulkObjectType,
+            items: &[(BulkObjectKey, SaiAttribute)],
+            mode: BulkOpErrorMode,
+        ) -> SaiResult<Vec<SaiStatus>> {
+            self.begin("set", items.len())?;
+            Ok(Self::statuses(items, mode, |(key, _)| {
+                self.failing.contains(key)
+            }))
+        }
+
+        fn bulk_remove(
+            &mut self,
+            _object_type: BulkObjectType,
+            keys: &[BulkObjectKey],
+            mode: BulkOpErrorMode,
+        ) -> SaiResult<Vec<SaiStatus>> {
+            self.begin("remove", keys.len())?;
+            // Drop the last status to check that short replies are padded
+            let mut statuses = Self::statuses(keys, mode, |key| self.failing.contains(key));
+            if keys.contains(&BulkObjectKey::Oid(999)) {
+                statuses.pop();
+            }
+            Ok(statuses)
+        }
+    }
+
+    fn switch() -> SwitchOid {
+        SwitchOid::from_raw_unchecked(1)
+    }
+
+    fn member_entry(weight: u32) -> BulkCreateEntry {
+        BulkCreateEntry {
+            key: None,
+            attrs: vec![
+                SaiAttribute::new(0, SaiAttributeValue::ObjectId(0x500)),
+                SaiAttribute::new(2, SaiAttributeValue::U32(weight)),
+            ],
+        }
+    }
+
+    fn route_key(prefix: &str) -> BulkObjectKey {
+        BulkObjectKey::Route(RouteEntry::new(
+            VirtualRouterOid::from_raw_unchecked(0x3000),
+            prefix.parse().unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_bulk_create_chunks_in_order() {
+        let mut api = BulkApi::with_backend(switch(), MockBulk::default()).max_batch(4);
+
+        let mut entries: Vec<_> = (1..=10).map(member_entry).collect();
+        entries[5]
+            .attrs
+            .push(SaiAttribute::new(FAIL_ATTR, SaiAttributeValue::Bool(true)));
+
+        let results = api
+            .bulk_create_objects(BulkObjectType::NextHopGroupMember, &entries)
+            .unwrap();
+
+        assert_eq!(
+            api.backend().calls,
+            vec![("create", 4), ("create", 4), ("create", 2)]
+        );
+        assert_eq!(results.len(), 10);
+
+        // Only the bad entry failed; the others got OIDs in request order
+        assert_eq!(results[5], BulkCreateStatus::failed(SaiStatus::Failure));
+        let oids: Vec<_> = results
+            .iter()
+            .filter(|r| r.status.is_success())
+            .map(|r| r.oid)
+            .collect();
+        assert_eq!(oids, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_bulk_remove_stop_on_error() {
+        let keys: Vec<_> = (0..6)
+            .map(|i| route_key(&format!("10.0.{}.0/24", i)))
+            .collect();
+        let mut backend = MockBulk::default();
+        backend.failing.insert(keys[2].clone());
+        let mut api = BulkApi::with_backend(switch(), backend)
+            .max_batch(2)
+            .error_mode(BulkOpErrorMode::StopOnError);
+
+        let statuses = api
+            .bulk_remove_objects(BulkObjectType::RouteEntry, &keys)
+            .unwrap();
+
+        assert_eq!(
+            statuses,
+            vec![
+                SaiStatus::Success,
+                SaiStatus::Success,
+                SaiStatus::Failure,
+                SaiStatus::NotExecuted,
+                SaiStatus::NotExecuted,
+                SaiStatus::NotExecuted,
+            ]
+        );
+        // The last chunk was never issued
+        assert_eq!(api.backend().calls, vec![("remove", 2), ("remove", 2)]);
+    }
+
+    #[test]
+    fn test_bulk_failed_call_isolated_to_chunk() {
+        let backend = MockBulk {
+            failing_call: Some(2),
+            ..Default::default()
+        };
+        let mut api = BulkApi::with_backend(switch(), backend).max_batch(3);
+        let items: Vec<_> = (1..=7)
+            .map(|oid| {
+                (
+                    BulkObjectKey::Oid(oid),
+                    SaiAttribute::new(1, SaiAttributeValue::I32(0)),
+                )
+            })
+            .collect();
+
+        let statuses = api
+            .bulk_set_attributes(BulkObjectType::AclEntry, &items)
+            .unwrap();
+
+        assert_eq!(statuses.len(), 7);
+        assert!(statuses[..3].iter().all(SaiStatus::is_success));
+        assert!(statuses[3..6]
+            .iter()
+            .all(|s| *s == SaiStatus::InsufficientResources));
+        assert!(statuses[6].is_success());
+
+        // A reply with too few statuses is padded with failures
+        let keys = [BulkObjectKey::Oid(998), BulkObjectKey::Oid(999)];
+        let statuses = api
+            .bulk_remove_objects(BulkObjectType::AclEntry, &keys)
+            .unwrap();
+        assert_eq!(statuses, vec![SaiStatus::Success, SaiStatus::Failure]);
+    }
+
+    #[test]
+    fn test_bulk_key_validation() {
+        let mut api = BulkApi::with_backend(switch(), MockBulk::default());
+
+        let result = api.bulk_remove_objects(BulkObjectType::AclEntry, &[route_key("10.0.0.0/8")]);
+        assert!(matches!(result, Err(SaiError::InvalidParameter { .. })));
+        let result = api.bulk_remove_objects(BulkObjectType::AclEntry, &[BulkObjectKey::Oid(0)]);
+        assert!(result.is_err());
+
+        // OID types get their OID from the create; entry types need a key
+        let mut entry = member_entry(1);
+        entry.key = Some(BulkObjectKey::Oid(5));
+        assert!(api
+            .bulk_create_objects(BulkObjectType::NextHopGroupMember, &[entry])
+            .is_err());
+        assert!(api
+            .bulk_create_objects(BulkObjectType::RouteEntry, &[member_entry(1)])
+            .is_err());
+
+        // Nothing was sent to the backend
+        assert!(api.backend().calls.is_empty());
+        assert!(api
+            .bulk_create_objects(BulkObjectType::FdbEntry, &[])
+            .unwrap()
+            .is_empty());
+        assert!(api.backend().calls.is_empty());
+    }
+
+    #[test]
+    fn test_bulk_attr_lists_borrow_entries() {
+        let entries = vec![
+            member_entry(1),
+            BulkCreateEntry {
+                key: None,
+                attrs: Vec::new(),
+            },
+        ];
+        let lists = BulkAttrLists::new(&entries);
+
+        assert_eq!(lists.counts(), &[2, 0]);
+        assert_eq!(lists.lists()[0], entries[0].attrs.as_slice());
+        assert_eq!(lists.as_ptrs()[0], entries[0].attrs.as_ptr());
+    }
+
+    #[test]
+    fn test_bulk_ffi_not_enabled() {
+        let mut api = BulkApi::new(switch());
+        let statuses = api
+            .bulk_remove_objects(
+                BulkObjectType::NextHopGroupMember,
+                &[BulkObjectKey::Oid(1), BulkObjectKey::Oid(2)],
+            )
+            .unwrap();
+        assert_eq!(statuses, vec![SaiStatus::NotSupported; 2]);
+    }
+}
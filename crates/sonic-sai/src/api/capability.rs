@@ -0,0 +1,301 @@
+//! Safe wrapper for SAI attribute capability queries.
+//!
+//! Orchs validate configuration against what the ASIC implements before
+//! programming it: which attributes can be created, set or read, and which
+//! values an enum attribute (such as the port FEC mode) accepts. Queries go
+//! through the [`CapabilityQuery`] trait so orch tests can inject a
+//! [`StaticCapabilities`] table instead of a SAI library.
+
+use std::collections::HashMap;
+
+use crate::error::{SaiError, SaiResult, SaiStatus};
+use crate::types::SwitchOid;
+
+/// Initial enum list size; large enough for most attributes in one call.
+const ENUM_LIST_INITIAL_LEN: usize = 32;
+
+/// Number of times an enum list query is retried after a buffer overflow.
+const ENUM_LIST_MAX_RETRIES: usize = 3;
+
+/// Operations an attribute supports (`sai_attr_capability_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AttrCapability {
+    /// Attribute can be passed on create
+    pub create: bool,
+    /// Attribute can be set after create
+    pub set: bool,
+    /// Attribute can be read
+    pub get: bool,
+}
+
+impl AttrCapability {
+    /// Capability with every operation supported.
+    pub const ALL: Self = Self {
+        create: true,
+        set: true,
+        get: true,
+    };
+}
+
+/// Source of attribute capabilities.
+///
+/// `object_type` is a `sai_object_type_t` value and `attr_id` an attribute
+/// ID of that object type.
+pub trait CapabilityQuery: Send + Sync {
+    /// Returns which operations the attribute supports.
+    fn query_attr_capability(&self, object_type: i32, attr_id: i32) -> SaiResult<AttrCapability>;
+
+    /// Returns the enum values the attribute accepts.
+    fn query_enum_values(&self, object_type: i32, attr_id: i32) -> SaiResult<Vec<i32>>;
+
+    /// Returns true if the attribute accepts the enum value. A failed query
+    /// counts as unsupported.
+    fn supports_enum_value(&self, object_type: i32, attr_id: i32, value: i32) -> bool {
+        self.query_enum_values(object_type, attr_id)
+            .map(|values| values.contains(&value))
+            .unwrap_or(false)
+    }
+}
+
+/// Capability queries against the SAI library.
+pub struct CapabilityApi {
+    switch_id: SwitchOid,
+}
+
+impl CapabilityApi {
+    /// Creates a new CapabilityApi instance.
+    pub fn new(switch_id: SwitchOid) -> Self {
+        Self { switch_id }
+    }
+
+    /// Returns the switch ID this API is associated with.
+    pub fn switch_id(&self) -> SwitchOid {
+        self.switch_id
+    }
+}
+
+impl CapabilityQuery for CapabilityApi {
+    fn query_attr_capability(&self, object_type: i32, attr_id: i32) -> SaiResult<AttrCapability> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_query_attribute_capability()
+        let _ = (object_type, attr_id);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    fn query_enum_values(&self, object_type: i32, attr_id: i32) -> SaiResult<Vec<i32>> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        read_enum_list(|buf| {
+            // TODO: When FFI is enabled, call
+            // sai_query_attribute_enum_values_capability() with `buf` as the
+            // s32 list and return its status and count
+            let _ = (object_type, attr_id, buf);
+            (SaiStatus::NotSupported, 0)
+        })
+    }
+}
+
+/// Reads a variable-length enum list.
+///
+/// `query` fills the buffer and returns the status and the number of values.
+/// On `BufferOverflow` the count is the length needed, so the buffer is grown
+/// to that length and the query is issued again.
+pub fn read_enum_list(
+    mut query: impl FnMut(&mut [i32]) -> (SaiStatus, u32),
+) -> SaiResult<Vec<i32>> {
+    let mut buf = vec![0; ENUM_LIST_INITIAL_LEN];
+
+    for _ in 0..=ENUM_LIST_MAX_RETRIES {
+        let (status, count) = query(&mut buf);
+        let count = count as usize;
+        match status {
+            SaiStatus::Success => {
+                if count > buf.len() {
+                    return Err(SaiError::internal(format!(
+                        "enum list count {} exceeds buffer of {}",
+                        count,
+                        buf.len()
+                    )));
+                }
+                buf.truncate(count);
+                return Ok(buf);
+            }
+            SaiStatus::BufferOverflow if count > buf.len() => {
+                buf.resize(count, 0);
+            }
+            SaiStatus::BufferOverflow => {
+                return Err(SaiError::internal(format!(
+                    "buffer overflow without a larger count ({})",
+                    count
+                )));
+            }
+            status => return Err(SaiError::from_status(status)),
+        }
+    }
+    Err(SaiError::internal("enum list kept growing while queried"))
+}
+
+/// Fixed capability table, for tests and platforms without query support.
+///
+/// Attributes not in the table are reported as not supported.
+#[derive(Debug, Clone, Default)]
+pub struct StaticCapabilities {
+    attrs: HashMap<(i32, i32), AttrCapability>,
+    enum_values: HashMap<(i32, i32), Vec<i32>>,
+}
+
+impl StaticCapabilities {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the capability of an attribute.
+    pub fn with_attr(mut self, object_type: i32, attr_id: i32, capability: AttrCapability) -> Self {
+        self.attrs.insert((object_type, attr_id), capability);
+        self
+    }
+
+    /// Adds the accepted values of an enum attribute.
+    pub fn with_enum_values(mut self, object_type: i32, attr_id: i32, values: &[i32]) -> Self {
+        self.enum_values
+            .insert((object_type, attr_id), values.to_vec());
+        self
+    }
+}
+
+impl CapabilityQuery for StaticCapabilities {
+    fn query_attr_capability(&self, object_type: i32, attr_id: i32) -> SaiResult<AttrCapability> {
+        self.attrs
+            .get(&(object_type, attr_id))
+            .copied()
+            .ok_or_else(|| {
+                SaiError::not_supported(format!(
+                    "attribute {} of object type {}",
+                    attr_id, object_type
+                ))
+            })
+    }
+
+    fn query_enum_values(&self, object_type: i32, attr_id: i32) -> SaiResult<Vec<i32>> {
+        self.enum_values
+            .get(&(object_type, attr_id))
+            .cloned()
+            .ok_or_else(|| {
+                SaiError::not_supported(format!(
+                    "enum values of attribute {} of object type {}",
+                    attr_id, object_type
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PORT: i32 = 1;
+    const PORT_ATTR_FEC_MODE: i32 = 21;
+
+    #[test]
+    fn test_read_enum_list_fits() {
+        let values = read_enum_list(|buf| {
+            buf[..3].copy_from_slice(&[0, 1, 2]);
+            (SaiStatus::Success, 3)
+        })
+        .unwrap();
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_read_enum_list_grows_buffer() {
+        let mut sizes = Vec::new();
+        let values = read_enum_list(|buf| {
+            sizes.push(buf.len());
+            if buf.len() < 40 {
+                return (SaiStatus::BufferOverflow, 40);
+            }
+            for (i, value) in buf.iter_mut().enumerate() {
+                *value = i as i32;
+            }
+            (SaiStatus::Success, 40)
+        })
+        .unwrap();
+
+        assert_eq!(sizes, vec![ENUM_LIST_INITIAL_LEN, 40]);
+        assert_eq!(values.len(), 40);
+        assert_eq!(values[39], 39);
+    }
+
+    #[test]
+    fn test_read_enum_list_errors() {
+        // Overflow that does not ask for more room
+        assert!(read_enum_list(|_| (SaiStatus::BufferOverflow, 4)).is_err());
+
+        // A list that keeps growing gives up instead of looping forever
+        let mut calls = 0;
+        let result = read_enum_list(|buf| {
+            calls += 1;
+            (SaiStatus::BufferOverflow, buf.len() as u32 + 1)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, ENUM_LIST_MAX_RETRIES + 1);
+
+        // Other failures are passed through
+        let result = read_enum_list(|_| (SaiStatus::NotSupported, 0));
+        assert!(matches!(result, Err(SaiError::NotSupported { .. })));
+
+        // A count larger than the buffer on success is rejected
+        assert!(read_enum_list(|buf| (SaiStatus::Success, buf.len() as u32 + 1)).is_err());
+    }
+
+    #[test]
+    fn test_static_capabilities() {
+        let caps = StaticCapabilities::new()
+            .with_attr(
+                PORT,
+                PORT_ATTR_FEC_MODE,
+                AttrCapability {
+                    create: true,
+                    set: true,
+                    get: false,
+                },
+            )
+            .with_enum_values(PORT, PORT_ATTR_FEC_MODE, &[0, 1]);
+        let query: &dyn CapabilityQuery = &caps;
+
+        let cap = query
+            .query_attr_capability(PORT, PORT_ATTR_FEC_MODE)
+            .unwrap();
+        assert!(cap.set && !cap.get);
+        assert!(query.query_attr_capability(PORT, 0).is_err());
+
+        assert_eq!(
+            query.query_enum_values(PORT, PORT_ATTR_FEC_MODE).unwrap(),
+            vec![0, 1]
+        );
+        assert!(query.supports_enum_value(PORT, PORT_ATTR_FEC_MODE, 1));
+        assert!(!query.supports_enum_value(PORT, PORT_ATTR_FEC_MODE, 2));
+        assert!(!query.supports_enum_value(PORT, 0, 0));
+    }
+
+    #[test]
+    fn test_capability_api_validation() {
+        let api = CapabilityApi::new(SwitchOid::NULL);
+        assert!(matches!(
+            api.query_attr_capability(PORT, PORT_ATTR_FEC_MODE),
+            Err(SaiError::InvalidParameter { .. })
+        ));
+
+        let api = CapabilityApi::new(SwitchOid::from_raw_unchecked(1));
+        assert!(matches!(
+            api.query_enum_values(PORT, PORT_ATTR_FEC_MODE),
+            Err(SaiError::NotSupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+//! Safe wrapper for SAI ICMP echo session API.
+//!
+//! ICMP echo sessions offload periodic echo request transmission and reply
+//! monitoring to hardware. Session state changes are reported through the
+//! `SAI_SWITCH_ATTR_ICMP_ECHO_SESSION_STATE_CHANGE_NOTIFY` callback.
+
+use crate::error::{SaiError, SaiResult};
+use crate::types::{IcmpEchoSessionOid, PortOid, SwitchOid, VirtualRouterOid};
+use std::net::IpAddr;
+
+/// ICMP echo session state (`sai_icmp_echo_session_state_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IcmpEchoSessionState {
+    /// No replies received within the receive timeout
+    Down,
+    /// Replies are being received
+    Up,
+}
+
+impl IcmpEchoSessionState {
+    /// Converts a `sai_icmp_echo_session_state_t` value.
+    pub const fn from_sai(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Down),
+            1 => Some(Self::Up),
+            _ => None,
+        }
+    }
+}
+
+/// Attributes used to create an ICMP echo session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcmpEchoSessionAttrs {
+    /// Virtual router the session runs in
+    pub virtual_router: VirtualRouterOid,
+    /// Port used to transmit echo requests
+    pub port: PortOid,
+    /// Session GUID, echoed back as the ICMP identifier cookie
+    pub guid: u64,
+    /// Source IP address of echo requests
+    pub src_ip: IpAddr,
+    /// Destination IP address of echo requests
+    pub dst_ip: IpAddr,
+    /// Transmit interval in milliseconds
+    pub tx_interval_ms: u32,
+    /// Receive timeout in milliseconds
+    pub rx_timeout_ms: u32,
+}
+
+/// Safe wrapper for SAI ICMP echo session API.
+pub struct IcmpEchoApi {
+    switch_id: SwitchOid,
+    // When FFI is enabled:
+    // api: *const sai_icmp_echo_api_t,
+}
+
+impl IcmpEchoApi {
+    /// Creates a new IcmpEchoApi instance.
+    pub fn new(switch_id: SwitchOid) -> Self {
+        Self { switch_id }
+    }
+
+    /// Returns the switch ID this API is associated with.
+    pub fn switch_id(&self) -> SwitchOid {
+        self.switch_id
+    }
+
+    /// Creates a hardware ICMP echo session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the addresses are of different families, an
+    /// interval is zero, or session creation fails.
+    pub fn create_session(&self, attrs: &IcmpEchoSessionAttrs) -> SaiResult<IcmpEchoSessionOid> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+        if attrs.src_ip.is_ipv4() != attrs.dst_ip.is_ipv4() {
+            return Err(SaiError::invalid_parameter(
+                "source and destination IP families differ",
+            ));
+        }
+        if attrs.tx_interval_ms == 0 || attrs.rx_timeout_ms == 0 {
+            return Err(SaiError::invalid_parameter(
+                "session intervals must be non-zero",
+            ));
+        }
+
+        // TODO: When FFI is enabled, call sai_icmp_echo_api->create_icmp_echo_session()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Removes a hardware ICMP echo session.
+    pub fn remove_session(&self, session: IcmpEchoSessionOid) -> SaiResult<()> {
+        if session.is_null() {
+            return Err(SaiError::invalid_parameter("session OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_icmp_echo_api->remove_icmp_echo_session()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Updates the transmit interval and receive timeout of a session.
+    pub fn set_session_intervals(
+        &self,
+        session: IcmpEchoSessionOid,
+        tx_interval_ms: u32,
+        rx_timeout_ms: u32,
+    ) -> SaiResult<()> {
+        if session.is_null() {
+            return Err(SaiError::invalid_parameter("session OID is null"));
+        }
+        if tx_interval_ms == 0 || rx_timeout_ms == 0 {
+            return Err(SaiError::invalid_parameter(
+                "session intervals must be non-zero",
+            ));
+        }
+
+        // TODO: When FFI is enabled, call sai_icmp_echo_api->set_icmp_echo_session_attribute()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_icmp_echo_api_validation() {
+        let api = IcmpEchoApi::new(SwitchOid::from_raw_unchecked(1));
+        let mut attrs = IcmpEchoSessionAttrs {
+            virtual_router: VirtualRouterOid::NULL,
+            port: PortOid::NULL,
+            guid: 5000,
+            src_ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            dst_ip: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            tx_interval_ms: 1000,
+            rx_timeout_ms: 3000,
+        };
+        assert!(api.create_session(&attrs).is_err());
+
+        attrs.dst_ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        attrs.tx_interval_ms = 0;
+        assert!(api.create_session(&attrs).is_err());
+
+        assert!(api.remove_session(IcmpEchoSessionOid::NULL).is_err());
+        assert!(api
+            .set_session_intervals(IcmpEchoSessionOid::NULL, 1000, 3000)
+            .is_err());
+    }
+
+    #[test]
+    fn test_icmp_echo_session_state_from_sai() {
+        assert_eq!(
+            IcmpEchoSessionState::from_sai(0),
+            Some(IcmpEchoSessionState::Down)
+        );
+        assert_eq!(
+            IcmpEchoSessionState::from_sai(1),
+            Some(IcmpEchoSessionState::Up)
+        );
+        assert_eq!(IcmpEchoSessionState::from_sai(2), None);
+    }
+}
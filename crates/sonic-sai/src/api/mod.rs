@@ -9,18 +9,37 @@
 //!
 //! # Available API Modules
 //!
+//! - [`acl`]: ACL capability queries
+//! - [`attr_builder`]: Typed attribute lists for port, ACL entry and route objects
+//! - [`bulk`]: Chunked bulk create, set and remove with per-object status
+//! - [`capability`]: Attribute capability and enum value queries
+//! - [`icmp_echo`]: Hardware ICMP echo session offload
 //! - [`port`]: Port configuration and management
 //! - [`route`]: Route and next-hop management
 //! - [`switch`]: Switch-level configuration
 //! - [`vlan`]: VLAN management
-//! - [`acl`]: ACL table and rule management
 //! - [`neighbor`]: Neighbor entry management
 //! - [`fdb`]: FDB (MAC address table) management
 //! - [`buffer`]: Buffer pool and profile management
 
+pub mod acl;
+pub mod attr_builder;
+pub mod bulk;
+pub mod capability;
+pub mod icmp_echo;
 pub mod port;
 pub mod route;
+pub mod switch;
 
 // Re-export commonly used items
-pub use port::PortApi;
-pub use route::RouteApi;
+pub use acl::{AclApi, AclBindPoint, AclCapability, AclPacketAction, AclStage};
+pub use attr_builder::{AclEntryAttrBuilder, PortAttrBuilder, RouteAttrBuilder};
+pub use bulk::{
+    BulkApi, BulkAttrLists, BulkBackend, BulkCreateEntry, BulkCreateStatus, BulkObjectKey,
+    BulkObjectType, FdbEntry, FfiBulkBackend,
+};
+pub use capability::{AttrCapability, CapabilityApi, CapabilityQuery, StaticCapabilities};
+pub use icmp_echo::{IcmpEchoApi, IcmpEchoSessionAttrs, IcmpEchoSessionState};
+pub use port::{FecMode, PortApi};
+pub use route::{BulkOpErrorMode, RouteAction, RouteApi};
+pub use switch::{HashAlgorithm, NativeHashField, SwitchApi};
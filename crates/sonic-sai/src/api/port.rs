@@ -60,6 +60,19 @@ pub enum FecMode {
     Auto,
 }
 
+impl FecMode {
+    /// Returns the `sai_port_fec_mode_t` value, or `None` for auto, which
+    /// leaves the FEC mode to negotiation.
+    pub const fn to_sai(self) -> Option<i32> {
+        match self {
+            Self::None => Some(0),
+            Self::Rs => Some(1),
+            Self::Fc => Some(2),
+            Self::Auto => None,
+        }
+    }
+}
+
 /// Port operational status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum PortOperStatus {
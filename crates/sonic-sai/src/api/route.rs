@@ -116,6 +116,19 @@ pub enum RouteAction {
     Deny,
 }
 
+impl RouteAction {
+    /// Returns the `sai_packet_action_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::Drop => 0,
+            Self::Forward => 1,
+            Self::Trap => 4,
+            Self::Log => 5,
+            Self::Deny => 6,
+        }
+    }
+}
+
 /// Configuration for creating a route.
 #[derive(Debug, Clone)]
 pub struct RouteConfig {
@@ -129,6 +142,18 @@ pub struct RouteConfig {
     pub next_hop_group: Option<NextHopGroupOid>,
 }
 
+/// Error handling mode for bulk operations.
+///
+/// Corresponds to `sai_bulk_op_error_mode_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BulkOpErrorMode {
+    /// Stop at the first failed entry; remaining entries are not executed.
+    StopOnError,
+    /// Attempt every entry regardless of earlier failures.
+    #[default]
+    IgnoreError,
+}
+
 /// Safe wrapper for SAI route API.
 pub struct RouteApi {
     vrf_id: VirtualRouterOid,
@@ -181,6 +206,64 @@ impl RouteApi {
         Err(SaiError::not_supported("FFI not enabled"))
     }
 
+    /// Creates route entries in bulk.
+    ///
+    /// Returns one status per entry, in the same order as `configs`. The outer
+    /// error is reserved for failures of the bulk call itself.
+    pub fn create_routes_bulk(
+        &self,
+        configs: &[RouteConfig],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiResult<()>>> {
+        if configs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // TODO: When FFI is enabled, call sai_route_api->create_route_entries()
+        let _ = mode;
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Removes route entries in bulk.
+    ///
+    /// Returns one status per entry, in the same order as `entries`.
+    pub fn remove_routes_bulk(
+        &self,
+        entries: &[RouteEntry],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiResult<()>>> {
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+        if entries.iter().any(|e| e.vrf_id.is_null()) {
+            return Err(SaiError::invalid_parameter("VRF ID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_route_api->remove_route_entries()
+        let _ = mode;
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Updates the action and next-hop of route entries in bulk.
+    ///
+    /// Returns one status per entry, in the same order as `configs`.
+    pub fn set_routes_bulk(
+        &self,
+        configs: &[RouteConfig],
+        mode: BulkOpErrorMode,
+    ) -> SaiResult<Vec<SaiResult<()>>> {
+        if configs.is_empty() {
+            return Ok(Vec::new());
+        }
+        if configs.iter().any(|c| c.entry.vrf_id.is_null()) {
+            return Err(SaiError::invalid_parameter("VRF ID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_route_api->set_route_entries_attribute()
+        let _ = mode;
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
     /// Creates a new next-hop group.
     ///
     /// # Arguments
@@ -252,6 +335,23 @@ impl RouteApi {
         Err(SaiError::not_supported("FFI not enabled"))
     }
 
+    /// Updates the weight of a next-hop group member in place.
+    pub fn set_next_hop_group_member_weight(
+        &self,
+        member: NextHopGroupMemberOid,
+        weight: u32,
+    ) -> SaiResult<()> {
+        if member.is_null() {
+            return Err(SaiError::invalid_parameter("member OID is null"));
+        }
+        if weight == 0 {
+            return Err(SaiError::invalid_parameter("weight must be non-zero"));
+        }
+
+        // TODO: When FFI is enabled, call sai_next_hop_group_api->set_next_hop_group_member_attribute()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
     /// Sets the route action.
     pub fn set_route_action(&self, entry: &RouteEntry, action: RouteAction) -> SaiResult<()> {
         if entry.vrf_id.is_null() {
@@ -346,4 +446,23 @@ mod tests {
 
         assert!(api.create_route(&config).is_err());
     }
+
+    #[test]
+    fn test_route_api_bulk_validation() {
+        let api = RouteApi::new(VirtualRouterOid::NULL);
+
+        // Empty batches are a no-op
+        assert!(api
+            .create_routes_bulk(&[], BulkOpErrorMode::default())
+            .unwrap()
+            .is_empty());
+        assert!(api
+            .remove_routes_bulk(&[], BulkOpErrorMode::StopOnError)
+            .unwrap()
+            .is_empty());
+
+        let entry = RouteEntry::new(VirtualRouterOid::NULL, "10.0.0.0/24".parse().unwrap());
+        let result = api.remove_routes_bulk(&[entry], BulkOpErrorMode::IgnoreError);
+        assert!(matches!(result, Err(SaiError::InvalidParameter { .. })));
+    }
 }
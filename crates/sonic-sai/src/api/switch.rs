@@ -0,0 +1,204 @@
+//! Safe wrapper for SAI switch API.
+//!
+//! This module provides type-safe access to switch-level attributes such as
+//! the buffer capacity used to size dynamic buffer pools and the ECMP/LAG
+//! hash configuration.
+
+use crate::error::{SaiError, SaiResult};
+use crate::types::{HashOid, SwitchOid};
+
+/// Packet field used in hash calculation (`sai_native_hash_field_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NativeHashField {
+    /// Source IP address
+    SrcIp,
+    /// Destination IP address
+    DstIp,
+    /// Inner source IP address
+    InnerSrcIp,
+    /// Inner destination IP address
+    InnerDstIp,
+    /// VLAN ID
+    VlanId,
+    /// IP protocol
+    IpProtocol,
+    /// Ethertype
+    EtherType,
+    /// L4 source port
+    L4SrcPort,
+    /// L4 destination port
+    L4DstPort,
+    /// Source MAC address
+    SrcMac,
+    /// Destination MAC address
+    DstMac,
+    /// Ingress port
+    InPort,
+    /// Inner IP protocol
+    InnerIpProtocol,
+    /// Inner ethertype
+    InnerEtherType,
+    /// Inner L4 source port
+    InnerL4SrcPort,
+    /// Inner L4 destination port
+    InnerL4DstPort,
+    /// Inner source MAC address
+    InnerSrcMac,
+    /// Inner destination MAC address
+    InnerDstMac,
+}
+
+impl NativeHashField {
+    /// Returns the `sai_native_hash_field_t` value.
+    pub const fn to_sai(self) -> i32 {
+        match self {
+            Self::SrcIp => 0,
+            Self::DstIp => 1,
+            Self::InnerSrcIp => 2,
+            Self::InnerDstIp => 3,
+            Self::VlanId => 12,
+            Self::IpProtocol => 13,
+            Self::EtherType => 14,
+            Self::L4SrcPort => 15,
+            Self::L4DstPort => 16,
+            Self::SrcMac => 17,
+            Self::DstMac => 18,
+            Self::InPort => 19,
+            Self::InnerIpProtocol => 20,
+            Self::InnerEtherType => 21,
+            Self::InnerL4SrcPort => 22,
+            Self::InnerL4DstPort => 23,
+            Self::InnerSrcMac => 24,
+            Self::InnerDstMac => 25,
+        }
+    }
+}
+
+/// Default hash algorithm (`sai_hash_algorithm_t`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HashAlgorithm {
+    /// CRC-based hash
+    #[default]
+    Crc,
+    /// XOR-based hash
+    Xor,
+    /// Random hash
+    Random,
+    /// Lower 16 bits of CRC32
+    Crc32Lo,
+    /// CRC-CCITT hash
+    CrcCcitt,
+}
+
+/// Safe wrapper for SAI switch API.
+pub struct SwitchApi {
+    switch_id: SwitchOid,
+    // When FFI is enabled:
+    // api: *const sai_switch_api_t,
+}
+
+impl SwitchApi {
+    /// Creates a new SwitchApi instance.
+    pub fn new(switch_id: SwitchOid) -> Self {
+        Self { switch_id }
+    }
+
+    /// Returns the switch ID this API is associated with.
+    pub fn switch_id(&self) -> SwitchOid {
+        self.switch_id
+    }
+
+    /// Gets the total buffer size of the switch in bytes.
+    ///
+    /// SAI reports `SAI_SWITCH_ATTR_TOTAL_BUFFER_SIZE` in KB; the value is
+    /// converted to bytes.
+    pub fn get_total_buffer_size(&self) -> SaiResult<u64> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_switch_api->get_switch_attribute()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Creates a hash object with the given native field list and sets it as
+    /// the switch's ECMP or LAG hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field list is empty or hash creation fails.
+    pub fn create_hash(&self, is_ecmp: bool, fields: &[NativeHashField]) -> SaiResult<HashOid> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+        if fields.is_empty() {
+            return Err(SaiError::invalid_parameter(
+                "hash field list cannot be empty",
+            ));
+        }
+
+        // TODO: When FFI is enabled, call sai_hash_api->create_hash() and
+        // sai_switch_api->set_switch_attribute(SAI_SWITCH_ATTR_ECMP_HASH/LAG_HASH)
+        let _ = is_ecmp;
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Replaces the native field list of an existing hash object.
+    pub fn set_hash_fields(&self, hash: HashOid, fields: &[NativeHashField]) -> SaiResult<()> {
+        if hash.is_null() {
+            return Err(SaiError::invalid_parameter("hash OID is null"));
+        }
+        if fields.is_empty() {
+            return Err(SaiError::invalid_parameter(
+                "hash field list cannot be empty",
+            ));
+        }
+
+        // TODO: When FFI is enabled, call sai_hash_api->set_hash_attribute()
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Sets the default ECMP or LAG hash algorithm.
+    pub fn set_hash_algorithm(&self, is_ecmp: bool, algorithm: HashAlgorithm) -> SaiResult<()> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_switch_api->set_switch_attribute()
+        let _ = (is_ecmp, algorithm);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+
+    /// Sets the default ECMP or LAG hash seed.
+    pub fn set_hash_seed(&self, is_ecmp: bool, seed: u32) -> SaiResult<()> {
+        if self.switch_id.is_null() {
+            return Err(SaiError::invalid_parameter("switch OID is null"));
+        }
+
+        // TODO: When FFI is enabled, call sai_switch_api->set_switch_attribute()
+        let _ = (is_ecmp, seed);
+        Err(SaiError::not_supported("FFI not enabled"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_switch_api_null_validation() {
+        let api = SwitchApi::new(SwitchOid::NULL);
+        assert!(api.get_total_buffer_size().is_err());
+        assert!(api.create_hash(true, &[NativeHashField::SrcIp]).is_err());
+        assert!(api
+            .set_hash_fields(HashOid::NULL, &[NativeHashField::SrcIp])
+            .is_err());
+    }
+
+    #[test]
+    fn test_native_hash_field_values() {
+        assert_eq!(NativeHashField::SrcIp.to_sai(), 0);
+        assert_eq!(NativeHashField::L4DstPort.to_sai(), 16);
+        assert_eq!(NativeHashField::InnerDstMac.to_sai(), 25);
+    }
+}
@@ -0,0 +1,79 @@
+//! Owned SAI attribute values.
+//!
+//! [`SaiAttribute`] mirrors `sai_attribute_t`, but list values own their
+//! backing storage. When an attribute is handed to the C API, the list
+//! pointers borrow from this storage, so the attribute must outlive the call.
+
+use crate::types::RawSaiObjectId;
+use std::net::IpAddr;
+
+/// Value of a SAI attribute (`sai_attribute_value_t`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaiAttributeValue {
+    /// `booldata`
+    Bool(bool),
+    /// `u8`
+    U8(u8),
+    /// `u16`
+    U16(u16),
+    /// `u32`
+    U32(u32),
+    /// `s32`, also used for enum values
+    I32(i32),
+    /// `u64`
+    U64(u64),
+    /// `mac`
+    Mac([u8; 6]),
+    /// `ipaddr`
+    IpAddress(IpAddr),
+    /// `oid`
+    ObjectId(RawSaiObjectId),
+    /// `objlist`
+    ObjectList(Vec<RawSaiObjectId>),
+    /// `u32list`
+    U32List(Vec<u32>),
+    /// `s32list`
+    I32List(Vec<i32>),
+}
+
+impl SaiAttributeValue {
+    /// Returns the number of list elements, or `None` for scalar values.
+    pub fn list_len(&self) -> Option<usize> {
+        match self {
+            Self::ObjectList(list) => Some(list.len()),
+            Self::U32List(list) => Some(list.len()),
+            Self::I32List(list) => Some(list.len()),
+            _ => None,
+        }
+    }
+}
+
+/// A SAI attribute ID and its value (`sai_attribute_t`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaiAttribute {
+    /// Attribute ID (`sai_attr_id_t`)
+    pub id: i32,
+    /// Attribute value
+    pub value: SaiAttributeValue,
+}
+
+impl SaiAttribute {
+    /// Creates a new attribute.
+    pub fn new(id: i32, value: SaiAttributeValue) -> Self {
+        Self { id, value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_list_len() {
+        let lanes = SaiAttribute::new(0, SaiAttributeValue::U32List(vec![0, 1, 2, 3]));
+        assert_eq!(lanes.value.list_len(), Some(4));
+
+        let speed = SaiAttribute::new(1, SaiAttributeValue::U32(100_000));
+        assert_eq!(speed.value.list_len(), None);
+    }
+}
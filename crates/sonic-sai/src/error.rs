@@ -260,6 +260,23 @@ impl SaiError {
         }
     }
 
+    /// Returns the SAI status that best describes this error.
+    ///
+    /// Used where a per-object status is expected, such as bulk results.
+    pub fn to_status(&self) -> SaiStatus {
+        match self {
+            SaiError::Status { status } => *status,
+            SaiError::NotSupported { .. } => SaiStatus::NotSupported,
+            SaiError::InvalidParameter { .. } => SaiStatus::InvalidParameter,
+            SaiError::NotFound { .. } => SaiStatus::ItemNotFound,
+            SaiError::AlreadyExists { .. } => SaiStatus::ItemAlreadyExists,
+            SaiError::TableFull { .. } => SaiStatus::TableFull,
+            SaiError::ObjectInUse { .. } => SaiStatus::ObjectInUse,
+            SaiError::Uninitialized => SaiStatus::Uninitialized,
+            SaiError::Internal { .. } => SaiStatus::Failure,
+        }
+    }
+
     /// Returns true if this error is retryable.
     pub fn is_retryable(&self) -> bool {
         matches!(
@@ -328,6 +345,23 @@ mod tests {
         assert!((-7_i32).to_result().is_err());
     }
 
+    #[test]
+    fn test_error_to_status() {
+        assert_eq!(
+            SaiError::from_status(SaiStatus::ItemNotFound).to_status(),
+            SaiStatus::ItemNotFound
+        );
+        assert_eq!(
+            SaiError::from_status(SaiStatus::NotExecuted).to_status(),
+            SaiStatus::NotExecuted
+        );
+        assert_eq!(
+            SaiError::not_supported("FFI not enabled").to_status(),
+            SaiStatus::NotSupported
+        );
+        assert_eq!(SaiError::internal("oops").to_status(), SaiStatus::Failure);
+    }
+
     #[test]
     fn test_error_retryable() {
         let err = SaiError::from_status(SaiStatus::InsufficientResources);
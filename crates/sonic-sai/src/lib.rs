@@ -10,7 +10,10 @@
 //!
 //! - [`types`]: Core SAI types including type-safe object IDs
 //! - [`error`]: Error types and status handling
+//! - [`attribute`]: Owned SAI attribute values
 //! - [`api`]: Safe wrappers around SAI API functions (port, route, acl, etc.)
+//! - [`SaiContext`]: Per-switch access to the API wrappers
+//! - [`recording`]: sairedis-style `.rec` recording and replay of API calls
 //!
 //! # Example
 //!
@@ -26,9 +29,15 @@
 //! ```
 
 pub mod api;
+pub mod attribute;
+mod context;
 pub mod error;
+pub mod recording;
 pub mod types;
 
+pub use attribute::{SaiAttribute, SaiAttributeValue};
+pub use context::SaiContext;
+
 // Re-export commonly used types
 pub use types::{
     AclEntryKind, AclEntryOid, AclTableKind, AclTableOid, BridgeKind, BridgeOid, BridgePortKind,
@@ -194,6 +194,7 @@ define_object_kind!(HostifTrapGroupKind, "HostifTrapGroup", HostifTrapGroupOid);
 define_object_kind!(HashKind, "Hash", HashOid);
 define_object_kind!(SamplePacketKind, "SamplePacket", SamplePacketOid);
 define_object_kind!(CounterKind, "Counter", CounterOid);
+define_object_kind!(IcmpEchoSessionKind, "IcmpEchoSession", IcmpEchoSessionOid);
 
 #[cfg(test)]
 mod tests {
@@ -80,6 +80,15 @@ pub fn build_del_tunnel_route_cmd(prefix: &IpPrefix) -> String {
     }
 }
 
+/// Build the commands re-installing routes through a recreated tunnel
+///
+/// Deleting the tunnel device flushes every route through it.
+pub fn build_tunnel_route_replay_cmds<'a>(
+    routes: impl IntoIterator<Item = &'a IpPrefix>,
+) -> Vec<String> {
+    routes.into_iter().map(build_add_tunnel_route_cmd).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +166,17 @@ mod tests {
         // Should be quoted to prevent injection
         assert!(cmd.contains("\"10.1.0.32; rm -rf /\""));
     }
+
+    #[test]
+    fn test_build_tunnel_route_replay_cmds() {
+        let routes: Vec<IpPrefix> = ["192.168.1.0/24", "2001:db8::/32"]
+            .iter()
+            .map(|p| p.parse().unwrap())
+            .collect();
+
+        let cmds = build_tunnel_route_replay_cmds(&routes);
+        assert_eq!(cmds.len(), 2);
+        assert_eq!(cmds[0], build_add_tunnel_route_cmd(&routes[0]));
+        assert!(cmds[1].contains("ip -6 route replace"));
+    }
 }
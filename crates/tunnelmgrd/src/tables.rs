@@ -22,6 +22,15 @@ pub mod tunnel_fields {
     pub const DST_IP: &str = "dst_ip";
     pub const SRC_IP: &str = "src_ip";
     pub const TUNNEL_TYPE: &str = "tunnel_type";
+    pub const DECAP_DSCP_TO_TC_MAP: &str = "decap_dscp_to_tc_map";
+    pub const DECAP_TC_TO_PG_MAP: &str = "decap_tc_to_pg_map";
+    pub const ENCAP_ECN_MODE: &str = "encap_ecn_mode";
+}
+
+/// QoS map tables referenced by TUNNEL entries
+pub mod qos_map_tables {
+    pub const DSCP_TO_TC_MAP: &str = "DSCP_TO_TC_MAP";
+    pub const TC_TO_PRIORITY_GROUP_MAP: &str = "TC_TO_PRIORITY_GROUP_MAP";
 }
 
 /// TUNNEL_DECAP_TERM table fields
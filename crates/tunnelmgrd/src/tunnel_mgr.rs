@@ -1,20 +1,31 @@
 //! Tunnel Manager - Core tunnel lifecycle and route management
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 use async_trait::async_trait;
 use sonic_cfgmgr_common::{
-    shell, CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, FieldValuesExt, WarmRestartState,
+    shell, CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, FieldValuesExt, KeyOpFieldsValues,
+    WarmRestartState,
 };
 use sonic_orch_common::Orch;
-use tracing::{info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::commands::*;
 use crate::tables::{
-    decap_term_fields, tunnel_fields, CFG_LOOPBACK_INTERFACE_TABLE, CFG_TUNNEL_TABLE,
+    decap_term_fields, tunnel_fields, APP_TUNNEL_DECAP_TABLE, APP_TUNNEL_DECAP_TERM_TABLE,
+    APP_TUNNEL_ROUTE_TABLE, CFG_LOOPBACK_INTERFACE_TABLE, CFG_TUNNEL_TABLE,
 };
 use crate::types::*;
 
+/// An APPL_DB write, recorded in tests
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq)]
+enum AppDbOp {
+    Set(&'static str, String, FieldValues),
+    HDel(&'static str, String, Vec<String>),
+    Del(&'static str, String),
+}
+
 /// Tunnel Manager
 ///
 /// Manages IP-in-IP tunnel lifecycle, route management, and APPL_DB synchronization
@@ -25,6 +36,12 @@ pub struct TunnelMgr {
     /// Loopback interface IP cache
     intf_cache: HashMap<String, IpPrefix>,
 
+    /// TUNNEL_DECAP_TABLE fields last published per tunnel
+    decap_fields: HashMap<String, FieldValues>,
+
+    /// Routes installed through the tunnel device
+    tunnel_routes: BTreeSet<IpPrefix>,
+
     /// Peer switch IP address (remote tunnel endpoint)
     peer_ip: Option<String>,
 
@@ -34,11 +51,17 @@ pub struct TunnelMgr {
     /// Warm restart completion flag
     replay_done: bool,
 
+    /// Queued (table, entry) updates
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
     #[cfg(test)]
     mock_mode: bool,
 
     #[cfg(test)]
     captured_commands: Vec<String>,
+
+    #[cfg(test)]
+    app_db_ops: Vec<AppDbOp>,
 }
 
 impl TunnelMgr {
@@ -49,13 +72,18 @@ impl TunnelMgr {
         Self {
             tunnel_cache: HashMap::new(),
             intf_cache: HashMap::new(),
+            decap_fields: HashMap::new(),
+            tunnel_routes: BTreeSet::new(),
             peer_ip: None,
             tunnel_replay: HashSet::new(),
             replay_done: false,
+            tasks: VecDeque::new(),
             #[cfg(test)]
             mock_mode: false,
             #[cfg(test)]
             captured_commands: Vec::new(),
+            #[cfg(test)]
+            app_db_ops: Vec::new(),
         }
     }
 
@@ -152,14 +180,31 @@ impl TunnelMgr {
             return Ok(true);
         }
 
+        // Reject bad QoS fields before touching the kernel or APPL_DB
+        for (field, value) in values {
+            if let Some(Err(msg)) = validate_qos_field(field, value) {
+                return Err(CfgMgrError::invalid_config(field.as_str(), msg));
+            }
+        }
+
         let mut tunnel_info = TunnelInfo::new(tunnel_type, dst_ip.clone()).with_src_ip(src_ip);
+        let prev_info = self.tunnel_cache.get(tunnel_name).cloned();
+        let endpoint_changed = prev_info.as_ref().is_some_and(|prev| {
+            prev.dst_ip != tunnel_info.dst_ip || prev.src_ip != tunnel_info.src_ip
+        });
 
         // Set remote IP from peer if available
         if let Some(peer_ip) = &self.peer_ip {
             tunnel_info = tunnel_info.with_remote_ip(peer_ip.clone());
 
             // Configure Linux tunnel interface
-            if !self.config_ip_tunnel(&tunnel_info).await? {
+            if endpoint_changed {
+                info!(
+                    "Tunnel {} endpoints changed, recreating {}",
+                    tunnel_name, TUNNEL_INTERFACE
+                );
+                self.recreate_ip_tunnel(&tunnel_info).await?;
+            } else if prev_info.is_none() && !self.config_ip_tunnel(&tunnel_info).await? {
                 return Ok(false); // Retry
             }
         } else {
@@ -168,7 +213,7 @@ impl TunnelMgr {
 
         // Write to APPL_DB (skip if in warm restart replay)
         if !self.tunnel_replay.contains(tunnel_name) {
-            self.write_tunnel_to_appl_db(tunnel_name, values, &tunnel_info)
+            self.write_tunnel_to_appl_db(tunnel_name, values, &tunnel_info, prev_info.as_ref())
                 .await?;
         }
 
@@ -200,32 +245,78 @@ impl TunnelMgr {
     }
 
     /// Write tunnel to APPL_DB
+    ///
+    /// TUNNEL_DECAP_TABLE gets every field but dst_ip, including the QoS
+    /// map references; fields dropped from CONFIG_DB are deleted. After an
+    /// endpoint change the new decap term is written before the old one is
+    /// removed, so the tunnel always has a term to decapsulate with.
     async fn write_tunnel_to_appl_db(
         &mut self,
         tunnel_name: &str,
         values: &FieldValues,
         tunnel_info: &TunnelInfo,
+        prev_info: Option<&TunnelInfo>,
     ) -> CfgMgrResult<()> {
-        // TODO: Use ProducerStateTable to write to APP_TUNNEL_DECAP_TABLE
-        // Filter out dst_ip field (only include tunnel_type, src_ip)
-        let _filtered_values: Vec<_> = values
+        // Filter out dst_ip field (only include tunnel_type, src_ip, QoS)
+        let decap_fvs: FieldValues = values
             .iter()
             .filter(|(k, _)| k != tunnel_fields::DST_IP)
+            .cloned()
             .collect();
+        let removed: Vec<String> = self
+            .decap_fields
+            .get(tunnel_name)
+            .map(|old| {
+                old.iter()
+                    .map(|(field, _)| field)
+                    .filter(|field| !decap_fvs.has_field(field))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.app_db_set(APP_TUNNEL_DECAP_TABLE, tunnel_name, decap_fvs.clone())
+            .await?;
+        if !removed.is_empty() {
+            self.app_db_hdel(APP_TUNNEL_DECAP_TABLE, tunnel_name, removed)
+                .await?;
+        }
+        self.decap_fields.insert(tunnel_name.to_string(), decap_fvs);
 
-        // Write decap term entry
-        let _term_key = format!("{}:{}", tunnel_name, tunnel_info.dst_ip);
-
-        // TODO: Use ProducerStateTable to write to APP_TUNNEL_DECAP_TERM_TABLE
-        // with P2P/P2MP term_type based on src_ip presence
+        // Write decap term entry with P2P/P2MP term_type based on src_ip presence
+        let term_key = format!("{}:{}", tunnel_name, tunnel_info.dst_ip);
         let term_type = if tunnel_info.is_p2p() {
             decap_term_fields::TERM_TYPE_P2P
         } else {
             decap_term_fields::TERM_TYPE_P2MP
         };
+        let mut term_fvs = vec![(
+            decap_term_fields::TERM_TYPE.to_string(),
+            term_type.to_string(),
+        )];
+        if let Some(src_ip) = &tunnel_info.src_ip {
+            term_fvs.push((decap_term_fields::SRC_IP.to_string(), src_ip.clone()));
+        }
+        self.app_db_set(APP_TUNNEL_DECAP_TERM_TABLE, &term_key, term_fvs)
+            .await?;
+
+        if let Some(prev) = prev_info {
+            if prev.dst_ip != tunnel_info.dst_ip {
+                let old_term_key = format!("{}:{}", tunnel_name, prev.dst_ip);
+                self.app_db_del(APP_TUNNEL_DECAP_TERM_TABLE, &old_term_key)
+                    .await?;
+            } else if prev.is_p2p() && !tunnel_info.is_p2p() {
+                self.app_db_hdel(
+                    APP_TUNNEL_DECAP_TERM_TABLE,
+                    &term_key,
+                    vec![decap_term_fields::SRC_IP.to_string()],
+                )
+                .await?;
+            }
+        }
 
         info!(
-            "Would write tunnel {} to APPL_DB (term_type: {})",
+            "Wrote tunnel {} to APPL_DB (term_type: {})",
             tunnel_name, term_type
         );
         Ok(())
@@ -237,9 +328,74 @@ impl TunnelMgr {
         tunnel_name: &str,
         dst_ip: &str,
     ) -> CfgMgrResult<()> {
-        let _term_key = format!("{}:{}", tunnel_name, dst_ip);
-        // TODO: Use ProducerStateTable to delete from both tables
-        info!("Would delete tunnel {} from APPL_DB", tunnel_name);
+        let term_key = format!("{}:{}", tunnel_name, dst_ip);
+        self.app_db_del(APP_TUNNEL_DECAP_TERM_TABLE, &term_key)
+            .await?;
+        self.app_db_del(APP_TUNNEL_DECAP_TABLE, tunnel_name).await?;
+        self.decap_fields.remove(tunnel_name);
+        info!("Deleted tunnel {} from APPL_DB", tunnel_name);
+        Ok(())
+    }
+
+    /// Set fields of an APPL_DB entry
+    async fn app_db_set(
+        &mut self,
+        table: &'static str,
+        key: &str,
+        fvs: FieldValues,
+    ) -> CfgMgrResult<()> {
+        // TODO: Use ProducerStateTable
+        debug!("Writing {}:{} {:?}", table, key, fvs);
+        #[cfg(test)]
+        self.app_db_ops
+            .push(AppDbOp::Set(table, key.to_string(), fvs));
+        Ok(())
+    }
+
+    /// Delete fields of an APPL_DB entry
+    async fn app_db_hdel(
+        &mut self,
+        table: &'static str,
+        key: &str,
+        fields: Vec<String>,
+    ) -> CfgMgrResult<()> {
+        debug!("Deleting {:?} from {}:{}", fields, table, key);
+        #[cfg(test)]
+        self.app_db_ops
+            .push(AppDbOp::HDel(table, key.to_string(), fields));
+        Ok(())
+    }
+
+    /// Delete an APPL_DB entry
+    async fn app_db_del(&mut self, table: &'static str, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", table, key);
+        #[cfg(test)]
+        self.app_db_ops.push(AppDbOp::Del(table, key.to_string()));
+        Ok(())
+    }
+
+    /// Replace the kernel tunnel device after an endpoint change
+    ///
+    /// The device is deleted and created again with the new endpoints.
+    /// Deleting it flushes the routes through it, so they are re-added.
+    async fn recreate_ip_tunnel(&mut self, info: &TunnelInfo) -> CfgMgrResult<()> {
+        let cmd = build_del_tunnel_cmd();
+        if let Err(e) = self.exec(&cmd).await {
+            warn!("Failed to delete tunnel {}: {}", TUNNEL_INTERFACE, e);
+        }
+
+        self.config_ip_tunnel(info).await?;
+
+        for cmd in build_tunnel_route_replay_cmds(&self.tunnel_routes) {
+            if let Err(e) = self.exec(&cmd).await {
+                warn!("Failed to restore tunnel route: {}", e);
+            }
+        }
+        info!(
+            "Recreated {} with {} route(s)",
+            TUNNEL_INTERFACE,
+            self.tunnel_routes.len()
+        );
         Ok(())
     }
 
@@ -325,6 +481,7 @@ impl TunnelMgr {
                 warn!("Failed to add route {}: {}", prefix, e);
             } else {
                 info!("Route {} added through tunnel", prefix);
+                self.tunnel_routes.insert(prefix);
             }
         } else if op == "DEL" {
             let cmd = build_del_tunnel_route_cmd(&prefix);
@@ -333,11 +490,32 @@ impl TunnelMgr {
             } else {
                 info!("Route {} deleted from tunnel", prefix);
             }
+            self.tunnel_routes.remove(&prefix);
         }
 
         Ok(true)
     }
 
+    /// Processes one queued CONFIG_DB or APPL_DB entry
+    async fn process_task(&mut self, table: &str, entry: KeyOpFieldsValues) -> CfgMgrResult<()> {
+        let op = if entry.op.is_set() { "SET" } else { "DEL" };
+        let done = match table {
+            CFG_TUNNEL_TABLE => self.do_tunnel_task(&entry.key, op, &entry.fvs).await?,
+            CFG_LOOPBACK_INTERFACE_TABLE if entry.op.is_set() => {
+                self.do_loopback_intf_task(&entry.key, &entry.fvs).await?
+            }
+            APP_TUNNEL_ROUTE_TABLE => {
+                self.do_tunnel_route_task(&entry.key, op, &entry.fvs)
+                    .await?
+            }
+            _ => true,
+        };
+        if !done {
+            debug!("{}|{} deferred", table, entry.key);
+        }
+        Ok(())
+    }
+
     /// Finalize warm restart
     fn finalize_warm_restart(&mut self) {
         self.replay_done = true;
@@ -371,8 +549,16 @@ impl Orch for TunnelMgr {
     }
 
     async fn do_task(&mut self) {
-        // TODO: Process consumers
-        // This will be implemented when integrating with ConsumerStateTable
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            let key = entry.key.clone();
+            if let Err(e) = self.process_task(&table, entry).await {
+                error!("Failed to process {}|{}: {}", table, key, e);
+            }
+        }
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        !self.tasks.is_empty()
     }
 }
 
@@ -404,6 +590,25 @@ impl CfgMgr for TunnelMgr {
         &[CFG_TUNNEL_TABLE, CFG_LOOPBACK_INTERFACE_TABLE]
     }
 
+    fn appl_table_names(&self) -> &[&str] {
+        &[APP_TUNNEL_ROUTE_TABLE]
+    }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        match (db, table) {
+            (DbId::ConfigDb, _) | (DbId::ApplDb, APP_TUNNEL_ROUTE_TABLE) => {
+                self.tasks
+                    .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+            }
+            _ => debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            ),
+        }
+    }
+
     fn is_replay_done(&self) -> bool {
         self.replay_done
     }
@@ -545,6 +750,167 @@ mod tests {
         assert!(cmds.iter().any(|c| c.contains("ip -6 route replace")));
     }
 
+    #[tokio::test]
+    async fn test_tunnel_qos_fields_add_remove() {
+        let mut mgr = TunnelMgr::new_mock().with_peer_ip("10.1.0.33".to_string());
+
+        let mut fvs = make_tunnel_fields("10.1.0.32", "IPINIP", None);
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+
+        fvs.push((
+            "decap_dscp_to_tc_map".to_string(),
+            "[DSCP_TO_TC_MAP|AZURE_TUNNEL]".to_string(),
+        ));
+        fvs.push(("decap_tc_to_pg_map".to_string(), "AZURE_TUNNEL".to_string()));
+        fvs.push(("encap_ecn_mode".to_string(), "standard".to_string()));
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+
+        // Same endpoints: the kernel tunnel is left alone
+        assert!(mgr.get_captured_commands().is_empty());
+        match &mgr.app_db_ops[0] {
+            AppDbOp::Set(APP_TUNNEL_DECAP_TABLE, key, decap) => {
+                assert_eq!(key, "MuxTunnel0");
+                assert_eq!(
+                    decap.get_field("decap_dscp_to_tc_map"),
+                    Some("[DSCP_TO_TC_MAP|AZURE_TUNNEL]")
+                );
+                assert_eq!(decap.get_field("decap_tc_to_pg_map"), Some("AZURE_TUNNEL"));
+                assert_eq!(decap.get_field("encap_ecn_mode"), Some("standard"));
+                assert!(!decap.has_field("dst_ip"));
+            }
+            op => panic!("unexpected {:?}", op),
+        }
+
+        // Dropping the QoS fields deletes them from APPL_DB
+        mgr.app_db_ops.clear();
+        let fvs = make_tunnel_fields("10.1.0.32", "IPINIP", None);
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+
+        assert!(mgr.get_captured_commands().is_empty());
+        assert!(mgr.app_db_ops.contains(&AppDbOp::HDel(
+            APP_TUNNEL_DECAP_TABLE,
+            "MuxTunnel0".to_string(),
+            vec![
+                "decap_dscp_to_tc_map".to_string(),
+                "decap_tc_to_pg_map".to_string(),
+                "encap_ecn_mode".to_string(),
+            ],
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_tunnel_qos_fields_rejected() {
+        let mut mgr = TunnelMgr::new_mock().with_peer_ip("10.1.0.33".to_string());
+
+        for (field, value) in [
+            ("decap_dscp_to_tc_map", "[TC_TO_PRIORITY_GROUP_MAP|AZURE]"),
+            ("decap_tc_to_pg_map", ""),
+            ("encap_ecn_mode", "copy_from_outer"),
+        ] {
+            let mut fvs = make_tunnel_fields("10.1.0.32", "IPINIP", None);
+            fvs.push((field.to_string(), value.to_string()));
+            assert!(mgr.do_tunnel_add("MuxTunnel0", &fvs).await.is_err());
+        }
+
+        assert!(mgr.get_captured_commands().is_empty());
+        assert!(mgr.app_db_ops.is_empty());
+        assert!(!mgr.tunnel_cache.contains_key("MuxTunnel0"));
+    }
+
+    #[tokio::test]
+    async fn test_tunnel_endpoint_change_preserves_routes() {
+        let mut mgr = TunnelMgr::new_mock().with_peer_ip("10.1.0.33".to_string());
+
+        let fvs = make_tunnel_fields("10.1.0.32", "IPINIP", Some("10.0.0.1"));
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+        for prefix in ["192.168.1.0/24", "192.168.2.0/24"] {
+            mgr.do_tunnel_route_task(prefix, "SET", &vec![])
+                .await
+                .unwrap();
+        }
+
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+        let fvs = make_tunnel_fields("10.1.0.40", "IPINIP", Some("10.0.0.1"));
+        assert!(mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap());
+
+        let cmds = mgr.get_captured_commands();
+        assert_eq!(cmds.len(), 5);
+        assert!(cmds[0].contains("ip tunnel del tun0"));
+        assert!(cmds[1].contains("ip tunnel add") && cmds[1].contains("10.1.0.40"));
+        assert!(cmds[2].contains("ip link set dev tun0 up"));
+        assert!(cmds[3].contains("route replace") && cmds[3].contains("192.168.1.0/24"));
+        assert!(cmds[4].contains("route replace") && cmds[4].contains("192.168.2.0/24"));
+
+        // The new term is published before the old one is removed
+        assert!(matches!(
+            &mgr.app_db_ops[0],
+            AppDbOp::Set(APP_TUNNEL_DECAP_TABLE, key, _) if key == "MuxTunnel0"
+        ));
+        assert_eq!(
+            mgr.app_db_ops[1],
+            AppDbOp::Set(
+                APP_TUNNEL_DECAP_TERM_TABLE,
+                "MuxTunnel0:10.1.0.40".to_string(),
+                vec![
+                    ("term_type".to_string(), "P2P".to_string()),
+                    ("src_ip".to_string(), "10.0.0.1".to_string()),
+                ],
+            )
+        );
+        assert_eq!(
+            mgr.app_db_ops[2],
+            AppDbOp::Del(
+                APP_TUNNEL_DECAP_TERM_TABLE,
+                "MuxTunnel0:10.1.0.32".to_string()
+            )
+        );
+        assert_eq!(mgr.tunnel_cache["MuxTunnel0"].dst_ip, "10.1.0.40");
+    }
+
+    #[tokio::test]
+    async fn test_tunnel_src_ip_removed() {
+        let mut mgr = TunnelMgr::new_mock().with_peer_ip("10.1.0.33".to_string());
+
+        let fvs = make_tunnel_fields("10.1.0.32", "IPINIP", Some("10.0.0.1"));
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+        let fvs = make_tunnel_fields("10.1.0.32", "IPINIP", None);
+        mgr.do_tunnel_add("MuxTunnel0", &fvs).await.unwrap();
+
+        assert!(mgr.get_captured_commands()[0].contains("ip tunnel del tun0"));
+        assert!(mgr.app_db_ops.contains(&AppDbOp::Set(
+            APP_TUNNEL_DECAP_TERM_TABLE,
+            "MuxTunnel0:10.1.0.32".to_string(),
+            vec![("term_type".to_string(), "P2MP".to_string())],
+        )));
+        assert!(mgr.app_db_ops.contains(&AppDbOp::HDel(
+            APP_TUNNEL_DECAP_TERM_TABLE,
+            "MuxTunnel0:10.1.0.32".to_string(),
+            vec!["src_ip".to_string()],
+        )));
+        assert!(!mgr.tunnel_cache["MuxTunnel0"].is_p2p());
+    }
+
+    #[tokio::test]
+    async fn test_route_tracking() {
+        let mut mgr = TunnelMgr::new_mock();
+
+        mgr.do_tunnel_route_task("192.168.1.0/24", "SET", &vec![])
+            .await
+            .unwrap();
+        assert_eq!(mgr.tunnel_routes.len(), 1);
+
+        mgr.do_tunnel_route_task("192.168.1.0/24", "DEL", &vec![])
+            .await
+            .unwrap();
+        assert!(mgr.tunnel_routes.is_empty());
+    }
+
     #[tokio::test]
     async fn test_warm_restart_state() {
         let mut mgr = TunnelMgr::new();
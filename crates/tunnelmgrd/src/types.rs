@@ -1,5 +1,7 @@
 //! Tunnel type definitions and constants
 
+use crate::tables::{qos_map_tables, tunnel_fields};
+
 /// Tunnel type identifier for IP-in-IP tunnels
 pub const TUNNEL_TYPE_IPINIP: &str = "IPINIP";
 
@@ -9,8 +11,56 @@ pub const TUNNEL_INTERFACE: &str = "tun0";
 /// Loopback interface used as tunnel source
 pub const LOOPBACK_SRC: &str = "Loopback3";
 
+/// Accepted encap_ecn_mode values
+pub const ENCAP_ECN_MODES: &[&str] = &["standard", "user_defined"];
+
+/// Validate a dual-ToR QoS field of a TUNNEL entry
+///
+/// The map fields name an entry of their QoS map table, either bare
+/// (`AZURE`) or as a reference (`[DSCP_TO_TC_MAP|AZURE]`). Returns None for
+/// fields that are not QoS fields.
+pub fn validate_qos_field(field: &str, value: &str) -> Option<Result<(), String>> {
+    let map_table = match field {
+        tunnel_fields::DECAP_DSCP_TO_TC_MAP => qos_map_tables::DSCP_TO_TC_MAP,
+        tunnel_fields::DECAP_TC_TO_PG_MAP => qos_map_tables::TC_TO_PRIORITY_GROUP_MAP,
+        tunnel_fields::ENCAP_ECN_MODE => {
+            return Some(if ENCAP_ECN_MODES.contains(&value) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Invalid {} '{}', expected one of {:?}",
+                    field, value, ENCAP_ECN_MODES
+                ))
+            });
+        }
+        _ => return None,
+    };
+
+    let name = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(reference) => match reference.split_once('|') {
+            Some((table, name)) if table == map_table => name,
+            _ => {
+                return Some(Err(format!(
+                    "{} must reference {}, got '{}'",
+                    field, map_table, value
+                )))
+            }
+        },
+        None => value,
+    };
+    let valid = !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '|' | '[' | ']'));
+    Some(if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid {} map name '{}'", field, value))
+    })
+}
+
 /// Simple IP prefix representation
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IpPrefix {
     prefix: String,
 }
@@ -114,6 +164,38 @@ mod tests {
         assert_eq!(info.src_ip, None);
     }
 
+    #[test]
+    fn test_validate_qos_map_fields() {
+        for (field, value) in [
+            ("decap_dscp_to_tc_map", "AZURE_TUNNEL"),
+            ("decap_dscp_to_tc_map", "[DSCP_TO_TC_MAP|AZURE_TUNNEL]"),
+            ("decap_tc_to_pg_map", "AZURE_TUNNEL"),
+            (
+                "decap_tc_to_pg_map",
+                "[TC_TO_PRIORITY_GROUP_MAP|AZURE_TUNNEL]",
+            ),
+            ("encap_ecn_mode", "standard"),
+        ] {
+            assert_eq!(validate_qos_field(field, value), Some(Ok(())), "{}", value);
+        }
+
+        for (field, value) in [
+            ("decap_dscp_to_tc_map", ""),
+            ("decap_dscp_to_tc_map", "[TC_TO_PRIORITY_GROUP_MAP|AZURE]"),
+            ("decap_tc_to_pg_map", "[TC_TO_PRIORITY_GROUP_MAP|]"),
+            ("decap_tc_to_pg_map", "AZURE TUNNEL"),
+            ("encap_ecn_mode", "copy_from_outer"),
+        ] {
+            assert!(
+                matches!(validate_qos_field(field, value), Some(Err(_))),
+                "{}",
+                value
+            );
+        }
+
+        assert_eq!(validate_qos_field("dscp_mode", "uniform"), None);
+    }
+
     #[test]
     fn test_tunnel_type_constant() {
         assert_eq!(TUNNEL_TYPE_IPINIP, "IPINIP");
@@ -4,7 +4,8 @@ use crate::commands::{
     build_check_bridge_exists_cmd, build_init_bridge_cmd, build_no_linklocal_learn_cmd,
     build_vlan_filtering_cmd,
 };
-use sonic_cfgmgr_common::{shell, CfgMgrResult};
+use sonic_cfgmgr_common::shell::Executor;
+use sonic_cfgmgr_common::CfgMgrResult;
 use tracing::{debug, info};
 
 /// Initialize the dot1q bridge
@@ -12,7 +13,11 @@ use tracing::{debug, info};
 /// Creates the Bridge interface with VLAN filtering enabled.
 /// This is called on daemon startup unless warm restart is active
 /// and the bridge already exists.
-pub async fn init_bridge(mac_address: &str, mock_mode: bool) -> CfgMgrResult<()> {
+pub async fn init_bridge(
+    executor: &dyn Executor,
+    mac_address: &str,
+    mock_mode: bool,
+) -> CfgMgrResult<()> {
     info!("Initializing dot1q bridge");
 
     if mock_mode {
@@ -22,17 +27,17 @@ pub async fn init_bridge(mac_address: &str, mock_mode: bool) -> CfgMgrResult<()>
 
     // Create bridge with dummy interface
     let init_cmd = build_init_bridge_cmd(mac_address);
-    shell::exec(&init_cmd).await?;
+    executor.exec(&init_cmd).await?;
     info!("Bridge created successfully");
 
     // Enable VLAN filtering
     let vlan_filter_cmd = build_vlan_filtering_cmd();
-    shell::exec(&vlan_filter_cmd).await?;
+    executor.exec(&vlan_filter_cmd).await?;
     info!("VLAN filtering enabled");
 
     // Disable link-local learning
     let no_ll_cmd = build_no_linklocal_learn_cmd();
-    shell::exec(&no_ll_cmd).await?;
+    executor.exec(&no_ll_cmd).await?;
     info!("Link-local learning disabled");
 
     Ok(())
@@ -42,13 +47,13 @@ pub async fn init_bridge(mac_address: &str, mock_mode: bool) -> CfgMgrResult<()>
 ///
 /// Used during warm restart to determine if bridge initialization
 /// should be skipped.
-pub async fn bridge_exists(mock_mode: bool) -> CfgMgrResult<bool> {
+pub async fn bridge_exists(executor: &dyn Executor, mock_mode: bool) -> CfgMgrResult<bool> {
     if mock_mode {
         return Ok(false);
     }
 
     let check_cmd = build_check_bridge_exists_cmd();
-    match shell::exec(&check_cmd).await {
+    match executor.exec(&check_cmd).await {
         Ok(_) => {
             info!("Bridge already exists");
             Ok(true)
@@ -63,18 +68,19 @@ pub async fn bridge_exists(mock_mode: bool) -> CfgMgrResult<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sonic_cfgmgr_common::shell::ShellExecutor;
 
     #[tokio::test]
     async fn test_init_bridge_mock_mode() {
         // In mock mode, should succeed without executing commands
-        let result = init_bridge("00:11:22:33:44:55", true).await;
+        let result = init_bridge(&ShellExecutor, "00:11:22:33:44:55", true).await;
         assert!(result.is_ok());
     }
 
     #[tokio::test]
     async fn test_bridge_exists_mock_mode() {
         // In mock mode, should always return false
-        let result = bridge_exists(true).await;
+        let result = bridge_exists(&ShellExecutor, true).await;
         assert!(result.is_ok());
         assert!(!result.unwrap());
     }
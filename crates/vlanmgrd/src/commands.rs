@@ -1,4 +1,9 @@
 //! Shell command builders for VLAN operations
+//!
+//! Single-link changes (admin state, MTU, member tagging mode) go through
+//! the manager's [`CfgBackend`](sonic_cfgmgr_common::CfgBackend); the
+//! builders here cover the compound bridge and VLAN commands it has no
+//! operation for.
 
 use sonic_cfgmgr_common::shell;
 
@@ -129,51 +134,38 @@ pub fn build_remove_vlan_cmd(vlan_id: u16) -> String {
     )
 }
 
-/// Build set VLAN admin state command
-pub fn build_set_vlan_admin_cmd(vlan_id: u16, admin_status: &str) -> String {
-    let admin_quoted = shell::shellquote(admin_status);
-    format!(
-        "{} link set {}{} {}",
-        shell::IP_CMD,
-        VLAN_PREFIX,
-        vlan_id,
-        admin_quoted
-    )
-}
-
-/// Build set VLAN MTU command
-pub fn build_set_vlan_mtu_cmd(vlan_id: u16, mtu: u32) -> String {
-    format!(
-        "{} link set {}{} mtu {}",
-        shell::IP_CMD,
-        VLAN_PREFIX,
-        vlan_id,
-        mtu
-    )
-}
-
 /// Build set VLAN MAC address command
+///
+/// Only the VLAN interface changes; the Bridge keeps the system MAC.
 pub fn build_set_vlan_mac_cmd(vlan_id: u16, mac: &str) -> String {
-    let mac_quoted = shell::shellquote(mac);
-    let bridge_down = format!("{} link set {} down", shell::IP_CMD, DOT1Q_BRIDGE_NAME);
-    let set_mac = format!(
-        "{} link set {}{} address {} && {} link set {} address {}",
+    format!(
+        "{} link set dev {}{} address {}",
         shell::IP_CMD,
         VLAN_PREFIX,
         vlan_id,
-        mac_quoted,
-        shell::IP_CMD,
-        DOT1Q_BRIDGE_NAME,
-        mac_quoted
-    );
-    let bridge_up = format!("{} link set {} up", shell::IP_CMD, DOT1Q_BRIDGE_NAME);
-
-    format!("{} && {} && {}", bridge_down, set_mac, bridge_up)
+        shell::shellquote(mac)
+    )
 }
 
 /// Build add VLAN member command
-pub fn build_add_vlan_member_cmd(vlan_id: u16, port_alias: &str, tagging_cmd: &str) -> String {
+pub fn build_add_vlan_member_cmd(
+    vlan_id: u16,
+    port_alias: &str,
+    tagging_cmd: &str,
+    attach: bool,
+) -> String {
     let port_quoted = shell::shellquote(port_alias);
+    if !attach {
+        return format!(
+            "{} vlan add vid {} dev {} {}",
+            shell::BRIDGE_CMD,
+            vlan_id,
+            port_quoted,
+            tagging_cmd
+        )
+        .trim_end()
+        .to_string();
+    }
     let inner = format!(
         "{} link set {} master {} && \
          {} vlan del vid {} dev {} && \
@@ -194,31 +186,22 @@ pub fn build_add_vlan_member_cmd(vlan_id: u16, port_alias: &str, tagging_cmd: &s
 
 /// Build remove VLAN member command
 ///
-/// This command is complex: it removes the VLAN from the port, then checks if
-/// the port has any remaining VLANs. If not, it detaches the port from the bridge.
-pub fn build_remove_vlan_member_cmd(vlan_id: u16, port_alias: &str) -> String {
+/// Removes the VLAN from the port; `detach` also takes the port off the
+/// bridge, for its last VLAN.
+pub fn build_remove_vlan_member_cmd(vlan_id: u16, port_alias: &str, detach: bool) -> String {
     let port_quoted = shell::shellquote(port_alias);
-    let inner = format!(
-        r#"{} vlan del vid {} dev {} && \
-           ( vlanShow=$({} vlan show dev {}); \
-           ret=$?; \
-           if [ $ret -eq 0 ]; then \
-           if (! echo "$vlanShow" | {} -q {}) \
-             || (echo "$vlanShow" | {} -q None$) \
-             || (echo "$vlanShow" | {} -q {}$); then \
-           {} link set {} nomaster; \
-           fi; \
-           else exit $ret; fi )"#,
+    let del = format!(
+        "{} vlan del vid {} dev {}",
         shell::BRIDGE_CMD,
         vlan_id,
-        port_quoted,
-        shell::BRIDGE_CMD,
-        port_quoted,
-        shell::GREP_CMD,
-        port_quoted,
-        shell::GREP_CMD,
-        shell::GREP_CMD,
-        port_quoted,
+        port_quoted
+    );
+    if !detach {
+        return del;
+    }
+    let inner = format!(
+        "{} && {} link set {} nomaster",
+        del,
         shell::IP_CMD,
         port_quoted
     );
@@ -260,39 +243,42 @@ mod tests {
     }
 
     #[test]
-    fn test_build_set_vlan_admin_cmd() {
-        let cmd = build_set_vlan_admin_cmd(100, "up");
-        assert!(cmd.contains("Vlan100"));
-        assert!(cmd.contains("up"));
-    }
-
-    #[test]
-    fn test_build_set_vlan_mtu_cmd() {
-        let cmd = build_set_vlan_mtu_cmd(100, 1500);
-        assert!(cmd.contains("Vlan100"));
-        assert!(cmd.contains("mtu 1500"));
+    fn test_build_set_vlan_mac_cmd() {
+        let cmd = build_set_vlan_mac_cmd(100, "00:11:22:33:44:55");
+        assert_eq!(
+            cmd,
+            "/sbin/ip link set dev Vlan100 address \"00:11:22:33:44:55\""
+        );
+        assert!(!cmd.contains(DOT1Q_BRIDGE_NAME));
     }
 
     #[test]
     fn test_build_add_vlan_member_cmd() {
-        let cmd = build_add_vlan_member_cmd(100, "Ethernet0", "pvid untagged");
+        let cmd = build_add_vlan_member_cmd(100, "Ethernet0", "pvid untagged", true);
         assert!(cmd.contains("Ethernet0"));
+        assert!(cmd.contains("master Bridge"));
         assert!(cmd.contains("vid 100"));
         assert!(cmd.contains("pvid untagged"));
+
+        let cmd = build_add_vlan_member_cmd(200, "Ethernet0", "", false);
+        assert_eq!(cmd, "/sbin/bridge vlan add vid 200 dev \"Ethernet0\"");
     }
 
     #[test]
     fn test_build_remove_vlan_member_cmd() {
-        let cmd = build_remove_vlan_member_cmd(100, "Ethernet0");
+        let cmd = build_remove_vlan_member_cmd(100, "Ethernet0", true);
         assert!(cmd.contains("vlan del vid 100"));
         assert!(cmd.contains("Ethernet0"));
         assert!(cmd.contains("nomaster"));
+
+        let cmd = build_remove_vlan_member_cmd(100, "Ethernet0", false);
+        assert_eq!(cmd, "/sbin/bridge vlan del vid 100 dev \"Ethernet0\"");
     }
 
     #[test]
     fn test_shellquote_safety() {
         // Test that dangerous characters are properly quoted
-        let cmd = build_add_vlan_member_cmd(100, "Ethernet0; rm -rf /", "");
+        let cmd = build_add_vlan_member_cmd(100, "Ethernet0; rm -rf /", "", true);
         // The inner command gets shellquoted, which means the quotes around the port
         // name get escaped. This prevents command injection.
         assert!(cmd.contains("\\\"Ethernet0; rm -rf /\\\""));
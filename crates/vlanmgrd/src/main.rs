@@ -3,9 +3,10 @@
 //! Entry point for the vlanmgrd daemon.
 
 use std::process::ExitCode;
-use tracing::{info, Level};
+use tracing::{error, info, Level};
 use tracing_subscriber::FmtSubscriber;
 
+use sonic_cfgmgr_common::BackendKind;
 use sonic_vlanmgrd::VlanMgr;
 
 /// Initializes tracing/logging subsystem
@@ -24,7 +25,20 @@ async fn main() -> ExitCode {
 
     info!("--- Starting vlanmgrd (Rust) ---");
 
-    let _mgr = VlanMgr::new();
+    let backend_kind = BackendKind::from_env();
+    let backend = match backend_kind.create() {
+        Ok(backend) => backend,
+        Err(e) => {
+            error!("Failed to create {} backend: {}", backend_kind, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    info!(
+        "Applying link changes through the {} backend",
+        backend.name()
+    );
+
+    let _mgr = VlanMgr::new().with_backend(backend);
 
     // TODO: Implement event loop when swss-common bindings are ready
     // For now, this is a placeholder that demonstrates the daemon structure
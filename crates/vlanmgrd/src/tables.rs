@@ -30,6 +30,9 @@ pub const STATE_VLAN_TABLE_NAME: &str = "VLAN_TABLE";
 /// STATE_DB VLAN_MEMBER table name
 pub const STATE_VLAN_MEMBER_TABLE_NAME: &str = "VLAN_MEMBER_TABLE";
 
+/// STATE_DB `state` value of a VLAN or member that is ready
+pub const STATE_OK: &str = "ok";
+
 /// Field names
 pub mod fields {
     /// VLAN ID field
@@ -49,4 +52,13 @@ pub mod fields {
 
     /// Untagged members field
     pub const UNTAGGED_MEMBERS: &str = "untagged_members";
+
+    /// DHCP relay server list field
+    pub const DHCP_SERVERS: &str = "dhcp_servers";
+
+    /// DHCPv6 relay server list field
+    pub const DHCPV6_SERVERS: &str = "dhcpv6_servers";
+
+    /// STATE_DB readiness field
+    pub const STATE: &str = "state";
 }
@@ -32,6 +32,16 @@ impl VlanInfo {
     }
 }
 
+/// Parses a colon-separated MAC address, returning it in lowercase
+pub fn parse_mac(mac: &str) -> Option<String> {
+    let octets: Vec<&str> = mac.split(':').collect();
+    let valid = octets.len() == 6
+        && octets
+            .iter()
+            .all(|o| o.len() == 2 && o.chars().all(|c| c.is_ascii_hexdigit()));
+    valid.then(|| mac.to_ascii_lowercase())
+}
+
 /// VLAN member information
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VlanMemberInfo {
@@ -88,6 +98,13 @@ impl TaggingMode {
         }
     }
 
+    /// Whether the member is the port's PVID (egresses untagged)
+    ///
+    /// A port can have at most one such VLAN.
+    pub fn is_untagged(&self) -> bool {
+        matches!(self, TaggingMode::Untagged | TaggingMode::PriorityTagged)
+    }
+
     /// Convert to bridge command argument
     pub fn to_bridge_cmd(&self) -> &str {
         match self {
@@ -110,6 +127,19 @@ mod tests {
         assert!(info.members.is_empty());
     }
 
+    #[test]
+    fn test_parse_mac() {
+        assert_eq!(
+            parse_mac("00:AA:bb:33:44:55").as_deref(),
+            Some("00:aa:bb:33:44:55")
+        );
+        assert!(parse_mac("00:11:22:33:44").is_none());
+        assert!(parse_mac("00:11:22:33:44:5").is_none());
+        assert!(parse_mac("00-11-22-33-44-55").is_none());
+        assert!(parse_mac("00:11:22:33:44:zz").is_none());
+        assert!(parse_mac("").is_none());
+    }
+
     #[test]
     fn test_tagging_mode_from_str() {
         assert_eq!(
@@ -137,6 +167,13 @@ mod tests {
         assert_eq!(TaggingMode::PriorityTagged.to_bridge_cmd(), "pvid untagged");
     }
 
+    #[test]
+    fn test_tagging_mode_is_untagged() {
+        assert!(!TaggingMode::Tagged.is_untagged());
+        assert!(TaggingMode::Untagged.is_untagged());
+        assert!(TaggingMode::PriorityTagged.is_untagged());
+    }
+
     #[test]
     fn test_vlan_member_info_new() {
         let member = VlanMemberInfo::new(100, "Ethernet0", TaggingMode::Untagged);
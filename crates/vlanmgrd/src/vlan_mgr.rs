@@ -1,18 +1,47 @@
 //! VlanMgr - Core VLAN configuration manager implementation
 
 use async_trait::async_trait;
-use std::collections::{HashMap, HashSet};
-use tracing::{debug, info, instrument, warn};
-
-use sonic_cfgmgr_common::{shell, CfgMgr, CfgMgrResult, FieldValues, Orch, WarmRestartState};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use tracing::{debug, error, info, instrument, warn};
+
+use sonic_cfgmgr_common::nl::BridgeVlan;
+use sonic_cfgmgr_common::shell::{Executor, ShellExecutor};
+use sonic_cfgmgr_common::{
+    CfgBackend, CfgMgr, CfgMgrError, CfgMgrResult, DbId, FieldValues, FieldValuesExt,
+    KeyOpFieldsValues, Orch, ShellBackend, VlanRangeList, WarmRestartHelper, WarmRestartState,
+    WarmRestartStore,
+};
 
 use crate::commands::{
     build_add_vlan_cmd, build_add_vlan_member_cmd, build_arp_evict_nocarrier_cmd,
-    build_remove_vlan_cmd, build_remove_vlan_member_cmd, build_set_vlan_admin_cmd,
-    build_set_vlan_mac_cmd, build_set_vlan_mtu_cmd, LAG_PREFIX, VLAN_PREFIX,
+    build_remove_vlan_cmd, build_remove_vlan_member_cmd, build_set_vlan_mac_cmd, LAG_PREFIX,
+    VLAN_PREFIX,
+};
+use crate::tables::{
+    fields, APP_VLAN_MEMBER_TABLE_NAME, APP_VLAN_TABLE_NAME, CFG_VLAN_MEMBER_TABLE_NAME,
+    CFG_VLAN_TABLE_NAME, STATE_OK, STATE_VLAN_MEMBER_TABLE_NAME, STATE_VLAN_TABLE_NAME,
 };
-use crate::tables::{fields, CFG_VLAN_MEMBER_TABLE_NAME, CFG_VLAN_TABLE_NAME};
-use crate::types::{TaggingMode, VlanInfo};
+use crate::types::{parse_mac, TaggingMode, VlanInfo};
+
+/// An APPL_DB write, recorded in tests
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq)]
+enum AppDbOp {
+    Set(&'static str, String, FieldValues),
+    HDel(&'static str, String, Vec<String>),
+    Del(&'static str, String),
+}
+
+/// Whether a VLAN field is a DHCP relay server list
+///
+/// CONFIG_DB list fields may carry a trailing '@'.
+fn is_dhcp_servers_field(field: &str) -> bool {
+    matches!(
+        field.trim_end_matches('@'),
+        fields::DHCP_SERVERS | fields::DHCPV6_SERVERS
+    )
+}
 
 /// VlanMgr manages VLAN configuration
 ///
@@ -26,17 +55,34 @@ pub struct VlanMgr {
     /// VLAN information cache (vlan_id -> VlanInfo)
     vlan_info: HashMap<u16, VlanInfo>,
 
+    /// VLAN_TABLE fields last published per VLAN
+    vlan_app_fields: HashMap<String, FieldValues>,
+
     /// Port to VLAN membership: port -> vlan -> tagging_mode
-    port_vlan_member: HashMap<String, HashMap<String, String>>,
+    port_vlan_member: HashMap<String, HashMap<String, TaggingMode>>,
 
     /// Warm restart replay lists
     vlan_replay: HashSet<String>,
     vlan_member_replay: HashSet<String>,
-    replay_done: bool,
+
+    /// Warm restart state, present when warm restart is configured
+    warm_restart: Option<WarmRestartHelper>,
 
     /// Global MAC address
     global_mac: Option<String>,
 
+    /// Runs the compound `ip`/`bridge` commands
+    executor: Arc<dyn Executor>,
+
+    /// Applies admin state, MTU and member tagging mode changes
+    backend: Arc<dyn CfgBackend>,
+
+    /// Store the APPL_DB and STATE_DB entries are published to, if attached
+    store: Option<Arc<dyn WarmRestartStore>>,
+
+    /// Queued (table, entry) updates
+    tasks: VecDeque<(String, KeyOpFieldsValues)>,
+
     /// Mock mode for testing
     #[cfg(test)]
     mock_mode: bool,
@@ -44,6 +90,10 @@ pub struct VlanMgr {
     /// Captured commands in mock mode
     #[cfg(test)]
     captured_commands: Vec<String>,
+
+    /// Captured APPL_DB writes
+    #[cfg(test)]
+    app_db_ops: Vec<AppDbOp>,
 }
 
 impl VlanMgr {
@@ -52,16 +102,184 @@ impl VlanMgr {
         Self {
             vlans: HashSet::new(),
             vlan_info: HashMap::new(),
+            vlan_app_fields: HashMap::new(),
             port_vlan_member: HashMap::new(),
             vlan_replay: HashSet::new(),
             vlan_member_replay: HashSet::new(),
-            replay_done: false,
+            warm_restart: None,
             global_mac: None,
+            executor: Arc::new(ShellExecutor),
+            backend: Arc::new(ShellBackend),
+            store: None,
+            tasks: VecDeque::new(),
             #[cfg(test)]
             mock_mode: false,
             #[cfg(test)]
             captured_commands: Vec::new(),
+            #[cfg(test)]
+            app_db_ops: Vec::new(),
+        }
+    }
+
+    /// Attaches the warm restart helper
+    pub fn with_warm_restart(mut self, helper: WarmRestartHelper) -> Self {
+        self.warm_restart = Some(helper);
+        self
+    }
+
+    /// Sets the executor that runs the compound `ip`/`bridge` commands
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Sets the backend that applies single-link changes
+    pub fn with_backend(mut self, backend: Arc<dyn CfgBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Publishes APPL_DB and STATE_DB entries through `store`
+    pub fn with_store(mut self, store: Arc<dyn WarmRestartStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Writes an entry to a table of the attached store
+    async fn store_set(
+        &self,
+        db: DbId,
+        table: &str,
+        key: &str,
+        fvs: &FieldValues,
+    ) -> CfgMgrResult<()> {
+        match &self.store {
+            Some(store) => store.set(db, table, key, fvs).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Deletes an entry from a table of the attached store
+    async fn store_del(&self, db: DbId, table: &str, key: &str) -> CfgMgrResult<()> {
+        match &self.store {
+            Some(store) => store.del(db, table, key).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Marks a VLAN or VLAN member ready in STATE_DB
+    async fn set_state_ok(&self, table: &str, key: &str) -> CfgMgrResult<()> {
+        let fvs = vec![(fields::STATE.to_string(), STATE_OK.to_string())];
+        self.store_set(DbId::StateDb, table, key, &fvs).await
+    }
+
+    /// Notes an APPL_DB write for warm restart reconciliation
+    fn record_app_write(&mut self, table: &str, key: &str) {
+        if let Some(helper) = self.warm_restart.as_mut() {
+            helper.record(table, key);
+        }
+    }
+
+    /// Writes a VLAN member to APPL_DB VLAN_MEMBER_TABLE
+    async fn write_vlan_member_to_app_db(
+        &mut self,
+        key: &str,
+        tagging_mode: TaggingMode,
+    ) -> CfgMgrResult<()> {
+        // TODO: Use ProducerStateTable
+        let fvs = vec![(
+            fields::TAGGING_MODE.to_string(),
+            tagging_mode.as_str().to_string(),
+        )];
+        debug!("Writing {}:{} {:?}", APP_VLAN_MEMBER_TABLE_NAME, key, fvs);
+        self.store_set(DbId::ApplDb, APP_VLAN_MEMBER_TABLE_NAME, key, &fvs)
+            .await?;
+        #[cfg(test)]
+        self.app_db_ops.push(AppDbOp::Set(
+            APP_VLAN_MEMBER_TABLE_NAME,
+            key.to_string(),
+            fvs,
+        ));
+        self.record_app_write(APP_VLAN_MEMBER_TABLE_NAME, key);
+        Ok(())
+    }
+
+    /// Deletes a VLAN member from APPL_DB VLAN_MEMBER_TABLE
+    async fn delete_vlan_member_from_app_db(&mut self, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", APP_VLAN_MEMBER_TABLE_NAME, key);
+        self.store_del(DbId::ApplDb, APP_VLAN_MEMBER_TABLE_NAME, key)
+            .await?;
+        #[cfg(test)]
+        self.app_db_ops
+            .push(AppDbOp::Del(APP_VLAN_MEMBER_TABLE_NAME, key.to_string()));
+        Ok(())
+    }
+
+    /// Publishes a VLAN to APPL_DB VLAN_TABLE
+    ///
+    /// Only fields that differ from the last published entry are written,
+    /// and fields that disappeared are deleted, so a DHCP server list change
+    /// is a single field update.
+    async fn write_vlan_to_app_db(&mut self, key: &str, fvs: FieldValues) -> CfgMgrResult<()> {
+        let published = self.vlan_app_fields.get(key);
+        let changed: FieldValues = fvs
+            .iter()
+            .filter(|(field, value)| {
+                published.and_then(|old| old.get_field(field)) != Some(value.as_str())
+            })
+            .cloned()
+            .collect();
+        let removed: Vec<String> = published
+            .map(|old| {
+                old.iter()
+                    .map(|(field, _)| field)
+                    .filter(|field| !fvs.has_field(field))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // TODO: Use ProducerStateTable
+        if !removed.is_empty() {
+            // The store has no field delete: rewrite the entry
+            self.store_del(DbId::ApplDb, APP_VLAN_TABLE_NAME, key)
+                .await?;
+            self.store_set(DbId::ApplDb, APP_VLAN_TABLE_NAME, key, &fvs)
+                .await?;
+        } else if !changed.is_empty() || published.is_none() {
+            self.store_set(DbId::ApplDb, APP_VLAN_TABLE_NAME, key, &changed)
+                .await?;
+        }
+        if !changed.is_empty() || published.is_none() {
+            debug!("Writing {}:{} {:?}", APP_VLAN_TABLE_NAME, key, changed);
+            #[cfg(test)]
+            self.app_db_ops
+                .push(AppDbOp::Set(APP_VLAN_TABLE_NAME, key.to_string(), changed));
         }
+        if !removed.is_empty() {
+            debug!(
+                "Deleting {:?} from {}:{}",
+                removed, APP_VLAN_TABLE_NAME, key
+            );
+            #[cfg(test)]
+            self.app_db_ops
+                .push(AppDbOp::HDel(APP_VLAN_TABLE_NAME, key.to_string(), removed));
+        }
+        self.record_app_write(APP_VLAN_TABLE_NAME, key);
+        self.vlan_app_fields.insert(key.to_string(), fvs);
+        Ok(())
+    }
+
+    /// Deletes a VLAN from APPL_DB VLAN_TABLE
+    async fn delete_vlan_from_app_db(&mut self, key: &str) -> CfgMgrResult<()> {
+        debug!("Deleting {}:{}", APP_VLAN_TABLE_NAME, key);
+        self.store_del(DbId::ApplDb, APP_VLAN_TABLE_NAME, key)
+            .await?;
+        #[cfg(test)]
+        self.app_db_ops
+            .push(AppDbOp::Del(APP_VLAN_TABLE_NAME, key.to_string()));
+        self.vlan_app_fields.remove(key);
+        Ok(())
     }
 
     /// Enables mock mode for testing
@@ -86,10 +304,22 @@ impl VlanMgr {
             return Ok(());
         }
 
-        shell::exec(cmd).await?;
+        self.executor.exec(cmd).await?;
         Ok(())
     }
 
+    /// Whether to capture `cmd` instead of calling the backend
+    ///
+    /// `cmd` is the command [`ShellBackend`] would run for the operation.
+    #[cfg(test)]
+    fn capture_backend_op(&mut self, cmd: String) -> bool {
+        if self.mock_mode {
+            info!("Mock backend: {}", cmd);
+            self.captured_commands.push(cmd);
+        }
+        self.mock_mode
+    }
+
     /// Set global MAC address
     pub fn set_global_mac(&mut self, mac: impl Into<String>) {
         self.global_mac = Some(mac.into());
@@ -100,9 +330,13 @@ impl VlanMgr {
         self.global_mac.is_some()
     }
 
-    /// Extract VLAN ID from key like "Vlan100"
+    /// Extract VLAN ID from key like "Vlan100"; IDs outside 1-4094 are
+    /// rejected
     fn extract_vlan_id(key: &str) -> Option<u16> {
-        key.strip_prefix(VLAN_PREFIX)?.parse().ok()
+        key.strip_prefix(VLAN_PREFIX)?
+            .parse()
+            .ok()
+            .filter(|id| VlanRangeList::is_valid_id(*id))
     }
 
     /// Parse VLAN member key "Vlan100|Ethernet0" into (vlan_id, port_alias)
@@ -154,8 +388,25 @@ impl VlanMgr {
         vlan_id: u16,
         admin_status: &str,
     ) -> CfgMgrResult<bool> {
-        let cmd = build_set_vlan_admin_cmd(vlan_id, admin_status);
-        self.exec(&cmd).await?;
+        let up = match admin_status {
+            "up" => true,
+            "down" => false,
+            _ => {
+                warn!(
+                    "Invalid admin status '{}' for VLAN {}",
+                    admin_status, vlan_id
+                );
+                return Ok(false);
+            }
+        };
+        let vlan = format!("{}{}", VLAN_PREFIX, vlan_id);
+
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::set_link_admin_cmd(&vlan, up)) {
+            return Ok(true);
+        }
+
+        self.backend.set_link_admin(&vlan, up).await?;
 
         info!("Set VLAN {} admin state to {}", vlan_id, admin_status);
         Ok(true)
@@ -164,8 +415,14 @@ impl VlanMgr {
     /// Set VLAN MTU
     #[instrument(skip(self))]
     pub async fn set_host_vlan_mtu(&mut self, vlan_id: u16, mtu: u32) -> CfgMgrResult<bool> {
-        let cmd = build_set_vlan_mtu_cmd(vlan_id, mtu);
-        match self.exec(&cmd).await {
+        let vlan = format!("{}{}", VLAN_PREFIX, vlan_id);
+
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::set_link_mtu_cmd(&vlan, mtu)) {
+            return Ok(true);
+        }
+
+        match self.backend.set_link_mtu(&vlan, mtu).await {
             Ok(_) => {
                 info!("Set VLAN {} MTU to {}", vlan_id, mtu);
                 Ok(true)
@@ -199,7 +456,9 @@ impl VlanMgr {
         tagging_mode: TaggingMode,
     ) -> CfgMgrResult<bool> {
         let tagging_cmd = tagging_mode.to_bridge_cmd();
-        let cmd = build_add_vlan_member_cmd(vlan_id, port_alias, tagging_cmd);
+        // Only the first VLAN attaches the port to the bridge
+        let attach = self.port_vlans(port_alias).is_empty();
+        let cmd = build_add_vlan_member_cmd(vlan_id, port_alias, tagging_cmd, attach);
 
         // Handle LAG race condition with retry
         match self.exec(&cmd).await {
@@ -220,6 +479,64 @@ impl VlanMgr {
         }
     }
 
+    /// Change the tagging mode of an existing VLAN member in place
+    ///
+    /// Re-adding an existing VLAN to a port rewrites its flags: untagged
+    /// makes the VLAN the PVID, tagged clears the PVID and untagged flags.
+    /// The port never leaves the VLAN.
+    #[instrument(skip(self))]
+    pub async fn set_host_vlan_member_mode(
+        &mut self,
+        vlan_id: u16,
+        port_alias: &str,
+        tagging_mode: TaggingMode,
+    ) -> CfgMgrResult<bool> {
+        let vlan = if tagging_mode.is_untagged() {
+            BridgeVlan::untagged(vlan_id)
+        } else {
+            BridgeVlan::tagged(vlan_id)
+        };
+
+        #[cfg(test)]
+        if self.capture_backend_op(ShellBackend::bridge_vlan_cmd(port_alias, &vlan, true)) {
+            return Ok(true);
+        }
+
+        self.backend.bridge_vlan_add(port_alias, &vlan).await?;
+
+        info!(
+            "Changed {} in VLAN {} to {}",
+            port_alias,
+            vlan_id,
+            tagging_mode.as_str()
+        );
+        Ok(true)
+    }
+
+    /// VLANs a port is a member of, tagged or untagged
+    pub fn port_vlans(&self, port_alias: &str) -> VlanRangeList {
+        let mut vlans = VlanRangeList::new();
+        for vlan_id in self
+            .port_vlan_member
+            .get(port_alias)
+            .into_iter()
+            .flat_map(|vlans| vlans.keys())
+            .filter_map(|vlan| Self::extract_vlan_id(vlan))
+        {
+            let _ = vlans.insert(vlan_id);
+        }
+        vlans
+    }
+
+    /// The VLAN a port is untagged in, other than `except`
+    fn untagged_vlan_of(&self, port_alias: &str, except: &str) -> Option<&str> {
+        self.port_vlan_member
+            .get(port_alias)?
+            .iter()
+            .find(|(vlan, mode)| vlan.as_str() != except && mode.is_untagged())
+            .map(|(vlan, _)| vlan.as_str())
+    }
+
     /// Remove VLAN member
     #[instrument(skip(self))]
     pub async fn remove_host_vlan_member(
@@ -227,10 +544,16 @@ impl VlanMgr {
         vlan_id: u16,
         port_alias: &str,
     ) -> CfgMgrResult<bool> {
-        let cmd = build_remove_vlan_member_cmd(vlan_id, port_alias);
+        // The port leaves the bridge along with its last VLAN
+        let mut remaining = self.port_vlans(port_alias);
+        remaining.remove(vlan_id);
+        let cmd = build_remove_vlan_member_cmd(vlan_id, port_alias, remaining.is_empty());
         self.exec(&cmd).await?;
 
-        info!("Removed {} from VLAN {}", port_alias, vlan_id);
+        info!(
+            "Removed {} from VLAN {}, still in [{}]",
+            port_alias, vlan_id, remaining
+        );
         Ok(true)
     }
 
@@ -250,38 +573,60 @@ impl VlanMgr {
             }
         };
 
+        // Reject a bad MAC before touching the kernel
+        let mac = match values.get_field(fields::MAC) {
+            Some(mac) => Some(parse_mac(mac).ok_or_else(|| {
+                CfgMgrError::invalid_config(fields::MAC, format!("Invalid MAC address: {}", mac))
+            })?),
+            None => None,
+        };
+        let system_mac = self.global_mac.clone().unwrap_or_default();
+
         // Check if this is a new VLAN
         let is_new = !self.vlans.contains(key);
 
         if is_new {
-            // Add VLAN interface
+            // Add VLAN interface, created with the system MAC
             self.add_host_vlan(vlan_id).await?;
             self.vlans.insert(key.to_string());
-            self.vlan_info.insert(vlan_id, VlanInfo::new(vlan_id));
-        }
-
-        // Process configuration fields
-        for (field, value) in values {
-            match field.as_str() {
-                fields::ADMIN_STATUS => {
-                    self.set_host_vlan_admin_state(vlan_id, value).await?;
-                }
-                fields::MTU => {
-                    if let Ok(mtu) = value.parse::<u32>() {
-                        self.set_host_vlan_mtu(vlan_id, mtu).await?;
-                    }
-                }
-                fields::MAC => {
-                    self.set_host_vlan_mac(vlan_id, value).await?;
-                }
-                _ => {
-                    debug!("Ignoring unknown VLAN field: {}", field);
-                }
+            let mut info = VlanInfo::new(vlan_id);
+            info.mac = system_mac.clone();
+            self.vlan_info.insert(vlan_id, info);
+            self.set_state_ok(STATE_VLAN_TABLE_NAME, key).await?;
+        }
+
+        // MAC goes first so the interface never comes up with the old one
+        let mac = mac.unwrap_or(system_mac);
+        let current_mac = self.vlan_info.get(&vlan_id).map(|info| info.mac.as_str());
+        if current_mac != Some(mac.as_str()) {
+            self.set_host_vlan_mac(vlan_id, &mac).await?;
+            if let Some(info) = self.vlan_info.get_mut(&vlan_id) {
+                info.mac = mac.clone();
+            }
+        }
+
+        if let Some(mtu) = values.get_field(fields::MTU) {
+            if let Ok(mtu) = mtu.parse::<u32>() {
+                self.set_host_vlan_mtu(vlan_id, mtu).await?;
             }
         }
+        if let Some(admin_status) = values.get_field(fields::ADMIN_STATUS) {
+            self.set_host_vlan_admin_state(vlan_id, admin_status)
+                .await?;
+        }
 
-        // TODO: Write to APPL_DB (requires ProducerStateTable integration)
-        debug!("Would write VLAN {} to APPL_DB", vlan_id);
+        // DHCP relay server lists pass through untouched
+        let mut app_fvs: FieldValues = values
+            .iter()
+            .filter(|(field, _)| {
+                matches!(field.as_str(), fields::ADMIN_STATUS | fields::MTU)
+                    || is_dhcp_servers_field(field)
+            })
+            .cloned()
+            .collect();
+        app_fvs.push((fields::MAC.to_string(), mac));
+        self.write_vlan_to_app_db(key, app_fvs).await?;
+        self.vlan_replay.remove(key);
 
         Ok(())
     }
@@ -302,8 +647,9 @@ impl VlanMgr {
         self.vlans.remove(key);
         self.vlan_info.remove(&vlan_id);
 
-        // TODO: Delete from APPL_DB
-        debug!("Would delete VLAN {} from APPL_DB", vlan_id);
+        self.store_del(DbId::StateDb, STATE_VLAN_TABLE_NAME, key)
+            .await?;
+        self.delete_vlan_from_app_db(key).await?;
 
         Ok(())
     }
@@ -329,22 +675,52 @@ impl VlanMgr {
             .find(|(k, _)| k == fields::TAGGING_MODE)
             .and_then(|(_, v)| v.parse().ok())
             .unwrap_or(TaggingMode::Tagged);
+        let vlan = format!("{}{}", VLAN_PREFIX, vlan_id);
+
+        // A port has a single PVID
+        if tagging_mode.is_untagged() {
+            if let Some(other) = self.untagged_vlan_of(&port_alias, &vlan) {
+                return Err(CfgMgrError::invalid_config(
+                    fields::TAGGING_MODE,
+                    format!(
+                        "{} is already untagged in {}, cannot be untagged in {}",
+                        port_alias, other, vlan
+                    ),
+                ));
+            }
+        }
 
-        // Add member
-        self.add_host_vlan_member(vlan_id, &port_alias, tagging_mode)
-            .await?;
+        let current = self
+            .port_vlan_member
+            .get(&port_alias)
+            .and_then(|vlans| vlans.get(&vlan))
+            .copied();
+        match current {
+            Some(mode) if mode == tagging_mode => {
+                debug!("{} already {} in {}", port_alias, mode.as_str(), vlan);
+            }
+            // Existing member: switch modes without leaving the VLAN
+            Some(_) => {
+                self.set_host_vlan_member_mode(vlan_id, &port_alias, tagging_mode)
+                    .await?;
+            }
+            None => {
+                self.add_host_vlan_member(vlan_id, &port_alias, tagging_mode)
+                    .await?;
+            }
+        }
 
         // Track membership
         self.port_vlan_member
             .entry(port_alias.clone())
             .or_default()
-            .insert(
-                format!("Vlan{}", vlan_id),
-                tagging_mode.as_str().to_string(),
-            );
+            .insert(vlan, tagging_mode);
 
-        // TODO: Write to APPL_DB
-        debug!("Would write VLAN member {} to APPL_DB", key);
+        let app_key = format!("Vlan{}:{}", vlan_id, port_alias);
+        self.write_vlan_member_to_app_db(&app_key, tagging_mode)
+            .await?;
+        self.set_state_ok(STATE_VLAN_MEMBER_TABLE_NAME, key).await?;
+        self.vlan_member_replay.remove(key);
 
         Ok(())
     }
@@ -366,13 +742,57 @@ impl VlanMgr {
         // Update tracking
         if let Some(port_vlans) = self.port_vlan_member.get_mut(&port_alias) {
             port_vlans.remove(&format!("Vlan{}", vlan_id));
+            if port_vlans.is_empty() {
+                self.port_vlan_member.remove(&port_alias);
+            }
         }
 
-        // TODO: Delete from APPL_DB
-        debug!("Would delete VLAN member {} from APPL_DB", key);
+        let app_key = format!("Vlan{}:{}", vlan_id, port_alias);
+        self.delete_vlan_member_from_app_db(&app_key).await?;
+        self.store_del(DbId::StateDb, STATE_VLAN_MEMBER_TABLE_NAME, key)
+            .await?;
 
         Ok(())
     }
+
+    /// Whether a queued entry can be processed yet
+    ///
+    /// VLANs wait for the system MAC and members for their VLAN, as
+    /// `bridge vlan add` fails on a VLAN that does not exist.
+    fn is_task_ready(&self, table: &str, entry: &KeyOpFieldsValues) -> bool {
+        if !entry.op.is_set() {
+            return true;
+        }
+        match table {
+            CFG_VLAN_TABLE_NAME => self.is_vlan_mac_ok(),
+            CFG_VLAN_MEMBER_TABLE_NAME => entry
+                .key
+                .split_once('|')
+                .is_none_or(|(vlan, _)| self.vlans.contains(vlan)),
+            _ => true,
+        }
+    }
+
+    /// Processes one queued CONFIG_DB entry
+    async fn process_task(&mut self, table: &str, entry: &KeyOpFieldsValues) -> CfgMgrResult<()> {
+        match (table, entry.op.is_set()) {
+            (CFG_VLAN_TABLE_NAME, true) => self.process_vlan_set(&entry.key, &entry.fvs).await,
+            (CFG_VLAN_TABLE_NAME, false) => self.process_vlan_del(&entry.key).await,
+            (CFG_VLAN_MEMBER_TABLE_NAME, true) => {
+                self.process_vlan_member_set(&entry.key, &entry.fvs).await
+            }
+            (CFG_VLAN_MEMBER_TABLE_NAME, false) => self.process_vlan_member_del(&entry.key).await,
+            _ => {
+                warn!("Ignoring {}|{} from unexpected table", table, entry.key);
+                Ok(())
+            }
+        }
+    }
+
+    /// Number of queued entries, including those waiting on their VLAN
+    pub fn pending_count(&self) -> usize {
+        self.tasks.len()
+    }
 }
 
 impl Default for VlanMgr {
@@ -389,11 +809,22 @@ impl Orch for VlanMgr {
     }
 
     async fn do_task(&mut self) {
-        // Placeholder - actual implementation would:
-        // 1. Drain consumers for VLAN and VLAN_MEMBER tables
-        // 2. Process SET/DEL operations
-        // 3. Write to APPL_DB via producers
-        debug!("do_task called (placeholder)");
+        let mut deferred = VecDeque::new();
+        while let Some((table, entry)) = self.tasks.pop_front() {
+            if !self.is_task_ready(&table, &entry) {
+                debug!("{}|{} is not ready, deferring", table, entry.key);
+                deferred.push_back((table, entry));
+                continue;
+            }
+            if let Err(e) = self.process_task(&table, &entry).await {
+                error!("Failed to process {}|{}: {}", table, entry.key, e);
+            }
+        }
+        self.tasks = deferred;
+    }
+
+    fn has_pending_tasks(&self) -> bool {
+        !self.tasks.is_empty()
     }
 }
 
@@ -405,21 +836,49 @@ impl CfgMgr for VlanMgr {
     }
 
     fn is_warm_restart(&self) -> bool {
-        !self.vlan_replay.is_empty() || !self.vlan_member_replay.is_empty()
+        self.warm_restart
+            .as_ref()
+            .is_some_and(WarmRestartHelper::is_warm_start)
     }
 
     fn warm_restart_state(&self) -> WarmRestartState {
-        if self.replay_done {
-            WarmRestartState::Reconciled
-        } else if self.is_warm_restart() {
-            WarmRestartState::Replayed
+        self.warm_restart
+            .as_ref()
+            .map_or(WarmRestartState::Disabled, WarmRestartHelper::state)
+    }
+
+    async fn set_warm_restart_state(&mut self, state: WarmRestartState) {
+        let Some(helper) = self.warm_restart.as_mut() else {
+            return;
+        };
+
+        let result = if state == WarmRestartState::Reconciled {
+            helper.reconcile().await.map(|removed| {
+                info!("Removed {} stale APPL_DB entries", removed);
+            })
         } else {
-            WarmRestartState::Disabled
+            helper.set_state(state).await
+        };
+        if let Err(e) = result {
+            warn!("Failed to set warm restart state {}: {}", state.as_str(), e);
         }
     }
 
-    async fn set_warm_restart_state(&mut self, _state: WarmRestartState) {
-        // State transitions handled internally
+    fn is_replay_done(&self) -> bool {
+        self.vlan_replay.is_empty() && self.vlan_member_replay.is_empty()
+    }
+
+    async fn build_replay_list(&mut self) {
+        let Some(helper) = self.warm_restart.as_mut() else {
+            return;
+        };
+
+        if let Err(e) = helper
+            .restore(&[APP_VLAN_TABLE_NAME, APP_VLAN_MEMBER_TABLE_NAME])
+            .await
+        {
+            warn!("Failed to cache APPL_DB for warm restart: {}", e);
+        }
     }
 
     fn config_table_names(&self) -> &[&str] {
@@ -429,11 +888,26 @@ impl CfgMgr for VlanMgr {
     fn state_table_names(&self) -> &[&str] {
         &[] // TODO: Add STATE_DB tables when needed
     }
+
+    fn add_to_sync(&mut self, db: DbId, table: &str, entries: Vec<KeyOpFieldsValues>) {
+        if db != DbId::ConfigDb {
+            debug!(
+                "Ignoring {} entries from {}:{}",
+                entries.len(),
+                db.name(),
+                table
+            );
+            return;
+        }
+        self.tasks
+            .extend(entries.into_iter().map(|entry| (table.to_string(), entry)));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sonic_cfgmgr_test::{CannedResponse, CommandHarness};
 
     #[test]
     fn test_vlan_mgr_new() {
@@ -447,6 +921,9 @@ mod tests {
     fn test_extract_vlan_id() {
         assert_eq!(VlanMgr::extract_vlan_id("Vlan100"), Some(100));
         assert_eq!(VlanMgr::extract_vlan_id("Vlan1"), Some(1));
+        assert_eq!(VlanMgr::extract_vlan_id("Vlan4094"), Some(4094));
+        assert_eq!(VlanMgr::extract_vlan_id("Vlan0"), None);
+        assert_eq!(VlanMgr::extract_vlan_id("Vlan4095"), None);
         assert_eq!(VlanMgr::extract_vlan_id("Invalid"), None);
     }
 
@@ -496,6 +973,37 @@ mod tests {
             .any(|c| c.contains("Vlan100") && c.contains("down")));
     }
 
+    #[tokio::test]
+    async fn test_link_changes_go_through_backend() {
+        let harness = Arc::new(CommandHarness::new());
+        harness.stub(r"mtu 9216$", CannedResponse::exit(2, "Invalid argument"));
+        let mut mgr = VlanMgr::new()
+            .with_executor(harness.clone())
+            .with_backend(harness.clone());
+
+        assert!(mgr.set_host_vlan_admin_state(100, "up").await.unwrap());
+        assert!(!mgr
+            .set_host_vlan_admin_state(100, "sideways")
+            .await
+            .unwrap());
+        assert!(mgr.set_host_vlan_mtu(100, 9100).await.unwrap());
+        // Rejected MTU (above a member's) is retried, not an error
+        assert!(!mgr.set_host_vlan_mtu(100, 9216).await.unwrap());
+        mgr.set_host_vlan_member_mode(100, "Ethernet0", TaggingMode::Untagged)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            harness.commands(),
+            [
+                ShellBackend::set_link_admin_cmd("Vlan100", true),
+                ShellBackend::set_link_mtu_cmd("Vlan100", 9100),
+                ShellBackend::set_link_mtu_cmd("Vlan100", 9216),
+                ShellBackend::bridge_vlan_cmd("Ethernet0", &BridgeVlan::untagged(100), true),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_add_vlan_member() {
         let mut mgr = VlanMgr::new().with_mock_mode();
@@ -544,6 +1052,336 @@ mod tests {
             .any(|c| c.contains("Ethernet0") && c.contains("pvid untagged")));
     }
 
+    fn member_mode(mode: &str) -> FieldValues {
+        vec![("tagging_mode".to_string(), mode.to_string())]
+    }
+
+    #[tokio::test]
+    async fn test_vlan_member_tagged_to_untagged() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("tagged"))
+            .await
+            .unwrap();
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap();
+
+        // One in-place change, no removal from the VLAN
+        let cmds = mgr.captured_commands();
+        assert_eq!(cmds.len(), 1);
+        assert!(cmds[0].contains("vlan add vid 100") && cmds[0].ends_with("pvid untagged"));
+        assert!(!cmds[0].contains("vlan del") && !cmds[0].contains("master"));
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![AppDbOp::Set(
+                APP_VLAN_MEMBER_TABLE_NAME,
+                "Vlan100:Ethernet0".to_string(),
+                member_mode("untagged")
+            )]
+        );
+        assert_eq!(
+            mgr.port_vlan_member["Ethernet0"]["Vlan100"],
+            TaggingMode::Untagged
+        );
+    }
+
+    #[tokio::test]
+    async fn test_vlan_member_untagged_to_tagged() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap();
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("tagged"))
+            .await
+            .unwrap();
+
+        let cmds = mgr.captured_commands();
+        assert_eq!(cmds.len(), 1);
+        assert!(cmds[0].ends_with("vlan add vid 100 dev \"Ethernet0\""));
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![AppDbOp::Set(
+                APP_VLAN_MEMBER_TABLE_NAME,
+                "Vlan100:Ethernet0".to_string(),
+                member_mode("tagged")
+            )]
+        );
+
+        // The PVID is free again
+        mgr.process_vlan_member_set("Vlan200|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_vlan_member_same_mode_no_command() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("tagged"))
+            .await
+            .unwrap();
+        mgr.captured_commands.clear();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("tagged"))
+            .await
+            .unwrap();
+        assert!(mgr.captured_commands().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_vlan_member_second_untagged_rejected() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap();
+        mgr.process_vlan_member_set("Vlan200|Ethernet0", &member_mode("tagged"))
+            .await
+            .unwrap();
+        mgr.captured_commands.clear();
+        mgr.app_db_ops.clear();
+
+        // New membership
+        let err = mgr
+            .process_vlan_member_set("Vlan300|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already untagged in Vlan100"));
+
+        // Mode change of an existing membership
+        assert!(mgr
+            .process_vlan_member_set("Vlan200|Ethernet0", &member_mode("untagged"))
+            .await
+            .is_err());
+
+        assert!(mgr.captured_commands().is_empty());
+        assert!(mgr.app_db_ops.is_empty());
+        assert!(!mgr.port_vlan_member["Ethernet0"].contains_key("Vlan300"));
+        assert_eq!(
+            mgr.port_vlan_member["Ethernet0"]["Vlan200"],
+            TaggingMode::Tagged
+        );
+
+        // Other ports are unaffected
+        mgr.process_vlan_member_set("Vlan300|Ethernet4", &member_mode("untagged"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_member_del() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &member_mode("untagged"))
+            .await
+            .unwrap();
+        mgr.process_vlan_member_del("Vlan100|Ethernet0")
+            .await
+            .unwrap();
+
+        assert!(!mgr.port_vlan_member.contains_key("Ethernet0"));
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::Del(
+                APP_VLAN_MEMBER_TABLE_NAME,
+                "Vlan100:Ethernet0".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_port_vlans_attach_and_detach_bridge() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+
+        for vlan in ["Vlan100", "Vlan101", "Vlan102", "Vlan200"] {
+            mgr.process_vlan_member_set(&format!("{}|Ethernet0", vlan), &member_mode("tagged"))
+                .await
+                .unwrap();
+        }
+        assert_eq!(mgr.port_vlans("Ethernet0").to_string(), "100-102,200");
+        assert!(mgr.port_vlans("Ethernet4").is_empty());
+
+        // Only the first VLAN attaches the port to the bridge
+        let cmds = mgr.captured_commands();
+        assert_eq!(cmds.len(), 4);
+        assert!(cmds[0].contains("master Bridge"));
+        assert!(cmds[1..].iter().all(|c| !c.contains("master")));
+
+        mgr.captured_commands.clear();
+        mgr.process_vlan_member_del("Vlan101|Ethernet0")
+            .await
+            .unwrap();
+        assert_eq!(mgr.port_vlans("Ethernet0").to_string(), "100,102,200");
+        for vlan in ["Vlan100", "Vlan102", "Vlan200"] {
+            mgr.process_vlan_member_del(&format!("{}|Ethernet0", vlan))
+                .await
+                .unwrap();
+        }
+
+        // Only the last VLAN detaches it
+        let cmds = mgr.captured_commands();
+        assert_eq!(cmds.len(), 4);
+        assert!(cmds[..3].iter().all(|c| !c.contains("nomaster")));
+        assert!(cmds[3].contains("vlan del vid 200") && cmds[3].contains("nomaster"));
+        assert!(mgr.port_vlans("Ethernet0").is_empty());
+    }
+
+    fn position(cmds: &[String], needle: &str) -> usize {
+        cmds.iter()
+            .position(|c| c.contains(needle))
+            .unwrap_or_else(|| panic!("no command with {}", needle))
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_set_mac_before_admin_up() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+        mgr.set_global_mac("00:11:22:33:44:55");
+
+        let fields = vec![
+            ("admin_status".to_string(), "up".to_string()),
+            ("mac".to_string(), "00:AA:BB:CC:DD:EE".to_string()),
+        ];
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+
+        let cmds = mgr.captured_commands();
+        let create = position(cmds, "vlan add vid 100");
+        let mac = position(cmds, "dev Vlan100 address \"00:aa:bb:cc:dd:ee\"");
+        let admin = position(cmds, "\"Vlan100\" up");
+        assert!(create < mac && mac < admin);
+        assert!(!cmds.iter().any(|c| c.contains("Bridge address")));
+        assert_eq!(mgr.vlan_info[&100].mac, "00:aa:bb:cc:dd:ee");
+
+        // Dropping the mac field restores the system MAC
+        mgr.captured_commands.clear();
+        let fields = vec![("admin_status".to_string(), "up".to_string())];
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+
+        let cmds = mgr.captured_commands();
+        let mac = position(cmds, "dev Vlan100 address \"00:11:22:33:44:55\"");
+        let admin = position(cmds, "\"Vlan100\" up");
+        assert!(mac < admin);
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_set_system_mac_no_command() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+        mgr.set_global_mac("00:11:22:33:44:55");
+
+        mgr.process_vlan_set("Vlan100", &Vec::new()).await.unwrap();
+
+        // The VLAN is created with the system MAC already
+        assert!(!mgr
+            .captured_commands()
+            .iter()
+            .any(|c| c.contains("dev Vlan100 address")));
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![AppDbOp::Set(
+                APP_VLAN_TABLE_NAME,
+                "Vlan100".to_string(),
+                vec![("mac".to_string(), "00:11:22:33:44:55".to_string())]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_set_invalid_mac() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+        mgr.set_global_mac("00:11:22:33:44:55");
+
+        let fields = vec![
+            ("admin_status".to_string(), "up".to_string()),
+            ("mac".to_string(), "00:11:22:33:44".to_string()),
+        ];
+        let err = mgr.process_vlan_set("Vlan100", &fields).await.unwrap_err();
+
+        assert!(err.to_string().contains("Invalid MAC address"));
+        assert!(mgr.captured_commands().is_empty());
+        assert!(mgr.app_db_ops.is_empty());
+        assert!(!mgr.vlans.contains("Vlan100"));
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_set_dhcp_servers_diff() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+        mgr.set_global_mac("00:11:22:33:44:55");
+
+        let mut fields = vec![
+            ("admin_status".to_string(), "up".to_string()),
+            (
+                "dhcp_servers@".to_string(),
+                "192.0.2.1,192.0.2.2".to_string(),
+            ),
+            ("dhcpv6_servers@".to_string(), "2001:db8::1".to_string()),
+        ];
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+        match &mgr.app_db_ops[0] {
+            AppDbOp::Set(APP_VLAN_TABLE_NAME, key, fvs) => {
+                assert_eq!(key, "Vlan100");
+                assert_eq!(fvs.get_field("dhcp_servers@"), Some("192.0.2.1,192.0.2.2"));
+                assert_eq!(fvs.get_field("dhcpv6_servers@"), Some("2001:db8::1"));
+            }
+            op => panic!("unexpected {:?}", op),
+        }
+
+        // A new server only rewrites the list
+        mgr.app_db_ops.clear();
+        fields[1].1 = "192.0.2.1,192.0.2.2,192.0.2.3".to_string();
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![AppDbOp::Set(
+                APP_VLAN_TABLE_NAME,
+                "Vlan100".to_string(),
+                vec![(
+                    "dhcp_servers@".to_string(),
+                    "192.0.2.1,192.0.2.2,192.0.2.3".to_string()
+                )]
+            )]
+        );
+
+        // Removing the DHCPv6 list deletes just that field
+        mgr.app_db_ops.clear();
+        fields.pop();
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+        assert_eq!(
+            mgr.app_db_ops,
+            vec![AppDbOp::HDel(
+                APP_VLAN_TABLE_NAME,
+                "Vlan100".to_string(),
+                vec!["dhcpv6_servers@".to_string()]
+            )]
+        );
+
+        // No change, no write
+        mgr.app_db_ops.clear();
+        mgr.process_vlan_set("Vlan100", &fields).await.unwrap();
+        assert!(mgr.app_db_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_vlan_del_removes_app_entry() {
+        let mut mgr = VlanMgr::new().with_mock_mode();
+        mgr.set_global_mac("00:11:22:33:44:55");
+
+        mgr.process_vlan_set("Vlan100", &Vec::new()).await.unwrap();
+        mgr.process_vlan_del("Vlan100").await.unwrap();
+
+        assert_eq!(
+            mgr.app_db_ops.last(),
+            Some(&AppDbOp::Del(APP_VLAN_TABLE_NAME, "Vlan100".to_string()))
+        );
+        assert!(mgr.vlan_app_fields.is_empty());
+    }
+
     #[test]
     fn test_cfgmgr_trait() {
         let mgr = VlanMgr::new();
@@ -561,4 +1399,131 @@ mod tests {
         let mgr = VlanMgr::new();
         assert_eq!(mgr.name(), "vlanmgr");
     }
+
+    #[tokio::test]
+    async fn test_warm_restart_removes_stale_entries() {
+        use sonic_cfgmgr_common::warm_restart::{
+            fields as warm_fields, STATE_WARM_RESTART_ENABLE_TABLE,
+        };
+        use sonic_cfgmgr_common::{field_values, DbId, InMemoryStore, WarmRestartStore};
+        use std::sync::Arc;
+
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .set(
+                DbId::StateDb,
+                STATE_WARM_RESTART_ENABLE_TABLE,
+                "swss",
+                &field_values! { warm_fields::ENABLE => "true" },
+            )
+            .await
+            .unwrap();
+        for (table, key) in [
+            (APP_VLAN_TABLE_NAME, "Vlan100"),
+            (APP_VLAN_TABLE_NAME, "Vlan200"),
+            (APP_VLAN_MEMBER_TABLE_NAME, "Vlan100:Ethernet0"),
+            (APP_VLAN_MEMBER_TABLE_NAME, "Vlan200:Ethernet4"),
+        ] {
+            store
+                .set(DbId::ApplDb, table, key, &field_values! { "k" => "v" })
+                .await
+                .unwrap();
+        }
+
+        let mut helper = WarmRestartHelper::new("vlanmgrd", store.clone());
+        assert!(helper.initialize("swss").await.unwrap());
+        let mut mgr = VlanMgr::new().with_mock_mode().with_warm_restart(helper);
+        mgr.set_global_mac("00:11:22:33:44:55");
+        assert!(mgr.is_warm_restart());
+
+        // Replay only Vlan100 and its member
+        mgr.build_replay_list().await;
+        mgr.process_vlan_set("Vlan100", &Vec::new()).await.unwrap();
+        mgr.process_vlan_member_set("Vlan100|Ethernet0", &Vec::new())
+            .await
+            .unwrap();
+        mgr.set_warm_restart_state(WarmRestartState::Replayed).await;
+        assert!(mgr.is_replay_done());
+        mgr.set_warm_restart_state(WarmRestartState::Reconciled)
+            .await;
+
+        assert_eq!(mgr.warm_restart_state(), WarmRestartState::Reconciled);
+        assert_eq!(
+            store.keys(DbId::ApplDb, APP_VLAN_TABLE_NAME).await.unwrap(),
+            vec!["Vlan100"]
+        );
+        assert_eq!(
+            store
+                .keys(DbId::ApplDb, APP_VLAN_MEMBER_TABLE_NAME)
+                .await
+                .unwrap(),
+            vec!["Vlan100:Ethernet0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_do_task_defers_member_until_vlan_exists() {
+        use sonic_cfgmgr_common::{field_values, InMemoryStore};
+
+        let store = Arc::new(InMemoryStore::new());
+        let mut mgr = VlanMgr::new().with_mock_mode().with_store(store.clone());
+
+        // Member first, and the VLAN before the system MAC is known
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_VLAN_MEMBER_TABLE_NAME,
+            vec![KeyOpFieldsValues::set(
+                "Vlan100|Ethernet0",
+                field_values! { fields::TAGGING_MODE => "untagged" },
+            )],
+        );
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_VLAN_TABLE_NAME,
+            vec![KeyOpFieldsValues::set(
+                "Vlan100",
+                field_values! { fields::VLAN_ID => "100" },
+            )],
+        );
+        mgr.do_task().await;
+        assert_eq!(mgr.pending_count(), 2);
+        assert!(mgr.captured_commands().is_empty());
+
+        mgr.set_global_mac("00:11:22:33:44:55");
+        mgr.do_task().await;
+        assert_eq!(mgr.pending_count(), 1);
+        mgr.do_task().await;
+        assert!(!mgr.has_pending_tasks());
+
+        let state = store
+            .get_all(DbId::StateDb, STATE_VLAN_TABLE_NAME, "Vlan100")
+            .await
+            .unwrap();
+        assert_eq!(state.get_field(fields::STATE), Some(STATE_OK));
+        let member = store
+            .get_all(
+                DbId::ApplDb,
+                APP_VLAN_MEMBER_TABLE_NAME,
+                "Vlan100:Ethernet0",
+            )
+            .await
+            .unwrap();
+        assert_eq!(member.get_field(fields::TAGGING_MODE), Some("untagged"));
+
+        mgr.add_to_sync(
+            DbId::ConfigDb,
+            CFG_VLAN_MEMBER_TABLE_NAME,
+            vec![KeyOpFieldsValues::del("Vlan100|Ethernet0")],
+        );
+        mgr.do_task().await;
+        assert!(store
+            .get_all(
+                DbId::StateDb,
+                STATE_VLAN_MEMBER_TABLE_NAME,
+                "Vlan100|Ethernet0"
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -2,7 +2,10 @@
 
 use sonic_cfgmgr_common::shell;
 
-use crate::types::TABLE_LOCAL_PREF;
+use crate::types::{
+    MgmtVrfStep, L3MDEV_RULE_PREF, MGMT_LOOPBACK, MGMT_LOOPBACK_ADDR, MGMT_SERVICE_RULE_PREF,
+    MGMT_VRF_NAME, MGMT_VRF_SERVICES, MGMT_VRF_TABLE_ID, TABLE_LOCAL_PREF,
+};
 
 /// Build VRF creation command
 ///
@@ -64,6 +67,83 @@ pub fn build_check_prio0_rule_cmd() -> String {
     format!("{} rule | {} '^0:'", shell::IP_CMD, shell::GREP_CMD)
 }
 
+/// Build the management VRF bring-up sequence
+///
+/// The VRF, its loopback and the policy rules are set up before the
+/// management interface is enslaved, so eth0 loses its default-VRF routes
+/// only in the last step, once the management table is ready to take over.
+/// Disabling runs the undo commands in reverse, releasing eth0 first.
+pub fn build_mgmt_vrf_steps(mgmt_intf: &str) -> Vec<MgmtVrfStep> {
+    let ip = shell::IP_CMD;
+    let mut steps = vec![
+        MgmtVrfStep::new(
+            format!(
+                "{} link add {} type vrf table {}",
+                ip, MGMT_VRF_NAME, MGMT_VRF_TABLE_ID
+            ),
+            format!("{} link del {}", ip, MGMT_VRF_NAME),
+        ),
+        MgmtVrfStep::new(format!("{} link set dev {} up", ip, MGMT_VRF_NAME), None),
+    ];
+
+    // Shared with data VRFs, so left in place on disable
+    for family in ["", " -6"] {
+        steps.push(MgmtVrfStep::new(
+            format!(
+                "{ip}{family} rule show pref {pref} | {grep} -q l3mdev || \
+                 {ip}{family} rule add pref {pref} l3mdev",
+                ip = ip,
+                family = family,
+                pref = L3MDEV_RULE_PREF,
+                grep = shell::GREP_CMD
+            ),
+            None,
+        ));
+    }
+
+    steps.extend([
+        MgmtVrfStep::new(
+            format!("{} link add {} type dummy", ip, MGMT_LOOPBACK),
+            format!("{} link del {}", ip, MGMT_LOOPBACK),
+        ),
+        MgmtVrfStep::new(
+            format!(
+                "{} addr add {} dev {}",
+                ip, MGMT_LOOPBACK_ADDR, MGMT_LOOPBACK
+            ),
+            None,
+        ),
+        MgmtVrfStep::new(
+            format!(
+                "{} link set dev {} master {}",
+                ip, MGMT_LOOPBACK, MGMT_VRF_NAME
+            ),
+            None,
+        ),
+        MgmtVrfStep::new(format!("{} link set dev {} up", ip, MGMT_LOOPBACK), None),
+    ]);
+
+    for family in ["", " -6"] {
+        for svc in MGMT_VRF_SERVICES {
+            let rule = format!(
+                "pref {} ipproto {} {} {} table {}",
+                MGMT_SERVICE_RULE_PREF, svc.proto, svc.port_match, svc.port, MGMT_VRF_TABLE_ID
+            );
+            steps.push(MgmtVrfStep::new(
+                format!("{}{} rule add {}", ip, family, rule),
+                format!("{}{} rule del {}", ip, family, rule),
+            ));
+        }
+    }
+
+    let intf = shell::shellquote(mgmt_intf);
+    steps.push(MgmtVrfStep::new(
+        format!("{} link set dev {} master {}", ip, intf, MGMT_VRF_NAME),
+        format!("{} link set dev {} nomaster", ip, intf),
+    ));
+    steps
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +186,36 @@ mod tests {
         assert!(cmd.contains("ip -6 rule del pref 0"));
     }
 
+    #[test]
+    fn test_build_mgmt_vrf_steps_order() {
+        let steps = build_mgmt_vrf_steps("eth0");
+
+        assert!(steps[0].apply.contains("link add mgmt type vrf table 5000"));
+        assert_eq!(steps[0].undo.as_deref(), Some("/sbin/ip link del mgmt"));
+
+        // eth0 is enslaved last, after every rule is in place
+        let last = steps.last().unwrap();
+        assert_eq!(last.apply, "/sbin/ip link set dev \"eth0\" master mgmt");
+        assert_eq!(
+            last.undo.as_deref(),
+            Some("/sbin/ip link set dev \"eth0\" nomaster")
+        );
+        assert_eq!(steps.iter().filter(|s| s.apply.contains("eth0")).count(), 1);
+
+        let rules: Vec<&MgmtVrfStep> = steps
+            .iter()
+            .filter(|s| s.apply.contains("rule add pref 1002"))
+            .collect();
+        assert_eq!(rules.len(), 2 * MGMT_VRF_SERVICES.len());
+        for rule in rules {
+            assert!(rule.apply.ends_with("table 5000"));
+            assert_eq!(
+                rule.undo.as_deref(),
+                Some(rule.apply.replace(" rule add ", " rule del ").as_str())
+            );
+        }
+    }
+
     #[test]
     fn test_shellquote_safety() {
         let cmd = build_add_vrf_cmd("Vrf'; rm -rf /", 1001);
@@ -46,6 +46,82 @@ pub const MGMT_VRF_TABLE_ID: u32 = 5000;
 /// Management VRF name
 pub const MGMT_VRF_NAME: &str = "mgmt";
 
+/// Management interface moved into the management VRF
+pub const MGMT_INTERFACE: &str = "eth0";
+
+/// Loopback device giving the management VRF its own 127.0.0.1
+pub const MGMT_LOOPBACK: &str = "lo-m";
+
+/// Address of the management VRF loopback
+pub const MGMT_LOOPBACK_ADDR: &str = "127.0.0.1/16";
+
+/// l3mdev rule preference (ahead of the relocated local table)
+pub const L3MDEV_RULE_PREF: u32 = 1000;
+
+/// Preference of the management service rules
+pub const MGMT_SERVICE_RULE_PREF: u32 = 1002;
+
+/// A service whose traffic is routed through the management VRF
+///
+/// Traffic matching `ipproto <proto> <port_match> <port>` looks up the
+/// management table instead of the default route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MgmtVrfService {
+    /// Service name, for logging
+    pub name: &'static str,
+    /// IP protocol ("tcp" or "udp")
+    pub proto: &'static str,
+    /// "sport" for local servers, "dport" for local clients
+    pub port_match: &'static str,
+    /// Service port
+    pub port: u16,
+}
+
+/// Services reachable only through the management VRF: SSH replies, NTP and
+/// DNS queries
+pub const MGMT_VRF_SERVICES: &[MgmtVrfService] = &[
+    MgmtVrfService {
+        name: "ssh",
+        proto: "tcp",
+        port_match: "sport",
+        port: 22,
+    },
+    MgmtVrfService {
+        name: "ntp",
+        proto: "udp",
+        port_match: "dport",
+        port: 123,
+    },
+    MgmtVrfService {
+        name: "dns",
+        proto: "udp",
+        port_match: "dport",
+        port: 53,
+    },
+];
+
+/// One step of bringing up the management VRF
+///
+/// `undo` reverts `apply`; steps whose effect is reverted by an earlier
+/// step's undo (addresses on a device that is deleted) have none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MgmtVrfStep {
+    /// Command applied on enable
+    pub apply: String,
+    /// Command reverting it on disable or rollback
+    pub undo: Option<String>,
+}
+
+impl MgmtVrfStep {
+    /// Create a step with its undo command
+    pub fn new(apply: String, undo: impl Into<Option<String>>) -> Self {
+        Self {
+            apply,
+            undo: undo.into(),
+        }
+    }
+}
+
 /// EVPN NVO configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvpnNvoConfig {
@@ -3,11 +3,12 @@
 use std::collections::{BTreeSet, HashMap};
 
 use async_trait::async_trait;
+use sonic_cfgmgr_common::shell::ExecRequest;
 use sonic_cfgmgr_common::{
-    shell, CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, WarmRestartState,
+    shell, CfgMgr, CfgMgrError, CfgMgrResult, FieldValues, FieldValuesExt, WarmRestartState,
 };
 use sonic_orch_common::Orch;
-use tracing::{debug, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 
 use crate::commands::*;
 use crate::tables::fields;
@@ -29,11 +30,17 @@ pub struct VrfMgr {
     /// EVPN VXLAN tunnel name
     evpn_vxlan_tunnel: Option<String>,
 
+    /// Whether the management VRF is set up
+    mgmt_vrf_enabled: bool,
+
     /// Testing support
     #[cfg(test)]
     mock_mode: bool,
     #[cfg(test)]
     captured_commands: Vec<String>,
+    /// Mock commands containing this fail
+    #[cfg(test)]
+    fail_command: Option<String>,
 }
 
 impl VrfMgr {
@@ -55,10 +62,13 @@ impl VrfMgr {
             free_tables,
             vrf_vni_map: HashMap::new(),
             evpn_vxlan_tunnel: None,
+            mgmt_vrf_enabled: false,
             #[cfg(test)]
             mock_mode: false,
             #[cfg(test)]
             captured_commands: Vec::new(),
+            #[cfg(test)]
+            fail_command: None,
         }
     }
 
@@ -263,6 +273,86 @@ impl VrfMgr {
         Ok(())
     }
 
+    /// Process MGMT_VRF_CONFIG SET operation
+    #[instrument(skip(self))]
+    pub async fn process_mgmt_vrf_config_set(
+        &mut self,
+        _key: &str,
+        values: &FieldValues,
+    ) -> CfgMgrResult<()> {
+        let enabled = values.get_field(fields::MGMT_VRF_ENABLED) == Some("true");
+        if enabled == self.mgmt_vrf_enabled {
+            return Ok(());
+        }
+
+        if enabled {
+            self.enable_mgmt_vrf().await
+        } else {
+            self.disable_mgmt_vrf().await;
+            Ok(())
+        }
+    }
+
+    /// Process MGMT_VRF_CONFIG DEL operation
+    #[instrument(skip(self))]
+    pub async fn process_mgmt_vrf_config_del(&mut self, _key: &str) -> CfgMgrResult<()> {
+        if self.mgmt_vrf_enabled {
+            self.disable_mgmt_vrf().await;
+        }
+        Ok(())
+    }
+
+    /// Whether the management VRF is set up
+    pub fn is_mgmt_vrf_enabled(&self) -> bool {
+        self.mgmt_vrf_enabled
+    }
+
+    /// Set up the management VRF and move the management interface into it
+    ///
+    /// The steps run as one batch. If one fails, the steps already applied
+    /// are undone in reverse so eth0 is left in the default VRF.
+    async fn enable_mgmt_vrf(&mut self) -> CfgMgrResult<()> {
+        let steps = build_mgmt_vrf_steps(MGMT_INTERFACE);
+        let apply: Vec<String> = steps.iter().map(|step| step.apply.clone()).collect();
+
+        match self.exec_batch(&apply).await {
+            Ok(()) => {
+                self.mgmt_vrf_enabled = true;
+                info!(
+                    "Management VRF {} enabled on {}",
+                    MGMT_VRF_NAME, MGMT_INTERFACE
+                );
+                Ok(())
+            }
+            Err(CfgMgrError::ShellBatchFailed { index, source }) => {
+                error!(
+                    "Management VRF step {} failed, rolling back: {}",
+                    index, source
+                );
+                self.undo_mgmt_vrf_steps(&steps[..index]).await;
+                Err(*source)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Release the management interface and remove the management VRF
+    async fn disable_mgmt_vrf(&mut self) {
+        let steps = build_mgmt_vrf_steps(MGMT_INTERFACE);
+        self.undo_mgmt_vrf_steps(&steps).await;
+        self.mgmt_vrf_enabled = false;
+        info!("Management VRF {} disabled", MGMT_VRF_NAME);
+    }
+
+    /// Run the undo commands of `steps` in reverse, continuing past failures
+    async fn undo_mgmt_vrf_steps(&mut self, steps: &[MgmtVrfStep]) {
+        for undo in steps.iter().rev().filter_map(|step| step.undo.as_deref()) {
+            if let Err(e) = self.exec(undo).await {
+                warn!("Management VRF teardown command failed: {}", e);
+            }
+        }
+    }
+
     /// Update VXLAN_VRF_TABLE in APPL_DB
     async fn update_vxlan_vrf_table(
         &self,
@@ -316,6 +406,35 @@ impl VrfMgr {
         Ok(())
     }
 
+    /// Execute commands in order, stopping at the first failure
+    async fn exec_batch(&mut self, cmds: &[String]) -> CfgMgrResult<()> {
+        #[cfg(test)]
+        if self.mock_mode {
+            for (index, cmd) in cmds.iter().enumerate() {
+                self.captured_commands.push(cmd.clone());
+                if self
+                    .fail_command
+                    .as_deref()
+                    .is_some_and(|fail| cmd.contains(fail))
+                {
+                    return Err(CfgMgrError::ShellBatchFailed {
+                        index,
+                        source: Box::new(CfgMgrError::ShellCommandFailed {
+                            command: cmd.clone(),
+                            exit_code: 2,
+                            output: String::new(),
+                        }),
+                    });
+                }
+            }
+            return Ok(());
+        }
+
+        let requests: Vec<ExecRequest> = cmds.iter().map(ExecRequest::new).collect();
+        shell::exec_all(&requests).await?;
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn with_mock_mode(mut self) -> Self {
         self.mock_mode = true;
@@ -511,4 +630,120 @@ mod tests {
         assert_eq!(mgr.daemon_name(), "vrfmgrd");
         assert!(!mgr.is_warm_restart());
     }
+
+    fn mgmt_vrf_config(enabled: &str) -> FieldValues {
+        vec![(fields::MGMT_VRF_ENABLED.to_string(), enabled.to_string())]
+    }
+
+    #[tokio::test]
+    async fn test_mgmt_vrf_enable_disable_order() {
+        shell::set_dry_run(true);
+        shell::take_dry_run_journal();
+        let mut mgr = VrfMgr::new();
+
+        mgr.process_mgmt_vrf_config_set("vrf_global", &mgmt_vrf_config("true"))
+            .await
+            .unwrap();
+        assert!(mgr.is_mgmt_vrf_enabled());
+
+        // Re-applying the same setting is a no-op
+        mgr.process_mgmt_vrf_config_set("vrf_global", &mgmt_vrf_config("true"))
+            .await
+            .unwrap();
+
+        let l3mdev = |family: &str| {
+            format!(
+                "/sbin/ip{0} rule show pref 1000 | /bin/grep -q l3mdev || \
+                 /sbin/ip{0} rule add pref 1000 l3mdev",
+                family
+            )
+        };
+        let enable = vec![
+            "/sbin/ip link add mgmt type vrf table 5000".to_string(),
+            "/sbin/ip link set dev mgmt up".to_string(),
+            l3mdev(""),
+            l3mdev(" -6"),
+            "/sbin/ip link add lo-m type dummy".to_string(),
+            "/sbin/ip addr add 127.0.0.1/16 dev lo-m".to_string(),
+            "/sbin/ip link set dev lo-m master mgmt".to_string(),
+            "/sbin/ip link set dev lo-m up".to_string(),
+            "/sbin/ip rule add pref 1002 ipproto tcp sport 22 table 5000".to_string(),
+            "/sbin/ip rule add pref 1002 ipproto udp dport 123 table 5000".to_string(),
+            "/sbin/ip rule add pref 1002 ipproto udp dport 53 table 5000".to_string(),
+            "/sbin/ip -6 rule add pref 1002 ipproto tcp sport 22 table 5000".to_string(),
+            "/sbin/ip -6 rule add pref 1002 ipproto udp dport 123 table 5000".to_string(),
+            "/sbin/ip -6 rule add pref 1002 ipproto udp dport 53 table 5000".to_string(),
+            "/sbin/ip link set dev \"eth0\" master mgmt".to_string(),
+        ];
+        assert_eq!(shell::take_dry_run_journal(), enable);
+
+        mgr.process_mgmt_vrf_config_set("vrf_global", &mgmt_vrf_config("false"))
+            .await
+            .unwrap();
+        assert!(!mgr.is_mgmt_vrf_enabled());
+
+        // eth0 is released before anything else is removed
+        let disable = vec![
+            "/sbin/ip link set dev \"eth0\" nomaster",
+            "/sbin/ip -6 rule del pref 1002 ipproto udp dport 53 table 5000",
+            "/sbin/ip -6 rule del pref 1002 ipproto udp dport 123 table 5000",
+            "/sbin/ip -6 rule del pref 1002 ipproto tcp sport 22 table 5000",
+            "/sbin/ip rule del pref 1002 ipproto udp dport 53 table 5000",
+            "/sbin/ip rule del pref 1002 ipproto udp dport 123 table 5000",
+            "/sbin/ip rule del pref 1002 ipproto tcp sport 22 table 5000",
+            "/sbin/ip link del lo-m",
+            "/sbin/ip link del mgmt",
+        ];
+        assert_eq!(shell::take_dry_run_journal(), disable);
+        shell::set_dry_run(false);
+    }
+
+    #[tokio::test]
+    async fn test_mgmt_vrf_enable_rollback() {
+        let mut mgr = VrfMgr::new().with_mock_mode();
+        mgr.fail_command = Some("dport 123".to_string());
+
+        let result = mgr
+            .process_mgmt_vrf_config_set("vrf_global", &mgmt_vrf_config("true"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(CfgMgrError::ShellCommandFailed { .. })
+        ));
+        assert!(!mgr.is_mgmt_vrf_enabled());
+
+        // eth0 was never touched; the applied steps are undone in reverse
+        let cmds = mgr.captured_commands();
+        assert!(!cmds.iter().any(|c| c.contains("eth0")));
+        let failed = cmds.iter().position(|c| c.contains("dport 123")).unwrap();
+        assert_eq!(
+            cmds[failed + 1..],
+            [
+                "/sbin/ip rule del pref 1002 ipproto tcp sport 22 table 5000",
+                "/sbin/ip link del lo-m",
+                "/sbin/ip link del mgmt",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mgmt_vrf_config_del() {
+        let mut mgr = VrfMgr::new().with_mock_mode();
+
+        // Nothing to tear down
+        mgr.process_mgmt_vrf_config_del("vrf_global").await.unwrap();
+        assert!(mgr.captured_commands().is_empty());
+
+        mgr.process_mgmt_vrf_config_set("vrf_global", &mgmt_vrf_config("true"))
+            .await
+            .unwrap();
+        mgr.captured_commands.clear();
+        mgr.process_mgmt_vrf_config_del("vrf_global").await.unwrap();
+
+        assert!(!mgr.is_mgmt_vrf_enabled());
+        assert_eq!(
+            mgr.captured_commands().first().map(String::as_str),
+            Some("/sbin/ip link set dev \"eth0\" nomaster")
+        );
+    }
 }